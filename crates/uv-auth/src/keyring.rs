@@ -1,9 +1,15 @@
 use std::process::Stdio;
+use std::sync::Arc;
+
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use tracing::{instrument, trace, warn};
 use url::Url;
 
 use crate::credentials::Credentials;
+use crate::realm::Realm;
+use crate::token_cache::TokenCache;
+use crate::TOKEN_CACHE;
 
 /// A backend for retrieving credentials from a keyring.
 ///
@@ -12,6 +18,8 @@ use crate::credentials::Credentials;
 #[derive(Debug)]
 pub struct KeyringProvider {
     backend: KeyringProviderBackend,
+    /// A persistent cache of the credentials retrieved from the keyring, if enabled.
+    token_cache: Option<Arc<TokenCache>>,
 }
 
 #[derive(Debug)]
@@ -27,6 +35,7 @@ impl KeyringProvider {
     pub fn subprocess() -> Self {
         Self {
             backend: KeyringProviderBackend::Subprocess,
+            token_cache: TOKEN_CACHE.clone(),
         }
     }
 
@@ -50,6 +59,13 @@ impl KeyringProvider {
             "Should only use keyring with a username"
         );
 
+        // Check the persistent token cache, if enabled
+        if let Some(token_cache) = self.token_cache.as_ref() {
+            if let Some(password) = token_cache.get(self, &Realm::from(url), username).await {
+                return Some(Credentials::new(Some(username.to_string()), Some(password)));
+            }
+        }
+
         // Check the full URL first
         // <https://github.com/pypa/pip/blob/ae5fff36b0aad6e5e0037884927eaa29163c0611/src/pip/_internal/network/auth.py#L376C1-L379C14>
         trace!("Checking keyring for URL {url}");
@@ -79,9 +95,55 @@ impl KeyringProvider {
             };
         }
 
+        if let (Some(token_cache), Some(password)) = (self.token_cache.as_ref(), password.as_ref())
+        {
+            token_cache
+                .insert(self, &Realm::from(url), username, password)
+                .await;
+        }
+
         password.map(|password| Credentials::new(Some(username.to_string()), Some(password)))
     }
 
+    /// Evict the credentials for the given [`Url`] and username from the persistent token cache,
+    /// e.g., after they were rejected by the server.
+    pub(crate) async fn evict(&self, url: &Url, username: &str) {
+        if let Some(token_cache) = self.token_cache.as_ref() {
+            token_cache.remove(self, &Realm::from(url), username).await;
+        }
+    }
+
+    /// Fetch a secret for the given service and username from the keyring.
+    pub(crate) async fn fetch_secret(&self, service_name: &str, username: &str) -> Option<String> {
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                self.fetch_subprocess(service_name, username).await
+            }
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(ref store) => {
+                Self::fetch_dummy(store, service_name, username)
+            }
+        }
+    }
+
+    /// Store a secret for the given service and username in the keyring.
+    ///
+    /// Returns `true` if the secret was stored.
+    pub(crate) async fn store_secret(
+        &self,
+        service_name: &str,
+        username: &str,
+        secret: &str,
+    ) -> bool {
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                self.store_subprocess(service_name, username, secret).await
+            }
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(_) => false,
+        }
+    }
+
     #[instrument(skip(self))]
     async fn fetch_subprocess(&self, service_name: &str, username: &str) -> Option<String> {
         // https://github.com/pypa/pip/blob/24.0/src/pip/_internal/network/auth.py#L136-L141
@@ -114,6 +176,36 @@ impl KeyringProvider {
         }
     }
 
+    #[instrument(skip(self, secret))]
+    async fn store_subprocess(&self, service_name: &str, username: &str, secret: &str) -> bool {
+        let Ok(mut child) = Command::new("keyring")
+            .arg("set")
+            .arg(service_name)
+            .arg(username)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .inspect_err(|err| warn!("Failure running `keyring` command: {err}"))
+        else {
+            return false;
+        };
+
+        // The `keyring` command reads the password from stdin when it's not a terminal.
+        if let Some(mut stdin) = child.stdin.take() {
+            if let Err(err) = stdin.write_all(format!("{secret}\n").as_bytes()).await {
+                warn!("Failed to write to `keyring` command: {err}");
+                return false;
+            }
+        }
+
+        child
+            .wait()
+            .await
+            .inspect_err(|err| warn!("Failed to wait for `keyring` command: {err}"))
+            .is_ok_and(|status| status.success())
+    }
+
     #[cfg(test)]
     fn fetch_dummy(
         store: &std::collections::HashMap<(String, &'static str), &'static str>,
@@ -136,6 +228,7 @@ impl KeyringProvider {
                     .map(|((service, username), password)| ((service.into(), username), password))
                     .collect(),
             ),
+            token_cache: None,
         }
     }
 
@@ -146,8 +239,17 @@ impl KeyringProvider {
 
         Self {
             backend: KeyringProviderBackend::Dummy(HashMap::new()),
+            token_cache: None,
         }
     }
+
+    /// Configure a [`TokenCache`] for the provider.
+    #[cfg(test)]
+    #[must_use]
+    pub(crate) fn with_token_cache(mut self, token_cache: TokenCache) -> Self {
+        self.token_cache = Some(Arc::new(token_cache));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -8,12 +8,14 @@ pub use credentials::Credentials;
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
 use realm::Realm;
+use token_cache::TokenCache;
 
 mod cache;
 mod credentials;
 mod keyring;
 mod middleware;
 mod realm;
+mod token_cache;
 
 // TODO(zanieb): Consider passing a cache explicitly throughout
 
@@ -23,6 +25,12 @@ mod realm;
 pub(crate) static CREDENTIALS_CACHE: LazyLock<CredentialsCache> =
     LazyLock::new(CredentialsCache::default);
 
+/// Global persistent cache of credentials retrieved from the keyring, if enabled.
+///
+/// This is used to share credentials across uv invocations.
+pub(crate) static TOKEN_CACHE: LazyLock<Option<Arc<TokenCache>>> =
+    LazyLock::new(|| TokenCache::from_env().map(Arc::new));
+
 /// Populate the global authentication store with credentials on a URL, if there are any.
 ///
 /// Returns `true` if the store was updated.
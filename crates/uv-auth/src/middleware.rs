@@ -358,6 +358,18 @@ impl AuthMiddleware {
         {
             trace!("Updating cached credentials for {url} to {credentials:?}");
             self.cache().insert(&url, credentials);
+        } else if result.as_ref().is_ok_and(|response| {
+            matches!(
+                response.status(),
+                StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED
+            )
+        }) {
+            // Evict rejected credentials from the persistent token cache, such that the next
+            // invocation queries the keyring again
+            if let (Some(keyring), Some(username)) = (self.keyring.as_ref(), credentials.username())
+            {
+                keyring.evict(&url, username).await;
+            }
         };
 
         result
@@ -0,0 +1,382 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::prelude::{BASE64_STANDARD, BASE64_URL_SAFE_NO_PAD};
+use base64::Engine;
+use fs_err as fs;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+use tracing::{debug, trace, warn};
+
+use uv_fs::LockedFile;
+use uv_state::{StateBucket, StateStore};
+use uv_static::EnvVars;
+
+use crate::keyring::KeyringProvider;
+use crate::realm::Realm;
+
+/// The length of the encryption key, in bytes.
+const KEY_LEN: usize = 32;
+
+/// The keyring service under which the encryption key is stored, if not provided via
+/// `UV_AUTH_TOKEN_CACHE_KEY`.
+const KEY_SERVICE: &str = "uv-auth-token-cache";
+
+/// The keyring username under which the encryption key is stored.
+const KEY_USERNAME: &str = "uv";
+
+/// Additional authenticated data bound to each encrypted cache file, to reject files written in
+/// an incompatible format.
+const AAD: &[u8] = b"uv-auth-token-cache-v1";
+
+/// The default duration for which credentials are cached.
+const DEFAULT_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// A persistent cache of credentials retrieved from the keyring, shared across uv invocations.
+///
+/// Keyring lookups can be slow, or require user interaction, and are repeated by every uv
+/// invocation. When enabled via `UV_AUTH_TOKEN_CACHE`, passwords retrieved from the keyring are
+/// stored in a file in the uv state directory, encrypted with ChaCha20-Poly1305, and reused by
+/// later invocations until they expire.
+///
+/// The encryption key is read from `UV_AUTH_TOKEN_CACHE_KEY` or, if unset, from the keyring
+/// itself (in which case a key is generated and stored on first use). In the latter case, each
+/// invocation still performs a single keyring lookup, for the key, in place of a lookup per index;
+/// only an explicit `UV_AUTH_TOKEN_CACHE_KEY` avoids the keyring entirely.
+///
+/// Modifications to the cache file, and the generation of the key, are serialized across processes
+/// via a lock file alongside the cache.
+///
+/// Credentials expire after `UV_AUTH_TOKEN_CACHE_TTL` seconds (15 minutes, by default) or, if the
+/// password is a JSON Web Token, at the token's expiration time, if earlier. Credentials that are
+/// rejected by the server are evicted.
+///
+/// Errors in reading or writing the cache are logged and treated as cache misses.
+#[derive(Debug)]
+pub(crate) struct TokenCache {
+    /// The path to the encrypted cache file.
+    path: PathBuf,
+    /// The duration for which credentials are cached.
+    ttl: Duration,
+    /// The encryption key, if provided explicitly.
+    key: Option<[u8; KEY_LEN]>,
+    /// The resolved encryption key, or `None` if no key could be resolved.
+    resolved: OnceCell<Option<LessSafeKey>>,
+}
+
+/// A cached password.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    /// The cached password.
+    password: String,
+    /// The expiration time of the entry, in seconds since the Unix epoch.
+    expires: u64,
+}
+
+/// The decrypted contents of the cache, keyed by `{username}@{realm}`.
+type Entries = BTreeMap<String, Entry>;
+
+impl TokenCache {
+    /// Create a new cache at the given path.
+    ///
+    /// If `key` is `None`, the key is read from (or generated and stored in) the keyring.
+    pub(crate) fn new(path: PathBuf, ttl: Duration, key: Option<[u8; KEY_LEN]>) -> Self {
+        Self {
+            path,
+            ttl,
+            key,
+            resolved: OnceCell::new(),
+        }
+    }
+
+    /// Create a cache from the `UV_AUTH_TOKEN_CACHE` environment variables, if enabled.
+    pub(crate) fn from_env() -> Option<Self> {
+        let enabled = std::env::var(EnvVars::UV_AUTH_TOKEN_CACHE)
+            .ok()
+            .is_some_and(|value| matches!(value.as_str(), "1" | "true" | "yes" | "on"));
+        if !enabled {
+            return None;
+        }
+
+        let key = match std::env::var(EnvVars::UV_AUTH_TOKEN_CACHE_KEY) {
+            Ok(key) => match decode_key(&key) {
+                Some(key) => Some(key),
+                None => {
+                    warn!(
+                        "Ignoring `{}`: expected a base64-encoded {KEY_LEN}-byte key; the token cache is disabled",
+                        EnvVars::UV_AUTH_TOKEN_CACHE_KEY
+                    );
+                    return None;
+                }
+            },
+            Err(_) => None,
+        };
+
+        let ttl = match std::env::var(EnvVars::UV_AUTH_TOKEN_CACHE_TTL) {
+            Ok(ttl) => match u64::from_str(&ttl) {
+                Ok(seconds) => Duration::from_secs(seconds),
+                Err(err) => {
+                    warn!(
+                        "Ignoring invalid `{}` value `{ttl}`: {err}",
+                        EnvVars::UV_AUTH_TOKEN_CACHE_TTL
+                    );
+                    DEFAULT_TTL
+                }
+            },
+            Err(_) => DEFAULT_TTL,
+        };
+
+        let store = StateStore::from_settings(None)
+            .inspect_err(|err| warn!("Failed to locate the token cache: {err}"))
+            .ok()?;
+        let path = store.bucket(StateBucket::Credentials).join("tokens.bin");
+
+        debug!("Using token cache at: {}", path.display());
+        Some(Self::new(path, ttl, key))
+    }
+
+    /// Return the cached password for the given realm and username, if any.
+    pub(crate) async fn get(
+        &self,
+        keyring: &KeyringProvider,
+        realm: &Realm,
+        username: &str,
+    ) -> Option<String> {
+        let key = self.key(keyring).await?;
+        let entries = self
+            .read(key)
+            .inspect_err(|err| debug!("Failed to read token cache: {err}"))
+            .ok()?;
+        let entry = entries.get(&entry_key(realm, username))?;
+        if entry.expires <= now() {
+            trace!("Cached token for {username}@{realm} has expired");
+            return None;
+        }
+        trace!("Found cached token for {username}@{realm}");
+        Some(entry.password.clone())
+    }
+
+    /// Cache the password for the given realm and username.
+    pub(crate) async fn insert(
+        &self,
+        keyring: &KeyringProvider,
+        realm: &Realm,
+        username: &str,
+        password: &str,
+    ) {
+        let now = now();
+        let mut expires = now.saturating_add(self.ttl.as_secs());
+        if let Some(exp) = jwt_expiration(password) {
+            expires = expires.min(exp);
+        }
+        if expires <= now {
+            trace!("Skipping caching of expired token for {username}@{realm}");
+            return;
+        }
+
+        self.update(keyring, |entries| {
+            entries.insert(
+                entry_key(realm, username),
+                Entry {
+                    password: password.to_string(),
+                    expires,
+                },
+            );
+        })
+        .await;
+    }
+
+    /// Remove the cached password for the given realm and username, if any.
+    pub(crate) async fn remove(&self, keyring: &KeyringProvider, realm: &Realm, username: &str) {
+        self.update(keyring, |entries| {
+            if entries.remove(&entry_key(realm, username)).is_some() {
+                debug!("Evicted cached token for {username}@{realm}");
+            }
+        })
+        .await;
+    }
+
+    /// Apply a modification to the cache, pruning any expired entries.
+    ///
+    /// The cache is locked for the duration of the read-modify-write, such that concurrent writers
+    /// don't overwrite each other's changes.
+    async fn update(&self, keyring: &KeyringProvider, f: impl FnOnce(&mut Entries)) {
+        let Some(key) = self.key(keyring).await else {
+            return;
+        };
+        let _lock = match self.lock().await {
+            Ok(lock) => lock,
+            Err(err) => {
+                warn!("Failed to lock token cache: {err}");
+                return;
+            }
+        };
+        let mut entries = self.read(key).unwrap_or_else(|err| {
+            debug!("Discarding unreadable token cache: {err}");
+            Entries::new()
+        });
+        let now = now();
+        entries.retain(|_, entry| entry.expires > now);
+        f(&mut entries);
+        if let Err(err) = self.write(key, &entries) {
+            warn!("Failed to write token cache: {err}");
+        }
+    }
+
+    /// Resolve the encryption key, from the environment or the keyring.
+    async fn key(&self, keyring: &KeyringProvider) -> Option<&LessSafeKey> {
+        self.resolved
+            .get_or_init(|| async {
+                let bytes = if let Some(key) = self.key {
+                    key
+                } else if let Some(key) = keyring.fetch_secret(KEY_SERVICE, KEY_USERNAME).await {
+                    let Some(key) = decode_key(&key) else {
+                        warn!("Ignoring invalid token cache key in keyring; the token cache is disabled");
+                        return None;
+                    };
+                    key
+                } else {
+                    // Hold the lock while generating the key, and check the keyring again once
+                    // acquired, such that concurrent first runs agree on a single key.
+                    let _lock = self
+                        .lock()
+                        .await
+                        .inspect_err(|err| warn!("Failed to lock token cache: {err}"))
+                        .ok()?;
+                    if let Some(key) = keyring.fetch_secret(KEY_SERVICE, KEY_USERNAME).await {
+                        let Some(key) = decode_key(&key) else {
+                            warn!("Ignoring invalid token cache key in keyring; the token cache is disabled");
+                            return None;
+                        };
+                        key
+                    } else {
+                        debug!("Generating token cache key");
+                        let mut key = [0u8; KEY_LEN];
+                        SystemRandom::new().fill(&mut key).ok()?;
+                        if !keyring
+                            .store_secret(KEY_SERVICE, KEY_USERNAME, &BASE64_STANDARD.encode(key))
+                            .await
+                        {
+                            warn!("Failed to store token cache key in keyring; the token cache is disabled");
+                            return None;
+                        }
+                        key
+                    }
+                };
+                Some(LessSafeKey::new(
+                    UnboundKey::new(&CHACHA20_POLY1305, &bytes).ok()?,
+                ))
+            })
+            .await
+            .as_ref()
+    }
+
+    /// Acquire an exclusive lock on the cache, across processes.
+    async fn lock(&self) -> Result<LockedFile, io::Error> {
+        let parent = self.path.parent().unwrap_or(Path::new("."));
+        fs::create_dir_all(parent)?;
+        LockedFile::acquire(parent.join(".tokens.lock"), "token cache").await
+    }
+
+    /// Read and decrypt the cache file.
+    fn read(&self, key: &LessSafeKey) -> Result<Entries, io::Error> {
+        let mut contents = match fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Entries::new()),
+            Err(err) => return Err(err),
+        };
+        if contents.len() < NONCE_LEN {
+            return Err(invalid_data("truncated token cache"));
+        }
+        let mut ciphertext = contents.split_off(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(&contents)
+            .map_err(|_| invalid_data("invalid nonce"))?;
+        let plaintext = key
+            .open_in_place(nonce, Aad::from(AAD), &mut ciphertext)
+            .map_err(|_| invalid_data("failed to decrypt token cache"))?;
+        serde_json::from_slice(plaintext).map_err(io::Error::other)
+    }
+
+    /// Encrypt and write the cache file.
+    fn write(&self, key: &LessSafeKey, entries: &Entries) -> Result<(), io::Error> {
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| io::Error::other("failed to generate nonce"))?;
+        let mut buffer = serde_json::to_vec(entries).map_err(io::Error::other)?;
+        key.seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(AAD),
+            &mut buffer,
+        )
+        .map_err(|_| io::Error::other("failed to encrypt token cache"))?;
+
+        let parent = self.path.parent().unwrap_or(Path::new("."));
+        fs::create_dir_all(parent)?;
+
+        // Write to a temporary file, then rename, such that readers never observe a partial write.
+        let temp = parent.join(format!(".tokens.{}.tmp", std::process::id()));
+        {
+            let mut options = fs::OpenOptions::new();
+            options.write(true).create(true).truncate(true);
+            #[cfg(unix)]
+            {
+                use fs_err::os::unix::fs::OpenOptionsExt;
+                options.mode(0o600);
+            }
+            let mut file = options.open(&temp)?;
+            file.write_all(&nonce)?;
+            file.write_all(&buffer)?;
+        }
+        fs::rename(&temp, &self.path)?;
+        Ok(())
+    }
+}
+
+/// Decode a base64-encoded encryption key.
+fn decode_key(key: &str) -> Option<[u8; KEY_LEN]> {
+    BASE64_STANDARD.decode(key.trim()).ok()?.try_into().ok()
+}
+
+/// Return the cache key for the given realm and username.
+fn entry_key(realm: &Realm, username: &str) -> String {
+    format!("{username}@{realm}")
+}
+
+/// Return the expiration time of the password, if it's a JSON Web Token with an `exp` claim.
+fn jwt_expiration(password: &str) -> Option<u64> {
+    #[derive(Deserialize)]
+    struct Claims {
+        exp: u64,
+    }
+
+    let mut parts = password.split('.');
+    let (Some(_header), Some(payload), Some(_signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+    let payload = BASE64_URL_SAFE_NO_PAD.decode(payload).ok()?;
+    let claims: Claims = serde_json::from_slice(&payload).ok()?;
+    Some(claims.exp)
+}
+
+/// Return the current time, in seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,151 @@
+use url::Url;
+
+use super::*;
+use crate::credentials::Credentials;
+
+fn cache(dir: &Path, key: [u8; KEY_LEN]) -> TokenCache {
+    TokenCache::new(dir.join("tokens.bin"), DEFAULT_TTL, Some(key))
+}
+
+#[tokio::test]
+async fn round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let keyring = KeyringProvider::empty();
+    let realm = Realm::from(&Url::parse("https://example.com/simple").unwrap());
+
+    let cache = cache(dir.path(), [1; KEY_LEN]);
+    assert_eq!(cache.get(&keyring, &realm, "user").await, None);
+
+    cache.insert(&keyring, &realm, "user", "password").await;
+    assert_eq!(
+        cache.get(&keyring, &realm, "user").await.as_deref(),
+        Some("password")
+    );
+    assert_eq!(cache.get(&keyring, &realm, "other").await, None);
+
+    // The file is encrypted.
+    let contents = fs::read(dir.path().join("tokens.bin")).unwrap();
+    assert!(!contents.windows(8).any(|window| window == b"password"));
+
+    cache.remove(&keyring, &realm, "user").await;
+    assert_eq!(cache.get(&keyring, &realm, "user").await, None);
+}
+
+#[tokio::test]
+async fn wrong_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let keyring = KeyringProvider::empty();
+    let realm = Realm::from(&Url::parse("https://example.com").unwrap());
+
+    cache(dir.path(), [1; KEY_LEN])
+        .insert(&keyring, &realm, "user", "password")
+        .await;
+    assert_eq!(
+        cache(dir.path(), [2; KEY_LEN])
+            .get(&keyring, &realm, "user")
+            .await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn expired_jwt() {
+    let dir = tempfile::tempdir().unwrap();
+    let keyring = KeyringProvider::empty();
+    let realm = Realm::from(&Url::parse("https://example.com").unwrap());
+
+    // A token that expired at the Unix epoch is never cached.
+    let token = format!(
+        "header.{}.signature",
+        BASE64_URL_SAFE_NO_PAD.encode(r#"{"exp":1}"#)
+    );
+    let cache = cache(dir.path(), [1; KEY_LEN]);
+    cache.insert(&keyring, &realm, "user", &token).await;
+    assert_eq!(cache.get(&keyring, &realm, "user").await, None);
+}
+
+#[test]
+fn jwt_expiration_claim() {
+    let token = format!(
+        "header.{}.signature",
+        BASE64_URL_SAFE_NO_PAD.encode(r#"{"sub":"user","exp":1700000000}"#)
+    );
+    assert_eq!(jwt_expiration(&token), Some(1_700_000_000));
+    assert_eq!(jwt_expiration("password"), None);
+    assert_eq!(jwt_expiration("a.b.c.d"), None);
+}
+
+#[tokio::test]
+async fn keyring_without_key() {
+    // If no key is provided and none can be stored in the keyring, the cache is disabled.
+    let dir = tempfile::tempdir().unwrap();
+    let keyring = KeyringProvider::empty();
+    let realm = Realm::from(&Url::parse("https://example.com").unwrap());
+
+    let cache = TokenCache::new(dir.path().join("tokens.bin"), DEFAULT_TTL, None);
+    cache.insert(&keyring, &realm, "user", "password").await;
+    assert_eq!(cache.get(&keyring, &realm, "user").await, None);
+    assert!(!dir.path().join("tokens.bin").exists());
+}
+
+#[tokio::test]
+async fn keyring_fetch_uses_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let url = Url::parse("https://example.com").unwrap();
+
+    // Populate the cache via a keyring lookup.
+    let keyring = KeyringProvider::dummy([((url.host_str().unwrap(), "user"), "password")])
+        .with_token_cache(cache(dir.path(), [1; KEY_LEN]));
+    assert!(keyring.fetch(&url, "user").await.is_some());
+
+    // A keyring without the credentials retrieves them from the cache.
+    let keyring = KeyringProvider::empty().with_token_cache(cache(dir.path(), [1; KEY_LEN]));
+    assert_eq!(
+        keyring.fetch(&url, "user").await,
+        Some(Credentials::new(
+            Some("user".to_string()),
+            Some("password".to_string())
+        ))
+    );
+
+    // Once evicted, the credentials are no longer available.
+    keyring.evict(&url, "user").await;
+    assert_eq!(keyring.fetch(&url, "user").await, None);
+}
+
+#[test]
+fn concurrent_inserts() {
+    // Concurrent writers, each with their own handle to the cache, don't lose each other's entries.
+    let dir = tempfile::tempdir().unwrap();
+    let realm = Realm::from(&Url::parse("https://example.com").unwrap());
+
+    std::thread::scope(|scope| {
+        for i in 0..16 {
+            let dir = dir.path();
+            let realm = &realm;
+            scope.spawn(move || {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .build()
+                    .unwrap();
+                runtime.block_on(cache(dir, [1; KEY_LEN]).insert(
+                    &KeyringProvider::empty(),
+                    realm,
+                    &format!("user{i}"),
+                    &format!("password{i}"),
+                ));
+            });
+        }
+    });
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let cache = cache(dir.path(), [1; KEY_LEN]);
+    let keyring = KeyringProvider::empty();
+    for i in 0..16 {
+        assert_eq!(
+            runtime.block_on(cache.get(&keyring, &realm, &format!("user{i}"))),
+            Some(format!("password{i}"))
+        );
+    }
+}
@@ -96,7 +96,7 @@ mod resolver {
     use uv_install_wheel::linker::LinkMode;
     use uv_pep440::Version;
     use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder};
-    use uv_platform_tags::{Arch, Os, Platform, Tags};
+    use uv_platform_tags::{Arch, Os, Platform, TagPreferences, Tags};
     use uv_pypi_types::{ConflictingGroupList, ResolverMarkerEnvironment};
     use uv_python::Interpreter;
     use uv_resolver::{
@@ -130,7 +130,16 @@ mod resolver {
     );
 
     static TAGS: LazyLock<Tags> = LazyLock::new(|| {
-        Tags::from_env(&PLATFORM, (3, 11), "cpython", (3, 11), false, false).unwrap()
+        Tags::from_env(
+            &PLATFORM,
+            (3, 11),
+            "cpython",
+            (3, 11),
+            false,
+            false,
+            &TagPreferences::default(),
+        )
+        .unwrap()
     });
 
     pub(crate) async fn resolve(
@@ -162,6 +171,7 @@ mod resolver {
         let installed_packages = EmptyInstalledPackages;
         let options = OptionsBuilder::new().exclude_newer(exclude_newer).build();
         let sources = SourceStrategy::default();
+        let tag_preferences = TagPreferences::default();
         let dependency_metadata = DependencyMetadata::default();
         let conflicting_groups = ConflictingGroupList::empty();
 
@@ -196,6 +206,7 @@ mod resolver {
             LowerBound::default(),
             sources,
             concurrency,
+            &tag_preferences,
         );
 
         let markers = if universal {
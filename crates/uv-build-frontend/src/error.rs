@@ -181,18 +181,23 @@ impl Display for MissingHeaderCause {
     }
 }
 
-#[derive(Debug, Error)]
-pub struct BuildBackendError {
-    message: String,
-    exit_code: ExitStatus,
-    stdout: Vec<String>,
-    stderr: Vec<String>,
+/// The captured output of a failed build backend invocation, along with the requirements that
+/// were installed into the build environment.
+#[derive(Debug, Clone)]
+pub struct BuildLog {
+    /// The `stdout` of the build backend.
+    pub stdout: Vec<String>,
+    /// The `stderr` of the build backend.
+    pub stderr: Vec<String>,
+    /// The resolved requirements of the build environment, e.g., `setuptools==75.1.0`.
+    ///
+    /// Empty if build isolation is disabled.
+    pub requirements: Vec<String>,
 }
 
-impl Display for BuildBackendError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} ({})", self.message, self.exit_code)?;
-
+impl BuildLog {
+    /// Write the captured output to the formatter, as displayed with `--verbose`.
+    fn write_output(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut non_empty = false;
 
         if self.stdout.iter().any(|line| !line.trim().is_empty()) {
@@ -213,12 +218,33 @@ impl Display for BuildBackendError {
     }
 }
 
+#[derive(Debug, Error)]
+pub struct BuildBackendError {
+    message: String,
+    exit_code: ExitStatus,
+    log: BuildLog,
+    /// Whether to include the captured output in the error message. The output is always
+    /// retained for build reports, but is only displayed if it wasn't already streamed to the
+    /// user.
+    show_output: bool,
+}
+
+impl Display for BuildBackendError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.message, self.exit_code)?;
+        if self.show_output {
+            self.log.write_output(f)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Error)]
 pub struct MissingHeaderError {
     message: String,
     exit_code: ExitStatus,
-    stdout: Vec<String>,
-    stderr: Vec<String>,
+    log: BuildLog,
+    show_output: bool,
     #[source]
     cause: MissingHeaderCause,
 }
@@ -226,23 +252,9 @@ pub struct MissingHeaderError {
 impl Display for MissingHeaderError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} ({})", self.message, self.exit_code)?;
-
-        let mut non_empty = false;
-
-        if self.stdout.iter().any(|line| !line.trim().is_empty()) {
-            write!(f, "\n\n{}\n{}", "[stdout]".red(), self.stdout.join("\n"))?;
-            non_empty = true;
-        }
-
-        if self.stderr.iter().any(|line| !line.trim().is_empty()) {
-            write!(f, "\n\n{}\n{}", "[stderr]".red(), self.stderr.join("\n"))?;
-            non_empty = true;
+        if self.show_output {
+            self.log.write_output(f)?;
         }
-
-        if non_empty {
-            writeln!(f)?;
-        }
-
         Ok(())
     }
 }
@@ -253,6 +265,7 @@ impl Error {
         message: String,
         output: &PythonRunnerOutput,
         level: BuildOutput,
+        requirements: &[String],
         name: Option<&PackageName>,
         version: Option<&Version>,
         version_id: Option<&str>,
@@ -284,50 +297,48 @@ impl Error {
             }
         });
 
-        if let Some(missing_library) = missing_library {
-            return match level {
-                BuildOutput::Stderr | BuildOutput::Quiet => {
-                    Self::MissingHeader(MissingHeaderError {
-                        message,
-                        exit_code: output.status,
-                        stdout: vec![],
-                        stderr: vec![],
-                        cause: MissingHeaderCause {
-                            missing_library,
-                            package_name: name.cloned(),
-                            package_version: version.cloned(),
-                            version_id: version_id.map(ToString::to_string),
-                        },
-                    })
-                }
-                BuildOutput::Debug => Self::MissingHeader(MissingHeaderError {
-                    message,
-                    exit_code: output.status,
-                    stdout: output.stdout.clone(),
-                    stderr: output.stderr.clone(),
-                    cause: MissingHeaderCause {
-                        missing_library,
-                        package_name: name.cloned(),
-                        package_version: version.cloned(),
-                        version_id: version_id.map(ToString::to_string),
-                    },
-                }),
-            };
-        }
+        let log = BuildLog {
+            stdout: output.stdout.clone(),
+            stderr: output.stderr.clone(),
+            requirements: requirements.to_vec(),
+        };
 
-        match level {
-            BuildOutput::Stderr | BuildOutput::Quiet => Self::BuildBackend(BuildBackendError {
-                message,
-                exit_code: output.status,
-                stdout: vec![],
-                stderr: vec![],
-            }),
-            BuildOutput::Debug => Self::BuildBackend(BuildBackendError {
+        // With `BuildOutput::Stderr`, the output was already streamed to the user.
+        let show_output = match level {
+            BuildOutput::Stderr | BuildOutput::Quiet => false,
+            BuildOutput::Debug => true,
+        };
+
+        if let Some(missing_library) = missing_library {
+            return Self::MissingHeader(MissingHeaderError {
                 message,
                 exit_code: output.status,
-                stdout: output.stdout.clone(),
-                stderr: output.stderr.clone(),
-            }),
+                log,
+                show_output,
+                cause: MissingHeaderCause {
+                    missing_library,
+                    package_name: name.cloned(),
+                    package_version: version.cloned(),
+                    version_id: version_id.map(ToString::to_string),
+                },
+            });
+        }
+
+        Self::BuildBackend(BuildBackendError {
+            message,
+            exit_code: output.status,
+            log,
+            show_output,
+        })
+    }
+
+    /// Return the captured output of the failed build backend invocation, if the error originated
+    /// in the build backend.
+    pub fn build_log(&self) -> Option<&BuildLog> {
+        match self {
+            Self::BuildBackend(err) => Some(&err.log),
+            Self::MissingHeader(err) => Some(&err.log),
+            _ => None,
         }
     }
 }
@@ -372,6 +383,7 @@ mod test {
             "Failed building wheel through setup.py".to_string(),
             &output,
             BuildOutput::Debug,
+            &[],
             None,
             None,
             Some("pygraphviz-1.11"),
@@ -430,6 +442,7 @@ mod test {
             "Failed building wheel through setup.py".to_string(),
             &output,
             BuildOutput::Debug,
+            &[],
             None,
             None,
             Some("pygraphviz-1.11"),
@@ -477,6 +490,7 @@ mod test {
             "Failed building wheel through setup.py".to_string(),
             &output,
             BuildOutput::Debug,
+            &[],
             None,
             None,
             Some("pygraphviz-1.11"),
@@ -522,6 +536,7 @@ mod test {
             "Failed building wheel through setup.py".to_string(),
             &output,
             BuildOutput::Debug,
+            &[],
             Some(&PackageName::from_str("pygraphviz").unwrap()),
             Some(&Version::new([1, 11])),
             Some("pygraphviz-1.11"),
@@ -29,7 +29,9 @@ use tracing::{debug, info_span, instrument, Instrument};
 
 use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, LowerBound, SourceStrategy};
 use uv_distribution::RequiresDist;
-use uv_distribution_types::{IndexLocations, Resolution};
+use uv_distribution_types::{
+    DistributionMetadata, IndexLocations, Name, Resolution, VersionOrUrlRef,
+};
 use uv_fs::{PythonExt, Simplified};
 use uv_pep440::Version;
 use uv_pep508::PackageName;
@@ -38,7 +40,7 @@ use uv_python::{Interpreter, PythonEnvironment};
 use uv_static::EnvVars;
 use uv_types::{BuildContext, BuildIsolation, SourceBuildTrait};
 
-pub use crate::error::{Error, MissingHeaderCause};
+pub use crate::error::{BuildLog, Error, MissingHeaderCause};
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend {
@@ -229,6 +231,11 @@ pub struct SourceBuild {
     build_kind: BuildKind,
     /// Whether to send build output to `stderr` or `tracing`, etc.
     level: BuildOutput,
+    /// The resolved requirements of the build environment, for error reporting.
+    build_requirements: Vec<String>,
+    /// The name and version of each resolved requirement of the build environment, for recording
+    /// in lockfiles.
+    build_dependencies: Vec<(PackageName, Version)>,
     /// Modified PATH that contains the `venv_bin`, `user_path` and `system_path` variables in that order
     modified_path: OsString,
     /// Environment variables to be passed in during metadata or wheel building
@@ -311,7 +318,8 @@ impl SourceBuild {
 
         // Set up the build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        if build_isolation.is_isolated(package_name.as_ref()) {
+        let mut build_dependencies = Vec::new();
+        let mut build_requirements = if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Resolving build requirements");
 
             let resolved_requirements = Self::get_resolved_requirements(
@@ -326,9 +334,16 @@ impl SourceBuild {
                 .install(&resolved_requirements, &venv)
                 .await
                 .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err))?;
+
+            build_dependencies = versioned_distributions(&resolved_requirements);
+            resolved_requirements
+                .distributions()
+                .map(ToString::to_string)
+                .collect()
         } else {
             debug!("Proceeding without build isolation");
-        }
+            Vec::new()
+        };
 
         // Figure out what the modified path should be, and remove the PATH variable from the
         // environment variables if it's there.
@@ -381,6 +396,8 @@ impl SourceBuild {
                 source_strategy,
                 build_kind,
                 level,
+                &mut build_requirements,
+                &mut build_dependencies,
                 &config_settings,
                 &environment_variables,
                 &modified_path,
@@ -397,6 +414,8 @@ impl SourceBuild {
             venv,
             build_kind,
             level,
+            build_requirements,
+            build_dependencies,
             config_settings,
             metadata_directory: None,
             package_name,
@@ -536,6 +555,13 @@ impl SourceBuild {
         }
     }
 
+    /// Return the name and version of each resolved requirement of the build environment.
+    ///
+    /// Empty if build isolation is disabled, as the build environment is provided by the user.
+    pub fn build_dependencies(&self) -> &[(PackageName, Version)] {
+        &self.build_dependencies
+    }
+
     /// Try calling `prepare_metadata_for_build_wheel` to get the metadata without executing the
     /// actual build.
     pub async fn get_metadata_without_build(&mut self) -> Result<Option<PathBuf>, Error> {
@@ -631,6 +657,7 @@ impl SourceBuild {
                 ),
                 &output,
                 self.level,
+                &self.build_requirements,
                 self.package_name.as_ref(),
                 self.package_version.as_ref(),
                 self.version_id.as_deref(),
@@ -755,6 +782,7 @@ impl SourceBuild {
                 ),
                 &output,
                 self.level,
+                &self.build_requirements,
                 self.package_name.as_ref(),
                 self.package_version.as_ref(),
                 self.version_id.as_deref(),
@@ -770,6 +798,7 @@ impl SourceBuild {
                 ),
                 &output,
                 self.level,
+                &self.build_requirements,
                 self.package_name.as_ref(),
                 self.package_version.as_ref(),
                 self.version_id.as_deref(),
@@ -810,6 +839,8 @@ async fn create_pep517_build_environment(
     source_strategy: SourceStrategy,
     build_kind: BuildKind,
     level: BuildOutput,
+    build_requirements: &mut Vec<String>,
+    build_dependencies: &mut Vec<(PackageName, Version)>,
     config_settings: &ConfigSettings,
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
@@ -867,6 +898,7 @@ async fn create_pep517_build_environment(
             ),
             &output,
             level,
+            build_requirements,
             package_name,
             package_version,
             version_id,
@@ -882,6 +914,7 @@ async fn create_pep517_build_environment(
             ),
             &output,
             level,
+            build_requirements,
             package_name,
             package_version,
             version_id,
@@ -899,6 +932,7 @@ async fn create_pep517_build_environment(
                     ),
                     &output,
                     level,
+                    build_requirements,
                     package_name,
                     package_version,
                     version_id,
@@ -958,11 +992,29 @@ async fn create_pep517_build_environment(
             .install(&resolution, venv)
             .await
             .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err))?;
+
+        *build_requirements = resolution
+            .distributions()
+            .map(ToString::to_string)
+            .collect();
+        *build_dependencies = versioned_distributions(&resolution);
     }
 
     Ok(())
 }
 
+/// Return the name and version of each distribution in the [`Resolution`], omitting any
+/// distributions without a known version (e.g., those provided as direct URLs).
+fn versioned_distributions(resolution: &Resolution) -> Vec<(PackageName, Version)> {
+    resolution
+        .distributions()
+        .filter_map(|dist| match dist.version_or_url() {
+            VersionOrUrlRef::Version(version) => Some((dist.name().clone(), version.clone())),
+            VersionOrUrlRef::Url(_) => None,
+        })
+        .collect()
+}
+
 /// A runner that manages the execution of external python processes with a
 /// concurrency limit.
 #[derive(Debug)]
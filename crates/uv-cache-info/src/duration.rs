@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A duration for which cached data may be used, e.g., `30s`, `10m`, `24h`, or `7d`.
+///
+/// A bare number is interpreted as a number of seconds.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CacheDuration(Duration);
+
+impl CacheDuration {
+    /// Return the underlying [`Duration`].
+    pub fn as_duration(&self) -> Duration {
+        self.0
+    }
+}
+
+impl From<Duration> for CacheDuration {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+impl FromStr for CacheDuration {
+    type Err = CacheDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (value, unit) = s
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or((s, ""), |index| s.split_at(index));
+        let value = value
+            .parse::<u64>()
+            .map_err(|_| CacheDurationError(s.to_string()))?;
+        let seconds = match unit.trim() {
+            "" | "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            "w" => 7 * 24 * 60 * 60,
+            _ => return Err(CacheDurationError(s.to_string())),
+        };
+        let seconds = value
+            .checked_mul(seconds)
+            .ok_or_else(|| CacheDurationError(s.to_string()))?;
+        Ok(Self(Duration::from_secs(seconds)))
+    }
+}
+
+impl Display for CacheDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let seconds = self.0.as_secs();
+        for (unit, size) in [
+            ("w", 7 * 24 * 60 * 60),
+            ("d", 24 * 60 * 60),
+            ("h", 60 * 60),
+            ("m", 60),
+        ] {
+            if seconds > 0 && seconds % size == 0 {
+                return write!(f, "{}{unit}", seconds / size);
+            }
+        }
+        write!(f, "{seconds}s")
+    }
+}
+
+impl Serialize for CacheDuration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CacheDuration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid duration `{0}` (expected, e.g., `30s`, `10m`, `24h`, or `7d`)")]
+pub struct CacheDurationError(String);
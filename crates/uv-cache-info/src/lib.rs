@@ -1,6 +1,8 @@
 pub use crate::cache_info::*;
+pub use crate::duration::*;
 pub use crate::timestamp::*;
 
 mod cache_info;
+mod duration;
 mod git_info;
 mod timestamp;
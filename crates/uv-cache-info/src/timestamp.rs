@@ -43,4 +43,9 @@ impl Timestamp {
     pub fn now() -> Self {
         Self(std::time::SystemTime::now())
     }
+
+    /// Return the time elapsed since the [`Timestamp`], or zero if it lies in the future.
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.0.elapsed().unwrap_or_default()
+    }
 }
@@ -127,8 +127,12 @@ impl RepositoryUrl {
     pub fn new(url: &Url) -> Self {
         let mut url = CanonicalUrl::new(url).0;
 
-        // If a Git URL ends in a reference (like a branch, tag, or commit), remove it.
-        if url.scheme().starts_with("git+") {
+        // If a Git URL ends in a reference (like a branch, tag, or commit), remove it. The same
+        // applies to Mercurial and Subversion URLs.
+        if ["git+", "hg+", "svn+"]
+            .iter()
+            .any(|prefix| url.scheme().starts_with(prefix))
+        {
             if let Some(prefix) = url
                 .path()
                 .rsplit_once('@')
@@ -1,5 +1,8 @@
 use std::io;
 use std::path::{Path, PathBuf};
+
+use uv_cache_info::CacheDuration;
+use uv_normalize::PackageName;
 use uv_static::EnvVars;
 
 use crate::Cache;
@@ -29,6 +32,28 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR)]
     pub cache_dir: Option<PathBuf>,
+
+    /// The maximum time to wait for a cache lock held by another uv process (e.g., `30s` or `10m`).
+    ///
+    /// By default, uv waits indefinitely for other processes to release their locks, e.g., when
+    /// multiple processes attempt to build the same source distribution.
+    #[arg(global = true, long, env = EnvVars::UV_CACHE_LOCK_TIMEOUT, value_name = "DURATION")]
+    pub cache_lock_timeout: Option<CacheDuration>,
+
+    /// Allow the use of stale index metadata from the cache if the network is unavailable, as long
+    /// as it was fetched or revalidated within the given duration (e.g., `30m`, `24h`, or `7d`).
+    ///
+    /// By default, uv fails if cached index metadata is stale and the index can't be reached.
+    #[arg(global = true, long, env = EnvVars::UV_OFFLINE_GRACE, value_name = "DURATION")]
+    pub offline_grace: Option<CacheDuration>,
+
+    /// Always revalidate cached index metadata for a specific package, regardless of the
+    /// `Cache-Control` headers returned by the index or any `cache-ttl` configured for the index.
+    ///
+    /// Unlike `--refresh-package`, cached wheels and source distribution builds for the package
+    /// are reused.
+    #[arg(global = true, long, value_name = "PACKAGE")]
+    pub revalidate_package: Vec<PackageName>,
 }
 
 impl Cache {
@@ -82,7 +107,14 @@ impl TryFrom<CacheArgs> for Cache {
     type Error = io::Error;
 
     fn try_from(value: CacheArgs) -> Result<Self, Self::Error> {
-        Cache::from_settings(value.no_cache, value.cache_dir)
+        Ok(Cache::from_settings(value.no_cache, value.cache_dir)?
+            .with_lock_timeout(
+                value
+                    .cache_lock_timeout
+                    .map(|timeout| timeout.as_duration()),
+            )
+            .with_revalidate(value.revalidate_package)
+            .with_offline_grace(value.offline_grace.map(|grace| grace.as_duration())))
     }
 }
 
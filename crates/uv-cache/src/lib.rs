@@ -5,6 +5,7 @@ use std::io::Write;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use rustc_hash::FxHashSet;
 use tracing::debug;
@@ -12,13 +13,14 @@ use tracing::debug;
 pub use archive::ArchiveId;
 use uv_cache_info::Timestamp;
 use uv_distribution_types::InstalledDist;
-use uv_fs::{cachedir, directories};
+use uv_fs::{cachedir, directories, LockMode, LockedFile};
 use uv_normalize::PackageName;
 use uv_pypi_types::ResolutionMetadata;
 
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::memory::{enable_memory_cache, MemoryCache};
 use crate::removal::Remover;
 pub use crate::removal::{rm_rf, Removal};
 pub use crate::wheel::WheelCache;
@@ -28,6 +30,8 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod memory;
+pub mod metrics;
 mod removal;
 mod wheel;
 
@@ -120,6 +124,12 @@ pub struct Cache {
     root: PathBuf,
     /// The refresh strategy to use when reading from the cache.
     refresh: Refresh,
+    /// The packages for which cached index metadata must always be revalidated.
+    revalidate: Vec<PackageName>,
+    /// The maximum age of stale index metadata that may be used if the network is unavailable.
+    offline_grace: Option<Duration>,
+    /// The maximum time to wait for a lock held by another process, if any.
+    lock_timeout: Option<Duration>,
     /// A temporary cache directory, if the user requested `--no-cache`.
     ///
     /// Included to ensure that the temporary directory exists for the length of the operation, but
@@ -133,6 +143,9 @@ impl Cache {
         Self {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
+            revalidate: Vec::new(),
+            offline_grace: None,
+            lock_timeout: None,
             temp_dir: None,
         }
     }
@@ -143,6 +156,9 @@ impl Cache {
         Ok(Self {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
+            revalidate: Vec::new(),
+            offline_grace: None,
+            lock_timeout: None,
             temp_dir: Some(Arc::new(temp_dir)),
         })
     }
@@ -153,6 +169,30 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the packages for which cached index metadata must always be revalidated.
+    #[must_use]
+    pub fn with_revalidate(self, revalidate: Vec<PackageName>) -> Self {
+        Self { revalidate, ..self }
+    }
+
+    /// Set the maximum age of stale index metadata that may be used if the network is unavailable.
+    #[must_use]
+    pub fn with_offline_grace(self, offline_grace: Option<Duration>) -> Self {
+        Self {
+            offline_grace,
+            ..self
+        }
+    }
+
+    /// Set the maximum time to wait for a lock held by another process.
+    #[must_use]
+    pub fn with_lock_timeout(self, lock_timeout: Option<Duration>) -> Self {
+        Self {
+            lock_timeout,
+            ..self
+        }
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -209,6 +249,33 @@ impl Cache {
         }
     }
 
+    /// Returns `true` if cached index metadata for the package must be revalidated, regardless of
+    /// its freshness.
+    pub fn revalidates(&self, package: &PackageName) -> bool {
+        self.revalidate.contains(package)
+    }
+
+    /// Return the maximum age of stale index metadata that may be used if the network is
+    /// unavailable, if any.
+    pub fn offline_grace(&self) -> Option<Duration> {
+        self.offline_grace
+    }
+
+    /// Acquire an exclusive lock on a [`CacheShard`], e.g., to prevent concurrent builds.
+    ///
+    /// Entries are always written atomically, so reading from the cache doesn't require a lock; a
+    /// lock is only required to avoid duplicating work across processes.
+    pub async fn lock_shard(&self, shard: &CacheShard) -> Result<LockedFile, io::Error> {
+        fs_err::create_dir_all(shard)?;
+        LockedFile::acquire_with(
+            shard.join(".lock"),
+            shard.display(),
+            LockMode::Exclusive,
+            self.lock_timeout,
+        )
+        .await
+    }
+
     /// Returns the [`Freshness`] for a cache entry, validating it against the [`Refresh`] policy.
     ///
     /// A cache entry is considered fresh if it was created after the cache itself was
@@ -394,6 +461,24 @@ impl Cache {
         Ok(summary)
     }
 
+    /// Return the entries for a package across the cache buckets that are indexed by package
+    /// name, i.e., [`CacheBucket::Wheels`], [`CacheBucket::SourceDistributions`], and
+    /// [`CacheBucket::Simple`].
+    ///
+    /// Only entries that exist are returned. Unzipped wheels are referenced by links within the
+    /// returned entries, rather than returned directly.
+    pub fn package_entries(&self, name: &PackageName) -> Vec<PathBuf> {
+        [
+            CacheBucket::Wheels,
+            CacheBucket::SourceDistributions,
+            CacheBucket::Simple,
+        ]
+        .into_iter()
+        .flat_map(|bucket| bucket.entries(self, name))
+        .filter(|entry| entry.exists())
+        .collect()
+    }
+
     /// Run the garbage collector on the cache, removing any dangling entries.
     pub fn prune(&self, ci: bool) -> Result<Removal, io::Error> {
         let mut summary = Removal::default();
@@ -404,9 +489,11 @@ impl Cache {
             let entry = entry?;
             let metadata = entry.metadata()?;
 
+            // Retain the socket directory of a running `uv daemon`.
             if entry.file_name() == "CACHEDIR.TAG"
                 || entry.file_name() == ".gitignore"
                 || entry.file_name() == ".git"
+                || entry.file_name() == "daemon"
             {
                 continue;
             }
@@ -774,10 +861,18 @@ pub enum CacheBucket {
     Builds,
     /// Reusable virtual environments used to invoke Python tools.
     Environments,
+    /// The state used to run commands in containers (`uv run --container`), per image, including
+    /// a cache, virtual environments, and Python installations for use within the container.
+    Containers,
+    /// Distribution files served by `uv proxy`, as downloaded from their upstream index.
+    ///
+    /// Cache structure: `proxy-v0/<package_name>/<digest(url)>/<filename>`
+    Proxy,
 }
 
 impl CacheBucket {
-    fn to_str(self) -> &'static str {
+    /// Return the name of the bucket's directory within the cache root.
+    pub fn to_str(self) -> &'static str {
         match self {
             // Note that when bumping this, you'll also need to bump it
             // in crates/uv/tests/cache_prune.rs.
@@ -787,20 +882,23 @@ impl CacheBucket {
             Self::Interpreter => "interpreter-v3",
             // Note that when bumping this, you'll also need to bump it
             // in crates/uv/tests/cache_clean.rs.
-            Self::Simple => "simple-v14",
+            Self::Simple => "simple-v15",
             // Note that when bumping this, you'll also need to bump it
             // in crates/uv/tests/cache_prune.rs.
             Self::Wheels => "wheels-v3",
             Self::Archive => "archive-v0",
             Self::Builds => "builds-v0",
             Self::Environments => "environments-v1",
+            Self::Containers => "containers-v0",
+            Self::Proxy => "proxy-v0",
         }
     }
 
-    /// Remove a package from the cache bucket.
+    /// Return the entries for a package in the cache bucket.
     ///
-    /// Returns the number of entries removed from the cache.
-    fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
+    /// The [`CacheBucket::FlatIndex`] bucket can't be attributed to individual packages, so the
+    /// entire bucket is returned.
+    fn entries(self, cache: &Cache, name: &PackageName) -> Vec<PathBuf> {
         /// Returns `true` if the [`Path`] represents a built wheel for the given package.
         fn is_match(path: &Path, name: &PackageName) -> bool {
             let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
@@ -812,37 +910,37 @@ impl CacheBucket {
             metadata.name == *name
         }
 
-        let mut summary = Removal::default();
+        let mut entries = Vec::new();
         match self {
             Self::Wheels => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                entries.push(root.join(name.to_string()));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root) {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    entries.push(directory.join(name.to_string()));
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
                 // directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for directory in directories(root) {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    entries.push(directory.join(name.to_string()));
                 }
             }
             Self::SourceDistributions => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                entries.push(root.join(name.to_string()));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root) {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    entries.push(directory.join(name.to_string()));
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
@@ -851,7 +949,7 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for url in directories(root) {
                     if directories(&url).any(|version| is_match(&version, name)) {
-                        summary += rm_rf(url)?;
+                        entries.push(url);
                     }
                 }
 
@@ -861,7 +959,7 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Path);
                 for path in directories(root) {
                     if directories(&path).any(|version| is_match(&version, name)) {
-                        summary += rm_rf(path)?;
+                        entries.push(path);
                     }
                 }
 
@@ -872,7 +970,7 @@ impl CacheBucket {
                 for repository in directories(root) {
                     for sha in directories(repository) {
                         if is_match(&sha, name) {
-                            summary += rm_rf(sha)?;
+                            entries.push(sha);
                         }
                     }
                 }
@@ -880,20 +978,19 @@ impl CacheBucket {
             Self::Simple => {
                 // For `pypi` wheels, we expect a rkyv file per package, indexed by name.
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(format!("{name}.rkyv")))?;
+                entries.push(root.join(format!("{name}.rkyv")));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root) {
-                    summary += rm_rf(directory.join(format!("{name}.rkyv")))?;
+                    entries.push(directory.join(format!("{name}.rkyv")));
                 }
             }
             Self::FlatIndex => {
-                // We can't know if the flat index includes a package, so we just remove the entire
+                // We can't know if the flat index includes a package, so we just return the entire
                 // cache entry.
-                let root = cache.bucket(self);
-                summary += rm_rf(root)?;
+                entries.push(cache.bucket(self));
             }
             Self::Git => {
                 // Nothing to do.
@@ -910,6 +1007,24 @@ impl CacheBucket {
             Self::Environments => {
                 // Nothing to do.
             }
+            Self::Containers => {
+                // Nothing to do.
+            }
+            Self::Proxy => {
+                // We expect a directory per package (indexed by name).
+                entries.push(cache.bucket(self).join(name.to_string()));
+            }
+        }
+        entries
+    }
+
+    /// Remove a package from the cache bucket.
+    ///
+    /// Returns the number of entries removed from the cache.
+    fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+        for entry in self.entries(cache, name) {
+            summary += rm_rf(entry)?;
         }
         Ok(summary)
     }
@@ -926,6 +1041,8 @@ impl CacheBucket {
             Self::Archive,
             Self::Builds,
             Self::Environments,
+            Self::Containers,
+            Self::Proxy,
         ]
         .iter()
         .copied()
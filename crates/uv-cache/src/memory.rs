@@ -0,0 +1,179 @@
+use std::hash::Hash;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Whether in-memory caching is enabled for the current process.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enable in-memory caching for the current process (and any processes forked from it).
+pub fn enable_memory_cache() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Returns `true` if in-memory caching is enabled for the current process.
+fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// An in-memory layer in front of an on-disk cache.
+///
+/// In-memory caching is disabled by default, since most invocations read each entry at most once.
+/// It's enabled (via [`enable_memory_cache`]) by long-lived processes (i.e., `uv daemon`), which preload entries such that
+/// subsequent invocations can skip reading and deserializing them.
+///
+/// The cache holds at most `capacity` entries; when full, the least recently used entry is evicted.
+#[derive(Debug)]
+pub struct MemoryCache<K, V> {
+    capacity: usize,
+    entries: Mutex<Option<Entries<K, V>>>,
+}
+
+#[derive(Debug)]
+struct Entries<K, V> {
+    /// The cached values, along with the tick at which each was last used.
+    values: FxHashMap<K, (V, u64)>,
+    /// The keys that have been inserted since the last call to [`MemoryCache::take_updated`].
+    updated: FxHashSet<K>,
+    /// A counter that's incremented on every access, to track recency.
+    tick: u64,
+}
+
+impl<K: Clone + Eq + Hash, V> Entries<K, V> {
+    fn new() -> Self {
+        Self {
+            values: FxHashMap::default(),
+            updated: FxHashSet::default(),
+            tick: 0,
+        }
+    }
+
+    /// Remove the least recently used entry.
+    fn evict(&mut self) {
+        let Some(key) = self
+            .values
+            .iter()
+            .min_by_key(|(_, (_, tick))| *tick)
+            .map(|(key, _)| key.clone())
+        else {
+            return;
+        };
+        self.values.remove(&key);
+        self.updated.remove(&key);
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> MemoryCache<K, V> {
+    /// Create an empty [`MemoryCache`] that holds at most `capacity` entries.
+    pub const fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(None),
+        }
+    }
+
+    /// Return the cached value for the given key, if it exists and is still valid.
+    pub fn get(&self, key: &K, is_valid: impl FnOnce(&V) -> bool) -> Option<V> {
+        if !is_enabled() {
+            return None;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        let entries = entries.as_mut()?;
+        entries.tick += 1;
+        let tick = entries.tick;
+        let (value, used) = entries.values.get_mut(key)?;
+        if !is_valid(value) {
+            return None;
+        }
+        *used = tick;
+        Some(value.clone())
+    }
+
+    /// Insert a value into the cache, if in-memory caching is enabled.
+    pub fn insert(&self, key: K, value: V) {
+        self.insert_with(key, || value);
+    }
+
+    /// Insert a lazily-computed value into the cache, if in-memory caching is enabled.
+    pub fn insert_with(&self, key: K, value: impl FnOnce() -> V) {
+        if !is_enabled() || self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        let entries = entries.get_or_insert_with(Entries::new);
+        if !entries.values.contains_key(&key) && entries.values.len() >= self.capacity {
+            entries.evict();
+        }
+        entries.tick += 1;
+        let tick = entries.tick;
+        entries.updated.insert(key.clone());
+        entries.values.insert(key, (value(), tick));
+    }
+
+    /// Return the keys that have been inserted since the last call, e.g., such that a child
+    /// process can report them back to the daemon that spawned it.
+    pub fn take_updated(&self) -> Vec<K> {
+        self.entries
+            .lock()
+            .unwrap()
+            .as_mut()
+            .map(|entries| entries.updated.drain().collect())
+            .unwrap_or_default()
+    }
+
+    /// Return the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map_or(0, |entries| entries.values.len())
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{enable_memory_cache, MemoryCache};
+
+    #[test]
+    fn evict_least_recently_used() {
+        enable_memory_cache();
+
+        let cache = MemoryCache::new(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+
+        // Use `a`, such that `b` is the least recently used entry.
+        assert_eq!(cache.get(&"a", |_| true), Some(1));
+
+        cache.insert("c", 3);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"a", |_| true), Some(1));
+        assert_eq!(cache.get(&"b", |_| true), None);
+        assert_eq!(cache.get(&"c", |_| true), Some(3));
+
+        // Evicted entries are no longer reported as updated.
+        let mut updated = cache.take_updated();
+        updated.sort_unstable();
+        assert_eq!(updated, vec!["a", "c"]);
+        assert!(cache.take_updated().is_empty());
+    }
+
+    #[test]
+    fn replace_without_eviction() {
+        enable_memory_cache();
+
+        let cache = MemoryCache::new(1);
+        cache.insert("a", 1);
+        cache.insert("a", 2);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(&"a", |_| true), Some(2));
+        assert_eq!(cache.get(&"a", |value| *value == 1), None);
+    }
+}
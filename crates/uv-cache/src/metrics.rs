@@ -0,0 +1,180 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// An event that reflects how effectively the cache was used by the current process.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CacheEvent {
+    /// An HTTP response (e.g., for an index page, metadata, or a wheel) was served from the
+    /// cache, without a request.
+    HttpCached,
+    /// An HTTP response was served from the cache, after revalidating it with the server.
+    HttpRevalidated,
+    /// An HTTP response was fetched from the network.
+    HttpNetwork,
+    /// A wheel was requested from an index or URL.
+    Wheel,
+    /// A wheel was downloaded from an index or URL.
+    WheelDownloaded,
+    /// A wheel (or metadata) was requested for a source distribution.
+    SourceDist,
+    /// A source distribution was built, to produce a wheel or its metadata.
+    Build,
+}
+
+impl CacheEvent {
+    fn counter(self) -> &'static AtomicU64 {
+        match self {
+            Self::HttpCached => &HTTP_CACHED,
+            Self::HttpRevalidated => &HTTP_REVALIDATED,
+            Self::HttpNetwork => &HTTP_NETWORK,
+            Self::Wheel => &WHEELS,
+            Self::WheelDownloaded => &WHEELS_DOWNLOADED,
+            Self::SourceDist => &SOURCE_DISTS,
+            Self::Build => &BUILDS,
+        }
+    }
+}
+
+/// A phase of a command, timed for the [`CacheSummary`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Phase {
+    /// Resolving the requirements.
+    Resolve,
+    /// Downloading, building, and unzipping the distributions.
+    Prepare,
+    /// Removing distributions from the environment.
+    Uninstall,
+    /// Installing distributions into the environment.
+    Install,
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Resolve => write!(f, "resolve"),
+            Self::Prepare => write!(f, "prepare"),
+            Self::Uninstall => write!(f, "uninstall"),
+            Self::Install => write!(f, "install"),
+        }
+    }
+}
+
+static HTTP_CACHED: AtomicU64 = AtomicU64::new(0);
+static HTTP_REVALIDATED: AtomicU64 = AtomicU64::new(0);
+static HTTP_NETWORK: AtomicU64 = AtomicU64::new(0);
+static WHEELS: AtomicU64 = AtomicU64::new(0);
+static WHEELS_DOWNLOADED: AtomicU64 = AtomicU64::new(0);
+static SOURCE_DISTS: AtomicU64 = AtomicU64::new(0);
+static BUILDS: AtomicU64 = AtomicU64::new(0);
+static PHASES: Mutex<Vec<(Phase, Duration)>> = Mutex::new(Vec::new());
+
+/// Record an event for the [`CacheSummary`] of the current process.
+pub fn record(event: CacheEvent) {
+    event.counter().fetch_add(1, Ordering::Relaxed);
+}
+
+/// Record the time spent in a phase for the [`CacheSummary`] of the current process.
+///
+/// Repeated phases (e.g., multiple resolutions) are accumulated.
+pub fn record_phase(phase: Phase, duration: Duration) {
+    let mut phases = PHASES.lock().unwrap();
+    if let Some((_, total)) = phases.iter_mut().find(|(existing, _)| *existing == phase) {
+        *total += duration;
+    } else {
+        phases.push((phase, duration));
+    }
+}
+
+/// Reset the recorded events and phases, e.g., at the start of a command in a long-lived
+/// process.
+pub fn reset() {
+    for counter in [
+        &HTTP_CACHED,
+        &HTTP_REVALIDATED,
+        &HTTP_NETWORK,
+        &WHEELS,
+        &WHEELS_DOWNLOADED,
+        &SOURCE_DISTS,
+        &BUILDS,
+    ] {
+        counter.store(0, Ordering::Relaxed);
+    }
+    PHASES.lock().unwrap().clear();
+}
+
+/// A summary of how effectively the cache was used by the current process.
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheSummary {
+    /// The HTTP responses served from the cache or the network.
+    pub requests: RequestSummary,
+    /// The wheels served from the cache or downloaded.
+    pub wheels: WheelSummary,
+    /// The source distributions served from the cache or built.
+    pub builds: BuildSummary,
+    /// The time spent in each phase, in the order in which the phases were first entered.
+    pub phases: Vec<PhaseSummary>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RequestSummary {
+    pub cached: u64,
+    pub revalidated: u64,
+    pub network: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WheelSummary {
+    pub cached: u64,
+    pub downloaded: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildSummary {
+    pub cached: u64,
+    pub built: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseSummary {
+    pub phase: Phase,
+    pub seconds: f64,
+}
+
+impl CacheSummary {
+    /// Return the summary of the events and phases recorded by the current process.
+    pub fn current() -> Self {
+        let load = |event: CacheEvent| event.counter().load(Ordering::Relaxed);
+        let downloaded = load(CacheEvent::WheelDownloaded);
+        let built = load(CacheEvent::Build);
+        Self {
+            requests: RequestSummary {
+                cached: load(CacheEvent::HttpCached),
+                revalidated: load(CacheEvent::HttpRevalidated),
+                network: load(CacheEvent::HttpNetwork),
+            },
+            wheels: WheelSummary {
+                cached: load(CacheEvent::Wheel).saturating_sub(downloaded),
+                downloaded,
+            },
+            // A single source distribution may be built more than once (e.g., if its metadata
+            // couldn't be extracted without building a wheel), so saturate.
+            builds: BuildSummary {
+                cached: load(CacheEvent::SourceDist).saturating_sub(built),
+                built,
+            },
+            phases: PHASES
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(phase, duration)| PhaseSummary {
+                    phase: *phase,
+                    seconds: duration.as_secs_f64(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -11,13 +11,16 @@ use clap::{Args, Parser, Subcommand};
 use url::Url;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    AddBound, BuildTarget, ComponentChannel, ConfigSettingEntry, DistroPackageEntry, ExportFormat,
+    IndexStrategy, KeyringProviderType, PackageNameSpecifier, ProjectBuildBackend,
+    PycInvalidationMode, ScopedPackageName, TargetTriple, TrustedHost, TrustedPublishing,
+    VersionControlSystem,
 };
 use uv_distribution_types::{Index, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::Requirement;
 use uv_pypi_types::VerbatimParsedUrl;
+use uv_python::managed::RegistryScope;
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, PrereleaseMode, ResolutionMode};
 use uv_static::EnvVars;
@@ -35,6 +38,32 @@ pub enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum VersionBump {
+    /// Increment the major version (e.g., `1.2.3` to `2.0.0`).
+    Major,
+    /// Increment the minor version (e.g., `1.2.3` to `1.3.0`).
+    Minor,
+    /// Increment the patch version (e.g., `1.2.3` to `1.2.4`).
+    Patch,
+    /// Remove any pre-, post-, or dev-release (e.g., `1.2.3rc1` to `1.2.3`).
+    Stable,
+    /// Increment or begin an alpha pre-release (e.g., `1.2.3a1` to `1.2.3a2`).
+    Alpha,
+    /// Increment or begin a beta pre-release (e.g., `1.2.3a1` to `1.2.3b1`).
+    Beta,
+    /// Increment or begin a release candidate (e.g., `1.2.3b1` to `1.2.3rc1`).
+    Rc,
+    /// Increment the existing pre-release, preserving its kind (e.g., `1.2.3b1` to `1.2.3b2`).
+    Prerelease,
+    /// Increment or begin a post-release (e.g., `1.2.3` to `1.2.3.post1`).
+    Post,
+    /// Increment or begin a dev-release (e.g., `1.2.3.dev1` to `1.2.3.dev2`).
+    Dev,
+    /// Increment the epoch (e.g., `1.2.3` to `1!1.2.3`).
+    Epoch,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -47,6 +76,49 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CacheSummaryFormat {
+    /// Display the summary as plain text.
+    #[default]
+    Text,
+    /// Display the summary as JSON.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum BundleFormat {
+    /// Create an executable zip application (`.pyz`), which extracts its dependencies to a cache
+    /// directory on first run.
+    #[default]
+    Zipapp,
+    /// Create a directory containing the dependencies and a `__main__.py` entrypoint.
+    Directory,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum VendorFormat {
+    /// Create an executable zip application (`.pyz`).
+    #[default]
+    Zipapp,
+    /// Create a directory containing the script, its dependencies, and a `__main__.py`
+    /// entrypoint.
+    Directory,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum FileCategory {
+    /// Pure Python modules and packages, installed into the `purelib` directory.
+    Purelib,
+    /// Platform-specific modules and packages, installed into the `platlib` directory.
+    Platlib,
+    /// Scripts and executables, installed into the `scripts` directory (e.g., `bin`).
+    Scripts,
+    /// C headers, installed into the `include` directory.
+    Headers,
+    /// Shared data (e.g., man pages), installed relative to the root of the environment.
+    Data,
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -95,6 +167,9 @@ pub struct TopLevelArgs {
 
     /// The path to a `uv.toml` file to use for configuration.
     ///
+    /// May be provided multiple times, in which case the files are layered, with later files
+    /// taking precedence over earlier ones.
+    ///
     /// While uv configuration can be included in a `pyproject.toml` file, it is
     /// not allowed in this context.
     #[arg(
@@ -103,7 +178,7 @@ pub struct TopLevelArgs {
         env = EnvVars::UV_CONFIG_FILE,
         help_heading = "Global options"
     )]
-    pub config_file: Option<PathBuf>,
+    pub config_file: Vec<PathBuf>,
 
     /// Avoid discovering configuration files (`pyproject.toml`, `uv.toml`).
     ///
@@ -256,6 +331,35 @@ pub struct GlobalArgs {
     #[arg(global = true, long, env = EnvVars::UV_NO_PROGRESS, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_progress: bool,
 
+    /// The format in which to report progress.
+    ///
+    /// With `json`, progress bars are replaced by a stream of newline-delimited JSON events
+    /// (e.g., resolution phases, and per-package download, build, and install progress), intended
+    /// for editors and other tools that render their own progress UI.
+    #[arg(global = true, long, value_enum, env = EnvVars::UV_PROGRESS_FORMAT)]
+    pub progress_format: Option<ProgressFormat>,
+
+    /// The file descriptor to which JSON progress events should be written.
+    ///
+    /// Defaults to stderr. Only supported on Unix, and only used with `--progress-format json`.
+    #[arg(global = true, long, env = EnvVars::UV_PROGRESS_FD, requires = "progress_format")]
+    pub progress_fd: Option<u32>,
+
+    /// Write a report to the given directory when a source distribution fails to build.
+    ///
+    /// The report is a JSON file that includes the output of the build backend, the resolved
+    /// requirements of the build environment, and the chain of errors that led to the failure.
+    #[arg(global = true, long, env = EnvVars::UV_BUILD_REPORT, value_name = "DIR")]
+    pub build_report: Option<PathBuf>,
+
+    /// Write the suggested fixes for a failed resolution to the given file, as JSON.
+    ///
+    /// When resolution fails due to a version conflict, uv suggests actions that may resolve the
+    /// conflict (e.g., relaxing a requirement, or adding an override). The same suggestions are
+    /// written to the file, for consumption by other tools.
+    #[arg(global = true, long, value_name = "FILE")]
+    pub resolution_suggestions: Option<PathBuf>,
+
     /// Change to the given directory prior to running the command.
     ///
     /// Relative paths are resolved with the given directory as the base.
@@ -302,6 +406,16 @@ impl From<ColorChoice> for anstream::ColorChoice {
     }
 }
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum ProgressFormat {
+    /// Display progress bars and spinners.
+    #[default]
+    Text,
+
+    /// Emit newline-delimited JSON progress events.
+    Json,
+}
+
 #[derive(Subcommand)]
 #[allow(clippy::large_enum_variant)]
 pub enum Commands {
@@ -316,6 +430,13 @@ pub enum Commands {
     )]
     Tool(ToolNamespace),
 
+    /// Lock, sync, and inspect the dependencies of standalone scripts.
+    #[command(
+        after_help = "Use `uv help script` for more details.",
+        after_long_help = ""
+    )]
+    Script(ScriptNamespace),
+
     /// Manage Python versions and installations
     ///
     /// Generally, uv first searches for Python in a virtual environment, either active or in a
@@ -412,6 +533,43 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Download distributions into a directory, without installing them.
+    ///
+    /// By default, the distributions in the project's `uv.lock` are downloaded, including all
+    /// extras and dependency groups. The lockfile is read as-is, and is never updated. When
+    /// packages or `--requirements` are provided, the requirements are resolved for each target
+    /// instead.
+    ///
+    /// For each target, the wheel that best matches the target's platform and Python version is
+    /// downloaded, falling back to the source distribution if no compatible wheel exists. Local
+    /// files are copied. Git and local directory dependencies can't be downloaded, and are
+    /// skipped.
+    ///
+    /// The hashes of each downloaded file are verified against the lockfile or index, if known,
+    /// and recorded in a `manifest.json` file in the output directory, alongside the file's
+    /// package, version, and source URL.
+    #[command(
+        after_help = "Use `uv help download` for more details.",
+        after_long_help = ""
+    )]
+    Download(Box<DownloadArgs>),
+    /// Run a local caching proxy for the simple repository API.
+    ///
+    /// Serves the packages available on the configured indexes (PyPI, by default) via the simple
+    /// repository API (PEP 503), such that other tools (e.g., `pip` or `poetry`) and other machines
+    /// can install packages through uv, reusing its cache and its handling of index credentials.
+    ///
+    /// Index pages are served from uv's cache, subject to the usual HTTP caching semantics, and
+    /// each distribution is downloaded from its upstream index once, verified against its hashes,
+    /// and served from the cache thereafter. With `--offline`, only cached index pages and
+    /// distributions are served.
+    ///
+    /// The proxy serves the index at `/simple/` until interrupted.
+    #[command(
+        after_help = "Use `uv help proxy` for more details.",
+        after_long_help = ""
+    )]
+    Proxy(ProxyArgs),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -427,6 +585,40 @@ pub enum Commands {
         after_long_help = ""
     )]
     Cache(CacheNamespace),
+    /// Inspect and modify uv's configuration.
+    ///
+    /// Settings are read from (in order of precedence) environment variables, the files provided
+    /// via `--config-file`, and the discovered project, user, and system configuration files.
+    #[command(
+        after_help = "Use `uv help config` for more details.",
+        after_long_help = ""
+    )]
+    Config(ConfigNamespace),
+    /// Run uv as a long-lived daemon with warm caches.
+    #[command(
+        after_help = "Use `uv help daemon` for more details.",
+        after_long_help = ""
+    )]
+    Daemon(DaemonNamespace),
+    /// Query project and environment state in a machine-readable format.
+    ///
+    /// Each query writes a single JSON object to stdout, of the form
+    /// `{"schema_version": 1, "query": "...", "result": {...}}`. If the query fails, `result` is
+    /// replaced by `"error": {"message": "...", "causes": [...]}` and uv exits with a non-zero
+    /// status. No other output is written to stdout or stderr.
+    ///
+    /// Within a schema version, fields may be added, but will never be removed or change meaning.
+    #[command(
+        after_help = "Use `uv help query` for more details.",
+        after_long_help = ""
+    )]
+    Query(QueryNamespace),
+    /// Inspect the members of a workspace and the dependencies between them.
+    #[command(
+        after_help = "Use `uv help workspace` for more details.",
+        after_long_help = ""
+    )]
+    Workspace(WorkspaceNamespace),
     /// Manage the uv executable.
     #[command(name = "self")]
     Self_(SelfNamespace),
@@ -434,13 +626,15 @@ pub enum Commands {
     #[command(hide = true)]
     Clean(CleanArgs),
     /// Display uv's version
-    Version {
-        #[arg(long, value_enum, default_value = "text")]
-        output_format: VersionFormat,
-    },
+    ///
+    /// With `--bump`, bumps the version of the current project instead.
+    Version(VersionArgs),
     /// Generate shell completion
     #[command(alias = "--generate-shell-completion", hide = true)]
     GenerateShellCompletion(GenerateShellCompletionArgs),
+    /// Complete the word under the cursor, for use by the shell completion scripts.
+    #[command(name = "__complete", hide = true)]
+    Complete(CompleteArgs),
     /// Display documentation for a command.
     // To avoid showing the global options when displaying help for the help command, we are
     // responsible for maintaining the options using the `after_help`.
@@ -478,6 +672,18 @@ pub struct SelfNamespace {
 pub enum SelfCommand {
     /// Update uv.
     Update(SelfUpdateArgs),
+    /// Install or upgrade optional uv components.
+    ///
+    /// Components are optional subsystems (e.g., vulnerability databases, project templates, or
+    /// credential helpers) that are versioned and distributed separately from uv. Each component
+    /// is installed from the index for its release channel into uv's data directory.
+    ///
+    /// If a component is already installed, it's upgraded to the latest release on its channel.
+    Add(SelfAddArgs),
+    /// Remove optional uv components.
+    Remove(SelfRemoveArgs),
+    /// List the installed optional uv components.
+    List(SelfListArgs),
 }
 
 #[derive(Args, Debug)]
@@ -491,6 +697,38 @@ pub struct SelfUpdateArgs {
     pub token: Option<String>,
 }
 
+#[derive(Args, Debug)]
+pub struct SelfAddArgs {
+    /// The components to install or upgrade.
+    #[arg(required = true)]
+    pub components: Vec<String>,
+
+    /// The release channel from which to install the components.
+    ///
+    /// Defaults to the channel from which each component was previously installed, or `stable`
+    /// for components that aren't yet installed.
+    #[arg(long, value_enum)]
+    pub channel: Option<ComponentChannel>,
+
+    /// Reinstall the components, even if the latest release is already installed.
+    #[arg(long)]
+    pub reinstall: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct SelfRemoveArgs {
+    /// The components to remove.
+    #[arg(required = true)]
+    pub components: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct SelfListArgs {
+    /// Check each component's channel for newer releases, and list those that are outdated.
+    #[arg(long)]
+    pub outdated: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CacheNamespace {
@@ -504,6 +742,45 @@ pub enum CacheCommand {
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
     Prune(PruneArgs),
+    /// Prefetch the distributions required by a lockfile or requirements file into the cache.
+    ///
+    /// By default, the distributions in the project's `uv.lock` are prefetched, including all
+    /// extras and dependency groups. The lockfile is read as-is, and is never updated.
+    ///
+    /// When `--requirements` is provided, the requirements are resolved for each target instead,
+    /// which populates the cache with the index metadata required for resolution.
+    ///
+    /// Distributions are downloaded (and, for the current platform, built) into the cache, but never
+    /// installed, such that subsequent installations for the same targets can be served entirely
+    /// from the cache.
+    Warm(Box<WarmArgs>),
+    /// Verify the integrity of the cache.
+    ///
+    /// Checks that every unzipped wheel in the cache matches the hashes and sizes in its `RECORD`
+    /// file, and that every link and pointer in the cache refers to an existing entry. Unzipped
+    /// wheels that aren't referenced by any other entry are reported as orphaned.
+    ///
+    /// Exits with a non-zero status if any problems are found, unless `--repair` is provided.
+    Verify(VerifyArgs),
+    /// Show the size and age of the entries in the cache.
+    ///
+    /// Reports the size, number of files, and oldest and newest modification times of each cache
+    /// bucket.
+    ///
+    /// Cache usage is also attributed to the project in the current directory (or to the
+    /// lockfiles provided via `--lockfile`), reporting the size of the cache entries for its locked
+    /// packages, and the fraction of its locked packages that can be served from the cache. As
+    /// the cache is shared, entries used by multiple projects are attributed to each of them.
+    Stats(StatsArgs),
+    /// Export the package metadata in the cache as a portable dataset.
+    ///
+    /// For each package, writes the versions available on the index, along with the
+    /// `Requires-Dist`, `Requires-Python`, and `Provides-Extra` metadata of each version whose
+    /// wheel metadata is cached, as newline-delimited JSON (one package per line).
+    ///
+    /// The export is read entirely from the cache, and never accesses the network. Packages (or
+    /// versions) that aren't in the cache are omitted, or exported without metadata.
+    Export(CacheExportArgs),
     /// Show the cache directory.
     ///
     ///
@@ -521,128 +798,908 @@ pub enum CacheCommand {
     Dir,
 }
 
-#[derive(Args, Debug)]
-#[allow(clippy::struct_excessive_bools)]
-pub struct CleanArgs {
-    /// The packages to remove from the cache.
-    pub package: Vec<PackageName>,
+#[derive(Args)]
+pub struct ConfigNamespace {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
 }
 
-#[derive(Args, Debug)]
-#[allow(clippy::struct_excessive_bools)]
-pub struct PruneArgs {
-    /// Optimize the cache for persistence in a continuous integration environment, like GitHub
-    /// Actions.
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// List the effective value of each setting.
     ///
-    /// By default, uv caches both the wheels that it builds from source and the pre-built wheels
-    /// that it downloads directly, to enable high-performance package installation. In some
-    /// scenarios, though, persisting pre-built wheels may be undesirable. For example, in GitHub
-    /// Actions, it's faster to omit pre-built wheels from the cache and instead have re-download
-    /// them on each run. However, it typically _is_ faster to cache wheels that are built from
-    /// source, since the wheel building process can be expensive, especially for extension
-    /// modules.
+    /// Only settings that are set (in a configuration file or via an environment variable) are
+    /// listed.
+    List(ConfigListArgs),
+    /// Show the effective value of a setting.
     ///
-    /// In `--ci` mode, uv will prune any pre-built wheels from the cache, but retain any wheels
-    /// that were built from source.
+    /// Nested settings are addressed with a dot, e.g., `pip.index-url`.
+    ///
+    /// Exits with a non-zero status if the setting is not set.
+    Get(ConfigGetArgs),
+    /// Set the value of a setting in a configuration file.
+    ///
+    /// The value is parsed as TOML (e.g., `true`, `4`, or `["a", "b"]`), falling back to a string
+    /// otherwise.
+    ///
+    /// By default, the setting is written to the highest-precedence file provided via
+    /// `--config-file`, or to the discovered project configuration file (or a `uv.toml` in the
+    /// project root, if none exists).
+    Set(ConfigSetArgs),
+}
+
+#[derive(Args)]
+pub struct ConfigListArgs {
+    /// Show the environment variable or file that provided each value.
     #[arg(long)]
-    pub ci: bool,
+    pub origin: bool,
 }
 
 #[derive(Args)]
-#[allow(clippy::struct_excessive_bools)]
-pub struct PipNamespace {
+pub struct ConfigGetArgs {
+    /// The name of the setting, e.g., `index-url` or `pip.index-url`.
+    pub key: String,
+
+    /// Show the environment variable or file that provided the value.
+    #[arg(long)]
+    pub origin: bool,
+}
+
+#[derive(Args)]
+pub struct ConfigSetArgs {
+    /// The name of the setting, e.g., `index-url` or `pip.index-url`.
+    pub key: String,
+
+    /// The value of the setting.
+    pub value: String,
+
+    /// Write the setting to the user configuration file (e.g., `~/.config/uv/uv.toml`).
+    #[arg(long, conflicts_with = "file")]
+    pub user: bool,
+
+    /// Write the setting to the given `uv.toml` or `pyproject.toml` file.
+    #[arg(long)]
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct DaemonNamespace {
     #[command(subcommand)]
-    pub command: PipCommand,
+    pub command: DaemonCommand,
 }
 
 #[derive(Subcommand)]
-pub enum PipCommand {
-    /// Compile a `requirements.in` file to a `requirements.txt` file.
-    #[command(
-        after_help = "Use `uv help pip compile` for more details.",
-        after_long_help = ""
-    )]
-    Compile(PipCompileArgs),
-    /// Sync an environment with a `requirements.txt` file.
-    #[command(
-        after_help = "Use `uv help pip sync` for more details.",
-        after_long_help = ""
-    )]
-    Sync(Box<PipSyncArgs>),
-    /// Install packages into an environment.
-    #[command(
-        after_help = "Use `uv help pip install` for more details.",
-        after_long_help = ""
-    )]
-    Install(PipInstallArgs),
-    /// Uninstall packages from an environment.
-    #[command(
-        after_help = "Use `uv help pip uninstall` for more details.",
-        after_long_help = ""
-    )]
-    Uninstall(PipUninstallArgs),
-    /// List, in requirements format, packages installed in an environment.
-    #[command(
-        after_help = "Use `uv help pip freeze` for more details.",
-        after_long_help = ""
-    )]
-    Freeze(PipFreezeArgs),
-    /// List, in tabular format, packages installed in an environment.
-    #[command(
-        after_help = "Use `uv help pip list` for more details.",
-        after_long_help = ""
-    )]
-    List(PipListArgs),
-    /// Show information about one or more installed packages.
-    #[command(
-        after_help = "Use `uv help pip show` for more details.",
-        after_long_help = ""
-    )]
-    Show(PipShowArgs),
-    /// Display the dependency tree for an environment.
-    #[command(
-        after_help = "Use `uv help pip tree` for more details.",
-        after_long_help = ""
-    )]
-    Tree(PipTreeArgs),
-    /// Verify installed packages have compatible dependencies.
-    #[command(
-        after_help = "Use `uv help pip check` for more details.",
-        after_long_help = ""
-    )]
-    Check(PipCheckArgs),
+pub enum DaemonCommand {
+    /// Start the daemon in the foreground.
+    ///
+    /// While the daemon is running, resolution and inspection commands (e.g., `uv lock` and `uv pip
+    /// compile`) that use the same cache directory delegate to it over a local socket, reusing the
+    /// index metadata, parsed lockfiles, and interpreter discovery results that it keeps in memory.
+    ///
+    /// Set `UV_NO_DAEMON` to run an invocation without the daemon.
+    ///
+    /// Only supported on Unix.
+    Start,
+    /// Stop the running daemon.
+    Stop,
+    /// Show whether a daemon is running.
+    Status,
+}
+
+#[derive(Args)]
+pub struct QueryNamespace {
+    #[command(subcommand)]
+    pub command: QueryCommand,
 }
 
 #[derive(Subcommand)]
-pub enum ProjectCommand {
-    /// Run a command or script.
+pub enum QueryCommand {
+    /// List the packages in the lockfile that depend directly on the given package.
+    Dependents(QueryDependentsArgs),
+    /// Resolve the given requirements against the current interpreter, without installing them.
+    Resolve(QueryResolveArgs),
+    /// Show the Python interpreter that uv would use in the current directory.
+    Interpreter(QueryInterpreterArgs),
+    /// Report whether the project's lockfile is missing, up-to-date, or outdated.
+    LockStatus(QueryLockStatusArgs),
+}
+
+#[derive(Args)]
+pub struct ScriptNamespace {
+    #[command(subcommand)]
+    pub command: ScriptCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ScriptCommand {
+    /// Update the script's lockfile.
+    ///
+    /// Resolves the dependencies declared in the script's inline metadata, and writes the
+    /// resolution to a lockfile alongside the script, e.g., `example.py.lock` for `example.py`.
+    ///
+    /// Once a script is locked, `uv run` and `uv script sync` install the locked versions of its
+    /// dependencies.
+    Lock(ScriptLockArgs),
+    /// Update the script's environment.
+    ///
+    /// Installs the locked dependencies of the script into the cached environment that `uv run`
+    /// uses to run the script, locking the script first if necessary.
+    Sync(ScriptSyncArgs),
+    /// Display the script's dependency tree.
+    Tree(ScriptTreeArgs),
+    /// List the script's locked dependencies that have newer versions available.
+    Outdated(ScriptOutdatedArgs),
+    /// Audit the script's locked dependencies.
+    ///
+    /// Reports any locked dependencies that are no longer maintained (as configured by
+    /// `tool.uv.maintenance` in the script metadata), and any that lack a compatible distribution
+    /// for some of the Python versions in the script's `requires-python`.
+    ///
+    /// Exits with a non-zero status if the lockfile is missing or out-of-date, or if any problems
+    /// are found.
+    Audit(ScriptAuditArgs),
+    /// Vendor the script and its dependencies into a self-contained artifact.
+    ///
+    /// Writes a zip application (or directory) containing the script and its locked pure-Python
+    /// dependencies, which can be run with `python` on a machine without network access or uv.
+    ///
+    /// Dependencies that are only available as platform-specific wheels are not vendored;
+    /// instead, they're listed in the artifact's `manifest.json`, and must be installed
+    /// separately.
+    Vendor(ScriptVendorArgs),
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ScriptLockArgs {
+    /// The path to the script, which must contain inline script metadata.
+    #[arg(value_parser = parse_file_path)]
+    pub script: PathBuf,
+
+    /// Assert that the script's lockfile will remain unchanged.
     ///
-    /// Ensures that the command runs in a Python environment.
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Use the script's lockfile without updating it.
     ///
-    /// When used with a file ending in `.py` or an HTTP(S) URL, the file
-    /// will be treated as a script and run with a Python interpreter,
-    /// i.e., `uv run file.py` is equivalent to `uv run python file.py`.
-    /// For URLs, the script is temporarily downloaded before execution. If
-    /// the script contains inline dependency metadata, it will be installed
-    /// into an isolated, ephemeral environment. When used with `-`, the
-    /// input will be read from stdin, and treated as a Python script.
+    /// Requires that the lockfile exists.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    /// Perform a dry run, without writing the lockfile.
     ///
-    /// When used in a project, the project environment will be created and
-    /// updated before invoking the command.
+    /// In dry-run mode, uv will resolve the script's dependencies and report on the resulting
+    /// changes, but will not write the lockfile to disk.
+    #[arg(long, conflicts_with = "frozen", conflicts_with = "locked")]
+    pub dry_run: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use for the script.
     ///
-    /// When used outside a project, if a virtual environment can be found in
-    /// the current directory or a parent directory, the command will be run in
-    /// that environment. Otherwise, the command will be run in the environment
-    /// of the discovered interpreter.
+    /// By default, the first interpreter that satisfies the script's `requires-python` is used.
     ///
-    /// Arguments following the command (or script) are not interpreted as
-    /// arguments to uv. All options to uv must be provided before the command,
-    /// e.g., `uv run --verbose foo`. A `--` can be used to separate the command
-    /// from uv options for clarity, e.g., `uv run --python 3.12 -- python`.
-    #[command(
-        after_help = "Use `uv help run` for more details.",
-        after_long_help = ""
-    )]
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ScriptSyncArgs {
+    /// The path to the script, which must contain inline script metadata.
+    #[arg(value_parser = parse_file_path)]
+    pub script: PathBuf,
+
+    /// Assert that the script's lockfile will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Use the script's lockfile without updating it.
+    ///
+    /// Requires that the lockfile exists.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use for the script.
+    ///
+    /// By default, the first interpreter that satisfies the script's `requires-python` is used.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ScriptTreeArgs {
+    /// The path to the script, which must contain inline script metadata.
+    #[arg(value_parser = parse_file_path)]
+    pub script: PathBuf,
+
+    /// Show a platform-independent dependency tree.
+    ///
+    /// Shows resolved package versions for all Python versions and platforms,
+    /// rather than filtering to those that are relevant for the current
+    /// environment.
+    ///
+    /// Multiple versions may be shown for each package.
+    #[arg(long)]
+    pub universal: bool,
+
+    #[command(flatten)]
+    pub tree: DisplayTreeArgs,
+
+    /// Assert that the script's lockfile will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Use the script's lockfile without updating it.
+    ///
+    /// Requires that the lockfile exists.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    /// The Python interpreter to use for the script.
+    ///
+    /// By default, the first interpreter that satisfies the script's `requires-python` is used.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct ScriptOutdatedArgs {
+    /// The path to the script, which must contain inline script metadata.
+    #[arg(value_parser = parse_file_path)]
+    pub script: PathBuf,
+
+    /// Assert that the script's lockfile will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Use the script's lockfile without updating it.
+    ///
+    /// Requires that the lockfile exists.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    /// The Python interpreter to use for the script.
+    ///
+    /// By default, the first interpreter that satisfies the script's `requires-python` is used.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct ScriptAuditArgs {
+    /// The path to the script, which must contain inline script metadata.
+    #[arg(value_parser = parse_file_path)]
+    pub script: PathBuf,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    /// The Python interpreter to use for the script.
+    ///
+    /// By default, the first interpreter that satisfies the script's `requires-python` is used.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ScriptVendorArgs {
+    /// The path to the script, which must contain inline script metadata.
+    #[arg(value_parser = parse_file_path)]
+    pub script: PathBuf,
+
+    /// The format of the vendored script.
+    #[arg(long, value_enum, default_value_t = VendorFormat::default())]
+    pub format: VendorFormat,
+
+    /// The path to write the vendored script to.
+    ///
+    /// Defaults to the script's path with a `.pyz` extension (or without an extension, for
+    /// directories), e.g., `example.pyz` for `example.py`.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+
+    /// Assert that the script's lockfile will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Use the script's lockfile without updating it.
+    ///
+    /// Requires that the lockfile exists.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to vendor the script for.
+    ///
+    /// By default, the first interpreter that satisfies the script's `requires-python` is used.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// The platform to vendor the script for.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// Determines which of the script's dependencies are required, and which are listed as
+    /// platform-specific in the manifest.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
+}
+
+#[derive(Args)]
+pub struct WorkspaceNamespace {
+    #[command(subcommand)]
+    pub command: WorkspaceCommand,
+}
+
+#[derive(Subcommand)]
+pub enum WorkspaceCommand {
+    /// List the members of the workspace.
+    List(WorkspaceListArgs),
+    /// Show the dependencies between the members of the workspace.
+    ///
+    /// A member depends on another member if it lists it in its `project.dependencies`,
+    /// `project.optional-dependencies`, `dependency-groups`, or `tool.uv.dev-dependencies`.
+    Graph(WorkspaceGraphArgs),
+    /// List the members of the workspace that are affected by a set of changed files.
+    ///
+    /// A member is affected if any of the changed files are within its directory, or if it
+    /// depends, directly or transitively, on an affected member. Changes to the workspace's
+    /// `uv.lock` or root `pyproject.toml` affect every member.
+    ///
+    /// The changed files can be provided as arguments, or computed from a Git revision with
+    /// `--base`.
+    Affected(WorkspaceAffectedArgs),
+    /// Report dependencies that are specified inconsistently across the members of the workspace.
+    ///
+    /// A dependency is specified inconsistently if the members (or the sections of a single
+    /// member) require the same package with different version specifiers or extras.
+    ///
+    /// With `--write`, the version specifiers of each inconsistent dependency are combined into
+    /// a shared entry in the `tool.uv.workspace.dependencies` table at the workspace root, and
+    /// removed from the members' requirements, which opt in to the shared entry instead (via
+    /// `workspace = true` in `tool.uv.sources`).
+    DedupeReport(WorkspaceDedupeReportArgs),
+}
+
+#[derive(Args)]
+pub struct WorkspaceListArgs {
+    /// Display the members as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args)]
+pub struct WorkspaceGraphArgs {
+    /// Display the graph as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args)]
+pub struct WorkspaceAffectedArgs {
+    /// The paths of the changed files, relative to the current directory.
+    #[arg(required_unless_present = "base")]
+    pub files: Vec<PathBuf>,
+
+    /// Consider the files that changed since the given Git revision, as reported by
+    /// `git diff --name-only`.
+    ///
+    /// Accepts any revision or range understood by `git diff`, e.g., `main` or
+    /// `origin/main...HEAD`. Uncommitted changes to tracked files are included; untracked files
+    /// are not.
+    #[arg(long, value_name = "REV")]
+    pub base: Option<String>,
+
+    /// Display the affected members as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args)]
+pub struct WorkspaceDedupeReportArgs {
+    /// Move the version specifiers of each inconsistent dependency to the
+    /// `tool.uv.workspace.dependencies` table.
+    ///
+    /// Dependencies that are declared with a URL, with environment markers, or with a
+    /// (non-workspace) source are left as-is.
+    #[arg(long, conflicts_with = "json")]
+    pub write: bool,
+
+    /// Display the report as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args)]
+pub struct QueryDependentsArgs {
+    /// The package to find the dependents of.
+    pub package: PackageName,
+}
+
+#[derive(Args)]
+pub struct QueryResolveArgs {
+    /// The requirements to resolve.
+    #[arg(required = true)]
+    pub requirements: Vec<String>,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    /// The Python interpreter to resolve against.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct QueryInterpreterArgs {
+    /// The Python request.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub request: Option<String>,
+}
+
+#[derive(Args)]
+pub struct QueryLockStatusArgs {
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    /// The Python interpreter to use when checking the lockfile.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct CleanArgs {
+    /// The packages to remove from the cache.
+    pub package: Vec<PackageName>,
+}
+
+#[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PruneArgs {
+    /// Optimize the cache for persistence in a continuous integration environment, like GitHub
+    /// Actions.
+    ///
+    /// By default, uv caches both the wheels that it builds from source and the pre-built wheels
+    /// that it downloads directly, to enable high-performance package installation. In some
+    /// scenarios, though, persisting pre-built wheels may be undesirable. For example, in GitHub
+    /// Actions, it's faster to omit pre-built wheels from the cache and instead have re-download
+    /// them on each run. However, it typically _is_ faster to cache wheels that are built from
+    /// source, since the wheel building process can be expensive, especially for extension
+    /// modules.
+    ///
+    /// In `--ci` mode, uv will prune any pre-built wheels from the cache, but retain any wheels
+    /// that were built from source.
+    #[arg(long)]
+    pub ci: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct VerifyArgs {
+    /// Remove any corrupted, orphaned, or dangling entries from the cache.
+    ///
+    /// Removed distributions are downloaded (or rebuilt) again the next time they're needed.
+    #[arg(long)]
+    pub repair: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct StatsArgs {
+    /// Attribute cache usage to the packages in the given lockfiles, rather than the lockfile of
+    /// the project in the current directory.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_parser = parse_file_path)]
+    pub lockfile: Vec<PathBuf>,
+
+    /// Display the statistics as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args)]
+pub struct CacheExportArgs {
+    /// The packages to export.
+    ///
+    /// Defaults to every package with cached index metadata.
+    pub package: Vec<PackageName>,
+
+    /// Write the export to the given file, rather than to stdout.
+    #[arg(long, short, value_parser = parse_file_path)]
+    pub output: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+}
+
+#[derive(Args)]
+pub struct WarmArgs {
+    /// Prefetch the distributions required by the given requirements files, rather than those in
+    /// the project's lockfile.
+    ///
+    /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will extract the
+    /// requirements for the relevant project.
+    #[arg(long, short, value_name = "FILE", value_parser = parse_file_path)]
+    pub requirements: Vec<PathBuf>,
+
+    /// A Python version for which to prefetch distributions.
+    ///
+    /// May be provided multiple times. Each Python version is combined with each
+    /// `--python-platform` to form the set of targets.
+    ///
+    /// Defaults to the version of the discovered Python interpreter.
+    #[arg(long)]
+    pub python_version: Vec<PythonVersion>,
+
+    /// A platform for which to prefetch distributions.
+    ///
+    /// May be provided multiple times. Each platform is combined with each `--python-version` to
+    /// form the set of targets.
+    ///
+    /// Source distributions can only be built for the current platform, so they're skipped for
+    /// any explicit `--python-platform`.
+    ///
+    /// Defaults to the platform of the discovered Python interpreter.
+    #[arg(long)]
+    pub python_platform: Vec<TargetTriple>,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    /// The Python interpreter to use when resolving requirements and building source
+    /// distributions.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct DownloadArgs {
+    /// Download the given packages, rather than those in the project's lockfile.
+    pub package: Vec<String>,
+
+    /// Download the packages required by the given requirements files, rather than those in the
+    /// project's lockfile.
+    ///
+    /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will extract the
+    /// requirements for the relevant project.
+    #[arg(long, short, value_name = "FILE", value_parser = parse_file_path)]
+    pub requirements: Vec<PathBuf>,
+
+    /// The directory to which distributions should be written.
+    ///
+    /// Defaults to the `wheelhouse` subdirectory within the current working directory.
+    #[arg(long, short, value_parser = parse_file_path)]
+    pub out_dir: Option<PathBuf>,
+
+    /// A Python version for which to download distributions.
+    ///
+    /// May be provided multiple times. Each Python version is combined with each
+    /// `--python-platform` to form the set of targets.
+    ///
+    /// Defaults to the version of the discovered Python interpreter.
+    #[arg(long)]
+    pub python_version: Vec<PythonVersion>,
+
+    /// A platform for which to download distributions.
+    ///
+    /// May be provided multiple times. Each platform is combined with each `--python-version` to
+    /// form the set of targets.
+    ///
+    /// Defaults to the platform of the discovered Python interpreter.
+    #[arg(long)]
+    pub python_platform: Vec<TargetTriple>,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    /// The Python interpreter to use when resolving requirements.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct ProxyArgs {
+    /// The address on which to listen for requests.
+    ///
+    /// To serve other machines on the network, pass `0.0.0.0` (or `::`).
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: String,
+
+    /// The port on which to listen for requests.
+    #[arg(long, default_value_t = 3141)]
+    pub port: u16,
+
+    #[command(flatten)]
+    pub index_args: IndexArgs,
+
+    /// The strategy to use when resolving against multiple index URLs.
+    ///
+    /// By default, uv will stop at the first index on which a given package is available, and
+    /// limit resolutions to those present on that first index (`first-match`). This prevents
+    /// "dependency confusion" attacks, whereby an attacker can upload a malicious package under the
+    /// same name to an alternate index.
+    #[arg(long, value_enum, env = EnvVars::UV_INDEX_STRATEGY)]
+    pub index_strategy: Option<IndexStrategy>,
+
+    /// Attempt to use `keyring` for authentication for index URLs.
+    ///
+    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
+    /// use the `keyring` CLI to handle authentication.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+
+    /// Limit the served distributions to those that were uploaded prior to the given date.
+    ///
+    /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and local dates in the same
+    /// format (e.g., `2006-12-02`) in your system's configured time zone.
+    #[arg(long, env = EnvVars::UV_EXCLUDE_NEWER)]
+    pub exclude_newer: Option<ExcludeNewer>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PipNamespace {
+    #[command(subcommand)]
+    pub command: PipCommand,
+}
+
+#[derive(Subcommand)]
+pub enum PipCommand {
+    /// Compile a `requirements.in` file to a `requirements.txt` file.
+    #[command(
+        after_help = "Use `uv help pip compile` for more details.",
+        after_long_help = ""
+    )]
+    Compile(PipCompileArgs),
+    /// Sync an environment with a `requirements.txt` file.
+    #[command(
+        after_help = "Use `uv help pip sync` for more details.",
+        after_long_help = ""
+    )]
+    Sync(Box<PipSyncArgs>),
+    /// Install packages into an environment.
+    #[command(
+        after_help = "Use `uv help pip install` for more details.",
+        after_long_help = ""
+    )]
+    Install(PipInstallArgs),
+    /// Uninstall packages from an environment.
+    #[command(
+        after_help = "Use `uv help pip uninstall` for more details.",
+        after_long_help = ""
+    )]
+    Uninstall(PipUninstallArgs),
+    /// List, in requirements format, packages installed in an environment.
+    #[command(
+        after_help = "Use `uv help pip freeze` for more details.",
+        after_long_help = ""
+    )]
+    Freeze(PipFreezeArgs),
+    /// List, in tabular format, packages installed in an environment.
+    #[command(
+        after_help = "Use `uv help pip list` for more details.",
+        after_long_help = ""
+    )]
+    List(PipListArgs),
+    /// Show information about one or more installed packages.
+    #[command(
+        after_help = "Use `uv help pip show` for more details.",
+        after_long_help = ""
+    )]
+    Show(PipShowArgs),
+    /// Display the dependency tree for an environment.
+    #[command(
+        after_help = "Use `uv help pip tree` for more details.",
+        after_long_help = ""
+    )]
+    Tree(PipTreeArgs),
+    /// Verify installed packages have compatible dependencies.
+    #[command(
+        after_help = "Use `uv help pip check` for more details.",
+        after_long_help = ""
+    )]
+    Check(PipCheckArgs),
+}
+
+#[derive(Subcommand)]
+pub enum ProjectCommand {
+    /// Run a command or script.
+    ///
+    /// Ensures that the command runs in a Python environment.
+    ///
+    /// When used with a file ending in `.py` or an HTTP(S) URL, the file
+    /// will be treated as a script and run with a Python interpreter,
+    /// i.e., `uv run file.py` is equivalent to `uv run python file.py`.
+    /// For URLs, the script is temporarily downloaded before execution. If
+    /// the script contains inline dependency metadata, it will be installed
+    /// into an isolated, ephemeral environment. When used with `-`, the
+    /// input will be read from stdin, and treated as a Python script.
+    ///
+    /// When used in a project, the project environment will be created and
+    /// updated before invoking the command.
+    ///
+    /// When used outside a project, if a virtual environment can be found in
+    /// the current directory or a parent directory, the command will be run in
+    /// that environment. Otherwise, the command will be run in the environment
+    /// of the discovered interpreter.
+    ///
+    /// Arguments following the command (or script) are not interpreted as
+    /// arguments to uv. All options to uv must be provided before the command,
+    /// e.g., `uv run --verbose foo`. A `--` can be used to separate the command
+    /// from uv options for clarity, e.g., `uv run --python 3.12 -- python`.
+    #[command(
+        after_help = "Use `uv help run` for more details.",
+        after_long_help = ""
+    )]
     Run(RunArgs),
     /// Create a new project.
     ///
@@ -710,6 +1767,19 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Remove(RemoveArgs),
+    /// Manage pinned packages, which can't be upgraded past a given version.
+    ///
+    /// Unlike constraints, pins only apply to upgrades (e.g., `uv lock --upgrade`): a pinned package
+    /// that's being upgraded will never be upgraded past its pinned version.
+    ///
+    /// Pins are stored in the `[tool.uv.pins]` table of the project's `pyproject.toml` (or in the
+    /// `[pins]` table of a `uv.toml`), or, with `--global`, in a `uv.pins.toml` file in the user
+    /// configuration directory.
+    #[command(
+        after_help = "Use `uv help pin` for more details.",
+        after_long_help = ""
+    )]
+    Pin(PinNamespace),
     /// Update the project's environment.
     ///
     /// Syncing ensures that all project dependencies are installed and up-to-date with the
@@ -751,7 +1821,8 @@ pub enum ProjectCommand {
     Lock(LockArgs),
     /// Export the project's lockfile to an alternate format.
     ///
-    /// At present, only `requirements-txt` is supported.
+    /// Supports `requirements.txt`, `Dockerfile`, Nix flake, Bazel (`rules_python`), RPM `.spec`,
+    /// and Debian `control` formats.
     ///
     /// The project is re-locked before exporting unless the `--locked` or `--frozen` flag is
     /// provided.
@@ -766,6 +1837,39 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Export(ExportArgs),
+    /// Build a container image for the project from the lockfile (experimental).
+    ///
+    /// Generates a `Dockerfile` as in `uv export --format dockerfile`, then builds it with
+    /// `docker build`, using the workspace root as the build context. The locked third-party
+    /// dependencies and the project are installed in separate layers, such that changes to the
+    /// project don't invalidate the dependency layer.
+    ///
+    /// If multiple platforms are requested, the image is built with `docker buildx build`, and the
+    /// wheels for each platform are selected from the lockfile.
+    #[command(
+        after_help = "Use `uv help build-image` for more details.",
+        after_long_help = ""
+    )]
+    BuildImage(BuildImageArgs),
+    /// Bundle the project and its locked dependencies into a self-contained executable
+    /// (experimental).
+    ///
+    /// By default, creates a zip application (`.pyz`) that can be run with any compatible Python
+    /// interpreter, as in `python project.pyz`. On first run, the dependencies are extracted to a
+    /// cache directory, such that extension modules can be imported.
+    ///
+    /// The bundle targets the Python version of the discovered interpreter (see `--python`) and,
+    /// by default, the current platform. Use `--python-platform` to bundle the wheels for a
+    /// different platform.
+    ///
+    /// When run, the bundle invokes the `--entry-point`, if provided, or the project's console
+    /// script, if it defines exactly one. Otherwise, the bundle behaves like a Python interpreter
+    /// with the project and its dependencies available.
+    #[command(
+        after_help = "Use `uv help bundle` for more details.",
+        after_long_help = ""
+    )]
+    Bundle(BundleArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
 }
@@ -1174,6 +2278,42 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("universal"), hide = true)]
     pub no_universal: bool,
 
+    /// Write a fully-evaluated `requirements.txt` file to the given directory for each Python
+    /// version and platform in the split matrix, in addition to the universal output.
+    ///
+    /// Each file is derived from the universal resolution by evaluating its markers for the given
+    /// Python version and platform, such that each file contains exactly the packages to install
+    /// on that target, without markers. Files are named after the output file and the target, as
+    /// in `requirements-py312-linux-x86_64.txt`.
+    ///
+    /// The matrix is the product of `--split-python-version` and `--split-python-platform`.
+    ///
+    /// Requires `--universal`.
+    #[arg(long, requires = "universal", value_name = "DIR")]
+    pub split_output_dir: Option<PathBuf>,
+
+    /// A Python version for which to write a split output file.
+    ///
+    /// Defaults to the minimum Python version of the universal resolution (i.e., the current Python
+    /// version, or `--python-version`).
+    #[arg(long, requires = "split_output_dir")]
+    pub split_python_version: Vec<PythonVersion>,
+
+    /// A platform for which to write a split output file.
+    ///
+    /// Defaults to `linux`, `macos`, and `windows`.
+    #[arg(long, requires = "split_output_dir")]
+    pub split_python_platform: Vec<TargetTriple>,
+
+    /// The URL of a Pyodide package index to search when resolving for `wasm32-pyodide`.
+    ///
+    /// Most packages with extension modules aren't published to PyPI for Pyodide. When
+    /// `--python-platform wasm32-pyodide` (or `--split-python-platform wasm32-pyodide`) is
+    /// provided, uv will search this index in addition to the configured indexes, with a higher
+    /// priority than the default index.
+    #[arg(long, env = EnvVars::UV_PYODIDE_INDEX_URL, help_heading = "Index options")]
+    pub pyodide_index_url: Option<IndexUrl>,
+
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
     #[arg(long, alias = "unsafe-package")]
@@ -1233,9 +2373,19 @@ pub struct PipSyncArgs {
     /// extract the requirements for the relevant project.
     ///
     /// If `-` is provided, then requirements will be read from stdin.
-    #[arg(required(true), value_parser = parse_file_path)]
+    #[arg(required_unless_present = "from_conda", value_parser = parse_file_path)]
     pub src_file: Vec<PathBuf>,
 
+    /// Include the `pip` dependencies of the given conda environment files (e.g.,
+    /// `environment.yml`).
+    ///
+    /// Only the packages listed in the `pip` section of the `dependencies` are installed; the
+    /// conda dependencies are expected to have been installed by conda. Packages installed by
+    /// conda are never removed or replaced, allowing uv to reconcile the pip-installed portion
+    /// of a conda environment.
+    #[arg(long, value_parser = parse_file_path)]
+    pub from_conda: Vec<PathBuf>,
+
     /// Constrain versions using the given requirements files.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -1370,6 +2520,16 @@ pub struct PipSyncArgs {
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
+    /// Stage the installation under the specified root directory, for packaging.
+    ///
+    /// Files are written to the `--prefix` directory within the root directory, while scripts and
+    /// installation records reference the `--prefix` directory itself. For example, `--root
+    /// /staging --prefix /usr` installs into `/staging/usr`, for distribution as `/usr`. If the
+    /// installing interpreter is itself within the root directory, scripts reference its location
+    /// outside of the root.
+    #[arg(long, requires = "prefix")]
+    pub root: Option<PathBuf>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -1454,6 +2614,10 @@ pub struct PipSyncArgs {
 
     /// Perform a dry run, i.e., don't actually install anything but resolve the dependencies and
     /// print the resulting plan.
+    ///
+    /// The plan lists each package that would be installed, upgraded, downgraded, or removed,
+    /// along with whether it would be linked from the cache, downloaded as a wheel (and the size
+    /// of the download), or built from source.
     #[arg(long)]
     pub dry_run: bool,
 
@@ -1653,6 +2817,16 @@ pub struct PipInstallArgs {
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
+    /// Stage the installation under the specified root directory, for packaging.
+    ///
+    /// Files are written to the `--prefix` directory within the root directory, while scripts and
+    /// installation records reference the `--prefix` directory itself. For example, `--root
+    /// /staging --prefix /usr` installs into `/staging/usr`, for distribution as `/usr`. If the
+    /// installing interpreter is itself within the root directory, scripts reference its location
+    /// outside of the root.
+    #[arg(long, requires = "prefix")]
+    pub root: Option<PathBuf>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -1742,9 +2916,24 @@ pub struct PipInstallArgs {
 
     /// Perform a dry run, i.e., don't actually install anything but resolve the dependencies and
     /// print the resulting plan.
+    ///
+    /// The plan lists each package that would be installed, upgraded, downgraded, or removed,
+    /// along with whether it would be linked from the cache, downloaded as a wheel (and the size
+    /// of the download), or built from source.
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Write a JSON report of the packages to be installed to the given path.
+    ///
+    /// The report follows the format of `pip install --report`, and includes the chosen
+    /// distribution for each package, along with its hashes, metadata, and whether it was
+    /// requested directly. Use `-` to write the report to stdout.
+    ///
+    /// Packages that are already installed in the environment are omitted from the report. Combine
+    /// with `--dry-run` to generate a report without modifying the environment.
+    #[arg(long, value_name = "PATH")]
+    pub report: Option<PathBuf>,
+
     #[command(flatten)]
     pub compat_args: compat::PipInstallCompatArgs,
 }
@@ -1913,12 +3102,25 @@ pub struct PipListArgs {
     ///
     /// The latest version of each package will be shown alongside the installed version. Up-to-date
     /// packages will be omitted from the output.
+    ///
+    /// If a newer version exists but can't be installed (e.g., as it requires a newer Python
+    /// version, or is a pre-release), the version and the constraint that blocks the upgrade will
+    /// also be shown.
     #[arg(long, overrides_with("no_outdated"))]
     pub outdated: bool,
 
     #[arg(long, overrides_with("outdated"), hide = true)]
     pub no_outdated: bool,
 
+    /// List up-to-date packages.
+    ///
+    /// Only packages at the latest version available on the index will be included in the output.
+    #[arg(long, overrides_with("no_uptodate"), conflicts_with = "outdated")]
+    pub uptodate: bool,
+
+    #[arg(long, overrides_with("uptodate"), hide = true)]
+    pub no_uptodate: bool,
+
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -2024,6 +3226,16 @@ pub struct PipShowArgs {
     #[arg(short, long)]
     pub files: bool,
 
+    /// Only show the installed files in the given categories.
+    ///
+    /// Files are categorized by the installation scheme path into which they were installed, such
+    /// that, e.g., `--category headers` shows the C headers installed by a package, and
+    /// `--category data` shows any shared data files, like man pages.
+    ///
+    /// Implies `--files`.
+    #[arg(long, value_enum)]
+    pub category: Vec<FileCategory>,
+
     /// The Python interpreter to find the package in.
     ///
     /// By default, uv looks for packages in a virtual environment but will look
@@ -2161,6 +3373,22 @@ pub struct BuildArgs {
     #[arg(long)]
     pub wheel: bool,
 
+    /// Build a wheel for each of the given targets.
+    ///
+    /// Each target is composed of a Python tag and a platform tag, as in
+    /// `cp312-manylinux_2_28_x86_64` or `cp313-macosx_arm64`. Each wheel is built with a Python
+    /// interpreter that satisfies the Python tag, which will be downloaded if necessary.
+    ///
+    /// When targeting a platform other than the current platform, the build backend is instructed
+    /// to cross-compile via environment variables (i.e., `_PYTHON_HOST_PLATFORM`, `ARCHFLAGS`,
+    /// `MACOSX_DEPLOYMENT_TARGET`, and `CARGO_BUILD_TARGET`). Cross-compilation must be supported
+    /// by the build backend.
+    ///
+    /// May be provided multiple times. Implies `--wheel`; if `--sdist` is also provided, a single
+    /// source distribution is built in addition to the wheels.
+    #[arg(long, alias = "wheel-for", conflicts_with = "python")]
+    pub target: Vec<BuildTarget>,
+
     #[arg(long, overrides_with("no_build_logs"), hide = true)]
     pub build_logs: bool,
 
@@ -2694,6 +3922,26 @@ pub struct RunArgs {
     #[arg(long, value_delimiter = ',', value_parser = parse_maybe_file_path)]
     pub with_requirements: Vec<Maybe<PathBuf>>,
 
+    /// Record the resolved `--with` requirements to the given overlay file.
+    ///
+    /// The overlay file is written in `requirements.txt` format, with every package in the
+    /// ephemeral environment pinned to an exact version alongside its hashes. The environment can
+    /// later be reproduced exactly with `--with-overlay`.
+    #[arg(long, value_name = "PATH", conflicts_with = "with_overlay")]
+    pub with_lock: Option<PathBuf>,
+
+    /// Run with the packages recorded in the given overlay file installed.
+    ///
+    /// The overlay file is typically produced by `--with-lock`. Every package must be pinned to an
+    /// exact version with hashes; the ephemeral environment will contain exactly the recorded
+    /// packages, each verified against its hashes.
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["with", "with_editable", "with_requirements"]
+    )]
+    pub with_overlay: Option<PathBuf>,
+
     /// Run the command in an isolated virtual environment.
     ///
     /// Usually, the project environment is reused for performance. This option
@@ -2707,6 +3955,24 @@ pub struct RunArgs {
     #[arg(long)]
     pub isolated: bool,
 
+    /// Run the command in a container created from the given OCI image.
+    ///
+    /// The workspace is mounted into the container, and the command is run
+    /// with `uv run` in the container, in an environment that's synced with
+    /// the lockfile (as with `--locked`). The environment, along with the cache
+    /// and any Python installations used in the container, is persisted in the
+    /// uv cache across invocations.
+    ///
+    /// The image must provide a POSIX shell, along with either `uv` or one of
+    /// `curl` or `wget` (to install `uv`).
+    ///
+    /// Uses `docker` by default; set `UV_CONTAINER_RUNTIME` to use a different
+    /// runtime (e.g., `podman`).
+    ///
+    /// Requires `--isolated`, as the project environment (`.venv`) is not used.
+    #[arg(long, requires = "isolated", conflicts_with_all = ["no_project", "script"], value_name = "IMAGE")]
+    pub container: Option<String>,
+
     /// Avoid syncing the virtual environment.
     ///
     /// Implies `--frozen`, as the project dependencies will be ignored (i.e., the lockfile will not
@@ -2903,6 +4169,28 @@ pub struct SyncArgs {
     #[arg(long)]
     pub no_install_package: Vec<PackageName>,
 
+    /// Only install the given package(s), along with their transitive dependencies.
+    ///
+    /// By default, all of the project's dependencies are installed into the environment. The
+    /// `--only` option restricts the installation to the subgraph of the lockfile rooted at the
+    /// given packages, which is useful for building slim deployment environments from a large
+    /// workspace, e.g., with `--only` set to a single workspace member.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE")]
+    pub only: Vec<PackageName>,
+
+    /// Do not install the given package(s), or any dependencies that are only required through
+    /// them.
+    ///
+    /// Unlike `--no-install-package`, which omits the package itself but retains its
+    /// dependencies, `--except` omits the entire subtree of the dependency graph below the given
+    /// packages, except for those dependencies that are required by other packages.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE")]
+    pub except: Vec<PackageName>,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or
@@ -2920,6 +4208,86 @@ pub struct SyncArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
     pub frozen: bool,
 
+    /// Perform a dry run, without writing the lockfile or modifying the environment.
+    ///
+    /// In dry-run mode, uv will lock the project and report on the changes that would be made to
+    /// the environment, but will not write the lockfile to disk, create the virtual environment,
+    /// or install or uninstall any packages.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// In dry-run mode, report the size of each package to be installed or removed.
+    ///
+    /// Reports the download size of each package that isn't yet in the cache (per the index
+    /// metadata), the unpacked size of each package that's already in the cache, and the size of
+    /// each installed package that would be removed, along with the total download size and the
+    /// change in the size of the environment.
+    #[arg(long, requires = "dry_run")]
+    pub show_sizes: bool,
+
+    /// Display the size report as JSON, written to stdout.
+    #[arg(long, requires = "show_sizes")]
+    pub json: bool,
+
+    /// Require that each registry package is installed from the index recorded in the lockfile.
+    ///
+    /// By default, a package that's already installed is retained if its version matches the
+    /// lockfile, regardless of where it was installed from. With `--locked-indexes`, uv will
+    /// exit with an error if any package wasn't installed by uv from the locked index (e.g., a
+    /// package installed from a different index, or by another tool), even if its filename and
+    /// hash match. Use `--reinstall-package` to replace such packages from the locked index.
+    #[arg(long)]
+    pub locked_indexes: bool,
+
+    /// Regenerate the entry point launchers of all packages installed in the environment.
+    ///
+    /// Launchers embed the path to the Python interpreter, and so may need to be regenerated
+    /// after the interpreter is moved or upgraded in-place, or after changing the
+    /// `script-launcher` settings. Launchers are only generated on Windows; on other platforms,
+    /// the shebangs of the existing entry point scripts are rewritten instead.
+    #[arg(long, conflicts_with = "dry_run")]
+    pub refresh_scripts: bool,
+
+    /// Install the project and its locked dependencies into the specified directory, rather than
+    /// into the project environment.
+    ///
+    /// The packages are installed at the top-level of the directory, as expected by serverless
+    /// platforms like AWS Lambda and Google Cloud Functions. The project itself is installed as a
+    /// non-editable package, and scripts (e.g., entry points) are omitted.
+    ///
+    /// Only the project's runtime dependencies are installed by default; use `--group` or `--dev`
+    /// to include dependency groups. uv writes a `uv-target.toml` manifest to the directory,
+    /// listing the installed packages.
+    #[arg(long, conflicts_with = "refresh_scripts")]
+    pub target: Option<PathBuf>,
+
+    /// Watch the project for changes, and re-sync the environment whenever they occur.
+    ///
+    /// After the initial sync, uv watches the `pyproject.toml` of the project and of each
+    /// workspace member, along with the `uv.lock`, `uv.toml`, and `.python-version` files, and
+    /// re-locks and re-syncs the environment whenever any of them change. Bursts of changes are
+    /// debounced into a single sync. Failed syncs are reported, but don't end the watch.
+    ///
+    /// Distributions added to (or rebuilt in) any `--find-links` directories also trigger a
+    /// re-sync.
+    ///
+    /// With `--progress-format json`, uv emits `sync-start`, `sync-complete`, and `watch-change`
+    /// events, for use by editors and other tooling.
+    #[arg(long, conflicts_with = "dry_run")]
+    pub watch: bool,
+
+    /// Print a summary of how effectively the cache was used.
+    ///
+    /// Reports the number of HTTP responses (for index pages, metadata, and wheels) that were
+    /// served from the cache, revalidated, or fetched from the network; the number of wheels that
+    /// were served from the cache or downloaded; and the number of source distributions that were
+    /// served from the cache or built; alongside the time spent in each phase (e.g., resolving,
+    /// preparing, and installing).
+    ///
+    /// With `--cache-summary=json`, the summary is written to stdout as JSON.
+    #[arg(long, value_enum, num_args = 0..=1, require_equals = true, default_missing_value = "text")]
+    pub cache_summary: Option<CacheSummaryFormat>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -2968,6 +4336,17 @@ pub struct SyncArgs {
         value_parser = parse_maybe_string,
     )]
     pub python: Option<Maybe<String>>,
+
+    /// The platform for which the `--target` directory should be populated.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// Any source distributions are built for the current platform, so installing for a different
+    /// platform generally requires that wheels are available for every dependency.
+    #[arg(long, requires = "target", help_heading = "Python options")]
+    pub python_platform: Option<TargetTriple>,
 }
 
 #[derive(Args)]
@@ -2984,12 +4363,128 @@ pub struct LockArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
     pub frozen: bool,
 
-    /// Perform a dry run, without writing the lockfile.
+    /// Perform a dry run, without writing the lockfile.
+    ///
+    /// In dry-run mode, uv will resolve the project's dependencies and report on the resulting
+    /// changes, but will not write the lockfile to disk.
+    #[arg(long, conflicts_with = "frozen", conflicts_with = "locked")]
+    pub dry_run: bool,
+
+    /// Attempt to unify packages that are locked at multiple versions across forks.
+    ///
+    /// After resolving, uv will re-resolve with a preference for a single version of each package
+    /// that was locked at multiple versions (e.g., one version for Linux and another for Windows),
+    /// and merge the duplicates whenever a single version satisfies every fork.
+    ///
+    /// uv will report the duplicates that were merged, along with those that are irreducible.
+    #[arg(long, conflicts_with = "frozen", conflicts_with = "locked")]
+    pub minimize: bool,
+
+    /// Check whether the locked packages support every Python version in `requires-python`.
+    ///
+    /// For each Python minor version included in the project's `requires-python` range, uv will
+    /// verify that every locked package required on that version has a compatible wheel or source
+    /// distribution, and warn about any dependency that effectively narrows the supported range,
+    /// along with the chain of dependencies through which it's required.
+    #[arg(long)]
+    pub check_requires_python: bool,
+
+    /// Check which locked packages would need to be built from source on each target environment.
+    ///
+    /// For each environment in `tool.uv.environments` (or, if none are declared, for Linux,
+    /// Windows, and macOS), uv will report the locked packages that are required in that
+    /// environment but lack a compatible wheel, along with the chain of dependencies through which
+    /// they're required. Packages for which building from source is disabled via `no-build` or
+    /// `no-build-package` are reported as warnings.
+    #[arg(long)]
+    pub check_source_builds: bool,
+
+    /// Check whether the conflicts declared in `tool.uv.conflicting-groups` are minimal and
+    /// complete.
+    ///
+    /// uv will re-resolve the workspace with each extra in its own fork, and warn about any pair
+    /// of extras that require different versions of the same package but aren't declared as
+    /// conflicting, along with any declared conflicting extra that doesn't conflict with the other
+    /// extras in its set. Each conflict is reported with the chains of dependencies through which
+    /// the extras require the package.
+    ///
+    /// The check is performed even if the lock operation fails, as an undeclared conflict is a
+    /// common cause of resolution failures.
+    #[arg(long, conflicts_with = "frozen")]
+    pub check_conflicts: bool,
+
+    /// Record the inputs that influenced the resolution alongside the lockfile.
+    ///
+    /// After locking, uv will write a `uv.provenance.toml` next to the `uv.lock`, recording the uv
+    /// version, the `exclude-newer` cutoff, the resolution and pre-release strategies, the
+    /// interpreter used for the resolution, a digest of the remaining resolver configuration, and
+    /// a snapshot of the files each index offers for the locked packages.
+    ///
+    /// Use `--verify-provenance` to detect when any of these inputs has since changed.
+    #[arg(long, conflicts_with = "frozen", conflicts_with = "dry_run")]
+    pub provenance: bool,
+
+    /// Verify that the inputs recorded by `--provenance` haven't changed.
+    ///
+    /// uv will recompute the inputs to the existing lockfile's resolution, without re-locking,
+    /// and exit with an error if any input differs from those recorded in `uv.provenance.toml`
+    /// (e.g., if the index now offers different files for a locked package, or the resolver
+    /// configuration has changed).
+    ///
+    /// Index pages are revalidated against the index, rather than read from the cache.
+    #[arg(
+        long,
+        conflicts_with = "frozen",
+        conflicts_with = "locked",
+        conflicts_with = "dry_run",
+        conflicts_with = "provenance"
+    )]
+    pub verify_provenance: bool,
+
+    /// Write a report of aggregate statistics on the lockfile to the given path, as JSON.
+    ///
+    /// The report includes the number of locked packages, overall and as required by each
+    /// dependency group and target environment; the number and total size of the locked source
+    /// distributions and wheels; and the packages that were added, removed, or updated relative to
+    /// the previous lockfile.
+    #[arg(long, value_name = "PATH")]
+    pub report: Option<PathBuf>,
+
+    /// If the resolution fails, interactively walk through the suggested fixes.
+    ///
+    /// For each of the conflicting requirements identified by the resolver (most likely first),
+    /// uv will prompt for an action: relaxing the requirement in a workspace member, adding an
+    /// override to `tool.uv.override-dependencies`, or declaring the conflicting extras in
+    /// `tool.uv.conflicting-groups`. The selected edits are applied to the `pyproject.toml` files,
+    /// and the lock is retried.
+    ///
+    /// Has no effect if stderr is not a terminal.
+    #[arg(
+        long,
+        conflicts_with = "frozen",
+        conflicts_with = "locked",
+        conflicts_with = "dry_run"
+    )]
+    pub interactive: bool,
+
+    /// Print a summary of how effectively the cache was used.
+    ///
+    /// Reports the number of HTTP responses (for index pages, metadata, and wheels) that were
+    /// served from the cache, revalidated, or fetched from the network; the number of wheels that
+    /// were served from the cache or downloaded; and the number of source distributions that were
+    /// served from the cache or built; alongside the time spent in each phase (e.g., resolving,
+    /// preparing, and installing).
+    ///
+    /// With `--cache-summary=json`, the summary is written to stdout as JSON.
+    #[arg(long, value_enum, num_args = 0..=1, require_equals = true, default_missing_value = "text")]
+    pub cache_summary: Option<CacheSummaryFormat>,
+
+    /// Lock the project for the named environment only, as declared in `tool.uv.environments`.
     ///
-    /// In dry-run mode, uv will resolve the project's dependencies and report on the resulting
-    /// changes, but will not write the lockfile to disk.
-    #[arg(long, conflicts_with = "frozen", conflicts_with = "locked")]
-    pub dry_run: bool,
+    /// The lockfile will be restricted to the given environment, e.g., to lock for a target like
+    /// Pyodide or an embedded platform that's described by a complete set of marker values.
+    #[arg(long, value_name = "NAME")]
+    pub environment: Option<String>,
 
     #[command(flatten)]
     pub resolver: ResolverArgs,
@@ -3033,6 +4528,18 @@ pub struct AddArgs {
     #[arg(long, short, group = "sources", value_parser = parse_file_path)]
     pub requirements: Vec<PathBuf>,
 
+    /// Add the third-party packages imported by the project's Python sources.
+    ///
+    /// uv will scan the Python files in the project for imports that are neither part of the
+    /// standard library nor defined within the project, map each imported module to the
+    /// distribution that provides it, and propose adding any that aren't already declared as
+    /// dependencies.
+    ///
+    /// When running interactively, uv will prompt for confirmation before adding the proposed
+    /// packages.
+    #[arg(long, group = "sources", conflicts_with = "script")]
+    pub from_imports: bool,
+
     /// Add the requirements to the development dependency group.
     ///
     /// This option is an alias for `--group dev`.
@@ -3075,6 +4582,19 @@ pub struct AddArgs {
     )]
     pub raw_sources: bool,
 
+    /// The version specifier to write for dependencies added without one.
+    ///
+    /// By default, uv adds a lower bound on the locked version (e.g., `>=1.4.2`). Use `major` or
+    /// `minor` to also cap the dependency at the next major or minor version, or `exact` to pin the
+    /// locked version.
+    ///
+    /// With `--upgrade` or `--upgrade-package`, the specifiers of any existing dependencies that are
+    /// upgraded are rewritten according to the same policy.
+    ///
+    /// Defaults to the `add-bound` setting, if set.
+    #[arg(long, value_enum, conflicts_with = "raw_sources")]
+    pub bound: Option<AddBound>,
+
     /// Commit to use when adding a dependency from Git.
     #[arg(long, group = "git-ref", action = clap::ArgAction::Set)]
     pub rev: Option<String>,
@@ -3154,9 +4674,31 @@ pub struct AddArgs {
 #[allow(clippy::struct_excessive_bools)]
 pub struct RemoveArgs {
     /// The names of the dependencies to remove (e.g., `ruff`).
-    #[arg(required = true)]
+    #[arg(required_unless_present = "unused")]
     pub packages: Vec<PackageName>,
 
+    /// Remove the dependencies that aren't imported by the project's Python sources.
+    ///
+    /// uv will scan the Python files in the project for imports, and propose removing any
+    /// dependencies (of the selected type, e.g., `--dev` or `--optional`) that provide none of the
+    /// imported modules. The modules provided by each dependency are read from the project
+    /// environment, if it exists; otherwise, they're inferred from the dependency's name.
+    ///
+    /// Dependencies that are used without being imported (e.g., plugins or command-line tools)
+    /// can be excluded via `tool.uv.ignore-unused-dependencies`.
+    ///
+    /// When running interactively, uv will prompt for confirmation before removing the proposed
+    /// dependencies. Otherwise, the proposed dependencies are only reported, unless `--yes` is
+    /// provided.
+    #[arg(long, conflicts_with_all = ["packages", "script"])]
+    pub unused: bool,
+
+    /// Remove the dependencies proposed by `--unused` without prompting for confirmation.
+    ///
+    /// Required to remove unused dependencies when uv isn't running in an interactive terminal.
+    #[arg(long, requires = "unused")]
+    pub yes: bool,
+
     /// Remove the packages from the development dependency group.
     ///
     /// This option is an alias for `--group dev`.
@@ -3223,6 +4765,52 @@ pub struct RemoveArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct PinNamespace {
+    #[command(subcommand)]
+    pub command: PinCommand,
+}
+
+#[derive(Subcommand)]
+pub enum PinCommand {
+    /// Pin packages, such that they can't be upgraded past the given version.
+    ///
+    /// Each package may be provided with an exact version (e.g., `django==4.2.16`), or by name
+    /// alone, in which case the package is pinned to its version in the project's lockfile.
+    Add(PinAddArgs),
+    /// Remove pins for the given packages.
+    Remove(PinRemoveArgs),
+    /// List the pinned packages.
+    ///
+    /// Lists the pins that apply to the current project, including global pins, with project pins
+    /// taking precedence.
+    List,
+}
+
+#[derive(Args)]
+pub struct PinAddArgs {
+    /// The packages to pin (e.g., `django==4.2.16` or `django`).
+    #[arg(required = true)]
+    pub packages: Vec<Requirement<VerbatimParsedUrl>>,
+
+    /// Write the pins to the global `uv.pins.toml` file (e.g., `~/.config/uv/uv.pins.toml`),
+    /// rather than the project.
+    #[arg(long)]
+    pub global: bool,
+}
+
+#[derive(Args)]
+pub struct PinRemoveArgs {
+    /// The packages to unpin (e.g., `django`).
+    #[arg(required = true)]
+    pub packages: Vec<PackageName>,
+
+    /// Remove the pins from the global `uv.pins.toml` file (e.g., `~/.config/uv/uv.pins.toml`),
+    /// rather than the project.
+    #[arg(long)]
+    pub global: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct TreeArgs {
@@ -3239,6 +4827,35 @@ pub struct TreeArgs {
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
+    /// Show why the given package is included in the dependency tree.
+    ///
+    /// Displays every path from the given package to the workspace members that depend on it,
+    /// annotating each edge with the extras, dependency groups, and markers under which it
+    /// applies, and each dependent that isn't always installed with the extras and dependency
+    /// groups of the workspace members that activate it (e.g., `(via group: dev)`). Multiple
+    /// edges between the same pair of packages are merged into a single line, and subtrees that
+    /// were already displayed are collapsed and marked with `(*)`. Implies `--invert`.
+    #[arg(long, value_name = "PACKAGE", conflicts_with_all = ["invert", "package"])]
+    pub why: Vec<PackageName>,
+
+    /// Compare the lockfile against a previous lockfile at the given path.
+    ///
+    /// Rather than the dependency tree, displays the packages that were added, removed, or
+    /// updated, the dependency edges that were added or removed, and the packages whose set of
+    /// dependents changed.
+    ///
+    /// The lockfile at the given path is treated as the previous state, and the project's
+    /// lockfile (updated as necessary, unless `--locked` or `--frozen` is provided) as the
+    /// current state.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["why", "invert", "outdated", "package"])]
+    pub diff: Option<PathBuf>,
+
+    /// Display the lockfile differences as JSON.
+    ///
+    /// Intended for automation, e.g., to generate release notes from the changes to a lockfile.
+    #[arg(long, requires = "diff")]
+    pub json: bool,
+
     /// Include the development dependency group.
     ///
     /// Development dependencies are defined via `dependency-groups.dev` or
@@ -3256,31 +4873,263 @@ pub struct TreeArgs {
     #[arg(long, conflicts_with("no_dev"))]
     pub only_dev: bool,
 
-    /// Omit the development dependency group.
+    /// Omit the development dependency group.
+    ///
+    /// This option is an alias for `--no-group dev`.
+    #[arg(long, overrides_with("dev"), conflicts_with = "invert")]
+    pub no_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with("only_group"))]
+    pub group: Vec<GroupName>,
+
+    /// Exclude dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_group: Vec<GroupName>,
+
+    /// Only include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    ///
+    /// The project itself will also be omitted.
+    #[arg(long, conflicts_with("group"))]
+    pub only_group: Vec<GroupName>,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Display the requirements without locking the project.
+    ///
+    /// If the lockfile is missing, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    /// The Python version to use when filtering the tree.
+    ///
+    /// For example, pass `--python-version 3.10` to display the dependencies
+    /// that would be included when installing on Python 3.10.
+    ///
+    /// Defaults to the version of the discovered Python interpreter.
+    #[arg(long, conflicts_with = "universal")]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform to use when filtering the tree.
+    ///
+    /// For example, pass `--platform windows` to display the dependencies that
+    /// would be included when installing on Windows.
+    ///
+    /// Represented as a "target triple", a string that describes the target
+    /// platform in terms of its CPU, vendor, and operating system name, like
+    /// `x86_64-unknown-linux-gnu` or `aarch64-apple-darwin`.
+    #[arg(long, conflicts_with = "universal")]
+    pub python_platform: Option<TargetTriple>,
+
+    /// The Python interpreter to use for locking and filtering.
+    ///
+    /// By default, the tree is filtered to match the platform as reported by
+    /// the Python interpreter. Use `--universal` to display the tree for all
+    /// platforms, or use `--python-version` or `--python-platform` to override
+    /// a subset of markers.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ExportArgs {
+    /// The format to which `uv.lock` should be exported.
+    ///
+    /// Supports `requirements-txt`, `requirements-in`, `dockerfile`, `nix`, `bazel`, `rpm-spec`, and
+    /// `deb-control`.
+    #[arg(long, value_enum, default_value_t = ExportFormat::default())]
+    pub format: ExportFormat,
+
+    /// Export the entire workspace.
+    ///
+    /// The dependencies for all workspace members will be included in the
+    /// exported requirements file.
+    ///
+    /// Any extras or groups specified via `--extra`, `--group`, or related options
+    /// will be applied to all workspace members.
+    #[arg(long, conflicts_with = "package")]
+    pub all_packages: bool,
+
+    /// Export the dependencies for a specific package in the workspace.
+    ///
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long, conflicts_with = "all_packages")]
+    pub package: Option<PackageName>,
+
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Include the development dependency group.
+    ///
+    /// This option is an alias for `--group dev`.
+    #[arg(long, overrides_with("no_dev"), hide = true)]
+    pub dev: bool,
+
+    /// Omit the development dependency group.
+    ///
+    /// This option is an alias for `--no-group dev`.
+    #[arg(long, overrides_with("dev"))]
+    pub no_dev: bool,
+
+    /// Only include the development dependency group.
+    ///
+    /// Omit other dependencies. The project itself will also be omitted.
+    ///
+    /// This option is an alias for `--only-group dev`.
+    #[arg(long, conflicts_with("no_dev"))]
+    pub only_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with("only_group"))]
+    pub group: Vec<GroupName>,
+
+    /// Exclude dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_group: Vec<GroupName>,
+
+    /// Only include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    ///
+    /// The project itself will also be omitted.
+    #[arg(long, conflicts_with("group"))]
+    pub only_group: Vec<GroupName>,
+
+    /// Exclude the comment header at the top of the generated output file.
+    #[arg(long, overrides_with("header"))]
+    pub no_header: bool,
+
+    #[arg(long, overrides_with("no_header"), hide = true)]
+    pub header: bool,
+
+    /// Install any editable dependencies, including the project and any workspace members, as
+    /// non-editable.
+    #[arg(long)]
+    pub no_editable: bool,
+
+    /// Include hashes for all dependencies.
+    #[arg(long, overrides_with("no_hashes"), hide = true)]
+    pub hashes: bool,
+
+    /// Omit hashes in the generated output.
+    #[arg(long, overrides_with("hashes"))]
+    pub no_hashes: bool,
+
+    /// Annotate each requirement with the packages that require it, and the index from which it
+    /// was locked.
+    ///
+    /// Only applies to the `requirements-txt` format.
+    #[arg(long, overrides_with("no_annotate"))]
+    pub annotate: bool,
+
+    #[arg(long, overrides_with("annotate"), hide = true)]
+    pub no_annotate: bool,
+
+    /// Write the exported requirements to the given file.
+    #[arg(long, short)]
+    pub output_file: Option<PathBuf>,
+
+    /// The distribution package to require in place of a Python package, as `PACKAGE=DISTRO_PACKAGE`
+    /// (e.g., `pyyaml=python3-yaml`).
+    ///
+    /// Only applies to the `rpm-spec` and `deb-control` formats. By default, dependencies are
+    /// mapped to `python3dist(<package>)` for RPM and `python3-<package>` for Debian.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub distro_package: Vec<DistroPackageEntry>,
+
+    /// Do not emit the current project.
+    ///
+    /// By default, the current project is included in the exported requirements file with all of its
+    /// dependencies. The `--no-emit-project` option allows the project to be excluded, but all of
+    /// its dependencies to remain included.
+    #[arg(long, alias = "no-install-project")]
+    pub no_emit_project: bool,
+
+    /// Do not emit any workspace members, including the root project.
+    ///
+    /// By default, all workspace members and their dependencies are included in the exported
+    /// requirements file, with all of their dependencies. The `--no-emit-workspace` option allows
+    /// exclusion of all the workspace members while retaining their dependencies.
+    #[arg(long, alias = "no-install-workspace")]
+    pub no_emit_workspace: bool,
+
+    /// Do not emit the given package(s).
     ///
-    /// This option is an alias for `--no-group dev`.
-    #[arg(long, overrides_with("dev"), conflicts_with = "invert")]
-    pub no_dev: bool,
+    /// By default, all of the project's dependencies are included in the exported requirements
+    /// file. The `--no-install-package` option allows exclusion of specific packages.
+    #[arg(long, alias = "no-install-package")]
+    pub no_emit_package: Vec<PackageName>,
 
-    /// Include dependencies from the specified dependency group.
+    /// Export the requirements for the named environment, as declared in `tool.uv.environments`.
     ///
-    /// May be provided multiple times.
-    #[arg(long, conflicts_with("only_group"))]
-    pub group: Vec<GroupName>,
+    /// Packages that aren't required in the given environment are omitted, along with any markers
+    /// that are always satisfied in the environment.
+    ///
+    /// Only applies to the `requirements-txt` format.
+    #[arg(long, value_name = "NAME", conflicts_with = "python_platform")]
+    pub environment: Option<String>,
 
-    /// Exclude dependencies from the specified dependency group.
+    /// Restrict the export to the requirements of the given target platform.
     ///
-    /// May be provided multiple times.
+    /// Packages that aren't required on the platform are omitted, along with any markers that are
+    /// always satisfied on it. Unlike `--environment`, the Python version is left unrestricted.
+    ///
+    /// Only applies to the `requirements-txt` format.
     #[arg(long)]
-    pub no_group: Vec<GroupName>,
+    pub python_platform: Option<TargetTriple>,
 
-    /// Only include dependencies from the specified dependency group.
+    /// The URL of a Pyodide package index to include in the export for `wasm32-pyodide`.
     ///
-    /// May be provided multiple times.
-    ///
-    /// The project itself will also be omitted.
-    #[arg(long, conflicts_with("group"))]
-    pub only_group: Vec<GroupName>,
+    /// When exporting with `--python-platform wasm32-pyodide`, the index is written to the output
+    /// as an `--extra-index-url`, such that installers search it for packages that aren't published
+    /// to PyPI for Pyodide. The index isn't used when locking; to resolve against it, declare it
+    /// in `tool.uv.index` instead.
+    #[arg(long, env = EnvVars::UV_PYODIDE_INDEX_URL)]
+    pub pyodide_index_url: Option<IndexUrl>,
 
     /// Assert that the `uv.lock` will remain unchanged.
     ///
@@ -3289,44 +5138,28 @@ pub struct TreeArgs {
     #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
     pub locked: bool,
 
-    /// Display the requirements without locking the project.
+    /// Do not update the `uv.lock` before exporting.
     ///
-    /// If the lockfile is missing, uv will exit with an error.
+    /// If a `uv.lock` does not exist, uv will exit with an error.
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
     pub frozen: bool,
 
     #[command(flatten)]
-    pub build: BuildOptionsArgs,
+    pub resolver: ResolverArgs,
 
     #[command(flatten)]
-    pub resolver: ResolverArgs,
+    pub build: BuildOptionsArgs,
 
-    /// The Python version to use when filtering the tree.
-    ///
-    /// For example, pass `--python-version 3.10` to display the dependencies
-    /// that would be included when installing on Python 3.10.
-    ///
-    /// Defaults to the version of the discovered Python interpreter.
-    #[arg(long, conflicts_with = "universal")]
-    pub python_version: Option<PythonVersion>,
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
 
-    /// The platform to use when filtering the tree.
-    ///
-    /// For example, pass `--platform windows` to display the dependencies that
-    /// would be included when installing on Windows.
+    /// The Python interpreter to use during resolution.
     ///
-    /// Represented as a "target triple", a string that describes the target
-    /// platform in terms of its CPU, vendor, and operating system name, like
-    /// `x86_64-unknown-linux-gnu` or `aarch64-apple-darwin`.
-    #[arg(long, conflicts_with = "universal")]
-    pub python_platform: Option<TargetTriple>,
-
-    /// The Python interpreter to use for locking and filtering.
+    /// A Python interpreter is required for building source distributions to
+    /// determine package metadata when there are not wheels.
     ///
-    /// By default, the tree is filtered to match the platform as reported by
-    /// the Python interpreter. Use `--universal` to display the tree for all
-    /// platforms, or use `--python-version` or `--python-platform` to override
-    /// a subset of markers.
+    /// The interpreter is also used as the fallback value for the minimum
+    /// Python version if `requires-python` is not set.
     ///
     /// See `uv help python` for details on Python discovery and supported
     /// request formats.
@@ -3343,24 +5176,23 @@ pub struct TreeArgs {
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub struct ExportArgs {
-    /// The format to which `uv.lock` should be exported.
-    ///
-    /// At present, only `requirements-txt` is supported.
-    #[arg(long, value_enum, default_value_t = ExportFormat::default())]
-    pub format: ExportFormat,
+pub struct BuildImageArgs {
+    /// The name and optionally a tag for the image, in the `name:tag` format.
+    #[arg(long, short)]
+    pub tag: Option<String>,
 
-    /// Export the entire workspace.
-    ///
-    /// The dependencies for all workspace members will be included in the
-    /// exported requirements file.
+    /// The platforms to build the image for, e.g., `linux/amd64`.
     ///
-    /// Any extras or groups specified via `--extra`, `--group`, or related options
-    /// will be applied to all workspace members.
+    /// May be provided more than once. Building for a platform other than that of the host
+    /// requires `docker buildx`.
+    #[arg(long)]
+    pub platform: Vec<String>,
+
+    /// Install all workspace members in the image.
     #[arg(long, conflicts_with = "package")]
     pub all_packages: bool,
 
-    /// Export the dependencies for a specific package in the workspace.
+    /// Install a specific package in the workspace in the image.
     ///
     /// If the workspace member does not exist, uv will exit with an error.
     #[arg(long, conflicts_with = "all_packages")]
@@ -3419,52 +5251,113 @@ pub struct ExportArgs {
     #[arg(long, conflicts_with("group"))]
     pub only_group: Vec<GroupName>,
 
-    /// Exclude the comment header at the top of the generated output file.
-    #[arg(long, overrides_with("header"))]
-    pub no_header: bool,
-
-    #[arg(long, overrides_with("no_header"), hide = true)]
-    pub header: bool,
-
     /// Install any editable dependencies, including the project and any workspace members, as
     /// non-editable.
     #[arg(long)]
     pub no_editable: bool,
 
-    /// Include hashes for all dependencies.
-    #[arg(long, overrides_with("no_hashes"), hide = true)]
-    pub hashes: bool,
+    /// Do not install the current project.
+    #[arg(long)]
+    pub no_install_project: bool,
 
-    /// Omit hashes in the generated output.
-    #[arg(long, overrides_with("hashes"))]
-    pub no_hashes: bool,
+    /// Do not install any workspace members, including the root project.
+    #[arg(long)]
+    pub no_install_workspace: bool,
 
-    /// Write the exported requirements to the given file.
+    /// Do not install the given package(s).
+    #[arg(long)]
+    pub no_install_package: Vec<PackageName>,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Do not update the `uv.lock` before building the image.
+    ///
+    /// If a `uv.lock` does not exist, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// The image itself uses the minimum Python version supported by the lockfile.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct BundleArgs {
+    /// The format of the bundle.
+    #[arg(long, value_enum, default_value_t = BundleFormat::default())]
+    pub format: BundleFormat,
+
+    /// The path to write the bundle to.
+    ///
+    /// Defaults to `<project>.pyz` (or `<project>`, for directory bundles) in the `dist`
+    /// subdirectory of the workspace root.
     #[arg(long, short)]
-    pub output_file: Option<PathBuf>,
+    pub output: Option<PathBuf>,
 
-    /// Do not emit the current project.
+    /// The entrypoint to invoke when the bundle is run.
     ///
-    /// By default, the current project is included in the exported requirements file with all of its
-    /// dependencies. The `--no-emit-project` option allows the project to be excluded, but all of
-    /// its dependencies to remain included.
-    #[arg(long, alias = "no-install-project")]
-    pub no_emit_project: bool,
+    /// Either a console script provided by an installed package (e.g., `flask`), or an object
+    /// reference in the `module:function` format.
+    #[arg(long, short)]
+    pub entry_point: Option<String>,
 
-    /// Do not emit any workspace members, including the root project.
+    /// Bundle a specific package in the workspace.
     ///
-    /// By default, all workspace members and their dependencies are included in the exported
-    /// requirements file, with all of their dependencies. The `--no-emit-workspace` option allows
-    /// exclusion of all the workspace members while retaining their dependencies.
-    #[arg(long, alias = "no-install-workspace")]
-    pub no_emit_workspace: bool,
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long)]
+    pub package: Option<PackageName>,
 
-    /// Do not emit the given package(s).
+    /// Include optional dependencies from the specified extra name.
     ///
-    /// By default, all of the project's dependencies are included in the exported requirements
-    /// file. The `--no-install-package` option allows exclusion of specific packages.
-    #[arg(long, alias = "no-install-package")]
-    pub no_emit_package: Vec<PackageName>,
+    /// May be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Include the development dependency group.
+    ///
+    /// Development dependencies are excluded from bundles by default.
+    #[arg(long)]
+    pub dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub group: Vec<GroupName>,
 
     /// Assert that the `uv.lock` will remain unchanged.
     ///
@@ -3473,14 +5366,14 @@ pub struct ExportArgs {
     #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
     pub locked: bool,
 
-    /// Do not update the `uv.lock` before exporting.
+    /// Bundle without updating the `uv.lock` file.
     ///
     /// If a `uv.lock` does not exist, uv will exit with an error.
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
     pub frozen: bool,
 
     #[command(flatten)]
-    pub resolver: ResolverArgs,
+    pub installer: ResolverInstallerArgs,
 
     #[command(flatten)]
     pub build: BuildOptionsArgs,
@@ -3488,13 +5381,10 @@ pub struct ExportArgs {
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
-    /// The Python interpreter to use during resolution.
+    /// The Python interpreter to use for the bundle.
     ///
-    /// A Python interpreter is required for building source distributions to
-    /// determine package metadata when there are not wheels.
-    ///
-    /// The interpreter is also used as the fallback value for the minimum
-    /// Python version if `requires-python` is not set.
+    /// The bundle can be run by any interpreter with the same Python version (and, for extension
+    /// modules, the same implementation).
     ///
     /// See `uv help python` for details on Python discovery and supported
     /// request formats.
@@ -3507,6 +5397,17 @@ pub struct ExportArgs {
         value_parser = parse_maybe_string,
     )]
     pub python: Option<Maybe<String>>,
+
+    /// The platform for which the bundle should be built.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// Any source distributions are built for the current platform, so bundling for a different
+    /// platform generally requires that wheels are available for every dependency.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
 }
 
 #[derive(Args)]
@@ -3883,6 +5784,16 @@ pub enum PythonCommand {
 
     /// Uninstall Python versions.
     Uninstall(PythonUninstallArgs),
+
+    /// Invalidate the cached information for Python interpreters.
+    ///
+    /// uv caches the results of querying each Python interpreter (e.g., its version, markers, and
+    /// tags), keyed by the modification time of the interpreter executable. The cached results are
+    /// reused until the executable changes.
+    ///
+    /// Use this command to force uv to query every Python interpreter again, e.g., if an
+    /// interpreter was modified without changing its executable.
+    Refresh,
 }
 
 #[derive(Args)]
@@ -3905,6 +5816,15 @@ pub struct PythonListArgs {
     /// By default, available downloads for the current platform are shown.
     #[arg(long)]
     pub only_installed: bool,
+
+    /// Display the Python versions as JSON.
+    ///
+    /// Each entry includes the installation key, version, implementation, platform, and variant;
+    /// the source of the installation (`managed`, `system`, `pyenv`, or `download`); the path to
+    /// the interpreter, if installed; the download URL, checksum, and size, if available for
+    /// download; and the end-of-life date of the minor version, if known.
+    #[arg(long)]
+    pub json: bool,
 }
 
 #[derive(Args)]
@@ -3968,6 +5888,29 @@ pub struct PythonInstallArgs {
     /// Implies `--reinstall`.
     #[arg(long, short)]
     pub force: bool,
+
+    /// Register the installed Python versions in the Windows registry.
+    ///
+    /// Registered installations follow PEP 514, such that they're discoverable by other tools, like
+    /// the `py` launcher (e.g., `py -V:Astral/cpython-3.12.4-windows-x86_64-none`). The
+    /// registrations are removed when the Python versions are uninstalled.
+    ///
+    /// Only supported on Windows.
+    #[arg(long, env = EnvVars::UV_PYTHON_INSTALL_REGISTRY, value_parser = clap::builder::BoolishValueParser::new(), overrides_with("no_registry"))]
+    pub registry: bool,
+
+    #[arg(long, overrides_with("registry"), hide = true)]
+    pub no_registry: bool,
+
+    /// The scope in which to register the installed Python versions in the Windows registry.
+    ///
+    /// By default, installations are registered for the current user (`HKEY_CURRENT_USER`).
+    /// Registering installations for all users (`HKEY_LOCAL_MACHINE`) requires administrator
+    /// privileges.
+    ///
+    /// Implies `--registry`.
+    #[arg(long, value_enum, env = EnvVars::UV_PYTHON_INSTALL_REGISTRY_SCOPE)]
+    pub registry_scope: Option<RegistryScope>,
 }
 
 #[derive(Args)]
@@ -3982,6 +5925,15 @@ pub struct PythonUninstallArgs {
     /// Uninstall all managed Python versions.
     #[arg(long, conflicts_with("targets"))]
     pub all: bool,
+
+    /// Migrate any environments that use the uninstalled Python versions.
+    ///
+    /// The tool environments, the project environment, and the active virtual environment (i.e.,
+    /// `VIRTUAL_ENV`) are checked for references to the uninstalled Python versions. Each affected
+    /// environment is re-pointed to another installed managed Python version with the same minor
+    /// version, if any. Environments that can't be migrated are reported.
+    #[arg(long)]
+    pub migrate: bool,
 }
 
 #[derive(Args)]
@@ -4052,6 +6004,36 @@ pub struct PythonPinArgs {
     pub no_project: bool,
 }
 
+#[derive(Args)]
+pub struct VersionArgs {
+    /// Bump the version of the current project, rather than displaying uv's version.
+    ///
+    /// The `project.version` field in the project's `pyproject.toml` is updated in place. May be
+    /// provided multiple times, in which case the bumps are applied in order, e.g., `--bump patch
+    /// --bump alpha` would bump `1.2.3` to `1.2.4a1`.
+    ///
+    /// The bumped version must be greater than the current version.
+    #[arg(long, value_enum)]
+    pub bump: Vec<VersionBump>,
+
+    /// Display the bumped version, but don't update the `pyproject.toml`.
+    #[arg(long, requires = "bump")]
+    pub dry_run: bool,
+
+    #[arg(long, value_enum, default_value = "text")]
+    pub output_format: VersionFormat,
+}
+
+#[derive(Args)]
+pub struct CompleteArgs {
+    /// The shell requesting the completions.
+    pub shell: clap_complete_command::Shell,
+
+    /// The command line, up to the cursor.
+    #[arg(allow_hyphen_values = true)]
+    pub line: String,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct GenerateShellCompletionArgs {
@@ -4082,7 +6064,7 @@ pub struct GenerateShellCompletionArgs {
     #[arg(long, hide = true)]
     pub no_progress: bool,
     #[arg(long, hide = true)]
-    pub config_file: Option<PathBuf>,
+    pub config_file: Vec<PathBuf>,
     #[arg(long, hide = true)]
     pub no_config: bool,
     #[arg(long, short, action = clap::ArgAction::HelpShort, hide = true)]
@@ -4205,8 +6187,11 @@ pub struct BuildOptionsArgs {
     pub build: bool,
 
     /// Don't build source distributions for a specific package.
+    ///
+    /// May include a marker expression to only disable source builds in matching environments,
+    /// e.g., `--no-build-package "numpy; sys_platform == 'win32'"`.
     #[arg(long, help_heading = "Build options")]
-    pub no_build_package: Vec<PackageName>,
+    pub no_build_package: Vec<ScopedPackageName>,
 
     /// Don't install pre-built wheels.
     ///
@@ -4363,6 +6348,18 @@ pub struct InstallerArgs {
     )]
     pub no_compile_bytecode: bool,
 
+    /// The strategy to use when invalidating compiled bytecode.
+    ///
+    /// By default, bytecode files embed the modification time of their source file
+    /// (`timestamp`). The hash-based modes (`checked-hash` and `unchecked-hash`) embed a hash of
+    /// the source file instead, producing reproducible `.pyc` files, as required by some
+    /// deployment and signing pipelines.
+    ///
+    /// Only used when bytecode compilation is enabled. Defaults to `checked-hash` if
+    /// `SOURCE_DATE_EPOCH` is set, and `timestamp` otherwise.
+    #[arg(long, value_enum, help_heading = "Installer options")]
+    pub invalidation_mode: Option<PycInvalidationMode>,
+
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any local or Git
     /// sources.
@@ -4498,6 +6495,15 @@ pub struct ResolverArgs {
     #[arg(long, env = EnvVars::UV_EXCLUDE_NEWER, help_heading = "Resolver options")]
     pub exclude_newer: Option<ExcludeNewer>,
 
+    /// The maximum number of times the resolver may backtrack before giving up.
+    ///
+    /// When the limit is exceeded, resolution fails with a summary of the packages that consumed
+    /// the most effort, such that they can be constrained or pinned.
+    ///
+    /// By default, the number of backtracks is unlimited.
+    #[arg(long, env = EnvVars::UV_MAX_BACKTRACKS, help_heading = "Resolver options")]
+    pub max_backtracks: Option<usize>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// This option is only used when building source distributions.
@@ -4670,6 +6676,15 @@ pub struct ResolverInstallerArgs {
     #[arg(long, env = EnvVars::UV_EXCLUDE_NEWER, help_heading = "Resolver options")]
     pub exclude_newer: Option<ExcludeNewer>,
 
+    /// The maximum number of times the resolver may backtrack before giving up.
+    ///
+    /// When the limit is exceeded, resolution fails with a summary of the packages that consumed
+    /// the most effort, such that they can be constrained or pinned.
+    ///
+    /// By default, the number of backtracks is unlimited.
+    #[arg(long, env = EnvVars::UV_MAX_BACKTRACKS, help_heading = "Resolver options")]
+    pub max_backtracks: Option<usize>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
@@ -4711,6 +6726,18 @@ pub struct ResolverInstallerArgs {
     )]
     pub no_compile_bytecode: bool,
 
+    /// The strategy to use when invalidating compiled bytecode.
+    ///
+    /// By default, bytecode files embed the modification time of their source file
+    /// (`timestamp`). The hash-based modes (`checked-hash` and `unchecked-hash`) embed a hash of
+    /// the source file instead, producing reproducible `.pyc` files, as required by some
+    /// deployment and signing pipelines.
+    ///
+    /// Only used when bytecode compilation is enabled. Defaults to `checked-hash` if
+    /// `SOURCE_DATE_EPOCH` is set, and `timestamp` otherwise.
+    #[arg(long, value_enum, help_heading = "Installer options")]
+    pub invalidation_mode: Option<PycInvalidationMode>,
+
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any local or Git
     /// sources.
@@ -4832,11 +6859,11 @@ pub struct PublishArgs {
     )]
     pub token: Option<String>,
 
-    /// Configure using trusted publishing through GitHub Actions.
+    /// Configure using trusted publishing through GitHub Actions, GitLab CI, or CircleCI.
     ///
-    /// By default, uv checks for trusted publishing when running in GitHub Actions, but ignores it
-    /// if it isn't configured or the workflow doesn't have enough permissions (e.g., a pull request
-    /// from a fork).
+    /// By default, uv checks for trusted publishing when running in GitHub Actions, GitLab CI, or
+    /// CircleCI, but ignores it if it isn't configured or the workflow doesn't have enough
+    /// permissions (e.g., a pull request from a fork).
     #[arg(long)]
     pub trusted_publishing: Option<TrustedPublishing>,
 
@@ -4865,7 +6892,18 @@ pub struct PublishArgs {
     #[arg(long, env = EnvVars::UV_PUBLISH_CHECK_URL)]
     pub check_url: Option<IndexUrl>,
 
-    #[arg(long, hide = true)]
+    /// Skip files that already exist in the registry.
+    ///
+    /// Before uploading, the registry is queried for the file. If the exact same file already
+    /// exists, the file will not be uploaded, such that publishing can be retried after a partial
+    /// failure, e.g., in CI. If a file with the same name but different contents exists, publishing
+    /// fails.
+    ///
+    /// Unlike `--check-url`, the index to query is inferred from the publish URL. PyPI and TestPyPI
+    /// are queried via the JSON API, while GitLab, Artifactory, and Nexus are queried via the
+    /// simple API that they serve alongside the upload endpoint. For other registries, use
+    /// `--check-url` instead.
+    #[arg(long)]
     pub skip_existing: bool,
 }
 
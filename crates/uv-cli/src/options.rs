@@ -1,5 +1,5 @@
 use uv_cache::Refresh;
-use uv_configuration::ConfigSettings;
+use uv_configuration::{CompileBytecode, ConfigSettings};
 use uv_distribution_types::{PipExtraIndex, PipFindLinks, PipIndex};
 use uv_resolver::PrereleaseMode;
 use uv_settings::{Combine, PipOptions, ResolverInstallerOptions, ResolverOptions};
@@ -48,6 +48,7 @@ impl From<ResolverArgs> for PipOptions {
             no_build_isolation_package,
             build_isolation,
             exclude_newer,
+            max_backtracks,
             link_mode,
             no_sources,
         } = args;
@@ -68,6 +69,7 @@ impl From<ResolverArgs> for PipOptions {
             no_build_isolation: flag(no_build_isolation, build_isolation),
             no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
+            max_backtracks,
             link_mode,
             no_sources: if no_sources { Some(true) } else { None },
             ..PipOptions::from(index_args)
@@ -91,6 +93,7 @@ impl From<InstallerArgs> for PipOptions {
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
+            invalidation_mode,
             no_sources,
         } = args;
 
@@ -104,7 +107,9 @@ impl From<InstallerArgs> for PipOptions {
             no_build_isolation: flag(no_build_isolation, build_isolation),
             exclude_newer,
             link_mode,
-            compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
+            compile_bytecode: flag(compile_bytecode, no_compile_bytecode)
+                .map(CompileBytecode::from),
+            invalidation_mode,
             no_sources: if no_sources { Some(true) } else { None },
             ..PipOptions::from(index_args)
         }
@@ -131,9 +136,11 @@ impl From<ResolverInstallerArgs> for PipOptions {
             no_build_isolation_package,
             build_isolation,
             exclude_newer,
+            max_backtracks,
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
+            invalidation_mode,
             no_sources,
         } = args;
 
@@ -155,8 +162,11 @@ impl From<ResolverInstallerArgs> for PipOptions {
             no_build_isolation: flag(no_build_isolation, build_isolation),
             no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
+            max_backtracks,
             link_mode,
-            compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
+            compile_bytecode: flag(compile_bytecode, no_compile_bytecode)
+                .map(CompileBytecode::from),
+            invalidation_mode,
             no_sources: if no_sources { Some(true) } else { None },
             ..PipOptions::from(index_args)
         }
@@ -240,6 +250,7 @@ pub fn resolver_options(
         no_build_isolation_package,
         build_isolation,
         exclude_newer,
+        max_backtracks,
         link_mode,
         no_sources,
     } = resolver_args;
@@ -297,6 +308,7 @@ pub fn resolver_options(
         no_build_isolation: flag(no_build_isolation, build_isolation),
         no_build_isolation_package: Some(no_build_isolation_package),
         exclude_newer,
+        max_backtracks,
         link_mode,
         no_build: flag(no_build, build),
         no_build_package: Some(no_build_package),
@@ -329,9 +341,11 @@ pub fn resolver_installer_options(
         no_build_isolation_package,
         build_isolation,
         exclude_newer,
+        max_backtracks,
         link_mode,
         compile_bytecode,
         no_compile_bytecode,
+        invalidation_mode,
         no_sources,
     } = resolver_installer_args;
 
@@ -402,8 +416,10 @@ pub fn resolver_installer_options(
             Some(no_build_isolation_package)
         },
         exclude_newer,
+        max_backtracks,
         link_mode,
-        compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
+        compile_bytecode: flag(compile_bytecode, no_compile_bytecode).map(CompileBytecode::from),
+        invalidation_mode,
         no_build: flag(no_build, build),
         no_build_package: if no_build_package.is_empty() {
             None
@@ -24,6 +24,7 @@ use uv_warnings::warn_user_once;
 
 use crate::linehaul::LineHaul;
 use crate::middleware::OfflineMiddleware;
+use crate::proxy::ProxyResolver;
 use crate::tls::read_identity;
 use crate::Connectivity;
 
@@ -56,6 +57,7 @@ pub struct BaseClientBuilder<'a> {
     auth_integration: AuthIntegration,
     default_timeout: Duration,
     extra_middleware: Option<ExtraMiddleware>,
+    index_proxies: Vec<(Url, Url)>,
 }
 
 /// A list of user-defined middlewares to be applied to the client.
@@ -90,6 +92,7 @@ impl BaseClientBuilder<'_> {
             auth_integration: AuthIntegration::default(),
             default_timeout: Duration::from_secs(30),
             extra_middleware: None,
+            index_proxies: vec![],
         }
     }
 }
@@ -161,6 +164,13 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the proxies to use for specific indexes, as pairs of the index URL and the proxy URL.
+    #[must_use]
+    pub fn index_proxies(mut self, index_proxies: Vec<(Url, Url)>) -> Self {
+        self.index_proxies = index_proxies;
+        self
+    }
+
     pub fn is_offline(&self) -> bool {
         matches!(self.connectivity, Connectivity::Offline)
     }
@@ -206,11 +216,15 @@ impl<'a> BaseClientBuilder<'a> {
             .unwrap_or(self.default_timeout);
         debug!("Using request timeout of {}s", timeout.as_secs());
 
+        // Select proxies per-request if any index proxies or a PAC file are configured.
+        let proxy_resolver = ProxyResolver::from_env(self.index_proxies.clone()).map(Arc::new);
+
         // Create a secure client that validates certificates.
         let raw_client = self.create_client(
             &user_agent_string,
             timeout,
             ssl_cert_file_exists,
+            proxy_resolver.as_ref(),
             Security::Secure,
         );
 
@@ -219,6 +233,7 @@ impl<'a> BaseClientBuilder<'a> {
             &user_agent_string,
             timeout,
             ssl_cert_file_exists,
+            proxy_resolver.as_ref(),
             Security::Insecure,
         );
 
@@ -259,6 +274,7 @@ impl<'a> BaseClientBuilder<'a> {
         user_agent: &str,
         timeout: Duration,
         ssl_cert_file_exists: bool,
+        proxy_resolver: Option<&Arc<ProxyResolver>>,
         security: Security,
     ) -> Client {
         // Configure the builder.
@@ -294,6 +310,17 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder
         };
 
+        // Configure the proxy. Note that setting any proxy disables `reqwest`'s own handling of
+        // the proxy environment variables, which the resolver replicates.
+        let client_builder = if let Some(proxy_resolver) = proxy_resolver {
+            let proxy_resolver = proxy_resolver.clone();
+            client_builder.proxy(reqwest::Proxy::custom(move |url| {
+                proxy_resolver.proxy_for(url)
+            }))
+        } else {
+            client_builder
+        };
+
         client_builder
             .build()
             .expect("Failed to build HTTP client.")
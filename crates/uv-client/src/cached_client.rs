@@ -1,4 +1,9 @@
-use std::{borrow::Cow, future::Future, path::Path};
+use std::{
+    borrow::Cow,
+    future::Future,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
 
 use futures::FutureExt;
 use reqwest::{Request, Response};
@@ -7,7 +12,8 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info_span, instrument, trace, warn, Instrument};
 
-use uv_cache::{CacheEntry, Freshness};
+use uv_cache::metrics::{self, CacheEvent};
+use uv_cache::{CacheEntry, Freshness, MemoryCache};
 use uv_fs::write_atomic;
 
 use crate::BaseClient;
@@ -17,6 +23,24 @@ use crate::{
     Error, ErrorKind,
 };
 
+/// The contents of each cached HTTP response that has been read from disk, along with the file's
+/// modification time and size, for use by long-lived processes.
+///
+/// Simple API responses for large projects can span several megabytes, so only the most recently
+/// used responses are retained.
+static RESPONSES: MemoryCache<PathBuf, (SystemTime, u64, AlignedVec)> = MemoryCache::new(1024);
+
+/// Return the cached HTTP responses that have been read since the last call, such that they can
+/// be preloaded by `uv daemon`.
+pub fn take_read_responses() -> Vec<PathBuf> {
+    RESPONSES.take_updated()
+}
+
+/// Preload the cached HTTP response at the given path into memory.
+pub fn preload_response(path: &Path) -> Result<(), Error> {
+    DataWithCachePolicy::from_path_sync(path).map(|_| ())
+}
+
 /// A trait the generalizes (de)serialization at a high level.
 ///
 /// The main purpose of this trait is to make the `CachedClient` work for
@@ -244,6 +268,11 @@ impl CachedClient {
                 cache_policy,
             }
         };
+        metrics::record(match &cached_response {
+            CachedResponse::FreshCache(_) => CacheEvent::HttpCached,
+            CachedResponse::NotModified { .. } => CacheEvent::HttpRevalidated,
+            CachedResponse::ModifiedOrNew { .. } => CacheEvent::HttpNetwork,
+        });
         match cached_response {
             CachedResponse::FreshCache(cached) => match Payload::from_aligned_bytes(cached.data) {
                 Ok(payload) => Ok(payload),
@@ -320,6 +349,7 @@ impl CachedClient {
         Callback: FnOnce(Response) -> CallbackReturn,
         CallbackReturn: Future<Output = Result<Payload, CallBackError>>,
     {
+        metrics::record(CacheEvent::HttpNetwork);
         let (response, cache_policy) = self.fresh_request(req).await?;
 
         let payload = self
@@ -625,12 +655,37 @@ impl DataWithCachePolicy {
     /// file given fails, then this returns an error.
     #[instrument]
     fn from_path_sync(path: &Path) -> Result<Self, Error> {
-        let file = fs_err::File::open(path).map_err(ErrorKind::Io)?;
+        let mut file = fs_err::File::open(path).map_err(ErrorKind::Io)?;
+
+        // If the file is unchanged since it was last read into memory, reuse its contents.
+        let stamp = file
+            .metadata()
+            .ok()
+            .and_then(|metadata| Some((metadata.modified().ok()?, metadata.len())));
+        if let Some((modified, len)) = stamp {
+            if let Some((.., bytes)) = RESPONSES
+                .get(&path.to_path_buf(), |(cached, cached_len, _)| {
+                    *cached == modified && *cached_len == len
+                })
+            {
+                return Self::from_aligned_bytes(bytes);
+            }
+        }
+
         // Note that we don't wrap our file in a buffer because it will just
         // get passed to AlignedVec::extend_from_reader, which doesn't benefit
         // from an intermediary buffer. In effect, the AlignedVec acts as the
         // buffer.
-        Self::from_reader(file)
+        let mut aligned_bytes = AlignedVec::new();
+        aligned_bytes
+            .extend_from_reader(&mut file)
+            .map_err(ErrorKind::Io)?;
+        if let Some((modified, len)) = stamp {
+            RESPONSES.insert_with(path.to_path_buf(), || {
+                (modified, len, aligned_bytes.clone())
+            });
+        }
+        Self::from_aligned_bytes(aligned_bytes)
     }
 
     /// Loads cached data and its associated HTTP cache policy from the given
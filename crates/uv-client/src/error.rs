@@ -43,6 +43,15 @@ impl Error {
         matches!(&*self.kind, ErrorKind::Offline(_))
     }
 
+    /// Returns `true` if the request failed to reach the server, e.g., due to a connection error
+    /// or a timeout.
+    pub(crate) fn is_connectivity_failure(&self) -> bool {
+        match &*self.kind {
+            ErrorKind::WrappedReqwestError(_, err) => err.is_connectivity_failure(),
+            _ => false,
+        }
+    }
+
     /// Returns `true` if this error corresponds to an I/O "not found" error.
     pub(crate) fn is_file_not_exists(&self) -> bool {
         let ErrorKind::Io(ref err) = &*self.kind else {
@@ -266,22 +275,7 @@ impl WrappedReqwestError {
     /// * dns error: failed to lookup address information: Name or service not known
     /// * failed to lookup address information: Name or service not known
     fn is_likely_offline(&self) -> bool {
-        let reqwest_err = match &self.0 {
-            reqwest_middleware::Error::Reqwest(err) => Some(err),
-            reqwest_middleware::Error::Middleware(err) => err.chain().find_map(|err| {
-                if let Some(err) = err.downcast_ref::<reqwest::Error>() {
-                    Some(err)
-                } else if let Some(reqwest_middleware::Error::Reqwest(err)) =
-                    err.downcast_ref::<reqwest_middleware::Error>()
-                {
-                    Some(err)
-                } else {
-                    None
-                }
-            }),
-        };
-
-        if let Some(reqwest_err) = reqwest_err {
+        if let Some(reqwest_err) = self.reqwest_error() {
             if !reqwest_err.is_connect() {
                 return false;
             }
@@ -297,6 +291,31 @@ impl WrappedReqwestError {
         }
         false
     }
+
+    /// Returns `true` if the request failed to reach the server, e.g., due to a connection error
+    /// or a timeout.
+    pub(crate) fn is_connectivity_failure(&self) -> bool {
+        self.reqwest_error()
+            .is_some_and(|err| err.is_connect() || err.is_timeout())
+    }
+
+    /// Return the underlying [`reqwest::Error`], if any.
+    fn reqwest_error(&self) -> Option<&reqwest::Error> {
+        match &self.0 {
+            reqwest_middleware::Error::Reqwest(err) => Some(err),
+            reqwest_middleware::Error::Middleware(err) => err.chain().find_map(|err| {
+                if let Some(err) = err.downcast_ref::<reqwest::Error>() {
+                    Some(err)
+                } else if let Some(reqwest_middleware::Error::Reqwest(err)) =
+                    err.downcast_ref::<reqwest_middleware::Error>()
+                {
+                    Some(err)
+                } else {
+                    None
+                }
+            }),
+        }
+    }
 }
 
 impl From<reqwest::Error> for WrappedReqwestError {
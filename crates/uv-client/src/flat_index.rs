@@ -1,11 +1,13 @@
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use futures::{FutureExt, StreamExt};
 use reqwest::Response;
-use tracing::{debug, info_span, warn, Instrument};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info_span, trace, warn, Instrument};
 use url::Url;
 
-use uv_cache::{Cache, CacheBucket};
+use uv_cache::{Cache, CacheBucket, Freshness};
 use uv_cache_key::cache_digest;
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{File, FileLocation, IndexUrl, UrlString};
@@ -104,7 +106,7 @@ impl<'a> FlatIndexClient<'a> {
                         let path = url
                             .to_file_path()
                             .map_err(|()| FlatIndexError::NonFileUrl(url.to_url()))?;
-                        Self::read_from_directory(&path, index)
+                        self.read_from_directory(&path, index)
                             .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))?
                     }
                     IndexUrl::Pypi(url) | IndexUrl::Url(url) => self
@@ -228,11 +230,91 @@ impl<'a> FlatIndexClient<'a> {
     }
 
     /// Read a flat remote index from a `--find-links` directory.
+    ///
+    /// The listing of the directory is cached, and invalidated whenever the modification time of
+    /// the directory changes (i.e., whenever a file is added, removed, or renamed), such that
+    /// newly built distributions are picked up without a `--refresh`.
     fn read_from_directory(
+        &self,
         path: &Path,
         flat_index: &IndexUrl,
     ) -> Result<FlatIndexEntries, FindLinksDirectoryError> {
+        let cache_entry = self.cache.entry(
+            CacheBucket::FlatIndex,
+            "dir",
+            format!("{}.msgpack", cache_digest(&path)),
+        );
+        let modified = fs_err::metadata(path)?.modified()?;
+
+        // Read the cached listing, if it's up-to-date.
+        let cached = self
+            .cache
+            .freshness(&cache_entry, None)
+            .is_ok_and(Freshness::is_fresh)
+            .then(|| fs_err::read(cache_entry.path()).ok())
+            .flatten()
+            .and_then(|bytes| rmp_serde::from_slice::<DirectoryListing>(&bytes).ok())
+            .filter(|listing| listing.modified == modified);
+
+        let filenames = if let Some(listing) = cached {
+            trace!(
+                "Using cached listing for `--find-links` directory: {}",
+                path.display()
+            );
+            listing.filenames
+        } else {
+            let filenames = Self::list_directory(path)?;
+            let listing = DirectoryListing {
+                modified,
+                filenames,
+            };
+            // Caching the listing is best-effort.
+            if let Err(err) = rmp_serde::to_vec(&listing)
+                .map_err(std::io::Error::other)
+                .and_then(|bytes| {
+                    fs_err::create_dir_all(cache_entry.dir())?;
+                    uv_fs::write_atomic_sync(cache_entry.path(), bytes)
+                })
+            {
+                warn!(
+                    "Failed to cache listing for `--find-links` directory {}: {err}",
+                    path.display()
+                );
+            }
+            listing.filenames
+        };
+
         let mut dists = Vec::new();
+        for filename in filenames {
+            // SAFETY: The index path is itself constructed from a URL.
+            let url = Url::from_file_path(path.join(&filename)).unwrap();
+
+            let Some(dist_filename) = DistFilename::try_from_normalized_filename(&filename) else {
+                debug!(
+                    "Ignoring `--find-links` entry (expected a wheel or source distribution filename): {}",
+                    path.join(&filename).display()
+                );
+                continue;
+            };
+
+            let file = File {
+                dist_info_metadata: false,
+                filename,
+                hashes: Vec::new(),
+                requires_python: None,
+                size: None,
+                upload_time_utc_ms: None,
+                url: FileLocation::AbsoluteUrl(UrlString::from(url)),
+                yanked: None,
+            };
+            dists.push((dist_filename, file, flat_index.clone()));
+        }
+        Ok(FlatIndexEntries::from_entries(dists))
+    }
+
+    /// List the names of the files in a `--find-links` directory.
+    fn list_directory(path: &Path) -> Result<Vec<String>, FindLinksDirectoryError> {
+        let mut filenames = Vec::new();
         for entry in fs_err::read_dir(path)? {
             let entry = entry?;
             let metadata = entry.metadata()?;
@@ -261,30 +343,18 @@ impl<'a> FlatIndexClient<'a> {
                 );
                 continue;
             };
-
-            // SAFETY: The index path is itself constructed from a URL.
-            let url = Url::from_file_path(entry.path()).unwrap();
-
-            let file = File {
-                dist_info_metadata: false,
-                filename: filename.to_string(),
-                hashes: Vec::new(),
-                requires_python: None,
-                size: None,
-                upload_time_utc_ms: None,
-                url: FileLocation::AbsoluteUrl(UrlString::from(url)),
-                yanked: None,
-            };
-
-            let Some(filename) = DistFilename::try_from_normalized_filename(&filename) else {
-                debug!(
-                    "Ignoring `--find-links` entry (expected a wheel or source distribution filename): {}",
-                    entry.path().display()
-                );
-                continue;
-            };
-            dists.push((filename, file, flat_index.clone()));
+            filenames.push(filename);
         }
-        Ok(FlatIndexEntries::from_entries(dists))
+        filenames.sort_unstable();
+        Ok(filenames)
     }
 }
+
+/// The cached listing of a `--find-links` directory.
+#[derive(Debug, Serialize, Deserialize)]
+struct DirectoryListing {
+    /// The modification time of the directory at the time of the listing.
+    modified: SystemTime,
+    /// The names of the files in the directory.
+    filenames: Vec<String>,
+}
@@ -6,7 +6,9 @@ use url::Url;
 
 use uv_pep440::VersionSpecifiers;
 use uv_pypi_types::LenientVersionSpecifiers;
-use uv_pypi_types::{BaseUrl, CoreMetadata, File, Hashes, Yanked};
+use uv_pypi_types::{
+    BaseUrl, CoreMetadata, File, Hashes, ProjectStatus, ProjectStatusMarker, Yanked,
+};
 
 /// A parsed structure from PyPI "HTML" index format for a single package.
 #[derive(Debug, Clone)]
@@ -15,6 +17,8 @@ pub(crate) struct SimpleHtml {
     pub(crate) base: BaseUrl,
     /// The list of [`File`]s available for download sorted by filename.
     pub(crate) files: Vec<File>,
+    /// The status of the project, as provided by the `pypi:project-status` meta tags.
+    pub(crate) project_status: ProjectStatus,
 }
 
 impl SimpleHtml {
@@ -56,7 +60,39 @@ impl SimpleHtml {
         // probably be the thing that does the sorting.)
         files.sort_unstable_by(|f1, f2| f1.filename.cmp(&f2.filename));
 
-        Ok(Self { base, files })
+        // Parse the `pypi:project-status` and `pypi:project-status-reason` meta tags, if any.
+        let mut project_status = ProjectStatus::default();
+        for meta in dom
+            .nodes()
+            .iter()
+            .filter_map(|node| node.as_tag())
+            .filter(|tag| tag.name().as_bytes() == b"meta")
+        {
+            let Some(Some(name)) = meta.attributes().get("name") else {
+                continue;
+            };
+            let Some(Some(content)) = meta.attributes().get("content") else {
+                continue;
+            };
+            let content = std::str::from_utf8(content.as_bytes())?;
+            let content = html_escape::decode_html_entities(content);
+            match name.as_bytes() {
+                b"pypi:project-status" => {
+                    project_status.status = ProjectStatusMarker::from_str(&content)
+                        .unwrap_or(ProjectStatusMarker::Unknown);
+                }
+                b"pypi:project-status-reason" => {
+                    project_status.reason = Some(content.into_owned());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            base,
+            files,
+            project_status,
+        })
     }
 
     /// Parse the `href` from a `<base>` tag.
@@ -54,6 +54,10 @@ fn parse_sha256() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -112,6 +116,10 @@ fn parse_md5() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -173,6 +181,10 @@ fn parse_base() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -231,6 +243,10 @@ fn parse_escaped_fragment() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -289,6 +305,10 @@ fn parse_encoded_fragment() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -345,6 +365,10 @@ fn parse_quoted_filepath() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -401,6 +425,10 @@ fn parse_missing_hash() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -491,6 +519,10 @@ fn parse_empty_fragment() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -547,6 +579,10 @@ fn parse_query_string() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -655,6 +691,10 @@ fn parse_flat_index_html() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -773,6 +813,10 @@ fn parse_code_artifact_index_html() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -841,6 +885,10 @@ fn parse_file_requires_python_trailing_comma() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
     }
     "###);
 }
@@ -990,6 +1038,38 @@ fn parse_core_metadata() {
                 yanked: None,
             },
         ],
+        project_status: ProjectStatus {
+            status: Active,
+            reason: None,
+        },
+    }
+    "###);
+}
+
+#[test]
+fn parse_project_status() {
+    let text = r#"
+<!DOCTYPE html>
+<html>
+<head>
+<meta name="pypi:repository-version" content="1.4">
+<meta name="pypi:project-status" content="deprecated">
+<meta name="pypi:project-status-reason" content="Superseded by &quot;jinja3&quot;">
+</head>
+<body>
+<h1>Links for jinja2</h1>
+<a href="/whl/Jinja2-3.1.2-py3-none-any.whl">Jinja2-3.1.2-py3-none-any.whl</a><br/>
+</body>
+</html>
+    "#;
+    let base = Url::parse("https://download.pytorch.org/whl/jinja2/").unwrap();
+    let result = SimpleHtml::parse(text, &base).unwrap();
+    insta::assert_debug_snapshot!(result.project_status, @r###"
+    ProjectStatus {
+        status: Deprecated,
+        reason: Some(
+            "Superseded by \"jinja3\"",
+        ),
     }
     "###);
 }
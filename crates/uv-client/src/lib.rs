@@ -1,7 +1,10 @@
 pub use base_client::{
     AuthIntegration, BaseClient, BaseClientBuilder, UvRetryableStrategy, DEFAULT_RETRIES,
 };
-pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
+pub use cached_client::{
+    preload_response, take_read_responses, CacheControl, CachedClient, CachedClientError,
+    DataWithCachePolicy,
+};
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexError};
 pub use linehaul::LineHaul;
@@ -19,6 +22,7 @@ mod html;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod proxy;
 mod registry_client;
 mod remote_metadata;
 mod rkyvutil;
@@ -0,0 +1,200 @@
+//! Proxy selection for outgoing requests.
+//!
+//! By default, uv defers to `reqwest`, which respects the `HTTP_PROXY`, `HTTPS_PROXY`,
+//! `ALL_PROXY`, and `NO_PROXY` environment variables. When a proxy is configured for a specific
+//! index, uv instead selects the proxy for each request itself, via [`ProxyResolver`].
+use std::env;
+use std::net::IpAddr;
+
+use url::Url;
+
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+/// Selects the proxy (if any) to use for each request.
+#[derive(Debug, Clone)]
+pub(crate) struct ProxyResolver {
+    /// The proxies configured for specific indexes, as pairs of the index URL and the proxy URL.
+    index_proxies: Vec<(Url, Url)>,
+    /// The hosts that should bypass the environment proxies.
+    no_proxy: NoProxy,
+    /// The proxy to use for `http://` requests.
+    http_proxy: Option<Url>,
+    /// The proxy to use for `https://` requests.
+    https_proxy: Option<Url>,
+}
+
+impl ProxyResolver {
+    /// Create a [`ProxyResolver`] for the given index proxies, reading the remaining
+    /// configuration from the environment.
+    ///
+    /// Returns `None` if no index proxies are configured, in which case proxy selection should be
+    /// left to `reqwest`.
+    pub(crate) fn from_env(index_proxies: Vec<(Url, Url)>) -> Option<Self> {
+        if index_proxies.is_empty() {
+            return None;
+        }
+
+        let all_proxy = proxy_from_env(EnvVars::ALL_PROXY);
+        Some(Self {
+            index_proxies,
+            no_proxy: var(EnvVars::NO_PROXY)
+                .map(|value| NoProxy::parse(&value))
+                .unwrap_or_default(),
+            http_proxy: proxy_from_env(EnvVars::HTTP_PROXY).or_else(|| all_proxy.clone()),
+            https_proxy: proxy_from_env(EnvVars::HTTPS_PROXY).or(all_proxy),
+        })
+    }
+
+    /// Return the proxy to use for a request to the given URL, or `None` to connect directly.
+    ///
+    /// A proxy configured for the index takes precedence over the environment proxies (which are
+    /// subject to `NO_PROXY`).
+    pub(crate) fn proxy_for(&self, url: &Url) -> Option<Url> {
+        if let Some((_, proxy)) = self
+            .index_proxies
+            .iter()
+            .find(|(index, _)| is_same_origin(index, url))
+        {
+            return Some(proxy.clone());
+        }
+
+        if self.no_proxy.matches(url) {
+            return None;
+        }
+
+        match url.scheme() {
+            "https" => self.https_proxy.clone(),
+            "http" => self.http_proxy.clone(),
+            _ => None,
+        }
+    }
+}
+
+/// A `NO_PROXY`-style list of hosts that should bypass the proxy.
+///
+/// Entries are comma-separated, and may be a domain (matching the domain and all of its
+/// subdomains, with or without a leading `.`), an IP address, a CIDR block (e.g., `10.0.0.0/8`),
+/// or `*` to bypass the proxy for all hosts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct NoProxy(Vec<NoProxyEntry>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NoProxyEntry {
+    /// Bypass the proxy for all hosts.
+    All,
+    /// Bypass the proxy for a domain and its subdomains.
+    Domain(String),
+    /// Bypass the proxy for an IP network.
+    Network(IpAddr, u8),
+}
+
+impl NoProxy {
+    /// Parse a comma-separated `NO_PROXY` value.
+    pub(crate) fn parse(s: &str) -> Self {
+        let entries = s
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                if entry == "*" {
+                    return NoProxyEntry::All;
+                }
+                if let Some((addr, prefix)) = entry.split_once('/') {
+                    if let (Ok(addr), Ok(prefix)) = (addr.parse::<IpAddr>(), prefix.parse::<u8>()) {
+                        return NoProxyEntry::Network(addr, prefix);
+                    }
+                }
+                let host = entry.trim_start_matches("*.").trim_start_matches('.');
+                if let Ok(addr) = host.trim_matches(['[', ']']).parse::<IpAddr>() {
+                    let prefix = if addr.is_ipv4() { 32 } else { 128 };
+                    return NoProxyEntry::Network(addr, prefix);
+                }
+                // Ignore any port, as in `localhost:8080`.
+                let host = host.split_once(':').map_or(host, |(host, _)| host);
+                NoProxyEntry::Domain(host.to_ascii_lowercase())
+            })
+            .collect();
+        Self(entries)
+    }
+
+    /// Returns `true` if requests to the given URL should bypass the proxy.
+    pub(crate) fn matches(&self, url: &Url) -> bool {
+        let Some(host) = url.host() else {
+            return false;
+        };
+        let addr = match host {
+            url::Host::Domain(_) => None,
+            url::Host::Ipv4(addr) => Some(IpAddr::V4(addr)),
+            url::Host::Ipv6(addr) => Some(IpAddr::V6(addr)),
+        };
+        let domain = url.host_str().unwrap_or_default().to_ascii_lowercase();
+
+        self.0.iter().any(|entry| match entry {
+            NoProxyEntry::All => true,
+            NoProxyEntry::Domain(suffix) => {
+                domain == *suffix
+                    || domain
+                        .strip_suffix(suffix.as_str())
+                        .is_some_and(|prefix| prefix.ends_with('.'))
+            }
+            NoProxyEntry::Network(network, prefix) => {
+                addr.is_some_and(|addr| in_network(addr, *network, *prefix))
+            }
+        })
+    }
+}
+
+/// Returns `true` if the `addr` is contained in the network with the given prefix length.
+fn in_network(addr: IpAddr, network: IpAddr, prefix: u8) -> bool {
+    match (addr, network) {
+        (IpAddr::V4(addr), IpAddr::V4(network)) => {
+            let mask = u32::MAX
+                .checked_shl(32 - u32::from(prefix.min(32)))
+                .unwrap_or(0);
+            u32::from(addr) & mask == u32::from(network) & mask
+        }
+        (IpAddr::V6(addr), IpAddr::V6(network)) => {
+            let mask = u128::MAX
+                .checked_shl(128 - u32::from(prefix.min(128)))
+                .unwrap_or(0);
+            u128::from(addr) & mask == u128::from(network) & mask
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if the two URLs share a scheme, host, and port.
+fn is_same_origin(a: &Url, b: &Url) -> bool {
+    a.scheme() == b.scheme()
+        && a.host_str() == b.host_str()
+        && a.port_or_known_default() == b.port_or_known_default()
+}
+
+/// Read an environment variable, falling back to its lowercase variant (e.g., `no_proxy`).
+fn var(name: &str) -> Option<String> {
+    env::var(name)
+        .or_else(|_| env::var(name.to_ascii_lowercase()))
+        .ok()
+        .filter(|value| !value.is_empty())
+}
+
+/// Read a proxy URL from the environment, assuming `http://` if no scheme is provided.
+fn proxy_from_env(name: &str) -> Option<Url> {
+    let value = var(name)?;
+    let url = if value.contains("://") {
+        Url::parse(&value)
+    } else {
+        Url::parse(&format!("http://{value}"))
+    };
+    match url {
+        Ok(url) => Some(url),
+        Err(err) => {
+            warn_user_once!("Ignoring invalid `{name}`: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
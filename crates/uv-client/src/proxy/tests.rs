@@ -0,0 +1,36 @@
+use url::Url;
+
+use crate::proxy::NoProxy;
+
+fn matches(no_proxy: &str, url: &str) -> bool {
+    NoProxy::parse(no_proxy).matches(&Url::parse(url).unwrap())
+}
+
+#[test]
+fn no_proxy_domains() {
+    assert!(matches("example.com", "https://example.com/simple"));
+    assert!(matches("example.com", "https://pypi.example.com/simple"));
+    assert!(matches(".example.com", "https://pypi.example.com/simple"));
+    assert!(matches("*.example.com", "https://pypi.example.com/simple"));
+    assert!(matches("localhost:8080", "http://localhost:8080"));
+    assert!(matches("EXAMPLE.com", "https://example.COM"));
+    assert!(!matches("example.com", "https://notexample.com/simple"));
+    assert!(!matches("pypi.example.com", "https://example.com/simple"));
+}
+
+#[test]
+fn no_proxy_addresses() {
+    assert!(matches("127.0.0.1", "http://127.0.0.1:8000"));
+    assert!(matches("10.0.0.0/8", "http://10.1.2.3/simple"));
+    assert!(matches("::1", "http://[::1]:8000"));
+    assert!(matches("fd00::/8", "http://[fd12:3456::1]/simple"));
+    assert!(!matches("10.0.0.0/8", "http://11.1.2.3/simple"));
+    assert!(!matches("10.0.0.0/8", "http://ten.example.com/simple"));
+}
+
+#[test]
+fn no_proxy_wildcard() {
+    assert!(matches("*", "https://pypi.org/simple"));
+    assert!(matches("example.com, *", "https://pypi.org/simple"));
+    assert!(!matches("", "https://pypi.org/simple"));
+}
@@ -12,7 +12,8 @@ use std::time::Duration;
 use tracing::{info_span, instrument, trace, warn, Instrument};
 use url::Url;
 
-use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness, WheelCache};
+use uv_cache_info::{CacheDuration, Timestamp};
 use uv_configuration::KeyringProviderType;
 use uv_configuration::{IndexStrategy, TrustedHost};
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
@@ -24,7 +25,8 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
-use uv_pypi_types::{ResolutionMetadata, SimpleJson};
+use uv_pypi_types::{ProjectStatus, ResolutionMetadata, SimpleJson};
+use uv_warnings::warn_user;
 
 use crate::base_client::{BaseClientBuilder, ExtraMiddleware};
 use crate::cached_client::CacheControl;
@@ -56,6 +58,12 @@ impl RegistryClientBuilder<'_> {
 impl<'a> RegistryClientBuilder<'a> {
     #[must_use]
     pub fn index_urls(mut self, index_urls: IndexUrls) -> Self {
+        self.base_client_builder = self.base_client_builder.index_proxies(
+            index_urls
+                .proxies()
+                .map(|(url, proxy)| (url.clone(), proxy.clone()))
+                .collect(),
+        );
         self.index_urls = index_urls;
         self
     }
@@ -213,6 +221,16 @@ impl RegistryClient {
         self.client.uncached().for_host(url)
     }
 
+    /// Return a copy of this client that fetches packages from the given index URLs, sharing the
+    /// underlying HTTP client.
+    #[must_use]
+    pub fn with_index_urls(&self, index_urls: IndexUrls) -> Self {
+        Self {
+            index_urls,
+            ..self.clone()
+        }
+    }
+
     /// Return the [`Connectivity`] mode used by this client.
     pub fn connectivity(&self) -> Connectivity {
         self.connectivity
@@ -320,11 +338,28 @@ impl RegistryClient {
             format!("{package_name}.rkyv"),
         );
         let cache_control = match self.connectivity {
-            Connectivity::Online => CacheControl::from(
-                self.cache
+            Connectivity::Online => {
+                let freshness = self
+                    .cache
                     .freshness(&cache_entry, Some(package_name))
-                    .map_err(ErrorKind::Io)?,
-            ),
+                    .map_err(ErrorKind::Io)?;
+                if self.cache.revalidates(package_name) {
+                    CacheControl::MustRevalidate
+                } else if let (Freshness::Fresh, Some(ttl)) =
+                    (freshness, self.index_urls.cache_ttl(index))
+                {
+                    // Apply the index's TTL in lieu of the `Cache-Control` headers.
+                    match Timestamp::from_path(cache_entry.path()) {
+                        Ok(timestamp) if timestamp.elapsed() < ttl.as_duration() => {
+                            CacheControl::AllowStale
+                        }
+                        Ok(_) => CacheControl::MustRevalidate,
+                        Err(_) => CacheControl::None,
+                    }
+                } else {
+                    CacheControl::from(freshness)
+                }
+            }
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
@@ -381,7 +416,12 @@ impl RegistryClient {
                         let data: SimpleJson = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
 
-                        SimpleMetadata::from_files(data.files, package_name, &url)
+                        SimpleMetadata::from_files(
+                            data.files,
+                            data.project_status,
+                            package_name,
+                            &url,
+                        )
                     }
                     MediaType::Html => {
                         let text = response
@@ -396,7 +436,11 @@ impl RegistryClient {
             .boxed_local()
             .instrument(info_span!("parse_simple_api", package = %package_name))
         };
-        self.cached_client()
+        let stale_request = simple_request
+            .try_clone()
+            .expect("HTTP request must be cloneable");
+        let result = self
+            .cached_client()
             .get_cacheable(
                 simple_request,
                 cache_entry,
@@ -407,7 +451,41 @@ impl RegistryClient {
             .map_err(|err| match err {
                 CachedClientError::Client(err) => err,
                 CachedClientError::Callback(err) => err,
-            })
+            });
+
+        // If the index is unreachable, fall back to stale metadata within the offline grace period.
+        match result {
+            Err(err) if err.is_connectivity_failure() => {
+                let Some(grace) = self.cache.offline_grace() else {
+                    return Err(err);
+                };
+                let Ok(timestamp) = Timestamp::from_path(cache_entry.path()) else {
+                    return Err(err);
+                };
+                let age = timestamp.elapsed();
+                if age > grace {
+                    return Err(err);
+                }
+                warn_user!(
+                    "Failed to reach `{}`; using cached metadata for `{package_name}` from {} ago",
+                    url.as_str().cyan(),
+                    CacheDuration::from(Duration::from_secs(age.as_secs()))
+                );
+                self.cached_client()
+                    .get_cacheable(
+                        stale_request,
+                        cache_entry,
+                        CacheControl::AllowStale,
+                        parse_simple_response,
+                    )
+                    .await
+                    .map_err(|err| match err {
+                        CachedClientError::Client(err) => err,
+                        CachedClientError::Callback(err) => err,
+                    })
+            }
+            result => result,
+        }
     }
 
     /// Fetch the [`SimpleMetadata`] from a local file, using a PEP 503-compatible directory
@@ -815,7 +893,10 @@ pub struct VersionSourceDist {
 
 #[derive(Default, Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
-pub struct SimpleMetadata(Vec<SimpleMetadatum>);
+pub struct SimpleMetadata {
+    versions: Vec<SimpleMetadatum>,
+    project_status: ProjectStatus,
+}
 
 #[derive(Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
@@ -826,10 +907,20 @@ pub struct SimpleMetadatum {
 
 impl SimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &SimpleMetadatum> {
-        self.0.iter()
+        self.versions.iter()
     }
 
-    fn from_files(files: Vec<uv_pypi_types::File>, package_name: &PackageName, base: &Url) -> Self {
+    /// Returns the status of the project, as reported by the index.
+    pub fn project_status(&self) -> &ProjectStatus {
+        &self.project_status
+    }
+
+    fn from_files(
+        files: Vec<uv_pypi_types::File>,
+        project_status: ProjectStatus,
+        package_name: &PackageName,
+        base: &Url,
+    ) -> Self {
         let mut map: BTreeMap<Version, VersionFiles> = BTreeMap::default();
 
         // Group the distributions by version and kind
@@ -863,20 +954,26 @@ impl SimpleMetadata {
                 }
             }
         }
-        Self(
-            map.into_iter()
+        Self {
+            versions: map
+                .into_iter()
                 .map(|(version, files)| SimpleMetadatum { version, files })
                 .collect(),
-        )
+            project_status,
+        }
     }
 
     /// Read the [`SimpleMetadata`] from an HTML index.
     fn from_html(text: &str, package_name: &PackageName, url: &Url) -> Result<Self, Error> {
-        let SimpleHtml { base, files } =
-            SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
+        let SimpleHtml {
+            base,
+            files,
+            project_status,
+        } = SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
 
         Ok(SimpleMetadata::from_files(
             files,
+            project_status,
             package_name,
             base.as_url(),
         ))
@@ -888,17 +985,22 @@ impl IntoIterator for SimpleMetadata {
     type IntoIter = std::vec::IntoIter<SimpleMetadatum>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.versions.into_iter()
     }
 }
 
 impl ArchivedSimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &rkyv::Archived<SimpleMetadatum>> {
-        self.0.iter()
+        self.versions.iter()
     }
 
     pub fn datum(&self, i: usize) -> Option<&rkyv::Archived<SimpleMetadatum>> {
-        self.0.get(i)
+        self.versions.get(i)
+    }
+
+    /// Returns the status of the project, as reported by the index.
+    pub fn project_status(&self) -> &rkyv::Archived<ProjectStatus> {
+        &self.project_status
     }
 }
 
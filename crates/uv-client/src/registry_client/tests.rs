@@ -46,6 +46,7 @@ fn ignore_failing_files() {
     let base = Url::parse("https://pypi.org/simple/pyflyby/").unwrap();
     let simple_metadata = SimpleMetadata::from_files(
         data.files,
+        data.project_status,
         &PackageName::from_str("pyflyby").unwrap(),
         &base,
     );
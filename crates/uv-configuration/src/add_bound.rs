@@ -0,0 +1,48 @@
+use uv_pep508::uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+
+/// The version specifier to write when adding a dependency without one (e.g., with `uv add`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AddBound {
+    /// Require at least the locked version, without an upper bound (e.g., `>=1.4.2`).
+    #[default]
+    None,
+    /// Allow newer versions with the same major version as the locked version (e.g.,
+    /// `>=1.4.2,<2`).
+    ///
+    /// For versions before `1.0`, newer versions with the same minor version are allowed instead
+    /// (e.g., `>=0.4.2,<0.5`).
+    Major,
+    /// Allow newer versions with the same major and minor version as the locked version (e.g.,
+    /// `>=1.4.2,<1.5`).
+    Minor,
+    /// Require the locked version exactly (e.g., `==1.4.2`).
+    Exact,
+}
+
+impl AddBound {
+    /// Return the version specifiers to write for a dependency locked at the given version.
+    pub fn specifiers(self, version: &Version) -> VersionSpecifiers {
+        let major = version.release().first().copied().unwrap_or(0);
+        let minor = version.release().get(1).copied().unwrap_or(0);
+        let upper = match self {
+            Self::None => None,
+            Self::Major if major == 0 => Some(Version::new([0, minor + 1])),
+            Self::Major => Some(Version::new([major + 1])),
+            Self::Minor => Some(Version::new([major, minor + 1])),
+            Self::Exact => {
+                return VersionSpecifiers::from(VersionSpecifier::equals_version(version.clone()))
+            }
+        };
+        std::iter::once(VersionSpecifier::greater_than_equal_version(
+            version.clone(),
+        ))
+        .chain(upper.map(VersionSpecifier::less_than_version))
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests;
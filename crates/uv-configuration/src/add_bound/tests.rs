@@ -0,0 +1,19 @@
+use std::str::FromStr;
+
+use super::*;
+
+#[test]
+fn add_bound_specifiers() {
+    let specifiers = |bound: AddBound, version: &str| {
+        bound
+            .specifiers(&Version::from_str(version).unwrap())
+            .to_string()
+    };
+
+    assert_eq!(specifiers(AddBound::None, "1.4.2"), ">=1.4.2");
+    assert_eq!(specifiers(AddBound::Major, "1.4.2"), ">=1.4.2, <2");
+    assert_eq!(specifiers(AddBound::Major, "0.4.2"), ">=0.4.2, <0.5");
+    assert_eq!(specifiers(AddBound::Minor, "1.4.2"), ">=1.4.2, <1.5");
+    assert_eq!(specifiers(AddBound::Minor, "2"), ">=2, <2.1");
+    assert_eq!(specifiers(AddBound::Exact, "1.4.2"), "==1.4.2");
+}
@@ -1,8 +1,8 @@
 use std::fmt::{Display, Formatter};
 
-use uv_pep508::PackageName;
+use uv_pep508::{MarkerEnvironment, MarkerTree, PackageName};
 
-use crate::{PackageNameSpecifier, PackageNameSpecifiers};
+use crate::{PackageNameSpecifier, PackageNameSpecifiers, ScopedPackageName};
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum BuildKind {
@@ -63,7 +63,9 @@ impl BuildOptions {
             NoBinary::None => false,
             NoBinary::All => match &self.no_build {
                 // Allow `all` to be overridden by specific build exclusions
-                NoBuild::Packages(packages) => !packages.contains(package_name),
+                NoBuild::Packages(packages) => !packages
+                    .iter()
+                    .any(|package| package.is_unconditional() && package.name() == package_name),
                 _ => true,
             },
             NoBinary::Packages(packages) => packages.contains(package_name),
@@ -78,7 +80,23 @@ impl BuildOptions {
                 _ => true,
             },
             NoBuild::None => false,
-            NoBuild::Packages(packages) => packages.contains(package_name),
+            NoBuild::Packages(packages) => packages
+                .iter()
+                .any(|package| package.is_unconditional() && package.name() == package_name),
+        }
+    }
+
+    /// Returns `true` if building the given package from source is disabled in any environment
+    /// that matches the given marker, accounting for environment-scoped exclusions.
+    pub fn no_build_package_within(&self, package_name: &PackageName, marker: &MarkerTree) -> bool {
+        if self.no_build_package(package_name) {
+            return true;
+        }
+        match &self.no_build {
+            NoBuild::Packages(packages) => packages.iter().any(|package| {
+                package.name() == package_name && !package.marker().is_disjoint(marker)
+            }),
+            _ => false,
         }
     }
 
@@ -113,6 +131,34 @@ impl BuildOptions {
     pub fn no_binary(&self) -> &NoBinary {
         &self.no_binary
     }
+
+    /// Evaluate any environment-scoped build exclusions (e.g., `numpy; sys_platform == 'win32'`)
+    /// against the given environment.
+    ///
+    /// Scoped exclusions are ignored until evaluated, e.g., when resolving for multiple
+    /// environments at once.
+    #[must_use]
+    pub fn for_environment(&self, markers: &MarkerEnvironment) -> Self {
+        let no_build = match &self.no_build {
+            NoBuild::Packages(packages) => {
+                let packages = packages
+                    .iter()
+                    .filter(|package| package.marker().evaluate(markers, &[]))
+                    .map(|package| ScopedPackageName::from(package.name().clone()))
+                    .collect::<Vec<_>>();
+                if packages.is_empty() {
+                    NoBuild::None
+                } else {
+                    NoBuild::Packages(packages)
+                }
+            }
+            no_build => no_build.clone(),
+        };
+        Self {
+            no_binary: self.no_binary.clone(),
+            no_build,
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -221,13 +267,14 @@ pub enum NoBuild {
     /// Do not allow building wheels from any source distribution.
     All,
 
-    /// Do not allow building wheels from the given package's source distributions.
-    Packages(Vec<PackageName>),
+    /// Do not allow building wheels from the given package's source distributions, optionally
+    /// limited to the environments that match a marker expression.
+    Packages(Vec<ScopedPackageName>),
 }
 
 impl NoBuild {
     /// Determine the build strategy to use for the given arguments.
-    pub fn from_args(no_build: Option<bool>, no_build_package: Vec<PackageName>) -> Self {
+    pub fn from_args(no_build: Option<bool>, no_build_package: Vec<ScopedPackageName>) -> Self {
         match no_build {
             Some(true) => Self::All,
             Some(false) => Self::None,
@@ -250,7 +297,9 @@ impl NoBuild {
             match combined {
                 PackageNameSpecifiers::All => Self::All,
                 PackageNameSpecifiers::None => Self::None,
-                PackageNameSpecifiers::Packages(packages) => Self::Packages(packages),
+                PackageNameSpecifiers::Packages(packages) => {
+                    Self::Packages(packages.into_iter().map(ScopedPackageName::from).collect())
+                }
             }
         }
     }
@@ -2,6 +2,8 @@ use std::str::FromStr;
 
 use anyhow::Error;
 
+use uv_pep508::MarkerEnvironmentBuilder;
+
 use super::*;
 
 #[test]
@@ -31,8 +33,8 @@ fn no_build_from_args() -> Result<(), Error> {
             false
         ),
         NoBuild::Packages(vec![
-            PackageName::from_str("foo")?,
-            PackageName::from_str("bar")?
+            ScopedPackageName::from_str("foo")?,
+            ScopedPackageName::from_str("bar")?
         ]),
     );
     assert_eq!(
@@ -54,8 +56,68 @@ fn no_build_from_args() -> Result<(), Error> {
             ],
             false
         ),
-        NoBuild::Packages(vec![PackageName::from_str("bar")?]),
+        NoBuild::Packages(vec![ScopedPackageName::from_str("bar")?]),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn no_build_for_environment() -> Result<(), Error> {
+    let build_options = BuildOptions::new(
+        NoBinary::None,
+        NoBuild::from_args(
+            None,
+            vec![
+                ScopedPackageName::from_str("foo")?,
+                ScopedPackageName::from_str("numpy; sys_platform == 'win32'")?,
+                ScopedPackageName::from_str("scipy ; sys_platform == 'linux'")?,
+            ],
+        ),
     );
 
+    let foo = PackageName::from_str("foo")?;
+    let numpy = PackageName::from_str("numpy")?;
+    let scipy = PackageName::from_str("scipy")?;
+
+    // Scoped exclusions don't apply until they're evaluated against an environment.
+    assert!(build_options.no_build_package(&foo));
+    assert!(!build_options.no_build_package(&numpy));
+    assert!(!build_options.no_build_package(&scipy));
+
+    let markers = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+        implementation_name: "cpython",
+        implementation_version: "3.12.2",
+        os_name: "posix",
+        platform_machine: "x86_64",
+        platform_python_implementation: "CPython",
+        platform_release: "6.5.0-1016-azure",
+        platform_system: "Linux",
+        platform_version: "#16~22.04.1-Ubuntu SMP Fri Feb 16 15:42:02 UTC 2024",
+        python_full_version: "3.12.2",
+        python_version: "3.12",
+        sys_platform: "linux",
+    })?;
+    let build_options = build_options.for_environment(&markers);
+    assert!(build_options.no_build_package(&foo));
+    assert!(!build_options.no_build_package(&numpy));
+    assert!(build_options.no_build_package(&scipy));
+
+    Ok(())
+}
+
+#[test]
+fn scoped_package_name() -> Result<(), Error> {
+    let scoped = ScopedPackageName::from_str("numpy;sys_platform == 'win32'")?;
+    assert_eq!(scoped.name(), &PackageName::from_str("numpy")?);
+    assert!(!scoped.is_unconditional());
+    assert_eq!(scoped.to_string(), "numpy; sys_platform == 'win32'");
+
+    let unscoped = ScopedPackageName::from_str("numpy")?;
+    assert!(unscoped.is_unconditional());
+    assert_eq!(unscoped.to_string(), "numpy");
+
+    assert!(ScopedPackageName::from_str("numpy; sys_platform ==").is_err());
+
     Ok(())
 }
@@ -0,0 +1,239 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use uv_platform_tags::{Arch, Os, Platform};
+
+/// A target for which to build a wheel, composed of a Python tag and a platform tag, as in
+/// `cp312-manylinux_2_28_x86_64` or `cp313-macosx_arm64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildTarget {
+    /// The Python tag, e.g., `cp312`.
+    python_tag: String,
+    /// The platform tag, e.g., `manylinux_2_28_x86_64`.
+    platform_tag: String,
+    /// The operating system of the target.
+    os: TargetOs,
+    /// The architecture of the target.
+    arch: Arch,
+}
+
+/// The operating system of a [`BuildTarget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TargetOs {
+    Manylinux,
+    Musllinux,
+    Macos { version: Option<(u16, u16)> },
+    Windows,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BuildTargetError {
+    #[error("Expected a target of the form `<python-tag>-<platform-tag>` (e.g., `cp312-manylinux_2_28_x86_64`), but found: `{0}`")]
+    MissingPlatformTag(String),
+    #[error(
+        "Unsupported Python tag `{0}`; expected a CPython or PyPy tag (e.g., `cp312` or `pp310`)"
+    )]
+    PythonTag(String),
+    #[error("Unsupported platform tag `{0}`; expected a `manylinux`, `musllinux`, `macosx`, or `win` tag (e.g., `manylinux_2_28_x86_64`)")]
+    PlatformTag(String),
+}
+
+impl BuildTarget {
+    /// Return the Python tag for the target, e.g., `cp312`.
+    ///
+    /// The Python tag is also a valid Python request (e.g., `uv python find cp312`).
+    pub fn python_tag(&self) -> &str {
+        &self.python_tag
+    }
+
+    /// Return the platform tag for the target, e.g., `manylinux_2_28_x86_64`.
+    pub fn platform_tag(&self) -> &str {
+        &self.platform_tag
+    }
+
+    /// Returns `true` if the target platform is the same as the given platform, such that no
+    /// cross-compilation is necessary.
+    pub fn is_native(&self, platform: &Platform) -> bool {
+        if platform.arch() != self.arch {
+            return false;
+        }
+        matches!(
+            (self.os, platform.os()),
+            (TargetOs::Manylinux, Os::Manylinux { .. })
+                | (TargetOs::Musllinux, Os::Musllinux { .. })
+                | (TargetOs::Macos { .. }, Os::Macos { .. })
+                | (TargetOs::Windows, Os::Windows)
+        )
+    }
+
+    /// Returns `true` if a wheel with the given platform tag is compatible with the target's
+    /// operating system and architecture.
+    pub fn matches_platform_tag(&self, tag: &str) -> bool {
+        if tag == "any" {
+            return true;
+        }
+        match self.os {
+            TargetOs::Windows => tag == self.platform_tag,
+            TargetOs::Manylinux => {
+                tag.starts_with("manylinux") && tag.ends_with(&format!("_{}", self.arch))
+            }
+            TargetOs::Musllinux => {
+                tag.starts_with("musllinux") && tag.ends_with(&format!("_{}", self.arch))
+            }
+            TargetOs::Macos { .. } => {
+                tag.starts_with("macosx")
+                    && (tag.ends_with(&format!("_{}", macos_arch(self.arch)))
+                        || tag.ends_with("_universal2"))
+            }
+        }
+    }
+
+    /// Return the environment variables that instruct build backends to cross-compile for the
+    /// target, e.g., `_PYTHON_HOST_PLATFORM` (respected by `setuptools`), `ARCHFLAGS`, and
+    /// `CARGO_BUILD_TARGET` (respected by `maturin` and `setuptools-rust`).
+    pub fn environment_variables(&self) -> Vec<(&'static str, String)> {
+        let mut env = Vec::new();
+        match self.os {
+            TargetOs::Manylinux | TargetOs::Musllinux => {
+                env.push(("_PYTHON_HOST_PLATFORM", format!("linux-{}", self.arch)));
+            }
+            TargetOs::Macos { version } => {
+                let (major, minor) = version.unwrap_or(match self.arch {
+                    Arch::Aarch64 => (11, 0),
+                    _ => (10, 12),
+                });
+                env.push((
+                    "_PYTHON_HOST_PLATFORM",
+                    format!("macosx-{major}.{minor}-{}", macos_arch(self.arch)),
+                ));
+                env.push(("ARCHFLAGS", format!("-arch {}", macos_arch(self.arch))));
+                env.push(("MACOSX_DEPLOYMENT_TARGET", format!("{major}.{minor}")));
+            }
+            TargetOs::Windows => {
+                let platform = match self.arch {
+                    Arch::X86 => "win32",
+                    Arch::Aarch64 => "win-arm64",
+                    _ => "win-amd64",
+                };
+                env.push(("_PYTHON_HOST_PLATFORM", platform.to_string()));
+            }
+        }
+        env.push(("CARGO_BUILD_TARGET", self.cargo_target()));
+        env
+    }
+
+    /// Return the Rust target triple for the target, e.g., `x86_64-unknown-linux-gnu`.
+    fn cargo_target(&self) -> String {
+        let arch = match self.arch {
+            Arch::Armv7L => "armv7".to_string(),
+            Arch::Powerpc64Le => "powerpc64le".to_string(),
+            arch => arch.to_string(),
+        };
+        match (self.os, self.arch) {
+            (TargetOs::Manylinux, Arch::Armv7L) => format!("{arch}-unknown-linux-gnueabihf"),
+            (TargetOs::Manylinux, _) => format!("{arch}-unknown-linux-gnu"),
+            (TargetOs::Musllinux, Arch::Armv7L) => format!("{arch}-unknown-linux-musleabihf"),
+            (TargetOs::Musllinux, _) => format!("{arch}-unknown-linux-musl"),
+            (TargetOs::Macos { .. }, _) => format!("{arch}-apple-darwin"),
+            (TargetOs::Windows, _) => format!("{arch}-pc-windows-msvc"),
+        }
+    }
+}
+
+/// Return the name of the architecture as used in macOS platform tags.
+fn macos_arch(arch: Arch) -> &'static str {
+    match arch {
+        Arch::Aarch64 => "arm64",
+        _ => "x86_64",
+    }
+}
+
+/// Parse an architecture from a platform tag.
+fn parse_arch(arch: &str) -> Option<Arch> {
+    match arch {
+        "x86_64" | "amd64" => Some(Arch::X86_64),
+        "aarch64" | "arm64" => Some(Arch::Aarch64),
+        "i686" => Some(Arch::X86),
+        "armv7l" => Some(Arch::Armv7L),
+        "ppc64le" => Some(Arch::Powerpc64Le),
+        "s390x" => Some(Arch::S390X),
+        _ => None,
+    }
+}
+
+/// Parse a `{major}_{minor}_{arch}` suffix, as in `manylinux_2_28_x86_64`.
+fn parse_versioned(rest: &str) -> Option<(u16, u16, Arch)> {
+    let (major, rest) = rest.split_once('_')?;
+    let (minor, arch) = rest.split_once('_')?;
+    Some((major.parse().ok()?, minor.parse().ok()?, parse_arch(arch)?))
+}
+
+impl FromStr for BuildTarget {
+    type Err = BuildTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((python_tag, platform_tag)) = s.split_once('-') else {
+            return Err(BuildTargetError::MissingPlatformTag(s.to_string()));
+        };
+
+        // Validate the Python tag, e.g., `cp312` or `pp310`.
+        let version = python_tag
+            .strip_prefix("cp")
+            .or_else(|| python_tag.strip_prefix("pp"));
+        if !version.is_some_and(|version| {
+            version.len() >= 2
+                && version.starts_with('3')
+                && version.chars().all(|c| c.is_ascii_digit())
+        }) {
+            return Err(BuildTargetError::PythonTag(python_tag.to_string()));
+        }
+
+        let platform_error = || BuildTargetError::PlatformTag(platform_tag.to_string());
+        let (os, arch) = if let Some(rest) = platform_tag.strip_prefix("manylinux_") {
+            let (_, _, arch) = parse_versioned(rest).ok_or_else(platform_error)?;
+            (TargetOs::Manylinux, arch)
+        } else if let Some(rest) = platform_tag.strip_prefix("musllinux_") {
+            let (_, _, arch) = parse_versioned(rest).ok_or_else(platform_error)?;
+            (TargetOs::Musllinux, arch)
+        } else if let Some(rest) = platform_tag.strip_prefix("macosx_") {
+            if let Some((major, minor, arch)) = parse_versioned(rest) {
+                (
+                    TargetOs::Macos {
+                        version: Some((major, minor)),
+                    },
+                    arch,
+                )
+            } else {
+                let arch = match rest {
+                    "arm64" => Arch::Aarch64,
+                    "x86_64" => Arch::X86_64,
+                    _ => return Err(platform_error()),
+                };
+                (TargetOs::Macos { version: None }, arch)
+            }
+        } else {
+            match platform_tag {
+                "win_amd64" => (TargetOs::Windows, Arch::X86_64),
+                "win32" => (TargetOs::Windows, Arch::X86),
+                "win_arm64" => (TargetOs::Windows, Arch::Aarch64),
+                _ => return Err(platform_error()),
+            }
+        };
+
+        Ok(Self {
+            python_tag: python_tag.to_string(),
+            platform_tag: platform_tag.to_string(),
+            os,
+            arch,
+        })
+    }
+}
+
+impl Display for BuildTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.python_tag, self.platform_tag)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,66 @@
+use super::BuildTarget;
+
+#[test]
+fn parse() {
+    let target = "cp312-manylinux_2_28_x86_64"
+        .parse::<BuildTarget>()
+        .unwrap();
+    assert_eq!(target.python_tag(), "cp312");
+    assert_eq!(target.platform_tag(), "manylinux_2_28_x86_64");
+    assert_eq!(target.to_string(), "cp312-manylinux_2_28_x86_64");
+
+    assert!("cp313-macosx_arm64".parse::<BuildTarget>().is_ok());
+    assert!("cp313-macosx_11_0_arm64".parse::<BuildTarget>().is_ok());
+    assert!("pp310-musllinux_1_2_aarch64".parse::<BuildTarget>().is_ok());
+    assert!("cp312-win_amd64".parse::<BuildTarget>().is_ok());
+
+    assert!("cp312".parse::<BuildTarget>().is_err());
+    assert!("py3-manylinux_2_28_x86_64".parse::<BuildTarget>().is_err());
+    assert!("cp312-linux_x86_64".parse::<BuildTarget>().is_err());
+    assert!("cp312-manylinux_2_28_sparc".parse::<BuildTarget>().is_err());
+}
+
+#[test]
+fn matches_platform_tag() {
+    let target = "cp312-manylinux_2_28_x86_64"
+        .parse::<BuildTarget>()
+        .unwrap();
+    assert!(target.matches_platform_tag("any"));
+    assert!(target.matches_platform_tag("manylinux_2_28_x86_64"));
+    assert!(target.matches_platform_tag("manylinux_2_17_x86_64"));
+    assert!(!target.matches_platform_tag("manylinux_2_28_aarch64"));
+    assert!(!target.matches_platform_tag("linux_x86_64"));
+
+    let target = "cp313-macosx_arm64".parse::<BuildTarget>().unwrap();
+    assert!(target.matches_platform_tag("macosx_11_0_arm64"));
+    assert!(target.matches_platform_tag("macosx_10_12_universal2"));
+    assert!(!target.matches_platform_tag("macosx_10_12_x86_64"));
+}
+
+#[test]
+fn environment_variables() {
+    let target = "cp313-macosx_arm64".parse::<BuildTarget>().unwrap();
+    assert_eq!(
+        target.environment_variables(),
+        vec![
+            ("_PYTHON_HOST_PLATFORM", "macosx-11.0-arm64".to_string()),
+            ("ARCHFLAGS", "-arch arm64".to_string()),
+            ("MACOSX_DEPLOYMENT_TARGET", "11.0".to_string()),
+            ("CARGO_BUILD_TARGET", "aarch64-apple-darwin".to_string()),
+        ]
+    );
+
+    let target = "cp312-manylinux_2_28_aarch64"
+        .parse::<BuildTarget>()
+        .unwrap();
+    assert_eq!(
+        target.environment_variables(),
+        vec![
+            ("_PYTHON_HOST_PLATFORM", "linux-aarch64".to_string()),
+            (
+                "CARGO_BUILD_TARGET",
+                "aarch64-unknown-linux-gnu".to_string()
+            ),
+        ]
+    );
+}
@@ -0,0 +1,112 @@
+use uv_normalize::PackageName;
+
+/// Whether to compile Python source files to bytecode after installation.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum CompileBytecode {
+    /// Don't compile any packages.
+    #[default]
+    None,
+    /// Compile all packages in the environment.
+    All,
+    /// Compile the given packages only.
+    Packages(Vec<PackageName>),
+}
+
+impl CompileBytecode {
+    /// Returns `true` if any packages should be compiled.
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Packages(packages) => !packages.is_empty(),
+        }
+    }
+}
+
+impl From<bool> for CompileBytecode {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::All
+        } else {
+            Self::None
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CompileBytecode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde_untagged::UntaggedEnumVisitor::new()
+            .bool(|value| Ok(CompileBytecode::from(value)))
+            .seq(|seq| seq.deserialize().map(CompileBytecode::Packages))
+            .deserialize(deserializer)
+    }
+}
+
+impl serde::Serialize for CompileBytecode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_bool(false),
+            Self::All => serializer.serialize_bool(true),
+            Self::Packages(packages) => packages.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CompileBytecode {
+    fn schema_name() -> String {
+        "CompileBytecode".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+                any_of: Some(vec![
+                    gen.subschema_for::<bool>(),
+                    gen.subschema_for::<Vec<String>>(),
+                ]),
+                ..schemars::schema::SubschemaValidation::default()
+            })),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("Whether to compile all packages to bytecode, or a list of packages to compile.".to_string()),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+/// The strategy Python uses to determine whether a bytecode file is up-to-date with its source.
+///
+/// See: <https://docs.python.org/3/library/py_compile.html#py_compile.PycInvalidationMode>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PycInvalidationMode {
+    /// Embed the source file's timestamp and size in the bytecode file.
+    Timestamp,
+    /// Embed a hash of the source file in the bytecode file, and validate it against the source
+    /// file at runtime.
+    CheckedHash,
+    /// Embed a hash of the source file in the bytecode file, but never validate it at runtime.
+    UncheckedHash,
+}
+
+impl PycInvalidationMode {
+    /// Returns the name of the mode, as expected by `py_compile.PycInvalidationMode`.
+    pub fn as_py_compile(&self) -> &'static str {
+        match self {
+            Self::Timestamp => "TIMESTAMP",
+            Self::CheckedHash => "CHECKED_HASH",
+            Self::UncheckedHash => "UNCHECKED_HASH",
+        }
+    }
+}
@@ -0,0 +1,30 @@
+/// The release channel from which to install optional components via `uv self add`.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ComponentChannel {
+    /// Install the latest stable release of the component.
+    #[default]
+    Stable,
+    /// Install the latest preview release of the component, which may change without warning.
+    Preview,
+}
+
+impl ComponentChannel {
+    /// Returns the name of the channel, as used in the component index URL.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Preview => "preview",
+        }
+    }
+}
+
+impl std::fmt::Display for ComponentChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use uv_normalize::PackageName;
+
+/// A mapping from a Python package to the equivalent distribution package, e.g., `pyyaml=python3-yaml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistroPackageEntry {
+    /// The name of the Python package. For example, given `pyyaml=python3-yaml`, this would be
+    /// `pyyaml`.
+    name: PackageName,
+    /// The name of the distribution package. For example, given `pyyaml=python3-yaml`, this would
+    /// be `python3-yaml`.
+    distro: String,
+}
+
+impl FromStr for DistroPackageEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, distro)) = s.split_once('=') else {
+            return Err(format!(
+                "Invalid distribution package mapping: {s} (expected `PACKAGE=DISTRO_PACKAGE`)"
+            ));
+        };
+        let name = PackageName::from_str(name.trim()).map_err(|err| err.to_string())?;
+        let distro = distro.trim();
+        if distro.is_empty() {
+            return Err(format!(
+                "Invalid distribution package mapping: {s} (expected `PACKAGE=DISTRO_PACKAGE`)"
+            ));
+        }
+        Ok(Self {
+            name,
+            distro: distro.to_string(),
+        })
+    }
+}
+
+/// The distribution packages to use in place of Python packages when exporting to distribution
+/// package formats, like RPM and Debian.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DistroPackages(BTreeMap<PackageName, String>);
+
+impl DistroPackages {
+    /// Create a mapping from the given entries, such that later entries take precedence.
+    pub fn from_entries(entries: Vec<DistroPackageEntry>) -> Self {
+        Self(
+            entries
+                .into_iter()
+                .map(|entry| (entry.name, entry.distro))
+                .collect(),
+        )
+    }
+
+    /// Return the distribution package for the given Python package, if one was provided.
+    pub fn get(&self, name: &PackageName) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+}
@@ -7,4 +7,19 @@ pub enum ExportFormat {
     /// Export in `requirements.txt` format.
     #[default]
     RequirementsTxt,
+    /// Export the direct requirements of the project in `requirements.in` format, as declared in
+    /// `pyproject.toml`, rather than the locked versions.
+    RequirementsIn,
+    /// Export as a `Dockerfile` that installs the locked dependencies and the project in separate
+    /// layers.
+    Dockerfile,
+    /// Export as a Nix flake, with the compatible wheels for each system pinned via `fetchurl`.
+    Nix,
+    /// Export as a requirements lock file for Bazel's `rules_python`.
+    Bazel,
+    /// Export as an RPM `.spec` file, with the locked dependencies mapped to distribution packages.
+    RpmSpec,
+    /// Export as a Debian `control` file, with the locked dependencies mapped to distribution
+    /// packages.
+    DebControl,
 }
@@ -12,6 +12,11 @@ pub struct InstallOptions {
     pub no_install_workspace: bool,
     /// Omit the specified packages from the resolution.
     pub no_install_package: Vec<PackageName>,
+    /// Only include the specified packages, along with their transitive dependencies.
+    pub only_package: Vec<PackageName>,
+    /// Omit the specified packages, along with any dependencies that are only required through
+    /// them.
+    pub except_package: Vec<PackageName>,
 }
 
 impl InstallOptions {
@@ -24,9 +29,30 @@ impl InstallOptions {
             no_install_project,
             no_install_workspace,
             no_install_package,
+            only_package: Vec::new(),
+            except_package: Vec::new(),
         }
     }
 
+    /// Restrict the resolution to a subgraph of the dependency graph: the given `only` packages
+    /// (if any) and their transitive dependencies, omitting the `except` packages and any
+    /// dependencies that are only required through them.
+    #[must_use]
+    pub fn with_subgraph(
+        mut self,
+        only_package: Vec<PackageName>,
+        except_package: Vec<PackageName>,
+    ) -> Self {
+        self.only_package = only_package;
+        self.except_package = except_package;
+        self
+    }
+
+    /// Returns `true` if the resolution is restricted to a subgraph of the dependency graph.
+    pub fn is_subgraph(&self) -> bool {
+        !self.only_package.is_empty() || !self.except_package.is_empty()
+    }
+
     /// Returns `true` if a package passes the install filters.
     pub fn include_package(
         &self,
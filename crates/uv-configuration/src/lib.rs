@@ -1,10 +1,15 @@
+pub use add_bound::*;
 pub use authentication::*;
 pub use bounds::*;
 pub use build_options::*;
+pub use build_target::*;
+pub use compile_bytecode::*;
+pub use component_channel::*;
 pub use concurrency::*;
 pub use config_settings::*;
 pub use constraints::*;
 pub use dev::*;
+pub use distro_package::*;
 pub use editable::*;
 pub use export_format::*;
 pub use extras::*;
@@ -21,13 +26,18 @@ pub use trusted_host::*;
 pub use trusted_publishing::*;
 pub use vcs::*;
 
+mod add_bound;
 mod authentication;
 mod bounds;
 mod build_options;
+mod build_target;
+mod compile_bytecode;
+mod component_channel;
 mod concurrency;
 mod config_settings;
 mod constraints;
 mod dev;
+mod distro_package;
 mod editable;
 mod export_format;
 mod extras;
@@ -1,6 +1,7 @@
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use uv_pep508::PackageName;
+use uv_pep508::{MarkerTree, PackageName, Pep508Error};
 
 /// A specifier used for (e.g.) pip's `--no-binary` flag.
 ///
@@ -127,3 +128,113 @@ impl PackageNameSpecifiers {
         }
     }
 }
+
+/// A package name, optionally scoped to the environments that match a marker expression, as in
+/// `numpy; sys_platform == 'win32'`.
+///
+/// Used for (e.g.) `--no-build-package`, to disable source builds only on the platforms on which
+/// they're known to fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopedPackageName {
+    name: PackageName,
+    marker: MarkerTree,
+}
+
+impl ScopedPackageName {
+    /// Return the name of the package.
+    pub fn name(&self) -> &PackageName {
+        &self.name
+    }
+
+    /// Return the marker expression that determines the environments to which the entry applies.
+    pub fn marker(&self) -> &MarkerTree {
+        &self.marker
+    }
+
+    /// Returns `true` if the entry applies in every environment.
+    pub fn is_unconditional(&self) -> bool {
+        self.marker.is_true()
+    }
+}
+
+impl From<PackageName> for ScopedPackageName {
+    fn from(name: PackageName) -> Self {
+        Self {
+            name,
+            marker: MarkerTree::TRUE,
+        }
+    }
+}
+
+impl FromStr for ScopedPackageName {
+    type Err = ScopedPackageNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, marker) = match s.split_once(';') {
+            Some((name, marker)) => {
+                let marker = MarkerTree::from_str(marker.trim()).map_err(|err| {
+                    ScopedPackageNameError::InvalidMarker(s.to_string(), Box::new(err))
+                })?;
+                (name, marker)
+            }
+            None => (s, MarkerTree::TRUE),
+        };
+        let name = PackageName::from_str(name.trim())?;
+        Ok(Self { name, marker })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ScopedPackageNameError {
+    #[error(transparent)]
+    InvalidName(#[from] uv_normalize::InvalidNameError),
+    #[error("Invalid marker in `{0}` (expected `PACKAGE; MARKER`)")]
+    InvalidMarker(String, #[source] Box<Pep508Error>),
+}
+
+impl Display for ScopedPackageName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.marker.contents() {
+            Some(marker) => write!(f, "{}; {marker}", self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl serde::Serialize for ScopedPackageName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ScopedPackageName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ScopedPackageName {
+    fn schema_name() -> String {
+        "ScopedPackageName".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("The name of a package, optionally followed by a marker expression that limits the environments in which it applies (e.g., `numpy; sys_platform == 'win32'`).".to_string()),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
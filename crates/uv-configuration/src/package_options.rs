@@ -1,10 +1,13 @@
+use std::collections::BTreeMap;
+
 use either::Either;
-use uv_pep508::PackageName;
+use uv_pep508::uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{MarkerTree, PackageName};
 
 use rustc_hash::FxHashMap;
 use uv_cache::Refresh;
 use uv_cache_info::Timestamp;
-use uv_pypi_types::Requirement;
+use uv_pypi_types::{Requirement, RequirementSource};
 
 /// Whether to reinstall packages.
 #[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
@@ -68,7 +71,9 @@ pub enum Upgrade {
     None,
 
     /// Allow package upgrades for all packages, ignoring the existing lockfile.
-    All,
+    ///
+    /// Upgrades are subject to the given constraints (e.g., from pinned packages).
+    All(Vec<Requirement>),
 
     /// Allow package upgrades, but only for the specified packages.
     Packages(FxHashMap<PackageName, Vec<Requirement>>),
@@ -78,7 +83,7 @@ impl Upgrade {
     /// Determine the [`Upgrade`] strategy from the command-line arguments.
     pub fn from_args(upgrade: Option<bool>, upgrade_package: Vec<Requirement>) -> Self {
         match upgrade {
-            Some(true) => Self::All,
+            Some(true) => Self::All(vec![]),
             Some(false) => Self::None,
             None => {
                 if upgrade_package.is_empty() {
@@ -114,14 +119,14 @@ impl Upgrade {
 
     /// Returns `true` if all packages should be upgraded.
     pub fn is_all(&self) -> bool {
-        matches!(self, Self::All)
+        matches!(self, Self::All(_))
     }
 
     /// Returns `true` if the specified package should be upgraded.
     pub fn contains(&self, package_name: &PackageName) -> bool {
         match &self {
             Self::None => false,
-            Self::All => true,
+            Self::All(_) => true,
             Self::Packages(packages) => packages.contains_key(package_name),
         }
     }
@@ -130,14 +135,49 @@ impl Upgrade {
     ///
     /// When upgrading, users can provide bounds on the upgrade (e.g., `--upgrade-package flask<3`).
     pub fn constraints(&self) -> impl Iterator<Item = &Requirement> {
-        if let Self::Packages(packages) = self {
-            Either::Right(
+        match self {
+            Self::None => Either::Left(std::iter::empty()),
+            Self::All(constraints) => Either::Right(Either::Left(constraints.iter())),
+            Self::Packages(packages) => Either::Right(Either::Right(
                 packages
                     .values()
                     .flat_map(|requirements| requirements.iter()),
-            )
-        } else {
-            Either::Left(std::iter::empty())
+            )),
+        }
+    }
+
+    /// Apply a set of pinned packages to the [`Upgrade`] strategy.
+    ///
+    /// Pinned packages may not be upgraded past the given version. Unlike constraints, pins only
+    /// apply to the packages that are being upgraded.
+    #[must_use]
+    pub fn with_pins(self, pins: &BTreeMap<PackageName, Version>) -> Self {
+        let pin = |name: &PackageName, version: &Version| Requirement {
+            name: name.clone(),
+            extras: vec![],
+            marker: MarkerTree::TRUE,
+            source: RequirementSource::Registry {
+                specifier: VersionSpecifiers::from(VersionSpecifier::less_than_equal_version(
+                    version.clone(),
+                )),
+                index: None,
+            },
+            origin: None,
+        };
+        match self {
+            Self::None => Self::None,
+            Self::All(mut constraints) => {
+                constraints.extend(pins.iter().map(|(name, version)| pin(name, version)));
+                Self::All(constraints)
+            }
+            Self::Packages(mut packages) => {
+                for (name, requirements) in &mut packages {
+                    if let Some(version) = pins.get(name) {
+                        requirements.push(pin(name, version));
+                    }
+                }
+                Self::Packages(packages)
+            }
         }
     }
 
@@ -147,8 +187,13 @@ impl Upgrade {
         match (self, other) {
             // If both are `None`, the result is `None`.
             (Self::None, Self::None) => Self::None,
+            // If both are `All`, the result is `All`, with the union of the constraints.
+            (Self::All(mut a), Self::All(b)) => {
+                a.extend(b);
+                Self::All(a)
+            }
             // If either is `All`, the result is `All`.
-            (Self::All, _) | (_, Self::All) => Self::All,
+            (Self::All(constraints), _) | (_, Self::All(constraints)) => Self::All(constraints),
             // If one is `None`, the result is the other.
             (Self::Packages(a), Self::None) => Self::Packages(a),
             (Self::None, Self::Packages(b)) => Self::Packages(b),
@@ -166,7 +211,7 @@ impl From<Upgrade> for Refresh {
     fn from(value: Upgrade) -> Self {
         match value {
             Upgrade::None => Self::None(Timestamp::now()),
-            Upgrade::All => Self::All(Timestamp::now()),
+            Upgrade::All(_) => Self::All(Timestamp::now()),
             Upgrade::Packages(packages) => {
                 Self::Packages(packages.into_keys().collect::<Vec<_>>(), Timestamp::now())
             }
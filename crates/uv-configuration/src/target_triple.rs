@@ -1,6 +1,8 @@
+use std::str::FromStr;
+
 use tracing::debug;
 
-use uv_pep508::MarkerEnvironment;
+use uv_pep508::{MarkerEnvironment, MarkerTree};
 use uv_platform_tags::{Arch, Os, Platform};
 use uv_static::EnvVars;
 
@@ -97,6 +99,11 @@ pub enum TargetTriple {
     #[cfg_attr(feature = "clap", value(name = "aarch64-manylinux_2_31"))]
     #[cfg_attr(feature = "schemars", schemars(rename = "aarch64-manylinux_2_31"))]
     Aarch64Manylinux231,
+
+    /// A wasm32 target for the Pyodide 2024 platform, for use with Python 3.12.
+    #[cfg_attr(feature = "clap", value(name = "wasm32-pyodide"))]
+    #[cfg_attr(feature = "schemars", schemars(rename = "wasm32-pyodide"))]
+    Wasm32Pyodide,
 }
 
 impl TargetTriple {
@@ -181,6 +188,13 @@ impl TargetTriple {
                 },
                 Arch::Aarch64,
             ),
+            Self::Wasm32Pyodide => Platform::new(
+                Os::Pyodide {
+                    major: 2024,
+                    minor: 0,
+                },
+                Arch::Wasm32,
+            ),
         }
     }
 
@@ -201,6 +215,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux217 => "aarch64",
             Self::Aarch64Manylinux228 => "aarch64",
             Self::Aarch64Manylinux231 => "aarch64",
+            Self::Wasm32Pyodide => "wasm32",
         }
     }
 
@@ -221,6 +236,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux217 => "Linux",
             Self::Aarch64Manylinux228 => "Linux",
             Self::Aarch64Manylinux231 => "Linux",
+            Self::Wasm32Pyodide => "Emscripten",
         }
     }
 
@@ -241,6 +257,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux217 => "",
             Self::Aarch64Manylinux228 => "",
             Self::Aarch64Manylinux231 => "",
+            Self::Wasm32Pyodide => "#1",
         }
     }
 
@@ -261,6 +278,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux217 => "",
             Self::Aarch64Manylinux228 => "",
             Self::Aarch64Manylinux231 => "",
+            Self::Wasm32Pyodide => "3.1.58",
         }
     }
 
@@ -281,6 +299,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux217 => "posix",
             Self::Aarch64Manylinux228 => "posix",
             Self::Aarch64Manylinux231 => "posix",
+            Self::Wasm32Pyodide => "posix",
         }
     }
 
@@ -301,6 +320,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux217 => "linux",
             Self::Aarch64Manylinux228 => "linux",
             Self::Aarch64Manylinux231 => "linux",
+            Self::Wasm32Pyodide => "emscripten",
         }
     }
 
@@ -318,6 +338,19 @@ impl TargetTriple {
             .with_platform_release(self.platform_release())
             .with_platform_version(self.platform_version())
     }
+
+    /// Return a [`MarkerTree`] that's satisfied on the given [`TargetTriple`], for any Python
+    /// version.
+    pub fn marker(self) -> MarkerTree {
+        MarkerTree::from_str(&format!(
+            "os_name == '{}' and platform_machine == '{}' and platform_system == '{}' and sys_platform == '{}'",
+            self.os_name(),
+            self.platform_machine(),
+            self.platform_system(),
+            self.sys_platform(),
+        ))
+        .expect("target triple markers should be valid")
+    }
 }
 
 /// Return the macOS deployment target as parsed from the environment.
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum TrustedPublishing {
-    /// Try trusted publishing when we're already in GitHub Actions, continue if that fails.
+    /// Try trusted publishing when running in a supported CI provider, continue if that fails.
     #[default]
     Automatic,
     // Force trusted publishing.
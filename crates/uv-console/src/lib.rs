@@ -6,28 +6,7 @@ use std::{cmp::Ordering, iter};
 /// This is a slimmed-down version of `dialoguer::Confirm`, with the post-confirmation report
 /// enabled.
 pub fn confirm(message: &str, term: &Term, default: bool) -> std::io::Result<bool> {
-    // Set the Ctrl-C handler to exit the process.
-    let result = ctrlc::set_handler(move || {
-        let term = Term::stderr();
-        term.show_cursor().ok();
-        term.flush().ok();
-
-        #[allow(clippy::exit, clippy::cast_possible_wrap)]
-        std::process::exit(if cfg!(windows) {
-            0xC000_013A_u32 as i32
-        } else {
-            130
-        });
-    });
-
-    match result {
-        Ok(()) => {}
-        Err(ctrlc::Error::MultipleHandlers) => {
-            // If multiple handlers were set, we assume that the existing handler is our
-            // confirmation handler, and continue.
-        }
-        Err(err) => return Err(std::io::Error::new(std::io::ErrorKind::Other, err)),
-    }
+    set_ctrlc_handler()?;
 
     let prompt = format!(
         "{} {} {} {} {}",
@@ -74,6 +53,68 @@ pub fn confirm(message: &str, term: &Term, default: bool) -> std::io::Result<boo
     Ok(response)
 }
 
+/// Prompt the user to select one of the given items in the given [`Term`].
+///
+/// Items are selected by their (one-based) index, so at most nine items are supported.
+///
+/// This is a slimmed-down version of `dialoguer::Select`, with the post-selection report enabled.
+pub fn select(message: &str, items: &[String], term: &Term) -> std::io::Result<usize> {
+    assert!(
+        (1..=9).contains(&items.len()),
+        "expected between one and nine items"
+    );
+
+    set_ctrlc_handler()?;
+
+    let prompt = format!(
+        "{} {} {}",
+        style("?".to_string()).for_stderr().yellow(),
+        style(message).for_stderr().bold(),
+        style(format!("[1-{}]", items.len()))
+            .for_stderr()
+            .black()
+            .bright(),
+    );
+
+    term.write_line(&prompt)?;
+    for (index, item) in items.iter().enumerate() {
+        term.write_line(&format!(
+            "  {} {item}",
+            style(format!("{}.", index + 1)).for_stderr().cyan()
+        ))?;
+    }
+    term.hide_cursor()?;
+    term.flush()?;
+
+    // Match continuously on every keystroke, and do not wait for user to hit the
+    // `Enter` key.
+    let selection = loop {
+        if let Key::Char(c) = term.read_key()? {
+            if let Some(index) = c.to_digit(10) {
+                let index = index as usize;
+                if (1..=items.len()).contains(&index) {
+                    break index - 1;
+                }
+            }
+        }
+    };
+
+    let report = format!(
+        "{} {} {} {}",
+        style("✔".to_string()).for_stderr().green(),
+        style(message).for_stderr().bold(),
+        style("·").for_stderr().black().bright(),
+        style(&items[selection]).for_stderr().cyan(),
+    );
+
+    term.clear_last_lines(items.len() + 1)?;
+    term.write_line(&report)?;
+    term.show_cursor()?;
+    term.flush()?;
+
+    Ok(selection)
+}
+
 /// Prompt the user for password in the given [`Term`].
 ///
 /// This is a slimmed-down version of `dialoguer::Password`.
@@ -260,3 +301,29 @@ pub fn input(prompt: &str, term: &Term) -> std::io::Result<String> {
 
     Ok(input)
 }
+
+/// Set the Ctrl-C handler to restore the cursor and exit the process.
+fn set_ctrlc_handler() -> std::io::Result<()> {
+    let result = ctrlc::set_handler(move || {
+        let term = Term::stderr();
+        term.show_cursor().ok();
+        term.flush().ok();
+
+        #[allow(clippy::exit, clippy::cast_possible_wrap)]
+        std::process::exit(if cfg!(windows) {
+            0xC000_013A_u32 as i32
+        } else {
+            130
+        });
+    });
+
+    match result {
+        Ok(()) => Ok(()),
+        Err(ctrlc::Error::MultipleHandlers) => {
+            // If multiple handlers were set, we assume that the existing handler is ours,
+            // and continue.
+            Ok(())
+        }
+        Err(err) => Err(std::io::Error::new(std::io::ErrorKind::Other, err)),
+    }
+}
@@ -34,6 +34,7 @@ pub(crate) async fn compile(args: CompileArgs) -> anyhow::Result<()> {
         &fs_err::canonicalize(args.root)?,
         &interpreter,
         cache.root(),
+        None,
     )
     .await?;
     info!("Compiled {files} files");
@@ -4,6 +4,7 @@
 
 use std::ffi::{OsStr, OsString};
 use std::path::Path;
+use std::sync::Mutex;
 
 use anyhow::{anyhow, Context, Result};
 use futures::FutureExt;
@@ -26,6 +27,9 @@ use uv_distribution_types::{
 };
 use uv_git::GitResolver;
 use uv_installer::{Installer, Plan, Planner, Preparer, SitePackages};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_platform_tags::TagPreferences;
 use uv_pypi_types::{ConflictingGroupList, Requirement};
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_resolver::{
@@ -57,9 +61,13 @@ pub struct BuildDispatch<'a> {
     exclude_newer: Option<ExcludeNewer>,
     source_build_context: SourceBuildContext,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
+    /// The resolved build requirements of each source distribution built via this dispatch.
+    build_dependencies:
+        Mutex<FxHashMap<(PackageName, Option<Version>), Vec<(PackageName, Version)>>>,
     bounds: LowerBound,
     sources: SourceStrategy,
     concurrency: Concurrency,
+    tag_preferences: &'a TagPreferences,
 }
 
 impl<'a> BuildDispatch<'a> {
@@ -85,6 +93,7 @@ impl<'a> BuildDispatch<'a> {
         bounds: LowerBound,
         sources: SourceStrategy,
         concurrency: Concurrency,
+        tag_preferences: &'a TagPreferences,
     ) -> Self {
         Self {
             client,
@@ -107,9 +116,11 @@ impl<'a> BuildDispatch<'a> {
             exclude_newer,
             source_build_context: SourceBuildContext::default(),
             build_extra_env_vars: FxHashMap::default(),
+            build_dependencies: Mutex::default(),
             bounds,
             sources,
             concurrency,
+            tag_preferences,
         }
     }
 
@@ -127,6 +138,20 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Return the resolved build requirements of the source distribution with the given name and
+    /// version, if it was built via this dispatch.
+    pub fn build_dependencies(
+        &self,
+        name: &PackageName,
+        version: &Version,
+    ) -> Option<Vec<(PackageName, Version)>> {
+        let build_dependencies = self.build_dependencies.lock().unwrap();
+        build_dependencies
+            .get(&(name.clone(), Some(version.clone())))
+            .or_else(|| build_dependencies.get(&(name.clone(), None)))
+            .cloned()
+    }
 }
 
 impl<'a> BuildContext for BuildDispatch<'a> {
@@ -136,6 +161,10 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         self.interpreter
     }
 
+    fn tag_preferences(&self) -> &TagPreferences {
+        self.tag_preferences
+    }
+
     fn cache(&self) -> &Cache {
         self.cache
     }
@@ -175,7 +204,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
     async fn resolve<'data>(&'data self, requirements: &'data [Requirement]) -> Result<Resolution> {
         let python_requirement = PythonRequirement::from_interpreter(self.interpreter);
         let marker_env = self.interpreter.resolver_marker_environment();
-        let tags = self.interpreter.tags()?;
+        let tags = self.interpreter.tags(self.tag_preferences)?;
 
         let resolver = Resolver::new(
             Manifest::simple(requirements.to_vec()).with_constraints(self.constraints.clone()),
@@ -189,7 +218,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             // Conflicting groups only make sense when doing
             // universal resolution.
             ConflictingGroupList::empty(),
-            Some(tags),
+            Some(&tags),
             self.flat_index,
             self.index,
             self.hasher,
@@ -231,7 +260,12 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         );
 
         // Determine the current environment markers.
-        let tags = self.interpreter.tags()?;
+        let tags = self.interpreter.tags(self.tag_preferences)?;
+
+        // Evaluate any environment-scoped build exclusions against the build environment.
+        let build_options = self
+            .build_options
+            .for_environment(venv.interpreter().markers());
 
         // Determine the set of installed packages.
         let site_packages = SitePackages::from_environment(venv)?;
@@ -244,13 +278,13 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         } = Planner::new(resolution).build(
             site_packages,
             &Reinstall::default(),
-            self.build_options,
+            &build_options,
             self.hasher,
             self.index_locations,
             self.config_settings,
             self.cache(),
             venv,
-            tags,
+            &tags,
         )?;
 
         // Nothing to do.
@@ -266,9 +300,9 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             // TODO(konstin): Check that there is no endless recursion.
             let preparer = Preparer::new(
                 self.cache,
-                tags,
+                &tags,
                 self.hasher,
-                self.build_options,
+                &build_options,
                 DistributionDatabase::new(self.client, self, self.concurrency.downloads),
             );
 
@@ -341,6 +375,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         // unless all builds are disabled.
         if self
             .build_options
+            .for_environment(self.interpreter.markers())
             .no_build_requirement(dist_name)
             // We always allow editable builds
             && !matches!(build_kind, BuildKind::Editable)
@@ -375,6 +410,17 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         )
         .boxed_local()
         .await?;
+
+        // Record the resolved build requirements, e.g., to include them in the lockfile.
+        if let Some(name) = dist_name {
+            if !builder.build_dependencies().is_empty() {
+                self.build_dependencies.lock().unwrap().insert(
+                    (name.clone(), dist_version.cloned()),
+                    builder.build_dependencies().to_vec(),
+                );
+            }
+        }
+
         Ok(builder)
     }
 }
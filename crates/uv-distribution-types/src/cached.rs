@@ -9,8 +9,8 @@ use uv_pep508::VerbatimUrl;
 use uv_pypi_types::{HashDigest, ParsedDirectoryUrl};
 
 use crate::{
-    BuiltDist, Dist, DistributionMetadata, Hashed, InstalledMetadata, InstalledVersion, Name,
-    ParsedUrl, SourceDist, VersionOrUrlRef,
+    BuiltDist, Dist, DistributionMetadata, Hashed, IndexUrl, InstalledMetadata, InstalledVersion,
+    Name, ParsedUrl, SourceDist, VersionOrUrlRef,
 };
 
 /// A built distribution (wheel) that exists in the local cache.
@@ -28,6 +28,8 @@ pub struct CachedRegistryDist {
     pub path: PathBuf,
     pub hashes: Vec<HashDigest>,
     pub cache_info: CacheInfo,
+    /// The index from which the distribution was downloaded.
+    pub index: IndexUrl,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -51,11 +53,12 @@ impl CachedDist {
         path: PathBuf,
     ) -> Self {
         match remote {
-            Dist::Built(BuiltDist::Registry(_dist)) => Self::Registry(CachedRegistryDist {
+            Dist::Built(BuiltDist::Registry(dist)) => Self::Registry(CachedRegistryDist {
                 filename,
                 path,
                 hashes,
                 cache_info,
+                index: dist.best_wheel().index.clone(),
             }),
             Dist::Built(BuiltDist::DirectUrl(dist)) => Self::Url(CachedDirectUrlDist {
                 filename,
@@ -75,11 +78,12 @@ impl CachedDist {
                 editable: false,
                 r#virtual: false,
             }),
-            Dist::Source(SourceDist::Registry(_dist)) => Self::Registry(CachedRegistryDist {
+            Dist::Source(SourceDist::Registry(dist)) => Self::Registry(CachedRegistryDist {
                 filename,
                 path,
                 hashes,
                 cache_info,
+                index: dist.index,
             }),
             Dist::Source(SourceDist::DirectUrl(dist)) => Self::Url(CachedDirectUrlDist {
                 filename,
@@ -136,6 +140,15 @@ impl CachedDist {
         }
     }
 
+    /// Return the [`IndexUrl`] from which the distribution was downloaded, if it came from a
+    /// registry.
+    pub fn index(&self) -> Option<&IndexUrl> {
+        match self {
+            Self::Registry(dist) => Some(&dist.index),
+            Self::Url(_) => None,
+        }
+    }
+
     /// Return the [`ParsedUrl`] of the distribution, if it exists.
     pub fn parsed_url(&self) -> Result<Option<ParsedUrl>> {
         match self {
@@ -4,6 +4,7 @@ use thiserror::Error;
 use url::Url;
 
 use uv_auth::Credentials;
+use uv_cache_info::CacheDuration;
 
 use crate::index_name::{IndexName, IndexNameError};
 use crate::origin::Origin;
@@ -57,6 +58,36 @@ pub struct Index {
     /// is given the highest priority when resolving packages.
     #[serde(default)]
     pub default: bool,
+    /// The proxy to use for requests to the index.
+    ///
+    /// Supports `http://`, `https://`, `socks5://`, and `socks5h://` proxies. The proxy is used
+    /// for all requests to the index's host, and takes precedence over the `HTTP_PROXY`,
+    /// `HTTPS_PROXY`, `ALL_PROXY`, and `NO_PROXY` environment variables.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// proxy = "socks5://proxy.example.com:1080"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Url>,
+    /// The duration for which cached responses from the index's Simple API are considered fresh.
+    ///
+    /// By default, uv respects the `Cache-Control` headers returned by the index. When set, uv
+    /// instead reuses cached responses without revalidation until they reach the given age, and
+    /// revalidates them afterwards, regardless of the headers. Accepts, e.g., `30s`, `10m`, `24h`,
+    /// or `7d`.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// cache-ttl = "10m"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub cache_ttl: Option<CacheDuration>,
     /// The origin of the index (e.g., a CLI flag, a user-level configuration file, etc.).
     #[serde(skip)]
     pub origin: Option<Origin>,
@@ -89,6 +120,8 @@ impl Index {
             name: None,
             explicit: false,
             default: true,
+            proxy: None,
+            cache_ttl: None,
             origin: None,
         }
     }
@@ -100,6 +133,8 @@ impl Index {
             name: None,
             explicit: false,
             default: false,
+            proxy: None,
+            cache_ttl: None,
             origin: None,
         }
     }
@@ -111,6 +146,8 @@ impl Index {
             name: None,
             explicit: false,
             default: false,
+            proxy: None,
+            cache_ttl: None,
             origin: None,
         }
     }
@@ -165,6 +202,9 @@ impl FromStr for Index {
                     url,
                     explicit: false,
                     default: false,
+                    proxy: None,
+                    cache_ttl: None,
+                    cache_ttl: None,
                     origin: None,
                 });
             }
@@ -177,6 +217,8 @@ impl FromStr for Index {
             url,
             explicit: false,
             default: false,
+            proxy: None,
+            cache_ttl: None,
             origin: None,
         })
     }
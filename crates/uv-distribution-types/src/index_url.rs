@@ -9,6 +9,7 @@ use std::sync::{Arc, LazyLock, RwLock};
 use thiserror::Error;
 use url::{ParseError, Url};
 
+use uv_cache_info::CacheDuration;
 use uv_pep508::{VerbatimUrl, VerbatimUrlError};
 
 use crate::{Index, Verbatim};
@@ -384,6 +385,24 @@ impl<'a> IndexUrls {
             .chain(self.default_index())
             .filter(|index| !index.explicit)
     }
+
+    /// Return an iterator over the proxies configured for each [`Index`], as pairs of the index
+    /// URL and the proxy URL.
+    ///
+    /// Unlike [`IndexUrls::indexes`], explicit indexes are included.
+    pub fn proxies(&'a self) -> impl Iterator<Item = (&'a Url, &'a Url)> + 'a {
+        self.indexes
+            .iter()
+            .filter_map(|index| Some((index.raw_url(), index.proxy.as_ref()?)))
+    }
+
+    /// Return the cache TTL configured for the [`Index`] with the given URL, if any.
+    pub fn cache_ttl(&'a self, url: &IndexUrl) -> Option<CacheDuration> {
+        self.indexes
+            .iter()
+            .find(|index| index.raw_url() == url.url())
+            .and_then(|index| index.cache_ttl)
+    }
 }
 
 bitflags::bitflags! {
@@ -284,6 +284,25 @@ impl InstalledDist {
         Ok(Some(cache_info))
     }
 
+    /// Read the `uv_index.json` file from a `.dist-info` directory, returning the URL of the
+    /// index from which the distribution was installed.
+    pub fn index_url(path: &Path) -> Result<Option<String>> {
+        /// The contents of a `uv_index.json` file.
+        #[derive(serde::Deserialize)]
+        struct InstalledIndex {
+            url: String,
+        }
+
+        let path = path.join("uv_index.json");
+        let file = match fs_err::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let index = serde_json::from_reader::<fs_err::File, InstalledIndex>(file)?;
+        Ok(Some(index.url))
+    }
+
     /// Read the `METADATA` file from a `.dist-info` directory.
     pub fn metadata(&self) -> Result<uv_pypi_types::ResolutionMetadata> {
         match self {
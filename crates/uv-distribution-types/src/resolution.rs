@@ -213,6 +213,7 @@ impl From<&ResolvedDist> for Requirement {
                 Dist::Source(SourceDist::Git(sdist)) => RequirementSource::Git {
                     url: sdist.url.clone(),
                     repository: sdist.git.repository().clone(),
+                    backend: sdist.git.backend(),
                     reference: sdist.git.reference().clone(),
                     precise: sdist.git.precise(),
                     subdirectory: sdist.subdirectory.clone(),
@@ -14,6 +14,7 @@ use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tracing::{debug, info_span, instrument, warn, Instrument};
 use url::Url;
 
+use uv_cache::metrics::{self, CacheEvent};
 use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
@@ -28,6 +29,7 @@ use uv_fs::write_atomic;
 use uv_platform_tags::Tags;
 use uv_pypi_types::HashDigest;
 use uv_types::BuildContext;
+use uv_warnings::warn_user;
 
 use crate::archive::Archive;
 use crate::locks::Locks;
@@ -172,6 +174,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 }
 
                 // Download and unzip.
+                metrics::record(CacheEvent::Wheel);
                 match self
                     .stream_wheel(
                         url.clone(),
@@ -235,6 +238,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 );
 
                 // Download and unzip.
+                metrics::record(CacheEvent::Wheel);
                 match self
                     .stream_wheel(
                         wheel.url.raw().clone(),
@@ -312,6 +316,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<LocalWheel, Error> {
+        metrics::record(CacheEvent::SourceDist);
+
         let lock = self.locks.acquire(&Dist::Source(dist.clone())).await;
         let _guard = lock.lock().await;
 
@@ -339,7 +345,11 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         // Otherwise, unzip the wheel.
         let id = self
-            .unzip_wheel(&built_wheel.path, &built_wheel.target)
+            .unzip_wheel(
+                &built_wheel.path,
+                &built_wheel.filename,
+                &built_wheel.target,
+            )
             .await?;
 
         Ok(LocalWheel {
@@ -452,6 +462,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             }
         }
 
+        metrics::record(CacheEvent::SourceDist);
+
         let lock = self.locks.acquire(source).await;
         let _guard = lock.lock().await;
 
@@ -484,6 +496,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let download = |response: reqwest::Response| {
             async {
+                metrics::record(CacheEvent::WheelDownloaded);
                 let size = size.or_else(|| content_length(&response));
 
                 let progress = self
@@ -520,6 +533,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     hasher.finish().await.map_err(Error::HashExhaustion)?;
                 }
 
+                check_record(temp_dir.path(), filename).await;
+
                 // Persist the temporary directory to the directory store.
                 let id = self
                     .build_context
@@ -606,6 +621,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let download = |response: reqwest::Response| {
             async {
+                metrics::record(CacheEvent::WheelDownloaded);
                 let size = size.or_else(|| content_length(&response));
 
                 let progress = self
@@ -677,6 +693,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     hashers.into_iter().map(HashDigest::from).collect()
                 };
 
+                check_record(temp_dir.path(), filename).await;
+
                 // Persist the temporary directory to the directory store.
                 let id = self
                     .build_context
@@ -778,7 +796,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             })
         } else if hashes.is_none() {
             // Otherwise, unzip the wheel.
-            let archive = Archive::new(self.unzip_wheel(path, wheel_entry.path()).await?, vec![]);
+            let archive = Archive::new(
+                self.unzip_wheel(path, filename, wheel_entry.path()).await?,
+                vec![],
+            );
 
             // Write the archive pointer to the cache.
             let pointer = LocalArchivePointer {
@@ -815,6 +836,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
             let hashes = hashers.into_iter().map(HashDigest::from).collect();
 
+            check_record(temp_dir.path(), filename).await;
+
             // Persist the temporary directory to the directory store.
             let id = self
                 .build_context
@@ -844,7 +867,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     }
 
     /// Unzip a wheel into the cache, returning the path to the unzipped directory.
-    async fn unzip_wheel(&self, path: &Path, target: &Path) -> Result<ArchiveId, Error> {
+    async fn unzip_wheel(
+        &self,
+        path: &Path,
+        filename: &WheelFilename,
+        target: &Path,
+    ) -> Result<ArchiveId, Error> {
         let temp_dir = tokio::task::spawn_blocking({
             let path = path.to_owned();
             let root = self.build_context.cache().root().to_path_buf();
@@ -857,6 +885,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         })
         .await??;
 
+        check_record(temp_dir.path(), filename).await;
+
         // Persist the temporary directory to the directory store.
         let id = self
             .build_context
@@ -927,6 +957,33 @@ fn content_length(response: &reqwest::Response) -> Option<u64> {
         .and_then(|val| val.parse::<u64>().ok())
 }
 
+/// Warn if the files in a freshly unzipped wheel don't match the hashes in its `RECORD`.
+///
+/// Installers (like pip) don't enforce the hashes in the `RECORD`, and some wheels ship with
+/// inaccurate entries, so mismatches are reported, but don't fail the installation. The check runs
+/// once, as the wheel is unzipped into the cache, rather than each time it's installed.
+async fn check_record(wheel: &Path, filename: &WheelFilename) {
+    let mismatches = tokio::task::spawn_blocking({
+        let wheel = wheel.to_path_buf();
+        move || uv_install_wheel::linker::record_mismatches(wheel)
+    })
+    .await;
+
+    match mismatches {
+        Ok(Ok(mismatches)) => {
+            if let Some(first) = mismatches.first() {
+                warn_user!(
+                    "The RECORD file of `{filename}` doesn't match the contents of the wheel ({} {} differ, including `{first}`)",
+                    mismatches.len(),
+                    if mismatches.len() == 1 { "file" } else { "files" },
+                );
+            }
+        }
+        Ok(Err(err)) => debug!("Failed to verify the RECORD file of `{filename}`: {err}"),
+        Err(err) => debug!("Failed to verify the RECORD file of `{filename}`: {err}"),
+    }
+}
+
 /// An asynchronous reader that reports progress as bytes are read.
 struct ProgressReader<'a, R> {
     reader: R,
@@ -5,7 +5,7 @@ use crate::{HttpArchivePointer, LocalArchivePointer};
 use uv_cache::{Cache, CacheBucket, CacheEntry};
 use uv_cache_info::CacheInfo;
 use uv_distribution_filename::WheelFilename;
-use uv_distribution_types::{CachedDirectUrlDist, CachedRegistryDist, Hashed};
+use uv_distribution_types::{CachedDirectUrlDist, CachedRegistryDist, Hashed, IndexUrl};
 use uv_pep508::VerbatimUrl;
 use uv_pypi_types::HashDigest;
 
@@ -43,13 +43,14 @@ impl CachedWheel {
         })
     }
 
-    /// Convert a [`CachedWheel`] into a [`CachedRegistryDist`].
-    pub fn into_registry_dist(self) -> CachedRegistryDist {
+    /// Convert a [`CachedWheel`] into a [`CachedRegistryDist`], downloaded from the given index.
+    pub fn into_registry_dist(self, index: &IndexUrl) -> CachedRegistryDist {
         CachedRegistryDist {
             filename: self.filename,
             path: self.entry.into_path_buf(),
             hashes: self.hashes,
             cache_info: self.cache_info,
+            index: index.clone(),
         }
     }
 
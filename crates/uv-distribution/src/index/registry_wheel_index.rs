@@ -3,6 +3,7 @@ use std::collections::hash_map::Entry;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_cache_info::CacheInfo;
 use uv_distribution_types::{CachedRegistryDist, Hashed, Index, IndexLocations, IndexUrl};
 use uv_fs::{directories, files, symlinks};
 use uv_normalize::PackageName;
@@ -116,7 +117,7 @@ impl<'a> RegistryWheelIndex<'a> {
                                         ),
                                     ) {
                                         entries.push(IndexEntry {
-                                            dist: wheel.into_registry_dist(),
+                                            dist: wheel.into_registry_dist(index.url()),
                                             index,
                                             built: false,
                                         });
@@ -126,7 +127,7 @@ impl<'a> RegistryWheelIndex<'a> {
                         }
                     }
                     // Add files from local registries (e.g., `--find-links`).
-                    IndexUrl::Path(_) => {
+                    IndexUrl::Path(url) => {
                         if file
                             .extension()
                             .is_some_and(|ext| ext.eq_ignore_ascii_case("rev"))
@@ -134,6 +135,18 @@ impl<'a> RegistryWheelIndex<'a> {
                             if let Some(wheel) =
                                 CachedWheel::from_local_pointer(wheel_dir.join(file), cache)
                             {
+                                // Skip wheels that were rebuilt (or removed) since they were
+                                // cached.
+                                let is_fresh = url.to_file_path().is_ok_and(|directory| {
+                                    CacheInfo::from_path(
+                                        &directory.join(wheel.filename.to_string()),
+                                    )
+                                    .is_ok_and(|cache_info| cache_info == wheel.cache_info)
+                                });
+                                if !is_fresh {
+                                    continue;
+                                }
+
                                 if wheel.filename.compatibility(tags).is_compatible() {
                                     // Enforce hash-checking based on the built distribution.
                                     if wheel.satisfies(
@@ -143,7 +156,7 @@ impl<'a> RegistryWheelIndex<'a> {
                                         ),
                                     ) {
                                         entries.push(IndexEntry {
-                                            dist: wheel.into_registry_dist(),
+                                            dist: wheel.into_registry_dist(index.url()),
                                             index,
                                             built: false,
                                         });
@@ -199,7 +212,7 @@ impl<'a> RegistryWheelIndex<'a> {
                                         .get_package(&wheel.filename.name, &wheel.filename.version),
                                 ) {
                                     entries.push(IndexEntry {
-                                        dist: wheel.into_registry_dist(),
+                                        dist: wheel.into_registry_dist(index.url()),
                                         index,
                                         built: true,
                                     });
@@ -9,14 +9,14 @@ use url::Url;
 use uv_configuration::LowerBound;
 use uv_distribution_filename::DistExtension;
 use uv_distribution_types::{Index, IndexLocations, IndexName, Origin};
-use uv_git::GitReference;
+use uv_git::{GitReference, VcsBackend};
 use uv_normalize::PackageName;
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::{MarkerTree, VerbatimUrl, VersionOrUrl};
 use uv_pypi_types::{ParsedUrlError, Requirement, RequirementSource, VerbatimParsedUrl};
 use uv_warnings::warn_user_once;
-use uv_workspace::pyproject::{PyProjectToml, Source, Sources};
-use uv_workspace::Workspace;
+use uv_workspace::pyproject::{PyProjectToml, Source, Sources, ToolUvSources};
+use uv_workspace::{Workspace, WorkspaceMember};
 
 use crate::metadata::GitWorkspaceMember;
 
@@ -34,10 +34,10 @@ enum RequirementOrigin {
 impl LoweredRequirement {
     /// Combine `project.dependencies` or `project.optional-dependencies` with `tool.uv.sources`.
     pub(crate) fn from_requirement<'data>(
-        requirement: uv_pep508::Requirement<VerbatimParsedUrl>,
+        mut requirement: uv_pep508::Requirement<VerbatimParsedUrl>,
         project_name: &'data PackageName,
         project_dir: &'data Path,
-        project_sources: &'data BTreeMap<PackageName, Sources>,
+        project_sources: &'data ToolUvSources,
         project_indexes: &'data [Index],
         locations: &'data IndexLocations,
         workspace: &'data Workspace,
@@ -49,16 +49,81 @@ impl LoweredRequirement {
         } else if let Some(source) = workspace.sources().get(&requirement.name) {
             (Some(source), RequirementOrigin::Workspace)
         } else {
-            (None, RequirementOrigin::Project)
+            match pattern_source(
+                &requirement,
+                project_name,
+                project_sources,
+                project_indexes,
+                workspace,
+            ) {
+                Ok(Some((source, origin))) => (Some(source), origin),
+                Ok(None) => (None, RequirementOrigin::Project),
+                Err(err) => return Either::Left(std::iter::once(Err(err))),
+            }
         };
-        let source = source.cloned();
+        let mut source = source.cloned();
+
+        // A workspace source (`workspace = true`) for a package that isn't a workspace member
+        // inherits the version specifiers and source declared in `tool.uv.workspace.dependencies`.
+        let mut origin = origin;
+        if !workspace.packages().contains_key(&requirement.name)
+            && source.as_ref().is_some_and(|sources| {
+                sources.iter().all(|source| {
+                    matches!(
+                        source,
+                        Source::Workspace {
+                            workspace: true,
+                            ..
+                        }
+                    )
+                })
+            })
+        {
+            let Some(dependency) = workspace.shared_dependencies().get(&requirement.name) else {
+                return Either::Left(std::iter::once(Err(LoweringError::MissingWorkspaceMember(
+                    requirement.name.clone(),
+                ))));
+            };
+            if requirement.version_or_url.as_ref().is_some_and(
+                |version_or_url| match version_or_url {
+                    VersionOrUrl::VersionSpecifier(specifiers) => !specifiers.is_empty(),
+                    VersionOrUrl::Url(_) => true,
+                },
+            ) {
+                return Either::Left(std::iter::once(Err(
+                    LoweringError::WorkspaceDependencyVersion(requirement.name.clone()),
+                )));
+            }
+            requirement.version_or_url = dependency
+                .version
+                .clone()
+                .map(VersionOrUrl::VersionSpecifier);
+            source = dependency.source.clone().map(Sources::from);
+            origin = RequirementOrigin::Workspace;
+        }
 
         let workspace_package_declared =
             // We require that when you use a package that's part of the workspace, ...
             !workspace.packages().contains_key(&requirement.name)
                 // ... it must be declared as a workspace dependency (`workspace = true`), ...
                 || source.as_ref().filter(|sources| !sources.is_empty()).is_some_and(|source| source.iter().all(|source| {
-                    matches!(source, Source::Workspace { workspace: true, .. })
+                    match source {
+                        Source::Workspace { workspace: true, .. } => true,
+                        // ... or via a `path` source that points to the member, ...
+                        Source::Path { path, .. } => workspace
+                            .packages()
+                            .get(&requirement.name)
+                            .is_some_and(|member| {
+                                is_member_path(
+                                    path.as_ref(),
+                                    origin,
+                                    project_dir,
+                                    workspace,
+                                    member,
+                                )
+                            }),
+                        _ => false,
+                    }
                 }))
                 // ... except for recursive self-inclusion (extras that activate other extras), e.g.
                 // `framework[machine_learning]` depends on `framework[cuda]`.
@@ -70,6 +135,28 @@ impl LoweredRequirement {
         }
 
         let Some(source) = source else {
+            // If the workspace selects a hardware variant for the package, and an index shares the
+            // variant's name, pin the package to that index.
+            if !matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
+                if let Some(index) =
+                    workspace
+                        .variants()
+                        .get(&requirement.name)
+                        .and_then(|variant| {
+                            variant_index(variant, locations, project_indexes, workspace)
+                        })
+                {
+                    let requirement =
+                        registry_source(&requirement, index, lower_bound).map(|source| {
+                            Self(Requirement {
+                                source,
+                                ..Requirement::from(requirement)
+                            })
+                        });
+                    return Either::Left(std::iter::once(requirement));
+                }
+            }
+
             let has_sources = !project_sources.is_empty() || !workspace.sources().is_empty();
             if matches!(lower_bound, LowerBound::Warn) {
                 // Support recursive editable inclusions.
@@ -130,9 +217,52 @@ impl LoweredRequirement {
                             )?;
                             (source, marker)
                         }
+                        Source::Hg {
+                            hg,
+                            subdirectory,
+                            rev,
+                            tag,
+                            branch,
+                            marker,
+                        } => {
+                            if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
+                                return Err(LoweringError::ConflictingUrls);
+                            }
+                            let source = vcs_source(
+                                VcsBackend::Mercurial,
+                                &hg,
+                                subdirectory.map(PathBuf::from),
+                                rev,
+                                tag,
+                                branch,
+                            )?;
+                            (source, marker)
+                        }
+                        Source::Svn {
+                            svn,
+                            subdirectory,
+                            rev,
+                            marker,
+                        } => {
+                            if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
+                                return Err(LoweringError::ConflictingUrls);
+                            }
+                            let source = vcs_source(
+                                VcsBackend::Subversion,
+                                &svn,
+                                subdirectory.map(PathBuf::from),
+                                rev,
+                                None,
+                                None,
+                            )?;
+                            (source, marker)
+                        }
                         Source::Url {
                             url,
                             subdirectory,
+                            // The pinned hash isn't carried on the requirement; instead, the
+                            // lock's hash policy validates the archive at the lowered URL.
+                            hash: _,
                             marker,
                         } => {
                             if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
@@ -149,13 +279,32 @@ impl LoweredRequirement {
                             if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
                                 return Err(LoweringError::ConflictingUrls);
                             }
-                            let source = path_source(
-                                PathBuf::from(path),
-                                origin,
-                                project_dir,
-                                workspace.install_path(),
-                                editable.unwrap_or(false),
-                            )?;
+                            // A path to a workspace member (e.g., a member of a nested workspace
+                            // that's included in this workspace) is treated as the member itself.
+                            let member =
+                                workspace
+                                    .packages()
+                                    .get(&requirement.name)
+                                    .filter(|member| {
+                                        is_member_path(
+                                            path.as_ref(),
+                                            origin,
+                                            project_dir,
+                                            workspace,
+                                            member,
+                                        )
+                                    });
+                            let source = if let Some(member) = member {
+                                workspace_member_source(member, git_member)?
+                            } else {
+                                path_source(
+                                    PathBuf::from(path),
+                                    origin,
+                                    project_dir,
+                                    workspace.install_path(),
+                                    editable.unwrap_or(false),
+                                )?
+                            };
                             (source, marker)
                         }
                         Source::Registry { index, marker } => {
@@ -190,69 +339,11 @@ impl LoweredRequirement {
                             if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
                                 return Err(LoweringError::ConflictingUrls);
                             }
-                            let member = workspace
-                                .packages()
-                                .get(&requirement.name)
-                                .ok_or(LoweringError::UndeclaredWorkspacePackage)?
-                                .clone();
-
-                            // Say we have:
-                            // ```
-                            // root
-                            // ├── main_workspace  <- We want to the path from here ...
-                            // │   ├── pyproject.toml
-                            // │   └── uv.lock
-                            // └──current_workspace
-                            //    └── packages
-                            //        └── current_package  <- ... to here.
-                            //            └── pyproject.toml
-                            // ```
-                            // The path we need in the lockfile: `../current_workspace/packages/current_project`
-                            // member root: `/root/current_workspace/packages/current_project`
-                            // workspace install root: `/root/current_workspace`
-                            // relative to workspace: `packages/current_project`
-                            // workspace lock root: `../current_workspace`
-                            // relative to main workspace: `../current_workspace/packages/current_project`
-                            let url = VerbatimUrl::from_absolute_path(member.root())?;
-                            let install_path = url.to_file_path().map_err(|()| {
-                                LoweringError::RelativeTo(io::Error::new(
-                                    io::ErrorKind::Other,
-                                    "Invalid path in file URL",
-                                ))
-                            })?;
-
-                            let source = if let Some(git_member) = &git_member {
-                                // If the workspace comes from a git dependency, all workspace
-                                // members need to be git deps, too.
-                                let subdirectory =
-                                    uv_fs::relative_to(member.root(), git_member.fetch_root)
-                                        .expect("Workspace member must be relative");
-                                RequirementSource::Git {
-                                    repository: git_member.git_source.git.repository().clone(),
-                                    reference: git_member.git_source.git.reference().clone(),
-                                    precise: git_member.git_source.git.precise(),
-                                    subdirectory: if subdirectory == PathBuf::new() {
-                                        None
-                                    } else {
-                                        Some(subdirectory)
-                                    },
-                                    url,
-                                }
-                            } else if member.pyproject_toml().is_package() {
-                                RequirementSource::Directory {
-                                    install_path,
-                                    url,
-                                    editable: true,
-                                    r#virtual: false,
-                                }
-                            } else {
-                                RequirementSource::Directory {
-                                    install_path,
-                                    url,
-                                    editable: false,
-                                    r#virtual: true,
-                                }
-                            };
+                            let member =
+                                workspace.packages().get(&requirement.name).ok_or_else(|| {
+                                    LoweringError::MissingWorkspaceMember(requirement.name.clone())
+                                })?;
+                            let source = workspace_member_source(member, git_member)?;
                             (source, marker)
                         }
                     };
@@ -335,9 +426,52 @@ impl LoweredRequirement {
                             )?;
                             (source, marker)
                         }
+                        Source::Hg {
+                            hg,
+                            subdirectory,
+                            rev,
+                            tag,
+                            branch,
+                            marker,
+                        } => {
+                            if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
+                                return Err(LoweringError::ConflictingUrls);
+                            }
+                            let source = vcs_source(
+                                VcsBackend::Mercurial,
+                                &hg,
+                                subdirectory.map(PathBuf::from),
+                                rev,
+                                tag,
+                                branch,
+                            )?;
+                            (source, marker)
+                        }
+                        Source::Svn {
+                            svn,
+                            subdirectory,
+                            rev,
+                            marker,
+                        } => {
+                            if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
+                                return Err(LoweringError::ConflictingUrls);
+                            }
+                            let source = vcs_source(
+                                VcsBackend::Subversion,
+                                &svn,
+                                subdirectory.map(PathBuf::from),
+                                rev,
+                                None,
+                                None,
+                            )?;
+                            (source, marker)
+                        }
                         Source::Url {
                             url,
                             subdirectory,
+                            // The pinned hash isn't carried on the requirement; instead, the
+                            // lock's hash policy validates the archive at the lowered URL.
+                            hash: _,
                             marker,
                         } => {
                             if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
@@ -417,10 +551,16 @@ impl LoweredRequirement {
 pub enum LoweringError {
     #[error("Package is not included as workspace package in `tool.uv.workspace`")]
     UndeclaredWorkspacePackage,
+    #[error("`{0}` is declared as a workspace source (`workspace = true`), but is neither a member of the workspace nor declared in `tool.uv.workspace.dependencies`; to depend on a member of another workspace, use a `path` source instead")]
+    MissingWorkspaceMember(PackageName),
+    #[error("`{0}` inherits its specification from `tool.uv.workspace.dependencies` (`workspace = true`), and so can't declare its own version specifiers or URL")]
+    WorkspaceDependencyVersion(PackageName),
     #[error("Can only specify one of: `rev`, `tag`, or `branch`")]
     MoreThanOneGitRef,
     #[error("Package `{0}` references an undeclared index: `{1}`")]
     MissingIndex(PackageName, IndexName),
+    #[error("Index `{1}` is used by the `tool.uv.sources` pattern `{0}`, and so must be marked as `explicit = true`")]
+    ImplicitPatternIndex(String, IndexName),
     #[error("Workspace members are not allowed in non-workspace contexts")]
     WorkspaceMember,
     #[error(transparent)]
@@ -450,6 +590,21 @@ fn git_source(
     rev: Option<String>,
     tag: Option<String>,
     branch: Option<String>,
+) -> Result<RequirementSource, LoweringError> {
+    vcs_source(VcsBackend::Git, git, subdirectory, rev, tag, branch)
+}
+
+/// Convert a Git, Mercurial, or Subversion source into a [`RequirementSource`].
+///
+/// Mercurial and Subversion sources share the Git pipeline, and are distinguished by the
+/// [`VcsBackend`] recorded on the [`RequirementSource`].
+fn vcs_source(
+    backend: VcsBackend,
+    repository: &Url,
+    subdirectory: Option<PathBuf>,
+    rev: Option<String>,
+    tag: Option<String>,
+    branch: Option<String>,
 ) -> Result<RequirementSource, LoweringError> {
     let reference = match (rev, tag, branch) {
         (None, None, None) => GitReference::DefaultBranch,
@@ -460,7 +615,10 @@ fn git_source(
     };
 
     // Create a PEP 508-compatible URL.
-    let mut url = Url::parse(&format!("git+{git}"))?;
+    let mut url = Url::parse(&format!(
+        "{}{repository}",
+        backend.missing_prefix(repository)
+    ))?;
     if let Some(rev) = reference.as_str() {
         url.set_path(&format!("{}@{}", url.path(), rev));
     }
@@ -472,11 +630,12 @@ fn git_source(
     }
     let url = VerbatimUrl::from_url(url);
 
-    let repository = git.clone();
+    let repository = repository.clone();
 
     Ok(RequirementSource::Git {
         url,
         repository,
+        backend,
         reference,
         precise: None,
         subdirectory,
@@ -508,6 +667,76 @@ fn url_source(url: Url, subdirectory: Option<PathBuf>) -> Result<RequirementSour
     })
 }
 
+/// Return the sources for a requirement that matches a pattern in `tool.uv.sources`, if any.
+///
+/// Patterns only apply to registry requirements on packages outside the workspace. Like package
+/// names, patterns are matched from the project, then the workspace. Transitive dependencies that
+/// match a pattern are pinned to its index during resolution.
+///
+/// Since a pattern routes a namespace to an index, packages outside the namespace are forbidden
+/// from that index, so the index must be marked as `explicit = true`.
+fn pattern_source<'data>(
+    requirement: &uv_pep508::Requirement<VerbatimParsedUrl>,
+    project_name: &PackageName,
+    project_sources: &'data ToolUvSources,
+    project_indexes: &[Index],
+    workspace: &'data Workspace,
+) -> Result<Option<(&'data Sources, RequirementOrigin)>, LoweringError> {
+    if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_)))
+        || workspace.packages().contains_key(&requirement.name)
+        || &requirement.name == project_name
+    {
+        return Ok(None);
+    }
+
+    let (pattern, sources, origin) = if let Some((pattern, sources)) =
+        project_sources.get_pattern(&requirement.name)
+    {
+        (pattern, sources, RequirementOrigin::Project)
+    } else if let Some((pattern, sources)) = workspace.sources().get_pattern(&requirement.name) {
+        (pattern, sources, RequirementOrigin::Workspace)
+    } else {
+        return Ok(None);
+    };
+
+    for source in sources.iter() {
+        let Source::Registry { index, .. } = source else {
+            continue;
+        };
+        if project_indexes
+            .iter()
+            .chain(workspace.indexes().iter())
+            .any(|candidate| candidate.name.as_ref() == Some(index) && !candidate.explicit)
+        {
+            return Err(LoweringError::ImplicitPatternIndex(
+                pattern.as_str().to_string(),
+                index.clone(),
+            ));
+        }
+    }
+
+    Ok(Some((sources, origin)))
+}
+
+/// Return the URL of the index that shares its name with the given hardware variant, if any.
+///
+/// Like named indexes in `tool.uv.sources`, indexes are matched from the command line, then the
+/// project, then the workspace.
+fn variant_index(
+    variant: &str,
+    locations: &IndexLocations,
+    project_indexes: &[Index],
+    workspace: &Workspace,
+) -> Option<Url> {
+    locations
+        .indexes()
+        .filter(|index| matches!(index.origin, Some(Origin::Cli)))
+        .chain(project_indexes.iter())
+        .chain(workspace.indexes().iter())
+        .find(|Index { name, .. }| name.as_deref() == Some(variant))
+        .map(|Index { url, .. }| url.clone().into_url())
+}
+
 /// Convert a registry source into a [`RequirementSource`].
 fn registry_source(
     requirement: &uv_pep508::Requirement<VerbatimParsedUrl>,
@@ -602,3 +831,83 @@ fn path_source(
         })
     }
 }
+
+/// Convert a workspace member into a [`RequirementSource`].
+fn workspace_member_source(
+    member: &WorkspaceMember,
+    git_member: Option<&GitWorkspaceMember<'_>>,
+) -> Result<RequirementSource, LoweringError> {
+    // Say we have:
+    // ```
+    // root
+    // ├── main_workspace  <- We want to the path from here ...
+    // │   ├── pyproject.toml
+    // │   └── uv.lock
+    // └──current_workspace
+    //    └── packages
+    //        └── current_package  <- ... to here.
+    //            └── pyproject.toml
+    // ```
+    // The path we need in the lockfile: `../current_workspace/packages/current_project`
+    // member root: `/root/current_workspace/packages/current_project`
+    // workspace install root: `/root/current_workspace`
+    // relative to workspace: `packages/current_project`
+    // workspace lock root: `../current_workspace`
+    // relative to main workspace: `../current_workspace/packages/current_project`
+    let url = VerbatimUrl::from_absolute_path(member.root())?;
+    let install_path = url.to_file_path().map_err(|()| {
+        LoweringError::RelativeTo(io::Error::new(
+            io::ErrorKind::Other,
+            "Invalid path in file URL",
+        ))
+    })?;
+
+    let source = if let Some(git_member) = git_member {
+        // If the workspace comes from a git dependency, all workspace
+        // members need to be git deps, too.
+        let subdirectory = uv_fs::relative_to(member.root(), git_member.fetch_root)
+            .expect("Workspace member must be relative");
+        RequirementSource::Git {
+            repository: git_member.git_source.git.repository().clone(),
+            backend: git_member.git_source.git.backend(),
+            reference: git_member.git_source.git.reference().clone(),
+            precise: git_member.git_source.git.precise(),
+            subdirectory: if subdirectory == PathBuf::new() {
+                None
+            } else {
+                Some(subdirectory)
+            },
+            url,
+        }
+    } else if member.pyproject_toml().is_package() {
+        RequirementSource::Directory {
+            install_path,
+            url,
+            editable: true,
+            r#virtual: false,
+        }
+    } else {
+        RequirementSource::Directory {
+            install_path,
+            url,
+            editable: false,
+            r#virtual: true,
+        }
+    };
+    Ok(source)
+}
+
+/// Returns `true` if the `path` source refers to the root of the given workspace member.
+fn is_member_path(
+    path: &Path,
+    origin: RequirementOrigin,
+    project_dir: &Path,
+    workspace: &Workspace,
+    member: &WorkspaceMember,
+) -> bool {
+    let base = match origin {
+        RequirementOrigin::Project => project_dir,
+        RequirementOrigin::Workspace => workspace.install_path(),
+    };
+    uv_fs::normalize_path(&base.join(path)) == uv_fs::normalize_path(member.root())
+}
@@ -97,7 +97,7 @@ impl RequiresDist {
         };
 
         // Collect any `tool.uv.sources` and `tool.uv.dev_dependencies` from `pyproject.toml`.
-        let empty = BTreeMap::default();
+        let empty = ToolUvSources::default();
         let project_sources = match source_strategy {
             SourceStrategy::Enabled => project_workspace
                 .current_project()
@@ -106,7 +106,6 @@ impl RequiresDist {
                 .as_ref()
                 .and_then(|tool| tool.uv.as_ref())
                 .and_then(|uv| uv.sources.as_ref())
-                .map(ToolUvSources::inner)
                 .unwrap_or(&empty),
             SourceStrategy::Disabled => &empty,
         };
@@ -130,57 +129,63 @@ impl RequiresDist {
                 .flatten()
                 .collect::<BTreeMap<_, _>>();
 
-            // Resolve any `include-group` entries in `dependency-groups`.
-            let dependency_groups =
-                FlatDependencyGroups::from_dependency_groups(&dependency_groups)?
-                    .into_iter()
-                    .chain(
-                        // Only add the `dev` group if `dev-dependencies` is defined.
-                        dev_dependencies
+            // Resolve any `include-group` entries in `dependency-groups`, including those that
+            // refer to other workspace members.
+            let dependency_groups = FlatDependencyGroups::from_workspace(
+                &dependency_groups,
+                project_workspace.project_root(),
+                project_workspace.workspace(),
+            )?;
+
+            let dependency_groups = dependency_groups
+                .into_iter()
+                .chain(
+                    // Only add the `dev` group if `dev-dependencies` is defined.
+                    dev_dependencies
+                        .into_iter()
+                        .map(|requirements| (DEV_DEPENDENCIES.clone(), requirements.clone())),
+                )
+                .map(|(name, requirements)| {
+                    let requirements = match source_strategy {
+                        SourceStrategy::Enabled => requirements
                             .into_iter()
-                            .map(|requirements| (DEV_DEPENDENCIES.clone(), requirements.clone())),
-                    )
-                    .map(|(name, requirements)| {
-                        let requirements = match source_strategy {
-                            SourceStrategy::Enabled => requirements
-                                .into_iter()
-                                .flat_map(|requirement| {
-                                    let group_name = name.clone();
-                                    let requirement_name = requirement.name.clone();
-                                    LoweredRequirement::from_requirement(
-                                        requirement,
-                                        &metadata.name,
-                                        project_workspace.project_root(),
-                                        project_sources,
-                                        project_indexes,
-                                        locations,
-                                        project_workspace.workspace(),
-                                        lower_bound,
-                                        git_member,
-                                    )
-                                    .map(move |requirement| {
-                                        match requirement {
-                                            Ok(requirement) => Ok(requirement.into_inner()),
-                                            Err(err) => Err(MetadataError::GroupLoweringError(
-                                                group_name.clone(),
-                                                requirement_name.clone(),
-                                                Box::new(err),
-                                            )),
-                                        }
-                                    })
+                            .flat_map(|requirement| {
+                                let group_name = name.clone();
+                                let requirement_name = requirement.name.clone();
+                                LoweredRequirement::from_requirement(
+                                    requirement,
+                                    &metadata.name,
+                                    project_workspace.project_root(),
+                                    project_sources,
+                                    project_indexes,
+                                    locations,
+                                    project_workspace.workspace(),
+                                    lower_bound,
+                                    git_member,
+                                )
+                                .map(move |requirement| {
+                                    match requirement {
+                                        Ok(requirement) => Ok(requirement.into_inner()),
+                                        Err(err) => Err(MetadataError::GroupLoweringError(
+                                            group_name.clone(),
+                                            requirement_name.clone(),
+                                            Box::new(err),
+                                        )),
+                                    }
                                 })
-                                .collect::<Result<Vec<_>, _>>(),
-                            SourceStrategy::Disabled => Ok(requirements
-                                .into_iter()
-                                .map(uv_pypi_types::Requirement::from)
-                                .collect()),
-                        }?;
-                        Ok::<(GroupName, Vec<uv_pypi_types::Requirement>), MetadataError>((
-                            name,
-                            requirements,
-                        ))
-                    })
-                    .collect::<Result<Vec<_>, _>>()?;
+                            })
+                            .collect::<Result<Vec<_>, _>>(),
+                        SourceStrategy::Disabled => Ok(requirements
+                            .into_iter()
+                            .map(uv_pypi_types::Requirement::from)
+                            .collect()),
+                    }?;
+                    Ok::<(GroupName, Vec<uv_pypi_types::Requirement>), MetadataError>((
+                        name,
+                        requirements,
+                    ))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
 
             // Merge any overlapping groups.
             let mut map = BTreeMap::new();
@@ -257,6 +262,7 @@ mod test {
     use insta::assert_snapshot;
     use uv_configuration::{LowerBound, SourceStrategy};
     use uv_distribution_types::IndexLocations;
+    use uv_pypi_types::RequirementSource;
     use uv_workspace::pyproject::PyProjectToml;
     use uv_workspace::{DiscoveryOptions, ProjectWorkspace};
 
@@ -382,7 +388,51 @@ mod test {
           |
         8 | tqdm = { git = "https://github.com/tqdm/tqdm", ref = "baaaaaab" }
           |                                                ^^^
-        unknown field `ref`, expected one of `git`, `subdirectory`, `rev`, `tag`, `branch`, `url`, `path`, `editable`, `index`, `workspace`, `marker`
+        unknown field `ref`, expected one of `git`, `hg`, `svn`, `subdirectory`, `rev`, `tag`, `branch`, `url`, `path`, `editable`, `index`, `workspace`, `marker`
+        "###);
+    }
+
+    #[tokio::test]
+    async fn too_many_vcs_specs() {
+        let input = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.0.0"
+            dependencies = [
+              "tqdm",
+            ]
+            [tool.uv.sources]
+            tqdm = { git = "https://github.com/tqdm/tqdm", hg = "https://hg.example.com/tqdm" }
+        "#};
+
+        assert_snapshot!(format_err(input).await, @r###"
+        error: TOML parse error at line 8, column 8
+          |
+        8 | tqdm = { git = "https://github.com/tqdm/tqdm", hg = "https://hg.example.com/tqdm" }
+          |        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+        expected at most one of `git`, `hg`, or `svn`
+        "###);
+    }
+
+    #[tokio::test]
+    async fn svn_tag() {
+        let input = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.0.0"
+            dependencies = [
+              "tqdm",
+            ]
+            [tool.uv.sources]
+            tqdm = { svn = "https://svn.example.com/tqdm", tag = "v1.0.0" }
+        "#};
+
+        assert_snapshot!(format_err(input).await, @r###"
+        error: TOML parse error at line 8, column 8
+          |
+        8 | tqdm = { svn = "https://svn.example.com/tqdm", tag = "v1.0.0" }
+          |        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+        cannot specify both `svn` and `tag`; include the tag path in the repository URL instead
         "###);
     }
 
@@ -500,7 +550,7 @@ mod test {
 
         assert_snapshot!(format_err(input).await, @r###"
         error: Failed to parse entry: `tqdm`
-          Caused by: Package is not included as workspace package in `tool.uv.workspace`
+          Caused by: `tqdm` is declared as a workspace source (`workspace = true`), but is neither a member of the workspace nor declared in `tool.uv.workspace.dependencies`; to depend on a member of another workspace, use a `path` source instead
         "###);
     }
 
@@ -522,6 +572,81 @@ mod test {
         "###);
     }
 
+    #[tokio::test]
+    async fn pattern_index() {
+        let input = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.0.0"
+            dependencies = [
+              "MyCorp_Utils>=1.0",
+              "mycorp-core-extra>=1.0",
+              "tqdm>=4",
+            ]
+            [tool.uv.sources]
+            "mycorp-*" = { index = "internal" }
+            "mycorp-core-*" = { index = "core" }
+
+            [[tool.uv.index]]
+            name = "internal"
+            url = "https://internal.example.com/simple"
+            explicit = true
+
+            [[tool.uv.index]]
+            name = "core"
+            url = "https://core.example.com/simple"
+            explicit = true
+        "#};
+
+        let requires_dist = requires_dist_from_pyproject_toml(input).await.unwrap();
+        let indexes = requires_dist
+            .requires_dist
+            .iter()
+            .map(|requirement| match &requirement.source {
+                RequirementSource::Registry { index, .. } => (
+                    requirement.name.to_string(),
+                    index
+                        .as_ref()
+                        .and_then(|index| index.host_str())
+                        .map(ToString::to_string),
+                ),
+                _ => panic!("Expected a registry requirement"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            indexes,
+            vec![
+                (
+                    "mycorp-utils".to_string(),
+                    Some("internal.example.com".to_string())
+                ),
+                (
+                    "mycorp-core-extra".to_string(),
+                    Some("core.example.com".to_string())
+                ),
+                ("tqdm".to_string(), None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn pattern_non_index() {
+        let input = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.0.0"
+            dependencies = [
+              "mycorp-utils",
+            ]
+            [tool.uv.sources]
+            "mycorp-*" = { path = "../mycorp" }
+        "#};
+
+        assert!(format_err(input)
+            .await
+            .contains("sources for pattern `mycorp-*` must be `index` sources"));
+    }
+
     #[tokio::test]
     async fn missing_project_section() {
         let input = indoc! {"
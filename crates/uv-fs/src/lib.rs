@@ -329,49 +329,153 @@ pub fn is_temporary(path: impl AsRef<Path>) -> bool {
         .map_or(false, |name| name.starts_with(".tmp"))
 }
 
+/// The mode in which to acquire a [`LockedFile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+    /// A shared lock, which may be held by any number of processes at once, but excludes any
+    /// exclusive lock.
+    Shared,
+    /// An exclusive lock, which may be held by a single process at a time.
+    Exclusive,
+}
+
+impl Display for LockMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shared => write!(f, "shared"),
+            Self::Exclusive => write!(f, "exclusive"),
+        }
+    }
+}
+
 /// A file lock that is automatically released when dropped.
+///
+/// If the underlying filesystem doesn't support advisory locks (e.g., an NFS mount without a lock
+/// manager), the lock is a no-op, and callers must rely on atomic writes for consistency.
 #[derive(Debug)]
-pub struct LockedFile(fs_err::File);
+pub struct LockedFile(Option<(fs_err::File, LockMode)>);
 
 impl LockedFile {
     /// Inner implementation for [`LockedFile::acquire_blocking`] and [`LockedFile::acquire`].
-    fn lock_file_blocking(file: fs_err::File, resource: &str) -> Result<Self, std::io::Error> {
+    fn lock_file_blocking(
+        file: fs_err::File,
+        resource: &str,
+        mode: LockMode,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<Self, std::io::Error> {
         trace!(
-            "Checking lock for `{resource}` at `{}`",
+            "Checking {mode} lock for `{resource}` at `{}`",
             file.path().user_display()
         );
-        match file.file().try_lock_exclusive() {
+        let err = match try_lock(&file, mode) {
             Ok(()) => {
-                debug!("Acquired lock for `{resource}`");
-                Ok(Self(file))
+                debug!("Acquired {mode} lock for `{resource}`");
+                return Ok(Self::locked(file, mode));
             }
-            Err(err) => {
-                // Log error code and enum kind to help debugging more exotic failures.
-                if err.kind() != std::io::ErrorKind::WouldBlock {
-                    debug!("Try lock error: {err:?}");
+            Err(err) => err,
+        };
+
+        // If the filesystem doesn't support locking, proceed without a lock.
+        if is_lock_unsupported(&err) {
+            warn!(
+                "File locking is not supported at `{}`; proceeding without a lock for `{resource}`: {err}",
+                file.path().user_display()
+            );
+            return Ok(Self(None));
+        }
+
+        // Log error code and enum kind to help debugging more exotic failures.
+        if !is_lock_contended(&err) {
+            debug!("Try lock error: {err:?}");
+        }
+        info!(
+            "Waiting to acquire {mode} lock for `{resource}` at `{}`{}",
+            file.path().user_display(),
+            holder(&file)
+        );
+
+        let Some(timeout) = timeout else {
+            match mode {
+                LockMode::Shared => file.file().lock_shared(),
+                LockMode::Exclusive => file.file().lock_exclusive(),
+            }
+            .map_err(|err| lock_error(&file, resource, &err))?;
+
+            debug!("Acquired {mode} lock for `{resource}`");
+            return Ok(Self::locked(file, mode));
+        };
+
+        // Poll for the lock, backing off between attempts, until the timeout elapses.
+        let start = std::time::Instant::now();
+        let mut delay = std::time::Duration::from_millis(10);
+        let mut report = std::time::Duration::from_secs(10);
+        loop {
+            std::thread::sleep(delay.min(timeout.saturating_sub(start.elapsed())));
+            match try_lock(&file, mode) {
+                Ok(()) => {
+                    debug!(
+                        "Acquired {mode} lock for `{resource}` after {:.1}s",
+                        start.elapsed().as_secs_f32()
+                    );
+                    return Ok(Self::locked(file, mode));
                 }
+                Err(err) if is_lock_contended(&err) => {}
+                Err(err) => return Err(lock_error(&file, resource, &err)),
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!(
+                        "Timed out after {}s waiting to acquire lock for `{resource}` at `{}`{}",
+                        timeout.as_secs(),
+                        file.path().user_display(),
+                        holder(&file)
+                    ),
+                ));
+            }
+            if elapsed >= report {
                 info!(
-                    "Waiting to acquire lock for `{resource}` at `{}`",
-                    file.path().user_display(),
+                    "Still waiting to acquire {mode} lock for `{resource}` after {}s{}",
+                    elapsed.as_secs(),
+                    holder(&file)
                 );
-                file.file().lock_exclusive().map_err(|err| {
-                    // Not an fs_err method, we need to build our own path context
-                    std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        format!(
-                            "Could not acquire lock for `{resource}` at `{}`: {}",
-                            file.path().user_display(),
-                            err
-                        ),
-                    )
-                })?;
-
-                debug!("Acquired lock for `{resource}`");
-                Ok(Self(file))
+                report += std::time::Duration::from_secs(10);
             }
+            delay = (delay * 2).min(std::time::Duration::from_millis(500));
         }
     }
 
+    /// Wrap a file on which a lock was acquired.
+    ///
+    /// For exclusive locks, the ID of the current process is written to the file, to help diagnose
+    /// contention in other processes.
+    fn locked(file: fs_err::File, mode: LockMode) -> Self {
+        if mode == LockMode::Exclusive {
+            use std::io::Write;
+
+            let mut handle = file.file();
+            if let Err(err) = handle
+                .set_len(0)
+                .and_then(|()| write!(handle, "{}", std::process::id()))
+            {
+                trace!("Failed to record lock holder: {err}");
+            }
+        }
+        Self(Some((file, mode)))
+    }
+
+    /// Open (without truncating) the file to be used for locking.
+    fn open(path: &Path) -> Result<fs_err::File, std::io::Error> {
+        fs_err::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+    }
+
     /// The same as [`LockedFile::acquire`], but for synchronous contexts. Do not use from an async
     /// context, as this can block the runtime while waiting for another process to release the
     /// lock.
@@ -379,9 +483,9 @@ impl LockedFile {
         path: impl AsRef<Path>,
         resource: impl Display,
     ) -> Result<Self, std::io::Error> {
-        let file = fs_err::File::create(path.as_ref())?;
+        let file = Self::open(path.as_ref())?;
         let resource = resource.to_string();
-        Self::lock_file_blocking(file, &resource)
+        Self::lock_file_blocking(file, &resource, LockMode::Exclusive, None)
     }
 
     /// Acquire a cross-process lock for a resource using a file at the provided path.
@@ -390,26 +494,103 @@ impl LockedFile {
         path: impl AsRef<Path>,
         resource: impl Display,
     ) -> Result<Self, std::io::Error> {
-        let file = fs_err::File::create(path.as_ref())?;
+        Self::acquire_with(path, resource, LockMode::Exclusive, None).await
+    }
+
+    /// Acquire a cross-process lock for a resource using a file at the provided path, in the given
+    /// [`LockMode`].
+    ///
+    /// If a timeout is provided, returns an error of kind [`std::io::ErrorKind::TimedOut`] if the
+    /// lock can't be acquired within the timeout.
+    #[cfg(feature = "tokio")]
+    pub async fn acquire_with(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+        mode: LockMode,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<Self, std::io::Error> {
+        let file = Self::open(path.as_ref())?;
         let resource = resource.to_string();
-        tokio::task::spawn_blocking(move || Self::lock_file_blocking(file, &resource)).await?
+        tokio::task::spawn_blocking(move || {
+            Self::lock_file_blocking(file, &resource, mode, timeout)
+        })
+        .await?
     }
 }
 
 impl Drop for LockedFile {
     fn drop(&mut self) {
-        if let Err(err) = self.0.file().unlock() {
+        let Some((file, mode)) = &self.0 else {
+            return;
+        };
+
+        // Clear the recorded holder before releasing the lock.
+        if *mode == LockMode::Exclusive {
+            if let Err(err) = file.file().set_len(0) {
+                trace!("Failed to clear lock holder: {err}");
+            }
+        }
+
+        if let Err(err) = file.file().unlock() {
             error!(
                 "Failed to unlock {}; program may be stuck: {}",
-                self.0.path().display(),
+                file.path().display(),
                 err
             );
         } else {
-            debug!("Released lock at `{}`", self.0.path().display());
+            debug!("Released lock at `{}`", file.path().display());
         }
     }
 }
 
+/// Attempt to acquire a lock on the file in the given mode, without blocking.
+fn try_lock(file: &fs_err::File, mode: LockMode) -> Result<(), std::io::Error> {
+    match mode {
+        LockMode::Shared => file.file().try_lock_shared(),
+        LockMode::Exclusive => file.file().try_lock_exclusive(),
+    }
+}
+
+/// Returns `true` if the error indicates that the lock is held by another process.
+fn is_lock_contended(err: &std::io::Error) -> bool {
+    err.kind() == std::io::ErrorKind::WouldBlock
+        || err.raw_os_error() == fs2::lock_contended_error().raw_os_error()
+}
+
+/// Returns `true` if the error indicates that the filesystem doesn't support locking.
+fn is_lock_unsupported(err: &std::io::Error) -> bool {
+    if err.kind() == std::io::ErrorKind::Unsupported {
+        return true;
+    }
+    #[cfg(unix)]
+    if err.raw_os_error() == Some(rustix::io::Errno::NOLCK.raw_os_error()) {
+        return true;
+    }
+    false
+}
+
+/// Describe the process holding an exclusive lock on the file, if known.
+fn holder(file: &fs_err::File) -> String {
+    std::fs::read_to_string(file.path())
+        .ok()
+        .and_then(|contents| contents.trim().parse::<u32>().ok())
+        .map(|pid| format!(" (held by process {pid})"))
+        .unwrap_or_default()
+}
+
+/// Build an error for a failure to acquire a lock.
+fn lock_error(file: &fs_err::File, resource: &str, err: &std::io::Error) -> std::io::Error {
+    // Not an fs_err method, we need to build our own path context
+    std::io::Error::new(
+        std::io::ErrorKind::Other,
+        format!(
+            "Could not acquire lock for `{resource}` at `{}`: {}",
+            file.path().user_display(),
+            err
+        ),
+    )
+}
+
 /// An asynchronous reader that reports progress as bytes are read.
 #[cfg(feature = "tokio")]
 pub struct ProgressReader<Reader: tokio::io::AsyncRead + Unpin, Callback: Fn(usize) + Unpin> {
@@ -349,6 +349,12 @@ impl std::fmt::Display for PortablePathBuf {
     }
 }
 
+impl AsRef<Path> for PortablePathBuf {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
 impl From<PortablePathBuf> for PathBuf {
     fn from(portable: PortablePathBuf) -> Self {
         portable.0
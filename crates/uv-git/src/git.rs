@@ -20,7 +20,7 @@ use uv_static::EnvVars;
 
 /// A file indicates that if present, `git reset` has been done and a repo
 /// checkout is ready to go. See [`GitCheckout::reset`] for why we need this.
-const CHECKOUT_READY_LOCK: &str = ".ok";
+pub(crate) const CHECKOUT_READY_LOCK: &str = ".ok";
 
 /// A global cache of the result of `which git`.
 pub static GIT: LazyLock<Result<PathBuf, which::Error>> = LazyLock::new(|| which::which("git"));
@@ -0,0 +1,146 @@
+//! Support for Mercurial repositories, via the `hg` command-line tool.
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use anyhow::{Context, Result};
+use cargo_util::{paths, ProcessBuilder};
+use tracing::debug;
+use url::Url;
+
+use uv_fs::Simplified;
+
+use crate::git::CHECKOUT_READY_LOCK;
+use crate::{GitReference, GitSha};
+
+/// A global cache of the result of `which hg`.
+pub static HG: LazyLock<Result<PathBuf, which::Error>> = LazyLock::new(|| which::which("hg"));
+
+/// A local Mercurial repository, used as the database for checkouts.
+pub(crate) struct HgRepository {
+    /// Path to the underlying Mercurial repository on the local filesystem.
+    path: PathBuf,
+}
+
+impl HgRepository {
+    /// Opens an existing Mercurial repository at `path`.
+    pub(crate) fn open(path: &Path) -> Result<Self> {
+        hg()?.arg("root").cwd(path).exec_with_output()?;
+        Ok(Self {
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Clones the `remote` into `path`, or pulls the latest changes if a repository already
+    /// exists at that location.
+    pub(crate) fn fetch(remote: &Url, path: &Path) -> Result<Self> {
+        if let Ok(repo) = Self::open(path) {
+            debug!("Pulling Mercurial repository from: {remote}");
+            hg()?
+                .arg("pull")
+                .arg("--")
+                .arg(remote.as_str())
+                .cwd(&repo.path)
+                .exec_with_output()
+                .with_context(|| format!("failed to pull from `{remote}`"))?;
+            return Ok(repo);
+        }
+
+        debug!("Cloning Mercurial repository from: {remote}");
+        let _ = paths::remove_dir_all(path);
+        if let Some(parent) = path.parent() {
+            paths::create_dir_all(parent)?;
+        }
+        hg()?
+            .arg("clone")
+            .arg("--noupdate")
+            .arg("--")
+            .arg(remote.as_str())
+            .arg(path)
+            .exec_with_output()
+            .with_context(|| format!("failed to clone `{remote}`"))?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Checks if `rev` resolves to a changeset in this repository.
+    pub(crate) fn contains(&self, rev: GitSha) -> bool {
+        self.log(&rev.to_string()).is_ok()
+    }
+
+    /// Resolves a [`GitReference`] to the full changeset ID it points to.
+    ///
+    /// Mercurial resolves branches, tags, bookmarks, and (short) changeset IDs through the same
+    /// revision syntax, so the reference kind is only used to select the default branch.
+    pub(crate) fn resolve(&self, reference: &GitReference) -> Result<GitSha> {
+        let rev = match reference {
+            GitReference::DefaultBranch => "default",
+            reference => reference.as_rev(),
+        };
+        self.log(rev).with_context(|| {
+            format!(
+                "failed to find {} `{}` in Mercurial repository",
+                reference.kind_str(),
+                rev
+            )
+        })
+    }
+
+    /// Exports the given changeset to `destination`, unless a complete export already exists.
+    pub(crate) fn copy_to(&self, rev: GitSha, destination: &Path) -> Result<()> {
+        let ok_file = destination.join(CHECKOUT_READY_LOCK);
+        if ok_file.exists() {
+            return Ok(());
+        }
+
+        debug!("Exporting {rev} to: {}", destination.user_display());
+        let _ = paths::remove_dir_all(destination);
+        if let Some(parent) = destination.parent() {
+            paths::create_dir_all(parent)?;
+        }
+        hg()?
+            .arg("archive")
+            .arg("--rev")
+            .arg(rev.to_string())
+            .arg("--type")
+            .arg("files")
+            .arg("--no-decode")
+            .arg(destination)
+            .cwd(&self.path)
+            .exec_with_output()?;
+
+        paths::create(ok_file)?;
+        Ok(())
+    }
+
+    /// Returns the full changeset ID for a single revision.
+    fn log(&self, rev: &str) -> Result<GitSha> {
+        let output = hg()?
+            .arg("log")
+            .arg("--rev")
+            .arg(rev)
+            .arg("--limit")
+            .arg("1")
+            .arg("--template")
+            .arg("{node}")
+            .cwd(&self.path)
+            .exec_with_output()?;
+
+        let node = String::from_utf8(output.stdout)?;
+        let node = node.trim();
+        if node.is_empty() {
+            anyhow::bail!("revision `{rev}` does not exist");
+        }
+        Ok(GitSha::from_str(node)?)
+    }
+}
+
+/// Returns a [`ProcessBuilder`] for `hg`, with user configuration that could affect the output
+/// disabled.
+fn hg() -> Result<ProcessBuilder> {
+    let mut cmd = ProcessBuilder::new(HG.as_ref()?);
+    cmd.env("HGPLAIN", "1");
+    Ok(cmd)
+}
@@ -2,28 +2,39 @@ use url::Url;
 
 pub use crate::credentials::{store_credentials_from_url, GIT_STORE};
 pub use crate::git::{GitReference, GIT};
+pub use crate::hg::HG;
 pub use crate::resolver::{
     GitResolver, GitResolverError, RepositoryReference, ResolvedRepositoryReference,
 };
 pub use crate::sha::{GitOid, GitSha, OidParseError};
 pub use crate::source::{Fetch, GitSource, Reporter};
+pub use crate::svn::SVN;
+pub use crate::vcs::VcsBackend;
 
 mod credentials;
 mod git;
+mod hg;
 mod resolver;
 mod sha;
 mod source;
+mod svn;
+mod vcs;
 
 /// A URL reference to a Git repository.
+///
+/// Mercurial and Subversion repositories are represented in the same way, with the version
+/// control system recorded as the [`VcsBackend`].
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash, Ord)]
 pub struct GitUrl {
     /// The URL of the Git repository, with any query parameters, fragments, and leading `git+`
-    /// removed.
+    /// (or `hg+`, or `svn+`) removed.
     repository: Url,
     /// The reference to the commit to use, which could be a branch, tag or revision.
     reference: GitReference,
     /// The precise commit to use, if known.
     precise: Option<GitSha>,
+    /// The version control system that backs the repository.
+    backend: VcsBackend,
 }
 
 impl GitUrl {
@@ -34,6 +45,7 @@ impl GitUrl {
             repository,
             reference,
             precise,
+            backend: VcsBackend::Git,
         }
     }
 
@@ -43,9 +55,17 @@ impl GitUrl {
             repository,
             reference,
             precise: Some(precise),
+            backend: VcsBackend::Git,
         }
     }
 
+    /// Set the [`VcsBackend`] that backs the repository.
+    #[must_use]
+    pub fn with_backend(mut self, backend: VcsBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+
     /// Set the precise [`GitSha`] to use for this Git URL.
     #[must_use]
     pub fn with_precise(mut self, precise: GitSha) -> Self {
@@ -74,6 +94,11 @@ impl GitUrl {
     pub fn precise(&self) -> Option<GitSha> {
         self.precise
     }
+
+    /// Return the version control system that backs the repository.
+    pub fn backend(&self) -> VcsBackend {
+        self.backend
+    }
 }
 
 impl TryFrom<Url> for GitUrl {
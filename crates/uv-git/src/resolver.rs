@@ -159,8 +159,8 @@ impl GitResolver {
         // Convert `b` to a repository URL.
         let b_ref = RepositoryReference::from(b);
 
-        // The URLs must refer to the same repository.
-        if a_ref.url != b_ref.url {
+        // The URLs must refer to the same repository, in the same version control system.
+        if a_ref.url != b_ref.url || a.backend() != b.backend() {
             return false;
         }
 
@@ -9,8 +9,11 @@ pub struct GitSha(GitOid);
 
 impl GitSha {
     /// Convert the SHA to a truncated representation, i.e., the first 16 characters of the SHA.
+    ///
+    /// Shorter identifiers, like Subversion revision numbers, are returned as-is.
     pub fn to_short_string(&self) -> String {
-        self.0.to_string()[0..16].to_string()
+        let sha = self.0.as_str();
+        sha[..sha.len().min(16)].to_string()
     }
 }
 
@@ -13,7 +13,8 @@ use url::Url;
 use uv_cache_key::{cache_digest, RepositoryUrl};
 
 use crate::git::GitRemote;
-use crate::{GitOid, GitSha, GitUrl, GIT_STORE};
+use crate::hg::HgRepository;
+use crate::{svn, GitOid, GitSha, GitUrl, VcsBackend, GIT_STORE};
 
 /// A remote Git source that can be checked out locally.
 pub struct GitSource {
@@ -59,7 +60,7 @@ impl GitSource {
 
         // The path to the repo, within the Git database.
         let ident = cache_digest(&canonical);
-        let db_path = self.cache.join("db").join(&ident);
+        let db_path = self.cache().join("db").join(&ident);
 
         // Authenticate the URL, if necessary.
         let remote = if let Some(credentials) = GIT_STORE.get(&canonical) {
@@ -68,6 +69,17 @@ impl GitSource {
             Cow::Borrowed(&self.git.repository)
         };
 
+        match self.git.backend() {
+            VcsBackend::Git => {}
+            VcsBackend::Mercurial => {
+                let (_, actual_rev) = self.resolve_mercurial(&remote, &db_path)?;
+                return Ok(actual_rev);
+            }
+            VcsBackend::Subversion => {
+                return self.resolve_subversion(&remote);
+            }
+        }
+
         let remote = GitRemote::new(&remote);
         let (db, actual_rev, task) = match (self.git.precise, remote.db_at(&db_path).ok()) {
             // If we have a locked revision, and we have a preexisting database
@@ -121,7 +133,7 @@ impl GitSource {
 
         // The path to the repo, within the Git database.
         let ident = cache_digest(&canonical);
-        let db_path = self.cache.join("db").join(&ident);
+        let db_path = self.cache().join("db").join(&ident);
 
         // Authenticate the URL, if necessary.
         let remote = if let Some(credentials) = GIT_STORE.get(&canonical) {
@@ -130,6 +142,36 @@ impl GitSource {
             Cow::Borrowed(&self.git.repository)
         };
 
+        match self.git.backend() {
+            VcsBackend::Git => {}
+            VcsBackend::Mercurial => {
+                let (db, actual_rev) = self.resolve_mercurial(&remote, &db_path)?;
+                let checkout_path = self
+                    .cache()
+                    .join("checkouts")
+                    .join(&ident)
+                    .join(actual_rev.to_short_string());
+                db.copy_to(actual_rev, &checkout_path)?;
+                return Ok(Fetch {
+                    git: self.git.with_precise(actual_rev),
+                    path: checkout_path,
+                });
+            }
+            VcsBackend::Subversion => {
+                let actual_rev = self.resolve_subversion(&remote)?;
+                let checkout_path = self
+                    .cache()
+                    .join("checkouts")
+                    .join(&ident)
+                    .join(actual_rev.to_short_string());
+                svn::export(&remote, actual_rev, &checkout_path)?;
+                return Ok(Fetch {
+                    git: self.git.with_precise(actual_rev),
+                    path: checkout_path,
+                });
+            }
+        }
+
         let remote = GitRemote::new(&remote);
         let (db, actual_rev, task) = match (self.git.precise, remote.db_at(&db_path).ok()) {
             // If we have a locked revision, and we have a preexisting database
@@ -190,6 +232,91 @@ impl GitSource {
             path: checkout_path,
         })
     }
+
+    /// Return the path to the source database for the repository's [`VcsBackend`].
+    ///
+    /// Mercurial and Subversion sources are stored separately from Git sources, since the same
+    /// repository URL could be served by more than one version control system.
+    fn cache(&self) -> Cow<'_, Path> {
+        match self.git.backend() {
+            VcsBackend::Git => Cow::Borrowed(&self.cache),
+            backend => Cow::Owned(self.cache.join(backend.key())),
+        }
+    }
+
+    /// Resolve a Mercurial source to a specific changeset, pulling into the database at `db_path`
+    /// if necessary.
+    fn resolve_mercurial(&self, remote: &Url, db_path: &Path) -> Result<(HgRepository, GitSha)> {
+        // If we have a locked revision, and we have a preexisting database which has that
+        // revision, then no update needs to happen.
+        if let Some(rev) = self.git.precise {
+            if let Some(db) = HgRepository::open(db_path)
+                .ok()
+                .filter(|db| db.contains(rev))
+            {
+                debug!("Using existing Mercurial source `{}`", self.git.repository);
+                return Ok((db, rev));
+            }
+        }
+
+        debug!("Updating Mercurial source `{}`", self.git.repository);
+
+        // Report the checkout operation to the reporter.
+        let task = self
+            .reporter
+            .as_ref()
+            .map(|reporter| reporter.on_checkout_start(remote, self.git.reference.as_rev()));
+
+        let db = HgRepository::fetch(remote, db_path)?;
+        let actual_rev = if let Some(rev) = self.git.precise {
+            if !db.contains(rev) {
+                anyhow::bail!("Mercurial repository `{remote}` does not contain changeset `{rev}`");
+            }
+            rev
+        } else {
+            db.resolve(&self.git.reference)?
+        };
+
+        // Report the checkout operation to the reporter.
+        if let Some(task) = task {
+            if let Some(reporter) = self.reporter.as_ref() {
+                reporter.on_checkout_complete(remote, &actual_rev.to_short_string(), task);
+            }
+        }
+
+        Ok((db, actual_rev))
+    }
+
+    /// Resolve a Subversion source to a specific revision.
+    fn resolve_subversion(&self, remote: &Url) -> Result<GitSha> {
+        // Subversion revisions are immutable, so a locked revision can be used directly.
+        if let Some(rev) = self.git.precise {
+            debug!(
+                "Using locked Subversion revision `{rev}` for `{}`",
+                self.git.repository
+            );
+            return Ok(rev);
+        }
+
+        debug!("Resolving Subversion source `{}`", self.git.repository);
+
+        // Report the checkout operation to the reporter.
+        let task = self
+            .reporter
+            .as_ref()
+            .map(|reporter| reporter.on_checkout_start(remote, self.git.reference.as_rev()));
+
+        let actual_rev = svn::resolve(remote, &self.git.reference)?;
+
+        // Report the checkout operation to the reporter.
+        if let Some(task) = task {
+            if let Some(reporter) = self.reporter.as_ref() {
+                reporter.on_checkout_complete(remote, &actual_rev.to_short_string(), task);
+            }
+        }
+
+        Ok(actual_rev)
+    }
 }
 
 pub struct Fetch {
@@ -0,0 +1,79 @@
+//! Support for Subversion repositories, via the `svn` command-line tool.
+//!
+//! Unlike Git and Mercurial, Subversion is centralized, so there's no local database: revisions
+//! are resolved against the remote, and exported directly into the checkout directory.
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use anyhow::{Context, Result};
+use cargo_util::{paths, ProcessBuilder};
+use tracing::debug;
+use url::Url;
+
+use uv_fs::Simplified;
+
+use crate::git::CHECKOUT_READY_LOCK;
+use crate::{GitReference, GitSha};
+
+/// A global cache of the result of `which svn`.
+pub static SVN: LazyLock<Result<PathBuf, which::Error>> = LazyLock::new(|| which::which("svn"));
+
+/// Resolves a [`GitReference`] to the revision in which the repository path last changed.
+///
+/// Using the last-changed revision (rather than the repository-wide revision) ensures that
+/// unrelated commits elsewhere in the repository don't invalidate the lockfile.
+pub(crate) fn resolve(remote: &Url, reference: &GitReference) -> Result<GitSha> {
+    let rev = match reference {
+        GitReference::DefaultBranch => "HEAD",
+        GitReference::Branch(_) | GitReference::Tag(_) | GitReference::NamedRef(_) => {
+            anyhow::bail!(
+                "Subversion does not support a {} reference; reference the path in the repository URL, and use `rev` to select a revision",
+                reference.kind_str()
+            );
+        }
+        reference => reference.as_rev(),
+    };
+
+    let output = svn()?
+        .arg("info")
+        .arg("--show-item")
+        .arg("last-changed-revision")
+        .arg(format!("{remote}@{rev}"))
+        .exec_with_output()
+        .with_context(|| format!("failed to resolve revision `{rev}` of `{remote}`"))?;
+
+    let revision = String::from_utf8(output.stdout)?;
+    Ok(GitSha::from_str(revision.trim())?)
+}
+
+/// Exports the given revision of the `remote` to `destination`, unless a complete export
+/// already exists.
+pub(crate) fn export(remote: &Url, rev: GitSha, destination: &Path) -> Result<()> {
+    let ok_file = destination.join(CHECKOUT_READY_LOCK);
+    if ok_file.exists() {
+        return Ok(());
+    }
+
+    debug!("Exporting r{rev} to: {}", destination.user_display());
+    let _ = paths::remove_dir_all(destination);
+    if let Some(parent) = destination.parent() {
+        paths::create_dir_all(parent)?;
+    }
+    svn()?
+        .arg("export")
+        .arg(format!("{remote}@{rev}"))
+        .arg(destination)
+        .exec_with_output()
+        .with_context(|| format!("failed to export revision `{rev}` of `{remote}`"))?;
+
+    paths::create(ok_file)?;
+    Ok(())
+}
+
+/// Returns a [`ProcessBuilder`] for `svn`, configured for non-interactive use.
+fn svn() -> Result<ProcessBuilder> {
+    let mut cmd = ProcessBuilder::new(SVN.as_ref()?);
+    cmd.arg("--non-interactive");
+    Ok(cmd)
+}
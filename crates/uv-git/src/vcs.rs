@@ -0,0 +1,86 @@
+use url::Url;
+
+/// The version control system that backs a [`crate::GitUrl`].
+///
+/// Mercurial and Subversion repositories share the Git pipeline (resolution, locking, and
+/// caching), and are distinguished by the backend recorded alongside the repository URL.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VcsBackend {
+    /// A Git repository.
+    #[default]
+    Git,
+    /// A Mercurial repository.
+    Mercurial,
+    /// A Subversion repository.
+    Subversion,
+}
+
+impl VcsBackend {
+    /// Determine the [`VcsBackend`] for a PEP 508 URL, based on its scheme prefix (e.g., `hg+`).
+    ///
+    /// URLs without a recognized prefix are assumed to refer to Git repositories.
+    pub fn from_url(url: &Url) -> Self {
+        if url.scheme().starts_with("hg+") {
+            Self::Mercurial
+        } else if url.scheme().starts_with("svn+") {
+            Self::Subversion
+        } else {
+            Self::Git
+        }
+    }
+
+    /// Return the scheme prefix used to identify the backend in a PEP 508 URL (e.g., `hg+`).
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Self::Git => "git+",
+            Self::Mercurial => "hg+",
+            Self::Subversion => "svn+",
+        }
+    }
+
+    /// Return the prefix that must be prepended to a repository URL to form a PEP 508 URL.
+    ///
+    /// As in pip, `svn+ssh://` repository URLs already include their prefix, since `svn+ssh` is a
+    /// native Subversion scheme.
+    pub fn missing_prefix(self, repository: &Url) -> &'static str {
+        if repository.scheme().starts_with(self.prefix()) {
+            ""
+        } else {
+            self.prefix()
+        }
+    }
+
+    /// Return the name of the key used to identify the backend in `tool.uv.sources` and the
+    /// lockfile (e.g., `hg`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Git => "git",
+            Self::Mercurial => "hg",
+            Self::Subversion => "svn",
+        }
+    }
+
+    /// Return the repository URL for a PEP 508 URL, i.e., with the `git+`, `hg+`, or `svn+`
+    /// prefix removed.
+    ///
+    /// As in pip, `svn+ssh://` URLs retain their prefix, since `svn+ssh` is a native Subversion
+    /// scheme.
+    pub fn remote(self, url: &Url) -> Result<Url, url::ParseError> {
+        match url.as_str().strip_prefix(self.prefix()) {
+            Some(stripped) if !(self == Self::Subversion && stripped.starts_with("ssh://")) => {
+                Url::parse(stripped)
+            }
+            _ => Ok(url.clone()),
+        }
+    }
+}
+
+impl std::fmt::Display for VcsBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Git => write!(f, "Git"),
+            Self::Mercurial => write!(f, "Mercurial"),
+            Self::Subversion => write!(f, "Subversion"),
+        }
+    }
+}
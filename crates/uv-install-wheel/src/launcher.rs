@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use uv_platform_tags::Arch;
+pub use uv_trampoline_builder::LauncherArch;
+use uv_trampoline_builder::LauncherOptions;
+
+/// Options for the launchers generated for entry points on Windows.
+///
+/// On Windows, each console and GUI entry point is installed as a small `.exe` launcher that
+/// embeds the path to the Python interpreter along with the entry point script. These options
+/// have no effect on other platforms.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ScriptLauncher {
+    /// Entry points to launch with the GUI subsystem (i.e., without a console window), even if
+    /// they're declared as `console_scripts`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub gui: Vec<String>,
+    /// Entry points to launch with the console subsystem, even if they're declared as
+    /// `gui_scripts`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub console: Vec<String>,
+    /// Embed the interpreter path as an extended-length path (e.g., `\\?\C:\...`), for
+    /// environments located at paths longer than `MAX_PATH` (260 characters).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub long_paths: bool,
+    /// The architecture of the launchers. Defaults to the architecture of the Python interpreter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arch: Option<LauncherArch>,
+}
+
+impl ScriptLauncher {
+    /// Use launchers matching the interpreter's architecture, unless one was set explicitly.
+    #[must_use]
+    pub fn with_default_arch(self, arch: Arch) -> Self {
+        let default = match arch {
+            Arch::X86 => Some(LauncherArch::X86),
+            Arch::X86_64 => Some(LauncherArch::X86_64),
+            Arch::Aarch64 => Some(LauncherArch::Aarch64),
+            _ => None,
+        };
+        Self {
+            arch: self.arch.or(default),
+            ..self
+        }
+    }
+
+    /// Returns `true` if the entry point with the given name should be launched with the GUI
+    /// subsystem, given whether it was declared as a GUI script.
+    pub(crate) fn is_gui(&self, name: &str, declared_gui: bool) -> bool {
+        if self.gui.iter().any(|gui| gui == name) {
+            true
+        } else if self.console.iter().any(|console| console == name) {
+            false
+        } else {
+            declared_gui
+        }
+    }
+
+    /// Returns the [`LauncherOptions`] to use when building a launcher.
+    pub(crate) fn options(&self) -> LauncherOptions {
+        LauncherOptions {
+            arch: self.arch,
+            long_paths: self.long_paths,
+        }
+    }
+}
@@ -7,18 +7,29 @@ use platform_info::PlatformInfoError;
 use thiserror::Error;
 use zip::result::ZipError;
 
+pub use launcher::{LauncherArch, ScriptLauncher};
+pub use rebuild::{
+    has_native_sources, install_rebuild_hook, installed_rebuild_hooks, uninstall_rebuild_hook,
+};
 pub use uninstall::{uninstall_egg, uninstall_legacy_editable, uninstall_wheel, Uninstall};
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_platform_tags::{Arch, Os};
 use uv_pypi_types::Scheme;
+pub use vendor::{
+    install_vendored_wheel, is_valid_module_name, rewrite_dependent_imports, rewrite_imports,
+    vendored_module,
+};
 pub use wheel::{parse_wheel_file, read_record_file, LibKind};
 
+mod launcher;
 pub mod linker;
+mod rebuild;
 mod record;
 mod script;
 mod uninstall;
+mod vendor;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
@@ -32,6 +43,9 @@ pub struct Layout {
     pub os_name: String,
     /// The [`Scheme`] paths for the interpreter.
     pub scheme: Scheme,
+    /// Whether to install scripts, i.e., entry points and any scripts included in the wheel's
+    /// `.data` directory.
+    pub install_scripts: bool,
 }
 
 /// Note: The caller is responsible for adding the path of the wheel we're installing.
@@ -63,6 +77,8 @@ pub enum Error {
     PythonSubcommand(#[source] io::Error),
     #[error("Failed to move data files")]
     WalkDir(#[from] walkdir::Error),
+    #[error("Invalid path in wheel: {}", _0.user_display())]
+    InvalidPath(PathBuf),
     #[error("RECORD file doesn't match wheel contents: {0}")]
     RecordFile(String),
     #[error("RECORD file is invalid")]
@@ -99,4 +115,6 @@ pub enum Error {
     InvalidEggLink(PathBuf),
     #[error(transparent)]
     LauncherError(#[from] uv_trampoline_builder::Error),
+    #[error("Unable to install vendored package: {0}")]
+    Vendor(String),
 }
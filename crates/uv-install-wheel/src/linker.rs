@@ -1,18 +1,21 @@
 //! Like `wheel.rs`, but for installing wheels that have already been unzipped, rather than
 //! reading from a zip file.
 
+use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::SystemTime;
 
+use crate::record::RecordEntry;
 use crate::script::{scripts_from_ini, Script};
 use crate::wheel::{
-    extra_dist_info, install_data, parse_wheel_file, read_record_file, write_script_entrypoints,
-    LibKind,
+    copy_and_hash, extra_dist_info, install_data, parse_wheel_file, read_record_file,
+    write_script_entrypoints, LibKind,
 };
-use crate::{Error, Layout};
+use crate::{Error, Layout, ScriptLauncher};
 use fs_err as fs;
 use fs_err::{DirEntry, File};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use reflink_copy as reflink;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
@@ -20,6 +23,7 @@ use tempfile::tempdir_in;
 use tracing::{debug, instrument, trace};
 use uv_cache_info::CacheInfo;
 use uv_distribution_filename::WheelFilename;
+use uv_fs::Simplified;
 use uv_pypi_types::{DirectUrl, Metadata12};
 use uv_warnings::warn_user_once;
 use walkdir::WalkDir;
@@ -42,8 +46,10 @@ pub fn install_wheel(
     filename: &WheelFilename,
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&CacheInfo>,
+    index: Option<&str>,
     installer: Option<&str>,
     link_mode: LinkMode,
+    launcher: &ScriptLauncher,
     locks: &Locks,
 ) -> Result<(), Error> {
     let dist_info_prefix = find_dist_info(&wheel)?;
@@ -79,10 +85,7 @@ pub fn install_wheel(
         LibKind::Pure => &layout.scheme.purelib,
         LibKind::Plat => &layout.scheme.platlib,
     };
-    let num_unpacked = link_mode.link_wheel_files(site_packages, &wheel, locks)?;
-    trace!(?name, "Extracted {num_unpacked} files");
 
-    // Read the RECORD file.
     let mut record_file = File::open(
         wheel
             .as_ref()
@@ -90,10 +93,22 @@ pub fn install_wheel(
     )?;
     let mut record = read_record_file(&mut record_file)?;
 
+    // The RECORD is written incrementally: first, before any files are linked, and then again
+    // after each step that adds files. As such, an interrupted installation leaves behind a RECORD
+    // that covers all of the installed files, which allows it to be uninstalled.
+    let record_path = site_packages.join(format!("{dist_info_prefix}.dist-info/RECORD"));
+    fs::create_dir_all(site_packages.join(format!("{dist_info_prefix}.dist-info")))?;
+    write_record_file(&record_path, &mut record)?;
+
+    let num_unpacked = link_mode.link_wheel_files(site_packages, &wheel, locks)?;
+    trace!(?name, "Extracted {num_unpacked} files");
+
     let (console_scripts, gui_scripts) =
         parse_scripts(&wheel, &dist_info_prefix, None, layout.python_version.1)?;
 
-    if console_scripts.is_empty() && gui_scripts.is_empty() {
+    if !layout.install_scripts {
+        trace!(?name, "Skipping entrypoints");
+    } else if console_scripts.is_empty() && gui_scripts.is_empty() {
         trace!(?name, "No entrypoints");
     } else {
         trace!(?name, "Writing entrypoints");
@@ -106,6 +121,7 @@ pub fn install_wheel(
             &console_scripts,
             &mut record,
             false,
+            launcher,
         )?;
         write_script_entrypoints(
             layout,
@@ -114,7 +130,9 @@ pub fn install_wheel(
             &gui_scripts,
             &mut record,
             true,
+            launcher,
         )?;
+        write_record_file(&record_path, &mut record)?;
     }
 
     // 2.a Unpacked archive includes distribution-1.0.dist-info/ and (if there is data) distribution-1.0.data/.
@@ -136,6 +154,7 @@ pub fn install_wheel(
         // Script are unsupported through data
         // 2.e Remove empty distribution-1.0.data directory.
         fs::remove_dir_all(data_dir)?;
+        write_record_file(&record_path, &mut record)?;
     } else {
         trace!(?name, "No data");
     }
@@ -147,29 +166,180 @@ pub fn install_wheel(
         true,
         direct_url,
         cache_info,
+        index,
         installer,
         &mut record,
     )?;
 
     trace!(?name, "Writing record");
+    write_record_file(&record_path, &mut record)?;
+
+    Ok(())
+}
+
+/// Regenerate the entry point launchers of an installed distribution, e.g., to point them at the
+/// environment's current interpreter after a Python upgrade.
+///
+/// The `RECORD` file of the distribution is updated to match. Returns the number of entry points
+/// that were written.
+pub fn refresh_entrypoints(
+    layout: &Layout,
+    relocatable: bool,
+    dist_info: &Path,
+    launcher: &ScriptLauncher,
+) -> Result<usize, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Ok(0);
+    };
+    let Some(dist_info_prefix) = dist_info
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(".dist-info"))
+    else {
+        return Ok(0);
+    };
+
+    let (console_scripts, gui_scripts) = parse_scripts(
+        site_packages,
+        dist_info_prefix,
+        None,
+        layout.python_version.1,
+    )?;
+    if console_scripts.is_empty() && gui_scripts.is_empty() {
+        return Ok(0);
+    }
+
+    let record_path = dist_info.join("RECORD");
+    let mut record = read_record_file(&mut File::open(&record_path)?)?;
+
+    fs_err::create_dir_all(&layout.scheme.scripts)?;
+    let mut refreshed = Vec::new();
+    write_script_entrypoints(
+        layout,
+        relocatable,
+        site_packages,
+        &console_scripts,
+        &mut refreshed,
+        false,
+        launcher,
+    )?;
+    write_script_entrypoints(
+        layout,
+        relocatable,
+        site_packages,
+        &gui_scripts,
+        &mut refreshed,
+        true,
+        launcher,
+    )?;
+
+    // Replace the entries for the rewritten launchers, since their hashes have changed.
+    record.retain(|entry| {
+        !refreshed
+            .iter()
+            .any(|refreshed| refreshed.path == entry.path)
+    });
+    let count = refreshed.len();
+    record.extend(refreshed);
+    write_record_file(&record_path, &mut record)?;
+
+    Ok(count)
+}
+
+/// Write the entries to a `RECORD` file, in sorted order.
+fn write_record_file(path: &Path, record: &mut [RecordEntry]) -> Result<(), Error> {
     let mut record_writer = csv::WriterBuilder::new()
         .has_headers(false)
         .escape(b'"')
-        .from_path(site_packages.join(format!("{dist_info_prefix}.dist-info/RECORD")))?;
+        .from_path(path)?;
     record.sort();
-    for entry in record {
+    for entry in record.iter() {
         record_writer.serialize(entry)?;
     }
+    record_writer.flush()?;
+    Ok(())
+}
+
+/// Verify that the contents of an unzipped wheel match the hashes and sizes in its `RECORD` file.
+///
+/// Entries without a hash (like the `RECORD` file itself), or with a hash algorithm other than
+/// SHA-256, are ignored.
+pub fn verify_wheel(wheel: impl AsRef<Path>) -> Result<(), Error> {
+    let wheel = wheel.as_ref();
+    let dist_info_prefix = find_dist_info(wheel)?;
+
+    let record_path = wheel.join(format!("{dist_info_prefix}.dist-info/RECORD"));
+    let mut record_file = match File::open(&record_path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(Error::InvalidWheel("Missing RECORD file".to_string()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let record = read_record_file(&mut record_file)?;
+
+    for entry in record {
+        let Some(expected) = entry.hash.as_deref() else {
+            continue;
+        };
+        if !expected.starts_with("sha256=") {
+            continue;
+        }
+
+        let mut file = match File::open(wheel.join(&entry.path)) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(Error::RecordFile(format!("Missing file: {}", entry.path)));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let (size, actual) = copy_and_hash(&mut file, &mut std::io::sink())?;
+        if let Some(expected) = entry.size.filter(|expected| *expected != size) {
+            return Err(Error::RecordFile(format!(
+                "Size mismatch for {}: expected {expected} bytes, found {size} bytes",
+                entry.path
+            )));
+        }
+        if actual != expected {
+            return Err(Error::RecordFile(format!(
+                "Hash mismatch for {}: expected `{expected}`, found `{actual}`",
+                entry.path
+            )));
+        }
+    }
 
     Ok(())
 }
 
+/// Return the files in an unzipped wheel whose contents don't match the hashes in its `RECORD`
+/// file, in sorted order.
+///
+/// Unlike [`verify_wheel`], mismatches are collected rather than treated as errors, since
+/// installers (like pip) don't enforce the hashes in the `RECORD`, and some wheels ship with
+/// inaccurate entries. The files are hashed in parallel. Entries without a hash, or with a hash
+/// algorithm other than SHA-256, are ignored.
+pub fn record_mismatches(wheel: impl AsRef<Path>) -> Result<Vec<String>, Error> {
+    let wheel = wheel.as_ref();
+    let dist_info_prefix = find_dist_info(wheel)?;
+
+    let record_path = wheel.join(format!("{dist_info_prefix}.dist-info/RECORD"));
+    let record = read_record_file(&mut File::open(record_path)?)?;
+    let digests = RecordDigests::from_record(&record);
+
+    WheelContents::walk(wheel)?
+        .files
+        .par_iter()
+        .try_for_each(|relative| digests.verify(&wheel.join(relative), relative))?;
+
+    Ok(digests.mismatches())
+}
+
 /// Find the `dist-info` directory in an unzipped wheel.
 ///
 /// See: <https://github.com/PyO3/python-pkginfo-rs>
 ///
 /// See: <https://github.com/pypa/pip/blob/36823099a9cdd83261fdbc8c1d2a24fa2eea72ca/src/pip/_internal/utils/wheel.py#L38>
-fn find_dist_info(path: impl AsRef<Path>) -> Result<String, Error> {
+pub(crate) fn find_dist_info(path: impl AsRef<Path>) -> Result<String, Error> {
     // Iterate over `path` to find the `.dist-info` directory. It should be at the top-level.
     let Some(dist_info) = fs::read_dir(path.as_ref())?.find_map(|entry| {
         let entry = entry.ok()?;
@@ -368,7 +538,7 @@ fn clone_recursive(
 ) -> Result<(), Error> {
     // Determine the existing and destination paths.
     let from = entry.path();
-    let to = site_packages.join(from.strip_prefix(wheel).unwrap());
+    let to = site_packages.join(relative_path(&from, wheel)?);
 
     trace!("Cloning {} to {}", from.display(), to.display());
 
@@ -393,7 +563,7 @@ fn clone_recursive(
                     } else {
                         // If file already exists, overwrite it.
                         let tempdir = tempdir_in(site_packages)?;
-                        let tempfile = tempdir.path().join(from.file_name().unwrap());
+                        let tempfile = tempdir.path().join(entry.file_name());
                         if reflink::reflink(&from, &tempfile).is_ok() {
                             fs::rename(&tempfile, to)?;
                         } else {
@@ -429,7 +599,7 @@ fn clone_recursive(
                     } else {
                         // If file already exists, overwrite it.
                         let tempdir = tempdir_in(site_packages)?;
-                        let tempfile = tempdir.path().join(from.file_name().unwrap());
+                        let tempfile = tempdir.path().join(entry.file_name());
                         reflink::reflink(&from, &tempfile)?;
                         fs::rename(&tempfile, to)?;
                     }
@@ -457,33 +627,59 @@ fn clone_recursive(
     Ok(())
 }
 
+/// The contents of an unzipped wheel, relative to the root of the wheel.
+#[derive(Debug, Default)]
+struct WheelContents {
+    /// The directories in the wheel, with each parent preceding its children.
+    directories: Vec<PathBuf>,
+    /// The files in the wheel.
+    files: Vec<PathBuf>,
+}
+
+impl WheelContents {
+    /// Walk an unzipped wheel to collect its directories and files.
+    fn walk(wheel: &Path) -> Result<Self, Error> {
+        let mut contents = Self::default();
+        for entry in WalkDir::new(wheel) {
+            let entry = entry?;
+            let relative = relative_path(entry.path(), wheel)?.to_path_buf();
+            if entry.file_type().is_dir() {
+                contents.directories.push(relative);
+            } else {
+                contents.files.push(relative);
+            }
+        }
+        Ok(contents)
+    }
+
+    /// Create the wheel's directories in site packages, such that its files can be linked in
+    /// parallel.
+    fn create_directories(&self, site_packages: &Path) -> Result<(), Error> {
+        for directory in &self.directories {
+            fs::create_dir_all(site_packages.join(directory))?;
+        }
+        Ok(())
+    }
+}
+
 /// Extract a wheel by copying all of its files into site packages.
 fn copy_wheel_files(
     site_packages: impl AsRef<Path>,
     wheel: impl AsRef<Path>,
     locks: &Locks,
 ) -> Result<usize, Error> {
-    let mut count = 0usize;
-
-    // Walk over the directory.
-    for entry in walkdir::WalkDir::new(&wheel) {
-        let entry = entry?;
-        let path = entry.path();
+    let site_packages = site_packages.as_ref();
+    let wheel = wheel.as_ref();
 
-        let relative = path.strip_prefix(&wheel).unwrap();
-        let out_path = site_packages.as_ref().join(relative);
+    let contents = WheelContents::walk(wheel)?;
+    contents.create_directories(site_packages)?;
 
-        if entry.file_type().is_dir() {
-            fs::create_dir_all(&out_path)?;
-            continue;
-        }
+    // Copy the files in parallel.
+    contents.files.par_iter().try_for_each(|relative| {
+        synchronized_copy(&wheel.join(relative), &site_packages.join(relative), locks)
+    })?;
 
-        synchronized_copy(path, &out_path, locks)?;
-
-        count += 1;
-    }
-
-    Ok(count)
+    Ok(contents.files.len())
 }
 
 /// Extract a wheel by hard-linking all of its files into site packages.
@@ -492,190 +688,195 @@ fn hardlink_wheel_files(
     wheel: impl AsRef<Path>,
     locks: &Locks,
 ) -> Result<usize, Error> {
-    let mut attempt = Attempt::default();
-    let mut count = 0usize;
+    link_wheel_files(
+        site_packages.as_ref(),
+        wheel.as_ref(),
+        locks,
+        "hardlink",
+        |from, to| fs::hard_link(from, to),
+    )
+}
 
-    // Walk over the directory.
-    for entry in walkdir::WalkDir::new(&wheel) {
-        let entry = entry?;
-        let path = entry.path();
+/// Extract a wheel by symbolically-linking all of its files into site packages.
+fn symlink_wheel_files(
+    site_packages: impl AsRef<Path>,
+    wheel: impl AsRef<Path>,
+    locks: &Locks,
+) -> Result<usize, Error> {
+    link_wheel_files(
+        site_packages.as_ref(),
+        wheel.as_ref(),
+        locks,
+        "symlink",
+        |from, to| create_symlink(from, to),
+    )
+}
 
-        let relative = path.strip_prefix(&wheel).unwrap();
-        let out_path = site_packages.as_ref().join(relative);
+/// Extract a wheel by linking all of its files into site packages with the given `link` function
+/// (e.g., a hard link or symlink), falling back to copying if linking isn't supported.
+///
+/// The first file is linked on its own, to determine whether linking is supported; the remaining
+/// files are then linked in parallel.
+fn link_wheel_files(
+    site_packages: &Path,
+    wheel: &Path,
+    locks: &Locks,
+    verb: &str,
+    link: impl Fn(&Path, &Path) -> std::io::Result<()> + Sync,
+) -> Result<usize, Error> {
+    let contents = WheelContents::walk(wheel)?;
+    contents.create_directories(site_packages)?;
 
-        if entry.file_type().is_dir() {
-            fs::create_dir_all(&out_path)?;
-            continue;
-        }
+    let mut files = contents.files.iter();
+    let mut attempt = Attempt::default();
+    for relative in files.by_ref() {
+        let path = wheel.join(relative);
+        let out_path = site_packages.join(relative);
 
-        // The `RECORD` file is modified during installation, so we copy it instead of hard-linking.
+        // The `RECORD` file is modified during installation, so we copy it instead of linking.
         if path.ends_with("RECORD") {
-            synchronized_copy(path, &out_path, locks)?;
-            count += 1;
+            synchronized_copy(&path, &out_path, locks)?;
             continue;
         }
 
-        // Fallback to copying if hardlinks aren't supported for this installation.
-        match attempt {
-            Attempt::Initial => {
-                // Once https://github.com/rust-lang/rust/issues/86442 is stable, use that.
-                attempt = Attempt::Subsequent;
-                if let Err(err) = fs::hard_link(path, &out_path) {
-                    // If the file already exists, remove it and try again.
-                    if err.kind() == std::io::ErrorKind::AlreadyExists {
-                        debug!(
-                            "File already exists (initial attempt), overwriting: {}",
-                            out_path.display()
-                        );
-                        // Removing and recreating would lead to race conditions.
-                        let tempdir = tempdir_in(&site_packages)?;
-                        let tempfile = tempdir.path().join(entry.file_name());
-                        if fs::hard_link(path, &tempfile).is_ok() {
-                            fs_err::rename(&tempfile, &out_path)?;
-                        } else {
-                            debug!(
-                                "Failed to hardlink `{}` to `{}`, attempting to copy files as a fallback",
-                                out_path.display(),
-                                path.display()
-                            );
-                            synchronized_copy(path, &out_path, locks)?;
-                            attempt = Attempt::UseCopyFallback;
-                        }
-                    } else {
-                        debug!(
-                            "Failed to hardlink `{}` to `{}`, attempting to copy files as a fallback",
-                            out_path.display(),
-                            path.display()
-                        );
-                        synchronized_copy(path, &out_path, locks)?;
-                        attempt = Attempt::UseCopyFallback;
-                    }
-                }
-            }
-            Attempt::Subsequent => {
-                if let Err(err) = fs::hard_link(path, &out_path) {
-                    // If the file already exists, remove it and try again.
-                    if err.kind() == std::io::ErrorKind::AlreadyExists {
-                        debug!(
-                            "File already exists (subsequent attempt), overwriting: {}",
-                            out_path.display()
-                        );
-                        // Removing and recreating would lead to race conditions.
-                        let tempdir = tempdir_in(&site_packages)?;
-                        let tempfile = tempdir.path().join(entry.file_name());
-                        fs::hard_link(path, &tempfile)?;
-                        fs_err::rename(&tempfile, &out_path)?;
-                    } else {
-                        return Err(err.into());
-                    }
+        // Fallback to copying if links aren't supported for this installation.
+        // Once https://github.com/rust-lang/rust/issues/86442 is stable, use that.
+        attempt = Attempt::Subsequent;
+        if let Err(err) = link(&path, &out_path) {
+            // If the file already exists, remove it and try again.
+            if err.kind() == std::io::ErrorKind::AlreadyExists {
+                debug!(
+                    "File already exists (initial attempt), overwriting: {}",
+                    out_path.display()
+                );
+                // Removing and recreating would lead to race conditions.
+                let tempdir = tempdir_in(site_packages)?;
+                let tempfile = tempdir.path().join(file_name(relative)?);
+                if link(&path, &tempfile).is_ok() {
+                    fs::rename(&tempfile, &out_path)?;
+                } else {
+                    debug!(
+                        "Failed to {verb} `{}` to `{}`, attempting to copy files as a fallback",
+                        out_path.display(),
+                        path.display()
+                    );
+                    synchronized_copy(&path, &out_path, locks)?;
+                    attempt = Attempt::UseCopyFallback;
                 }
+            } else {
+                debug!(
+                    "Failed to {verb} `{}` to `{}`, attempting to copy files as a fallback",
+                    out_path.display(),
+                    path.display()
+                );
+                synchronized_copy(&path, &out_path, locks)?;
+                attempt = Attempt::UseCopyFallback;
             }
-            Attempt::UseCopyFallback => {
-                synchronized_copy(path, &out_path, locks)?;
-                warn_user_once!("Failed to hardlink files; falling back to full copy. This may lead to degraded performance.\n         If the cache and target directories are on different filesystems, hardlinking may not be supported.\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.");
+        }
+        break;
+    }
+
+    // Link the remaining files in parallel.
+    let remaining = files.as_slice();
+    remaining.par_iter().try_for_each(|relative| {
+        let path = wheel.join(relative);
+        let out_path = site_packages.join(relative);
+
+        // The `RECORD` file is modified during installation, so we copy it instead of linking.
+        if path.ends_with("RECORD") {
+            return Ok(synchronized_copy(&path, &out_path, locks)?);
+        }
+
+        if attempt == Attempt::UseCopyFallback {
+            return Ok(synchronized_copy(&path, &out_path, locks)?);
+        }
+
+        if let Err(err) = link(&path, &out_path) {
+            // If the file already exists, remove it and try again.
+            if err.kind() == std::io::ErrorKind::AlreadyExists {
+                debug!(
+                    "File already exists (subsequent attempt), overwriting: {}",
+                    out_path.display()
+                );
+                // Removing and recreating would lead to race conditions.
+                let tempdir = tempdir_in(site_packages)?;
+                let tempfile = tempdir.path().join(file_name(relative)?);
+                link(&path, &tempfile)?;
+                fs::rename(&tempfile, &out_path)?;
+            } else {
+                return Err(err.into());
             }
         }
+        Ok(())
+    })?;
 
-        count += 1;
+    if attempt == Attempt::UseCopyFallback {
+        warn_user_once!("Failed to {verb} files; falling back to full copy. This may lead to degraded performance.\n         If the cache and target directories are on different filesystems, {verb}ing may not be supported.\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.");
     }
 
-    Ok(count)
+    Ok(contents.files.len())
 }
 
-/// Extract a wheel by symbolically-linking all of its files into site packages.
-fn symlink_wheel_files(
-    site_packages: impl AsRef<Path>,
-    wheel: impl AsRef<Path>,
-    locks: &Locks,
-) -> Result<usize, Error> {
-    let mut attempt = Attempt::default();
-    let mut count = 0usize;
-
-    // Walk over the directory.
-    for entry in WalkDir::new(&wheel) {
-        let entry = entry?;
-        let path = entry.path();
-
-        let relative = path.strip_prefix(&wheel).unwrap();
-        let out_path = site_packages.as_ref().join(relative);
+/// The expected SHA-256 digests of the files in a wheel, keyed by their path in the `RECORD` file,
+/// along with the files that were found not to match.
+#[derive(Debug, Default)]
+struct RecordDigests {
+    digests: FxHashMap<String, String>,
+    mismatches: Mutex<Vec<String>>,
+}
 
-        if entry.file_type().is_dir() {
-            fs::create_dir_all(&out_path)?;
-            continue;
+impl RecordDigests {
+    /// Collect the SHA-256 digests from the entries of a `RECORD` file.
+    ///
+    /// Entries without a hash (like the `RECORD` file itself), or with a hash algorithm other than
+    /// SHA-256, are ignored.
+    fn from_record(record: &[RecordEntry]) -> Self {
+        Self {
+            digests: record
+                .iter()
+                .filter_map(|entry| {
+                    let hash = entry.hash.as_ref()?;
+                    hash.starts_with("sha256=")
+                        .then(|| (entry.path.clone(), hash.clone()))
+                })
+                .collect(),
+            mismatches: Mutex::default(),
         }
+    }
 
-        // The `RECORD` file is modified during installation, so we copy it instead of symlinking.
-        if path.ends_with("RECORD") {
-            synchronized_copy(path, &out_path, locks)?;
-            count += 1;
-            continue;
-        }
-
-        // Fallback to copying if symlinks aren't supported for this installation.
-        match attempt {
-            Attempt::Initial => {
-                // Once https://github.com/rust-lang/rust/issues/86442 is stable, use that.
-                attempt = Attempt::Subsequent;
-                if let Err(err) = create_symlink(path, &out_path) {
-                    // If the file already exists, remove it and try again.
-                    if err.kind() == std::io::ErrorKind::AlreadyExists {
-                        debug!(
-                            "File already exists (initial attempt), overwriting: {}",
-                            out_path.display()
-                        );
-                        // Removing and recreating would lead to race conditions.
-                        let tempdir = tempdir_in(&site_packages)?;
-                        let tempfile = tempdir.path().join(entry.file_name());
-                        if create_symlink(path, &tempfile).is_ok() {
-                            fs::rename(&tempfile, &out_path)?;
-                        } else {
-                            debug!(
-                                "Failed to symlink `{}` to `{}`, attempting to copy files as a fallback",
-                                out_path.display(),
-                                path.display()
-                            );
-                            synchronized_copy(path, &out_path, locks)?;
-                            attempt = Attempt::UseCopyFallback;
-                        }
-                    } else {
-                        debug!(
-                            "Failed to symlink `{}` to `{}`, attempting to copy files as a fallback",
-                            out_path.display(),
-                            path.display()
-                        );
-                        synchronized_copy(path, &out_path, locks)?;
-                        attempt = Attempt::UseCopyFallback;
-                    }
-                }
-            }
-            Attempt::Subsequent => {
-                if let Err(err) = create_symlink(path, &out_path) {
-                    // If the file already exists, remove it and try again.
-                    if err.kind() == std::io::ErrorKind::AlreadyExists {
-                        debug!(
-                            "File already exists (subsequent attempt), overwriting: {}",
-                            out_path.display()
-                        );
-                        // Removing and recreating would lead to race conditions.
-                        let tempdir = tempdir_in(&site_packages)?;
-                        let tempfile = tempdir.path().join(entry.file_name());
-                        create_symlink(path, &tempfile)?;
-                        fs::rename(&tempfile, &out_path)?;
-                    } else {
-                        return Err(err.into());
-                    }
-                }
-            }
-            Attempt::UseCopyFallback => {
-                synchronized_copy(path, &out_path, locks)?;
-                warn_user_once!("Failed to symlink files; falling back to full copy. This may lead to degraded performance.\n         If the cache and target directories are on different filesystems, symlinking may not be supported.\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning.");
-            }
+    /// Hash the file at `path` and compare it to the expected digest for `relative`, if any,
+    /// recording a mismatch if the digests differ.
+    fn verify(&self, path: &Path, relative: &Path) -> Result<(), Error> {
+        let relative = relative.portable_display().to_string();
+        let Some(expected) = self.digests.get(&relative) else {
+            return Ok(());
+        };
+        let (_, actual) = copy_and_hash(&mut File::open(path)?, &mut std::io::sink())?;
+        if actual != *expected {
+            debug!("Hash mismatch for {relative}: expected `{expected}`, found `{actual}`");
+            self.mismatches.lock().unwrap().push(relative);
         }
+        Ok(())
+    }
 
-        count += 1;
+    /// Return the files whose contents didn't match the `RECORD`, in sorted order.
+    fn mismatches(self) -> Vec<String> {
+        let mut mismatches = self.mismatches.into_inner().unwrap();
+        mismatches.sort_unstable();
+        mismatches
     }
+}
 
-    Ok(count)
+/// Return the path of `path` relative to the root of the unzipped `wheel`.
+fn relative_path<'a>(path: &'a Path, wheel: &Path) -> Result<&'a Path, Error> {
+    path.strip_prefix(wheel)
+        .map_err(|_| Error::InvalidPath(path.to_path_buf()))
+}
+
+/// Return the final component of a file path in a wheel.
+fn file_name(path: &Path) -> Result<&OsStr, Error> {
+    path.file_name()
+        .ok_or_else(|| Error::InvalidPath(path.to_path_buf()))
 }
 
 /// Copy from `from` to `to`, ensuring that the parent directory is locked. Avoids simultaneous
@@ -714,3 +915,188 @@ fn create_symlink<P: AsRef<Path>, Q: AsRef<Path>>(original: P, link: Q) -> std::
         std::os::windows::fs::symlink_file(original, link)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+    use assert_fs::TempDir;
+
+    use crate::wheel::copy_and_hash;
+
+    use super::{link_wheel_files, record_mismatches, LinkMode, Locks};
+
+    /// Create an unzipped wheel in which the `RECORD` digest for `foo/data.txt` is incorrect.
+    fn unzipped_wheel(wheel: &TempDir) -> Result<()> {
+        let files = [
+            ("foo/__init__.py", "from .bar import baz\n"),
+            ("foo/bar.py", "baz = 1\n"),
+            ("foo/data.txt", "data\n"),
+        ];
+
+        let mut record = String::new();
+        for (path, contents) in files {
+            wheel.child(path).write_str(contents)?;
+            let (size, hash) = copy_and_hash(&mut Cursor::new(contents), &mut std::io::sink())?;
+            let hash = if path == "foo/data.txt" {
+                "sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU".to_string()
+            } else {
+                hash
+            };
+            record.push_str(&format!("{path},{hash},{size}\n"));
+        }
+        record.push_str("foo-1.0.0.dist-info/RECORD,,\n");
+        wheel
+            .child("foo-1.0.0.dist-info/RECORD")
+            .write_str(&record)?;
+
+        Ok(())
+    }
+
+    /// Assert that the files in the wheel were installed into site packages.
+    fn assert_installed(wheel: &TempDir, site_packages: &TempDir) -> Result<()> {
+        site_packages
+            .child("foo/__init__.py")
+            .assert("from .bar import baz\n");
+        site_packages.child("foo/bar.py").assert("baz = 1\n");
+        site_packages.child("foo/data.txt").assert("data\n");
+        site_packages
+            .child("foo-1.0.0.dist-info/RECORD")
+            .assert(fs_err::read_to_string(
+                wheel.child("foo-1.0.0.dist-info/RECORD"),
+            )?);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    fn same_file(left: &std::path::Path, right: &std::path::Path) -> Result<bool> {
+        use std::os::unix::fs::MetadataExt;
+        Ok(fs_err::metadata(left)?.ino() == fs_err::metadata(right)?.ino())
+    }
+
+    #[test]
+    fn mismatches() -> Result<()> {
+        let wheel = TempDir::new()?;
+        unzipped_wheel(&wheel)?;
+
+        assert_eq!(record_mismatches(wheel.path())?, vec!["foo/data.txt"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn copy() -> Result<()> {
+        let wheel = TempDir::new()?;
+        let site_packages = TempDir::new()?;
+        unzipped_wheel(&wheel)?;
+
+        let count = LinkMode::Copy.link_wheel_files(
+            site_packages.path(),
+            wheel.path(),
+            &Locks::default(),
+        )?;
+        assert_eq!(count, 4);
+        assert_installed(&wheel, &site_packages)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn hardlink() -> Result<()> {
+        let wheel = TempDir::new()?;
+        let site_packages = TempDir::new()?;
+        unzipped_wheel(&wheel)?;
+
+        LinkMode::Hardlink.link_wheel_files(
+            site_packages.path(),
+            wheel.path(),
+            &Locks::default(),
+        )?;
+        assert_installed(&wheel, &site_packages)?;
+
+        #[cfg(unix)]
+        {
+            assert!(same_file(
+                &wheel.child("foo/bar.py"),
+                &site_packages.child("foo/bar.py")
+            )?);
+
+            // The `RECORD` is modified during installation, so it's always copied.
+            assert!(!same_file(
+                &wheel.child("foo-1.0.0.dist-info/RECORD"),
+                &site_packages.child("foo-1.0.0.dist-info/RECORD")
+            )?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn symlink() -> Result<()> {
+        let wheel = TempDir::new()?;
+        let site_packages = TempDir::new()?;
+        unzipped_wheel(&wheel)?;
+
+        LinkMode::Symlink.link_wheel_files(
+            site_packages.path(),
+            wheel.path(),
+            &Locks::default(),
+        )?;
+        assert_installed(&wheel, &site_packages)?;
+
+        assert!(site_packages.child("foo/bar.py").is_symlink());
+        assert!(!site_packages
+            .child("foo-1.0.0.dist-info/RECORD")
+            .is_symlink());
+
+        Ok(())
+    }
+
+    /// If linking fails, all files are copied instead.
+    #[test]
+    fn copy_fallback() -> Result<()> {
+        let wheel = TempDir::new()?;
+        let site_packages = TempDir::new()?;
+        unzipped_wheel(&wheel)?;
+
+        link_wheel_files(
+            site_packages.path(),
+            wheel.path(),
+            &Locks::default(),
+            "hardlink",
+            |_, _| Err(std::io::Error::from(std::io::ErrorKind::Unsupported)),
+        )?;
+        assert_installed(&wheel, &site_packages)?;
+
+        #[cfg(unix)]
+        assert!(!same_file(
+            &wheel.child("foo/bar.py"),
+            &site_packages.child("foo/bar.py")
+        )?);
+
+        Ok(())
+    }
+
+    /// Existing files in site packages are overwritten, regardless of the link mode.
+    #[test]
+    fn overwrite_existing() -> Result<()> {
+        for link_mode in [LinkMode::Copy, LinkMode::Hardlink] {
+            let wheel = TempDir::new()?;
+            let site_packages = TempDir::new()?;
+            unzipped_wheel(&wheel)?;
+
+            site_packages
+                .child("foo/__init__.py")
+                .write_str("# An older version of the file, with different contents\n")?;
+            site_packages.child("foo/data.txt").write_str("stale\n")?;
+
+            link_mode.link_wheel_files(site_packages.path(), wheel.path(), &Locks::default())?;
+            assert_installed(&wheel, &site_packages)?;
+        }
+
+        Ok(())
+    }
+}
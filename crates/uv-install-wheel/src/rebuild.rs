@@ -0,0 +1,411 @@
+//! Install an import hook that rebuilds the compiled extension modules of an editable
+//! installation when their sources change.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::UNIX_EPOCH;
+
+use fs_err as fs;
+use fs_err::File;
+use serde::Serialize;
+use tracing::{debug, instrument};
+use walkdir::WalkDir;
+
+use uv_normalize::PackageName;
+
+use crate::vendor::write_record;
+use crate::wheel::{read_record_file, write_file_recorded};
+use crate::Error;
+
+/// The prefix of the files that make up the import hook for a given package.
+const HOOK_PREFIX: &str = "_uv_rebuild_";
+
+/// The Python source of the import hook.
+const HOOK_SOURCE: &str = include_str!("rebuild_hook.py");
+
+/// The file suffixes of native sources that, when modified, require the project to be rebuilt.
+const SOURCE_SUFFIXES: &[&str] = &[
+    ".c", ".h", ".cc", ".cpp", ".cxx", ".hh", ".hpp", ".hxx", ".pyx", ".pxd", ".pxi", ".rs", ".f",
+    ".f90", ".cu", ".m", ".mm",
+];
+
+/// The names of build configuration files that, when modified, require the project to be
+/// rebuilt.
+const SOURCE_FILES: &[&str] = &[
+    "Cargo.toml",
+    "Cargo.lock",
+    "CMakeLists.txt",
+    "meson.build",
+    "setup.py",
+];
+
+/// Directories that never contain native sources, and are skipped when scanning the project
+/// (along with any hidden directories).
+const EXCLUDED_DIRECTORIES: &[&str] = &[
+    "__pycache__",
+    "build",
+    "dist",
+    "node_modules",
+    "target",
+    "venv",
+];
+
+/// The configuration of the import hook, as read by `rebuild_hook.py`.
+#[derive(Debug, Serialize)]
+struct RebuildConfig<'a> {
+    /// The name of the project.
+    name: &'a PackageName,
+    /// The name of the project, as used in the name of its `.dist-info` directory.
+    dist_info_name: &'a str,
+    /// The root of the project's source tree.
+    root: &'a Path,
+    /// The top-level modules whose import triggers the staleness check.
+    modules: &'a [String],
+    /// The command to run to rebuild the project.
+    command: &'a [String],
+    /// The file suffixes of native sources.
+    suffixes: &'static [&'static str],
+    /// The names of build configuration files.
+    files: &'static [&'static str],
+    /// The names of directories to skip when scanning for sources.
+    exclude: &'static [&'static str],
+    /// The time of the last build, in seconds since the Unix epoch.
+    built: f64,
+}
+
+/// Returns `true` if the source tree at the given path contains native sources, i.e., if the
+/// project is likely to build compiled extension modules.
+pub fn has_native_sources(root: &Path) -> bool {
+    source_files(root).any(|path| {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| {
+                name != "setup.py" && (is_native_source(name) || SOURCE_FILES.contains(&name))
+            })
+    })
+}
+
+/// Install an import hook that rebuilds the given editable project when its native sources are
+/// modified after the installation at `dist_info`.
+///
+/// The hook consists of a `.pth` file, which imports a small Python module at interpreter
+/// startup, and a JSON file containing the hook's configuration. The files are added to the
+/// `RECORD` of the distribution, such that they're removed along with it; when the hook itself
+/// reinstalls the distribution, it writes (and records) its files again afterwards.
+#[instrument(skip_all, fields(name = %name))]
+pub fn install_rebuild_hook(
+    site_packages: &Path,
+    dist_info: &Path,
+    name: &PackageName,
+    root: &Path,
+    command: &[String],
+) -> Result<(), Error> {
+    let modules = top_level_modules(dist_info, name)?;
+
+    // The `RECORD` is modified below, so use the `METADATA` to determine the time of the build.
+    let built = fs::metadata(dist_info.join("METADATA"))?
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64())
+        .unwrap_or_default();
+
+    let config = RebuildConfig {
+        name,
+        dist_info_name: &name.as_dist_info_name(),
+        root,
+        modules: &modules,
+        command,
+        suffixes: SOURCE_SUFFIXES,
+        files: SOURCE_FILES,
+        exclude: EXCLUDED_DIRECTORIES,
+        built,
+    };
+
+    debug!(
+        "Installing rebuild hook for `{name}` (modules: {})",
+        modules.join(", ")
+    );
+    let stem = hook_stem(name);
+    let mut record = read_record_file(&mut File::open(dist_info.join("RECORD"))?)?;
+    record.retain(|entry| !is_hook_file(&entry.path, &stem));
+    write_file_recorded(
+        site_packages,
+        Path::new(&format!("{stem}.json")),
+        serde_json::to_string_pretty(&config).map_err(io::Error::other)?,
+        &mut record,
+    )?;
+    write_file_recorded(
+        site_packages,
+        Path::new(&format!("{stem}.py")),
+        HOOK_SOURCE,
+        &mut record,
+    )?;
+    write_file_recorded(
+        site_packages,
+        Path::new(&format!("{stem}.pth")),
+        format!("import {stem}\n"),
+        &mut record,
+    )?;
+    write_record(&dist_info.join("RECORD"), record)?;
+
+    Ok(())
+}
+
+/// Remove the import hook for the given project, if one is installed, along with its entries in
+/// the `RECORD` of any installed distribution of the project.
+///
+/// Returns `true` if a hook was removed.
+pub fn uninstall_rebuild_hook(site_packages: &Path, name: &PackageName) -> Result<bool, Error> {
+    let stem = hook_stem(name);
+    let mut removed = false;
+    for extension in ["pth", "py", "json"] {
+        match fs::remove_file(site_packages.join(format!("{stem}.{extension}"))) {
+            Ok(()) => removed = true,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    if removed {
+        let prefix = format!("{}-", name.as_dist_info_name());
+        for entry in fs::read_dir(site_packages)? {
+            let entry = entry?;
+            let is_dist_info = entry.file_name().to_str().is_some_and(|file_name| {
+                file_name.starts_with(&prefix) && file_name.ends_with(".dist-info")
+            });
+            if !is_dist_info {
+                continue;
+            }
+            let record_path = entry.path().join("RECORD");
+            let mut record = match File::open(&record_path) {
+                Ok(mut file) => read_record_file(&mut file)?,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err.into()),
+            };
+            let len = record.len();
+            record.retain(|entry| !is_hook_file(&entry.path, &stem));
+            if record.len() != len {
+                write_record(&record_path, record)?;
+            }
+        }
+    }
+
+    Ok(removed)
+}
+
+/// Return the names of the packages for which an import hook is installed in the given site
+/// packages directory.
+pub fn installed_rebuild_hooks(site_packages: &Path) -> io::Result<Vec<PackageName>> {
+    let mut names = Vec::new();
+    let entries = match fs::read_dir(site_packages) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(names),
+        Err(err) => return Err(err.into()),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        if !path.extension().is_some_and(|extension| extension == "pth") {
+            continue;
+        }
+        let Some(name) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.strip_prefix(HOOK_PREFIX))
+            .and_then(|name| PackageName::from_str(name).ok())
+        else {
+            continue;
+        };
+        names.push(name);
+    }
+    names.sort_unstable();
+    Ok(names)
+}
+
+/// Returns `true` if the `RECORD` entry at `path` is one of the files of the hook with the given
+/// stem.
+fn is_hook_file(path: &str, stem: &str) -> bool {
+    ["pth", "py", "json"]
+        .iter()
+        .any(|extension| path == format!("{stem}.{extension}"))
+}
+
+/// Returns the file stem shared by the files that make up the hook for the given package.
+fn hook_stem(name: &PackageName) -> String {
+    format!("{HOOK_PREFIX}{}", name.as_dist_info_name())
+}
+
+/// Returns the top-level modules of the installed distribution, as listed in its
+/// `top_level.txt`, falling back to the normalized package name.
+fn top_level_modules(dist_info: &Path, name: &PackageName) -> Result<Vec<String>, Error> {
+    let modules = match fs::read_to_string(dist_info.join("top_level.txt")) {
+        Ok(contents) => contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(ToString::to_string)
+            .collect(),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(err.into()),
+    };
+    if modules.is_empty() {
+        Ok(vec![name.as_dist_info_name().to_string()])
+    } else {
+        Ok(modules)
+    }
+}
+
+/// Returns `true` if the file name has the suffix of a native source.
+fn is_native_source(name: &str) -> bool {
+    SOURCE_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+}
+
+/// Iterate over the files in the source tree, skipping hidden and excluded directories.
+fn source_files(root: &Path) -> impl Iterator<Item = PathBuf> {
+    WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !entry.file_type().is_dir()
+                || entry.file_name().to_str().is_some_and(|name| {
+                    !name.starts_with('.') && !EXCLUDED_DIRECTORIES.contains(&name)
+                })
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn native_sources() -> Result<(), Error> {
+        let root = tempfile::tempdir()?;
+        fs::create_dir_all(root.path().join("src/example"))?;
+        fs::write(root.path().join("setup.py"), "")?;
+        fs::write(root.path().join("src/example/__init__.py"), "")?;
+        assert!(!has_native_sources(root.path()));
+
+        // Sources in excluded directories are ignored.
+        fs::create_dir_all(root.path().join("build"))?;
+        fs::write(root.path().join("build/generated.c"), "")?;
+        assert!(!has_native_sources(root.path()));
+
+        fs::write(root.path().join("src/example/_speedups.c"), "")?;
+        assert!(has_native_sources(root.path()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn install_and_uninstall() -> Result<(), Error> {
+        let name = PackageName::from_str("example-ext")?;
+        let root = tempfile::tempdir()?;
+        let site_packages = tempfile::tempdir()?;
+        let dist_info = site_packages.path().join("example_ext-0.1.0.dist-info");
+        fs::create_dir_all(&dist_info)?;
+        fs::write(dist_info.join("METADATA"), "")?;
+        fs::write(
+            dist_info.join("RECORD"),
+            "example/__init__.py,,\nexample_ext-0.1.0.dist-info/RECORD,,\n",
+        )?;
+        fs::write(dist_info.join("top_level.txt"), "example\n")?;
+
+        let command = ["uv".to_string(), "pip".to_string(), "install".to_string()];
+        install_rebuild_hook(
+            site_packages.path(),
+            &dist_info,
+            &name,
+            root.path(),
+            &command,
+        )?;
+        assert_eq!(
+            fs::read_to_string(site_packages.path().join("_uv_rebuild_example_ext.pth"))?,
+            "import _uv_rebuild_example_ext\n"
+        );
+        let config: serde_json::Value = serde_json::from_str(&fs::read_to_string(
+            site_packages.path().join("_uv_rebuild_example_ext.json"),
+        )?)?;
+        assert_eq!(config["modules"], serde_json::json!(["example"]));
+        assert_eq!(config["dist_info_name"], serde_json::json!("example_ext"));
+        assert!(config["built"].as_f64().is_some_and(|built| built > 0.0));
+        assert_eq!(
+            installed_rebuild_hooks(site_packages.path())?,
+            vec![name.clone()]
+        );
+
+        // The hook's files are recorded, exactly once, even if the hook is installed repeatedly.
+        install_rebuild_hook(
+            site_packages.path(),
+            &dist_info,
+            &name,
+            root.path(),
+            &command,
+        )?;
+        let record = read_record_file(&mut File::open(dist_info.join("RECORD"))?)?;
+        let paths = record
+            .iter()
+            .map(|entry| entry.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "_uv_rebuild_example_ext.json",
+                "_uv_rebuild_example_ext.pth",
+                "_uv_rebuild_example_ext.py",
+                "example/__init__.py",
+                "example_ext-0.1.0.dist-info/RECORD",
+            ]
+        );
+        assert!(record[..3].iter().all(|entry| entry.hash.is_some()));
+
+        // Uninstalling the hook removes its files and its `RECORD` entries.
+        assert!(uninstall_rebuild_hook(site_packages.path(), &name)?);
+        assert!(!site_packages
+            .path()
+            .join("_uv_rebuild_example_ext.py")
+            .exists());
+        let record = read_record_file(&mut File::open(dist_info.join("RECORD"))?)?;
+        assert_eq!(record.len(), 2);
+        assert!(installed_rebuild_hooks(site_packages.path())?.is_empty());
+        assert!(!uninstall_rebuild_hook(site_packages.path(), &name)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn uninstall_with_distribution() -> Result<(), Error> {
+        let name = PackageName::from_str("example-ext")?;
+        let root = tempfile::tempdir()?;
+        let site_packages = tempfile::tempdir()?;
+        let dist_info = site_packages.path().join("example_ext-0.1.0.dist-info");
+        fs::create_dir_all(&dist_info)?;
+        fs::write(dist_info.join("METADATA"), "")?;
+        fs::write(
+            dist_info.join("RECORD"),
+            "example_ext-0.1.0.dist-info/METADATA,,\nexample_ext-0.1.0.dist-info/RECORD,,\n",
+        )?;
+
+        install_rebuild_hook(
+            site_packages.path(),
+            &dist_info,
+            &name,
+            root.path(),
+            &["uv".to_string()],
+        )?;
+
+        // Uninstalling the distribution removes the hook along with it.
+        crate::uninstall_wheel(&dist_info)?;
+        assert!(installed_rebuild_hooks(site_packages.path())?.is_empty());
+        for extension in ["pth", "py", "json"] {
+            assert!(!site_packages
+                .path()
+                .join(format!("_uv_rebuild_example_ext.{extension}"))
+                .exists());
+        }
+
+        Ok(())
+    }
+}
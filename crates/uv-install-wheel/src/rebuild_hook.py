@@ -0,0 +1,162 @@
+"""Rebuild the compiled extension modules of an editable project when their sources change.
+
+This file is generated by uv, alongside a JSON configuration file of the same name, and is
+imported at interpreter startup via a `.pth` file. On the first import of any of the project's
+top-level modules, the native sources of the project are compared against the time of the last
+build; if any source is newer, the project is reinstalled before the import proceeds.
+
+The hook's files are listed in the `RECORD` of the project's distribution, so reinstalling the
+project removes them; after a rebuild, the hook writes (and records) them again.
+"""
+
+import base64
+import glob
+import hashlib
+import json
+import os
+import subprocess
+import sys
+import threading
+import time
+
+_STEM = os.path.splitext(os.path.abspath(__file__))[0]
+_CONFIG_PATH = _STEM + ".json"
+_DISABLE_VAR = "UV_NO_REBUILD_ON_IMPORT"
+
+
+class _RebuildFinder:
+    """A meta path finder that triggers a rebuild, but never finds any modules itself."""
+
+    def __init__(self, config):
+        self._config = config
+        self._modules = frozenset(config["modules"])
+        self._lock = threading.Lock()
+        self._checked = False
+
+    def find_spec(self, fullname, path=None, target=None):
+        if self._checked or fullname.partition(".")[0] not in self._modules:
+            return None
+        with self._lock:
+            if not self._checked:
+                self._checked = True
+                try:
+                    sys.meta_path.remove(self)
+                except ValueError:
+                    pass
+                _rebuild_if_stale(self._config)
+        return None
+
+
+def _newest_source(config):
+    suffixes = tuple(config["suffixes"])
+    files = frozenset(config["files"])
+    exclude = frozenset(config["exclude"])
+    newest = 0.0
+    for dirpath, dirnames, filenames in os.walk(config["root"]):
+        dirnames[:] = [
+            dirname
+            for dirname in dirnames
+            if not dirname.startswith(".") and dirname not in exclude
+        ]
+        for filename in filenames:
+            if not filename.endswith(suffixes) and filename not in files:
+                continue
+            try:
+                mtime = os.stat(os.path.join(dirpath, filename)).st_mtime
+            except OSError:
+                continue
+            newest = max(newest, mtime)
+    return newest
+
+
+def _rebuild_if_stale(config):
+    if _newest_source(config) <= config["built"]:
+        return
+
+    # The reinstallation removes the hook's files, so read its source beforehand.
+    try:
+        with open(_STEM + ".py", "rb") as file:
+            source = file.read()
+    except OSError:
+        source = None
+
+    name = config["name"]
+    sys.stderr.write(f"Rebuilding `{name}` after changes to its native sources\n")
+    started = time.time()
+    env = dict(os.environ)
+    env[_DISABLE_VAR] = "1"
+    try:
+        result = subprocess.run(config["command"], cwd=config["root"], env=env)
+    except OSError as err:
+        sys.stderr.write(f"warning: Failed to rebuild `{name}`: {err}\n")
+        return
+    if result.returncode != 0:
+        sys.stderr.write(
+            f"warning: Failed to rebuild `{name}` (exit status {result.returncode})\n"
+        )
+        return
+
+    # Record the time at which the rebuild started, such that any sources modified during the
+    # build are considered stale on the next run.
+    config["built"] = started
+    if source is not None:
+        try:
+            _reinstall_hook(config, source)
+        except OSError as err:
+            sys.stderr.write(
+                f"warning: Failed to reinstall the rebuild hook for `{name}`: {err}\n"
+            )
+
+    import importlib
+
+    importlib.invalidate_caches()
+
+
+def _reinstall_hook(config, source):
+    """Write the hook's files, and add them to the `RECORD` of the reinstalled distribution."""
+    site_packages = os.path.dirname(_STEM)
+    stem = os.path.basename(_STEM)
+    files = {
+        stem + ".json": json.dumps(config, indent=2).encode("utf-8"),
+        stem + ".py": source,
+        stem + ".pth": f"import {stem}\n".encode("utf-8"),
+    }
+    for path, contents in files.items():
+        with open(os.path.join(site_packages, path), "wb") as file:
+            file.write(contents)
+
+    pattern = os.path.join(
+        site_packages, glob.escape(config["dist_info_name"]) + "-*.dist-info"
+    )
+    for dist_info in glob.glob(pattern):
+        record = os.path.join(dist_info, "RECORD")
+        if not os.path.isfile(record):
+            continue
+        with open(record, encoding="utf-8") as file:
+            lines = [
+                line
+                for line in file.read().splitlines()
+                if line.split(",", 1)[0] not in files
+            ]
+        for path, contents in files.items():
+            digest = hashlib.sha256(contents).digest()
+            digest = base64.urlsafe_b64encode(digest).rstrip(b"=")
+            lines.append(f"{path},sha256={digest.decode('ascii')},{len(contents)}")
+        with open(record, "w", encoding="utf-8") as file:
+            file.write("\n".join(sorted(lines)) + "\n")
+
+
+def _install():
+    if os.environ.get(_DISABLE_VAR):
+        return
+    try:
+        with open(_CONFIG_PATH, encoding="utf-8") as file:
+            config = json.load(file)
+    except (OSError, ValueError):
+        return
+    if not os.path.isdir(config.get("root", "")):
+        return
+    sys.meta_path.insert(0, _RebuildFinder(config))
+
+
+_install()
@@ -0,0 +1,533 @@
+//! Install a wheel under an alias (i.e., a rewritten top-level module name), such that it can
+//! coexist with another version of the same package in a single environment.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use data_encoding::BASE64URL_NOPAD;
+use fs_err as fs;
+use fs_err::File;
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use tracing::{debug, instrument, trace};
+use walkdir::WalkDir;
+
+use uv_distribution_filename::WheelFilename;
+
+use crate::linker::find_dist_info;
+use crate::record::RecordEntry;
+use crate::wheel::{extra_dist_info, parse_wheel_file, read_record_file, write_file_recorded};
+use crate::{Error, Layout, LibKind};
+
+/// The file in the `.dist-info` directory of a vendored package that records the original
+/// top-level module name.
+const VENDORED_FILE: &str = "VENDORED";
+
+/// Install the given (unzipped) wheel under the given alias.
+///
+/// The wheel must contain a single, pure-Python top-level module or package, which is installed
+/// under the alias, with any absolute imports of the module rewritten to refer to the alias. The
+/// `.dist-info` directory is renamed to match, such that the vendored copy is recorded as a
+/// distinct distribution. Entrypoints and data files are not installed.
+///
+/// Returns the name of the original top-level module.
+#[instrument(skip_all, fields(wheel = %filename, alias = alias))]
+pub fn install_vendored_wheel(
+    layout: &Layout,
+    wheel: impl AsRef<Path>,
+    filename: &WheelFilename,
+    alias: &str,
+    installer: Option<&str>,
+) -> Result<String, Error> {
+    let wheel = wheel.as_ref();
+
+    if !is_valid_module_name(alias) {
+        return Err(Error::Vendor(format!(
+            "`{alias}` is not a valid Python module name"
+        )));
+    }
+
+    let dist_info_prefix = find_dist_info(wheel)?;
+    let wheel_text = fs::read_to_string(wheel.join(format!("{dist_info_prefix}.dist-info/WHEEL")))?;
+    if parse_wheel_file(&wheel_text)? != LibKind::Pure {
+        return Err(Error::Vendor(format!(
+            "`{filename}` is not a pure-Python wheel"
+        )));
+    }
+
+    let module = top_level_module(wheel, &dist_info_prefix)?;
+    let site_packages = &layout.scheme.purelib;
+    let mut record = Vec::new();
+
+    // Copy the module under the alias, rewriting any imports along the way.
+    trace!("Installing `{module}` as `{alias}`");
+    for entry in WalkDir::new(wheel).min_depth(1) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(wheel)
+            .expect("walkdir starts with root");
+        let Some(target) = rename_module_path(relative, &module, alias) else {
+            continue;
+        };
+        if relative
+            .components()
+            .any(|component| component.as_os_str() == "__pycache__")
+        {
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(site_packages.join(parent))?;
+        }
+        let contents = fs::read(entry.path())?;
+        let contents = if target.extension().is_some_and(|ext| ext == "py") {
+            match String::from_utf8(contents) {
+                Ok(source) => rewrite_imports(&source, &module, alias)
+                    .into_owned()
+                    .into_bytes(),
+                Err(err) => err.into_bytes(),
+            }
+        } else {
+            contents
+        };
+        write_file_recorded(site_packages, &target, contents, &mut record)?;
+    }
+
+    // Write the `.dist-info` directory under the alias.
+    let vendored_prefix = format!("{alias}-{}", filename.version);
+    let vendored_dist_info = PathBuf::from(format!("{vendored_prefix}.dist-info"));
+    fs::create_dir_all(site_packages.join(&vendored_dist_info))?;
+    for entry in fs::read_dir(wheel.join(format!("{dist_info_prefix}.dist-info")))? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        let file_name = entry.file_name();
+        let contents = match file_name.to_string_lossy().as_ref() {
+            // The `RECORD` file is rewritten below.
+            "RECORD" | "INSTALLER" | "REQUESTED" | "direct_url.json" => continue,
+            // Entrypoints would collide with those of the non-vendored package.
+            "entry_points.txt" => continue,
+            "METADATA" => rename_metadata(&fs::read_to_string(entry.path())?, alias).into_bytes(),
+            "top_level.txt" => format!("{alias}\n").into_bytes(),
+            _ => fs::read(entry.path())?,
+        };
+        write_file_recorded(
+            site_packages,
+            &vendored_dist_info.join(&file_name),
+            contents,
+            &mut record,
+        )?;
+    }
+    write_file_recorded(
+        site_packages,
+        &vendored_dist_info.join(VENDORED_FILE),
+        format!("{module}\n"),
+        &mut record,
+    )?;
+    extra_dist_info(
+        site_packages,
+        &vendored_prefix,
+        false,
+        None,
+        None,
+        None,
+        installer,
+        &mut record,
+    )?;
+
+    record.push(RecordEntry {
+        path: vendored_dist_info.join("RECORD").display().to_string(),
+        hash: None,
+        size: None,
+    });
+    write_record(
+        &site_packages.join(vendored_dist_info.join("RECORD")),
+        record,
+    )?;
+
+    Ok(module)
+}
+
+/// Read the original top-level module name of a vendored package from its installed
+/// `.dist-info` directory, if the package was installed by [`install_vendored_wheel`].
+pub fn vendored_module(dist_info: &Path) -> Result<Option<String>, Error> {
+    match fs::read_to_string(dist_info.join(VENDORED_FILE)) {
+        Ok(contents) => Ok(Some(contents.trim().to_string())),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Rewrite the imports of an installed distribution, such that any imports of `module` refer to
+/// `alias` instead.
+///
+/// Only the Python files listed in the distribution's `RECORD` are rewritten, and the `RECORD` is
+/// updated to reflect the new contents. Rewriting is idempotent.
+///
+/// Returns the number of files that were modified.
+pub fn rewrite_dependent_imports(
+    site_packages: &Path,
+    dist_info: &Path,
+    module: &str,
+    alias: &str,
+) -> Result<usize, Error> {
+    let record_path = dist_info.join("RECORD");
+    let mut record = read_record_file(&mut File::open(&record_path)?)?;
+
+    let mut modified = 0;
+    for entry in &mut record {
+        // Skip any files outside of `site-packages` (like scripts).
+        if !Path::new(&entry.path)
+            .extension()
+            .is_some_and(|ext| ext == "py")
+            || entry.path.starts_with("..")
+        {
+            continue;
+        }
+        let path = site_packages.join(&entry.path);
+        let Ok(source) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let Cow::Owned(rewritten) = rewrite_imports(&source, module, alias) else {
+            continue;
+        };
+        uv_fs::write_atomic_sync(&path, rewritten.as_bytes())?;
+
+        let hash = Sha256::new().chain_update(rewritten.as_bytes()).finalize();
+        entry.hash = Some(format!("sha256={}", BASE64URL_NOPAD.encode(&hash)));
+        entry.size = Some(rewritten.len() as u64);
+        modified += 1;
+    }
+
+    if modified > 0 {
+        debug!(
+            "Rewrote imports of `{module}` to `{alias}` in {modified} file{}",
+            if modified == 1 { "" } else { "s" }
+        );
+        write_record(&record_path, record)?;
+    }
+
+    Ok(modified)
+}
+
+/// Rewrite the absolute imports of `module` in the given Python source to refer to `alias`.
+///
+/// The rewritten imports continue to bind the original name, such that the remainder of the
+/// source is unaffected:
+///
+/// - `import six` becomes `import six_legacy as six`.
+/// - `import six.moves` becomes `import six_legacy.moves; six = six_legacy`.
+/// - `from six.moves import urllib` becomes `from six_legacy.moves import urllib`.
+///
+/// Imports are rewritten line-by-line; imports that span multiple lines via a backslash are left
+/// as-is.
+pub fn rewrite_imports<'a>(source: &'a str, module: &str, alias: &str) -> Cow<'a, str> {
+    let mut rewritten = String::with_capacity(source.len());
+    let mut modified = false;
+    for line in source.split_inclusive('\n') {
+        let (content, ending) = match line.strip_suffix("\r\n") {
+            Some(content) => (content, "\r\n"),
+            None => match line.strip_suffix('\n') {
+                Some(content) => (content, "\n"),
+                None => (line, ""),
+            },
+        };
+        if let Some(content) = rewrite_import_line(content, module, alias) {
+            rewritten.push_str(&content);
+            rewritten.push_str(ending);
+            modified = true;
+        } else {
+            rewritten.push_str(line);
+        }
+    }
+    if modified {
+        Cow::Owned(rewritten)
+    } else {
+        Cow::Borrowed(source)
+    }
+}
+
+/// Rewrite a single line of Python source, returning `None` if the line is unchanged.
+fn rewrite_import_line(line: &str, module: &str, alias: &str) -> Option<String> {
+    static IMPORT: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^(?P<indent>\s*)import\s+(?P<names>[^#;\\()]+?)(?P<trailing>\s*(?:#.*)?)$")
+            .unwrap()
+    });
+    static FROM_IMPORT: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^(?P<indent>\s*)from\s+(?P<module>\w+)(?P<rest>(?:\.[\w.]*)?\s+import\b.*)$")
+            .unwrap()
+    });
+
+    if let Some(captures) = FROM_IMPORT.captures(line) {
+        if &captures["module"] != module {
+            return None;
+        }
+        return Some(format!(
+            "{}from {alias}{}",
+            &captures["indent"], &captures["rest"]
+        ));
+    }
+
+    let captures = IMPORT.captures(line)?;
+    let mut rebind = false;
+    let mut modified = false;
+    let mut names = Vec::new();
+    for name in captures["names"].split(',') {
+        let tokens = name.split_whitespace().collect::<Vec<_>>();
+        let (path, asname) = match tokens.as_slice() {
+            [path] => (*path, None),
+            [path, "as", asname] => (*path, Some(*asname)),
+            _ => return None,
+        };
+        let (head, tail) = match path.split_once('.') {
+            Some((head, tail)) => (head, Some(tail)),
+            None => (path, None),
+        };
+        if head != module {
+            names.push(name.trim().to_string());
+            continue;
+        }
+        modified = true;
+        names.push(match (tail, asname) {
+            (None, None) => format!("{alias} as {module}"),
+            (None, Some(asname)) => format!("{alias} as {asname}"),
+            (Some(tail), Some(asname)) => format!("{alias}.{tail} as {asname}"),
+            (Some(tail), None) => {
+                rebind = true;
+                format!("{alias}.{tail}")
+            }
+        });
+    }
+    if !modified {
+        return None;
+    }
+
+    let mut rewritten = format!("{}import {}", &captures["indent"], names.join(", "));
+    if rebind {
+        write!(rewritten, "; {module} = {alias}").unwrap();
+    }
+    rewritten.push_str(&captures["trailing"]);
+    Some(rewritten)
+}
+
+/// Determine the single top-level module or package in an unzipped wheel.
+fn top_level_module(wheel: &Path, dist_info_prefix: &str) -> Result<String, Error> {
+    let mut modules = Vec::new();
+    for entry in fs::read_dir(wheel)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name == format!("{dist_info_prefix}.dist-info")
+            || file_name == format!("{dist_info_prefix}.data")
+            || file_name == "__pycache__"
+        {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            modules.push(file_name);
+        } else if let Some(stem) = file_name.strip_suffix(".py") {
+            modules.push(stem.to_string());
+        } else {
+            return Err(Error::Vendor(format!(
+                "unsupported top-level file: `{file_name}`"
+            )));
+        }
+    }
+    match modules.as_slice() {
+        [module] => Ok(module.clone()),
+        [] => Err(Error::Vendor("no top-level module found".to_string())),
+        modules => Err(Error::Vendor(format!(
+            "expected a single top-level module, but found: {}",
+            modules
+                .iter()
+                .map(|module| format!("`{module}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
+
+/// Map a path in the wheel to its path under the alias, or `None` if the path isn't part of the
+/// top-level module.
+fn rename_module_path(relative: &Path, module: &str, alias: &str) -> Option<PathBuf> {
+    let mut components = relative.components();
+    let first = components.next()?.as_os_str().to_str()?;
+    if first == module {
+        Some(Path::new(alias).join(components.as_path()))
+    } else if first == format!("{module}.py") {
+        Some(PathBuf::from(format!("{alias}.py")))
+    } else {
+        None
+    }
+}
+
+/// Replace the `Name` field in the core metadata with the alias.
+fn rename_metadata(metadata: &str, alias: &str) -> String {
+    let mut renamed = String::with_capacity(metadata.len());
+    let mut in_headers = true;
+    for line in metadata.split_inclusive('\n') {
+        if in_headers && line.trim_end().is_empty() {
+            in_headers = false;
+        }
+        if in_headers && line.starts_with("Name:") {
+            writeln!(renamed, "Name: {alias}").unwrap();
+        } else {
+            renamed.push_str(line);
+        }
+    }
+    renamed
+}
+
+/// Returns `true` if the string is a valid Python module name (restricted to ASCII).
+pub fn is_valid_module_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Write the `RECORD` file for a distribution.
+pub(crate) fn write_record(path: &Path, mut record: Vec<RecordEntry>) -> Result<(), Error> {
+    let mut record_writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .escape(b'"')
+        .from_path(path)?;
+    record.sort();
+    for entry in record {
+        record_writer.serialize(entry)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrite() {
+        let source = indoc::indoc! {r"
+            import six
+            import os, six.moves  # comment
+            import six.moves.urllib as urllib
+            from six import PY2
+            from six.moves import (
+                range,
+            )
+            from sixty import x
+            import sixty
+            from . import six
+            try:
+                import six as _six
+            except ImportError:
+                pass
+        "};
+        assert_eq!(
+            rewrite_imports(source, "six", "six_legacy"),
+            indoc::indoc! {r"
+                import six_legacy as six
+                import os, six_legacy.moves; six = six_legacy  # comment
+                import six_legacy.moves.urllib as urllib
+                from six_legacy import PY2
+                from six_legacy.moves import (
+                    range,
+                )
+                from sixty import x
+                import sixty
+                from . import six
+                try:
+                    import six_legacy as _six
+                except ImportError:
+                    pass
+            "}
+        );
+    }
+
+    #[test]
+    fn rewrite_idempotent() {
+        let source = "import six\nimport six.moves\nfrom six import PY2\n";
+        let once = rewrite_imports(source, "six", "six_legacy").into_owned();
+        assert!(matches!(
+            rewrite_imports(&once, "six", "six_legacy"),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn install() -> Result<(), Error> {
+        use std::str::FromStr;
+
+        use uv_pypi_types::Scheme;
+
+        let wheel = tempfile::tempdir()?;
+        let dist_info = wheel.path().join("six-1.10.0.dist-info");
+        fs::create_dir_all(&dist_info)?;
+        fs::write(
+            wheel.path().join("six.py"),
+            "import six.moves\nfrom six import PY2\n",
+        )?;
+        fs::write(
+            dist_info.join("METADATA"),
+            "Metadata-Version: 2.1\nName: six\nVersion: 1.10.0\n",
+        )?;
+        fs::write(
+            dist_info.join("WHEEL"),
+            "Wheel-Version: 1.0\nRoot-Is-Purelib: true\n",
+        )?;
+        fs::write(dist_info.join("top_level.txt"), "six\n")?;
+        fs::write(dist_info.join("RECORD"), "")?;
+
+        let site_packages = tempfile::tempdir()?;
+        let layout = Layout {
+            sys_executable: PathBuf::from("python"),
+            python_version: (3, 12),
+            os_name: "posix".to_string(),
+            scheme: Scheme {
+                purelib: site_packages.path().to_path_buf(),
+                platlib: site_packages.path().to_path_buf(),
+                scripts: site_packages.path().join("bin"),
+                data: site_packages.path().to_path_buf(),
+                include: site_packages.path().join("include"),
+            },
+            install_scripts: true,
+        };
+        let filename = WheelFilename::from_str("six-1.10.0-py2.py3-none-any.whl")?;
+
+        let module = install_vendored_wheel(&layout, wheel.path(), &filename, "six_legacy", None)?;
+        assert_eq!(module, "six");
+        assert_eq!(
+            fs::read_to_string(site_packages.path().join("six_legacy.py"))?,
+            "import six_legacy.moves; six = six_legacy\nfrom six_legacy import PY2\n"
+        );
+
+        let vendored_dist_info = site_packages.path().join("six_legacy-1.10.0.dist-info");
+        assert_eq!(
+            fs::read_to_string(vendored_dist_info.join("METADATA"))?,
+            "Metadata-Version: 2.1\nName: six_legacy\nVersion: 1.10.0\n"
+        );
+        assert_eq!(
+            vendored_module(&vendored_dist_info)?.as_deref(),
+            Some("six")
+        );
+
+        let record = read_record_file(&mut File::open(vendored_dist_info.join("RECORD"))?)?;
+        assert!(record.iter().any(|entry| entry.path == "six_legacy.py"));
+        assert!(!site_packages.path().join("six.py").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn metadata() {
+        let metadata = "Metadata-Version: 2.1\nName: six\nVersion: 1.10.0\n\nName: not a header\n";
+        assert_eq!(
+            rename_metadata(metadata, "six_legacy"),
+            "Metadata-Version: 2.1\nName: six_legacy\nVersion: 1.10.0\n\nName: not a header\n"
+        );
+    }
+}
@@ -20,7 +20,7 @@ use uv_trampoline_builder::windows_script_launcher;
 
 use crate::record::RecordEntry;
 use crate::script::Script;
-use crate::{Error, Layout};
+use crate::{Error, Layout, ScriptLauncher};
 
 /// Wrapper script template function
 ///
@@ -73,7 +73,10 @@ pub(crate) fn read_scripts_from_section(
 /// <https://github.com/richo/hashing-copy/blob/d8dd2fdb63c6faf198de0c9e5713d6249cbb5323/src/lib.rs#L10-L52>
 /// which in turn got it from std
 /// <https://doc.rust-lang.org/1.58.0/src/std/io/copy.rs.html#128-156>
-fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<(u64, String)> {
+pub(crate) fn copy_and_hash(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> io::Result<(u64, String)> {
     // TODO: Do we need to support anything besides sha256?
     let mut hasher = Sha256::new();
     // Same buf size as std. Note that this number is important for performance
@@ -178,9 +181,11 @@ pub(crate) fn write_script_entrypoints(
     entrypoints: &[Script],
     record: &mut Vec<RecordEntry>,
     is_gui: bool,
+    launcher: &ScriptLauncher,
 ) -> Result<(), Error> {
     for entrypoint in entrypoints {
         let entrypoint_absolute = entrypoint_path(entrypoint, layout);
+        let is_gui = launcher.is_gui(&entrypoint.name, is_gui);
 
         let entrypoint_relative = pathdiff::diff_paths(&entrypoint_absolute, site_packages)
             .ok_or_else(|| {
@@ -207,7 +212,12 @@ pub(crate) fn write_script_entrypoints(
             write_file_recorded(
                 site_packages,
                 &entrypoint_relative,
-                &windows_script_launcher(&launcher_python_script, is_gui, &launcher_executable)?,
+                &windows_script_launcher(
+                    &launcher_python_script,
+                    is_gui,
+                    &launcher_executable,
+                    launcher.options(),
+                )?,
                 record,
             )?;
         } else {
@@ -525,7 +535,7 @@ pub(crate) fn install_data(
     dist_name: &PackageName,
     console_scripts: &[Script],
     gui_scripts: &[Script],
-    record: &mut [RecordEntry],
+    record: &mut Vec<RecordEntry>,
 ) -> Result<(), Error> {
     for entry in fs::read_dir(data_dir)? {
         let entry = entry?;
@@ -536,6 +546,14 @@ pub(crate) fn install_data(
                 // Move the content of the folder to the root of the venv
                 move_folder_recorded(&path, &layout.scheme.data, site_packages, record)?;
             }
+            Some("scripts") if !layout.install_scripts => {
+                // Drop the scripts, which are removed along with the `.data` directory, from the
+                // RECORD.
+                let relative = path
+                    .strip_prefix(site_packages)
+                    .expect("Data directory must be within site-packages");
+                record.retain(|entry| !Path::new(&entry.path).starts_with(relative));
+            }
             Some("scripts") => {
                 let mut initialized = false;
                 for file in fs::read_dir(path)? {
@@ -615,13 +633,15 @@ pub(crate) fn write_file_recorded(
     Ok(())
 }
 
-/// Adds `INSTALLER`, `REQUESTED` and `direct_url.json` to the .dist-info dir
+/// Adds `INSTALLER`, `REQUESTED` and `direct_url.json` to the .dist-info dir, along with
+/// `uv_cache.json` and `uv_index.json` (the index from which the wheel was installed), if known.
 pub(crate) fn extra_dist_info(
     site_packages: &Path,
     dist_info_prefix: &str,
     requested: bool,
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&CacheInfo>,
+    index: Option<&str>,
     installer: Option<&str>,
     record: &mut Vec<RecordEntry>,
 ) -> Result<(), Error> {
@@ -645,6 +665,14 @@ pub(crate) fn extra_dist_info(
             record,
         )?;
     }
+    if let Some(index) = index {
+        write_file_recorded(
+            site_packages,
+            &dist_info_dir.join("uv_index.json"),
+            serde_json::to_string(&serde_json::json!({ "url": index }))?.as_bytes(),
+            record,
+        )?;
+    }
     if let Some(installer) = installer {
         write_file_recorded(
             site_packages,
@@ -14,6 +14,7 @@ use tokio::sync::oneshot;
 use tracing::{debug, instrument};
 use walkdir::WalkDir;
 
+use uv_configuration::PycInvalidationMode;
 use uv_fs::Simplified;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
@@ -69,6 +70,68 @@ pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
     cache: &Path,
+    invalidation_mode: Option<PycInvalidationMode>,
+) -> Result<usize, CompileError> {
+    let source_files = WalkDir::new(dir)
+        .into_iter()
+        // Otherwise we stumble over temporary files from `compileall`.
+        .filter_entry(|dir| dir.file_name() != "__pycache__")
+        .filter_map(|entry| {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => return Some(Err(CompileError::Walkdir(err))),
+            };
+            // https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L593-L604
+            match entry.metadata() {
+                Ok(metadata)
+                    if metadata.is_file()
+                        && entry.path().extension().is_some_and(|ext| ext == "py") =>
+                {
+                    Some(Ok(entry.into_path()))
+                }
+                Ok(_) => None,
+                Err(err) => Some(Err(CompileError::Walkdir(err))),
+            }
+        });
+    compile_source_files(
+        dir,
+        source_files,
+        python_executable,
+        cache,
+        invalidation_mode,
+    )
+    .await
+}
+
+/// Bytecode compile the given `.py` files, all of which must be located within `dir`.
+///
+/// Like [`compile_tree`], but limited to a known set of files, e.g., those belonging to a
+/// subset of the installed packages.
+#[instrument(skip(files, python_executable))]
+pub async fn compile_files(
+    dir: &Path,
+    files: Vec<PathBuf>,
+    python_executable: &Path,
+    cache: &Path,
+    invalidation_mode: Option<PycInvalidationMode>,
+) -> Result<usize, CompileError> {
+    compile_source_files(
+        dir,
+        files.into_iter().map(Ok),
+        python_executable,
+        cache,
+        invalidation_mode,
+    )
+    .await
+}
+
+/// Send each of the `source_files` to a pool of bytecode compilation workers.
+async fn compile_source_files(
+    dir: &Path,
+    source_files: impl Iterator<Item = Result<PathBuf, CompileError>>,
+    python_executable: &Path,
+    cache: &Path,
+    invalidation_mode: Option<PycInvalidationMode>,
 ) -> Result<usize, CompileError> {
     debug_assert!(
         dir.is_absolute(),
@@ -95,6 +158,7 @@ pub async fn compile_tree(
             dir.to_path_buf(),
             python_executable.to_path_buf(),
             pip_compileall_py.clone(),
+            invalidation_mode,
             receiver.clone(),
         );
 
@@ -122,25 +186,18 @@ pub async fn compile_tree(
     drop(receiver);
 
     // Start the producer, sending all `.py` files to workers.
-    let mut source_files = 0;
+    let mut source_files_count = 0;
     let mut send_error = None;
-    let walker = WalkDir::new(dir)
-        .into_iter()
-        // Otherwise we stumble over temporary files from `compileall`.
-        .filter_entry(|dir| dir.file_name() != "__pycache__");
-    for entry in walker {
-        let entry = entry?;
-        // https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L593-L604
-        if entry.metadata()?.is_file() && entry.path().extension().is_some_and(|ext| ext == "py") {
-            source_files += 1;
-            if let Err(err) = sender.send(entry.path().to_owned()).await {
-                // The workers exited.
-                // If e.g. something with the Python interpreter is wrong, the workers have exited
-                // with an error. We try to report this informative error and only if that fails,
-                // report the send error.
-                send_error = Some(err);
-                break;
-            }
+    for source_file in source_files {
+        let source_file = source_file?;
+        source_files_count += 1;
+        if let Err(err) = sender.send(source_file).await {
+            // The workers exited.
+            // If e.g. something with the Python interpreter is wrong, the workers have exited
+            // with an error. We try to report this informative error and only if that fails,
+            // report the send error.
+            send_error = Some(err);
+            break;
         }
     }
 
@@ -165,13 +222,14 @@ pub async fn compile_tree(
         return Err(CompileError::WorkerDisappeared(send_error));
     }
 
-    Ok(source_files)
+    Ok(source_files_count)
 }
 
 async fn worker(
     dir: PathBuf,
     interpreter: PathBuf,
     pip_compileall_py: PathBuf,
+    invalidation_mode: Option<PycInvalidationMode>,
     receiver: Receiver<PathBuf>,
 ) -> Result<(), CompileError> {
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
@@ -186,7 +244,8 @@ async fn worker(
         loop {
             // If the interpreter started successful, return it, else retry.
             if let Some(child) =
-                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
+                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py, invalidation_mode)
+                    .await?
             {
                 break Ok::<_, CompileError>(child);
             }
@@ -250,6 +309,7 @@ async fn launch_bytecode_compiler(
     dir: &Path,
     interpreter: &Path,
     pip_compileall_py: &Path,
+    invalidation_mode: Option<PycInvalidationMode>,
 ) -> Result<
     Option<(
         Child,
@@ -260,16 +320,23 @@ async fn launch_bytecode_compiler(
     CompileError,
 > {
     // We input the paths through stdin and get the successful paths returned through stdout.
-    let mut bytecode_compiler = Command::new(interpreter)
+    let mut command = Command::new(interpreter);
+    command
         .arg(pip_compileall_py)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .current_dir(dir)
         // Otherwise stdout is buffered and we'll wait forever for a response
-        .env(EnvVars::PYTHONUNBUFFERED, "1")
-        .spawn()
-        .map_err(CompileError::PythonSubcommand)?;
+        .env(EnvVars::PYTHONUNBUFFERED, "1");
+    // If unset, the script falls back to Python's default, which respects `SOURCE_DATE_EPOCH`.
+    if let Some(invalidation_mode) = invalidation_mode {
+        command.env(
+            EnvVars::PYC_INVALIDATION_MODE,
+            invalidation_mode.as_py_compile(),
+        );
+    }
+    let mut bytecode_compiler = command.spawn().map_err(CompileError::PythonSubcommand)?;
 
     // https://stackoverflow.com/questions/49218599/write-to-child-process-stdin-in-rust/49597789#comment120223107_49597789
     // Unbuffered, we need to write immediately or the python process will get stuck waiting
@@ -0,0 +1,94 @@
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{Context, Result};
+use fs_err as fs;
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+use tracing::debug;
+
+use uv_distribution_types::InstalledDist;
+use uv_fs::Simplified;
+
+/// The installed distributions in an environment that are managed by conda.
+///
+/// conda records the files of each package it installs in a `conda-meta/<package>.json` file
+/// within the environment prefix. Distributions that appear in those records (or that were
+/// marked as installed by conda via their `INSTALLER` file) must not be removed or replaced by
+/// uv, as doing so would leave conda's view of the environment inconsistent.
+#[derive(Debug, Default)]
+pub(crate) struct CondaPackages {
+    /// The absolute paths to the `.dist-info` and `.egg-info` directories installed by conda.
+    paths: FxHashSet<PathBuf>,
+}
+
+/// A package record in the `conda-meta` directory.
+#[derive(Debug, Deserialize)]
+struct CondaMeta {
+    #[serde(default)]
+    files: Vec<PathBuf>,
+}
+
+impl CondaPackages {
+    /// Read the conda package records for the environment at the given prefix, if any.
+    pub(crate) fn from_prefix(prefix: &Path) -> Result<Self> {
+        let entries = match fs::read_dir(prefix.join("conda-meta")) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err).context("Failed to read `conda-meta` directory"),
+        };
+
+        let mut paths = FxHashSet::default();
+        for entry in entries {
+            let path = entry?.path();
+            if !path.extension().is_some_and(|ext| ext == "json") {
+                continue;
+            }
+            let meta = match serde_json::from_str::<CondaMeta>(&fs::read_to_string(&path)?) {
+                Ok(meta) => meta,
+                Err(err) => {
+                    debug!(
+                        "Ignoring invalid conda package record `{}`: {err}",
+                        path.user_display()
+                    );
+                    continue;
+                }
+            };
+            for file in meta.files {
+                if let Some(metadata) = metadata_directory(&file) {
+                    paths.insert(prefix.join(metadata));
+                }
+            }
+        }
+
+        Ok(Self { paths })
+    }
+
+    /// Returns `true` if the given distribution is managed by conda.
+    pub(crate) fn contains(&self, dist: &InstalledDist) -> bool {
+        if self.paths.contains(dist.path()) {
+            return true;
+        }
+        dist.installer()
+            .ok()
+            .flatten()
+            .is_some_and(|installer| installer.trim() == "conda")
+    }
+}
+
+/// Return the `.dist-info` or `.egg-info` directory that contains the given file, if any.
+fn metadata_directory(file: &Path) -> Option<PathBuf> {
+    let mut directory = PathBuf::new();
+    for component in file.components() {
+        let Component::Normal(name) = component else {
+            return None;
+        };
+        directory.push(name);
+        if Path::new(name)
+            .extension()
+            .is_some_and(|ext| ext == "dist-info" || ext == "egg-info")
+        {
+            return Some(directory);
+        }
+    }
+    None
+}
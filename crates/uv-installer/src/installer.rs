@@ -3,7 +3,7 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::convert;
 use tokio::sync::oneshot;
 use tracing::instrument;
-use uv_install_wheel::{linker::LinkMode, Layout};
+use uv_install_wheel::{linker::LinkMode, Layout, ScriptLauncher};
 
 use uv_cache::Cache;
 use uv_distribution_types::CachedDist;
@@ -12,6 +12,7 @@ use uv_python::PythonEnvironment;
 pub struct Installer<'a> {
     venv: &'a PythonEnvironment,
     link_mode: LinkMode,
+    script_launcher: ScriptLauncher,
     cache: Option<&'a Cache>,
     reporter: Option<Box<dyn Reporter>>,
     installer_name: Option<String>,
@@ -23,6 +24,7 @@ impl<'a> Installer<'a> {
         Self {
             venv,
             link_mode: LinkMode::default(),
+            script_launcher: ScriptLauncher::default(),
             cache: None,
             reporter: None,
             installer_name: Some("uv".to_string()),
@@ -35,6 +37,15 @@ impl<'a> Installer<'a> {
         Self { link_mode, ..self }
     }
 
+    /// Set the [`ScriptLauncher`] options to use for entry points on Windows.
+    #[must_use]
+    pub fn with_script_launcher(self, script_launcher: ScriptLauncher) -> Self {
+        Self {
+            script_launcher,
+            ..self
+        }
+    }
+
     /// Set the [`Cache`] to use for this installer.
     #[must_use]
     pub fn with_cache(self, cache: &'a Cache) -> Self {
@@ -69,6 +80,7 @@ impl<'a> Installer<'a> {
             venv,
             cache,
             link_mode,
+            script_launcher,
             reporter,
             installer_name,
         } = self;
@@ -85,12 +97,14 @@ impl<'a> Installer<'a> {
 
         let layout = venv.interpreter().layout();
         let relocatable = venv.relocatable();
+        let script_launcher = script_launcher.with_default_arch(venv.interpreter().arch());
         rayon::spawn(move || {
             let result = install(
                 wheels,
                 layout,
                 installer_name,
                 link_mode,
+                &script_launcher,
                 reporter,
                 relocatable,
             );
@@ -120,6 +134,9 @@ impl<'a> Installer<'a> {
             self.venv.interpreter().layout(),
             self.installer_name,
             self.link_mode,
+            &self
+                .script_launcher
+                .with_default_arch(self.venv.interpreter().arch()),
             self.reporter,
             self.venv.relocatable(),
         )
@@ -133,6 +150,7 @@ fn install(
     layout: Layout,
     installer_name: Option<String>,
     link_mode: LinkMode,
+    script_launcher: &ScriptLauncher,
     reporter: Option<Box<dyn Reporter>>,
     relocatable: bool,
 ) -> Result<Vec<CachedDist>> {
@@ -154,8 +172,13 @@ fn install(
             } else {
                 Some(wheel.cache_info())
             },
+            wheel
+                .index()
+                .map(|index| index.redacted().to_string())
+                .as_deref(),
             installer_name.as_deref(),
             link_mode,
+            script_launcher,
             &locks,
         )
         .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
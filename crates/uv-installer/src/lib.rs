@@ -1,4 +1,4 @@
-pub use compile::{compile_tree, CompileError};
+pub use compile::{compile_files, compile_tree, CompileError};
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
 pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter};
@@ -6,6 +6,7 @@ pub use site_packages::{SatisfiesResult, SitePackages, SitePackagesDiagnostic};
 pub use uninstall::{uninstall, UninstallError};
 
 mod compile;
+mod conda;
 mod preparer;
 
 mod installer;
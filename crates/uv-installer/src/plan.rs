@@ -17,6 +17,7 @@ use uv_pypi_types::Requirement;
 use uv_python::PythonEnvironment;
 use uv_types::HashStrategy;
 
+use crate::conda::CondaPackages;
 use crate::satisfies::RequirementSatisfaction;
 use crate::SitePackages;
 
@@ -64,8 +65,15 @@ impl<'a> Planner<'a> {
         let mut reinstalls = vec![];
         let mut extraneous = vec![];
 
+        // Identify the distributions that are managed by conda, which should never be modified.
+        let conda = CondaPackages::from_prefix(venv.interpreter().sys_prefix())?;
+
         for dist in self.resolution.distributions() {
             // Check if the package should be reinstalled.
+            let explicit = matches!(
+                reinstall,
+                Reinstall::Packages(packages) if packages.contains(dist.name())
+            );
             let reinstall = match reinstall {
                 Reinstall::None => false,
                 Reinstall::All => true,
@@ -80,32 +88,48 @@ impl<'a> Planner<'a> {
 
             // Determine whether the distribution is already installed.
             let installed_dists = site_packages.remove_packages(&requirement.name);
-            if reinstall {
-                reinstalls.extend(installed_dists);
+            let replaced = if reinstall {
+                installed_dists
             } else {
                 match installed_dists.as_slice() {
-                    [] => {}
+                    [] => vec![],
                     [distribution] => {
                         match RequirementSatisfaction::check(distribution, &requirement.source)? {
                             RequirementSatisfaction::Mismatch => {
                                 debug!("Requirement installed, but mismatched: {distribution:?}");
                             }
                             RequirementSatisfaction::Satisfied => {
-                                debug!("Requirement already installed: {distribution}");
-                                continue;
+                                if is_rebuilt(distribution, dist) {
+                                    debug!("Requirement installed, but rebuilt: {distribution}");
+                                } else {
+                                    debug!("Requirement already installed: {distribution}");
+                                    continue;
+                                }
                             }
                             RequirementSatisfaction::OutOfDate => {
                                 debug!("Requirement installed, but not fresh: {distribution}");
                             }
                         }
-                        reinstalls.push(distribution.clone());
+                        installed_dists
                     }
                     // We reinstall installed distributions with multiple versions because
                     // we do not want to keep multiple incompatible versions but removing
                     // one version is likely to break another.
-                    _ => reinstalls.extend(installed_dists),
+                    _ => installed_dists,
+                }
+            };
+
+            // Refuse to replace a distribution managed by conda, unless the user explicitly
+            // requested a reinstall of that package.
+            if let Some(distribution) = replaced.iter().find(|dist| conda.contains(dist)) {
+                if !explicit {
+                    bail!(
+                        "`{distribution}` is managed by conda, and can't be replaced with `{dist}`. Use `conda install` to change it, or pass `--reinstall-package {}` to replace it anyway.",
+                        dist.name()
+                    );
                 }
             }
+            reinstalls.extend(replaced);
 
             let ResolvedDist::Installable(installable) = dist else {
                 unreachable!("Installed distribution could not be found in site-packages: {dist}");
@@ -329,6 +353,11 @@ impl<'a> Planner<'a> {
                     continue;
                 }
 
+                if conda.contains(&dist_info) {
+                    debug!("Preserving conda-managed package: {dist_info}");
+                    continue;
+                }
+
                 debug!("Unnecessary package: {dist_info}");
                 extraneous.push(dist_info);
             }
@@ -361,3 +390,27 @@ pub struct Plan {
     /// _not_ necessary to satisfy the requirements.
     pub extraneous: Vec<InstalledDist>,
 }
+
+/// Returns `true` if the installed distribution was installed from a wheel in a local registry
+/// (e.g., a `--find-links` directory) that has since been rebuilt in-place.
+fn is_rebuilt(installed: &InstalledDist, dist: &ResolvedDist) -> bool {
+    let ResolvedDist::Installable(Dist::Built(BuiltDist::Registry(wheel))) = dist else {
+        return false;
+    };
+    let InstalledDist::Registry(installed) = installed else {
+        return false;
+    };
+    let Some(cache_info) = installed.cache_info.as_ref() else {
+        return false;
+    };
+    let Ok(url) = wheel.best_wheel().file.url.to_url() else {
+        return false;
+    };
+    if url.scheme() != "file" {
+        return false;
+    }
+    let Ok(path) = url.to_file_path() else {
+        return false;
+    };
+    CacheInfo::from_path(&path).is_ok_and(|current| current != *cache_info)
+}
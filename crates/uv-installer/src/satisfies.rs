@@ -97,6 +97,7 @@ impl RequirementSatisfaction {
             RequirementSource::Git {
                 url: _,
                 repository: requested_repository,
+                backend: requested_backend,
                 reference: requested_reference,
                 precise: requested_precise,
                 subdirectory: requested_subdirectory,
@@ -109,7 +110,7 @@ impl RequirementSatisfaction {
                     url: installed_url,
                     vcs_info:
                         VcsInfo {
-                            vcs: VcsKind::Git,
+                            vcs: installed_vcs,
                             requested_revision: installed_reference,
                             commit_id: _,
                         },
@@ -119,6 +120,14 @@ impl RequirementSatisfaction {
                     return Ok(Self::Mismatch);
                 };
 
+                if *installed_vcs != VcsKind::from(*requested_backend) {
+                    debug!(
+                        "VCS mismatch: {:?} vs. {:?}",
+                        installed_vcs, requested_backend
+                    );
+                    return Ok(Self::Mismatch);
+                }
+
                 if requested_subdirectory != installed_subdirectory {
                     debug!(
                         "Subdirectory mismatch: {:?} vs. {:?}",
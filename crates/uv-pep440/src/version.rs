@@ -166,6 +166,75 @@ impl std::fmt::Display for Operator {
     }
 }
 
+/// A structured operation for incrementing a component of a [`Version`].
+///
+/// See [`Version::bump`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BumpCommand {
+    /// Increment the epoch, e.g., `1.2.3` → `1!1.2.3`.
+    Epoch,
+    /// Increment the release segment at the given index, resetting all subsequent segments to
+    /// zero and removing any pre-, post-, and dev-release, e.g., `1.2.3rc1` → `1.3.0` for
+    /// index `1`.
+    ///
+    /// If the release has fewer segments than the index, it is padded with zeros.
+    Release {
+        /// The index of the release segment to increment (e.g., `0` for the major version).
+        index: usize,
+    },
+    /// Increment (or begin) a pre-release of the given kind, removing any post- or dev-release,
+    /// e.g., `1.2.0a1` → `1.2.0a2`, or `1.2.0a2` → `1.2.0rc1`.
+    ///
+    /// Moving to an earlier kind of pre-release (e.g., from a release candidate to an alpha) is
+    /// an error.
+    Pre {
+        /// The kind of pre-release.
+        kind: PrereleaseKind,
+    },
+    /// Increment the existing pre-release, preserving its kind, e.g., `1.2.0b1` → `1.2.0b2`.
+    ///
+    /// Bumping a version that is not a pre-release is an error.
+    Prerelease,
+    /// Increment (or begin) a post-release, removing any dev-release, e.g., `1.2.0` →
+    /// `1.2.0.post1`.
+    Post,
+    /// Increment (or begin) a dev-release, e.g., `1.2.0.dev1` → `1.2.0.dev2`.
+    Dev,
+    /// Remove any pre-, post-, and dev-release, e.g., `1.2.0rc1` → `1.2.0`.
+    Stable,
+}
+
+/// An error that occurs when a [`BumpCommand`] can't be applied to a [`Version`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionBumpError {
+    /// The version is not a pre-release, and so the pre-release can't be incremented.
+    NotAPrerelease(Version),
+    /// The version is already a later kind of pre-release than the one requested.
+    PrereleaseRegression {
+        /// The version that was bumped.
+        version: Version,
+        /// The kind of pre-release that was requested.
+        kind: PrereleaseKind,
+    },
+}
+
+impl std::error::Error for VersionBumpError {}
+
+impl std::fmt::Display for VersionBumpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NotAPrerelease(version) => write!(
+                f,
+                "Cannot bump the pre-release of `{version}`, since it is not a pre-release"
+            ),
+            Self::PrereleaseRegression { version, kind } => write!(
+                f,
+                "Cannot bump `{version}` to an earlier kind of pre-release (`{kind}`)"
+            ),
+        }
+    }
+}
+
 /// An error that occurs when parsing an invalid version specifier operator.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct OperatorParseError {
@@ -580,6 +649,77 @@ impl Version {
         Self::new(self.release().iter().copied())
     }
 
+    /// Apply the given [`BumpCommand`] to this version and return the updated version.
+    ///
+    /// Any local version segments are dropped. Note that some bumps are not guaranteed to
+    /// produce a greater version on their own (e.g., bumping `1.2.0` to a dev release yields
+    /// `1.2.0.dev1`, which sorts before `1.2.0`); such bumps are typically combined with a
+    /// release bump, as in `1.2.0` → `1.2.1` → `1.2.1.dev1`.
+    ///
+    /// Pre-release bumps follow the standard pre-release sequence, such that successive bumps
+    /// of `1.2.0a1` may produce `1.2.0a2`, then `1.2.0rc1`, then (with [`BumpCommand::Stable`])
+    /// `1.2.0`.
+    pub fn bump(self, command: BumpCommand) -> Result<Self, VersionBumpError> {
+        let version = self.without_local();
+        match command {
+            BumpCommand::Epoch => {
+                let epoch = version.epoch() + 1;
+                Ok(version.with_epoch(epoch))
+            }
+            BumpCommand::Release { index } => {
+                let mut release = version.release().to_vec();
+                if release.len() <= index {
+                    release.resize(index + 1, 0);
+                }
+                release[index] += 1;
+                for number in &mut release[index + 1..] {
+                    *number = 0;
+                }
+                Ok(version
+                    .with_release(release)
+                    .with_pre(None)
+                    .with_post(None)
+                    .with_dev(None))
+            }
+            BumpCommand::Pre { kind } => {
+                let pre = match version.pre() {
+                    Some(pre) if pre.kind > kind => {
+                        return Err(VersionBumpError::PrereleaseRegression { version, kind });
+                    }
+                    // A dev release precedes the pre-release itself, e.g., `1.0a1.dev1` → `1.0a1`.
+                    Some(pre) if pre.kind == kind && version.is_dev() && !version.is_post() => pre,
+                    Some(pre) if pre.kind == kind => Prerelease {
+                        kind,
+                        number: pre.number + 1,
+                    },
+                    _ => Prerelease { kind, number: 1 },
+                };
+                Ok(version.with_pre(Some(pre)).with_post(None).with_dev(None))
+            }
+            BumpCommand::Prerelease => {
+                let Some(pre) = version.pre() else {
+                    return Err(VersionBumpError::NotAPrerelease(version));
+                };
+                version.bump(BumpCommand::Pre { kind: pre.kind })
+            }
+            BumpCommand::Post => {
+                let post = match version.post() {
+                    // A dev release precedes the post-release itself, e.g., `1.0.post1.dev1` →
+                    // `1.0.post1`.
+                    Some(post) if version.is_dev() => post,
+                    Some(post) => post + 1,
+                    None => 1,
+                };
+                Ok(version.with_post(Some(post)).with_dev(None))
+            }
+            BumpCommand::Dev => {
+                let dev = version.dev().map_or(1, |dev| dev + 1);
+                Ok(version.with_dev(Some(dev)))
+            }
+            BumpCommand::Stable => Ok(version.with_pre(None).with_post(None).with_dev(None)),
+        }
+    }
+
     /// Set the min-release component and return the updated version.
     ///
     /// The "min" component is internal-only, and does not exist in PEP 440.
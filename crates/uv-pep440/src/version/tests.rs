@@ -1304,6 +1304,72 @@ fn max_version() {
 }
 
 // Tests our bespoke u64 decimal integer parser.
+#[test]
+fn bump() {
+    let alpha = BumpCommand::Pre {
+        kind: PrereleaseKind::Alpha,
+    };
+    let rc = BumpCommand::Pre {
+        kind: PrereleaseKind::Rc,
+    };
+    let patch = BumpCommand::Release { index: 2 };
+
+    let cases = [
+        ("1.2.3", BumpCommand::Release { index: 0 }, "2.0.0"),
+        ("1.2.3", BumpCommand::Release { index: 1 }, "1.3.0"),
+        ("1.2.3", patch, "1.2.4"),
+        ("1.2", patch, "1.2.1"),
+        ("1.2.3rc1.post2.dev3+local", patch, "1.2.4"),
+        ("1.2.3", BumpCommand::Epoch, "1!1.2.3"),
+        ("1!1.2.3", BumpCommand::Epoch, "2!1.2.3"),
+        ("1.2.0", alpha, "1.2.0a1"),
+        ("1.2.0a1", alpha, "1.2.0a2"),
+        ("1.2.0a2", rc, "1.2.0rc1"),
+        ("1.2.0a1.dev1", alpha, "1.2.0a1"),
+        ("1.2.0b1", BumpCommand::Prerelease, "1.2.0b2"),
+        ("1.2.0rc1", BumpCommand::Stable, "1.2.0"),
+        ("1.2.0", BumpCommand::Post, "1.2.0.post1"),
+        ("1.2.0.post1", BumpCommand::Post, "1.2.0.post2"),
+        ("1.2.0.post1.dev1", BumpCommand::Post, "1.2.0.post1"),
+        ("1.2.0", BumpCommand::Dev, "1.2.0.dev1"),
+        ("1.2.0.dev1", BumpCommand::Dev, "1.2.0.dev2"),
+    ];
+    for (version, command, expected) in cases {
+        let bumped = Version::from_str(version).unwrap().bump(command).unwrap();
+        assert_eq!(
+            bumped,
+            Version::from_str(expected).unwrap(),
+            "{version} ({command:?})"
+        );
+    }
+
+    // The standard pre-release sequence, ending in a stable release.
+    let mut version = Version::from_str("1.2.0a1").unwrap();
+    let mut sequence = vec![version.to_string()];
+    for command in [BumpCommand::Prerelease, rc, BumpCommand::Stable] {
+        version = version.bump(command).unwrap();
+        sequence.push(version.to_string());
+    }
+    assert_eq!(sequence, ["1.2.0a1", "1.2.0a2", "1.2.0rc1", "1.2.0"]);
+
+    assert_eq!(
+        Version::from_str("1.2.0")
+            .unwrap()
+            .bump(BumpCommand::Prerelease)
+            .unwrap_err()
+            .to_string(),
+        "Cannot bump the pre-release of `1.2.0`, since it is not a pre-release"
+    );
+    assert_eq!(
+        Version::from_str("1.2.0rc1")
+            .unwrap()
+            .bump(alpha)
+            .unwrap_err()
+            .to_string(),
+        "Cannot bump `1.2.0rc1` to an earlier kind of pre-release (`a`)"
+    );
+}
+
 #[test]
 fn parse_number_u64() {
     let p = |s: &str| parse_u64(s.as_bytes());
@@ -704,6 +704,15 @@ impl MarkerTree {
         INTERNER.lock().is_disjoint(self.0, other.0)
     }
 
+    /// Returns `true` if this marker tree implies the given one, i.e. every environment in which
+    /// this marker evaluates to `true` also satisfies `other`.
+    ///
+    /// Like [`MarkerTree::is_disjoint`], this method may return false negatives for complex
+    /// expressions.
+    pub fn implies(&self, other: &MarkerTree) -> bool {
+        INTERNER.lock().is_disjoint(self.0, other.0.not())
+    }
+
     /// Returns the contents of this marker tree, if it contains at least one expression.
     ///
     /// If the marker is `true`, this method will return `None`.
@@ -954,6 +963,60 @@ impl MarkerTree {
         }
     }
 
+    /// Simplify this marker tree by evaluating every environment marker against the given
+    /// environment, leaving only the `extra` expressions in place.
+    ///
+    /// This allows a marker to be evaluated in two stages: once the target environment is known,
+    /// and again once the set of active extras is known. For example, given an environment with
+    /// `sys_platform == 'linux'`, the marker `sys_platform == 'linux' and extra == 'cuda'` would
+    /// be simplified to `extra == 'cuda'`.
+    ///
+    /// Evaluating the returned marker with [`MarkerTree::evaluate_extras`] is equivalent to
+    /// evaluating the original marker with [`MarkerTree::evaluate`].
+    #[must_use]
+    pub fn simplify_environment(&self, env: &MarkerEnvironment) -> MarkerTree {
+        match self.kind() {
+            MarkerTreeKind::True => MarkerTree::TRUE,
+            MarkerTreeKind::False => MarkerTree::FALSE,
+            MarkerTreeKind::Version(marker) => marker
+                .edges()
+                .find(|(range, _)| range.contains(env.get_version(marker.key())))
+                .map(|(_, tree)| tree.simplify_environment(env))
+                .unwrap_or(MarkerTree::FALSE),
+            MarkerTreeKind::String(marker) => {
+                let value = env.get_string(marker.key()).to_string();
+                marker
+                    .children()
+                    .find(|(range, _)| range.contains(&value))
+                    .map(|(_, tree)| tree.simplify_environment(env))
+                    .unwrap_or(MarkerTree::FALSE)
+            }
+            MarkerTreeKind::In(marker) => marker
+                .edge(marker.value().contains(env.get_string(marker.key())))
+                .simplify_environment(env),
+            MarkerTreeKind::Contains(marker) => marker
+                .edge(env.get_string(marker.key()).contains(marker.value()))
+                .simplify_environment(env),
+            MarkerTreeKind::Extra(marker) => {
+                // Retain the extra, but simplify both of its branches.
+                let mut high = MarkerTree::expression(MarkerExpression::Extra {
+                    operator: ExtraOperator::Equal,
+                    name: marker.name().clone(),
+                });
+                high.and(marker.edge(true).simplify_environment(env));
+
+                let mut low = MarkerTree::expression(MarkerExpression::Extra {
+                    operator: ExtraOperator::NotEqual,
+                    name: marker.name().clone(),
+                });
+                low.and(marker.edge(false).simplify_environment(env));
+
+                high.or(low);
+                high
+            }
+        }
+    }
+
     /// Checks if the requirement should be activated with the given set of active extras without evaluating
     /// the remaining environment markers, i.e. if there is potentially an environment that could activate this
     /// requirement.
@@ -2627,6 +2690,51 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_implies() {
+        assert!(m("sys_platform == 'linux' and python_version >= '3.9'")
+            .implies(&m("sys_platform == 'linux'")));
+        assert!(m("python_version >= '3.10'").implies(&m("python_version >= '3.9'")));
+        assert!(!m("python_version >= '3.9'").implies(&m("python_version >= '3.10'")));
+        assert!(m("extra == 'foo'").implies(&m("extra == 'foo' or extra == 'bar'")));
+        assert!(MarkerTree::FALSE.implies(&m("sys_platform == 'win32'")));
+        assert!(m("sys_platform == 'win32'").implies(&MarkerTree::TRUE));
+        assert!(!MarkerTree::TRUE.implies(&m("sys_platform == 'win32'")));
+    }
+
+    #[test]
+    fn test_simplify_environment() {
+        let env = env37();
+
+        assert_eq!(
+            m("sys_platform == 'linux' and extra == 'cuda'")
+                .simplify_environment(&env)
+                .try_to_string()
+                .unwrap(),
+            "extra == 'cuda'"
+        );
+        assert_eq!(
+            m("(sys_platform == 'win32' and extra == 'cuda') or python_version < '3.8'")
+                .simplify_environment(&env),
+            MarkerTree::TRUE
+        );
+        assert_eq!(
+            m("sys_platform == 'win32' or extra == 'cuda'")
+                .simplify_environment(&env)
+                .try_to_string()
+                .unwrap(),
+            "extra == 'cuda'"
+        );
+        assert_eq!(
+            m("os_name == 'nt' and extra == 'cuda'").simplify_environment(&env),
+            MarkerTree::FALSE
+        );
+        assert_eq!(
+            m("'linux' in sys_platform").simplify_environment(&env),
+            MarkerTree::TRUE
+        );
+    }
+
     #[test]
     fn test_extra_disjointness() {
         assert!(!is_disjoint("extra == 'a'", "python_version == '1'"));
@@ -1,5 +1,7 @@
 pub use platform::{Arch, Os, Platform, PlatformError};
+pub use preferences::{ManylinuxVersion, TagPreferences};
 pub use tags::{IncompatibleTag, TagCompatibility, TagPriority, Tags, TagsError};
 
 mod platform;
+mod preferences;
 mod tags;
@@ -50,6 +50,7 @@ pub enum Os {
     Dragonfly { release: String },
     Illumos { release: String, arch: String },
     Haiku { release: String },
+    Pyodide { major: u16, minor: u16 },
 }
 
 impl fmt::Display for Os {
@@ -65,6 +66,7 @@ impl fmt::Display for Os {
             Self::Dragonfly { .. } => write!(f, "DragonFly"),
             Self::Illumos { .. } => write!(f, "Illumos"),
             Self::Haiku { .. } => write!(f, "Haiku"),
+            Self::Pyodide { .. } => write!(f, "Pyodide"),
         }
     }
 }
@@ -88,6 +90,7 @@ pub enum Arch {
     X86_64,
     S390X,
     Riscv64,
+    Wasm32,
 }
 
 impl fmt::Display for Arch {
@@ -102,6 +105,7 @@ impl fmt::Display for Arch {
             Self::X86_64 => write!(f, "x86_64"),
             Self::S390X => write!(f, "s390x"),
             Self::Riscv64 => write!(f, "riscv64"),
+            Self::Wasm32 => write!(f, "wasm32"),
         }
     }
 }
@@ -120,7 +124,7 @@ impl Arch {
             // manylinux_2_31
             Self::Riscv64 => Some(31),
             // unsupported
-            Self::Armv6L => None,
+            Self::Armv6L | Self::Wasm32 => None,
         }
     }
 }
@@ -0,0 +1,186 @@
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// The user's preferences for the prioritization of platform tags.
+///
+/// Passed to [`Tags::from_env`](crate::Tags::from_env) whenever the compatible tags for an
+/// environment are computed, such that they affect both resolution and the selection of a wheel
+/// at install time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagPreferences {
+    /// Platform tag patterns (e.g., `musllinux_*` or `macosx_*_universal2`), in descending order
+    /// of priority.
+    prefer: Vec<String>,
+    /// The newest `manylinux` version to consider compatible, if any.
+    max_manylinux: Option<ManylinuxVersion>,
+}
+
+impl TagPreferences {
+    /// Create a set of [`TagPreferences`] from an ordered list of platform tag patterns and an
+    /// optional cap on the `manylinux` version.
+    pub fn new(prefer: Vec<String>, max_manylinux: Option<ManylinuxVersion>) -> Self {
+        Self {
+            prefer,
+            max_manylinux,
+        }
+    }
+
+    /// Apply the preferences to a list of platform tags, ordered from highest to lowest priority.
+    ///
+    /// Tags that are newer than the `manylinux` cap are removed. The remaining tags are
+    /// reordered such that tags matching an earlier pattern take precedence over tags matching a
+    /// later pattern, which in turn take precedence over tags that don't match any pattern.
+    /// Otherwise, the existing order is preserved.
+    pub(crate) fn apply(&self, platform_tags: &mut Vec<String>) {
+        if let Some(max_manylinux) = self.max_manylinux {
+            platform_tags.retain(|tag| {
+                ManylinuxVersion::from_tag(tag).map_or(true, |version| version <= max_manylinux)
+            });
+        }
+        if !self.prefer.is_empty() {
+            platform_tags.sort_by_key(|tag| {
+                self.prefer
+                    .iter()
+                    .position(|pattern| matches(pattern, tag))
+                    .unwrap_or(self.prefer.len())
+            });
+        }
+    }
+}
+
+/// Returns `true` if the tag matches the pattern, in which `*` matches any sequence of
+/// characters.
+fn matches(pattern: &str, tag: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return false;
+    };
+    let Some(mut rest) = tag.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts = parts.peekable();
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            // The last part must match the end of the tag.
+            return rest.ends_with(part);
+        }
+        let Some(index) = rest.find(part) else {
+            return false;
+        };
+        rest = &rest[index + part.len()..];
+    }
+    // The pattern doesn't contain a wildcard, so the tag must match exactly.
+    rest.is_empty()
+}
+
+/// A `manylinux` version, i.e., the glibc version targeted by a `manylinux` tag (e.g., `2.28`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ManylinuxVersion {
+    major: u16,
+    minor: u16,
+}
+
+impl ManylinuxVersion {
+    /// Extract the [`ManylinuxVersion`] from a platform tag, including the legacy aliases (e.g.,
+    /// `manylinux2014_x86_64`).
+    fn from_tag(tag: &str) -> Option<Self> {
+        let rest = tag.strip_prefix("manylinux")?;
+        let (major, minor) = if let Some(rest) = rest.strip_prefix('_') {
+            let mut parts = rest.splitn(3, '_');
+            let major = parts.next()?.parse().ok()?;
+            let minor = parts.next()?.parse().ok()?;
+            (major, minor)
+        } else if rest.starts_with("2014_") {
+            (2, 17)
+        } else if rest.starts_with("2010_") {
+            (2, 12)
+        } else if rest.starts_with("1_") {
+            (2, 5)
+        } else {
+            return None;
+        };
+        Some(Self { major, minor })
+    }
+}
+
+impl FromStr for ManylinuxVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (major, minor) = s
+            .split_once(['.', '_'])
+            .ok_or_else(|| format!("expected a version like `2.28`, but found: `{s}`"))?;
+        let major = major
+            .parse()
+            .map_err(|_| format!("invalid major version in: `{s}`"))?;
+        let minor = minor
+            .parse()
+            .map_err(|_| format!("invalid minor version in: `{s}`"))?;
+        Ok(Self { major, minor })
+    }
+}
+
+impl std::fmt::Display for ManylinuxVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ManylinuxVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl serde::Serialize for ManylinuxVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern() {
+        assert!(matches("musllinux_*", "musllinux_1_2_x86_64"));
+        assert!(matches("macosx_*_universal2", "macosx_11_0_universal2"));
+        assert!(!matches("macosx_*_universal2", "macosx_11_0_arm64"));
+        assert!(matches("*_x86_64", "manylinux_2_17_x86_64"));
+        assert!(matches("linux_x86_64", "linux_x86_64"));
+        assert!(!matches("linux", "linux_x86_64"));
+    }
+
+    #[test]
+    fn apply() {
+        let mut tags = vec![
+            "manylinux_2_31_x86_64".to_string(),
+            "manylinux_2_28_x86_64".to_string(),
+            "manylinux_2_17_x86_64".to_string(),
+            "manylinux2014_x86_64".to_string(),
+            "linux_x86_64".to_string(),
+        ];
+        TagPreferences::new(
+            vec!["linux_*".to_string()],
+            Some(ManylinuxVersion::from_str("2.28").unwrap()),
+        )
+        .apply(&mut tags);
+        assert_eq!(
+            tags,
+            [
+                "linux_x86_64",
+                "manylinux_2_28_x86_64",
+                "manylinux_2_17_x86_64",
+                "manylinux2014_x86_64",
+            ]
+        );
+    }
+}
@@ -5,7 +5,7 @@ use std::{cmp, num::NonZeroU32};
 
 use rustc_hash::FxHashMap;
 
-use crate::{Arch, Os, Platform, PlatformError};
+use crate::{Arch, Os, Platform, PlatformError, TagPreferences};
 
 #[derive(Debug, thiserror::Error)]
 pub enum TagsError {
@@ -94,7 +94,7 @@ impl Tags {
     }
 
     /// Returns the compatible tags for the given Python implementation (e.g., `cpython`), version,
-    /// and platform.
+    /// and platform, prioritized according to the given [`TagPreferences`].
     pub fn from_env(
         platform: &Platform,
         python_version: (u8, u8),
@@ -102,6 +102,7 @@ impl Tags {
         implementation_version: (u8, u8),
         manylinux_compatible: bool,
         gil_disabled: bool,
+        preferences: &TagPreferences,
     ) -> Result<Self, TagsError> {
         let implementation = Implementation::parse(implementation_name, gil_disabled)?;
 
@@ -111,6 +112,7 @@ impl Tags {
             if matches!(platform.os(), Os::Manylinux { .. }) && !manylinux_compatible {
                 platform_tags.retain(|tag| !tag.starts_with("manylinux"));
             }
+            preferences.apply(&mut platform_tags);
             platform_tags
         };
 
@@ -507,6 +509,15 @@ fn compatible_tags(platform: &Platform) -> Result<Vec<String>, PlatformError> {
             vec!["win_amd64".to_string()]
         }
         (Os::Windows, Arch::Aarch64) => vec!["win_arm64".to_string()],
+        (Os::Pyodide { major, minor }, Arch::Wasm32) => {
+            // See: <https://peps.python.org/pep-0783/>
+            let mut platform_tags = vec![format!("pyodide_{major}_{minor}_{arch}")];
+            // Support the legacy Emscripten tags with lower priority.
+            if let Some(version) = emscripten_version(*major, *minor) {
+                platform_tags.push(format!("emscripten_{version}_{arch}"));
+            }
+            platform_tags
+        }
         (
             Os::FreeBsd { release }
             | Os::NetBsd { release }
@@ -552,6 +563,17 @@ fn compatible_tags(platform: &Platform) -> Result<Vec<String>, PlatformError> {
     Ok(platform_tags)
 }
 
+/// Return the Emscripten version (e.g., `3_1_58`) that corresponds to a Pyodide ABI version.
+///
+/// See: <https://pyodide.org/en/stable/development/abi.html>
+fn emscripten_version(major: u16, minor: u16) -> Option<&'static str> {
+    match (major, minor) {
+        (2024, 0) => Some("3_1_58"),
+        (2025, 0) => Some("4_0_9"),
+        _ => None,
+    }
+}
+
 /// Determine the appropriate binary formats for a macOS version.
 /// Source: <https://github.com/pypa/packaging/blob/fd4f11139d1c884a637be8aa26bb60a31fbc9411/packaging/tags.py#L314>
 fn get_mac_binary_formats(arch: Arch) -> Vec<String> {
@@ -380,6 +380,7 @@ fn test_manylinux_incompatible() {
         (3, 9),
         false,
         false,
+        &TagPreferences::default(),
     )
     .unwrap();
     assert_snapshot!(
@@ -443,6 +444,7 @@ fn test_system_tags_manylinux() {
         (3, 9),
         true,
         false,
+        &TagPreferences::default(),
     )
     .unwrap();
     assert_snapshot!(
@@ -1067,6 +1069,7 @@ fn test_system_tags_macos() {
         (3, 9),
         false,
         false,
+        &TagPreferences::default(),
     )
     .unwrap();
     assert_snapshot!(
@@ -0,0 +1,453 @@
+//! Support for uv plugins: external executables that enforce custom policies at fixed hook points.
+//!
+//! Plugins are executables named `uv-plugin-<name>`, typically installed via `uv tool install`.
+//! Each plugin is spawned lazily, the first time a hook is invoked, and kept alive for the
+//! remainder of the process. uv communicates with plugins via JSON-RPC 2.0 over the plugin's
+//! standard input and output, with one message per line. The plugin's standard error is inherited,
+//! such that plugins can log diagnostics.
+//!
+//! After spawning a plugin, uv sends an `initialize` request, to which the plugin responds with the
+//! hooks that it implements (e.g., `{"hooks": ["filter-candidates"]}`). Hooks that a plugin doesn't
+//! advertise are never sent to it.
+use std::collections::BTreeSet;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::debug;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+/// The version of the plugin protocol.
+const PROTOCOL_VERSION: u32 = 1;
+
+/// The prefix of plugin executables.
+pub const PLUGIN_PREFIX: &str = "uv-plugin-";
+
+/// The plugins registered for the current process.
+static PLUGINS: OnceLock<Vec<Plugin>> = OnceLock::new();
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to start plugin `{0}`")]
+    Spawn(String, #[source] std::io::Error),
+    #[error("Failed to communicate with plugin `{0}`")]
+    Io(String, #[source] std::io::Error),
+    #[error("Plugin `{0}` exited unexpectedly")]
+    Exited(String),
+    #[error("Plugin `{0}` sent an invalid response")]
+    InvalidResponse(String, #[source] serde_json::Error),
+    #[error("Plugin `{plugin}` failed to handle `{method}`: {message}")]
+    Rpc {
+        plugin: String,
+        method: &'static str,
+        message: String,
+    },
+    #[error("Plugin `{plugin}` rejected `{name}=={version}`: {reason}")]
+    Rejected {
+        plugin: String,
+        name: PackageName,
+        version: Version,
+        reason: String,
+    },
+}
+
+/// A point at which uv invokes plugins.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Hook {
+    /// Called during resolution, with the versions available for a package, to exclude any
+    /// versions that shouldn't be considered.
+    FilterCandidates,
+    /// Called prior to installation, with the artifacts to be installed, to reject any artifacts
+    /// that shouldn't be installed.
+    VerifyArtifacts,
+    /// Called after a lockfile is written, with the locked packages, to report on the lock.
+    PostLock,
+}
+
+impl Hook {
+    /// The JSON-RPC method name for the hook.
+    fn method(self) -> &'static str {
+        match self {
+            Self::FilterCandidates => "filter-candidates",
+            Self::VerifyArtifacts => "verify-artifacts",
+            Self::PostLock => "post-lock",
+        }
+    }
+}
+
+/// A plugin executable.
+#[derive(Debug)]
+pub struct Plugin {
+    name: String,
+    path: PathBuf,
+    connection: Mutex<Option<Connection>>,
+}
+
+impl Plugin {
+    /// Create a [`Plugin`] for the executable at the given path.
+    pub fn new(name: String, path: PathBuf) -> Self {
+        Self {
+            name,
+            path,
+            connection: Mutex::new(None),
+        }
+    }
+
+    /// The name of the plugin, without the `uv-plugin-` prefix.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The path to the plugin executable.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Send a hook request to the plugin, spawning the plugin if necessary.
+    ///
+    /// Returns `Ok(None)` if the plugin doesn't implement the hook.
+    fn call(&self, hook: Hook, params: &Value) -> Result<Option<Value>, Error> {
+        let mut connection = self.connection.lock().unwrap();
+        let connection = match &mut *connection {
+            Some(connection) => connection,
+            None => connection.insert(Connection::spawn(&self.name, &self.path)?),
+        };
+        if !connection.hooks.contains(hook.method()) {
+            return Ok(None);
+        }
+        connection
+            .request(&self.name, hook.method(), params)
+            .map(Some)
+    }
+}
+
+/// A running plugin process.
+#[derive(Debug)]
+struct Connection {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    /// The hooks advertised by the plugin.
+    hooks: BTreeSet<String>,
+    /// The ID of the next request.
+    id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct InitializeResult {
+    #[serde(default)]
+    hooks: BTreeSet<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Request<'a> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'static str,
+    params: &'a Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    id: u64,
+    result: Option<Value>,
+    error: Option<ResponseError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseError {
+    message: String,
+}
+
+impl Connection {
+    /// Spawn the plugin and perform the `initialize` handshake.
+    fn spawn(name: &str, path: &Path) -> Result<Self, Error> {
+        debug!("Starting plugin `{name}` at: {}", path.display());
+        let mut child = Command::new(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|err| Error::Spawn(name.to_string(), err))?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+
+        let mut connection = Self {
+            child,
+            stdin,
+            stdout,
+            hooks: BTreeSet::new(),
+            id: 0,
+        };
+
+        let params = serde_json::json!({
+            "protocol_version": PROTOCOL_VERSION,
+            "uv_version": uv_version::version(),
+        });
+        let result = connection.request(name, "initialize", &params)?;
+        let result = serde_json::from_value::<InitializeResult>(result)
+            .map_err(|err| Error::InvalidResponse(name.to_string(), err))?;
+        debug!(
+            "Plugin `{name}` implements: {}",
+            result.hooks.iter().cloned().collect::<Vec<_>>().join(", ")
+        );
+        connection.hooks = result.hooks;
+
+        Ok(connection)
+    }
+
+    /// Send a request to the plugin and wait for its response.
+    fn request(
+        &mut self,
+        name: &str,
+        method: &'static str,
+        params: &Value,
+    ) -> Result<Value, Error> {
+        self.id += 1;
+        let request = Request {
+            jsonrpc: "2.0",
+            id: self.id,
+            method,
+            params,
+        };
+        let mut line = serde_json::to_string(&request).expect("requests are serializable");
+        line.push('\n');
+        self.stdin
+            .write_all(line.as_bytes())
+            .and_then(|()| self.stdin.flush())
+            .map_err(|err| {
+                if err.kind() == std::io::ErrorKind::BrokenPipe {
+                    Error::Exited(name.to_string())
+                } else {
+                    Error::Io(name.to_string(), err)
+                }
+            })?;
+
+        // Skip any responses to earlier requests (e.g., if a previous read was interrupted).
+        loop {
+            let mut line = String::new();
+            let read = self
+                .stdout
+                .read_line(&mut line)
+                .map_err(|err| Error::Io(name.to_string(), err))?;
+            if read == 0 {
+                return Err(Error::Exited(name.to_string()));
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = serde_json::from_str::<Response>(&line)
+                .map_err(|err| Error::InvalidResponse(name.to_string(), err))?;
+            if response.id != self.id {
+                continue;
+            }
+            if let Some(error) = response.error {
+                return Err(Error::Rpc {
+                    plugin: name.to_string(),
+                    method,
+                    message: error.message,
+                });
+            }
+            return Ok(response.result.unwrap_or(Value::Null));
+        }
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // Closing standard input signals the plugin to exit; but don't rely on it.
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Register the plugins for the current process.
+///
+/// Plugins can only be registered once; subsequent calls are ignored.
+pub fn register(plugins: Vec<Plugin>) {
+    if !plugins.is_empty() {
+        debug!(
+            "Registered plugins: {}",
+            plugins
+                .iter()
+                .map(Plugin::name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    let _ = PLUGINS.set(plugins);
+}
+
+/// Returns the registered plugins.
+fn plugins() -> &'static [Plugin] {
+    PLUGINS.get().map(Vec::as_slice).unwrap_or_default()
+}
+
+/// Invoke a hook on every registered plugin that implements it, returning each plugin's response.
+async fn invoke(hook: Hook, params: Value) -> Result<Vec<(&'static Plugin, Value)>, Error> {
+    let plugins = plugins();
+    if plugins.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Plugins communicate over blocking pipes, so avoid blocking the runtime.
+    tokio::task::spawn_blocking(move || {
+        let mut responses = Vec::new();
+        for plugin in plugins {
+            if let Some(response) = plugin.call(hook, &params)? {
+                responses.push((plugin, response));
+            }
+        }
+        Ok(responses)
+    })
+    .await
+    .expect("plugin task panicked")
+}
+
+/// Deserialize the result of a hook.
+fn parse<T: for<'de> Deserialize<'de>>(plugin: &Plugin, result: Value) -> Result<T, Error> {
+    serde_json::from_value(result).map_err(|err| Error::InvalidResponse(plugin.name.clone(), err))
+}
+
+#[derive(Debug, Deserialize)]
+struct FilterCandidatesResult {
+    #[serde(default)]
+    exclude: Vec<Version>,
+}
+
+/// Invoke the `filter-candidates` hook, returning the versions of the package that should be
+/// excluded from resolution.
+///
+/// The request parameters are `{"package": <name>, "versions": [<version>, ...]}`; plugins respond
+/// with `{"exclude": [<version>, ...]}`.
+pub async fn filter_candidates(
+    package: &PackageName,
+    versions: Vec<&Version>,
+) -> Result<BTreeSet<Version>, Error> {
+    if plugins().is_empty() {
+        return Ok(BTreeSet::new());
+    }
+    let params = serde_json::json!({
+        "package": package,
+        "versions": versions,
+    });
+
+    let mut excluded = BTreeSet::new();
+    for (plugin, result) in invoke(Hook::FilterCandidates, params).await? {
+        let result = parse::<FilterCandidatesResult>(plugin, result)?;
+        for version in result.exclude {
+            debug!("Plugin `{}` excluded: {package}=={version}", plugin.name);
+            excluded.insert(version);
+        }
+    }
+    Ok(excluded)
+}
+
+/// An artifact that's about to be installed.
+#[derive(Debug, Serialize)]
+pub struct Artifact<'a> {
+    pub name: &'a PackageName,
+    pub version: &'a Version,
+    /// The filename of the wheel.
+    pub filename: String,
+    /// The path to the unzipped wheel in the cache.
+    pub path: &'a Path,
+    /// The known hashes of the wheel, as `<algorithm>:<digest>`.
+    pub hashes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerifyArtifactsResult {
+    #[serde(default)]
+    rejected: Vec<Rejection>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Rejection {
+    name: PackageName,
+    version: Version,
+    reason: String,
+}
+
+/// Invoke the `verify-artifacts` hook, returning an error if any plugin rejects an artifact.
+///
+/// The request parameters are `{"artifacts": [<artifact>, ...]}`; plugins respond with
+/// `{"rejected": [{"name": <name>, "version": <version>, "reason": <reason>}, ...]}`.
+pub async fn verify_artifacts(artifacts: &[Artifact<'_>]) -> Result<(), Error> {
+    if plugins().is_empty() || artifacts.is_empty() {
+        return Ok(());
+    }
+    let params = serde_json::json!({ "artifacts": artifacts });
+
+    for (plugin, result) in invoke(Hook::VerifyArtifacts, params).await? {
+        let result = parse::<VerifyArtifactsResult>(plugin, result)?;
+        if let Some(rejection) = result.rejected.into_iter().next() {
+            return Err(Error::Rejected {
+                plugin: plugin.name.clone(),
+                name: rejection.name,
+                version: rejection.version,
+                reason: rejection.reason,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// A package in a lockfile.
+#[derive(Debug, Serialize)]
+pub struct LockedPackage<'a> {
+    pub name: &'a PackageName,
+    pub version: &'a Version,
+    /// The source of the package (e.g., the index URL), if known.
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PostLockResult {
+    #[serde(default)]
+    messages: Vec<String>,
+}
+
+/// A message reported by a plugin.
+#[derive(Debug)]
+pub struct Message {
+    pub plugin: String,
+    pub message: String,
+}
+
+/// Invoke the `post-lock` hook, returning any messages that plugins reported.
+///
+/// The request parameters are `{"lockfile": <path>, "changed": <bool>, "packages": [<package>,
+/// ...]}`; plugins respond with `{"messages": [<message>, ...]}`.
+pub async fn post_lock(
+    lockfile: &Path,
+    changed: bool,
+    packages: &[LockedPackage<'_>],
+) -> Result<Vec<Message>, Error> {
+    if plugins().is_empty() {
+        return Ok(Vec::new());
+    }
+    let params = serde_json::json!({
+        "lockfile": lockfile,
+        "changed": changed,
+        "packages": packages,
+    });
+
+    let mut messages = Vec::new();
+    for (plugin, result) in invoke(Hook::PostLock, params).await? {
+        let result = parse::<PostLockResult>(plugin, result)?;
+        messages.extend(result.messages.into_iter().map(|message| Message {
+            plugin: plugin.name.clone(),
+            message,
+        }));
+    }
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests;
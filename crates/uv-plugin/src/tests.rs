@@ -0,0 +1,67 @@
+#![cfg(unix)]
+
+use std::os::unix::fs::PermissionsExt;
+
+use super::*;
+
+/// Write an executable shell script to the given directory.
+fn script(dir: &Path, contents: &str) -> PathBuf {
+    let path = dir.join("uv-plugin-test");
+    fs_err::write(&path, contents).unwrap();
+    fs_err::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+/// A plugin that implements `filter-candidates`, excluding `1.0.0` for every package.
+const FILTER: &str = r#"#!/bin/sh
+while IFS= read -r line; do
+  id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  case "$line" in
+    *'"initialize"'*) printf '{"jsonrpc":"2.0","id":%s,"result":{"hooks":["filter-candidates"]}}\n' "$id" ;;
+    *) printf '{"jsonrpc":"2.0","id":%s,"result":{"exclude":["1.0.0"]}}\n' "$id" ;;
+  esac
+done
+"#;
+
+#[test]
+fn call_implemented_hook() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let plugin = Plugin::new("test".to_string(), script(temp_dir.path(), FILTER));
+
+    let params = serde_json::json!({ "package": "flask", "versions": ["1.0.0", "2.0.0"] });
+    let result = plugin
+        .call(Hook::FilterCandidates, &params)
+        .unwrap()
+        .unwrap();
+    let result = parse::<FilterCandidatesResult>(&plugin, result).unwrap();
+    assert_eq!(result.exclude, vec![Version::new([1, 0, 0])]);
+
+    // The plugin should be reused across requests.
+    assert!(plugin
+        .call(Hook::FilterCandidates, &params)
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn skip_unimplemented_hook() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let plugin = Plugin::new("test".to_string(), script(temp_dir.path(), FILTER));
+
+    let params = serde_json::json!({ "lockfile": "uv.lock", "changed": true, "packages": [] });
+    assert!(plugin.call(Hook::PostLock, &params).unwrap().is_none());
+}
+
+#[test]
+fn plugin_exits() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let plugin = Plugin::new(
+        "test".to_string(),
+        script(temp_dir.path(), "#!/bin/sh\nexit 0\n"),
+    );
+
+    let err = plugin
+        .call(Hook::FilterCandidates, &Value::Null)
+        .unwrap_err();
+    assert!(matches!(err, Error::Exited(_)), "{err}");
+}
@@ -1,6 +1,6 @@
 mod trusted_publishing;
 
-use crate::trusted_publishing::TrustedPublishingError;
+use crate::trusted_publishing::{TrustedPublishingError, TrustedPublishingProvider};
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use fs_err::tokio::File;
@@ -16,7 +16,7 @@ use rustc_hash::FxHashSet;
 use serde::Deserialize;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::{env, fmt, io};
+use std::{fmt, io};
 use thiserror::Error;
 use tokio::io::{AsyncReadExt, BufReader};
 use tokio_util::io::ReaderStream;
@@ -27,8 +27,8 @@ use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_filename::{DistFilename, SourceDistExtension, SourceDistFilename};
 use uv_fs::{ProgressReader, Simplified};
 use uv_metadata::read_metadata_async_seek;
+use uv_pep508::VerbatimUrl;
 use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
-use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 
 pub use trusted_publishing::TrustedPublishingToken;
@@ -59,6 +59,12 @@ pub enum PublishError {
     MixedCredentials(String),
     #[error("Failed to query check URL")]
     CheckUrlIndex(#[source] uv_client::Error),
+    #[error("Failed to query {0} for existing files")]
+    CheckPypiJson(Url, #[source] reqwest_middleware::Error),
+    #[error("Failed to query {0} for existing files: status code {1}")]
+    CheckPypiJsonStatus(Url, StatusCode),
+    #[error("Failed to parse the response from {0}")]
+    CheckPypiJsonParse(Url, #[source] reqwest::Error),
     #[error(
         "Local file and index file do not match for {filename}. \
         Local: {hash_algorithm}={local}, Remote: {hash_algorithm}={remote}"
@@ -120,9 +126,69 @@ pub trait Reporter: Send + Sync + 'static {
     fn on_download_complete(&self, id: usize);
 }
 
+/// An index that lists the files that were uploaded to a registry.
+#[derive(Debug, Clone)]
+pub enum CheckIndex {
+    /// A simple repository API, e.g., as provided via `--check-url`.
+    Simple(IndexUrl),
+    /// The base URL of a PyPI JSON API, e.g., `https://pypi.org/pypi/`.
+    PypiJson(Url),
+}
+
+impl CheckIndex {
+    /// Infer the index that lists the files uploaded to the given publish URL.
+    ///
+    /// Returns `None` if the publish URL doesn't belong to a known registry:
+    ///
+    /// * PyPI and TestPyPI (`https://upload.pypi.org/legacy/`), which are queried via the JSON API.
+    /// * GitLab (`https://gitlab.com/api/v4/projects/<project>/packages/pypi`).
+    /// * Artifactory (`https://<host>/artifactory/api/pypi/<repository>`).
+    /// * Nexus (`https://<host>/repository/<repository>/`).
+    ///
+    /// For GitLab, Artifactory, and Nexus, the simple API is served at `simple/` below the publish
+    /// URL.
+    pub fn from_publish_url(publish_url: &Url) -> Option<Self> {
+        let host = publish_url.host_str()?;
+        let path = publish_url.path().trim_end_matches('/');
+
+        if host == "upload.pypi.org" {
+            return Url::parse("https://pypi.org/pypi/")
+                .ok()
+                .map(Self::PypiJson);
+        }
+        if host == "test.pypi.org" && path == "/legacy" {
+            return Url::parse("https://test.pypi.org/pypi/")
+                .ok()
+                .map(Self::PypiJson);
+        }
+
+        let is_gitlab = path.contains("/api/v4/projects/") && path.ends_with("/packages/pypi");
+        let is_artifactory = path.contains("/api/pypi/");
+        let is_nexus = path.starts_with("/repository/");
+        if is_gitlab || is_artifactory || is_nexus {
+            let mut url = publish_url.clone();
+            url.set_path(&format!("{path}/simple/"));
+            url.set_query(None);
+            url.set_fragment(None);
+            return Some(Self::Simple(IndexUrl::from(VerbatimUrl::from_url(url))));
+        }
+
+        None
+    }
+}
+
+impl fmt::Display for CheckIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Simple(index_url) => write!(f, "{index_url}"),
+            Self::PypiJson(url) => write!(f, "{url}"),
+        }
+    }
+}
+
 /// Context for using a fresh registry client for check URL requests.
 pub struct CheckUrlClient<'a> {
-    pub index_url: IndexUrl,
+    pub index: CheckIndex,
     pub registry_client_builder: RegistryClientBuilder<'a>,
     pub client: &'a BaseClient,
     pub index_capabilities: IndexCapabilities,
@@ -306,14 +372,15 @@ pub async fn check_trusted_publishing(
             {
                 return Ok(TrustedPublishResult::Skipped);
             }
-            // If we aren't in GitHub Actions, we can't use trusted publishing.
-            if env::var(EnvVars::GITHUB_ACTIONS) != Ok("true".to_string()) {
+            // If we aren't in a supported CI provider, we can't use trusted publishing.
+            let Some(provider) = TrustedPublishingProvider::detect() else {
                 return Ok(TrustedPublishResult::Skipped);
-            }
+            };
             // We could check for credentials from the keyring or netrc the auth middleware first, but
-            // given that we are in GitHub Actions we check for trusted publishing first.
-            debug!("Running on GitHub Actions without explicit credentials, checking for trusted publishing");
-            match trusted_publishing::get_token(registry, client.for_host(registry)).await {
+            // given that we are in CI we check for trusted publishing first.
+            debug!("Running on {provider} without explicit credentials, checking for trusted publishing");
+            match trusted_publishing::get_token(provider, registry, client.for_host(registry)).await
+            {
                 Ok(token) => Ok(TrustedPublishResult::Configured(token)),
                 Err(err) => {
                     // TODO(konsti): It would be useful if we could differentiate between actual errors
@@ -325,7 +392,7 @@ pub async fn check_trusted_publishing(
             }
         }
         TrustedPublishing::Always => {
-            debug!("Using trusted publishing for GitHub Actions");
+            debug!("Using trusted publishing");
 
             let mut conflicts = Vec::new();
             if username.is_some() {
@@ -341,13 +408,16 @@ pub async fn check_trusted_publishing(
                 return Err(PublishError::MixedCredentials(conflicts.join(" and ")));
             }
 
-            if env::var(EnvVars::GITHUB_ACTIONS) != Ok("true".to_string()) {
+            let provider = TrustedPublishingProvider::detect().unwrap_or_else(|| {
                 warn_user_once!(
-                    "Trusted publishing was requested, but you're not in GitHub Actions."
+                    "Trusted publishing was requested, but you're not in GitHub Actions, GitLab CI, or CircleCI."
                 );
-            }
+                TrustedPublishingProvider::GitHubActions
+            });
 
-            let token = trusted_publishing::get_token(registry, client.for_host(registry)).await?;
+            let token =
+                trusted_publishing::get_token(provider, registry, client.for_host(registry))
+                    .await?;
             Ok(TrustedPublishResult::Configured(token))
         }
         TrustedPublishing::Never => Ok(TrustedPublishResult::Skipped),
@@ -444,13 +514,20 @@ pub async fn check_url(
     filename: &DistFilename,
 ) -> Result<bool, PublishError> {
     let CheckUrlClient {
-        index_url,
+        index,
         registry_client_builder,
         client,
         index_capabilities,
         cache,
     } = check_url_client;
 
+    let index_url = match index {
+        CheckIndex::Simple(index_url) => index_url,
+        CheckIndex::PypiJson(base_url) => {
+            return check_pypi_json(base_url, client, file, filename).await;
+        }
+    };
+
     // Avoid using the PyPI 10min default cache.
     let cache_refresh = (*cache)
         .clone()
@@ -496,35 +573,125 @@ pub async fn check_url(
 
     // TODO(konsti): Do we have a preference for a hash here?
     if let Some(remote_hash) = archived_file.hashes.first() {
-        // We accept the risk for TOCTOU errors here, since we already read the file once before the
-        // streaming upload to compute the hash for the form metadata.
-        let local_hash = hash_file(file, Hasher::from(remote_hash.algorithm))
-            .await
-            .map_err(|err| {
-                PublishError::PublishPrepare(
-                    file.to_path_buf(),
-                    Box::new(PublishPrepareError::Io(err)),
-                )
-            })?;
-        if local_hash.digest == remote_hash.digest {
-            debug!(
-                "Found {filename} in the registry with matching hash {}",
-                remote_hash.digest
-            );
-            Ok(true)
-        } else {
-            Err(PublishError::HashMismatch {
-                filename: Box::new(filename.clone()),
-                hash_algorithm: remote_hash.algorithm,
-                local: local_hash.digest,
-                remote: remote_hash.digest.clone(),
-            })
+        compare_hash(file, filename, remote_hash).await
+    } else {
+        Err(PublishError::MissingHash(Box::new(filename.clone())))
+    }
+}
+
+/// Check whether the file already exists on the index, using the PyPI JSON API.
+///
+/// See <https://docs.pypi.org/api/json/#get-a-release>.
+async fn check_pypi_json(
+    base_url: &Url,
+    client: &BaseClient,
+    file: &Path,
+    filename: &DistFilename,
+) -> Result<bool, PublishError> {
+    #[derive(Deserialize)]
+    struct Release {
+        urls: Vec<ReleaseFile>,
+    }
+
+    #[derive(Deserialize)]
+    struct ReleaseFile {
+        filename: String,
+        digests: ReleaseDigests,
+    }
+
+    #[derive(Deserialize)]
+    struct ReleaseDigests {
+        sha256: Option<String>,
+    }
+
+    let version = filename.version().to_string();
+    let mut url = base_url.clone();
+    url.path_segments_mut()
+        .expect("the JSON API URL is a base URL")
+        .pop_if_empty()
+        .extend([filename.name().as_ref(), version.as_str(), "json"]);
+
+    debug!("Checking for {filename} in the registry at {url}");
+    let response = client
+        .for_host(&url)
+        .get(url.clone())
+        .send()
+        .await
+        .map_err(|err| PublishError::CheckPypiJson(url.clone(), err))?;
+
+    // The project or release doesn't exist yet.
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(false);
+    }
+    if !response.status().is_success() {
+        return Err(PublishError::CheckPypiJsonStatus(url, response.status()));
+    }
+
+    let release: Release = response
+        .json()
+        .await
+        .map_err(|err| PublishError::CheckPypiJsonParse(url.clone(), err))?;
+
+    let Some(release_file) = release.urls.into_iter().find(|release_file| {
+        match (
+            DistFilename::try_from_normalized_filename(&release_file.filename),
+            filename,
+        ) {
+            (
+                Some(DistFilename::SourceDistFilename(remote)),
+                DistFilename::SourceDistFilename(local),
+            ) => remote == *local,
+            (Some(DistFilename::WheelFilename(remote)), DistFilename::WheelFilename(local)) => {
+                remote == *local
+            }
+            _ => false,
         }
+    }) else {
+        return Ok(false);
+    };
+
+    if let Some(digest) = release_file.digests.sha256 {
+        let remote_hash = HashDigest {
+            algorithm: HashAlgorithm::Sha256,
+            digest: digest.into_boxed_str(),
+        };
+        compare_hash(file, filename, &remote_hash).await
     } else {
         Err(PublishError::MissingHash(Box::new(filename.clone())))
     }
 }
 
+/// Compare the hash of the local file to the hash of the file in the registry.
+///
+/// Returns `true` if the hashes match, and errors if they don't.
+async fn compare_hash(
+    file: &Path,
+    filename: &DistFilename,
+    remote_hash: &HashDigest,
+) -> Result<bool, PublishError> {
+    // We accept the risk for TOCTOU errors here, since we already read the file once before the
+    // streaming upload to compute the hash for the form metadata.
+    let local_hash = hash_file(file, Hasher::from(remote_hash.algorithm))
+        .await
+        .map_err(|err| {
+            PublishError::PublishPrepare(file.to_path_buf(), Box::new(PublishPrepareError::Io(err)))
+        })?;
+    if local_hash.digest == remote_hash.digest {
+        debug!(
+            "Found {filename} in the registry with matching hash {}",
+            remote_hash.digest
+        );
+        Ok(true)
+    } else {
+        Err(PublishError::HashMismatch {
+            filename: Box::new(filename.clone()),
+            hash_algorithm: remote_hash.algorithm,
+            local: local_hash.digest,
+            remote: remote_hash.digest.clone(),
+        })
+    }
+}
+
 /// Calculate the SHA256 of a file.
 async fn hash_file(path: impl AsRef<Path>, hasher: Hasher) -> Result<HashDigest, io::Error> {
     debug!("Hashing {}", path.as_ref().display());
@@ -1,4 +1,4 @@
-use crate::{build_request, form_metadata, Reporter};
+use crate::{build_request, form_metadata, CheckIndex, Reporter};
 use insta::{assert_debug_snapshot, assert_snapshot};
 use itertools::Itertools;
 use std::path::PathBuf;
@@ -271,3 +271,43 @@ async fn upload_request_wheel() {
         "###);
     });
 }
+
+/// Infer the index to check for existing files from the publish URL of known registries.
+#[test]
+fn check_index_from_publish_url() {
+    let check_index = |url: &str| {
+        CheckIndex::from_publish_url(&Url::parse(url).unwrap()).map(|index| index.to_string())
+    };
+
+    assert_debug_snapshot!(
+        [
+            "https://upload.pypi.org/legacy/",
+            "https://test.pypi.org/legacy/",
+            "https://gitlab.com/api/v4/projects/1234/packages/pypi",
+            "https://example.jfrog.io/artifactory/api/pypi/pypi-local",
+            "https://nexus.example.com/repository/pypi-internal/",
+            "https://example.com/upload/",
+        ]
+        .map(check_index),
+        @r###"
+    [
+        Some(
+            "https://pypi.org/pypi/",
+        ),
+        Some(
+            "https://test.pypi.org/pypi/",
+        ),
+        Some(
+            "https://gitlab.com/api/v4/projects/1234/packages/pypi/simple/",
+        ),
+        Some(
+            "https://example.jfrog.io/artifactory/api/pypi/pypi-local/simple/",
+        ),
+        Some(
+            "https://nexus.example.com/repository/pypi-internal/simple/",
+        ),
+        None,
+    ]
+    "###
+    );
+}
@@ -1,4 +1,4 @@
-//! Trusted publishing (via OIDC) with GitHub actions.
+//! Trusted publishing (via OIDC) with GitHub Actions, GitLab CI, and CircleCI.
 
 use reqwest::{header, StatusCode};
 use reqwest_middleware::ClientWithMiddleware;
@@ -7,7 +7,9 @@ use std::env;
 use std::env::VarError;
 use std::ffi::OsString;
 use std::fmt::Display;
+use std::io;
 use thiserror::Error;
+use tokio::process::Command;
 use tracing::{debug, trace};
 use url::Url;
 use uv_static::EnvVars;
@@ -30,6 +32,14 @@ pub enum TrustedPublishingError {
         "PyPI returned error code {0}, is trusted publishing correctly configured?\nResponse: {1}"
     )]
     Pypi(StatusCode, String),
+    #[error(
+        "Environment variable {0} not set, is an ID token configured via `id_tokens` for the job?"
+    )]
+    MissingGitLabIdToken(&'static str),
+    #[error("Failed to run `circleci run oidc get`, is the CircleCI CLI installed?")]
+    CircleCi(#[source] io::Error),
+    #[error("`circleci run oidc get` failed with {0}:\n{1}")]
+    CircleCiStatus(std::process::ExitStatus, String),
 }
 
 impl TrustedPublishingError {
@@ -41,6 +51,43 @@ impl TrustedPublishingError {
     }
 }
 
+/// A CI provider that can issue OIDC tokens for trusted publishing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TrustedPublishingProvider {
+    /// GitHub Actions, with a token requested via `$ACTIONS_ID_TOKEN_REQUEST_URL`.
+    GitHubActions,
+    /// GitLab CI, with a token provided via `id_tokens` as `$PYPI_ID_TOKEN`.
+    GitLabCi,
+    /// CircleCI, with a token requested via `circleci run oidc get`.
+    CircleCi,
+}
+
+impl TrustedPublishingProvider {
+    /// Detect the CI provider from the environment, if any.
+    pub(crate) fn detect() -> Option<Self> {
+        let is_set = |var| env::var(var).is_ok_and(|value| value == "true");
+        if is_set(EnvVars::GITHUB_ACTIONS) {
+            Some(Self::GitHubActions)
+        } else if is_set(EnvVars::GITLAB_CI) {
+            Some(Self::GitLabCi)
+        } else if is_set(EnvVars::CIRCLECI) {
+            Some(Self::CircleCi)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for TrustedPublishingProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GitHubActions => write!(f, "GitHub Actions"),
+            Self::GitLabCi => write!(f, "GitLab CI"),
+            Self::CircleCi => write!(f, "CircleCI"),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(transparent)]
 pub struct TrustedPublishingToken(String);
@@ -77,29 +124,56 @@ struct PublishToken {
 
 /// Returns the short-lived token to use for uploading.
 pub(crate) async fn get_token(
+    provider: TrustedPublishingProvider,
     registry: &Url,
     client: &ClientWithMiddleware,
 ) -> Result<TrustedPublishingToken, TrustedPublishingError> {
-    // If this fails, we can skip the audience request.
-    let oidc_token_request_token =
-        env::var(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN).map_err(|err| {
-            TrustedPublishingError::from_var_err(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN, err)
-        })?;
+    let oidc_token = match provider {
+        TrustedPublishingProvider::GitHubActions => {
+            // If this fails, we can skip the audience request.
+            let oidc_token_request_token = env::var(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN)
+                .map_err(|err| {
+                    TrustedPublishingError::from_var_err(
+                        EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN,
+                        err,
+                    )
+                })?;
 
-    // Request 1: Get the audience
-    let audience = get_audience(registry, client).await?;
+            // Request 1: Get the audience
+            let audience = get_audience(registry, client).await?;
 
-    // Request 2: Get the OIDC token from GitHub.
-    let oidc_token = get_oidc_token(&audience, &oidc_token_request_token, client).await?;
+            // Request 2: Get the OIDC token from GitHub.
+            get_oidc_token(&audience, &oidc_token_request_token, client).await?
+        }
+        TrustedPublishingProvider::GitLabCi => {
+            // On GitLab, the token is minted for the audience configured in the job, so there's
+            // nothing to request.
+            env::var(EnvVars::PYPI_ID_TOKEN).map_err(|err| match err {
+                VarError::NotPresent => {
+                    TrustedPublishingError::MissingGitLabIdToken(EnvVars::PYPI_ID_TOKEN)
+                }
+                VarError::NotUnicode(os_string) => {
+                    TrustedPublishingError::InvalidEnvVar(EnvVars::PYPI_ID_TOKEN, os_string)
+                }
+            })?
+        }
+        TrustedPublishingProvider::CircleCi => {
+            // Request 1: Get the audience
+            let audience = get_audience(registry, client).await?;
 
-    // Request 3: Get the publishing token from PyPI.
+            // Request 2: Get the OIDC token from the CircleCI CLI.
+            get_circleci_oidc_token(&audience).await?
+        }
+    };
+
+    // Get the publishing token from PyPI.
     let publish_token = get_publish_token(registry, &oidc_token, client).await?;
 
-    debug!("Received token, using trusted publishing");
+    debug!("Received token from {provider}, using trusted publishing");
 
     // Tell GitHub Actions to mask the token in any console logs.
     #[allow(clippy::print_stdout)]
-    if env::var(EnvVars::GITHUB_ACTIONS) == Ok("true".to_string()) {
+    if provider == TrustedPublishingProvider::GitHubActions {
         println!("::add-mask::{}", &publish_token);
     }
 
@@ -158,6 +232,26 @@ async fn get_oidc_token(
     Ok(oidc_token.value)
 }
 
+/// Request an OIDC token for the given audience from the CircleCI CLI.
+///
+/// See <https://circleci.com/docs/openid-connect-tokens/#oidc-tokens-with-custom-claims>.
+async fn get_circleci_oidc_token(audience: &str) -> Result<String, TrustedPublishingError> {
+    let claims = serde_json::json!({ "aud": audience }).to_string();
+    debug!("Querying the trusted publishing OIDC token from `circleci run oidc get`");
+    let output = Command::new("circleci")
+        .args(["run", "oidc", "get", "--claims", &claims])
+        .output()
+        .await
+        .map_err(TrustedPublishingError::CircleCi)?;
+    if !output.status.success() {
+        return Err(TrustedPublishingError::CircleCiStatus(
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 async fn get_publish_token(
     registry: &Url,
     oidc_token: &str,
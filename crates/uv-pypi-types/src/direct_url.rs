@@ -4,6 +4,8 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use uv_git::VcsBackend;
+
 /// Metadata for a distribution that was installed via a direct URL.
 ///
 /// See: <https://packaging.python.org/en/latest/specifications/direct-url-data-structure/>
@@ -87,6 +89,16 @@ impl std::fmt::Display for VcsKind {
     }
 }
 
+impl From<VcsBackend> for VcsKind {
+    fn from(backend: VcsBackend) -> Self {
+        match backend {
+            VcsBackend::Git => Self::Git,
+            VcsBackend::Mercurial => Self::Hg,
+            VcsBackend::Subversion => Self::Svn,
+        }
+    }
+}
+
 impl TryFrom<&DirectUrl> for Url {
     type Error = url::ParseError;
 
@@ -109,7 +121,13 @@ impl TryFrom<&DirectUrl> for Url {
                 vcs_info,
                 subdirectory,
             } => {
-                let mut url = Self::parse(&format!("{}+{}", vcs_info.vcs, url))?;
+                // Subversion URLs over SSH retain their prefix (i.e., `svn+ssh://`).
+                let prefix = format!("{}+", vcs_info.vcs);
+                let mut url = if url.starts_with(&prefix) {
+                    Self::parse(url)?
+                } else {
+                    Self::parse(&format!("{prefix}{url}"))?
+                };
                 if let Some(commit_id) = &vcs_info.commit_id {
                     url.set_path(&format!("{}@{commit_id}", url.path()));
                 } else if let Some(requested_revision) = &vcs_info.requested_revision {
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use thiserror::Error;
 use url::{ParseError, Url};
 use uv_distribution_filename::{DistExtension, ExtensionError};
-use uv_git::{GitReference, GitSha, GitUrl, OidParseError};
+use uv_git::{GitReference, GitSha, GitUrl, OidParseError, VcsBackend};
 use uv_pep508::{Pep508Url, UnnamedRequirementUrl, VerbatimUrl, VerbatimUrlError};
 
 use crate::{ArchiveInfo, DirInfo, DirectUrl, VcsInfo, VcsKind};
@@ -227,9 +227,14 @@ impl ParsedDirectoryUrl {
 
 /// A Git repository URL.
 ///
+/// Mercurial and Subversion repositories are represented in the same way, with the version control
+/// system recorded as the [`VcsBackend`] of the [`GitUrl`].
+///
 /// Examples:
 /// * `git+https://git.example.com/MyProject.git`
 /// * `git+https://git.example.com/MyProject.git@v1.0#egg=pkg&subdirectory=pkg_dir`
+/// * `hg+https://hg.example.com/MyProject@v1.0`
+/// * `svn+https://svn.example.com/MyProject/trunk@2019`
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Hash, Ord)]
 pub struct ParsedGitUrl {
     pub url: GitUrl,
@@ -243,33 +248,37 @@ impl ParsedGitUrl {
         reference: GitReference,
         precise: Option<GitSha>,
         subdirectory: Option<PathBuf>,
+        backend: VcsBackend,
     ) -> Self {
         let url = if let Some(precise) = precise {
             GitUrl::from_commit(repository, reference, precise)
         } else {
             GitUrl::from_reference(repository, reference)
         };
-        Self { url, subdirectory }
+        Self {
+            url: url.with_backend(backend),
+            subdirectory,
+        }
     }
 }
 
 impl TryFrom<Url> for ParsedGitUrl {
     type Error = ParsedUrlError;
 
-    /// Supports URLs with and without the `git+` prefix.
+    /// Supports URLs with and without the `git+` (or `hg+`, or `svn+`) prefix.
     ///
     /// When the URL includes a prefix, it's presumed to come from a PEP 508 requirement; when it's
-    /// excluded, it's presumed to come from `tool.uv.sources`.
+    /// excluded, it's presumed to come from `tool.uv.sources`, and to refer to a Git repository.
     fn try_from(url_in: Url) -> Result<Self, Self::Error> {
         let subdirectory = get_subdirectory(&url_in);
 
-        let url = url_in
-            .as_str()
-            .strip_prefix("git+")
-            .unwrap_or(url_in.as_str());
-        let url = Url::parse(url).map_err(|err| ParsedUrlError::UrlParse(url.to_string(), err))?;
+        let backend = VcsBackend::from_url(&url_in);
+        let url = backend
+            .remote(&url_in)
+            .map_err(|err| ParsedUrlError::UrlParse(url_in.to_string(), err))?;
         let url = GitUrl::try_from(url)
-            .map_err(|err| ParsedUrlError::GitShaParse(url_in.to_string(), err))?;
+            .map_err(|err| ParsedUrlError::GitShaParse(url_in.to_string(), err))?
+            .with_backend(backend);
         Ok(Self { url, subdirectory })
     }
 }
@@ -333,22 +342,12 @@ impl TryFrom<Url> for ParsedUrl {
     fn try_from(url: Url) -> Result<Self, Self::Error> {
         if let Some((prefix, ..)) = url.scheme().split_once('+') {
             match prefix {
-                "git" => Ok(Self::Git(ParsedGitUrl::try_from(url)?)),
+                "git" | "hg" | "svn" => Ok(Self::Git(ParsedGitUrl::try_from(url)?)),
                 "bzr" => Err(ParsedUrlError::UnsupportedUrlPrefix {
                     prefix: prefix.to_string(),
                     url: url.to_string(),
                     message: "Bazaar is not supported",
                 }),
-                "hg" => Err(ParsedUrlError::UnsupportedUrlPrefix {
-                    prefix: prefix.to_string(),
-                    url: url.to_string(),
-                    message: "Mercurial is not supported",
-                }),
-                "svn" => Err(ParsedUrlError::UnsupportedUrlPrefix {
-                    prefix: prefix.to_string(),
-                    url: url.to_string(),
-                    message: "Subversion is not supported",
-                }),
                 _ => Err(ParsedUrlError::UnsupportedUrlPrefix {
                     prefix: prefix.to_string(),
                     url: url.to_string(),
@@ -453,7 +452,7 @@ impl TryFrom<&ParsedGitUrl> for DirectUrl {
         Ok(Self::VcsUrl {
             url: value.url.repository().to_string(),
             vcs_info: VcsInfo {
-                vcs: VcsKind::Git,
+                vcs: VcsKind::from(value.url.backend()),
                 commit_id: value.url.precise().as_ref().map(ToString::to_string),
                 requested_revision: value.url.reference().as_str().map(ToString::to_string),
             },
@@ -497,7 +496,8 @@ impl From<ParsedArchiveUrl> for Url {
 
 impl From<ParsedGitUrl> for Url {
     fn from(value: ParsedGitUrl) -> Self {
-        let mut url = Self::parse(&format!("{}{}", "git+", Self::from(value.url).as_str()))
+        let prefix = value.url.backend().missing_prefix(value.url.repository());
+        let mut url = Self::parse(&format!("{}{}", prefix, Self::from(value.url).as_str()))
             .expect("Git URL is invalid");
         if let Some(subdirectory) = value.subdirectory {
             url.set_fragment(Some(&format!("subdirectory={}", subdirectory.display())));
@@ -1,7 +1,10 @@
 use anyhow::Result;
 use url::Url;
 
+use uv_git::VcsBackend;
+
 use crate::parsed_url::ParsedUrl;
+use crate::{DirectUrl, VcsKind};
 
 #[test]
 fn direct_url_from_url() -> Result<()> {
@@ -31,6 +34,50 @@ fn direct_url_from_url() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn direct_url_from_vcs_url() -> Result<()> {
+    let expected = Url::parse("hg+https://hg.example.com/project@1.0#subdirectory=pkg_dir")?;
+    let actual = Url::from(ParsedUrl::try_from(expected.clone())?);
+    assert_eq!(expected, actual);
+
+    let expected = Url::parse("svn+https://svn.example.com/project/trunk@2019")?;
+    let actual = Url::from(ParsedUrl::try_from(expected.clone())?);
+    assert_eq!(expected, actual);
+
+    // `svn+ssh` is a native Subversion scheme, so the prefix is retained on the repository.
+    let expected = Url::parse("svn+ssh://svn.example.com/project/trunk@2019")?;
+    let ParsedUrl::Git(parsed) = ParsedUrl::try_from(expected.clone())? else {
+        panic!("expected a VCS URL");
+    };
+    assert_eq!(parsed.url.backend(), VcsBackend::Subversion);
+    assert_eq!(
+        parsed.url.repository().as_str(),
+        "svn+ssh://svn.example.com/project/trunk"
+    );
+    assert_eq!(expected, Url::from(ParsedUrl::Git(parsed)));
+
+    let ParsedUrl::Git(parsed) =
+        ParsedUrl::try_from(Url::parse("hg+https://hg.example.com/project@1.0")?)?
+    else {
+        panic!("expected a VCS URL");
+    };
+    assert_eq!(parsed.url.backend(), VcsBackend::Mercurial);
+    assert_eq!(
+        parsed.url.repository().as_str(),
+        "https://hg.example.com/project"
+    );
+    let DirectUrl::VcsUrl { url, vcs_info, .. } = DirectUrl::try_from(&parsed)? else {
+        panic!("expected a VCS direct URL");
+    };
+    assert_eq!(url, "https://hg.example.com/project");
+    assert_eq!(vcs_info.vcs, VcsKind::Hg);
+    assert_eq!(vcs_info.requested_revision.as_deref(), Some("1.0"));
+
+    assert!(ParsedUrl::try_from(Url::parse("bzr+https://bzr.example.com/project")?).is_err());
+
+    Ok(())
+}
+
 #[test]
 #[cfg(unix)]
 fn direct_url_from_url_absolute() -> Result<()> {
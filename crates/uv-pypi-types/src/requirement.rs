@@ -8,7 +8,7 @@ use url::Url;
 use uv_distribution_filename::DistExtension;
 
 use uv_fs::{relative_to, PortablePathBuf, CWD};
-use uv_git::{GitReference, GitSha, GitUrl};
+use uv_git::{GitReference, GitSha, GitUrl, VcsBackend};
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::{
@@ -136,6 +136,7 @@ impl From<Requirement> for uv_pep508::Requirement<VerbatimParsedUrl> {
                 })),
                 RequirementSource::Git {
                     repository,
+                    backend,
                     reference,
                     precise,
                     subdirectory,
@@ -148,7 +149,7 @@ impl From<Requirement> for uv_pep508::Requirement<VerbatimParsedUrl> {
                     };
                     Some(VersionOrUrl::Url(VerbatimParsedUrl {
                         parsed_url: ParsedUrl::Git(ParsedGitUrl {
-                            url: git_url,
+                            url: git_url.with_backend(backend),
                             subdirectory,
                         }),
                         verbatim: url,
@@ -241,11 +242,12 @@ impl Display for Requirement {
             RequirementSource::Git {
                 url: _,
                 repository,
+                backend,
                 reference,
                 precise: _,
                 subdirectory,
             } => {
-                write!(f, " @ git+{repository}")?;
+                write!(f, " @ {}{repository}", backend.missing_prefix(repository))?;
                 if let Some(reference) = reference.as_str() {
                     write!(f, "@{reference}")?;
                 }
@@ -302,9 +304,14 @@ pub enum RequirementSource {
         url: VerbatimUrl,
     },
     /// A remote Git repository, over either HTTPS or SSH.
+    ///
+    /// Mercurial and Subversion repositories are also represented as Git sources, with the version
+    /// control system recorded as the [`VcsBackend`].
     Git {
-        /// The repository URL (without the `git+` prefix).
+        /// The repository URL (without the `git+`, `hg+`, or `svn+` prefix).
         repository: Url,
+        /// The version control system that backs the repository.
+        backend: VcsBackend,
         /// Optionally, the revision, tag, or branch to use.
         reference: GitReference,
         /// The precise commit to use, if known.
@@ -361,6 +368,7 @@ impl RequirementSource {
             ParsedUrl::Git(git) => RequirementSource::Git {
                 url,
                 repository: git.url.repository().clone(),
+                backend: git.url.backend(),
                 reference: git.url.reference().clone(),
                 precise: git.url.precise(),
                 subdirectory: git.subdirectory,
@@ -425,6 +433,7 @@ impl RequirementSource {
             }),
             Self::Git {
                 repository,
+                backend,
                 reference,
                 precise,
                 subdirectory,
@@ -435,6 +444,7 @@ impl RequirementSource {
                     reference.clone(),
                     *precise,
                     subdirectory.clone(),
+                    *backend,
                 )),
                 verbatim: url.clone(),
             }),
@@ -525,11 +535,12 @@ impl Display for RequirementSource {
             Self::Git {
                 url: _,
                 repository,
+                backend,
                 reference,
                 precise: _,
                 subdirectory,
             } => {
-                write!(f, " git+{repository}")?;
+                write!(f, " {}{repository}", backend.missing_prefix(repository))?;
                 if let Some(reference) = reference.as_str() {
                     write!(f, "@{reference}")?;
                 }
@@ -553,6 +564,10 @@ impl Display for RequirementSource {
 enum RequirementSourceWire {
     /// Ex) `source = { git = "<https://github.com/astral-test/uv-public-pypackage?rev=0.0.1#0dacfd662c64cb4ceb16e6cf65a157a8b715b979>" }`
     Git { git: String },
+    /// Ex) `source = { hg = "<https://hg.example.com/project?rev=1.0#1f0f1d1c8d8a6b1e2b4c5d6e7f8091a2b3c4d5e6>" }`
+    Hg { hg: String },
+    /// Ex) `source = { svn = "<https://svn.example.com/project/trunk#2019>" }`
+    Svn { svn: String },
     /// Ex) `source = { url = "<https://example.org/foo-1.0.zip>" }`
     Direct {
         url: Url,
@@ -600,6 +615,7 @@ impl From<RequirementSource> for RequirementSourceWire {
             },
             RequirementSource::Git {
                 repository,
+                backend,
                 reference,
                 precise,
                 subdirectory,
@@ -646,8 +662,16 @@ impl From<RequirementSource> for RequirementSourceWire {
                     url.set_fragment(Some(&precise.to_string()));
                 }
 
-                Self::Git {
-                    git: url.to_string(),
+                match backend {
+                    VcsBackend::Git => Self::Git {
+                        git: url.to_string(),
+                    },
+                    VcsBackend::Mercurial => Self::Hg {
+                        hg: url.to_string(),
+                    },
+                    VcsBackend::Subversion => Self::Svn {
+                        svn: url.to_string(),
+                    },
                 }
             }
             RequirementSource::Path {
@@ -681,6 +705,58 @@ impl From<RequirementSource> for RequirementSourceWire {
     }
 }
 
+impl RequirementSource {
+    /// Parse a Git (or Mercurial, or Subversion) source from its serialized URL, in which the
+    /// requested reference and subdirectory are encoded in the query, and the precise commit is
+    /// encoded in the fragment.
+    fn from_vcs_wire(wire: &str, backend: VcsBackend) -> Result<Self, RequirementError> {
+        let mut repository = Url::parse(wire)?;
+
+        let mut reference = GitReference::DefaultBranch;
+        let mut subdirectory = None;
+        for (key, val) in repository.query_pairs() {
+            match &*key {
+                "tag" => reference = GitReference::Tag(val.into_owned()),
+                "branch" => reference = GitReference::Branch(val.into_owned()),
+                "rev" => reference = GitReference::from_rev(val.into_owned()),
+                "subdirectory" => subdirectory = Some(val.into_owned()),
+                _ => continue,
+            };
+        }
+
+        let precise = repository.fragment().map(GitSha::from_str).transpose()?;
+
+        // Clear out any existing state.
+        repository.set_fragment(None);
+        repository.set_query(None);
+
+        // Redact the credentials.
+        redact_credentials(&mut repository);
+
+        // Create a PEP 508-compatible URL.
+        let mut url = Url::parse(&format!(
+            "{}{repository}",
+            backend.missing_prefix(&repository)
+        ))?;
+        if let Some(rev) = reference.as_str() {
+            url.set_path(&format!("{}@{}", url.path(), rev));
+        }
+        if let Some(subdirectory) = &subdirectory {
+            url.set_fragment(Some(&format!("subdirectory={subdirectory}")));
+        }
+        let url = VerbatimUrl::from_url(url);
+
+        Ok(Self::Git {
+            repository,
+            backend,
+            reference,
+            precise,
+            subdirectory: subdirectory.map(PathBuf::from),
+            url,
+        })
+    }
+}
+
 impl TryFrom<RequirementSourceWire> for RequirementSource {
     type Error = RequirementError;
 
@@ -689,48 +765,9 @@ impl TryFrom<RequirementSourceWire> for RequirementSource {
             RequirementSourceWire::Registry { specifier, index } => {
                 Ok(Self::Registry { specifier, index })
             }
-            RequirementSourceWire::Git { git } => {
-                let mut repository = Url::parse(&git)?;
-
-                let mut reference = GitReference::DefaultBranch;
-                let mut subdirectory = None;
-                for (key, val) in repository.query_pairs() {
-                    match &*key {
-                        "tag" => reference = GitReference::Tag(val.into_owned()),
-                        "branch" => reference = GitReference::Branch(val.into_owned()),
-                        "rev" => reference = GitReference::from_rev(val.into_owned()),
-                        "subdirectory" => subdirectory = Some(val.into_owned()),
-                        _ => continue,
-                    };
-                }
-
-                let precise = repository.fragment().map(GitSha::from_str).transpose()?;
-
-                // Clear out any existing state.
-                repository.set_fragment(None);
-                repository.set_query(None);
-
-                // Redact the credentials.
-                redact_credentials(&mut repository);
-
-                // Create a PEP 508-compatible URL.
-                let mut url = Url::parse(&format!("git+{repository}"))?;
-                if let Some(rev) = reference.as_str() {
-                    url.set_path(&format!("{}@{}", url.path(), rev));
-                }
-                if let Some(subdirectory) = &subdirectory {
-                    url.set_fragment(Some(&format!("subdirectory={subdirectory}")));
-                }
-                let url = VerbatimUrl::from_url(url);
-
-                Ok(Self::Git {
-                    repository,
-                    reference,
-                    precise,
-                    subdirectory: subdirectory.map(PathBuf::from),
-                    url,
-                })
-            }
+            RequirementSourceWire::Git { git } => Self::from_vcs_wire(&git, VcsBackend::Git),
+            RequirementSourceWire::Hg { hg } => Self::from_vcs_wire(&hg, VcsBackend::Mercurial),
+            RequirementSourceWire::Svn { svn } => Self::from_vcs_wire(&svn, VcsBackend::Subversion),
             RequirementSourceWire::Direct { url, subdirectory } => Ok(Self::Url {
                 url: VerbatimUrl::from_url(url.clone()),
                 location: url.clone(),
@@ -1,3 +1,4 @@
+use std::convert::Infallible;
 use std::str::FromStr;
 
 use jiff::Timestamp;
@@ -12,6 +13,9 @@ pub struct SimpleJson {
     /// The list of [`File`]s available for download sorted by filename.
     #[serde(deserialize_with = "sorted_simple_json_files")]
     pub files: Vec<File>,
+    /// The status of the project, if provided by the index.
+    #[serde(default, rename = "project-status")]
+    pub project_status: ProjectStatus,
 }
 
 /// Deserializes a sequence of "simple" files from `PyPI` and ensures that they
@@ -131,6 +135,91 @@ impl Default for Yanked {
     }
 }
 
+/// The status of a project, as reported by the index.
+///
+/// <https://peps.python.org/pep-0792/>
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+)]
+#[rkyv(derive(Debug))]
+pub struct ProjectStatus {
+    /// The status marker of the project.
+    #[serde(default)]
+    pub status: ProjectStatusMarker,
+    /// A free-form explanation of the status, if any.
+    pub reason: Option<String>,
+}
+
+/// A project status marker, as defined by PEP 792.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[rkyv(derive(Debug))]
+pub enum ProjectStatusMarker {
+    /// The project is active, and may receive new releases.
+    #[default]
+    Active,
+    /// The project doesn't expect to receive any new releases.
+    Archived,
+    /// The project is considered unsafe to install by the index.
+    Quarantined,
+    /// The project is considered obsolete, and may have been superseded by another project.
+    Deprecated,
+    /// A status marker that isn't recognized, which is treated as `active`.
+    #[serde(other)]
+    Unknown,
+}
+
+impl ProjectStatusMarker {
+    /// Returns `true` if the marker indicates that the project is no longer maintained.
+    pub fn is_unmaintained(self) -> bool {
+        matches!(self, Self::Archived | Self::Quarantined | Self::Deprecated)
+    }
+}
+
+impl FromStr for ProjectStatusMarker {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "active" => Self::Active,
+            "archived" => Self::Archived,
+            "quarantined" => Self::Quarantined,
+            "deprecated" => Self::Deprecated,
+            _ => Self::Unknown,
+        })
+    }
+}
+
+impl std::fmt::Display for ProjectStatusMarker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Active | Self::Unknown => write!(f, "active"),
+            Self::Archived => write!(f, "archived"),
+            Self::Quarantined => write!(f, "quarantined"),
+            Self::Deprecated => write!(f, "deprecated"),
+        }
+    }
+}
+
 /// A dictionary mapping a hash name to a hex encoded digest of the file.
 ///
 /// PEP 691 says multiple hashes can be included and the interpretation is left to the client.
@@ -1,42 +1,118 @@
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
-use serde::ser::SerializeSeq;
+use serde::ser::{SerializeMap, SerializeSeq};
 
 use uv_pep508::MarkerTree;
 
 /// A list of supported marker environments.
+///
+/// Environments may be given as a list of markers, or as a table of named environments, each
+/// defined by a marker or by a (partial or complete) set of marker values.
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
-pub struct SupportedEnvironments(Vec<MarkerTree>);
+pub struct SupportedEnvironments {
+    markers: Vec<MarkerTree>,
+    /// The name of each environment, if the environments were declared as a table.
+    names: Vec<String>,
+}
 
 impl SupportedEnvironments {
     /// Return the list of marker trees.
     pub fn as_markers(&self) -> &[MarkerTree] {
-        &self.0
+        &self.markers
     }
 
     /// Convert the [`SupportedEnvironments`] struct into a list of marker trees.
     pub fn into_markers(self) -> Vec<MarkerTree> {
-        self.0
+        self.markers
+    }
+
+    /// Return the names of the environments, if they were declared as a table.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+
+    /// Return the marker for the environment with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&MarkerTree> {
+        self.names
+            .iter()
+            .position(|candidate| candidate == name)
+            .map(|index| &self.markers[index])
+    }
+
+    /// Restrict the [`SupportedEnvironments`] to the environment with the given name, if any.
+    #[must_use]
+    pub fn only(&self, name: &str) -> Option<Self> {
+        let marker = self.get(name)?;
+        Some(Self {
+            markers: vec![marker.clone()],
+            names: vec![name.to_string()],
+        })
     }
 }
 
-/// Serialize a [`SupportedEnvironments`] struct into a list of marker strings.
+/// Serialize a [`SupportedEnvironments`] struct into a list of marker strings, or a table of
+/// marker strings for named environments.
 impl serde::Serialize for SupportedEnvironments {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
-        for element in &self.0 {
-            if let Some(contents) = element.contents() {
-                seq.serialize_element(&contents)?;
+        if self.names.is_empty() {
+            let mut seq = serializer.serialize_seq(Some(self.markers.len()))?;
+            for element in &self.markers {
+                if let Some(contents) = element.contents() {
+                    seq.serialize_element(&contents)?;
+                }
+            }
+            seq.end()
+        } else {
+            let mut map = serializer.serialize_map(Some(self.markers.len()))?;
+            for (name, element) in self.names.iter().zip(&self.markers) {
+                let contents = element
+                    .contents()
+                    .map(|contents| contents.to_string())
+                    .unwrap_or_default();
+                map.serialize_entry(name, &contents)?;
             }
+            map.end()
         }
-        seq.end()
     }
 }
 
-/// Deserialize a marker string or list of marker strings into a [`SupportedEnvironments`] struct.
+/// A named environment, defined by a marker or by a set of marker values.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum NamedEnvironment {
+    /// A marker, e.g., `sys_platform == 'linux'`.
+    Marker(String),
+    /// A set of marker values, e.g., `{ sys_platform = "linux", platform_machine = "armv7l" }`.
+    Values(BTreeMap<String, String>),
+}
+
+impl NamedEnvironment {
+    /// Convert the named environment into a marker tree.
+    fn into_marker(self) -> Result<MarkerTree, String> {
+        let marker = match self {
+            Self::Marker(marker) => marker,
+            Self::Values(values) => values
+                .into_iter()
+                .map(|(key, value)| {
+                    if value.contains('\'') {
+                        format!("{key} == \"{value}\"")
+                    } else {
+                        format!("{key} == '{value}'")
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" and "),
+        };
+        MarkerTree::from_str(&marker).map_err(|err| err.to_string())
+    }
+}
+
+/// Deserialize a marker string, a list of marker strings, or a table of named environments into a
+/// [`SupportedEnvironments`] struct.
 impl<'de> serde::Deserialize<'de> for SupportedEnvironments {
     fn deserialize<D>(deserializer: D) -> Result<SupportedEnvironments, D::Error>
     where
@@ -48,7 +124,7 @@ impl<'de> serde::Deserialize<'de> for SupportedEnvironments {
             type Value = SupportedEnvironments;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("a string or a list of strings")
+                formatter.write_str("a string, a list of strings, or a table of named environments")
             }
 
             fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
@@ -56,7 +132,10 @@ impl<'de> serde::Deserialize<'de> for SupportedEnvironments {
                 E: serde::de::Error,
             {
                 let marker = MarkerTree::from_str(value).map_err(serde::de::Error::custom)?;
-                Ok(SupportedEnvironments(vec![marker]))
+                Ok(SupportedEnvironments {
+                    markers: vec![marker],
+                    names: Vec::new(),
+                })
             }
 
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -70,7 +149,30 @@ impl<'de> serde::Deserialize<'de> for SupportedEnvironments {
                     markers.push(marker);
                 }
 
-                Ok(SupportedEnvironments(markers))
+                Ok(SupportedEnvironments {
+                    markers,
+                    names: Vec::new(),
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut markers = Vec::new();
+                let mut names = Vec::new();
+
+                while let Some((name, environment)) =
+                    map.next_entry::<String, NamedEnvironment>()?
+                {
+                    let marker = environment.into_marker().map_err(|err| {
+                        serde::de::Error::custom(format!("invalid environment `{name}`: {err}"))
+                    })?;
+                    markers.push(marker);
+                    names.push(name);
+                }
+
+                Ok(SupportedEnvironments { markers, names })
             }
         }
 
@@ -0,0 +1,538 @@
+//! Python bindings for uv's resolver and lockfile APIs.
+//!
+//! Resolutions and lockfiles are both exposed as a [`LockGraph`]: a list of packages, each with
+//! its direct dependencies and the markers under which they apply.
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+
+use uv_cache::Cache;
+use uv_client::{FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    BuildOptions, Concurrency, ConfigSettings, Constraints, IndexStrategy, LowerBound,
+    SourceStrategy,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution_types::{
+    DependencyMetadata, Index, IndexCapabilities, IndexLocations, IndexUrl,
+};
+use uv_git::GitResolver;
+use uv_install_wheel::linker::LinkMode;
+use uv_pep508::{ExtraName, MarkerEnvironment, MarkerEnvironmentBuilder, MarkerTree};
+use uv_platform_tags::TagPreferences;
+use uv_pypi_types::{Requirement, VerbatimParsedUrl};
+use uv_python::Interpreter;
+use uv_resolver::{FlatIndex, InMemoryIndex, Lock, ResolveError, Resolver};
+use uv_types::{BuildIsolation, HashStrategy, InFlight};
+
+create_exception!(
+    uv_python_api,
+    ResolutionError,
+    PyException,
+    "Raised when the requirements can't be resolved."
+);
+
+/// A PEP 508 environment marker.
+#[pyclass(frozen, eq, hash, module = "uv_python_api", name = "Marker")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct PyMarker(MarkerTree);
+
+#[pymethods]
+impl PyMarker {
+    #[new]
+    fn new(marker: &str) -> PyResult<Self> {
+        MarkerTree::from_str(marker)
+            .map(Self)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Returns `True` if the marker is always satisfied.
+    fn is_always_true(&self) -> bool {
+        self.0.is_true()
+    }
+
+    /// Returns `True` if the marker can never be satisfied.
+    fn is_never_true(&self) -> bool {
+        self.0.is_false()
+    }
+
+    /// Evaluate the marker against the given environment, or that of the current interpreter.
+    #[pyo3(signature = (environment=None, extras=Vec::new()))]
+    #[allow(clippy::needless_pass_by_value)]
+    fn evaluate(
+        &self,
+        py: Python<'_>,
+        environment: Option<HashMap<String, String>>,
+        extras: Vec<String>,
+    ) -> PyResult<bool> {
+        let environment = match environment {
+            Some(environment) => marker_environment(&environment)?,
+            None => {
+                let python = current_executable(py)?;
+                py.allow_threads(|| query_interpreter(&python))
+                    .map_err(to_py_err)?
+                    .markers()
+                    .clone()
+            }
+        };
+        let extras = extras
+            .iter()
+            .map(|extra| ExtraName::from_str(extra))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        Ok(self.0.evaluate(&environment, &extras))
+    }
+
+    fn __and__(&self, other: &Self) -> Self {
+        let mut marker = self.0.clone();
+        marker.and(other.0.clone());
+        Self(marker)
+    }
+
+    fn __or__(&self, other: &Self) -> Self {
+        let mut marker = self.0.clone();
+        marker.or(other.0.clone());
+        Self(marker)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.try_to_string().unwrap_or_else(|| "true".to_string())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Marker({:?})", self.__str__())
+    }
+}
+
+/// A direct dependency of a [`Package`].
+#[pyclass(frozen, get_all, module = "uv_python_api")]
+#[derive(Clone)]
+struct Dependency {
+    name: String,
+    version: String,
+    /// The extras that are enabled on the dependency.
+    extras: Vec<String>,
+    /// The marker under which the dependency applies.
+    marker: PyMarker,
+    /// The extra that declares the dependency, if it's an optional dependency.
+    extra: Option<String>,
+    /// The dependency group that declares the dependency, if it's a development dependency.
+    group: Option<String>,
+}
+
+#[pymethods]
+impl Dependency {
+    fn __repr__(&self) -> String {
+        format!(
+            "Dependency(name={:?}, version={:?})",
+            self.name, self.version
+        )
+    }
+}
+
+/// A package in a [`LockGraph`].
+#[pyclass(frozen, get_all, module = "uv_python_api")]
+#[derive(Clone)]
+struct Package {
+    name: String,
+    version: String,
+    dependencies: Vec<Dependency>,
+}
+
+#[pymethods]
+impl Package {
+    fn __repr__(&self) -> String {
+        format!("Package(name={:?}, version={:?})", self.name, self.version)
+    }
+}
+
+/// A resolved dependency graph, either from a resolution or from a `uv.lock` file.
+#[pyclass(frozen, get_all, module = "uv_python_api")]
+struct LockGraph {
+    requires_python: String,
+    packages: Vec<Package>,
+}
+
+#[pymethods]
+impl LockGraph {
+    /// Return the packages with the given name (of which there may be several, in a universal
+    /// resolution).
+    fn package(&self, name: &str) -> Vec<Package> {
+        self.packages
+            .iter()
+            .filter(|package| package.name == name)
+            .cloned()
+            .collect()
+    }
+
+    /// Return the packages that depend directly on the package with the given name.
+    fn dependents(&self, name: &str) -> Vec<Package> {
+        self.packages
+            .iter()
+            .filter(|package| package.dependencies.iter().any(|dep| dep.name == name))
+            .cloned()
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.packages.len()
+    }
+}
+
+impl From<&Lock> for LockGraph {
+    fn from(lock: &Lock) -> Self {
+        let packages = lock
+            .packages()
+            .iter()
+            .map(|package| Package {
+                name: package.name().to_string(),
+                version: package.version().to_string(),
+                dependencies: package
+                    .dependencies()
+                    .into_iter()
+                    .map(|dep| Dependency {
+                        name: dep.name.to_string(),
+                        version: dep.version.to_string(),
+                        extras: dep.extras.iter().map(ToString::to_string).collect(),
+                        marker: PyMarker(dep.marker.clone()),
+                        extra: dep.extra.map(ToString::to_string),
+                        group: dep.group.map(ToString::to_string),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self {
+            requires_python: lock.requires_python().to_string(),
+            packages,
+        }
+    }
+}
+
+/// Read the `uv.lock` file at the given path.
+#[pyfunction]
+#[allow(clippy::needless_pass_by_value)]
+fn inspect_lock(path: PathBuf) -> PyResult<LockGraph> {
+    let contents = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read `{}`", path.display()))
+        .map_err(to_py_err)?;
+    let lock = toml::from_str::<Lock>(&contents)
+        .with_context(|| format!("Failed to parse `{}`", path.display()))
+        .map_err(to_py_err)?;
+    Ok(LockGraph::from(&lock))
+}
+
+/// Resolve the given PEP 508 requirements.
+///
+/// By default, the requirements are resolved for the current interpreter. If `universal` is
+/// set, the resolution is valid across all platforms, as in `uv lock`.
+#[pyfunction]
+#[pyo3(signature = (requirements, *, python=None, index_url=None, universal=false))]
+fn resolve(
+    py: Python<'_>,
+    requirements: Vec<String>,
+    python: Option<PathBuf>,
+    index_url: Option<String>,
+    universal: bool,
+) -> PyResult<LockGraph> {
+    let python = match python {
+        Some(python) => python,
+        None => current_executable(py)?,
+    };
+    py.allow_threads(|| {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?
+            .block_on(resolve_impl(requirements, &python, index_url, universal))
+    })
+    .map_err(|err| match err.downcast_ref::<ResolveError>() {
+        Some(ResolveError::NoSolution(_)) => ResolutionError::new_err(format!("{err:#}")),
+        _ => to_py_err(err),
+    })
+}
+
+async fn resolve_impl(
+    requirements: Vec<String>,
+    python: &Path,
+    index_url: Option<String>,
+    universal: bool,
+) -> Result<LockGraph> {
+    let cache = Cache::from_settings(false, None)?.init()?;
+    let interpreter = Interpreter::query(python, &cache)?;
+
+    let requirements = requirements
+        .iter()
+        .map(|requirement| {
+            uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement)
+                .map(Requirement::from)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let index_locations = IndexLocations::new(
+        index_url
+            .map(|url| IndexUrl::from_str(&url).map(Index::from_index_url))
+            .transpose()?
+            .into_iter()
+            .collect(),
+        Vec::new(),
+        false,
+    );
+
+    let client = RegistryClientBuilder::new(cache.clone())
+        .index_urls(index_locations.index_urls())
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    let build_options = BuildOptions::default();
+    let hasher = HashStrategy::None;
+    let tag_preferences = TagPreferences::default();
+    let flat_index = {
+        let client = FlatIndexClient::new(&client, &cache);
+        let entries = client
+            .fetch(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        let tags = if universal {
+            None
+        } else {
+            Some(interpreter.tags(&tag_preferences)?)
+        };
+        FlatIndex::from_entries(entries, tags.as_ref(), &hasher, &build_options)
+    };
+
+    let source_index = InMemoryIndex::default();
+    let git = GitResolver::default();
+    let capabilities = IndexCapabilities::default();
+    let in_flight = InFlight::default();
+    let dependency_metadata = DependencyMetadata::default();
+    let config_settings = ConfigSettings::default();
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        Constraints::default(),
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        &source_index,
+        &git,
+        &capabilities,
+        &in_flight,
+        IndexStrategy::default(),
+        &config_settings,
+        BuildIsolation::Isolated,
+        LinkMode::default(),
+        &build_options,
+        &hasher,
+        None,
+        LowerBound::default(),
+        SourceStrategy::default(),
+        Concurrency::default(),
+        &tag_preferences,
+    );
+
+    let mut builder = Resolver::builder(&client, &build_dispatch).flat_index(flat_index.clone());
+    if universal {
+        builder = builder.universal(Vec::new());
+    }
+    let graph = builder.resolve(requirements).await?;
+
+    let lock = Lock::from_resolution_graph(&graph, &std::env::current_dir()?)?;
+    Ok(LockGraph::from(&lock))
+}
+
+/// Construct a [`MarkerEnvironment`] from a mapping of PEP 508 marker names to values.
+fn marker_environment(environment: &HashMap<String, String>) -> PyResult<MarkerEnvironment> {
+    let get = |key: &str| {
+        environment.get(key).map(String::as_str).ok_or_else(|| {
+            PyValueError::new_err(format!("Missing marker environment key: `{key}`"))
+        })
+    };
+    MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+        implementation_name: get("implementation_name")?,
+        implementation_version: get("implementation_version")?,
+        os_name: get("os_name")?,
+        platform_machine: get("platform_machine")?,
+        platform_python_implementation: get("platform_python_implementation")?,
+        platform_release: get("platform_release")?,
+        platform_system: get("platform_system")?,
+        platform_version: get("platform_version")?,
+        python_full_version: get("python_full_version")?,
+        python_version: get("python_version")?,
+        sys_platform: get("sys_platform")?,
+    })
+    .map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+/// Return the path to the interpreter that's running this module.
+fn current_executable(py: Python<'_>) -> PyResult<PathBuf> {
+    py.import_bound("sys")?.getattr("executable")?.extract()
+}
+
+fn query_interpreter(python: &Path) -> Result<Interpreter> {
+    let cache = Cache::from_settings(false, None)?.init()?;
+    Ok(Interpreter::query(python, &cache)?)
+}
+
+fn to_py_err(err: anyhow::Error) -> PyErr {
+    PyException::new_err(format!("{err:#}"))
+}
+
+#[pymodule]
+fn uv_python_api(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyMarker>()?;
+    m.add_class::<Dependency>()?;
+    m.add_class::<Package>()?;
+    m.add_class::<LockGraph>()?;
+    m.add(
+        "ResolutionError",
+        m.py().get_type_bound::<ResolutionError>(),
+    )?;
+    m.add_function(wrap_pyfunction!(inspect_lock, m)?)?;
+    m.add_function(wrap_pyfunction!(resolve, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use indoc::indoc;
+
+    use uv_pep508::MarkerTree;
+    use uv_resolver::Lock;
+
+    use super::{marker_environment, LockGraph, PyMarker};
+
+    const LOCK: &str = indoc! {r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "anyio"
+        version = "4.3.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio", marker = "sys_platform == 'linux'" },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+        ]
+
+        [package.optional-dependencies]
+        dns = [
+            { name = "idna" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "anyio" },
+            { name = "idna", marker = "extra == 'dns'" },
+        ]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+    "#};
+
+    #[test]
+    fn lock_graph() {
+        let lock = toml::from_str::<Lock>(LOCK).unwrap();
+        let graph = LockGraph::from(&lock);
+
+        assert_eq!(graph.requires_python, ">=3.12");
+        assert_eq!(graph.packages.len(), 4);
+
+        let anyio = graph.package("anyio");
+        assert_eq!(anyio.len(), 1);
+        assert_eq!(anyio[0].version, "4.3.0");
+        let sniffio = anyio[0]
+            .dependencies
+            .iter()
+            .find(|dep| dep.name == "sniffio")
+            .unwrap();
+        assert_eq!(sniffio.version, "1.3.1");
+        // The markers of locked dependencies include the lockfile's `requires-python` bound.
+        assert_eq!(
+            sniffio.marker.__str__(),
+            "python_full_version >= '3.12' and sys_platform == 'linux'"
+        );
+
+        // Optional dependencies are tagged with the extra that declares them.
+        let project = graph.package("project");
+        let idna = project[0]
+            .dependencies
+            .iter()
+            .find(|dep| dep.name == "idna")
+            .unwrap();
+        assert_eq!(idna.extra.as_deref(), Some("dns"));
+        assert!(idna.group.is_none());
+
+        let dependents = graph
+            .dependents("idna")
+            .into_iter()
+            .map(|package| package.name)
+            .collect::<Vec<_>>();
+        assert_eq!(dependents, ["anyio", "project"]);
+
+        assert!(graph.package("flask").is_empty());
+    }
+
+    #[test]
+    fn marker_operators() {
+        let linux = PyMarker(MarkerTree::from_str("sys_platform == 'linux'").unwrap());
+        let darwin = PyMarker(MarkerTree::from_str("sys_platform == 'darwin'").unwrap());
+
+        assert!(linux.__and__(&darwin).is_never_true());
+        assert_eq!(
+            linux.__or__(&darwin).__str__(),
+            "sys_platform == 'darwin' or sys_platform == 'linux'"
+        );
+        assert!(PyMarker(MarkerTree::TRUE).is_always_true());
+        assert_eq!(PyMarker(MarkerTree::TRUE).__repr__(), "Marker(\"true\")");
+    }
+
+    #[test]
+    fn environment() {
+        let mut environment = HashMap::from(
+            [
+                ("implementation_name", "cpython"),
+                ("implementation_version", "3.12.1"),
+                ("os_name", "posix"),
+                ("platform_machine", "x86_64"),
+                ("platform_python_implementation", "CPython"),
+                ("platform_release", "6.5.0"),
+                ("platform_system", "Linux"),
+                ("platform_version", "#1 SMP"),
+                ("python_full_version", "3.12.1"),
+                ("python_version", "3.12"),
+                ("sys_platform", "linux"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+        let markers = marker_environment(&environment).unwrap();
+        let marker =
+            MarkerTree::from_str("sys_platform == 'linux' and python_version >= '3.12'").unwrap();
+        assert!(marker.evaluate(&markers, &[]));
+
+        environment.remove("sys_platform");
+        assert!(marker_environment(&environment).is_err());
+    }
+}
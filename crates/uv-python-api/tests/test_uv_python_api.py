@@ -0,0 +1,88 @@
+"""Tests for the `uv_python_api` extension module.
+
+Run with `maturin develop && python -m unittest discover tests` from the crate directory.
+"""
+
+import pathlib
+import sys
+import tempfile
+import textwrap
+import unittest
+
+import uv_python_api
+
+LOCK = textwrap.dedent(
+    """
+    version = 1
+    requires-python = ">=3.8"
+
+    [[package]]
+    name = "anyio"
+    version = "4.3.0"
+    source = { registry = "https://pypi.org/simple" }
+    dependencies = [
+        { name = "idna" },
+        { name = "sniffio" },
+    ]
+
+    [[package]]
+    name = "idna"
+    version = "3.6"
+    source = { registry = "https://pypi.org/simple" }
+
+    [[package]]
+    name = "sniffio"
+    version = "1.3.1"
+    source = { registry = "https://pypi.org/simple" }
+    """
+)
+
+
+class MarkerTest(unittest.TestCase):
+    def test_operators(self):
+        linux = uv_python_api.Marker("sys_platform == 'linux'")
+        darwin = uv_python_api.Marker("sys_platform == 'darwin'")
+        self.assertTrue((linux & darwin).is_never_true())
+        self.assertFalse((linux | darwin).is_always_true())
+        self.assertEqual(linux, uv_python_api.Marker("sys_platform == 'linux'"))
+
+    def test_evaluate(self):
+        marker = uv_python_api.Marker(f"sys_platform == '{sys.platform}'")
+        self.assertTrue(marker.evaluate())
+
+    def test_invalid(self):
+        with self.assertRaises(ValueError):
+            uv_python_api.Marker("sys_platform ==")
+
+
+class InspectLockTest(unittest.TestCase):
+    def test_inspect_lock(self):
+        with tempfile.TemporaryDirectory() as directory:
+            path = pathlib.Path(directory) / "uv.lock"
+            path.write_text(LOCK)
+            lock = uv_python_api.inspect_lock(path)
+
+        self.assertEqual(len(lock), 3)
+        self.assertEqual(lock.requires_python, ">=3.8")
+        self.assertEqual([package.name for package in lock.dependents("idna")], ["anyio"])
+        self.assertEqual(lock.package("sniffio")[0].version, "1.3.1")
+
+    def test_missing(self):
+        with self.assertRaises(Exception):
+            uv_python_api.inspect_lock("missing.lock")
+
+
+class ResolveTest(unittest.TestCase):
+    def test_resolve(self):
+        graph = uv_python_api.resolve(["anyio==4.3.0"], universal=True)
+        [anyio] = graph.package("anyio")
+        self.assertEqual(anyio.version, "4.3.0")
+        self.assertIn("idna", [dependency.name for dependency in anyio.dependencies])
+
+    def test_no_solution(self):
+        with self.assertRaises(uv_python_api.ResolutionError):
+            uv_python_api.resolve(["anyio==4.3.0", "anyio==4.2.0"])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,68 @@
+from os import PathLike
+from typing import Mapping, Optional, Sequence
+
+class ResolutionError(Exception):
+    """Raised when the requirements can't be resolved."""
+
+class Marker:
+    """A PEP 508 environment marker."""
+
+    def __init__(self, marker: str) -> None: ...
+    def is_always_true(self) -> bool: ...
+    def is_never_true(self) -> bool: ...
+    def evaluate(
+        self,
+        environment: Optional[Mapping[str, str]] = None,
+        extras: Sequence[str] = (),
+    ) -> bool: ...
+    def __and__(self, other: Marker) -> Marker: ...
+    def __or__(self, other: Marker) -> Marker: ...
+
+class Dependency:
+    """A direct dependency of a package."""
+
+    @property
+    def name(self) -> str: ...
+    @property
+    def version(self) -> str: ...
+    @property
+    def extras(self) -> list[str]: ...
+    @property
+    def marker(self) -> Marker: ...
+    @property
+    def extra(self) -> Optional[str]: ...
+    @property
+    def group(self) -> Optional[str]: ...
+
+class Package:
+    """A package in a resolved dependency graph."""
+
+    @property
+    def name(self) -> str: ...
+    @property
+    def version(self) -> str: ...
+    @property
+    def dependencies(self) -> list[Dependency]: ...
+
+class LockGraph:
+    """A resolved dependency graph, either from a resolution or from a `uv.lock` file."""
+
+    @property
+    def requires_python(self) -> str: ...
+    @property
+    def packages(self) -> list[Package]: ...
+    def package(self, name: str) -> list[Package]: ...
+    def dependents(self, name: str) -> list[Package]: ...
+    def __len__(self) -> int: ...
+
+def inspect_lock(path: str | PathLike[str]) -> LockGraph:
+    """Read the `uv.lock` file at the given path."""
+
+def resolve(
+    requirements: Sequence[str],
+    *,
+    python: Optional[str | PathLike[str]] = None,
+    index_url: Optional[str] = None,
+    universal: bool = False,
+) -> LockGraph:
+    """Resolve the given PEP 508 requirements."""
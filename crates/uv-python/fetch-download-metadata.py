@@ -120,6 +120,7 @@ class PythonDownload:
     filename: str
     url: str
     sha256: str | None = None
+    size: int | None = None
     build_options: list[str] = field(default_factory=list)
     variant: Variant | None = None
 
@@ -220,6 +221,7 @@ class CPythonFinder(Finder):
                     download = self._parse_download_url(url)
                     if download is None:
                         continue
+                    download.size = asset.get("size")
                     logging.debug("Found %s (%s)", download.key(), download.filename)
                     downloads_by_version.setdefault(download.version, []).append(
                         download
@@ -548,6 +550,7 @@ def render(downloads: list[PythonDownload]) -> None:
             "prerelease": download.version.prerelease,
             "url": download.url,
             "sha256": download.sha256,
+            "size": download.size,
             "variant": download.variant if download.variant else None,
         }
 
@@ -3,6 +3,7 @@ use regex::Regex;
 use same_file::is_same_file;
 use std::env::consts::EXE_SUFFIX;
 use std::fmt::{self, Debug, Formatter};
+use std::time::Instant;
 use std::{env, io, iter};
 use std::{path::Path, path::PathBuf, str::FromStr};
 use thiserror::Error;
@@ -979,6 +980,24 @@ pub(crate) fn find_python_installation(
     environments: EnvironmentPreference,
     preference: PythonPreference,
     cache: &Cache,
+) -> Result<FindPythonResult, Error> {
+    let start = Instant::now();
+    let result = find_first_python_installation(request, environments, preference, cache);
+    debug!(
+        "Python discovery for {request} completed in {:.2?}",
+        start.elapsed()
+    );
+    result
+}
+
+/// Find the first Python installation that satisfies the given request.
+///
+/// See [`find_python_installation`].
+fn find_first_python_installation(
+    request: &PythonRequest,
+    environments: EnvironmentPreference,
+    preference: PythonPreference,
+    cache: &Cache,
 ) -> Result<FindPythonResult, Error> {
     let installations = find_python_installations(request, environments, preference, cache);
     let mut first_prerelease = None;
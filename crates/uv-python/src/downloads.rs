@@ -88,6 +88,7 @@ pub struct ManagedPythonDownload {
     key: PythonInstallationKey,
     url: &'static str,
     sha256: Option<&'static str>,
+    size: Option<u64>,
 }
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
@@ -458,6 +459,11 @@ impl ManagedPythonDownload {
         self.sha256
     }
 
+    /// Return the size of the download, in bytes, if known.
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
     /// Download and extract
     #[instrument(skip(client, installation_dir, cache_dir, reporter), fields(download = % self.key()))]
     pub async fn fetch(
@@ -327,6 +327,34 @@ impl PythonInstallationKey {
         &self.libc
     }
 
+    pub fn variant(&self) -> &PythonVariant {
+        &self.variant
+    }
+
+    /// Return the end-of-life date of the Python minor version, as `YYYY-MM`, if known.
+    ///
+    /// Only CPython versions are tracked, per the release schedule at
+    /// <https://devguide.python.org/versions/>.
+    pub fn end_of_life(&self) -> Option<&'static str> {
+        if !matches!(
+            self.implementation,
+            LenientImplementationName::Known(ImplementationName::CPython)
+        ) {
+            return None;
+        }
+        match (self.major, self.minor) {
+            (3, 7) => Some("2023-06"),
+            (3, 8) => Some("2024-10"),
+            (3, 9) => Some("2025-10"),
+            (3, 10) => Some("2026-10"),
+            (3, 11) => Some("2027-10"),
+            (3, 12) => Some("2028-10"),
+            (3, 13) => Some("2029-10"),
+            (3, 14) => Some("2030-10"),
+            _ => None,
+        }
+    }
+
     /// Return a canonical name for a versioned executable.
     pub fn versioned_executable_name(&self) -> String {
         format!(
@@ -440,3 +468,34 @@ impl Ord for PythonInstallationKey {
             .then_with(|| self.variant.cmp(&other.variant))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::PythonInstallationKey;
+
+    #[test]
+    fn end_of_life() {
+        let end_of_life = |key: &str| PythonInstallationKey::from_str(key).unwrap().end_of_life();
+
+        assert_eq!(
+            end_of_life("cpython-3.8.20-linux-x86_64-gnu"),
+            Some("2024-10")
+        );
+        assert_eq!(
+            end_of_life("cpython-3.12.7-windows-x86_64-none"),
+            Some("2028-10")
+        );
+        // The end-of-life date applies to every variant and patch release of a minor version.
+        assert_eq!(
+            end_of_life("cpython-3.13.0rc3+freethreaded-macos-aarch64-none"),
+            Some("2029-10")
+        );
+        // Unknown minor versions have no known end-of-life date.
+        assert_eq!(end_of_life("cpython-3.6.15-linux-x86_64-gnu"), None);
+        assert_eq!(end_of_life("cpython-3.15.0-linux-x86_64-gnu"), None);
+        // Only CPython follows the CPython release schedule.
+        assert_eq!(end_of_life("pypy-3.10.14-linux-x86_64-gnu"), None);
+    }
+}
@@ -3,15 +3,16 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus};
 use std::sync::OnceLock;
+use std::time::Instant;
 
 use configparser::ini::Ini;
 use fs_err as fs;
 use same_file::is_same_file;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use tracing::{trace, warn};
+use tracing::{debug, trace, warn};
 
-use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness};
+use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness, MemoryCache};
 use uv_cache_info::Timestamp;
 use uv_cache_key::cache_digest;
 use uv_fs::{write_atomic_sync, PythonExt, Simplified};
@@ -19,7 +20,7 @@ use uv_install_wheel::Layout;
 use uv_pep440::Version;
 use uv_pep508::{MarkerEnvironment, StringVersion};
 use uv_platform_tags::Platform;
-use uv_platform_tags::{Tags, TagsError};
+use uv_platform_tags::{TagPreferences, Tags, TagsError};
 use uv_pypi_types::{ResolverMarkerEnvironment, Scheme};
 
 use crate::implementation::LenientImplementationName;
@@ -46,7 +47,7 @@ pub struct Interpreter {
     sys_path: Vec<PathBuf>,
     stdlib: PathBuf,
     sysconfig_prefix: Option<PathBuf>,
-    tags: OnceLock<Tags>,
+    tags: OnceLock<(TagPreferences, Tags)>,
     target: Option<Target>,
     prefix: Option<Prefix>,
     pointer_size: PointerSize,
@@ -191,20 +192,26 @@ impl Interpreter {
         Os::from(self.platform().os())
     }
 
-    /// Returns the [`Tags`] for this Python executable.
-    pub fn tags(&self) -> Result<&Tags, TagsError> {
-        if self.tags.get().is_none() {
-            let tags = Tags::from_env(
-                self.platform(),
-                self.python_tuple(),
-                self.implementation_name(),
-                self.implementation_tuple(),
-                self.manylinux_compatible,
-                self.gil_disabled,
-            )?;
-            self.tags.set(tags).expect("tags should not be set");
+    /// Returns the [`Tags`] for this Python executable, prioritized according to the given
+    /// [`TagPreferences`].
+    pub fn tags(&self, preferences: &TagPreferences) -> Result<Tags, TagsError> {
+        if let Some((cached, tags)) = self.tags.get() {
+            if cached == preferences {
+                return Ok(tags.clone());
+            }
         }
-        Ok(self.tags.get().expect("tags should be set"))
+        let tags = Tags::from_env(
+            self.platform(),
+            self.python_tuple(),
+            self.implementation_name(),
+            self.implementation_tuple(),
+            self.manylinux_compatible,
+            self.gil_disabled,
+            preferences,
+        )?;
+        // In practice, a single set of preferences is used per process, so only the first is cached.
+        let _ = self.tags.set((preferences.clone(), tags.clone()));
+        Ok(tags)
     }
 
     /// Returns `true` if the environment is a PEP 405-compliant virtual environment.
@@ -448,7 +455,11 @@ impl Interpreter {
     pub fn layout(&self) -> Layout {
         Layout {
             python_version: self.python_tuple(),
-            sys_executable: self.sys_executable().to_path_buf(),
+            sys_executable: if let Some(prefix) = self.prefix.as_ref() {
+                prefix.executable(self.sys_executable())
+            } else {
+                self.sys_executable().to_path_buf()
+            },
             os_name: self.markers.os_name().to_string(),
             scheme: if let Some(target) = self.target.as_ref() {
                 target.scheme()
@@ -473,6 +484,7 @@ impl Interpreter {
                     },
                 }
             },
+            install_scripts: self.target.as_ref().map_or(true, Target::scripts),
         }
     }
 
@@ -611,6 +623,16 @@ pub enum InterpreterInfoError {
     UnsupportedPython,
 }
 
+/// The interpreter info for each (absolute) executable path, along with the timestamp of the
+/// underlying interpreter, for use by long-lived processes.
+static INTERPRETERS: MemoryCache<PathBuf, (Timestamp, InterpreterInfo)> = MemoryCache::new(64);
+
+/// Return the executables that have been queried since the last call, such that their interpreter
+/// info can be preloaded by `uv daemon`.
+pub fn take_queried_interpreters() -> Vec<PathBuf> {
+    INTERPRETERS.take_updated()
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 struct InterpreterInfo {
     platform: Platform,
@@ -764,6 +786,13 @@ impl InterpreterInfo {
                 }
             })?;
 
+        // Read from the in-memory cache.
+        if let Some((_, info)) =
+            INTERPRETERS.get(&absolute, |(timestamp, _)| *timestamp == modified)
+        {
+            return Ok(info);
+        }
+
         // Read from the cache.
         if cache
             .freshness(&cache_entry, None)
@@ -778,6 +807,7 @@ impl InterpreterInfo {
                                 cached.data.markers.python_full_version(),
                                 executable.user_display()
                             );
+                            INTERPRETERS.insert(absolute, (modified, cached.data.clone()));
                             return Ok(cached.data);
                         }
 
@@ -802,7 +832,13 @@ impl InterpreterInfo {
             "Querying interpreter executable at {}",
             executable.display()
         );
+        let start = Instant::now();
         let info = Self::query(executable, cache)?;
+        debug!(
+            "Queried interpreter at {} in {:.2?}",
+            executable.user_display(),
+            start.elapsed()
+        );
 
         // If `executable` is a pyenv shim, a bash script that redirects to the activated
         // python executable at another path, we're not allowed to cache the interpreter info.
@@ -815,6 +851,7 @@ impl InterpreterInfo {
                     data: info.clone(),
                 })?,
             )?;
+            INTERPRETERS.insert(absolute, (modified, info.clone()));
         }
 
         Ok(info)
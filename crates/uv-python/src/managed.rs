@@ -86,6 +86,36 @@ pub enum Error {
     NameParseError(#[from] installation::PythonInstallationKeyError),
     #[error(transparent)]
     LibcDetection(#[from] LibcDetectionError),
+    #[cfg(windows)]
+    #[error("Failed to update the Windows registry entry for {0}")]
+    Registry(PythonInstallationKey, #[source] windows_result::Error),
+}
+
+/// The scope in which to register managed Python installations in the Windows registry, following
+/// [PEP 514](https://peps.python.org/pep-0514/).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum RegistryScope {
+    /// Register the installation for the current user (i.e., in `HKEY_CURRENT_USER`).
+    #[default]
+    User,
+    /// Register the installation for all users (i.e., in `HKEY_LOCAL_MACHINE`), which requires
+    /// administrator privileges.
+    Machine,
+}
+
+impl RegistryScope {
+    /// All registry scopes.
+    pub const ALL: [Self; 2] = [Self::User, Self::Machine];
+}
+
+impl fmt::Display for RegistryScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::User => f.write_str("user"),
+            Self::Machine => f.write_str("machine"),
+        }
+    }
 }
 /// A collection of uv-managed Python installations installed on the current system.
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -543,6 +573,34 @@ impl ManagedPythonInstallation {
         }
     }
 
+    /// Register the installation in the Windows registry, such that it's discoverable by other
+    /// tools, like the `py` launcher.
+    #[cfg(windows)]
+    pub fn register(&self, scope: RegistryScope) -> Result<(), Error> {
+        crate::py_launcher::register_installation(self, scope)
+            .map_err(|err| Error::Registry(self.key.clone(), err))
+    }
+
+    /// Remove the installation from the Windows registry in the given scope.
+    ///
+    /// Returns `true` if the installation was registered.
+    #[cfg(windows)]
+    pub fn deregister(&self, scope: RegistryScope) -> Result<bool, Error> {
+        crate::py_launcher::deregister_installation(self, scope)
+            .map_err(|err| Error::Registry(self.key.clone(), err))
+    }
+
+    /// Returns `true` if self can replace other as the base interpreter of a virtual environment,
+    /// i.e., if it has the same implementation, variant, platform, and minor version.
+    pub fn can_replace(&self, other: &ManagedPythonInstallation) -> bool {
+        self.key.implementation == other.key.implementation
+            && self.key.variant == other.key.variant
+            && (self.key.major, self.key.minor) == (other.key.major, other.key.minor)
+            && (self.key.os, self.key.arch, self.key.libc)
+                == (other.key.os, other.key.arch, other.key.libc)
+            && self.key != other.key
+    }
+
     /// Returns `true` if self is a suitable upgrade of other.
     pub fn is_upgrade_of(&self, other: &ManagedPythonInstallation) -> bool {
         // Require matching implementation
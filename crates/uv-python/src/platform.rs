@@ -179,6 +179,7 @@ impl From<&uv_platform_tags::Arch> for Arch {
             uv_platform_tags::Arch::Riscv64 => Self(target_lexicon::Architecture::Riscv64(
                 target_lexicon::Riscv64Architecture::Riscv64,
             )),
+            uv_platform_tags::Arch::Wasm32 => Self(target_lexicon::Architecture::Wasm32),
         }
     }
 }
@@ -209,6 +210,9 @@ impl From<&uv_platform_tags::Os> for Os {
             uv_platform_tags::Os::NetBsd { .. } => Self(target_lexicon::OperatingSystem::Netbsd),
             uv_platform_tags::Os::OpenBsd { .. } => Self(target_lexicon::OperatingSystem::Openbsd),
             uv_platform_tags::Os::Windows => Self(target_lexicon::OperatingSystem::Windows),
+            uv_platform_tags::Os::Pyodide { .. } => {
+                Self(target_lexicon::OperatingSystem::Emscripten)
+            }
         }
     }
 }
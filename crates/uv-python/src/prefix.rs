@@ -1,27 +1,59 @@
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use uv_pypi_types::Scheme;
 
 /// A `--prefix` directory into which packages can be installed, separate from a virtual environment
 /// or system Python interpreter.
+///
+/// The installation may be staged under a `--root` directory, in which case files are written to
+/// the `--prefix` directory within the `--root`, but the installed packages reference the
+/// `--prefix` directory itself, like `DESTDIR` in a `make install`.
 #[derive(Debug, Clone)]
-pub struct Prefix(PathBuf);
+pub struct Prefix {
+    /// The directory into which packages are installed, i.e., the `--prefix` directory, or the
+    /// `--prefix` directory within the `--root` directory.
+    install_dir: PathBuf,
+    /// The `--root` directory under which the installation is staged, if any.
+    root: Option<PathBuf>,
+}
 
 impl Prefix {
+    /// Stage the installation under the given `--root` directory.
+    #[must_use]
+    pub fn with_root(self, root: PathBuf) -> Self {
+        // Strip any leading `/` (or, on Windows, the drive prefix), such that the `--prefix`
+        // directory is nested within the root.
+        let relative = self
+            .install_dir
+            .components()
+            .filter(|component| !matches!(component, Component::Prefix(_) | Component::RootDir))
+            .collect::<PathBuf>();
+        Self {
+            install_dir: root.join(relative),
+            root: Some(root),
+        }
+    }
+
     /// Return the [`Scheme`] for the `--prefix` directory.
     pub fn scheme(&self, virtualenv: &Scheme) -> Scheme {
         Scheme {
-            purelib: self.0.join(&virtualenv.purelib),
-            platlib: self.0.join(&virtualenv.platlib),
-            scripts: self.0.join(&virtualenv.scripts),
-            data: self.0.join(&virtualenv.data),
-            include: self.0.join(&virtualenv.include),
+            purelib: self.install_dir.join(&virtualenv.purelib),
+            platlib: self.install_dir.join(&virtualenv.platlib),
+            scripts: self.install_dir.join(&virtualenv.scripts),
+            data: self.install_dir.join(&virtualenv.data),
+            include: self.install_dir.join(&virtualenv.include),
         }
     }
 
     /// Return an iterator over the `site-packages` directories inside the environment.
+    ///
+    /// Includes the `platlib` directory, if it differs from the `purelib` directory (e.g., `lib64`
+    /// on some Linux distributions), since platform-specific wheels are installed into it.
     pub fn site_packages(&self, virtualenv: &Scheme) -> impl Iterator<Item = PathBuf> {
-        std::iter::once(self.0.join(&virtualenv.purelib))
+        let purelib = self.install_dir.join(&virtualenv.purelib);
+        let platlib = self.install_dir.join(&virtualenv.platlib);
+        let platlib = (platlib != purelib).then_some(platlib);
+        std::iter::once(purelib).chain(platlib)
     }
 
     /// Initialize the `--prefix` directory.
@@ -32,14 +64,33 @@ impl Prefix {
         Ok(())
     }
 
-    /// Return the path to the `--prefix` directory.
+    /// Return the Python executable that installed scripts should reference.
+    ///
+    /// If the installation is staged under a `--root` that contains the interpreter (e.g., when
+    /// installing into a staged distribution image), the `--root` is stripped, such that the scripts
+    /// reference its final location.
+    pub fn executable(&self, sys_executable: &Path) -> PathBuf {
+        let Some(relative) = self
+            .root
+            .as_ref()
+            .and_then(|root| sys_executable.strip_prefix(root).ok())
+        else {
+            return sys_executable.to_path_buf();
+        };
+        Path::new(std::path::MAIN_SEPARATOR_STR).join(relative)
+    }
+
+    /// Return the path to the directory into which packages are installed.
     pub fn root(&self) -> &Path {
-        &self.0
+        &self.install_dir
     }
 }
 
 impl From<PathBuf> for Prefix {
     fn from(path: PathBuf) -> Self {
-        Self(path)
+        Self {
+            install_dir: path,
+            root: None,
+        }
     }
 }
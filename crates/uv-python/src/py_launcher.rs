@@ -1,10 +1,18 @@
+use crate::managed::{ManagedPythonInstallation, RegistryScope};
 use crate::PythonVersion;
 use std::cmp::Ordering;
 use std::path::PathBuf;
 use std::str::FromStr;
+use target_lexicon::PointerWidth;
 use tracing::debug;
 use windows_registry::{Key, CURRENT_USER, LOCAL_MACHINE};
 
+/// The PEP 514 company under which uv registers its managed Python installations.
+const COMPANY: &str = "Astral";
+
+/// The support URL to register for uv's managed Python installations.
+const SUPPORT_URL: &str = "https://github.com/astral-sh/uv";
+
 /// A Python interpreter found in the Windows registry through PEP 514 or from a known Microsoft
 /// Store path.
 ///
@@ -28,6 +36,11 @@ pub(crate) fn registry_pythons() -> Result<Vec<WindowsPython>, windows_result::E
             if company == "PyLauncher" {
                 continue;
             }
+            // Installations registered by uv are managed installations, which are discovered
+            // separately.
+            if company == COMPANY {
+                continue;
+            }
             let Ok(company_key) = key_python.open(&company) else {
                 // Ignore invalid entries
                 continue;
@@ -96,3 +109,94 @@ fn read_registry_entry(company: &str, tag: &str, tag_key: &Key) -> Option<Window
         version,
     })
 }
+
+/// Return the registry root for the given [`RegistryScope`].
+fn root_key(scope: RegistryScope) -> &'static Key {
+    match scope {
+        RegistryScope::User => CURRENT_USER,
+        RegistryScope::Machine => LOCAL_MACHINE,
+    }
+}
+
+/// Register a managed Python installation in the Windows registry following PEP 514, such that
+/// it's discoverable by other tools, like the `py` launcher (e.g., `py -V:Astral/<key>`).
+pub(crate) fn register_installation(
+    installation: &ManagedPythonInstallation,
+    scope: RegistryScope,
+) -> Result<(), windows_result::Error> {
+    let key = installation.key();
+    let executable = installation.executable();
+
+    let company = root_key(scope).create(format!(r"Software\Python\{COMPANY}"))?;
+    company.set_string("DisplayName", "Astral Software Inc.")?;
+    company.set_string("SupportUrl", SUPPORT_URL)?;
+
+    let architecture = match key.arch().pointer_width() {
+        Ok(PointerWidth::U16 | PointerWidth::U32) => "32bit",
+        Ok(PointerWidth::U64) | Err(()) => "64bit",
+    };
+
+    let tag = company.create(key.to_string())?;
+    tag.set_string(
+        "DisplayName",
+        format!(
+            "{} {} ({architecture})",
+            installation.implementation().pretty(),
+            key.version()
+        )
+        .as_str(),
+    )?;
+    tag.set_string("SupportUrl", SUPPORT_URL)?;
+    tag.set_string("Version", key.version().to_string().as_str())?;
+    tag.set_string(
+        "SysVersion",
+        format!("{}.{}", key.major, key.minor).as_str(),
+    )?;
+    tag.set_string("SysArchitecture", architecture)?;
+
+    let install_path = tag.create("InstallPath")?;
+    if let Some(parent) = executable.parent() {
+        install_path.set_string("", parent.to_string_lossy().as_ref())?;
+    }
+    install_path.set_string("ExecutablePath", executable.to_string_lossy().as_ref())?;
+
+    // The windowed executable (e.g., `pythonw.exe`) is optional.
+    if let Some(name) = executable.file_stem().and_then(|stem| stem.to_str()) {
+        let windowed = executable.with_file_name(format!("{name}w.exe"));
+        if windowed.is_file() {
+            install_path.set_string(
+                "WindowedExecutablePath",
+                windowed.to_string_lossy().as_ref(),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove the PEP 514 registration of a managed Python installation, along with the company key
+/// if no other installations remain registered.
+///
+/// Returns `true` if the installation was registered in the given scope.
+pub(crate) fn deregister_installation(
+    installation: &ManagedPythonInstallation,
+    scope: RegistryScope,
+) -> Result<bool, windows_result::Error> {
+    let root = root_key(scope);
+    let company_path = format!(r"Software\Python\{COMPANY}");
+    let Ok(company) = root.open(&company_path) else {
+        return Ok(false);
+    };
+
+    let tag = installation.key().to_string();
+    if company.open(&tag).is_err() {
+        return Ok(false);
+    }
+    company.remove_tree(&tag)?;
+
+    if company.keys()?.next().is_none() {
+        root.remove_tree(&company_path)?;
+    }
+
+    Ok(true)
+}
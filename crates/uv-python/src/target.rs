@@ -5,39 +5,60 @@ use uv_pypi_types::Scheme;
 /// A `--target` directory into which packages can be installed, separate from a virtual environment
 /// or system Python interpreter.
 #[derive(Debug, Clone)]
-pub struct Target(PathBuf);
+pub struct Target {
+    root: PathBuf,
+    scripts: bool,
+}
 
 impl Target {
+    /// Omit scripts (i.e., entry points and any scripts included in a wheel) when installing into
+    /// the `--target` directory, as for deployment bundles that are never executed directly.
+    #[must_use]
+    pub fn without_scripts(self) -> Self {
+        Self {
+            scripts: false,
+            ..self
+        }
+    }
+
+    /// Returns `true` if scripts should be installed into the `--target` directory.
+    pub fn scripts(&self) -> bool {
+        self.scripts
+    }
+
     /// Return the [`Scheme`] for the `--target` directory.
     pub fn scheme(&self) -> Scheme {
         Scheme {
-            purelib: self.0.clone(),
-            platlib: self.0.clone(),
-            scripts: self.0.join("bin"),
-            data: self.0.clone(),
-            include: self.0.join("include"),
+            purelib: self.root.clone(),
+            platlib: self.root.clone(),
+            scripts: self.root.join("bin"),
+            data: self.root.clone(),
+            include: self.root.join("include"),
         }
     }
 
     /// Return an iterator over the `site-packages` directories inside the environment.
     pub fn site_packages(&self) -> impl Iterator<Item = &Path> {
-        std::iter::once(self.0.as_path())
+        std::iter::once(self.root.as_path())
     }
 
     /// Initialize the `--target` directory.
     pub fn init(&self) -> std::io::Result<()> {
-        fs_err::create_dir_all(&self.0)?;
+        fs_err::create_dir_all(&self.root)?;
         Ok(())
     }
 
     /// Return the path to the `--target` directory.
     pub fn root(&self) -> &Path {
-        &self.0
+        &self.root
     }
 }
 
 impl From<PathBuf> for Target {
-    fn from(path: PathBuf) -> Self {
-        Self(path)
+    fn from(root: PathBuf) -> Self {
+        Self {
+            root,
+            scripts: true,
+        }
     }
 }
@@ -34,6 +34,8 @@ pub struct PyVenvConfiguration {
     pub(crate) relocatable: bool,
     /// Was the virtual environment populated with seed packages?
     pub(crate) seed: bool,
+    /// The directory containing the base Python interpreter, i.e., the `home` key.
+    pub(crate) home: Option<PathBuf>,
 }
 
 #[derive(Debug, Error)]
@@ -185,6 +187,7 @@ impl PyVenvConfiguration {
         let mut uv = false;
         let mut relocatable = false;
         let mut seed = false;
+        let mut home = None;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -208,6 +211,9 @@ impl PyVenvConfiguration {
                 "seed" => {
                     seed = value.trim().to_lowercase() == "true";
                 }
+                "home" => {
+                    home = Some(PathBuf::from(value.trim()));
+                }
                 _ => {}
             }
         }
@@ -217,6 +223,7 @@ impl PyVenvConfiguration {
             uv,
             relocatable,
             seed,
+            home,
         })
     }
 
@@ -239,4 +246,9 @@ impl PyVenvConfiguration {
     pub fn is_seed(&self) -> bool {
         self.seed
     }
+
+    /// Returns the directory containing the base Python interpreter, if set.
+    pub fn home(&self) -> Option<&Path> {
+        self.home.as_deref()
+    }
 }
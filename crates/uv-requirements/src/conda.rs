@@ -0,0 +1,172 @@
+//! Extract the `pip` dependencies from a conda `environment.yml` file.
+//!
+//! A conda environment file lists its dependencies under a top-level `dependencies` key, with
+//! packages to be installed by pip nested in a `pip` entry:
+//!
+//! ```yaml
+//! name: example
+//! dependencies:
+//!   - python=3.12
+//!   - numpy
+//!   - pip:
+//!     - flask>=3
+//!     - -r requirements.txt
+//! ```
+//!
+//! Only the block-style subset of YAML that conda itself emits (e.g., via `conda env export`) is
+//! supported. The conda dependencies are ignored, as they're managed by conda.
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CondaEnvironmentError {
+    #[error("Expected a list of dependencies at line {0}")]
+    ExpectedList(usize),
+    #[error("Flow-style lists (e.g., `[a, b]`) are not supported at line {0}")]
+    FlowList(usize),
+    #[error("Unterminated string at line {0}")]
+    UnterminatedString(usize),
+}
+
+/// Return the entries of the `pip` section of a conda environment file, in `requirements.txt`
+/// format.
+pub(crate) fn pip_requirements(content: &str) -> Result<Vec<String>, CondaEnvironmentError> {
+    let mut requirements = Vec::new();
+
+    // The state of the parser, as we descend into `dependencies` and its `pip` entry.
+    let mut dependencies: Option<Section> = None;
+    let mut pip: Option<Section> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let number = index + 1;
+        let line = strip_comment(line);
+        if line.trim().is_empty() {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        let line = line.trim();
+
+        // Collect the entries of the `pip` section, until we reach an entry that isn't nested
+        // within it.
+        if let Some(section) = pip {
+            let items = section.items.unwrap_or(indent);
+            if indent > section.indent && indent == items {
+                let Some(value) = line.strip_prefix('-') else {
+                    return Err(CondaEnvironmentError::ExpectedList(number));
+                };
+                requirements.push(scalar(value, number)?);
+                pip = Some(Section {
+                    items: Some(items),
+                    ..section
+                });
+                continue;
+            }
+            if indent > items {
+                return Err(CondaEnvironmentError::ExpectedList(number));
+            }
+            pip = None;
+        }
+
+        // Search the `dependencies` section for a `pip` entry, until we reach another top-level
+        // key.
+        if let Some(section) = dependencies {
+            let nested =
+                line.starts_with('-') && section.items.map_or(true, |items| indent == items);
+            if nested {
+                dependencies = Some(Section {
+                    items: Some(indent),
+                    ..section
+                });
+                let value = line[1..].trim();
+                if let Some(rest) = value.strip_prefix("pip") {
+                    if let Some(rest) = rest.trim_start().strip_prefix(':') {
+                        let rest = rest.trim();
+                        if rest.starts_with('[') {
+                            return Err(CondaEnvironmentError::FlowList(number));
+                        }
+                        if !rest.is_empty() {
+                            return Err(CondaEnvironmentError::ExpectedList(number));
+                        }
+                        // The `pip` entries are nested beyond the start of the `pip` key.
+                        pip = Some(Section {
+                            indent: indent + 1,
+                            items: None,
+                        });
+                    }
+                }
+                continue;
+            }
+            if indent > section.indent {
+                continue;
+            }
+            dependencies = None;
+        }
+
+        if indent == 0 {
+            if let Some(rest) = line.strip_prefix("dependencies") {
+                if let Some(rest) = rest.trim_start().strip_prefix(':') {
+                    let rest = rest.trim();
+                    if rest.starts_with('[') {
+                        return Err(CondaEnvironmentError::FlowList(number));
+                    }
+                    if !rest.is_empty() {
+                        return Err(CondaEnvironmentError::ExpectedList(number));
+                    }
+                    dependencies = Some(Section {
+                        indent: 0,
+                        items: None,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(requirements)
+}
+
+/// A YAML block, identified by the indentation of its key and of its list items.
+#[derive(Debug, Copy, Clone)]
+struct Section {
+    indent: usize,
+    items: Option<usize>,
+}
+
+/// Strip a trailing comment from a line, ignoring `#` characters within quotes and those that
+/// aren't preceded by whitespace (e.g., in a URL fragment).
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (index, char) in line.char_indices() {
+        match (quote, char) {
+            (None, '"' | '\'') => quote = Some(char),
+            (Some(open), _) if open == char => quote = None,
+            (None, '#') if previous.is_whitespace() => return &line[..index],
+            _ => {}
+        }
+        previous = char;
+    }
+    line
+}
+
+/// Parse a YAML scalar, removing the surrounding quotes, if any.
+fn scalar(value: &str, number: usize) -> Result<String, CondaEnvironmentError> {
+    let value = value.trim();
+    if value.starts_with('[') {
+        return Err(CondaEnvironmentError::FlowList(number));
+    }
+    let Some(quote) = value
+        .chars()
+        .next()
+        .filter(|char| matches!(char, '"' | '\''))
+    else {
+        return Ok(value.to_string());
+    };
+    let Some(inner) = value[1..].strip_suffix(quote) else {
+        return Err(CondaEnvironmentError::UnterminatedString(number));
+    };
+    if quote == '\'' {
+        Ok(inner.replace("''", "'"))
+    } else {
+        Ok(inner.replace("\\\"", "\"").replace("\\\\", "\\"))
+    }
+}
@@ -0,0 +1,611 @@
+//! Discover the third-party imports in a project's Python sources, and map them to the
+//! distributions that provide them (as in `uv add --from-imports`).
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use fs_err as fs;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_normalize::PackageName;
+
+/// Directories that never contain the project's own sources, and are skipped when scanning (along
+/// with any hidden directories and virtual environments).
+const EXCLUDED_DIRECTORIES: &[&str] = &[
+    "__pycache__",
+    "build",
+    "dist",
+    "node_modules",
+    "site-packages",
+];
+
+/// The top-level modules of the standard library, across all supported Python versions.
+const STDLIB_MODULES: &[&str] = &[
+    "__future__",
+    "abc",
+    "aifc",
+    "argparse",
+    "array",
+    "ast",
+    "asynchat",
+    "asyncio",
+    "asyncore",
+    "atexit",
+    "audioop",
+    "base64",
+    "bdb",
+    "binascii",
+    "bisect",
+    "builtins",
+    "bz2",
+    "cProfile",
+    "calendar",
+    "cgi",
+    "cgitb",
+    "chunk",
+    "cmath",
+    "cmd",
+    "code",
+    "codecs",
+    "codeop",
+    "collections",
+    "colorsys",
+    "compileall",
+    "concurrent",
+    "configparser",
+    "contextlib",
+    "contextvars",
+    "copy",
+    "copyreg",
+    "crypt",
+    "csv",
+    "ctypes",
+    "curses",
+    "dataclasses",
+    "datetime",
+    "dbm",
+    "decimal",
+    "difflib",
+    "dis",
+    "distutils",
+    "doctest",
+    "email",
+    "encodings",
+    "ensurepip",
+    "enum",
+    "errno",
+    "faulthandler",
+    "fcntl",
+    "filecmp",
+    "fileinput",
+    "fnmatch",
+    "fractions",
+    "ftplib",
+    "functools",
+    "gc",
+    "getopt",
+    "getpass",
+    "gettext",
+    "glob",
+    "graphlib",
+    "grp",
+    "gzip",
+    "hashlib",
+    "heapq",
+    "hmac",
+    "html",
+    "http",
+    "idlelib",
+    "imaplib",
+    "imghdr",
+    "imp",
+    "importlib",
+    "inspect",
+    "io",
+    "ipaddress",
+    "itertools",
+    "json",
+    "keyword",
+    "lib2to3",
+    "linecache",
+    "locale",
+    "logging",
+    "lzma",
+    "mailbox",
+    "mailcap",
+    "marshal",
+    "math",
+    "mimetypes",
+    "mmap",
+    "modulefinder",
+    "msilib",
+    "msvcrt",
+    "multiprocessing",
+    "netrc",
+    "nis",
+    "nntplib",
+    "ntpath",
+    "numbers",
+    "opcode",
+    "operator",
+    "optparse",
+    "os",
+    "ossaudiodev",
+    "pathlib",
+    "pdb",
+    "pickle",
+    "pickletools",
+    "pipes",
+    "pkgutil",
+    "platform",
+    "plistlib",
+    "poplib",
+    "posix",
+    "posixpath",
+    "pprint",
+    "profile",
+    "pstats",
+    "pty",
+    "pwd",
+    "py_compile",
+    "pyclbr",
+    "pydoc",
+    "pydoc_data",
+    "pyexpat",
+    "queue",
+    "quopri",
+    "random",
+    "re",
+    "readline",
+    "reprlib",
+    "resource",
+    "rlcompleter",
+    "runpy",
+    "sched",
+    "secrets",
+    "select",
+    "selectors",
+    "shelve",
+    "shlex",
+    "shutil",
+    "signal",
+    "site",
+    "smtpd",
+    "smtplib",
+    "sndhdr",
+    "socket",
+    "socketserver",
+    "spwd",
+    "sqlite3",
+    "sre_compile",
+    "sre_constants",
+    "sre_parse",
+    "ssl",
+    "stat",
+    "statistics",
+    "string",
+    "stringprep",
+    "struct",
+    "subprocess",
+    "sunau",
+    "symtable",
+    "sys",
+    "sysconfig",
+    "syslog",
+    "tabnanny",
+    "tarfile",
+    "telnetlib",
+    "tempfile",
+    "termios",
+    "textwrap",
+    "this",
+    "threading",
+    "time",
+    "timeit",
+    "tkinter",
+    "token",
+    "tokenize",
+    "tomllib",
+    "trace",
+    "traceback",
+    "tracemalloc",
+    "tty",
+    "turtle",
+    "turtledemo",
+    "types",
+    "typing",
+    "unicodedata",
+    "unittest",
+    "urllib",
+    "uu",
+    "uuid",
+    "venv",
+    "warnings",
+    "wave",
+    "weakref",
+    "webbrowser",
+    "winreg",
+    "winsound",
+    "wsgiref",
+    "xdrlib",
+    "xml",
+    "xmlrpc",
+    "zipapp",
+    "zipfile",
+    "zipimport",
+    "zlib",
+    "zoneinfo",
+];
+
+/// Top-level modules that are provided by a distribution with a different name.
+const MODULE_DISTRIBUTIONS: &[(&str, &str)] = &[
+    ("Crypto", "pycryptodome"),
+    ("MySQLdb", "mysqlclient"),
+    ("OpenSSL", "pyopenssl"),
+    ("PIL", "pillow"),
+    ("Xlib", "python-xlib"),
+    ("attr", "attrs"),
+    ("bs4", "beautifulsoup4"),
+    ("cv2", "opencv-python"),
+    ("dateutil", "python-dateutil"),
+    ("dns", "dnspython"),
+    ("docx", "python-docx"),
+    ("dotenv", "python-dotenv"),
+    ("faiss", "faiss-cpu"),
+    ("fitz", "pymupdf"),
+    ("gi", "pygobject"),
+    ("git", "gitpython"),
+    ("googleapiclient", "google-api-python-client"),
+    ("grpc", "grpcio"),
+    ("jose", "python-jose"),
+    ("jwt", "pyjwt"),
+    ("kafka", "kafka-python"),
+    ("ldap", "python-ldap"),
+    ("magic", "python-magic"),
+    ("mpl_toolkits", "matplotlib"),
+    ("multipart", "python-multipart"),
+    ("pkg_resources", "setuptools"),
+    ("ruamel", "ruamel-yaml"),
+    ("serial", "pyserial"),
+    ("skimage", "scikit-image"),
+    ("sklearn", "scikit-learn"),
+    ("slugify", "python-slugify"),
+    ("socks", "pysocks"),
+    ("telegram", "python-telegram-bot"),
+    ("usb", "pyusb"),
+    ("websocket", "websocket-client"),
+    ("win32api", "pywin32"),
+    ("win32con", "pywin32"),
+    ("xdist", "pytest-xdist"),
+    ("yaml", "pyyaml"),
+    ("zmq", "pyzmq"),
+];
+
+/// The third-party imports found in a project's Python sources.
+#[derive(Debug, Default)]
+pub struct ThirdPartyImports {
+    /// The distributions that provide the imported modules, along with the imported top-level
+    /// module and the first file that imports it.
+    packages: BTreeMap<PackageName, (String, PathBuf)>,
+    /// The imported modules that couldn't be mapped to a distribution, along with the first file
+    /// that imports each.
+    unresolved: BTreeMap<String, PathBuf>,
+    /// The imported third-party top-level modules.
+    modules: BTreeSet<String>,
+}
+
+impl ThirdPartyImports {
+    /// Scan the Python sources in the given directory for imports of third-party modules, i.e.,
+    /// modules that are neither part of the standard library nor defined within the directory.
+    ///
+    /// Nested projects (i.e., directories with their own `pyproject.toml`), hidden directories,
+    /// and virtual environments are skipped.
+    pub fn scan(root: &Path) -> io::Result<Self> {
+        let mut local = BTreeSet::new();
+        let mut imports = BTreeMap::<String, PathBuf>::new();
+
+        let walker = WalkDir::new(root).into_iter().filter_entry(|entry| {
+            if entry.depth() == 0 || !entry.file_type().is_dir() {
+                return true;
+            }
+            let Some(name) = entry.file_name().to_str() else {
+                return false;
+            };
+            !name.starts_with('.')
+                && !EXCLUDED_DIRECTORIES.contains(&name)
+                && !entry.path().join("pyvenv.cfg").is_file()
+                && !entry.path().join("pyproject.toml").is_file()
+        });
+        for entry in walker {
+            let entry = entry.map_err(io::Error::other)?;
+            let path = entry.path();
+            if entry.file_type().is_dir() || !path.extension().is_some_and(|ext| ext == "py") {
+                continue;
+            }
+
+            // Any module or package defined within the project is local.
+            if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                local.insert(stem.to_string());
+            }
+            for ancestor in path.ancestors().skip(1) {
+                if ancestor == root {
+                    break;
+                }
+                if let Some(name) = ancestor.file_name().and_then(|name| name.to_str()) {
+                    local.insert(name.to_string());
+                }
+            }
+
+            let source = match fs::read_to_string(path) {
+                Ok(source) => source,
+                Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+                    debug!("Skipping non-UTF-8 file: {}", path.display());
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+            for module in parse_imports(&source) {
+                imports.entry(module).or_insert_with(|| path.to_path_buf());
+            }
+        }
+
+        let mut result = Self::default();
+        for (module, path) in imports {
+            let top_level = module.split('.').next().unwrap_or(&module);
+            if top_level.starts_with('_')
+                || STDLIB_MODULES.contains(&top_level)
+                || local.contains(top_level)
+            {
+                continue;
+            }
+            result.modules.insert(top_level.to_string());
+            match package_for_module(&module) {
+                Some(package) => {
+                    result
+                        .packages
+                        .entry(package)
+                        .or_insert_with(|| (top_level.to_string(), path));
+                }
+                None => {
+                    result
+                        .unresolved
+                        .entry(top_level.to_string())
+                        .or_insert(path);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the distributions that provide the imported modules, along with the imported
+    /// top-level module and the first file that imports it.
+    pub fn packages(&self) -> impl Iterator<Item = (&PackageName, &str, &Path)> {
+        self.packages
+            .iter()
+            .map(|(package, (module, path))| (package, module.as_str(), path.as_path()))
+    }
+
+    /// Returns the imported modules that couldn't be mapped to a distribution, along with the
+    /// first file that imports each.
+    pub fn unresolved(&self) -> impl Iterator<Item = (&str, &Path)> {
+        self.unresolved
+            .iter()
+            .map(|(module, path)| (module.as_str(), path.as_path()))
+    }
+
+    /// Remove the given distributions, e.g., those that are already declared as dependencies.
+    pub fn retain(&mut self, mut predicate: impl FnMut(&PackageName) -> bool) {
+        self.packages.retain(|package, _| predicate(package));
+    }
+
+    /// Returns `true` if the given distribution is known to provide an imported module.
+    pub fn imports_package(&self, package: &PackageName) -> bool {
+        self.packages.contains_key(package)
+    }
+
+    /// Returns `true` if the given third-party top-level module is imported.
+    pub fn imports_module(&self, module: &str) -> bool {
+        self.modules.contains(module)
+    }
+
+    /// Returns `true` if no third-party imports were found.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty() && self.unresolved.is_empty()
+    }
+}
+
+/// Return the distribution that likely provides the given (absolute, possibly dotted) module.
+///
+/// Returns `None` for namespace packages that are shared by several distributions, unless the
+/// distribution can be inferred from the submodule.
+fn package_for_module(module: &str) -> Option<PackageName> {
+    let mut parts = module.split('.');
+    let top_level = parts.next()?;
+    match top_level {
+        "google" => match parts.next()? {
+            "protobuf" => PackageName::from_str("protobuf").ok(),
+            "cloud" => PackageName::from_str(&format!("google-cloud-{}", parts.next()?)).ok(),
+            _ => None,
+        },
+        _ => {
+            let name = MODULE_DISTRIBUTIONS
+                .iter()
+                .find(|(candidate, _)| *candidate == top_level)
+                .map_or(top_level, |(_, package)| *package);
+            PackageName::from_str(name).ok()
+        }
+    }
+}
+
+/// Extract the absolute modules imported by the given Python source.
+///
+/// This is a line-based approximation of the Python grammar: it understands `import` and
+/// `from ... import` statements (including those nested in blocks, and those continued with a
+/// backslash), and skips relative imports and the contents of triple-quoted strings.
+fn parse_imports(source: &str) -> Vec<String> {
+    let mut modules = Vec::new();
+    let mut in_string: Option<&str> = None;
+    let mut continued = String::new();
+
+    for line in source.lines() {
+        // Skip the contents of multi-line strings (e.g., docstrings with examples).
+        let mut code = line;
+        if let Some(quote) = in_string {
+            let Some(end) = code.find(quote) else {
+                continue;
+            };
+            code = &code[end + quote.len()..];
+            in_string = None;
+        }
+        for quote in [r#"""""#, "'''"] {
+            if code.matches(quote).count() % 2 == 1 {
+                if let Some(start) = code.rfind(quote) {
+                    code = &code[..start];
+                    in_string = Some(quote);
+                }
+            }
+        }
+
+        // Join lines that are continued with a backslash.
+        let rest = code.split('#').next().unwrap_or_default();
+        if let Some(stripped) = rest.trim_end().strip_suffix('\\') {
+            continued.push_str(stripped);
+            continued.push(' ');
+            continue;
+        }
+        continued.push_str(rest);
+        let statement = std::mem::take(&mut continued);
+
+        for statement in statement.split(';') {
+            let statement = statement.trim();
+            if let Some(names) = statement.strip_prefix("import ") {
+                for name in names.split(',') {
+                    let module = name.split_whitespace().next().unwrap_or_default();
+                    if is_module_path(module) {
+                        modules.push(module.to_string());
+                    }
+                }
+            } else if let Some(from) = statement.strip_prefix("from ") {
+                let module = from.split_whitespace().next().unwrap_or_default();
+                if is_module_path(module) {
+                    modules.push(module.to_string());
+                }
+            }
+        }
+    }
+
+    modules
+}
+
+/// Returns `true` if the given string is an absolute, dotted module path.
+fn is_module_path(module: &str) -> bool {
+    !module.is_empty()
+        && module.split('.').all(|part| {
+            part.chars()
+                .next()
+                .is_some_and(|c| c.is_alphabetic() || c == '_')
+                && part.chars().all(|c| c.is_alphanumeric() || c == '_')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let source = r#"
+"""Example module.
+
+import not_an_import
+"""
+import os, sys
+import numpy as np
+from requests.adapters import HTTPAdapter
+from . import sibling
+from .sibling import helper
+import cv2; import yaml  # comment
+from google.cloud import storage
+import xml.etree.ElementTree as ET, \
+    attr
+
+def f():
+    import rich
+"#;
+        assert_eq!(
+            parse_imports(source),
+            [
+                "os",
+                "sys",
+                "numpy",
+                "requests.adapters",
+                "cv2",
+                "yaml",
+                "google.cloud",
+                "xml.etree.ElementTree",
+                "attr",
+                "rich",
+            ]
+        );
+    }
+
+    #[test]
+    fn packages() {
+        let package = |module| package_for_module(module).map(|package| package.to_string());
+        assert_eq!(package("requests.adapters").as_deref(), Some("requests"));
+        assert_eq!(package("PIL").as_deref(), Some("pillow"));
+        assert_eq!(
+            package("typing_extensions").as_deref(),
+            Some("typing-extensions")
+        );
+        assert_eq!(package("google.protobuf").as_deref(), Some("protobuf"));
+        assert_eq!(
+            package("google.cloud.storage").as_deref(),
+            Some("google-cloud-storage")
+        );
+        assert_eq!(package("google.auth"), None);
+    }
+
+    #[test]
+    fn scan() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        fs::create_dir_all(root.path().join("src/example"))?;
+        fs::write(
+            root.path().join("src/example/__init__.py"),
+            "import json\nimport requests\nfrom example import utils\nfrom PIL import Image\n",
+        )?;
+        fs::write(
+            root.path().join("src/example/utils.py"),
+            "import google.auth\n",
+        )?;
+
+        // Virtual environments are skipped.
+        fs::create_dir_all(root.path().join(".venv/lib"))?;
+        fs::write(root.path().join(".venv/lib/vendored.py"), "import flask\n")?;
+
+        let imports = ThirdPartyImports::scan(root.path())?;
+        assert_eq!(
+            imports
+                .packages()
+                .map(|(package, module, _)| format!("{module} -> {package}"))
+                .collect::<Vec<_>>(),
+            ["PIL -> pillow", "requests -> requests"]
+        );
+        assert_eq!(
+            imports
+                .unresolved()
+                .map(|(module, _)| module)
+                .collect::<Vec<_>>(),
+            ["google"]
+        );
+        assert!(imports.imports_package(&PackageName::from_str("pillow").unwrap()));
+        assert!(imports.imports_module("google"));
+        assert!(!imports.imports_module("json"));
+        assert!(!imports.imports_module("flask"));
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use uv_distribution_types::{BuiltDist, Dist, GitSourceDist, SourceDist};
 use uv_git::GitUrl;
 use uv_pypi_types::{Requirement, RequirementSource};
 
+pub use crate::conda::CondaEnvironmentError;
 pub use crate::extras::*;
 pub use crate::lookahead::*;
 pub use crate::source_tree::*;
@@ -9,7 +10,9 @@ pub use crate::sources::*;
 pub use crate::specification::*;
 pub use crate::unnamed::*;
 
+mod conda;
 mod extras;
+pub mod imports;
 mod lookahead;
 mod source_tree;
 mod sources;
@@ -58,6 +61,7 @@ pub(crate) fn required_dist(
         )?,
         RequirementSource::Git {
             repository,
+            backend,
             reference,
             precise,
             subdirectory,
@@ -67,7 +71,8 @@ pub(crate) fn required_dist(
                 GitUrl::from_commit(repository.clone(), reference.clone(), *precise)
             } else {
                 GitUrl::from_reference(repository.clone(), reference.clone())
-            };
+            }
+            .with_backend(*backend);
             Dist::Source(SourceDist::Git(GitSourceDist {
                 name: requirement.name.clone(),
                 git: Box::new(git_url),
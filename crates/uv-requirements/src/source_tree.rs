@@ -178,6 +178,7 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
         let hashes = match self.hasher {
             HashStrategy::None => HashPolicy::None,
             HashStrategy::Generate => HashPolicy::Generate,
+            HashStrategy::GenerateVerify(_) => HashPolicy::Generate,
             HashStrategy::Verify(_) => HashPolicy::Generate,
             HashStrategy::Require(_) => {
                 return Err(anyhow::anyhow!(
@@ -21,6 +21,9 @@ pub enum RequirementsSource {
     SetupCfg(PathBuf),
     /// Dependencies were provided via a path to a source tree (e.g., `pip install .`).
     SourceTree(PathBuf),
+    /// Dependencies were provided via the `pip` section of a conda environment file (e.g.,
+    /// `pip sync --from-conda environment.yml`).
+    CondaEnvironment(PathBuf),
 }
 
 impl RequirementsSource {
@@ -188,7 +191,8 @@ impl std::fmt::Display for RequirementsSource {
             | Self::PyprojectToml(path)
             | Self::SetupPy(path)
             | Self::SetupCfg(path)
-            | Self::SourceTree(path) => {
+            | Self::SourceTree(path)
+            | Self::CondaEnvironment(path) => {
                 write!(f, "{}", path.simplified_display())
             }
         }
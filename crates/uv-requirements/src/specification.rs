@@ -45,9 +45,10 @@ use uv_pep508::{MarkerTree, UnnamedRequirement, UnnamedRequirementUrl};
 use uv_pypi_types::Requirement;
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_requirements_txt::{RequirementsTxt, RequirementsTxtRequirement};
+use uv_warnings::warn_user;
 use uv_workspace::pyproject::PyProjectToml;
 
-use crate::RequirementsSource;
+use crate::{conda, RequirementsSource};
 
 #[derive(Debug, Default)]
 pub struct RequirementsSpecification {
@@ -113,40 +114,49 @@ impl RequirementsSpecification {
                 }
 
                 let requirements_txt = RequirementsTxt::parse(path, &*CWD, client_builder).await?;
-                Self {
-                    requirements: requirements_txt
-                        .requirements
-                        .into_iter()
-                        .map(UnresolvedRequirementSpecification::from)
-                        .chain(
-                            requirements_txt
-                                .editables
-                                .into_iter()
-                                .map(UnresolvedRequirementSpecification::from),
-                        )
-                        .collect(),
-                    constraints: requirements_txt
-                        .constraints
-                        .into_iter()
-                        .map(Requirement::from)
-                        .map(NameRequirementSpecification::from)
-                        .collect(),
-                    index_url: requirements_txt.index_url.map(IndexUrl::from),
-                    extra_index_urls: requirements_txt
-                        .extra_index_urls
-                        .into_iter()
-                        .map(IndexUrl::from)
-                        .collect(),
-                    no_index: requirements_txt.no_index,
-                    find_links: requirements_txt
-                        .find_links
-                        .into_iter()
-                        .map(IndexUrl::from)
-                        .collect(),
-                    no_binary: requirements_txt.no_binary,
-                    no_build: requirements_txt.only_binary,
-                    ..Self::default()
+                Self::from_requirements_txt(requirements_txt)
+            }
+            RequirementsSource::CondaEnvironment(path) => {
+                let contents = match fs_err::tokio::read_to_string(&path).await {
+                    Ok(contents) => contents,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                        return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+                    }
+                    Err(err) => {
+                        return Err(anyhow::anyhow!(
+                            "Failed to read `{}`: {}",
+                            path.user_display(),
+                            err
+                        ));
+                    }
+                };
+                let requirements = conda::pip_requirements(&contents)
+                    .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+                if requirements.is_empty() {
+                    warn_user!(
+                        "Environment file `{}` does not contain any `pip` dependencies",
+                        path.user_display()
+                    );
                 }
+
+                // Like conda, resolve relative paths against the directory of the environment
+                // file.
+                let directory = path.parent().unwrap_or(&*CWD);
+                let requirements_txt = RequirementsTxt::parse_inner(
+                    &requirements.join("\n"),
+                    directory,
+                    directory,
+                    client_builder,
+                    path,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to parse the `pip` dependencies in: `{}`",
+                        path.user_display()
+                    )
+                })?;
+                Self::from_requirements_txt(requirements_txt)
             }
             RequirementsSource::PyprojectToml(path) => {
                 let contents = match fs_err::tokio::read_to_string(&path).await {
@@ -205,6 +215,44 @@ impl RequirementsSpecification {
         })
     }
 
+    /// Create a [`RequirementsSpecification`] from a parsed `requirements.txt` file.
+    fn from_requirements_txt(requirements_txt: RequirementsTxt) -> Self {
+        Self {
+            requirements: requirements_txt
+                .requirements
+                .into_iter()
+                .map(UnresolvedRequirementSpecification::from)
+                .chain(
+                    requirements_txt
+                        .editables
+                        .into_iter()
+                        .map(UnresolvedRequirementSpecification::from),
+                )
+                .collect(),
+            constraints: requirements_txt
+                .constraints
+                .into_iter()
+                .map(Requirement::from)
+                .map(NameRequirementSpecification::from)
+                .collect(),
+            index_url: requirements_txt.index_url.map(IndexUrl::from),
+            extra_index_urls: requirements_txt
+                .extra_index_urls
+                .into_iter()
+                .map(IndexUrl::from)
+                .collect(),
+            no_index: requirements_txt.no_index,
+            find_links: requirements_txt
+                .find_links
+                .into_iter()
+                .map(IndexUrl::from)
+                .collect(),
+            no_binary: requirements_txt.no_binary,
+            no_build: requirements_txt.only_binary,
+            ..Self::default()
+        }
+    }
+
     /// Read the combined requirements and constraints from a set of sources.
     pub async fn from_sources(
         requirements: &[RequirementsSource],
@@ -53,7 +53,7 @@ pub async fn read_requirements_txt(
         // Respect all pinned versions from the existing lockfile.
         Upgrade::None => preferences,
         // Ignore all pinned versions from the existing lockfile.
-        Upgrade::All => vec![],
+        Upgrade::All(_) => vec![],
         // Ignore pinned versions for the specified packages.
         Upgrade::Packages(packages) => preferences
             .into_iter()
@@ -15,7 +15,9 @@ use crate::preferences::Preferences;
 use crate::prerelease::{AllowPrerelease, PrereleaseStrategy};
 use crate::resolution_mode::ResolutionStrategy;
 use crate::version_map::{VersionMap, VersionMapDistHandle};
-use crate::{Exclusions, Manifest, Options, ResolverEnvironment};
+use crate::{
+    Exclusions, GroupResolution, Manifest, Options, ResolutionMode, ResolverEnvironment, Variants,
+};
 
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_field_names)]
@@ -23,6 +25,8 @@ pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
     prerelease_strategy: PrereleaseStrategy,
     index_strategy: IndexStrategy,
+    variants: Variants,
+    group_resolution: GroupResolution,
 }
 
 impl CandidateSelector {
@@ -46,6 +50,8 @@ impl CandidateSelector {
                 options.dependency_mode,
             ),
             index_strategy: options.index_strategy,
+            variants: manifest.variants.clone(),
+            group_resolution: manifest.group_resolution.clone(),
         }
     }
 
@@ -101,7 +107,9 @@ impl CandidateSelector {
 
         // Check for a locally installed distribution that satisfies the range and is allowed.
         if !is_excluded {
-            if let Some(installed) = Self::get_installed(package_name, range, installed_packages) {
+            if let Some(installed) = Self::get_installed(package_name, range, installed_packages)
+                .filter(|installed| self.variants.allows(package_name, installed.version))
+            {
                 trace!(
                     "Using preference {} {} from installed package",
                     installed.name,
@@ -174,6 +182,11 @@ impl CandidateSelector {
                 continue;
             }
 
+            // Respect the selected hardware variant, e.g., if the variant changed since locking.
+            if !self.variants.allows(package_name, version) {
+                continue;
+            }
+
             // Check for a locally installed distribution that matches the preferred version.
             if !is_excluded {
                 let installed_dists = installed_packages.get_packages(package_name);
@@ -269,12 +282,45 @@ impl CandidateSelector {
 
     /// Select a [`Candidate`] without checking for version preference such as an existing
     /// lockfile.
+    ///
+    /// If a variant is selected for the package, versions that match the variant are preferred
+    /// over those without a local version label.
     pub(crate) fn select_no_preference<'a>(
         &'a self,
         package_name: &'a PackageName,
         range: &Range<Version>,
         version_maps: &'a [VersionMap],
         env: &ResolverEnvironment,
+    ) -> Option<Candidate> {
+        if self.variants.get(package_name).is_some() {
+            if let Some(candidate) = self.select_no_preference_with(
+                package_name,
+                range,
+                version_maps,
+                env,
+                VariantMatch::Exact,
+            ) {
+                return Some(candidate);
+            }
+        }
+        self.select_no_preference_with(
+            package_name,
+            range,
+            version_maps,
+            env,
+            VariantMatch::Compatible,
+        )
+    }
+
+    /// Select a [`Candidate`] from the versions that are eligible under the given
+    /// [`VariantMatch`], without checking for version preference.
+    fn select_no_preference_with<'a>(
+        &'a self,
+        package_name: &'a PackageName,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        env: &ResolverEnvironment,
+        variant_match: VariantMatch,
     ) -> Option<Candidate> {
         trace!(
             "Selecting candidate for {package_name} with range {range} with {} remote versions",
@@ -314,6 +360,8 @@ impl CandidateSelector {
                     package_name,
                     range,
                     allow_prerelease,
+                    &self.variants,
+                    variant_match,
                 )
             } else {
                 Self::select_candidate(
@@ -336,6 +384,8 @@ impl CandidateSelector {
                     package_name,
                     range,
                     allow_prerelease,
+                    &self.variants,
+                    variant_match,
                 )
             }
         } else {
@@ -346,6 +396,8 @@ impl CandidateSelector {
                         package_name,
                         range,
                         allow_prerelease,
+                        &self.variants,
+                        variant_match,
                     )
                 })
             } else {
@@ -355,6 +407,8 @@ impl CandidateSelector {
                         package_name,
                         range,
                         allow_prerelease,
+                        &self.variants,
+                        variant_match,
                     )
                 })
             }
@@ -368,6 +422,11 @@ impl CandidateSelector {
         package_name: &PackageName,
         env: &ResolverEnvironment,
     ) -> bool {
+        // The direct dependencies of a dependency group may use a strategy other than the
+        // default. Since they're direct dependencies, `lowest` and `lowest-direct` coincide.
+        if let Some(mode) = self.group_resolution.get(package_name) {
+            return mode == ResolutionMode::Highest;
+        }
         match &self.resolution_strategy {
             ResolutionStrategy::Highest => true,
             ResolutionStrategy::Lowest => false,
@@ -388,6 +447,8 @@ impl CandidateSelector {
         package_name: &'a PackageName,
         range: &Range<Version>,
         allow_prerelease: bool,
+        variants: &Variants,
+        variant_match: VariantMatch,
     ) -> Option<Candidate<'a>> {
         let mut steps = 0usize;
         let mut incompatible: Option<Candidate> = None;
@@ -412,6 +473,9 @@ impl CandidateSelector {
                 if !range.contains(version) {
                     continue;
                 };
+                if !variants.allows_with(package_name, version, variant_match) {
+                    continue;
+                }
                 let Some(dist) = maybe_dist.prioritized_dist() else {
                     continue;
                 };
@@ -19,11 +19,14 @@ use uv_static::EnvVars;
 use crate::candidate_selector::CandidateSelector;
 use crate::dependency_provider::UvDependencyProvider;
 use crate::fork_urls::ForkUrls;
-use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter};
+use crate::pubgrub::{
+    suggestions, PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter, Suggestion,
+};
 use crate::python_requirement::PythonRequirement;
 use crate::resolution::ConflictingDistributionError;
 use crate::resolver::{
-    IncompletePackage, ResolverEnvironment, UnavailablePackage, UnavailableReason,
+    IncompletePackage, ResolverEnvironment, ResolverStatistics, UnavailablePackage,
+    UnavailableReason,
 };
 use crate::Options;
 
@@ -32,6 +35,9 @@ pub enum ResolveError {
     #[error(transparent)]
     Client(#[from] uv_client::Error),
 
+    #[error(transparent)]
+    Plugin(#[from] uv_plugin::Error),
+
     #[error("The channel closed unexpectedly")]
     ChannelClosed,
 
@@ -93,6 +99,9 @@ pub enum ResolveError {
     #[error(transparent)]
     ParsedUrl(#[from] uv_pypi_types::ParsedUrlError),
 
+    #[error(transparent)]
+    Tags(#[from] uv_platform_tags::TagsError),
+
     #[error("Failed to download `{0}`")]
     Download(Box<BuiltDist>, #[source] uv_distribution::Error),
 
@@ -123,6 +132,12 @@ pub enum ResolveError {
 
     #[error("Package `{0}` is unavailable")]
     PackageUnavailable(PackageName),
+
+    #[error("Resolution was aborted after exceeding the limit of {limit} backtracks (`--max-backtracks`). The following packages consumed the most effort; consider constraining or pinning them:\n{statistics}")]
+    BacktrackLimit {
+        limit: usize,
+        statistics: Box<ResolverStatistics>,
+    },
 }
 
 impl<T> From<tokio::sync::mpsc::error::SendError<T>> for ResolveError {
@@ -407,6 +422,16 @@ impl NoSolutionError {
     pub fn header(&self) -> NoSolutionHeader {
         NoSolutionHeader::new(self.env.clone())
     }
+
+    /// Suggest concrete actions to resolve the conflict, ordered from most to least likely to
+    /// help.
+    pub fn suggestions(&self) -> Vec<Suggestion> {
+        suggestions(
+            &self.error,
+            &self.available_versions,
+            &self.workspace_members,
+        )
+    }
 }
 
 impl std::error::Error for NoSolutionError {}
@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use rustc_hash::FxHashMap;
+
+use uv_normalize::{GroupName, PackageName, DEV_DEPENDENCIES};
+use uv_warnings::warn_user_once;
+use uv_workspace::dependency_groups::FlatDependencyGroups;
+use uv_workspace::pyproject::{GroupResolutionMode, PyProjectToml};
+use uv_workspace::Workspace;
+
+use crate::ResolutionMode;
+
+/// The resolution strategies to use for the direct dependencies of specific dependency groups,
+/// e.g., `test-lowest = "lowest-direct"`, keyed by package name.
+///
+/// A group's strategy takes precedence over the default strategy for every package that the group
+/// declares directly. If groups with different strategies declare the same package, the package
+/// is resolved with the default strategy.
+#[derive(Debug, Default, Clone)]
+pub struct GroupResolution(FxHashMap<PackageName, ResolutionMode>);
+
+impl GroupResolution {
+    /// Collect the per-group strategies selected in the `tool.uv.group-resolution` table of the
+    /// workspace root, and assign them to the packages declared by each group.
+    pub fn from_workspace(workspace: &Workspace) -> Self {
+        let strategies = Self::strategies(workspace);
+        if strategies.is_empty() {
+            return Self::default();
+        }
+
+        // Collect the strategies of the groups that declare each package.
+        let mut declarations = BTreeMap::<PackageName, Vec<ResolutionMode>>::new();
+        let pyprojects = workspace
+            .packages()
+            .values()
+            .map(|member| (member.root().as_path(), member.pyproject_toml()))
+            .chain(workspace.is_non_project().then(|| {
+                (
+                    workspace.install_path().as_path(),
+                    workspace.pyproject_toml(),
+                )
+            }));
+        for (root, pyproject_toml) in pyprojects {
+            for (group, name) in group_dependencies(root, pyproject_toml, workspace) {
+                let Some(mode) = strategies.get(&group).copied() else {
+                    continue;
+                };
+                let modes = declarations.entry(name).or_default();
+                if !modes.contains(&mode) {
+                    modes.push(mode);
+                }
+            }
+        }
+
+        let mut packages = FxHashMap::default();
+        for (name, modes) in declarations {
+            match modes.as_slice() {
+                [mode] => {
+                    packages.insert(name, *mode);
+                }
+                [_, _, ..] => {
+                    warn_user_once!(
+                        "`{name}` is declared in dependency groups with different resolution strategies; falling back to the default strategy"
+                    );
+                }
+                _ => {}
+            }
+        }
+        Self(packages)
+    }
+
+    /// Return the strategy selected for each dependency group in the `tool.uv.group-resolution`
+    /// table of the workspace root.
+    pub fn strategies(workspace: &Workspace) -> BTreeMap<GroupName, ResolutionMode> {
+        workspace
+            .group_resolution()
+            .iter()
+            .map(|(group, mode)| (group.clone(), ResolutionMode::from(*mode)))
+            .collect()
+    }
+
+    /// Return the strategy selected for the given package, if it differs from the default.
+    pub fn get(&self, package_name: &PackageName) -> Option<ResolutionMode> {
+        self.0.get(package_name).copied()
+    }
+}
+
+/// Returns the names of the direct dependencies declared by each dependency group in the given
+/// `pyproject.toml`.
+fn group_dependencies(
+    root: &Path,
+    pyproject_toml: &PyProjectToml,
+    workspace: &Workspace,
+) -> Vec<(GroupName, PackageName)> {
+    let mut dependencies = Vec::new();
+
+    // Collect the legacy `tool.uv.dev-dependencies`, which are equivalent to the `dev` group.
+    if let Some(dev_dependencies) = pyproject_toml
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.dev_dependencies.as_ref())
+    {
+        for requirement in dev_dependencies {
+            dependencies.push((DEV_DEPENDENCIES.clone(), requirement.name.clone()));
+        }
+    }
+
+    // Collect the dependency groups, resolving any `include-group` entries. Invalid groups are
+    // reported when the groups are resolved for the lock.
+    let groups = pyproject_toml
+        .dependency_groups
+        .iter()
+        .flatten()
+        .collect::<BTreeMap<_, _>>();
+    if let Ok(groups) = FlatDependencyGroups::from_workspace(&groups, root, workspace) {
+        for (group, requirements) in groups {
+            for requirement in requirements {
+                dependencies.push((group.clone(), requirement.name));
+            }
+        }
+    }
+
+    dependencies
+}
+
+impl From<GroupResolutionMode> for ResolutionMode {
+    fn from(mode: GroupResolutionMode) -> Self {
+        match mode {
+            GroupResolutionMode::Highest => Self::Highest,
+            GroupResolutionMode::Lowest => Self::Lowest,
+            GroupResolutionMode::LowestDirect => Self::LowestDirect,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use rustc_hash::FxHashSet;
+
+use uv_distribution_types::{Index, IndexLocations, IndexUrl, Origin};
+use uv_normalize::PackageName;
+use uv_pep508::MarkerTree;
+use uv_workspace::pyproject::{Source, SourcePattern};
+use uv_workspace::Workspace;
+
+use crate::ResolverEnvironment;
+
+/// The indexes to which families of packages are pinned by glob patterns in `tool.uv.sources`,
+/// e.g., `"mycorp-*" = { index = "internal" }`.
+///
+/// Unlike the sources for individual packages, which only apply to the direct dependencies of
+/// the workspace members, patterns apply to every matching package in the resolution, including
+/// transitive dependencies.
+#[derive(Debug, Default, Clone)]
+pub struct IndexPatterns(Vec<IndexPattern>);
+
+/// An index to which the packages that match a pattern are pinned.
+#[derive(Debug, Clone)]
+struct IndexPattern {
+    pattern: SourcePattern,
+    marker: MarkerTree,
+    index: IndexUrl,
+}
+
+impl IndexPatterns {
+    /// Collect the patterns declared in the `tool.uv.sources` tables of the workspace members and
+    /// the workspace root.
+    ///
+    /// If multiple tables declare the same pattern, the first takes precedence. Like named indexes
+    /// in `tool.uv.sources`, indexes are matched from the command line, then the declaring
+    /// project, then the workspace root. Patterns that reference an undeclared index are skipped,
+    /// as they're reported when lowering any direct dependencies that match them.
+    pub fn from_workspace(workspace: &Workspace, locations: &IndexLocations) -> Self {
+        let members = workspace.packages().values().filter_map(|member| {
+            let uv = member.pyproject_toml().tool.as_ref()?.uv.as_ref()?;
+            Some((
+                uv.sources.as_ref()?,
+                uv.index.as_deref().unwrap_or_default(),
+            ))
+        });
+        let root = std::iter::once((workspace.sources(), workspace.indexes()));
+
+        let mut seen = FxHashSet::default();
+        let mut patterns = Vec::new();
+        for (sources, indexes) in members.chain(root) {
+            for (pattern, sources) in sources.patterns() {
+                if !seen.insert(pattern.as_str()) {
+                    continue;
+                }
+                for source in sources.iter() {
+                    let Source::Registry { index, marker } = source else {
+                        continue;
+                    };
+                    let Some(url) = locations
+                        .indexes()
+                        .filter(|index| matches!(index.origin, Some(Origin::Cli)))
+                        .chain(indexes.iter())
+                        .chain(workspace.indexes().iter())
+                        .find(|Index { name, .. }| name.as_ref() == Some(index))
+                        .map(|Index { url, .. }| url.clone())
+                    else {
+                        continue;
+                    };
+                    patterns.push(IndexPattern {
+                        pattern: pattern.clone(),
+                        marker: marker.clone(),
+                        index: url,
+                    });
+                }
+            }
+        }
+
+        Self(patterns)
+    }
+
+    /// Returns `true` if any pattern matches the given package.
+    pub(crate) fn matches(&self, package_name: &PackageName) -> bool {
+        self.0
+            .iter()
+            .any(|pattern| pattern.pattern.matches(package_name))
+    }
+
+    /// Return the indexes to which the given package is pinned in the given fork, from the most
+    /// specific (i.e., the longest) pattern that matches it.
+    pub(crate) fn get(
+        &self,
+        package_name: &PackageName,
+        env: &ResolverEnvironment,
+    ) -> Vec<&IndexUrl> {
+        let Some(pattern) = self
+            .0
+            .iter()
+            .filter(|pattern| pattern.pattern.matches(package_name))
+            .max_by_key(|pattern| pattern.pattern.as_str().len())
+            .map(|pattern| &pattern.pattern)
+        else {
+            return Vec::new();
+        };
+        self.0
+            .iter()
+            .filter(|candidate| candidate.pattern.as_str() == pattern.as_str())
+            .filter(|candidate| env.included_by_marker(&candidate.marker))
+            .map(|candidate| &candidate.index)
+            .collect()
+    }
+}
@@ -3,25 +3,35 @@ pub use error::{NoSolutionError, NoSolutionHeader, ResolveError};
 pub use exclude_newer::ExcludeNewer;
 pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
+pub use group_resolution::GroupResolution;
+pub use index_patterns::IndexPatterns;
 pub use lock::{
-    InstallTarget, Lock, LockError, LockVersion, PackageMap, RequirementsTxtExport,
-    ResolverManifest, SatisfiesResult, TreeDisplay, VERSION,
+    BazelExport, DebControlExport, Dependent, DockerfileExport, ExtraConflict, ExtraConflicts,
+    InstallTarget, Lock, LockDiff, LockError, LockStatistics, LockVersion, NixExport,
+    PackageDependency, PackageMap, PythonSupport, RequirementsInExport, RequirementsTxtExport,
+    ResolverManifest, RpmSpecExport, SatisfiesResult, SourceBuildPackage, SourceBuilds,
+    TreeDisplay, UnnecessaryConflict, UnsupportedPackage, VendoredPackage, VERSION,
 };
 pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
 pub use preferences::{Preference, PreferenceError, Preferences};
 pub use prerelease::PrereleaseMode;
+pub use pubgrub::{ConflictItem, Suggestion};
 pub use python_requirement::PythonRequirement;
 pub use requires_python::{RequiresPython, RequiresPythonRange};
 pub use resolution::{
-    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolutionGraph,
+    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, InstallationReport,
+    ResolutionGraph,
 };
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
-    BuildId, DefaultResolverProvider, InMemoryIndex, MetadataResponse, PackageVersionsResult,
-    Reporter as ResolverReporter, Resolver, ResolverEnvironment, ResolverProvider,
-    VersionsResponse, WheelMetadataResult,
+    BuildId, DefaultResolverProvider, InMemoryIndex, MetadataResponse, PackageStatistics,
+    PackageVersionsResult, Reporter as ResolverReporter, Resolver, ResolverBuilder,
+    ResolverEnvironment, ResolverProvider, ResolverStatistics, VersionsResponse,
+    WheelMetadataResult,
 };
+pub use variants::Variants;
+pub use vendored::Vendored;
 pub use version_map::VersionMap;
 pub use yanks::AllowedYanks;
 
@@ -37,6 +47,8 @@ mod flat_index;
 mod fork_indexes;
 mod fork_urls;
 mod graph_ops;
+mod group_resolution;
+mod index_patterns;
 mod lock;
 mod manifest;
 mod marker;
@@ -51,5 +63,7 @@ mod requires_python;
 mod resolution;
 mod resolution_mode;
 mod resolver;
+mod variants;
+mod vendored;
 mod version_map;
 mod yanks;
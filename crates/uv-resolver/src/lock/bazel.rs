@@ -0,0 +1,108 @@
+use std::fmt::Formatter;
+
+use uv_configuration::{DevGroupsManifest, EditableMode, ExtrasSpecification, InstallOptions};
+use uv_fs::PortablePath;
+
+use crate::lock::requirements_txt::{requirements, write_requirement, Requirement};
+use crate::lock::{RegistrySource, Source};
+use crate::{InstallTarget, LockError};
+
+/// The default index URL, which is used as the primary index when present.
+const PYPI_URL: &str = "https://pypi.org/simple";
+
+/// An export of a [`Lock`] that renders as a requirements lock file for the `rules_python`
+/// `pip_parse` and `pip.parse` rules.
+///
+/// The format is a `requirements.txt` with hashes for every distribution, preceded by the index
+/// URLs from which the distributions were locked. Local packages are omitted, as they're built
+/// from source by Bazel.
+#[derive(Debug)]
+pub struct BazelExport<'lock> {
+    nodes: Vec<Requirement<'lock>>,
+    index_urls: Vec<String>,
+    find_links: Vec<String>,
+}
+
+impl<'lock> BazelExport<'lock> {
+    pub fn from_lock(
+        target: InstallTarget<'lock>,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsManifest,
+        install_options: &'lock InstallOptions,
+    ) -> Result<Self, LockError> {
+        let nodes = requirements(target, extras, dev, install_options);
+
+        // Collect the indexes in order of first use, with PyPI (if used) as the primary index.
+        let mut index_urls = Vec::new();
+        let mut find_links = Vec::new();
+        for node in &nodes {
+            let Source::Registry(registry) = &node.package.id.source else {
+                continue;
+            };
+            match registry {
+                RegistrySource::Url(url) => {
+                    let url = url.to_string();
+                    if !index_urls.contains(&url) {
+                        index_urls.push(url);
+                    }
+                }
+                RegistrySource::Path(path) => {
+                    let path = PortablePath::from(path).to_string();
+                    if !find_links.contains(&path) {
+                        find_links.push(path);
+                    }
+                }
+            }
+        }
+        if let Some(position) = index_urls.iter().position(|url| url == PYPI_URL) {
+            let pypi = index_urls.remove(position);
+            index_urls.insert(0, pypi);
+        }
+
+        Ok(Self {
+            nodes,
+            index_urls,
+            find_links,
+        })
+    }
+}
+
+impl std::fmt::Display for BazelExport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut index_urls = self.index_urls.iter();
+        if let Some(url) = index_urls.next() {
+            writeln!(f, "--index-url {url}")?;
+        }
+        for url in index_urls {
+            writeln!(f, "--extra-index-url {url}")?;
+        }
+        for path in &self.find_links {
+            writeln!(f, "--find-links {path}")?;
+        }
+        if !self.index_urls.is_empty() || !self.find_links.is_empty() {
+            writeln!(f)?;
+        }
+
+        let mut local = Vec::new();
+        for requirement in &self.nodes {
+            match &requirement.package.id.source {
+                Source::Path(_) | Source::Directory(_) | Source::Editable(_) => {
+                    local.push(requirement);
+                }
+                Source::Virtual(_) => {}
+                Source::Registry(_) | Source::Git(..) | Source::Direct(..) => {
+                    write_requirement(f, requirement, EditableMode::NonEditable, true, false)?;
+                }
+            }
+        }
+
+        if !local.is_empty() {
+            writeln!(f, "# The following local packages are omitted:")?;
+            for requirement in local {
+                writeln!(f, "#     {}", requirement.package.id.name)?;
+            }
+        }
+
+        Ok(())
+    }
+}
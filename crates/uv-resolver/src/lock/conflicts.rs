@@ -0,0 +1,253 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use rustc_hash::FxHashMap;
+
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::Version;
+use uv_pep508::MarkerTree;
+use uv_pypi_types::{ConflictingGroup, ConflictingGroupList, ConflictingGroups};
+
+use crate::lock::{Lock, Package, PackageId};
+
+/// An analysis of the conflicts between the extras of the workspace members in a [`Lock`].
+///
+/// Two extras conflict if they require different versions (or sources) of the same package
+/// under overlapping markers, i.e., if they can't be installed into the same environment. The
+/// analysis is only meaningful for a lock in which each extra was resolved in its own fork, as
+/// otherwise, extras that conflict would have failed to resolve in the first place.
+#[derive(Debug)]
+pub struct ExtraConflicts {
+    /// The pairs of extras that conflict with one another.
+    conflicts: Vec<ExtraConflict>,
+}
+
+/// A pair of extras that require different versions of the same package.
+#[derive(Debug)]
+pub struct ExtraConflict {
+    /// The first extra.
+    pub first: ConflictingGroup,
+    /// The second extra.
+    pub second: ConflictingGroup,
+    /// The chain of dependencies through which the first extra requires the package, starting
+    /// from the workspace member and ending with the package itself.
+    pub first_chain: Vec<(PackageName, Version)>,
+    /// The chain of dependencies through which the second extra requires the package, starting
+    /// from the workspace member and ending with the package itself.
+    pub second_chain: Vec<(PackageName, Version)>,
+}
+
+/// A declared conflicting extra that doesn't conflict with any other extra in its set.
+#[derive(Debug)]
+pub struct UnnecessaryConflict<'a> {
+    /// The extra that doesn't conflict.
+    pub group: &'a ConflictingGroup,
+    /// The declared set of conflicting extras that includes the extra.
+    pub groups: &'a ConflictingGroups,
+}
+
+impl ExtraConflicts {
+    /// Analyze the conflicts between the extras of the workspace members in the given [`Lock`].
+    pub fn from_lock(lock: &Lock) -> Self {
+        let members = lock
+            .packages()
+            .iter()
+            .filter(|package| lock.members().contains(package.name()))
+            .chain(lock.root())
+            .collect::<Vec<_>>();
+
+        // Determine the packages that are reachable from each extra.
+        let mut reachable = Vec::new();
+        for package in members {
+            for extra in package.optional_dependencies.keys() {
+                reachable.push((
+                    ConflictingGroup::from((package.name().clone(), extra.clone())),
+                    Reachable::from_extra(lock, package, extra),
+                ));
+            }
+        }
+        reachable.sort_by(|(a, _), (b, _)| a.cmp(b));
+        reachable.dedup_by(|(a, _), (b, _)| a == b);
+
+        let mut conflicts = Vec::new();
+        for (i, (first, first_reachable)) in reachable.iter().enumerate() {
+            for (second, second_reachable) in &reachable[i + 1..] {
+                if let Some((first_id, second_id)) = first_reachable.conflict(second_reachable) {
+                    conflicts.push(ExtraConflict {
+                        first: first.clone(),
+                        second: second.clone(),
+                        first_chain: first_reachable.derivation_chain(first_id),
+                        second_chain: second_reachable.derivation_chain(second_id),
+                    });
+                }
+            }
+        }
+
+        Self { conflicts }
+    }
+
+    /// Returns the pairs of extras that conflict with one another.
+    pub fn conflicts(&self) -> &[ExtraConflict] {
+        &self.conflicts
+    }
+
+    /// Returns the pairs of extras that conflict with one another, but aren't declared as
+    /// conflicting in the given list.
+    pub fn undeclared<'a>(
+        &'a self,
+        declared: &'a ConflictingGroupList,
+    ) -> impl Iterator<Item = &'a ExtraConflict> + 'a {
+        self.conflicts.iter().filter(|conflict| {
+            !declared.iter().any(|groups| {
+                groups.contains(conflict.first.package(), conflict.first.extra())
+                    && groups.contains(conflict.second.package(), conflict.second.extra())
+            })
+        })
+    }
+
+    /// Returns the declared conflicting extras that don't conflict with any other extra in the
+    /// same set.
+    pub fn unnecessary<'a>(
+        &self,
+        declared: &'a ConflictingGroupList,
+    ) -> Vec<UnnecessaryConflict<'a>> {
+        let mut unnecessary = Vec::new();
+        for groups in declared.iter() {
+            for group in groups.iter() {
+                let conflicts = self.conflicts.iter().any(|conflict| {
+                    let other = if conflict.first == *group {
+                        &conflict.second
+                    } else if conflict.second == *group {
+                        &conflict.first
+                    } else {
+                        return false;
+                    };
+                    groups.contains(other.package(), other.extra())
+                });
+                if !conflicts {
+                    unnecessary.push(UnnecessaryConflict { group, groups });
+                }
+            }
+        }
+        unnecessary
+    }
+}
+
+/// The packages that are reachable from an extra, along with the markers under which each
+/// package is required.
+#[derive(Debug)]
+struct Reachable<'lock> {
+    /// The workspace member that declares the extra.
+    member: &'lock PackageId,
+    /// The markers under which each package is reachable, by name.
+    packages: BTreeMap<&'lock PackageName, Vec<(&'lock PackageId, MarkerTree)>>,
+    /// The dependent through which each package was first reached.
+    parents: FxHashMap<&'lock PackageId, &'lock PackageId>,
+}
+
+impl<'lock> Reachable<'lock> {
+    /// Traverse the packages that are reachable from the given extra of a workspace member.
+    fn from_extra(lock: &'lock Lock, member: &'lock Package, extra: &ExtraName) -> Self {
+        let mut markers = FxHashMap::<(&PackageId, Option<&ExtraName>), MarkerTree>::default();
+        let mut parents = FxHashMap::default();
+        let mut queue = VecDeque::new();
+
+        // Seed the traversal with the dependencies of the extra.
+        for dependency in member
+            .optional_dependencies
+            .get(extra)
+            .into_iter()
+            .flatten()
+        {
+            parents.entry(&dependency.package_id).or_insert(&member.id);
+            for node in std::iter::once(None).chain(dependency.extra.iter().map(Some)) {
+                let marker = markers
+                    .entry((&dependency.package_id, node))
+                    .or_insert(MarkerTree::FALSE);
+                marker.or(dependency.complexified_marker.clone());
+                queue.push_back((&dependency.package_id, node));
+            }
+        }
+
+        // Propagate the markers to the transitive dependencies, until they no longer change.
+        while let Some((id, node)) = queue.pop_front() {
+            let package = lock.find_by_id(id);
+            let parent_marker = markers[&(id, node)].clone();
+            let dependencies = match node {
+                None => package.dependencies.as_slice(),
+                Some(extra) => package
+                    .optional_dependencies
+                    .get(extra)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+            };
+            for dependency in dependencies {
+                let mut edge_marker = parent_marker.clone();
+                edge_marker.and(dependency.complexified_marker.clone());
+                if edge_marker.is_false() {
+                    continue;
+                }
+                parents.entry(&dependency.package_id).or_insert(id);
+                for node in std::iter::once(None).chain(dependency.extra.iter().map(Some)) {
+                    let marker = markers
+                        .entry((&dependency.package_id, node))
+                        .or_insert(MarkerTree::FALSE);
+                    let mut combined = marker.clone();
+                    combined.or(edge_marker.clone());
+                    if combined != *marker {
+                        *marker = combined;
+                        queue.push_back((&dependency.package_id, node));
+                    }
+                }
+            }
+        }
+
+        let mut packages = BTreeMap::<_, Vec<_>>::new();
+        for ((id, node), marker) in markers {
+            if node.is_none() {
+                packages.entry(&id.name).or_default().push((id, marker));
+            }
+        }
+        for versions in packages.values_mut() {
+            versions.sort_by(|(a, _), (b, _)| a.cmp(b));
+        }
+
+        Self {
+            member: &member.id,
+            packages,
+            parents,
+        }
+    }
+
+    /// Returns a pair of distinct packages with the same name, one reachable from each extra,
+    /// that are required under overlapping markers.
+    fn conflict(&self, other: &Self) -> Option<(&'lock PackageId, &'lock PackageId)> {
+        for (name, versions) in &self.packages {
+            let Some(other_versions) = other.packages.get(name) else {
+                continue;
+            };
+            for (id, marker) in versions {
+                for (other_id, other_marker) in other_versions {
+                    if id != other_id && !marker.is_disjoint(other_marker) {
+                        return Some((id, other_id));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Reconstruct the chain of dependencies from the workspace member to the given package.
+    fn derivation_chain(&self, id: &PackageId) -> Vec<(PackageName, Version)> {
+        let mut chain = vec![(id.name.clone(), id.version.clone())];
+        let mut current = id;
+        while let Some(parent) = self.parents.get(current) {
+            chain.push((parent.name.clone(), parent.version.clone()));
+            if *parent == self.member {
+                break;
+            }
+            current = parent;
+        }
+        chain.reverse();
+        chain
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Formatter;
+
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::Version;
+
+use crate::lock::{Dependency, Lock};
+
+/// The structural differences between two [`Lock`]s: the packages that were added, removed, or
+/// updated, the dependency edges that were added or removed, and the packages that are now
+/// required by a different set of dependents.
+#[derive(Debug, Default, Serialize)]
+pub struct LockDiff {
+    /// The packages that are only present in the current lockfile.
+    added: Vec<PackageVersions>,
+    /// The packages that are only present in the previous lockfile.
+    removed: Vec<PackageVersions>,
+    /// The packages that are present in both lockfiles, but at different versions.
+    updated: Vec<VersionChange>,
+    /// The dependency edges that were added or removed, by dependent.
+    dependencies: Vec<EdgeChanges>,
+    /// The packages that are present in both lockfiles, but are required by a different set of
+    /// dependents.
+    derivations: Vec<DerivationChange>,
+}
+
+/// The locked versions of a package.
+#[derive(Debug, Serialize)]
+struct PackageVersions {
+    name: PackageName,
+    versions: BTreeSet<Version>,
+}
+
+/// A package that was locked at different versions in the previous lockfile.
+#[derive(Debug, Serialize)]
+struct VersionChange {
+    name: PackageName,
+    previous: BTreeSet<Version>,
+    current: BTreeSet<Version>,
+}
+
+/// The dependency edges of a single dependent that were added or removed.
+#[derive(Debug, Serialize)]
+struct EdgeChanges {
+    /// The package that declares the dependencies.
+    dependent: PackageName,
+    added: Vec<Edge>,
+    removed: Vec<Edge>,
+}
+
+/// A package whose set of dependents changed between the lockfiles.
+#[derive(Debug, Serialize)]
+struct DerivationChange {
+    name: PackageName,
+    /// The dependents that only require the package in the current lockfile.
+    added: Vec<String>,
+    /// The dependents that only required the package in the previous lockfile.
+    removed: Vec<String>,
+}
+
+/// A dependency edge in a [`Lock`], independent of the locked versions.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+struct Edge {
+    /// The package that is required.
+    package: PackageName,
+    /// The extra of the dependent under which the dependency applies, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extra: Option<ExtraName>,
+    /// The dependency group of the dependent under which the dependency applies, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<GroupName>,
+    /// The markers under which the dependency applies, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<String>,
+}
+
+impl Edge {
+    fn new(dependency: &Dependency, extra: Option<&ExtraName>, group: Option<&GroupName>) -> Self {
+        Self {
+            package: dependency.package_id.name.clone(),
+            extra: extra.cloned(),
+            group: group.cloned(),
+            marker: dependency.simplified_marker.try_to_string(),
+        }
+    }
+
+    /// Render the dependent that declares the edge, e.g., `project[extra]` or `project:group`.
+    fn dependent(&self, dependent: &PackageName) -> String {
+        match (&self.extra, &self.group) {
+            (Some(extra), _) => format!("{dependent}[{extra}]"),
+            (None, Some(group)) => format!("{dependent}:{group}"),
+            (None, None) => dependent.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Edge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.package)?;
+        if let Some(extra) = &self.extra {
+            write!(f, " (extra: {extra})")?;
+        }
+        if let Some(group) = &self.group {
+            write!(f, " (group: {group})")?;
+        }
+        if let Some(marker) = &self.marker {
+            write!(f, " (marker: {marker})")?;
+        }
+        Ok(())
+    }
+}
+
+/// The packages and dependency edges in a [`Lock`], keyed by package name.
+#[derive(Debug, Default)]
+struct LockGraph {
+    versions: BTreeMap<PackageName, BTreeSet<Version>>,
+    edges: BTreeMap<PackageName, BTreeSet<Edge>>,
+}
+
+impl LockGraph {
+    fn from_lock(lock: &Lock) -> Self {
+        let mut graph = Self::default();
+        for package in lock.packages() {
+            graph
+                .versions
+                .entry(package.id.name.clone())
+                .or_default()
+                .insert(package.id.version.clone());
+
+            let edges = graph.edges.entry(package.id.name.clone()).or_default();
+            for dependency in &package.dependencies {
+                edges.insert(Edge::new(dependency, None, None));
+            }
+            for (extra, dependencies) in &package.optional_dependencies {
+                for dependency in dependencies {
+                    edges.insert(Edge::new(dependency, Some(extra), None));
+                }
+            }
+            for (group, dependencies) in &package.dependency_groups {
+                for dependency in dependencies {
+                    edges.insert(Edge::new(dependency, None, Some(group)));
+                }
+            }
+        }
+        graph
+    }
+
+    /// Return the dependents of each package, as rendered by [`Edge::dependent`].
+    fn dependents(&self) -> BTreeMap<&PackageName, BTreeSet<String>> {
+        let mut dependents = BTreeMap::<_, BTreeSet<_>>::new();
+        for (dependent, edges) in &self.edges {
+            for edge in edges {
+                dependents
+                    .entry(&edge.package)
+                    .or_default()
+                    .insert(edge.dependent(dependent));
+            }
+        }
+        dependents
+    }
+}
+
+impl LockDiff {
+    /// Compute the differences between the previous and current [`Lock`].
+    pub fn from_locks(previous: &Lock, current: &Lock) -> Self {
+        let previous = LockGraph::from_lock(previous);
+        let current = LockGraph::from_lock(current);
+
+        // Compare the locked versions of each package.
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        let mut updated = Vec::new();
+        for (name, versions) in &previous.versions {
+            match current.versions.get(name) {
+                Some(current) if current != versions => updated.push(VersionChange {
+                    name: name.clone(),
+                    previous: versions.clone(),
+                    current: current.clone(),
+                }),
+                Some(_) => {}
+                None => removed.push(PackageVersions {
+                    name: name.clone(),
+                    versions: versions.clone(),
+                }),
+            }
+        }
+        for (name, versions) in &current.versions {
+            if !previous.versions.contains_key(name) {
+                added.push(PackageVersions {
+                    name: name.clone(),
+                    versions: versions.clone(),
+                });
+            }
+        }
+
+        // Compare the dependency edges of each dependent.
+        let empty = BTreeSet::new();
+        let dependencies = previous
+            .edges
+            .keys()
+            .chain(current.edges.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter_map(|dependent| {
+                let previous = previous.edges.get(dependent).unwrap_or(&empty);
+                let current = current.edges.get(dependent).unwrap_or(&empty);
+                let added = current.difference(previous).cloned().collect::<Vec<_>>();
+                let removed = previous.difference(current).cloned().collect::<Vec<_>>();
+                if added.is_empty() && removed.is_empty() {
+                    None
+                } else {
+                    Some(EdgeChanges {
+                        dependent: dependent.clone(),
+                        added,
+                        removed,
+                    })
+                }
+            })
+            .collect();
+
+        // Compare the dependents of each package that's present in both lockfiles.
+        let previous_dependents = previous.dependents();
+        let current_dependents = current.dependents();
+        let empty = BTreeSet::new();
+        let derivations = previous
+            .versions
+            .keys()
+            .filter(|name| current.versions.contains_key(*name))
+            .filter_map(|name| {
+                let previous = previous_dependents.get(name).unwrap_or(&empty);
+                let current = current_dependents.get(name).unwrap_or(&empty);
+                if previous == current {
+                    return None;
+                }
+                Some(DerivationChange {
+                    name: name.clone(),
+                    added: current.difference(previous).cloned().collect(),
+                    removed: previous.difference(current).cloned().collect(),
+                })
+            })
+            .collect();
+
+        Self {
+            added,
+            removed,
+            updated,
+            dependencies,
+            derivations,
+        }
+    }
+
+    /// Returns `true` if the lockfiles are structurally identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.updated.is_empty()
+            && self.dependencies.is_empty()
+            && self.derivations.is_empty()
+    }
+}
+
+/// Render a set of versions, e.g., `v1.0.0, v2.0.0`.
+fn versions(versions: &BTreeSet<Version>) -> String {
+    versions
+        .iter()
+        .map(|version| format!("v{version}"))
+        .join(", ")
+}
+
+/// Render a tree of changes beneath a root, with added entries prefixed by `+` and removed entries
+/// prefixed by `-`.
+fn write_tree(
+    f: &mut Formatter<'_>,
+    root: &impl std::fmt::Display,
+    added: &[impl std::fmt::Display],
+    removed: &[impl std::fmt::Display],
+) -> std::fmt::Result {
+    writeln!(f, "{root}")?;
+    let lines = added
+        .iter()
+        .map(|entry| format!("{} {entry}", "+".green()))
+        .chain(removed.iter().map(|entry| format!("{} {entry}", "-".red())))
+        .collect::<Vec<_>>();
+    for (index, line) in lines.iter().enumerate() {
+        let prefix = if index == lines.len() - 1 {
+            "└── "
+        } else {
+            "├── "
+        };
+        writeln!(f, "{prefix}{line}")?;
+    }
+    Ok(())
+}
+
+impl std::fmt::Display for LockDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        let mut section = |f: &mut Formatter<'_>, title: &str| {
+            if !std::mem::take(&mut first) {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", title.bold())
+        };
+
+        if !self.added.is_empty() || !self.removed.is_empty() || !self.updated.is_empty() {
+            section(f, "Packages:")?;
+            for package in &self.added {
+                writeln!(
+                    f,
+                    "{} {} {}",
+                    "+".green(),
+                    package.name,
+                    versions(&package.versions)
+                )?;
+            }
+            for package in &self.removed {
+                writeln!(
+                    f,
+                    "{} {} {}",
+                    "-".red(),
+                    package.name,
+                    versions(&package.versions)
+                )?;
+            }
+            for change in &self.updated {
+                writeln!(
+                    f,
+                    "{} {} {} -> {}",
+                    "~".yellow(),
+                    change.name,
+                    versions(&change.previous),
+                    versions(&change.current)
+                )?;
+            }
+        }
+
+        if !self.dependencies.is_empty() {
+            section(f, "Dependencies:")?;
+            for change in &self.dependencies {
+                write_tree(f, &change.dependent, &change.added, &change.removed)?;
+            }
+        }
+
+        if !self.derivations.is_empty() {
+            section(f, "Required by:")?;
+            for change in &self.derivations {
+                write_tree(f, &change.name, &change.added, &change.removed)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,407 @@
+use std::collections::BTreeMap;
+use std::fmt::{Formatter, Write};
+use std::str::FromStr;
+
+use itertools::Itertools;
+use toml_edit::{ImDocument, Item};
+
+use uv_configuration::{DevGroupsManifest, DistroPackages, ExtrasSpecification, InstallOptions};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::MarkerTree;
+
+use crate::lock::requirements_txt::{requirements, Requirement};
+use crate::lock::Source;
+use crate::{InstallTarget, LockError};
+
+/// The build requirements assumed for projects without a `[build-system]` table, per PEP 517.
+const DEFAULT_BUILD_REQUIRES: &str = "setuptools";
+
+/// An export of a [`Lock`] that renders as an RPM `.spec` file.
+///
+/// The locked dependencies are mapped to the `python3dist(...)` virtual provides generated for
+/// Python packages by RPM-based distributions, unless an explicit mapping is provided, and are
+/// required at or above the locked version.
+#[derive(Debug)]
+pub struct RpmSpecExport<'lock> {
+    package: DistroPackage<'lock>,
+    distro_packages: DistroPackages,
+}
+
+impl<'lock> RpmSpecExport<'lock> {
+    pub fn from_lock(
+        target: InstallTarget<'lock>,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsManifest,
+        install_options: &'lock InstallOptions,
+        distro_packages: &DistroPackages,
+    ) -> Result<Self, LockError> {
+        Ok(Self {
+            package: DistroPackage::from_lock(target, extras, dev, install_options),
+            distro_packages: distro_packages.clone(),
+        })
+    }
+
+    /// Return the distribution package for the given Python package.
+    fn distro_name(&self, name: &PackageName) -> String {
+        self.distro_packages
+            .get(name)
+            .map(ToString::to_string)
+            .unwrap_or_else(|| format!("python3dist({name})"))
+    }
+}
+
+impl std::fmt::Display for RpmSpecExport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let package = &self.package;
+        let summary = package.summary();
+
+        writeln!(f, "Name:           {}", package.name)?;
+        writeln!(f, "Version:        {}", distro_version(package.version))?;
+        writeln!(f, "Release:        1%{{?dist}}")?;
+        writeln!(f, "Summary:        {summary}")?;
+        if let Some(license) = &package.license {
+            writeln!(f, "License:        {license}")?;
+        }
+        writeln!(
+            f,
+            "Source0:        {}-{}.tar.gz",
+            package.name.as_dist_info_name(),
+            package.version
+        )?;
+        writeln!(f, "BuildArch:      noarch")?;
+        writeln!(f)?;
+
+        writeln!(f, "BuildRequires:  python3-devel")?;
+        writeln!(f, "BuildRequires:  uv")?;
+        for name in &package.build_requires {
+            writeln!(f, "BuildRequires:  {}", self.distro_name(name))?;
+        }
+        if !package.requires.is_empty() {
+            writeln!(f)?;
+            for (name, version) in &package.requires {
+                writeln!(
+                    f,
+                    "Requires:       {} >= {}",
+                    self.distro_name(name),
+                    distro_version(version)
+                )?;
+            }
+        }
+        writeln!(f)?;
+
+        writeln!(f, "%description")?;
+        writeln!(f, "{summary}")?;
+        writeln!(f)?;
+
+        writeln!(f, "%prep")?;
+        writeln!(
+            f,
+            "%autosetup -n {}-{}",
+            package.name.as_dist_info_name(),
+            package.version
+        )?;
+        writeln!(f)?;
+
+        writeln!(f, "%build")?;
+        writeln!(
+            f,
+            "uv build --wheel --no-build-isolation --python %{{python3}} --out-dir dist"
+        )?;
+        writeln!(f)?;
+
+        writeln!(f, "%install")?;
+        writeln!(
+            f,
+            "uv pip install --no-deps --no-index --python %{{python3}} --root %{{buildroot}} --prefix %{{_prefix}} dist/*.whl"
+        )?;
+        writeln!(f)?;
+
+        writeln!(f, "%files")?;
+        writeln!(f, "%{{python3_sitelib}}/*")?;
+        for script in &package.scripts {
+            writeln!(f, "%{{_bindir}}/{script}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An export of a [`Lock`] that renders as a Debian `control` file.
+///
+/// The locked dependencies are mapped to the `python3-...` packages used for Python packages by
+/// Debian-based distributions, unless an explicit mapping is provided, and are required at or
+/// above the locked version. As the build and install steps belong in `debian/rules`, they're
+/// included as a trailing comment.
+#[derive(Debug)]
+pub struct DebControlExport<'lock> {
+    package: DistroPackage<'lock>,
+    distro_packages: DistroPackages,
+}
+
+impl<'lock> DebControlExport<'lock> {
+    pub fn from_lock(
+        target: InstallTarget<'lock>,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsManifest,
+        install_options: &'lock InstallOptions,
+        distro_packages: &DistroPackages,
+    ) -> Result<Self, LockError> {
+        Ok(Self {
+            package: DistroPackage::from_lock(target, extras, dev, install_options),
+            distro_packages: distro_packages.clone(),
+        })
+    }
+
+    /// Return the distribution package for the given Python package.
+    fn distro_name(&self, name: &PackageName) -> String {
+        self.distro_packages
+            .get(name)
+            .map(ToString::to_string)
+            .unwrap_or_else(|| format!("python3-{name}"))
+    }
+}
+
+impl std::fmt::Display for DebControlExport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let package = &self.package;
+
+        writeln!(f, "Source: {}", package.name)?;
+        writeln!(f, "Section: python")?;
+        writeln!(f, "Priority: optional")?;
+        if let Some(maintainer) = &package.maintainer {
+            writeln!(f, "Maintainer: {maintainer}")?;
+        }
+        writeln!(f, "Build-Depends:")?;
+        writeln!(f, " debhelper-compat (= 13),")?;
+        writeln!(f, " python3,")?;
+        writeln!(f, " uv,")?;
+        for name in &package.build_requires {
+            writeln!(f, " {},", self.distro_name(name))?;
+        }
+        writeln!(f, "Rules-Requires-Root: no")?;
+        writeln!(f)?;
+
+        writeln!(f, "Package: {}", package.name)?;
+        writeln!(f, "Architecture: all")?;
+        writeln!(f, "Depends:")?;
+        writeln!(f, " ${{misc:Depends}},")?;
+        writeln!(f, " python3,")?;
+        for (name, version) in &package.requires {
+            writeln!(
+                f,
+                " {} (>= {}),",
+                self.distro_name(name),
+                distro_version(version)
+            )?;
+        }
+        writeln!(f, "Description: {}", package.summary())?;
+        writeln!(f)?;
+
+        writeln!(f, "# The build and install steps, for `debian/rules`:")?;
+        writeln!(f, "#")?;
+        writeln!(f, "#     %:")?;
+        writeln!(f, "#     \tdh $@")?;
+        writeln!(f, "#")?;
+        writeln!(f, "#     override_dh_auto_build:")?;
+        writeln!(
+            f,
+            "#     \tuv build --wheel --no-build-isolation --python python3 --out-dir dist"
+        )?;
+        writeln!(f, "#")?;
+        writeln!(f, "#     override_dh_auto_install:")?;
+        writeln!(
+            f,
+            "#     \tuv pip install --no-deps --no-index --python python3 --root $(CURDIR)/debian/{} --prefix /usr dist/*.whl",
+            package.name
+        )?;
+
+        Ok(())
+    }
+}
+
+/// The project to package for a distribution, along with its locked dependencies.
+#[derive(Debug)]
+struct DistroPackage<'lock> {
+    name: &'lock PackageName,
+    version: &'lock Version,
+    summary: Option<String>,
+    license: Option<String>,
+    maintainer: Option<String>,
+    scripts: Vec<String>,
+    /// The build requirements of the project.
+    build_requires: Vec<PackageName>,
+    /// The locked runtime dependencies of the project, along with their minimum version.
+    requires: BTreeMap<&'lock PackageName, &'lock Version>,
+}
+
+impl<'lock> DistroPackage<'lock> {
+    fn from_lock(
+        target: InstallTarget<'lock>,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsManifest,
+        install_options: &'lock InstallOptions,
+    ) -> Self {
+        let lock = target.lock();
+        let roots = target.packages().collect::<Vec<_>>();
+        let root = target
+            .project_name()
+            .or_else(|| roots.first().copied())
+            .expect("a target has at least one package");
+        let package = lock
+            .find_by_name(root)
+            .expect("found too many packages matching root")
+            .expect("could not find root");
+
+        // Distribution packages are built for Linux, so omit any dependencies that are never
+        // installed there.
+        let platform = lock.requires_python().to_marker_tree();
+        let mut platform_linux = MarkerTree::from_str(
+            "sys_platform == 'linux' and platform_system == 'Linux' and os_name == 'posix'",
+        )
+        .expect("Linux markers are valid");
+        platform_linux.and(platform);
+
+        // If there are multiple locked versions of a dependency, require the lowest.
+        let mut requires = BTreeMap::new();
+        for Requirement {
+            package, marker, ..
+        } in requirements(target, extras, dev, install_options)
+        {
+            if roots.contains(&&package.id.name) || matches!(package.id.source, Source::Virtual(_))
+            {
+                continue;
+            }
+            let mut marker = marker.clone();
+            marker.and(platform_linux.clone());
+            if marker.is_false() {
+                continue;
+            }
+            requires
+                .entry(&package.id.name)
+                .and_modify(|version: &mut &Version| {
+                    if package.id.version < **version {
+                        *version = &package.id.version;
+                    }
+                })
+                .or_insert(&package.id.version);
+        }
+
+        // Read the remaining metadata from the `pyproject.toml`, if it's available.
+        let pyproject = target
+            .workspace()
+            .packages()
+            .get(root)
+            .and_then(|member| ImDocument::from_str(&member.pyproject_toml().raw).ok());
+        let project = pyproject
+            .as_ref()
+            .and_then(|pyproject| pyproject.get("project"));
+
+        let summary = project
+            .and_then(|project| project.get("description"))
+            .and_then(Item::as_str)
+            .map(ToString::to_string);
+        let license = project
+            .and_then(|project| project.get("license"))
+            .and_then(|license| {
+                license
+                    .as_str()
+                    .or_else(|| license.get("text").and_then(Item::as_str))
+            })
+            .filter(|license| !license.contains('\n'))
+            .map(ToString::to_string);
+        let maintainer = project
+            .and_then(|project| {
+                project
+                    .get("maintainers")
+                    .or_else(|| project.get("authors"))
+            })
+            .and_then(Item::as_array)
+            .and_then(|people| people.iter().next())
+            .and_then(|person| person.as_inline_table())
+            .and_then(|person| {
+                let name = person.get("name").and_then(|name| name.as_str());
+                let email = person.get("email").and_then(|email| email.as_str());
+                match (name, email) {
+                    (Some(name), Some(email)) => Some(format!("{name} <{email}>")),
+                    (Some(name), None) => Some(name.to_string()),
+                    (None, Some(email)) => Some(format!("<{email}>")),
+                    (None, None) => None,
+                }
+            });
+        let scripts = project
+            .and_then(|project| project.get("scripts"))
+            .and_then(Item::as_table_like)
+            .map(|scripts| {
+                scripts
+                    .iter()
+                    .map(|(name, _)| name.to_string())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        // Map the build requirements to package names, falling back to the PEP 517 default.
+        let build_system = pyproject
+            .as_ref()
+            .and_then(|pyproject| pyproject.get("build-system"))
+            .and_then(|build_system| build_system.get("requires"))
+            .and_then(Item::as_array);
+        let build_requires = match build_system {
+            Some(requires) => requires
+                .iter()
+                .filter_map(|requirement| requirement.as_str())
+                .filter_map(|requirement| uv_pep508::Requirement::from_str(requirement).ok())
+                .map(|requirement: uv_pep508::Requirement| requirement.name)
+                .unique()
+                .collect(),
+            None => vec![PackageName::from_str(DEFAULT_BUILD_REQUIRES)
+                .expect("default build requirement is a valid package name")],
+        };
+
+        Self {
+            name: &package.id.name,
+            version: &package.id.version,
+            summary,
+            license,
+            maintainer,
+            scripts,
+            build_requires,
+            requires,
+        }
+    }
+
+    /// Return the summary of the project, falling back to its name.
+    fn summary(&self) -> String {
+        self.summary
+            .as_deref()
+            .filter(|summary| !summary.is_empty() && !summary.contains('\n'))
+            .map(ToString::to_string)
+            .unwrap_or_else(|| self.name.to_string())
+    }
+}
+
+/// Convert a Python version to a version that sorts correctly in RPM and Debian, in which a `~`
+/// sorts before any other character (including the end of the version), such that pre-releases
+/// and development releases sort before the final release.
+fn distro_version(version: &Version) -> String {
+    let mut distro = String::new();
+    if version.epoch() != 0 {
+        write!(distro, "{}:", version.epoch()).unwrap();
+    }
+    write!(distro, "{}", version.release().iter().join(".")).unwrap();
+    if let Some(pre) = version.pre() {
+        write!(distro, "~{pre}").unwrap();
+    }
+    if let Some(post) = version.post() {
+        write!(distro, ".post{post}").unwrap();
+    }
+    if let Some(dev) = version.dev() {
+        // A development release sorts before any pre-release of the same version.
+        if version.pre().is_none() && version.post().is_none() {
+            write!(distro, "~~dev{dev}").unwrap();
+        } else {
+            write!(distro, "~dev{dev}").unwrap();
+        }
+    }
+    distro
+}
@@ -0,0 +1,224 @@
+use std::collections::BTreeSet;
+use std::fmt::Formatter;
+use std::ops::Bound;
+use std::path::{Component, PathBuf};
+
+use uv_configuration::{
+    DevGroupsSpecification, EditableMode, ExtrasSpecification, GroupsSpecification, InstallOptions,
+};
+use uv_fs::PortablePath;
+use uv_normalize::PackageName;
+
+use crate::lock::Source;
+use crate::{InstallTarget, LockError};
+
+/// The Python version to use for the base image if the lockfile has no lower bound.
+const DEFAULT_PYTHON_VERSION: &str = "3.12";
+
+/// An export of a [`Lock`] that renders as a Dockerfile.
+///
+/// The Dockerfile installs the project in two layers: one for the locked third-party
+/// dependencies, which is only invalidated when the lockfile or a `pyproject.toml` changes, and one
+/// for the project itself. Both layers run `uv sync --frozen` against the lockfile, such that the
+/// appropriate wheels are selected for each target platform in multi-platform builds.
+#[derive(Debug)]
+pub struct DockerfileExport {
+    /// The Python version of the base image.
+    python_version: String,
+    /// The `pyproject.toml` files of the workspace members, relative to the workspace root.
+    pyprojects: Vec<PathBuf>,
+    /// The local, non-member packages within the workspace, which can only be installed once the
+    /// workspace is copied into the image.
+    local: BTreeSet<PackageName>,
+    /// The local packages outside the workspace, which can't be copied into the image.
+    external: Vec<(PackageName, PathBuf)>,
+    /// The arguments to pass to `uv sync` in both layers.
+    args: Vec<String>,
+}
+
+impl DockerfileExport {
+    pub fn from_lock(
+        target: InstallTarget<'_>,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsSpecification,
+        editable: EditableMode,
+        install_options: &InstallOptions,
+    ) -> Result<Self, LockError> {
+        let lock = target.lock();
+        let workspace = target.workspace();
+
+        // Use the minimum supported Python version for the base image.
+        let python_version = match &*lock.requires_python().bound_major_minor() {
+            Bound::Included(version) => version.to_string(),
+            _ => DEFAULT_PYTHON_VERSION.to_string(),
+        };
+
+        // Mount every `pyproject.toml` in the workspace, such that the workspace can be discovered
+        // before it's copied into the image. Local packages that aren't workspace members can only
+        // be installed once the workspace is copied into the image.
+        let mut pyprojects = vec![PathBuf::from("pyproject.toml")];
+        let mut local = BTreeSet::new();
+        let mut external = Vec::new();
+        for package in &lock.packages {
+            let (Source::Path(path)
+            | Source::Directory(path)
+            | Source::Editable(path)
+            | Source::Virtual(path)) = &package.id.source
+            else {
+                continue;
+            };
+            let member = lock.members().contains(&package.id.name)
+                || workspace.packages().contains_key(&package.id.name);
+            if path.is_absolute() || path.components().next() == Some(Component::ParentDir) {
+                external.push((package.id.name.clone(), path.clone()));
+            } else if member {
+                if path
+                    .components()
+                    .all(|component| component == Component::CurDir)
+                {
+                    continue;
+                }
+                pyprojects.push(path.join("pyproject.toml"));
+            } else {
+                local.insert(package.id.name.clone());
+            }
+        }
+        pyprojects.sort();
+        pyprojects.dedup();
+
+        let mut args = Vec::new();
+        match target {
+            InstallTarget::Project { name, .. } => {
+                let root = workspace
+                    .pyproject_toml()
+                    .project
+                    .as_ref()
+                    .map(|project| &project.name);
+                if root != Some(name) {
+                    args.push(format!("--package {name}"));
+                }
+            }
+            InstallTarget::Workspace { .. } | InstallTarget::NonProjectWorkspace { .. } => {
+                args.push("--all-packages".to_string());
+            }
+        }
+        match extras {
+            ExtrasSpecification::None => {}
+            ExtrasSpecification::All => args.push("--all-extras".to_string()),
+            ExtrasSpecification::Some(extras) => {
+                args.extend(extras.iter().map(|extra| format!("--extra {extra}")));
+            }
+        }
+        if let Some(dev) = dev.dev_mode() {
+            args.push(dev.as_flag().to_string());
+        }
+        match dev.groups() {
+            None => {}
+            Some(GroupsSpecification::Include { include, exclude }) => {
+                args.extend(include.iter().map(|group| format!("--group {group}")));
+                args.extend(exclude.iter().map(|group| format!("--no-group {group}")));
+            }
+            Some(GroupsSpecification::Only { include, exclude }) => {
+                args.extend(include.iter().map(|group| format!("--only-group {group}")));
+                args.extend(exclude.iter().map(|group| format!("--no-group {group}")));
+            }
+        }
+        if matches!(editable, EditableMode::NonEditable) {
+            args.push("--no-editable".to_string());
+        }
+        if install_options.no_install_project {
+            args.push("--no-install-project".to_string());
+        }
+        if install_options.no_install_workspace {
+            args.push("--no-install-workspace".to_string());
+        }
+        args.extend(
+            install_options
+                .no_install_package
+                .iter()
+                .map(|package| format!("--no-install-package {package}")),
+        );
+
+        Ok(Self {
+            python_version,
+            pyprojects,
+            local,
+            external,
+            args,
+        })
+    }
+}
+
+impl std::fmt::Display for DockerfileExport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (name, path) in &self.external {
+            writeln!(
+                f,
+                "# Warning: `{name}` is installed from `{}`, outside of the build context",
+                PortablePath::from(path)
+            )?;
+        }
+
+        writeln!(f, "FROM python:{}-slim", self.python_version)?;
+        writeln!(f)?;
+        writeln!(f, "# Install uv.")?;
+        writeln!(
+            f,
+            "COPY --from=ghcr.io/astral-sh/uv:{} /uv /uvx /bin/",
+            uv_version::version()
+        )?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "# Compile bytecode for faster startup, copy from the cache mount rather than linking,"
+        )?;
+        writeln!(f, "# and use the base image's Python interpreter.")?;
+        writeln!(
+            f,
+            "ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy UV_PYTHON_DOWNLOADS=never"
+        )?;
+        writeln!(f)?;
+        writeln!(f, "WORKDIR /app")?;
+        writeln!(f)?;
+
+        // Install the third-party dependencies, excluding anything that requires the workspace.
+        writeln!(
+            f,
+            "# Install the locked third-party dependencies, in a layer that's only invalidated when"
+        )?;
+        writeln!(f, "# the lockfile or a `pyproject.toml` changes.")?;
+        writeln!(f, "RUN --mount=type=cache,target=/root/.cache/uv \\")?;
+        writeln!(f, "    --mount=type=bind,source=uv.lock,target=uv.lock \\")?;
+        for pyproject in &self.pyprojects {
+            let pyproject = PortablePath::from(pyproject);
+            writeln!(
+                f,
+                "    --mount=type=bind,source={pyproject},target={pyproject} \\"
+            )?;
+        }
+        write!(f, "    uv sync --frozen --no-install-workspace")?;
+        for name in &self.local {
+            write!(f, " --no-install-package {name}")?;
+        }
+        for arg in &self.args {
+            write!(f, " {arg}")?;
+        }
+        writeln!(f)?;
+        writeln!(f)?;
+
+        // Install the project.
+        writeln!(f, "# Install the project.")?;
+        writeln!(f, "COPY . /app")?;
+        writeln!(f, "RUN --mount=type=cache,target=/root/.cache/uv \\")?;
+        write!(f, "    uv sync --frozen")?;
+        for arg in &self.args {
+            write!(f, " {arg}")?;
+        }
+        writeln!(f)?;
+        writeln!(f)?;
+        writeln!(f, "# Place the environment's executables on the `PATH`.")?;
+        writeln!(f, "ENV PATH=\"/app/.venv/bin:$PATH\"")?;
+
+        Ok(())
+    }
+}
@@ -13,8 +13,18 @@ use std::sync::{Arc, LazyLock};
 use toml_edit::{value, Array, ArrayOfTables, InlineTable, Item, Table, Value};
 use url::Url;
 
+pub use crate::lock::bazel::BazelExport;
+pub use crate::lock::conflicts::{ExtraConflict, ExtraConflicts, UnnecessaryConflict};
+pub use crate::lock::diff::LockDiff;
+pub use crate::lock::distro::{DebControlExport, RpmSpecExport};
+pub use crate::lock::dockerfile::DockerfileExport;
 pub use crate::lock::map::PackageMap;
+pub use crate::lock::nix::NixExport;
+pub use crate::lock::python_support::{PythonSupport, UnsupportedPackage};
+pub use crate::lock::requirements_in::RequirementsInExport;
 pub use crate::lock::requirements_txt::RequirementsTxtExport;
+pub use crate::lock::source_builds::{SourceBuildPackage, SourceBuilds};
+pub use crate::lock::statistics::LockStatistics;
 pub use crate::lock::target::InstallTarget;
 pub use crate::lock::tree::TreeDisplay;
 use crate::requires_python::SimplifiedMarkerTree;
@@ -31,10 +41,10 @@ use uv_distribution_types::{
     BuiltDist, DependencyMetadata, DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist,
     Dist, DistributionMetadata, FileLocation, GitSourceDist, IndexLocations, IndexUrl, Name,
     PathBuiltDist, PathSourceDist, RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist,
-    RemoteSource, ResolvedDist, StaticMetadata, ToUrlError, UrlString,
+    RemoteSource, Resolution, ResolvedDist, StaticMetadata, ToUrlError, UrlString,
 };
 use uv_fs::{relative_to, PortablePath, PortablePathBuf};
-use uv_git::{GitReference, GitSha, RepositoryReference, ResolvedRepositoryReference};
+use uv_git::{GitReference, GitSha, RepositoryReference, ResolvedRepositoryReference, VcsBackend};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_pep508::{split_scheme, MarkerEnvironment, MarkerTree, VerbatimUrl, VerbatimUrlError};
@@ -47,8 +57,18 @@ use uv_types::{BuildContext, HashStrategy};
 use uv_workspace::dependency_groups::DependencyGroupError;
 use uv_workspace::Workspace;
 
+mod bazel;
+mod conflicts;
+mod diff;
+mod distro;
+mod dockerfile;
 mod map;
+mod nix;
+mod python_support;
+mod requirements_in;
 mod requirements_txt;
+mod source_builds;
+mod statistics;
 mod target;
 mod tree;
 
@@ -74,6 +94,46 @@ static MAC_MARKERS: LazyLock<MarkerTree> = LazyLock::new(|| {
     .unwrap()
 });
 
+/// Returns the markers of the platform targeted by the given wheel, if the wheel is specific to
+/// Linux, Windows, or macOS.
+fn wheel_platform_markers(filename: &WheelFilename) -> Option<&'static MarkerTree> {
+    // See https://github.com/pypi/warehouse/blob/ccff64920db7965078cf1fdb50f028e640328887/warehouse/forklift/legacy.py#L100-L169
+    // for a list of relevant platforms.
+    let linux_tags = [
+        "manylinux1_",
+        "manylinux2010_",
+        "manylinux2014_",
+        "musllinux_",
+        "manylinux_",
+    ];
+    let windows_tags = ["win32", "win_arm64", "win_amd64", "win_ia64"];
+
+    // Naively, we'd check whether `platform_system == 'Linux'` is disjoint, or
+    // `os_name == 'posix'` is disjoint, or `sys_platform == 'linux'` is disjoint (each on its
+    // own sufficient to exclude linux wheels), but due to
+    // `(A ∩ (B ∩ C) = ∅) => ((A ∩ B = ∅) or (A ∩ C = ∅))`
+    // a single disjointness check with the intersection is sufficient, so we have one
+    // constant per platform.
+    let platform_tags = &filename.platform_tag;
+    if platform_tags.iter().all(|tag| {
+        linux_tags.into_iter().any(|linux_tag| {
+            // These two linux tags are allowed by warehouse.
+            tag.starts_with(linux_tag) || tag == "linux_armv6l" || tag == "linux_armv7l"
+        })
+    }) {
+        Some(&*LINUX_MARKERS)
+    } else if platform_tags
+        .iter()
+        .all(|tag| windows_tags.contains(&&**tag))
+    {
+        Some(&*WINDOWS_MARKERS)
+    } else if platform_tags.iter().all(|tag| tag.starts_with("macosx_")) {
+        Some(&*MAC_MARKERS)
+    } else {
+        None
+    }
+}
+
 #[derive(Clone, Debug, serde::Deserialize)]
 #[serde(try_from = "LockWire")]
 pub struct Lock {
@@ -105,6 +165,8 @@ pub struct Lock {
     by_id: FxHashMap<PackageId, usize>,
     /// The input requirements to the resolution.
     manifest: ResolverManifest,
+    /// The packages that are installed under an alias for a specific set of dependents.
+    vendored: Vec<VendoredPackage>,
 }
 
 impl Lock {
@@ -232,6 +294,7 @@ impl Lock {
             resolution_mode: graph.options.resolution_mode,
             prerelease_mode: graph.options.prerelease_mode,
             exclude_newer: graph.options.exclude_newer,
+            group_resolution: BTreeMap::default(),
         };
         let lock = Self::new(
             VERSION,
@@ -262,49 +325,10 @@ impl Lock {
             .retain(|wheel| requires_python.matches_wheel_tag(&wheel.filename));
 
         // Filter by platform tags.
-
-        // See https://github.com/pypi/warehouse/blob/ccff64920db7965078cf1fdb50f028e640328887/warehouse/forklift/legacy.py#L100-L169
-        // for a list of relevant platforms.
-        let linux_tags = [
-            "manylinux1_",
-            "manylinux2010_",
-            "manylinux2014_",
-            "musllinux_",
-            "manylinux_",
-        ];
-        let windows_tags = ["win32", "win_arm64", "win_amd64", "win_ia64"];
-
         locked_dist.wheels.retain(|wheel| {
-            // Naively, we'd check whether `platform_system == 'Linux'` is disjoint, or
-            // `os_name == 'posix'` is disjoint, or `sys_platform == 'linux'` is disjoint (each on its
-            // own sufficient to exclude linux wheels), but due to
-            // `(A ∩ (B ∩ C) = ∅) => ((A ∩ B = ∅) or (A ∩ C = ∅))`
-            // a single disjointness check with the intersection is sufficient, so we have one
-            // constant per platform.
-            let platform_tags = &wheel.filename.platform_tag;
-            if platform_tags.iter().all(|tag| {
-                linux_tags.into_iter().any(|linux_tag| {
-                    // These two linux tags are allowed by warehouse.
-                    tag.starts_with(linux_tag) || tag == "linux_armv6l" || tag == "linux_armv7l"
-                })
-            }) {
-                !graph.petgraph[node_index]
-                    .marker()
-                    .is_disjoint(&LINUX_MARKERS)
-            } else if platform_tags
-                .iter()
-                .all(|tag| windows_tags.contains(&&**tag))
-            {
-                !graph.petgraph[node_index]
-                    .marker()
-                    .is_disjoint(&WINDOWS_MARKERS)
-            } else if platform_tags.iter().all(|tag| tag.starts_with("macosx_")) {
-                !graph.petgraph[node_index]
-                    .marker()
-                    .is_disjoint(&MAC_MARKERS)
-            } else {
-                true
-            }
+            wheel_platform_markers(&wheel.filename).map_or(true, |markers| {
+                !graph.petgraph[node_index].marker().is_disjoint(markers)
+            })
         });
     }
 
@@ -472,6 +496,7 @@ impl Lock {
             packages,
             by_id,
             manifest,
+            vendored: Vec::new(),
         };
         Ok(lock)
     }
@@ -483,6 +508,62 @@ impl Lock {
         self
     }
 
+    /// Record the resolved build requirements of each package that was built from source.
+    ///
+    /// The build requirements are provided by `build_dependencies` for each package that was
+    /// built in the current operation. Otherwise, those recorded for the same package in the
+    /// `previous` lock (if any) are retained, since packages with cached metadata aren't rebuilt.
+    #[must_use]
+    pub fn with_build_dependencies(
+        mut self,
+        build_dependencies: impl Fn(&PackageName, &Version) -> Option<Vec<(PackageName, Version)>>,
+        previous: Option<&Lock>,
+    ) -> Self {
+        for package in &mut self.packages {
+            // Editable and virtual packages are never recorded, as they're built on every install.
+            if matches!(package.id.source, Source::Editable(_) | Source::Virtual(_)) {
+                continue;
+            }
+            if let Some(dependencies) = build_dependencies(&package.id.name, &package.id.version) {
+                let mut dependencies = dependencies
+                    .into_iter()
+                    .map(|(name, version)| BuildDependency { name, version })
+                    .collect::<Vec<_>>();
+                dependencies.sort();
+                dependencies.dedup();
+                package.build_dependencies = dependencies;
+            } else if let Some(previous) = previous.and_then(|lock| {
+                lock.by_id
+                    .get(&package.id)
+                    .map(|index| &lock.packages[*index])
+            }) {
+                package
+                    .build_dependencies
+                    .clone_from(&previous.build_dependencies);
+            }
+        }
+        self
+    }
+
+    /// Record the packages that are installed under an alias.
+    #[must_use]
+    pub fn with_vendored(mut self, mut vendored: Vec<VendoredPackage>) -> Self {
+        vendored.sort_by(|a, b| a.alias.cmp(&b.alias));
+        self.vendored = vendored;
+        self
+    }
+
+    /// Record the resolution strategies of the dependency groups that were used to generate this
+    /// lock.
+    #[must_use]
+    pub fn with_group_resolution(
+        mut self,
+        group_resolution: BTreeMap<GroupName, ResolutionMode>,
+    ) -> Self {
+        self.options.group_resolution = group_resolution;
+        self
+    }
+
     /// Record the conflicting groups that were used to generate this lock.
     #[must_use]
     pub fn with_conflicting_groups(mut self, conflicting_groups: ConflictingGroupList) -> Self {
@@ -529,6 +610,11 @@ impl Lock {
         &self.packages
     }
 
+    /// Returns the packages that are installed under an alias.
+    pub fn vendored(&self) -> &[VendoredPackage] {
+        &self.vendored
+    }
+
     /// Returns the supported Python version range for the lockfile, if present.
     pub fn requires_python(&self) -> &RequiresPython {
         &self.requires_python
@@ -549,6 +635,11 @@ impl Lock {
         self.options.exclude_newer
     }
 
+    /// Returns the resolution strategies of the dependency groups used to generate this lock.
+    pub fn group_resolution(&self) -> &BTreeMap<GroupName, ResolutionMode> {
+        &self.options.group_resolution
+    }
+
     /// Returns the conflicting groups that were used to generate this lock.
     pub fn conflicting_groups(&self) -> &ConflictingGroupList {
         &self.conflicting_groups
@@ -574,6 +665,44 @@ impl Lock {
         })
     }
 
+    /// Returns the packages that depend directly on the package with the given name, along with
+    /// the extra or dependency group (if any) that declares each dependency.
+    pub fn dependents(&self, name: &PackageName) -> Vec<Dependent<'_>> {
+        let mut dependents = Vec::new();
+        for package in &self.packages {
+            if package
+                .dependencies
+                .iter()
+                .any(|dep| dep.package_id.name == *name)
+            {
+                dependents.push(Dependent {
+                    package,
+                    extra: None,
+                    group: None,
+                });
+            }
+            for (extra, deps) in &package.optional_dependencies {
+                if deps.iter().any(|dep| dep.package_id.name == *name) {
+                    dependents.push(Dependent {
+                        package,
+                        extra: Some(extra),
+                        group: None,
+                    });
+                }
+            }
+            for (group, deps) in &package.dependency_groups {
+                if deps.iter().any(|dep| dep.package_id.name == *name) {
+                    dependents.push(Dependent {
+                        package,
+                        extra: None,
+                        group: Some(group),
+                    });
+                }
+            }
+        }
+        dependents
+    }
+
     /// Returns the supported environments that were used to generate this
     /// lock.
     ///
@@ -666,6 +795,15 @@ impl Lock {
             if let Some(exclude_newer) = self.options.exclude_newer {
                 options_table.insert("exclude-newer", value(exclude_newer.to_string()));
             }
+            if !self.options.group_resolution.is_empty() {
+                let group_resolution = self
+                    .options
+                    .group_resolution
+                    .iter()
+                    .map(|(group, mode)| (group.to_string(), Value::from(mode.to_string())))
+                    .collect::<InlineTable>();
+                options_table.insert("group-resolution", value(group_resolution));
+            }
 
             if !options_table.is_empty() {
                 doc.insert("options", Item::Table(options_table));
@@ -802,6 +940,15 @@ impl Lock {
         }
 
         doc.insert("package", Item::ArrayOfTables(packages));
+
+        if !self.vendored.is_empty() {
+            let mut vendored = ArrayOfTables::new();
+            for dist in &self.vendored {
+                vendored.push(dist.to_toml(&self.requires_python)?);
+            }
+            doc.insert("vendored", Item::ArrayOfTables(vendored));
+        }
+
         Ok(doc.to_string())
     }
 
@@ -1307,6 +1454,9 @@ struct ResolverOptions {
     prerelease_mode: PrereleaseMode,
     /// The [`ExcludeNewer`] used to generate this lock.
     exclude_newer: Option<ExcludeNewer>,
+    /// The [`ResolutionMode`] for each dependency group used to generate this lock.
+    #[serde(default)]
+    group_resolution: BTreeMap<GroupName, ResolutionMode>,
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize, PartialEq, Eq)]
@@ -1392,6 +1542,8 @@ struct LockWire {
     manifest: ResolverManifest,
     #[serde(rename = "package", alias = "distribution", default)]
     packages: Vec<PackageWire>,
+    #[serde(default)]
+    vendored: Vec<VendoredPackageWire>,
 }
 
 impl TryFrom<LockWire> for Lock {
@@ -1420,6 +1572,11 @@ impl TryFrom<LockWire> for Lock {
             .into_iter()
             .map(|dist| dist.unwire(&wire.requires_python, &unambiguous_package_ids))
             .collect::<Result<Vec<_>, _>>()?;
+        let vendored = wire
+            .vendored
+            .into_iter()
+            .map(|dist| dist.unwire(&wire.requires_python))
+            .collect::<Result<Vec<_>, _>>()?;
         let supported_environments = wire
             .supported_environments
             .into_iter()
@@ -1440,7 +1597,8 @@ impl TryFrom<LockWire> for Lock {
                 .unwrap_or_else(ConflictingGroupList::empty),
             supported_environments,
             fork_markers,
-        )?;
+        )?
+        .with_vendored(vendored);
 
         Ok(lock)
     }
@@ -1461,6 +1619,34 @@ impl LockVersion {
     }
 }
 
+/// A package that depends on another package in a [`Lock`], as returned by [`Lock::dependents`].
+#[derive(Clone, Copy, Debug)]
+pub struct Dependent<'lock> {
+    /// The dependent package.
+    pub package: &'lock Package,
+    /// The extra that declares the dependency, if it's an optional dependency.
+    pub extra: Option<&'lock ExtraName>,
+    /// The dependency group that declares the dependency, if it's a development dependency.
+    pub group: Option<&'lock GroupName>,
+}
+
+/// A direct dependency of a package in a [`Lock`], as returned by [`Package::dependencies`].
+#[derive(Clone, Copy, Debug)]
+pub struct PackageDependency<'lock> {
+    /// The name of the dependency.
+    pub name: &'lock PackageName,
+    /// The locked version of the dependency.
+    pub version: &'lock Version,
+    /// The extras that are enabled on the dependency.
+    pub extras: &'lock BTreeSet<ExtraName>,
+    /// The marker under which the dependency applies.
+    pub marker: &'lock MarkerTree,
+    /// The extra that declares the dependency, if it's an optional dependency.
+    pub extra: Option<&'lock ExtraName>,
+    /// The dependency group that declares the dependency, if it's a development dependency.
+    pub group: Option<&'lock GroupName>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Package {
     pub(crate) id: PackageId,
@@ -1478,6 +1664,10 @@ pub struct Package {
     optional_dependencies: BTreeMap<ExtraName, Vec<Dependency>>,
     /// The resolved PEP 735 dependency groups of the package.
     dependency_groups: BTreeMap<GroupName, Vec<Dependency>>,
+    /// The resolved PEP 517 build requirements of the package, if it was built from source.
+    ///
+    /// Named `build-dependencies` in `uv.lock`.
+    build_dependencies: Vec<BuildDependency>,
     /// The exact requirements from the package metadata.
     metadata: PackageMetadata,
 }
@@ -1533,6 +1723,7 @@ impl Package {
             dependencies: vec![],
             optional_dependencies: BTreeMap::default(),
             dependency_groups: BTreeMap::default(),
+            build_dependencies: vec![],
             metadata: PackageMetadata {
                 requires_dist,
                 dependency_groups,
@@ -1814,7 +2005,8 @@ impl Package {
                     url,
                     GitReference::from(git.kind.clone()),
                     git.precise,
-                );
+                )
+                .with_backend(git.backend);
 
                 // Reconstruct the PEP 508-compatible URL from the `GitSource`.
                 let url = Url::from(ParsedGitUrl {
@@ -1999,6 +2191,13 @@ impl Package {
             }
         }
 
+        if !self.build_dependencies.is_empty() {
+            let deps = each_element_on_its_line_array(
+                self.build_dependencies.iter().map(BuildDependency::to_toml),
+            );
+            table.insert("build-dependencies", value(deps));
+        }
+
         if let Some(ref sdist) = self.sdist {
             table.insert("sdist", value(sdist.to_toml()?));
         }
@@ -2102,11 +2301,48 @@ impl Package {
         &self.id.name
     }
 
+    /// Returns the direct dependencies of the package, including its optional dependencies and
+    /// dependency groups.
+    pub fn dependencies(&self) -> Vec<PackageDependency<'_>> {
+        fn dependency(dep: &Dependency) -> PackageDependency<'_> {
+            PackageDependency {
+                name: &dep.package_id.name,
+                version: &dep.package_id.version,
+                extras: &dep.extra,
+                marker: &dep.complexified_marker,
+                extra: None,
+                group: None,
+            }
+        }
+
+        let mut dependencies = self.dependencies.iter().map(dependency).collect::<Vec<_>>();
+        for (extra, deps) in &self.optional_dependencies {
+            dependencies.extend(deps.iter().map(|dep| PackageDependency {
+                extra: Some(extra),
+                ..dependency(dep)
+            }));
+        }
+        for (group, deps) in &self.dependency_groups {
+            dependencies.extend(deps.iter().map(|dep| PackageDependency {
+                group: Some(group),
+                ..dependency(dep)
+            }));
+        }
+        dependencies
+    }
+
     /// Returns the [`Version`] of the package.
     pub fn version(&self) -> &Version {
         &self.id.version
     }
 
+    /// Returns the resolved build requirements of the package, if it was built from source.
+    pub fn build_dependencies(&self) -> impl Iterator<Item = (&PackageName, &Version)> {
+        self.build_dependencies
+            .iter()
+            .map(|dependency| (&dependency.name, &dependency.version))
+    }
+
     /// Return the fork markers for this package, if any.
     pub fn fork_markers(&self) -> &[MarkerTree] {
         self.fork_markers.as_slice()
@@ -2130,8 +2366,16 @@ impl Package {
         }
     }
 
+    /// Returns the URL of the package, if it is a direct URL source.
+    pub fn direct_url(&self) -> Option<Url> {
+        match &self.id.source {
+            Source::Direct(url, _) => Some(url.to_url()),
+            _ => None,
+        }
+    }
+
     /// Returns all the hashes associated with this [`Package`].
-    fn hashes(&self) -> Vec<HashDigest> {
+    pub fn hashes(&self) -> Vec<HashDigest> {
         let mut hashes = Vec::new();
         if let Some(ref sdist) = self.sdist {
             if let Some(hash) = sdist.hash() {
@@ -2144,6 +2388,23 @@ impl Package {
         hashes
     }
 
+    /// Returns the filename and hash of each wheel associated with this [`Package`].
+    pub fn wheels(&self) -> impl Iterator<Item = (&WheelFilename, Option<&HashDigest>)> {
+        self.wheels
+            .iter()
+            .map(|wheel| (&wheel.filename, wheel.hash.as_ref().map(|hash| &hash.0)))
+    }
+
+    /// Returns `true` if the package is only available as platform-specific wheels, e.g., because
+    /// it includes extension modules.
+    pub fn is_platform_specific(&self) -> bool {
+        !self.wheels.is_empty()
+            && self
+                .wheels
+                .iter()
+                .all(|wheel| !wheel.filename.platform_tag.iter().any(|tag| tag == "any"))
+    }
+
     /// Returns the [`ResolvedRepositoryReference`] for the package, if it is a Git source.
     pub fn as_git_ref(&self) -> Option<ResolvedRepositoryReference> {
         match &self.id.source {
@@ -2188,6 +2449,8 @@ struct PackageWire {
     optional_dependencies: BTreeMap<ExtraName, Vec<DependencyWire>>,
     #[serde(default, rename = "dev-dependencies", alias = "dependency-groups")]
     dependency_groups: BTreeMap<GroupName, Vec<DependencyWire>>,
+    #[serde(default)]
+    build_dependencies: Vec<BuildDependency>,
 }
 
 #[derive(Clone, Default, Debug, Eq, PartialEq, serde::Deserialize)]
@@ -2231,6 +2494,160 @@ impl PackageWire {
                 .into_iter()
                 .map(|(group, deps)| Ok((group, unwire_deps(deps)?)))
                 .collect::<Result<_, LockError>>()?,
+            build_dependencies: self.build_dependencies,
+        })
+    }
+}
+
+/// A resolved PEP 517 build requirement of a [`Package`] that was built from source.
+///
+/// Build requirements are resolved for the environment in which the package was built, and are
+/// recorded separately from the runtime dependency graph.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Deserialize)]
+struct BuildDependency {
+    name: PackageName,
+    version: Version,
+}
+
+impl BuildDependency {
+    fn to_toml(&self) -> InlineTable {
+        let mut table = InlineTable::new();
+        table.insert("name", Value::from(self.name.to_string()));
+        table.insert("version", Value::from(self.version.to_string()));
+        table
+    }
+}
+
+/// A package that's installed under an alias (i.e., a rewritten top-level module name), for use
+/// by a specific set of dependents in lieu of the package selected by the main resolution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VendoredPackage {
+    /// The top-level module name under which the package is installed.
+    alias: String,
+    /// The packages that import the vendored copy.
+    dependents: BTreeSet<PackageName>,
+    /// The locked package, without any dependencies.
+    package: Package,
+}
+
+impl VendoredPackage {
+    /// Initialize a [`VendoredPackage`] from the [`ResolutionGraph`] for the vendored package.
+    pub fn from_resolution_graph(
+        graph: &ResolutionGraph,
+        root: &Path,
+        name: &PackageName,
+        alias: String,
+        dependents: impl IntoIterator<Item = PackageName>,
+    ) -> Result<Self, LockError> {
+        let lock = Lock::from_resolution_graph(graph, root)?;
+        let Some(mut package) = lock
+            .packages
+            .into_iter()
+            .find(|package| package.id.name == *name)
+        else {
+            return Err(LockErrorKind::MissingVendoredPackage { name: name.clone() }.into());
+        };
+
+        // Vendored packages are installed as leaves.
+        package.fork_markers.clear();
+        package.dependencies.clear();
+        package.optional_dependencies.clear();
+        package.dependency_groups.clear();
+
+        Ok(Self {
+            alias,
+            dependents: dependents.into_iter().collect(),
+            package,
+        })
+    }
+
+    /// Returns the name of the vendored package.
+    pub fn name(&self) -> &PackageName {
+        &self.package.id.name
+    }
+
+    /// Returns the locked version of the vendored package.
+    pub fn version(&self) -> &Version {
+        &self.package.id.version
+    }
+
+    /// Returns the top-level module name under which the package is installed.
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    /// Returns the packages that import the vendored copy.
+    pub fn dependents(&self) -> &BTreeSet<PackageName> {
+        &self.dependents
+    }
+
+    /// Returns the locked [`Package`] for the vendored copy.
+    pub fn package(&self) -> &Package {
+        &self.package
+    }
+
+    /// Convert the [`VendoredPackage`] to a [`Resolution`] containing only the vendored package.
+    pub fn to_resolution(
+        &self,
+        workspace_root: &Path,
+        tags: &Tags,
+        build_options: &BuildOptions,
+    ) -> Result<Resolution, LockError> {
+        let dist =
+            self.package
+                .to_dist(workspace_root, TagPolicy::Required(tags), build_options)?;
+        Ok(Resolution::new(
+            BTreeMap::from([(self.name().clone(), ResolvedDist::Installable(dist))]),
+            BTreeMap::from([(self.name().clone(), self.package.hashes())]),
+            Vec::new(),
+        ))
+    }
+
+    fn to_toml(&self, requires_python: &RequiresPython) -> anyhow::Result<Table> {
+        let package = self
+            .package
+            .to_toml(requires_python, &FxHashMap::default())?;
+
+        // Write the alias and dependents immediately after the package ID.
+        let mut table = Table::new();
+        for (key, item) in package {
+            let is_source = key.as_str() == "source";
+            table.insert(&key, item);
+            if is_source {
+                table.insert("alias", value(self.alias.as_str()));
+                table.insert(
+                    "dependents",
+                    value(
+                        self.dependents
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Array>(),
+                    ),
+                );
+            }
+        }
+        Ok(table)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct VendoredPackageWire {
+    alias: String,
+    #[serde(default)]
+    dependents: BTreeSet<PackageName>,
+    #[serde(flatten)]
+    package: PackageWire,
+}
+
+impl VendoredPackageWire {
+    fn unwire(self, requires_python: &RequiresPython) -> Result<VendoredPackage, LockError> {
+        Ok(VendoredPackage {
+            alias: self.alias,
+            dependents: self.dependents,
+            package: self
+                .package
+                .unwire(requires_python, &FxHashMap::default())?,
         })
     }
 }
@@ -2357,6 +2774,29 @@ enum Source {
 }
 
 impl Source {
+    /// Parse a Git (or Mercurial, or Subversion) source from its lockfile URL.
+    fn from_git_wire(git: &str, backend: VcsBackend) -> Result<Source, LockError> {
+        let url = Url::parse(git)
+            .map_err(|err| SourceParseError::InvalidUrl {
+                given: git.to_string(),
+                err,
+            })
+            .map_err(LockErrorKind::InvalidGitSourceUrl)?;
+
+        let git_source = GitSource::from_url(&url, backend)
+            .map_err(|err| match err {
+                GitSourceError::InvalidSha => SourceParseError::InvalidSha {
+                    given: git.to_string(),
+                },
+                GitSourceError::MissingSha => SourceParseError::MissingSha {
+                    given: git.to_string(),
+                },
+            })
+            .map_err(LockErrorKind::InvalidGitSourceUrl)?;
+
+        Ok(Source::Git(UrlString::from(url), git_source))
+    }
+
     fn from_resolved_dist(resolved_dist: &ResolvedDist, root: &Path) -> Result<Source, LockError> {
         match *resolved_dist {
             // We pass empty installed packages for locking.
@@ -2501,6 +2941,7 @@ impl Source {
                     .as_deref()
                     .and_then(Path::to_str)
                     .map(ToString::to_string),
+                backend: git_dist.git.backend(),
             },
         )
     }
@@ -2552,8 +2993,9 @@ impl Source {
                     );
                 }
             },
-            Source::Git(ref url, _) => {
-                source_table.insert("git", Value::from(url.as_ref()));
+            Source::Git(ref url, ref git) => {
+                // Mercurial and Subversion sources are keyed by their backend (e.g., `hg`).
+                source_table.insert(git.backend.key(), Value::from(url.as_ref()));
             }
             Source::Direct(ref url, DirectSource { ref subdirectory }) => {
                 source_table.insert("url", Value::from(url.as_ref()));
@@ -2607,7 +3049,7 @@ impl Source {
     fn name(&self) -> &str {
         match *self {
             Self::Registry(..) => "registry",
-            Self::Git(..) => "git",
+            Self::Git(_, ref git) => git.backend.key(),
             Self::Direct(..) => "direct",
             Self::Path(..) => "path",
             Self::Directory(..) => "directory",
@@ -2643,6 +3085,12 @@ enum SourceWire {
     Git {
         git: String,
     },
+    Hg {
+        hg: String,
+    },
+    Svn {
+        svn: String,
+    },
     Direct {
         url: UrlString,
         #[serde(default)]
@@ -2671,27 +3119,9 @@ impl TryFrom<SourceWire> for Source {
 
         match wire {
             Registry { registry } => Ok(Source::Registry(registry)),
-            Git { git } => {
-                let url = Url::parse(&git)
-                    .map_err(|err| SourceParseError::InvalidUrl {
-                        given: git.to_string(),
-                        err,
-                    })
-                    .map_err(LockErrorKind::InvalidGitSourceUrl)?;
-
-                let git_source = GitSource::from_url(&url)
-                    .map_err(|err| match err {
-                        GitSourceError::InvalidSha => SourceParseError::InvalidSha {
-                            given: git.to_string(),
-                        },
-                        GitSourceError::MissingSha => SourceParseError::MissingSha {
-                            given: git.to_string(),
-                        },
-                    })
-                    .map_err(LockErrorKind::InvalidGitSourceUrl)?;
-
-                Ok(Source::Git(UrlString::from(url), git_source))
-            }
+            Git { git } => Source::from_git_wire(&git, VcsBackend::Git),
+            Hg { hg } => Source::from_git_wire(&hg, VcsBackend::Mercurial),
+            Svn { svn } => Source::from_git_wire(&svn, VcsBackend::Subversion),
             Direct { url, subdirectory } => Ok(Source::Direct(url, DirectSource { subdirectory })),
             Path { path } => Ok(Source::Path(path.into())),
             Directory { directory } => Ok(Source::Directory(directory.into())),
@@ -2773,6 +3203,7 @@ struct GitSource {
     precise: GitSha,
     subdirectory: Option<String>,
     kind: GitSourceKind,
+    backend: VcsBackend,
 }
 
 /// An error that occurs when a source string could not be parsed.
@@ -2785,7 +3216,7 @@ enum GitSourceError {
 impl GitSource {
     /// Extracts a Git source reference from the query pairs and the hash
     /// fragment in the given URL.
-    fn from_url(url: &Url) -> Result<GitSource, GitSourceError> {
+    fn from_url(url: &Url, backend: VcsBackend) -> Result<GitSource, GitSourceError> {
         let mut kind = GitSourceKind::DefaultBranch;
         let mut subdirectory = None;
         for (key, val) in url.query_pairs() {
@@ -2804,6 +3235,7 @@ impl GitSource {
             precise,
             subdirectory,
             kind,
+            backend,
         })
     }
 }
@@ -3679,6 +4111,7 @@ fn normalize_requirement(
     match requirement.source {
         RequirementSource::Git {
             mut repository,
+            backend,
             reference,
             precise,
             subdirectory,
@@ -3698,6 +4131,7 @@ fn normalize_requirement(
                 marker: requirement.marker,
                 source: RequirementSource::Git {
                     repository,
+                    backend,
                     reference,
                     precise,
                     subdirectory,
@@ -4040,6 +4474,13 @@ enum LockErrorKind {
         /// The name of the dependency that is missing a `source` field.
         name: PackageName,
     },
+    /// An error that occurs when the resolution for a vendored package
+    /// doesn't contain the package itself.
+    #[error("Resolution for vendored package `{name}` does not include `{name}`")]
+    MissingVendoredPackage {
+        /// The name of the vendored package.
+        name: PackageName,
+    },
     /// An error that occurs when parsing an existing requirement.
     #[error("Could not compute relative path between workspace and requirement")]
     RequirementRelativePath(
@@ -4080,6 +4521,13 @@ enum LockErrorKind {
         /// The ID of the package.
         name: PackageName,
     },
+    /// An error that occurs when a package selected via `--only` isn't part of the dependency
+    /// graph.
+    #[error("Could not find `{name}` among the packages to install")]
+    MissingSubgraphPackage {
+        /// The name of the package.
+        name: PackageName,
+    },
     /// An error that occurs when resolving metadata for a package.
     #[error("Failed to generate package metadata for `{id}`")]
     Resolution {
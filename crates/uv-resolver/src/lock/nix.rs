@@ -0,0 +1,295 @@
+use std::fmt::Formatter;
+use std::ops::Bound;
+
+use uv_configuration::{DevGroupsManifest, ExtrasSpecification, InstallOptions, TargetTriple};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder};
+use uv_platform_tags::{TagPreferences, Tags};
+use uv_pypi_types::HashAlgorithm;
+
+use crate::lock::requirements_txt::{requirements, Requirement};
+use crate::lock::{Package, Source, TagPolicy, WheelWireSource};
+use crate::{InstallTarget, LockError};
+
+/// The Python version to use if the lockfile has no lower bound.
+const DEFAULT_PYTHON_VERSION: (u8, u8) = (3, 12);
+
+/// The Nix systems for which wheels are selected, along with the equivalent target.
+const SYSTEMS: &[(&str, TargetTriple)] = &[
+    ("aarch64-darwin", TargetTriple::Aarch64AppleDarwin),
+    ("aarch64-linux", TargetTriple::Aarch64UnknownLinuxGnu),
+    ("x86_64-darwin", TargetTriple::X8664AppleDarwin),
+    ("x86_64-linux", TargetTriple::X8664UnknownLinuxGnu),
+];
+
+/// An export of a [`Lock`] that renders as a Nix flake.
+///
+/// As Nix builds are platform-specific, the compatible wheel for each package is selected upfront
+/// for every supported Nix system, and pinned by URL and hash via `fetchurl`. The flake exposes a
+/// Python environment containing the wheels as the default package for each system.
+#[derive(Debug)]
+pub struct NixExport<'lock> {
+    python_version: (u8, u8),
+    systems: Vec<(&'static str, Vec<NixWheel<'lock>>)>,
+    local: Vec<&'lock PackageName>,
+}
+
+/// A wheel to fetch for a given system.
+#[derive(Debug)]
+enum NixWheel<'lock> {
+    /// A wheel that can be fetched from a URL.
+    Url {
+        name: &'lock PackageName,
+        version: &'lock Version,
+        url: String,
+        sha256: String,
+    },
+    /// A package without a wheel that can be fetched for the system.
+    Unavailable {
+        name: &'lock PackageName,
+        version: &'lock Version,
+        reason: &'static str,
+    },
+}
+
+impl<'lock> NixExport<'lock> {
+    pub fn from_lock(
+        target: InstallTarget<'lock>,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsManifest,
+        install_options: &'lock InstallOptions,
+        tag_preferences: &TagPreferences,
+    ) -> Result<Self, LockError> {
+        let nodes = requirements(target, extras, dev, install_options);
+
+        // Target the minimum supported Python version.
+        let python_version = match &*target.lock().requires_python().bound_major_minor() {
+            Bound::Included(version) => match version.release() {
+                [major, minor, ..] => u8::try_from(*major)
+                    .ok()
+                    .zip(u8::try_from(*minor).ok())
+                    .unwrap_or(DEFAULT_PYTHON_VERSION),
+                _ => DEFAULT_PYTHON_VERSION,
+            },
+            _ => DEFAULT_PYTHON_VERSION,
+        };
+
+        // Local packages are built from source, and so can't be pinned in the flake.
+        let mut local = Vec::new();
+        for Requirement { package, .. } in &nodes {
+            if matches!(
+                package.id.source,
+                Source::Path(_) | Source::Directory(_) | Source::Editable(_) | Source::Virtual(_)
+            ) {
+                local.push(&package.id.name);
+            }
+        }
+
+        let mut systems = Vec::with_capacity(SYSTEMS.len());
+        for (system, triple) in SYSTEMS {
+            let markers = markers(*triple, python_version);
+            let tags = Tags::from_env(
+                &triple.platform(),
+                python_version,
+                "cpython",
+                python_version,
+                true,
+                false,
+                tag_preferences,
+            )
+            .expect("CPython tags are valid for all supported systems");
+
+            let wheels = nodes
+                .iter()
+                .filter(|Requirement { marker, .. }| marker.evaluate(&markers, &[]))
+                .filter_map(|Requirement { package, .. }| NixWheel::select(package, &tags))
+                .collect();
+            systems.push((*system, wheels));
+        }
+
+        Ok(Self {
+            python_version,
+            systems,
+            local,
+        })
+    }
+}
+
+impl<'lock> NixWheel<'lock> {
+    /// Select the most compatible wheel for the given tags, if the package should be fetched.
+    fn select(package: &'lock Package, tags: &Tags) -> Option<Self> {
+        let name = &package.id.name;
+        let version = &package.id.version;
+        match package.id.source {
+            Source::Registry(_) | Source::Direct(..) => {}
+            Source::Git(..) => {
+                return Some(Self::Unavailable {
+                    name,
+                    version,
+                    reason: "Git dependencies are not supported",
+                });
+            }
+            Source::Path(_) | Source::Directory(_) | Source::Editable(_) | Source::Virtual(_) => {
+                return None;
+            }
+        }
+
+        let Some(index) = package.find_best_wheel(TagPolicy::Required(tags)) else {
+            return Some(Self::Unavailable {
+                name,
+                version,
+                reason: "no compatible wheel",
+            });
+        };
+        let wheel = &package.wheels[index];
+        let WheelWireSource::Url { url } = &wheel.url else {
+            return Some(Self::Unavailable {
+                name,
+                version,
+                reason: "the wheel is not available from a URL",
+            });
+        };
+        let Some(hash) = wheel
+            .hash
+            .as_ref()
+            .filter(|hash| hash.0.algorithm == HashAlgorithm::Sha256)
+        else {
+            return Some(Self::Unavailable {
+                name,
+                version,
+                reason: "the wheel has no SHA-256 hash",
+            });
+        };
+
+        Some(Self::Url {
+            name,
+            version,
+            url: url.to_string(),
+            sha256: hash.0.digest.to_string(),
+        })
+    }
+}
+
+/// Return a [`MarkerEnvironment`] for CPython on the given target.
+fn markers(triple: TargetTriple, (major, minor): (u8, u8)) -> MarkerEnvironment {
+    let python_version = format!("{major}.{minor}");
+    let python_full_version = format!("{major}.{minor}.0");
+    let base = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+        implementation_name: "cpython",
+        implementation_version: &python_full_version,
+        os_name: "",
+        platform_machine: "",
+        platform_python_implementation: "CPython",
+        platform_release: "",
+        platform_system: "",
+        platform_version: "",
+        python_full_version: &python_full_version,
+        python_version: &python_version,
+        sys_platform: "",
+    })
+    .expect("Python version is valid");
+    triple.markers(&base)
+}
+
+/// Quote a string as a Nix string literal.
+fn quote(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace("${", "\\${");
+    format!("\"{escaped}\"")
+}
+
+impl std::fmt::Display for NixExport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (major, minor) = self.python_version;
+
+        writeln!(f, "{{")?;
+        writeln!(
+            f,
+            "  description = \"A Python environment exported from uv.lock\";"
+        )?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "  inputs.nixpkgs.url = \"github:NixOS/nixpkgs/nixos-unstable\";"
+        )?;
+        writeln!(f)?;
+        writeln!(f, "  outputs =")?;
+        writeln!(f, "    {{ nixpkgs, ... }}:")?;
+        writeln!(f, "    let")?;
+        if !self.local.is_empty() {
+            writeln!(
+                f,
+                "      # The following local packages are omitted, as they're built from source:"
+            )?;
+            for name in &self.local {
+                writeln!(f, "      #     {name}")?;
+            }
+        }
+        writeln!(f, "      wheels = {{")?;
+        for (system, wheels) in &self.systems {
+            writeln!(f, "        {} = [", quote(system))?;
+            for wheel in wheels {
+                match wheel {
+                    NixWheel::Url {
+                        name,
+                        version,
+                        url,
+                        sha256,
+                    } => {
+                        writeln!(f, "          {{")?;
+                        writeln!(f, "            pname = {};", quote(name.as_ref()))?;
+                        writeln!(f, "            version = {};", quote(&version.to_string()))?;
+                        writeln!(f, "            url = {};", quote(url))?;
+                        writeln!(f, "            sha256 = {};", quote(sha256))?;
+                        writeln!(f, "          }}")?;
+                    }
+                    NixWheel::Unavailable {
+                        name,
+                        version,
+                        reason,
+                    } => {
+                        let message =
+                            format!("Cannot fetch `{name}=={version}` for {system}: {reason}");
+                        writeln!(f, "          (throw {})", quote(&message))?;
+                    }
+                }
+            }
+            writeln!(f, "        ];")?;
+        }
+        writeln!(f, "      }};")?;
+        writeln!(f, "      environment =")?;
+        writeln!(f, "        system:")?;
+        writeln!(f, "        let")?;
+        writeln!(f, "          pkgs = nixpkgs.legacyPackages.${{system}};")?;
+        writeln!(f, "          python = pkgs.python{major}{minor};")?;
+        writeln!(f, "        in")?;
+        writeln!(f, "        python.withPackages (")?;
+        writeln!(f, "          ps:")?;
+        writeln!(f, "          map (")?;
+        writeln!(f, "            wheel:")?;
+        writeln!(f, "            ps.buildPythonPackage {{")?;
+        writeln!(f, "              inherit (wheel) pname version;")?;
+        writeln!(f, "              format = \"wheel\";")?;
+        writeln!(
+            f,
+            "              src = pkgs.fetchurl {{ inherit (wheel) url sha256; }};"
+        )?;
+        writeln!(f, "              dontCheckRuntimeDeps = true;")?;
+        writeln!(f, "            }}")?;
+        writeln!(f, "          ) wheels.${{system}}")?;
+        writeln!(f, "        );")?;
+        writeln!(f, "    in")?;
+        writeln!(f, "    {{")?;
+        writeln!(
+            f,
+            "      packages = builtins.mapAttrs (system: _: {{ default = environment system; }}) wheels;"
+        )?;
+        writeln!(f, "    }};")?;
+        writeln!(f, "}}")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::ops::Bound;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+
+use crate::lock::{Dependency, Lock, Package, PackageId, Source};
+use crate::{RequiresPython, RequiresPythonRange};
+
+/// The oldest Python 3 minor version to consider when `requires-python` has no lower bound.
+const OLDEST_PYTHON_MINOR: u64 = 8;
+
+/// The newest Python 3 minor version to consider when `requires-python` has no upper bound.
+const LATEST_PYTHON_MINOR: u64 = 13;
+
+/// An analysis of whether the packages in a [`Lock`] can be installed on every Python version
+/// included in its `requires-python` range.
+///
+/// For each Python minor version in the range, the locked packages that are reachable from the
+/// workspace members on that version are checked for a compatible distribution: either a source
+/// distribution (or other buildable source), or a wheel with a compatible Python and ABI tag.
+#[derive(Debug)]
+pub struct PythonSupport {
+    /// The Python minor versions included in the `requires-python` range.
+    versions: Vec<Version>,
+    /// The packages that lack a compatible distribution for at least one Python version.
+    unsupported: Vec<UnsupportedPackage>,
+}
+
+/// A locked package that lacks a compatible distribution for some Python versions.
+#[derive(Debug)]
+pub struct UnsupportedPackage {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The locked version of the package.
+    pub version: Version,
+    /// The Python minor versions for which the package has no compatible distribution.
+    pub python_versions: Vec<Version>,
+    /// The chain of dependencies through which the package is required, starting from a
+    /// workspace member and ending with the package itself.
+    pub chain: Vec<(PackageName, Version)>,
+}
+
+impl PythonSupport {
+    /// Analyze the Python support of the given [`Lock`].
+    pub fn from_lock(lock: &Lock) -> Self {
+        let versions = python_minor_versions(lock.requires_python().range());
+
+        let roots = traversal_roots(lock);
+
+        let mut unsupported = BTreeMap::<&PackageId, UnsupportedPackage>::new();
+        for python_version in &versions {
+            let upper = Version::new([3, python_version.release()[1] + 1]);
+            let requires_python = RequiresPython::from_specifiers(&VersionSpecifiers::from(
+                VersionSpecifier::equals_star_version(python_version.clone()),
+            ));
+
+            // Traverse the packages that are reachable on this Python version, tracking the
+            // dependent through which each package was first reached.
+            let mut parents = FxHashMap::<&PackageId, Option<&PackageId>>::default();
+            let mut queue = VecDeque::new();
+            for root in &roots {
+                parents.insert(*root, None);
+                queue.push_back(*root);
+            }
+            while let Some(id) = queue.pop_front() {
+                let package = lock.find_by_id(id);
+
+                if !package.supports_python(&requires_python) {
+                    unsupported
+                        .entry(id)
+                        .or_insert_with(|| UnsupportedPackage {
+                            name: id.name.clone(),
+                            version: id.version.clone(),
+                            python_versions: Vec::new(),
+                            chain: derivation_chain(id, &parents),
+                        })
+                        .python_versions
+                        .push(python_version.clone());
+                }
+
+                for dependency in package.all_dependencies() {
+                    let marker = dependency
+                        .complexified_marker
+                        .clone()
+                        .simplify_python_versions(
+                            Bound::Included(python_version),
+                            Bound::Excluded(&upper),
+                        );
+                    if marker.is_false() || parents.contains_key(&dependency.package_id) {
+                        continue;
+                    }
+                    parents.insert(&dependency.package_id, Some(id));
+                    queue.push_back(&dependency.package_id);
+                }
+            }
+        }
+
+        Self {
+            versions,
+            unsupported: unsupported.into_values().collect(),
+        }
+    }
+
+    /// Returns the Python minor versions included in the `requires-python` range.
+    pub fn versions(&self) -> &[Version] {
+        &self.versions
+    }
+
+    /// Returns the packages that lack a compatible distribution for some Python versions.
+    pub fn unsupported(&self) -> &[UnsupportedPackage] {
+        &self.unsupported
+    }
+
+    /// Returns the Python minor versions for which every reachable package has a compatible
+    /// distribution.
+    pub fn supported_versions(&self) -> Vec<&Version> {
+        self.versions
+            .iter()
+            .filter(|version| {
+                self.unsupported
+                    .iter()
+                    .all(|package| !package.python_versions.contains(version))
+            })
+            .collect()
+    }
+}
+
+impl Package {
+    /// Returns all dependencies of the package, including optional dependencies and dependency
+    /// groups.
+    pub(super) fn all_dependencies(&self) -> impl Iterator<Item = &Dependency> {
+        self.dependencies
+            .iter()
+            .chain(self.optional_dependencies.values().flatten())
+            .chain(self.dependency_groups.values().flatten())
+    }
+
+    /// Returns `true` if the package can be installed on the given Python version, i.e., if it
+    /// can be built from source or has a compatible wheel.
+    fn supports_python(&self, requires_python: &RequiresPython) -> bool {
+        if !matches!(self.id.source, Source::Registry(_) | Source::Direct(..)) {
+            return true;
+        }
+        if self.sdist.is_some() {
+            return true;
+        }
+        if self.wheels.is_empty() {
+            // A direct URL to a source archive.
+            return matches!(self.id.source, Source::Direct(..));
+        }
+        self.wheels
+            .iter()
+            .any(|wheel| requires_python.matches_wheel_tag(&wheel.filename))
+    }
+}
+
+/// Returns the packages from which to traverse the [`Lock`]: the workspace members and the root
+/// package or, if there are none, every package that isn't a dependency of another package.
+pub(super) fn traversal_roots(lock: &Lock) -> Vec<&PackageId> {
+    let mut roots = lock
+        .packages()
+        .iter()
+        .filter(|package| lock.members().contains(package.name()))
+        .chain(lock.root())
+        .map(|package| &package.id)
+        .collect::<Vec<_>>();
+    if roots.is_empty() {
+        let dependencies = lock
+            .packages()
+            .iter()
+            .flat_map(Package::all_dependencies)
+            .map(|dependency| &dependency.package_id)
+            .collect::<FxHashSet<_>>();
+        roots.extend(
+            lock.packages()
+                .iter()
+                .map(|package| &package.id)
+                .filter(|id| !dependencies.contains(id)),
+        );
+    }
+    roots.sort_unstable();
+    roots.dedup();
+    roots
+}
+
+/// Returns the Python 3 minor versions (e.g., `3.8`, `3.9`) that overlap with the given range.
+fn python_minor_versions(range: &RequiresPythonRange) -> Vec<Version> {
+    (0..=LATEST_PYTHON_MINOR)
+        .filter(|minor| {
+            let lower = Version::new([3, *minor]);
+            let upper = Version::new([3, minor + 1]);
+            let above_lower = match &**range.lower() {
+                Bound::Included(version) | Bound::Excluded(version) => *version < upper,
+                Bound::Unbounded => *minor >= OLDEST_PYTHON_MINOR,
+            };
+            let below_upper = match &**range.upper() {
+                Bound::Included(version) => *version >= lower,
+                Bound::Excluded(version) => *version > lower,
+                Bound::Unbounded => true,
+            };
+            above_lower && below_upper
+        })
+        .map(|minor| Version::new([3, minor]))
+        .collect()
+}
+
+/// Reconstruct the chain of dependencies from a root package to the given package.
+pub(super) fn derivation_chain(
+    id: &PackageId,
+    parents: &FxHashMap<&PackageId, Option<&PackageId>>,
+) -> Vec<(PackageName, Version)> {
+    let mut chain = vec![(id.name.clone(), id.version.clone())];
+    let mut current = id;
+    while let Some(Some(parent)) = parents.get(current) {
+        chain.push((parent.name.clone(), parent.version.clone()));
+        current = parent;
+    }
+    chain.reverse();
+    chain
+}
@@ -0,0 +1,225 @@
+use std::collections::BTreeSet;
+use std::fmt::{Formatter, Write};
+
+use url::Url;
+
+use uv_configuration::{DevGroupsManifest, EditableMode, ExtrasSpecification, InstallOptions};
+use uv_fs::Simplified;
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep508::MarkerTree;
+use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, RequirementSource};
+
+use crate::lock::requirements_txt::{anchor, write_requirement, Requirement};
+use crate::lock::Package;
+use crate::{InstallTarget, LockError};
+
+/// An export of a [`Lock`] that renders the top-level requirements of the target in
+/// `requirements.in` format.
+///
+/// Rather than the locked versions, the export includes the direct requirements of the target
+/// packages as declared in their `pyproject.toml` files (i.e., with their original specifiers,
+/// extras, and markers), such that it can be compiled by other tools, like `pip-compile`.
+#[derive(Debug)]
+pub struct RequirementsInExport<'lock> {
+    /// The target packages themselves.
+    projects: Vec<&'lock Package>,
+    /// The direct requirements of the target packages, rendered in `requirements.in` format.
+    requirements: BTreeSet<String>,
+    editable: EditableMode,
+}
+
+impl<'lock> RequirementsInExport<'lock> {
+    pub fn from_lock(
+        target: InstallTarget<'lock>,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsManifest,
+        editable: EditableMode,
+        install_options: &'lock InstallOptions,
+    ) -> Result<Self, LockError> {
+        let lock = target.lock();
+        let roots = target.packages().collect::<BTreeSet<_>>();
+        let include = |name: &PackageName| {
+            install_options.include_package(name, target.project_name(), lock.members())
+        };
+
+        let mut projects = Vec::new();
+        let mut requirements = BTreeSet::new();
+        for root in &roots {
+            let package = lock
+                .find_by_name(root)
+                .expect("found too many packages matching root")
+                .expect("could not find root");
+
+            if dev.prod() {
+                if include(&package.id.name) {
+                    projects.push(package);
+                }
+
+                // Include the requirements of the base package and any enabled extras, removing
+                // the extras from their markers.
+                let enabled = match extras {
+                    ExtrasSpecification::None => Vec::new(),
+                    ExtrasSpecification::All => {
+                        package.optional_dependencies.keys().cloned().collect()
+                    }
+                    ExtrasSpecification::Some(extras) => extras.clone(),
+                };
+                for requirement in &package.metadata.requires_dist {
+                    if roots.contains(&&requirement.name) || !include(&requirement.name) {
+                        continue;
+                    }
+                    if !requirement.marker.evaluate_extras(&enabled) {
+                        continue;
+                    }
+                    let marker = requirement.marker.clone().simplify_extras(&enabled);
+                    if let Some(line) = render(requirement, &marker, editable) {
+                        requirements.insert(line);
+                    }
+                }
+            }
+
+            // Include the requirements of the enabled dependency groups.
+            for group in dev.iter() {
+                for requirement in package
+                    .metadata
+                    .dependency_groups
+                    .get(group)
+                    .into_iter()
+                    .flatten()
+                {
+                    if roots.contains(&&requirement.name) || !include(&requirement.name) {
+                        continue;
+                    }
+                    if let Some(line) = render(requirement, &requirement.marker, editable) {
+                        requirements.insert(line);
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            projects,
+            requirements,
+            editable,
+        })
+    }
+}
+
+/// Render a requirement in `requirements.in` format, with the given marker.
+///
+/// Returns `None` for virtual packages, since they can't be installed.
+fn render(
+    requirement: &uv_pypi_types::Requirement,
+    marker: &MarkerTree,
+    editable: EditableMode,
+) -> Option<String> {
+    let extras = if requirement.extras.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "[{}]",
+            requirement
+                .extras
+                .iter()
+                .map(ExtraName::as_str)
+                .collect::<Vec<_>>()
+                .join(",")
+        )
+    };
+
+    let mut line = String::new();
+    match &requirement.source {
+        RequirementSource::Registry { specifier, .. } => {
+            write!(line, "{}{extras}{specifier}", requirement.name).ok()?;
+        }
+        RequirementSource::Url {
+            location,
+            subdirectory,
+            ext,
+            ..
+        } => {
+            let url = Url::from(ParsedArchiveUrl {
+                url: location.clone(),
+                subdirectory: subdirectory.clone(),
+                ext: *ext,
+            });
+            write!(line, "{}{extras} @ {url}", requirement.name).ok()?;
+        }
+        RequirementSource::Git {
+            repository,
+            backend,
+            reference,
+            precise,
+            subdirectory,
+            ..
+        } => {
+            let git_url = if let Some(precise) = precise {
+                uv_git::GitUrl::from_commit(repository.clone(), reference.clone(), *precise)
+            } else {
+                uv_git::GitUrl::from_reference(repository.clone(), reference.clone())
+            }
+            .with_backend(*backend);
+            let url = Url::from(ParsedGitUrl {
+                url: git_url,
+                subdirectory: subdirectory.clone(),
+            });
+            write!(line, "{}{extras} @ {url}", requirement.name).ok()?;
+        }
+        RequirementSource::Directory {
+            r#virtual: true, ..
+        } => return None,
+        RequirementSource::Directory {
+            install_path,
+            editable: true,
+            ..
+        } if editable == EditableMode::Editable => {
+            write!(
+                line,
+                "-e {}{extras}",
+                anchor(install_path).portable_display()
+            )
+            .ok()?;
+        }
+        RequirementSource::Path { install_path, .. }
+        | RequirementSource::Directory { install_path, .. } => {
+            if install_path.is_absolute() {
+                let url = Url::from_file_path(install_path).ok()?;
+                write!(line, "{}{extras} @ {url}", requirement.name).ok()?;
+            } else {
+                write!(line, "{}{extras}", anchor(install_path).portable_display()).ok()?;
+            }
+        }
+    }
+
+    if let Some(contents) = marker.contents() {
+        write!(line, " ; {contents}").ok()?;
+    }
+
+    Some(line)
+}
+
+impl std::fmt::Display for RequirementsInExport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Write out the target packages themselves.
+        for &package in &self.projects {
+            write_requirement(
+                f,
+                &Requirement {
+                    package,
+                    marker: MarkerTree::TRUE,
+                    via: BTreeSet::default(),
+                },
+                self.editable,
+                false,
+                false,
+            )?;
+        }
+
+        // Write out their direct requirements.
+        for requirement in &self.requirements {
+            writeln!(f, "{requirement}")?;
+        }
+
+        Ok(())
+    }
+}
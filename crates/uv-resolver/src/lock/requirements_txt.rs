@@ -1,12 +1,13 @@
 use std::borrow::Cow;
 use std::collections::hash_map::Entry;
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, VecDeque};
 use std::fmt::Formatter;
 use std::path::{Component, Path, PathBuf};
 
 use either::Either;
+use petgraph::graph::NodeIndex;
 use petgraph::visit::IntoNodeReferences;
-use petgraph::{Directed, Graph};
+use petgraph::{Directed, Direction, Graph};
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 use url::Url;
 
@@ -19,7 +20,7 @@ use uv_pep508::MarkerTree;
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl};
 
 use crate::graph_ops::marker_reachability;
-use crate::lock::{Package, PackageId, Source};
+use crate::lock::{Package, PackageId, RegistrySource, Source};
 use crate::{InstallTarget, LockError};
 
 type LockGraph<'lock> = Graph<Node<'lock>, Edge, Directed>;
@@ -29,6 +30,7 @@ type LockGraph<'lock> = Graph<Node<'lock>, Edge, Directed>;
 pub struct RequirementsTxtExport<'lock> {
     nodes: Vec<Requirement<'lock>>,
     hashes: bool,
+    annotate: bool,
     editable: EditableMode,
 }
 
@@ -41,100 +43,103 @@ impl<'lock> RequirementsTxtExport<'lock> {
         hashes: bool,
         install_options: &'lock InstallOptions,
     ) -> Result<Self, LockError> {
-        let size_guess = target.lock().packages.len();
-        let mut petgraph = LockGraph::with_capacity(size_guess, size_guess);
-        let mut inverse = FxHashMap::with_capacity_and_hasher(size_guess, FxBuildHasher);
-
-        let mut queue: VecDeque<(&Package, Option<&ExtraName>)> = VecDeque::new();
-        let mut seen = FxHashSet::default();
-
-        let root = petgraph.add_node(Node::Root);
-
-        // Add the workspace package to the queue.
-        for root_name in target.packages() {
-            let dist = target
-                .lock()
-                .find_by_name(root_name)
-                .expect("found too many packages matching root")
-                .expect("could not find root");
-
-            if dev.prod() {
-                // Add the workspace package to the graph.
-                if let Entry::Vacant(entry) = inverse.entry(&dist.id) {
-                    entry.insert(petgraph.add_node(Node::Package(dist)));
-                }
+        let nodes = requirements(target, extras, dev, install_options);
+        Ok(Self {
+            nodes,
+            hashes,
+            annotate: false,
+            editable,
+        })
+    }
 
-                // Add an edge from the root.
-                let index = inverse[&dist.id];
-                petgraph.add_edge(root, index, MarkerTree::TRUE);
+    /// Annotate each requirement with the packages that depend on it, and the index from which it
+    /// was locked.
+    #[must_use]
+    pub fn with_annotations(mut self) -> Self {
+        self.annotate = true;
+        self
+    }
 
-                // Push its dependencies on the queue.
-                queue.push_back((dist, None));
-                match extras {
-                    ExtrasSpecification::None => {}
-                    ExtrasSpecification::All => {
-                        for extra in dist.optional_dependencies.keys() {
-                            queue.push_back((dist, Some(extra)));
-                        }
-                    }
-                    ExtrasSpecification::Some(extras) => {
-                        for extra in extras {
-                            queue.push_back((dist, Some(extra)));
-                        }
-                    }
-                }
+    /// Restrict the export to the requirements of the environment described by the given marker.
+    ///
+    /// Requirements that can't apply in the environment are omitted, and the markers of those that
+    /// always apply in the environment are removed.
+    #[must_use]
+    pub fn with_environment(mut self, environment: &MarkerTree) -> Self {
+        self.nodes
+            .retain(|requirement| !requirement.marker.is_disjoint(environment));
+        for requirement in &mut self.nodes {
+            let mut excluded = requirement.marker.negate();
+            excluded.and(environment.clone());
+            if excluded.is_false() {
+                requirement.marker = MarkerTree::TRUE;
             }
+        }
+        self
+    }
+}
 
-            // Add any development dependencies.
-            for group in dev.iter() {
-                for dep in dist.dependency_groups.get(group).into_iter().flatten() {
-                    let dep_dist = target.lock().find_by_id(&dep.package_id);
-
-                    // Add the dependency to the graph.
-                    if let Entry::Vacant(entry) = inverse.entry(&dep.package_id) {
-                        entry.insert(petgraph.add_node(Node::Package(dep_dist)));
-                    }
+/// Flatten the packages in the lockfile that are required by the given target into a list of
+/// requirements, each with the marker under which it's reachable from the target.
+///
+/// Unnamed requirements (i.e., editables and local paths) are sorted ahead of named requirements.
+pub(crate) fn requirements<'lock>(
+    target: InstallTarget<'lock>,
+    extras: &ExtrasSpecification,
+    dev: &DevGroupsManifest,
+    install_options: &'lock InstallOptions,
+) -> Vec<Requirement<'lock>> {
+    let size_guess = target.lock().packages.len();
+    let mut petgraph = LockGraph::with_capacity(size_guess, size_guess);
+    let mut inverse = FxHashMap::with_capacity_and_hasher(size_guess, FxBuildHasher);
+
+    let mut queue: VecDeque<(&Package, Option<&ExtraName>)> = VecDeque::new();
+    let mut seen = FxHashSet::default();
+
+    let root = petgraph.add_node(Node::Root);
+
+    // Development dependencies are connected directly to the root, so track the dependency groups
+    // that require them separately.
+    let mut groups: FxHashMap<NodeIndex, BTreeSet<String>> = FxHashMap::default();
+
+    // Add the workspace package to the queue.
+    for root_name in target.packages() {
+        let dist = target
+            .lock()
+            .find_by_name(root_name)
+            .expect("found too many packages matching root")
+            .expect("could not find root");
+
+        if dev.prod() {
+            // Add the workspace package to the graph.
+            if let Entry::Vacant(entry) = inverse.entry(&dist.id) {
+                entry.insert(petgraph.add_node(Node::Package(dist)));
+            }
 
-                    // Add an edge from the root. Development dependencies may be installed without
-                    // installing the workspace package itself (which can never have markers on it
-                    // anyway), so they're directly connected to the root.
-                    let dep_index = inverse[&dep.package_id];
-                    petgraph.add_edge(
-                        root,
-                        dep_index,
-                        dep.simplified_marker.as_simplified_marker_tree().clone(),
-                    );
-
-                    // Push its dependencies on the queue.
-                    if seen.insert((&dep.package_id, None)) {
-                        queue.push_back((dep_dist, None));
+            // Add an edge from the root.
+            let index = inverse[&dist.id];
+            petgraph.add_edge(root, index, MarkerTree::TRUE);
+
+            // Push its dependencies on the queue.
+            queue.push_back((dist, None));
+            match extras {
+                ExtrasSpecification::None => {}
+                ExtrasSpecification::All => {
+                    for extra in dist.optional_dependencies.keys() {
+                        queue.push_back((dist, Some(extra)));
                     }
-                    for extra in &dep.extra {
-                        if seen.insert((&dep.package_id, Some(extra))) {
-                            queue.push_back((dep_dist, Some(extra)));
-                        }
+                }
+                ExtrasSpecification::Some(extras) => {
+                    for extra in extras {
+                        queue.push_back((dist, Some(extra)));
                     }
                 }
             }
         }
 
-        // Create all the relevant nodes.
-        while let Some((package, extra)) = queue.pop_front() {
-            let index = inverse[&package.id];
-
-            let deps = if let Some(extra) = extra {
-                Either::Left(
-                    package
-                        .optional_dependencies
-                        .get(extra)
-                        .into_iter()
-                        .flatten(),
-                )
-            } else {
-                Either::Right(package.dependencies.iter())
-            };
-
-            for dep in deps {
+        // Add any development dependencies.
+        for group in dev.iter() {
+            for dep in dist.dependency_groups.get(group).into_iter().flatten() {
                 let dep_dist = target.lock().find_by_id(&dep.package_id);
 
                 // Add the dependency to the graph.
@@ -142,13 +147,19 @@ impl<'lock> RequirementsTxtExport<'lock> {
                     entry.insert(petgraph.add_node(Node::Package(dep_dist)));
                 }
 
-                // Add the edge.
+                // Add an edge from the root. Development dependencies may be installed without
+                // installing the workspace package itself (which can never have markers on it
+                // anyway), so they're directly connected to the root.
                 let dep_index = inverse[&dep.package_id];
                 petgraph.add_edge(
-                    index,
+                    root,
                     dep_index,
                     dep.simplified_marker.as_simplified_marker_tree().clone(),
                 );
+                groups
+                    .entry(dep_index)
+                    .or_default()
+                    .insert(format!("{} (group: {group})", dist.id.name));
 
                 // Push its dependencies on the queue.
                 if seen.insert((&dep.package_id, None)) {
@@ -161,125 +172,220 @@ impl<'lock> RequirementsTxtExport<'lock> {
                 }
             }
         }
+    }
+
+    // Create all the relevant nodes.
+    while let Some((package, extra)) = queue.pop_front() {
+        let index = inverse[&package.id];
+
+        let deps = if let Some(extra) = extra {
+            Either::Left(
+                package
+                    .optional_dependencies
+                    .get(extra)
+                    .into_iter()
+                    .flatten(),
+            )
+        } else {
+            Either::Right(package.dependencies.iter())
+        };
+
+        for dep in deps {
+            let dep_dist = target.lock().find_by_id(&dep.package_id);
+
+            // Add the dependency to the graph.
+            if let Entry::Vacant(entry) = inverse.entry(&dep.package_id) {
+                entry.insert(petgraph.add_node(Node::Package(dep_dist)));
+            }
+
+            // Add the edge.
+            let dep_index = inverse[&dep.package_id];
+            petgraph.add_edge(
+                index,
+                dep_index,
+                dep.simplified_marker.as_simplified_marker_tree().clone(),
+            );
+
+            // Push its dependencies on the queue.
+            if seen.insert((&dep.package_id, None)) {
+                queue.push_back((dep_dist, None));
+            }
+            for extra in &dep.extra {
+                if seen.insert((&dep.package_id, Some(extra))) {
+                    queue.push_back((dep_dist, Some(extra)));
+                }
+            }
+        }
+    }
+
+    let mut reachability = marker_reachability(&petgraph, &[]);
 
-        let mut reachability = marker_reachability(&petgraph, &[]);
-
-        // Collect all packages.
-        let mut nodes = petgraph
-            .node_references()
-            .filter_map(|(index, node)| match node {
-                Node::Root => None,
-                Node::Package(package) => Some((index, package)),
-            })
-            .filter(|(_index, package)| {
-                install_options.include_package(
-                    &package.id.name,
-                    target.project_name(),
-                    target.lock().members(),
-                )
-            })
-            .map(|(index, package)| Requirement {
+    // Collect all packages.
+    let mut nodes = petgraph
+        .node_references()
+        .filter_map(|(index, node)| match node {
+            Node::Root => None,
+            Node::Package(package) => Some((index, package)),
+        })
+        .filter(|(_index, package)| {
+            install_options.include_package(
+                &package.id.name,
+                target.project_name(),
+                target.lock().members(),
+            )
+        })
+        .map(|(index, package)| {
+            // Record the packages (and dependency groups) that require the package.
+            let mut via = groups.remove(&index).unwrap_or_default();
+            via.extend(
+                petgraph
+                    .neighbors_directed(index, Direction::Incoming)
+                    .filter_map(|source| match &petgraph[source] {
+                        Node::Root => None,
+                        Node::Package(package) => Some(package.id.name.to_string()),
+                    }),
+            );
+            Requirement {
                 package,
                 marker: reachability.remove(&index).unwrap_or_default(),
-            })
-            .collect::<Vec<_>>();
+                via,
+            }
+        })
+        .collect::<Vec<_>>();
 
-        // Sort the nodes, such that unnamed URLs (editables) appear at the top.
-        nodes.sort_unstable_by(|a, b| {
-            RequirementComparator::from(a.package).cmp(&RequirementComparator::from(b.package))
-        });
+    // Sort the nodes, such that unnamed URLs (editables) appear at the top.
+    nodes.sort_unstable_by(|a, b| {
+        RequirementComparator::from(a.package).cmp(&RequirementComparator::from(b.package))
+    });
 
-        Ok(Self {
-            nodes,
-            hashes,
-            editable,
-        })
-    }
+    nodes
 }
 
 impl std::fmt::Display for RequirementsTxtExport<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         // Write out each package.
-        for Requirement { package, marker } in &self.nodes {
-            match &package.id.source {
-                Source::Registry(_) => {
-                    write!(f, "{}=={}", package.id.name, package.id.version)?;
-                }
-                Source::Git(url, git) => {
-                    // Remove the fragment and query from the URL; they're already present in the
-                    // `GitSource`.
-                    let mut url = url.to_url();
-                    url.set_fragment(None);
-                    url.set_query(None);
-
-                    // Reconstruct the `GitUrl` from the `GitSource`.
-                    let git_url = uv_git::GitUrl::from_commit(
-                        url,
-                        GitReference::from(git.kind.clone()),
-                        git.precise,
-                    );
-
-                    // Reconstruct the PEP 508-compatible URL from the `GitSource`.
-                    let url = Url::from(ParsedGitUrl {
-                        url: git_url.clone(),
-                        subdirectory: git.subdirectory.as_ref().map(PathBuf::from),
-                    });
-
-                    write!(f, "{} @ {}", package.id.name, url)?;
-                }
-                Source::Direct(url, direct) => {
-                    let subdirectory = direct.subdirectory.as_ref().map(PathBuf::from);
-                    let url = Url::from(ParsedArchiveUrl {
-                        url: url.to_url(),
-                        subdirectory: subdirectory.clone(),
-                        ext: DistExtension::Source(SourceDistExtension::TarGz),
-                    });
-                    write!(f, "{} @ {}", package.id.name, url)?;
-                }
-                Source::Path(path) | Source::Directory(path) => {
-                    if path.is_absolute() {
-                        write!(f, "{}", Url::from_file_path(path).unwrap())?;
-                    } else {
-                        write!(f, "{}", anchor(path).portable_display())?;
-                    }
-                }
-                Source::Editable(path) => match self.editable {
-                    EditableMode::Editable => {
-                        write!(f, "-e {}", anchor(path).portable_display())?;
-                    }
-                    EditableMode::NonEditable => {
-                        if path.is_absolute() {
-                            write!(f, "{}", Url::from_file_path(path).unwrap())?;
-                        } else {
-                            write!(f, "{}", anchor(path).portable_display())?;
-                        }
-                    }
-                },
-                Source::Virtual(_) => {
-                    continue;
-                }
-            }
+        for requirement in &self.nodes {
+            write_requirement(f, requirement, self.editable, self.hashes, self.annotate)?;
+        }
 
-            if let Some(contents) = marker.contents() {
-                write!(f, " ; {contents}")?;
-            }
+        Ok(())
+    }
+}
 
-            if self.hashes {
-                let hashes = package.hashes();
-                if !hashes.is_empty() {
-                    for hash in &hashes {
-                        writeln!(f, " \\")?;
-                        write!(f, "    --hash=")?;
-                        write!(f, "{hash}")?;
-                    }
+/// Write a single requirement in `requirements.txt` format, followed by a newline.
+///
+/// If `annotate` is set, the requirement is followed by comments listing the packages that require
+/// it and the index from which it was locked.
+///
+/// Virtual packages are skipped, since they can't be installed.
+pub(crate) fn write_requirement(
+    f: &mut Formatter<'_>,
+    Requirement {
+        package,
+        marker,
+        via,
+    }: &Requirement<'_>,
+    editable: EditableMode,
+    hashes: bool,
+    annotate: bool,
+) -> std::fmt::Result {
+    match &package.id.source {
+        Source::Registry(_) => {
+            write!(f, "{}=={}", package.id.name, package.id.version)?;
+        }
+        Source::Git(url, git) => {
+            // Remove the fragment and query from the URL; they're already present in the
+            // `GitSource`.
+            let mut url = url.to_url();
+            url.set_fragment(None);
+            url.set_query(None);
+
+            // Reconstruct the `GitUrl` from the `GitSource`.
+            let git_url =
+                uv_git::GitUrl::from_commit(url, GitReference::from(git.kind.clone()), git.precise)
+                    .with_backend(git.backend);
+
+            // Reconstruct the PEP 508-compatible URL from the `GitSource`.
+            let url = Url::from(ParsedGitUrl {
+                url: git_url.clone(),
+                subdirectory: git.subdirectory.as_ref().map(PathBuf::from),
+            });
+
+            write!(f, "{} @ {}", package.id.name, url)?;
+        }
+        Source::Direct(url, direct) => {
+            let subdirectory = direct.subdirectory.as_ref().map(PathBuf::from);
+            let url = Url::from(ParsedArchiveUrl {
+                url: url.to_url(),
+                subdirectory: subdirectory.clone(),
+                ext: DistExtension::Source(SourceDistExtension::TarGz),
+            });
+            write!(f, "{} @ {}", package.id.name, url)?;
+        }
+        Source::Path(path) | Source::Directory(path) => {
+            if path.is_absolute() {
+                write!(f, "{}", Url::from_file_path(path).unwrap())?;
+            } else {
+                write!(f, "{}", anchor(path).portable_display())?;
+            }
+        }
+        Source::Editable(path) => match editable {
+            EditableMode::Editable => {
+                write!(f, "-e {}", anchor(path).portable_display())?;
+            }
+            EditableMode::NonEditable => {
+                if path.is_absolute() {
+                    write!(f, "{}", Url::from_file_path(path).unwrap())?;
+                } else {
+                    write!(f, "{}", anchor(path).portable_display())?;
                 }
             }
+        },
+        Source::Virtual(_) => {
+            return Ok(());
+        }
+    }
 
-            writeln!(f)?;
+    if let Some(contents) = marker.contents() {
+        write!(f, " ; {contents}")?;
+    }
+
+    if hashes {
+        let hashes = package.hashes();
+        if !hashes.is_empty() {
+            for hash in &hashes {
+                writeln!(f, " \\")?;
+                write!(f, "    --hash=")?;
+                write!(f, "{hash}")?;
+            }
         }
+    }
 
-        Ok(())
+    writeln!(f)?;
+
+    if annotate {
+        if !via.is_empty() {
+            writeln!(
+                f,
+                "    # via {}",
+                via.iter()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        match &package.id.source {
+            Source::Registry(RegistrySource::Url(url)) => {
+                writeln!(f, "    # from {url}")?;
+            }
+            Source::Registry(RegistrySource::Path(path)) => {
+                writeln!(f, "    # from {}", anchor(path).portable_display())?;
+            }
+            _ => {}
+        }
     }
+
+    Ok(())
 }
 
 /// A node in the [`LockGraph`].
@@ -294,9 +400,11 @@ type Edge = MarkerTree;
 
 /// A flat requirement, with its associated marker.
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Requirement<'lock> {
-    package: &'lock Package,
-    marker: MarkerTree,
+pub(crate) struct Requirement<'lock> {
+    pub(crate) package: &'lock Package,
+    pub(crate) marker: MarkerTree,
+    /// The names of the packages (and dependency groups) that require the package.
+    pub(crate) via: BTreeSet<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -319,7 +427,7 @@ impl<'lock> From<&'lock Package> for RequirementComparator<'lock> {
 /// Modify a relative [`Path`] to anchor it at the current working directory.
 ///
 /// For example, given `foo/bar`, returns `./foo/bar`.
-fn anchor(path: &Path) -> Cow<'_, Path> {
+pub(crate) fn anchor(path: &Path) -> Cow<'_, Path> {
     match path.components().next() {
         None => Cow::Owned(PathBuf::from(".")),
         Some(Component::CurDir | Component::ParentDir) => Cow::Borrowed(path),
@@ -0,0 +1,137 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use rustc_hash::FxHashMap;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::MarkerTree;
+
+use crate::lock::python_support::{derivation_chain, traversal_roots};
+use crate::lock::{
+    wheel_platform_markers, Lock, Package, PackageId, Source, LINUX_MARKERS, MAC_MARKERS,
+    WINDOWS_MARKERS,
+};
+
+/// An analysis of which packages in a [`Lock`] would need to be built from source on each of its
+/// target environments.
+///
+/// The target environments are the lockfile's `supported-environments` or, if none were declared,
+/// Linux, Windows, and macOS. For each environment, the locked packages that are reachable from
+/// the workspace members in that environment are checked for a wheel that could be installed
+/// there.
+#[derive(Debug)]
+pub struct SourceBuilds {
+    /// The target environments.
+    environments: Vec<MarkerTree>,
+    /// The packages that would need to be built from source in at least one environment.
+    packages: Vec<SourceBuildPackage>,
+}
+
+/// A locked package that would need to be built from source in some target environments.
+#[derive(Debug)]
+pub struct SourceBuildPackage {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The locked version of the package.
+    pub version: Version,
+    /// The target environments in which the package would need to be built from source.
+    pub environments: Vec<MarkerTree>,
+    /// The chain of dependencies through which the package is required, starting from a
+    /// workspace member and ending with the package itself.
+    pub chain: Vec<(PackageName, Version)>,
+}
+
+impl SourceBuilds {
+    /// Analyze the source builds required to install the given [`Lock`].
+    pub fn from_lock(lock: &Lock) -> Self {
+        let environments = if lock.supported_environments().is_empty() {
+            vec![
+                LINUX_MARKERS.clone(),
+                WINDOWS_MARKERS.clone(),
+                MAC_MARKERS.clone(),
+            ]
+        } else {
+            lock.supported_environments().to_vec()
+        };
+
+        let roots = traversal_roots(lock);
+
+        let mut packages = BTreeMap::<&PackageId, SourceBuildPackage>::new();
+        for environment in &environments {
+            // Traverse the packages that are reachable in this environment, tracking the
+            // dependent through which each package was first reached.
+            let mut parents = FxHashMap::<&PackageId, Option<&PackageId>>::default();
+            let mut queue = VecDeque::new();
+            for root in &roots {
+                parents.insert(*root, None);
+                queue.push_back(*root);
+            }
+            while let Some(id) = queue.pop_front() {
+                let package = lock.find_by_id(id);
+
+                if package.requires_build(environment) {
+                    packages
+                        .entry(id)
+                        .or_insert_with(|| SourceBuildPackage {
+                            name: id.name.clone(),
+                            version: id.version.clone(),
+                            environments: Vec::new(),
+                            chain: derivation_chain(id, &parents),
+                        })
+                        .environments
+                        .push(environment.clone());
+                }
+
+                for dependency in package.all_dependencies() {
+                    if dependency.complexified_marker.is_disjoint(environment)
+                        || parents.contains_key(&dependency.package_id)
+                    {
+                        continue;
+                    }
+                    parents.insert(&dependency.package_id, Some(id));
+                    queue.push_back(&dependency.package_id);
+                }
+            }
+        }
+
+        Self {
+            environments,
+            packages: packages.into_values().collect(),
+        }
+    }
+
+    /// Returns the target environments.
+    pub fn environments(&self) -> &[MarkerTree] {
+        &self.environments
+    }
+
+    /// Returns the packages that would need to be built from source in some environments.
+    pub fn packages(&self) -> &[SourceBuildPackage] {
+        &self.packages
+    }
+}
+
+impl Package {
+    /// Returns `true` if the package would need to be built from source in the given
+    /// environment, i.e., if it lacks a wheel for the environment's platform.
+    fn requires_build(&self, environment: &MarkerTree) -> bool {
+        match self.id.source {
+            // Editable builds are always allowed, and virtual packages aren't built at all.
+            Source::Editable(_) | Source::Virtual(_) => return false,
+            Source::Git(..) | Source::Directory(_) => return true,
+            Source::Registry(_) | Source::Direct(..) | Source::Path(_) => {}
+        }
+        let has_wheel = self.wheels.iter().any(|wheel| {
+            wheel_platform_markers(&wheel.filename)
+                .map_or(true, |markers| !markers.is_disjoint(environment))
+        });
+        if has_wheel {
+            return false;
+        }
+        if self.sdist.is_some() {
+            return true;
+        }
+        // A direct URL or path to a source archive.
+        self.wheels.is_empty() && !matches!(self.id.source, Source::Registry(_))
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use rustc_hash::FxHashSet;
+
+use uv_normalize::GroupName;
+use uv_pep508::MarkerTree;
+
+use crate::lock::python_support::traversal_roots;
+use crate::lock::{Dependency, Lock, PackageId, LINUX_MARKERS, MAC_MARKERS, WINDOWS_MARKERS};
+
+/// Aggregate statistics on the packages and artifacts in a [`Lock`].
+#[derive(Debug)]
+pub struct LockStatistics {
+    /// The number of locked packages, excluding the workspace members.
+    pub packages: usize,
+    /// The number of distinct build requirements recorded for packages built from source.
+    pub build_dependencies: usize,
+    /// The number of locked packages required by the dependency groups of the workspace members,
+    /// by group.
+    pub groups: BTreeMap<GroupName, usize>,
+    /// The number of locked packages required in each of the lockfile's target environments.
+    pub environments: Vec<(MarkerTree, usize)>,
+    /// The number of locked source distributions.
+    pub sdists: usize,
+    /// The total size of the locked source distributions, in bytes.
+    pub sdist_size: u64,
+    /// The number of locked wheels.
+    pub wheels: usize,
+    /// The total size of the locked wheels, in bytes.
+    pub wheel_size: u64,
+    /// The number of locked artifacts (source distributions or wheels) without a known size.
+    ///
+    /// Sizes are only recorded for artifacts that come from registries.
+    pub unknown_size: usize,
+}
+
+impl LockStatistics {
+    /// Compute the statistics for the given [`Lock`].
+    pub fn from_lock(lock: &Lock) -> Self {
+        let roots = traversal_roots(lock);
+
+        let packages = lock
+            .packages()
+            .iter()
+            .filter(|package| !roots.contains(&&package.id))
+            .count();
+
+        let build_dependencies = lock
+            .packages()
+            .iter()
+            .flat_map(|package| &package.build_dependencies)
+            .collect::<FxHashSet<_>>()
+            .len();
+
+        // Count the packages required by each dependency group, across the workspace members.
+        let mut groups = BTreeMap::<GroupName, FxHashSet<&PackageId>>::new();
+        for root in &roots {
+            let package = lock.find_by_id(root);
+            for (group, dependencies) in &package.dependency_groups {
+                groups.entry(group.clone()).or_default().extend(reachable(
+                    lock,
+                    dependencies,
+                    None,
+                ));
+            }
+        }
+        let groups = groups
+            .into_iter()
+            .map(|(group, packages)| (group, packages.len()))
+            .collect();
+
+        // Count the packages required in each target environment.
+        let environments = if lock.supported_environments().is_empty() {
+            vec![
+                LINUX_MARKERS.clone(),
+                WINDOWS_MARKERS.clone(),
+                MAC_MARKERS.clone(),
+            ]
+        } else {
+            lock.supported_environments().to_vec()
+        };
+        let environments = environments
+            .into_iter()
+            .map(|environment| {
+                let mut packages = FxHashSet::default();
+                for root in &roots {
+                    let package = lock.find_by_id(root);
+                    packages.extend(reachable(
+                        lock,
+                        package.all_dependencies(),
+                        Some(&environment),
+                    ));
+                }
+                packages.retain(|id| !roots.contains(id));
+                (environment, packages.len())
+            })
+            .collect();
+
+        // Tally the locked artifacts.
+        let mut sdists = 0;
+        let mut sdist_size = 0;
+        let mut wheels = 0;
+        let mut wheel_size = 0;
+        let mut unknown_size = 0;
+        for package in lock.packages() {
+            if let Some(sdist) = &package.sdist {
+                sdists += 1;
+                match sdist.size() {
+                    Some(size) => sdist_size += size,
+                    None => unknown_size += 1,
+                }
+            }
+            for wheel in &package.wheels {
+                wheels += 1;
+                match wheel.size {
+                    Some(size) => wheel_size += size,
+                    None => unknown_size += 1,
+                }
+            }
+        }
+
+        Self {
+            packages,
+            build_dependencies,
+            groups,
+            environments,
+            sdists,
+            sdist_size,
+            wheels,
+            wheel_size,
+            unknown_size,
+        }
+    }
+}
+
+/// Return the packages that are reachable from the given dependencies, optionally restricted to
+/// those required in the given environment.
+fn reachable<'lock>(
+    lock: &'lock Lock,
+    dependencies: impl IntoIterator<Item = &'lock Dependency>,
+    environment: Option<&MarkerTree>,
+) -> FxHashSet<&'lock PackageId> {
+    let is_required = |dependency: &Dependency| {
+        environment.map_or(true, |environment| {
+            !dependency.complexified_marker.is_disjoint(environment)
+        })
+    };
+
+    let mut seen = FxHashSet::default();
+    let mut queue = VecDeque::new();
+    for dependency in dependencies {
+        if is_required(dependency) && seen.insert(&dependency.package_id) {
+            queue.push_back(dependency);
+        }
+    }
+    while let Some(dependency) = queue.pop_front() {
+        let package = lock.find_by_id(&dependency.package_id);
+        let extras = dependency
+            .extra
+            .iter()
+            .filter_map(|extra| package.optional_dependencies.get(extra))
+            .flatten();
+        for dependency in package.dependencies.iter().chain(extras) {
+            if is_required(dependency) && seen.insert(&dependency.package_id) {
+                queue.push_back(dependency);
+            }
+        }
+    }
+    seen
+}
@@ -1,7 +1,7 @@
 use std::collections::{BTreeMap, VecDeque};
 
 use either::Either;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_configuration::{BuildOptions, DevGroupsManifest, ExtrasSpecification, InstallOptions};
 use uv_distribution_types::{Resolution, ResolvedDist};
@@ -11,7 +11,7 @@ use uv_pypi_types::{ResolverMarkerEnvironment, VerbatimParsedUrl};
 use uv_workspace::dependency_groups::{DependencyGroupError, FlatDependencyGroups};
 use uv_workspace::Workspace;
 
-use crate::lock::{LockErrorKind, Package, TagPolicy};
+use crate::lock::{LockErrorKind, Package, PackageId, TagPolicy};
 use crate::{Lock, LockError};
 
 /// A target that can be installed from a lockfile.
@@ -109,18 +109,21 @@ impl<'env> InstallTarget<'env> {
                     .flatten()
                     .collect::<BTreeMap<_, _>>();
 
+                // Resolve any `include-group` entries in `dependency-groups`.
+                let dependency_groups = FlatDependencyGroups::from_workspace(
+                    &dependency_groups,
+                    workspace.install_path(),
+                    workspace,
+                )?;
+
                 // Merge any overlapping groups.
                 let mut map = BTreeMap::new();
-                for (name, dependencies) in
-                    FlatDependencyGroups::from_dependency_groups(&dependency_groups)?
+                for (name, dependencies) in dependency_groups.into_iter().chain(
+                    // Only add the `dev` group if `dev-dependencies` is defined.
+                    dev_dependencies
                         .into_iter()
-                        .chain(
-                            // Only add the `dev` group if `dev-dependencies` is defined.
-                            dev_dependencies.into_iter().map(|requirements| {
-                                (DEV_DEPENDENCIES.clone(), requirements.clone())
-                            }),
-                        )
-                {
+                        .map(|requirements| (DEV_DEPENDENCIES.clone(), requirements.clone())),
+                ) {
                     match map.entry(name) {
                         std::collections::btree_map::Entry::Vacant(entry) => {
                             entry.insert(dependencies);
@@ -158,6 +161,17 @@ impl<'env> InstallTarget<'env> {
         let mut queue: VecDeque<(&Package, Option<&ExtraName>)> = VecDeque::new();
         let mut seen = FxHashSet::default();
 
+        // Evaluate any environment-scoped build exclusions for the target environment.
+        let build_options = &build_options.for_environment(marker_env);
+
+        // The nodes from which the traversal starts, and the dependencies of each node, to
+        // determine the subgraph to install (as in `--only` or `--except`).
+        let mut roots: Vec<(&PackageId, Option<&ExtraName>)> = Vec::new();
+        let mut edges: FxHashMap<
+            (&PackageId, Option<&ExtraName>),
+            Vec<(&PackageId, Option<&ExtraName>)>,
+        > = FxHashMap::default();
+
         // Add the workspace packages to the queue.
         for root_name in self.packages() {
             let root = self
@@ -173,6 +187,7 @@ impl<'env> InstallTarget<'env> {
             if dev.prod() {
                 // Add the base package.
                 queue.push_back((root, None));
+                roots.push((&root.id, None));
 
                 // Add any extras.
                 match extras {
@@ -180,11 +195,13 @@ impl<'env> InstallTarget<'env> {
                     ExtrasSpecification::All => {
                         for extra in root.optional_dependencies.keys() {
                             queue.push_back((root, Some(extra)));
+                            roots.push((&root.id, Some(extra)));
                         }
                     }
                     ExtrasSpecification::Some(extras) => {
                         for extra in extras {
                             queue.push_back((root, Some(extra)));
+                            roots.push((&root.id, Some(extra)));
                         }
                     }
                 }
@@ -195,10 +212,12 @@ impl<'env> InstallTarget<'env> {
                 for dep in root.dependency_groups.get(group).into_iter().flatten() {
                     if dep.complexified_marker.evaluate(marker_env, &[]) {
                         let dep_dist = self.lock().find_by_id(&dep.package_id);
+                        roots.push((&dep.package_id, None));
                         if seen.insert((&dep.package_id, None)) {
                             queue.push_back((dep_dist, None));
                         }
                         for extra in &dep.extra {
+                            roots.push((&dep.package_id, Some(extra)));
                             if seen.insert((&dep.package_id, Some(extra))) {
                                 queue.push_back((dep_dist, Some(extra)));
                             }
@@ -229,17 +248,18 @@ impl<'env> InstallTarget<'env> {
 
                     // Add the base package.
                     queue.push_back((root, None));
+                    roots.push((&root.id, None));
 
                     // Add any extras.
                     for extra in &dependency.extras {
                         queue.push_back((root, Some(extra)));
+                        roots.push((&root.id, Some(extra)));
                     }
                 }
             }
         }
 
-        let mut map = BTreeMap::default();
-        let mut hashes = BTreeMap::default();
+        let mut visited = Vec::new();
         while let Some((dist, extra)) = queue.pop_front() {
             let deps = if let Some(extra) = extra {
                 Either::Left(dist.optional_dependencies.get(extra).into_iter().flatten())
@@ -249,16 +269,67 @@ impl<'env> InstallTarget<'env> {
             for dep in deps {
                 if dep.complexified_marker.evaluate(marker_env, &[]) {
                     let dep_dist = self.lock().find_by_id(&dep.package_id);
+                    let children = edges.entry((&dist.id, extra)).or_default();
+                    children.push((&dep.package_id, None));
                     if seen.insert((&dep.package_id, None)) {
                         queue.push_back((dep_dist, None));
                     }
                     for extra in &dep.extra {
+                        children.push((&dep.package_id, Some(extra)));
                         if seen.insert((&dep.package_id, Some(extra))) {
                             queue.push_back((dep_dist, Some(extra)));
                         }
                     }
                 }
             }
+            visited.push((dist, extra));
+        }
+
+        // If requested, restrict the installation to a subgraph.
+        let subgraph = if install_options.is_subgraph() {
+            let start = if install_options.only_package.is_empty() {
+                roots
+            } else {
+                for name in &install_options.only_package {
+                    if !visited.iter().any(|(dist, _)| dist.id.name == *name) {
+                        return Err(
+                            LockErrorKind::MissingSubgraphPackage { name: name.clone() }.into()
+                        );
+                    }
+                }
+                visited
+                    .iter()
+                    .filter(|(dist, _)| install_options.only_package.contains(&dist.id.name))
+                    .map(|(dist, extra)| (&dist.id, *extra))
+                    .collect()
+            };
+            let mut included = FxHashSet::default();
+            let mut seen = FxHashSet::default();
+            let mut queue = VecDeque::from(start);
+            while let Some(node) = queue.pop_front() {
+                if install_options.except_package.contains(&node.0.name) {
+                    continue;
+                }
+                if !seen.insert(node) {
+                    continue;
+                }
+                included.insert(node.0);
+                queue.extend(edges.get(&node).into_iter().flatten().copied());
+            }
+            Some(included)
+        } else {
+            None
+        };
+
+        let mut map = BTreeMap::default();
+        let mut hashes = BTreeMap::default();
+        for (dist, _) in visited {
+            if subgraph
+                .as_ref()
+                .is_some_and(|subgraph| !subgraph.contains(&dist.id))
+            {
+                continue;
+            }
             if install_options.include_package(
                 &dist.id.name,
                 self.project_name(),
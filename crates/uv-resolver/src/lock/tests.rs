@@ -279,3 +279,231 @@ source = { editable = "path/to/dir" }
     let result: Result<Lock, _> = toml::from_str(data);
     insta::assert_debug_snapshot!(result);
 }
+
+#[test]
+fn python_support_narrowed_by_wheels() {
+    let data = r#"
+version = 1
+requires-python = ">=3.10, <3.14"
+
+[manifest]
+members = ["project"]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "a" },
+]
+
+[[package]]
+name = "a"
+version = "0.1.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "b", marker = "python_full_version >= '3.11'" },
+]
+wheels = [{ url = "https://example.com/a-0.1.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" }]
+
+[[package]]
+name = "b"
+version = "0.1.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://example.com/b-0.1.0-cp311-cp311-manylinux_2_17_x86_64.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" },
+    { url = "https://example.com/b-0.1.0-cp312-cp312-manylinux_2_17_x86_64.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" },
+]
+"#;
+    let lock: Lock = toml::from_str(data).unwrap();
+    let support = PythonSupport::from_lock(&lock);
+
+    // `b` is only required on Python 3.11 and later, but has no wheels for Python 3.13.
+    assert_eq!(
+        support
+            .versions()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        ["3.10", "3.11", "3.12", "3.13"]
+    );
+    let [unsupported] = support.unsupported() else {
+        panic!("expected a single unsupported package: {support:?}");
+    };
+    assert_eq!(unsupported.name.as_ref(), "b");
+    assert_eq!(unsupported.python_versions, [Version::new([3, 13])]);
+    assert_eq!(
+        unsupported
+            .chain
+            .iter()
+            .map(|(name, _)| name.as_ref())
+            .collect::<Vec<_>>(),
+        ["project", "a", "b"]
+    );
+    assert_eq!(
+        support
+            .supported_versions()
+            .into_iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        ["3.10", "3.11", "3.12"]
+    );
+}
+
+#[test]
+fn extra_conflicts() {
+    let data = r#"
+version = 1
+requires-python = ">=3.12"
+conflicting-groups = [[
+    { package = "project", extra = "project2" },
+    { package = "project", extra = "project3" },
+]]
+
+[manifest]
+members = ["project"]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+
+[package.optional-dependencies]
+project1 = [
+    { name = "wrapper" },
+]
+project2 = [
+    { name = "sortedcontainers", version = "2.4.0", source = { registry = "https://pypi.org/simple" }, marker = "sys_platform == 'linux'" },
+]
+project3 = [
+    { name = "sortedcontainers", version = "2.3.0", source = { registry = "https://pypi.org/simple" }, marker = "sys_platform == 'win32'" },
+]
+
+[[package]]
+name = "wrapper"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "sortedcontainers", version = "2.3.0", source = { registry = "https://pypi.org/simple" } },
+]
+wheels = [{ url = "https://example.com/wrapper-1.0.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" }]
+
+[[package]]
+name = "sortedcontainers"
+version = "2.3.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [{ url = "https://example.com/sortedcontainers-2.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" }]
+
+[[package]]
+name = "sortedcontainers"
+version = "2.4.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [{ url = "https://example.com/sortedcontainers-2.4.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" }]
+"#;
+    let lock: Lock = toml::from_str(data).unwrap();
+    let conflicts = ExtraConflicts::from_lock(&lock);
+
+    // `project3` selects the same version as `project1`, and is disjoint from `project2`.
+    let [conflict] = conflicts.conflicts() else {
+        panic!("expected a single conflict: {conflicts:?}");
+    };
+    assert_eq!(conflict.first.extra().as_ref(), "project1");
+    assert_eq!(conflict.second.extra().as_ref(), "project2");
+    assert_eq!(
+        conflict
+            .first_chain
+            .iter()
+            .map(|(name, version)| format!("{name}=={version}"))
+            .collect::<Vec<_>>(),
+        [
+            "project==0.1.0",
+            "wrapper==1.0.0",
+            "sortedcontainers==2.3.0"
+        ]
+    );
+    assert_eq!(
+        conflict
+            .second_chain
+            .iter()
+            .map(|(name, version)| format!("{name}=={version}"))
+            .collect::<Vec<_>>(),
+        ["project==0.1.0", "sortedcontainers==2.4.0"]
+    );
+
+    // The conflict between `project1` and `project2` is undeclared, while the declared conflict
+    // between `project2` and `project3` is unnecessary.
+    assert_eq!(conflicts.undeclared(lock.conflicting_groups()).count(), 1);
+    assert_eq!(
+        conflicts
+            .unnecessary(lock.conflicting_groups())
+            .iter()
+            .map(|unnecessary| unnecessary.group.extra().as_ref())
+            .collect::<Vec<_>>(),
+        ["project2", "project3"]
+    );
+}
+
+#[test]
+fn build_dependencies() {
+    let data = r#"
+version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "a"
+version = "0.1.0"
+source = { registry = "https://pypi.org/simple" }
+build-dependencies = [
+    { name = "setuptools", version = "75.1.0" },
+]
+sdist = { url = "https://example.com/a-0.1.0.tar.gz", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+
+[[package]]
+name = "b"
+version = "0.1.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://example.com/b-0.1.0.tar.gz", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+"#;
+    let previous: Lock = toml::from_str(data).unwrap();
+    let [a, b] = previous.packages() else {
+        panic!("expected two packages");
+    };
+    assert_eq!(
+        a.build_dependencies()
+            .map(|(name, version)| format!("{name}=={version}"))
+            .collect::<Vec<_>>(),
+        ["setuptools==75.1.0"]
+    );
+    assert_eq!(b.build_dependencies().count(), 0);
+
+    // Build requirements that were resolved in the current operation take precedence, while those
+    // of packages that weren't built are retained from the previous lock.
+    let lock = previous.clone().with_build_dependencies(
+        |name, _| {
+            (name.as_ref() == "b").then(|| {
+                vec![
+                    (
+                        PackageName::from_str("wheel").unwrap(),
+                        Version::from_str("0.44.0").unwrap(),
+                    ),
+                    (
+                        PackageName::from_str("hatchling").unwrap(),
+                        Version::from_str("1.25.0").unwrap(),
+                    ),
+                ]
+            })
+        },
+        Some(&previous),
+    );
+    let toml = lock.to_toml().unwrap();
+    assert!(toml.contains(
+        "build-dependencies = [\n    { name = \"setuptools\", version = \"75.1.0\" },\n]\n"
+    ));
+    assert!(toml.contains(
+        "build-dependencies = [\n    { name = \"hatchling\", version = \"1.25.0\" },\n    { name = \"wheel\", version = \"0.44.0\" },\n]\n"
+    ));
+
+    // The lock round-trips.
+    let roundtrip: Lock = toml::from_str(&toml).unwrap();
+    assert_eq!(roundtrip.packages(), lock.packages());
+}
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, VecDeque};
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
@@ -24,10 +24,20 @@ pub struct TreeDisplay<'env> {
     roots: Vec<NodeIndex>,
     /// The latest known version of each package.
     latest: &'env PackageMap<Version>,
+    /// The reason each package is considered unmaintained, if any.
+    unmaintained: &'env PackageMap<String>,
     /// Maximum display depth of the dependency tree.
     depth: usize,
     /// Whether to de-duplicate the displayed dependencies.
     no_dedupe: bool,
+    /// Whether the graph is inverted, such that edges point from dependencies to dependents.
+    invert: bool,
+    /// Whether to annotate each edge with the conditions under which it applies, merging the
+    /// edges between the same pair of packages.
+    annotate: bool,
+    /// The extras and dependency groups of the workspace members through which each package is
+    /// reachable, if annotating.
+    activations: FxHashMap<&'env PackageId, BTreeSet<Activation<'env>>>,
 }
 
 impl<'env> TreeDisplay<'env> {
@@ -36,12 +46,14 @@ impl<'env> TreeDisplay<'env> {
         lock: &'env Lock,
         markers: Option<&'env ResolverMarkerEnvironment>,
         latest: &'env PackageMap<Version>,
+        unmaintained: &'env PackageMap<String>,
         depth: usize,
         prune: &[PackageName],
         packages: &[PackageName],
         dev: &DevGroupsManifest,
         no_dedupe: bool,
         invert: bool,
+        annotate: bool,
     ) -> Self {
         // Identify the workspace members.
         let members: FxHashSet<&PackageId> = if lock.members().is_empty() {
@@ -163,8 +175,9 @@ impl<'env> TreeDisplay<'env> {
             }
         }
 
-        // Filter the graph to remove any unreachable nodes.
-        {
+        // Filter the graph to remove any unreachable nodes. If the lockfile has no members (e.g.,
+        // for a script), the requested packages serve as the starting points instead.
+        if !members.is_empty() {
             let mut reachable = graph
                 .node_indices()
                 .filter(|index| members.contains(graph[*index]))
@@ -182,6 +195,13 @@ impl<'env> TreeDisplay<'env> {
             graph.retain_nodes(|_, index| reachable.contains(&index));
         }
 
+        // Determine the extras and dependency groups that activate each package.
+        let activations = if annotate {
+            activations(&graph, &members)
+        } else {
+            FxHashMap::default()
+        };
+
         // Reverse the graph.
         if invert {
             graph.reverse();
@@ -248,8 +268,12 @@ impl<'env> TreeDisplay<'env> {
             graph,
             roots,
             latest,
+            unmaintained,
             depth,
             no_dedupe,
+            invert,
+            annotate,
+            activations,
         }
     }
 
@@ -266,32 +290,60 @@ impl<'env> TreeDisplay<'env> {
         }
 
         let package_id = self.graph[cursor.node()];
-        let edge = cursor.edge().map(|edge_id| &self.graph[edge_id]);
+
+        // When annotating, merge the edges between the same pair of packages into a single line.
+        let mut edges = match cursor.edge() {
+            Some(edge_id) if self.annotate => {
+                let (source, target) = self.graph.edge_endpoints(edge_id).unwrap();
+                self.graph
+                    .edges_connecting(source, target)
+                    .map(|edge| edge.weight())
+                    .collect::<Vec<_>>()
+            }
+            Some(edge_id) => vec![&self.graph[edge_id]],
+            None => vec![],
+        };
+        edges.sort();
 
         let line = {
             let mut line = format!("{}", package_id.name);
 
-            if let Some(edge) = edge {
-                let extras = &edge.dependency().extra;
-                if !extras.is_empty() {
-                    line.push('[');
-                    line.push_str(extras.iter().join(", ").as_str());
-                    line.push(']');
-                }
+            let extras = edges
+                .iter()
+                .flat_map(|edge| &edge.dependency().extra)
+                .collect::<BTreeSet<_>>();
+            if !extras.is_empty() {
+                line.push('[');
+                line.push_str(extras.iter().join(", ").as_str());
+                line.push(']');
             }
 
             line.push(' ');
             line.push('v');
             line.push_str(&format!("{}", package_id.version));
 
-            if let Some(edge) = edge {
-                match edge {
-                    Edge::Prod(_) => {}
-                    Edge::Optional(extra, _) => {
-                        line.push_str(&format!(" (extra: {extra})"));
-                    }
-                    Edge::Dev(group, _) => {
-                        line.push_str(&format!(" (group: {group})"));
+            // If any of the edges applies unconditionally, so does the dependency.
+            let conditions = edges
+                .iter()
+                .map(|edge| edge.conditions(self.annotate))
+                .collect::<Vec<_>>();
+            if conditions.iter().all(|condition| !condition.is_empty()) {
+                line.push_str(&conditions.iter().dedup().join(" or"));
+            }
+
+            // Identify the extras and dependency groups that activate the dependent, if it isn't
+            // always installed.
+            if self.annotate && !edges.is_empty() {
+                let dependent = if self.invert {
+                    Some(package_id)
+                } else {
+                    path.last().copied()
+                };
+                if let Some(activations) =
+                    dependent.and_then(|dependent| self.activations.get(dependent))
+                {
+                    if !activations.contains(&Activation::Prod) {
+                        line.push_str(&format!(" (via {})", activations.iter().join(", ")));
                     }
                 }
             }
@@ -319,6 +371,13 @@ impl<'env> TreeDisplay<'env> {
             line
         };
 
+        // Flag the package if it's no longer maintained.
+        let line = if let Some(reason) = self.unmaintained.get(package_id) {
+            format!("{line} {}", format!("({reason})").bold().yellow())
+        } else {
+            line
+        };
+
         let mut dependencies = self
             .graph
             .edges_directed(cursor.node(), Direction::Outgoing)
@@ -336,6 +395,12 @@ impl<'env> TreeDisplay<'env> {
             (edge, package_id)
         });
 
+        // When annotating, the edges to the same package are rendered on a single line.
+        if self.annotate {
+            let mut seen = FxHashSet::default();
+            dependencies.retain(|node| seen.insert(node.node()));
+        }
+
         let mut lines = vec![line];
 
         // Keep track of the dependency path to avoid cycles.
@@ -428,6 +493,103 @@ impl<'env> Edge<'env> {
             Self::Dev(group, _) => EdgeKind::Dev(group),
         }
     }
+
+    /// Return the [`Activation`] that a workspace member's edge contributes to the dependency.
+    fn activation(&self) -> Activation<'env> {
+        match self {
+            Self::Prod(_) => Activation::Prod,
+            Self::Optional(extra, _) => Activation::Extra(extra),
+            Self::Dev(group, _) => Activation::Group(group),
+        }
+    }
+
+    /// Render the conditions under which the edge applies, e.g., ` (group: dev)`, or an empty
+    /// string if it applies unconditionally.
+    ///
+    /// If `markers` is set, the edge's markers are included.
+    fn conditions(&self, markers: bool) -> String {
+        let mut conditions = String::new();
+        match self {
+            Self::Prod(_) => {}
+            Self::Optional(extra, _) => {
+                conditions.push_str(&format!(" (extra: {extra})"));
+            }
+            Self::Dev(group, _) => {
+                conditions.push_str(&format!(" (group: {group})"));
+            }
+        }
+        if markers {
+            if let Some(marker) = self.dependency().simplified_marker.try_to_string() {
+                conditions.push_str(&format!(" (marker: {marker})"));
+            }
+        }
+        conditions
+    }
+}
+
+/// A reason for which a package is included in the workspace's environment: unconditionally, or
+/// via an extra or dependency group of a workspace member.
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
+enum Activation<'env> {
+    Prod,
+    Extra(&'env ExtraName),
+    Group(&'env GroupName),
+}
+
+impl std::fmt::Display for Activation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Prod => write!(f, "prod"),
+            Self::Extra(extra) => write!(f, "extra: {extra}"),
+            Self::Group(group) => write!(f, "group: {group}"),
+        }
+    }
+}
+
+/// Determine the [`Activation`]s of every package reachable from the workspace members.
+///
+/// The workspace members are always included; any other package is included via the extras and
+/// dependency groups of the members through which it's reachable.
+fn activations<'env>(
+    graph: &petgraph::graph::Graph<&'env PackageId, Edge<'env>, petgraph::Directed>,
+    members: &FxHashSet<&PackageId>,
+) -> FxHashMap<&'env PackageId, BTreeSet<Activation<'env>>> {
+    let mut activations = FxHashMap::<&PackageId, BTreeSet<Activation>>::default();
+    let mut queue = VecDeque::new();
+    for node in graph.node_indices() {
+        if members.contains(graph[node]) {
+            activations
+                .entry(graph[node])
+                .or_default()
+                .insert(Activation::Prod);
+            queue.push_back(node);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let source = graph[node];
+        let inherited = if members.contains(source) {
+            None
+        } else {
+            activations.get(source).cloned()
+        };
+        for edge in graph.edges_directed(node, Direction::Outgoing) {
+            let target = graph[edge.target()];
+            let entry = activations.entry(target).or_default();
+            let len = entry.len();
+            match &inherited {
+                Some(inherited) => entry.extend(inherited.iter().cloned()),
+                None => {
+                    entry.insert(edge.weight().activation());
+                }
+            }
+            if entry.len() != len {
+                queue.push_back(edge.target());
+            }
+        }
+    }
+
+    activations
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
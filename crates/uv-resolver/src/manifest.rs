@@ -9,7 +9,10 @@ use uv_pypi_types::Requirement;
 use uv_types::RequestedRequirements;
 
 use crate::preferences::Preferences;
-use crate::{DependencyMode, Exclusions, ResolverEnvironment};
+use crate::{
+    DependencyMode, Exclusions, GroupResolution, IndexPatterns, ResolverEnvironment, Variants,
+    Vendored,
+};
 
 /// A manifest of requirements, constraints, and preferences.
 #[derive(Clone, Debug)]
@@ -52,6 +55,21 @@ pub struct Manifest {
     /// determinations around "allowed" versions (for example, "allowed" URLs or "allowed"
     /// pre-release versions).
     pub(crate) lookaheads: Vec<RequestedRequirements>,
+
+    /// The hardware variants to select for the project's dependencies.
+    pub(crate) variants: Variants,
+
+    /// The resolution strategies to use for the direct dependencies of specific dependency
+    /// groups, in lieu of the default strategy.
+    pub(crate) group_resolution: GroupResolution,
+
+    /// The dependencies that are vendored under an alias, and so are omitted from the
+    /// requirements of their dependents.
+    pub(crate) vendored: Vendored,
+
+    /// The indexes to which families of packages are pinned by glob patterns in
+    /// `tool.uv.sources`.
+    pub(crate) index_patterns: IndexPatterns,
 }
 
 impl Manifest {
@@ -76,6 +94,10 @@ impl Manifest {
             workspace_members: workspace_members.unwrap_or_default(),
             exclusions,
             lookaheads,
+            variants: Variants::default(),
+            group_resolution: GroupResolution::default(),
+            vendored: Vendored::default(),
+            index_patterns: IndexPatterns::default(),
         }
     }
 
@@ -90,6 +112,10 @@ impl Manifest {
             exclusions: Exclusions::default(),
             workspace_members: BTreeSet::new(),
             lookaheads: Vec::new(),
+            variants: Variants::default(),
+            group_resolution: GroupResolution::default(),
+            vendored: Vendored::default(),
+            index_patterns: IndexPatterns::default(),
         }
     }
 
@@ -99,6 +125,42 @@ impl Manifest {
         self
     }
 
+    #[must_use]
+    pub fn with_overrides(mut self, overrides: Overrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    #[must_use]
+    pub fn with_preferences(mut self, preferences: Preferences) -> Self {
+        self.preferences = preferences;
+        self
+    }
+
+    #[must_use]
+    pub fn with_variants(mut self, variants: Variants) -> Self {
+        self.variants = variants;
+        self
+    }
+
+    #[must_use]
+    pub fn with_group_resolution(mut self, group_resolution: GroupResolution) -> Self {
+        self.group_resolution = group_resolution;
+        self
+    }
+
+    #[must_use]
+    pub fn with_vendored(mut self, vendored: Vendored) -> Self {
+        self.vendored = vendored;
+        self
+    }
+
+    #[must_use]
+    pub fn with_index_patterns(mut self, index_patterns: IndexPatterns) -> Self {
+        self.index_patterns = index_patterns;
+        self
+    }
+
     /// Return an iterator over all requirements, constraints, and overrides, in priority order,
     /// such that requirements come first, followed by constraints, followed by overrides.
     ///
@@ -11,6 +11,7 @@ pub struct Options {
     pub exclude_newer: Option<ExcludeNewer>,
     pub index_strategy: IndexStrategy,
     pub flexibility: Flexibility,
+    pub max_backtracks: Option<usize>,
 }
 
 /// Builder for [`Options`].
@@ -22,6 +23,7 @@ pub struct OptionsBuilder {
     exclude_newer: Option<ExcludeNewer>,
     index_strategy: IndexStrategy,
     flexibility: Flexibility,
+    max_backtracks: Option<usize>,
 }
 
 impl OptionsBuilder {
@@ -72,6 +74,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the maximum number of backtracks before resolution is aborted.
+    #[must_use]
+    pub fn max_backtracks(mut self, max_backtracks: Option<usize>) -> Self {
+        self.max_backtracks = max_backtracks;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -81,6 +90,7 @@ impl OptionsBuilder {
             exclude_newer: self.exclude_newer,
             index_strategy: self.index_strategy,
             flexibility: self.flexibility,
+            max_backtracks: self.max_backtracks,
         }
     }
 }
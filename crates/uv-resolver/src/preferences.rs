@@ -96,6 +96,17 @@ impl Preference {
         }
     }
 
+    /// Create a [`Preference`] for a specific version of a package, shared by all forks.
+    pub fn from_version(name: PackageName, version: Version) -> Self {
+        Self {
+            name,
+            version,
+            marker: MarkerTree::TRUE,
+            fork_markers: vec![],
+            hashes: Vec::new(),
+        }
+    }
+
     /// Return the [`PackageName`] of the package for this [`Preference`].
     pub fn name(&self) -> &PackageName {
         &self.name
@@ -113,6 +113,7 @@ impl PubGrubRequirement {
             }
             RequirementSource::Git {
                 repository,
+                backend,
                 reference,
                 precise,
                 url,
@@ -123,6 +124,7 @@ impl PubGrubRequirement {
                     reference.clone(),
                     *precise,
                     subdirectory.clone(),
+                    *backend,
                 ));
                 (url, parsed_url)
             }
@@ -3,9 +3,12 @@ pub(crate) use crate::pubgrub::distribution::PubGrubDistribution;
 pub(crate) use crate::pubgrub::package::{PubGrubPackage, PubGrubPackageInner, PubGrubPython};
 pub(crate) use crate::pubgrub::priority::{PubGrubPriorities, PubGrubPriority};
 pub(crate) use crate::pubgrub::report::PubGrubReportFormatter;
+pub(crate) use crate::pubgrub::suggestions::suggestions;
+pub use crate::pubgrub::suggestions::{ConflictItem, Suggestion};
 
 mod dependencies;
 mod distribution;
 mod package;
 mod priority;
 mod report;
+mod suggestions;
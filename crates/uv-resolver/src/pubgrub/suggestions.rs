@@ -0,0 +1,444 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use indexmap::IndexSet;
+use owo_colors::OwoColorize;
+use pubgrub::{DerivationTree, External, Range};
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::{Version, VersionSpecifiers};
+
+use crate::error::ErrorTree;
+
+use super::{PubGrubPackage, PubGrubPackageInner};
+
+/// A concrete action that may resolve a version conflict, derived from the incompatibilities
+/// reported by the resolver.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum Suggestion {
+    /// Relax the requirement that a dependent declares on a package.
+    Relax {
+        /// The package declaring the requirement, or `None` for the root requirements.
+        dependent: Option<String>,
+        /// The package that is required.
+        package: PackageName,
+        /// The specifier that should be relaxed.
+        specifier: String,
+    },
+    /// Override the requirements on a package across the entire resolution.
+    Override {
+        /// The package to override.
+        package: PackageName,
+        /// The specifier to use for the override.
+        specifier: String,
+    },
+    /// Declare the extras (or dependency groups) of workspace members as conflicting, such that
+    /// they're resolved separately.
+    Conflicts {
+        /// The extras or groups that can't be installed together.
+        items: Vec<ConflictItem>,
+    },
+}
+
+/// An extra or dependency group of a workspace member that participates in a conflict.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct ConflictItem {
+    package: PackageName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extra: Option<ExtraName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<GroupName>,
+}
+
+impl ConflictItem {
+    /// The workspace member that declares the extra or group.
+    pub fn package(&self) -> &PackageName {
+        &self.package
+    }
+
+    /// The extra that participates in the conflict, if any.
+    pub fn extra(&self) -> Option<&ExtraName> {
+        self.extra.as_ref()
+    }
+
+    /// The dependency group that participates in the conflict, if any.
+    pub fn group(&self) -> Option<&GroupName> {
+        self.group.as_ref()
+    }
+
+    /// Extract the extra or dependency group enabled by the given PubGrub package, if any.
+    fn from_package(package: &PubGrubPackage) -> Option<Self> {
+        match &**package {
+            PubGrubPackageInner::Package {
+                name,
+                extra: Some(extra),
+                ..
+            }
+            | PubGrubPackageInner::Extra { name, extra, .. } => Some(Self {
+                package: name.clone(),
+                extra: Some(extra.clone()),
+                group: None,
+            }),
+            PubGrubPackageInner::Package {
+                name,
+                dev: Some(dev),
+                ..
+            }
+            | PubGrubPackageInner::Dev { name, dev, .. } => Some(Self {
+                package: name.clone(),
+                extra: None,
+                group: Some(dev.clone()),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ConflictItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.extra, &self.group) {
+            (Some(extra), _) => write!(f, "{}[{extra}]", self.package),
+            (None, Some(group)) => write!(f, "{}:{group}", self.package),
+            (None, None) => write!(f, "{}", self.package),
+        }
+    }
+}
+
+impl std::fmt::Display for Suggestion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Relax {
+                dependent: Some(dependent),
+                package,
+                specifier,
+            } => write!(
+                f,
+                "Relax the requirement {} in {}",
+                format!("`{package}{specifier}`").bold(),
+                format!("`{dependent}`").bold(),
+            ),
+            Self::Relax {
+                dependent: None,
+                package,
+                specifier,
+            } => write!(
+                f,
+                "Relax the requirement {} in your requirements",
+                format!("`{package}{specifier}`").bold(),
+            ),
+            Self::Override { package, specifier } => write!(
+                f,
+                "Add an override for {} (via `{}` or `{}`)",
+                format!("`{package}{specifier}`").bold(),
+                "tool.uv.override-dependencies".green(),
+                "--override".green(),
+            ),
+            Self::Conflicts { items } => {
+                let list = items
+                    .iter()
+                    .map(|item| format!("`{item}`").bold().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" and ");
+                if items.iter().any(|item| item.group.is_some()) {
+                    write!(
+                        f,
+                        "Move {list} into extras and declare them as conflicting (via `{}`)",
+                        "tool.uv.conflicting-groups".green(),
+                    )
+                } else {
+                    write!(
+                        f,
+                        "Declare {list} as conflicting (via `{}`)",
+                        "tool.uv.conflicting-groups".green(),
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// A requirement on a package, as derived from an [`External::FromDependencyOf`] incompatibility.
+struct Edge<'a> {
+    dependent: &'a PubGrubPackage,
+    range: &'a Range<Version>,
+}
+
+/// Analyze the derivation tree of a failed resolution and suggest actions that may resolve the
+/// conflict, ordered from most to least likely to help.
+///
+/// For every package that is required with disjoint ranges by two different dependents, we
+/// suggest (in order):
+///
+/// 1. Declaring the dependents as conflicting, if both are extras or groups of workspace members.
+/// 2. Relaxing the requirement that excludes the newest available version of the package.
+/// 3. Relaxing the other requirement.
+/// 4. Overriding the requirement on the package.
+pub(crate) fn suggestions(
+    tree: &ErrorTree,
+    available_versions: &FxHashMap<PackageName, BTreeSet<Version>>,
+    workspace_members: &BTreeSet<PackageName>,
+) -> Vec<Suggestion> {
+    let mut edges = BTreeMap::default();
+    collect_edges(tree, &mut edges);
+
+    let mut ranked = Vec::new();
+    for (package, edges) in &edges {
+        let newest = available_versions
+            .get(*package)
+            .and_then(|versions| versions.last());
+
+        for (index, first) in edges.iter().enumerate() {
+            for second in &edges[index + 1..] {
+                if first.dependent == second.dependent || !first.range.is_disjoint(second.range) {
+                    continue;
+                }
+
+                // If both requirements are part of the user's own input, there's nothing to
+                // suggest beyond what the error already says.
+                if first.dependent.name_no_root().is_none()
+                    && second.dependent.name_no_root().is_none()
+                {
+                    continue;
+                }
+
+                // Requirements from separate extras or groups of workspace members can be split
+                // into separate forks.
+                if let (Some(first_item), Some(second_item)) = (
+                    ConflictItem::from_package(first.dependent),
+                    ConflictItem::from_package(second.dependent),
+                ) {
+                    if workspace_members.contains(&first_item.package)
+                        && workspace_members.contains(&second_item.package)
+                        && first_item != second_item
+                    {
+                        let mut items = vec![first_item, second_item];
+                        items.sort();
+                        ranked.push((0, Suggestion::Conflicts { items }));
+                    }
+                }
+
+                // Prefer relaxing the requirement that excludes the newest version, since the
+                // other requirement can then typically be satisfied.
+                let (blocking, other) = match newest {
+                    Some(newest)
+                        if !first.range.contains(newest) && second.range.contains(newest) =>
+                    {
+                        (first, second)
+                    }
+                    Some(newest)
+                        if first.range.contains(newest) && !second.range.contains(newest) =>
+                    {
+                        (second, first)
+                    }
+                    _ => {
+                        ranked.push((2, relax(first.dependent, package, first.range)));
+                        ranked.push((2, relax(second.dependent, package, second.range)));
+                        ranked.push((3, overrides(package, second.range)));
+                        continue;
+                    }
+                };
+                ranked.push((1, relax(blocking.dependent, package, blocking.range)));
+                ranked.push((2, relax(other.dependent, package, other.range)));
+                ranked.push((3, overrides(package, other.range)));
+            }
+        }
+    }
+
+    ranked.sort_by_key(|(rank, _)| *rank);
+    ranked
+        .into_iter()
+        .map(|(_, suggestion)| suggestion)
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Collect the requirements on each package from the derivation tree.
+fn collect_edges<'a>(tree: &'a ErrorTree, edges: &mut BTreeMap<&'a PackageName, Vec<Edge<'a>>>) {
+    match tree {
+        DerivationTree::External(External::FromDependencyOf(dependent, _, dependency, range)) => {
+            // Skip the requirements on the Python version, and the requirements between a
+            // package and its own extras or groups.
+            let Some(name) = dependency.name_no_root() else {
+                return;
+            };
+            if dependent.name_no_root() == Some(name) || range.is_empty() {
+                return;
+            }
+            edges
+                .entry(name)
+                .or_default()
+                .push(Edge { dependent, range });
+        }
+        DerivationTree::External(_) => {}
+        DerivationTree::Derived(derived) => {
+            collect_edges(&derived.cause1, edges);
+            collect_edges(&derived.cause2, edges);
+        }
+    }
+}
+
+fn relax(dependent: &PubGrubPackage, package: &PackageName, range: &Range<Version>) -> Suggestion {
+    Suggestion::Relax {
+        dependent: dependent_name(dependent),
+        package: package.clone(),
+        specifier: specifier(range),
+    }
+}
+
+fn overrides(package: &PackageName, range: &Range<Version>) -> Suggestion {
+    Suggestion::Override {
+        package: package.clone(),
+        specifier: specifier(range),
+    }
+}
+
+/// Render the name of a dependent, or `None` for the root requirements.
+fn dependent_name(package: &PubGrubPackage) -> Option<String> {
+    match &**package {
+        PubGrubPackageInner::Root(name) => name.as_ref().map(ToString::to_string),
+        PubGrubPackageInner::Python(_) => None,
+        PubGrubPackageInner::Package {
+            name, extra, dev, ..
+        } => Some(match (extra, dev) {
+            (Some(extra), _) => format!("{name}[{extra}]"),
+            (None, Some(dev)) => format!("{name}:{dev}"),
+            (None, None) => name.to_string(),
+        }),
+        PubGrubPackageInner::Extra { name, extra, .. } => Some(format!("{name}[{extra}]")),
+        PubGrubPackageInner::Dev { name, dev, .. } => Some(format!("{name}:{dev}")),
+        PubGrubPackageInner::Marker { name, .. } => Some(name.to_string()),
+    }
+}
+
+/// Render a range as a PEP 440 specifier (e.g., `>=1.0,<2.0`).
+fn specifier(range: &Range<Version>) -> String {
+    VersionSpecifiers::from_release_only_bounds(range.iter())
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    use pubgrub::{DerivationTree, Derived, External, Map, Range};
+    use rustc_hash::FxHashMap;
+
+    use uv_normalize::{ExtraName, PackageName};
+    use uv_pep440::Version;
+
+    use crate::error::ErrorTree;
+    use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner};
+
+    use super::{suggestions, Suggestion};
+
+    fn package(name: &str, extra: Option<&str>) -> PubGrubPackage {
+        PubGrubPackage::from(PubGrubPackageInner::Package {
+            name: PackageName::from_str(name).unwrap(),
+            extra: extra.map(|extra| ExtraName::from_str(extra).unwrap()),
+            dev: None,
+            marker: None,
+        })
+    }
+
+    fn edge(
+        dependent: &PubGrubPackage,
+        dependency: &PubGrubPackage,
+        range: Range<Version>,
+    ) -> ErrorTree {
+        DerivationTree::External(External::FromDependencyOf(
+            dependent.clone(),
+            Range::full(),
+            dependency.clone(),
+            range,
+        ))
+    }
+
+    fn derived(cause1: ErrorTree, cause2: ErrorTree) -> ErrorTree {
+        DerivationTree::Derived(Derived {
+            terms: Map::default(),
+            shared_id: None,
+            cause1: Arc::new(cause1),
+            cause2: Arc::new(cause2),
+        })
+    }
+
+    #[test]
+    fn relax_blocking_requirement() {
+        let six = package("six", None);
+        let tree = derived(
+            edge(
+                &package("legacy", None),
+                &six,
+                Range::strictly_lower_than(Version::new([1, 14])),
+            ),
+            edge(
+                &package("modern", None),
+                &six,
+                Range::higher_than(Version::new([1, 16])),
+            ),
+        );
+        let available_versions = FxHashMap::from_iter([(
+            PackageName::from_str("six").unwrap(),
+            BTreeSet::from_iter([Version::new([1, 13]), Version::new([1, 16])]),
+        )]);
+
+        let suggestions = suggestions(&tree, &available_versions, &BTreeSet::default());
+        let suggestions = suggestions
+            .into_iter()
+            .map(|suggestion| match suggestion {
+                Suggestion::Relax {
+                    dependent,
+                    package,
+                    specifier,
+                } => format!("relax {package}{specifier} in {}", dependent.unwrap()),
+                Suggestion::Override { package, specifier } => {
+                    format!("override {package}{specifier}")
+                }
+                Suggestion::Conflicts { items } => format!("conflicts {items:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            suggestions,
+            [
+                "relax six<1.14 in legacy",
+                "relax six>=1.16 in modern",
+                "override six>=1.16",
+            ]
+        );
+    }
+
+    #[test]
+    fn conflicting_extras() {
+        let torch = package("torch", None);
+        let tree = derived(
+            edge(
+                &package("project", Some("cpu")),
+                &torch,
+                Range::singleton(Version::new([2, 0])),
+            ),
+            edge(
+                &package("project", Some("gpu")),
+                &torch,
+                Range::singleton(Version::new([2, 1])),
+            ),
+        );
+        let workspace_members = BTreeSet::from_iter([PackageName::from_str("project").unwrap()]);
+
+        let suggestions = suggestions(&tree, &FxHashMap::default(), &workspace_members);
+        let Some(Suggestion::Conflicts { items }) = suggestions.first() else {
+            panic!("expected a conflicts suggestion first: {suggestions:?}");
+        };
+        assert_eq!(
+            items.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["project[cpu]", "project[gpu]"]
+        );
+    }
+}
@@ -9,7 +9,7 @@ use uv_distribution_types::{
     DistributionMetadata, Name, SourceAnnotation, SourceAnnotations, VersionId,
 };
 use uv_normalize::PackageName;
-use uv_pep508::MarkerTree;
+use uv_pep508::{MarkerEnvironment, MarkerTree};
 
 use crate::resolution::{RequirementsTxtDist, ResolutionGraphNode};
 use crate::{ResolutionGraph, ResolverEnvironment};
@@ -38,6 +38,9 @@ pub struct DisplayResolutionGraph<'a> {
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
+    /// The marker environment against which to evaluate each package's markers, if any. Packages
+    /// that don't apply to the environment are omitted, and the rest are written without markers.
+    evaluate: Option<&'a MarkerEnvironment>,
 }
 
 #[derive(Debug)]
@@ -70,8 +73,23 @@ impl<'a> DisplayResolutionGraph<'a> {
             include_annotations,
             include_index_annotation,
             annotation_style,
+            evaluate: None,
         }
     }
+
+    /// Evaluate the markers of each package against the given [`MarkerEnvironment`], writing only
+    /// those packages that apply to the environment, without markers.
+    #[must_use]
+    pub fn with_evaluated_markers(mut self, markers: &'a MarkerEnvironment) -> Self {
+        self.evaluate = Some(markers);
+        self
+    }
+
+    /// Returns `true` if the given package applies to the environment being evaluated, if any.
+    fn applies(&self, dist: &RequirementsTxtDist) -> bool {
+        self.evaluate
+            .map_or(true, |markers| dist.markers.evaluate(markers, &[]))
+    }
 }
 
 /// Write the graph in the `{name}=={version}` format of requirements.txt that pip uses.
@@ -80,10 +98,14 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
         // Determine the annotation sources for each package.
         let sources = if self.include_annotations {
             let mut sources = SourceAnnotations::default();
+            let markers = self.evaluate.or(self.env.marker_environment());
 
-            for requirement in self.resolution.requirements.iter().filter(|requirement| {
-                requirement.evaluate_markers(self.env.marker_environment(), &[])
-            }) {
+            for requirement in self
+                .resolution
+                .requirements
+                .iter()
+                .filter(|requirement| requirement.evaluate_markers(markers, &[]))
+            {
                 if let Some(origin) = &requirement.origin {
                     sources.add(
                         &requirement.name,
@@ -96,9 +118,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 .resolution
                 .constraints
                 .requirements()
-                .filter(|requirement| {
-                    requirement.evaluate_markers(self.env.marker_environment(), &[])
-                })
+                .filter(|requirement| requirement.evaluate_markers(markers, &[]))
             {
                 if let Some(origin) = &requirement.origin {
                     sources.add(
@@ -112,9 +132,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 .resolution
                 .overrides
                 .requirements()
-                .filter(|requirement| {
-                    requirement.evaluate_markers(self.env.marker_environment(), &[])
-                })
+                .filter(|requirement| requirement.evaluate_markers(markers, &[]))
             {
                 if let Some(origin) = &requirement.origin {
                     sources.add(
@@ -165,6 +183,9 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 if self.no_emit_packages.contains(name) {
                     return None;
                 }
+                if !self.applies(dist) {
+                    return None;
+                }
 
                 Some((index, dist))
             })
@@ -177,7 +198,10 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
         for (index, node) in nodes {
             // Display the node itself.
             let mut line = node
-                .to_requirements_txt(&self.resolution.requires_python, self.include_markers)
+                .to_requirements_txt(
+                    &self.resolution.requires_python,
+                    self.include_markers && self.evaluate.is_none(),
+                )
                 .to_string();
 
             // Display the distribution hashes, if any.
@@ -202,6 +226,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                     let mut dependents = petgraph
                         .edges_directed(index, Direction::Incoming)
                         .map(|edge| &petgraph[edge.source()])
+                        .filter(|dist| self.applies(dist))
                         .map(uv_distribution_types::Name::name)
                         .collect::<Vec<_>>();
                     dependents.sort_unstable();
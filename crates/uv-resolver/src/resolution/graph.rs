@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Display, Formatter};
 
 use indexmap::IndexSet;
@@ -558,7 +558,7 @@ impl ResolutionGraph {
     }
 
     /// Returns an iterator over the distinct packages in the graph.
-    fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
+    pub(crate) fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
         self.petgraph
             .node_indices()
             .filter_map(move |index| match &self.petgraph[index] {
@@ -582,6 +582,31 @@ impl ResolutionGraph {
         self.dists().any(|dist| dist.name() == name)
     }
 
+    /// Returns the versions of the given package in the graph, across all forks.
+    pub fn versions(&self, name: &PackageName) -> BTreeSet<&Version> {
+        self.dists()
+            .filter(|dist| dist.is_base() && dist.name() == name)
+            .map(|dist| &dist.version)
+            .collect()
+    }
+
+    /// Returns the packages that were resolved to more than one version across forks, along with
+    /// their versions in descending order.
+    pub fn duplicates(&self) -> BTreeMap<PackageName, Vec<Version>> {
+        let mut versions: BTreeMap<PackageName, BTreeSet<Version>> = BTreeMap::new();
+        for dist in self.dists().filter(|dist| dist.is_base()) {
+            versions
+                .entry(dist.name.clone())
+                .or_default()
+                .insert(dist.version.clone());
+        }
+        versions
+            .into_iter()
+            .filter(|(_, versions)| versions.len() > 1)
+            .map(|(name, versions)| (name, versions.into_iter().rev().collect()))
+            .collect()
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
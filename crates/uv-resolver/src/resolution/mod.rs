@@ -13,10 +13,12 @@ use uv_pypi_types::HashDigest;
 pub use crate::resolution::display::{AnnotationStyle, DisplayResolutionGraph};
 pub(crate) use crate::resolution::graph::ResolutionGraphNode;
 pub use crate::resolution::graph::{ConflictingDistributionError, ResolutionGraph};
+pub use crate::resolution::report::InstallationReport;
 pub(crate) use crate::resolution::requirements_txt::RequirementsTxtDist;
 
 mod display;
 mod graph;
+mod report;
 mod requirements_txt;
 
 /// A pinned package with its resolved distribution and metadata. The [`ResolvedDist`] refers to a
@@ -0,0 +1,228 @@
+use std::collections::BTreeMap;
+
+use petgraph::Direction;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use uv_distribution_types::{BuiltDist, Dist, File, ResolvedDist, SourceDist};
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep508::MarkerEnvironment;
+use uv_pypi_types::{
+    ArchiveInfo, DirInfo, DirectUrl, HashAlgorithm, HashDigest, VcsInfo, VcsKind, Yanked,
+};
+
+use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
+use crate::ResolutionGraph;
+
+/// An installation report, compatible with the format produced by `pip install --report`.
+///
+/// See: <https://pip.pypa.io/en/stable/reference/installation-report/>
+#[derive(Debug, Serialize)]
+pub struct InstallationReport {
+    /// The version of the report format.
+    version: &'static str,
+    /// The version of the installer that produced the report. As the format is defined by pip,
+    /// the field retains pip's name, but contains the version of uv.
+    pip_version: &'static str,
+    /// The distributions that are to be installed.
+    install: Vec<InstallationReportItem>,
+    /// The marker environment against which the requirements were resolved.
+    environment: MarkerEnvironment,
+}
+
+/// A distribution in an [`InstallationReport`].
+#[derive(Debug, Serialize)]
+struct InstallationReportItem {
+    /// The metadata of the distribution, in the JSON form of the core metadata.
+    metadata: ReportMetadata,
+    /// Whether the distribution was requested directly, i.e., via a URL or a path, rather than
+    /// from an index.
+    is_direct: bool,
+    /// Whether the distribution was yanked from the index.
+    is_yanked: bool,
+    /// The provenance of the distribution, in the format of `direct_url.json`.
+    download_info: DirectUrl,
+    /// Whether the distribution was requested by the user, rather than as a dependency.
+    requested: bool,
+    /// The extras requested by the user, if any.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requested_extras: Vec<ExtraName>,
+}
+
+/// The JSON form of the core metadata fields known to the resolver.
+///
+/// See: <https://packaging.python.org/en/latest/specifications/core-metadata/>
+#[derive(Debug, Serialize)]
+struct ReportMetadata {
+    metadata_version: &'static str,
+    name: PackageName,
+    version: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requires_dist: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires_python: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    provides_extra: Vec<ExtraName>,
+}
+
+impl InstallationReport {
+    /// Create an [`InstallationReport`] from a [`ResolutionGraph`].
+    ///
+    /// Distributions that are already installed are omitted, as they won't be installed.
+    pub fn from_graph(graph: &ResolutionGraph, markers: &MarkerEnvironment) -> Self {
+        // Identify the packages (and extras) that were requested directly.
+        let mut requested: FxHashMap<&PackageName, Vec<ExtraName>> = FxHashMap::default();
+        for index in graph.petgraph.node_indices() {
+            let ResolutionGraphNode::Dist(dist) = &graph.petgraph[index] else {
+                continue;
+            };
+            let is_requested = graph
+                .petgraph
+                .neighbors_directed(index, Direction::Incoming)
+                .any(|parent| matches!(graph.petgraph[parent], ResolutionGraphNode::Root));
+            if !is_requested {
+                continue;
+            }
+            let extras = requested.entry(&dist.name).or_default();
+            if let Some(extra) = &dist.extra {
+                extras.push(extra.clone());
+            }
+        }
+
+        let mut install = graph
+            .dists()
+            .filter(|dist| dist.is_base())
+            .filter_map(|dist| {
+                let ResolvedDist::Installable(installable) = &dist.dist else {
+                    return None;
+                };
+                let download_info = download_info(installable, &dist.hashes)?;
+                let mut requested_extras = requested.get(&dist.name).cloned().unwrap_or_default();
+                requested_extras.sort();
+                requested_extras.dedup();
+                Some(InstallationReportItem {
+                    metadata: ReportMetadata::from(dist),
+                    is_direct: installable.index().is_none(),
+                    is_yanked: installable
+                        .file()
+                        .and_then(|file| file.yanked.as_ref())
+                        .is_some_and(Yanked::is_yanked),
+                    download_info,
+                    requested: requested.contains_key(&dist.name),
+                    requested_extras,
+                })
+            })
+            .collect::<Vec<_>>();
+        install.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+
+        Self {
+            version: "1",
+            pip_version: uv_version::version(),
+            install,
+            environment: markers.clone(),
+        }
+    }
+}
+
+impl From<&AnnotatedDist> for ReportMetadata {
+    fn from(dist: &AnnotatedDist) -> Self {
+        let Some(metadata) = dist.metadata.as_ref() else {
+            return Self {
+                metadata_version: "2.1",
+                name: dist.name.clone(),
+                version: dist.version.to_string(),
+                requires_dist: vec![],
+                requires_python: None,
+                provides_extra: vec![],
+            };
+        };
+        Self {
+            metadata_version: "2.1",
+            name: metadata.name.clone(),
+            version: metadata.version.to_string(),
+            requires_dist: metadata
+                .requires_dist
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            requires_python: metadata.requires_python.as_ref().map(ToString::to_string),
+            provides_extra: metadata.provides_extras.clone(),
+        }
+    }
+}
+
+/// Return the `direct_url.json`-style provenance of a distribution.
+fn download_info(dist: &Dist, hashes: &[HashDigest]) -> Option<DirectUrl> {
+    Some(match dist {
+        Dist::Built(BuiltDist::Registry(wheels)) => archive(&wheels.best_wheel().file, hashes)?,
+        Dist::Source(SourceDist::Registry(sdist)) => archive(&sdist.file, hashes)?,
+        Dist::Built(BuiltDist::DirectUrl(wheel)) => DirectUrl::ArchiveUrl {
+            url: wheel.location.to_string(),
+            archive_info: archive_info(hashes),
+            subdirectory: None,
+        },
+        Dist::Source(SourceDist::DirectUrl(sdist)) => DirectUrl::ArchiveUrl {
+            url: sdist.location.to_string(),
+            archive_info: archive_info(hashes),
+            subdirectory: sdist.subdirectory.clone(),
+        },
+        Dist::Built(BuiltDist::Path(wheel)) => DirectUrl::ArchiveUrl {
+            url: wheel.url.to_url().to_string(),
+            archive_info: archive_info(hashes),
+            subdirectory: None,
+        },
+        Dist::Source(SourceDist::Path(sdist)) => DirectUrl::ArchiveUrl {
+            url: sdist.url.to_url().to_string(),
+            archive_info: archive_info(hashes),
+            subdirectory: None,
+        },
+        Dist::Source(SourceDist::Directory(directory)) => DirectUrl::LocalDirectory {
+            url: directory.url.to_url().to_string(),
+            dir_info: DirInfo {
+                editable: directory.editable.then_some(true),
+            },
+        },
+        Dist::Source(SourceDist::Git(git)) => DirectUrl::VcsUrl {
+            url: git.git.repository().to_string(),
+            vcs_info: VcsInfo {
+                vcs: VcsKind::Git,
+                commit_id: git.git.precise().map(|sha| sha.to_string()),
+                requested_revision: git.git.reference().as_str().map(ToString::to_string),
+            },
+            subdirectory: git.subdirectory.clone(),
+        },
+    })
+}
+
+/// Return the provenance of a distribution from an index.
+fn archive(file: &File, hashes: &[HashDigest]) -> Option<DirectUrl> {
+    let url = file.url.to_url().ok()?;
+    let hashes = if hashes.is_empty() {
+        file.hashes.as_slice()
+    } else {
+        hashes
+    };
+    Some(DirectUrl::ArchiveUrl {
+        url: url.to_string(),
+        archive_info: archive_info(hashes),
+        subdirectory: None,
+    })
+}
+
+/// Return the [`ArchiveInfo`] for the given hashes, preferring SHA-256 for the legacy `hash`
+/// field.
+fn archive_info(hashes: &[HashDigest]) -> ArchiveInfo {
+    let hash = hashes
+        .iter()
+        .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+        .or_else(|| hashes.iter().max_by_key(|digest| digest.algorithm))
+        .map(|digest| format!("{}={}", digest.algorithm, digest.digest));
+    let hashes = hashes
+        .iter()
+        .map(|digest| (digest.algorithm.to_string(), digest.digest.to_string()))
+        .collect::<BTreeMap<_, _>>();
+    ArchiveInfo {
+        hash,
+        hashes: (!hashes.is_empty()).then_some(hashes),
+    }
+}
@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+
+use uv_client::RegistryClient;
+use uv_configuration::{Constraints, Overrides};
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{Index, IndexLocations};
+use uv_pep508::{MarkerEnvironment, MarkerTree};
+use uv_platform_tags::Tags;
+use uv_pypi_types::{ConflictingGroupList, Requirement};
+use uv_types::{BuildContext, EmptyInstalledPackages, HashStrategy};
+
+use crate::{
+    FlatIndex, InMemoryIndex, Manifest, Options, Preference, Preferences, PythonRequirement,
+    ResolutionGraph, ResolveError, Resolver, ResolverEnvironment,
+};
+
+/// A builder for a [`Resolver`], for use by tools that embed uv's resolver.
+///
+/// Caching and source distribution builds are configured on the [`RegistryClient`] and
+/// [`BuildContext`] that the builder is created with. Packages are fetched from the indexes
+/// configured on the client, unless any are provided via [`ResolverBuilder::index`]. By default,
+/// the builder resolves for the interpreter of the [`BuildContext`], using its marker environment,
+/// platform tags (prioritized according to the context's tag preferences), and Python version.
+///
+/// ```no_run
+/// use std::str::FromStr;
+///
+/// use uv_client::RegistryClient;
+/// use uv_distribution_types::{Index, IndexUrl};
+/// use uv_pep508::MarkerEnvironment;
+/// use uv_pypi_types::Requirement;
+/// use uv_resolver::{ResolutionGraph, Resolver};
+/// use uv_types::BuildContext;
+///
+/// async fn resolve(
+///     client: &RegistryClient,
+///     build_context: &impl BuildContext,
+///     markers: MarkerEnvironment,
+///     requirements: Vec<Requirement>,
+/// ) -> anyhow::Result<ResolutionGraph> {
+///     let index = IndexUrl::from_str("https://pypi.org/simple")?;
+///     let resolution = Resolver::builder(client, build_context)
+///         .index(Index::from_index_url(index))
+///         .markers(markers)
+///         .resolve(requirements)
+///         .await?;
+///     Ok(resolution)
+/// }
+/// ```
+pub struct ResolverBuilder<'a, Context: BuildContext> {
+    client: &'a RegistryClient,
+    build_context: &'a Context,
+    constraints: Vec<Requirement>,
+    overrides: Vec<Requirement>,
+    preferences: Vec<Preference>,
+    options: Options,
+    environment: Option<ResolverEnvironment>,
+    python_requirement: Option<PythonRequirement>,
+    tags: Option<Tags>,
+    indexes: Vec<Index>,
+    flat_index: FlatIndex,
+    hasher: HashStrategy,
+    concurrent_downloads: usize,
+}
+
+impl<'a, Context: BuildContext> ResolverBuilder<'a, Context> {
+    /// The default number of concurrent downloads.
+    const DEFAULT_CONCURRENT_DOWNLOADS: usize = 50;
+
+    /// Create a [`ResolverBuilder`] that fetches packages with the given client.
+    pub fn new(client: &'a RegistryClient, build_context: &'a Context) -> Self {
+        Self {
+            client,
+            build_context,
+            constraints: Vec::new(),
+            overrides: Vec::new(),
+            preferences: Vec::new(),
+            options: Options::default(),
+            environment: None,
+            python_requirement: None,
+            tags: None,
+            indexes: Vec::new(),
+            flat_index: FlatIndex::default(),
+            hasher: HashStrategy::None,
+            concurrent_downloads: Self::DEFAULT_CONCURRENT_DOWNLOADS,
+        }
+    }
+
+    /// Resolve for a specific marker environment, rather than that of the build context's
+    /// interpreter.
+    #[must_use]
+    pub fn markers(mut self, markers: MarkerEnvironment) -> Self {
+        self.environment = Some(ResolverEnvironment::specific(markers.into()));
+        self
+    }
+
+    /// Perform a universal resolution, producing a resolution that's valid across all
+    /// environments, as in `uv lock`.
+    ///
+    /// If provided, the resolution is split upfront into the given environments.
+    #[must_use]
+    pub fn universal(mut self, environments: Vec<MarkerTree>) -> Self {
+        self.environment = Some(ResolverEnvironment::universal(environments));
+        self
+    }
+
+    /// Set the Python requirement for the resolution.
+    #[must_use]
+    pub fn python_requirement(mut self, python_requirement: PythonRequirement) -> Self {
+        self.python_requirement = Some(python_requirement);
+        self
+    }
+
+    /// Set the platform tags used to select compatible wheels.
+    #[must_use]
+    pub fn tags(mut self, tags: Tags) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// Set the resolution [`Options`] (e.g., the resolution and pre-release strategies).
+    #[must_use]
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Constrain the versions of any packages in the resolution.
+    #[must_use]
+    pub fn constraints(mut self, constraints: impl IntoIterator<Item = Requirement>) -> Self {
+        self.constraints.extend(constraints);
+        self
+    }
+
+    /// Override the requirements of any packages in the resolution.
+    #[must_use]
+    pub fn overrides(mut self, overrides: impl IntoIterator<Item = Requirement>) -> Self {
+        self.overrides.extend(overrides);
+        self
+    }
+
+    /// Prefer the given versions (e.g., from an existing lockfile), where compatible.
+    #[must_use]
+    pub fn preferences(mut self, preferences: impl IntoIterator<Item = Preference>) -> Self {
+        self.preferences.extend(preferences);
+        self
+    }
+
+    /// Fetch packages from the given [`Index`], rather than the indexes configured on the
+    /// [`RegistryClient`].
+    ///
+    /// May be called multiple times. As with `--index` and `--default-index`, indexes are searched
+    /// in the order in which they're provided, followed by the index marked as the default (or
+    /// PyPI, if no index is marked as the default). Per-index proxies are not applied, and must be
+    /// configured on the client instead.
+    #[must_use]
+    pub fn index(mut self, index: Index) -> Self {
+        self.indexes.push(index);
+        self
+    }
+
+    /// Set the flat index entries (i.e., `--find-links`) to consider.
+    #[must_use]
+    pub fn flat_index(mut self, flat_index: FlatIndex) -> Self {
+        self.flat_index = flat_index;
+        self
+    }
+
+    /// Set the hash strategy with which to validate distributions.
+    #[must_use]
+    pub fn hasher(mut self, hasher: HashStrategy) -> Self {
+        self.hasher = hasher;
+        self
+    }
+
+    /// Set the maximum number of concurrent downloads.
+    #[must_use]
+    pub fn concurrent_downloads(mut self, concurrent_downloads: usize) -> Self {
+        self.concurrent_downloads = concurrent_downloads;
+        self
+    }
+
+    /// Resolve the given requirements.
+    pub async fn resolve(
+        self,
+        requirements: impl IntoIterator<Item = Requirement>,
+    ) -> Result<ResolutionGraph, ResolveError> {
+        let interpreter = self.build_context.interpreter();
+
+        let environment = self.environment.unwrap_or_else(|| {
+            ResolverEnvironment::specific(interpreter.resolver_marker_environment())
+        });
+        let python_requirement = self
+            .python_requirement
+            .unwrap_or_else(|| PythonRequirement::from_interpreter(interpreter));
+
+        // Universal resolutions aren't specific to any platform.
+        let tags = match (self.tags, environment.marker_environment()) {
+            (Some(tags), _) => Some(tags),
+            (None, Some(_)) => Some(interpreter.tags(self.build_context.tag_preferences())?),
+            (None, None) => None,
+        };
+
+        // If any indexes were provided, fetch packages from those indexes, rather than the
+        // indexes configured on the client.
+        let client = if self.indexes.is_empty() {
+            Cow::Borrowed(self.client)
+        } else {
+            let locations = IndexLocations::new(self.indexes, Vec::new(), false);
+            Cow::Owned(self.client.with_index_urls(locations.index_urls()))
+        };
+
+        let preferences = Preferences::from_iter(self.preferences, &environment);
+        let manifest = Manifest::simple(requirements.into_iter().collect())
+            .with_constraints(Constraints::from_requirements(self.constraints.into_iter()))
+            .with_overrides(Overrides::from_requirements(self.overrides))
+            .with_preferences(preferences);
+
+        let index = InMemoryIndex::default();
+        Resolver::new(
+            manifest,
+            self.options,
+            &python_requirement,
+            environment,
+            ConflictingGroupList::empty(),
+            tags.as_ref(),
+            &self.flat_index,
+            &index,
+            &self.hasher,
+            self.build_context,
+            EmptyInstalledPackages,
+            DistributionDatabase::new(&client, self.build_context, self.concurrent_downloads),
+        )?
+        .resolve()
+        .await
+    }
+}
@@ -4,7 +4,7 @@ use uv_pep508::VerbatimUrl;
 use uv_pypi_types::RequirementSource;
 
 use crate::resolver::ForkMap;
-use crate::{DependencyMode, Manifest, ResolverEnvironment};
+use crate::{DependencyMode, IndexPatterns, Manifest, ResolverEnvironment};
 
 /// A map of package names to their explicit index.
 ///
@@ -19,8 +19,14 @@ use crate::{DependencyMode, Manifest, ResolverEnvironment};
 /// ```
 ///
 /// [`Indexes`] would contain a single entry mapping `torch` to `https://download.pytorch.org/whl/cu121`.
+///
+/// Packages that aren't pinned by name fall back to any glob patterns in `tool.uv.sources` that
+/// match them, which apply to transitive dependencies too.
 #[derive(Debug, Default, Clone)]
-pub(crate) struct Indexes(ForkMap<IndexUrl>);
+pub(crate) struct Indexes {
+    pins: ForkMap<IndexUrl>,
+    patterns: IndexPatterns,
+}
 
 impl Indexes {
     /// Determine the set of explicit, pinned indexes in the [`Manifest`].
@@ -42,12 +48,15 @@ impl Indexes {
             indexes.add(&requirement, index);
         }
 
-        Self(indexes)
+        Self {
+            pins: indexes,
+            patterns: manifest.index_patterns.clone(),
+        }
     }
 
     /// Returns `true` if the map contains any indexes for a package.
     pub(crate) fn contains_key(&self, name: &PackageName) -> bool {
-        self.0.contains_key(name)
+        self.pins.contains_key(name) || self.patterns.matches(name)
     }
 
     /// Return the explicit index used for a package in the given fork.
@@ -56,6 +65,10 @@ impl Indexes {
         package_name: &PackageName,
         env: &ResolverEnvironment,
     ) -> Vec<&IndexUrl> {
-        self.0.get(package_name, env)
+        if self.pins.contains_key(package_name) {
+            self.pins.get(package_name, env)
+        } else {
+            self.patterns.get(package_name, env)
+        }
     }
 }
@@ -20,10 +20,12 @@ use tokio::sync::oneshot;
 use tokio_stream::wrappers::ReceiverStream;
 use tracing::{debug, info, instrument, trace, warn, Level};
 
+pub use builder::ResolverBuilder;
 use environment::ForkingPossibility;
 pub use environment::ResolverEnvironment;
 pub(crate) use fork_map::{ForkMap, ForkSet};
 pub(crate) use urls::Urls;
+use uv_client::RegistryClient;
 use uv_configuration::{Constraints, Overrides};
 use uv_distribution::{ArchiveMetadata, DistributionDatabase};
 use uv_distribution_types::{
@@ -41,7 +43,7 @@ use uv_pypi_types::{
     ConflictingGroup, ConflictingGroupList, ConflictingGroupRef, Requirement, ResolutionMetadata,
     VerbatimParsedUrl,
 };
-use uv_types::{BuildContext, HashStrategy, InstalledPackagesProvider};
+use uv_types::{BuildContext, EmptyInstalledPackages, HashStrategy, InstalledPackagesProvider};
 use uv_warnings::warn_user_once;
 
 use crate::candidate_selector::{CandidateDist, CandidateSelector};
@@ -72,11 +74,14 @@ pub use crate::resolver::provider::{
 };
 use crate::resolver::reporter::Facade;
 pub use crate::resolver::reporter::{BuildId, Reporter};
+pub use crate::resolver::statistics::{PackageStatistics, ResolverStatistics};
+use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
-use crate::{marker, DependencyMode, Exclusions, FlatIndex, Options, ResolutionMode};
+use crate::{marker, DependencyMode, Exclusions, FlatIndex, Options, ResolutionMode, Vendored};
 
 mod availability;
 mod batch_prefetch;
+mod builder;
 mod environment;
 mod fork_map;
 mod groups;
@@ -84,6 +89,7 @@ mod index;
 mod indexes;
 mod provider;
 mod reporter;
+mod statistics;
 mod urls;
 
 pub struct Resolver<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider> {
@@ -112,6 +118,7 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     python_requirement: PythonRequirement,
     conflicting_groups: ConflictingGroupList,
     workspace_members: BTreeSet<PackageName>,
+    vendored: Vendored,
     selector: CandidateSelector,
     index: InMemoryIndex,
     installed_packages: InstalledPackages,
@@ -125,6 +132,19 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     reporter: Option<Arc<dyn Reporter>>,
 }
 
+impl<'a, Context: BuildContext>
+    Resolver<DefaultResolverProvider<'a, Context>, EmptyInstalledPackages>
+{
+    /// Create a [`ResolverBuilder`], which resolves requirements using the given client and build
+    /// context.
+    pub fn builder(
+        client: &'a RegistryClient,
+        build_context: &'a Context,
+    ) -> ResolverBuilder<'a, Context> {
+        ResolverBuilder::new(client, build_context)
+    }
+}
+
 impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
     Resolver<DefaultResolverProvider<'a, Context>, InstalledPackages>
 {
@@ -218,6 +238,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             groups: Groups::from_manifest(&manifest, &env),
             project: manifest.project,
             workspace_members: manifest.workspace_members,
+            vendored: manifest.vendored,
             requirements: manifest.requirements,
             constraints: manifest.constraints,
             overrides: manifest.overrides,
@@ -306,6 +327,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
         let root = PubGrubPackage::from(PubGrubPackageInner::Root(self.project.clone()));
         let mut prefetcher = BatchPrefetcher::default();
+        let mut statistics = ResolverStatistics::default();
         let state = ForkState::new(
             State::init(root.clone(), MIN_VERSION.clone()),
             root,
@@ -325,6 +347,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             loop {
                 // Run unit propagation.
                 if let Err(err) = state.pubgrub.unit_propagation(state.next.clone()) {
+                    if statistics.backtracks() > 0 {
+                        debug!(
+                            "Backtracked {} times across {} tried versions:\n{statistics}",
+                            statistics.backtracks(),
+                            statistics.tried()
+                        );
+                    }
                     return Err(self.convert_no_solution_err(
                         err,
                         state.fork_urls,
@@ -411,6 +440,24 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                 prefetcher.version_tried(state.next.clone());
 
+                // If we already selected a version of this package in the current fork, the
+                // previous selection was discarded, i.e., we backtracked.
+                let backtracked = state
+                    .added_dependencies
+                    .get(&state.next)
+                    .is_some_and(|versions| !versions.is_empty());
+                statistics.record(&state.next, backtracked);
+                if backtracked {
+                    if let Some(limit) = self.options.max_backtracks {
+                        if statistics.backtracks() > limit {
+                            return Err(ResolveError::BacktrackLimit {
+                                limit,
+                                statistics: Box::new(statistics),
+                            });
+                        }
+                    }
+                }
+
                 let term_intersection = state
                     .pubgrub
                     .partial_solution
@@ -599,6 +646,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         for resolution in &resolutions {
             Self::trace_resolution(resolution);
         }
+        if statistics.backtracks() > 0 {
+            debug!(
+                "Backtracked {} times across {} tried versions:\n{statistics}",
+                statistics.backtracks(),
+                statistics.tried()
+            );
+        }
         ResolutionGraph::from_state(
             &resolutions,
             &self.requirements,
@@ -1345,7 +1399,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         return Err(err);
                     }
                 }
-                let requirements = self.flatten_requirements(
+                let mut requirements = self.flatten_requirements(
                     &metadata.requires_dist,
                     &metadata.dependency_groups,
                     extra.as_ref(),
@@ -1355,6 +1409,10 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     python_requirement,
                 );
 
+                // Omit any dependencies that are vendored for this package, since they're
+                // resolved separately.
+                requirements.retain(|requirement| !self.vendored.contains(name, &requirement.name));
+
                 let mut dependencies: Vec<_> = requirements
                     .iter()
                     .flat_map(|requirement| {
@@ -1773,12 +1831,25 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         match request {
             // Fetch package metadata from the registry.
             Request::Package(package_name, index) => {
-                let package_versions = provider
+                let mut package_versions = provider
                     .get_package_versions(&package_name, index.as_ref())
                     .boxed_local()
                     .await
                     .map_err(ResolveError::Client)?;
 
+                // Allow any plugins to exclude candidate versions, whether they came from a
+                // registry, a flat index, or both.
+                if let VersionsResponse::Found(ref mut version_maps) = package_versions {
+                    let excluded = uv_plugin::filter_candidates(
+                        &package_name,
+                        version_maps.iter().flat_map(VersionMap::versions).collect(),
+                    )
+                    .await?;
+                    for version_map in version_maps {
+                        version_map.exclude(&excluded);
+                    }
+                }
+
                 Ok(Some(Response::Package(
                     package_name,
                     index,
@@ -0,0 +1,152 @@
+use std::fmt::{Display, Formatter};
+
+use rustc_hash::FxHashMap;
+
+use uv_normalize::PackageName;
+
+use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner};
+
+/// The number of packages to include when summarizing the resolver's effort.
+const SUMMARY_LIMIT: usize = 10;
+
+/// Statistics on the effort spent by the resolver on each package.
+#[derive(Debug, Default, Clone)]
+pub struct ResolverStatistics {
+    packages: FxHashMap<PackageName, PackageStatistics>,
+}
+
+/// The effort spent by the resolver on a single package.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PackageStatistics {
+    /// The number of versions that were tried for the package.
+    pub tried: usize,
+    /// The number of times a previously selected version of the package was discarded.
+    pub backtracks: usize,
+}
+
+impl ResolverStatistics {
+    /// Record that a version of the given package was tried.
+    ///
+    /// If `backtracked` is `true`, a version of the package had already been selected in the
+    /// current fork, and so the previous selection was discarded.
+    pub(crate) fn record(&mut self, package: &PubGrubPackage, backtracked: bool) {
+        // Only track base packages, no virtual packages from extras.
+        let PubGrubPackageInner::Package {
+            name,
+            extra: None,
+            dev: None,
+            marker: None,
+        } = &**package
+        else {
+            return;
+        };
+        let statistics = self.packages.entry(name.clone()).or_default();
+        statistics.tried += 1;
+        if backtracked {
+            statistics.backtracks += 1;
+        }
+    }
+
+    /// Returns the total number of backtracks across all packages.
+    pub fn backtracks(&self) -> usize {
+        self.packages
+            .values()
+            .map(|statistics| statistics.backtracks)
+            .sum()
+    }
+
+    /// Returns the total number of versions tried across all packages.
+    pub fn tried(&self) -> usize {
+        self.packages
+            .values()
+            .map(|statistics| statistics.tried)
+            .sum()
+    }
+
+    /// Returns the statistics for each package, ordered from most to least effort.
+    pub fn packages(&self) -> Vec<(&PackageName, PackageStatistics)> {
+        let mut packages = self
+            .packages
+            .iter()
+            .map(|(name, statistics)| (name, *statistics))
+            .collect::<Vec<_>>();
+        packages.sort_by(|(name1, stats1), (name2, stats2)| {
+            stats2
+                .backtracks
+                .cmp(&stats1.backtracks)
+                .then(stats2.tried.cmp(&stats1.tried))
+                .then(name1.cmp(name2))
+        });
+        packages
+    }
+}
+
+impl Display for ResolverStatistics {
+    /// Summarize the packages that consumed the most effort, one per line.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let packages = self.packages();
+        for (index, (name, statistics)) in packages.iter().take(SUMMARY_LIMIT).enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "- {name}: {} {}, {} {} tried",
+                statistics.backtracks,
+                if statistics.backtracks == 1 {
+                    "backtrack"
+                } else {
+                    "backtracks"
+                },
+                statistics.tried,
+                if statistics.tried == 1 {
+                    "version"
+                } else {
+                    "versions"
+                },
+            )?;
+        }
+        if packages.len() > SUMMARY_LIMIT {
+            write!(f, "\n- ... and {} more", packages.len() - SUMMARY_LIMIT)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+
+    use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner};
+
+    use super::ResolverStatistics;
+
+    fn package(name: &str) -> PubGrubPackage {
+        PubGrubPackage::from(PubGrubPackageInner::Package {
+            name: PackageName::from_str(name).unwrap(),
+            extra: None,
+            dev: None,
+            marker: None,
+        })
+    }
+
+    #[test]
+    fn summary() {
+        let mut statistics = ResolverStatistics::default();
+        statistics.record(&package("boto3"), false);
+        statistics.record(&package("botocore"), false);
+        statistics.record(&package("botocore"), true);
+        statistics.record(&package("botocore"), true);
+        statistics.record(&package("urllib3"), false);
+        statistics.record(&package("urllib3"), true);
+
+        assert_eq!(statistics.backtracks(), 3);
+        assert_eq!(statistics.tried(), 6);
+        assert_eq!(
+            statistics.to_string(),
+            "- botocore: 2 backtracks, 3 versions tried\n- urllib3: 1 backtrack, 2 versions tried\n- boto3: 0 backtracks, 1 version tried"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use rustc_hash::FxHashMap;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_workspace::Workspace;
+
+/// The hardware variants to select for packages that publish accelerator-specific builds, e.g.,
+/// `torch = "cu124"`.
+///
+/// Variants are matched against the local version label (e.g., `2.5.1+cu124`).
+#[derive(Debug, Default, Clone)]
+pub struct Variants(FxHashMap<PackageName, String>);
+
+impl Variants {
+    /// Collect the variants selected in the `tool.uv.variants` table of the workspace root.
+    pub fn from_workspace(workspace: &Workspace) -> Self {
+        workspace
+            .variants()
+            .iter()
+            .map(|(name, variant)| (name.clone(), variant.clone()))
+            .collect()
+    }
+
+    /// Return the variant selected for the given package, if any.
+    pub fn get(&self, package_name: &PackageName) -> Option<&str> {
+        self.0.get(package_name).map(String::as_str)
+    }
+
+    /// Returns `true` if the given version of the package is compatible with the selected
+    /// variant.
+    ///
+    /// A version is compatible if no variant was selected for the package, if the version has no
+    /// local version label, or if the label matches the variant.
+    pub fn allows(&self, package_name: &PackageName, version: &Version) -> bool {
+        self.get(package_name)
+            .map_or(true, |variant| is_compatible(variant, version))
+    }
+
+    /// Returns `true` if the given version of the package matches the selected variant, i.e., if
+    /// a variant was selected for the package and the version's local version label matches it.
+    pub fn matches(&self, package_name: &PackageName, version: &Version) -> bool {
+        self.get(package_name)
+            .is_some_and(|variant| is_match(variant, version))
+    }
+
+    /// Returns `true` if the given version of the package is eligible under the given
+    /// [`VariantMatch`].
+    pub(crate) fn allows_with(
+        &self,
+        package_name: &PackageName,
+        version: &Version,
+        variant_match: VariantMatch,
+    ) -> bool {
+        match variant_match {
+            VariantMatch::Exact => self.matches(package_name, version),
+            VariantMatch::Compatible => self.allows(package_name, version),
+        }
+    }
+}
+
+/// Which versions are eligible when selecting a candidate for a package with a selected variant.
+///
+/// Versions that match the variant are preferred (e.g., `2.5.1+cu124` over `2.6.0`): candidates
+/// are first selected from the [`VariantMatch::Exact`] versions, falling back to the
+/// [`VariantMatch::Compatible`] versions if none are available.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum VariantMatch {
+    /// Only versions whose local version label matches the variant.
+    Exact,
+    /// Versions whose local version label matches the variant, and versions without a local
+    /// version label.
+    Compatible,
+}
+
+/// Returns `true` if the version has no local version label, or if its label matches the variant.
+fn is_compatible(variant: &str, version: &Version) -> bool {
+    version.local().is_empty() || is_match(variant, version)
+}
+
+/// Returns `true` if the version has a local version label that matches the variant.
+fn is_match(variant: &str, version: &Version) -> bool {
+    let local = version.local();
+    !local.is_empty() && local.to_string().eq_ignore_ascii_case(variant)
+}
+
+impl FromIterator<(PackageName, String)> for Variants {
+    fn from_iter<T: IntoIterator<Item = (PackageName, String)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn allows() {
+        let torch = PackageName::from_str("torch").unwrap();
+        let numpy = PackageName::from_str("numpy").unwrap();
+        let variants = Variants::from_iter([(torch.clone(), "cu124".to_string())]);
+
+        let version = |version: &str| Version::from_str(version).unwrap();
+        assert!(variants.allows(&torch, &version("2.5.1+cu124")));
+        assert!(variants.allows(&torch, &version("2.5.1+CU124")));
+        assert!(variants.allows(&torch, &version("2.5.1")));
+        assert!(!variants.allows(&torch, &version("2.5.1+cpu")));
+        assert!(!variants.allows(&torch, &version("2.5.1+cu121")));
+        assert!(variants.allows(&numpy, &version("2.1.0+cpu")));
+    }
+
+    #[test]
+    fn matches() {
+        let torch = PackageName::from_str("torch").unwrap();
+        let numpy = PackageName::from_str("numpy").unwrap();
+        let variants = Variants::from_iter([(torch.clone(), "cu124".to_string())]);
+
+        let version = |version: &str| Version::from_str(version).unwrap();
+        assert!(variants.matches(&torch, &version("2.5.1+cu124")));
+        assert!(variants.matches(&torch, &version("2.5.1+CU124")));
+        assert!(!variants.matches(&torch, &version("2.6.0")));
+        assert!(!variants.matches(&torch, &version("2.5.1+cpu")));
+        assert!(!variants.matches(&numpy, &version("2.1.0")));
+
+        assert!(variants.allows_with(&torch, &version("2.6.0"), VariantMatch::Compatible));
+        assert!(!variants.allows_with(&torch, &version("2.6.0"), VariantMatch::Exact));
+    }
+}
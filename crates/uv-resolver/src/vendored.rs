@@ -0,0 +1,46 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_normalize::PackageName;
+use uv_workspace::Workspace;
+
+/// The dependencies that are vendored under an alias for a specific set of dependents, e.g.,
+/// `six` for `legacy-lib`.
+///
+/// A vendored dependency is resolved separately from the rest of the workspace, so the
+/// dependents' requirements on the dependency are omitted from the main resolution.
+#[derive(Debug, Default, Clone)]
+pub struct Vendored(FxHashMap<PackageName, FxHashSet<PackageName>>);
+
+impl Vendored {
+    /// Collect the vendored dependencies declared in the `tool.uv.vendor` table of the workspace
+    /// root.
+    pub fn from_workspace(workspace: &Workspace) -> Self {
+        workspace
+            .vendored()
+            .iter()
+            .flat_map(|dependency| {
+                dependency
+                    .dependents
+                    .iter()
+                    .map(|dependent| (dependent.clone(), dependency.name.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the given dependency is vendored for the given dependent.
+    pub fn contains(&self, dependent: &PackageName, dependency: &PackageName) -> bool {
+        self.0
+            .get(dependent)
+            .is_some_and(|dependencies| dependencies.contains(dependency))
+    }
+}
+
+impl FromIterator<(PackageName, PackageName)> for Vendored {
+    fn from_iter<T: IntoIterator<Item = (PackageName, PackageName)>>(iter: T) -> Self {
+        let mut vendored = Self::default();
+        for (dependent, dependency) in iter {
+            vendored.0.entry(dependent).or_default().insert(dependency);
+        }
+        vendored
+    }
+}
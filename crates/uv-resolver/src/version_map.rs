@@ -1,5 +1,6 @@
 use pubgrub::Range;
 use std::collections::btree_map::{BTreeMap, Entry};
+use std::collections::BTreeSet;
 use std::sync::OnceLock;
 use tracing::instrument;
 
@@ -232,6 +233,30 @@ impl VersionMap {
             VersionMapInner::Lazy(ref map) => map.stable,
         }
     }
+
+    /// Remove the given versions from the map (e.g., versions excluded by a plugin).
+    pub(crate) fn exclude(&mut self, versions: &BTreeSet<Version>) {
+        if versions.is_empty() {
+            return;
+        }
+        match self.inner {
+            VersionMapInner::Eager(VersionMapEager {
+                ref mut map,
+                ref mut stable,
+            }) => {
+                map.retain(|version, _| !versions.contains(version));
+                *stable = map.keys().any(Version::is_stable);
+            }
+            VersionMapInner::Lazy(VersionMapLazy {
+                ref mut map,
+                ref mut stable,
+                ..
+            }) => {
+                map.retain(|version, _| !versions.contains(version));
+                *stable = map.keys().any(Version::is_stable);
+            }
+        }
+    }
 }
 
 impl From<FlatDistributions> for VersionMap {
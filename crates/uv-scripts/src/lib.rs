@@ -11,7 +11,8 @@ use uv_pep440::VersionSpecifiers;
 use uv_pep508::PackageName;
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_settings::{GlobalOptions, ResolverInstallerOptions};
-use uv_workspace::pyproject::Sources;
+use uv_workspace::pyproject::{PyProjectToml, PyprojectTomlError, Sources};
+use uv_workspace::{Workspace, WorkspaceError};
 
 static FINDER: LazyLock<Finder> = LazyLock::new(|| Finder::new(b"# /// script"));
 
@@ -179,6 +180,56 @@ impl Pep723Script {
         Ok(fs_err::tokio::write(file, script).await?)
     }
 
+    /// Convert the script into a non-project [`Workspace`], such that it can be locked and synced
+    /// like a project.
+    ///
+    /// The script's dependencies are declared as the `dev-dependencies` of the workspace root,
+    /// along with the `tool.uv.sources` and `tool.uv.index` from the script metadata.
+    pub fn to_workspace(&self) -> Result<Workspace, Pep723Error> {
+        let mut uv = toml::from_str::<toml::Table>(&self.metadata.raw)?
+            .remove("tool")
+            .and_then(|tool| match tool {
+                toml::Value::Table(mut tool) => tool.remove("uv"),
+                _ => None,
+            })
+            .and_then(|uv| match uv {
+                toml::Value::Table(uv) => Some(uv),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        // Scripts declare their indexes in `tool.uv.indexes`, rather than `tool.uv.index`.
+        if let Some(indexes) = uv.remove("indexes") {
+            uv.insert("index".to_string(), indexes);
+        }
+
+        let dependencies = self
+            .metadata
+            .dependencies
+            .iter()
+            .flatten()
+            .map(|requirement| toml::Value::String(requirement.to_string()))
+            .collect();
+        uv.insert(
+            "dev-dependencies".to_string(),
+            toml::Value::Array(dependencies),
+        );
+
+        let mut tool = toml::Table::new();
+        tool.insert("uv".to_string(), toml::Value::Table(uv));
+        let mut document = toml::Table::new();
+        document.insert("tool".to_string(), toml::Value::Table(tool));
+
+        let pyproject_toml = PyProjectToml::from_string(toml::to_string(&document)?)
+            .map_err(|err| Pep723Error::PyprojectToml(Box::new(err)))?;
+        let workspace = Workspace::from_script(
+            &self.path,
+            self.metadata.requires_python.clone(),
+            pyproject_toml,
+        )?;
+        Ok(workspace)
+    }
+
     /// Replace the existing metadata in the file with new metadata and write the updated content.
     pub async fn write(&self, metadata: &str) -> Result<(), Pep723Error> {
         let content = format!(
@@ -284,6 +335,12 @@ pub enum Pep723Error {
     Utf8(#[from] std::str::Utf8Error),
     #[error(transparent)]
     Toml(#[from] toml::de::Error),
+    #[error(transparent)]
+    TomlSerialize(#[from] toml::ser::Error),
+    #[error("The script metadata is not a valid `tool.uv` table")]
+    PyprojectToml(#[source] Box<PyprojectTomlError>),
+    #[error(transparent)]
+    Workspace(#[from] WorkspaceError),
     #[error("Invalid filename `{0}` supplied")]
     InvalidFilename(String),
 }
@@ -1,13 +1,17 @@
+use std::collections::BTreeMap;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
 use url::Url;
 
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, TargetTriple, TrustedPublishing,
+    AddBound, CompileBytecode, ConfigSettings, IndexStrategy, KeyringProviderType,
+    PycInvalidationMode, TargetTriple, TrustedPublishing,
 };
 use uv_distribution_types::{Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_install_wheel::linker::LinkMode;
+use uv_install_wheel::ScriptLauncher;
+use uv_platform_tags::ManylinuxVersion;
 use uv_pypi_types::{SchemaConflictingGroupList, SupportedEnvironments};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, PrereleaseMode, ResolutionMode};
@@ -71,19 +75,23 @@ macro_rules! impl_combine_or {
     };
 }
 
+impl_combine_or!(AddBound);
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(CompileBytecode);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(Index);
 impl_combine_or!(IndexStrategy);
 impl_combine_or!(IndexUrl);
 impl_combine_or!(KeyringProviderType);
 impl_combine_or!(LinkMode);
+impl_combine_or!(ManylinuxVersion);
 impl_combine_or!(NonZeroUsize);
 impl_combine_or!(PathBuf);
 impl_combine_or!(PipExtraIndex);
 impl_combine_or!(PipFindLinks);
 impl_combine_or!(PipIndex);
 impl_combine_or!(PrereleaseMode);
+impl_combine_or!(PycInvalidationMode);
 impl_combine_or!(PythonDownloads);
 impl_combine_or!(PythonPreference);
 impl_combine_or!(PythonVersion);
@@ -91,10 +99,12 @@ impl_combine_or!(ResolutionMode);
 impl_combine_or!(String);
 impl_combine_or!(SupportedEnvironments);
 impl_combine_or!(SchemaConflictingGroupList);
+impl_combine_or!(ScriptLauncher);
 impl_combine_or!(TargetTriple);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
 impl_combine_or!(bool);
+impl_combine_or!(usize);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
@@ -110,6 +120,20 @@ impl<T> Combine for Option<Vec<T>> {
     }
 }
 
+impl<K: Ord, V> Combine for Option<BTreeMap<K, V>> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`, preferring the values in `self`.
+    fn combine(self, other: Option<BTreeMap<K, V>>) -> Option<BTreeMap<K, V>> {
+        match (self, other) {
+            (Some(a), Some(mut b)) => {
+                b.extend(a);
+                Some(b)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ConfigSettings> {
     /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
     /// `Some`.
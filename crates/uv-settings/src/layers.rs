@@ -0,0 +1,287 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use uv_fs::Simplified;
+use uv_options_metadata::{OptionEntry, OptionSet};
+use uv_static::EnvVars;
+
+use crate::{validate_uv_toml, Error, Options, PyProjectToml};
+
+/// The settings that can be provided via an environment variable, along with the variable.
+const ENV_SETTINGS: &[(&str, &str)] = &[
+    ("allow-insecure-host", EnvVars::UV_INSECURE_HOST),
+    ("cache-dir", EnvVars::UV_CACHE_DIR),
+    ("compile-bytecode", EnvVars::UV_COMPILE_BYTECODE),
+    ("concurrent-builds", EnvVars::UV_CONCURRENT_BUILDS),
+    ("concurrent-downloads", EnvVars::UV_CONCURRENT_DOWNLOADS),
+    ("concurrent-installs", EnvVars::UV_CONCURRENT_INSTALLS),
+    ("exclude-newer", EnvVars::UV_EXCLUDE_NEWER),
+    ("extra-index-url", EnvVars::UV_EXTRA_INDEX_URL),
+    ("find-links", EnvVars::UV_FIND_LINKS),
+    ("index", EnvVars::UV_INDEX),
+    ("index-strategy", EnvVars::UV_INDEX_STRATEGY),
+    ("index-url", EnvVars::UV_INDEX_URL),
+    ("keyring-provider", EnvVars::UV_KEYRING_PROVIDER),
+    ("link-mode", EnvVars::UV_LINK_MODE),
+    ("max-backtracks", EnvVars::UV_MAX_BACKTRACKS),
+    ("native-tls", EnvVars::UV_NATIVE_TLS),
+    ("no-build-isolation", EnvVars::UV_NO_BUILD_ISOLATION),
+    ("no-cache", EnvVars::UV_NO_CACHE),
+    ("prerelease", EnvVars::UV_PRERELEASE),
+    ("preview", EnvVars::UV_PREVIEW),
+    ("publish-url", EnvVars::UV_PUBLISH_URL),
+    ("pypy-install-mirror", EnvVars::UV_PYPY_INSTALL_MIRROR),
+    ("python-downloads", EnvVars::UV_PYTHON_DOWNLOADS),
+    ("python-install-mirror", EnvVars::UV_PYTHON_INSTALL_MIRROR),
+    ("python-preference", EnvVars::UV_PYTHON_PREFERENCE),
+    ("resolution", EnvVars::UV_RESOLUTION),
+];
+
+/// The origin of a configuration value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigOrigin {
+    /// The value was provided by an environment variable.
+    Env(&'static str),
+    /// The value was read from a configuration file.
+    File(PathBuf),
+}
+
+impl Display for ConfigOrigin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Env(var) => write!(f, "env:{var}"),
+            Self::File(path) => write!(f, "file:{}", path.user_display()),
+        }
+    }
+}
+
+/// A single configuration value, along with its origin.
+#[derive(Debug, Clone)]
+pub struct ConfigEntry {
+    /// The name of the setting, e.g., `index-url` or `pip.index-url`.
+    pub key: String,
+    /// The value of the setting, rendered as TOML.
+    pub value: String,
+    /// The origin of the value.
+    pub origin: ConfigOrigin,
+}
+
+/// A source of configuration values, i.e., the environment or a configuration file.
+#[derive(Debug, Clone)]
+pub struct ConfigLayer {
+    origin: ConfigOrigin,
+    table: toml::Table,
+}
+
+impl ConfigLayer {
+    /// Read the settings from a `uv.toml` or `pyproject.toml` file.
+    ///
+    /// For a `pyproject.toml` file, the settings are read from the `[tool.uv]` table.
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        let content = fs_err::read_to_string(path)?;
+        let table = if is_pyproject_toml(path) {
+            let mut table = toml::Table::from_str(&content)
+                .map_err(|err| Error::PyprojectToml(path.to_path_buf(), Box::new(err)))?;
+            match table
+                .remove("tool")
+                .and_then(|tool| tool.as_table().and_then(|tool| tool.get("uv")).cloned())
+            {
+                Some(toml::Value::Table(uv)) => uv,
+                _ => toml::Table::new(),
+            }
+        } else {
+            toml::Table::from_str(&content)
+                .map_err(|err| Error::UvToml(path.to_path_buf(), Box::new(err)))?
+        };
+        Ok(Self {
+            origin: ConfigOrigin::File(path.to_path_buf()),
+            table,
+        })
+    }
+
+    /// Read the settings that are provided via environment variables.
+    pub fn from_env() -> Vec<Self> {
+        ENV_SETTINGS
+            .iter()
+            .filter_map(|(key, var)| {
+                let value = std::env::var(var).ok().filter(|value| !value.is_empty())?;
+                let mut table = toml::Table::new();
+                table.insert((*key).to_string(), toml::Value::String(value));
+                Some(Self {
+                    origin: ConfigOrigin::Env(var),
+                    table,
+                })
+            })
+            .collect()
+    }
+
+    /// Return the settings in the layer, with nested settings (e.g., `pip.index-url`) flattened.
+    pub fn entries(&self) -> Vec<ConfigEntry> {
+        let mut entries = Vec::new();
+        flatten(
+            &self.table,
+            &OptionSet::of::<Options>(),
+            "",
+            &self.origin,
+            &mut entries,
+        );
+        entries
+    }
+}
+
+/// Flatten the settings in a TOML table, descending into nested option groups.
+///
+/// Keys that don't correspond to a known setting are omitted.
+fn flatten(
+    table: &toml::Table,
+    options: &OptionSet,
+    prefix: &str,
+    origin: &ConfigOrigin,
+    entries: &mut Vec<ConfigEntry>,
+) {
+    for (key, value) in table {
+        let name = format!("{prefix}{key}");
+        match (options.find(key), value) {
+            (Some(OptionEntry::Set(set)), toml::Value::Table(table)) => {
+                flatten(table, &set, &format!("{name}."), origin, entries);
+            }
+            (Some(OptionEntry::Field(_)), value) => {
+                entries.push(ConfigEntry {
+                    key: name,
+                    value: value.to_string(),
+                    origin: origin.clone(),
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Find the nearest configuration file for the given path, i.e., a `uv.toml` file or a
+/// `pyproject.toml` file with a `[tool.uv]` table, as in [`FilesystemOptions::find`].
+///
+/// [`FilesystemOptions::find`]: crate::FilesystemOptions::find
+pub fn find_config_file(path: &Path) -> Option<PathBuf> {
+    path.ancestors().find_map(|ancestor| {
+        let uv_toml = ancestor.join("uv.toml");
+        if uv_toml.is_file() {
+            return Some(uv_toml);
+        }
+        let pyproject_toml = ancestor.join("pyproject.toml");
+        let content = fs_err::read_to_string(&pyproject_toml).ok()?;
+        let pyproject = toml::from_str::<PyProjectToml>(&content).ok()?;
+        pyproject
+            .tool
+            .is_some_and(|tool| tool.uv.is_some())
+            .then_some(pyproject_toml)
+    })
+}
+
+/// Resolve the effective value of each setting from the given layers, in order of precedence.
+pub fn effective_settings(layers: &[ConfigLayer]) -> Vec<ConfigEntry> {
+    let mut settings = Vec::<ConfigEntry>::new();
+    for entry in layers.iter().flat_map(ConfigLayer::entries) {
+        if !settings.iter().any(|setting| setting.key == entry.key) {
+            settings.push(entry);
+        }
+    }
+    settings.sort_by(|a, b| a.key.cmp(&b.key));
+    settings
+}
+
+/// Returns `true` if the given key refers to a setting (rather than a group of settings).
+pub fn is_setting(key: &str) -> bool {
+    matches!(
+        OptionSet::of::<Options>().find(key),
+        Some(OptionEntry::Field(_))
+    )
+}
+
+/// Set a value in a `uv.toml` or `pyproject.toml` file, creating the file if necessary.
+///
+/// The value is parsed as TOML, falling back to a string if it isn't valid TOML (e.g., a bare
+/// URL). The file is only written if the resulting configuration is valid.
+pub fn set_setting(path: &Path, key: &str, value: &str) -> Result<(), SetError> {
+    if !is_setting(key) {
+        return Err(SetError::UnknownSetting(key.to_string()));
+    }
+
+    let content = match fs_err::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(Error::from(err).into()),
+    };
+    let mut document = toml_edit::DocumentMut::from_str(&content)
+        .map_err(|err| SetError::Parse(path.to_path_buf(), Box::new(err)))?;
+
+    let value = toml_edit::Value::from_str(value)
+        .unwrap_or_else(|_| toml_edit::Value::from(value.to_string()));
+
+    // Settings in a `pyproject.toml` are stored in the `[tool.uv]` table.
+    let mut table = document.as_table_mut();
+    if is_pyproject_toml(path) {
+        table = implicit_table(table, "tool");
+        table = implicit_table(table, "uv");
+    }
+    let mut parts = key.split('.').collect::<Vec<_>>();
+    let field = parts.pop().expect("setting name is non-empty");
+    for part in parts {
+        table = implicit_table(table, part);
+    }
+    table.insert(field, toml_edit::Item::Value(value));
+
+    // Validate the updated configuration before writing it.
+    let content = document.to_string();
+    if is_pyproject_toml(path) {
+        toml::from_str::<PyProjectToml>(&content)
+            .map_err(|err| Error::PyprojectToml(path.to_path_buf(), Box::new(err)))?;
+    } else {
+        let options = toml::from_str::<Options>(&content)
+            .map_err(|err| Error::UvToml(path.to_path_buf(), Box::new(err)))?;
+        validate_uv_toml(path, &options)?;
+    }
+
+    if let Some(parent) = path.parent() {
+        fs_err::create_dir_all(parent).map_err(Error::from)?;
+    }
+    fs_err::write(path, content).map_err(Error::from)?;
+
+    Ok(())
+}
+
+/// Return the table with the given name, creating it if necessary.
+pub(crate) fn implicit_table<'a>(
+    table: &'a mut toml_edit::Table,
+    name: &str,
+) -> &'a mut toml_edit::Table {
+    let item = table.entry(name).or_insert_with(|| {
+        let mut table = toml_edit::Table::new();
+        table.set_implicit(true);
+        toml_edit::Item::Table(table)
+    });
+    if !item.is_table() {
+        *item = toml_edit::Item::Table(toml_edit::Table::new());
+    }
+    item.as_table_mut().expect("item is a table")
+}
+
+pub(crate) fn is_pyproject_toml(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|file_name| file_name == "pyproject.toml")
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SetError {
+    #[error(transparent)]
+    Settings(#[from] Error),
+
+    #[error("Failed to parse: `{}`", _0.user_display())]
+    Parse(PathBuf, #[source] Box<toml_edit::TomlError>),
+
+    #[error("Unknown setting: `{0}`")]
+    UnknownSetting(String),
+
+    #[error("Pins in `{}` are malformed", _0.user_display())]
+    MalformedPins(PathBuf),
+}
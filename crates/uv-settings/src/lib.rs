@@ -9,9 +9,13 @@ use uv_static::EnvVars;
 use uv_warnings::warn_user;
 
 pub use crate::combine::*;
+pub use crate::layers::*;
+pub use crate::pins::*;
 pub use crate::settings::*;
 
 mod combine;
+mod layers;
+mod pins;
 mod settings;
 
 /// The [`Options`] as loaded from a configuration file on disk.
@@ -34,13 +38,32 @@ impl Deref for FilesystemOptions {
 }
 
 impl FilesystemOptions {
-    /// Load the user [`FilesystemOptions`].
+    /// Load the user [`FilesystemOptions`], including any global pins.
     pub fn user() -> Result<Option<Self>, Error> {
         let Some(dir) = user_config_dir() else {
             return Ok(None);
         };
-        let root = dir.join("uv");
-        let file = root.join("uv.toml");
+        let options = Self::user_config(&dir)?;
+
+        // Merge in the global pins, which take precedence over any pins in the user `uv.toml`.
+        let file = pins::user_pins_file(&dir);
+        let pins = match pins::read_pins_file(&file) {
+            Ok(pins) => pins,
+            Err(Error::Io(_)) if !dir.is_dir() => None,
+            Err(err) => return Err(err),
+        };
+        let Some(pins) = pins else {
+            return Ok(options);
+        };
+        tracing::debug!("Found global pins in: `{}`", file.display());
+        let mut options = options.map(Self::into_options).unwrap_or_default();
+        options.pins = Some(pins).combine(options.pins.take());
+        Ok(Some(Self(options)))
+    }
+
+    /// Load the user `uv.toml` from the given configuration directory.
+    fn user_config(dir: &Path) -> Result<Option<Self>, Error> {
+        let file = user_config_file(dir);
 
         tracing::debug!("Searching for user configuration in: `{}`", file.display());
         match read_file(&file) {
@@ -176,6 +199,17 @@ impl From<Options> for FilesystemOptions {
     }
 }
 
+/// Returns the path to the user configuration file, i.e., `uv/uv.toml` in the user configuration
+/// directory, whether or not it exists.
+pub fn user_config_path() -> Option<PathBuf> {
+    user_config_dir().map(|dir| user_config_file(&dir))
+}
+
+/// Returns the path to the user configuration file within the given configuration directory.
+fn user_config_file(dir: &Path) -> PathBuf {
+    dir.join("uv").join("uv.toml")
+}
+
 /// Returns the path to the user configuration directory.
 ///
 /// On Windows, use, e.g., C:\Users\Alice\AppData\Roaming
@@ -214,7 +248,7 @@ fn locate_system_config_windows(system_drive: &std::ffi::OsStr) -> Option<PathBu
 /// `/etc/xdg/uv/uv.toml` if unset or empty) and then `/etc/uv/uv.toml`
 ///
 /// On Windows, uses `%SYSTEMDRIVE%\ProgramData\uv\uv.toml`.
-fn system_config_file() -> Option<PathBuf> {
+pub fn system_config_file() -> Option<PathBuf> {
     #[cfg(windows)]
     {
         env::var_os(EnvVars::SYSTEMDRIVE)
@@ -262,6 +296,12 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if options.sources.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "sources"));
     }
+    if options.variants.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "variants"));
+    }
+    if options.vendor.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "vendor"));
+    }
     if options.dev_dependencies.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -274,6 +314,15 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
             "default-groups",
         ));
     }
+    if options.group_resolution.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "group-resolution",
+        ));
+    }
+    if options.maintenance.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "maintenance"));
+    }
     if options.managed.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "managed"));
     }
@@ -294,6 +343,9 @@ pub enum Error {
     #[error("Failed to parse: `{}`", _0.user_display())]
     UvToml(PathBuf, #[source] Box<toml::de::Error>),
 
+    #[error("Failed to parse: `{}`", _0.user_display())]
+    PinsToml(PathBuf, #[source] Box<toml::de::Error>),
+
     #[error("Failed to parse: `{}`. The `{1}` field is not allowed in a `uv.toml` file. `{1}` is only applicable in the context of a project, and should be placed in a `pyproject.toml` file instead.", _0.user_display())]
     PyprojectOnlyField(PathBuf, &'static str),
 }
@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use uv_normalize::PackageName;
+use uv_pep508::uv_pep440::Version;
+
+use crate::layers::{implicit_table, is_pyproject_toml};
+use crate::{user_config_dir, validate_uv_toml, Error, Options, PyProjectToml, SetError};
+
+/// Returns the path to the global pins file, i.e., `uv/uv.pins.toml` in the user configuration
+/// directory, whether or not it exists.
+pub fn user_pins_path() -> Option<PathBuf> {
+    user_config_dir().map(|dir| user_pins_file(&dir))
+}
+
+/// Returns the path to the global pins file within the given configuration directory.
+pub(crate) fn user_pins_file(dir: &Path) -> PathBuf {
+    dir.join("uv").join("uv.pins.toml")
+}
+
+/// Read the pinned packages from a `uv.pins.toml` file, if it exists.
+///
+/// A `uv.pins.toml` file contains a single table of pins, mapping package names to the version
+/// past which they may not be upgraded.
+pub(crate) fn read_pins_file(path: &Path) -> Result<Option<BTreeMap<PackageName, Version>>, Error> {
+    let content = match fs_err::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let pins = toml::from_str(&content)
+        .map_err(|err| Error::PinsToml(path.to_path_buf(), Box::new(err)))?;
+    Ok(Some(pins))
+}
+
+/// Pin a package to the given version in a `uv.pins.toml`, `uv.toml`, or `pyproject.toml` file,
+/// creating the file if necessary.
+///
+/// In a `pyproject.toml` file, pins are stored in the `[tool.uv.pins]` table; in a `uv.toml`
+/// file, in the `[pins]` table; and in a `uv.pins.toml` file, at the top level.
+pub fn set_pin(path: &Path, name: &PackageName, version: &Version) -> Result<(), SetError> {
+    edit_pins(path, |pins| {
+        pins.insert(name.as_ref(), toml_edit::value(version.to_string()));
+        true
+    })?;
+    Ok(())
+}
+
+/// Remove a pinned package from a `uv.pins.toml`, `uv.toml`, or `pyproject.toml` file.
+///
+/// Returns `false` if the package was not pinned in the file.
+pub fn remove_pin(path: &Path, name: &PackageName) -> Result<bool, SetError> {
+    if !path.is_file() {
+        return Ok(false);
+    }
+    edit_pins(path, |pins| {
+        // Match against the normalized name, in case the key isn't normalized.
+        let key = pins
+            .iter()
+            .map(|(key, _)| key.to_string())
+            .find(|key| PackageName::from_str(key).is_ok_and(|key| key == *name));
+        key.is_some_and(|key| pins.remove(&key).is_some())
+    })
+}
+
+/// Apply an edit to the table of pins in the given file, writing the file if the edit returns
+/// `true`.
+fn edit_pins(
+    path: &Path,
+    edit: impl FnOnce(&mut toml_edit::Table) -> bool,
+) -> Result<bool, SetError> {
+    let content = match fs_err::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(Error::from(err).into()),
+    };
+    let mut document = toml_edit::DocumentMut::from_str(&content)
+        .map_err(|err| SetError::Parse(path.to_path_buf(), Box::new(err)))?;
+
+    let is_pins_toml = is_pins_toml(path);
+    let mut table = document.as_table_mut();
+    if !is_pins_toml {
+        if is_pyproject_toml(path) {
+            table = implicit_table(table, "tool");
+            table = implicit_table(table, "uv");
+        }
+        table = table
+            .entry("pins")
+            .or_insert_with(|| toml_edit::Item::Table(toml_edit::Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| SetError::MalformedPins(path.to_path_buf()))?;
+    }
+
+    if !edit(table) {
+        return Ok(false);
+    }
+
+    // Remove the table of pins if it's now empty.
+    if !is_pins_toml && table.is_empty() {
+        let mut table = document.as_table_mut();
+        if is_pyproject_toml(path) {
+            table = implicit_table(table, "tool");
+            table = implicit_table(table, "uv");
+        }
+        table.remove("pins");
+    }
+
+    // Validate the updated configuration before writing it.
+    let content = document.to_string();
+    if is_pins_toml {
+        toml::from_str::<BTreeMap<PackageName, Version>>(&content)
+            .map_err(|err| Error::PinsToml(path.to_path_buf(), Box::new(err)))?;
+    } else if is_pyproject_toml(path) {
+        toml::from_str::<PyProjectToml>(&content)
+            .map_err(|err| Error::PyprojectToml(path.to_path_buf(), Box::new(err)))?;
+    } else {
+        let options = toml::from_str::<Options>(&content)
+            .map_err(|err| Error::UvToml(path.to_path_buf(), Box::new(err)))?;
+        validate_uv_toml(path, &options)?;
+    }
+
+    if let Some(parent) = path.parent() {
+        fs_err::create_dir_all(parent).map_err(Error::from)?;
+    }
+    fs_err::write(path, content).map_err(Error::from)?;
+
+    Ok(true)
+}
+
+fn is_pins_toml(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|file_name| file_name == "uv.pins.toml")
+}
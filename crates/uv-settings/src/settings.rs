@@ -1,16 +1,20 @@
 use serde::{Deserialize, Serialize};
-use std::{fmt::Debug, num::NonZeroUsize, path::PathBuf};
+use std::{collections::BTreeMap, fmt::Debug, num::NonZeroUsize, path::PathBuf};
 use url::Url;
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
-    TrustedHost, TrustedPublishing,
+    AddBound, CompileBytecode, ConfigSettings, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, PycInvalidationMode, ScopedPackageName, TargetTriple, TrustedHost,
+    TrustedPublishing,
 };
 use uv_distribution_types::{Index, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata};
 use uv_install_wheel::linker::LinkMode;
+use uv_install_wheel::ScriptLauncher;
 use uv_macros::{CombineOptions, OptionsMetadata};
 use uv_normalize::{ExtraName, PackageName};
+use uv_pep508::uv_pep440::Version;
 use uv_pep508::Requirement;
+use uv_platform_tags::ManylinuxVersion;
 use uv_pypi_types::{SupportedEnvironments, VerbatimParsedUrl};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, PrereleaseMode, ResolutionMode};
@@ -89,6 +93,47 @@ pub struct Options {
     )]
     cache_keys: Option<Vec<CacheKey>>,
 
+    /// The version specifier to write when adding a dependency without one via `uv add`.
+    ///
+    /// By default, uv adds a lower bound on the locked version (e.g., `>=1.4.2`). Use `major`
+    /// or `minor` to also cap the dependency at the next major or minor version (e.g.,
+    /// `>=1.4.2,<2` or `>=1.4.2,<1.5`), or `exact` to pin the locked version (e.g., `==1.4.2`).
+    ///
+    /// The same policy is used to rewrite the specifiers of existing dependencies that are
+    /// upgraded via `uv add --upgrade` or `uv add --upgrade-package`.
+    #[option(
+        default = "\"none\"",
+        value_type = "str",
+        example = r#"
+            add-bound = "major"
+        "#,
+        possible_values = true
+    )]
+    pub add_bound: Option<AddBound>,
+
+    /// Packages that may not be upgraded past the given version.
+    ///
+    /// Unlike constraints, pins only apply to upgrades (e.g., `uv lock --upgrade` or
+    /// `uv sync --upgrade-package`): a fresh resolution may select any version, but a pinned
+    /// package that's being upgraded will never be upgraded past its pinned version.
+    ///
+    /// Pins can be declared in a `pyproject.toml` or `uv.toml` file, along with a global
+    /// `uv.pins.toml` file in the user configuration directory (e.g., `~/.config/uv/uv.pins.toml`),
+    /// which accepts the same table of pins at the top level. Project pins take precedence over
+    /// global pins. Use `uv pin` to manage them.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<BTreeMap<PackageName, String>>")
+    )]
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            pins = { django = "4.2.16" }
+        "#
+    )]
+    pub pins: Option<BTreeMap<PackageName, Version>>,
+
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're respected in both `pyproject.toml` and `uv.toml` files.
@@ -113,17 +158,35 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub sources: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub variants: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub vendor: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub dev_dependencies: Option<serde::de::IgnoredAny>,
 
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub default_groups: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub group_resolution: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub managed: Option<serde::de::IgnoredAny>,
 
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub r#package: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub rebuild_on_import: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub ignore_unused_dependencies: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub maintenance: Option<serde::de::IgnoredAny>,
 }
 
 impl Options {
@@ -269,6 +332,39 @@ pub struct GlobalOptions {
         "#
     )]
     pub allow_insecure_host: Option<Vec<TrustedHost>>,
+    /// Platform tags to prefer when selecting wheels, in descending order of priority.
+    ///
+    /// By default, uv prefers the most specific platform tags supported by the current platform
+    /// (e.g., `manylinux_2_28_x86_64` over `manylinux_2_17_x86_64`). Each entry is a pattern in
+    /// which `*` matches any sequence of characters (e.g., `musllinux_*` or
+    /// `macosx_*_universal2`). Tags that match an earlier pattern take precedence over tags that
+    /// match a later pattern, which in turn take precedence over tags that don't match any
+    /// pattern.
+    ///
+    /// Affects both resolution and the selection of a wheel at install time.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            # Prefer universal wheels over architecture-specific wheels on macOS.
+            platform-tag-preference = ["macosx_*_universal2"]
+        "#
+    )]
+    pub platform_tag_preference: Option<Vec<String>>,
+    /// The newest `manylinux` version to consider compatible with the current platform (e.g.,
+    /// `2.28`).
+    ///
+    /// Wheels that require a newer glibc than the given version (e.g., `manylinux_2_31_x86_64`)
+    /// will be ignored, even if they're compatible with the current platform.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            max-manylinux = "2.28"
+        "#
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub max_manylinux: Option<ManylinuxVersion>,
 }
 
 /// Settings relevant to all installer operations.
@@ -284,11 +380,13 @@ pub struct InstallerOptions {
     pub config_settings: Option<ConfigSettings>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
-    pub compile_bytecode: Option<bool>,
+    pub compile_bytecode: Option<CompileBytecode>,
+    pub invalidation_mode: Option<PycInvalidationMode>,
+    pub script_launcher: Option<ScriptLauncher>,
     pub reinstall: Option<bool>,
     pub reinstall_package: Option<Vec<PackageName>>,
     pub no_build: Option<bool>,
-    pub no_build_package: Option<Vec<PackageName>>,
+    pub no_build_package: Option<Vec<ScopedPackageName>>,
     pub no_binary: Option<bool>,
     pub no_binary_package: Option<Vec<PackageName>>,
     pub no_build_isolation: Option<bool>,
@@ -310,11 +408,12 @@ pub struct ResolverOptions {
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub max_backtracks: Option<usize>,
     pub link_mode: Option<LinkMode>,
     pub upgrade: Option<bool>,
     pub upgrade_package: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     pub no_build: Option<bool>,
-    pub no_build_package: Option<Vec<PackageName>>,
+    pub no_build_package: Option<Vec<ScopedPackageName>>,
     pub no_binary: Option<bool>,
     pub no_binary_package: Option<Vec<PackageName>>,
     pub no_build_isolation: Option<bool>,
@@ -553,6 +652,20 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub exclude_newer: Option<ExcludeNewer>,
+    /// The maximum number of times the resolver may backtrack before giving up.
+    ///
+    /// When the limit is exceeded, resolution fails with a summary of the packages that consumed
+    /// the most effort, such that they can be constrained or pinned.
+    ///
+    /// By default, the number of backtracks is unlimited.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            max-backtracks = 1000
+        "#
+    )]
+    pub max_backtracks: Option<usize>,
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
@@ -576,14 +689,54 @@ pub struct ResolverInstallerOptions {
     /// When enabled, uv will process the entire site-packages directory (including packages that
     /// are not being modified by the current operation) for consistency. Like pip, it will also
     /// ignore errors.
+    ///
+    /// If a list of package names is provided, only the files belonging to those packages will be
+    /// compiled.
     #[option(
         default = "false",
-        value_type = "bool",
+        value_type = "bool | list[str]",
         example = r#"
-            compile-bytecode = true
+            compile-bytecode = ["numpy", "django"]
         "#
     )]
-    pub compile_bytecode: Option<bool>,
+    pub compile_bytecode: Option<CompileBytecode>,
+    /// The strategy to use when invalidating compiled bytecode.
+    ///
+    /// By default, bytecode files embed the modification time of their source file
+    /// (`timestamp`). The hash-based modes (`checked-hash` and `unchecked-hash`) embed a hash of
+    /// the source file instead, producing reproducible `.pyc` files, as required by some
+    /// deployment and signing pipelines.
+    ///
+    /// Only used when bytecode compilation is enabled. Defaults to `checked-hash` if
+    /// `SOURCE_DATE_EPOCH` is set, and `timestamp` otherwise.
+    #[option(
+        default = "\"timestamp\"",
+        value_type = "str",
+        example = r#"
+            invalidation-mode = "checked-hash"
+        "#,
+        possible_values = true
+    )]
+    pub invalidation_mode: Option<PycInvalidationMode>,
+    /// Options for the launchers generated for entry points on Windows.
+    ///
+    /// On Windows, uv installs each console and GUI entry point as a small `.exe` launcher that
+    /// embeds the path to the Python interpreter. Use `gui` and `console` to override the
+    /// subsystem of individual entry points (e.g., to launch a GUI application without a console
+    /// window), `long-paths` to support environments at paths longer than `MAX_PATH`, and `arch`
+    /// to override the architecture of the launchers, which otherwise matches the interpreter
+    /// (e.g., `aarch64` launchers for ARM64 Python builds).
+    ///
+    /// Launchers of an existing environment can be regenerated with `uv sync --refresh-scripts`,
+    /// e.g., after upgrading the environment's Python interpreter.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            script-launcher = { gui = ["my-app"], long-paths = true }
+        "#
+    )]
+    pub script_launcher: Option<ScriptLauncher>,
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any local or Git
     /// sources.
@@ -649,14 +802,21 @@ pub struct ResolverInstallerOptions {
     )]
     pub no_build: Option<bool>,
     /// Don't build source distributions for a specific package.
+    ///
+    /// Each entry may include a marker expression, in which case source builds are only disabled
+    /// in matching environments, e.g., `numpy; sys_platform == 'win32'` to disable building
+    /// `numpy` from source on Windows alone. Such entries are evaluated against the target
+    /// environment when building and installing, but are ignored when resolving for multiple
+    /// environments (as in `uv lock`). Use `uv lock --check-source-builds` to list the packages
+    /// that would be built from source in each environment.
     #[option(
         default = "[]",
         value_type = "list[str]",
         example = r#"
-            no-build-package = ["ruff"]
+            no-build-package = ["ruff", "numpy; sys_platform == 'win32'"]
         "#
     )]
-    pub no_build_package: Option<Vec<PackageName>>,
+    pub no_build_package: Option<Vec<ScopedPackageName>>,
     /// Don't install pre-built wheels.
     ///
     /// The given packages will be built and installed from source. The resolver will still use
@@ -818,6 +978,19 @@ pub struct PipOptions {
         "#
     )]
     pub prefix: Option<PathBuf>,
+    /// Stage the installation under the specified root directory, for packaging.
+    ///
+    /// Files are written to the `prefix` directory within the root directory, while scripts and
+    /// installation records reference the `prefix` directory itself. Only applies when `prefix` is
+    /// set.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            root = "./staging"
+        "#
+    )]
+    pub root: Option<PathBuf>,
     #[serde(skip)]
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub index: Option<Vec<Index>>,
@@ -1219,6 +1392,20 @@ pub struct PipOptions {
         "#
     )]
     pub exclude_newer: Option<ExcludeNewer>,
+    /// The maximum number of times the resolver may backtrack before giving up.
+    ///
+    /// When the limit is exceeded, resolution fails with a summary of the packages that consumed
+    /// the most effort, such that they can be constrained or pinned.
+    ///
+    /// By default, the number of backtracks is unlimited.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            max-backtracks = 1000
+        "#
+    )]
+    pub max_backtracks: Option<usize>,
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
     #[option(
@@ -1314,14 +1501,54 @@ pub struct PipOptions {
     /// When enabled, uv will process the entire site-packages directory (including packages that
     /// are not being modified by the current operation) for consistency. Like pip, it will also
     /// ignore errors.
+    ///
+    /// If a list of package names is provided, only the files belonging to those packages will be
+    /// compiled.
     #[option(
         default = "false",
-        value_type = "bool",
+        value_type = "bool | list[str]",
+        example = r#"
+            compile-bytecode = ["numpy", "django"]
+        "#
+    )]
+    pub compile_bytecode: Option<CompileBytecode>,
+    /// The strategy to use when invalidating compiled bytecode.
+    ///
+    /// By default, bytecode files embed the modification time of their source file
+    /// (`timestamp`). The hash-based modes (`checked-hash` and `unchecked-hash`) embed a hash of
+    /// the source file instead, producing reproducible `.pyc` files, as required by some
+    /// deployment and signing pipelines.
+    ///
+    /// Only used when bytecode compilation is enabled. Defaults to `checked-hash` if
+    /// `SOURCE_DATE_EPOCH` is set, and `timestamp` otherwise.
+    #[option(
+        default = "\"timestamp\"",
+        value_type = "str",
+        example = r#"
+            invalidation-mode = "checked-hash"
+        "#,
+        possible_values = true
+    )]
+    pub invalidation_mode: Option<PycInvalidationMode>,
+    /// Options for the launchers generated for entry points on Windows.
+    ///
+    /// On Windows, uv installs each console and GUI entry point as a small `.exe` launcher that
+    /// embeds the path to the Python interpreter. Use `gui` and `console` to override the
+    /// subsystem of individual entry points (e.g., to launch a GUI application without a console
+    /// window), `long-paths` to support environments at paths longer than `MAX_PATH`, and `arch`
+    /// to override the architecture of the launchers, which otherwise matches the interpreter
+    /// (e.g., `aarch64` launchers for ARM64 Python builds).
+    ///
+    /// Launchers of an existing environment can be regenerated with `uv sync --refresh-scripts`,
+    /// e.g., after upgrading the environment's Python interpreter.
+    #[option(
+        default = "{}",
+        value_type = "dict",
         example = r#"
-            compile-bytecode = true
+            script-launcher = { gui = ["my-app"], long-paths = true }
         "#
     )]
-    pub compile_bytecode: Option<bool>,
+    pub script_launcher: Option<ScriptLauncher>,
     /// Require a matching hash for each requirement.
     ///
     /// Hash-checking mode is all or nothing. If enabled, _all_ requirements must be provided
@@ -1423,6 +1650,7 @@ impl From<ResolverInstallerOptions> for ResolverOptions {
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             exclude_newer: value.exclude_newer,
+            max_backtracks: value.max_backtracks,
             link_mode: value.link_mode,
             upgrade: value.upgrade,
             upgrade_package: value.upgrade_package,
@@ -1451,6 +1679,8 @@ impl From<ResolverInstallerOptions> for InstallerOptions {
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
+            invalidation_mode: value.invalidation_mode,
+            script_launcher: value.script_launcher,
             reinstall: value.reinstall,
             reinstall_package: value.reinstall_package,
             no_build: value.no_build,
@@ -1488,10 +1718,12 @@ pub struct ToolOptions {
     pub no_build_isolation_package: Option<Vec<PackageName>>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
-    pub compile_bytecode: Option<bool>,
+    pub compile_bytecode: Option<CompileBytecode>,
+    pub invalidation_mode: Option<PycInvalidationMode>,
+    pub script_launcher: Option<ScriptLauncher>,
     pub no_sources: Option<bool>,
     pub no_build: Option<bool>,
-    pub no_build_package: Option<Vec<PackageName>>,
+    pub no_build_package: Option<Vec<ScopedPackageName>>,
     pub no_binary: Option<bool>,
     pub no_binary_package: Option<Vec<PackageName>>,
 }
@@ -1515,6 +1747,8 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
+            invalidation_mode: value.invalidation_mode,
+            script_launcher: value.script_launcher,
             no_sources: value.no_sources,
             no_build: value.no_build,
             no_build_package: value.no_build_package,
@@ -1541,8 +1775,11 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             no_build_isolation: value.no_build_isolation,
             no_build_isolation_package: value.no_build_isolation_package,
             exclude_newer: value.exclude_newer,
+            max_backtracks: None,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
+            invalidation_mode: value.invalidation_mode,
+            script_launcher: value.script_launcher,
             no_sources: value.no_sources,
             upgrade: None,
             upgrade_package: None,
@@ -1573,6 +1810,8 @@ pub struct OptionsWire {
     concurrent_downloads: Option<NonZeroUsize>,
     concurrent_builds: Option<NonZeroUsize>,
     concurrent_installs: Option<NonZeroUsize>,
+    platform_tag_preference: Option<Vec<String>>,
+    max_manylinux: Option<ManylinuxVersion>,
 
     // #[serde(flatten)]
     // top_level: ResolverInstallerOptions
@@ -1591,15 +1830,18 @@ pub struct OptionsWire {
     no_build_isolation: Option<bool>,
     no_build_isolation_package: Option<Vec<PackageName>>,
     exclude_newer: Option<ExcludeNewer>,
+    max_backtracks: Option<usize>,
     link_mode: Option<LinkMode>,
-    compile_bytecode: Option<bool>,
+    compile_bytecode: Option<CompileBytecode>,
+    invalidation_mode: Option<PycInvalidationMode>,
+    script_launcher: Option<ScriptLauncher>,
     no_sources: Option<bool>,
     upgrade: Option<bool>,
     upgrade_package: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     reinstall: Option<bool>,
     reinstall_package: Option<Vec<PackageName>>,
     no_build: Option<bool>,
-    no_build_package: Option<Vec<PackageName>>,
+    no_build_package: Option<Vec<ScopedPackageName>>,
     no_binary: Option<bool>,
     no_binary_package: Option<Vec<PackageName>>,
 
@@ -1615,6 +1857,8 @@ pub struct OptionsWire {
 
     pip: Option<PipOptions>,
     cache_keys: Option<Vec<CacheKey>>,
+    add_bound: Option<AddBound>,
+    pins: Option<BTreeMap<PackageName, Version>>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
@@ -1629,9 +1873,15 @@ pub struct OptionsWire {
     conflicting_groups: Option<serde::de::IgnoredAny>,
     workspace: Option<serde::de::IgnoredAny>,
     sources: Option<serde::de::IgnoredAny>,
+    variants: Option<serde::de::IgnoredAny>,
+    vendor: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
     r#package: Option<serde::de::IgnoredAny>,
+    rebuild_on_import: Option<serde::de::IgnoredAny>,
     default_groups: Option<serde::de::IgnoredAny>,
+    group_resolution: Option<serde::de::IgnoredAny>,
+    ignore_unused_dependencies: Option<serde::de::IgnoredAny>,
+    maintenance: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
 }
 
@@ -1650,6 +1900,8 @@ impl From<OptionsWire> for Options {
             concurrent_downloads,
             concurrent_builds,
             concurrent_installs,
+            platform_tag_preference,
+            max_manylinux,
             index,
             index_url,
             extra_index_url,
@@ -1665,8 +1917,11 @@ impl From<OptionsWire> for Options {
             no_build_isolation,
             no_build_isolation_package,
             exclude_newer,
+            max_backtracks,
             link_mode,
             compile_bytecode,
+            invalidation_mode,
+            script_launcher,
             no_sources,
             upgrade,
             upgrade_package,
@@ -1678,6 +1933,8 @@ impl From<OptionsWire> for Options {
             no_binary_package,
             pip,
             cache_keys,
+            add_bound,
+            pins,
             override_dependencies,
             constraint_dependencies,
             environments,
@@ -1686,10 +1943,16 @@ impl From<OptionsWire> for Options {
             trusted_publishing,
             workspace,
             sources,
+            variants,
+            vendor,
             default_groups,
+            group_resolution,
             dev_dependencies,
             managed,
             package,
+            rebuild_on_import,
+            ignore_unused_dependencies,
+            maintenance,
         } = value;
 
         Self {
@@ -1706,6 +1969,8 @@ impl From<OptionsWire> for Options {
                 concurrent_installs,
                 // Used twice for backwards compatibility
                 allow_insecure_host: allow_insecure_host.clone(),
+                platform_tag_preference,
+                max_manylinux,
             },
             top_level: ResolverInstallerOptions {
                 index,
@@ -1722,8 +1987,11 @@ impl From<OptionsWire> for Options {
                 no_build_isolation,
                 no_build_isolation_package,
                 exclude_newer,
+                max_backtracks,
                 link_mode,
                 compile_bytecode,
+                invalidation_mode,
+                script_launcher,
                 no_sources,
                 upgrade,
                 upgrade_package,
@@ -1736,6 +2004,8 @@ impl From<OptionsWire> for Options {
             },
             pip,
             cache_keys,
+            add_bound,
+            pins,
             override_dependencies,
             constraint_dependencies,
             environments,
@@ -1750,10 +2020,16 @@ impl From<OptionsWire> for Options {
             },
             workspace,
             sources,
+            variants,
+            vendor,
             dev_dependencies,
             default_groups,
+            group_resolution,
             managed,
             package,
+            rebuild_on_import,
+            ignore_unused_dependencies,
+            maintenance,
         }
     }
 }
@@ -1775,11 +2051,11 @@ pub struct PublishOptions {
     )]
     pub publish_url: Option<Url>,
 
-    /// Configure trusted publishing via GitHub Actions.
+    /// Configure trusted publishing via GitHub Actions, GitLab CI, or CircleCI.
     ///
-    /// By default, uv checks for trusted publishing when running in GitHub Actions, but ignores it
-    /// if it isn't configured or the workflow doesn't have enough permissions (e.g., a pull request
-    /// from a fork).
+    /// By default, uv checks for trusted publishing when running in GitHub Actions, GitLab CI, or
+    /// CircleCI, but ignores it if it isn't configured or the workflow doesn't have enough
+    /// permissions (e.g., a pull request from a fork).
     #[option(
         default = "automatic",
         value_type = "str",
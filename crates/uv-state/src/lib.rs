@@ -105,6 +105,10 @@ pub enum StateBucket {
     ManagedPython,
     /// Installed tools.
     Tools,
+    /// Cached credentials.
+    Credentials,
+    /// Optional components installed via `uv self add`.
+    Components,
 }
 
 impl StateBucket {
@@ -112,6 +116,8 @@ impl StateBucket {
         match self {
             Self::ManagedPython => "python",
             Self::Tools => "tools",
+            Self::Credentials => "credentials",
+            Self::Components => "components",
         }
     }
 }
@@ -23,6 +23,11 @@ impl EnvVars {
     /// (Deprecated: use `UV_INDEX` instead.)
     pub const UV_EXTRA_INDEX_URL: &'static str = "UV_EXTRA_INDEX_URL";
 
+    /// Equivalent to the `--pyodide-index-url` command-line argument. If set, uv will search this
+    /// Pyodide package index when resolving for `--python-platform wasm32-pyodide` in
+    /// `uv pip compile`, and include it in the output of `uv export --python-platform wasm32-pyodide`.
+    pub const UV_PYODIDE_INDEX_URL: &'static str = "UV_PYODIDE_INDEX_URL";
+
     /// Equivalent to the `--find-links` command-line argument. If set, uv will use this
     /// comma-separated list of additional locations to search for packages.
     pub const UV_FIND_LINKS: &'static str = "UV_FIND_LINKS";
@@ -31,10 +36,19 @@ impl EnvVars {
     /// directory for caching instead of the default cache directory.
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// Equivalent to the `--cache-lock-timeout` command-line argument. If set, uv will wait at
+    /// most the given duration (e.g., `30s`) for a cache lock held by another uv process.
+    pub const UV_CACHE_LOCK_TIMEOUT: &'static str = "UV_CACHE_LOCK_TIMEOUT";
+
     /// Equivalent to the `--no-cache` command-line argument. If set, uv will not use the
     /// cache for any operations.
     pub const UV_NO_CACHE: &'static str = "UV_NO_CACHE";
 
+    /// Equivalent to the `--offline-grace` command-line argument. If set, uv will use stale index
+    /// metadata from the cache when the network is unavailable, as long as it was fetched or
+    /// revalidated within the given duration (e.g., `24h`).
+    pub const UV_OFFLINE_GRACE: &'static str = "UV_OFFLINE_GRACE";
+
     /// Equivalent to the `--resolution` command-line argument. For example, if set to
     /// `lowest-direct`, uv will install the lowest compatible versions of all direct dependencies.
     pub const UV_RESOLUTION: &'static str = "UV_RESOLUTION";
@@ -104,6 +118,19 @@ impl EnvVars {
     /// will use this value as the keyring provider.
     pub const UV_KEYRING_PROVIDER: &'static str = "UV_KEYRING_PROVIDER";
 
+    /// If set to `1`, uv will cache credentials retrieved from the keyring in an encrypted file in
+    /// the uv state directory, such that they can be reused across invocations until they expire.
+    pub const UV_AUTH_TOKEN_CACHE: &'static str = "UV_AUTH_TOKEN_CACHE";
+
+    /// The base64-encoded 32-byte key used to encrypt the token cache. If unset, a key is
+    /// generated and stored in the keyring on first use, and retrieved from the keyring once per
+    /// invocation.
+    pub const UV_AUTH_TOKEN_CACHE_KEY: &'static str = "UV_AUTH_TOKEN_CACHE_KEY";
+
+    /// The duration, in seconds, for which credentials are stored in the token cache (default:
+    /// 900). Credentials that are JSON Web Tokens expire no later than their `exp` claim.
+    pub const UV_AUTH_TOKEN_CACHE_TTL: &'static str = "UV_AUTH_TOKEN_CACHE_TTL";
+
     /// Equivalent to the `--config-file` command-line argument. Expects a path to a
     /// local `uv.toml` file to use as the configuration file.
     pub const UV_CONFIG_FILE: &'static str = "UV_CONFIG_FILE";
@@ -117,6 +144,10 @@ impl EnvVars {
     /// exclude distributions published after the specified date.
     pub const UV_EXCLUDE_NEWER: &'static str = "UV_EXCLUDE_NEWER";
 
+    /// Equivalent to the `--max-backtracks` command-line argument. If set, uv will abort
+    /// resolution after backtracking the given number of times.
+    pub const UV_MAX_BACKTRACKS: &'static str = "UV_MAX_BACKTRACKS";
+
     /// Equivalent to the `--python-preference` command-line argument. Whether uv
     /// should prefer system or managed Python versions.
     pub const UV_PYTHON_PREFERENCE: &'static str = "UV_PYTHON_PREFERENCE";
@@ -153,6 +184,10 @@ impl EnvVars {
     /// the environment.
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
 
+    /// Disables the import hook that rebuilds compiled extension modules of editable projects
+    /// with `tool.uv.rebuild-on-import` enabled.
+    pub const UV_NO_REBUILD_ON_IMPORT: &'static str = "UV_NO_REBUILD_ON_IMPORT";
+
     /// Equivalent to the `--locked` command-line argument. If set, uv will assert that the
     /// `uv.lock` remains unchanged.
     pub const UV_LOCKED: &'static str = "UV_LOCKED";
@@ -188,6 +223,37 @@ impl EnvVars {
     /// Disables all progress output. For example, spinners and progress bars.
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
 
+    /// Run the invocation directly, rather than delegating it to a running `uv daemon`.
+    pub const UV_NO_DAEMON: &'static str = "UV_NO_DAEMON";
+
+    /// Disable plugins (i.e., `uv-plugin-*` executables installed via `uv tool install` or
+    /// `uv self add`), which are otherwise enabled in preview mode.
+    pub const UV_NO_PLUGINS: &'static str = "UV_NO_PLUGINS";
+
+    /// The timeout, in milliseconds, for querying the package index for versions during shell
+    /// completion, if the package isn't in the cache. By default, completions are only read
+    /// from the cache.
+    pub const UV_COMPLETION_INDEX_TIMEOUT: &'static str = "UV_COMPLETION_INDEX_TIMEOUT";
+
+    /// The URL from which to fetch the index of optional components installable via
+    /// `uv self add`. The index for each channel is read from `<url>/components-<channel>.json`.
+    /// Indexes can be read from a local directory by using the `file://` URL scheme.
+    ///
+    /// There is no default index, so this must be set to install or upgrade components.
+    pub const UV_COMPONENT_INDEX_URL: &'static str = "UV_COMPONENT_INDEX_URL";
+
+    /// Equivalent to the `--progress-format` command-line argument. If set to `json`, uv will
+    /// emit newline-delimited JSON progress events in lieu of progress bars.
+    pub const UV_PROGRESS_FORMAT: &'static str = "UV_PROGRESS_FORMAT";
+
+    /// Equivalent to the `--progress-fd` command-line argument. The file descriptor to which
+    /// JSON progress events are written.
+    pub const UV_PROGRESS_FD: &'static str = "UV_PROGRESS_FD";
+
+    /// Equivalent to the `--build-report` command-line argument. If set, uv will write a report
+    /// to this directory when a source distribution fails to build.
+    pub const UV_BUILD_REPORT: &'static str = "UV_BUILD_REPORT";
+
     /// Specifies the directory where uv stores managed tools.
     pub const UV_TOOL_DIR: &'static str = "UV_TOOL_DIR";
 
@@ -199,6 +265,10 @@ impl EnvVars {
     /// for more details.
     pub const UV_PROJECT_ENVIRONMENT: &'static str = "UV_PROJECT_ENVIRONMENT";
 
+    /// The container runtime to use for `uv run --container` (e.g., `podman`). Defaults to
+    /// `docker`.
+    pub const UV_CONTAINER_RUNTIME: &'static str = "UV_CONTAINER_RUNTIME";
+
     /// Specifies the directory to place links to installed, managed Python executables.
     pub const UV_PYTHON_BIN_DIR: &'static str = "UV_PYTHON_BIN_DIR";
 
@@ -213,6 +283,14 @@ impl EnvVars {
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
     pub const UV_PYTHON_INSTALL_MIRROR: &'static str = "UV_PYTHON_INSTALL_MIRROR";
 
+    /// Equivalent to the `--registry` command-line argument for `uv python install`. If set to
+    /// `true`, uv will register installed Python versions in the Windows registry (PEP 514).
+    pub const UV_PYTHON_INSTALL_REGISTRY: &'static str = "UV_PYTHON_INSTALL_REGISTRY";
+
+    /// Equivalent to the `--registry-scope` command-line argument for `uv python install`, i.e.,
+    /// `user` or `machine`.
+    pub const UV_PYTHON_INSTALL_REGISTRY_SCOPE: &'static str = "UV_PYTHON_INSTALL_REGISTRY_SCOPE";
+
     /// Managed PyPy installations are downloaded from
     /// [python.org](https://downloads.python.org/). This variable can be set to a mirror URL to use a
     /// different source for PyPy installations. The provided URL will replace
@@ -236,6 +314,10 @@ impl EnvVars {
     /// Use to disable line wrapping for diagnostics.
     pub const UV_NO_WRAP: &'static str = "UV_NO_WRAP";
 
+    /// Use to disable the suggested fixes that are displayed when resolution fails due to a
+    /// version conflict.
+    pub const UV_NO_RESOLUTION_SUGGESTIONS: &'static str = "UV_NO_RESOLUTION_SUGGESTIONS";
+
     /// Use to control the stack size used by uv. Typically more relevant for Windows in debug mode.
     pub const UV_STACK_SIZE: &'static str = "UV_STACK_SIZE";
 
@@ -317,6 +399,10 @@ impl EnvVars {
     /// General proxy for all network requests.
     pub const ALL_PROXY: &'static str = "ALL_PROXY";
 
+    /// Comma-separated list of hosts (e.g., `example.com`, `.example.com`, `10.0.0.0/8`, or `*`)
+    /// for which the proxy should be bypassed.
+    pub const NO_PROXY: &'static str = "NO_PROXY";
+
     /// Timeout (in seconds) for HTTP requests. (default: 30 s)
     pub const UV_HTTP_TIMEOUT: &'static str = "UV_HTTP_TIMEOUT";
 
@@ -439,6 +525,16 @@ impl EnvVars {
     /// Used for trusted publishing via `uv publish`. Contains the oidc request token.
     pub const ACTIONS_ID_TOKEN_REQUEST_TOKEN: &'static str = "ACTIONS_ID_TOKEN_REQUEST_TOKEN";
 
+    /// Used for trusted publishing via `uv publish` on GitLab CI.
+    pub const GITLAB_CI: &'static str = "GITLAB_CI";
+
+    /// Used for trusted publishing via `uv publish` on GitLab CI. Contains the OIDC token
+    /// configured via `id_tokens`.
+    pub const PYPI_ID_TOKEN: &'static str = "PYPI_ID_TOKEN";
+
+    /// Used for trusted publishing via `uv publish` on CircleCI.
+    pub const CIRCLECI: &'static str = "CIRCLECI";
+
     /// Sets the encoding for standard I/O streams (e.g., PYTHONIOENCODING=utf-8).
     #[attr_hidden]
     pub const PYTHONIOENCODING: &'static str = "PYTHONIOENCODING";
@@ -478,6 +574,14 @@ impl EnvVars {
     /// Use to create the tracing durations file via the `tracing-durations-export` feature.
     pub const TRACING_DURATIONS_FILE: &'static str = "TRACING_DURATIONS_FILE";
 
+    /// The OpenTelemetry collector endpoint (e.g., `http://localhost:4318`) to which uv should
+    /// export tracing spans via OTLP/HTTP, using the JSON encoding.
+    pub const UV_OTEL_ENDPOINT: &'static str = "UV_OTEL_ENDPOINT";
+
+    /// A W3C trace context (e.g., `00-<trace-id>-<span-id>-01`) to which spans exported via
+    /// `UV_OTEL_ENDPOINT` should be attached.
+    pub const TRACEPARENT: &'static str = "TRACEPARENT";
+
     /// Used to set `RUST_HOST_TARGET` at build time via `build.rs`.
     #[attr_hidden]
     pub const TARGET: &'static str = "TARGET";
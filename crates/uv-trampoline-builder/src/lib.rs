@@ -8,27 +8,27 @@ use uv_fs::Simplified;
 use zip::write::FileOptions;
 use zip::ZipWriter;
 
-#[cfg(all(windows, target_arch = "x86"))]
+#[cfg(windows)]
 const LAUNCHER_I686_GUI: &[u8] =
     include_bytes!("../../uv-trampoline/trampolines/uv-trampoline-i686-gui.exe");
 
-#[cfg(all(windows, target_arch = "x86"))]
+#[cfg(windows)]
 const LAUNCHER_I686_CONSOLE: &[u8] =
     include_bytes!("../../uv-trampoline/trampolines/uv-trampoline-i686-console.exe");
 
-#[cfg(all(windows, target_arch = "x86_64"))]
+#[cfg(windows)]
 const LAUNCHER_X86_64_GUI: &[u8] =
     include_bytes!("../../uv-trampoline/trampolines/uv-trampoline-x86_64-gui.exe");
 
-#[cfg(all(windows, target_arch = "x86_64"))]
+#[cfg(windows)]
 const LAUNCHER_X86_64_CONSOLE: &[u8] =
     include_bytes!("../../uv-trampoline/trampolines/uv-trampoline-x86_64-console.exe");
 
-#[cfg(all(windows, target_arch = "aarch64"))]
+#[cfg(windows)]
 const LAUNCHER_AARCH64_GUI: &[u8] =
     include_bytes!("../../uv-trampoline/trampolines/uv-trampoline-aarch64-gui.exe");
 
-#[cfg(all(windows, target_arch = "aarch64"))]
+#[cfg(windows)]
 const LAUNCHER_AARCH64_CONSOLE: &[u8] =
     include_bytes!("../../uv-trampoline/trampolines/uv-trampoline-aarch64-console.exe");
 
@@ -191,42 +191,89 @@ pub enum Error {
     NotWindows,
 }
 
+/// The architecture of a launcher binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum LauncherArch {
+    /// A launcher for 32-bit x86 Python interpreters.
+    X86,
+    /// A launcher for 64-bit x86 Python interpreters.
+    X86_64,
+    /// A launcher for 64-bit ARM Python interpreters.
+    Aarch64,
+}
+
+impl LauncherArch {
+    /// Return the [`LauncherArch`] matching the architecture of the current process, if any.
+    pub fn host() -> Option<Self> {
+        match std::env::consts::ARCH {
+            "x86" => Some(Self::X86),
+            "x86_64" => Some(Self::X86_64),
+            "aarch64" => Some(Self::Aarch64),
+            _ => None,
+        }
+    }
+}
+
+/// Options to use when building a launcher.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LauncherOptions {
+    /// The architecture of the launcher binary. Defaults to that of the current process.
+    pub arch: Option<LauncherArch>,
+    /// Whether to embed the Python executable as an extended-length path (i.e., with a `\\?\`
+    /// prefix), such that the launcher works for environments at paths longer than `MAX_PATH`.
+    pub long_paths: bool,
+}
+
 #[allow(clippy::unnecessary_wraps, unused_variables)]
-fn get_launcher_bin(gui: bool) -> Result<&'static [u8], Error> {
-    Ok(match std::env::consts::ARCH {
-        #[cfg(all(windows, target_arch = "x86"))]
-        "x86" => {
+fn get_launcher_bin(gui: bool, arch: Option<LauncherArch>) -> Result<&'static [u8], Error> {
+    let Some(arch) = arch.or_else(LauncherArch::host) else {
+        return Err(Error::UnsupportedWindowsArch(std::env::consts::ARCH));
+    };
+    Ok(match arch {
+        #[cfg(windows)]
+        LauncherArch::X86 => {
             if gui {
                 LAUNCHER_I686_GUI
             } else {
                 LAUNCHER_I686_CONSOLE
             }
         }
-        #[cfg(all(windows, target_arch = "x86_64"))]
-        "x86_64" => {
+        #[cfg(windows)]
+        LauncherArch::X86_64 => {
             if gui {
                 LAUNCHER_X86_64_GUI
             } else {
                 LAUNCHER_X86_64_CONSOLE
             }
         }
-        #[cfg(all(windows, target_arch = "aarch64"))]
-        "aarch64" => {
+        #[cfg(windows)]
+        LauncherArch::Aarch64 => {
             if gui {
                 LAUNCHER_AARCH64_GUI
             } else {
                 LAUNCHER_AARCH64_CONSOLE
             }
         }
-        #[cfg(windows)]
-        arch => {
-            return Err(Error::UnsupportedWindowsArch(arch));
-        }
         #[cfg(not(windows))]
         _ => &[],
     })
 }
 
+/// Format the path to the Python executable to embed in a launcher.
+fn launcher_python_path(python: &Path, long_paths: bool) -> String {
+    let python_path = python.simplified_display().to_string();
+    if !long_paths {
+        return python_path;
+    }
+    if let Some(unc) = python_path.strip_prefix(r"\\") {
+        format!(r"\\?\UNC\{unc}")
+    } else {
+        format!(r"\\?\{python_path}")
+    }
+}
+
 /// A Windows script is a minimal .exe launcher binary with the python entrypoint script appended as
 /// stored zip file.
 ///
@@ -236,6 +283,7 @@ pub fn windows_script_launcher(
     launcher_python_script: &str,
     is_gui: bool,
     python_executable: impl AsRef<Path>,
+    options: LauncherOptions,
 ) -> Result<Vec<u8>, Error> {
     // This method should only be called on Windows, but we avoid `#[cfg(windows)]` to retain
     // compilation on all platforms.
@@ -243,7 +291,7 @@ pub fn windows_script_launcher(
         return Err(Error::NotWindows);
     }
 
-    let launcher_bin: &[u8] = get_launcher_bin(is_gui)?;
+    let launcher_bin: &[u8] = get_launcher_bin(is_gui, options.arch)?;
 
     let mut payload: Vec<u8> = Vec::new();
     {
@@ -260,8 +308,7 @@ pub fn windows_script_launcher(
         archive.finish().expect(error_msg);
     }
 
-    let python = python_executable.as_ref();
-    let python_path = python.simplified_display().to_string();
+    let python_path = launcher_python_path(python_executable.as_ref(), options.long_paths);
 
     let mut launcher: Vec<u8> = Vec::with_capacity(launcher_bin.len() + payload.len());
     launcher.extend_from_slice(launcher_bin);
@@ -291,7 +338,7 @@ pub fn windows_python_launcher(
         return Err(Error::NotWindows);
     }
 
-    let launcher_bin: &[u8] = get_launcher_bin(is_gui)?;
+    let launcher_bin: &[u8] = get_launcher_bin(is_gui, None)?;
 
     let python = python_executable.as_ref();
     let python_path = python.simplified_display().to_string();
@@ -323,54 +370,28 @@ mod test {
 
     use which::which;
 
-    use super::{windows_python_launcher, windows_script_launcher, Launcher, LauncherKind};
-
-    #[test]
-    #[cfg(all(windows, target_arch = "x86", feature = "production"))]
-    fn test_launchers_are_small() {
-        // At time of writing, they are ~45kb.
-        assert!(
-            super::LAUNCHER_I686_GUI.len() < 45 * 1024,
-            "GUI launcher: {}",
-            super::LAUNCHER_I686_GUI.len()
-        );
-        assert!(
-            super::LAUNCHER_I686_CONSOLE.len() < 45 * 1024,
-            "CLI launcher: {}",
-            super::LAUNCHER_I686_CONSOLE.len()
-        );
-    }
-
-    #[test]
-    #[cfg(all(windows, target_arch = "x86_64", feature = "production"))]
-    fn test_launchers_are_small() {
-        // At time of writing, they are ~45kb.
-        assert!(
-            super::LAUNCHER_X86_64_GUI.len() < 45 * 1024,
-            "GUI launcher: {}",
-            super::LAUNCHER_X86_64_GUI.len()
-        );
-        assert!(
-            super::LAUNCHER_X86_64_CONSOLE.len() < 45 * 1024,
-            "CLI launcher: {}",
-            super::LAUNCHER_X86_64_CONSOLE.len()
-        );
-    }
+    use super::{
+        windows_python_launcher, windows_script_launcher, Launcher, LauncherKind, LauncherOptions,
+    };
 
     #[test]
-    #[cfg(all(windows, target_arch = "aarch64", feature = "production"))]
+    #[cfg(all(windows, feature = "production"))]
     fn test_launchers_are_small() {
         // At time of writing, they are ~45kb.
-        assert!(
-            super::LAUNCHER_AARCH64_GUI.len() < 45 * 1024,
-            "GUI launcher: {}",
-            super::LAUNCHER_AARCH64_GUI.len()
-        );
-        assert!(
-            super::LAUNCHER_AARCH64_CONSOLE.len() < 45 * 1024,
-            "CLI launcher: {}",
-            super::LAUNCHER_AARCH64_CONSOLE.len()
-        );
+        for (name, launcher) in [
+            ("i686 GUI", super::LAUNCHER_I686_GUI),
+            ("i686 CLI", super::LAUNCHER_I686_CONSOLE),
+            ("x86_64 GUI", super::LAUNCHER_X86_64_GUI),
+            ("x86_64 CLI", super::LAUNCHER_X86_64_CONSOLE),
+            ("aarch64 GUI", super::LAUNCHER_AARCH64_GUI),
+            ("aarch64 CLI", super::LAUNCHER_AARCH64_CONSOLE),
+        ] {
+            assert!(
+                launcher.len() < 45 * 1024,
+                "{name} launcher: {}",
+                launcher.len()
+            );
+        }
     }
 
     /// Utility script for the test.
@@ -438,8 +459,12 @@ if __name__ == "__main__":
             get_script_launcher(&format_shebang(&python_executable_path), false);
 
         // Generate Launcher Payload
-        let console_launcher =
-            windows_script_launcher(&launcher_console_script, false, &python_executable_path)?;
+        let console_launcher = windows_script_launcher(
+            &launcher_console_script,
+            false,
+            &python_executable_path,
+            LauncherOptions::default(),
+        )?;
 
         // Create Launcher
         File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
@@ -534,8 +559,12 @@ if __name__ == "__main__":
             get_script_launcher(&format_shebang(&pythonw_executable_path), true);
 
         // Generate Launcher Payload
-        let gui_launcher =
-            windows_script_launcher(&launcher_gui_script, true, &pythonw_executable_path)?;
+        let gui_launcher = windows_script_launcher(
+            &launcher_gui_script,
+            true,
+            &pythonw_executable_path,
+            LauncherOptions::default(),
+        )?;
 
         // Create Launcher
         File::create(gui_bin_path.path())?.write_all(gui_launcher.as_ref())?;
@@ -20,6 +20,12 @@ pub enum HashStrategy {
     None,
     /// Hashes should be generated (specifically, a SHA-256 hash), but not validated.
     Generate,
+    /// Hashes should be validated, if present, and generated (specifically, a SHA-256 hash) if
+    /// absent.
+    ///
+    /// Used when locking with hashes pinned in `tool.uv.sources`, such that pinned archives are
+    /// rejected at download time while every other archive is hashed for the lockfile.
+    GenerateVerify(Arc<FxHashMap<VersionId, Vec<HashDigest>>>),
     /// Hashes should be validated, if present, but ignored if absent.
     ///
     /// If necessary, hashes should be generated to ensure that the archive is valid.
@@ -36,6 +42,11 @@ impl HashStrategy {
         match self {
             Self::None => HashPolicy::None,
             Self::Generate => HashPolicy::Generate,
+            Self::GenerateVerify(hashes) => hashes
+                .get(&distribution.version_id())
+                .map_or(HashPolicy::Generate, |hashes| {
+                    HashPolicy::Validate(hashes.as_slice())
+                }),
             Self::Verify(hashes) => {
                 if let Some(hashes) = hashes.get(&distribution.version_id()) {
                     HashPolicy::Validate(hashes.as_slice())
@@ -57,6 +68,11 @@ impl HashStrategy {
         match self {
             Self::None => HashPolicy::None,
             Self::Generate => HashPolicy::Generate,
+            Self::GenerateVerify(hashes) => hashes
+                .get(&VersionId::from_registry(name.clone(), version.clone()))
+                .map_or(HashPolicy::Generate, |hashes| {
+                    HashPolicy::Validate(hashes.as_slice())
+                }),
             Self::Verify(hashes) => {
                 if let Some(hashes) =
                     hashes.get(&VersionId::from_registry(name.clone(), version.clone()))
@@ -80,6 +96,11 @@ impl HashStrategy {
         match self {
             Self::None => HashPolicy::None,
             Self::Generate => HashPolicy::Generate,
+            Self::GenerateVerify(hashes) => hashes
+                .get(&VersionId::from_url(url))
+                .map_or(HashPolicy::Generate, |hashes| {
+                    HashPolicy::Validate(hashes.as_slice())
+                }),
             Self::Verify(hashes) => {
                 if let Some(hashes) = hashes.get(&VersionId::from_url(url)) {
                     HashPolicy::Validate(hashes.as_slice())
@@ -101,6 +122,7 @@ impl HashStrategy {
         match self {
             Self::None => true,
             Self::Generate => true,
+            Self::GenerateVerify(_) => true,
             Self::Verify(_) => true,
             Self::Require(hashes) => {
                 hashes.contains_key(&VersionId::from_registry(name.clone(), version.clone()))
@@ -113,6 +135,7 @@ impl HashStrategy {
         match self {
             Self::None => true,
             Self::Generate => true,
+            Self::GenerateVerify(_) => true,
             Self::Verify(_) => true,
             Self::Require(hashes) => hashes.contains_key(&VersionId::from_url(url)),
         }
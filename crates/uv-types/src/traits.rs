@@ -13,6 +13,7 @@ use uv_distribution_types::{
 };
 use uv_git::GitResolver;
 use uv_pep508::PackageName;
+use uv_platform_tags::TagPreferences;
 use uv_pypi_types::Requirement;
 use uv_python::{Interpreter, PythonEnvironment};
 
@@ -59,6 +60,9 @@ pub trait BuildContext {
     /// Return a reference to the interpreter.
     fn interpreter(&self) -> &Interpreter;
 
+    /// Return the preferences used to prioritize the interpreter's platform tags.
+    fn tag_preferences(&self) -> &TagPreferences;
+
     /// Return a reference to the cache.
     fn cache(&self) -> &Cache;
 
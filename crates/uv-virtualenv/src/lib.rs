@@ -71,3 +71,9 @@ pub fn create_venv(
     let interpreter = interpreter.with_virtualenv(virtualenv);
     Ok(PythonEnvironment::from_interpreter(interpreter))
 }
+
+/// Re-point an existing virtual environment to a different base interpreter of the same minor
+/// version, e.g., after its original base interpreter was uninstalled.
+pub fn repoint_venv(location: &Path, interpreter: &Interpreter) -> Result<(), Error> {
+    virtualenv::repoint(location, interpreter)
+}
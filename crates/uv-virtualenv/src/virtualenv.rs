@@ -3,7 +3,7 @@
 use std::env::consts::EXE_SUFFIX;
 use std::io;
 use std::io::{BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use fs_err as fs;
 use fs_err::File;
@@ -42,6 +42,80 @@ fn write_cfg(f: &mut impl Write, data: &[(String, String)]) -> io::Result<()> {
     Ok(())
 }
 
+/// Determine the base Python executable; that is, the Python executable that should be
+/// considered the "base" for the virtual environment. This is typically the Python executable
+/// from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
+/// the base Python executable is the Python executable of the interpreter's base interpreter.
+fn base_python(interpreter: &Interpreter) -> Result<PathBuf, Error> {
+    if cfg!(unix) && interpreter.is_standalone() {
+        // In `python-build-standalone`, a symlinked interpreter will return its own executable path
+        // as `sys._base_executable`. Using the symlinked path as the base Python executable is
+        // incorrect,  since it will cause `home` to point to something that is _not_ a Python
+        // installation.
+        //
+        // Instead, we want to fully resolve the symlink to the actual Python executable.
+        Ok(uv_fs::canonicalize_executable(
+            interpreter.sys_executable(),
+        )?)
+    } else {
+        Ok(std::path::absolute(
+            interpreter
+                .sys_base_executable()
+                .unwrap_or(interpreter.sys_executable()),
+        )?)
+    }
+}
+
+/// Re-point the [`VirtualEnvironment`] at the given location to a different base interpreter.
+///
+/// The `home` and `version_info` keys in the `pyvenv.cfg` are updated, and, on Unix, the `python`
+/// executable is re-linked to the new base interpreter. The interpreter is expected to share a
+/// minor version with the environment's original base interpreter, such that the layout of the
+/// environment (e.g., its `site-packages` directory) is unchanged.
+pub(crate) fn repoint(location: &Path, interpreter: &Interpreter) -> Result<(), Error> {
+    let base_python = base_python(interpreter)?;
+    let python_home = base_python.parent().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "The Python interpreter needs to have a parent directory",
+        )
+    })?;
+
+    // Update the `pyvenv.cfg`, preserving any other keys.
+    let pyvenv_cfg_path = location.join("pyvenv.cfg");
+    let pyvenv_cfg_data = fs::read_to_string(&pyvenv_cfg_path)?
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| {
+            let key = key.trim();
+            let value = match key {
+                "home" => python_home.simplified_display().to_string(),
+                "version_info" => interpreter.markers().python_full_version().string.clone(),
+                "venvlauncher_command" => python_home
+                    .join("graalpy.exe")
+                    .simplified_display()
+                    .to_string(),
+                _ => value.trim().to_string(),
+            };
+            (key.to_string(), value)
+        })
+        .collect::<Vec<_>>();
+
+    let mut pyvenv_cfg = BufWriter::new(File::create(&pyvenv_cfg_path)?);
+    write_cfg(&mut pyvenv_cfg, &pyvenv_cfg_data)?;
+    drop(pyvenv_cfg);
+
+    // Re-link the interpreter. The other executables (e.g., `python3`) are relative links to
+    // `python`. On Windows, the launchers read the `home` key from the `pyvenv.cfg` instead.
+    #[cfg(unix)]
+    {
+        let scripts = location.join(&interpreter.virtualenv().scripts);
+        uv_fs::replace_symlink(&base_python, scripts.join(format!("python{EXE_SUFFIX}")))?;
+    }
+
+    Ok(())
+}
+
 /// Create a [`VirtualEnvironment`] at the given location.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) fn create(
@@ -53,25 +127,7 @@ pub(crate) fn create(
     relocatable: bool,
     seed: bool,
 ) -> Result<VirtualEnvironment, Error> {
-    // Determine the base Python executable; that is, the Python executable that should be
-    // considered the "base" for the virtual environment. This is typically the Python executable
-    // from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
-    // the base Python executable is the Python executable of the interpreter's base interpreter.
-    let base_python = if cfg!(unix) && interpreter.is_standalone() {
-        // In `python-build-standalone`, a symlinked interpreter will return its own executable path
-        // as `sys._base_executable`. Using the symlinked path as the base Python executable is
-        // incorrect,  since it will cause `home` to point to something that is _not_ a Python
-        // installation.
-        //
-        // Instead, we want to fully resolve the symlink to the actual Python executable.
-        uv_fs::canonicalize_executable(interpreter.sys_executable())?
-    } else {
-        std::path::absolute(
-            interpreter
-                .sys_base_executable()
-                .unwrap_or(interpreter.sys_executable()),
-        )?
-    };
+    let base_python = base_python(interpreter)?;
 
     // Validate the existing location.
     match location.metadata() {
@@ -1,14 +1,16 @@
 use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use thiserror::Error;
 use tracing::warn;
 
-use uv_normalize::GroupName;
+use uv_normalize::{GroupName, PackageName};
 use uv_pep508::Pep508Error;
 use uv_pypi_types::VerbatimParsedUrl;
 
-use crate::pyproject::DependencyGroupSpecifier;
+use crate::pyproject::{DependencyGroupSpecifier, IncludeGroupSource};
+use crate::Workspace;
 
 /// PEP 735 dependency groups, with any `include-group` entries resolved.
 #[derive(Debug, Clone)]
@@ -19,80 +21,50 @@ pub struct FlatDependencyGroups(
 impl FlatDependencyGroups {
     /// Resolve the dependency groups (which may contain references to other groups) into concrete
     /// lists of requirements.
+    ///
+    /// Groups may only include other groups from the same `pyproject.toml`; to include groups from
+    /// other workspace members, use [`FlatDependencyGroups::from_workspace`].
     pub fn from_dependency_groups(
         groups: &BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
     ) -> Result<Self, DependencyGroupError> {
-        fn resolve_group<'data>(
-            resolved: &mut BTreeMap<GroupName, Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
-            groups: &'data BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
-            name: &'data GroupName,
-            parents: &mut Vec<&'data GroupName>,
-        ) -> Result<(), DependencyGroupError> {
-            let Some(specifiers) = groups.get(name) else {
-                // Missing group
-                let parent_name = parents
-                    .iter()
-                    .last()
-                    .copied()
-                    .expect("parent when group is missing");
-                return Err(DependencyGroupError::GroupNotFound(
-                    name.clone(),
-                    parent_name.clone(),
-                ));
-            };
-
-            // "Dependency Group Includes MUST NOT include cycles, and tools SHOULD report an error if they detect a cycle."
-            if parents.contains(&name) {
-                return Err(DependencyGroupError::DependencyGroupCycle(Cycle(
-                    parents.iter().copied().cloned().collect(),
-                )));
-            }
-
-            // If we already resolved this group, short-circuit.
-            if resolved.contains_key(name) {
-                return Ok(());
-            }
-
-            parents.push(name);
-            let mut requirements = Vec::with_capacity(specifiers.len());
-            for specifier in *specifiers {
-                match specifier {
-                    DependencyGroupSpecifier::Requirement(requirement) => {
-                        match uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement) {
-                            Ok(requirement) => requirements.push(requirement),
-                            Err(err) => {
-                                return Err(DependencyGroupError::GroupParseError(
-                                    name.clone(),
-                                    requirement.clone(),
-                                    Box::new(err),
-                                ));
-                            }
-                        }
-                    }
-                    DependencyGroupSpecifier::IncludeGroup { include_group } => {
-                        resolve_group(resolved, groups, include_group, parents)?;
-                        requirements
-                            .extend(resolved.get(include_group).into_iter().flatten().cloned());
-                    }
-                    DependencyGroupSpecifier::Object(map) => {
-                        warn!(
-                            "Ignoring Dependency Object Specifier referenced by `{name}`: {map:?}"
-                        );
-                    }
-                }
-            }
-            parents.pop();
+        Self::resolve(groups, None)
+    }
 
-            resolved.insert(name.clone(), requirements);
-            Ok(())
-        }
+    /// Resolve the dependency groups of the `pyproject.toml` in the given directory, which must be
+    /// the workspace root or a workspace member, into concrete lists of requirements.
+    ///
+    /// Groups may include groups from other workspace members (`package = "..."`) or from the
+    /// workspace root (`workspace = true`).
+    pub fn from_workspace(
+        groups: &BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
+        root: &Path,
+        workspace: &Workspace,
+    ) -> Result<Self, DependencyGroupError> {
+        Self::resolve(groups, Some((root, workspace)))
+    }
 
+    fn resolve(
+        groups: &BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
+        workspace: Option<(&Path, &Workspace)>,
+    ) -> Result<Self, DependencyGroupError> {
+        let resolver = Resolver { groups, workspace };
         let mut resolved = BTreeMap::new();
         for name in groups.keys() {
+            let group = GroupRef {
+                owner: None,
+                name: (*name).clone(),
+            };
             let mut parents = Vec::new();
-            resolve_group(&mut resolved, groups, name, &mut parents)?;
+            resolver.resolve_group(&mut resolved, &group, &mut parents)?;
         }
-        Ok(Self(resolved))
+        Ok(Self(
+            resolved
+                .into_iter()
+                .filter_map(|(group, requirements)| {
+                    group.owner.is_none().then_some((group.name, requirements))
+                })
+                .collect(),
+        ))
     }
 
     /// Return the requirements for a given group, if any.
@@ -116,6 +88,199 @@ impl IntoIterator for FlatDependencyGroups {
     }
 }
 
+/// A dependency group, along with the `pyproject.toml` that defines it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GroupRef {
+    /// The workspace member (or workspace root) that defines the group, or `None` for the
+    /// `pyproject.toml` whose groups are being resolved.
+    owner: Option<GroupOwner>,
+    /// The name of the group.
+    name: GroupName,
+}
+
+impl std::fmt::Display for GroupRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.owner {
+            None => write!(f, "`{}`", self.name),
+            Some(owner) => write!(f, "`{}` (from {owner})", self.name),
+        }
+    }
+}
+
+/// The `pyproject.toml` that defines a dependency group, other than the one being resolved.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct GroupOwner {
+    /// The directory containing the `pyproject.toml`.
+    root: PathBuf,
+    /// The name of the workspace member, if the `pyproject.toml` defines a project.
+    package: Option<PackageName>,
+}
+
+impl std::fmt::Display for GroupOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.package {
+            Some(package) => write!(f, "`{package}`"),
+            None => write!(f, "the workspace root"),
+        }
+    }
+}
+
+type ResolvedGroups = BTreeMap<GroupRef, Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>;
+
+/// Resolves `include-group` entries, across workspace members if a workspace is available.
+struct Resolver<'a> {
+    /// The groups of the `pyproject.toml` being resolved.
+    groups: &'a BTreeMap<&'a GroupName, &'a Vec<DependencyGroupSpecifier>>,
+    /// The directory of the `pyproject.toml` being resolved, and the workspace that contains it.
+    workspace: Option<(&'a Path, &'a Workspace)>,
+}
+
+impl<'a> Resolver<'a> {
+    fn resolve_group(
+        &self,
+        resolved: &mut ResolvedGroups,
+        group: &GroupRef,
+        parents: &mut Vec<GroupRef>,
+    ) -> Result<(), DependencyGroupError> {
+        let Some(specifiers) = self.specifiers(group) else {
+            // Missing group
+            let parent = parents
+                .last()
+                .cloned()
+                .expect("parent when group is missing");
+            return Err(match &group.owner {
+                None => DependencyGroupError::GroupNotFound(group.name.clone(), parent.name),
+                Some(_) => DependencyGroupError::IncludedGroupNotFound(group.clone(), parent),
+            });
+        };
+
+        // "Dependency Group Includes MUST NOT include cycles, and tools SHOULD report an error if they detect a cycle."
+        if parents.contains(group) {
+            return Err(DependencyGroupError::DependencyGroupCycle(Cycle(
+                parents.clone(),
+            )));
+        }
+
+        // If we already resolved this group, short-circuit.
+        if resolved.contains_key(group) {
+            return Ok(());
+        }
+
+        parents.push(group.clone());
+        let mut requirements = Vec::with_capacity(specifiers.len());
+        for specifier in specifiers {
+            match specifier {
+                DependencyGroupSpecifier::Requirement(requirement) => {
+                    match uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement) {
+                        Ok(requirement) => {
+                            // Omit exact duplicates, e.g., from groups that are included twice.
+                            if !requirements.contains(&requirement) {
+                                requirements.push(requirement);
+                            }
+                        }
+                        Err(err) => {
+                            return Err(DependencyGroupError::GroupParseError(
+                                group.name.clone(),
+                                requirement.clone(),
+                                Box::new(err),
+                            ));
+                        }
+                    }
+                }
+                DependencyGroupSpecifier::IncludeGroup {
+                    include_group,
+                    from,
+                } => {
+                    let owner = match from {
+                        None => group.owner.clone(),
+                        Some(from) => self.owner(from, &group.name)?,
+                    };
+                    let include = GroupRef {
+                        owner,
+                        name: include_group.clone(),
+                    };
+                    self.resolve_group(resolved, &include, parents)?;
+                    for requirement in resolved.get(&include).into_iter().flatten() {
+                        if !requirements.contains(requirement) {
+                            requirements.push(requirement.clone());
+                        }
+                    }
+                }
+                DependencyGroupSpecifier::Object(map) => {
+                    warn!(
+                        "Ignoring Dependency Object Specifier referenced by `{}`: {map:?}",
+                        group.name
+                    );
+                }
+            }
+        }
+        parents.pop();
+
+        resolved.insert(group.clone(), requirements);
+        Ok(())
+    }
+
+    /// Return the specifiers of the given group, if it exists.
+    fn specifiers(&self, group: &GroupRef) -> Option<&'a Vec<DependencyGroupSpecifier>> {
+        match &group.owner {
+            None => self.groups.get(&group.name).copied(),
+            Some(owner) => {
+                let (_, workspace) = self.workspace?;
+                let pyproject_toml = if owner.root == *workspace.install_path() {
+                    workspace.pyproject_toml()
+                } else {
+                    workspace
+                        .packages()
+                        .values()
+                        .find(|member| member.root() == &owner.root)?
+                        .pyproject_toml()
+                };
+                pyproject_toml.dependency_groups.as_ref()?.get(&group.name)
+            }
+        }
+    }
+
+    /// Return the owner of the groups referenced by an `include-group` entry in the given group.
+    ///
+    /// Returns `None` if the entry refers to the `pyproject.toml` being resolved.
+    fn owner(
+        &self,
+        from: &IncludeGroupSource,
+        parent: &GroupName,
+    ) -> Result<Option<GroupOwner>, DependencyGroupError> {
+        let Some((root, workspace)) = self.workspace else {
+            return Err(DependencyGroupError::WorkspaceRequired(parent.clone()));
+        };
+        let owner = match from {
+            IncludeGroupSource::Package(package) => {
+                let Some(member) = workspace.packages().get(package) else {
+                    return Err(DependencyGroupError::MemberNotFound(
+                        package.clone(),
+                        parent.clone(),
+                    ));
+                };
+                GroupOwner {
+                    root: member.root().clone(),
+                    package: Some(package.clone()),
+                }
+            }
+            IncludeGroupSource::Workspace => GroupOwner {
+                root: workspace.install_path().clone(),
+                package: workspace
+                    .pyproject_toml()
+                    .project
+                    .as_ref()
+                    .map(|project| project.name.clone()),
+            },
+        };
+        if owner.root == root {
+            Ok(None)
+        } else {
+            Ok(Some(owner))
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum DependencyGroupError {
     #[error("Failed to parse entry in group `{0}`: `{1}`")]
@@ -126,13 +291,19 @@ pub enum DependencyGroupError {
     ),
     #[error("Failed to find group `{0}` included by `{1}`")]
     GroupNotFound(GroupName, GroupName),
+    #[error("Failed to find group {0} included by {1}")]
+    IncludedGroupNotFound(GroupRef, GroupRef),
+    #[error("Failed to find workspace member `{0}` referenced by group `{1}`")]
+    MemberNotFound(PackageName, GroupName),
+    #[error("Group `{0}` includes a group from another `pyproject.toml`, which is only supported within a workspace")]
+    WorkspaceRequired(GroupName),
     #[error("Detected a cycle in `dependency-groups`: {0}")]
     DependencyGroupCycle(Cycle),
 }
 
 /// A cycle in the `dependency-groups` table.
 #[derive(Debug)]
-pub struct Cycle(Vec<GroupName>);
+pub struct Cycle(Vec<GroupRef>);
 
 /// Display a cycle, e.g., `a -> b -> c -> a`.
 impl std::fmt::Display for Cycle {
@@ -140,11 +311,11 @@ impl std::fmt::Display for Cycle {
         let [first, rest @ ..] = self.0.as_slice() else {
             return Ok(());
         };
-        write!(f, "`{first}`")?;
+        write!(f, "{first}")?;
         for group in rest {
-            write!(f, " -> `{group}`")?;
+            write!(f, " -> {group}")?;
         }
-        write!(f, " -> `{first}`")?;
+        write!(f, " -> {first}")?;
         Ok(())
     }
 }
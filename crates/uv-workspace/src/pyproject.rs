@@ -19,14 +19,14 @@ use url::Url;
 
 use uv_distribution_types::{Index, IndexName};
 use uv_fs::{relative_to, PortablePathBuf};
-use uv_git::GitReference;
+use uv_git::{GitReference, VcsBackend};
 use uv_macros::OptionsMetadata;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{
-    ConflictingGroupList, RequirementSource, SchemaConflictingGroupList, SupportedEnvironments,
-    VerbatimParsedUrl,
+    ConflictingGroupList, HashAlgorithm, HashDigest, RequirementSource, SchemaConflictingGroupList,
+    SupportedEnvironments, VerbatimParsedUrl,
 };
 
 #[derive(Error, Debug)]
@@ -146,11 +146,29 @@ pub enum DependencyGroupSpecifier {
     IncludeGroup {
         /// The name of the group to include.
         include_group: GroupName,
+        /// The `pyproject.toml` that defines the group, if not the current one.
+        from: Option<IncludeGroupSource>,
     },
     /// A Dependency Object Specifier.
     Object(BTreeMap<String, String>),
 }
 
+/// The `pyproject.toml` from which an `include-group` entry includes a group, as in:
+///
+/// ```toml
+/// [dependency-groups]
+/// test = [{ include-group = "dev", workspace = true }]
+/// lint = [{ include-group = "lint", package = "albatross" }]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+pub enum IncludeGroupSource {
+    /// Include the group from the workspace member with the given name (`package = "..."`).
+    Package(PackageName),
+    /// Include the group from the workspace root (`workspace = true`).
+    Workspace,
+}
+
 impl<'de> Deserialize<'de> for DependencyGroupSpecifier {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -176,12 +194,31 @@ impl<'de> Deserialize<'de> for DependencyGroupSpecifier {
             where
                 M: serde::de::MapAccess<'de>,
             {
+                /// A value in a Dependency Object Specifier.
+                #[derive(Deserialize)]
+                #[serde(untagged)]
+                enum Value {
+                    String(String),
+                    Bool(bool),
+                }
+
                 let mut map_data = BTreeMap::new();
-                while let Some((key, value)) = map.next_entry()? {
-                    map_data.insert(key, value);
+                let mut workspace = false;
+                while let Some((key, value)) = map.next_entry::<String, Value>()? {
+                    match value {
+                        Value::String(value) => {
+                            map_data.insert(key, value);
+                        }
+                        Value::Bool(value) if key == "workspace" => {
+                            workspace = value;
+                        }
+                        Value::Bool(value) => {
+                            map_data.insert(key, value.to_string());
+                        }
+                    }
                 }
 
-                if map_data.is_empty() {
+                if map_data.is_empty() && !workspace {
                     return Err(serde::de::Error::custom("missing field `include-group`"));
                 }
 
@@ -192,8 +229,30 @@ impl<'de> Deserialize<'de> for DependencyGroupSpecifier {
                     .transpose()
                     .map_err(serde::de::Error::custom)?
                 {
-                    Ok(DependencyGroupSpecifier::IncludeGroup { include_group })
+                    let package = map_data
+                        .get("package")
+                        .map(String::as_str)
+                        .map(PackageName::from_str)
+                        .transpose()
+                        .map_err(serde::de::Error::custom)?;
+                    let from = match (package, workspace) {
+                        (None, false) => None,
+                        (Some(package), false) => Some(IncludeGroupSource::Package(package)),
+                        (None, true) => Some(IncludeGroupSource::Workspace),
+                        (Some(_), true) => {
+                            return Err(serde::de::Error::custom(
+                                "`include-group` entries can't set both `package` and `workspace`",
+                            ));
+                        }
+                    };
+                    Ok(DependencyGroupSpecifier::IncludeGroup {
+                        include_group,
+                        from,
+                    })
                 } else {
+                    if workspace {
+                        map_data.insert("workspace".to_string(), "true".to_string());
+                    }
                     Ok(DependencyGroupSpecifier::Object(map_data))
                 }
             }
@@ -249,6 +308,9 @@ pub struct ToolUv {
     /// during development. A dependency source can be a Git repository, a URL, a local path, or an
     /// alternative registry.
     ///
+    /// Keys may also be glob patterns over package names (e.g., `"mycorp-*"`), to pin every
+    /// matching package to an index.
+    ///
     /// See [Dependencies](../concepts/dependencies.md) for more.
     #[option(
         default = "{}",
@@ -300,6 +362,74 @@ pub struct ToolUv {
     )]
     pub index: Option<Vec<Index>>,
 
+    /// The hardware variants to select for packages that publish accelerator-specific builds
+    /// (e.g., CUDA, ROCm, or CPU-only builds of PyTorch).
+    ///
+    /// Variants are typically published as local versions (e.g., `2.5.1+cu124`). When a variant is
+    /// selected for a package, uv will prefer versions with a matching local version label (even
+    /// over newer versions without a label), and will ignore versions with a different label.
+    /// Versions without a local version label remain eligible, as a fallback.
+    ///
+    /// If the project defines an index with the same name as the variant, and the package isn't
+    /// otherwise pinned to a source via `tool.uv.sources`, the package will be sourced from that
+    /// index exclusively, as in:
+    ///
+    /// ```toml
+    /// [tool.uv.variants]
+    /// torch = "cu124"
+    ///
+    /// [[tool.uv.index]]
+    /// name = "cu124"
+    /// url = "https://download.pytorch.org/whl/cu124"
+    /// explicit = true
+    /// ```
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `variants` from the
+    ///     `pyproject.toml` at the workspace root, and will ignore any declarations in other
+    ///     workspace members.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [tool.uv.variants]
+            torch = "cu124"
+            torchvision = "cu124"
+        "#
+    )]
+    pub variants: Option<BTreeMap<PackageName, String>>,
+
+    /// Dependencies to install alongside a conflicting version of the same package, under a
+    /// different top-level module name.
+    ///
+    /// When a dependency can't be unified across the workspace (e.g., one package requires
+    /// `six<1.11` while another requires `six>=1.16`), uv can resolve a separate copy of the
+    /// dependency for the listed `dependents`, and install that copy under the given `alias`.
+    /// During installation, uv rewrites the imports in the vendored copy and in each of the
+    /// dependents (e.g., `import six` becomes `import six_legacy as six`), such that the
+    /// dependents use the vendored copy while the rest of the environment uses the version
+    /// selected by the main resolution.
+    ///
+    /// Vendored dependencies are resolved without their own dependencies, and must consist of a
+    /// single, pure-Python top-level module or package.
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `vendor` from the
+    ///     `pyproject.toml` at the workspace root, and will ignore any declarations in other
+    ///     workspace members.
+    #[option(
+        default = "[]",
+        value_type = "list[dict]",
+        example = r#"
+            [[tool.uv.vendor]]
+            name = "six"
+            version = "<1.11"
+            alias = "six_legacy"
+            dependents = ["legacy-lib"]
+        "#
+    )]
+    pub vendor: Option<Vec<VendoredDependency>>,
+
     /// The workspace definition for the project, if any.
     #[option_group]
     pub workspace: Option<ToolUvWorkspace>,
@@ -334,6 +464,27 @@ pub struct ToolUv {
     )]
     pub package: Option<bool>,
 
+    /// Whether to rebuild the project's compiled extension modules when they're imported after
+    /// their sources have changed.
+    ///
+    /// When enabled, `uv sync` installs an import hook alongside the editable install of the
+    /// project. On the first import of the project's modules, the hook compares the modification
+    /// times of the native sources (e.g., `.c`, `.cpp`, `.pyx`, or `.rs` files, along with build
+    /// configuration such as `Cargo.toml` or `CMakeLists.txt`) against the time of the last build,
+    /// and, if any source is newer, reinstalls the project through the build backend before the
+    /// import proceeds.
+    ///
+    /// Only applies to projects that are installed in editable mode and contain native sources.
+    /// Set `UV_NO_REBUILD_ON_IMPORT` to disable the hook at runtime.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            rebuild-on-import = true
+        "#
+    )]
+    pub rebuild_on_import: Option<bool>,
+
     /// The list of `dependency-groups` to install by default.
     #[option(
         default = r#"["dev"]"#,
@@ -344,6 +495,76 @@ pub struct ToolUv {
     )]
     pub default_groups: Option<Vec<GroupName>>,
 
+    /// The resolution strategy to use for the direct dependencies of each dependency group.
+    ///
+    /// By default, all packages are resolved with the strategy provided via `--resolution`. This
+    /// setting allows a group to use a different strategy within the same lockfile, e.g., to
+    /// resolve a `test-lowest` group with `lowest-direct` to validate the lower bounds of the
+    /// project's dependencies, while the remaining groups continue to use `highest`.
+    ///
+    /// The strategy applies to the packages that are declared directly in the group, and takes
+    /// precedence over the default strategy for those packages throughout the lockfile, since the
+    /// lockfile contains a single version of each package per resolution fork. If groups with
+    /// different strategies declare the same package, uv will fall back to the default strategy
+    /// for that package.
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `group-resolution` from the
+    ///     `pyproject.toml` at the workspace root, and will ignore any declarations in other
+    ///     workspace members.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [tool.uv.group-resolution]
+            test-lowest = "lowest-direct"
+        "#
+    )]
+    pub group_resolution: Option<BTreeMap<GroupName, GroupResolutionMode>>,
+
+    /// Dependencies to consider used by `uv remove --unused`, even if they're never imported by
+    /// the project's sources.
+    ///
+    /// `uv remove --unused` treats a dependency as unused if none of the modules it provides are
+    /// imported by the project. Some dependencies are needed without ever being imported, e.g.,
+    /// plugins that are discovered via entry points, command-line tools, or database drivers that
+    /// are loaded by name; list them here to exclude them from the analysis.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            ignore-unused-dependencies = ["uvicorn", "psycopg2-binary"]
+        "#
+    )]
+    pub ignore_unused_dependencies: Option<Vec<PackageName>>,
+
+    /// Checks for dependencies that are no longer maintained, to run after `uv lock`.
+    ///
+    /// A dependency is flagged if its index marks the project as archived, deprecated, or
+    /// quarantined (via the [PEP 792](https://peps.python.org/pep-0792/) project status), or if the
+    /// project hasn't published a release in `stale-after` days (730 by default).
+    ///
+    /// The `policy` determines whether flagged dependencies are reported as warnings (`warn`, the
+    /// default) or cause `uv lock` to fail (`fail`). Packages listed in `ignore` are never flagged.
+    ///
+    /// Regardless of this setting, `uv tree --outdated` annotates flagged dependencies.
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `maintenance` from the
+    ///     `pyproject.toml` at the workspace root, and will ignore any declarations in other
+    ///     workspace members.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [tool.uv.maintenance]
+            policy = "fail"
+            stale-after = 365
+            ignore = ["six"]
+        "#
+    )]
+    pub maintenance: Option<ToolUvMaintenance>,
+
     /// The project's development dependencies.
     ///
     /// Development dependencies will be installed by default in `uv run` and `uv sync`, but will
@@ -444,16 +665,21 @@ pub struct ToolUv {
     ///
     /// These environments will also respected when `uv pip compile` is invoked with the
     /// `--universal` flag.
+    ///
+    /// Environments may also be declared as a table of named environments, each defined by a
+    /// marker or by a set of marker values (e.g., a complete marker environment for a target like
+    /// Pyodide or an embedded platform). Named environments can be selected with
+    /// `uv lock --environment` and `uv export --environment`.
     #[cfg_attr(
         feature = "schemars",
         schemars(
-            with = "Option<Vec<String>>",
-            description = "A list of environment markers, e.g., `python_version >= '3.6'`."
+            with = "Option<EnvironmentsWire>",
+            description = "A list of environment markers, e.g., `python_version >= '3.6'`, or a table of named environments."
         )
     )]
     #[option(
         default = "[]",
-        value_type = "str | list[str]",
+        value_type = "str | list[str] | dict[str, str | dict[str, str]]",
         example = r#"
             # Resolve for macOS, but not for Linux or Windows.
             environments = ["sys_platform == 'darwin'"]
@@ -507,21 +733,59 @@ pub struct ToolUv {
     pub conflicting_groups: Option<SchemaConflictingGroupList>,
 }
 
+/// The `tool.uv.sources` table.
+///
+/// Keys are either package names or glob patterns over package names (e.g., `mycorp-*`). Patterns
+/// may only pin packages to an index.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Serialize))]
-#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
-pub struct ToolUvSources(BTreeMap<PackageName, Sources>);
+pub struct ToolUvSources {
+    /// The sources for individual packages.
+    #[cfg_attr(test, serde(flatten))]
+    packages: BTreeMap<PackageName, Sources>,
+    /// The sources for packages that match a pattern.
+    #[cfg_attr(test, serde(flatten))]
+    patterns: BTreeMap<SourcePattern, Sources>,
+}
 
 impl ToolUvSources {
     /// Returns the underlying `BTreeMap` of package names to sources.
+    ///
+    /// Does not include the sources for patterns.
     pub fn inner(&self) -> &BTreeMap<PackageName, Sources> {
-        &self.0
+        &self.packages
     }
 
     /// Convert the [`ToolUvSources`] into its inner `BTreeMap`.
+    ///
+    /// Does not include the sources for patterns.
     #[must_use]
     pub fn into_inner(self) -> BTreeMap<PackageName, Sources> {
-        self.0
+        self.packages
+    }
+
+    /// Returns the sources for the given package name, if any.
+    pub fn get(&self, name: &PackageName) -> Option<&Sources> {
+        self.packages.get(name)
+    }
+
+    /// Returns the most specific pattern (i.e., the longest) that matches the given package name,
+    /// along with its sources.
+    pub fn get_pattern(&self, name: &PackageName) -> Option<(&SourcePattern, &Sources)> {
+        self.patterns
+            .iter()
+            .filter(|(pattern, _)| pattern.matches(name))
+            .max_by_key(|(pattern, _)| pattern.as_str().len())
+    }
+
+    /// Returns an iterator over the patterns and their sources.
+    pub fn patterns(&self) -> impl Iterator<Item = (&SourcePattern, &Sources)> {
+        self.patterns.iter()
+    }
+
+    /// Returns `true` if there are no sources.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty() && self.patterns.is_empty()
     }
 }
 
@@ -544,21 +808,47 @@ impl<'de> serde::de::Deserialize<'de> for ToolUvSources {
             where
                 M: serde::de::MapAccess<'de>,
             {
-                let mut sources = BTreeMap::new();
-                while let Some((key, value)) = access.next_entry::<PackageName, Sources>()? {
-                    match sources.entry(key) {
-                        std::collections::btree_map::Entry::Occupied(entry) => {
+                let mut packages = BTreeMap::new();
+                let mut patterns = BTreeMap::new();
+                while let Some((key, value)) = access.next_entry::<String, Sources>()? {
+                    if SourcePattern::is_pattern(&key) {
+                        let pattern =
+                            SourcePattern::from_str(&key).map_err(serde::de::Error::custom)?;
+                        if !value
+                            .iter()
+                            .all(|source| matches!(source, Source::Registry { .. }))
+                        {
                             return Err(serde::de::Error::custom(format!(
-                                "duplicate sources for package `{}`",
-                                entry.key()
+                                "sources for pattern `{pattern}` must be `index` sources"
                             )));
                         }
-                        std::collections::btree_map::Entry::Vacant(entry) => {
-                            entry.insert(value);
+                        match patterns.entry(pattern) {
+                            std::collections::btree_map::Entry::Occupied(entry) => {
+                                return Err(serde::de::Error::custom(format!(
+                                    "duplicate sources for pattern `{}`",
+                                    entry.key()
+                                )));
+                            }
+                            std::collections::btree_map::Entry::Vacant(entry) => {
+                                entry.insert(value);
+                            }
+                        }
+                    } else {
+                        let name = PackageName::from_str(&key).map_err(serde::de::Error::custom)?;
+                        match packages.entry(name) {
+                            std::collections::btree_map::Entry::Occupied(entry) => {
+                                return Err(serde::de::Error::custom(format!(
+                                    "duplicate sources for package `{}`",
+                                    entry.key()
+                                )));
+                            }
+                            std::collections::btree_map::Entry::Vacant(entry) => {
+                                entry.insert(value);
+                            }
                         }
                     }
                 }
-                Ok(ToolUvSources(sources))
+                Ok(ToolUvSources { packages, patterns })
             }
         }
 
@@ -566,6 +856,178 @@ impl<'de> serde::de::Deserialize<'de> for ToolUvSources {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ToolUvSources {
+    fn schema_name() -> String {
+        "ToolUvSources".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <BTreeMap<String, Sources> as schemars::JsonSchema>::json_schema(gen)
+    }
+}
+
+/// A glob pattern over normalized package names, as used in `tool.uv.sources` (e.g.,
+/// `mycorp-*`).
+///
+/// Supports the `*` and `?` wildcards. Like package names, patterns are matched
+/// case-insensitively, and treat runs of `-`, `_`, and `.` as equivalent.
+#[derive(Debug, Clone)]
+pub struct SourcePattern {
+    /// The normalized pattern.
+    source: String,
+    /// The compiled pattern.
+    pattern: Pattern,
+}
+
+impl SourcePattern {
+    /// Returns `true` if the given `tool.uv.sources` key is a pattern, rather than a package name.
+    fn is_pattern(key: &str) -> bool {
+        key.contains(['*', '?'])
+    }
+
+    /// Returns `true` if the pattern matches the given package name.
+    pub fn matches(&self, name: &PackageName) -> bool {
+        self.pattern.matches(name.as_ref())
+    }
+
+    /// Returns the normalized pattern.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+}
+
+impl FromStr for SourcePattern {
+    type Err = SourcePatternError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        let mut source = String::with_capacity(pattern.len());
+        let mut last = None;
+        for char in pattern.chars() {
+            match char {
+                'A'..='Z' => source.push(char.to_ascii_lowercase()),
+                'a'..='z' | '0'..='9' | '*' | '?' => source.push(char),
+                '-' | '_' | '.' => {
+                    if !matches!(last, Some('-' | '_' | '.')) {
+                        source.push('-');
+                    }
+                }
+                _ => return Err(SourcePatternError(pattern.to_string())),
+            }
+            last = Some(char);
+        }
+        let compiled =
+            Pattern::new(&source).map_err(|_| SourcePatternError(pattern.to_string()))?;
+        Ok(Self {
+            source,
+            pattern: compiled,
+        })
+    }
+}
+
+impl std::fmt::Display for SourcePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl PartialEq for SourcePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for SourcePattern {}
+
+impl PartialOrd for SourcePattern {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SourcePattern {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.source.cmp(&other.source)
+    }
+}
+
+#[cfg(test)]
+impl Serialize for SourcePattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Not a valid package name pattern: `{0}`. Patterns may only contain letters, numbers, `-`, `_`, `.`, and the `*` and `?` wildcards.")]
+pub struct SourcePatternError(String);
+
+/// A dependency that's installed under a different top-level module name, for use by a specific
+/// set of dependents.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct VendoredDependency {
+    /// The name of the package to vendor (e.g., `six`).
+    pub name: PackageName,
+    /// The version specifiers for the vendored copy (e.g., `<1.11`).
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub version: VersionSpecifiers,
+    /// The top-level module name under which to install the vendored copy (e.g., `six_legacy`).
+    pub alias: String,
+    /// The packages that should use the vendored copy in lieu of the version selected by the
+    /// main resolution.
+    pub dependents: Vec<PackageName>,
+}
+
+/// The checks to perform for dependencies that are no longer maintained.
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ToolUvMaintenance {
+    /// Whether to warn about unmaintained dependencies, or to fail.
+    #[serde(default)]
+    pub policy: MaintenancePolicy,
+    /// The number of days without a release after which a dependency is considered unmaintained.
+    pub stale_after: Option<u64>,
+    /// The packages to exclude from the checks.
+    #[serde(default)]
+    pub ignore: Vec<PackageName>,
+}
+
+/// How to report dependencies that are no longer maintained.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub enum MaintenancePolicy {
+    /// Warn about unmaintained dependencies.
+    #[default]
+    Warn,
+    /// Fail if any dependency is unmaintained.
+    Fail,
+}
+
+/// The resolution strategy to use for the direct dependencies of a dependency group.
+///
+/// Mirrors the resolver's `ResolutionMode`, which isn't available to the workspace crate.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub enum GroupResolutionMode {
+    /// Resolve the highest compatible version of each package.
+    Highest,
+    /// Resolve the lowest compatible version of each package.
+    Lowest,
+    /// Resolve the lowest compatible version of any direct dependencies, and the highest
+    /// compatible version of any transitive dependencies.
+    LowestDirect,
+}
+
 #[derive(Deserialize, OptionsMetadata, Default, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Serialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -598,6 +1060,110 @@ pub struct ToolUvWorkspace {
         "#
     )]
     pub exclude: Option<Vec<SerdePattern>>,
+    /// Dependency specifications to share across the workspace members, keyed by package name.
+    ///
+    /// Each entry is either a set of version specifiers (e.g., `anyio = ">=4,<5"`), or a table
+    /// with an optional `version` and any of the fields of a `tool.uv.sources` entry (e.g.,
+    /// `torch = { version = ">=2.5", index = "pytorch" }`).
+    ///
+    /// Members opt in to an entry by declaring the package as a workspace source (e.g.,
+    /// `anyio = { workspace = true }` in `tool.uv.sources`), in the same way as Cargo's workspace
+    /// dependencies. The member's requirement then inherits the entry's version specifiers and
+    /// source, and so must not declare its own version specifiers or URL. Extras and markers
+    /// remain specific to each member.
+    ///
+    /// Like `tool.uv.sources`, the shared specifications are only applied by uv, and are not
+    /// included in the metadata of distributions built from the members.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            dependencies = { anyio = ">=4,<5", torch = { version = ">=2.5", index = "pytorch" } }
+        "#
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<BTreeMap<PackageName, WorkspaceDependencyWire>>")
+    )]
+    pub dependencies: Option<BTreeMap<PackageName, WorkspaceDependency>>,
+}
+
+/// An entry in `tool.uv.workspace.dependencies`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct WorkspaceDependency {
+    /// The version specifiers for the dependency (e.g., `>=4,<5`).
+    pub version: Option<VersionSpecifiers>,
+    /// The source for the dependency, as in `tool.uv.sources`.
+    pub source: Option<Source>,
+}
+
+impl<'de> Deserialize<'de> for WorkspaceDependency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Wire {
+            Version(String),
+            Table(toml::Table),
+        }
+
+        let parse_version =
+            |version: &str| VersionSpecifiers::from_str(version).map_err(serde::de::Error::custom);
+
+        match Wire::deserialize(deserializer)? {
+            Wire::Version(version) => Ok(Self {
+                version: Some(parse_version(&version)?),
+                source: None,
+            }),
+            Wire::Table(mut table) => {
+                let version = match table.remove("version") {
+                    None => None,
+                    Some(toml::Value::String(version)) => Some(parse_version(&version)?),
+                    Some(_) => {
+                        return Err(serde::de::Error::custom(
+                            "expected `version` to be a string of version specifiers",
+                        ))
+                    }
+                };
+                let source = if table.is_empty() {
+                    None
+                } else {
+                    Some(
+                        Source::deserialize(toml::Value::Table(table))
+                            .map_err(serde::de::Error::custom)?,
+                    )
+                };
+                match source {
+                    Some(Source::Workspace { .. }) => Err(serde::de::Error::custom(
+                        "`workspace` sources are not allowed in `tool.uv.workspace.dependencies`",
+                    )),
+                    None if version.is_none() => Err(serde::de::Error::custom(
+                        "expected a `version`, a source, or both",
+                    )),
+                    source => Ok(Self { version, source }),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+#[derive(schemars::JsonSchema)]
+#[schemars(untagged)]
+#[allow(dead_code)]
+enum WorkspaceDependencyWire {
+    /// The version specifiers for the dependency (e.g., `>=4,<5`).
+    Version(String),
+    /// The version specifiers and source for the dependency (e.g.,
+    /// `{ version = ">=2.5", index = "pytorch" }`).
+    Table {
+        version: Option<String>,
+        #[serde(flatten)]
+        source: Option<Source>,
+    },
 }
 
 /// (De)serialize globs as strings.
@@ -727,6 +1293,12 @@ impl Sources {
     }
 }
 
+impl From<Source> for Sources {
+    fn from(source: Source) -> Self {
+        Self(vec![source])
+    }
+}
+
 impl IntoIterator for Sources {
     type Item = Source;
     type IntoIter = std::vec::IntoIter<Source>;
@@ -736,6 +1308,24 @@ impl IntoIterator for Sources {
     }
 }
 
+#[cfg(feature = "schemars")]
+#[derive(schemars::JsonSchema)]
+#[schemars(untagged)]
+#[allow(dead_code)]
+enum EnvironmentsWire {
+    Markers(Vec<String>),
+    Named(BTreeMap<String, NamedEnvironmentWire>),
+}
+
+#[cfg(feature = "schemars")]
+#[derive(schemars::JsonSchema)]
+#[schemars(untagged)]
+#[allow(dead_code)]
+enum NamedEnvironmentWire {
+    Marker(String),
+    Values(BTreeMap<String, String>),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema), schemars(untagged))]
 #[allow(clippy::large_enum_variant)]
@@ -855,6 +1445,47 @@ pub enum Source {
         )]
         marker: MarkerTree,
     },
+    /// A remote Mercurial repository, available over HTTPS or SSH.
+    ///
+    /// Example:
+    /// ```toml
+    /// flask = { hg = "https://hg.example.com/flask", tag = "3.0.0" }
+    /// ```
+    Hg {
+        /// The repository URL (without the `hg+` prefix).
+        hg: Url,
+        /// The path to the directory with the `pyproject.toml`, if it's not in the archive root.
+        subdirectory: Option<PortablePathBuf>,
+        // Only one of the three may be used; we'll validate this later and emit a custom error.
+        rev: Option<String>,
+        tag: Option<String>,
+        branch: Option<String>,
+        #[serde(
+            skip_serializing_if = "uv_pep508::marker::ser::is_empty",
+            serialize_with = "uv_pep508::marker::ser::serialize",
+            default
+        )]
+        marker: MarkerTree,
+    },
+    /// A remote Subversion repository, pinned to a revision with `rev`.
+    ///
+    /// Example:
+    /// ```toml
+    /// flask = { svn = "https://svn.example.com/flask/trunk", rev = "2019" }
+    /// ```
+    Svn {
+        /// The repository URL (without the `svn+` prefix).
+        svn: Url,
+        /// The path to the directory with the `pyproject.toml`, if it's not in the archive root.
+        subdirectory: Option<PortablePathBuf>,
+        rev: Option<String>,
+        #[serde(
+            skip_serializing_if = "uv_pep508::marker::ser::is_empty",
+            serialize_with = "uv_pep508::marker::ser::serialize",
+            default
+        )]
+        marker: MarkerTree,
+    },
     /// A remote `http://` or `https://` URL, either a wheel (`.whl`) or a source distribution
     /// (`.zip`, `.tar.gz`).
     ///
@@ -867,6 +1498,15 @@ pub enum Source {
         /// For source distributions, the path to the directory with the `pyproject.toml`, if it's
         /// not in the archive root.
         subdirectory: Option<PortablePathBuf>,
+        /// The expected SHA-256 hash of the archive, as `sha256:<digest>`.
+        ///
+        /// If set, the archive is verified against the hash when locking and installing.
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_hash"
+        )]
+        #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+        hash: Option<HashDigest>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -923,11 +1563,14 @@ impl<'de> Deserialize<'de> for Source {
         #[serde(rename_all = "kebab-case", deny_unknown_fields)]
         struct CatchAll {
             git: Option<Url>,
+            hg: Option<Url>,
+            svn: Option<Url>,
             subdirectory: Option<PortablePathBuf>,
             rev: Option<String>,
             tag: Option<String>,
             branch: Option<String>,
             url: Option<Url>,
+            hash: Option<String>,
             path: Option<PortablePathBuf>,
             editable: Option<bool>,
             index: Option<IndexName>,
@@ -943,11 +1586,14 @@ impl<'de> Deserialize<'de> for Source {
         // Attempt to deserialize as `CatchAll`.
         let CatchAll {
             git,
+            hg,
+            svn,
             subdirectory,
             rev,
             tag,
             branch,
             url,
+            hash,
             path,
             editable,
             index,
@@ -955,32 +1601,66 @@ impl<'de> Deserialize<'de> for Source {
             marker,
         } = CatchAll::deserialize(deserializer)?;
 
-        // If the `git` field is set, we're dealing with a Git source.
-        if let Some(git) = git {
-            if index.is_some() {
+        if hash.is_some() && url.is_none() {
+            return Err(serde::de::Error::custom(
+                "`hash` can only be specified for `url` sources",
+            ));
+        }
+
+        // Mercurial and Subversion sources are validated in the same way as Git sources.
+        let vcs = match (&git, hg, svn) {
+            (None, None, None) => None,
+            (Some(git), None, None) => Some((VcsBackend::Git, git.clone())),
+            (None, Some(hg), None) => Some((VcsBackend::Mercurial, hg)),
+            (None, None, Some(svn)) => Some((VcsBackend::Subversion, svn)),
+            _ => {
                 return Err(serde::de::Error::custom(
-                    "cannot specify both `git` and `index`",
-                ));
+                    "expected at most one of `git`, `hg`, or `svn`",
+                ))
+            }
+        };
+
+        // If the `git` (or `hg`, or `svn`) field is set, we're dealing with a VCS source.
+        if let Some((backend, repository)) = vcs {
+            let key = backend.key();
+            if index.is_some() {
+                return Err(serde::de::Error::custom(format!(
+                    "cannot specify both `{key}` and `index`"
+                )));
             }
             if workspace.is_some() {
-                return Err(serde::de::Error::custom(
-                    "cannot specify both `git` and `workspace`",
-                ));
+                return Err(serde::de::Error::custom(format!(
+                    "cannot specify both `{key}` and `workspace`"
+                )));
             }
             if path.is_some() {
-                return Err(serde::de::Error::custom(
-                    "cannot specify both `git` and `path`",
-                ));
+                return Err(serde::de::Error::custom(format!(
+                    "cannot specify both `{key}` and `path`"
+                )));
             }
             if url.is_some() {
-                return Err(serde::de::Error::custom(
-                    "cannot specify both `git` and `url`",
-                ));
+                return Err(serde::de::Error::custom(format!(
+                    "cannot specify both `{key}` and `url`"
+                )));
             }
             if editable.is_some() {
-                return Err(serde::de::Error::custom(
-                    "cannot specify both `git` and `editable`",
-                ));
+                return Err(serde::de::Error::custom(format!(
+                    "cannot specify both `{key}` and `editable`"
+                )));
+            }
+
+            // Subversion branches and tags are paths within the repository.
+            if backend == VcsBackend::Subversion {
+                if tag.is_some() {
+                    return Err(serde::de::Error::custom(
+                        "cannot specify both `svn` and `tag`; include the tag path in the repository URL instead",
+                    ));
+                }
+                if branch.is_some() {
+                    return Err(serde::de::Error::custom(
+                        "cannot specify both `svn` and `branch`; include the branch path in the repository URL instead",
+                    ));
+                }
             }
 
             // At most one of `rev`, `tag`, or `branch` may be set.
@@ -996,20 +1676,34 @@ impl<'de> Deserialize<'de> for Source {
                 }
             };
 
-            // If the user prefixed the URL with `git+`, strip it.
-            let git = if let Some(git) = git.as_str().strip_prefix("git+") {
-                Url::parse(git).map_err(serde::de::Error::custom)?
-            } else {
-                git
-            };
-
-            return Ok(Self::Git {
-                git,
-                subdirectory,
-                rev,
-                tag,
-                branch,
-                marker,
+            // If the user prefixed the URL with `git+` (or `hg+`, or `svn+`), strip it.
+            let repository = backend
+                .remote(&repository)
+                .map_err(serde::de::Error::custom)?;
+
+            return Ok(match backend {
+                VcsBackend::Git => Self::Git {
+                    git: repository,
+                    subdirectory,
+                    rev,
+                    tag,
+                    branch,
+                    marker,
+                },
+                VcsBackend::Mercurial => Self::Hg {
+                    hg: repository,
+                    subdirectory,
+                    rev,
+                    tag,
+                    branch,
+                    marker,
+                },
+                VcsBackend::Subversion => Self::Svn {
+                    svn: repository,
+                    subdirectory,
+                    rev,
+                    marker,
+                },
             });
         }
 
@@ -1056,9 +1750,25 @@ impl<'de> Deserialize<'de> for Source {
                 ));
             }
 
+            // Only SHA-256 hashes are recorded in the lockfile, and so only SHA-256 hashes can be
+            // verified against it.
+            let hash = hash
+                .map(|hash| HashDigest::from_str(&hash))
+                .transpose()
+                .map_err(serde::de::Error::custom)?;
+            if hash
+                .as_ref()
+                .is_some_and(|hash| hash.algorithm != HashAlgorithm::Sha256)
+            {
+                return Err(serde::de::Error::custom(
+                    "`hash` must be a SHA-256 hash (e.g., `sha256:<digest>`)",
+                ));
+            }
+
             return Ok(Self::Url {
                 url,
                 subdirectory,
+                hash,
                 marker,
             });
         }
@@ -1202,11 +1912,22 @@ impl<'de> Deserialize<'de> for Source {
 
         // If none of the fields are set, we're dealing with an error.
         Err(serde::de::Error::custom(
-            "expected one of `git`, `url`, `path`, `index`, or `workspace`",
+            "expected one of `git`, `hg`, `svn`, `url`, `path`, `index`, or `workspace`",
         ))
     }
 }
 
+/// Serialize a [`HashDigest`] in its `<algorithm>:<digest>` form.
+fn serialize_hash<S>(hash: &Option<HashDigest>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match hash {
+        Some(hash) => serializer.collect_str(hash),
+        None => serializer.serialize_none(),
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum SourceError {
     #[error("Failed to resolve Git reference: `{0}`")]
@@ -1223,6 +1944,10 @@ pub enum SourceError {
     UnusedTag(String, String),
     #[error("`{0}` did not resolve to a Git repository, but a Git reference (`--branch {1}`) was provided.")]
     UnusedBranch(String, String),
+    #[error("`{0}` resolved to a Subversion repository, which does not support tags (`--tag {1}`). Include the tag path in the repository URL instead.")]
+    UnsupportedSvnTag(String, String),
+    #[error("`{0}` resolved to a Subversion repository, which does not support branches (`--branch {1}`). Include the branch path in the repository URL instead.")]
+    UnsupportedSvnBranch(String, String),
     #[error("Failed to resolve absolute path")]
     Absolute(#[from] std::io::Error),
     #[error("Path contains invalid characters: `{}`", _0.display())]
@@ -1308,16 +2033,18 @@ impl Source {
             } => Source::Url {
                 url: url.to_url(),
                 subdirectory: subdirectory.map(PortablePathBuf::from),
+                hash: None,
                 marker: MarkerTree::TRUE,
             },
             RequirementSource::Git {
                 repository,
+                backend,
                 mut reference,
                 subdirectory,
                 ..
             } => {
-                if rev.is_none() && tag.is_none() && branch.is_none() {
-                    let rev = match reference {
+                let rev = if rev.is_none() && tag.is_none() && branch.is_none() {
+                    match reference {
                         GitReference::FullCommit(ref mut rev) => Some(mem::take(rev)),
                         GitReference::Branch(ref mut rev) => Some(mem::take(rev)),
                         GitReference::Tag(ref mut rev) => Some(mem::take(rev)),
@@ -1326,23 +2053,44 @@ impl Source {
                         GitReference::BranchOrTagOrCommit(ref mut rev) => Some(mem::take(rev)),
                         GitReference::NamedRef(ref mut rev) => Some(mem::take(rev)),
                         GitReference::DefaultBranch => None,
-                    };
-                    Source::Git {
+                    }
+                } else {
+                    rev
+                };
+                let subdirectory = subdirectory.map(PortablePathBuf::from);
+                match backend {
+                    VcsBackend::Git => Source::Git {
                         rev,
                         tag,
                         branch,
                         git: repository,
-                        subdirectory: subdirectory.map(PortablePathBuf::from),
+                        subdirectory,
                         marker: MarkerTree::TRUE,
-                    }
-                } else {
-                    Source::Git {
+                    },
+                    VcsBackend::Mercurial => Source::Hg {
                         rev,
                         tag,
                         branch,
-                        git: repository,
-                        subdirectory: subdirectory.map(PortablePathBuf::from),
+                        hg: repository,
+                        subdirectory,
                         marker: MarkerTree::TRUE,
+                    },
+                    VcsBackend::Subversion => {
+                        if let Some(tag) = tag {
+                            return Err(SourceError::UnsupportedSvnTag(name.to_string(), tag));
+                        }
+                        if let Some(branch) = branch {
+                            return Err(SourceError::UnsupportedSvnBranch(
+                                name.to_string(),
+                                branch,
+                            ));
+                        }
+                        Source::Svn {
+                            rev,
+                            svn: repository,
+                            subdirectory,
+                            marker: MarkerTree::TRUE,
+                        }
                     }
                 }
             }
@@ -1355,6 +2103,8 @@ impl Source {
     pub fn marker(&self) -> MarkerTree {
         match self {
             Source::Git { marker, .. } => marker.clone(),
+            Source::Hg { marker, .. } => marker.clone(),
+            Source::Svn { marker, .. } => marker.clone(),
             Source::Url { marker, .. } => marker.clone(),
             Source::Path { marker, .. } => marker.clone(),
             Source::Registry { marker, .. } => marker.clone(),
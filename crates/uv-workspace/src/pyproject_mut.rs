@@ -5,7 +5,8 @@ use std::{fmt, mem};
 use itertools::Itertools;
 use thiserror::Error;
 use toml_edit::{
-    Array, ArrayOfTables, DocumentMut, Formatted, Item, RawString, Table, TomlError, Value,
+    Array, ArrayOfTables, DocumentMut, Formatted, InlineTable, Item, RawString, Table, TomlError,
+    Value,
 };
 use url::Url;
 
@@ -13,7 +14,7 @@ use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::Index;
 use uv_fs::PortablePath;
 use uv_normalize::GroupName;
-use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::{ExtraName, MarkerTree, PackageName, Requirement, VersionOrUrl};
 
 use crate::pyproject::{DependencyType, Source};
@@ -41,10 +42,14 @@ pub enum Error {
     MalformedSources,
     #[error("Workspace in `pyproject.toml` is malformed")]
     MalformedWorkspace,
+    #[error("Conflicting groups in `pyproject.toml` are malformed")]
+    MalformedConflicts,
     #[error("Expected a dependency at index {0}")]
     MissingDependency(usize),
     #[error("Cannot perform ambiguous update; found multiple entries with matching package names")]
     Ambiguous,
+    #[error("Expected a static `project.version` in `pyproject.toml`")]
+    MissingVersion,
 }
 
 /// The result of editing an array in a TOML document.
@@ -124,6 +129,115 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Sets the version specifiers for a package in `tool.uv.workspace.dependencies`, retaining the
+    /// source of an existing entry, if any.
+    pub fn set_shared_dependency(
+        &mut self,
+        name: &PackageName,
+        specifiers: &VersionSpecifiers,
+    ) -> Result<(), Error> {
+        // Get or create `tool.uv.workspace.dependencies`.
+        let dependencies = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedWorkspace)?
+            .entry("uv")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedWorkspace)?
+            .entry("workspace")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or(Error::MalformedWorkspace)?
+            .entry("dependencies")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_like_mut()
+            .ok_or(Error::MalformedWorkspace)?;
+
+        // Update any existing entry, even if the key isn't normalized.
+        let key = dependencies
+            .iter()
+            .map(|(key, _)| key.to_string())
+            .find(|key| PackageName::from_str(key).is_ok_and(|key| key == *name))
+            .unwrap_or_else(|| name.to_string());
+        match dependencies.get_mut(&key).and_then(Item::as_table_like_mut) {
+            Some(dependency) => {
+                dependency.insert("version", toml_edit::value(specifiers.to_string()));
+            }
+            None => {
+                dependencies.insert(&key, toml_edit::value(specifiers.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the version specifiers from every requirement on the given package, such that the
+    /// requirements inherit the version specifiers in `tool.uv.workspace.dependencies`.
+    ///
+    /// Searches `project.dependencies`, `project.optional-dependencies`, `dependency-groups`, and
+    /// `tool.uv.dev-dependencies`. Requirements with a URL are left as-is.
+    ///
+    /// Returns the number of requirements that were updated.
+    pub fn clear_dependency_bounds(&mut self, name: &PackageName) -> Result<usize, Error> {
+        let mut cleared = 0;
+
+        if let Some(project) = self.project_mut()? {
+            // Update `project.dependencies`.
+            if let Some(dependencies) = project.get_mut("dependencies") {
+                let dependencies = dependencies
+                    .as_array_mut()
+                    .ok_or(Error::MalformedDependencies)?;
+                cleared += clear_bounds(name, dependencies);
+            }
+
+            // Update `project.optional-dependencies`.
+            if let Some(extras) = project.get_mut("optional-dependencies") {
+                let extras = extras
+                    .as_table_like_mut()
+                    .ok_or(Error::MalformedDependencies)?;
+                for (_, dependencies) in extras.iter_mut() {
+                    let dependencies = dependencies
+                        .as_array_mut()
+                        .ok_or(Error::MalformedDependencies)?;
+                    cleared += clear_bounds(name, dependencies);
+                }
+            }
+        }
+
+        // Update `dependency-groups`.
+        if let Some(groups) = self.doc.get_mut("dependency-groups") {
+            let groups = groups
+                .as_table_like_mut()
+                .ok_or(Error::MalformedDependencies)?;
+            for (_, dependencies) in groups.iter_mut() {
+                let dependencies = dependencies
+                    .as_array_mut()
+                    .ok_or(Error::MalformedDependencies)?;
+                cleared += clear_bounds(name, dependencies);
+            }
+        }
+
+        // Update `tool.uv.dev-dependencies`.
+        if let Some(dev_dependencies) = self
+            .doc
+            .get_mut("tool")
+            .and_then(Item::as_table_mut)
+            .and_then(|tool| tool.get_mut("uv"))
+            .and_then(Item::as_table_mut)
+            .and_then(|uv| uv.get_mut("dev-dependencies"))
+        {
+            let dev_dependencies = dev_dependencies
+                .as_array_mut()
+                .ok_or(Error::MalformedDependencies)?;
+            cleared += clear_bounds(name, dev_dependencies);
+        }
+
+        Ok(cleared)
+    }
+
     /// Retrieves a mutable reference to the `project` [`Table`] of the TOML document, creating the
     /// table if necessary.
     ///
@@ -457,11 +571,11 @@ impl PyProjectTomlMut {
         Ok(added)
     }
 
-    /// Set the minimum version for an existing dependency in `project.dependencies`.
-    pub fn set_dependency_minimum_version(
+    /// Set the version specifiers for an existing dependency in `project.dependencies`.
+    pub fn set_dependency_bound(
         &mut self,
         index: usize,
-        version: Version,
+        specifiers: VersionSpecifiers,
     ) -> Result<(), Error> {
         // Get or create `project.dependencies`.
         let dependencies = self
@@ -479,19 +593,17 @@ impl PyProjectTomlMut {
             .as_str()
             .and_then(try_parse_requirement)
             .ok_or(Error::MalformedDependencies)?;
-        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
-            VersionSpecifier::greater_than_equal_version(version),
-        )));
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
         dependencies.replace(index, req.to_string());
 
         Ok(())
     }
 
-    /// Set the minimum version for an existing dependency in `tool.uv.dev-dependencies`.
-    pub fn set_dev_dependency_minimum_version(
+    /// Set the version specifiers for an existing dependency in `tool.uv.dev-dependencies`.
+    pub fn set_dev_dependency_bound(
         &mut self,
         index: usize,
-        version: Version,
+        specifiers: VersionSpecifiers,
     ) -> Result<(), Error> {
         // Get or create `tool.uv.dev-dependencies`.
         let dev_dependencies = self
@@ -517,20 +629,18 @@ impl PyProjectTomlMut {
             .as_str()
             .and_then(try_parse_requirement)
             .ok_or(Error::MalformedDependencies)?;
-        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
-            VersionSpecifier::greater_than_equal_version(version),
-        )));
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
         dev_dependencies.replace(index, req.to_string());
 
         Ok(())
     }
 
-    /// Set the minimum version for an existing dependency in `project.optional-dependencies`.
-    pub fn set_optional_dependency_minimum_version(
+    /// Set the version specifiers for an existing dependency in `project.optional-dependencies`.
+    pub fn set_optional_dependency_bound(
         &mut self,
         group: &ExtraName,
         index: usize,
-        version: Version,
+        specifiers: VersionSpecifiers,
     ) -> Result<(), Error> {
         // Get or create `project.optional-dependencies`.
         let optional_dependencies = self
@@ -554,20 +664,18 @@ impl PyProjectTomlMut {
             .as_str()
             .and_then(try_parse_requirement)
             .ok_or(Error::MalformedDependencies)?;
-        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
-            VersionSpecifier::greater_than_equal_version(version),
-        )));
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
         group.replace(index, req.to_string());
 
         Ok(())
     }
 
-    /// Set the minimum version for an existing dependency in `dependency-groups`.
-    pub fn set_dependency_group_requirement_minimum_version(
+    /// Set the version specifiers for an existing dependency in `dependency-groups`.
+    pub fn set_dependency_group_requirement_bound(
         &mut self,
         group: &GroupName,
         index: usize,
-        version: Version,
+        specifiers: VersionSpecifiers,
     ) -> Result<(), Error> {
         // Get or create `dependency-groups`.
         let dependency_groups = self
@@ -591,16 +699,14 @@ impl PyProjectTomlMut {
             .as_str()
             .and_then(try_parse_requirement)
             .ok_or(Error::MalformedDependencies)?;
-        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
-            VersionSpecifier::greater_than_equal_version(version),
-        )));
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
         group.replace(index, req.to_string());
 
         Ok(())
     }
 
     /// Adds a source to `tool.uv.sources`.
-    fn add_source(&mut self, name: &PackageName, source: &Source) -> Result<(), Error> {
+    pub fn add_source(&mut self, name: &PackageName, source: &Source) -> Result<(), Error> {
         // Get or create `tool.uv.sources`.
         let sources = self
             .doc
@@ -625,6 +731,62 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Adds an override to `tool.uv.override-dependencies`, replacing any existing override on the
+    /// same package.
+    pub fn add_override_dependency(&mut self, req: &Requirement) -> Result<ArrayEdit, Error> {
+        // Get or create `tool.uv.override-dependencies`.
+        let overrides = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedDependencies)?
+            .entry("uv")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedDependencies)?
+            .entry("override-dependencies")
+            .or_insert(Item::Value(Value::Array(Array::new())))
+            .as_array_mut()
+            .ok_or(Error::MalformedDependencies)?;
+
+        add_dependency(req, overrides, false)
+    }
+
+    /// Declares the given extras as conflicting, by adding a set to `tool.uv.conflicting-groups`.
+    pub fn add_conflicting_extras(
+        &mut self,
+        extras: &[(PackageName, ExtraName)],
+    ) -> Result<(), Error> {
+        // Get or create `tool.uv.conflicting-groups`.
+        let conflicts = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedConflicts)?
+            .entry("uv")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedConflicts)?
+            .entry("conflicting-groups")
+            .or_insert(Item::Value(Value::Array(Array::new())))
+            .as_array_mut()
+            .ok_or(Error::MalformedConflicts)?;
+
+        let mut set = Array::new();
+        for (package, extra) in extras {
+            let mut item = InlineTable::new();
+            item.insert("package", Value::from(package.to_string()));
+            item.insert("extra", Value::from(extra.to_string()));
+            set.push(item);
+        }
+        conflicts.push(set);
+        reformat_array_multiline(conflicts);
+
+        Ok(())
+    }
+
     /// Removes all occurrences of dependencies with the given name.
     pub fn remove_dependency(&mut self, name: &PackageName) -> Result<Vec<Requirement>, Error> {
         // Try to get `project.dependencies`.
@@ -782,6 +944,23 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Sets the `project.version` field, preserving any surrounding formatting.
+    ///
+    /// Returns an error if the project doesn't declare a static version.
+    pub fn set_version(&mut self, version: &Version) -> Result<(), Error> {
+        let item = self
+            .project_mut()?
+            .and_then(|project| project.get_mut("version"))
+            .ok_or(Error::MissingVersion)?;
+        let value = item.as_value_mut().ok_or(Error::MissingVersion)?;
+
+        let mut updated = Value::from(version.to_string());
+        *updated.decor_mut() = value.decor().clone();
+        *value = updated;
+
+        Ok(())
+    }
+
     /// Returns `true` if the `tool.uv.dev-dependencies` table is present.
     pub fn has_dev_dependencies(&self) -> bool {
         self.doc
@@ -1038,6 +1217,21 @@ fn remove_dependency(name: &PackageName, deps: &mut Array) -> Vec<Requirement> {
     removed
 }
 
+/// Removes the version specifiers from every requirement on the given package in the `deps`
+/// array, returning the number of requirements that were updated.
+fn clear_bounds(name: &PackageName, deps: &mut Array) -> usize {
+    let mut cleared = 0;
+    for (i, mut req) in find_dependencies(name, None, deps) {
+        if matches!(&req.version_or_url, Some(VersionOrUrl::VersionSpecifier(specifiers)) if !specifiers.is_empty())
+        {
+            req.version_or_url = None;
+            deps.replace(i, req.to_string());
+            cleared += 1;
+        }
+    }
+    cleared
+}
+
 /// Returns a `Vec` containing the all dependencies with the given name, along with their positions
 /// in the array.
 fn find_dependencies(
@@ -1,6 +1,6 @@
 //! Resolve the current [`ProjectWorkspace`] or [`Workspace`].
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::path::{Path, PathBuf};
 
 use glob::{glob, GlobError, PatternError};
@@ -10,15 +10,16 @@ use tracing::{debug, trace, warn};
 use uv_distribution_types::Index;
 use uv_fs::{Simplified, CWD};
 use uv_normalize::{GroupName, PackageName, DEV_DEPENDENCIES};
+use uv_pep440::VersionSpecifiers;
 use uv_pep508::{MarkerTree, RequirementOrigin, VerbatimUrl};
 use uv_pypi_types::{ConflictingGroupList, Requirement, RequirementSource, SupportedEnvironments};
 use uv_static::EnvVars;
-use uv_warnings::{warn_user, warn_user_once};
+use uv_warnings::warn_user_once;
 
 use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroups};
 use crate::pyproject::{
-    DependencyGroups, Project, PyProjectToml, PyprojectTomlError, Sources, ToolUvSources,
-    ToolUvWorkspace,
+    DependencyGroups, GroupResolutionMode, Project, PyProjectToml, PyprojectTomlError,
+    ToolUvMaintenance, ToolUvSources, ToolUvWorkspace, VendoredDependency, WorkspaceDependency,
 };
 
 #[derive(thiserror::Error, Debug)]
@@ -47,6 +48,10 @@ pub enum WorkspaceError {
     Toml(PathBuf, #[source] Box<PyprojectTomlError>),
     #[error("Failed to normalize workspace member path")]
     Normalize(#[source] std::io::Error),
+    #[error(
+        "`{0}` is a workspace member, and so can't be declared in `tool.uv.workspace.dependencies`"
+    )]
+    SharedDependencyMember(PackageName),
 }
 
 #[derive(Debug, Default, Clone)]
@@ -82,13 +87,25 @@ pub struct Workspace {
     /// The sources table from the workspace `pyproject.toml`.
     ///
     /// This table is overridden by the project sources.
-    sources: BTreeMap<PackageName, Sources>,
+    sources: ToolUvSources,
     /// The index table from the workspace `pyproject.toml`.
     ///
     /// This table is overridden by the project indexes.
     indexes: Vec<Index>,
     /// The `pyproject.toml` of the workspace root.
     pyproject_toml: PyProjectToml,
+    /// The PEP 723 script from which the workspace was derived, if any.
+    #[cfg_attr(test, serde(skip))]
+    script: Option<WorkspaceScript>,
+}
+
+/// A PEP 723 script, locked as the root of a non-project workspace.
+#[derive(Debug, Clone)]
+struct WorkspaceScript {
+    /// The absolute path to the script.
+    path: PathBuf,
+    /// The `requires-python` from the script metadata.
+    requires_python: Option<VersionSpecifiers>,
 }
 
 impl Workspace {
@@ -220,6 +237,49 @@ impl Workspace {
         })
     }
 
+    /// Create a non-project workspace for a PEP 723 script.
+    ///
+    /// The workspace is rooted at the directory containing the script, and the script's
+    /// dependencies are read from the `dev-dependencies` in the given `pyproject.toml`, as for a
+    /// (legacy) non-project workspace root. The lockfile is written alongside the script (e.g.,
+    /// `example.py.lock` for `example.py`).
+    pub fn from_script(
+        path: &Path,
+        requires_python: Option<VersionSpecifiers>,
+        pyproject_toml: PyProjectToml,
+    ) -> Result<Self, WorkspaceError> {
+        let path = std::path::absolute(path)?;
+        let install_path = path
+            .parent()
+            .expect("script path has no parent")
+            .to_path_buf();
+
+        let sources = pyproject_toml
+            .tool
+            .clone()
+            .and_then(|tool| tool.uv)
+            .and_then(|uv| uv.sources)
+            .unwrap_or_default();
+        let indexes = pyproject_toml
+            .tool
+            .clone()
+            .and_then(|tool| tool.uv)
+            .and_then(|uv| uv.index)
+            .unwrap_or_default();
+
+        Ok(Self {
+            install_path,
+            packages: BTreeMap::new(),
+            sources,
+            indexes,
+            pyproject_toml,
+            script: Some(WorkspaceScript {
+                path,
+                requires_python,
+            }),
+        })
+    }
+
     /// Set the [`ProjectWorkspace`] for a given workspace member.
     ///
     /// Assumes that the project name is unchanged in the updated [`PyProjectToml`].
@@ -243,7 +303,6 @@ impl Workspace {
                 .clone()
                 .and_then(|tool| tool.uv)
                 .and_then(|uv| uv.sources)
-                .map(ToolUvSources::into_inner)
                 .unwrap_or_default();
 
             // Set the `pyproject.toml` for the member.
@@ -337,7 +396,7 @@ impl Workspace {
 
             // Resolve any `include-group` entries in `dependency-groups`.
             let dependency_groups =
-                FlatDependencyGroups::from_dependency_groups(&dependency_groups)?;
+                FlatDependencyGroups::from_workspace(&dependency_groups, &self.install_path, self)?;
 
             // Concatenate the two sets of requirements.
             let dev_dependencies = dependency_groups
@@ -392,6 +451,72 @@ impl Workspace {
             .and_then(|uv| uv.environments.as_ref())
     }
 
+    /// Restrict the set of supported environments to the environment with the given name, as
+    /// declared in `tool.uv.environments`.
+    ///
+    /// Returns `None` if the workspace doesn't declare an environment with the given name.
+    #[must_use]
+    pub fn with_environment(mut self, name: &str) -> Option<Self> {
+        let uv = self.pyproject_toml.tool.as_mut()?.uv.as_mut()?;
+        uv.environments = Some(uv.environments.as_ref()?.only(name)?);
+        Some(self)
+    }
+
+    /// Returns the set of hardware variants for the workspace, keyed by package name.
+    pub fn variants(&self) -> &BTreeMap<PackageName, String> {
+        static EMPTY: BTreeMap<PackageName, String> = BTreeMap::new();
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.variants.as_ref())
+            .unwrap_or(&EMPTY)
+    }
+
+    /// Returns the dependency specifications shared across the workspace members, as declared in
+    /// `tool.uv.workspace.dependencies`, keyed by package name.
+    pub fn shared_dependencies(&self) -> &BTreeMap<PackageName, WorkspaceDependency> {
+        static EMPTY: BTreeMap<PackageName, WorkspaceDependency> = BTreeMap::new();
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.workspace.as_ref())
+            .and_then(|workspace| workspace.dependencies.as_ref())
+            .unwrap_or(&EMPTY)
+    }
+
+    /// Returns the resolution strategy for each dependency group in the workspace, keyed by group
+    /// name.
+    pub fn group_resolution(&self) -> &BTreeMap<GroupName, GroupResolutionMode> {
+        static EMPTY: BTreeMap<GroupName, GroupResolutionMode> = BTreeMap::new();
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.group_resolution.as_ref())
+            .unwrap_or(&EMPTY)
+    }
+
+    /// Returns the checks for unmaintained dependencies in the workspace, if enabled.
+    pub fn maintenance(&self) -> Option<&ToolUvMaintenance> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.maintenance.as_ref())
+    }
+
+    /// Returns the dependencies to vendor under an alias for the workspace.
+    pub fn vendored(&self) -> &[VendoredDependency] {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.vendor.as_deref())
+            .unwrap_or_default()
+    }
+
     /// Returns the set of conflicts for the workspace.
     pub fn conflicting_groups(&self) -> ConflictingGroupList {
         let mut conflicting = ConflictingGroupList::empty();
@@ -469,6 +594,27 @@ impl Workspace {
         &self.install_path
     }
 
+    /// The path to the workspace lockfile.
+    ///
+    /// Uses `uv.lock` in the install path directory, unless the workspace was derived from a PEP
+    /// 723 script, in which case the lockfile is written alongside the script.
+    pub fn lockfile(&self) -> PathBuf {
+        if let Some(script) = &self.script {
+            let mut file_name = script.path.file_name().unwrap_or_default().to_os_string();
+            file_name.push(".lock");
+            script.path.with_file_name(file_name)
+        } else {
+            self.install_path.join("uv.lock")
+        }
+    }
+
+    /// The `requires-python` of the PEP 723 script from which the workspace was derived, if any.
+    pub fn script_requires_python(&self) -> Option<&VersionSpecifiers> {
+        self.script
+            .as_ref()
+            .and_then(|script| script.requires_python.as_ref())
+    }
+
     /// The path to the workspace virtual environment.
     ///
     /// Uses `.venv` in the install path directory by default.
@@ -560,7 +706,7 @@ impl Workspace {
     }
 
     /// The sources table from the workspace `pyproject.toml`.
-    pub fn sources(&self) -> &BTreeMap<PackageName, Sources> {
+    pub fn sources(&self) -> &ToolUvSources {
         &self.sources
     }
 
@@ -657,123 +803,150 @@ impl Workspace {
             workspace_members.insert(root_member.project.name.clone(), root_member);
         }
 
-        // Add all other workspace members.
-        for member_glob in workspace_definition.clone().members.unwrap_or_default() {
-            let absolute_glob = PathBuf::from(glob::Pattern::escape(
-                workspace_root.simplified().to_string_lossy().as_ref(),
-            ))
-            .join(member_glob.as_str())
-            .to_string_lossy()
-            .to_string();
-            for member_root in glob(&absolute_glob)
-                .map_err(|err| WorkspaceError::Pattern(absolute_glob.to_string(), err))?
-            {
-                let member_root = member_root
-                    .map_err(|err| WorkspaceError::Glob(absolute_glob.to_string(), err))?;
-                if !seen.insert(member_root.clone()) {
-                    continue;
-                }
-                let member_root = std::path::absolute(&member_root)
-                    .map_err(WorkspaceError::Normalize)?
-                    .clone();
-
-                // If the directory is explicitly ignored, skip it.
-                let skip = match &options.members {
-                    MemberDiscovery::All => false,
-                    MemberDiscovery::None => true,
-                    MemberDiscovery::Ignore(ignore) => ignore.contains(member_root.as_path()),
-                };
-                if skip {
-                    debug!(
-                        "Ignoring workspace member: `{}`",
-                        member_root.simplified_display()
-                    );
-                    continue;
-                }
-
-                // If the member is excluded, ignore it.
-                if is_excluded_from_workspace(&member_root, &workspace_root, &workspace_definition)?
+        // Add all other workspace members, including the members of any nested workspaces, i.e.,
+        // members that are themselves workspace roots.
+        let mut definitions =
+            VecDeque::from([(workspace_root.clone(), workspace_definition.clone())]);
+        while let Some((definition_root, definition)) = definitions.pop_front() {
+            for member_glob in definition.members.clone().unwrap_or_default() {
+                let absolute_glob = PathBuf::from(glob::Pattern::escape(
+                    definition_root.simplified().to_string_lossy().as_ref(),
+                ))
+                .join(member_glob.as_str())
+                .to_string_lossy()
+                .to_string();
+                for member_root in glob(&absolute_glob)
+                    .map_err(|err| WorkspaceError::Pattern(absolute_glob.to_string(), err))?
                 {
-                    debug!(
-                        "Ignoring workspace member: `{}`",
-                        member_root.simplified_display()
-                    );
-                    continue;
-                }
+                    let member_root = member_root
+                        .map_err(|err| WorkspaceError::Glob(absolute_glob.to_string(), err))?;
+                    if !seen.insert(member_root.clone()) {
+                        continue;
+                    }
+                    let member_root = std::path::absolute(&member_root)
+                        .map_err(WorkspaceError::Normalize)?
+                        .clone();
+
+                    // If the directory is explicitly ignored, skip it.
+                    let skip = match &options.members {
+                        MemberDiscovery::All => false,
+                        MemberDiscovery::None => true,
+                        MemberDiscovery::Ignore(ignore) => ignore.contains(member_root.as_path()),
+                    };
+                    if skip {
+                        debug!(
+                            "Ignoring workspace member: `{}`",
+                            member_root.simplified_display()
+                        );
+                        continue;
+                    }
 
-                trace!(
-                    "Processing workspace member: `{}`",
-                    member_root.user_display()
-                );
+                    // If the member is excluded, ignore it.
+                    if is_excluded_from_workspace(
+                        &member_root,
+                        &workspace_root,
+                        &workspace_definition,
+                    )? || is_excluded_from_workspace(
+                        &member_root,
+                        &definition_root,
+                        &definition,
+                    )? {
+                        debug!(
+                            "Ignoring workspace member: `{}`",
+                            member_root.simplified_display()
+                        );
+                        continue;
+                    }
+
+                    trace!(
+                        "Processing workspace member: `{}`",
+                        member_root.user_display()
+                    );
 
-                // Read the member `pyproject.toml`.
-                let pyproject_path = member_root.join("pyproject.toml");
-                let contents = match fs_err::tokio::read_to_string(&pyproject_path).await {
-                    Ok(contents) => contents,
-                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                        // If the directory is hidden, skip it.
-                        if member_root
-                            .file_name()
-                            .map(|name| name.as_encoded_bytes().starts_with(b"."))
-                            .unwrap_or(false)
-                        {
-                            debug!(
-                                "Ignoring hidden workspace member: `{}`",
+                    // Read the member `pyproject.toml`.
+                    let pyproject_path = member_root.join("pyproject.toml");
+                    let contents = match fs_err::tokio::read_to_string(&pyproject_path).await {
+                        Ok(contents) => contents,
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                            // If the directory is hidden, skip it.
+                            if member_root
+                                .file_name()
+                                .map(|name| name.as_encoded_bytes().starts_with(b"."))
+                                .unwrap_or(false)
+                            {
+                                debug!(
+                                    "Ignoring hidden workspace member: `{}`",
+                                    member_root.simplified_display()
+                                );
+                                continue;
+                            }
+
+                            return Err(WorkspaceError::MissingPyprojectTomlMember(
+                                member_root,
+                                member_glob.to_string(),
+                            ));
+                        }
+                        // If the entry is _not_ a directory, skip it.
+                        Err(_) if !member_root.is_dir() => {
+                            warn!(
+                                "Ignoring non-directory workspace member: `{}`",
                                 member_root.simplified_display()
                             );
                             continue;
                         }
-
-                        return Err(WorkspaceError::MissingPyprojectTomlMember(
-                            member_root,
-                            member_glob.to_string(),
-                        ));
+                        Err(err) => return Err(err.into()),
+                    };
+                    let pyproject_toml = PyProjectToml::from_string(contents).map_err(|err| {
+                        WorkspaceError::Toml(pyproject_path.clone(), Box::new(err))
+                    })?;
+
+                    // Check if the current project is explicitly marked as unmanaged.
+                    if pyproject_toml
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.managed)
+                        == Some(false)
+                    {
+                        debug!(
+                            "Project `{}` is marked as unmanaged; omitting from workspace members",
+                            pyproject_toml.project.as_ref().unwrap().name
+                        );
+                        continue;
                     }
-                    // If the entry is _not_ a directory, skip it.
-                    Err(_) if !member_root.is_dir() => {
-                        warn!(
-                            "Ignoring non-directory workspace member: `{}`",
+
+                    // Extract the package name.
+                    let Some(project) = pyproject_toml.project.clone() else {
+                        return Err(WorkspaceError::MissingProject(pyproject_path));
+                    };
+
+                    // If the member is itself a workspace root, include its members too.
+                    if let Some(nested) = pyproject_toml
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.workspace.as_ref())
+                    {
+                        debug!(
+                            "Including the members of nested workspace: `{}`",
                             member_root.simplified_display()
                         );
-                        continue;
+                        definitions.push_back((member_root.clone(), nested.clone()));
                     }
-                    Err(err) => return Err(err.into()),
-                };
-                let pyproject_toml = PyProjectToml::from_string(contents)
-                    .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
 
-                // Check if the current project is explicitly marked as unmanaged.
-                if pyproject_toml
-                    .tool
-                    .as_ref()
-                    .and_then(|tool| tool.uv.as_ref())
-                    .and_then(|uv| uv.managed)
-                    == Some(false)
-                {
                     debug!(
-                        "Project `{}` is marked as unmanaged; omitting from workspace members",
-                        pyproject_toml.project.as_ref().unwrap().name
+                        "Adding discovered workspace member: `{}`",
+                        member_root.simplified_display()
+                    );
+                    workspace_members.insert(
+                        project.name.clone(),
+                        WorkspaceMember {
+                            root: member_root.clone(),
+                            project,
+                            pyproject_toml,
+                        },
                     );
-                    continue;
                 }
-
-                // Extract the package name.
-                let Some(project) = pyproject_toml.project.clone() else {
-                    return Err(WorkspaceError::MissingProject(pyproject_path));
-                };
-
-                debug!(
-                    "Adding discovered workspace member: `{}`",
-                    member_root.simplified_display()
-                );
-                workspace_members.insert(
-                    project.name.clone(),
-                    WorkspaceMember {
-                        root: member_root.clone(),
-                        project,
-                        pyproject_toml,
-                    },
-                );
             }
         }
         let workspace_sources = workspace_pyproject_toml
@@ -781,7 +954,6 @@ impl Workspace {
             .clone()
             .and_then(|tool| tool.uv)
             .and_then(|uv| uv.sources)
-            .map(ToolUvSources::into_inner)
             .unwrap_or_default();
         let workspace_indexes = workspace_pyproject_toml
             .tool
@@ -790,12 +962,23 @@ impl Workspace {
             .and_then(|uv| uv.index)
             .unwrap_or_default();
 
+        // Members are always sourced from the workspace, so can't be shared as dependencies.
+        if let Some(name) = workspace_definition
+            .dependencies
+            .iter()
+            .flat_map(BTreeMap::keys)
+            .find(|name| workspace_members.contains_key(*name))
+        {
+            return Err(WorkspaceError::SharedDependencyMember(name.clone()));
+        }
+
         Ok(Workspace {
             install_path: workspace_root,
             packages: workspace_members,
             sources: workspace_sources,
             indexes: workspace_indexes,
             pyproject_toml: workspace_pyproject_toml,
+            script: None,
         })
     }
 }
@@ -1096,9 +1279,10 @@ impl ProjectWorkspace {
                     packages: current_project_as_members,
                     // There may be package sources, but we don't need to duplicate them into the
                     // workspace sources.
-                    sources: BTreeMap::default(),
+                    sources: ToolUvSources::default(),
                     indexes: Vec::default(),
                     pyproject_toml: project_pyproject_toml.clone(),
+                    script: None,
                 },
             });
         };
@@ -1222,7 +1406,7 @@ async fn find_workspace(
     Ok(None)
 }
 
-/// Warn when the valid workspace is included in another workspace.
+/// Report when the valid workspace is included in another workspace.
 pub fn check_nested_workspaces(inner_workspace_root: &Path, options: &DiscoveryOptions) {
     for outer_workspace_root in inner_workspace_root
         .ancestors()
@@ -1298,10 +1482,12 @@ pub fn check_nested_workspaces(inner_workspace_root: &Path, options: &DiscoveryO
             };
 
             if is_included && !is_excluded {
-                warn_user!(
-                    "Nested workspaces are not supported, but outer workspace (`{}`) includes `{}`",
-                    outer_workspace_root.simplified_display().cyan(),
-                    inner_workspace_root.simplified_display().cyan()
+                // The outer workspace includes the members of the nested workspace, but when
+                // invoked from within the nested workspace, it's treated as its own workspace.
+                debug!(
+                    "Outer workspace (`{}`) includes nested workspace `{}`; using the nested workspace",
+                    outer_workspace_root.simplified_display(),
+                    inner_workspace_root.simplified_display()
                 );
             }
         }
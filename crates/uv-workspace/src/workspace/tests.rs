@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::env;
 use std::path::Path;
 use std::str::FromStr;
@@ -7,9 +8,10 @@ use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
 use insta::assert_json_snapshot;
 
-use uv_normalize::GroupName;
+use uv_normalize::{GroupName, PackageName};
 
-use crate::pyproject::{DependencyGroupSpecifier, PyProjectToml};
+use crate::dependency_groups::FlatDependencyGroups;
+use crate::pyproject::{DependencyGroupSpecifier, IncludeGroupSource, PyProjectToml};
 use crate::workspace::{DiscoveryOptions, ProjectWorkspace};
 
 async fn workspace_test(folder: &str) -> (ProjectWorkspace, String) {
@@ -229,15 +231,22 @@ async fn albatross_root_workspace() {
                     ]
                   },
                   "index": null,
+                  "variants": null,
+                  "vendor": null,
                   "workspace": {
                     "members": [
                       "packages/*"
                     ],
-                    "exclude": null
+                    "exclude": null,
+                    "dependencies": null
                   },
                   "managed": null,
                   "package": null,
+                  "rebuild-on-import": null,
                   "default-groups": null,
+                  "group-resolution": null,
+                  "ignore-unused-dependencies": null,
+                  "maintenance": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -321,15 +330,22 @@ async fn albatross_virtual_workspace() {
                 "uv": {
                   "sources": null,
                   "index": null,
+                  "variants": null,
+                  "vendor": null,
                   "workspace": {
                     "members": [
                       "packages/*"
                     ],
-                    "exclude": null
+                    "exclude": null,
+                    "dependencies": null
                   },
                   "managed": null,
                   "package": null,
+                  "rebuild-on-import": null,
                   "default-groups": null,
+                  "group-resolution": null,
+                  "ignore-unused-dependencies": null,
+                  "maintenance": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -525,6 +541,8 @@ async fn exclude_package() -> Result<()> {
                 "uv": {
                   "sources": null,
                   "index": null,
+                  "variants": null,
+                  "vendor": null,
                   "workspace": {
                     "members": [
                       "packages/*"
@@ -535,7 +553,11 @@ async fn exclude_package() -> Result<()> {
                   },
                   "managed": null,
                   "package": null,
+                  "rebuild-on-import": null,
                   "default-groups": null,
+                  "group-resolution": null,
+                  "ignore-unused-dependencies": null,
+                  "maintenance": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -628,6 +650,8 @@ async fn exclude_package() -> Result<()> {
                 "uv": {
                   "sources": null,
                   "index": null,
+                  "variants": null,
+                  "vendor": null,
                   "workspace": {
                     "members": [
                       "packages/seeds",
@@ -639,7 +663,11 @@ async fn exclude_package() -> Result<()> {
                   },
                   "managed": null,
                   "package": null,
+                  "rebuild-on-import": null,
                   "default-groups": null,
+                  "group-resolution": null,
+                  "ignore-unused-dependencies": null,
+                  "maintenance": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -745,6 +773,8 @@ async fn exclude_package() -> Result<()> {
                 "uv": {
                   "sources": null,
                   "index": null,
+                  "variants": null,
+                  "vendor": null,
                   "workspace": {
                     "members": [
                       "packages/seeds",
@@ -756,7 +786,11 @@ async fn exclude_package() -> Result<()> {
                   },
                   "managed": null,
                   "package": null,
+                  "rebuild-on-import": null,
                   "default-groups": null,
+                  "group-resolution": null,
+                  "ignore-unused-dependencies": null,
+                  "maintenance": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -836,6 +870,8 @@ async fn exclude_package() -> Result<()> {
                 "uv": {
                   "sources": null,
                   "index": null,
+                  "variants": null,
+                  "vendor": null,
                   "workspace": {
                     "members": [
                       "packages/seeds",
@@ -847,7 +883,11 @@ async fn exclude_package() -> Result<()> {
                   },
                   "managed": null,
                   "package": null,
+                  "rebuild-on-import": null,
                   "default-groups": null,
+                  "group-resolution": null,
+                  "ignore-unused-dependencies": null,
+                  "maintenance": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -865,6 +905,80 @@ async fn exclude_package() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn nested_workspace() -> Result<()> {
+    let root = tempfile::TempDir::new()?;
+    let root = ChildPath::new(root.path());
+
+    // Create the outer workspace root, which includes the nested workspace as a member.
+    root.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [tool.uv.workspace]
+            members = ["libs/*"]
+            "#,
+    )?;
+
+    // Create the nested workspace root, with an included and an excluded member.
+    let nest = root.child("libs").child("nest");
+    nest.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "nest"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["egg"]
+
+            [tool.uv.sources]
+            egg = { workspace = true }
+
+            [tool.uv.workspace]
+            members = ["plugins/*"]
+            exclude = ["plugins/shell"]
+            "#,
+    )?;
+    for name in ["egg", "shell"] {
+        nest.child("plugins")
+            .child(name)
+            .child("pyproject.toml")
+            .write_str(&format!(
+                r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            "#
+            ))?;
+    }
+
+    // The outer workspace includes the members of the nested workspace.
+    let (project, _) = temporary_test(root.as_ref()).await;
+    let members = project
+        .workspace()
+        .packages()
+        .keys()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(members, ["albatross", "egg", "nest"]);
+
+    // From within the nested workspace, the nested workspace is used on its own.
+    let (project, _) = temporary_test(nest.as_ref()).await;
+    assert_eq!(project.workspace().install_path(), nest.path());
+    let members = project
+        .workspace()
+        .packages()
+        .keys()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(members, ["egg", "nest"]);
+
+    Ok(())
+}
+
 #[test]
 fn read_dependency_groups() {
     let toml = r#"
@@ -888,6 +1002,7 @@ bar = ["b"]
             DependencyGroupSpecifier::Requirement("a".to_string()),
             DependencyGroupSpecifier::IncludeGroup {
                 include_group: GroupName::from_str("bar").unwrap(),
+                from: None,
             }
         ]
     );
@@ -900,3 +1015,204 @@ bar = ["b"]
         &[DependencyGroupSpecifier::Requirement("b".to_string())]
     );
 }
+
+#[test]
+fn read_dependency_groups_from_workspace() {
+    let toml = r#"
+[dependency-groups]
+foo = [{include-group = "dev", workspace = true}, {include-group = "lint", package = "bird"}]
+"#;
+
+    let result =
+        PyProjectToml::from_string(toml.to_string()).expect("Deserialization should succeed");
+
+    let groups = result
+        .dependency_groups
+        .expect("`dependency-groups` should be present");
+    let foo = groups
+        .get(&GroupName::from_str("foo").unwrap())
+        .expect("Group `foo` should be present");
+    assert_eq!(
+        foo,
+        &[
+            DependencyGroupSpecifier::IncludeGroup {
+                include_group: GroupName::from_str("dev").unwrap(),
+                from: Some(IncludeGroupSource::Workspace),
+            },
+            DependencyGroupSpecifier::IncludeGroup {
+                include_group: GroupName::from_str("lint").unwrap(),
+                from: Some(IncludeGroupSource::Package(
+                    PackageName::from_str("bird").unwrap()
+                )),
+            }
+        ]
+    );
+
+    // A group can't be included from both a member and the workspace root.
+    let toml = r#"
+[dependency-groups]
+foo = [{include-group = "dev", workspace = true, package = "bird"}]
+"#;
+    assert!(PyProjectToml::from_string(toml.to_string()).is_err());
+}
+
+#[tokio::test]
+async fn include_group_from_workspace() -> Result<()> {
+    let root = tempfile::TempDir::new()?;
+    let root = ChildPath::new(root.path());
+
+    // Define a shared `dev` group at the (virtual) workspace root.
+    root.child("pyproject.toml").write_str(
+        r#"
+            [tool.uv.workspace]
+            members = ["packages/*"]
+
+            [dependency-groups]
+            dev = ["pytest", { include-group = "lint", package = "bird" }]
+            "#,
+    )?;
+    root.child("packages")
+        .child("bird")
+        .child("pyproject.toml")
+        .write_str(
+            r#"
+            [project]
+            name = "bird"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [dependency-groups]
+            lint = ["ruff", { include-group = "typing" }]
+            typing = ["mypy"]
+            "#,
+        )?;
+    let seeds = root.child("packages").child("seeds");
+    seeds.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "seeds"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [dependency-groups]
+            dev = [{ include-group = "dev", workspace = true }, "pytest", "coverage"]
+            "#,
+    )?;
+
+    let (project, _) = temporary_test(seeds.as_ref()).await;
+    let pyproject_toml = project.current_project().pyproject_toml();
+    let groups = pyproject_toml
+        .dependency_groups
+        .iter()
+        .flatten()
+        .collect::<BTreeMap<_, _>>();
+    let groups =
+        FlatDependencyGroups::from_workspace(&groups, project.project_root(), project.workspace())?;
+
+    // Groups are included transitively across members, and duplicates are omitted.
+    let dev = groups
+        .get(&GroupName::from_str("dev").unwrap())
+        .expect("Group `dev` should be present")
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(dev, ["pytest", "ruff", "mypy", "coverage"]);
+
+    // Groups from other members are only available to the groups that include them.
+    assert!(groups.get(&GroupName::from_str("lint").unwrap()).is_none());
+
+    // Outside of a workspace, groups can't be included from other members.
+    let groups = pyproject_toml
+        .dependency_groups
+        .iter()
+        .flatten()
+        .collect::<BTreeMap<_, _>>();
+    let err = FlatDependencyGroups::from_dependency_groups(&groups).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Group `dev` includes a group from another `pyproject.toml`, which is only supported within a workspace"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn include_group_cycle() -> Result<()> {
+    let root = tempfile::TempDir::new()?;
+    let root = ChildPath::new(root.path());
+
+    root.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [tool.uv.workspace]
+            members = ["packages/*"]
+
+            [dependency-groups]
+            dev = [{ include-group = "test", package = "bird" }]
+            "#,
+    )?;
+    root.child("packages")
+        .child("bird")
+        .child("pyproject.toml")
+        .write_str(
+            r#"
+            [project]
+            name = "bird"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [dependency-groups]
+            test = [{ include-group = "dev", package = "albatross" }]
+            "#,
+        )?;
+
+    let (project, _) = temporary_test(root.as_ref()).await;
+    let groups = project
+        .current_project()
+        .pyproject_toml()
+        .dependency_groups
+        .iter()
+        .flatten()
+        .collect::<BTreeMap<_, _>>();
+    let err =
+        FlatDependencyGroups::from_workspace(&groups, project.project_root(), project.workspace())
+            .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Detected a cycle in `dependency-groups`: `dev` -> `test` (from `bird`) -> `dev`"
+    );
+
+    // A missing group in another member is reported along with the member.
+    root.child("packages")
+        .child("bird")
+        .child("pyproject.toml")
+        .write_str(
+            r#"
+            [project]
+            name = "bird"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            "#,
+        )?;
+    let (project, _) = temporary_test(root.as_ref()).await;
+    let groups = project
+        .current_project()
+        .pyproject_toml()
+        .dependency_groups
+        .iter()
+        .flatten()
+        .collect::<BTreeMap<_, _>>();
+    let err =
+        FlatDependencyGroups::from_workspace(&groups, project.project_root(), project.workspace())
+            .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Failed to find group `test` (from `bird`) included by `dev`"
+    );
+
+    Ok(())
+}
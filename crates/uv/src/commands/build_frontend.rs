@@ -3,11 +3,13 @@ use std::fmt::Write as _;
 use std::io;
 use std::io::Write as _;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anyhow::Result;
 
 use owo_colors::OwoColorize;
-use uv_distribution_filename::SourceDistExtension;
+use tracing::debug;
+use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations};
 use uv_install_wheel::linker::LinkMode;
 
@@ -15,12 +17,13 @@ use uv_auth::store_credentials;
 use uv_cache::{Cache, CacheBucket};
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildKind, BuildOptions, BuildOutput, Concurrency, ConfigSettings, Constraints,
+    BuildKind, BuildOptions, BuildOutput, BuildTarget, Concurrency, ConfigSettings, Constraints,
     HashCheckingMode, IndexStrategy, KeyringProviderType, LowerBound, SourceStrategy, TrustedHost,
 };
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
+use uv_platform_tags::TagPreferences;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
@@ -49,6 +52,7 @@ pub(crate) async fn build_frontend(
     output_dir: Option<PathBuf>,
     sdist: bool,
     wheel: bool,
+    targets: Vec<BuildTarget>,
     build_logs: bool,
     build_constraints: Vec<RequirementsSource>,
     hash_checking: Option<HashCheckingMode>,
@@ -60,6 +64,7 @@ pub(crate) async fn build_frontend(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -73,6 +78,7 @@ pub(crate) async fn build_frontend(
         output_dir.as_deref(),
         sdist,
         wheel,
+        &targets,
         build_logs,
         &build_constraints,
         hash_checking,
@@ -84,6 +90,7 @@ pub(crate) async fn build_frontend(
         python_downloads,
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
@@ -115,6 +122,7 @@ async fn build_impl(
     output_dir: Option<&Path>,
     sdist: bool,
     wheel: bool,
+    targets: &[BuildTarget],
     build_logs: bool,
     build_constraints: &[RequirementsSource],
     hash_checking: Option<HashCheckingMode>,
@@ -126,6 +134,7 @@ async fn build_impl(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -143,6 +152,7 @@ async fn build_impl(
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        max_backtracks: _,
         link_mode,
         upgrade: _,
         build_options,
@@ -235,6 +245,7 @@ async fn build_impl(
             .map(|package| AnnotatedSource {
                 source: Source::Directory(Cow::Borrowed(package.root())),
                 package: Some(package.project().name.clone()),
+                target: None,
             })
             .collect();
 
@@ -250,46 +261,72 @@ async fn build_impl(
         vec![AnnotatedSource::from(src)]
     };
 
-    let results: Vec<_> = futures::future::join_all(packages.into_iter().map(|source| {
-        let future = build_package(
-            source.clone(),
-            output_dir,
-            python_request,
-            install_mirrors.clone(),
-            no_config,
-            workspace.as_ref(),
-            python_preference,
-            python_downloads,
-            cache,
-            printer,
-            index_locations,
-            &client_builder,
-            hash_checking,
-            build_logs,
-            build_constraints,
-            no_build_isolation,
-            no_build_isolation_package,
-            native_tls,
-            connectivity,
-            index_strategy,
-            keyring_provider,
-            allow_insecure_host,
-            exclude_newer,
-            sources,
-            concurrency,
-            build_options,
-            sdist,
-            wheel,
-            dependency_metadata,
-            link_mode,
-            config_setting,
-        );
-        async {
-            let result = future.await;
-            (source, result)
-        }
-    }))
-    .await;
+    // If targets were provided, build a wheel for each target, along with a single source
+    // distribution, if requested.
+    let builds: Vec<_> = if targets.is_empty() {
+        packages
+            .into_iter()
+            .map(|source| (source, sdist, wheel))
+            .collect()
+    } else {
+        packages
+            .into_iter()
+            .flat_map(|source| {
+                let sdist = sdist.then(|| (source.clone(), true, false));
+                let wheels = targets.iter().map(move |target| {
+                    let source = AnnotatedSource {
+                        target: Some(target.clone()),
+                        ..source.clone()
+                    };
+                    (source, false, true)
+                });
+                sdist.into_iter().chain(wheels)
+            })
+            .collect()
+    };
+
+    let results: Vec<_> =
+        futures::future::join_all(builds.into_iter().map(|(source, sdist, wheel)| {
+            let future = build_package(
+                source.clone(),
+                output_dir,
+                python_request,
+                install_mirrors.clone(),
+                no_config,
+                workspace.as_ref(),
+                python_preference,
+                python_downloads,
+                cache,
+                printer,
+                index_locations,
+                &client_builder,
+                hash_checking,
+                build_logs,
+                build_constraints,
+                no_build_isolation,
+                no_build_isolation_package,
+                native_tls,
+                connectivity,
+                index_strategy,
+                keyring_provider,
+                allow_insecure_host,
+                exclude_newer,
+                sources,
+                concurrency,
+                tag_preferences,
+                build_options,
+                sdist,
+                wheel,
+                dependency_metadata,
+                link_mode,
+                config_setting,
+            );
+            async {
+                let result = future.await;
+                (source, result)
+            }
+        }))
+        .await;
 
     for (source, result) in &results {
         match result {
@@ -339,6 +376,55 @@ async fn build_impl(
         }
     }
 
+    // Summarize the wheels built for each target.
+    if !targets.is_empty() {
+        writeln!(printer.stderr(), "{}", "Build matrix:".bold())?;
+        for (source, result) in &results {
+            let Some(target) = &source.target else {
+                continue;
+            };
+            let label = if let Some(package) = &source.package {
+                format!("{package} ({target})")
+            } else {
+                target.to_string()
+            };
+            match result {
+                Ok(BuiltDistributions::Wheel(wheel)) => {
+                    // Verify that the build backend respected the target platform.
+                    let filename = wheel
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .and_then(|name| WheelFilename::from_str(name).ok());
+                    let mismatch = filename.is_some_and(|filename| {
+                        !filename
+                            .platform_tag
+                            .iter()
+                            .any(|tag| target.matches_platform_tag(tag.as_str()))
+                    });
+                    if !mismatch {
+                        writeln!(
+                            printer.stderr(),
+                            " {} {label}: {}",
+                            "+".green(),
+                            wheel.user_display().cyan()
+                        )?;
+                    } else {
+                        writeln!(
+                            printer.stderr(),
+                            " {} {label}: {} (the platform tag does not match the target; the build backend may not support cross-compilation)",
+                            "!".yellow(),
+                            wheel.user_display().cyan()
+                        )?;
+                    }
+                }
+                Ok(_) => {}
+                Err(_) => {
+                    writeln!(printer.stderr(), " {} {label}: failed", "-".red())?;
+                }
+            }
+        }
+    }
+
     if results.iter().any(|(_, result)| result.is_err()) {
         Ok(BuildResult::Failure)
     } else {
@@ -373,6 +459,7 @@ async fn build_package(
     exclude_newer: Option<ExcludeNewer>,
     sources: SourceStrategy,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     build_options: &BuildOptions,
     sdist: bool,
     wheel: bool,
@@ -393,8 +480,12 @@ async fn build_package(
         }
     };
 
-    // (1) Explicit request from user
-    let mut interpreter_request = python_request.map(PythonRequest::parse);
+    // (1) Explicit request from user, or the Python tag of the target (e.g., `cp312`)
+    let mut interpreter_request = if let Some(target) = &source.target {
+        Some(PythonRequest::parse(target.python_tag()))
+    } else {
+        python_request.map(PythonRequest::parse)
+    };
 
     // (2) Request from `.python-version`
     if interpreter_request.is_none() {
@@ -502,6 +593,18 @@ async fn build_package(
     // Initialize any shared state.
     let state = SharedState::default();
 
+    // If building for a different platform, instruct the build backend to cross-compile.
+    let environment_variables = match &source.target {
+        Some(target) if !target.is_native(interpreter.platform()) => {
+            debug!(
+                "Cross-compiling for `{target}` from `{}`",
+                interpreter.platform().os()
+            );
+            target.environment_variables()
+        }
+        _ => Vec::new(),
+    };
+
     // Create a build dispatch.
     let build_dispatch = BuildDispatch::new(
         &client,
@@ -525,7 +628,9 @@ async fn build_package(
         LowerBound::Allow,
         sources,
         concurrency,
-    );
+        tag_preferences,
+    )
+    .with_build_extra_env_vars(environment_variables);
 
     // Create the output directory.
     fs_err::tokio::create_dir_all(&output_dir).await?;
@@ -789,6 +894,8 @@ struct AnnotatedSource<'a> {
     source: Source<'a>,
     /// The package name, if known.
     package: Option<PackageName>,
+    /// The target for which to build a wheel, if any.
+    target: Option<BuildTarget>,
 }
 
 impl AnnotatedSource<'_> {
@@ -801,10 +908,13 @@ impl AnnotatedSource<'_> {
     }
 
     fn annotate<'a>(&self, s: &'a str) -> Cow<'a, str> {
-        if let Some(package) = &self.package {
-            Cow::Owned(format!("[{}] {s}", package.cyan()))
-        } else {
-            Cow::Borrowed(s)
+        match (&self.package, &self.target) {
+            (Some(package), Some(target)) => {
+                Cow::Owned(format!("[{}, {}] {s}", package.cyan(), target.cyan()))
+            }
+            (Some(package), None) => Cow::Owned(format!("[{}] {s}", package.cyan())),
+            (None, Some(target)) => Cow::Owned(format!("[{}] {s}", target.cyan())),
+            (None, None) => Cow::Borrowed(s),
         }
     }
 }
@@ -814,6 +924,7 @@ impl<'a> From<Source<'a>> for AnnotatedSource<'a> {
         Self {
             source,
             package: None,
+            target: None,
         }
     }
 }
@@ -0,0 +1,260 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{BaseClientBuilder, Connectivity, RegistryClientBuilder, VersionFiles};
+use uv_configuration::{IndexStrategy, KeyringProviderType, TrustedHost};
+use uv_distribution_types::{
+    BuiltDist, File, IndexCapabilities, IndexLocations, RegistryBuiltDist, RegistryBuiltWheel,
+};
+use uv_fs::Simplified;
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pypi_types::{ResolutionMetadata, Yanked};
+use uv_resolver::ExcludeNewer;
+
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// The exported metadata for a single package on a single index.
+#[derive(Debug, Serialize)]
+struct PackageRecord {
+    /// The name of the package.
+    name: PackageName,
+    /// The index on which the package is available, with any credentials redacted.
+    index: String,
+    /// The versions of the package available on the index.
+    versions: Vec<VersionRecord>,
+}
+
+/// The exported metadata for a single version of a package.
+#[derive(Debug, Serialize)]
+struct VersionRecord {
+    version: Version,
+    /// The `Requires-Python` of the version, as reported by the index or the version's metadata.
+    requires_python: Option<VersionSpecifiers>,
+    /// The `Requires-Dist` of the version, if its metadata is cached.
+    requires_dist: Option<Vec<String>>,
+    /// The `Provides-Extra` of the version, if its metadata is cached.
+    provides_extras: Option<Vec<ExtraName>>,
+    /// Whether every distribution of the version is yanked.
+    yanked: bool,
+}
+
+/// Export the package metadata in the cache as newline-delimited JSON.
+pub(crate) async fn cache_export(
+    packages: &[PackageName],
+    output: Option<&Path>,
+    index_locations: &IndexLocations,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProviderType,
+    exclude_newer: Option<ExcludeNewer>,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    // If no packages were provided, export every package with cached index metadata.
+    let packages = if packages.is_empty() {
+        cached_packages(cache)?
+    } else {
+        packages.iter().cloned().collect()
+    };
+
+    // Read exclusively from the cache, regardless of the configured connectivity.
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(Connectivity::Offline)
+        .native_tls(native_tls)
+        .keyring(keyring_provider)
+        .allow_insecure_host(allow_insecure_host.to_vec());
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .build();
+    let capabilities = IndexCapabilities::default();
+
+    let mut contents = String::new();
+    let mut exported = 0usize;
+    let mut versions = 0usize;
+    for package in &packages {
+        let archives = match client.simple(package, None, &capabilities).await {
+            Ok(archives) => archives,
+            Err(err) => match err.into_kind() {
+                uv_client::ErrorKind::Offline(_)
+                | uv_client::ErrorKind::PackageNotFound(_)
+                | uv_client::ErrorKind::NoIndex(_) => {
+                    debug!("No cached index metadata for: {package}");
+                    continue;
+                }
+                kind => return Err(uv_client::Error::from(kind).into()),
+            },
+        };
+
+        for (index, archive) in archives {
+            let mut record = PackageRecord {
+                name: package.clone(),
+                index: index.redacted().to_string(),
+                versions: Vec::new(),
+            };
+
+            for datum in archive.iter() {
+                let version = rkyv::deserialize::<Version, rkyv::rancor::Error>(&datum.version)
+                    .expect("archived version always deserializes");
+                let mut files =
+                    rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                        .expect("archived version files always deserializes");
+
+                // Omit any distributions uploaded after the cutoff, along with versions for which
+                // no distributions remain.
+                if let Some(exclude_newer) = exclude_newer {
+                    let is_older = |file: &File| {
+                        file.upload_time_utc_ms.map_or(true, |upload_time| {
+                            upload_time < exclude_newer.timestamp_millis()
+                        })
+                    };
+                    files.wheels.retain(|wheel| is_older(&wheel.file));
+                    files.source_dists.retain(|sdist| is_older(&sdist.file));
+                    if files.wheels.is_empty() && files.source_dists.is_empty() {
+                        continue;
+                    }
+                }
+
+                let yanked = files
+                    .wheels
+                    .iter()
+                    .map(|wheel| &wheel.file)
+                    .chain(files.source_dists.iter().map(|sdist| &sdist.file))
+                    .all(|file| file.yanked.as_ref().is_some_and(Yanked::is_yanked));
+                let requires_python = files
+                    .wheels
+                    .iter()
+                    .map(|wheel| &wheel.file)
+                    .chain(files.source_dists.iter().map(|sdist| &sdist.file))
+                    .find_map(|file| file.requires_python.clone());
+
+                // The resolver only fetches the metadata of a single wheel for each version, so
+                // use the first wheel whose metadata is cached.
+                let mut metadata: Option<ResolutionMetadata> = None;
+                for wheel in files.wheels {
+                    let dist = BuiltDist::Registry(RegistryBuiltDist {
+                        wheels: vec![RegistryBuiltWheel {
+                            filename: wheel.name,
+                            file: Box::new(wheel.file),
+                            index: index.clone(),
+                        }],
+                        best_wheel_index: 0,
+                        sdist: None,
+                    });
+                    match client.wheel_metadata(&dist, &capabilities).await {
+                        Ok(wheel_metadata) => {
+                            metadata = Some(wheel_metadata);
+                            break;
+                        }
+                        Err(err) => {
+                            debug!("No cached metadata for {dist}: {err}");
+                        }
+                    }
+                }
+
+                if metadata.is_some() {
+                    versions += 1;
+                }
+                record.versions.push(match metadata {
+                    Some(metadata) => VersionRecord {
+                        version,
+                        requires_python: metadata.requires_python.or(requires_python),
+                        requires_dist: Some(
+                            metadata
+                                .requires_dist
+                                .iter()
+                                .map(ToString::to_string)
+                                .collect(),
+                        ),
+                        provides_extras: Some(metadata.provides_extras),
+                        yanked,
+                    },
+                    None => VersionRecord {
+                        version,
+                        requires_python,
+                        requires_dist: None,
+                        provides_extras: None,
+                        yanked,
+                    },
+                });
+            }
+
+            contents.push_str(&serde_json::to_string(&record)?);
+            contents.push('\n');
+            exported += 1;
+        }
+    }
+
+    if let Some(output) = output {
+        fs_err::write(output, contents)?;
+    } else {
+        write!(printer.stdout(), "{contents}")?;
+    }
+
+    let s = if exported == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Exported {} {}",
+            format!("{exported} package{s} ({versions} versions with metadata)").bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    if let Some(output) = output {
+        writeln!(
+            printer.stderr(),
+            "Wrote metadata to: {}",
+            output.user_display().cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the names of the packages with index metadata in the cache, across all indexes.
+pub(crate) fn cached_packages(cache: &Cache) -> Result<BTreeSet<PackageName>> {
+    let mut packages = BTreeSet::new();
+    let root = cache.bucket(CacheBucket::Simple);
+    if !root.is_dir() {
+        return Ok(packages);
+    }
+    for entry in WalkDir::new(root) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let path = entry.path();
+        if !path.extension().is_some_and(|ext| ext == "rkyv") {
+            continue;
+        }
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        match PackageName::from_str(stem) {
+            Ok(name) => {
+                packages.insert(name);
+            }
+            Err(err) => {
+                debug!("Skipping cache entry {}: {err}", path.user_display());
+            }
+        }
+    }
+    Ok(packages)
+}
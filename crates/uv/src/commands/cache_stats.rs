@@ -0,0 +1,349 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use jiff::Timestamp;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_distribution::{HttpArchivePointer, LocalArchivePointer};
+use uv_fs::Simplified;
+use uv_workspace::{DiscoveryOptions, Workspace};
+
+use crate::commands::project::lock;
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// The statistics for the cache.
+#[derive(Debug, Serialize)]
+struct CacheStats {
+    /// The root of the cache.
+    root: PathBuf,
+    /// The totals across all buckets.
+    total: Usage,
+    /// The statistics for each bucket that exists in the cache.
+    buckets: Vec<BucketStats>,
+    /// The statistics for each lockfile.
+    projects: Vec<ProjectStats>,
+}
+
+/// The statistics for a single cache bucket.
+#[derive(Debug, Serialize)]
+struct BucketStats {
+    /// The name of the bucket's directory (e.g., `wheels-v3`).
+    name: &'static str,
+    #[serde(flatten)]
+    usage: Usage,
+}
+
+/// The cache usage attributed to a single lockfile.
+#[derive(Debug, Serialize)]
+struct ProjectStats {
+    /// The path to the lockfile.
+    lockfile: PathBuf,
+    /// The number of locked packages, excluding workspace members.
+    packages: usize,
+    /// The number of locked packages with a wheel or source distribution in the cache.
+    cached: usize,
+    /// The fraction of locked packages with a wheel or source distribution in the cache.
+    hit_rate: Option<f64>,
+    #[serde(flatten)]
+    usage: Usage,
+}
+
+/// The size and age of a set of cache entries.
+#[derive(Debug, Default, Serialize)]
+struct Usage {
+    /// The total size of the entries, in bytes.
+    size: u64,
+    /// The number of files.
+    files: u64,
+    /// The modification time of the oldest file.
+    oldest: Option<Timestamp>,
+    /// The modification time of the newest file.
+    newest: Option<Timestamp>,
+}
+
+impl Usage {
+    /// Add the file at the given path to the usage.
+    fn add(&mut self, metadata: &std::fs::Metadata) {
+        self.size += metadata.len();
+        self.files += 1;
+        let Some(modified) = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| Timestamp::try_from(modified).ok())
+        else {
+            return;
+        };
+        if self.oldest.map_or(true, |oldest| modified < oldest) {
+            self.oldest = Some(modified);
+        }
+        if self.newest.map_or(true, |newest| modified > newest) {
+            self.newest = Some(modified);
+        }
+    }
+
+    /// Add another set of entries to the usage.
+    fn extend(&mut self, other: &Self) {
+        self.size += other.size;
+        self.files += other.files;
+        self.oldest = match (self.oldest, other.oldest) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.newest = self.newest.max(other.newest);
+    }
+
+    /// Add the regular files in the given directory (or the file itself) to the usage, returning
+    /// any links that were encountered.
+    fn walk(&mut self, path: &Path) -> Result<Vec<PathBuf>> {
+        let mut links = Vec::new();
+        for entry in walkdir::WalkDir::new(path) {
+            let entry = entry?;
+            if entry.file_type().is_symlink() {
+                links.push(entry.into_path());
+            } else if entry.file_type().is_file() {
+                self.add(&entry.metadata()?);
+            }
+        }
+        Ok(links)
+    }
+}
+
+/// Show the size and age of the entries in the cache.
+pub(crate) async fn cache_stats(
+    project_dir: &Path,
+    lockfiles: &[PathBuf],
+    json: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // If no lockfiles were provided, use the lockfile of the current project, if any.
+    let lockfiles = if lockfiles.is_empty() {
+        match Workspace::discover(project_dir, &DiscoveryOptions::default()).await {
+            Ok(workspace) => {
+                let lockfile = workspace.install_path().join("uv.lock");
+                if lockfile.is_file() {
+                    vec![lockfile]
+                } else {
+                    vec![]
+                }
+            }
+            Err(err) => {
+                debug!("Unable to discover a project to attribute cache usage to: {err}");
+                vec![]
+            }
+        }
+    } else {
+        lockfiles.to_vec()
+    };
+
+    let stats = stats(cache, &lockfiles)
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+
+    if json {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&stats)?
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stdout(),
+        "Cache at: {}",
+        stats.root.user_display().cyan()
+    )?;
+    for bucket in &stats.buckets {
+        writeln!(printer.stdout(), "{}: {}", bucket.name.bold(), bucket.usage)?;
+    }
+    writeln!(printer.stdout(), "{}: {}", "Total".bold(), stats.total)?;
+
+    for project in &stats.projects {
+        writeln!(printer.stdout())?;
+        writeln!(
+            printer.stdout(),
+            "Project at: {}",
+            project.lockfile.user_display().cyan()
+        )?;
+        let hit_rate = project
+            .hit_rate
+            .map(|hit_rate| format!(" ({:.0}%)", hit_rate * 100.0))
+            .unwrap_or_default();
+        writeln!(
+            printer.stdout(),
+            "Cached packages: {} of {}{hit_rate}",
+            project.cached,
+            project.packages
+        )?;
+        writeln!(printer.stdout(), "Cache usage: {}", project.usage)?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+impl std::fmt::Display for Usage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bytes = if self.size < 1024 {
+            format!("{}B", self.size)
+        } else {
+            let (bytes, unit) = human_readable_bytes(self.size);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(
+            f,
+            "{} in {} file{}",
+            bytes.green(),
+            self.files,
+            if self.files == 1 { "" } else { "s" }
+        )?;
+        if let (Some(oldest), Some(newest)) = (self.oldest, self.newest) {
+            write!(
+                f,
+                " (oldest modified {}, newest modified {})",
+                age(oldest),
+                age(newest)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Format the time elapsed since the given timestamp, at a coarse granularity.
+fn age(timestamp: Timestamp) -> String {
+    let seconds = Timestamp::now().as_second() - timestamp.as_second();
+    let (value, unit) = match seconds {
+        ..=59 => return "just now".to_string(),
+        60..=3_599 => (seconds / 60, "minute"),
+        3_600..=86_399 => (seconds / 3_600, "hour"),
+        _ => (seconds / 86_400, "day"),
+    };
+    format!("{value} {unit}{} ago", if value == 1 { "" } else { "s" })
+}
+
+/// Compute the statistics for the cache, attributing usage to the given lockfiles.
+fn stats(cache: &Cache, lockfiles: &[PathBuf]) -> Result<CacheStats> {
+    let mut total = Usage::default();
+    let mut buckets = Vec::new();
+    for bucket in CacheBucket::iter() {
+        let path = cache.bucket(bucket);
+        if !path.is_dir() {
+            continue;
+        }
+        let mut usage = Usage::default();
+        usage.walk(&path)?;
+        total.extend(&usage);
+        buckets.push(BucketStats {
+            name: bucket.to_str(),
+            usage,
+        });
+    }
+
+    let mut projects = Vec::with_capacity(lockfiles.len());
+    for lockfile in lockfiles {
+        let encoded = fs_err::read_to_string(lockfile)?;
+        let lock = lock::parse(lockfile.clone(), encoded)?;
+
+        let mut usage = Usage::default();
+        let mut archives = FxHashSet::default();
+        let mut packages = 0;
+        let mut cached = 0;
+        for package in lock.packages() {
+            // Workspace members are built from the workspace itself, rather than the cache.
+            if lock.members().contains(package.name())
+                || lock
+                    .root()
+                    .is_some_and(|root| root.name() == package.name())
+            {
+                continue;
+            }
+            packages += 1;
+
+            // A package can be served from the cache if it has an unzipped wheel or a source
+            // distribution in the cache.
+            let mut is_cached = false;
+            for entry in cache.package_entries(package.name()) {
+                if entry.starts_with(cache.bucket(CacheBucket::SourceDistributions)) {
+                    is_cached = true;
+                }
+                for link in usage.walk(&entry)? {
+                    if let Ok(target) = fs_err::canonicalize(&link) {
+                        archives.insert(target);
+                        is_cached = true;
+                    }
+                }
+                for target in pointers(cache, &entry)? {
+                    archives.insert(target);
+                    is_cached = true;
+                }
+            }
+            if is_cached {
+                cached += 1;
+            }
+        }
+
+        // Attribute the unzipped wheels referenced by the project's entries, once each.
+        for archive in &archives {
+            usage.walk(archive)?;
+        }
+
+        projects.push(ProjectStats {
+            lockfile: lockfile.clone(),
+            packages,
+            cached,
+            #[allow(clippy::cast_precision_loss)]
+            hit_rate: (packages > 0).then(|| cached as f64 / packages as f64),
+            usage,
+        });
+    }
+
+    Ok(CacheStats {
+        root: cache.root().to_path_buf(),
+        total,
+        buckets,
+        projects,
+    })
+}
+
+/// Return the unzipped wheels referenced by the archive pointers within the given entry.
+fn pointers(cache: &Cache, entry: &Path) -> Result<Vec<PathBuf>> {
+    let mut archives = Vec::new();
+    for entry in walkdir::WalkDir::new(entry) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let archive = match entry.path().extension().and_then(|ext| ext.to_str()) {
+            Some("http") => HttpArchivePointer::read_from(entry.path())
+                .ok()
+                .flatten()
+                .map(HttpArchivePointer::into_archive),
+            Some("rev") => LocalArchivePointer::read_from(entry.path())
+                .ok()
+                .flatten()
+                .map(LocalArchivePointer::into_archive),
+            _ => None,
+        };
+        if let Some(archive) = archive {
+            let path = cache.archive(&archive.id);
+            if path.is_dir() {
+                archives.push(fs_err::canonicalize(path)?);
+            }
+        }
+    }
+    Ok(archives)
+}
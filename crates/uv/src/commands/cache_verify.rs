@@ -0,0 +1,259 @@
+use std::fmt::Write;
+use std::io;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_cache::{rm_rf, Cache, CacheBucket, Removal};
+use uv_distribution::{HttpArchivePointer, LocalArchivePointer};
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// A problem with an entry in the cache.
+#[derive(Debug)]
+enum Problem {
+    /// An unzipped wheel whose contents don't match its `RECORD` file.
+    Corrupted(PathBuf, uv_install_wheel::Error),
+    /// An unzipped wheel that isn't referenced by any other entry.
+    Orphaned(PathBuf),
+    /// A link to an unzipped wheel that no longer exists.
+    BrokenLink(PathBuf),
+    /// A pointer to an unzipped wheel that no longer exists.
+    DanglingPointer(PathBuf),
+    /// A pointer that can't be read.
+    InvalidPointer(PathBuf, uv_distribution::Error),
+}
+
+impl Problem {
+    fn path(&self) -> &PathBuf {
+        match self {
+            Self::Corrupted(path, _)
+            | Self::Orphaned(path)
+            | Self::BrokenLink(path)
+            | Self::DanglingPointer(path)
+            | Self::InvalidPointer(path, _) => path,
+        }
+    }
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Corrupted(path, err) => write!(
+                f,
+                "Corrupted archive: {} ({err})",
+                path.user_display().cyan()
+            ),
+            Self::Orphaned(path) => {
+                write!(f, "Orphaned archive: {}", path.user_display().cyan())
+            }
+            Self::BrokenLink(path) => write!(f, "Broken link: {}", path.user_display().cyan()),
+            Self::DanglingPointer(path) => write!(
+                f,
+                "Pointer to missing archive: {}",
+                path.user_display().cyan()
+            ),
+            Self::InvalidPointer(path, err) => {
+                write!(f, "Invalid pointer: {} ({err})", path.user_display().cyan())
+            }
+        }
+    }
+}
+
+/// Verify the integrity of the cache, optionally removing any problematic entries.
+pub(crate) fn cache_verify(repair: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let Verification {
+        mut problems,
+        references,
+        archives,
+    } = verify(cache)
+        .with_context(|| format!("Failed to verify cache at: {}", cache.root().user_display()))?;
+    problems.sort_by(|a, b| a.path().cmp(b.path()));
+
+    for problem in &problems {
+        writeln!(printer.stderr(), "{problem}")?;
+    }
+
+    if problems.is_empty() {
+        let s = if archives == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Verified {archives} archive{s}; no problems found"
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let s = if problems.len() == 1 { "" } else { "s" };
+    if !repair {
+        writeln!(
+            printer.stderr(),
+            "Found {} problem{s}; run `{}` to remove the affected entries",
+            problems.len(),
+            "uv cache verify --repair".green()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Remove the problematic entries. For corrupted archives, remove every entry that refers to
+    // the archive, such that the distribution is fetched again the next time it's needed.
+    let mut summary = Removal::default();
+    for problem in &problems {
+        if let Problem::Corrupted(path, _) = problem {
+            for reference in references.get(path).into_iter().flatten() {
+                debug!(
+                    "Removing reference to corrupted archive: {}",
+                    reference.display()
+                );
+                summary += rm_rf(reference)?;
+            }
+        }
+        debug!("Removing cache entry: {}", problem.path().display());
+        summary += rm_rf(problem.path())?;
+    }
+
+    write!(
+        printer.stderr(),
+        "Repaired {} problem{s}, removing {} file{}",
+        problems.len(),
+        summary.num_files,
+        if summary.num_files == 1 { "" } else { "s" }
+    )?;
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({})", bytes.green())?;
+    }
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// The result of verifying the cache.
+#[derive(Debug)]
+struct Verification {
+    /// The problems found in the cache.
+    problems: Vec<Problem>,
+    /// The entries that refer to each corrupted unzipped wheel.
+    references: FxHashMap<PathBuf, Vec<PathBuf>>,
+    /// The number of unzipped wheels that were verified.
+    archives: usize,
+}
+
+/// Walk the cache buckets, verifying each entry.
+fn verify(cache: &Cache) -> Result<Verification> {
+    let mut problems = Vec::new();
+
+    // Index the links to unzipped wheels across all buckets.
+    let mut links: FxHashMap<PathBuf, Vec<PathBuf>> = FxHashMap::default();
+    for bucket in CacheBucket::iter() {
+        if bucket == CacheBucket::Archive {
+            continue;
+        }
+        let bucket = cache.bucket(bucket);
+        if !bucket.is_dir() {
+            continue;
+        }
+        for entry in walkdir::WalkDir::new(bucket) {
+            let entry = entry?;
+            if !entry.file_type().is_symlink() {
+                continue;
+            }
+            match fs_err::canonicalize(entry.path()) {
+                Ok(target) => links.entry(target).or_default().push(entry.into_path()),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    problems.push(Problem::BrokenLink(entry.into_path()));
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    // Index the pointers to unzipped wheels, which are stored alongside the links.
+    let mut pointers: FxHashMap<PathBuf, Vec<PathBuf>> = FxHashMap::default();
+    let bucket = cache.bucket(CacheBucket::Wheels);
+    if bucket.is_dir() {
+        for entry in walkdir::WalkDir::new(bucket) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let archive = match entry.path().extension().and_then(|ext| ext.to_str()) {
+                Some("http") => HttpArchivePointer::read_from(entry.path())
+                    .map(|pointer| pointer.map(HttpArchivePointer::into_archive)),
+                Some("rev") => LocalArchivePointer::read_from(entry.path())
+                    .map(|pointer| pointer.map(LocalArchivePointer::into_archive)),
+                _ => continue,
+            };
+            match archive {
+                Ok(Some(archive)) => {
+                    let target = cache.archive(&archive.id);
+                    if target.is_dir() {
+                        pointers.entry(target).or_default().push(entry.into_path());
+                    } else {
+                        problems.push(Problem::DanglingPointer(entry.into_path()));
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => problems.push(Problem::InvalidPointer(entry.into_path(), err)),
+            }
+        }
+    }
+
+    // Verify the contents of each unzipped wheel.
+    let mut references: FxHashMap<PathBuf, Vec<PathBuf>> = FxHashMap::default();
+    let mut archives = 0;
+    match fs_err::read_dir(cache.bucket(CacheBucket::Archive)) {
+        Ok(entries) => {
+            for entry in entries {
+                let path = entry?.path();
+                let canonical = fs_err::canonicalize(&path)?;
+                let Some(referrers) = links.remove(&canonical) else {
+                    problems.push(Problem::Orphaned(path));
+                    continue;
+                };
+
+                archives += 1;
+                if let Err(err) = uv_install_wheel::linker::verify_wheel(&path) {
+                    let referrers = referrers
+                        .into_iter()
+                        .chain(pointers.remove(&path).unwrap_or_default())
+                        .collect();
+                    references.insert(path.clone(), referrers);
+                    problems.push(Problem::Corrupted(path, err));
+                }
+            }
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+        Err(err) => return Err(err.into()),
+    }
+
+    Ok(Verification {
+        problems,
+        references,
+        archives,
+    })
+}
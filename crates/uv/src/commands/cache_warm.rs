@@ -0,0 +1,496 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    Concurrency, Constraints, DevGroupsManifest, ExtrasSpecification, HashCheckingMode,
+    InstallOptions, LowerBound, Reinstall, TargetTriple, TrustedHost,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution::{DistributionDatabase, RegistryWheelIndex};
+use uv_distribution_types::{
+    BuiltDist, Dist, DistributionId, Identifier, Index, Name, Origin, Resolution, ResolvedDist,
+    SourceDist,
+};
+use uv_installer::Preparer;
+use uv_platform_tags::TagPreferences;
+use uv_pypi_types::ConflictingGroupList;
+use uv_python::{
+    EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
+    PythonVersion,
+};
+use uv_requirements::RequirementsSource;
+use uv_resolver::{
+    FlatIndex, GroupResolution, IndexPatterns, InstallTarget, OptionsBuilder, PythonRequirement,
+    ResolverEnvironment, Variants, Vendored,
+};
+use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::project::sync::store_credentials_from_workspace;
+use crate::commands::project::{lock, ProjectError, WorkspacePython};
+use crate::commands::reporters::PrepareReporter;
+use crate::commands::{elapsed, ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Prefetch the distributions required by a lockfile or requirements file into the cache.
+pub(crate) async fn cache_warm(
+    project_dir: &Path,
+    requirements: &[PathBuf],
+    python: Option<String>,
+    python_versions: &[PythonVersion],
+    python_platforms: &[TargetTriple],
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let ResolverSettings {
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        resolution,
+        prerelease,
+        dependency_metadata,
+        config_setting,
+        no_build_isolation,
+        no_build_isolation_package,
+        exclude_newer,
+        max_backtracks,
+        link_mode,
+        upgrade,
+        build_options,
+        sources,
+    } = settings;
+
+    // If no requirements were provided, prefetch the distributions in the project's lockfile.
+    let project = if requirements.is_empty() {
+        Some(VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await?)
+    } else {
+        None
+    };
+    let lock = if let Some(project) = project.as_ref() {
+        Some(
+            lock::read(project.workspace())
+                .await?
+                .ok_or(ProjectError::MissingLockfile)?,
+        )
+    } else {
+        None
+    };
+
+    // Find an interpreter, which is used to build source distributions, and as the basis for
+    // the target environments.
+    let WorkspacePython { python_request, .. } = WorkspacePython::from_request(
+        python.as_deref().map(PythonRequest::parse),
+        project.as_ref().map(VirtualProject::workspace),
+        project_dir,
+        no_config,
+    )
+    .await?;
+    let interpreter = PythonInstallation::find(
+        &python_request.unwrap_or_default(),
+        EnvironmentPreference::Any,
+        python_preference,
+        cache,
+    )?
+    .into_interpreter();
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .keyring(keyring_provider)
+        .allow_insecure_host(allow_insecure_host.to_vec());
+
+    // Read the requirements, if any.
+    let spec = if requirements.is_empty() {
+        None
+    } else {
+        let sources = requirements
+            .iter()
+            .cloned()
+            .map(RequirementsSource::from_requirements_file)
+            .collect::<Vec<_>>();
+        Some(
+            operations::read_requirements(
+                &sources,
+                &[],
+                &[],
+                &ExtrasSpecification::default(),
+                &client_builder,
+            )
+            .await?,
+        )
+    };
+
+    // Incorporate any index locations and build options from the provided sources.
+    let (index_locations, build_options) = if let Some(spec) = spec.as_ref() {
+        (
+            index_locations.combine(
+                spec.extra_index_urls
+                    .iter()
+                    .cloned()
+                    .map(Index::from_extra_index_url)
+                    .chain(spec.index_url.clone().map(Index::from_index_url))
+                    .map(|index| index.with_origin(Origin::RequirementsTxt))
+                    .collect(),
+                spec.find_links
+                    .iter()
+                    .cloned()
+                    .map(Index::from_find_links)
+                    .map(|index| index.with_origin(Origin::RequirementsTxt))
+                    .collect(),
+                spec.no_index,
+            ),
+            build_options.combine(spec.no_binary.clone(), spec.no_build.clone()),
+        )
+    } else {
+        (index_locations, build_options)
+    };
+
+    // Add all authenticated sources to the cache.
+    for index in index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            uv_auth::store_credentials(index.raw_url(), credentials);
+        }
+    }
+    if let Some(project) = project.as_ref() {
+        store_credentials_from_workspace(project.workspace());
+    }
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Determine whether to enable build isolation.
+    let environment = PythonEnvironment::from_interpreter(interpreter.clone());
+    let build_isolation = if no_build_isolation {
+        BuildIsolation::Shared(&environment)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(&environment, &no_build_isolation_package)
+    };
+
+    let options = OptionsBuilder::new()
+        .resolution_mode(resolution)
+        .prerelease_mode(prerelease)
+        .exclude_newer(exclude_newer)
+        .max_backtracks(max_backtracks)
+        .index_strategy(index_strategy)
+        .build();
+
+    // Each Python version is combined with each platform to form the set of targets.
+    let python_versions = if python_versions.is_empty() {
+        vec![None]
+    } else {
+        python_versions.iter().map(Some).collect()
+    };
+    let python_platforms = if python_platforms.is_empty() {
+        vec![None]
+    } else {
+        python_platforms.iter().map(Some).collect()
+    };
+
+    let mut seen = FxHashSet::<DistributionId>::default();
+    let mut targets = 0usize;
+    let mut prefetched = 0usize;
+    let mut cached = 0usize;
+    let mut skipped = FxHashSet::<DistributionId>::default();
+
+    for python_platform in &python_platforms {
+        for python_version in &python_versions {
+            let marker_env = resolution_markers(*python_version, *python_platform, &interpreter);
+            let tags = resolution_tags(
+                *python_version,
+                *python_platform,
+                &interpreter,
+                tag_preferences,
+            )?;
+            let description = format!(
+                "Python {} on {} ({})",
+                marker_env.python_version(),
+                marker_env.sys_platform(),
+                marker_env.platform_machine()
+            );
+
+            // Read the distributions for the target from the lockfile, if available.
+            let locked = if let (Some(project), Some(lock)) = (project.as_ref(), lock.as_ref()) {
+                if !lock
+                    .requires_python()
+                    .contains(&marker_env.python_full_version().version)
+                {
+                    warn_user!(
+                        "Skipping {description}, which is not compatible with the locked Python requirement: `{}`",
+                        lock.requires_python()
+                    );
+                    continue;
+                }
+                let environments = lock.supported_environments();
+                if !environments.is_empty()
+                    && !environments
+                        .iter()
+                        .any(|env| env.evaluate(&marker_env, &[]))
+                {
+                    warn_user!(
+                        "Skipping {description}, which is not one of the lockfile's supported environments"
+                    );
+                    continue;
+                }
+
+                let target = match project {
+                    VirtualProject::Project(project) => InstallTarget::Workspace {
+                        workspace: project.workspace(),
+                        lock,
+                    },
+                    VirtualProject::NonProject(workspace) => {
+                        InstallTarget::NonProjectWorkspace { workspace, lock }
+                    }
+                };
+                let groups = project.workspace().groups().into_iter().cloned().collect();
+                Some(target.to_resolution(
+                    &marker_env,
+                    &tags,
+                    &ExtrasSpecification::All,
+                    &DevGroupsManifest::from_defaults(groups),
+                    &build_options,
+                    &InstallOptions::default(),
+                )?)
+            } else {
+                None
+            };
+
+            // Extract the hashes from the lockfile.
+            let hasher = if let Some(resolution) = locked.as_ref() {
+                HashStrategy::from_resolution(resolution, HashCheckingMode::Verify)?
+            } else {
+                HashStrategy::None
+            };
+
+            // Resolve the flat indexes from `--find-links`.
+            let flat_index = {
+                let client = FlatIndexClient::new(&client, cache);
+                let entries = client
+                    .fetch(index_locations.flat_indexes().map(Index::url))
+                    .await?;
+                FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+            };
+
+            // Use isolated state for each target, as the in-memory index is specific to the
+            // target's tags.
+            let state = SharedState::default();
+
+            // Create a build dispatch.
+            let build_dispatch = BuildDispatch::new(
+                &client,
+                cache,
+                Constraints::default(),
+                &interpreter,
+                &index_locations,
+                &flat_index,
+                &dependency_metadata,
+                &state.index,
+                &state.git,
+                &state.capabilities,
+                &state.in_flight,
+                index_strategy,
+                &config_setting,
+                build_isolation,
+                link_mode,
+                &build_options,
+                &HashStrategy::None,
+                exclude_newer,
+                LowerBound::Warn,
+                sources,
+                concurrency,
+                tag_preferences,
+            );
+
+            // Otherwise, resolve the requirements for the target.
+            let resolution = if let Some(resolution) = locked {
+                resolution
+            } else if let Some(spec) = spec.as_ref() {
+                let python_requirement = if let Some(python_version) = python_version {
+                    PythonRequirement::from_python_version(&interpreter, python_version)
+                } else {
+                    PythonRequirement::from_interpreter(&interpreter)
+                };
+                let graph = operations::resolve(
+                    spec.requirements.clone(),
+                    spec.constraints.clone(),
+                    spec.overrides.clone(),
+                    Vec::default(),
+                    spec.source_trees.clone(),
+                    spec.project.clone(),
+                    None,
+                    &ExtrasSpecification::default(),
+                    Vec::default(),
+                    EmptyInstalledPackages,
+                    &hasher,
+                    &Reinstall::None,
+                    &upgrade,
+                    Some(&tags),
+                    ResolverEnvironment::specific(marker_env.clone()),
+                    python_requirement,
+                    ConflictingGroupList::empty(),
+                    Variants::default(),
+                    GroupResolution::default(),
+                    Vendored::default(),
+                    IndexPatterns::default(),
+                    &client,
+                    &flat_index,
+                    &state.index,
+                    &build_dispatch,
+                    concurrency,
+                    options,
+                    Box::new(DefaultResolveLogger),
+                    printer,
+                )
+                .await?;
+                Resolution::from(graph)
+            } else {
+                unreachable!("either a lockfile or requirements must be provided")
+            };
+
+            targets += 1;
+
+            // Identify the distributions that are missing from the cache.
+            let mut registry_index =
+                RegistryWheelIndex::new(cache, &tags, &index_locations, &hasher);
+            let mut remote = vec![];
+            for dist in resolution.distributions() {
+                let ResolvedDist::Installable(dist) = dist else {
+                    continue;
+                };
+
+                // Local distributions are read from disk, and never need to be prefetched.
+                if matches!(
+                    dist,
+                    Dist::Built(BuiltDist::Path(_))
+                        | Dist::Source(SourceDist::Path(_) | SourceDist::Directory(_))
+                ) {
+                    continue;
+                }
+
+                // Source distributions can only be built for the current platform.
+                if python_platform.is_some() && matches!(dist, Dist::Source(_)) {
+                    debug!("Skipping source distribution for {description}: {dist}");
+                    skipped.insert(dist.distribution_id());
+                    continue;
+                }
+
+                if !seen.insert(dist.distribution_id()) {
+                    continue;
+                }
+
+                let index = match dist {
+                    Dist::Built(BuiltDist::Registry(wheels)) => Some((
+                        &wheels.best_wheel().index,
+                        &wheels.best_wheel().filename.version,
+                    )),
+                    Dist::Source(SourceDist::Registry(sdist)) => {
+                        Some((&sdist.index, &sdist.version))
+                    }
+                    _ => None,
+                };
+                if let Some((index, version)) = index {
+                    if registry_index.get(dist.name()).any(|entry| {
+                        entry.index.url() == index && entry.dist.filename.version == *version
+                    }) {
+                        debug!("Requirement already cached: {dist}");
+                        cached += 1;
+                        continue;
+                    }
+                }
+
+                remote.push(dist.clone());
+            }
+
+            if remote.is_empty() {
+                debug!("All distributions for {description} are already cached");
+                continue;
+            }
+
+            writeln!(
+                printer.stderr(),
+                "Prefetching {} for {description}",
+                format!(
+                    "{} package{}",
+                    remote.len(),
+                    if remote.len() == 1 { "" } else { "s" }
+                )
+                .bold()
+            )?;
+
+            let preparer = Preparer::new(
+                cache,
+                &tags,
+                &hasher,
+                &build_options,
+                DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+            )
+            .with_reporter(PrepareReporter::from(printer).with_length(remote.len() as u64));
+
+            prefetched += preparer.prepare(remote, &state.in_flight).await?.len();
+        }
+    }
+
+    let s = if prefetched == 1 { "" } else { "s" };
+    let t = if targets == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Prefetched {} for {} {}",
+            format!("{prefetched} package{s}").bold(),
+            format!("{targets} target{t}").bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    if cached > 0 {
+        let s = if cached == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("{cached} package{s} already cached").dimmed()
+        )?;
+    }
+    if !skipped.is_empty() {
+        let skipped = skipped.len();
+        let s = if skipped == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Skipped {skipped} source distribution{s}, which can only be built for the current platform"
+            )
+            .dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
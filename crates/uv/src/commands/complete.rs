@@ -0,0 +1,365 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::{Arg, CommandFactory};
+use clap_complete_command::Shell;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::Cli;
+use uv_client::{Connectivity, RegistryClient, RegistryClientBuilder};
+use uv_configuration::{KeyringProviderType, TrustedHost};
+use uv_distribution_types::{IndexCapabilities, IndexLocations};
+use uv_normalize::{PackageName, DEV_DEPENDENCIES};
+use uv_pep440::Version;
+use uv_static::EnvVars;
+use uv_workspace::pyproject::PyProjectToml;
+
+use crate::commands::cache_export::cached_packages;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The commands that accept requirements as positional arguments.
+const REQUIREMENT_COMMANDS: &[&[&str]] = &[
+    &["add"],
+    &["pip", "install"],
+    &["tool", "install"],
+    &["tool", "run"],
+];
+
+/// The kind of value to complete.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Completion {
+    /// The name of a package, e.g., `uv add <package>`.
+    Package,
+    /// A version of the given package, e.g., `uv add <package>==<version>`.
+    Version(PackageName),
+    /// An extra of the current project, e.g., `--extra <extra>`.
+    Extra,
+    /// A dependency group of the current project, e.g., `--group <group>`.
+    Group,
+}
+
+/// The word under the cursor, and the kind of value to complete it with.
+#[derive(Debug, PartialEq, Eq)]
+struct Context<'a> {
+    completion: Completion,
+    /// The portion of the word that precedes the value, e.g., `--extra=` or `requests==`.
+    prefix: &'a str,
+    /// The portion of the value that has been typed so far.
+    value: &'a str,
+}
+
+/// Complete the word under the cursor, for the dynamic layer of the shell completion scripts.
+///
+/// Prints nothing if the word can't be completed dynamically, in which case the shell falls back
+/// to the static completions.
+pub(crate) async fn complete(
+    shell: Shell,
+    line: &str,
+    project_dir: &Path,
+    index_locations: &IndexLocations,
+    keyring_provider: KeyringProviderType,
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let Some(context) = context(line) else {
+        return Ok(ExitStatus::Success);
+    };
+    debug!("Completing {:?} for: `{line}`", context.completion);
+
+    let candidates = match &context.completion {
+        Completion::Package => {
+            let value = context.value.to_lowercase().replace(['_', '.'], "-");
+            cached_packages(cache)?
+                .into_iter()
+                .map(|name| name.to_string())
+                .filter(|name| name.starts_with(&value))
+                .collect::<Vec<_>>()
+        }
+        Completion::Version(name) => {
+            let client = RegistryClientBuilder::new(cache.clone())
+                .native_tls(native_tls)
+                .keyring(keyring_provider)
+                .allow_insecure_host(allow_insecure_host.to_vec())
+                .index_urls(index_locations.index_urls());
+            versions(client, name, connectivity)
+                .await
+                .into_iter()
+                .map(|version| version.to_string())
+                .filter(|version| version.starts_with(context.value))
+                .collect()
+        }
+        Completion::Extra => project(project_dir)
+            .and_then(|pyproject| pyproject.project)
+            .and_then(|project| project.optional_dependencies)
+            .map(|extras| extras.into_keys().map(|extra| extra.to_string()).collect())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|extra: &String| extra.starts_with(context.value))
+            .collect(),
+        Completion::Group => {
+            let pyproject = project(project_dir);
+            let mut groups = pyproject
+                .as_ref()
+                .and_then(|pyproject| pyproject.dependency_groups.as_ref())
+                .map(|groups| groups.keys().map(ToString::to_string).collect::<Vec<_>>())
+                .unwrap_or_default();
+            if pyproject
+                .as_ref()
+                .and_then(|pyproject| pyproject.tool.as_ref())
+                .and_then(|tool| tool.uv.as_ref())
+                .is_some_and(|uv| uv.dev_dependencies.is_some())
+            {
+                groups.push(DEV_DEPENDENCIES.to_string());
+            }
+            groups.sort_unstable();
+            groups.dedup();
+            groups
+                .into_iter()
+                .filter(|group| group.starts_with(context.value))
+                .collect()
+        }
+    };
+
+    // Bash splits words on `=` and `:`, and expects candidates for the portion of the word that
+    // follows the last such character.
+    let word = format!("{}{}", context.prefix, context.value);
+    let skip = if matches!(shell, Shell::Bash) {
+        word.rfind(['=', ':']).map_or(0, |index| index + 1)
+    } else {
+        0
+    };
+    for candidate in candidates {
+        let candidate = format!("{}{candidate}", context.prefix);
+        writeln!(printer.stdout(), "{}", &candidate[skip..])?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Determine the kind of value with which to complete the last word of the command line.
+fn context(line: &str) -> Option<Context<'_>> {
+    let mut words = line.split_whitespace().collect::<Vec<_>>();
+    let current = if line.ends_with(char::is_whitespace) {
+        ""
+    } else {
+        words.pop()?
+    };
+
+    let mut cli = Cli::command();
+    cli.build();
+
+    // Walk the preceding words (skipping the executable) to determine the subcommand, and whether
+    // the current word is the value of an option.
+    let mut command = &cli;
+    let mut path = Vec::new();
+    let mut pending: Option<&Arg> = None;
+    for word in words.iter().skip(1) {
+        if pending.take().is_some() {
+            continue;
+        }
+        if *word == "--" {
+            // Any subsequent arguments are passed through, e.g., to `uv run`.
+            return None;
+        }
+        let arg = if let Some(long) = word.strip_prefix("--") {
+            if long.contains('=') {
+                continue;
+            }
+            command
+                .get_arguments()
+                .find(|arg| arg.get_long() == Some(long))
+        } else if let Some(short) = word.strip_prefix('-') {
+            let mut chars = short.chars();
+            match (chars.next(), chars.next()) {
+                (Some(short), None) => command
+                    .get_arguments()
+                    .find(|arg| arg.get_short() == Some(short)),
+                _ => None,
+            }
+        } else {
+            if let Some(subcommand) = command.find_subcommand(word) {
+                command = subcommand;
+                path.push(subcommand.get_name());
+            }
+            continue;
+        };
+        pending = arg.filter(|arg| arg.get_action().takes_values());
+    }
+
+    // Complete the value of an option, given as `--option <value>` or `--option=<value>`.
+    let option = if let Some(arg) = pending {
+        Some((arg.get_long()?, "", current))
+    } else if let Some((long, value)) = current
+        .strip_prefix("--")
+        .and_then(|option| option.split_once('='))
+    {
+        Some((long, &current[..current.len() - value.len()], value))
+    } else {
+        None
+    };
+    if let Some((long, prefix, value)) = option {
+        let completion = match long {
+            "extra" | "optional" => Completion::Extra,
+            "group" | "only-group" | "no-group" => Completion::Group,
+            "upgrade-package" | "reinstall-package" | "refresh-package" => Completion::Package,
+            _ => return None,
+        };
+        return Some(Context {
+            completion,
+            prefix,
+            value,
+        });
+    }
+
+    // Complete a requirement, e.g., `uv add <package>` or `uv add <package>==<version>`.
+    if current.starts_with('-')
+        || !REQUIREMENT_COMMANDS
+            .iter()
+            .any(|command| *command == path.as_slice())
+    {
+        return None;
+    }
+    if let Some((name, value)) = current.split_once("==") {
+        let name = name.split_once('[').map_or(name, |(name, _)| name);
+        return Some(Context {
+            completion: Completion::Version(PackageName::from_str(name).ok()?),
+            prefix: &current[..current.len() - value.len()],
+            value,
+        });
+    }
+    Some(Context {
+        completion: Completion::Package,
+        prefix: "",
+        value: current,
+    })
+}
+
+/// Return the available versions of a package, newest first.
+///
+/// Versions are read from the cache; if the package isn't cached, the index is queried instead,
+/// but only if `UV_COMPLETION_INDEX_TIMEOUT` is set, and only for up to the given timeout.
+async fn versions(
+    client: RegistryClientBuilder<'_>,
+    name: &PackageName,
+    connectivity: Connectivity,
+) -> Vec<Version> {
+    let cached = cached_versions(
+        &client.clone().connectivity(Connectivity::Offline).build(),
+        name,
+    )
+    .await;
+    if !cached.is_empty() || matches!(connectivity, Connectivity::Offline) {
+        return cached;
+    }
+
+    let Some(timeout) = std::env::var(EnvVars::UV_COMPLETION_INDEX_TIMEOUT)
+        .ok()
+        .and_then(|timeout| timeout.parse::<u64>().ok())
+        .filter(|timeout| *timeout > 0)
+        .map(Duration::from_millis)
+    else {
+        return cached;
+    };
+    let client = client.connectivity(connectivity).build();
+    tokio::time::timeout(timeout, cached_versions(&client, name))
+        .await
+        .unwrap_or_default()
+}
+
+/// Return the versions of a package available via the given client, newest first.
+async fn cached_versions(client: &RegistryClient, name: &PackageName) -> Vec<Version> {
+    let archives = match client
+        .simple(name, None, &IndexCapabilities::default())
+        .await
+    {
+        Ok(archives) => archives,
+        Err(err) => {
+            debug!("Failed to fetch versions of `{name}` for completion: {err}");
+            return Vec::new();
+        }
+    };
+
+    let mut versions = Vec::new();
+    for (_, archive) in archives {
+        for datum in archive.iter() {
+            versions.push(
+                rkyv::deserialize::<Version, rkyv::rancor::Error>(&datum.version)
+                    .expect("archived version always deserializes"),
+            );
+        }
+    }
+    versions.sort_unstable_by(|a, b| b.cmp(a));
+    versions.dedup();
+    versions
+}
+
+/// Read the `pyproject.toml` of the project containing the given directory, if any.
+fn project(project_dir: &Path) -> Option<PyProjectToml> {
+    project_dir.ancestors().find_map(|dir| {
+        let contents = fs_err::read_to_string(dir.join("pyproject.toml")).ok()?;
+        PyProjectToml::from_string(contents).ok()
+    })
+}
+
+/// Return the dynamic layer of the completion script for the given shell, if supported, to be
+/// appended to the static completions generated by `clap`.
+///
+/// The dynamic layer invokes `uv __complete` with the command line up to the cursor, and falls
+/// back to the static completions if it prints nothing.
+pub(crate) fn dynamic_completion_script(shell: Shell) -> Option<&'static str> {
+    match shell {
+        Shell::Bash => Some(
+            r#"
+_uv_dynamic() {
+    local IFS=$'\n'
+    local candidates
+    candidates=($(uv __complete bash -- "${COMP_LINE:0:COMP_POINT}" 2>/dev/null))
+    if [[ ${#candidates[@]} -gt 0 ]]; then
+        COMPREPLY=("${candidates[@]}")
+        return 0
+    fi
+    _uv "$@"
+}
+
+if [[ "${BASH_VERSINFO[0]}" -eq 4 && "${BASH_VERSINFO[1]}" -ge 4 || "${BASH_VERSINFO[0]}" -gt 4 ]]; then
+    complete -F _uv_dynamic -o nosort -o bashdefault -o default uv
+else
+    complete -F _uv_dynamic -o bashdefault -o default uv
+fi
+"#,
+        ),
+        Shell::Zsh => Some(
+            r#"
+_uv_dynamic() {
+    local -a candidates
+    candidates=("${(@f)$(uv __complete zsh -- "${LBUFFER}" 2>/dev/null)}")
+    if [[ -n "${candidates[1]}" ]]; then
+        compadd -- "${candidates[@]}"
+        return
+    fi
+    _uv "$@"
+}
+
+compdef _uv_dynamic uv
+"#,
+        ),
+        Shell::Fish => Some(
+            r#"
+function __uv_dynamic_complete
+    uv __complete fish -- (commandline -cp) 2>/dev/null
+end
+
+complete -c uv -f -n '__uv_dynamic_complete | string length -q' -a '(__uv_dynamic_complete)'
+"#,
+        ),
+        _ => None,
+    }
+}
@@ -0,0 +1,167 @@
+//! Inspect and modify uv's configuration (`uv config`).
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use uv_fs::Simplified;
+use uv_settings::{
+    effective_settings, find_config_file, is_setting, set_setting, system_config_file,
+    user_config_path, ConfigEntry, ConfigLayer, ConfigOrigin,
+};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the effective value of each setting.
+pub(crate) async fn config_list(
+    config_files: &[PathBuf],
+    no_config: bool,
+    project_dir: &Path,
+    origin: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let layers = config_layers(config_files, no_config, project_dir).await?;
+
+    for entry in effective_settings(&layers) {
+        write_entry(&entry, true, origin, printer)?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Show the effective value of a setting.
+pub(crate) async fn config_get(
+    config_files: &[PathBuf],
+    no_config: bool,
+    project_dir: &Path,
+    key: &str,
+    origin: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !is_setting(key) {
+        bail!("Unknown setting: `{key}`");
+    }
+
+    let layers = config_layers(config_files, no_config, project_dir).await?;
+
+    let Some(entry) = effective_settings(&layers)
+        .into_iter()
+        .find(|entry| entry.key == key)
+    else {
+        writeln!(printer.stderr(), "`{}` is not set", key.cyan())?;
+        return Ok(ExitStatus::Failure);
+    };
+
+    write_entry(&entry, false, origin, printer)?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Set the value of a setting in a configuration file.
+pub(crate) async fn config_set(
+    config_files: &[PathBuf],
+    no_config: bool,
+    project_dir: &Path,
+    key: &str,
+    value: &str,
+    user: bool,
+    file: Option<PathBuf>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let path = if let Some(file) = file {
+        file
+    } else if user {
+        let Some(path) = user_config_path() else {
+            bail!("Unable to determine the user configuration directory");
+        };
+        path
+    } else if let Some(path) = config_files.last() {
+        path.clone()
+    } else {
+        let root = project_root(project_dir).await;
+        find_config_file(&root).unwrap_or_else(|| root.join("uv.toml"))
+    };
+
+    set_setting(&path, key, value)?;
+
+    writeln!(
+        printer.stderr(),
+        "Set `{}` in: {}",
+        key.cyan(),
+        path.user_display().bold()
+    )?;
+
+    // Warn if the value is shadowed by a higher-precedence source.
+    let layers = config_layers(config_files, no_config, project_dir).await?;
+    if let Some(entry) = effective_settings(&layers)
+        .into_iter()
+        .find(|entry| entry.key == key)
+    {
+        if entry.origin != ConfigOrigin::File(path) {
+            warn_user!(
+                "`{key}` is overridden by `{}`, which takes precedence",
+                entry.origin
+            );
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Read the configuration layers, in order of precedence, mirroring the configuration discovery
+/// used by every other command.
+async fn config_layers(
+    config_files: &[PathBuf],
+    no_config: bool,
+    project_dir: &Path,
+) -> Result<Vec<ConfigLayer>> {
+    // Environment variables take precedence over any configuration file.
+    let mut layers = ConfigLayer::from_env();
+
+    if !config_files.is_empty() {
+        // Later files take precedence over earlier ones.
+        for config_file in config_files.iter().rev() {
+            layers.push(ConfigLayer::from_file(config_file)?);
+        }
+    } else if !no_config {
+        let root = project_root(project_dir).await;
+        let files = [
+            find_config_file(&root),
+            user_config_path().filter(|path| path.is_file()),
+            system_config_file(),
+        ];
+        for file in files.into_iter().flatten() {
+            layers.push(ConfigLayer::from_file(&file)?);
+        }
+    }
+
+    Ok(layers)
+}
+
+/// Return the root of the workspace containing the project directory, or the project directory
+/// itself, if it isn't part of a workspace.
+async fn project_root(project_dir: &Path) -> PathBuf {
+    match Workspace::discover(project_dir, &DiscoveryOptions::default()).await {
+        Ok(workspace) => workspace.install_path().clone(),
+        Err(_) => project_dir.to_path_buf(),
+    }
+}
+
+/// Write a configuration entry to stdout, optionally prefixed with its origin.
+fn write_entry(entry: &ConfigEntry, key: bool, origin: bool, printer: Printer) -> Result<()> {
+    let value = if key {
+        format!("{} = {}", entry.key, entry.value)
+    } else {
+        entry.value.clone()
+    };
+    if origin {
+        writeln!(printer.stdout(), "{}\t{value}", entry.origin)?;
+    } else {
+        writeln!(printer.stdout(), "{value}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,588 @@
+//! A long-running process that keeps caches warm across uv invocations.
+//!
+//! The daemon listens on a Unix socket in the cache directory. Delegated invocations send their
+//! arguments, working directory, and environment, along with their standard streams, and the daemon
+//! forks a child to run each one. As the children are forked from the daemon, they inherit its
+//! in-memory caches (see [`uv_cache::MemoryCache`]); when finished, each child reports the entries that it
+//! loaded, such that the daemon can preload them for subsequent invocations.
+//!
+//! Since the children inherit all of the daemon's process-global state, the daemon itself must
+//! avoid initializing any state that depends on the invocation (e.g., the working directory, via
+//! [`uv_fs::CWD`], or the `tracing` subscriber).
+use std::fmt::Write;
+use std::io::{IoSlice, IoSliceMut};
+use std::ops::ControlFlow;
+use std::os::fd::{AsFd, BorrowedFd, OwnedFd};
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use rustix::net::{
+    RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags, SendAncillaryBuffer,
+    SendAncillaryMessage, SendFlags,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::{
+    Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand, PythonNamespace,
+};
+use uv_configuration::PreviewMode;
+use uv_fs::Simplified;
+use uv_python::Interpreter;
+use uv_static::EnvVars;
+use uv_warnings::{warn_user, warn_user_once};
+
+use crate::commands::project::lock;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::GlobalSettings;
+
+/// Whether the current process is running an invocation on behalf of the daemon.
+static DELEGATED: AtomicBool = AtomicBool::new(false);
+
+/// The maximum size of a message, in bytes.
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// The time allowed for a client to send its request, once connected.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A request sent to the daemon.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+enum Request {
+    /// Run an invocation, using the file descriptors sent alongside the request as its standard
+    /// input, output, and error.
+    Run {
+        args: Vec<String>,
+        cwd: PathBuf,
+        env: Vec<(String, String)>,
+    },
+    /// Preload the entries that were loaded by a finished invocation.
+    Preload {
+        interpreters: Vec<PathBuf>,
+        responses: Vec<PathBuf>,
+        lockfiles: Vec<PathBuf>,
+    },
+    /// Report the status of the daemon.
+    Status,
+    /// Stop the daemon.
+    Stop,
+}
+
+/// A response sent by the daemon.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+enum Response {
+    /// The invocation exited with the given code.
+    Exit { code: u8 },
+    /// The daemon is running.
+    Status { pid: u32 },
+    /// The daemon has stopped.
+    Stopped,
+}
+
+/// Return the path to the daemon's socket for the given cache.
+///
+/// The socket is placed in a dedicated directory that's only accessible to the current user, such
+/// that it's never reachable by other users, even before its own permissions are set.
+fn socket_path(cache: &Cache) -> PathBuf {
+    cache.root().join("daemon").join("daemon.sock")
+}
+
+/// Start the daemon, running each delegated invocation via `execute`.
+pub(crate) fn daemon_start(
+    cache: &Cache,
+    preview: PreviewMode,
+    execute: fn(Cli) -> ExitStatus,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv daemon` is experimental and may change without warning. Pass `--preview` to disable this warning.");
+    }
+
+    let socket = socket_path(cache);
+
+    // If a daemon is already listening on the socket, don't start another. Otherwise, the socket
+    // (if any) was left behind by a daemon that exited uncleanly.
+    if UnixStream::connect(&socket).is_ok() {
+        anyhow::bail!(
+            "A daemon is already running at: `{}`",
+            socket.simplified_display()
+        );
+    }
+    match fs_err::remove_file(&socket) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    // Bind the socket within a private directory, rather than restricting the socket's own
+    // permissions after the fact, which would leave a window in which other users could connect.
+    let directory = socket.parent().expect("socket path has a parent directory");
+    std::fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(directory)?;
+    fs_err::set_permissions(directory, std::fs::Permissions::from_mode(0o700))?;
+
+    let listener = UnixListener::bind(&socket)?;
+    fs_err::set_permissions(&socket, std::fs::Permissions::from_mode(0o600))?;
+
+    // Retain the entries loaded by each invocation in memory.
+    uv_cache::enable_memory_cache();
+
+    writeln!(
+        printer.stderr(),
+        "Daemon listening on: {}",
+        socket.simplified_display().cyan()
+    )?;
+
+    // Read each request on a separate thread, such that a slow or misbehaving client can't block
+    // any others. The requests themselves are handled on the main thread, which forks a child to
+    // run each invocation.
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::Builder::new()
+        .name("uv-daemon-accept".to_string())
+        .spawn(move || accept(&listener, &sender))?;
+
+    for connection in receiver {
+        // Reap any children that have exited.
+        while let Ok(Some(_)) = rustix::process::waitpid(None, rustix::process::WaitOptions::NOHANG)
+        {
+        }
+
+        match connection.and_then(|connection| handle(cache, &socket, connection, execute)) {
+            Ok(ControlFlow::Continue(())) => {}
+            Ok(ControlFlow::Break(())) => break,
+            Err(err) => warn_user!("{err:#}"),
+        }
+    }
+
+    writeln!(printer.stderr(), "Daemon stopped")?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// A request received from a client, along with the connection on which to respond.
+struct Connection {
+    stream: UnixStream,
+    request: Request,
+    fds: Vec<OwnedFd>,
+}
+
+/// Accept connections, reading the request from each on a separate thread and passing it (or the
+/// error encountered) to the daemon's main thread.
+///
+/// Errors are reported by the main thread, rather than written to the standard streams directly,
+/// such that these threads never hold a lock that a forked child might need.
+fn accept(listener: &UnixListener, sender: &Sender<Result<Connection>>) {
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                let _ = sender.send(Err(
+                    anyhow::Error::from(err).context("Failed to accept connection")
+                ));
+                continue;
+            }
+        };
+        let client = sender.clone();
+        let spawned = std::thread::Builder::new()
+            .name("uv-daemon-client".to_string())
+            .spawn(move || {
+                let connection = stream
+                    .set_read_timeout(Some(REQUEST_TIMEOUT))
+                    .and_then(|()| receive::<Request>(&stream))
+                    .map(|(request, fds)| Connection {
+                        stream,
+                        request,
+                        fds,
+                    })
+                    .map_err(|err| anyhow::Error::from(err).context("Failed to read request"));
+                // If the daemon has stopped, the request is discarded.
+                let _ = client.send(connection);
+            });
+        if let Err(err) = spawned {
+            let _ =
+                sender
+                    .send(Err(anyhow::Error::from(err)
+                        .context("Failed to spawn a thread for the connection")));
+        }
+    }
+}
+
+/// Handle a request, returning [`ControlFlow::Break`] if the daemon should stop.
+fn handle(
+    cache: &Cache,
+    socket: &Path,
+    Connection {
+        stream,
+        request,
+        fds,
+    }: Connection,
+    execute: fn(Cli) -> ExitStatus,
+) -> Result<ControlFlow<()>> {
+    match request {
+        Request::Run { args, cwd, env } => {
+            let Ok([stdin, stdout, stderr]) = <[OwnedFd; 3]>::try_from(fds) else {
+                anyhow::bail!("Received an invocation without its standard streams");
+            };
+            spawn(
+                socket,
+                stream,
+                [stdin, stdout, stderr],
+                Invocation { args, cwd, env },
+                execute,
+            )?;
+        }
+        Request::Preload {
+            interpreters,
+            responses,
+            lockfiles,
+        } => {
+            preload(cache, interpreters, responses, lockfiles);
+        }
+        Request::Status => {
+            send(
+                &stream,
+                &Response::Status {
+                    pid: std::process::id(),
+                },
+                &[],
+            )?;
+        }
+        Request::Stop => {
+            if let Err(err) = fs_err::remove_file(socket) {
+                warn_user!("Failed to remove the daemon socket: {err}");
+            }
+            let _ = send(&stream, &Response::Stopped, &[]);
+            return Ok(ControlFlow::Break(()));
+        }
+    }
+    Ok(ControlFlow::Continue(()))
+}
+
+/// An invocation delegated to the daemon.
+struct Invocation {
+    args: Vec<String>,
+    cwd: PathBuf,
+    env: Vec<(String, String)>,
+}
+
+/// Fork a child to run the given invocation.
+#[allow(unsafe_code)]
+fn spawn(
+    socket: &Path,
+    stream: UnixStream,
+    fds: [OwnedFd; 3],
+    invocation: Invocation,
+    execute: fn(Cli) -> ExitStatus,
+) -> Result<()> {
+    // SAFETY: The daemon's other threads only accept connections and read requests; they never
+    // write to the standard streams or touch the in-memory caches, so the child can't inherit any
+    // locks that it would later need (aside from the allocator's, which are reset on fork).
+    match unsafe { nix::unistd::fork() }? {
+        nix::unistd::ForkResult::Parent { child } => {
+            debug!("Forked child {child} to run: {}", invocation.args.join(" "));
+            Ok(())
+        }
+        nix::unistd::ForkResult::Child => {
+            let code = run_child(socket, &stream, fds, invocation, execute);
+            drop(stream);
+            #[allow(clippy::exit)]
+            std::process::exit(i32::from(code));
+        }
+    }
+}
+
+/// Run an invocation in a forked child, returning its exit code.
+fn run_child(
+    socket: &Path,
+    stream: &UnixStream,
+    [stdin, stdout, stderr]: [OwnedFd; 3],
+    invocation: Invocation,
+    execute: fn(Cli) -> ExitStatus,
+) -> u8 {
+    // Detach from the daemon's session, such that signals sent to the daemon's terminal don't
+    // interrupt the invocation.
+    let _ = rustix::process::setsid();
+
+    // Adopt the client's standard streams, working directory, and environment.
+    if rustix::stdio::dup2_stdin(&stdin).is_err()
+        || rustix::stdio::dup2_stdout(&stdout).is_err()
+        || rustix::stdio::dup2_stderr(&stderr).is_err()
+    {
+        return u8::from(ExitStatus::Error);
+    }
+    drop((stdin, stdout, stderr));
+    if let Err(err) = std::env::set_current_dir(&invocation.cwd) {
+        anstream::eprintln!(
+            "{}: Failed to change to the working directory `{}`: {err}",
+            "error".red().bold(),
+            invocation.cwd.simplified_display()
+        );
+        return u8::from(ExitStatus::Error);
+    }
+    let (keys, _): (Vec<_>, Vec<_>) = std::env::vars_os().unzip();
+    for key in keys {
+        std::env::remove_var(key);
+    }
+    for (key, value) in invocation.env {
+        std::env::set_var(key, value);
+    }
+    DELEGATED.store(true, Ordering::Relaxed);
+
+    // Discard the entries loaded by the daemon itself, such that only those loaded by this
+    // invocation are reported.
+    take_loaded();
+
+    let status = match <Cli as clap::Parser>::try_parse_from(&invocation.args) {
+        Ok(mut cli) => {
+            // The client has already changed to the `--directory`, if any.
+            cli.top_level.global_args.directory = None;
+            execute(cli)
+        }
+        Err(err) => {
+            let _ = err.print();
+            ExitStatus::External(u8::try_from(err.exit_code()).unwrap_or(2))
+        }
+    };
+    let code = u8::from(status);
+    let _ = send(stream, &Response::Exit { code }, &[]);
+
+    // Report the entries loaded by this invocation, such that the daemon can preload them.
+    let (interpreters, responses, lockfiles) = take_loaded();
+    if let Ok(daemon) = UnixStream::connect(socket) {
+        let _ = send(
+            &daemon,
+            &Request::Preload {
+                interpreters,
+                responses,
+                lockfiles,
+            },
+            &[],
+        );
+    }
+
+    code
+}
+
+/// Return the entries that have been loaded since the last call.
+fn take_loaded() -> (Vec<PathBuf>, Vec<PathBuf>, Vec<PathBuf>) {
+    (
+        uv_python::take_queried_interpreters(),
+        uv_client::take_read_responses(),
+        lock::take_parsed_lockfiles(),
+    )
+}
+
+/// Load the given entries into the daemon's in-memory caches.
+fn preload(
+    cache: &Cache,
+    interpreters: Vec<PathBuf>,
+    responses: Vec<PathBuf>,
+    lockfiles: Vec<PathBuf>,
+) {
+    for interpreter in interpreters {
+        let _ = Interpreter::query(interpreter, cache);
+    }
+    for response in responses {
+        let _ = uv_client::preload_response(&response);
+    }
+    for lockfile in lockfiles {
+        if let Ok(encoded) = fs_err::read_to_string(&lockfile) {
+            let _ = lock::parse(lockfile, encoded);
+        }
+    }
+}
+
+/// Stop the running daemon.
+pub(crate) fn daemon_stop(cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    let socket = socket_path(cache);
+    let Ok(stream) = UnixStream::connect(&socket) else {
+        writeln!(printer.stderr(), "No daemon is running")?;
+        return Ok(ExitStatus::Failure);
+    };
+    send(&stream, &Request::Stop, &[])?;
+    let (Response::Stopped, _) = receive::<Response>(&stream)? else {
+        anyhow::bail!("Received an unexpected response from the daemon");
+    };
+    writeln!(printer.stderr(), "Stopped daemon")?;
+    Ok(ExitStatus::Success)
+}
+
+/// Show whether a daemon is running.
+pub(crate) fn daemon_status(cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    let socket = socket_path(cache);
+    let Ok(stream) = UnixStream::connect(&socket) else {
+        writeln!(printer.stderr(), "No daemon is running")?;
+        return Ok(ExitStatus::Failure);
+    };
+    send(&stream, &Request::Status, &[])?;
+    let (Response::Status { pid }, _) = receive::<Response>(&stream)? else {
+        anyhow::bail!("Received an unexpected response from the daemon");
+    };
+    writeln!(
+        printer.stdout(),
+        "Daemon running (pid {pid}) at: {}",
+        socket.simplified_display().cyan()
+    )?;
+    Ok(ExitStatus::Success)
+}
+
+/// Delegate the current invocation to a running daemon, if possible.
+///
+/// Returns `None` if the invocation should run in the current process instead.
+pub(crate) fn daemon_delegate(
+    command: &Commands,
+    globals: &GlobalSettings,
+    cache: &Cache,
+) -> Result<Option<ExitStatus>> {
+    if DELEGATED.load(Ordering::Relaxed) || std::env::var_os(EnvVars::UV_NO_DAEMON).is_some() {
+        return Ok(None);
+    }
+
+    // Only invocations that resolve or inspect are delegated. Others either modify state that the
+    // daemon depends on (e.g., `uv cache clean` removes the daemon's socket), run arbitrary
+    // commands (which forward signals to their children, and thus must run in the current process),
+    // or run indefinitely (e.g., `uv proxy`). Similarly, `--progress-fd` refers to a file
+    // descriptor that isn't shared with the daemon.
+    let delegable = match command {
+        Commands::Project(command) => matches!(
+            **command,
+            ProjectCommand::Lock(_) | ProjectCommand::Export(_) | ProjectCommand::Tree(_)
+        ),
+        Commands::Pip(PipNamespace { command }) => matches!(
+            command,
+            PipCommand::Compile(_)
+                | PipCommand::Freeze(_)
+                | PipCommand::List(_)
+                | PipCommand::Show(_)
+                | PipCommand::Tree(_)
+                | PipCommand::Check(_)
+        ),
+        Commands::Python(PythonNamespace { command }) => {
+            matches!(command, PythonCommand::List(_) | PythonCommand::Find(_))
+        }
+        _ => false,
+    };
+    if !delegable || globals.progress_fd.is_some() {
+        return Ok(None);
+    }
+
+    let socket = socket_path(cache);
+    if !socket.exists() {
+        return Ok(None);
+    }
+    let Ok(stream) = UnixStream::connect(&socket) else {
+        return Ok(None);
+    };
+
+    // The invocation is sent as JSON, so arguments and environment variables must be valid UTF-8.
+    let Ok(args) = std::env::args_os()
+        .map(std::ffi::OsString::into_string)
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        return Ok(None);
+    };
+    let Ok(env) = std::env::vars_os()
+        .map(|(key, value)| Ok((key.into_string()?, value.into_string()?)))
+        .collect::<Result<Vec<_>, std::ffi::OsString>>()
+    else {
+        return Ok(None);
+    };
+    let cwd = std::env::current_dir()?;
+    if cwd.to_str().is_none() {
+        return Ok(None);
+    }
+
+    debug!("Delegating to daemon at: {}", socket.user_display());
+
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    let stderr = std::io::stderr();
+    send(
+        &stream,
+        &Request::Run { args, cwd, env },
+        &[stdin.as_fd(), stdout.as_fd(), stderr.as_fd()],
+    )?;
+    match receive::<Response>(&stream) {
+        // Propagate the exit code of the delegated invocation.
+        Ok((Response::Exit { code }, _)) => Ok(Some(ExitStatus::External(code))),
+        Ok(_) => anyhow::bail!("Received an unexpected response from the daemon"),
+        Err(err) => Err(anyhow::Error::from(err).context("The daemon exited unexpectedly")),
+    }
+}
+
+/// Send a message, along with the given file descriptors.
+///
+/// Each message is framed as a four-byte, big-endian length, which carries the file descriptors
+/// (if any), followed by the message itself, encoded as JSON.
+fn send(stream: &UnixStream, message: &impl Serialize, fds: &[BorrowedFd]) -> std::io::Result<()> {
+    let payload = serde_json::to_vec(message)?;
+    let header = u32::try_from(payload.len())
+        .ok()
+        .filter(|_| payload.len() <= MAX_MESSAGE_SIZE)
+        .ok_or_else(|| std::io::Error::other("Message is too large"))?
+        .to_be_bytes();
+
+    let mut space = [0; rustix::cmsg_space!(ScmRights(3))];
+    let mut control = SendAncillaryBuffer::new(&mut space);
+    if !fds.is_empty() && !control.push(SendAncillaryMessage::ScmRights(fds)) {
+        return Err(std::io::Error::other("Too many file descriptors"));
+    }
+    let sent = rustix::net::sendmsg(
+        stream,
+        &[IoSlice::new(&header)],
+        &mut control,
+        SendFlags::empty(),
+    )?;
+
+    std::io::Write::write_all(&mut &*stream, &header[sent..])?;
+    std::io::Write::write_all(&mut &*stream, &payload)
+}
+
+/// Receive a message, along with any file descriptors sent alongside it.
+fn receive<T: DeserializeOwned>(stream: &UnixStream) -> std::io::Result<(T, Vec<OwnedFd>)> {
+    let mut header = [0; 4];
+    let mut space = [0; rustix::cmsg_space!(ScmRights(3))];
+    let mut control = RecvAncillaryBuffer::new(&mut space);
+    let received = rustix::net::recvmsg(
+        stream,
+        &mut [IoSliceMut::new(&mut header)],
+        &mut control,
+        RecvFlags::empty(),
+    )?;
+    if received.bytes == 0 {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+    let fds = control
+        .drain()
+        .filter_map(|message| match message {
+            RecvAncillaryMessage::ScmRights(fds) => Some(fds),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    std::io::Read::read_exact(&mut &*stream, &mut header[received.bytes..])?;
+    let length = usize::try_from(u32::from_be_bytes(header))
+        .ok()
+        .filter(|length| *length <= MAX_MESSAGE_SIZE)
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "Message is too large")
+        })?;
+
+    let mut payload = vec![0; length];
+    std::io::Read::read_exact(&mut &*stream, &mut payload)?;
+    let message = serde_json::from_slice(&payload)?;
+    Ok((message, fds))
+}
@@ -1,11 +1,16 @@
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::LazyLock;
+use std::sync::{LazyLock, OnceLock};
 
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 
 use uv_distribution_types::{BuiltDist, Name, SourceDist};
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
+use uv_static::EnvVars;
+use uv_warnings::warn_user;
 
 use crate::commands::pip;
 
@@ -26,6 +31,22 @@ static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::ne
         .collect()
 });
 
+/// The directory to which build failure reports should be written, if enabled.
+static BUILD_REPORT_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Enable build failure reports, writing them to the given directory.
+pub(crate) fn enable_build_report(dir: PathBuf) {
+    let _ = BUILD_REPORT_DIR.set(dir);
+}
+
+/// The file to which the suggested fixes for a failed resolution should be written, if enabled.
+static RESOLUTION_SUGGESTIONS_FILE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Enable writing the suggested fixes for a failed resolution to the given file.
+pub(crate) fn enable_resolution_suggestions(path: PathBuf) {
+    let _ = RESOLUTION_SUGGESTIONS_FILE.set(path);
+}
+
 /// A rich reporter for operational diagnostics, i.e., errors that occur during resolution and
 /// installation.
 #[derive(Debug, Default)]
@@ -137,6 +158,11 @@ pub(crate) fn download_and_build(sdist: Box<SourceDist>, cause: Error) {
         help: Option<String>,
     }
 
+    let build_report = write_build_report(
+        &sdist,
+        &format!("Failed to download and build `{sdist}`"),
+        &*cause,
+    );
     let report = miette::Report::new(Diagnostic {
         help: SUGGESTIONS.get(sdist.name()).map(|suggestion| {
             format!(
@@ -150,6 +176,9 @@ pub(crate) fn download_and_build(sdist: Box<SourceDist>, cause: Error) {
         cause,
     });
     anstream::eprint!("{report:?}");
+    if let Some(path) = build_report {
+        write_build_report_hint(&path);
+    }
 }
 
 /// Render a remote binary distribution download failure with a help message.
@@ -193,6 +222,7 @@ pub(crate) fn build(sdist: Box<SourceDist>, cause: Error) {
         help: Option<String>,
     }
 
+    let build_report = write_build_report(&sdist, &format!("Failed to build `{sdist}`"), &*cause);
     let report = miette::Report::new(Diagnostic {
         help: SUGGESTIONS.get(sdist.name()).map(|suggestion| {
             format!(
@@ -206,17 +236,123 @@ pub(crate) fn build(sdist: Box<SourceDist>, cause: Error) {
         cause,
     });
     anstream::eprint!("{report:?}");
+    if let Some(path) = build_report {
+        write_build_report_hint(&path);
+    }
+}
+
+/// A structured report of a source distribution build failure, written with `--build-report`.
+#[derive(Debug, Serialize)]
+struct BuildReport<'a> {
+    /// The name of the package that failed to build.
+    package: &'a PackageName,
+    /// The version of the package that failed to build, if known.
+    version: Option<String>,
+    /// The source distribution that failed to build.
+    distribution: String,
+    /// The chain of errors that led to the failure, from outermost to innermost.
+    errors: Vec<String>,
+    /// The resolved requirements of the build environment.
+    build_requirements: &'a [String],
+    /// The `stdout` of the build backend.
+    stdout: &'a [String],
+    /// The `stderr` of the build backend.
+    stderr: &'a [String],
+}
+
+/// Write a [`BuildReport`] for the given build failure to the directory provided via
+/// `--build-report`, if enabled.
+///
+/// Returns the path to the report, if one was written. Only failures that originate in the build
+/// backend (as opposed to, e.g., failing to resolve the build requirements) produce a report.
+fn write_build_report(
+    sdist: &SourceDist,
+    header: &str,
+    cause: &(dyn std::error::Error + 'static),
+) -> Option<PathBuf> {
+    let dir = BUILD_REPORT_DIR.get()?;
+
+    let chain = std::iter::successors(Some(cause), |err| err.source());
+    let log = chain
+        .clone()
+        .find_map(|err| {
+            if let Some(err) = err.downcast_ref::<uv_build_frontend::Error>() {
+                return Some(err);
+            }
+            // Build failures are propagated as an opaque `anyhow::Error` by the distribution
+            // database, which is transparent to `source()`.
+            if let Some(uv_distribution::Error::Build(err)) =
+                err.downcast_ref::<uv_distribution::Error>()
+            {
+                return err.downcast_ref::<uv_build_frontend::Error>();
+            }
+            None
+        })
+        .and_then(uv_build_frontend::Error::build_log)?;
+
+    // Render each error in the chain, omitting any output that was appended to the message.
+    let errors = std::iter::once(header.to_string())
+        .chain(chain.map(|err| {
+            let message = err.to_string();
+            let message = message.lines().next().unwrap_or_default();
+            anstream::adapter::strip_str(message).to_string()
+        }))
+        .collect();
+
+    let report = BuildReport {
+        package: sdist.name(),
+        version: sdist.version().map(ToString::to_string),
+        distribution: sdist.to_string(),
+        errors,
+        build_requirements: &log.requirements,
+        stdout: &log.stdout,
+        stderr: &log.stderr,
+    };
+
+    let filename = if let Some(version) = sdist.version() {
+        format!("{}-{version}.json", sdist.name())
+    } else {
+        format!("{}.json", sdist.name())
+    };
+    let path = dir.join(filename);
+
+    let result = serde_json::to_string_pretty(&report)
+        .map_err(std::io::Error::from)
+        .and_then(|contents| {
+            fs_err::create_dir_all(dir)?;
+            fs_err::write(&path, contents)
+        });
+    match result {
+        Ok(()) => Some(path),
+        Err(err) => {
+            warn_user!("Failed to write build report: {err}");
+            None
+        }
+    }
+}
+
+/// Point the user to a [`BuildReport`] that was written to disk.
+fn write_build_report_hint(path: &Path) {
+    anstream::eprintln!(
+        "\n{}{} A build report was written to: {}",
+        "hint".bold().cyan(),
+        ":".bold(),
+        path.user_display().cyan()
+    );
 }
 
 /// Render a [`uv_resolver::NoSolutionError`].
 pub(crate) fn no_solution(err: &uv_resolver::NoSolutionError) {
-    let report = miette::Report::msg(format!("{err}")).context(err.header());
+    write_suggestions(err);
+    let report = miette::Report::msg(format!("{}", WithSuggestions(err))).context(err.header());
     anstream::eprint!("{report:?}");
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with dedicated context.
 pub(crate) fn no_solution_context(err: &uv_resolver::NoSolutionError, context: &'static str) {
-    let report = miette::Report::msg(format!("{err}")).context(err.header().with_context(context));
+    write_suggestions(err);
+    let report = miette::Report::msg(format!("{}", WithSuggestions(err)))
+        .context(err.header().with_context(context));
     anstream::eprint!("{report:?}");
 }
 
@@ -229,16 +365,69 @@ pub(crate) fn no_solution_hint(err: uv_resolver::NoSolutionError, help: String)
         /// The header to render in the error message.
         header: uv_resolver::NoSolutionHeader,
 
-        /// The underlying error.
+        /// The underlying error, followed by any suggested fixes.
         #[source]
-        err: uv_resolver::NoSolutionError,
+        err: Source,
 
         /// The help message to display.
         #[help]
         help: String,
     }
 
+    #[derive(Debug, thiserror::Error)]
+    #[error("{}", WithSuggestions(&self.0))]
+    struct Source(uv_resolver::NoSolutionError);
+
+    write_suggestions(&err);
     let header = err.header();
-    let report = miette::Report::new(Error { header, err, help });
+    let report = miette::Report::new(Error {
+        header,
+        err: Source(err),
+        help,
+    });
     anstream::eprint!("{report:?}");
 }
+
+/// Renders a [`uv_resolver::NoSolutionError`], followed by the suggested fixes for the conflict,
+/// unless disabled via `UV_NO_RESOLUTION_SUGGESTIONS`.
+struct WithSuggestions<'a>(&'a uv_resolver::NoSolutionError);
+
+impl std::fmt::Display for WithSuggestions<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)?;
+        if std::env::var_os(EnvVars::UV_NO_RESOLUTION_SUGGESTIONS).is_some() {
+            return Ok(());
+        }
+        let suggestions = self.0.suggestions();
+        if !suggestions.is_empty() {
+            write!(
+                f,
+                "\n\n{}{} The conflict may be resolved by one of the following (most likely first):",
+                "hint".bold().cyan(),
+                ":".bold(),
+            )?;
+            for (index, suggestion) in suggestions.iter().enumerate() {
+                write!(f, "\n  {}. {suggestion}", index + 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write the suggested fixes for a [`uv_resolver::NoSolutionError`] to the file provided via
+/// `--resolution-suggestions`, if enabled.
+fn write_suggestions(err: &uv_resolver::NoSolutionError) {
+    let Some(path) = RESOLUTION_SUGGESTIONS_FILE.get() else {
+        return;
+    };
+    let suggestions = match serde_json::to_string_pretty(&err.suggestions()) {
+        Ok(suggestions) => suggestions,
+        Err(err) => {
+            warn_user!("Failed to serialize resolution suggestions: {err}");
+            return;
+        }
+    };
+    if let Err(err) = fs_err::write(path, suggestions) {
+        warn_user!("Failed to write resolution suggestions: {err}");
+    }
+}
@@ -0,0 +1,676 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use futures::{StreamExt, TryStreamExt};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+use tracing::debug;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::{
+    BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClient, RegistryClientBuilder,
+};
+use uv_configuration::{
+    Concurrency, Constraints, DevGroupsManifest, ExtrasSpecification, InstallOptions, LowerBound,
+    Reinstall, TargetTriple, TrustedHost,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution_types::{
+    BuiltDist, Dist, Index, Name, Origin, Resolution, ResolvedDist, SourceDist,
+};
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_platform_tags::TagPreferences;
+use uv_pypi_types::{ConflictingGroupList, HashAlgorithm, HashDigest};
+use uv_python::{
+    EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
+    PythonVersion,
+};
+use uv_requirements::RequirementsSource;
+use uv_resolver::{
+    FlatIndex, GroupResolution, IndexPatterns, InstallTarget, OptionsBuilder, PythonRequirement,
+    ResolverEnvironment, Variants, Vendored,
+};
+use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::project::sync::store_credentials_from_workspace;
+use crate::commands::project::{lock, ProjectError, WorkspacePython};
+use crate::commands::{elapsed, human_readable_bytes, ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// The name of the manifest file written to the output directory.
+const MANIFEST: &str = "manifest.json";
+
+/// The manifest of the distributions written to the output directory.
+#[derive(Debug, Serialize)]
+struct Manifest {
+    /// The downloaded distributions, sorted by filename.
+    files: Vec<ManifestEntry>,
+}
+
+/// A single distribution in the [`Manifest`].
+#[derive(Debug, Serialize)]
+struct ManifestEntry {
+    /// The name of the distribution's file within the output directory.
+    filename: String,
+    /// The name of the package.
+    name: PackageName,
+    /// The version of the package, if known.
+    version: Option<Version>,
+    /// The URL from which the distribution was downloaded.
+    url: String,
+    /// The size of the distribution, in bytes.
+    size: u64,
+    /// The hashes of the distribution, including at least a SHA-256 hash.
+    hashes: Vec<String>,
+}
+
+/// A distribution file to download.
+#[derive(Debug)]
+struct Artifact {
+    name: PackageName,
+    version: Option<Version>,
+    filename: String,
+    url: Url,
+    /// The expected hashes of the file, from the lockfile or index.
+    hashes: Vec<HashDigest>,
+}
+
+/// Download the distributions required by a lockfile or set of requirements into a directory.
+pub(crate) async fn download(
+    project_dir: &Path,
+    packages: &[String],
+    requirements: &[PathBuf],
+    out_dir: Option<&Path>,
+    python: Option<String>,
+    python_versions: &[PythonVersion],
+    python_platforms: &[TargetTriple],
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let ResolverSettings {
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        resolution,
+        prerelease,
+        dependency_metadata,
+        config_setting,
+        no_build_isolation,
+        no_build_isolation_package,
+        exclude_newer,
+        max_backtracks,
+        link_mode,
+        upgrade,
+        build_options,
+        sources,
+    } = settings;
+
+    let out_dir = out_dir.map_or_else(|| project_dir.join("wheelhouse"), Path::to_path_buf);
+
+    // If no requirements were provided, download the distributions in the project's lockfile.
+    let project = if packages.is_empty() && requirements.is_empty() {
+        Some(VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await?)
+    } else {
+        None
+    };
+    let lock = if let Some(project) = project.as_ref() {
+        Some(
+            lock::read(project.workspace())
+                .await?
+                .ok_or(ProjectError::MissingLockfile)?,
+        )
+    } else {
+        None
+    };
+
+    // Find an interpreter, which is used to build source distributions during resolution, and as
+    // the basis for the target environments.
+    let WorkspacePython { python_request, .. } = WorkspacePython::from_request(
+        python.as_deref().map(PythonRequest::parse),
+        project.as_ref().map(VirtualProject::workspace),
+        project_dir,
+        no_config,
+    )
+    .await?;
+    let interpreter = PythonInstallation::find(
+        &python_request.unwrap_or_default(),
+        EnvironmentPreference::Any,
+        python_preference,
+        cache,
+    )?
+    .into_interpreter();
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .keyring(keyring_provider)
+        .allow_insecure_host(allow_insecure_host.to_vec());
+
+    // Read the requirements, if any.
+    let spec = if project.is_some() {
+        None
+    } else {
+        let sources = packages
+            .iter()
+            .cloned()
+            .map(RequirementsSource::from_package)
+            .chain(
+                requirements
+                    .iter()
+                    .cloned()
+                    .map(RequirementsSource::from_requirements_file),
+            )
+            .collect::<Vec<_>>();
+        Some(
+            operations::read_requirements(
+                &sources,
+                &[],
+                &[],
+                &ExtrasSpecification::default(),
+                &client_builder,
+            )
+            .await?,
+        )
+    };
+
+    // Incorporate any index locations and build options from the provided sources.
+    let (index_locations, build_options) = if let Some(spec) = spec.as_ref() {
+        (
+            index_locations.combine(
+                spec.extra_index_urls
+                    .iter()
+                    .cloned()
+                    .map(Index::from_extra_index_url)
+                    .chain(spec.index_url.clone().map(Index::from_index_url))
+                    .map(|index| index.with_origin(Origin::RequirementsTxt))
+                    .collect(),
+                spec.find_links
+                    .iter()
+                    .cloned()
+                    .map(Index::from_find_links)
+                    .map(|index| index.with_origin(Origin::RequirementsTxt))
+                    .collect(),
+                spec.no_index,
+            ),
+            build_options.combine(spec.no_binary.clone(), spec.no_build.clone()),
+        )
+    } else {
+        (index_locations, build_options)
+    };
+
+    // Add all authenticated sources to the cache.
+    for index in index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            uv_auth::store_credentials(index.raw_url(), credentials);
+        }
+    }
+    if let Some(project) = project.as_ref() {
+        store_credentials_from_workspace(project.workspace());
+    }
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Determine whether to enable build isolation.
+    let environment = PythonEnvironment::from_interpreter(interpreter.clone());
+    let build_isolation = if no_build_isolation {
+        BuildIsolation::Shared(&environment)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(&environment, &no_build_isolation_package)
+    };
+
+    let options = OptionsBuilder::new()
+        .resolution_mode(resolution)
+        .prerelease_mode(prerelease)
+        .exclude_newer(exclude_newer)
+        .max_backtracks(max_backtracks)
+        .index_strategy(index_strategy)
+        .build();
+
+    // Each Python version is combined with each platform to form the set of targets.
+    let python_versions = if python_versions.is_empty() {
+        vec![None]
+    } else {
+        python_versions.iter().map(Some).collect()
+    };
+    let python_platforms = if python_platforms.is_empty() {
+        vec![None]
+    } else {
+        python_platforms.iter().map(Some).collect()
+    };
+
+    let mut seen = FxHashSet::<String>::default();
+    let mut artifacts = Vec::<Artifact>::new();
+    let mut targets = 0usize;
+    let mut skipped = FxHashSet::<PackageName>::default();
+
+    for python_platform in &python_platforms {
+        for python_version in &python_versions {
+            let marker_env = resolution_markers(*python_version, *python_platform, &interpreter);
+            let tags = resolution_tags(
+                *python_version,
+                *python_platform,
+                &interpreter,
+                tag_preferences,
+            )?;
+            let description = format!(
+                "Python {} on {} ({})",
+                marker_env.python_version(),
+                marker_env.sys_platform(),
+                marker_env.platform_machine()
+            );
+
+            // Read the distributions for the target from the lockfile, if available.
+            let locked = if let (Some(project), Some(lock)) = (project.as_ref(), lock.as_ref()) {
+                if !lock
+                    .requires_python()
+                    .contains(&marker_env.python_full_version().version)
+                {
+                    warn_user!(
+                        "Skipping {description}, which is not compatible with the locked Python requirement: `{}`",
+                        lock.requires_python()
+                    );
+                    continue;
+                }
+                let environments = lock.supported_environments();
+                if !environments.is_empty()
+                    && !environments
+                        .iter()
+                        .any(|env| env.evaluate(&marker_env, &[]))
+                {
+                    warn_user!(
+                        "Skipping {description}, which is not one of the lockfile's supported environments"
+                    );
+                    continue;
+                }
+
+                let target = match project {
+                    VirtualProject::Project(project) => InstallTarget::Workspace {
+                        workspace: project.workspace(),
+                        lock,
+                    },
+                    VirtualProject::NonProject(workspace) => {
+                        InstallTarget::NonProjectWorkspace { workspace, lock }
+                    }
+                };
+                let groups = project.workspace().groups().into_iter().cloned().collect();
+                Some(target.to_resolution(
+                    &marker_env,
+                    &tags,
+                    &ExtrasSpecification::All,
+                    &DevGroupsManifest::from_defaults(groups),
+                    &build_options,
+                    &InstallOptions::default(),
+                )?)
+            } else {
+                None
+            };
+
+            // Otherwise, resolve the requirements for the target.
+            let resolution = if let Some(resolution) = locked {
+                resolution
+            } else if let Some(spec) = spec.as_ref() {
+                // Resolve the flat indexes from `--find-links`.
+                let flat_index = {
+                    let client = FlatIndexClient::new(&client, cache);
+                    let entries = client
+                        .fetch(index_locations.flat_indexes().map(Index::url))
+                        .await?;
+                    FlatIndex::from_entries(
+                        entries,
+                        Some(&tags),
+                        &HashStrategy::None,
+                        &build_options,
+                    )
+                };
+
+                // Use isolated state for each target, as the in-memory index is specific to the
+                // target's tags.
+                let state = SharedState::default();
+
+                // Create a build dispatch.
+                let build_dispatch = BuildDispatch::new(
+                    &client,
+                    cache,
+                    Constraints::default(),
+                    &interpreter,
+                    &index_locations,
+                    &flat_index,
+                    &dependency_metadata,
+                    &state.index,
+                    &state.git,
+                    &state.capabilities,
+                    &state.in_flight,
+                    index_strategy,
+                    &config_setting,
+                    build_isolation,
+                    link_mode,
+                    &build_options,
+                    &HashStrategy::None,
+                    exclude_newer,
+                    LowerBound::Warn,
+                    sources,
+                    concurrency,
+                    tag_preferences,
+                );
+
+                let python_requirement = if let Some(python_version) = python_version {
+                    PythonRequirement::from_python_version(&interpreter, python_version)
+                } else {
+                    PythonRequirement::from_interpreter(&interpreter)
+                };
+                let graph = operations::resolve(
+                    spec.requirements.clone(),
+                    spec.constraints.clone(),
+                    spec.overrides.clone(),
+                    Vec::default(),
+                    spec.source_trees.clone(),
+                    spec.project.clone(),
+                    None,
+                    &ExtrasSpecification::default(),
+                    Vec::default(),
+                    EmptyInstalledPackages,
+                    &HashStrategy::None,
+                    &Reinstall::None,
+                    &upgrade,
+                    Some(&tags),
+                    ResolverEnvironment::specific(marker_env.clone()),
+                    python_requirement,
+                    ConflictingGroupList::empty(),
+                    Variants::default(),
+                    GroupResolution::default(),
+                    Vendored::default(),
+                    IndexPatterns::default(),
+                    &client,
+                    &flat_index,
+                    &state.index,
+                    &build_dispatch,
+                    concurrency,
+                    options,
+                    Box::new(DefaultResolveLogger),
+                    printer,
+                )
+                .await?;
+                Resolution::from(graph)
+            } else {
+                unreachable!("either a lockfile or requirements must be provided")
+            };
+
+            targets += 1;
+
+            let mut found = 0usize;
+            for dist in resolution.distributions() {
+                let ResolvedDist::Installable(dist) = dist else {
+                    continue;
+                };
+                let Some(artifact) = Artifact::from_dist(dist, &resolution)? else {
+                    debug!("Skipping distribution for {description}, which has no archive: {dist}");
+                    skipped.insert(dist.name().clone());
+                    continue;
+                };
+                found += 1;
+                if seen.insert(artifact.filename.clone()) {
+                    artifacts.push(artifact);
+                }
+            }
+            debug!("Found {found} distributions for {description}");
+        }
+    }
+
+    fs_err::tokio::create_dir_all(&out_dir).await?;
+
+    writeln!(
+        printer.stderr(),
+        "Downloading {} to {}",
+        format!(
+            "{} file{}",
+            artifacts.len(),
+            if artifacts.len() == 1 { "" } else { "s" }
+        )
+        .bold(),
+        out_dir.user_display().cyan()
+    )?;
+
+    let mut entries = futures::stream::iter(artifacts)
+        .map(|artifact| fetch(artifact, &client, &out_dir))
+        .buffer_unordered(concurrency.downloads)
+        .try_collect::<Vec<_>>()
+        .await?;
+    entries.sort_by(|a, b| a.filename.cmp(&b.filename));
+
+    // Write the manifest.
+    let size = entries.iter().map(|entry| entry.size).sum::<u64>();
+    let count = entries.len();
+    let manifest = Manifest { files: entries };
+    fs_err::tokio::write(
+        out_dir.join(MANIFEST),
+        serde_json::to_string_pretty(&manifest)? + "\n",
+    )
+    .await?;
+
+    let (bytes, unit) = human_readable_bytes(size);
+    let s = if count == 1 { "" } else { "s" };
+    let t = if targets == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Downloaded {} ({bytes:.1}{unit}) for {} {}",
+            format!("{count} file{s}").bold(),
+            format!("{targets} target{t}").bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    if !skipped.is_empty() {
+        let mut skipped = skipped.into_iter().collect::<Vec<_>>();
+        skipped.sort();
+        let s = if skipped.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Skipped {} package{s} without a distribution archive: {}",
+                skipped.len(),
+                skipped
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+            .dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+impl Artifact {
+    /// Determine the archive to download for a distribution, if any.
+    ///
+    /// Git and local directory dependencies don't have an archive, and return `None`.
+    fn from_dist(dist: &Dist, resolution: &Resolution) -> Result<Option<Self>> {
+        let name = dist.name().clone();
+        let version = dist.version().cloned();
+        let (filename, url, hashes) = match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                (
+                    wheel.file.filename.clone(),
+                    wheel.file.url.to_url()?,
+                    wheel.file.hashes.clone(),
+                )
+            }
+            Dist::Source(SourceDist::Registry(sdist)) => (
+                sdist.file.filename.clone(),
+                sdist.file.url.to_url()?,
+                sdist.file.hashes.clone(),
+            ),
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => (
+                wheel.filename.to_string(),
+                wheel.location.clone(),
+                resolution.get_hashes(&name).to_vec(),
+            ),
+            Dist::Built(BuiltDist::Path(wheel)) => (
+                wheel.filename.to_string(),
+                wheel.url.to_url(),
+                resolution.get_hashes(&name).to_vec(),
+            ),
+            Dist::Source(SourceDist::DirectUrl(sdist)) => {
+                let Some(filename) = sdist
+                    .location
+                    .path_segments()
+                    .and_then(Iterator::last)
+                    .filter(|filename| !filename.is_empty())
+                else {
+                    bail!("Failed to determine the filename for: {}", sdist.location);
+                };
+                (
+                    filename.to_string(),
+                    sdist.location.clone(),
+                    resolution.get_hashes(&name).to_vec(),
+                )
+            }
+            Dist::Source(SourceDist::Path(sdist)) => {
+                let Some(filename) = sdist.install_path.file_name() else {
+                    bail!(
+                        "Failed to determine the filename for: {}",
+                        sdist.install_path.user_display()
+                    );
+                };
+                (
+                    filename.to_string_lossy().to_string(),
+                    sdist.url.to_url(),
+                    resolution.get_hashes(&name).to_vec(),
+                )
+            }
+            Dist::Source(SourceDist::Git(_) | SourceDist::Directory(_)) => return Ok(None),
+        };
+        Ok(Some(Self {
+            name,
+            version,
+            filename,
+            url,
+            hashes,
+        }))
+    }
+}
+
+/// Download (or copy) an artifact into the output directory, verifying its hashes.
+async fn fetch(
+    artifact: Artifact,
+    client: &RegistryClient,
+    out_dir: &Path,
+) -> Result<ManifestEntry> {
+    // Always compute a SHA-256 hash, in addition to any algorithms with an expected digest.
+    let mut algorithms = vec![HashAlgorithm::Sha256];
+    for digest in &artifact.hashes {
+        if !algorithms.contains(&digest.algorithm()) {
+            algorithms.push(digest.algorithm());
+        }
+    }
+    let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+
+    // Write to a temporary file, such that a failed download never leaves a partial file behind.
+    let temp_file = tempfile::NamedTempFile::new_in(out_dir)?;
+    let mut size = 0u64;
+    {
+        use std::io::Write;
+
+        let mut writer = temp_file.as_file();
+        if artifact.url.scheme() == "file" {
+            let path = artifact
+                .url
+                .to_file_path()
+                .map_err(|()| anyhow::anyhow!("Invalid file URL: {}", artifact.url))?;
+            let contents = fs_err::tokio::read(&path).await?;
+            for hasher in &mut hashers {
+                hasher.update(&contents);
+            }
+            writer.write_all(&contents)?;
+            size += contents.len() as u64;
+        } else {
+            debug!("Downloading: {}", artifact.url);
+            let response = client
+                .uncached_client(&artifact.url)
+                .get(artifact.url.clone())
+                .send()
+                .await
+                .with_context(|| format!("Failed to download: {}", artifact.url))?
+                .error_for_status()
+                .with_context(|| format!("Failed to download: {}", artifact.url))?;
+            let mut reader = response.bytes_stream();
+            while let Some(chunk) = reader.next().await {
+                let chunk =
+                    chunk.with_context(|| format!("Failed to download: {}", artifact.url))?;
+                for hasher in &mut hashers {
+                    hasher.update(&chunk);
+                }
+                writer.write_all(&chunk)?;
+                size += chunk.len() as u64;
+            }
+        }
+    }
+    let digests = hashers
+        .into_iter()
+        .map(HashDigest::from)
+        .collect::<Vec<_>>();
+
+    // Verify the expected hashes, if any.
+    for expected in &artifact.hashes {
+        if let Some(actual) = digests
+            .iter()
+            .find(|digest| digest.algorithm() == expected.algorithm())
+        {
+            if actual.digest != expected.digest {
+                bail!(
+                    "Hash mismatch for `{}`\n\nExpected:\n  {expected}\n\nComputed:\n  {actual}",
+                    artifact.filename
+                );
+            }
+        }
+    }
+
+    let path = out_dir.join(&artifact.filename);
+    temp_file
+        .persist(&path)
+        .with_context(|| format!("Failed to write: {}", path.user_display()))?;
+
+    Ok(ManifestEntry {
+        filename: artifact.filename,
+        name: artifact.name,
+        version: artifact.version,
+        url: artifact.url.to_string(),
+        size,
+        hashes: digests.iter().map(ToString::to_string).collect(),
+    })
+}
@@ -2,15 +2,27 @@ use anstream::AutoStream;
 use anyhow::Context;
 use owo_colors::OwoColorize;
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::io::stdout;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
-use std::{fmt::Display, fmt::Write, process::ExitCode};
+use std::{fmt::Write, process::ExitCode};
+use tracing::debug;
 
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_export::cache_export;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_stats::cache_stats;
+pub(crate) use cache_verify::cache_verify;
+pub(crate) use cache_warm::cache_warm;
+pub(crate) use complete::{complete, dynamic_completion_script};
+pub(crate) use config::{config_get, config_list, config_set};
+#[cfg(unix)]
+pub(crate) use daemon::{daemon_delegate, daemon_start, daemon_status, daemon_stop};
+pub(crate) use diagnostics::{enable_build_report, enable_resolution_suggestions};
+pub(crate) use download::download;
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
@@ -22,41 +34,61 @@ pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
 pub(crate) use project::add::add;
+pub(crate) use project::build_image::build_image;
+pub(crate) use project::bundle::bundle;
+pub(crate) use project::container::run_in_container;
 pub(crate) use project::export::export;
 pub(crate) use project::init::{init, InitKind, InitProjectKind};
 pub(crate) use project::lock::lock;
+pub(crate) use project::pin::{pin_add, pin_list, pin_remove};
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{run, RunCommand};
-pub(crate) use project::sync::sync;
+pub(crate) use project::sync::{sync, sync_watch};
 pub(crate) use project::tree::tree;
+pub(crate) use proxy::proxy;
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
 pub(crate) use python::install::install as python_install;
 pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
+pub(crate) use python::refresh::refresh as python_refresh;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
+pub(crate) use query::{query_dependents, query_interpreter, query_lock_status, query_resolve};
+pub(crate) use script::audit::audit as script_audit;
+pub(crate) use script::lock::lock as script_lock;
+pub(crate) use script::outdated::outdated as script_outdated;
+pub(crate) use script::sync::sync as script_sync;
+pub(crate) use script::tree::tree as script_tree;
+pub(crate) use script::vendor::vendor as script_vendor;
+pub(crate) use self_component::{self_add, self_list, self_remove};
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
 pub(crate) use tool::install::install as tool_install;
+pub(crate) use tool::installed_plugins;
 pub(crate) use tool::list::list as tool_list;
 pub(crate) use tool::run::run as tool_run;
 pub(crate) use tool::run::ToolRunCommand;
 pub(crate) use tool::uninstall::uninstall as tool_uninstall;
 pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
+use uv_cache::metrics::CacheSummary;
 use uv_cache::Cache;
+use uv_cli::CacheSummaryFormat;
+use uv_configuration::{CompileBytecode, PycInvalidationMode};
 use uv_distribution_types::{IndexCapabilities, InstalledMetadata};
 use uv_fs::Simplified;
 use uv_git::GitResolver;
-use uv_installer::compile_tree;
-use uv_normalize::PackageName;
+use uv_installer::{compile_files, compile_tree, SitePackages};
 use uv_python::PythonEnvironment;
 use uv_resolver::InMemoryIndex;
 use uv_types::InFlight;
 pub(crate) use venv::venv;
-pub(crate) use version::version;
+pub(crate) use version::{version, version_bump};
+pub(crate) use workspace::{
+    workspace_affected, workspace_dedupe_report, workspace_graph, workspace_list,
+};
 
 use crate::printer::Printer;
 
@@ -64,19 +96,33 @@ pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
+mod cache_export;
 mod cache_prune;
+mod cache_stats;
+mod cache_verify;
+mod cache_warm;
+mod complete;
+mod config;
+#[cfg(unix)]
+mod daemon;
 mod diagnostics;
+mod download;
 mod help;
 pub(crate) mod pip;
 mod project;
+mod proxy;
 mod publish;
 mod python;
+mod query;
 pub(crate) mod reporters;
+mod script;
+mod self_component;
 #[cfg(feature = "self-update")]
 mod self_update;
 mod tool;
 mod venv;
 mod version;
+mod workspace;
 
 #[derive(Copy, Clone)]
 pub(crate) enum ExitStatus {
@@ -93,17 +139,23 @@ pub(crate) enum ExitStatus {
     External(u8),
 }
 
-impl From<ExitStatus> for ExitCode {
+impl From<ExitStatus> for u8 {
     fn from(status: ExitStatus) -> Self {
         match status {
-            ExitStatus::Success => Self::from(0),
-            ExitStatus::Failure => Self::from(1),
-            ExitStatus::Error => Self::from(2),
-            ExitStatus::External(code) => Self::from(code),
+            ExitStatus::Success => 0,
+            ExitStatus::Failure => 1,
+            ExitStatus::Error => 2,
+            ExitStatus::External(code) => code,
         }
     }
 }
 
+impl From<ExitStatus> for ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        Self::from(u8::from(status))
+    }
+}
+
 /// Format a duration as a human-readable string, Cargo-style.
 pub(super) fn elapsed(duration: Duration) -> String {
     let secs = duration.as_secs();
@@ -136,33 +188,92 @@ pub(super) struct ChangeEvent<'a, T: InstalledMetadata> {
     kind: ChangeEventKind,
 }
 
-#[derive(Debug)]
-pub(super) struct DryRunEvent<T: Display> {
-    name: PackageName,
-    version: T,
-    kind: ChangeEventKind,
-}
-
-/// Compile all Python source files in site-packages to bytecode, to speed up the
-/// initial run of any subsequent executions.
+/// Compile Python source files in site-packages to bytecode, to speed up the initial run of any
+/// subsequent executions.
+///
+/// If only a subset of packages is selected, the files listed in their `RECORD` are compiled;
+/// otherwise, the entire site-packages directory is compiled.
 ///
 /// See the `--compile` option on `pip sync` and `pip install`.
 pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
+    compile: &CompileBytecode,
+    invalidation_mode: Option<PycInvalidationMode>,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
     let mut files = 0;
-    for site_packages in venv.site_packages() {
-        files += compile_tree(&site_packages, venv.python_executable(), cache.root())
-            .await
-            .with_context(|| {
-                format!(
-                    "Failed to bytecode-compile Python file in: {}",
-                    site_packages.user_display()
+    match compile {
+        CompileBytecode::None => return Ok(()),
+        CompileBytecode::All => {
+            for site_packages in venv.site_packages() {
+                files += compile_tree(
+                    &site_packages,
+                    venv.python_executable(),
+                    cache.root(),
+                    invalidation_mode,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to bytecode-compile Python file in: {}",
+                        site_packages.user_display()
+                    )
+                })?;
+            }
+        }
+        CompileBytecode::Packages(packages) => {
+            // Group the source files by the `site-packages` directory that contains them.
+            let site_packages = SitePackages::from_environment(venv)?;
+            let mut source_files: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+            for package in packages {
+                for dist in site_packages.get_packages(package) {
+                    let Some(root) = dist.path().parent() else {
+                        continue;
+                    };
+                    let mut record = match fs_err::File::open(dist.path().join("RECORD")) {
+                        Ok(record) => record,
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                            debug!(
+                                "Skipping bytecode compilation for `{package}`: no `RECORD` file"
+                            );
+                            continue;
+                        }
+                        Err(err) => return Err(err.into()),
+                    };
+                    let record = uv_install_wheel::read_record_file(&mut record)?;
+                    source_files.entry(root.to_path_buf()).or_default().extend(
+                        record
+                            .into_iter()
+                            // Skip files installed outside of `site-packages`, like scripts.
+                            .filter(|entry| !entry.path.starts_with(".."))
+                            .filter(|entry| {
+                                Path::new(&entry.path)
+                                    .extension()
+                                    .is_some_and(|ext| ext == "py")
+                            })
+                            .map(|entry| root.join(entry.path)),
+                    );
+                }
+            }
+            for (site_packages, source_files) in source_files {
+                files += compile_files(
+                    &site_packages,
+                    source_files,
+                    venv.python_executable(),
+                    cache.root(),
+                    invalidation_mode,
                 )
-            })?;
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to bytecode-compile Python file in: {}",
+                        site_packages.user_display()
+                    )
+                })?;
+            }
+        }
     }
     let s = if files == 1 { "" } else { "s" };
     writeln!(
@@ -178,6 +289,71 @@ pub(super) async fn compile_bytecode(
     Ok(())
 }
 
+/// Write a summary of how effectively the cache was used by the current process.
+///
+/// See the `--cache-summary` option on `sync` and `lock`.
+pub(crate) fn write_cache_summary(
+    format: CacheSummaryFormat,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    let summary = CacheSummary::current();
+
+    if format == CacheSummaryFormat::Json {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&summary)?
+        )?;
+        return Ok(());
+    }
+
+    let plural =
+        |count: u64, noun: &str| format!("{count} {noun}{}", if count == 1 { "" } else { "s" });
+    let CacheSummary {
+        requests,
+        wheels,
+        builds,
+        phases,
+    } = summary;
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Cache summary: {} ({} cached, {} revalidated, {} from network), {} ({} cached, {} downloaded), {} ({} cached, {} built)",
+            plural(requests.cached + requests.revalidated + requests.network, "request").bold(),
+            requests.cached,
+            requests.revalidated,
+            requests.network,
+            plural(wheels.cached + wheels.downloaded, "wheel").bold(),
+            wheels.cached,
+            wheels.downloaded,
+            plural(builds.cached + builds.built, "source distribution").bold(),
+            builds.cached,
+            builds.built,
+        )
+        .dimmed()
+    )?;
+    if !phases.is_empty() {
+        let phases = phases
+            .iter()
+            .map(|phase| {
+                format!(
+                    "{} {}",
+                    phase.phase,
+                    elapsed(Duration::from_secs_f64(phase.seconds))
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Timings: {phases}").dimmed()
+        )?;
+    }
+    Ok(())
+}
+
 /// Formats a number of bytes into a human readable SI-prefixed size.
 ///
 /// Returns a tuple of `(quantity, units)`.
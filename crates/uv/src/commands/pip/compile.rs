@@ -1,5 +1,7 @@
 use std::env;
-use std::path::Path;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
@@ -16,25 +18,27 @@ use uv_configuration::{
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{
-    DependencyMetadata, Index, IndexCapabilities, IndexLocations, NameRequirementSpecification,
-    Origin, UnresolvedRequirementSpecification, Verbatim,
+    DependencyMetadata, Index, IndexCapabilities, IndexLocations, IndexUrl,
+    NameRequirementSpecification, Origin, UnresolvedRequirementSpecification, Verbatim,
 };
 use uv_fs::Simplified;
 use uv_git::GitResolver;
 use uv_install_wheel::linker::LinkMode;
 use uv_normalize::PackageName;
+use uv_pep508::MarkerEnvironment;
+use uv_platform_tags::TagPreferences;
 use uv_pypi_types::{ConflictingGroupList, Requirement, SupportedEnvironments};
 use uv_python::{
-    EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
-    PythonVersion, VersionRequest,
+    EnvironmentPreference, Interpreter, PythonEnvironment, PythonInstallation, PythonPreference,
+    PythonRequest, PythonVersion, VersionRequest,
 };
 use uv_requirements::{
     upgrade::read_requirements_txt, RequirementsSource, RequirementsSpecification,
 };
 use uv_resolver::{
     AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PythonRequirement, RequiresPython,
-    ResolutionMode, ResolverEnvironment,
+    GroupResolution, InMemoryIndex, IndexPatterns, OptionsBuilder, PrereleaseMode,
+    PythonRequirement, RequiresPython, ResolutionMode, ResolverEnvironment, Variants, Vendored,
 };
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 use uv_warnings::warn_user;
@@ -86,7 +90,12 @@ pub(crate) async fn pip_compile(
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     universal: bool,
+    split_output_dir: Option<&Path>,
+    split_python_versions: Vec<PythonVersion>,
+    split_python_platforms: Vec<TargetTriple>,
+    pyodide_index_url: Option<IndexUrl>,
     exclude_newer: Option<ExcludeNewer>,
+    max_backtracks: Option<usize>,
     sources: SourceStrategy,
     annotation_style: AnnotationStyle,
     link_mode: LinkMode,
@@ -94,6 +103,7 @@ pub(crate) async fn pip_compile(
     system: bool,
     python_preference: PythonPreference,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     quiet: bool,
     cache: Cache,
@@ -251,6 +261,38 @@ pub(crate) async fn pip_compile(
         PythonRequirement::from_interpreter(&interpreter)
     };
 
+    // When targeting Pyodide, search the Pyodide package index in addition to the configured
+    // indexes, since most packages with extension modules aren't published to PyPI for Pyodide.
+    let pyodide_index = if python_platform == Some(TargetTriple::Wasm32Pyodide)
+        || split_python_platforms.contains(&TargetTriple::Wasm32Pyodide)
+    {
+        if let Some(url) = pyodide_index_url {
+            debug!("Using Pyodide package index: {url}");
+            Some(Index::from_extra_index_url(url))
+        } else {
+            debug!(
+                "No Pyodide package index configured (provide `--pyodide-index-url` to search one)"
+            );
+            None
+        }
+    } else {
+        None
+    };
+
+    // Determine the targets for which to write fully-evaluated output files, if any.
+    let split_targets = if let Some(split_output_dir) = split_output_dir {
+        split_targets(
+            split_output_dir,
+            output_file,
+            split_python_versions,
+            split_python_platforms,
+            python_version.as_ref(),
+            &interpreter,
+        )?
+    } else {
+        Vec::new()
+    };
+
     // Determine the environment for the resolution.
     let (tags, resolver_env, conflicting_groups) = if universal {
         (
@@ -259,8 +301,12 @@ pub(crate) async fn pip_compile(
             conflicting_groups,
         )
     } else {
-        let (tags, marker_env) =
-            resolution_environment(python_version, python_platform, &interpreter)?;
+        let (tags, marker_env) = resolution_environment(
+            python_version,
+            python_platform,
+            &interpreter,
+            tag_preferences,
+        )?;
         (
             Some(tags),
             ResolverEnvironment::specific(marker_env),
@@ -285,6 +331,7 @@ pub(crate) async fn pip_compile(
             .map(Index::from_extra_index_url)
             .chain(index_url.map(Index::from_index_url))
             .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .chain(pyodide_index)
             .collect(),
         find_links
             .into_iter()
@@ -324,7 +371,7 @@ pub(crate) async fn pip_compile(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, tags.as_deref(), &hasher, &build_options)
+        FlatIndex::from_entries(entries, tags.as_ref(), &hasher, &build_options)
     };
 
     // Track in-flight downloads, builds, etc., across resolutions.
@@ -372,6 +419,7 @@ pub(crate) async fn pip_compile(
         LowerBound::Warn,
         sources,
         concurrency,
+        tag_preferences,
     );
 
     let options = OptionsBuilder::new()
@@ -379,6 +427,7 @@ pub(crate) async fn pip_compile(
         .prerelease_mode(prerelease_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
+        .max_backtracks(max_backtracks)
         .index_strategy(index_strategy)
         .build();
 
@@ -397,10 +446,14 @@ pub(crate) async fn pip_compile(
         &hasher,
         &Reinstall::None,
         &upgrade,
-        tags.as_deref(),
+        tags.as_ref(),
         resolver_env.clone(),
         python_requirement,
         conflicting_groups,
+        Variants::default(),
+        GroupResolution::default(),
+        Vendored::default(),
+        IndexPatterns::default(),
         &client,
         &flat_index,
         &top_level_index,
@@ -423,25 +476,19 @@ pub(crate) async fn pip_compile(
     // Write the resolved dependencies to the output channel.
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
 
+    let command = cmd(
+        include_index_url,
+        include_find_links,
+        custom_compile_command,
+    );
+
     if include_header {
         writeln!(
             writer,
             "{}",
             "# This file was autogenerated by uv via the following command:".green()
         )?;
-        writeln!(
-            writer,
-            "{}",
-            format!(
-                "#    {}",
-                cmd(
-                    include_index_url,
-                    include_find_links,
-                    custom_compile_command
-                )
-            )
-            .green()
-        )?;
+        writeln!(writer, "{}", format!("#    {command}").green())?;
     }
 
     if include_marker_expression {
@@ -458,19 +505,21 @@ pub(crate) async fn pip_compile(
         }
     }
 
-    let mut wrote_preamble = false;
+    // Determine the options to include ahead of the requirements (e.g., `--index-url`).
+    let mut preamble = Vec::new();
 
     // If necessary, include the `--index-url` and `--extra-index-url` locations.
     if include_index_url {
         if let Some(index) = index_locations.default_index() {
-            writeln!(writer, "--index-url {}", index.url().verbatim())?;
-            wrote_preamble = true;
+            preamble.push(format!("--index-url {}", index.url().verbatim()));
         }
         let mut seen = FxHashSet::default();
         for extra_index in index_locations.implicit_indexes() {
             if seen.insert(extra_index.url()) {
-                writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
-                wrote_preamble = true;
+                preamble.push(format!(
+                    "--extra-index-url {}",
+                    extra_index.url().verbatim()
+                ));
             }
         }
     }
@@ -478,8 +527,7 @@ pub(crate) async fn pip_compile(
     // If necessary, include the `--find-links` locations.
     if include_find_links {
         for flat_index in index_locations.flat_indexes() {
-            writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
-            wrote_preamble = true;
+            preamble.push(format!("--find-links {}", flat_index.url().verbatim()));
         }
     }
 
@@ -488,33 +536,34 @@ pub(crate) async fn pip_compile(
         match build_options.no_binary() {
             NoBinary::None => {}
             NoBinary::All => {
-                writeln!(writer, "--no-binary :all:")?;
-                wrote_preamble = true;
+                preamble.push("--no-binary :all:".to_string());
             }
             NoBinary::Packages(packages) => {
                 for package in packages {
-                    writeln!(writer, "--no-binary {package}")?;
-                    wrote_preamble = true;
+                    preamble.push(format!("--no-binary {package}"));
                 }
             }
         }
         match build_options.no_build() {
             NoBuild::None => {}
             NoBuild::All => {
-                writeln!(writer, "--only-binary :all:")?;
-                wrote_preamble = true;
+                preamble.push("--only-binary :all:".to_string());
             }
             NoBuild::Packages(packages) => {
-                for package in packages {
-                    writeln!(writer, "--only-binary {package}")?;
-                    wrote_preamble = true;
+                // Environment-scoped exclusions can't be expressed in a `requirements.txt`.
+                for package in packages.iter().filter(|package| package.is_unconditional()) {
+                    preamble.push(format!("--only-binary {}", package.name()));
                 }
             }
         }
     }
 
+    for line in &preamble {
+        writeln!(writer, "{line}")?;
+    }
+
     // If we wrote an index, add a newline to separate it from the requirements
-    if wrote_preamble {
+    if !preamble.is_empty() {
         writeln!(writer)?;
     }
 
@@ -536,7 +585,7 @@ pub(crate) async fn pip_compile(
 
     // If any "unsafe" packages were excluded, notify the user.
     let excluded = no_emit_packages
-        .into_iter()
+        .iter()
         .filter(|name| resolution.contains(name))
         .collect::<Vec<_>>();
     if !excluded.is_empty() {
@@ -554,6 +603,68 @@ pub(crate) async fn pip_compile(
     // Commit the output to disk.
     writer.commit().await?;
 
+    // Write the fully-evaluated output files for each target.
+    if let Some(split_output_dir) = split_output_dir {
+        fs_err::create_dir_all(split_output_dir)?;
+    }
+    for target in &split_targets {
+        let mut writer = OutputWriter::new(false, Some(&target.path));
+        if include_header {
+            writeln!(
+                writer,
+                "{}",
+                "# This file was autogenerated by uv via the following command:".green()
+            )?;
+            writeln!(writer, "{}", format!("#    {command}").green())?;
+            writeln!(
+                writer,
+                "{}",
+                format!(
+                    "# Evaluated for Python {} on {} ({})",
+                    target.python_version,
+                    target.markers.sys_platform(),
+                    target.markers.platform_machine()
+                )
+                .green()
+            )?;
+        }
+        for line in &preamble {
+            writeln!(writer, "{line}")?;
+        }
+        if !preamble.is_empty() {
+            writeln!(writer)?;
+        }
+        write!(
+            writer,
+            "{}",
+            DisplayResolutionGraph::new(
+                &resolution,
+                &resolver_env,
+                &no_emit_packages,
+                generate_hashes,
+                include_extras,
+                false,
+                include_annotations,
+                include_index_annotation,
+                annotation_style,
+            )
+            .with_evaluated_markers(&target.markers)
+        )?;
+        writer.commit().await?;
+        writeln!(
+            printer.stderr(),
+            "Wrote requirements for Python {} on {} to: {}",
+            target.python_version.cyan(),
+            format!(
+                "{} ({})",
+                target.markers.sys_platform(),
+                target.markers.platform_machine()
+            )
+            .cyan(),
+            target.path.user_display().cyan()
+        )?;
+    }
+
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
@@ -649,3 +760,86 @@ fn cmd(
         .join(" ");
     format!("uv {args}")
 }
+
+/// A Python version and platform for which to write a fully-evaluated output file.
+#[derive(Debug)]
+struct SplitTarget {
+    python_version: PythonVersion,
+    markers: MarkerEnvironment,
+    path: PathBuf,
+}
+
+/// Determine the targets for which to write fully-evaluated output files, from the product of the
+/// requested Python versions and platforms.
+fn split_targets(
+    split_output_dir: &Path,
+    output_file: Option<&Path>,
+    python_versions: Vec<PythonVersion>,
+    python_platforms: Vec<TargetTriple>,
+    python_version: Option<&PythonVersion>,
+    interpreter: &Interpreter,
+) -> Result<Vec<SplitTarget>> {
+    // The universal resolution uses the requested (or current) Python version as a lower bound.
+    let minimum = python_version.map_or_else(
+        || (interpreter.python_major(), interpreter.python_minor()),
+        |version| (version.major(), version.minor()),
+    );
+
+    let python_versions = if python_versions.is_empty() {
+        vec![
+            PythonVersion::from_str(&format!("{}.{}", minimum.0, minimum.1))
+                .map_err(|err| anyhow!(err))?,
+        ]
+    } else {
+        python_versions
+    };
+    let python_platforms = if python_platforms.is_empty() {
+        vec![
+            TargetTriple::Linux,
+            TargetTriple::Macos,
+            TargetTriple::Windows,
+        ]
+    } else {
+        python_platforms
+    };
+
+    for version in &python_versions {
+        if (version.major(), version.minor()) < minimum {
+            return Err(anyhow!(
+                "The split Python version `{version}` is below the minimum Python version of the universal resolution (`{}.{}`)",
+                minimum.0,
+                minimum.1
+            ));
+        }
+    }
+
+    let stem = output_file
+        .and_then(Path::file_stem)
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("requirements");
+
+    let mut targets: Vec<SplitTarget> = Vec::new();
+    for (version, platform) in python_versions.iter().cartesian_product(python_platforms) {
+        let markers = version.markers(&platform.markers(interpreter.markers()));
+        let path = split_output_dir.join(format!(
+            "{stem}-py{}{}-{}-{}.txt",
+            version.major(),
+            version.minor(),
+            markers.sys_platform(),
+            markers.platform_machine().to_lowercase()
+        ));
+        if targets.iter().any(|target| target.path == path) {
+            return Err(anyhow!(
+                "Multiple split targets would be written to the same file: `{}`",
+                path.user_display()
+            ));
+        }
+        targets.push(SplitTarget {
+            python_version: version.clone(),
+            markers,
+            path,
+        });
+    }
+
+    Ok(targets)
+}
@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
@@ -10,7 +11,7 @@ use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, HashCheckingMode,
     IndexStrategy, LowerBound, Reinstall, SourceStrategy, TrustedHost, Upgrade,
 };
-use uv_configuration::{KeyringProviderType, TargetTriple};
+use uv_configuration::{CompileBytecode, KeyringProviderType, PycInvalidationMode, TargetTriple};
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{
     DependencyMetadata, Index, IndexLocations, NameRequirementSpecification, Origin, Resolution,
@@ -18,22 +19,25 @@ use uv_distribution_types::{
 };
 use uv_fs::Simplified;
 use uv_install_wheel::linker::LinkMode;
+use uv_install_wheel::ScriptLauncher;
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_pep508::PackageName;
+use uv_platform_tags::TagPreferences;
 use uv_pypi_types::{ConflictingGroupList, Requirement};
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonRequest, PythonVersion, Target,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    DependencyMode, ExcludeNewer, FlatIndex, GroupResolution, IndexPatterns, InstallationReport,
+    OptionsBuilder, PrereleaseMode, PythonRequirement, ResolutionMode, ResolverEnvironment,
+    Variants, Vendored,
 };
 use uv_types::{BuildIsolation, HashStrategy};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::report_target_environment;
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{DryRun, Modifications};
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::{diagnostics, ExitStatus, SharedState};
 use crate::printer::Printer;
@@ -58,7 +62,9 @@ pub(crate) async fn pip_install(
     keyring_provider: KeyringProviderType,
     reinstall: Reinstall,
     link_mode: LinkMode,
-    compile: bool,
+    compile: CompileBytecode,
+    invalidation_mode: Option<PycInvalidationMode>,
+    script_launcher: &ScriptLauncher,
     hash_checking: Option<HashCheckingMode>,
     connectivity: Connectivity,
     config_settings: &ConfigSettings,
@@ -70,6 +76,7 @@ pub(crate) async fn pip_install(
     python_platform: Option<TargetTriple>,
     strict: bool,
     exclude_newer: Option<ExcludeNewer>,
+    max_backtracks: Option<usize>,
     sources: SourceStrategy,
     python: Option<String>,
     system: bool,
@@ -77,10 +84,12 @@ pub(crate) async fn pip_install(
     target: Option<Target>,
     prefix: Option<Prefix>,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: Cache,
     dry_run: bool,
+    report: Option<&Path>,
     printer: Printer,
 ) -> anyhow::Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -208,6 +217,7 @@ pub(crate) async fn pip_install(
         && source_trees.is_empty()
         && overrides.is_empty()
         && matches!(modifications, Modifications::Sufficient)
+        && report.is_none()
     {
         match site_packages.satisfies(&requirements, &constraints, &marker_env)? {
             // If the requirements are already satisfied, we're done.
@@ -248,6 +258,7 @@ pub(crate) async fn pip_install(
         python_version.as_ref(),
         python_platform.as_ref(),
         interpreter,
+        tag_preferences,
     )?;
 
     // Collect the set of required hashes.
@@ -372,6 +383,7 @@ pub(crate) async fn pip_install(
         LowerBound::Warn,
         sources,
         concurrency,
+        tag_preferences,
     );
 
     let options = OptionsBuilder::new()
@@ -379,6 +391,7 @@ pub(crate) async fn pip_install(
         .prerelease_mode(prerelease_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
+        .max_backtracks(max_backtracks)
         .index_strategy(index_strategy)
         .build();
 
@@ -401,6 +414,10 @@ pub(crate) async fn pip_install(
         ResolverEnvironment::specific(marker_env.clone()),
         python_requirement,
         ConflictingGroupList::empty(),
+        Variants::default(),
+        GroupResolution::default(),
+        Vendored::default(),
+        IndexPatterns::default(),
         &client,
         &flat_index,
         &state.index,
@@ -412,7 +429,21 @@ pub(crate) async fn pip_install(
     )
     .await
     {
-        Ok(resolution) => Resolution::from(resolution),
+        Ok(graph) => {
+            // Write the installation report, if requested.
+            if let Some(report) = report {
+                let contents = serde_json::to_string_pretty(&InstallationReport::from_graph(
+                    &graph,
+                    marker_env.markers(),
+                ))?;
+                if report == Path::new("-") {
+                    writeln!(printer.stdout(), "{contents}")?;
+                } else {
+                    fs_err::write(report, contents)?;
+                }
+            }
+            Resolution::from(graph)
+        }
         Err(err) => {
             return diagnostics::OperationDiagnostic::default()
                 .report(err)
@@ -428,7 +459,9 @@ pub(crate) async fn pip_install(
         &reinstall,
         &build_options,
         link_mode,
-        compile,
+        &compile,
+        invalidation_mode,
+        script_launcher,
         &index_locations,
         config_settings,
         &hasher,
@@ -440,7 +473,7 @@ pub(crate) async fn pip_install(
         &cache,
         &environment,
         Box::new(DefaultInstallLogger),
-        dry_run,
+        DryRun::from(dry_run),
         printer,
     )
     .await
@@ -1,8 +1,12 @@
+use jiff::Timestamp;
+
 use uv_client::{RegistryClient, VersionFiles};
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{IndexCapabilities, IndexUrl};
 use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::Tags;
+use uv_pypi_types::{ProjectStatus, Yanked};
 use uv_resolver::{ExcludeNewer, PrereleaseMode, RequiresPython};
 use uv_warnings::warn_user_once;
 
@@ -20,35 +24,101 @@ pub(crate) struct LatestClient<'env> {
     pub(crate) requires_python: &'env RequiresPython,
 }
 
+/// The latest version of a package, along with any newer version that can't be installed.
+#[derive(Debug, Default)]
+pub(crate) struct Latest {
+    /// The latest compatible distribution.
+    pub(crate) dist: Option<DistFilename>,
+    /// The newest version that's newer than the latest compatible distribution, but can't be
+    /// installed, along with the constraint that prevents it.
+    pub(crate) blocked: Option<(Version, UpgradeBlocker)>,
+    /// The status of the project, as reported by the index.
+    pub(crate) status: ProjectStatus,
+    /// The time of the most recent upload to the project, across all versions.
+    pub(crate) last_upload: Option<Timestamp>,
+}
+
+/// The constraint that prevents an upgrade to a newer version of a package.
+///
+/// Variants are ordered by precedence, such that the most fundamental constraint is reported
+/// when a version is blocked for multiple reasons.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum UpgradeBlocker {
+    /// The version is a pre-release, but pre-releases aren't allowed.
+    Prerelease,
+    /// The version requires an incompatible Python version.
+    RequiresPython(VersionSpecifiers),
+    /// The version has no distributions that are compatible with the current platform.
+    Platform,
+}
+
+impl std::fmt::Display for UpgradeBlocker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Prerelease => write!(f, "pre-release"),
+            Self::RequiresPython(specifiers) => write!(f, "requires-python {specifiers}"),
+            Self::Platform => write!(f, "platform"),
+        }
+    }
+}
+
 impl<'env> LatestClient<'env> {
-    /// Find the latest version of a package from an index.
-    pub(crate) async fn find_latest(
+    /// Find the latest version of a package from an index, along with the newest version that
+    /// can't be installed, if any.
+    pub(crate) async fn find_upgrade(
         &self,
         package: &PackageName,
         index: Option<&IndexUrl>,
-    ) -> anyhow::Result<Option<DistFilename>, uv_client::Error> {
+    ) -> anyhow::Result<Latest, uv_client::Error> {
         let archives = match self.client.simple(package, index, self.capabilities).await {
             Ok(archives) => archives,
             Err(err) => {
                 return match err.into_kind() {
-                    uv_client::ErrorKind::PackageNotFound(_) => Ok(None),
-                    uv_client::ErrorKind::NoIndex(_) => Ok(None),
-                    uv_client::ErrorKind::Offline(_) => Ok(None),
+                    uv_client::ErrorKind::PackageNotFound(_) => Ok(Latest::default()),
+                    uv_client::ErrorKind::NoIndex(_) => Ok(Latest::default()),
+                    uv_client::ErrorKind::Offline(_) => Ok(Latest::default()),
                     kind => Err(kind.into()),
                 }
             }
         };
 
         let mut latest: Option<DistFilename> = None;
+        let mut blocked: Option<(Version, UpgradeBlocker)> = None;
+        let mut status = ProjectStatus::default();
+        let mut last_upload: Option<i64> = None;
         for (_, archive) in archives {
+            // Report the first status that indicates the project is unmaintained.
+            if !status.status.is_unmaintained() {
+                status = rkyv::deserialize::<ProjectStatus, rkyv::rancor::Error>(
+                    archive.project_status(),
+                )
+                .expect("archived project status always deserializes");
+            }
+
             for datum in archive.iter().rev() {
                 // Find the first compatible distribution.
                 let files = rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
                     .expect("archived version files always deserializes");
 
+                // Track the most recent upload, including incompatible distributions.
+                let uploaded = files
+                    .wheels
+                    .iter()
+                    .map(|wheel| &wheel.file)
+                    .chain(files.source_dists.iter().map(|sdist| &sdist.file))
+                    .filter(|file| !file.yanked.as_ref().is_some_and(Yanked::is_yanked))
+                    .filter_map(|file| file.upload_time_utc_ms)
+                    .max();
+                last_upload = last_upload.max(uploaded);
+
                 // Determine whether there's a compatible wheel and/or source distribution.
                 let mut best = None;
 
+                // Track the most fundamental reason for skipping a distribution of this version.
+                // Versions published after the cutoff are ignored entirely.
+                let mut blocker: Option<UpgradeBlocker> = None;
+                let mut excluded = false;
+
                 for (filename, file) in files.all() {
                     // Skip distributions uploaded after the cutoff.
                     if let Some(exclude_newer) = self.exclude_newer {
@@ -56,6 +126,7 @@ impl<'env> LatestClient<'env> {
                             Some(&upload_time)
                                 if upload_time >= exclude_newer.timestamp_millis() =>
                             {
+                                excluded = true;
                                 continue;
                             }
                             None => {
@@ -71,6 +142,7 @@ impl<'env> LatestClient<'env> {
                     // Skip pre-release distributions.
                     if !filename.version().is_stable() {
                         if !matches!(self.prerelease, PrereleaseMode::Allow) {
+                            blocker = Some(UpgradeBlocker::Prerelease);
                             continue;
                         }
                     }
@@ -81,13 +153,14 @@ impl<'env> LatestClient<'env> {
                     }
 
                     // Skip distributions that are incompatible with the Python requirement.
-                    if file
-                        .requires_python
-                        .as_ref()
-                        .is_some_and(|requires_python| {
+                    if let Some(requires_python) =
+                        file.requires_python.as_ref().filter(|requires_python| {
                             !self.requires_python.is_contained_by(requires_python)
                         })
                     {
+                        let reason = UpgradeBlocker::RequiresPython(requires_python.clone());
+                        blocker =
+                            Some(blocker.map_or(reason.clone(), |blocker| blocker.min(reason)));
                         continue;
                     }
 
@@ -97,6 +170,9 @@ impl<'env> LatestClient<'env> {
                             .tags
                             .is_some_and(|tags| !filename.compatibility(tags).is_compatible())
                         {
+                            blocker = Some(blocker.map_or(UpgradeBlocker::Platform, |blocker| {
+                                blocker.min(UpgradeBlocker::Platform)
+                            }));
                             continue;
                         }
                     }
@@ -123,10 +199,36 @@ impl<'env> LatestClient<'env> {
                     (None, Some(best)) => {
                         latest = Some(best);
                     }
-                    _ => {}
+                    (_, None) => {
+                        // Track the newest version that was skipped.
+                        if let Some(blocker) = blocker.filter(|_| !excluded) {
+                            let version =
+                                rkyv::deserialize::<Version, rkyv::rancor::Error>(&datum.version)
+                                    .expect("archived version always deserializes");
+                            if blocked
+                                .as_ref()
+                                .map_or(true, |(blocked, _)| version > *blocked)
+                            {
+                                blocked = Some((version, blocker));
+                            }
+                        }
+                    }
                 }
             }
         }
-        Ok(latest)
+
+        // Only report blocked versions that are newer than the latest compatible version.
+        let blocked = blocked.filter(|(version, _)| {
+            latest
+                .as_ref()
+                .map_or(true, |latest| version > latest.version())
+        });
+
+        Ok(Latest {
+            dist: latest,
+            blocked,
+            status,
+            last_upload: last_upload.and_then(|ms| Timestamp::from_millisecond(ms).ok()),
+        })
     }
 }
@@ -22,11 +22,12 @@ use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_platform_tags::TagPreferences;
 use uv_python::PythonRequest;
 use uv_python::{EnvironmentPreference, PythonEnvironment};
 use uv_resolver::{ExcludeNewer, PrereleaseMode, RequiresPython};
 
-use crate::commands::pip::latest::LatestClient;
+use crate::commands::pip::latest::{Latest, LatestClient};
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
@@ -38,6 +39,7 @@ pub(crate) async fn pip_list(
     exclude: &[PackageName],
     format: &ListFormat,
     outdated: bool,
+    uptodate: bool,
     prerelease: PrereleaseMode,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -49,6 +51,7 @@ pub(crate) async fn pip_list(
     python: Option<&str>,
     system: bool,
     native_tls: bool,
+    tag_preferences: &TagPreferences,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -78,7 +81,7 @@ pub(crate) async fn pip_list(
         .collect_vec();
 
     // Determine the latest version for each package.
-    let latest = if outdated {
+    let latest = if outdated || uptodate {
         let capabilities = IndexCapabilities::default();
 
         // Initialize the registry client.
@@ -96,7 +99,7 @@ pub(crate) async fn pip_list(
 
         // Determine the platform tags.
         let interpreter = environment.interpreter();
-        let tags = interpreter.tags()?;
+        let tags = interpreter.tags(tag_preferences)?;
         let requires_python =
             RequiresPython::greater_than_equal_version(interpreter.python_full_version());
 
@@ -106,7 +109,7 @@ pub(crate) async fn pip_list(
             capabilities: &capabilities,
             prerelease,
             exclude_newer,
-            tags: Some(tags),
+            tags: Some(&tags),
             requires_python: &requires_python,
         };
 
@@ -114,8 +117,8 @@ pub(crate) async fn pip_list(
         results
             .iter()
             .map(|dist| async {
-                let latest = client.find_latest(dist.name(), None).await?;
-                Ok::<(&PackageName, Option<DistFilename>), uv_client::Error>((dist.name(), latest))
+                let latest = client.find_upgrade(dist.name(), None).await?;
+                Ok::<(&PackageName, Latest), uv_client::Error>((dist.name(), latest))
             })
             .collect::<FuturesUnordered<_>>()
             .try_collect::<FxHashMap<_, _>>()
@@ -124,16 +127,27 @@ pub(crate) async fn pip_list(
         FxHashMap::default()
     };
 
-    // Remove any up-to-date packages from the results.
+    // Remove any up-to-date (or outdated) packages from the results.
     let results = if outdated {
         results
             .into_iter()
             .filter(|dist| {
                 latest[dist.name()]
+                    .dist
                     .as_ref()
                     .is_some_and(|filename| filename.version() > dist.version())
             })
             .collect_vec()
+    } else if uptodate {
+        results
+            .into_iter()
+            .filter(|dist| {
+                latest[dist.name()]
+                    .dist
+                    .as_ref()
+                    .is_some_and(|filename| filename.version() <= dist.version())
+            })
+            .collect_vec()
     } else {
         results
     };
@@ -148,13 +162,21 @@ pub(crate) async fn pip_list(
                     version: dist.version().clone(),
                     latest_version: latest
                         .get(dist.name())
-                        .and_then(|filename| filename.as_ref())
+                        .and_then(|latest| latest.dist.as_ref())
                         .map(DistFilename::version)
                         .cloned(),
                     latest_filetype: latest
                         .get(dist.name())
-                        .and_then(|filename| filename.as_ref())
+                        .and_then(|latest| latest.dist.as_ref())
                         .map(FileType::from),
+                    blocked_version: latest
+                        .get(dist.name())
+                        .and_then(|latest| latest.blocked.as_ref())
+                        .map(|(version, _)| version.clone()),
+                    blocked_by: latest
+                        .get(dist.name())
+                        .and_then(|latest| latest.blocked.as_ref())
+                        .map(|(_, blocker)| blocker.to_string()),
                     editable_project_location: dist
                         .as_editable()
                         .map(|url| url.to_file_path().unwrap().simplified_display().to_string()),
@@ -193,7 +215,7 @@ pub(crate) async fn pip_list(
                         .map(|dist| {
                             latest
                                 .get(dist.name())
-                                .and_then(|filename| filename.as_ref())
+                                .and_then(|latest| latest.dist.as_ref())
                                 .map(DistFilename::version)
                                 .map(ToString::to_string)
                                 .unwrap_or_default()
@@ -207,7 +229,7 @@ pub(crate) async fn pip_list(
                         .map(|dist| {
                             latest
                                 .get(dist.name())
-                                .and_then(|filename| filename.as_ref())
+                                .and_then(|latest| latest.dist.as_ref())
                                 .map(FileType::from)
                                 .as_ref()
                                 .map(ToString::to_string)
@@ -217,6 +239,28 @@ pub(crate) async fn pip_list(
                 });
             }
 
+            // The blocked upgrade is only displayed if at least one package can't be upgraded to
+            // its newest version.
+            if results.iter().any(|dist| {
+                latest
+                    .get(dist.name())
+                    .is_some_and(|latest| latest.blocked.is_some())
+            }) {
+                columns.push(Column {
+                    header: String::from("Blocked upgrade"),
+                    rows: results
+                        .iter()
+                        .map(|dist| {
+                            latest
+                                .get(dist.name())
+                                .and_then(|latest| latest.blocked.as_ref())
+                                .map(|(version, blocker)| format!("{version} ({blocker})"))
+                                .unwrap_or_default()
+                        })
+                        .collect_vec(),
+                });
+            }
+
             // Editable column is only displayed if at least one editable package is found.
             if results.iter().copied().any(InstalledDist::is_editable) {
                 columns.push(Column {
@@ -313,16 +357,20 @@ struct Entry {
     #[serde(skip_serializing_if = "Option::is_none")]
     latest_filetype: Option<FileType>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    blocked_version: Option<Version>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blocked_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     editable_project_location: Option<String>,
 }
 
 /// A column in a table.
 #[derive(Debug)]
-struct Column {
+pub(crate) struct Column {
     /// The header of the column.
-    header: String,
+    pub(crate) header: String,
     /// The rows of the column.
-    rows: Vec<String>,
+    pub(crate) rows: Vec<String>,
 }
 
 impl<'a> Column {
@@ -335,7 +383,7 @@ impl<'a> Column {
     }
 
     /// Return an iterator of the column, with the header and rows formatted to the maximum width.
-    fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
+    pub(crate) fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
         let max_width = self.max_width();
         let header = vec![
             format!("{0:width$}", self.header, width = max_width),
@@ -352,7 +400,7 @@ impl<'a> Column {
 ///
 /// A combination of [`itertools::multizip`] and [`itertools::izip`].
 #[derive(Debug)]
-struct MultiZip<T>(Vec<T>);
+pub(crate) struct MultiZip<T>(pub(crate) Vec<T>);
 
 impl<T> Iterator for MultiZip<T>
 where
@@ -1,7 +1,5 @@
-use std::borrow::Cow;
-
 use uv_configuration::TargetTriple;
-use uv_platform_tags::{Tags, TagsError};
+use uv_platform_tags::{TagPreferences, Tags, TagsError};
 use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{Interpreter, PythonVersion};
 
@@ -37,37 +35,41 @@ pub(crate) fn resolution_markers(
     }
 }
 
-pub(crate) fn resolution_tags<'env>(
+pub(crate) fn resolution_tags(
     python_version: Option<&PythonVersion>,
     python_platform: Option<&TargetTriple>,
-    interpreter: &'env Interpreter,
-) -> Result<Cow<'env, Tags>, TagsError> {
+    interpreter: &Interpreter,
+    tag_preferences: &TagPreferences,
+) -> Result<Tags, TagsError> {
     Ok(match (python_platform, python_version.as_ref()) {
-        (Some(python_platform), Some(python_version)) => Cow::Owned(Tags::from_env(
+        (Some(python_platform), Some(python_version)) => Tags::from_env(
             &python_platform.platform(),
             (python_version.major(), python_version.minor()),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
-        )?),
-        (Some(python_platform), None) => Cow::Owned(Tags::from_env(
+            tag_preferences,
+        )?,
+        (Some(python_platform), None) => Tags::from_env(
             &python_platform.platform(),
             interpreter.python_tuple(),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
-        )?),
-        (None, Some(python_version)) => Cow::Owned(Tags::from_env(
+            tag_preferences,
+        )?,
+        (None, Some(python_version)) => Tags::from_env(
             interpreter.platform(),
             (python_version.major(), python_version.minor()),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
-        )?),
-        (None, None) => Cow::Borrowed(interpreter.tags()?),
+            tag_preferences,
+        )?,
+        (None, None) => interpreter.tags(tag_preferences)?,
     })
 }
 
@@ -76,33 +78,37 @@ pub(crate) fn resolution_environment(
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     interpreter: &Interpreter,
-) -> Result<(Cow<'_, Tags>, ResolverMarkerEnvironment), TagsError> {
+    tag_preferences: &TagPreferences,
+) -> Result<(Tags, ResolverMarkerEnvironment), TagsError> {
     let tags = match (python_platform, python_version.as_ref()) {
-        (Some(python_platform), Some(python_version)) => Cow::Owned(Tags::from_env(
+        (Some(python_platform), Some(python_version)) => Tags::from_env(
             &python_platform.platform(),
             (python_version.major(), python_version.minor()),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
-        )?),
-        (Some(python_platform), None) => Cow::Owned(Tags::from_env(
+            tag_preferences,
+        )?,
+        (Some(python_platform), None) => Tags::from_env(
             &python_platform.platform(),
             interpreter.python_tuple(),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
-        )?),
-        (None, Some(python_version)) => Cow::Owned(Tags::from_env(
+            tag_preferences,
+        )?,
+        (None, Some(python_version)) => Tags::from_env(
             interpreter.platform(),
             (python_version.major(), python_version.minor()),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
-        )?),
-        (None, None) => Cow::Borrowed(interpreter.tags()?),
+            tag_preferences,
+        )?,
+        (None, None) => interpreter.tags(tag_preferences)?,
     };
 
     // Apply the platform tags to the markers.
@@ -3,31 +3,37 @@
 use anyhow::{anyhow, Context};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
 use std::collections::{BTreeSet, HashSet};
 use std::fmt::Write;
-use std::path::PathBuf;
-use tracing::debug;
+use std::path::{Path, PathBuf};
+use tracing::{debug, instrument};
 use uv_tool::InstalledTools;
 
+use uv_cache::metrics::{self, Phase};
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, Overrides,
-    Reinstall, Upgrade,
+    BuildOptions, CompileBytecode, Concurrency, ConfigSettings, Constraints, ExtrasSpecification,
+    Overrides, PycInvalidationMode, Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
-    CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification,
-    ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
+    BuiltDist, Dist, DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution,
+    SourceDist,
 };
 use uv_distribution_types::{
-    DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution,
+    CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification,
+    ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::linker::LinkMode;
+use uv_install_wheel::ScriptLauncher;
 use uv_installer::{Plan, Planner, Preparer, SitePackages};
 use uv_normalize::{GroupName, PackageName};
+use uv_pep440::Version;
 use uv_platform_tags::Tags;
 use uv_pypi_types::{ConflictingGroupList, ResolverMarkerEnvironment};
 use uv_python::PythonEnvironment;
@@ -36,15 +42,16 @@ use uv_requirements::{
     SourceTreeResolver,
 };
 use uv_resolver::{
-    DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
-    Preferences, PythonRequirement, ResolutionGraph, Resolver, ResolverEnvironment,
+    DependencyMode, Exclusions, FlatIndex, GroupResolution, InMemoryIndex, IndexPatterns, Manifest,
+    Options, Preference, Preferences, PythonRequirement, ResolutionGraph, Resolver,
+    ResolverEnvironment, Variants, Vendored,
 };
 use uv_types::{HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger, ResolveLogger};
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
-use crate::commands::{compile_bytecode, ChangeEventKind, DryRunEvent};
+use crate::commands::{compile_bytecode, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Consolidate the requirements for an installation.
@@ -87,6 +94,7 @@ pub(crate) async fn read_constraints(
 }
 
 /// Resolve a set of requirements, similar to running `pip compile`.
+#[instrument(skip_all)]
 pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
     constraints: Vec<NameRequirementSpecification>,
@@ -105,6 +113,10 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     resolver_env: ResolverEnvironment,
     python_requirement: PythonRequirement,
     conflicting_groups: ConflictingGroupList,
+    variants: Variants,
+    group_resolution: GroupResolution,
+    vendored: Vendored,
+    index_patterns: IndexPatterns,
     client: &RegistryClient,
     flat_index: &FlatIndex,
     index: &InMemoryIndex,
@@ -274,7 +286,11 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         workspace_members,
         exclusions,
         lookaheads,
-    );
+    )
+    .with_variants(variants)
+    .with_group_resolution(group_resolution)
+    .with_vendored(vendored)
+    .with_index_patterns(index_patterns);
 
     // Resolve the dependencies.
     let resolution = {
@@ -305,6 +321,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         resolver.resolve().await?
     };
 
+    metrics::record_phase(Phase::Resolve, start.elapsed());
     logger.on_complete(resolution.len(), start, printer)?;
 
     Ok(resolution)
@@ -325,6 +342,47 @@ pub(crate) enum Modifications {
     Exact,
 }
 
+/// Whether to perform a dry run, and how to report on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DryRun {
+    /// Modify the environment.
+    Disabled,
+    /// Report the changes that would be made to the environment, without making them.
+    Enabled,
+    /// Report the changes that would be made to the environment, along with the size of each
+    /// package that would be installed or removed.
+    Sizes {
+        /// Write the size report to stdout as JSON.
+        json: bool,
+    },
+}
+
+impl DryRun {
+    /// Determine the [`DryRun`] mode from the command-line arguments.
+    pub(crate) fn from_args(dry_run: bool, show_sizes: bool, json: bool) -> Self {
+        match (dry_run, show_sizes) {
+            (false, _) => Self::Disabled,
+            (true, false) => Self::Enabled,
+            (true, true) => Self::Sizes { json },
+        }
+    }
+
+    /// Returns `true` if the environment should not be modified.
+    pub(crate) fn enabled(self) -> bool {
+        !matches!(self, Self::Disabled)
+    }
+}
+
+impl From<bool> for DryRun {
+    fn from(dry_run: bool) -> Self {
+        if dry_run {
+            Self::Enabled
+        } else {
+            Self::Disabled
+        }
+    }
+}
+
 /// A summary of the changes made to the environment during an installation.
 #[derive(Debug, Clone, Default)]
 pub(crate) struct Changelog {
@@ -380,6 +438,7 @@ impl Changelog {
 /// Install a set of requirements into the current environment.
 ///
 /// Returns a [`Changelog`] summarizing the changes made to the environment.
+#[instrument(skip_all)]
 pub(crate) async fn install(
     resolution: &Resolution,
     site_packages: SitePackages,
@@ -387,7 +446,9 @@ pub(crate) async fn install(
     reinstall: &Reinstall,
     build_options: &BuildOptions,
     link_mode: LinkMode,
-    compile: bool,
+    compile: &CompileBytecode,
+    invalidation_mode: Option<PycInvalidationMode>,
+    script_launcher: &ScriptLauncher,
     index_urls: &IndexLocations,
     config_settings: &ConfigSettings,
     hasher: &HashStrategy,
@@ -399,11 +460,14 @@ pub(crate) async fn install(
     cache: &Cache,
     venv: &PythonEnvironment,
     logger: Box<dyn InstallLogger>,
-    dry_run: bool,
+    dry_run: DryRun,
     printer: Printer,
 ) -> Result<Changelog, Error> {
     let start = std::time::Instant::now();
 
+    // Evaluate any environment-scoped build exclusions against the target environment.
+    let build_options = &build_options.for_environment(venv.interpreter().markers());
+
     // Partition into those that should be linked from the cache (`local`), those that need to be
     // downloaded (`remote`), and those that should be removed (`extraneous`).
     let plan = Planner::new(resolution)
@@ -420,9 +484,26 @@ pub(crate) async fn install(
         )
         .context("Failed to determine installation plan")?;
 
-    if dry_run {
-        report_dry_run(resolution, plan, modifications, start, printer)?;
-        return Ok(Changelog::default());
+    match dry_run {
+        DryRun::Disabled => {}
+        DryRun::Enabled => {
+            report_dry_run(resolution, plan, modifications, start, printer)?;
+            return Ok(Changelog::default());
+        }
+        DryRun::Sizes { json } => {
+            let sizes = SizeReport::from_plan(&plan, modifications);
+            if json {
+                writeln!(
+                    printer.stdout(),
+                    "{}",
+                    serde_json::to_string_pretty(&sizes).map_err(anyhow::Error::from)?
+                )?;
+            } else {
+                report_dry_run(resolution, plan, modifications, start, printer)?;
+                sizes.write(printer)?;
+            }
+            return Ok(Changelog::default());
+        }
     }
 
     let Plan {
@@ -461,11 +542,15 @@ pub(crate) async fn install(
 
         let wheels = preparer.prepare(remote.clone(), in_flight).await?;
 
+        metrics::record_phase(Phase::Prepare, start.elapsed());
         logger.on_prepare(wheels.len(), start, printer)?;
 
         wheels
     };
 
+    // Allow any plugins to verify the artifacts prior to installation.
+    verify_artifacts(&wheels, &cached).await?;
+
     // Remove any upgraded or extraneous installations.
     let uninstalls = extraneous.into_iter().chain(reinstalls).collect::<Vec<_>>();
     if !uninstalls.is_empty() {
@@ -503,6 +588,7 @@ pub(crate) async fn install(
             }
         }
 
+        metrics::record_phase(Phase::Uninstall, start.elapsed());
         logger.on_uninstall(uninstalls.len(), start, printer)?;
     }
 
@@ -512,6 +598,7 @@ pub(crate) async fn install(
         let start = std::time::Instant::now();
         installs = uv_installer::Installer::new(venv)
             .with_link_mode(link_mode)
+            .with_script_launcher(script_launcher.clone())
             .with_cache(cache)
             .with_reporter(InstallReporter::from(printer).with_length(installs.len() as u64))
             // This technically can block the runtime, but we are on the main thread and
@@ -519,11 +606,12 @@ pub(crate) async fn install(
             // task.
             .install_blocking(installs)?;
 
+        metrics::record_phase(Phase::Install, start.elapsed());
         logger.on_install(installs.len(), start, printer)?;
     }
 
-    if compile {
-        compile_bytecode(venv, cache, printer).await?;
+    if compile.is_enabled() {
+        compile_bytecode(venv, compile, invalidation_mode, cache, printer).await?;
     }
 
     // Construct a summary of the changes made to the environment.
@@ -650,53 +738,81 @@ fn report_dry_run(
         )?;
     }
 
-    // TODO(charlie): DRY this up with `report_modifications`. The types don't quite line up.
-    for event in reinstalls
+    // Pair each distribution to be installed with the distribution it replaces, if any, so that
+    // upgrades and downgrades are reported as a single transition.
+    let mut replaced = reinstalls
         .into_iter()
-        .chain(extraneous.into_iter())
-        .map(|distribution| DryRunEvent {
-            name: distribution.name().clone(),
-            version: distribution.installed_version().to_string(),
-            kind: ChangeEventKind::Removed,
-        })
-        .chain(wheels.into_iter().map(|distribution| DryRunEvent {
-            name: distribution.name().clone(),
-            version: distribution.version_or_url().to_string(),
-            kind: ChangeEventKind::Added,
-        }))
-        .chain(cached.into_iter().map(|distribution| DryRunEvent {
-            name: distribution.name().clone(),
-            version: distribution.installed_version().to_string(),
-            kind: ChangeEventKind::Added,
-        }))
-        .sorted_unstable_by(|a, b| a.name.cmp(&b.name).then_with(|| a.kind.cmp(&b.kind)))
-    {
+        .map(|dist| (dist.name().clone(), dist))
+        .collect::<FxHashMap<_, _>>();
+
+    let mut events = Vec::with_capacity(wheels.len() + cached.len());
+    for dist in &wheels {
+        events.push(DryRunEvent {
+            name: dist.name().clone(),
+            kind: DryRunEventKind::from_replaced(
+                replaced.remove(dist.name()),
+                &dist.version_or_url(),
+            ),
+            source: DryRunSource::from(dist),
+        });
+    }
+    for dist in &cached {
+        events.push(DryRunEvent {
+            name: dist.name().clone(),
+            kind: DryRunEventKind::from_replaced(
+                replaced.remove(dist.name()),
+                &dist.installed_version(),
+            ),
+            source: DryRunSource::Cached,
+        });
+    }
+    events.extend(
+        replaced
+            .into_values()
+            .chain(extraneous)
+            .map(|dist| DryRunEvent {
+                name: dist.name().clone(),
+                kind: DryRunEventKind::Remove(dist.installed_version().to_string()),
+                source: DryRunSource::Installed,
+            }),
+    );
+    events.sort_unstable_by(|a, b| a.name.cmp(&b.name).then_with(|| a.kind.cmp(&b.kind)));
+
+    for event in events {
+        let source = event.source.to_string();
+        let source = if source.is_empty() {
+            source
+        } else {
+            format!(" ({source})").dimmed().to_string()
+        };
         match event.kind {
-            ChangeEventKind::Added => {
+            DryRunEventKind::Install(version) => {
                 writeln!(
                     printer.stderr(),
-                    " {} {}{}",
+                    " {} {}{}{source}",
                     "+".green(),
                     event.name.bold(),
-                    event.version.dimmed()
+                    version.dimmed()
                 )?;
             }
-            ChangeEventKind::Removed => {
+            DryRunEventKind::Remove(version) => {
                 writeln!(
                     printer.stderr(),
-                    " {} {}{}",
+                    " {} {}{}{source}",
                     "-".red(),
                     event.name.bold(),
-                    event.version.dimmed()
+                    version.dimmed()
                 )?;
             }
-            ChangeEventKind::Reinstalled => {
+            DryRunEventKind::Replace { from, to } => {
                 writeln!(
                     printer.stderr(),
-                    " {} {}{}",
+                    " {} {} {} {} {}{source}",
                     "~".yellow(),
                     event.name.bold(),
-                    event.version.dimmed()
+                    from.dimmed(),
+                    "->".dimmed(),
+                    to.dimmed()
                 )?;
             }
         }
@@ -705,6 +821,292 @@ fn report_dry_run(
     Ok(())
 }
 
+/// A change to a single package in a dry run.
+#[derive(Debug)]
+struct DryRunEvent {
+    name: PackageName,
+    kind: DryRunEventKind,
+    source: DryRunSource,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum DryRunEventKind {
+    /// The package would be removed, e.g., `==1.0.0`.
+    Remove(String),
+    /// The package would be installed, e.g., `==1.0.0`.
+    Install(String),
+    /// An installed package would be replaced, e.g., upgraded from `1.0.0` to `2.0.0`.
+    Replace { from: String, to: String },
+}
+
+impl DryRunEventKind {
+    /// Determine the change for a distribution to be installed, given the installed distribution
+    /// it replaces, if any.
+    fn from_replaced(replaced: Option<InstalledDist>, version: &impl std::fmt::Display) -> Self {
+        let version = version.to_string();
+        match replaced {
+            None => Self::Install(version),
+            Some(replaced) => Self::Replace {
+                from: transition_version(&replaced.installed_version().to_string()),
+                to: transition_version(&version),
+            },
+        }
+    }
+}
+
+/// Strip the leading `==` or ` @ ` from a version or URL, for display in a transition (e.g.,
+/// `1.0.0 -> 2.0.0`).
+fn transition_version(version: &str) -> String {
+    version
+        .strip_prefix("==")
+        .or_else(|| version.strip_prefix(" @ "))
+        .unwrap_or(version)
+        .to_string()
+}
+
+/// Where the files for a package in a dry run would come from.
+#[derive(Debug)]
+enum DryRunSource {
+    /// The package is already installed (i.e., it would be removed).
+    Installed,
+    /// The package would be linked from a wheel in the cache.
+    Cached,
+    /// The package would be installed from a wheel on the local filesystem.
+    LocalWheel,
+    /// The package would be installed from a downloaded wheel.
+    Wheel(Option<u64>),
+    /// The package would be built from a downloaded source distribution.
+    SourceDist(Option<u64>),
+    /// The package would be built from a local directory, archive, or Git repository.
+    Build,
+}
+
+impl From<&Dist> for DryRunSource {
+    fn from(dist: &Dist) -> Self {
+        match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => Self::Wheel(wheels.best_wheel().file.size),
+            Dist::Built(BuiltDist::DirectUrl(_)) => Self::Wheel(None),
+            Dist::Built(BuiltDist::Path(_)) => Self::LocalWheel,
+            Dist::Source(SourceDist::Registry(sdist)) => Self::SourceDist(sdist.file.size),
+            Dist::Source(SourceDist::DirectUrl(_)) => Self::SourceDist(None),
+            Dist::Source(SourceDist::Git(_) | SourceDist::Path(_) | SourceDist::Directory(_)) => {
+                Self::Build
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for DryRunSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Installed => Ok(()),
+            Self::Cached => write!(f, "cached"),
+            Self::LocalWheel => write!(f, "local wheel"),
+            Self::Wheel(None) => write!(f, "wheel"),
+            Self::Wheel(Some(size)) => write!(f, "wheel, {} download", format_bytes(*size)),
+            Self::SourceDist(None) => write!(f, "source distribution, requires build"),
+            Self::SourceDist(Some(size)) => write!(
+                f,
+                "source distribution, {} download, requires build",
+                format_bytes(*size)
+            ),
+            Self::Build => write!(f, "requires build"),
+        }
+    }
+}
+
+/// The size of a package that would be installed or removed in a dry run.
+#[derive(Debug, Serialize)]
+struct PackageSize {
+    name: PackageName,
+    /// The version of the package, if known (i.e., for packages not installed from a URL).
+    version: Option<Version>,
+    action: SizeAction,
+    /// The size of the archive to download, if the package isn't in the cache.
+    download_size: Option<u64>,
+    /// The size of the package on disk, once installed (or prior to removal).
+    unpacked_size: Option<u64>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum SizeAction {
+    Install,
+    Remove,
+}
+
+/// A report on the size of the packages that would be installed or removed in a dry run.
+#[derive(Debug, Serialize)]
+struct SizeReport {
+    packages: Vec<PackageSize>,
+    /// The total size of the archives to download.
+    download_size: u64,
+    /// The change in the size of the environment, excluding any packages of unknown size.
+    environment_delta: i64,
+    /// The number of packages whose size could not be determined.
+    unknown: usize,
+}
+
+impl SizeReport {
+    /// Determine the sizes of the packages in an installation [`Plan`].
+    ///
+    /// Download sizes are read from the index metadata, and unpacked sizes from the unzipped wheels
+    /// in the cache. The sizes of installed packages are read from their `RECORD` files.
+    fn from_plan(plan: &Plan, modifications: Modifications) -> Self {
+        let extraneous: &[InstalledDist] = match modifications {
+            Modifications::Sufficient => &[],
+            Modifications::Exact => &plan.extraneous,
+        };
+
+        let mut packages = plan
+            .remote
+            .iter()
+            .map(|dist| PackageSize {
+                name: dist.name().clone(),
+                version: dist.version().cloned(),
+                action: SizeAction::Install,
+                download_size: dist.file().and_then(|file| file.size),
+                unpacked_size: None,
+            })
+            .chain(plan.cached.iter().map(|dist| PackageSize {
+                name: dist.name().clone(),
+                version: Some(dist.filename().version.clone()),
+                action: SizeAction::Install,
+                download_size: Some(0),
+                unpacked_size: Some(directory_size(dist.path())),
+            }))
+            .chain(
+                plan.reinstalls
+                    .iter()
+                    .chain(extraneous)
+                    .map(|dist| PackageSize {
+                        name: dist.name().clone(),
+                        version: Some(dist.version().clone()),
+                        action: SizeAction::Remove,
+                        download_size: None,
+                        unpacked_size: installed_size(dist),
+                    }),
+            )
+            .collect::<Vec<_>>();
+        packages.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.action.cmp(&b.action)));
+
+        let mut download_size = 0;
+        let mut environment_delta = 0i64;
+        let mut unknown = 0;
+        for package in &packages {
+            download_size += package.download_size.unwrap_or(0);
+            match (package.action, package.unpacked_size) {
+                (SizeAction::Install, Some(size)) => {
+                    environment_delta += i64::try_from(size).unwrap_or(i64::MAX);
+                }
+                (SizeAction::Remove, Some(size)) => {
+                    environment_delta -= i64::try_from(size).unwrap_or(i64::MAX);
+                }
+                (_, None) => unknown += 1,
+            }
+        }
+
+        Self {
+            packages,
+            download_size,
+            environment_delta,
+            unknown,
+        }
+    }
+
+    /// Write the report to the user.
+    fn write(&self, printer: Printer) -> Result<(), Error> {
+        if self.packages.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(printer.stderr(), "{}", "Package sizes:".bold())?;
+        for package in &self.packages {
+            let detail = match package.action {
+                SizeAction::Install => match (package.download_size, package.unpacked_size) {
+                    (_, Some(unpacked)) => format!("cached, {} unpacked", format_bytes(unpacked)),
+                    (Some(download), None) => format!("{} download", format_bytes(download)),
+                    (None, None) => "unknown size".to_string(),
+                },
+                SizeAction::Remove => match package.unpacked_size {
+                    Some(unpacked) => format!("{} removed", format_bytes(unpacked)),
+                    None => "unknown size".to_string(),
+                },
+            };
+            let sign = match package.action {
+                SizeAction::Install => "+".green().to_string(),
+                SizeAction::Remove => "-".red().to_string(),
+            };
+            let version = package
+                .version
+                .as_ref()
+                .map(|version| format!("=={version}"))
+                .unwrap_or_default();
+            writeln!(
+                printer.stderr(),
+                " {sign} {}{} {}",
+                package.name.bold(),
+                version.dimmed(),
+                format!("({detail})").dimmed()
+            )?;
+        }
+
+        let delta = format!(
+            "{}{}",
+            if self.environment_delta < 0 { "-" } else { "+" },
+            format_bytes(self.environment_delta.unsigned_abs())
+        );
+        write!(
+            printer.stderr(),
+            "Would download {} and change the environment size by {}",
+            format_bytes(self.download_size).bold(),
+            delta.bold()
+        )?;
+        if self.unknown > 0 {
+            let s = if self.unknown == 1 { "" } else { "s" };
+            write!(
+                printer.stderr(),
+                " (excluding {} package{s} of unknown unpacked size)",
+                self.unknown
+            )?;
+        }
+        writeln!(printer.stderr())?;
+
+        Ok(())
+    }
+}
+
+/// Format a size in bytes for display.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
+
+/// Return the total size of the files in a directory.
+fn directory_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(std::fs::Metadata::is_file)
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Return the size of an installed distribution, as recorded in its `RECORD` file.
+fn installed_size(dist: &InstalledDist) -> Option<u64> {
+    let (InstalledDist::Registry(_) | InstalledDist::Url(_)) = dist else {
+        return None;
+    };
+    let mut record = fs_err::File::open(dist.path().join("RECORD")).ok()?;
+    let entries = uv_install_wheel::read_record_file(&mut record).ok()?;
+    Some(entries.iter().filter_map(|entry| entry.size).sum())
+}
+
 /// Report any diagnostics on resolved distributions.
 pub(crate) fn diagnose_resolution(
     diagnostics: &[ResolutionDiagnostic],
@@ -748,6 +1150,31 @@ pub(crate) fn diagnose_environment(
     Ok(())
 }
 
+/// Invoke the `verify-artifacts` plugin hook for the distributions to be installed.
+async fn verify_artifacts(
+    wheels: &[CachedDist],
+    cached: &[CachedDist],
+) -> Result<(), uv_plugin::Error> {
+    let artifacts = wheels
+        .iter()
+        .chain(cached)
+        .map(|dist| {
+            let hashes = match dist {
+                CachedDist::Registry(dist) => &dist.hashes,
+                CachedDist::Url(dist) => &dist.hashes,
+            };
+            uv_plugin::Artifact {
+                name: &dist.filename().name,
+                version: &dist.filename().version,
+                filename: dist.filename().to_string(),
+                path: dist.path(),
+                hashes: hashes.iter().map(ToString::to_string).collect(),
+            }
+        })
+        .collect::<Vec<_>>();
+    uv_plugin::verify_artifacts(&artifacts).await
+}
+
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum Error {
     #[error("Failed to prepare distributions")]
@@ -771,6 +1198,9 @@ pub(crate) enum Error {
     #[error(transparent)]
     Requirements(#[from] uv_requirements::Error),
 
+    #[error(transparent)]
+    Plugin(#[from] uv_plugin::Error),
+
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 }
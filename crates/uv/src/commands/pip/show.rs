@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use fs_err::File;
@@ -7,11 +8,13 @@ use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 
 use uv_cache::Cache;
+use uv_cli::FileCategory;
 use uv_distribution_types::{Diagnostic, Name};
 use uv_fs::Simplified;
 use uv_install_wheel::read_record_file;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
+use uv_pypi_types::Scheme;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
 
 use crate::commands::pip::operations::report_target_environment;
@@ -25,6 +28,7 @@ pub(crate) fn pip_show(
     python: Option<&str>,
     system: bool,
     files: bool,
+    categories: &[FileCategory],
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -56,6 +60,9 @@ pub(crate) fn pip_show(
     // Determine the markers to use for resolution.
     let markers = environment.interpreter().resolver_marker_environment();
 
+    // Determine the installation scheme, to categorize the installed files.
+    let scheme = environment.interpreter().layout().scheme;
+
     // Sort and deduplicate the packages, which are keyed by name.
     packages.sort_unstable();
     packages.dedup();
@@ -192,8 +199,21 @@ pub(crate) fn pip_show(
         if files {
             let path = distribution.path().join("RECORD");
             let record = read_record_file(&mut File::open(path)?)?;
+            let site_packages = distribution
+                .path()
+                .parent()
+                .expect("package path is not root");
             writeln!(printer.stdout(), "Files:")?;
             for entry in record {
+                if !categories.is_empty() {
+                    let path = uv_fs::normalize_path(&site_packages.join(&entry.path));
+                    if !categories
+                        .iter()
+                        .any(|category| in_category(&path, *category, &scheme))
+                    {
+                        continue;
+                    }
+                }
                 writeln!(printer.stdout(), "  {}", entry.path)?;
             }
         }
@@ -214,3 +234,27 @@ pub(crate) fn pip_show(
 
     Ok(ExitStatus::Success)
 }
+
+/// Returns `true` if the installed file at the given path belongs to the given category, based on
+/// the installation scheme path into which it was installed.
+fn in_category(path: &Path, category: FileCategory, scheme: &Scheme) -> bool {
+    match category {
+        FileCategory::Purelib => path.starts_with(&scheme.purelib),
+        FileCategory::Platlib => path.starts_with(&scheme.platlib),
+        FileCategory::Scripts => path.starts_with(&scheme.scripts),
+        FileCategory::Headers => path.starts_with(&scheme.include),
+        // The `data` directory is typically the root of the environment, and so contains all other
+        // scheme paths.
+        FileCategory::Data => {
+            path.starts_with(&scheme.data)
+                && [
+                    FileCategory::Purelib,
+                    FileCategory::Platlib,
+                    FileCategory::Scripts,
+                    FileCategory::Headers,
+                ]
+                .into_iter()
+                .all(|category| !in_category(path, category, scheme))
+        }
+    }
+}
@@ -10,27 +10,29 @@ use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, HashCheckingMode,
     IndexStrategy, LowerBound, Reinstall, SourceStrategy, TrustedHost, Upgrade,
 };
-use uv_configuration::{KeyringProviderType, TargetTriple};
+use uv_configuration::{CompileBytecode, KeyringProviderType, PycInvalidationMode, TargetTriple};
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, Origin, Resolution};
 use uv_fs::Simplified;
 use uv_install_wheel::linker::LinkMode;
+use uv_install_wheel::ScriptLauncher;
 use uv_installer::SitePackages;
 use uv_pep508::PackageName;
+use uv_platform_tags::TagPreferences;
 use uv_pypi_types::ConflictingGroupList;
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonRequest, PythonVersion, Target,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    DependencyMode, ExcludeNewer, FlatIndex, GroupResolution, IndexPatterns, OptionsBuilder,
+    PrereleaseMode, PythonRequirement, ResolutionMode, ResolverEnvironment, Variants, Vendored,
 };
 use uv_types::{BuildIsolation, HashStrategy};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::report_target_environment;
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{DryRun, Modifications};
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::{diagnostics, ExitStatus, SharedState};
 use crate::printer::Printer;
@@ -43,7 +45,9 @@ pub(crate) async fn pip_sync(
     build_constraints: &[RequirementsSource],
     reinstall: Reinstall,
     link_mode: LinkMode,
-    compile: bool,
+    compile: CompileBytecode,
+    invalidation_mode: Option<PycInvalidationMode>,
+    script_launcher: &ScriptLauncher,
     hash_checking: Option<HashCheckingMode>,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -66,6 +70,7 @@ pub(crate) async fn pip_sync(
     prefix: Option<Prefix>,
     sources: SourceStrategy,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: Cache,
@@ -192,6 +197,7 @@ pub(crate) async fn pip_sync(
         python_version.as_ref(),
         python_platform.as_ref(),
         interpreter,
+        tag_preferences,
     )?;
 
     // Collect the set of required hashes.
@@ -315,6 +321,7 @@ pub(crate) async fn pip_sync(
         LowerBound::Warn,
         sources,
         concurrency,
+        tag_preferences,
     );
 
     // Determine the set of installed packages.
@@ -346,6 +353,10 @@ pub(crate) async fn pip_sync(
         ResolverEnvironment::specific(marker_env.clone()),
         python_requirement,
         ConflictingGroupList::empty(),
+        Variants::default(),
+        GroupResolution::default(),
+        Vendored::default(),
+        IndexPatterns::default(),
         &client,
         &flat_index,
         &state.index,
@@ -373,7 +384,9 @@ pub(crate) async fn pip_sync(
         &reinstall,
         &build_options,
         link_mode,
-        compile,
+        &compile,
+        invalidation_mode,
+        script_launcher,
         &index_locations,
         config_settings,
         &hasher,
@@ -385,7 +398,7 @@ pub(crate) async fn pip_sync(
         &cache,
         &environment,
         Box::new(DefaultInstallLogger),
-        dry_run,
+        DryRun::from(dry_run),
         printer,
     )
     .await
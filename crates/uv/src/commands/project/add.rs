@@ -2,11 +2,13 @@ use std::collections::hash_map::Entry;
 use std::fmt::Write;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anyhow::{bail, Context, Result};
+use console::Term;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 use tracing::debug;
 use url::Url;
 
@@ -14,9 +16,9 @@ use uv_cache::Cache;
 use uv_cache_key::RepositoryUrl;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification, DevMode, EditableMode,
-    ExtrasSpecification, GroupsSpecification, InstallOptions, LowerBound, SourceStrategy,
-    TrustedHost,
+    AddBound, Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification, DevMode,
+    EditableMode, ExtrasSpecification, GroupsSpecification, InstallOptions, LowerBound,
+    SourceStrategy, TrustedHost,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -25,25 +27,27 @@ use uv_fs::Simplified;
 use uv_git::{GitReference, GIT_STORE};
 use uv_normalize::{PackageName, DEV_DEPENDENCIES};
 use uv_pep508::{ExtraName, Requirement, UnnamedRequirement, VersionOrUrl};
+use uv_platform_tags::TagPreferences;
 use uv_pypi_types::{redact_credentials, ParsedUrl, RequirementSource, VerbatimParsedUrl};
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest,
 };
+use uv_requirements::imports::ThirdPartyImports;
 use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{FlatIndex, InstallTarget};
 use uv_scripts::{Pep723Item, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
-use uv_warnings::warn_user_once;
-use uv_workspace::pyproject::{DependencyType, Source, SourceError};
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject::{DependencyGroupSpecifier, DependencyType, Source, SourceError};
 use uv_workspace::pyproject_mut::{ArrayEdit, DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace};
 
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryResolveLogger,
 };
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{DryRun, Modifications};
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::{
     init_script_python_requirement, lock, validate_script_requires_python, ProjectError,
@@ -61,10 +65,12 @@ pub(crate) async fn add(
     locked: bool,
     frozen: bool,
     no_sync: bool,
-    requirements: Vec<RequirementsSource>,
+    mut requirements: Vec<RequirementsSource>,
+    from_imports: bool,
     editable: Option<bool>,
     dependency_type: DependencyType,
     raw_sources: bool,
+    bound: AddBound,
     indexes: Vec<Index>,
     rev: Option<String>,
     tag: Option<String>,
@@ -79,6 +85,7 @@ pub(crate) async fn add(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
@@ -262,6 +269,22 @@ pub(crate) async fn add(
         }
     };
 
+    // If requested, propose the third-party packages imported by the project's sources.
+    if from_imports {
+        let Target::Project(project, _) = &target else {
+            unreachable!("`--from-imports` is not supported for scripts");
+        };
+        let packages = imported_packages(project, printer)?;
+        if packages.is_empty() {
+            return Ok(ExitStatus::Success);
+        }
+        requirements.extend(
+            packages
+                .into_iter()
+                .map(|package| RequirementsSource::Package(package.to_string())),
+        );
+    }
+
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
@@ -342,6 +365,7 @@ pub(crate) async fn add(
         bounds,
         sources,
         concurrency,
+        tag_preferences,
     );
 
     // Resolve any unnamed requirements.
@@ -493,6 +517,52 @@ pub(crate) async fn add(
                     marker,
                 })
             }
+            Some(Source::Hg {
+                mut hg,
+                subdirectory,
+                rev,
+                tag,
+                branch,
+                marker,
+            }) => {
+                let credentials = uv_auth::Credentials::from_url(&hg);
+                if let Some(credentials) = credentials {
+                    debug!("Caching credentials for: {hg}");
+                    GIT_STORE.insert(RepositoryUrl::new(&hg), credentials);
+
+                    // Redact the credentials.
+                    redact_credentials(&mut hg);
+                };
+                Some(Source::Hg {
+                    hg,
+                    subdirectory,
+                    rev,
+                    tag,
+                    branch,
+                    marker,
+                })
+            }
+            Some(Source::Svn {
+                mut svn,
+                subdirectory,
+                rev,
+                marker,
+            }) => {
+                let credentials = uv_auth::Credentials::from_url(&svn);
+                if let Some(credentials) = credentials {
+                    debug!("Caching credentials for: {svn}");
+                    GIT_STORE.insert(RepositoryUrl::new(&svn), credentials);
+
+                    // Redact the credentials.
+                    redact_credentials(&mut svn);
+                };
+                Some(Source::Svn {
+                    svn,
+                    subdirectory,
+                    rev,
+                    marker,
+                })
+            }
             _ => source,
         };
 
@@ -670,10 +740,12 @@ pub(crate) async fn add(
         locked,
         &dependency_type,
         raw_sources,
+        bound,
         settings.as_ref(),
         bounds,
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
@@ -712,10 +784,12 @@ async fn lock_and_sync(
     locked: bool,
     dependency_type: &DependencyType,
     raw_sources: bool,
+    bound: AddBound,
     settings: ResolverInstallerSettingsRef<'_>,
     bounds: LowerBound,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -732,10 +806,12 @@ async fn lock_and_sync(
         project.workspace(),
         settings.into(),
         bounds,
+        false,
         &state,
         Box::new(DefaultResolveLogger),
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
@@ -764,15 +840,30 @@ async fn lock_and_sync(
             }
         }
 
-        // If any of the requirements were added without version specifiers, add a lower bound.
+        // If any of the requirements were added without version specifiers, add a bound.
         let mut modified = false;
         for edit in edits {
-            // Only set a minimum version for newly-added dependencies (as opposed to updates).
-            let ArrayEdit::Add(index) = &edit.edit else {
-                continue;
+            // Only set a bound for newly-added dependencies (as opposed to updates), unless the
+            // dependency was upgraded, in which case its existing bound is rewritten.
+            let index = match &edit.edit {
+                ArrayEdit::Add(index) => index,
+                ArrayEdit::Update(index) if settings.upgrade.contains(&edit.requirement.name) => {
+                    // Only rewrite the bound if the dependency is locked from a registry.
+                    let root = project.workspace().install_path();
+                    if !lock
+                        .packages()
+                        .iter()
+                        .filter(|dist| dist.name() == &edit.requirement.name)
+                        .all(|dist| dist.index(root).is_ok_and(|index| index.is_some()))
+                    {
+                        continue;
+                    }
+                    index
+                }
+                ArrayEdit::Update(_) => continue,
             };
 
-            // Only set a minimum version for registry requirements.
+            // Only set a bound for registry requirements.
             if edit
                 .source
                 .as_ref()
@@ -781,7 +872,7 @@ async fn lock_and_sync(
                 continue;
             }
 
-            // Only set a minimum version for registry requirements.
+            // Only set a bound for requirements without a version specifier.
             let is_empty = match edit.requirement.version_or_url.as_ref() {
                 Some(VersionOrUrl::VersionSpecifier(version)) => version.is_empty(),
                 Some(VersionOrUrl::Url(_)) => false,
@@ -791,7 +882,7 @@ async fn lock_and_sync(
                 continue;
             }
 
-            // Set the minimum version.
+            // Set the bound, based on the minimum locked version.
             let Some(minimum) = minimum_version.get(&edit.requirement.name) else {
                 continue;
             };
@@ -799,19 +890,20 @@ async fn lock_and_sync(
             // Drop the local version identifier, which isn't permitted in `>=` constraints.
             // For example, convert `1.2.3+local` to `1.2.3`.
             let minimum = (*minimum).clone().without_local();
+            let specifiers = bound.specifiers(&minimum);
 
             match edit.dependency_type {
                 DependencyType::Production => {
-                    toml.set_dependency_minimum_version(*index, minimum)?;
+                    toml.set_dependency_bound(*index, specifiers)?;
                 }
                 DependencyType::Dev => {
-                    toml.set_dev_dependency_minimum_version(*index, minimum)?;
+                    toml.set_dev_dependency_bound(*index, specifiers)?;
                 }
                 DependencyType::Optional(ref extra) => {
-                    toml.set_optional_dependency_minimum_version(extra, *index, minimum)?;
+                    toml.set_optional_dependency_bound(extra, *index, specifiers)?;
                 }
                 DependencyType::Group(ref group) => {
-                    toml.set_dependency_group_requirement_minimum_version(group, *index, minimum)?;
+                    toml.set_dependency_group_requirement_bound(group, *index, specifiers)?;
                 }
             }
 
@@ -820,7 +912,7 @@ async fn lock_and_sync(
 
         // Save the modified `pyproject.toml`. No need to check for changes in the underlying
         // string content, since the above loop _must_ change an empty specifier to a non-empty
-        // specifier.
+        // specifier (or, at worst, rewrite an upgraded dependency's bound as-is).
         if modified {
             let content = toml.to_string();
 
@@ -850,10 +942,12 @@ async fn lock_and_sync(
                 project.workspace(),
                 settings.into(),
                 bounds,
+                false,
                 &state,
                 Box::new(SummaryResolveLogger),
                 connectivity,
                 concurrency,
+                tag_preferences,
                 native_tls,
                 allow_insecure_host,
                 cache,
@@ -915,10 +1009,14 @@ async fn lock_and_sync(
         EditableMode::Editable,
         InstallOptions::default(),
         Modifications::Sufficient,
+        DryRun::Disabled,
+        false,
+        None,
         settings.into(),
         Box::new(DefaultInstallLogger),
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
@@ -962,6 +1060,7 @@ fn augment_requirement(
                 source: match requirement.source {
                     RequirementSource::Git {
                         repository,
+                        backend,
                         reference,
                         precise,
                         subdirectory,
@@ -978,6 +1077,7 @@ fn augment_requirement(
                         };
                         RequirementSource::Git {
                             repository,
+                            backend,
                             reference,
                             precise,
                             subdirectory,
@@ -1057,6 +1157,119 @@ fn resolve_requirement(
     Ok((processed_requirement, source))
 }
 
+/// Scan the project's Python sources for third-party imports, and return the packages that
+/// provide them, omitting any that are already declared.
+///
+/// If the terminal is interactive, the user is prompted to confirm the proposed packages; if they
+/// decline, no packages are returned.
+fn imported_packages(project: &VirtualProject, printer: Printer) -> Result<Vec<PackageName>> {
+    let mut imports = ThirdPartyImports::scan(project.root()).with_context(|| {
+        format!(
+            "Failed to scan Python sources in: `{}`",
+            project.root().user_display()
+        )
+    })?;
+
+    // Omit the packages that the project already declares, along with the workspace members.
+    let pyproject_toml = project.pyproject_toml();
+    let mut declared = pyproject_toml
+        .project
+        .iter()
+        .flat_map(|project| {
+            project.dependencies.iter().flatten().chain(
+                project
+                    .optional_dependencies
+                    .iter()
+                    .flat_map(|extras| extras.values().flatten()),
+            )
+        })
+        .chain(
+            pyproject_toml
+                .dependency_groups
+                .iter()
+                .flat_map(|groups| groups.into_iter().flat_map(|(_, group)| group))
+                .filter_map(|specifier| match specifier {
+                    DependencyGroupSpecifier::Requirement(requirement) => Some(requirement),
+                    _ => None,
+                }),
+        )
+        .filter_map(|requirement| {
+            Requirement::<VerbatimParsedUrl>::from_str(requirement)
+                .map(|requirement| requirement.name)
+                .ok()
+        })
+        .collect::<FxHashSet<_>>();
+    declared.extend(
+        pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.dev_dependencies.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|requirement| requirement.name.clone()),
+    );
+    declared.extend(project.workspace().packages().keys().cloned());
+    imports.retain(|package| !declared.contains(package));
+
+    for (module, path) in imports.unresolved() {
+        warn_user!(
+            "Unable to determine the package that provides `{module}` (imported in `{}`)",
+            path.user_display()
+        );
+    }
+
+    let packages = imports
+        .packages()
+        .map(|(package, ..)| package.clone())
+        .collect::<Vec<_>>();
+    if packages.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No undeclared third-party imports found in: `{}`",
+            project.root().user_display().cyan()
+        )?;
+        return Ok(packages);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Found {} undeclared third-party {}:",
+        packages.len(),
+        if packages.len() == 1 {
+            "import"
+        } else {
+            "imports"
+        }
+    )?;
+    for (package, module, path) in imports.packages() {
+        writeln!(
+            printer.stderr(),
+            " {} {} {}",
+            package.bold(),
+            format!("(`{module}`)").dimmed(),
+            format!("in `{}`", path.user_display()).dimmed()
+        )?;
+    }
+
+    let term = Term::stderr();
+    if term.is_term() {
+        let prompt = format!(
+            "Add {} to the project?",
+            if packages.len() == 1 {
+                "this package"
+            } else {
+                "these packages"
+            }
+        );
+        if !uv_console::confirm(&prompt, &term, true)? {
+            return Ok(Vec::new());
+        }
+    }
+
+    Ok(packages)
+}
+
 /// Represents the destination where dependencies are added, either to a project or a script.
 #[derive(Debug)]
 enum Target {
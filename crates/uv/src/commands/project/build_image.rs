@@ -0,0 +1,126 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, PreviewMode, TrustedHost};
+use uv_fs::Simplified;
+use uv_platform_tags::TagPreferences;
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_warnings::warn_user_once;
+use uv_workspace::{DiscoveryOptions, MemberDiscovery, Workspace};
+
+use crate::commands::project::export::export;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ExportSettings;
+
+/// Build a container image for the project from its `uv.lock`.
+pub(crate) async fn build_image(
+    project_dir: &Path,
+    tag: Option<String>,
+    platforms: Vec<String>,
+    settings: ExportSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv build-image` is experimental and may change without warning. Pass `--preview` to disable this warning.");
+    }
+
+    // The workspace root is used as the build context.
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions {
+            members: MemberDiscovery::None,
+            ..DiscoveryOptions::default()
+        },
+    )
+    .await?;
+
+    // Generate the `Dockerfile`.
+    let dockerfile = tempfile::NamedTempFile::with_prefix_in("Dockerfile.", cache.root())?;
+    let status = export(
+        project_dir,
+        settings.format,
+        settings.all_packages,
+        settings.package,
+        settings.hashes,
+        settings.annotate,
+        settings.install_options,
+        Some(dockerfile.path().to_path_buf()),
+        &settings.distro_packages,
+        settings.environment.as_deref(),
+        settings.python_platform,
+        settings.pyodide_index_url.as_ref(),
+        settings.extras,
+        settings.dev,
+        settings.editable,
+        settings.locked,
+        settings.frozen,
+        settings.include_header,
+        settings.python,
+        settings.install_mirrors,
+        settings.settings,
+        python_preference,
+        python_downloads,
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        no_config,
+        true,
+        cache,
+        printer,
+    )
+    .await?;
+    if !matches!(status, ExitStatus::Success) {
+        return Ok(status);
+    }
+
+    // Build the image. Multi-platform builds require `buildx`.
+    let mut command = std::process::Command::new("docker");
+    if platforms.is_empty() {
+        command.arg("build");
+    } else {
+        command.arg("buildx").arg("build");
+        command.arg("--platform").arg(platforms.join(","));
+    }
+    command.arg("--file").arg(dockerfile.path());
+    if let Some(tag) = tag.as_ref() {
+        command.arg("--tag").arg(tag);
+    }
+    command.arg(workspace.install_path());
+
+    debug!("Running: {command:?}");
+    let status = command
+        .status()
+        .context("Failed to run `docker`; is Docker installed and on the `PATH`?")?;
+    if !status.success() {
+        return Ok(ExitStatus::Failure);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Built image{} from {}",
+        tag.map(|tag| format!(" {}", tag.cyan()))
+            .unwrap_or_default(),
+        workspace.install_path().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,358 @@
+use std::fmt::Write;
+use std::hash::Hasher;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use walkdir::WalkDir;
+
+use uv_cache::Cache;
+use uv_cache_key::CacheKeyHasher;
+use uv_cli::BundleFormat;
+use uv_client::Connectivity;
+use uv_configuration::{
+    Concurrency, DevGroupsSpecification, EditableMode, ExtrasSpecification, InstallOptions,
+    LowerBound, PreviewMode, TargetTriple, TrustedHost,
+};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_platform_tags::TagPreferences;
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest, Target};
+use uv_resolver::InstallTarget;
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user_once;
+use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace};
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::pip::operations::{DryRun, Modifications};
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::sync::do_sync;
+use crate::commands::project::{DependencyGroupsTarget, ProjectError, ProjectInterpreter};
+use crate::commands::{diagnostics, ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// The bootstrap script that serves as the entrypoint of every bundle.
+const BOOTSTRAP: &str = include_str!("bundle/__main__.py");
+
+/// Bundle the project and its locked dependencies into a self-contained executable.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn bundle(
+    project_dir: &Path,
+    format: BundleFormat,
+    output: Option<PathBuf>,
+    entry_point: Option<String>,
+    package: Option<PackageName>,
+    extras: ExtrasSpecification,
+    dev: DevGroupsSpecification,
+    locked: bool,
+    frozen: bool,
+    python: Option<String>,
+    python_platform: Option<TargetTriple>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv bundle` is experimental and may change without warning. Pass `--preview` to disable this warning.");
+    }
+
+    // Identify the project.
+    let project = if frozen {
+        VirtualProject::discover(
+            project_dir,
+            &DiscoveryOptions {
+                members: MemberDiscovery::None,
+                ..DiscoveryOptions::default()
+            },
+        )
+        .await?
+    } else if let Some(package) = package.as_ref() {
+        VirtualProject::Project(
+            Workspace::discover(project_dir, &DiscoveryOptions::default())
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await?
+    };
+
+    let VirtualProject::Project(project) = &project else {
+        return Err(anyhow::anyhow!("Legacy non-project roots are not supported in `uv bundle`; add a `[project]` table to your `pyproject.toml` to enable bundling"));
+    };
+
+    // Validate that any referenced dependency groups are defined in the workspace.
+    if !frozen {
+        DependencyGroupsTarget::Project(project).validate(&dev)?;
+    }
+
+    // The bundle is built for the project's interpreter, but never installs into its environment.
+    let interpreter = ProjectInterpreter::discover(
+        project.workspace(),
+        project_dir,
+        python.as_deref().map(PythonRequest::parse),
+        python_preference,
+        python_downloads,
+        connectivity,
+        native_tls,
+        allow_insecure_host,
+        install_mirrors,
+        no_config,
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    // Determine the lock mode.
+    let mode = if frozen {
+        LockMode::Frozen
+    } else if locked {
+        LockMode::Locked(&interpreter)
+    } else {
+        LockMode::Write(&interpreter)
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Lock the project.
+    let lock = match do_safe_lock(
+        mode,
+        project.workspace(),
+        settings.as_ref().into(),
+        LowerBound::Warn,
+        false,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::default()
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let name = package.as_ref().unwrap_or(project.project_name());
+    let target = InstallTarget::Project {
+        workspace: project.workspace(),
+        name,
+        lock: &lock,
+    };
+
+    let output = output.unwrap_or_else(|| {
+        let dist = project.workspace().install_path().join("dist");
+        match format {
+            BundleFormat::Zipapp => dist.join(format!("{name}.pyz")),
+            BundleFormat::Directory => dist.join(name.to_string()),
+        }
+    });
+
+    // Install into a staging directory for zip applications, or directly into the bundle
+    // otherwise.
+    let staging = cache.venv_dir()?;
+    let site_packages = match format {
+        BundleFormat::Zipapp => staging.path().join("site-packages"),
+        BundleFormat::Directory => {
+            if output.exists() && !output.join("__main__.py").is_file() {
+                return Err(anyhow::anyhow!(
+                    "The output directory `{}` already exists and is not a bundle",
+                    output.user_display()
+                ));
+            }
+            if output.join("site-packages").exists() {
+                fs_err::remove_dir_all(output.join("site-packages"))?;
+            }
+            output.join("site-packages")
+        }
+    };
+    let environment = PythonEnvironment::from_interpreter(interpreter)
+        .with_target(Target::from(site_packages))?;
+
+    // Development dependencies are only included when requested, and the project is always
+    // installed as non-editable, such that the bundle doesn't depend on the source tree.
+    match do_sync(
+        target,
+        &environment,
+        &extras,
+        &dev.with_defaults(Vec::new()),
+        EditableMode::NonEditable,
+        InstallOptions::default(),
+        Modifications::Exact,
+        DryRun::Disabled,
+        false,
+        python_platform.as_ref(),
+        settings.as_ref().into(),
+        Box::new(DefaultInstallLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(()) => {}
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::default()
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    let interpreter = environment.interpreter();
+    match format {
+        BundleFormat::Zipapp => {
+            let shebang = format!(
+                "#!/usr/bin/env python{}.{}",
+                interpreter.python_major(),
+                interpreter.python_minor()
+            );
+            write_zipapp(
+                &output,
+                &shebang,
+                staging.path(),
+                entry_point.as_deref(),
+                name,
+            )?;
+        }
+        BundleFormat::Directory => {
+            let id = bundle_id(&output.join("site-packages"))?;
+            fs_err::write(
+                output.join("__main__.py"),
+                bootstrap(&id, entry_point.as_deref(), name),
+            )?;
+        }
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Bundled {} for Python {}.{} to: {}",
+        name.cyan(),
+        interpreter.python_major(),
+        interpreter.python_minor(),
+        output.user_display().bold()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Render the bootstrap script for a bundle.
+fn bootstrap(id: &str, entry_point: Option<&str>, project: &PackageName) -> String {
+    BOOTSTRAP
+        .replace("{{ BUNDLE_ID }}", id)
+        .replace("{{ ENTRY_POINT }}", entry_point.unwrap_or_default())
+        .replace("{{ PROJECT }}", project.as_ref())
+}
+
+/// Return the files in the given directory, sorted by their path relative to the root.
+pub(crate) fn walk(root: &Path) -> Result<Vec<(PathBuf, String)>> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(root).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(root)
+            .expect("walked path is within the root");
+        // Bytecode is specific to the interpreter that runs the bundle.
+        if relative.extension().is_some_and(|ext| ext == "pyc") {
+            continue;
+        }
+        let name = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        files.push((entry.path().to_path_buf(), name));
+    }
+    Ok(files)
+}
+
+/// Compute a stable identifier for the contents of a bundle, such that each version of a bundle
+/// is extracted separately.
+fn bundle_id(site_packages: &Path) -> Result<String> {
+    let mut hasher = CacheKeyHasher::new();
+    for (path, name) in walk(site_packages)? {
+        hasher.write(name.as_bytes());
+        hasher.write(&fs_err::read(path)?);
+    }
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+/// Write a zip application containing the given `site-packages` directory.
+fn write_zipapp(
+    output: &Path,
+    shebang: &str,
+    staging: &Path,
+    entry_point: Option<&str>,
+    project: &PackageName,
+) -> Result<()> {
+    if let Some(parent) = output.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+
+    let mut file = fs_err::File::create(output)?;
+    writeln!(file, "{shebang}")?;
+
+    let mut writer = zip::ZipWriter::new(file);
+    let mut hasher = CacheKeyHasher::new();
+    for (path, name) in walk(staging)? {
+        let contents = fs_err::read(&path)?;
+        hasher.write(name.as_bytes());
+        hasher.write(&contents);
+
+        writer.start_file(
+            name,
+            zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated),
+        )?;
+        writer.write_all(&contents)?;
+    }
+
+    // Write the bootstrap script last, since it includes the digest of the bundle's contents.
+    writer.start_file(
+        "__main__.py",
+        zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated),
+    )?;
+    writer.write_all(
+        bootstrap(&format!("{:016x}", hasher.finish()), entry_point, project).as_bytes(),
+    )?;
+    writer.finish()?;
+
+    // Mark the bundle as executable.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs_err::set_permissions(output, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+"""The entrypoint of a bundle created by `uv bundle`."""
+
+import os
+import site
+import sys
+
+BUNDLE_ID = "{{ BUNDLE_ID }}"
+ENTRY_POINT = "{{ ENTRY_POINT }}"
+PROJECT = "{{ PROJECT }}"
+
+
+def cache_dir():
+    """Return the directory to which zip application bundles are extracted."""
+    if os.environ.get("UV_BUNDLE_CACHE_DIR"):
+        return os.environ["UV_BUNDLE_CACHE_DIR"]
+    if os.name == "nt" and os.environ.get("LOCALAPPDATA"):
+        return os.path.join(os.environ["LOCALAPPDATA"], "uv", "bundles")
+    base = os.environ.get("XDG_CACHE_HOME") or os.path.join(
+        os.path.expanduser("~"), ".cache"
+    )
+    return os.path.join(base, "uv", "bundles")
+
+
+def site_packages():
+    """Return the `site-packages` directory of the bundle."""
+    root = os.path.dirname(os.path.abspath(__file__))
+    if os.path.isdir(root):
+        return os.path.join(root, "site-packages")
+
+    # Extension modules can't be imported from a zip archive, so extract the dependencies on
+    # first run.
+    target = os.path.join(cache_dir(), BUNDLE_ID)
+    if not os.path.isdir(target):
+        import shutil
+        import tempfile
+        import zipfile
+
+        os.makedirs(os.path.dirname(target), exist_ok=True)
+        staging = tempfile.mkdtemp(dir=os.path.dirname(target))
+        with zipfile.ZipFile(root) as archive:
+            members = [
+                name for name in archive.namelist() if name.startswith("site-packages/")
+            ]
+            archive.extractall(staging, members)
+        try:
+            os.rename(staging, target)
+        except OSError:
+            # The bundle was extracted by a concurrent invocation.
+            shutil.rmtree(staging, ignore_errors=True)
+    return os.path.join(target, "site-packages")
+
+
+def console_scripts():
+    from importlib.metadata import entry_points
+
+    try:
+        return list(entry_points(group="console_scripts"))
+    except TypeError:
+        # Python 3.8 and 3.9 return a dictionary of groups.
+        return list(entry_points().get("console_scripts", []))
+
+
+def normalize(name):
+    return name.lower().replace("_", "-").replace(".", "-")
+
+
+def resolve(entry_point):
+    """Resolve an entry point to a callable."""
+    if ":" in entry_point:
+        import importlib
+
+        module, _, attrs = entry_point.partition(":")
+        obj = importlib.import_module(module.strip())
+        for attr in attrs.strip().split("."):
+            obj = getattr(obj, attr)
+        return obj
+    for script in console_scripts():
+        if script.name == entry_point:
+            return script.load()
+    sys.exit(f"error: No console script named `{entry_point}` in the bundle")
+
+
+def main():
+    path = site_packages()
+    sys.path.insert(0, path)
+    site.addsitedir(path)
+
+    if ENTRY_POINT:
+        sys.exit(resolve(ENTRY_POINT)())
+
+    # Default to the project's console script, if it defines exactly one.
+    scripts = [
+        script
+        for script in console_scripts()
+        if getattr(script, "dist", None) is not None
+        and normalize(script.dist.name) == PROJECT
+    ]
+    if len(scripts) == 1:
+        sys.exit(scripts[0].load()())
+
+    # Otherwise, behave like the interpreter.
+    if len(sys.argv) > 1:
+        import runpy
+
+        sys.argv = sys.argv[1:]
+        if sys.argv[0] == "-m" and len(sys.argv) > 1:
+            sys.argv = sys.argv[1:]
+            runpy.run_module(sys.argv[0], run_name="__main__", alter_sys=True)
+        else:
+            runpy.run_path(sys.argv[0], run_name="__main__")
+    else:
+        import code
+
+        code.interact()
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,243 @@
+use std::ffi::OsString;
+use std::fmt::Write;
+use std::io::IsTerminal;
+use std::path::{Component, Path};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::cache_digest;
+use uv_configuration::PreviewMode;
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+use uv_workspace::{DiscoveryOptions, MemberDiscovery, Workspace};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The path at which the workspace is mounted in the container.
+const PROJECT_MOUNT: &str = "/project";
+
+/// The path at which the per-image state (cache, environments, Python installations, and `uv`
+/// itself) is mounted in the container.
+const STATE_MOUNT: &str = "/uv";
+
+/// The entrypoint of the container, which installs `uv` (if necessary) and invokes it with the
+/// forwarded arguments.
+const ENTRYPOINT: &str = r#"set -e
+if ! command -v uv >/dev/null 2>&1; then
+  if [ ! -x /uv/bin/uv ]; then
+    echo "Installing uv $UV_CONTAINER_VERSION in the container" >&2
+    if command -v curl >/dev/null 2>&1; then
+      curl -LsSf "https://astral.sh/uv/$UV_CONTAINER_VERSION/install.sh" | UV_INSTALL_DIR=/uv/bin INSTALLER_NO_MODIFY_PATH=1 sh >&2
+    elif command -v wget >/dev/null 2>&1; then
+      wget -qO- "https://astral.sh/uv/$UV_CONTAINER_VERSION/install.sh" | UV_INSTALL_DIR=/uv/bin INSTALLER_NO_MODIFY_PATH=1 sh >&2
+    else
+      echo "error: The container image must provide \`uv\`, \`curl\`, or \`wget\`" >&2
+      exit 2
+    fi
+  fi
+  PATH="/uv/bin:$PATH"
+fi
+exec uv "$@"
+"#;
+
+/// Options that are consumed by the host invocation and must not be forwarded to the container,
+/// along with whether they take a value.
+const HOST_OPTIONS: &[(&str, bool)] = &[
+    ("--container", true),
+    ("--isolated", false),
+    ("--directory", true),
+    ("--project", true),
+    ("--cache-dir", true),
+];
+
+/// Run a command in a container, using the given OCI image.
+///
+/// The workspace is mounted into the container, and `uv run` is invoked within the container
+/// with the same arguments as the current invocation. The virtual environment, cache, and Python
+/// installations used within the container are persisted in the uv cache, per image, such that
+/// subsequent runs only need to reconcile the environment with the lockfile.
+pub(crate) async fn run_in_container(
+    project_dir: &Path,
+    image: &str,
+    trailing: usize,
+    locked: bool,
+    cache: &Cache,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv run --container` is experimental and may change without warning. Pass `--preview` to disable this warning.");
+    }
+
+    // The workspace root is mounted into the container, such that all members are available.
+    let root = match Workspace::discover(
+        project_dir,
+        &DiscoveryOptions {
+            members: MemberDiscovery::None,
+            ..DiscoveryOptions::default()
+        },
+    )
+    .await
+    {
+        Ok(workspace) => workspace.install_path().clone(),
+        Err(err) => {
+            debug!(
+                "No workspace found ({err}); mounting: {}",
+                project_dir.user_display()
+            );
+            std::path::absolute(project_dir)?
+        }
+    };
+
+    // Run in the same directory, relative to the workspace root, as on the host.
+    let workdir = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| container_path(&root, &cwd))
+        .unwrap_or_else(|| PROJECT_MOUNT.to_string());
+
+    // Persist the state per image, and the environment per image and workspace.
+    let state = cache
+        .bucket(CacheBucket::Containers)
+        .join(cache_digest(image));
+    fs_err::create_dir_all(&state)?;
+    let environment = format!("{STATE_MOUNT}/environments/{}", cache_digest(&root));
+
+    // Forward the arguments of the current invocation to `uv` in the container.
+    let mut args = forwarded_args(&std::env::args_os().skip(1).collect::<Vec<_>>(), trailing);
+    if !locked && root.join("uv.lock").is_file() {
+        // Ensure that the environment matches the lockfile, without modifying it from within the
+        // container.
+        if let Some(position) = args.iter().position(|arg| arg == "run") {
+            args.insert(position + 1, OsString::from("--locked"));
+        }
+    }
+
+    let runtime =
+        std::env::var_os(EnvVars::UV_CONTAINER_RUNTIME).unwrap_or_else(|| OsString::from("docker"));
+
+    let mut command = std::process::Command::new(&runtime);
+    command.arg("run").arg("--rm").arg("--interactive");
+    if std::io::stdin().is_terminal() && std::io::stdout().is_terminal() {
+        command.arg("--tty");
+    }
+    command
+        .arg("--volume")
+        .arg(mount(&root, PROJECT_MOUNT))
+        .arg("--volume")
+        .arg(mount(&state, STATE_MOUNT))
+        .arg("--workdir")
+        .arg(&workdir);
+
+    // On Linux, Docker runs as root by default, which would leave root-owned files in the
+    // project; instead, run as the owner of the workspace.
+    #[cfg(target_os = "linux")]
+    if Path::new(&runtime).file_name() == Some(std::ffi::OsStr::new("docker")) {
+        use std::os::unix::fs::MetadataExt;
+        let metadata = fs_err::metadata(&root)?;
+        command
+            .arg("--user")
+            .arg(format!("{}:{}", metadata.uid(), metadata.gid()));
+    }
+
+    for (key, value) in [
+        (EnvVars::UV_CACHE_DIR, format!("{STATE_MOUNT}/cache")),
+        (
+            EnvVars::UV_PYTHON_INSTALL_DIR,
+            format!("{STATE_MOUNT}/python"),
+        ),
+        (EnvVars::UV_PROJECT_ENVIRONMENT, environment),
+        (EnvVars::HOME, format!("{STATE_MOUNT}/home")),
+        ("UV_CONTAINER_VERSION", uv_version::version().to_string()),
+    ] {
+        command.arg("--env").arg(format!("{key}={value}"));
+    }
+
+    command
+        .arg("--entrypoint")
+        .arg("sh")
+        .arg(image)
+        .arg("-c")
+        .arg(ENTRYPOINT)
+        .arg("uv")
+        .args(&args);
+
+    writeln!(
+        printer.stderr(),
+        "Running in container {} with {}",
+        image.cyan(),
+        runtime.to_string_lossy().cyan()
+    )?;
+
+    debug!("Running: {command:?}");
+    let status = command.status().with_context(|| {
+        format!(
+            "Failed to run `{}`; is it installed and on the `PATH`? Use `{}` to select a different container runtime",
+            runtime.to_string_lossy(),
+            EnvVars::UV_CONTAINER_RUNTIME
+        )
+    })?;
+
+    match status.code() {
+        Some(0) => Ok(ExitStatus::Success),
+        Some(code) => Ok(u8::try_from(code).map_or(ExitStatus::Failure, ExitStatus::External)),
+        None => Ok(ExitStatus::Failure),
+    }
+}
+
+/// Return the arguments to forward to `uv` in the container, omitting those that only apply to
+/// the host.
+///
+/// The last `trailing` arguments are the command to run, and are forwarded verbatim.
+fn forwarded_args(args: &[OsString], trailing: usize) -> Vec<OsString> {
+    let split = args.len().saturating_sub(trailing);
+    let (options, command) = args.split_at(split);
+
+    let mut forwarded = Vec::with_capacity(args.len());
+    let mut options = options.iter();
+    while let Some(arg) = options.next() {
+        let Some(text) = arg.to_str() else {
+            forwarded.push(arg.clone());
+            continue;
+        };
+        if let Some((_, takes_value)) = HOST_OPTIONS.iter().find(|(option, takes_value)| {
+            text == *option || (*takes_value && text.starts_with(&format!("{option}=")))
+        }) {
+            if *takes_value && !text.contains('=') {
+                // Skip the value.
+                options.next();
+            }
+            continue;
+        }
+        forwarded.push(arg.clone());
+    }
+    forwarded.extend(command.iter().cloned());
+    forwarded
+}
+
+/// Return the path in the container that corresponds to the given path on the host, if it's
+/// within the workspace root.
+fn container_path(root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+    let mut container = PROJECT_MOUNT.to_string();
+    for component in relative.components() {
+        let Component::Normal(component) = component else {
+            return None;
+        };
+        container.push('/');
+        container.push_str(component.to_str()?);
+    }
+    Some(container)
+}
+
+/// Format a bind mount of a host path into the container.
+fn mount(source: &Path, target: &str) -> OsString {
+    let mut mount = OsString::from(source.as_os_str());
+    mount.push(":");
+    mount.push(target);
+    mount
+}
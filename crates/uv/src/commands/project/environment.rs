@@ -12,6 +12,7 @@ use uv_cache_key::{cache_digest, hash_digest};
 use uv_client::Connectivity;
 use uv_configuration::{Concurrency, TrustedHost};
 use uv_distribution_types::Resolution;
+use uv_platform_tags::TagPreferences;
 use uv_python::{Interpreter, PythonEnvironment};
 
 /// A [`PythonEnvironment`] stored in the cache.
@@ -36,26 +37,13 @@ impl CachedEnvironment {
         install: Box<dyn InstallLogger>,
         connectivity: Connectivity,
         concurrency: Concurrency,
+        tag_preferences: &TagPreferences,
         native_tls: bool,
         allow_insecure_host: &[TrustedHost],
         cache: &Cache,
         printer: Printer,
     ) -> Result<Self, ProjectError> {
-        // When caching, always use the base interpreter, rather than that of the virtual
-        // environment.
-        let interpreter = if let Some(interpreter) = interpreter.to_base_interpreter(cache)? {
-            debug!(
-                "Caching via base interpreter: `{}`",
-                interpreter.sys_executable().display()
-            );
-            interpreter
-        } else {
-            debug!(
-                "Caching via interpreter: `{}`",
-                interpreter.sys_executable().display()
-            );
-            interpreter
-        };
+        let interpreter = base_interpreter(interpreter, cache)?;
 
         // Resolve the requirements with the interpreter.
         let graph = resolve_environment(
@@ -66,6 +54,7 @@ impl CachedEnvironment {
             resolve,
             connectivity,
             concurrency,
+            tag_preferences,
             native_tls,
             allow_insecure_host,
             cache,
@@ -74,6 +63,41 @@ impl CachedEnvironment {
         .await?;
         let resolution = Resolution::from(graph);
 
+        Self::from_resolution(
+            &resolution,
+            interpreter,
+            settings,
+            state,
+            install,
+            connectivity,
+            concurrency,
+            tag_preferences,
+            native_tls,
+            allow_insecure_host,
+            cache,
+            printer,
+        )
+        .await
+    }
+
+    /// Get or create a [`CachedEnvironment`] for a given [`Resolution`] (e.g., as read from a
+    /// lockfile) and a base interpreter.
+    pub(crate) async fn from_resolution(
+        resolution: &Resolution,
+        interpreter: Interpreter,
+        settings: &ResolverInstallerSettings,
+        state: &SharedState,
+        install: Box<dyn InstallLogger>,
+        connectivity: Connectivity,
+        concurrency: Concurrency,
+        tag_preferences: &TagPreferences,
+        native_tls: bool,
+        allow_insecure_host: &[TrustedHost],
+        cache: &Cache,
+        printer: Printer,
+    ) -> Result<Self, ProjectError> {
+        let interpreter = base_interpreter(interpreter, cache)?;
+
         // Hash the resolution by hashing the generated lockfile.
         // TODO(charlie): If the resolution contains any mutable metadata (like a path or URL
         // dependency), skip this step.
@@ -111,12 +135,13 @@ impl CachedEnvironment {
 
         sync_environment(
             venv,
-            &resolution,
+            resolution,
             settings.as_ref().into(),
             state,
             install,
             connectivity,
             concurrency,
+            tag_preferences,
             native_tls,
             allow_insecure_host,
             cache,
@@ -138,3 +163,15 @@ impl CachedEnvironment {
         self.0.into_interpreter()
     }
 }
+
+/// When caching, always use the base interpreter, rather than that of the virtual environment.
+fn base_interpreter(interpreter: Interpreter, cache: &Cache) -> Result<Interpreter, ProjectError> {
+    let Some(base_interpreter) = interpreter.to_base_interpreter(cache)? else {
+        return Ok(interpreter);
+    };
+    debug!(
+        "Caching via base interpreter: `{}`",
+        base_interpreter.sys_executable().display()
+    );
+    Ok(base_interpreter)
+}
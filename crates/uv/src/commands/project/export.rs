@@ -9,18 +9,24 @@ use uv_settings::PythonInstallMirrors;
 use uv_cache::Cache;
 use uv_client::Connectivity;
 use uv_configuration::{
-    Concurrency, DevGroupsSpecification, EditableMode, ExportFormat, ExtrasSpecification,
-    InstallOptions, LowerBound, TrustedHost,
+    Concurrency, DevGroupsSpecification, DistroPackages, EditableMode, ExportFormat,
+    ExtrasSpecification, InstallOptions, LowerBound, TargetTriple, TrustedHost,
 };
+use uv_distribution_types::{IndexUrl, Verbatim};
 use uv_normalize::PackageName;
+use uv_platform_tags::TagPreferences;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
-use uv_resolver::{InstallTarget, RequirementsTxtExport};
+use uv_resolver::{
+    BazelExport, DebControlExport, DockerfileExport, InstallTarget, NixExport,
+    RequirementsInExport, RequirementsTxtExport, RpmSpecExport,
+};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace};
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::project::lock::{do_safe_lock, LockMode};
 use crate::commands::project::{
-    default_dependency_groups, DependencyGroupsTarget, ProjectError, ProjectInterpreter,
+    default_dependency_groups, named_environment, DependencyGroupsTarget, ProjectError,
+    ProjectInterpreter,
 };
 use crate::commands::{diagnostics, ExitStatus, OutputWriter, SharedState};
 use crate::printer::Printer;
@@ -34,8 +40,13 @@ pub(crate) async fn export(
     all_packages: bool,
     package: Option<PackageName>,
     hashes: bool,
+    annotate: bool,
     install_options: InstallOptions,
     output_file: Option<PathBuf>,
+    distro_packages: &DistroPackages,
+    environment: Option<&str>,
+    python_platform: Option<TargetTriple>,
+    pyodide_index_url: Option<&IndexUrl>,
     extras: ExtrasSpecification,
     dev: DevGroupsSpecification,
     editable: EditableMode,
@@ -49,6 +60,7 @@ pub(crate) async fn export(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
@@ -56,6 +68,34 @@ pub(crate) async fn export(
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
+    // Distribution packages are generated for a single project.
+    if all_packages && matches!(format, ExportFormat::RpmSpec | ExportFormat::DebControl) {
+        return Err(anyhow::anyhow!(
+            "The `rpm-spec` and `deb-control` formats describe a single project, and can't be used with `--all-packages`"
+        ));
+    }
+
+    // Environments are only respected by the `requirements-txt` format.
+    if environment.is_some() && !matches!(format, ExportFormat::RequirementsTxt) {
+        return Err(anyhow::anyhow!(
+            "The `--environment` option is only supported with the `requirements-txt` format"
+        ));
+    }
+
+    // Target platforms are only respected by the `requirements-txt` format.
+    if python_platform.is_some() && !matches!(format, ExportFormat::RequirementsTxt) {
+        return Err(anyhow::anyhow!(
+            "The `--python-platform` option is only supported with the `requirements-txt` format"
+        ));
+    }
+
+    // Annotations are only supported by the `requirements-txt` format.
+    if annotate && !matches!(format, ExportFormat::RequirementsTxt) {
+        return Err(anyhow::anyhow!(
+            "The `--annotate` option is only supported with the `requirements-txt` format"
+        ));
+    }
+
     // Identify the project.
     let project = if frozen {
         VirtualProject::discover(
@@ -91,6 +131,21 @@ pub(crate) async fn export(
         target.validate(&dev)?;
     }
 
+    // Resolve the named environment or target platform, if any.
+    let environment = if let Some(python_platform) = python_platform {
+        Some(python_platform.marker())
+    } else {
+        environment
+            .map(|environment| named_environment(project.workspace(), environment))
+            .transpose()?
+            .cloned()
+    };
+
+    // When targeting Pyodide, point installers at the Pyodide package index, since most packages
+    // with extension modules aren't published to PyPI for Pyodide.
+    let pyodide_index_url =
+        pyodide_index_url.filter(|_| python_platform == Some(TargetTriple::Wasm32Pyodide));
+
     // Determine the default groups to include.
     let defaults = default_dependency_groups(project.current_project().pyproject_toml())?;
 
@@ -133,10 +188,12 @@ pub(crate) async fn export(
         project.workspace(),
         settings.as_ref(),
         LowerBound::Warn,
+        false,
         &state,
         Box::new(DefaultResolveLogger),
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
@@ -184,6 +241,130 @@ pub(crate) async fn export(
                 hashes,
                 &install_options,
             )?;
+            let export = if let Some(environment) = environment.as_ref() {
+                export.with_environment(environment)
+            } else {
+                export
+            };
+            let export = if annotate {
+                export.with_annotations()
+            } else {
+                export
+            };
+
+            if include_header {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# This file was autogenerated by uv via the following command:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+            }
+            if let Some(url) = pyodide_index_url {
+                writeln!(writer, "--extra-index-url {}", url.verbatim())?;
+            }
+            write!(writer, "{export}")?;
+        }
+        ExportFormat::RequirementsIn => {
+            let export = RequirementsInExport::from_lock(
+                target,
+                &extras,
+                &dev.with_defaults(defaults),
+                editable,
+                &install_options,
+            )?;
+
+            if include_header {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# This file was autogenerated by uv via the following command:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+            }
+            write!(writer, "{export}")?;
+        }
+        ExportFormat::Dockerfile => {
+            // The `sync` flags are forwarded as-is, such that `uv sync` applies the default groups
+            // within the image.
+            let export =
+                DockerfileExport::from_lock(target, &extras, &dev, editable, &install_options)?;
+
+            writeln!(writer, "# syntax=docker/dockerfile:1")?;
+            if include_header {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# This file was autogenerated by uv via the following command:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+            }
+            write!(writer, "{export}")?;
+        }
+        ExportFormat::Nix => {
+            let export = NixExport::from_lock(
+                target,
+                &extras,
+                &dev.with_defaults(defaults),
+                &install_options,
+                tag_preferences,
+            )?;
+
+            if include_header {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# This file was autogenerated by uv via the following command:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+            }
+            write!(writer, "{export}")?;
+        }
+        ExportFormat::Bazel => {
+            let export = BazelExport::from_lock(
+                target,
+                &extras,
+                &dev.with_defaults(defaults),
+                &install_options,
+            )?;
+
+            if include_header {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# This file was autogenerated by uv via the following command:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+            }
+            write!(writer, "{export}")?;
+        }
+        ExportFormat::RpmSpec => {
+            let export = RpmSpecExport::from_lock(
+                target,
+                &extras,
+                &dev.with_defaults(defaults),
+                &install_options,
+                distro_packages,
+            )?;
+
+            if include_header {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# This file was autogenerated by uv via the following command:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+            }
+            write!(writer, "{export}")?;
+        }
+        ExportFormat::DebControl => {
+            let export = DebControlExport::from_lock(
+                target,
+                &extras,
+                &dev.with_defaults(defaults),
+                &install_options,
+                distro_packages,
+            )?;
 
             if include_header {
                 writeln!(
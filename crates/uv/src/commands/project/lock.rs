@@ -1,49 +1,73 @@
 #![allow(clippy::single_match_else)]
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
-use std::path::Path;
+use std::future::Future;
+use std::path::{Path, PathBuf};
 
+use futures::{stream, StreamExt};
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
-use tracing::debug;
+use tracing::{debug, instrument};
 
-use uv_cache::Cache;
+use uv_cache::metrics::{self, Phase};
+use uv_cache::{Cache, MemoryCache, Refresh};
+use uv_cache_info::Timestamp;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, ExtrasSpecification, LowerBound, Reinstall, TrustedHost, Upgrade,
+    BuildOptions, Concurrency, Constraints, ExtrasSpecification, LowerBound, Reinstall,
+    TrustedHost, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
-    DependencyMetadata, Index, IndexLocations, NameRequirementSpecification,
+    DependencyMetadata, Index, IndexCapabilities, IndexLocations, NameRequirementSpecification,
     UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_pypi_types::{Requirement, SupportedEnvironments};
+use uv_pep508::MarkerTree;
+use uv_platform_tags::TagPreferences;
+use uv_pypi_types::{
+    ConflictingGroup, ConflictingGroupList, ConflictingGroups, Requirement, RequirementSource,
+    SupportedEnvironments,
+};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::ExtrasResolver;
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, LockVersion, Options, OptionsBuilder, PythonRequirement,
-    RequiresPython, ResolverEnvironment, ResolverManifest, SatisfiesResult, VERSION,
+    DependencyMode, ExtraConflicts, FlatIndex, GroupResolution, InMemoryIndex, IndexPatterns, Lock,
+    LockError, LockVersion, Options, OptionsBuilder, Preference, PythonRequirement, PythonSupport,
+    RequiresPython, ResolutionGraph, ResolverEnvironment, ResolverManifest, SatisfiesResult,
+    SourceBuilds, Variants, Vendored, VendoredPackage, VERSION,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject::MaintenancePolicy;
 use uv_workspace::{DiscoveryOptions, Workspace};
 
+use crate::commands::pip::latest::LatestClient;
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::project::lock_report::LockReport;
+use crate::commands::project::lock_wizard;
+use crate::commands::project::maintenance::MaintenanceChecks;
+use crate::commands::project::provenance::Provenance;
 use crate::commands::project::{
-    find_requires_python, ProjectError, ProjectInterpreter, SharedState,
+    find_requires_python, lock_hasher, named_environment, verify_source_hashes, ProjectError,
+    ProjectInterpreter, SharedState,
 };
 use crate::commands::reporters::ResolverReporter;
 use crate::commands::{diagnostics, pip, ExitStatus};
 use crate::printer::Printer;
 use crate::settings::{ResolverSettings, ResolverSettingsRef};
 
+/// The contents of each lockfile that has been parsed, along with the parsed lockfile, for use by
+/// long-lived processes.
+static LOCKS: MemoryCache<PathBuf, (String, Lock)> = MemoryCache::new(64);
+
 /// The result of running a lock operation.
 #[derive(Debug, Clone)]
 pub(crate) enum LockResult {
@@ -76,6 +100,15 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: bool,
+    minimize: bool,
+    check_requires_python: bool,
+    check_source_builds: bool,
+    check_conflicts: bool,
+    provenance: bool,
+    verify_provenance: bool,
+    report: Option<&Path>,
+    interactive: bool,
+    environment: Option<&str>,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
@@ -83,6 +116,7 @@ pub(crate) async fn lock(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
@@ -90,7 +124,7 @@ pub(crate) async fn lock(
     printer: Printer,
 ) -> anyhow::Result<ExitStatus> {
     // Find the project requirements.
-    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+    let mut workspace = discover_workspace(project_dir, environment).await?;
 
     // Determine the lock mode.
     let interpreter;
@@ -124,26 +158,92 @@ pub(crate) async fn lock(
         }
     };
 
-    // Initialize any shared state.
-    let state = SharedState::default();
+    // Verify the inputs to the existing lockfile against its recorded provenance, without
+    // re-locking.
+    if verify_provenance {
+        if let LockMode::Write(interpreter) = mode {
+            return report_provenance_drift(
+                &workspace,
+                interpreter,
+                settings.as_ref(),
+                connectivity,
+                concurrency,
+                native_tls,
+                allow_insecure_host,
+                cache,
+                printer,
+            )
+            .await;
+        }
+    }
 
-    // Perform the lock operation.
-    match do_safe_lock(
-        mode,
-        &workspace,
-        settings.as_ref(),
-        LowerBound::Warn,
-        &state,
-        Box::new(DefaultResolveLogger),
-        connectivity,
-        concurrency,
-        native_tls,
-        allow_insecure_host,
-        cache,
-        printer,
-    )
-    .await
-    {
+    let result = loop {
+        // Initialize any shared state.
+        let state = SharedState::default();
+
+        // Perform the lock operation.
+        let result = do_safe_lock(
+            mode,
+            &workspace,
+            settings.as_ref(),
+            LowerBound::Warn,
+            minimize,
+            &state,
+            Box::new(DefaultResolveLogger),
+            connectivity,
+            concurrency,
+            tag_preferences,
+            native_tls,
+            allow_insecure_host,
+            cache,
+            printer,
+        )
+        .await;
+
+        // If requested, walk through the suggested fixes for a resolution failure, and retry the
+        // lock with the selected fix applied.
+        if interactive {
+            if let Err(ProjectError::Operation(pip::operations::Error::Resolve(
+                uv_resolver::ResolveError::NoSolution(err),
+            ))) = &result
+            {
+                diagnostics::no_solution(err);
+                if !lock_wizard::resolve_interactively(err, &workspace, printer)? {
+                    return Ok(ExitStatus::Failure);
+                }
+                workspace = discover_workspace(project_dir, environment).await?;
+                continue;
+            }
+        }
+
+        break result;
+    };
+
+    // Check the declared conflicts, even if the lock operation failed, since an undeclared
+    // conflict is a common cause of resolution failures.
+    if check_conflicts {
+        if let LockMode::Write(interpreter)
+        | LockMode::DryRun(interpreter)
+        | LockMode::Locked(interpreter) = mode
+        {
+            report_conflicts(
+                &workspace,
+                interpreter,
+                result.as_ref().ok().map(LockResult::lock),
+                settings.as_ref(),
+                connectivity,
+                concurrency,
+                tag_preferences,
+                native_tls,
+                allow_insecure_host,
+                cache,
+                printer,
+            )
+            .await?;
+        }
+    }
+
+    match result {
         Ok(lock) => {
             if dry_run {
                 let changed = if let LockResult::Changed(previous, lock) = &lock {
@@ -164,6 +264,71 @@ pub(crate) async fn lock(
                 }
             }
 
+            // Record the inputs to the resolution alongside the lockfile.
+            if provenance {
+                if let LockMode::Write(interpreter) | LockMode::Locked(interpreter) = mode {
+                    let provenance = Provenance::from_lock(
+                        &workspace,
+                        lock.lock(),
+                        interpreter,
+                        settings.as_ref(),
+                        connectivity,
+                        concurrency,
+                        native_tls,
+                        allow_insecure_host,
+                        cache,
+                    )
+                    .await?;
+                    provenance.write(&workspace)?;
+                    writeln!(
+                        printer.stderr(),
+                        "Recorded provenance to: {}",
+                        Provenance::path(&workspace).user_display().cyan()
+                    )?;
+                }
+            }
+
+            // Write the aggregate statistics for the lockfile.
+            if let Some(path) = report {
+                let previous = match &lock {
+                    LockResult::Unchanged(lock) => Some(lock),
+                    LockResult::Changed(previous, _) => previous.as_ref(),
+                };
+                LockReport::from_lock(lock.lock(), previous).write(path)?;
+                writeln!(
+                    printer.stderr(),
+                    "Wrote lock report to: {}",
+                    path.user_display().cyan()
+                )?;
+            }
+
+            if check_requires_python {
+                report_python_support(lock.lock(), printer)?;
+            }
+
+            if check_source_builds {
+                report_source_builds(lock.lock(), &settings.build_options, printer)?;
+            }
+
+            // Check for unmaintained dependencies, if enabled in `tool.uv.maintenance`.
+            if workspace.maintenance().is_some() {
+                let success = report_unmaintained(
+                    &workspace,
+                    &MaintenanceChecks::from_workspace(&workspace),
+                    lock.lock(),
+                    settings.as_ref(),
+                    connectivity,
+                    native_tls,
+                    allow_insecure_host,
+                    cache,
+                    printer,
+                )
+                .await?;
+                if !success {
+                    return Ok(ExitStatus::Failure);
+                }
+            }
+
             Ok(ExitStatus::Success)
         }
         Err(ProjectError::Operation(err)) => diagnostics::OperationDiagnostic::default()
@@ -173,8 +338,27 @@ pub(crate) async fn lock(
     }
 }
 
+/// Discover the workspace at the given path, restricting its supported environments to the named
+/// environment, if any.
+async fn discover_workspace(
+    project_dir: &Path,
+    environment: Option<&str>,
+) -> anyhow::Result<Workspace> {
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    // If requested, restrict the supported environments to the named environment.
+    if let Some(environment) = environment {
+        named_environment(&workspace, environment)?;
+        Ok(workspace
+            .with_environment(environment)
+            .expect("environment is declared in the workspace"))
+    } else {
+        Ok(workspace)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
-pub(super) enum LockMode<'env> {
+pub(crate) enum LockMode<'env> {
     /// Write the lockfile to disk.
     Write(&'env Interpreter),
     /// Perform a resolution, but don't write the lockfile to disk.
@@ -187,15 +371,17 @@ pub(super) enum LockMode<'env> {
 
 /// Perform a lock operation, respecting the `--locked` and `--frozen` parameters.
 #[allow(clippy::fn_params_excessive_bools)]
-pub(super) async fn do_safe_lock(
+pub(crate) async fn do_safe_lock(
     mode: LockMode<'_>,
     workspace: &Workspace,
     settings: ResolverSettingsRef<'_>,
     bounds: LowerBound,
+    minimize: bool,
     state: &SharedState,
     logger: Box<dyn ResolveLogger>,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -222,10 +408,13 @@ pub(super) async fn do_safe_lock(
                 Some(existing),
                 settings,
                 bounds,
+                minimize,
+                &workspace.conflicting_groups(),
                 state,
                 logger,
                 connectivity,
                 concurrency,
+                tag_preferences,
                 native_tls,
                 allow_insecure_host,
                 cache,
@@ -238,6 +427,9 @@ pub(super) async fn do_safe_lock(
                 return Err(ProjectError::LockMismatch);
             }
 
+            // Verify any hashes pinned in `tool.uv.sources`.
+            verify_source_hashes(workspace, result.lock())?;
+
             Ok(result)
         }
         LockMode::Write(interpreter) | LockMode::DryRun(interpreter) => {
@@ -261,10 +453,13 @@ pub(super) async fn do_safe_lock(
                 existing,
                 settings,
                 bounds,
+                minimize,
+                &workspace.conflicting_groups(),
                 state,
                 logger,
                 connectivity,
                 concurrency,
+                tag_preferences,
                 native_tls,
                 allow_insecure_host,
                 cache,
@@ -272,11 +467,17 @@ pub(super) async fn do_safe_lock(
             )
             .await?;
 
+            // Verify any hashes pinned in `tool.uv.sources`, before writing the lockfile.
+            verify_source_hashes(workspace, result.lock())?;
+
             // If the lockfile changed, write it to disk.
             if !matches!(mode, LockMode::DryRun(_)) {
                 if let LockResult::Changed(_, lock) = &result {
                     commit(lock, workspace).await?;
                 }
+
+                // Allow any plugins to report on the lockfile.
+                report_post_lock(&result, workspace, printer).await?;
             }
 
             Ok(result)
@@ -285,16 +486,20 @@ pub(super) async fn do_safe_lock(
 }
 
 /// Lock the project requirements into a lockfile.
+#[instrument(skip_all)]
 async fn do_lock(
     workspace: &Workspace,
     interpreter: &Interpreter,
     existing_lock: Option<Lock>,
     settings: ResolverSettingsRef<'_>,
     bounds: LowerBound,
+    minimize: bool,
+    conflicting_groups: &ConflictingGroupList,
     state: &SharedState,
     logger: Box<dyn ResolveLogger>,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -314,6 +519,7 @@ async fn do_lock(
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        max_backtracks,
         link_mode,
         upgrade,
         build_options,
@@ -457,9 +663,10 @@ async fn do_lock(
         .resolution_mode(resolution)
         .prerelease_mode(prerelease)
         .exclude_newer(exclude_newer)
+        .max_backtracks(max_backtracks)
         .index_strategy(index_strategy)
         .build();
-    let hasher = HashStrategy::Generate;
+    let hasher = lock_hasher(workspace);
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
@@ -499,6 +706,7 @@ async fn do_lock(
         bounds,
         sources,
         concurrency,
+        tag_preferences,
     );
 
     let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads);
@@ -508,6 +716,7 @@ async fn do_lock(
         match ValidatedLock::validate(
             existing_lock,
             workspace,
+            conflicting_groups,
             &members,
             &requirements,
             &constraints,
@@ -515,6 +724,7 @@ async fn do_lock(
             environments,
             dependency_metadata,
             interpreter,
+            tag_preferences,
             &requires_python,
             index_locations,
             upgrade,
@@ -542,9 +752,11 @@ async fn do_lock(
     };
 
     match existing_lock {
-        // Resolution from the lockfile succeeded.
-        Some(ValidatedLock::Satisfies(lock)) => {
+        // Resolution from the lockfile succeeded. When minimizing, the lockfile is re-resolved
+        // regardless, to search for duplicate versions that can be merged.
+        Some(ValidatedLock::Satisfies(lock)) if !minimize => {
             // Print the success message after completing resolution.
+            metrics::record_phase(Phase::Resolve, start.elapsed());
             logger.on_complete(lock.len(), start, printer)?;
 
             Ok(LockResult::Unchanged(lock))
@@ -596,52 +808,63 @@ async fn do_lock(
                     }),
             );
 
-            // Resolve the requirements.
-            let resolution = pip::operations::resolve(
-                ExtrasResolver::new(&hasher, &state.index, database)
-                    .with_reporter(ResolverReporter::from(printer))
-                    .resolve(workspace.members_requirements())
-                    .await
-                    .map_err(|err| ProjectError::Operation(err.into()))?
-                    .into_iter()
-                    .chain(requirements.iter().cloned())
-                    .map(UnresolvedRequirementSpecification::from)
-                    .collect(),
-                constraints
-                    .iter()
-                    .cloned()
-                    .map(NameRequirementSpecification::from)
-                    .collect(),
-                overrides
-                    .iter()
-                    .cloned()
-                    .map(UnresolvedRequirementSpecification::from)
-                    .collect(),
-                dev,
-                source_trees,
-                // The root is always null in workspaces, it "depends on" the projects
-                None,
-                Some(workspace.packages().keys().cloned().collect()),
-                &extras,
-                preferences,
-                EmptyInstalledPackages,
-                &hasher,
-                &Reinstall::default(),
-                upgrade,
-                None,
-                resolver_env,
-                python_requirement,
-                workspace.conflicting_groups(),
-                &client,
-                &flat_index,
-                &state.index,
-                &build_dispatch,
-                concurrency,
-                options,
-                Box::new(SummaryResolveLogger),
-                printer,
-            )
-            .await?;
+            // Collect the requirements to resolve, including those of the workspace members.
+            let specifications = ExtrasResolver::new(&hasher, &state.index, database)
+                .with_reporter(ResolverReporter::from(printer))
+                .resolve(workspace.members_requirements())
+                .await
+                .map_err(|err| ProjectError::Operation(err.into()))?
+                .into_iter()
+                .chain(requirements.iter().cloned())
+                .map(UnresolvedRequirementSpecification::from)
+                .collect::<Vec<_>>();
+
+            // Resolve the requirements, with the given preferences.
+            let reinstall = Reinstall::default();
+            let resolve = |preferences: Vec<Preference>| {
+                pip::operations::resolve(
+                    specifications.clone(),
+                    constraints
+                        .iter()
+                        .cloned()
+                        .map(NameRequirementSpecification::from)
+                        .collect(),
+                    overrides
+                        .iter()
+                        .cloned()
+                        .map(UnresolvedRequirementSpecification::from)
+                        .collect(),
+                    dev.clone(),
+                    source_trees.clone(),
+                    // The root is always null in workspaces, it "depends on" the projects
+                    None,
+                    Some(workspace.packages().keys().cloned().collect()),
+                    &extras,
+                    preferences,
+                    EmptyInstalledPackages,
+                    &hasher,
+                    &reinstall,
+                    upgrade,
+                    None,
+                    resolver_env.clone(),
+                    python_requirement.clone(),
+                    conflicting_groups.clone(),
+                    Variants::from_workspace(workspace),
+                    GroupResolution::from_workspace(workspace),
+                    Vendored::from_workspace(workspace),
+                    IndexPatterns::from_workspace(workspace, index_locations),
+                    &client,
+                    &flat_index,
+                    &state.index,
+                    &build_dispatch,
+                    concurrency,
+                    options,
+                    Box::new(SummaryResolveLogger),
+                    printer,
+                )
+            };
+
+            let resolution = resolve(preferences.clone()).await?;
 
             // Print the success message after completing resolution.
             logger.on_complete(resolution.len(), start, printer)?;
@@ -649,6 +872,92 @@ async fn do_lock(
             // Notify the user of any resolution diagnostics.
             pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
+            // Attempt to unify any packages that were locked at multiple versions across forks.
+            let resolution = if minimize {
+                minimize_duplicates(resolution, &preferences, resolve, printer).await?
+            } else {
+                resolution
+            };
+
+            // Resolve each vendored package on its own, as a leaf, and independently of the
+            // versions selected in the main resolution.
+            let mut vendored = Vec::with_capacity(workspace.vendored().len());
+            for dependency in workspace.vendored() {
+                if !uv_install_wheel::is_valid_module_name(&dependency.alias) {
+                    return Err(ProjectError::InvalidVendorAlias(
+                        dependency.name.clone(),
+                        dependency.alias.clone(),
+                    ));
+                }
+
+                let requirement = Requirement {
+                    name: dependency.name.clone(),
+                    extras: vec![],
+                    marker: MarkerTree::TRUE,
+                    source: RequirementSource::Registry {
+                        specifier: dependency.version.clone(),
+                        index: None,
+                    },
+                    origin: None,
+                };
+
+                // Prefer the previously-locked version of the vendored package, if any.
+                let preferences = versions_lock
+                    .into_iter()
+                    .flat_map(Lock::vendored)
+                    .filter(|locked| {
+                        locked.alias() == dependency.alias
+                            && locked.name() == &dependency.name
+                            && !upgrade.contains(locked.name())
+                    })
+                    .map(|locked| Preference::from_lock(locked.package()))
+                    .collect();
+
+                let graph = pip::operations::resolve(
+                    vec![UnresolvedRequirementSpecification::from(requirement)],
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![],
+                    None,
+                    None,
+                    &extras,
+                    preferences,
+                    EmptyInstalledPackages,
+                    &hasher,
+                    &Reinstall::default(),
+                    upgrade,
+                    None,
+                    ResolverEnvironment::universal(vec![]),
+                    python_requirement.clone(),
+                    ConflictingGroupList::empty(),
+                    Variants::from_workspace(workspace),
+                    GroupResolution::default(),
+                    Vendored::default(),
+                    IndexPatterns::from_workspace(workspace, index_locations),
+                    &client,
+                    &flat_index,
+                    &state.index,
+                    &build_dispatch,
+                    concurrency,
+                    Options {
+                        dependency_mode: DependencyMode::Direct,
+                        ..options
+                    },
+                    Box::new(SummaryResolveLogger),
+                    printer,
+                )
+                .await?;
+
+                vendored.push(VendoredPackage::from_resolution_graph(
+                    &graph,
+                    workspace.install_path(),
+                    &dependency.name,
+                    dependency.alias.clone(),
+                    dependency.dependents.iter().cloned(),
+                )?);
+            }
+
             let manifest = ResolverManifest::new(
                 members,
                 requirements,
@@ -661,12 +970,18 @@ async fn do_lock(
             let previous = existing_lock.map(ValidatedLock::into_lock);
             let lock = Lock::from_resolution_graph(&resolution, workspace.install_path())?
                 .with_manifest(manifest)
-                .with_conflicting_groups(workspace.conflicting_groups())
+                .with_vendored(vendored)
+                .with_group_resolution(GroupResolution::strategies(workspace))
+                .with_conflicting_groups(conflicting_groups.clone())
                 .with_supported_environments(
                     environments
                         .cloned()
                         .map(SupportedEnvironments::into_markers)
                         .unwrap_or_default(),
+                )
+                .with_build_dependencies(
+                    |name, version| build_dispatch.build_dependencies(name, version),
+                    previous.as_ref(),
                 );
 
             Ok(LockResult::Changed(previous, lock))
@@ -674,6 +989,120 @@ async fn do_lock(
     }
 }
 
+/// Attempt to unify packages that were resolved to multiple versions across forks.
+///
+/// For each duplicated package, the locked versions are tried in descending order as a preference
+/// shared by all forks. A candidate resolution is accepted if it reduces the number of duplicated
+/// packages without introducing new ones. Packages that can't be unified are reported as
+/// irreducible.
+async fn minimize_duplicates<Resolve, ResolveFuture>(
+    mut resolution: ResolutionGraph,
+    preferences: &[Preference],
+    resolve: Resolve,
+    printer: Printer,
+) -> Result<ResolutionGraph, ProjectError>
+where
+    Resolve: Fn(Vec<Preference>) -> ResolveFuture,
+    ResolveFuture: Future<Output = Result<ResolutionGraph, pip::operations::Error>>,
+{
+    let original = resolution.duplicates();
+    if original.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "No duplicate versions to minimize".bold()
+        )?;
+        return Ok(resolution);
+    }
+
+    // The versions that have been selected for each merged package.
+    let mut merged = BTreeMap::<PackageName, Version>::new();
+
+    for round in 0.. {
+        let duplicates = resolution.duplicates();
+
+        // Select the next candidate version for each package that remains duplicated.
+        let candidates = duplicates
+            .keys()
+            .filter_map(|name| {
+                let version = original.get(name)?.get(round)?;
+                Some((name, version))
+            })
+            .collect::<Vec<_>>();
+        if candidates.is_empty() {
+            break;
+        }
+
+        debug!(
+            "Attempting to merge duplicate versions of: {}",
+            candidates
+                .iter()
+                .map(|(name, version)| format!("{name}=={version}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        // Prefer the candidate (and previously merged) versions across all forks, in lieu of any
+        // fork-specific preferences for the same packages.
+        let pinned = candidates
+            .iter()
+            .map(|(name, version)| (*name, *version))
+            .chain(merged.iter())
+            .collect::<BTreeMap<_, _>>();
+        let preferences = preferences
+            .iter()
+            .filter(|preference| !pinned.contains_key(preference.name()))
+            .cloned()
+            .chain(pinned.iter().map(|(name, version)| {
+                Preference::from_version((*name).clone(), (*version).clone())
+            }))
+            .collect();
+
+        let candidate = resolve(preferences).await?;
+
+        // Accept the candidate if it strictly reduces the set of duplicated packages.
+        let remaining = candidate.duplicates();
+        if remaining.len() < duplicates.len()
+            && remaining.keys().all(|name| duplicates.contains_key(name))
+        {
+            for name in duplicates.keys() {
+                if remaining.contains_key(name) {
+                    continue;
+                }
+                if let Some(version) = candidate.versions(name).into_iter().next() {
+                    merged.insert(name.clone(), version.clone());
+                }
+            }
+            resolution = candidate;
+        }
+    }
+
+    // Report the merged and irreducible duplicates.
+    for (name, versions) in &original {
+        let versions = versions
+            .iter()
+            .rev()
+            .map(|version| format!("v{version}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if let Some(version) = merged.get(name) {
+            writeln!(
+                printer.stderr(),
+                "{} {name} {versions} -> v{version}",
+                "Merged".green().bold()
+            )?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "{} {name} {versions} (irreducible)",
+                "Kept".yellow().bold()
+            )?;
+        }
+    }
+
+    Ok(resolution)
+}
+
 #[derive(Debug)]
 enum ValidatedLock {
     /// An existing lockfile was provided, and it satisfies the workspace requirements.
@@ -693,6 +1122,7 @@ impl ValidatedLock {
     async fn validate<Context: BuildContext>(
         lock: Lock,
         workspace: &Workspace,
+        conflicting_groups: &ConflictingGroupList,
         members: &[PackageName],
         requirements: &[Requirement],
         constraints: &[Requirement],
@@ -700,6 +1130,7 @@ impl ValidatedLock {
         environments: Option<&SupportedEnvironments>,
         dependency_metadata: &DependencyMetadata,
         interpreter: &Interpreter,
+        tag_preferences: &TagPreferences,
         requires_python: &RequiresPython,
         index_locations: &IndexLocations,
         upgrade: &Upgrade,
@@ -728,6 +1159,14 @@ impl ValidatedLock {
             );
             return Ok(Self::Unusable(lock));
         }
+        let group_resolution = GroupResolution::strategies(workspace);
+        if *lock.group_resolution() != group_resolution {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in group resolution strategies"
+            );
+            return Ok(Self::Unusable(lock));
+        }
         match (lock.exclude_newer(), options.exclude_newer) {
             (None, None) => (),
             (Some(existing), Some(provided)) if existing == provided => (),
@@ -760,7 +1199,7 @@ impl ValidatedLock {
 
         match upgrade {
             Upgrade::None => {}
-            Upgrade::All => {
+            Upgrade::All(_) => {
                 // If the user specified `--upgrade`, then we can't use the existing lockfile.
                 debug!("Ignoring existing lockfile due to `--upgrade`");
                 return Ok(Self::Unusable(lock));
@@ -806,15 +1245,50 @@ impl ValidatedLock {
         }
 
         // If the conflicting group config has changed, we have to perform a clean resolution.
-        if &workspace.conflicting_groups() != lock.conflicting_groups() {
+        if conflicting_groups != lock.conflicting_groups() {
             debug!(
                 "Ignoring existing lockfile due to change in conflicting groups: `{:?}` vs. `{:?}`",
-                workspace.conflicting_groups(),
+                conflicting_groups,
                 lock.conflicting_groups(),
             );
             return Ok(Self::Versions(lock));
         }
 
+        // If a locked version doesn't match the selected hardware variant, prefer the remaining
+        // locked versions.
+        let variants = Variants::from_workspace(workspace);
+        if let Some(package) = lock
+            .packages()
+            .iter()
+            .find(|package| !variants.allows(package.name(), package.version()))
+        {
+            debug!(
+                "Ignoring existing lockfile due to change in variant for `{}`: `{}`",
+                package.name(),
+                package.version(),
+            );
+            return Ok(Self::Preferable(lock));
+        }
+
+        // If the vendored packages have changed, prefer the remaining locked versions.
+        let vendored = workspace.vendored();
+        let unchanged = lock.vendored().len() == vendored.len()
+            && vendored.iter().all(|dependency| {
+                lock.vendored().iter().any(|locked| {
+                    locked.name() == &dependency.name
+                        && locked.alias() == dependency.alias
+                        && dependency.version.contains(locked.version())
+                        && locked
+                            .dependents()
+                            .iter()
+                            .eq(dependency.dependents.iter().collect::<BTreeSet<_>>())
+                })
+            });
+        if !unchanged {
+            debug!("Ignoring existing lockfile due to change in vendored packages");
+            return Ok(Self::Preferable(lock));
+        }
+
         // If the user provided at least one index URL (from the command line, or from a configuration
         // file), don't use the existing lockfile if it references any registries that are no longer
         // included in the current configuration.
@@ -838,7 +1312,7 @@ impl ValidatedLock {
                 overrides,
                 dependency_metadata,
                 indexes,
-                interpreter.tags()?,
+                &interpreter.tags(tag_preferences)?,
                 hasher,
                 index,
                 database,
@@ -956,7 +1430,47 @@ impl ValidatedLock {
 /// Write the lockfile to disk.
 async fn commit(lock: &Lock, workspace: &Workspace) -> Result<(), ProjectError> {
     let encoded = lock.to_toml()?;
-    fs_err::tokio::write(workspace.install_path().join("uv.lock"), encoded).await?;
+    fs_err::tokio::write(workspace.lockfile(), encoded).await?;
+    Ok(())
+}
+
+/// Invoke the `post-lock` plugin hook, and display any messages reported by plugins.
+async fn report_post_lock(
+    result: &LockResult,
+    workspace: &Workspace,
+    printer: Printer,
+) -> Result<(), ProjectError> {
+    let lock = result.lock();
+    let packages = lock
+        .packages()
+        .iter()
+        .map(|package| {
+            Ok(uv_plugin::LockedPackage {
+                name: package.name(),
+                version: package.version(),
+                source: package
+                    .index(workspace.install_path())?
+                    .map(|index| index.to_string()),
+            })
+        })
+        .collect::<Result<Vec<_>, LockError>>()?;
+
+    let messages = uv_plugin::post_lock(
+        &workspace.lockfile(),
+        matches!(result, LockResult::Changed(..)),
+        &packages,
+    )
+    .await?;
+    for message in messages {
+        writeln!(
+            printer.stderr(),
+            "{}{} {}",
+            message.plugin.bold(),
+            ":".bold(),
+            message.message
+        )?;
+    }
+
     Ok(())
 }
 
@@ -964,55 +1478,445 @@ async fn commit(lock: &Lock, workspace: &Workspace) -> Result<(), ProjectError>
 ///
 /// Returns `Ok(None)` if the lockfile does not exist.
 pub(crate) async fn read(workspace: &Workspace) -> Result<Option<Lock>, ProjectError> {
-    match fs_err::tokio::read_to_string(&workspace.install_path().join("uv.lock")).await {
-        Ok(encoded) => {
-            match toml::from_str::<Lock>(&encoded) {
-                Ok(lock) => {
-                    // If the lockfile uses an unsupported version, raise an error.
-                    if lock.version() != VERSION {
-                        return Err(ProjectError::UnsupportedLockVersion(
-                            VERSION,
-                            lock.version(),
-                        ));
-                    }
-                    Ok(Some(lock))
-                }
-                Err(err) => {
-                    // If we failed to parse the lockfile, determine whether it's a supported
-                    // version.
-                    if let Ok(lock) = toml::from_str::<LockVersion>(&encoded) {
-                        if lock.version() != VERSION {
-                            return Err(ProjectError::UnparsableLockVersion(
-                                VERSION,
-                                lock.version(),
-                                err,
-                            ));
-                        }
-                    }
-                    Err(ProjectError::UvLockParse(err))
+    let path = workspace.lockfile();
+    match fs_err::tokio::read_to_string(&path).await {
+        Ok(encoded) => parse(path, encoded).map(Some),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Parse the contents of the lockfile at the given path.
+///
+/// If the lockfile is unchanged since it was last parsed by a long-lived process (i.e.,
+/// `uv daemon`), the parsed lockfile is reused.
+#[allow(clippy::result_large_err)]
+pub(crate) fn parse(path: PathBuf, encoded: String) -> Result<Lock, ProjectError> {
+    if let Some((_, lock)) = LOCKS.get(&path, |(cached, _)| *cached == encoded) {
+        return Ok(lock);
+    }
+    match toml::from_str::<Lock>(&encoded) {
+        Ok(lock) => {
+            // If the lockfile uses an unsupported version, raise an error.
+            if lock.version() != VERSION {
+                return Err(ProjectError::UnsupportedLockVersion(
+                    VERSION,
+                    lock.version(),
+                ));
+            }
+            LOCKS.insert_with(path, || (encoded, lock.clone()));
+            Ok(lock)
+        }
+        Err(err) => {
+            // If we failed to parse the lockfile, determine whether it's a supported
+            // version.
+            if let Ok(lock) = toml::from_str::<LockVersion>(&encoded) {
+                if lock.version() != VERSION {
+                    return Err(ProjectError::UnparsableLockVersion(
+                        VERSION,
+                        lock.version(),
+                        err,
+                    ));
                 }
             }
+            Err(ProjectError::UvLockParse(err))
         }
-        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
-        Err(err) => Err(err.into()),
     }
 }
 
+/// Return the lockfiles that have been parsed since the last call, such that they can be
+/// preloaded by `uv daemon`.
+pub(crate) fn take_parsed_lockfiles() -> Vec<PathBuf> {
+    LOCKS.take_updated()
+}
+
 /// Read the lockfile from the workspace as bytes.
 ///
 /// Returns `Ok(None)` if the lockfile does not exist.
 pub(crate) async fn read_bytes(workspace: &Workspace) -> Result<Option<Vec<u8>>, ProjectError> {
-    match fs_err::tokio::read(&workspace.install_path().join("uv.lock")).await {
+    match fs_err::tokio::read(&workspace.lockfile()).await {
         Ok(encoded) => Ok(Some(encoded)),
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
         Err(err) => Err(err.into()),
     }
 }
 
+/// Report any locked packages that lack a compatible distribution for some of the Python versions
+/// included in the lockfile's `requires-python` range.
+///
+/// Returns `true` if all locked packages support every Python version in the range.
+pub(crate) fn report_python_support(lock: &Lock, printer: Printer) -> anyhow::Result<bool> {
+    let support = PythonSupport::from_lock(lock);
+
+    if support.unsupported().is_empty() {
+        writeln!(
+            printer.stderr(),
+            "All locked packages support Python {}",
+            format_python_versions(support.versions()).bold()
+        )?;
+        return Ok(true);
+    }
+
+    for package in support.unsupported() {
+        let chain = package
+            .chain
+            .iter()
+            .map(|(name, version)| format!("{name}=={version}"))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        warn_user!(
+            "`{}=={}` has no compatible distributions for Python {}, which is included in `requires-python = \"{}\"` (required by: {chain})",
+            package.name,
+            package.version,
+            format_python_versions(&package.python_versions),
+            lock.requires_python(),
+        );
+    }
+
+    let supported = support.supported_versions();
+    if supported.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "The locked packages don't support any Python version in `requires-python`".bold()
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "The locked packages support Python {} of {}",
+            format_python_versions(supported).bold(),
+            format_python_versions(support.versions()),
+        )?;
+    }
+
+    Ok(false)
+}
+
+/// Report any locked packages that would need to be built from source on some of the lockfile's
+/// target environments, i.e., that lack a wheel for the environment's platform.
+pub(crate) fn report_source_builds(
+    lock: &Lock,
+    build_options: &BuildOptions,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    let builds = SourceBuilds::from_lock(lock);
+
+    if builds.packages().is_empty() {
+        writeln!(
+            printer.stderr(),
+            "All locked packages have wheels for every target environment"
+        )?;
+        return Ok(());
+    }
+
+    for package in builds.packages() {
+        let chain = package
+            .chain
+            .iter()
+            .map(|(name, version)| format!("{name}=={version}"))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        for environment in &package.environments {
+            let display = environment
+                .try_to_string()
+                .unwrap_or_else(|| "all environments".to_string());
+            if build_options.no_build_package_within(&package.name, environment) {
+                warn_user!(
+                    "`{}=={}` would need to be built from source on `{display}`, but building it is disabled (required by: {chain})",
+                    package.name,
+                    package.version,
+                );
+            } else {
+                writeln!(
+                    printer.stderr(),
+                    "`{}=={}` would be built from source on `{}` (required by: {chain})",
+                    package.name,
+                    package.version,
+                    display.cyan(),
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Report any locked dependencies that are no longer maintained, according to their index.
+///
+/// Returns `false` if any dependency was flagged under the `fail` policy.
+pub(crate) async fn report_unmaintained(
+    workspace: &Workspace,
+    checks: &MaintenanceChecks<'_>,
+    lock: &Lock,
+    settings: ResolverSettingsRef<'_>,
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<bool> {
+    let capabilities = IndexCapabilities::default();
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .native_tls(native_tls)
+        .connectivity(connectivity)
+        .keyring(settings.keyring_provider)
+        .allow_insecure_host(allow_insecure_host.to_vec())
+        .build();
+
+    // Initialize the client to fetch the index's view of each package.
+    let client = LatestClient {
+        client: &client,
+        capabilities: &capabilities,
+        prerelease: lock.prerelease_mode(),
+        exclude_newer: lock.exclude_newer(),
+        requires_python: lock.requires_python(),
+        tags: None,
+    };
+
+    // Flag any registry packages that are no longer maintained.
+    let mut unmaintained = stream::iter(lock.packages())
+        .filter_map(|package| async {
+            let index = package.index(workspace.install_path()).ok()??;
+            let latest = client
+                .find_upgrade(package.name(), Some(&index))
+                .await
+                .ok()?;
+            let reason = checks.check(package.name(), &latest)?;
+            Some((package.name(), reason))
+        })
+        .collect::<Vec<_>>()
+        .await;
+    unmaintained.sort_by(|(a, _), (b, _)| a.cmp(b));
+    unmaintained.dedup_by(|(a, _), (b, _)| a == b);
+
+    match checks.policy() {
+        MaintenancePolicy::Warn => {
+            for (name, reason) in &unmaintained {
+                warn_user!("`{name}` is no longer maintained ({reason})");
+            }
+            Ok(true)
+        }
+        MaintenancePolicy::Fail => {
+            for (name, reason) in &unmaintained {
+                writeln!(
+                    printer.stderr(),
+                    "{}{} `{name}` is no longer maintained ({reason})",
+                    "error".red().bold(),
+                    ":".bold(),
+                )?;
+            }
+            Ok(unmaintained.is_empty())
+        }
+    }
+}
+
+/// Compare the inputs to the existing lockfile's resolution against those recorded by
+/// `uv lock --provenance`, and report any that have changed.
+async fn report_provenance_drift(
+    workspace: &Workspace,
+    interpreter: &Interpreter,
+    settings: ResolverSettingsRef<'_>,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let lock = read(workspace)
+        .await?
+        .ok_or_else(|| ProjectError::MissingLockfile)?;
+    let Some(recorded) = Provenance::read(workspace)? else {
+        anyhow::bail!(
+            "No provenance found at `{}`; run `uv lock --provenance` to record it",
+            Provenance::path(workspace).user_display()
+        );
+    };
+
+    // Revalidate the index pages, rather than trusting the cache.
+    let cache = cache.clone().with_refresh(Refresh::All(Timestamp::now()));
+    let current = Provenance::from_lock(
+        workspace,
+        &lock,
+        interpreter,
+        settings,
+        connectivity,
+        concurrency,
+        native_tls,
+        allow_insecure_host,
+        &cache,
+    )
+    .await?;
+
+    let drift = recorded.drift(&current);
+    if drift.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "No provenance drift detected".bold()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "{}{} The inputs to the lockfile have changed since its provenance was recorded:",
+        "error".red().bold(),
+        ":".bold(),
+    )?;
+    for drift in &drift {
+        writeln!(printer.stderr(), "  - {drift}")?;
+    }
+    Ok(ExitStatus::Failure)
+}
+
+/// Re-resolve the workspace with each extra in its own fork, and report any conflicts between
+/// extras that aren't declared in `tool.uv.conflicting-groups`, along with any declared
+/// conflicting extras that don't conflict with the other extras in their set.
+async fn report_conflicts(
+    workspace: &Workspace,
+    interpreter: &Interpreter,
+    existing: Option<&Lock>,
+    settings: ResolverSettingsRef<'_>,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    let declared = workspace.conflicting_groups();
+
+    // Declare every extra of every workspace member as conflicting, such that each extra is
+    // resolved in isolation.
+    let extras = workspace
+        .packages()
+        .iter()
+        .flat_map(|(name, member)| {
+            member
+                .project()
+                .optional_dependencies
+                .iter()
+                .flat_map(BTreeMap::keys)
+                .map(|extra| ConflictingGroup::from((name.clone(), extra.clone())))
+        })
+        .collect::<Vec<_>>();
+    let Ok(groups) = ConflictingGroups::try_from(extras) else {
+        writeln!(
+            printer.stderr(),
+            "No conflicts to check, as the workspace defines fewer than two extras"
+        )?;
+        return Ok(());
+    };
+    let mut isolated = ConflictingGroupList::empty();
+    isolated.push(groups);
+
+    // Use a separate state, as a failed resolution can leave the shared state unusable.
+    let state = SharedState::default();
+    let result = match do_lock(
+        workspace,
+        interpreter,
+        existing.cloned(),
+        settings,
+        LowerBound::Allow,
+        false,
+        &isolated,
+        &state,
+        Box::new(SummaryResolveLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(err) => {
+            debug!("Failed to resolve the extras in isolation: {err}");
+            warn_user!(
+                "Unable to check the declared conflicts, as the workspace fails to resolve even with each extra in isolation"
+            );
+            return Ok(());
+        }
+    };
+
+    let conflicts = ExtraConflicts::from_lock(result.lock());
+    let mut consistent = true;
+
+    for conflict in conflicts.undeclared(&declared) {
+        consistent = false;
+        warn_user!(
+            "`{}` and `{}` conflict, but aren't declared in `tool.uv.conflicting-groups` ({} vs. {})",
+            format_extra(&conflict.first),
+            format_extra(&conflict.second),
+            format_conflict_chain(&conflict.first, &conflict.first_chain),
+            format_conflict_chain(&conflict.second, &conflict.second_chain),
+        );
+    }
+
+    for unnecessary in conflicts.unnecessary(&declared) {
+        consistent = false;
+        warn_user!(
+            "`{}` is declared in `tool.uv.conflicting-groups`, but doesn't conflict with any other extra in its set ({})",
+            format_extra(unnecessary.group),
+            unnecessary
+                .groups
+                .iter()
+                .map(|group| format!("`{}`", format_extra(group)))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+    }
+
+    if consistent {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "The declared conflicts are minimal and complete".bold()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Format an extra of a workspace member, e.g., `project[cpu]`.
+fn format_extra(group: &ConflictingGroup) -> String {
+    format!("{}[{}]", group.package(), group.extra())
+}
+
+/// Format the chain of dependencies through which an extra requires a package, e.g.,
+/// `project[cpu] -> torch==2.5.1+cpu`.
+fn format_conflict_chain(group: &ConflictingGroup, chain: &[(PackageName, Version)]) -> String {
+    std::iter::once(format_extra(group))
+        .chain(
+            chain
+                .iter()
+                .skip(1)
+                .map(|(name, version)| format!("{name}=={version}")),
+        )
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+/// Format a list of Python versions, e.g., `3.10, 3.11, 3.12`.
+fn format_python_versions<'a>(versions: impl IntoIterator<Item = &'a Version>) -> String {
+    versions
+        .into_iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Reports on the versions that were upgraded in the new lockfile.
 ///
 /// Returns `true` if any upgrades were reported.
-fn report_upgrades(
+pub(crate) fn report_upgrades(
     existing_lock: Option<&Lock>,
     new_lock: &Lock,
     printer: Printer,
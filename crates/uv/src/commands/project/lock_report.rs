@@ -0,0 +1,156 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::Serialize;
+
+use uv_normalize::{GroupName, PackageName};
+use uv_pep440::Version;
+use uv_resolver::{Lock, LockStatistics};
+
+/// Aggregate statistics on a lockfile, as written by `uv lock --report`.
+#[derive(Debug, Serialize)]
+pub(crate) struct LockReport {
+    /// The version of uv that wrote the report.
+    uv_version: String,
+    /// The `requires-python` of the lockfile.
+    requires_python: String,
+    /// The number of locked packages, excluding the workspace members.
+    packages: usize,
+    /// The number of distinct build requirements recorded for packages built from source.
+    build_dependencies: usize,
+    /// The number of locked packages required by each dependency group.
+    groups: BTreeMap<GroupName, usize>,
+    /// The number of locked packages required in each target environment.
+    environments: Vec<EnvironmentReport>,
+    /// The locked source distributions and wheels.
+    artifacts: ArtifactReport,
+    /// The changes relative to the previous lockfile.
+    changes: ChangeReport,
+}
+
+/// The number of locked packages required in a target environment.
+#[derive(Debug, Serialize)]
+struct EnvironmentReport {
+    /// The markers that describe the environment.
+    marker: String,
+    /// The number of locked packages required in the environment.
+    packages: usize,
+}
+
+/// The locked source distributions and wheels.
+#[derive(Debug, Serialize)]
+struct ArtifactReport {
+    /// The number of source distributions.
+    sdists: usize,
+    /// The number of wheels.
+    wheels: usize,
+    /// The total size of the source distributions, in bytes.
+    sdist_size: u64,
+    /// The total size of the wheels, in bytes.
+    wheel_size: u64,
+    /// The number of artifacts without a known size (e.g., those that don't come from a registry).
+    unknown_size: usize,
+}
+
+/// The packages that were added, removed, or updated relative to the previous lockfile.
+///
+/// If there was no previous lockfile, every locked package is considered added.
+#[derive(Debug, Serialize)]
+struct ChangeReport {
+    added: Vec<PackageName>,
+    removed: Vec<PackageName>,
+    updated: Vec<UpdatedPackage>,
+}
+
+/// A package that was locked at different versions in the previous lockfile.
+#[derive(Debug, Serialize)]
+struct UpdatedPackage {
+    name: PackageName,
+    previous: BTreeSet<Version>,
+    current: BTreeSet<Version>,
+}
+
+impl LockReport {
+    /// Generate a [`LockReport`] for the given [`Lock`], relative to the previous [`Lock`], if any.
+    pub(crate) fn from_lock(lock: &Lock, previous: Option<&Lock>) -> Self {
+        let statistics = LockStatistics::from_lock(lock);
+
+        let environments = statistics
+            .environments
+            .into_iter()
+            .map(|(marker, packages)| EnvironmentReport {
+                marker: marker
+                    .try_to_string()
+                    .unwrap_or_else(|| "all environments".to_string()),
+                packages,
+            })
+            .collect();
+
+        Self {
+            uv_version: uv_version::version().to_string(),
+            requires_python: lock.requires_python().to_string(),
+            packages: statistics.packages,
+            build_dependencies: statistics.build_dependencies,
+            groups: statistics.groups,
+            environments,
+            artifacts: ArtifactReport {
+                sdists: statistics.sdists,
+                wheels: statistics.wheels,
+                sdist_size: statistics.sdist_size,
+                wheel_size: statistics.wheel_size,
+                unknown_size: statistics.unknown_size,
+            },
+            changes: ChangeReport::from_locks(previous, lock),
+        }
+    }
+
+    /// Write the [`LockReport`] to the given path, as JSON.
+    pub(crate) fn write(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        fs_err::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+}
+
+impl ChangeReport {
+    /// Compare the packages in the previous and current lockfiles.
+    fn from_locks(previous: Option<&Lock>, current: &Lock) -> Self {
+        let versions = |lock: &Lock| {
+            lock.packages().iter().fold(
+                BTreeMap::<PackageName, BTreeSet<Version>>::new(),
+                |mut acc, package| {
+                    acc.entry(package.name().clone())
+                        .or_default()
+                        .insert(package.version().clone());
+                    acc
+                },
+            )
+        };
+        let previous = previous.map(versions).unwrap_or_default();
+        let mut current = versions(current);
+
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        let mut updated = Vec::new();
+        for (name, previous) in previous {
+            match current.remove(&name) {
+                Some(current) if current != previous => updated.push(UpdatedPackage {
+                    name,
+                    previous,
+                    current,
+                }),
+                Some(_) => {}
+                None => removed.push(name),
+            }
+        }
+        added.extend(current.into_keys());
+
+        Self {
+            added,
+            removed,
+            updated,
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_fs::Simplified;
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep508::Requirement;
+use uv_resolver::{NoSolutionError, Suggestion};
+use uv_warnings::warn_user;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+use uv_workspace::Workspace;
+
+use crate::printer::Printer;
+
+/// The maximum number of fixes to offer for a single resolution failure.
+const MAX_FIXES: usize = 8;
+
+/// An edit to the workspace's `pyproject.toml` files that may resolve a resolution failure, as
+/// derived from a [`Suggestion`].
+#[derive(Debug, Clone)]
+enum Fix {
+    /// Remove the version specifiers from a workspace member's requirements on a package.
+    Relax {
+        member: PackageName,
+        package: PackageName,
+        specifier: String,
+    },
+    /// Add an override to `tool.uv.override-dependencies` in the workspace root.
+    Override { requirement: Requirement },
+    /// Declare the extras of workspace members as conflicting in `tool.uv.conflicting-groups`.
+    Conflicts {
+        extras: Vec<(PackageName, ExtraName)>,
+    },
+}
+
+impl Fix {
+    /// Convert a [`Suggestion`] into a [`Fix`], if it can be applied to the workspace.
+    ///
+    /// Requirements declared by packages outside the workspace can't be relaxed, and conflicts
+    /// that involve dependency groups can't be declared.
+    fn from_suggestion(suggestion: &Suggestion, workspace: &Workspace) -> Option<Self> {
+        match suggestion {
+            Suggestion::Relax {
+                dependent: Some(dependent),
+                package,
+                specifier,
+            } => {
+                // Strip any extra or group from the dependent, as in `project[extra]` or
+                // `project:group`.
+                let member = dependent
+                    .split(['[', ':'])
+                    .next()
+                    .and_then(|name| PackageName::from_str(name).ok())?;
+                if !workspace.packages().contains_key(&member) {
+                    return None;
+                }
+                Some(Self::Relax {
+                    member,
+                    package: package.clone(),
+                    specifier: specifier.clone(),
+                })
+            }
+            Suggestion::Relax {
+                dependent: None, ..
+            } => None,
+            Suggestion::Override { package, specifier } => {
+                let requirement = Requirement::from_str(&format!("{package}{specifier}")).ok()?;
+                Some(Self::Override { requirement })
+            }
+            Suggestion::Conflicts { items } => {
+                let extras = items
+                    .iter()
+                    .map(|item| Some((item.package().clone(), item.extra()?.clone())))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Self::Conflicts { extras })
+            }
+        }
+    }
+
+    /// Apply the [`Fix`] to the relevant `pyproject.toml` in the workspace.
+    ///
+    /// Returns `false` if the fix had no effect.
+    fn apply(
+        &self,
+        workspace: &Workspace,
+        documents: &mut BTreeMap<PathBuf, PyProjectTomlMut>,
+    ) -> Result<bool> {
+        match self {
+            Self::Relax {
+                member, package, ..
+            } => {
+                let path = workspace.packages()[member].root().join("pyproject.toml");
+                let cleared = document(documents, &path)?.clear_dependency_bounds(package)?;
+                Ok(cleared > 0)
+            }
+            Self::Override { requirement } => {
+                let path = workspace.install_path().join("pyproject.toml");
+                document(documents, &path)?.add_override_dependency(requirement)?;
+                Ok(true)
+            }
+            Self::Conflicts { extras } => {
+                let (member, _) = &extras[0];
+                let path = workspace.packages()[member].root().join("pyproject.toml");
+                document(documents, &path)?.add_conflicting_extras(extras)?;
+                Ok(true)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Fix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Relax {
+                member,
+                package,
+                specifier,
+            } => write!(
+                f,
+                "Relax the requirement `{package}{specifier}` in `{member}` (remove its version specifiers)"
+            ),
+            Self::Override { requirement } => write!(
+                f,
+                "Override the requirement on `{}` with `{requirement}` (via `tool.uv.override-dependencies`)",
+                requirement.name
+            ),
+            Self::Conflicts { extras } => {
+                let list = extras
+                    .iter()
+                    .map(|(package, extra)| format!("`{package}[{extra}]`"))
+                    .collect::<Vec<_>>()
+                    .join(" and ");
+                write!(
+                    f,
+                    "Declare {list} as conflicting (via `tool.uv.conflicting-groups`)"
+                )
+            }
+        }
+    }
+}
+
+/// Prompt the user to select one of the suggested fixes for a resolution failure, and apply it to
+/// the workspace.
+///
+/// Returns `true` if a fix was applied, such that the lock should be retried.
+pub(crate) fn resolve_interactively(
+    err: &NoSolutionError,
+    workspace: &Workspace,
+    printer: Printer,
+) -> Result<bool> {
+    let term = Term::stderr();
+    if !term.is_term() {
+        return Ok(false);
+    }
+
+    let fixes = err
+        .suggestions()
+        .iter()
+        .filter_map(|suggestion| Fix::from_suggestion(suggestion, workspace))
+        .take(MAX_FIXES)
+        .collect::<Vec<_>>();
+    if fixes.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No fixes can be applied to the workspace automatically"
+        )?;
+        return Ok(false);
+    }
+
+    let mut items = fixes.iter().map(ToString::to_string).collect::<Vec<_>>();
+    items.push("Abort".to_string());
+
+    loop {
+        let selection = uv_console::select("How should the conflict be resolved?", &items, &term)?;
+        let Some(fix) = fixes.get(selection) else {
+            return Ok(false);
+        };
+
+        let mut documents = BTreeMap::new();
+        if !fix.apply(workspace, &mut documents)? {
+            warn_user!("The selected fix had no effect on the workspace; select another");
+            continue;
+        }
+        for (path, document) in documents {
+            fs_err::write(&path, document.to_string())?;
+            writeln!(printer.stderr(), "Updated: {}", path.user_display().cyan())?;
+        }
+        return Ok(true);
+    }
+}
+
+/// Return the editable `pyproject.toml` at the given path, reading it if necessary.
+fn document<'a>(
+    documents: &'a mut BTreeMap<PathBuf, PyProjectTomlMut>,
+    path: &Path,
+) -> Result<&'a mut PyProjectTomlMut> {
+    if !documents.contains_key(path) {
+        let content = fs_err::read_to_string(path)?;
+        let document = PyProjectTomlMut::from_toml(&content, DependencyTarget::PyProjectToml)
+            .with_context(|| format!("Failed to parse `{}`", path.user_display()))?;
+        documents.insert(path.to_path_buf(), document);
+    }
+    Ok(documents.get_mut(path).expect("document was inserted"))
+}
@@ -0,0 +1,103 @@
+use std::fmt::{Display, Formatter};
+
+use jiff::{SignedDuration, Timestamp};
+
+use uv_normalize::PackageName;
+use uv_pypi_types::ProjectStatus;
+use uv_workspace::pyproject::MaintenancePolicy;
+use uv_workspace::Workspace;
+
+use crate::commands::pip::latest::Latest;
+
+/// The number of days without a release after which a project is considered unmaintained, by
+/// default.
+const DEFAULT_STALE_AFTER: u64 = 730;
+
+/// The checks to perform for dependencies that are no longer maintained, as configured in
+/// `tool.uv.maintenance`.
+#[derive(Debug)]
+pub(crate) struct MaintenanceChecks<'env> {
+    /// Whether to warn about unmaintained dependencies, or to fail.
+    policy: MaintenancePolicy,
+    /// The time without a release after which a project is considered unmaintained.
+    stale_after: SignedDuration,
+    /// The packages to exclude from the checks.
+    ignore: &'env [PackageName],
+    /// The time against which to measure the age of the last release.
+    now: Timestamp,
+}
+
+impl<'env> MaintenanceChecks<'env> {
+    /// Read the checks from the workspace root, falling back to the defaults if the workspace
+    /// doesn't configure any.
+    pub(crate) fn from_workspace(workspace: &'env Workspace) -> Self {
+        let maintenance = workspace.maintenance();
+        let stale_after = maintenance
+            .and_then(|maintenance| maintenance.stale_after)
+            .unwrap_or(DEFAULT_STALE_AFTER);
+        let stale_after = i64::try_from(stale_after)
+            .unwrap_or(i64::MAX)
+            .min(i64::MAX / 86_400);
+        Self {
+            policy: maintenance
+                .map(|maintenance| maintenance.policy)
+                .unwrap_or_default(),
+            stale_after: SignedDuration::from_hours(stale_after * 24),
+            ignore: maintenance
+                .map(|maintenance| maintenance.ignore.as_slice())
+                .unwrap_or_default(),
+            now: Timestamp::now(),
+        }
+    }
+
+    /// Override the policy for reporting unmaintained dependencies.
+    #[must_use]
+    pub(crate) fn with_policy(mut self, policy: MaintenancePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the policy for reporting unmaintained dependencies.
+    pub(crate) fn policy(&self) -> MaintenancePolicy {
+        self.policy
+    }
+
+    /// Determine whether the given package is unmaintained, based on the index's view of the
+    /// project.
+    pub(crate) fn check(&self, name: &PackageName, latest: &Latest) -> Option<Unmaintained> {
+        if self.ignore.contains(name) {
+            return None;
+        }
+        if latest.status.status.is_unmaintained() {
+            return Some(Unmaintained::Status(latest.status.clone()));
+        }
+        let last_upload = latest.last_upload?;
+        if self.now.duration_since(last_upload) > self.stale_after {
+            return Some(Unmaintained::Stale(last_upload));
+        }
+        None
+    }
+}
+
+/// The reason a dependency is considered unmaintained.
+#[derive(Debug, Clone)]
+pub(crate) enum Unmaintained {
+    /// The index marks the project as archived, deprecated, or quarantined.
+    Status(ProjectStatus),
+    /// The project hasn't published a release since the given time.
+    Stale(Timestamp),
+}
+
+impl Display for Unmaintained {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Status(ProjectStatus { status, reason }) => match reason {
+                Some(reason) => write!(f, "{status}: {reason}"),
+                None => write!(f, "{status}"),
+            },
+            Self::Stale(last_upload) => {
+                write!(f, "no release since {}", last_upload.strftime("%Y-%m-%d"))
+            }
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use std::fmt::Write;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
 use tracing::debug;
+use url::Url;
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
@@ -14,15 +17,19 @@ use uv_configuration::{
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
-    Index, Resolution, UnresolvedRequirement, UnresolvedRequirementSpecification,
+    Index, Resolution, UnresolvedRequirement, UnresolvedRequirementSpecification, VersionId,
 };
 use uv_fs::{Simplified, CWD};
 use uv_git::ResolvedRepositoryReference;
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::{ExtraName, GroupName, PackageName, DEV_DEPENDENCIES};
 use uv_pep440::{Version, VersionSpecifiers};
-use uv_pep508::MarkerTreeContents;
-use uv_pypi_types::{ConflictingGroupList, ConflictingGroups, Requirement};
+use uv_pep508::{MarkerTree, MarkerTreeContents};
+use uv_platform_tags::TagPreferences;
+use uv_pypi_types::{
+    redact_credentials, ConflictingGroupList, ConflictingGroups, HashDigest, Requirement,
+    SupportedEnvironments,
+};
 use uv_python::{
     EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonVariant, PythonVersionFile,
@@ -31,15 +38,15 @@ use uv_python::{
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
 use uv_resolver::{
-    FlatIndex, Lock, OptionsBuilder, PythonRequirement, RequiresPython, ResolutionGraph,
-    ResolverEnvironment,
+    FlatIndex, GroupResolution, IndexPatterns, Lock, OptionsBuilder, PythonRequirement,
+    RequiresPython, ResolutionGraph, ResolverEnvironment, Variants, Vendored,
 };
 use uv_scripts::Pep723Item;
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::dependency_groups::DependencyGroupError;
-use uv_workspace::pyproject::PyProjectToml;
+use uv_workspace::pyproject::{PyProjectToml, Source};
 use uv_workspace::{ProjectWorkspace, Workspace};
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
@@ -50,10 +57,18 @@ use crate::printer::Printer;
 use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings, ResolverSettingsRef};
 
 pub(crate) mod add;
+pub(crate) mod build_image;
+pub(crate) mod bundle;
+pub(crate) mod container;
 pub(crate) mod environment;
 pub(crate) mod export;
 pub(crate) mod init;
 pub(crate) mod lock;
+pub(crate) mod lock_report;
+pub(crate) mod lock_wizard;
+pub(crate) mod maintenance;
+pub(crate) mod pin;
+pub(crate) mod provenance;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
@@ -147,6 +162,15 @@ pub(crate) enum ProjectError {
     #[error("Default group `{0}` (from `tool.uv.default-groups`) is not defined in the project's `dependency-group` table")]
     MissingDefaultGroup(GroupName),
 
+    #[error("Vendored package `{0}` has an invalid alias (`{1}`); aliases must be valid Python module names")]
+    InvalidVendorAlias(PackageName, String),
+
+    #[error("Failed to install vendored package `{0}` as `{1}`")]
+    VendorInstall(PackageName, String, #[source] uv_install_wheel::Error),
+
+    #[error("Failed to install rebuild hook for `{0}`")]
+    RebuildHook(PackageName, #[source] uv_install_wheel::Error),
+
     #[error("Supported environments must be disjoint, but the following markers overlap: `{0}` and `{1}`.\n\n{hint}{colon} replace `{1}` with `{2}`.", hint = "hint".bold().cyan(), colon = ":".bold())]
     OverlappingMarkers(String, String, String),
 
@@ -156,6 +180,18 @@ pub(crate) enum ProjectError {
     #[error("Environment marker is empty")]
     EmptyEnvironment,
 
+    #[error("No environment named `{0}` is declared in `tool.uv.environments`{1}")]
+    MissingEnvironment(String, String),
+
+    #[error("Hash mismatch for `{0}` (from `{1}`)\n\nExpected:\n  {2}\n\nLocked:\n{3}")]
+    SourceHashMismatch(PackageName, String, HashDigest, String),
+
+    #[error("`{0}` is installed from {1}, but the lockfile requires `{0}` from `{2}`, and `--locked-indexes` was provided.\n\n{hint}{colon} To reinstall `{0}` from the locked index, pass `--reinstall-package {0}`", hint = "hint".bold().cyan(), colon = ":".bold())]
+    IndexMismatch(PackageName, String, String),
+
+    #[error("`{0}` has a hash pinned in `tool.uv.sources`, but the lockfile doesn't include `{0}` from `{1}`")]
+    SourceHashMissing(PackageName, String),
+
     #[error("Project virtual environment directory `{0}` cannot be used because {1}")]
     InvalidProjectEnvironmentDir(PathBuf, String),
 
@@ -198,6 +234,9 @@ pub(crate) enum ProjectError {
     #[error(transparent)]
     Tool(#[from] uv_tool::Error),
 
+    #[error(transparent)]
+    Plugin(#[from] uv_plugin::Error),
+
     #[error(transparent)]
     Name(#[from] uv_normalize::InvalidNameError),
 
@@ -217,11 +256,42 @@ pub(crate) enum ProjectError {
     Anyhow(#[from] anyhow::Error),
 }
 
+/// Return the marker for the named environment in the [`Workspace`]'s `tool.uv.environments`.
+pub(crate) fn named_environment<'a>(
+    workspace: &'a Workspace,
+    name: &str,
+) -> Result<&'a MarkerTree, ProjectError> {
+    let environments = workspace.environments();
+    if let Some(marker) = environments.and_then(|environments| environments.get(name)) {
+        return Ok(marker);
+    }
+
+    let names = environments
+        .into_iter()
+        .flat_map(SupportedEnvironments::names)
+        .map(|name| format!("`{name}`"))
+        .join(", ");
+    let hint = if names.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "\n\n{}{} the declared environments are: {names}",
+            "hint".bold().cyan(),
+            ":".bold()
+        )
+    };
+    Err(ProjectError::MissingEnvironment(name.to_string(), hint))
+}
+
 /// Compute the `Requires-Python` bound for the [`Workspace`].
 ///
 /// For a [`Workspace`] with multiple packages, the `Requires-Python` bound is the union of the
 /// `Requires-Python` bounds of all the packages.
 pub(crate) fn find_requires_python(workspace: &Workspace) -> Option<RequiresPython> {
+    // For a workspace derived from a PEP 723 script, use the script's `requires-python`.
+    if let Some(requires_python) = workspace.script_requires_python() {
+        return Some(RequiresPython::from_specifiers(requires_python));
+    }
     RequiresPython::intersection(workspace.packages().values().filter_map(|member| {
         member
             .pyproject_toml()
@@ -802,6 +872,7 @@ pub(crate) async fn resolve_names(
     state: &SharedState,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -835,8 +906,11 @@ pub(crate) async fn resolve_names(
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        max_backtracks: _,
         link_mode,
         compile_bytecode: _,
+        invalidation_mode: _,
+        script_launcher: _,
         sources,
         upgrade: _,
         reinstall: _,
@@ -904,6 +978,7 @@ pub(crate) async fn resolve_names(
         LowerBound::Allow,
         *sources,
         concurrency,
+        tag_preferences,
     );
 
     // Resolve the unnamed requirements.
@@ -927,6 +1002,8 @@ pub(crate) struct EnvironmentSpecification<'lock> {
     requirements: RequirementsSpecification,
     /// The lockfile from which to extract preferences.
     lock: Option<&'lock Lock>,
+    /// The hash policy to apply when resolving the requirements.
+    hasher: HashStrategy,
 }
 
 impl From<RequirementsSpecification> for EnvironmentSpecification<'_> {
@@ -934,6 +1011,7 @@ impl From<RequirementsSpecification> for EnvironmentSpecification<'_> {
         Self {
             requirements,
             lock: None,
+            hasher: HashStrategy::default(),
         }
     }
 }
@@ -943,6 +1021,11 @@ impl<'lock> EnvironmentSpecification<'lock> {
     pub(crate) fn with_lock(self, lock: Option<&'lock Lock>) -> Self {
         Self { lock, ..self }
     }
+
+    #[must_use]
+    pub(crate) fn with_hasher(self, hasher: HashStrategy) -> Self {
+        Self { hasher, ..self }
+    }
 }
 
 /// Run dependency resolution for an interpreter, returning the [`ResolutionGraph`].
@@ -954,6 +1037,7 @@ pub(crate) async fn resolve_environment<'a>(
     logger: Box<dyn ResolveLogger>,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -972,6 +1056,7 @@ pub(crate) async fn resolve_environment<'a>(
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        max_backtracks,
         link_mode,
         upgrade: _,
         build_options,
@@ -989,7 +1074,7 @@ pub(crate) async fn resolve_environment<'a>(
     } = spec.requirements;
 
     // Determine the tags, markers, and interpreter to use for resolution.
-    let tags = interpreter.tags()?;
+    let tags = interpreter.tags(tag_preferences)?;
     let marker_env = interpreter.resolver_marker_environment();
     let python_requirement = PythonRequirement::from_interpreter(interpreter);
 
@@ -1028,6 +1113,7 @@ pub(crate) async fn resolve_environment<'a>(
         .resolution_mode(resolution)
         .prerelease_mode(prerelease)
         .exclude_newer(exclude_newer)
+        .max_backtracks(max_backtracks)
         .index_strategy(index_strategy)
         .build();
 
@@ -1035,7 +1121,7 @@ pub(crate) async fn resolve_environment<'a>(
     // optional on the downstream APIs.
     let dev = Vec::default();
     let extras = ExtrasSpecification::default();
-    let hasher = HashStrategy::default();
+    let hasher = spec.hasher;
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
 
@@ -1062,7 +1148,7 @@ pub(crate) async fn resolve_environment<'a>(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, build_options)
     };
 
     // Create a build dispatch.
@@ -1088,6 +1174,7 @@ pub(crate) async fn resolve_environment<'a>(
         LowerBound::Allow,
         sources,
         concurrency,
+        tag_preferences,
     );
 
     // Resolve the requirements.
@@ -1105,10 +1192,14 @@ pub(crate) async fn resolve_environment<'a>(
         &hasher,
         &reinstall,
         &upgrade,
-        Some(tags),
+        Some(&tags),
         ResolverEnvironment::specific(marker_env),
         python_requirement,
         ConflictingGroupList::empty(),
+        Variants::default(),
+        GroupResolution::default(),
+        Vendored::default(),
+        IndexPatterns::default(),
         &client,
         &flat_index,
         &state.index,
@@ -1130,6 +1221,7 @@ pub(crate) async fn sync_environment(
     logger: Box<dyn InstallLogger>,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -1146,6 +1238,8 @@ pub(crate) async fn sync_environment(
         exclude_newer,
         link_mode,
         compile_bytecode,
+        invalidation_mode,
+        script_launcher,
         reinstall,
         build_options,
         sources,
@@ -1155,7 +1249,7 @@ pub(crate) async fn sync_environment(
 
     // Determine the markers tags to use for resolution.
     let interpreter = venv.interpreter();
-    let tags = venv.interpreter().tags()?;
+    let tags = venv.interpreter().tags(tag_preferences)?;
 
     // Add all authenticated sources to the cache.
     for index in index_locations.allowed_indexes() {
@@ -1189,7 +1283,7 @@ pub(crate) async fn sync_environment(
     // optional on the downstream APIs.
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
-    let dry_run = false;
+    let dry_run = pip::operations::DryRun::Disabled;
     let hasher = HashStrategy::default();
 
     // Resolve the flat indexes from `--find-links`.
@@ -1198,7 +1292,7 @@ pub(crate) async fn sync_environment(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, build_options)
     };
 
     // Create a build dispatch.
@@ -1224,6 +1318,7 @@ pub(crate) async fn sync_environment(
         LowerBound::Allow,
         sources,
         concurrency,
+        tag_preferences,
     );
 
     // Sync the environment.
@@ -1235,10 +1330,12 @@ pub(crate) async fn sync_environment(
         build_options,
         link_mode,
         compile_bytecode,
+        invalidation_mode,
+        script_launcher,
         index_locations,
         config_setting,
         &hasher,
-        tags,
+        &tags,
         &client,
         &state.in_flight,
         concurrency,
@@ -1283,6 +1380,7 @@ pub(crate) async fn update_environment(
     install: Box<dyn InstallLogger>,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -1301,8 +1399,11 @@ pub(crate) async fn update_environment(
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        max_backtracks,
         link_mode,
         compile_bytecode,
+        invalidation_mode,
+        script_launcher,
         sources,
         upgrade,
         reinstall,
@@ -1382,6 +1483,7 @@ pub(crate) async fn update_environment(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .exclude_newer(*exclude_newer)
+        .max_backtracks(*max_backtracks)
         .index_strategy(*index_strategy)
         .build();
 
@@ -1390,13 +1492,13 @@ pub(crate) async fn update_environment(
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
     let dev = Vec::default();
-    let dry_run = false;
+    let dry_run = pip::operations::DryRun::Disabled;
     let extras = ExtrasSpecification::default();
     let hasher = HashStrategy::default();
     let preferences = Vec::default();
 
     // Determine the tags to use for resolution.
-    let tags = venv.interpreter().tags()?;
+    let tags = venv.interpreter().tags(tag_preferences)?;
     let python_requirement = PythonRequirement::from_interpreter(interpreter);
 
     // Resolve the flat indexes from `--find-links`.
@@ -1405,7 +1507,7 @@ pub(crate) async fn update_environment(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, build_options)
     };
 
     // Create a build dispatch.
@@ -1431,6 +1533,7 @@ pub(crate) async fn update_environment(
         LowerBound::Allow,
         *sources,
         concurrency,
+        tag_preferences,
     );
 
     // Resolve the requirements.
@@ -1448,10 +1551,14 @@ pub(crate) async fn update_environment(
         &hasher,
         reinstall,
         upgrade,
-        Some(tags),
+        Some(&tags),
         ResolverEnvironment::specific(marker_env.clone()),
         python_requirement,
         ConflictingGroupList::empty(),
+        Variants::default(),
+        GroupResolution::default(),
+        Vendored::default(),
+        IndexPatterns::default(),
         &client,
         &flat_index,
         &state.index,
@@ -1475,11 +1582,13 @@ pub(crate) async fn update_environment(
         reinstall,
         build_options,
         *link_mode,
-        *compile_bytecode,
+        compile_bytecode,
+        *invalidation_mode,
+        script_launcher,
         index_locations,
         config_setting,
         &hasher,
-        tags,
+        &tags,
         &client,
         &state.in_flight,
         concurrency,
@@ -1619,6 +1728,130 @@ pub(crate) fn default_dependency_groups(
     }
 }
 
+/// A hash pinned for a URL source in `tool.uv.sources`.
+struct PinnedSourceHash {
+    /// The name of the package that the source applies to.
+    name: PackageName,
+    /// The URL of the archive, without credentials.
+    url: Url,
+    /// The URL of the archive, as lowered into a requirement (i.e., with the subdirectory, if
+    /// any, in the fragment).
+    verbatim: Url,
+    /// The expected hash of the archive.
+    hash: HashDigest,
+}
+
+/// Collect the hashes pinned for URL sources in the workspace root and each member.
+fn pinned_source_hashes(workspace: &Workspace) -> Vec<PinnedSourceHash> {
+    let sources = std::iter::once(workspace.sources()).chain(
+        workspace.packages().values().filter_map(|member| {
+            member
+                .pyproject_toml()
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.sources.as_ref())
+        }),
+    );
+
+    let mut pinned = Vec::new();
+    for sources in sources {
+        for (name, sources) in sources.inner() {
+            for source in sources.iter() {
+                let Source::Url {
+                    url,
+                    subdirectory,
+                    hash: Some(hash),
+                    ..
+                } = source
+                else {
+                    continue;
+                };
+
+                // Mirror the lowering of URL sources, which stores the subdirectory in the
+                // fragment of the verbatim URL.
+                let mut verbatim = url.clone();
+                if let Some(subdirectory) = subdirectory
+                    .as_ref()
+                    .map(|subdirectory| PathBuf::from(subdirectory.clone()))
+                {
+                    verbatim.set_fragment(subdirectory.to_str());
+                }
+
+                let mut url = url.clone();
+                url.set_fragment(None);
+                redact_credentials(&mut url);
+
+                pinned.push(PinnedSourceHash {
+                    name: name.clone(),
+                    url,
+                    verbatim,
+                    hash: hash.clone(),
+                });
+            }
+        }
+    }
+    pinned
+}
+
+/// Return the [`HashStrategy`] to use when locking the [`Workspace`].
+///
+/// Hashes are generated for every archive, and archives for URL sources with a hash pinned in
+/// `tool.uv.sources` are validated against it when they're downloaded, before they're built.
+pub(crate) fn lock_hasher(workspace: &Workspace) -> HashStrategy {
+    let pinned = pinned_source_hashes(workspace);
+    if pinned.is_empty() {
+        return HashStrategy::Generate;
+    }
+    let mut hashes = FxHashMap::<VersionId, Vec<HashDigest>>::default();
+    for PinnedSourceHash { verbatim, hash, .. } in pinned {
+        hashes
+            .entry(VersionId::from_url(&verbatim))
+            .or_default()
+            .push(hash);
+    }
+    HashStrategy::GenerateVerify(Arc::new(hashes))
+}
+
+/// Verify that the [`Lock`] matches the hashes pinned for URL sources in `tool.uv.sources`.
+///
+/// Every pinned source must be present in the lockfile, at the pinned URL, with the pinned hash.
+#[allow(clippy::result_large_err)]
+pub(crate) fn verify_source_hashes(workspace: &Workspace, lock: &Lock) -> Result<(), ProjectError> {
+    for PinnedSourceHash {
+        name, url, hash, ..
+    } in pinned_source_hashes(workspace)
+    {
+        let package = lock.packages().iter().find(|package| {
+            package.name() == &name
+                && package.direct_url().is_some_and(|mut locked| {
+                    redact_credentials(&mut locked);
+                    locked == url
+                })
+        });
+        let Some(package) = package else {
+            return Err(ProjectError::SourceHashMissing(name, url.to_string()));
+        };
+
+        let hashes = package.hashes();
+        if !hashes.contains(&hash) {
+            let locked = if hashes.is_empty() {
+                "  (none)".to_string()
+            } else {
+                hashes.iter().map(|hash| format!("  {hash}")).join("\n")
+            };
+            return Err(ProjectError::SourceHashMismatch(
+                name,
+                url.to_string(),
+                hash,
+                locked,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 /// Warn if the user provides (e.g.) an `--index-url` in a requirements file.
 fn warn_on_requirements_txt_setting(
     spec: &RequirementsSpecification,
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_fs::Simplified;
+use uv_pep440::{Operator, Version};
+use uv_pep508::{PackageName, Requirement, VersionOrUrl};
+use uv_pypi_types::VerbatimParsedUrl;
+use uv_settings::{remove_pin, set_pin, user_pins_path};
+use uv_workspace::{DiscoveryOptions, Workspace};
+
+use crate::commands::{project, ExitStatus};
+use crate::printer::Printer;
+
+/// Pin one or more packages, such that they can't be upgraded past the given version.
+pub(crate) async fn pin_add(
+    project_dir: &Path,
+    packages: Vec<Requirement<VerbatimParsedUrl>>,
+    global: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let (workspace, path) = if global {
+        let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default())
+            .await
+            .ok();
+        (workspace, global_pins_file()?)
+    } else {
+        let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+        let path = project_pins_file(&workspace);
+        (Some(workspace), path)
+    };
+
+    let mut lock = None;
+    for requirement in packages {
+        let version = match requirement.version_or_url {
+            None => None,
+            Some(VersionOrUrl::VersionSpecifier(specifiers)) if specifiers.is_empty() => None,
+            Some(VersionOrUrl::VersionSpecifier(specifiers)) => match &*specifiers {
+                [specifier] if *specifier.operator() == Operator::Equal => {
+                    Some(specifier.version().clone())
+                }
+                _ => bail!(
+                    "Pins must provide an exact version (e.g., `{}==1.0.0`), or none at all, but found: `{specifiers}`",
+                    requirement.name
+                ),
+            },
+            Some(VersionOrUrl::Url(_)) => bail!(
+                "Pins must provide an exact version (e.g., `{}==1.0.0`), not a URL",
+                requirement.name
+            ),
+        };
+
+        // If no version was provided, pin the package to its version in the lockfile.
+        let version = if let Some(version) = version {
+            version
+        } else {
+            let Some(workspace) = workspace.as_ref() else {
+                bail!(
+                    "No project found; provide a version to pin `{}` (e.g., `{}==1.0.0`)",
+                    requirement.name,
+                    requirement.name
+                );
+            };
+            if lock.is_none() {
+                lock = Some(project::lock::read(workspace).await?);
+            }
+            let Some(version) = lock
+                .iter()
+                .flatten()
+                .flat_map(|lock| lock.packages())
+                .filter(|package| package.name() == &requirement.name)
+                .map(|package| package.version())
+                .max()
+            else {
+                bail!(
+                    "`{}` is not in the lockfile; provide a version to pin (e.g., `{}==1.0.0`)",
+                    requirement.name,
+                    requirement.name
+                );
+            };
+            version.clone()
+        };
+
+        set_pin(&path, &requirement.name, &version)?;
+
+        writeln!(
+            printer.stderr(),
+            "Pinned `{}` in: {}",
+            format!("{}=={version}", requirement.name).cyan(),
+            path.user_display().bold()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove the pins for one or more packages.
+pub(crate) async fn pin_remove(
+    project_dir: &Path,
+    packages: Vec<PackageName>,
+    global: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let path = if global {
+        global_pins_file()?
+    } else {
+        let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+        project_pins_file(&workspace)
+    };
+
+    for package in packages {
+        if !remove_pin(&path, &package)? {
+            bail!(
+                "`{}` is not pinned in: {}",
+                package.cyan(),
+                path.user_display().bold()
+            );
+        }
+
+        writeln!(
+            printer.stderr(),
+            "Unpinned `{}` in: {}",
+            package.cyan(),
+            path.user_display().bold()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// List the pinned packages.
+pub(crate) fn pin_list(
+    pins: &BTreeMap<PackageName, Version>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if pins.is_empty() {
+        writeln!(printer.stderr(), "No packages are pinned")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for (name, version) in pins {
+        writeln!(printer.stdout(), "{name}=={version}")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the file in which to store pins for the workspace, i.e., the `uv.toml` at the workspace
+/// root, if it exists, or the root `pyproject.toml` otherwise.
+fn project_pins_file(workspace: &Workspace) -> PathBuf {
+    let uv_toml = workspace.install_path().join("uv.toml");
+    if uv_toml.is_file() {
+        uv_toml
+    } else {
+        workspace.install_path().join("pyproject.toml")
+    }
+}
+
+/// Return the global pins file.
+fn global_pins_file() -> Result<PathBuf> {
+    user_pins_path().context("Unable to determine the user configuration directory")
+}
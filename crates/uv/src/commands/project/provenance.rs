@@ -0,0 +1,362 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+
+use futures::{stream, StreamExt, TryStreamExt};
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+
+use uv_cache::Cache;
+use uv_cache_key::CacheKeyHasher;
+use uv_client::{Connectivity, RegistryClient, RegistryClientBuilder, VersionFiles};
+use uv_configuration::{Concurrency, TrustedHost};
+use uv_distribution_types::{IndexCapabilities, IndexUrl};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::Yanked;
+use uv_python::Interpreter;
+use uv_resolver::{ExcludeNewer, Lock};
+use uv_workspace::Workspace;
+
+use crate::settings::ResolverSettingsRef;
+
+/// The name of the file, alongside the `uv.lock`, that records the provenance of the lockfile.
+pub(crate) const PROVENANCE_FILENAME: &str = "uv.provenance.toml";
+
+/// The inputs that influenced a resolution, as recorded by `uv lock --provenance`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct Provenance {
+    /// The version of uv that performed the resolution.
+    uv_version: String,
+    /// The time at which the provenance was recorded. Not considered when detecting drift.
+    recorded_at: Timestamp,
+    /// The `exclude-newer` cutoff applied to the resolution, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_newer: Option<String>,
+    /// The resolution strategy.
+    resolution: String,
+    /// The pre-release strategy.
+    prerelease: String,
+    /// The `requires-python` of the lockfile.
+    requires_python: String,
+    /// A digest of the remaining resolver configuration, e.g., the index strategy, build options,
+    /// and config settings.
+    config_digest: String,
+    /// The interpreter that performed the resolution.
+    environment: Environment,
+    /// A snapshot of each index from which packages were locked.
+    #[serde(default, rename = "index", skip_serializing_if = "Vec::is_empty")]
+    indexes: Vec<IndexSnapshot>,
+}
+
+/// The interpreter that performed the resolution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Environment {
+    python: String,
+    implementation: String,
+    sys_platform: String,
+    platform_machine: String,
+}
+
+/// A snapshot of the files an index offered for the locked packages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct IndexSnapshot {
+    /// The URL of the index, with any credentials redacted.
+    url: String,
+    /// The number of locked packages from the index.
+    packages: usize,
+    /// A digest of the files available for each locked package, along with their yanked status.
+    digest: String,
+}
+
+/// An input to the resolution that has changed since the provenance was recorded.
+#[derive(Debug)]
+pub(crate) struct Drift {
+    /// The name of the input, as it appears in the provenance file.
+    field: String,
+    /// The recorded value, if the input was recorded.
+    recorded: Option<String>,
+    /// The current value, if the input is still present.
+    current: Option<String>,
+}
+
+impl Display for Drift {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.recorded, &self.current) {
+            (Some(recorded), Some(current)) => {
+                write!(
+                    f,
+                    "`{}` changed from `{recorded}` to `{current}`",
+                    self.field
+                )
+            }
+            (Some(recorded), None) => write!(f, "`{}` (`{recorded}`) was removed", self.field),
+            (None, Some(current)) => write!(f, "`{}` (`{current}`) was added", self.field),
+            (None, None) => write!(f, "`{}` changed", self.field),
+        }
+    }
+}
+
+impl Provenance {
+    /// Record the current inputs to the resolution of the given lockfile.
+    pub(crate) async fn from_lock(
+        workspace: &Workspace,
+        lock: &Lock,
+        interpreter: &Interpreter,
+        settings: ResolverSettingsRef<'_>,
+        connectivity: Connectivity,
+        concurrency: Concurrency,
+        native_tls: bool,
+        allow_insecure_host: &[TrustedHost],
+        cache: &Cache,
+    ) -> anyhow::Result<Self> {
+        let markers = interpreter.markers();
+        let environment = Environment {
+            python: interpreter.python_full_version().to_string(),
+            implementation: interpreter.implementation_name().to_string(),
+            sys_platform: markers.sys_platform().to_string(),
+            platform_machine: markers.platform_machine().to_string(),
+        };
+
+        // Initialize the registry client.
+        let client = RegistryClientBuilder::new(cache.clone())
+            .native_tls(native_tls)
+            .connectivity(connectivity)
+            .keyring(settings.keyring_provider)
+            .allow_insecure_host(allow_insecure_host.to_vec())
+            .build();
+
+        // Group the locked registry packages by index.
+        let mut packages_by_index: BTreeMap<IndexUrl, Vec<&PackageName>> = BTreeMap::new();
+        for package in lock.packages() {
+            if let Some(index) = package.index(workspace.install_path())? {
+                packages_by_index
+                    .entry(index)
+                    .or_default()
+                    .push(package.name());
+            }
+        }
+
+        let mut indexes = Vec::with_capacity(packages_by_index.len());
+        for (index, mut packages) in packages_by_index {
+            packages.sort_unstable();
+            packages.dedup();
+            indexes.push(
+                snapshot(
+                    &client,
+                    &index,
+                    &packages,
+                    lock.exclude_newer(),
+                    concurrency,
+                )
+                .await?,
+            );
+        }
+
+        Ok(Self {
+            uv_version: uv_version::version().to_string(),
+            recorded_at: Timestamp::now(),
+            exclude_newer: lock
+                .exclude_newer()
+                .map(|exclude_newer| exclude_newer.to_string()),
+            resolution: lock.resolution_mode().to_string(),
+            prerelease: lock.prerelease_mode().to_string(),
+            requires_python: lock.requires_python().to_string(),
+            config_digest: config_digest(settings),
+            environment,
+            indexes,
+        })
+    }
+
+    /// Read the recorded provenance from the workspace root, if it exists.
+    pub(crate) fn read(workspace: &Workspace) -> anyhow::Result<Option<Self>> {
+        let path = Self::path(workspace);
+        match fs_err::read_to_string(&path) {
+            Ok(contents) => Ok(Some(toml::from_str(&contents)?)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Write the provenance to the workspace root.
+    pub(crate) fn write(&self, workspace: &Workspace) -> anyhow::Result<()> {
+        fs_err::write(Self::path(workspace), toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// The path to the provenance file for the given workspace.
+    pub(crate) fn path(workspace: &Workspace) -> PathBuf {
+        workspace.install_path().join(PROVENANCE_FILENAME)
+    }
+
+    /// Return the inputs that differ between the recorded provenance and the current inputs.
+    pub(crate) fn drift(&self, current: &Self) -> Vec<Drift> {
+        let mut drift = Vec::new();
+
+        let mut compare = |field: &str, recorded: Option<&str>, current: Option<&str>| {
+            if recorded != current {
+                drift.push(Drift {
+                    field: field.to_string(),
+                    recorded: recorded.map(ToString::to_string),
+                    current: current.map(ToString::to_string),
+                });
+            }
+        };
+
+        compare(
+            "uv-version",
+            Some(&self.uv_version),
+            Some(&current.uv_version),
+        );
+        compare(
+            "exclude-newer",
+            self.exclude_newer.as_deref(),
+            current.exclude_newer.as_deref(),
+        );
+        compare(
+            "resolution",
+            Some(&self.resolution),
+            Some(&current.resolution),
+        );
+        compare(
+            "prerelease",
+            Some(&self.prerelease),
+            Some(&current.prerelease),
+        );
+        compare(
+            "requires-python",
+            Some(&self.requires_python),
+            Some(&current.requires_python),
+        );
+        compare(
+            "config-digest",
+            Some(&self.config_digest),
+            Some(&current.config_digest),
+        );
+        compare(
+            "environment.python",
+            Some(&self.environment.python),
+            Some(&current.environment.python),
+        );
+        compare(
+            "environment.implementation",
+            Some(&self.environment.implementation),
+            Some(&current.environment.implementation),
+        );
+        compare(
+            "environment.sys-platform",
+            Some(&self.environment.sys_platform),
+            Some(&current.environment.sys_platform),
+        );
+        compare(
+            "environment.platform-machine",
+            Some(&self.environment.platform_machine),
+            Some(&current.environment.platform_machine),
+        );
+
+        // Compare the snapshot of each index, by URL.
+        let recorded = self
+            .indexes
+            .iter()
+            .map(|index| (index.url.as_str(), index))
+            .collect::<BTreeMap<_, _>>();
+        let current = current
+            .indexes
+            .iter()
+            .map(|index| (index.url.as_str(), index))
+            .collect::<BTreeMap<_, _>>();
+        for (url, recorded) in &recorded {
+            let current = current.get(url);
+            compare(
+                &format!("index.{url}"),
+                Some(&recorded.digest),
+                current.map(|current| current.digest.as_str()),
+            );
+        }
+        for (url, current) in &current {
+            if !recorded.contains_key(url) {
+                compare(&format!("index.{url}"), None, Some(&current.digest));
+            }
+        }
+
+        drift
+    }
+}
+
+/// Compute a snapshot of the files the given index offers for the given packages.
+async fn snapshot(
+    client: &RegistryClient,
+    index: &IndexUrl,
+    packages: &[&PackageName],
+    exclude_newer: Option<ExcludeNewer>,
+    concurrency: Concurrency,
+) -> anyhow::Result<IndexSnapshot> {
+    let capabilities = IndexCapabilities::default();
+
+    let mut lines = stream::iter(packages)
+        .map(|package| {
+            let capabilities = &capabilities;
+            async move {
+                let archives = client.simple(package, Some(index), capabilities).await?;
+                let mut lines = Vec::new();
+                for (_, archive) in archives {
+                    for datum in archive.iter() {
+                        let version =
+                            rkyv::deserialize::<Version, rkyv::rancor::Error>(&datum.version)
+                                .expect("archived version always deserializes");
+                        let files =
+                            rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                                .expect("archived version files always deserializes");
+                        for (_, file) in files.all() {
+                            // Omit any files that the resolution couldn't have considered.
+                            if let Some(exclude_newer) = exclude_newer {
+                                if file.upload_time_utc_ms.is_some_and(|upload_time| {
+                                    upload_time >= exclude_newer.timestamp_millis()
+                                }) {
+                                    continue;
+                                }
+                            }
+                            let yanked = file.yanked.as_ref().is_some_and(Yanked::is_yanked);
+                            lines.push(format!("{package} {version} {} {yanked}", file.filename));
+                        }
+                    }
+                }
+                Ok::<_, uv_client::Error>(lines)
+            }
+        })
+        .buffer_unordered(concurrency.downloads)
+        .try_concat()
+        .await?;
+    lines.sort_unstable();
+
+    let mut hasher = CacheKeyHasher::new();
+    for line in &lines {
+        line.hash(&mut hasher);
+    }
+
+    Ok(IndexSnapshot {
+        url: index.redacted().to_string(),
+        packages: packages.len(),
+        digest: format!("{:016x}", hasher.finish()),
+    })
+}
+
+/// Compute a digest of the resolver settings that aren't otherwise recorded.
+fn config_digest(settings: ResolverSettingsRef<'_>) -> String {
+    let mut dependency_metadata = settings.dependency_metadata.values().collect::<Vec<_>>();
+    dependency_metadata.sort_unstable();
+
+    let mut hasher = CacheKeyHasher::new();
+    format!("{:?}", settings.index_strategy).hash(&mut hasher);
+    format!("{dependency_metadata:?}").hash(&mut hasher);
+    format!("{:?}", settings.config_setting).hash(&mut hasher);
+    settings.no_build_isolation.hash(&mut hasher);
+    format!("{:?}", settings.no_build_isolation_package).hash(&mut hasher);
+    format!("{:?}", settings.build_options).hash(&mut hasher);
+    format!("{:?}", settings.sources).hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
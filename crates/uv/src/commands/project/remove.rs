@@ -1,28 +1,39 @@
 use anyhow::{Context, Result};
+use std::collections::BTreeSet;
 use std::fmt::Write;
 use std::path::Path;
+use std::str::FromStr;
 use uv_settings::PythonInstallMirrors;
 
+use console::Term;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tracing::debug;
 use uv_cache::Cache;
 use uv_client::Connectivity;
 use uv_configuration::{
     Concurrency, DevGroupsManifest, EditableMode, ExtrasSpecification, InstallOptions, LowerBound,
     TrustedHost,
 };
+use uv_distribution_types::InstalledDist;
 use uv_fs::Simplified;
-use uv_normalize::DEV_DEPENDENCIES;
-use uv_pep508::PackageName;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_install_wheel::read_record_file;
+use uv_installer::SitePackages;
+use uv_normalize::{GroupName, DEV_DEPENDENCIES};
+use uv_pep508::{PackageName, Requirement};
+use uv_platform_tags::TagPreferences;
+use uv_pypi_types::VerbatimParsedUrl;
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_requirements::imports::ThirdPartyImports;
 use uv_resolver::InstallTarget;
 use uv_scripts::Pep723Script;
 use uv_warnings::{warn_user, warn_user_once};
-use uv_workspace::pyproject::DependencyType;
+use uv_workspace::pyproject::{DependencyGroupSpecifier, DependencyType};
 use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{DryRun, Modifications};
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::{default_dependency_groups, ProjectError};
 use crate::commands::{diagnostics, project, ExitStatus, SharedState};
@@ -36,7 +47,9 @@ pub(crate) async fn remove(
     locked: bool,
     frozen: bool,
     no_sync: bool,
-    packages: Vec<PackageName>,
+    mut packages: Vec<PackageName>,
+    unused: bool,
+    yes: bool,
     dependency_type: DependencyType,
     package: Option<PackageName>,
     python: Option<String>,
@@ -47,6 +60,7 @@ pub(crate) async fn remove(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
@@ -102,6 +116,17 @@ pub(crate) async fn remove(
         ),
     }?;
 
+    // If requested, propose the dependencies that aren't imported by the project's sources.
+    if unused {
+        let Target::Project(project) = &target else {
+            anyhow::bail!("`--unused` is not supported for Python scripts with inline metadata");
+        };
+        packages = unused_dependencies(project, &dependency_type, yes, cache, printer)?;
+        if packages.is_empty() {
+            return Ok(ExitStatus::Success);
+        }
+    }
+
     for package in packages {
         match dependency_type {
             DependencyType::Production => {
@@ -221,10 +246,12 @@ pub(crate) async fn remove(
         project.workspace(),
         settings.as_ref().into(),
         LowerBound::Allow,
+        false,
         &state,
         Box::new(DefaultResolveLogger),
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
@@ -274,10 +301,14 @@ pub(crate) async fn remove(
         EditableMode::Editable,
         install_options,
         Modifications::Exact,
+        DryRun::Disabled,
+        false,
+        None,
         settings.as_ref().into(),
         Box::new(DefaultInstallLogger),
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
@@ -297,6 +328,211 @@ pub(crate) async fn remove(
     Ok(ExitStatus::Success)
 }
 
+/// Scan the project's Python sources for imports, and return the dependencies of the given type
+/// that provide none of the imported modules.
+///
+/// Unless `yes` is set, the user is prompted to confirm the proposed dependencies; if they decline,
+/// or the terminal isn't interactive, no dependencies are returned.
+fn unused_dependencies(
+    project: &VirtualProject,
+    dependency_type: &DependencyType,
+    yes: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<Vec<PackageName>> {
+    let pyproject_toml = project.pyproject_toml();
+
+    // Collect the declared dependencies of the given type.
+    let group_requirements = |group: &GroupName| {
+        pyproject_toml
+            .dependency_groups
+            .as_ref()
+            .and_then(|groups| groups.get(group))
+            .into_iter()
+            .flatten()
+            .filter_map(|specifier| match specifier {
+                DependencyGroupSpecifier::Requirement(requirement) => Some(requirement),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    let requirements = match dependency_type {
+        DependencyType::Production => pyproject_toml
+            .project
+            .iter()
+            .flat_map(|project| project.dependencies.iter().flatten())
+            .collect(),
+        DependencyType::Optional(extra) => pyproject_toml
+            .project
+            .iter()
+            .filter_map(|project| project.optional_dependencies.as_ref())
+            .filter_map(|extras| extras.get(extra))
+            .flatten()
+            .collect(),
+        DependencyType::Dev => group_requirements(&DEV_DEPENDENCIES),
+        DependencyType::Group(group) => group_requirements(group),
+    };
+    let mut declared = requirements
+        .into_iter()
+        .map(|requirement| {
+            Requirement::<VerbatimParsedUrl>::from_str(requirement)
+                .map(|requirement| requirement.name)
+                .with_context(|| format!("Failed to parse requirement: `{requirement}`"))
+        })
+        .collect::<Result<BTreeSet<_>>>()?;
+    if matches!(dependency_type, DependencyType::Dev)
+        || matches!(dependency_type, DependencyType::Group(group) if *group == *DEV_DEPENDENCIES)
+    {
+        declared.extend(
+            pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.dev_dependencies.as_ref())
+                .into_iter()
+                .flatten()
+                .map(|requirement| requirement.name.clone()),
+        );
+    }
+
+    // Omit any dependencies that are used without being imported.
+    let ignored = pyproject_toml
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.ignore_unused_dependencies.as_ref())
+        .into_iter()
+        .flatten()
+        .collect::<FxHashSet<_>>();
+    declared.retain(|name| !ignored.contains(name));
+
+    let imports = ThirdPartyImports::scan(project.root()).with_context(|| {
+        format!(
+            "Failed to scan Python sources in: `{}`",
+            project.root().user_display()
+        )
+    })?;
+
+    // If the project environment exists, read the modules provided by each dependency from the
+    // installed distributions.
+    let site_packages = PythonEnvironment::from_root(project.workspace().venv(), cache)
+        .ok()
+        .and_then(|environment| SitePackages::from_environment(&environment).ok());
+    if site_packages.is_none() {
+        debug!("No project environment found; inferring the provided modules from package names");
+    }
+
+    let unused = declared
+        .into_iter()
+        .filter(|name| {
+            if imports.imports_package(name) {
+                return false;
+            }
+            let mut modules = FxHashSet::default();
+            modules.insert(name.as_dist_info_name().to_string());
+            for dist in site_packages
+                .iter()
+                .flat_map(|site_packages| site_packages.get_packages(name))
+            {
+                match installed_modules(dist) {
+                    Ok(installed) => modules.extend(installed),
+                    Err(err) => debug!("Failed to read the modules provided by `{name}`: {err}"),
+                }
+            }
+            !modules.iter().any(|module| imports.imports_module(module))
+        })
+        .collect::<Vec<_>>();
+
+    if unused.is_empty() {
+        writeln!(printer.stderr(), "No unused dependencies found")?;
+        return Ok(unused);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Found {} {} that {} not imported:",
+        unused.len(),
+        if unused.len() == 1 {
+            "dependency"
+        } else {
+            "dependencies"
+        },
+        if unused.len() == 1 { "is" } else { "are" }
+    )?;
+    for name in &unused {
+        writeln!(printer.stderr(), " {}", name.bold())?;
+    }
+
+    if yes {
+        return Ok(unused);
+    }
+
+    let term = Term::stderr();
+    if !term.is_term() {
+        writeln!(
+            printer.stderr(),
+            "{}{} Pass `--yes` to remove {}",
+            "hint".bold().cyan(),
+            ":".bold(),
+            if unused.len() == 1 { "it" } else { "them" }
+        )?;
+        return Ok(Vec::new());
+    }
+
+    let prompt = format!(
+        "Remove {} from the project?",
+        if unused.len() == 1 {
+            "this dependency"
+        } else {
+            "these dependencies"
+        }
+    );
+    if !uv_console::confirm(&prompt, &term, true)? {
+        return Ok(Vec::new());
+    }
+
+    Ok(unused)
+}
+
+/// Return the top-level modules provided by an installed distribution, as listed in its `RECORD`.
+fn installed_modules(dist: &InstalledDist) -> Result<FxHashSet<String>> {
+    let record = dist.path().join("RECORD");
+    if !record.is_file() {
+        return Ok(FxHashSet::default());
+    }
+    let entries = read_record_file(&mut fs_err::File::open(record)?)?;
+
+    let mut modules = FxHashSet::default();
+    for entry in entries {
+        // Skip any files outside of `site-packages` (e.g., scripts), along with the metadata.
+        if entry.path.starts_with("..") {
+            continue;
+        }
+        let (top_level, is_file) = match entry.path.split_once('/') {
+            Some((top_level, _)) => (top_level, false),
+            None => (entry.path.as_str(), true),
+        };
+        if top_level.ends_with(".dist-info")
+            || top_level.ends_with(".data")
+            || top_level == "__pycache__"
+        {
+            continue;
+        }
+        if is_file {
+            // Single-file modules, e.g., `six.py` or `_cffi_backend.cpython-312-darwin.so`.
+            let Some((stem, extension)) = top_level.split_once('.') else {
+                continue;
+            };
+            if matches!(extension.rsplit('.').next(), Some("py" | "so" | "pyd")) {
+                modules.insert(stem.to_string());
+            }
+        } else {
+            modules.insert(top_level.to_string());
+        }
+    }
+    Ok(modules)
+}
+
 /// Represents the destination where dependencies are added, either to a project or a script.
 #[derive(Debug)]
 enum Target {
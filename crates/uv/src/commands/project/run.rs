@@ -18,29 +18,36 @@ use uv_cli::ExternalCommand;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::{
     Concurrency, DevGroupsSpecification, EditableMode, ExtrasSpecification, GroupsSpecification,
-    InstallOptions, LowerBound, SourceStrategy, TrustedHost,
+    HashCheckingMode, InstallOptions, LowerBound, SourceStrategy, TrustedHost,
 };
 use uv_distribution::LoweredRequirement;
+use uv_distribution_types::Resolution;
 use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified};
 use uv_installer::{SatisfiesResult, SitePackages};
-use uv_normalize::PackageName;
+use uv_normalize::{PackageName, DEV_DEPENDENCIES};
+use uv_platform_tags::TagPreferences;
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVersionFile, VersionFileDiscoveryOptions,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
-use uv_resolver::{InstallTarget, Lock};
+use uv_resolver::{
+    AnnotationStyle, DisplayResolutionGraph, InstallTarget, Lock, ResolutionGraph,
+    ResolverEnvironment,
+};
 use uv_scripts::Pep723Item;
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
+use uv_types::HashStrategy;
 use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceError};
 
 use crate::commands::pip::loggers::{
-    DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
+    DefaultInstallLogger, DefaultResolveLogger, InstallLogger, ResolveLogger, SummaryInstallLogger,
+    SummaryResolveLogger,
 };
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{DryRun, Modifications};
 use crate::commands::project::environment::CachedEnvironment;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::{
@@ -58,7 +65,9 @@ pub(crate) async fn run(
     project_dir: &Path,
     script: Option<Pep723Item>,
     command: Option<RunCommand>,
-    requirements: Vec<RequirementsSource>,
+    mut requirements: Vec<RequirementsSource>,
+    with_lock: Option<PathBuf>,
+    with_overlay: Option<PathBuf>,
     show_resolution: bool,
     locked: bool,
     frozen: bool,
@@ -78,6 +87,7 @@ pub(crate) async fn run(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -85,6 +95,17 @@ pub(crate) async fn run(
     env_file: Vec<PathBuf>,
     no_env_file: bool,
 ) -> anyhow::Result<ExitStatus> {
+    // Restore the overlay, if provided, as if its requirements were passed via `--with-requirements`.
+    if let Some(with_overlay) = with_overlay.as_ref() {
+        requirements.push(RequirementsSource::from_requirements_file(
+            with_overlay.clone(),
+        ));
+    }
+
+    if with_lock.is_some() && requirements.is_empty() {
+        bail!("`--with-lock` requires additional requirements to be provided via `--with`, `--with-editable`, or `--with-requirements`");
+    }
+
     // These cases seem quite complex because (in theory) they should change the "current package".
     // Let's ban them entirely for now.
     for source in &requirements {
@@ -232,10 +253,102 @@ pub(crate) async fn run(
                 .to_owned(),
             Pep723Item::Stdin(..) | Pep723Item::Remote(..) => std::env::current_dir()?,
         };
+
+        // If the script has a lockfile (e.g., from `uv script lock`), use it.
+        let workspace = match &script {
+            Pep723Item::Script(script) => {
+                Some(script.to_workspace()?).filter(|workspace| workspace.lockfile().is_file())
+            }
+            Pep723Item::Stdin(..) | Pep723Item::Remote(..) => None,
+        };
+
         let script = script.into_metadata();
 
         // Install the script requirements, if necessary. Otherwise, use an isolated environment.
-        if let Some(dependencies) = script.dependencies {
+        if let Some(workspace) = workspace {
+            debug!(
+                "Using script lockfile at: `{}`",
+                workspace.lockfile().user_display()
+            );
+
+            // Update the lockfile, if necessary.
+            let lock = match project::lock::do_safe_lock(
+                LockMode::Write(&interpreter),
+                &workspace,
+                settings.as_ref().into(),
+                LowerBound::Allow,
+                false,
+                &state,
+                if show_resolution {
+                    Box::new(DefaultResolveLogger)
+                } else {
+                    Box::new(SummaryResolveLogger)
+                },
+                connectivity,
+                concurrency,
+                tag_preferences,
+                native_tls,
+                allow_insecure_host,
+                cache,
+                printer,
+            )
+            .await
+            {
+                Ok(result) => result.into_lock(),
+                Err(ProjectError::Operation(err)) => {
+                    return diagnostics::OperationDiagnostic::with_context("script")
+                        .report(err)
+                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            // The script's dependencies are locked as those of a (non-project) workspace root.
+            let target = InstallTarget::NonProjectWorkspace {
+                workspace: &workspace,
+                lock: &lock,
+            };
+            let resolution = target.to_resolution(
+                &interpreter.resolver_markers(),
+                &interpreter.tags(tag_preferences)?,
+                &ExtrasSpecification::None,
+                &DevGroupsSpecification::default().with_defaults(vec![DEV_DEPENDENCIES.clone()]),
+                &settings.build_options,
+                &InstallOptions::default(),
+            )?;
+
+            let result = CachedEnvironment::from_resolution(
+                &resolution,
+                interpreter,
+                &settings,
+                &state,
+                if show_resolution {
+                    Box::new(DefaultInstallLogger)
+                } else {
+                    Box::new(SummaryInstallLogger)
+                },
+                connectivity,
+                concurrency,
+                tag_preferences,
+                native_tls,
+                allow_insecure_host,
+                cache,
+                printer,
+            )
+            .await;
+
+            let environment = match result {
+                Ok(environment) => environment,
+                Err(ProjectError::Operation(err)) => {
+                    return diagnostics::OperationDiagnostic::with_context("script")
+                        .report(err)
+                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            Some(environment.into_interpreter())
+        } else if let Some(dependencies) = script.dependencies {
             // Collect any `tool.uv.index` from the script.
             let empty = Vec::default();
             let script_indexes = match settings.sources {
@@ -292,6 +405,7 @@ pub(crate) async fn run(
                 },
                 connectivity,
                 concurrency,
+                tag_preferences,
                 native_tls,
                 allow_insecure_host,
                 cache,
@@ -604,6 +718,7 @@ pub(crate) async fn run(
                     project.workspace(),
                     settings.as_ref().into(),
                     LowerBound::Allow,
+                    false,
                     &state,
                     if show_resolution {
                         Box::new(DefaultResolveLogger)
@@ -612,6 +727,7 @@ pub(crate) async fn run(
                     },
                     connectivity,
                     concurrency,
+                    tag_preferences,
                     native_tls,
                     allow_insecure_host,
                     cache,
@@ -660,6 +776,9 @@ pub(crate) async fn run(
                     editable,
                     install_options,
                     Modifications::Sufficient,
+                    DryRun::Disabled,
+                    false,
+                    None,
                     settings.as_ref().into(),
                     if show_resolution {
                         Box::new(DefaultInstallLogger)
@@ -668,6 +787,7 @@ pub(crate) async fn run(
                     },
                     connectivity,
                     concurrency,
+                    tag_preferences,
                     native_tls,
                     allow_insecure_host,
                     cache,
@@ -773,7 +893,11 @@ pub(crate) async fn run(
 
     // If necessary, create an environment for the ephemeral requirements or command.
     let temp_dir;
-    let ephemeral_env = if can_skip_ephemeral(spec.as_ref(), &base_interpreter, &settings) {
+    // When recording an overlay, always resolve the requirements, even if they're satisfied by the
+    // base environment.
+    let can_skip =
+        with_lock.is_none() && can_skip_ephemeral(spec.as_ref(), &base_interpreter, &settings);
+    let ephemeral_env = if can_skip {
         None
     } else {
         debug!("Creating ephemeral environment");
@@ -795,29 +919,95 @@ pub(crate) async fn run(
             Some(spec) => {
                 debug!("Syncing ephemeral requirements");
 
-                let result = CachedEnvironment::get_or_create(
-                    EnvironmentSpecification::from(spec).with_lock(lock.as_ref()),
-                    base_interpreter.clone(),
-                    &settings,
-                    &state,
-                    if show_resolution {
-                        Box::new(DefaultResolveLogger)
-                    } else {
-                        Box::new(SummaryResolveLogger)
-                    },
-                    if show_resolution {
-                        Box::new(DefaultInstallLogger)
-                    } else {
-                        Box::new(SummaryInstallLogger)
-                    },
-                    connectivity,
-                    concurrency,
-                    native_tls,
-                    allow_insecure_host,
-                    cache,
-                    printer,
-                )
-                .await;
+                // When restoring an overlay, require that every package matches its recorded
+                // hashes; when recording an overlay, generate the hashes to record.
+                let hasher = if with_overlay.is_some() {
+                    HashStrategy::from_requirements(
+                        spec.requirements
+                            .iter()
+                            .chain(spec.overrides.iter())
+                            .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+                        spec.constraints
+                            .iter()
+                            .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+                        Some(&base_interpreter.resolver_marker_environment()),
+                        HashCheckingMode::Require,
+                    )?
+                } else if with_lock.is_some() {
+                    HashStrategy::Generate
+                } else {
+                    HashStrategy::None
+                };
+                let spec = EnvironmentSpecification::from(spec)
+                    .with_lock(lock.as_ref())
+                    .with_hasher(hasher);
+
+                let resolve_logger: Box<dyn ResolveLogger> = if show_resolution {
+                    Box::new(DefaultResolveLogger)
+                } else {
+                    Box::new(SummaryResolveLogger)
+                };
+                let install_logger: Box<dyn InstallLogger> = if show_resolution {
+                    Box::new(DefaultInstallLogger)
+                } else {
+                    Box::new(SummaryInstallLogger)
+                };
+
+                let result = if let Some(with_lock) = with_lock.as_deref() {
+                    // Resolve the requirements, and record the resolution to the overlay file
+                    // prior to installing it.
+                    async {
+                        let graph = project::resolve_environment(
+                            spec,
+                            &base_interpreter,
+                            settings.as_ref().into(),
+                            &state,
+                            resolve_logger,
+                            connectivity,
+                            concurrency,
+                            tag_preferences,
+                            native_tls,
+                            allow_insecure_host,
+                            cache,
+                            printer,
+                        )
+                        .await?;
+                        write_overlay(with_lock, &graph, &base_interpreter)?;
+                        CachedEnvironment::from_resolution(
+                            &Resolution::from(graph),
+                            base_interpreter.clone(),
+                            &settings,
+                            &state,
+                            install_logger,
+                            connectivity,
+                            concurrency,
+                            tag_preferences,
+                            native_tls,
+                            allow_insecure_host,
+                            cache,
+                            printer,
+                        )
+                        .await
+                    }
+                    .await
+                } else {
+                    CachedEnvironment::get_or_create(
+                        spec,
+                        base_interpreter.clone(),
+                        &settings,
+                        &state,
+                        resolve_logger,
+                        install_logger,
+                        connectivity,
+                        concurrency,
+                        tag_preferences,
+                        native_tls,
+                        allow_insecure_host,
+                        cache,
+                        printer,
+                    )
+                    .await
+                };
 
                 let environment = match result {
                     Ok(resolution) => resolution,
@@ -1018,6 +1208,49 @@ fn terminate_process(child: &mut tokio::process::Child) -> anyhow::Result<()> {
     signal::kill(Pid::from_raw(pid.try_into()?), Signal::SIGTERM).context("Failed to send SIGTERM")
 }
 
+/// Write the resolved `--with` requirements to an overlay file, in `requirements.txt` format.
+///
+/// Every package is pinned to its resolved version, alongside its hashes, such that the overlay
+/// can be restored exactly via `--with-overlay`.
+fn write_overlay(
+    path: &Path,
+    graph: &ResolutionGraph,
+    interpreter: &Interpreter,
+) -> anyhow::Result<()> {
+    let resolver_env = ResolverEnvironment::specific(interpreter.resolver_marker_environment());
+
+    let mut contents = String::new();
+    writeln!(
+        contents,
+        "# This file was autogenerated by uv to record the requirements of an ephemeral environment."
+    )?;
+    writeln!(
+        contents,
+        "# To restore the environment, run: uv run --with-overlay {}",
+        path.user_display()
+    )?;
+    write!(
+        contents,
+        "{}",
+        DisplayResolutionGraph::new(
+            graph,
+            &resolver_env,
+            &[],
+            true,
+            false,
+            false,
+            false,
+            false,
+            AnnotationStyle::default(),
+        )
+    )?;
+
+    fs_err::write(path, contents)?;
+    debug!("Recorded ephemeral environment to: {}", path.user_display());
+
+    Ok(())
+}
+
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
 fn can_skip_ephemeral(
     spec: Option<&RequirementsSpecification>,
@@ -1,42 +1,59 @@
 use std::borrow::Cow;
-use std::path::Path;
+use std::fmt::Write;
+use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime};
 
 use anyhow::{Context, Result};
 use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::{debug, instrument};
 
 use uv_auth::store_credentials;
 use uv_cache::Cache;
-use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{Connectivity, FlatIndexClient, RegistryClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification, EditableMode,
-    ExtrasSpecification, HashCheckingMode, InstallOptions, LowerBound, TrustedHost,
+    BuildOptions, Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification,
+    EditableMode, ExtrasSpecification, HashCheckingMode, InstallOptions, LowerBound, Reinstall,
+    TargetTriple, TrustedHost,
 };
 use uv_dispatch::BuildDispatch;
-use uv_distribution_types::{DirectorySourceDist, Dist, Index, ResolvedDist, SourceDist};
-use uv_installer::SitePackages;
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{
+    BuiltDist, DirectorySourceDist, Dist, Index, InstalledDirectUrlDist, InstalledDist, Name,
+    Resolution, ResolvedDist, SourceDist,
+};
+use uv_fs::Simplified;
+use uv_install_wheel::ScriptLauncher;
+use uv_installer::{Preparer, SitePackages};
 use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::Version;
 use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
+use uv_platform_tags::{TagPreferences, Tags};
 use uv_pypi_types::{
     LenientRequirement, ParsedArchiveUrl, ParsedGitUrl, ParsedUrl, VerbatimParsedUrl,
 };
-use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
-use uv_resolver::{FlatIndex, InstallTarget};
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest, Target};
+use uv_resolver::{FlatIndex, InstallTarget, VendoredPackage};
 use uv_settings::PythonInstallMirrors;
-use uv_types::{BuildIsolation, HashStrategy};
+use uv_types::{BuildIsolation, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 use uv_workspace::pyproject::{DependencyGroupSpecifier, Source, Sources, ToolUvSources};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations;
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{DryRun, Modifications};
+use crate::commands::pip::{resolution_markers, resolution_tags};
 use crate::commands::project::lock::{do_safe_lock, LockMode};
 use crate::commands::project::{
-    default_dependency_groups, DependencyGroupsTarget, ProjectError, SharedState,
+    default_dependency_groups, verify_source_hashes, DependencyGroupsTarget, ProjectError,
+    ProjectInterpreter, SharedState,
 };
-use crate::commands::{diagnostics, project, ExitStatus};
+use crate::commands::{diagnostics, elapsed, project, ExitStatus};
 use crate::printer::Printer;
+use crate::progress::{self, ProgressEvent};
 use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings};
 
 /// Sync the project environment.
@@ -52,13 +69,19 @@ pub(crate) async fn sync(
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
+    dry_run: DryRun,
+    locked_indexes: bool,
+    refresh_scripts: bool,
+    target_dir: Option<PathBuf>,
     python: Option<String>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     settings: ResolverInstallerSettings,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
@@ -101,8 +124,20 @@ pub(crate) async fn sync(
         target.validate(&dev)?;
     }
 
-    // Determine the default groups to include.
-    let defaults = default_dependency_groups(project.pyproject_toml())?;
+    // Determine the default groups to include. When installing into a `--target` directory, only
+    // the runtime dependencies are included by default.
+    let defaults = if target_dir.is_some() {
+        Vec::new()
+    } else {
+        default_dependency_groups(project.pyproject_toml())?
+    };
+
+    // Packages in a `--target` directory can't reference the source tree.
+    let editable = if target_dir.is_some() {
+        EditableMode::NonEditable
+    } else {
+        editable
+    };
 
     // TODO(lucab): improve warning content
     // <https://github.com/astral-sh/uv/issues/7428>
@@ -112,21 +147,83 @@ pub(crate) async fn sync(
         warn_user!("Skipping installation of entry points (`project.scripts`) because this project is not packaged; to install entry points, set `tool.uv.package = true` or define a `build-system`");
     }
 
-    // Discover or create the virtual environment.
-    let venv = project::get_or_init_environment(
-        project.workspace(),
-        python.as_deref().map(PythonRequest::parse),
-        install_mirrors,
-        python_preference,
-        python_downloads,
-        connectivity,
-        native_tls,
-        allow_insecure_host,
-        no_config,
-        cache,
-        printer,
-    )
-    .await?;
+    // Discover or create the virtual environment. In dry-run mode, if the project environment
+    // would be (re)created, plan against an empty environment instead.
+    let (venv, _temp_dir) = if let Some(target_dir) = target_dir.as_ref() {
+        // Install into the `--target` directory, using the project's interpreter.
+        let interpreter = ProjectInterpreter::discover(
+            project.workspace(),
+            project_dir,
+            python.as_deref().map(PythonRequest::parse),
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            allow_insecure_host,
+            install_mirrors,
+            no_config,
+            cache,
+            printer,
+        )
+        .await?
+        .into_interpreter();
+        let venv = PythonEnvironment::from_interpreter(interpreter)
+            .with_target(Target::from(target_dir.clone()).without_scripts())?;
+        (venv, None)
+    } else if dry_run.enabled() {
+        match ProjectInterpreter::discover(
+            project.workspace(),
+            project_dir,
+            python.as_deref().map(PythonRequest::parse),
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            allow_insecure_host,
+            install_mirrors,
+            no_config,
+            cache,
+            printer,
+        )
+        .await?
+        {
+            ProjectInterpreter::Environment(venv) => (venv, None),
+            ProjectInterpreter::Interpreter(interpreter) => {
+                writeln!(
+                    printer.stderr(),
+                    "Would create virtual environment at: {}",
+                    project.workspace().venv().user_display().cyan()
+                )?;
+                let temp_dir = cache.venv_dir()?;
+                let venv = uv_virtualenv::create_venv(
+                    temp_dir.path(),
+                    interpreter,
+                    uv_virtualenv::Prompt::None,
+                    false,
+                    false,
+                    false,
+                    false,
+                )?;
+                (venv, Some(temp_dir))
+            }
+        }
+    } else {
+        let venv = project::get_or_init_environment(
+            project.workspace(),
+            python.as_deref().map(PythonRequest::parse),
+            install_mirrors,
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            allow_insecure_host,
+            no_config,
+            cache,
+            printer,
+        )
+        .await?;
+        (venv, None)
+    };
 
     // Initialize any shared state.
     let state = SharedState::default();
@@ -136,6 +233,8 @@ pub(crate) async fn sync(
         LockMode::Frozen
     } else if locked {
         LockMode::Locked(venv.interpreter())
+    } else if dry_run.enabled() {
+        LockMode::DryRun(venv.interpreter())
     } else {
         LockMode::Write(venv.interpreter())
     };
@@ -145,10 +244,12 @@ pub(crate) async fn sync(
         project.workspace(),
         settings.as_ref().into(),
         LowerBound::Warn,
+        false,
         &state,
         Box::new(DefaultResolveLogger),
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
@@ -199,10 +300,14 @@ pub(crate) async fn sync(
         editable,
         install_options,
         modifications,
+        dry_run,
+        locked_indexes,
+        python_platform.as_ref(),
         settings.as_ref().into(),
         Box::new(DefaultInstallLogger),
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
@@ -219,12 +324,302 @@ pub(crate) async fn sync(
         Err(err) => return Err(err.into()),
     }
 
+    if refresh_scripts {
+        refresh_entrypoints(&venv, &settings.script_launcher, printer)?;
+    }
+
+    if let Some(target_dir) = target_dir.as_ref() {
+        if !dry_run.enabled() {
+            write_target_manifest(target_dir, &venv)?;
+        }
+    }
+
     Ok(ExitStatus::Success)
 }
 
+/// The name of the manifest written to a `--target` directory.
+const TARGET_MANIFEST: &str = "uv-target.toml";
+
+/// A manifest of the packages installed into a `--target` directory.
+#[derive(Debug, serde::Serialize)]
+struct TargetManifest {
+    /// The Python version for which the packages were installed.
+    python: String,
+    /// The installed packages, sorted by name.
+    #[serde(rename = "package")]
+    packages: Vec<TargetPackage>,
+}
+
+/// A package installed into a `--target` directory.
+#[derive(Debug, serde::Serialize)]
+struct TargetPackage {
+    name: PackageName,
+    version: Version,
+}
+
+/// Write a [`TargetManifest`] listing the packages installed in the `--target` directory.
+fn write_target_manifest(target: &Path, venv: &PythonEnvironment) -> Result<()> {
+    let site_packages = SitePackages::from_environment(venv)?;
+    let packages = site_packages
+        .iter()
+        .map(|dist| TargetPackage {
+            name: dist.name().clone(),
+            version: dist.version().clone(),
+        })
+        .sorted_unstable_by(|a, b| a.name.cmp(&b.name))
+        .collect();
+    let manifest = TargetManifest {
+        python: venv.interpreter().python_minor_version().to_string(),
+        packages,
+    };
+    fs_err::write(target.join(TARGET_MANIFEST), toml::to_string(&manifest)?)?;
+    Ok(())
+}
+
+/// Regenerate the entry points of all distributions installed in the environment, e.g., to
+/// update the interpreter path embedded in each launcher.
+fn refresh_entrypoints(
+    venv: &PythonEnvironment,
+    script_launcher: &ScriptLauncher,
+    printer: Printer,
+) -> Result<()> {
+    let start = Instant::now();
+
+    let layout = venv.interpreter().layout();
+    let script_launcher = script_launcher
+        .clone()
+        .with_default_arch(venv.interpreter().arch());
+
+    let site_packages = SitePackages::from_environment(venv)?;
+    let mut refreshed = 0;
+    for dist in site_packages.iter() {
+        // Only `.dist-info` distributions record their entry points.
+        if !matches!(dist, InstalledDist::Registry(_) | InstalledDist::Url(_)) {
+            continue;
+        }
+        refreshed += uv_install_wheel::linker::refresh_entrypoints(
+            &layout,
+            venv.relocatable(),
+            dist.path(),
+            &script_launcher,
+        )
+        .with_context(|| format!("Failed to refresh entry points for: {}", dist.name()))?;
+    }
+
+    let s = if refreshed == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Refreshed {} {}",
+            format!("{refreshed} entry point{s}").bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    Ok(())
+}
+
+/// The interval at which to poll the watched files for changes.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The duration for which the watched files must remain unchanged before re-syncing, such that a
+/// burst of changes (e.g., from a `git checkout`) triggers a single sync.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Sync the project environment, then re-sync whenever the project's `pyproject.toml`,
+/// `uv.lock`, or the manifests of any workspace members change, until interrupted. Distributions
+/// added to (or rebuilt in) any of the given `--find-links` directories also trigger a re-sync.
+///
+/// Failed syncs are reported, but don't end the watch.
+pub(crate) async fn sync_watch<F, Fut>(
+    project_dir: &Path,
+    find_links: &[PathBuf],
+    printer: Printer,
+    mut sync: F,
+) -> Result<ExitStatus>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<ExitStatus>>,
+{
+    loop {
+        progress::emit(&ProgressEvent::SyncStart);
+        let success = match sync().await {
+            Ok(status) => matches!(status, ExitStatus::Success),
+            Err(err) => {
+                let mut causes = err.chain();
+                writeln!(
+                    printer.stderr(),
+                    "{}: {}",
+                    "error".red().bold(),
+                    causes.next().unwrap().to_string().trim()
+                )?;
+                for err in causes {
+                    writeln!(
+                        printer.stderr(),
+                        "  {}: {}",
+                        "Caused by".red().bold(),
+                        err.to_string().trim()
+                    )?;
+                }
+                false
+            }
+        };
+        progress::emit(&ProgressEvent::SyncComplete { success });
+
+        // Snapshot the watched files after the sync, which may itself have updated the lockfile.
+        let mut snapshot = WatchSnapshot::new(watched_paths(project_dir, find_links).await);
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Watching {} for changes...",
+                if snapshot.len() == 1 {
+                    "1 file".to_string()
+                } else {
+                    format!("{} files", snapshot.len())
+                }
+            )
+            .dimmed()
+        )?;
+
+        // Wait for a change, then for the changes to settle.
+        let mut changed = Vec::new();
+        let mut last_change = None;
+        loop {
+            tokio::select! {
+                () = tokio::time::sleep(WATCH_POLL_INTERVAL) => {}
+                _ = tokio::signal::ctrl_c() => return Ok(ExitStatus::Success),
+            }
+            let paths = snapshot.refresh();
+            if !paths.is_empty() {
+                changed.extend(paths);
+                last_change = Some(Instant::now());
+            } else if last_change.is_some_and(|last| last.elapsed() >= WATCH_DEBOUNCE) {
+                break;
+            }
+        }
+
+        changed.sort();
+        changed.dedup();
+        progress::emit(&ProgressEvent::WatchChange {
+            paths: changed
+                .iter()
+                .map(|path| path.simplified_display().to_string())
+                .collect(),
+        });
+        for path in &changed {
+            debug!("Detected change to: `{}`", path.user_display());
+        }
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Detected changes to {}; re-syncing...",
+                changed
+                    .iter()
+                    .map(|path| format!("`{}`", path.user_display()))
+                    .join(", ")
+            )
+            .dimmed()
+        )?;
+    }
+}
+
+/// Returns the files that should trigger a re-sync when modified: the `pyproject.toml` of the
+/// project and each workspace member, along with the workspace's `uv.lock` and `uv.toml`, and the
+/// contents of any `--find-links` directories.
+async fn watched_paths(project_dir: &Path, find_links: &[PathBuf]) -> Vec<PathBuf> {
+    let mut paths = vec![
+        project_dir.join("pyproject.toml"),
+        project_dir.join(".python-version"),
+    ];
+    match VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await {
+        Ok(project) => {
+            let workspace = project.workspace();
+            paths.push(workspace.install_path().join("pyproject.toml"));
+            paths.push(workspace.install_path().join("uv.lock"));
+            paths.push(workspace.install_path().join("uv.toml"));
+            paths.extend(
+                workspace
+                    .packages()
+                    .values()
+                    .map(|member| member.root().join("pyproject.toml")),
+            );
+        }
+        // If the project can't be discovered (e.g., due to an invalid `pyproject.toml`), keep
+        // watching the project itself, such that a fix triggers a re-sync.
+        Err(err) => debug!("Failed to discover project for watching: {err}"),
+    }
+    for directory in find_links {
+        // The modification time of the directory reflects added and removed files, while that of
+        // each file reflects in-place rebuilds.
+        paths.push(directory.clone());
+        if let Ok(entries) = fs_err::read_dir(directory) {
+            paths.extend(
+                entries
+                    .filter_map(Result::ok)
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_file()),
+            );
+        }
+    }
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// The modification times of a set of watched files.
+#[derive(Debug)]
+struct WatchSnapshot {
+    /// The modification time of each watched file, or `None` if the file doesn't exist.
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl WatchSnapshot {
+    /// Record the modification times of the given files.
+    fn new(paths: Vec<PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = Self::modified(&path);
+                (path, modified)
+            })
+            .collect();
+        Self { files }
+    }
+
+    /// Returns the number of watched files.
+    fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Re-read the modification times of the watched files, returning those that changed (or
+    /// were created or removed) since the last refresh.
+    fn refresh(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for (path, modified) in &mut self.files {
+            let current = Self::modified(path);
+            if current != *modified {
+                *modified = current;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+
+    fn modified(path: &Path) -> Option<SystemTime> {
+        fs_err::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+}
+
 /// Sync a lockfile with an environment.
+#[instrument(skip_all)]
 #[allow(clippy::fn_params_excessive_bools)]
-pub(super) async fn do_sync(
+pub(crate) async fn do_sync(
     target: InstallTarget<'_>,
     venv: &PythonEnvironment,
     extras: &ExtrasSpecification,
@@ -232,10 +627,14 @@ pub(super) async fn do_sync(
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
+    dry_run: DryRun,
+    locked_indexes: bool,
+    python_platform: Option<&TargetTriple>,
     settings: InstallerSettingsRef<'_>,
     logger: Box<dyn InstallLogger>,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -264,6 +663,8 @@ pub(super) async fn do_sync(
         exclude_newer,
         link_mode,
         compile_bytecode,
+        invalidation_mode,
+        script_launcher,
         reinstall,
         build_options,
         sources,
@@ -281,6 +682,10 @@ pub(super) async fn do_sync(
         ));
     }
 
+    // Validate that the lockfile matches any hashes pinned in `tool.uv.sources`, e.g., if the
+    // lockfile was modified by hand and is being installed with `--frozen`.
+    verify_source_hashes(target.workspace(), target.lock())?;
+
     // Validate that we aren't trying to install extras that are
     // declared as conflicting.
     let conflicting_groups = target.lock().conflicting_groups();
@@ -299,7 +704,7 @@ pub(super) async fn do_sync(
     }
 
     // Determine the markers to use for resolution.
-    let marker_env = venv.interpreter().resolver_marker_environment();
+    let marker_env = resolution_markers(None, python_platform, venv.interpreter());
 
     // Validate that the platform is supported by the lockfile.
     let environments = target.lock().supported_environments();
@@ -326,12 +731,12 @@ pub(super) async fn do_sync(
     }
 
     // Determine the tags to use for resolution.
-    let tags = venv.interpreter().tags()?;
+    let tags = resolution_tags(None, python_platform, venv.interpreter(), tag_preferences)?;
 
     // Read the lockfile.
     let resolution = target.to_resolution(
         &marker_env,
-        tags,
+        &tags,
         extras,
         dev,
         build_options,
@@ -380,7 +785,6 @@ pub(super) async fn do_sync(
     let bounds = LowerBound::default();
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
-    let dry_run = false;
 
     // Extract the hashes from the lockfile.
     let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
@@ -391,7 +795,7 @@ pub(super) async fn do_sync(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, build_options)
     };
 
     // Create a build dispatch.
@@ -417,9 +821,35 @@ pub(super) async fn do_sync(
         bounds,
         sources,
         concurrency,
+        tag_preferences,
     );
 
-    let site_packages = SitePackages::from_environment(venv)?;
+    let mut site_packages = SitePackages::from_environment(venv)?;
+
+    // Determine the vendored packages that are required by the packages being installed, and
+    // exclude any existing installations from the sync, since they're installed separately.
+    let vendored = target
+        .lock()
+        .vendored()
+        .iter()
+        .filter(|vendored| {
+            vendored
+                .dependents()
+                .iter()
+                .any(|dependent| resolution.packages().contains(dependent))
+        })
+        .collect::<Vec<_>>();
+    for vendored in &vendored {
+        if let Ok(alias) = PackageName::from_str(vendored.alias()) {
+            site_packages.remove_packages(&alias);
+        }
+    }
+
+    // If the locked indexes are enforced, refuse to retain any package that wasn't installed from
+    // the index recorded in the lockfile, rather than accepting it based on its version alone.
+    if locked_indexes {
+        verify_installed_indexes(&resolution, &site_packages, reinstall)?;
+    }
 
     // Sync the environment.
     operations::install(
@@ -430,10 +860,12 @@ pub(super) async fn do_sync(
         build_options,
         link_mode,
         compile_bytecode,
+        invalidation_mode,
+        script_launcher,
         index_locations,
         config_setting,
         &hasher,
-        tags,
+        &tags,
         &client,
         &state.in_flight,
         concurrency,
@@ -446,11 +878,261 @@ pub(super) async fn do_sync(
     )
     .await?;
 
+    // Install any vendored packages under their aliases.
+    if !dry_run.enabled() {
+        for vendored in vendored {
+            Box::pin(install_vendored(
+                vendored,
+                target.workspace().install_path(),
+                venv,
+                &tags,
+                build_options,
+                &client,
+                &build_dispatch,
+                &state.in_flight,
+                concurrency,
+                cache,
+            ))
+            .await?;
+        }
+
+        // Install (or remove) the rebuild hooks for any editable workspace members.
+        sync_rebuild_hooks(target.workspace(), venv)?;
+    }
+
+    Ok(())
+}
+
+/// Verify that each package in the [`Resolution`] that's already installed was installed from the
+/// index from which it was locked.
+///
+/// Packages that are installed from a different index (or from an unknown index, e.g., because
+/// they were installed by another tool) are rejected, unless they're marked for reinstallation.
+fn verify_installed_indexes(
+    resolution: &Resolution,
+    site_packages: &SitePackages,
+    reinstall: &Reinstall,
+) -> Result<(), ProjectError> {
+    for dist in resolution.distributions() {
+        let index = match dist {
+            ResolvedDist::Installable(Dist::Built(BuiltDist::Registry(wheels))) => {
+                &wheels.best_wheel().index
+            }
+            ResolvedDist::Installable(Dist::Source(SourceDist::Registry(sdist))) => &sdist.index,
+            _ => continue,
+        };
+        let reinstalled = match reinstall {
+            Reinstall::None => false,
+            Reinstall::All => true,
+            Reinstall::Packages(packages) => packages.contains(dist.name()),
+        };
+        if reinstalled {
+            continue;
+        }
+        let expected = index.redacted().to_string();
+        for installed in site_packages.get_packages(dist.name()) {
+            let actual = match installed {
+                InstalledDist::Registry(_) | InstalledDist::Url(_) => {
+                    InstalledDist::index_url(installed.path())?
+                }
+                _ => None,
+            };
+            if actual.as_deref() != Some(expected.as_str()) {
+                return Err(ProjectError::IndexMismatch(
+                    dist.name().clone(),
+                    actual.map_or_else(|| "an unknown index".to_string(), |url| format!("`{url}`")),
+                    expected,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Install an import hook for each editable workspace member that enables
+/// `tool.uv.rebuild-on-import` and contains native sources, such that its compiled extension
+/// modules are rebuilt on import after their sources change. Removes the hooks of any other
+/// members, and of any packages that are no longer workspace members.
+fn sync_rebuild_hooks(workspace: &Workspace, venv: &PythonEnvironment) -> Result<(), ProjectError> {
+    let site_packages = SitePackages::from_environment(venv)?;
+    let purelib = venv.interpreter().purelib();
+
+    // The hook reinstalls the member with the same `uv` executable that installed it.
+    let uv = std::env::current_exe()
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| "uv".to_string());
+
+    for (name, member) in workspace.packages() {
+        let hook_error = |err| ProjectError::RebuildHook(name.clone(), err);
+
+        let enabled = member
+            .pyproject_toml()
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.rebuild_on_import)
+            .unwrap_or(false);
+        let editable = site_packages.get_packages(name).into_iter().find(|dist| {
+            matches!(
+                dist,
+                InstalledDist::Url(InstalledDirectUrlDist { editable: true, .. })
+            )
+        });
+
+        match editable {
+            Some(dist) if enabled && uv_install_wheel::has_native_sources(member.root()) => {
+                let command = [
+                    uv.as_str(),
+                    "pip",
+                    "install",
+                    "--python",
+                    &venv.interpreter().sys_executable().to_string_lossy(),
+                    "--no-deps",
+                    "--reinstall-package",
+                    name.as_ref(),
+                    "--editable",
+                    &member.root().to_string_lossy(),
+                ]
+                .map(ToString::to_string);
+                uv_install_wheel::install_rebuild_hook(
+                    purelib,
+                    dist.path(),
+                    name,
+                    member.root(),
+                    &command,
+                )
+                .map_err(hook_error)?;
+            }
+            _ => {
+                if uv_install_wheel::uninstall_rebuild_hook(purelib, name).map_err(hook_error)? {
+                    debug!("Removed rebuild hook for `{name}`");
+                }
+            }
+        }
+    }
+
+    // Remove the hooks of any packages that are no longer workspace members.
+    for name in uv_install_wheel::installed_rebuild_hooks(purelib)? {
+        if workspace.packages().contains_key(&name) {
+            continue;
+        }
+        if uv_install_wheel::uninstall_rebuild_hook(purelib, &name)
+            .map_err(|err| ProjectError::RebuildHook(name.clone(), err))?
+        {
+            debug!("Removed rebuild hook for `{name}`");
+        }
+    }
+
+    Ok(())
+}
+
+/// Install a [`VendoredPackage`] under its alias, then rewrite the imports of its dependents to
+/// refer to the alias.
+async fn install_vendored(
+    vendored: &VendoredPackage,
+    workspace_root: &Path,
+    venv: &PythonEnvironment,
+    tags: &Tags,
+    build_options: &BuildOptions,
+    client: &RegistryClient,
+    build_dispatch: &BuildDispatch<'_>,
+    in_flight: &InFlight,
+    concurrency: Concurrency,
+    cache: &Cache,
+) -> Result<(), ProjectError> {
+    let alias = vendored.alias();
+    let vendor_error =
+        |err| ProjectError::VendorInstall(vendored.name().clone(), alias.to_string(), err);
+    let alias_name = PackageName::from_str(alias)
+        .map_err(|err| vendor_error(uv_install_wheel::Error::InvalidName(err)))?;
+
+    // Reuse the existing installation, if it matches the locked version.
+    let site_packages = SitePackages::from_environment(venv)?;
+    let mut module = None;
+    for dist in site_packages.get_packages(&alias_name) {
+        if dist.version() == vendored.version() {
+            if let Some(existing) =
+                uv_install_wheel::vendored_module(dist.path()).map_err(vendor_error)?
+            {
+                module = Some(existing);
+                continue;
+            }
+        }
+        uv_installer::uninstall(dist)
+            .await
+            .map_err(operations::Error::from)?;
+    }
+
+    let module = if let Some(module) = module {
+        debug!(
+            "Vendored package `{}` is already installed as `{alias}`",
+            vendored.name()
+        );
+        module
+    } else {
+        let resolution = vendored.to_resolution(workspace_root, tags, build_options)?;
+        let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+        let preparer = Preparer::new(
+            cache,
+            tags,
+            &hasher,
+            build_options,
+            DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+        );
+        let distributions = resolution
+            .distributions()
+            .filter_map(|dist| match dist {
+                ResolvedDist::Installable(dist) => Some(dist.clone()),
+                ResolvedDist::Installed(_) => None,
+            })
+            .collect();
+        let wheels = preparer
+            .prepare(distributions, in_flight)
+            .await
+            .map_err(operations::Error::from)?;
+
+        let mut module = None;
+        for wheel in wheels {
+            debug!(
+                "Installing vendored package `{}` as `{alias}`",
+                wheel.filename()
+            );
+            module = Some(
+                uv_install_wheel::install_vendored_wheel(
+                    &venv.interpreter().layout(),
+                    wheel.path(),
+                    wheel.filename(),
+                    alias,
+                    Some("uv"),
+                )
+                .map_err(vendor_error)?,
+            );
+        }
+        let Some(module) = module else {
+            return Ok(());
+        };
+        module
+    };
+
+    // Rewrite the imports of the dependents, which is a no-op if they were already rewritten.
+    let site_packages = SitePackages::from_environment(venv)?;
+    for dependent in vendored.dependents() {
+        for dist in site_packages.get_packages(dependent) {
+            uv_install_wheel::rewrite_dependent_imports(
+                venv.interpreter().purelib(),
+                dist.path(),
+                &module,
+                alias,
+            )
+            .map_err(vendor_error)?;
+        }
+    }
+
     Ok(())
 }
 
 /// Filter out any virtual workspace members.
-fn apply_no_virtual_project(
+pub(super) fn apply_no_virtual_project(
     resolution: uv_distribution_types::Resolution,
 ) -> uv_distribution_types::Resolution {
     resolution.filter(|dist| {
@@ -471,7 +1153,7 @@ fn apply_no_virtual_project(
 }
 
 /// If necessary, convert any editable requirements to non-editable.
-fn apply_editable_mode(
+pub(super) fn apply_editable_mode(
     resolution: uv_distribution_types::Resolution,
     editable: EditableMode,
 ) -> uv_distribution_types::Resolution {
@@ -511,7 +1193,7 @@ fn apply_editable_mode(
 ///
 /// These credentials can come from any of `tool.uv.sources`, `tool.uv.dev-dependencies`,
 /// `project.dependencies`, and `project.optional-dependencies`.
-fn store_credentials_from_workspace(workspace: &Workspace) {
+pub(crate) fn store_credentials_from_workspace(workspace: &Workspace) {
     for member in workspace.packages().values() {
         // Iterate over the `tool.uv.sources`.
         for source in member
@@ -525,8 +1207,10 @@ fn store_credentials_from_workspace(workspace: &Workspace) {
             .flat_map(|sources| sources.values().flat_map(Sources::iter))
         {
             match source {
-                Source::Git { git, .. } => {
-                    uv_git::store_credentials_from_url(git);
+                Source::Git { git: url, .. }
+                | Source::Hg { hg: url, .. }
+                | Source::Svn { svn: url, .. } => {
+                    uv_git::store_credentials_from_url(url);
                 }
                 Source::Url { url, .. } => {
                     uv_auth::store_credentials_from_url(url);
@@ -1,3 +1,4 @@
+use std::fmt::Write;
 use std::path::Path;
 
 use anstream::print;
@@ -13,15 +14,18 @@ use uv_configuration::{
 use uv_distribution_types::IndexCapabilities;
 use uv_pep440::Version;
 use uv_pep508::PackageName;
+use uv_platform_tags::TagPreferences;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
-use uv_resolver::{PackageMap, TreeDisplay};
+use uv_resolver::{Lock, LockDiff, PackageMap, TreeDisplay};
 use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, Workspace};
 
 use crate::commands::pip::latest::LatestClient;
 use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::pip::resolution_markers;
-use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::lock::{self, do_safe_lock, LockMode};
+use crate::commands::project::maintenance::MaintenanceChecks;
 use crate::commands::project::{
     default_dependency_groups, DependencyGroupsTarget, ProjectError, ProjectInterpreter,
 };
@@ -42,6 +46,9 @@ pub(crate) async fn tree(
     package: Vec<PackageName>,
     no_dedupe: bool,
     invert: bool,
+    why: Vec<PackageName>,
+    diff: Option<&Path>,
+    json: bool,
     outdated: bool,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
@@ -52,6 +59,7 @@ pub(crate) async fn tree(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
@@ -103,6 +111,15 @@ pub(crate) async fn tree(
         LockMode::Write(interpreter.as_ref().unwrap())
     };
 
+    // Read the previous lockfile before updating the project's lockfile, such that the project's
+    // own lockfile can be compared against the current resolution.
+    let previous = if let Some(path) = diff {
+        let encoded = fs_err::tokio::read_to_string(path).await?;
+        Some(lock::parse(path.to_path_buf(), encoded)?)
+    } else {
+        None
+    };
+
     // Initialize any shared state.
     let state = SharedState::default();
 
@@ -112,10 +129,12 @@ pub(crate) async fn tree(
         &workspace,
         settings.as_ref(),
         LowerBound::Allow,
+        false,
         &state,
         Box::new(DefaultResolveLogger),
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
@@ -132,6 +151,19 @@ pub(crate) async fn tree(
         Err(err) => return Err(err.into()),
     };
 
+    // If requested, display the differences from the previous lockfile instead of the tree.
+    if let Some(previous) = previous {
+        let diff = LockDiff::from_locks(&previous, &lock);
+        if json {
+            print!("{}", serde_json::to_string_pretty(&diff)? + "\n");
+        } else if diff.is_empty() {
+            writeln!(printer.stderr(), "No lockfile differences detected")?;
+        } else {
+            print!("{diff}");
+        }
+        return Ok(ExitStatus::Success);
+    }
+
     // Determine the markers to use for resolution.
     let markers = (!universal).then(|| {
         resolution_markers(
@@ -142,63 +174,32 @@ pub(crate) async fn tree(
     });
 
     // If necessary, look up the latest version of each package.
-    let latest = if outdated {
-        let ResolverSettings {
-            index_locations: _,
-            index_strategy: _,
-            keyring_provider,
-            resolution: _,
-            prerelease: _,
-            dependency_metadata: _,
-            config_setting: _,
-            no_build_isolation: _,
-            no_build_isolation_package: _,
-            exclude_newer: _,
-            link_mode: _,
-            upgrade: _,
-            build_options: _,
-            sources: _,
-        } = &settings;
-
-        let capabilities = IndexCapabilities::default();
-
-        // Initialize the registry client.
-        let client =
-            RegistryClientBuilder::new(cache.clone().with_refresh(Refresh::All(Timestamp::now())))
-                .native_tls(native_tls)
-                .connectivity(connectivity)
-                .keyring(*keyring_provider)
-                .allow_insecure_host(allow_insecure_host.to_vec())
-                .build();
-
-        // Initialize the client to fetch the latest version of each package.
-        let client = LatestClient {
-            client: &client,
-            capabilities: &capabilities,
-            prerelease: lock.prerelease_mode(),
-            exclude_newer: lock.exclude_newer(),
-            requires_python: lock.requires_python(),
-            tags: None,
-        };
-
-        // Fetch the latest version for each package.
-        stream::iter(lock.packages())
-            .filter_map(|package| async {
-                let index = package.index(workspace.install_path()).ok()??;
-                let filename = client
-                    .find_latest(package.name(), Some(&index))
-                    .await
-                    .ok()??;
-                if filename.version() == package.version() {
-                    None
-                } else {
-                    Some((package.clone(), filename.into_version()))
-                }
-            })
-            .collect::<PackageMap<Version>>()
-            .await
+    let (latest, unmaintained) = if outdated {
+        find_latest(
+            &lock,
+            &workspace,
+            &settings,
+            connectivity,
+            native_tls,
+            allow_insecure_host,
+            cache,
+        )
+        .await
+    } else {
+        (PackageMap::default(), PackageMap::default())
+    };
+
+    // When explaining why a package is included, display every path from the package to the
+    // workspace members that depend on it.
+    let (package, invert, annotate) = if why.is_empty() {
+        (package, invert, false)
     } else {
-        PackageMap::default()
+        for name in &why {
+            if !lock.packages().iter().any(|package| package.name() == name) {
+                warn_user!("`{name}` is not included in the lockfile");
+            }
+        }
+        (why, true, true)
     };
 
     // Render the tree.
@@ -206,15 +207,105 @@ pub(crate) async fn tree(
         &lock,
         markers.as_ref(),
         &latest,
+        &unmaintained,
         depth.into(),
         &prune,
         &package,
         &dev.with_defaults(defaults),
         no_dedupe,
         invert,
+        annotate,
     );
 
     print!("{tree}");
 
     Ok(ExitStatus::Success)
 }
+
+/// Look up the latest version of each package in the lockfile, along with any packages that are no
+/// longer maintained.
+pub(crate) async fn find_latest(
+    lock: &Lock,
+    workspace: &Workspace,
+    settings: &ResolverSettings,
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+) -> (PackageMap<Version>, PackageMap<String>) {
+    let ResolverSettings {
+        index_locations: _,
+        index_strategy: _,
+        keyring_provider,
+        resolution: _,
+        prerelease: _,
+        dependency_metadata: _,
+        config_setting: _,
+        no_build_isolation: _,
+        no_build_isolation_package: _,
+        exclude_newer: _,
+        max_backtracks: _,
+        link_mode: _,
+        upgrade: _,
+        build_options: _,
+        sources: _,
+    } = settings;
+
+    let capabilities = IndexCapabilities::default();
+
+    // Initialize the registry client.
+    let client =
+        RegistryClientBuilder::new(cache.clone().with_refresh(Refresh::All(Timestamp::now())))
+            .native_tls(native_tls)
+            .connectivity(connectivity)
+            .keyring(*keyring_provider)
+            .allow_insecure_host(allow_insecure_host.to_vec())
+            .build();
+
+    // Initialize the client to fetch the latest version of each package.
+    let client = LatestClient {
+        client: &client,
+        capabilities: &capabilities,
+        prerelease: lock.prerelease_mode(),
+        exclude_newer: lock.exclude_newer(),
+        requires_python: lock.requires_python(),
+        tags: None,
+    };
+
+    // Fetch the latest version for each package.
+    let upgrades = stream::iter(lock.packages())
+        .filter_map(|package| async {
+            let index = package.index(workspace.install_path()).ok()??;
+            let latest = client
+                .find_upgrade(package.name(), Some(&index))
+                .await
+                .ok()?;
+            Some((package, latest))
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+    // Flag any packages that are no longer maintained.
+    let checks = MaintenanceChecks::from_workspace(workspace);
+    let unmaintained = upgrades
+        .iter()
+        .filter_map(|(package, latest)| {
+            let unmaintained = checks.check(package.name(), latest)?;
+            Some(((*package).clone(), unmaintained.to_string()))
+        })
+        .collect::<PackageMap<String>>();
+
+    let latest = upgrades
+        .into_iter()
+        .filter_map(|(package, latest)| {
+            let filename = latest.dist?;
+            if filename.version() == package.version() {
+                None
+            } else {
+                Some((package.clone(), filename.into_version()))
+            }
+        })
+        .collect::<PackageMap<Version>>();
+
+    (latest, unmaintained)
+}
@@ -0,0 +1,579 @@
+//! A local caching proxy for the simple repository API.
+//!
+//! The proxy serves a PEP 503 project page for each package, as assembled from the configured
+//! indexes via the [`RegistryClient`], such that index responses are cached (and revalidated) like
+//! any other uv invocation. The distributions listed on each page are rewritten to point back at
+//! the proxy, which downloads each file from its upstream index once, verifies it against its
+//! hashes, and stores it in the [`CacheBucket::Proxy`] bucket.
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, bail, Context, Result};
+use futures::StreamExt;
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Full, StreamBody};
+use hyper::body::{Bytes, Frame, Incoming};
+use hyper::header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, LOCATION};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use indoc::indoc;
+use jiff::Timestamp;
+use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+use tokio_util::io::ReaderStream;
+use tracing::{debug, warn};
+use url::Url;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::cache_digest;
+use uv_client::{
+    BaseClientBuilder, Connectivity, ErrorKind, OwnedArchive, RegistryClient, RegistryClientBuilder,
+};
+use uv_configuration::{IndexStrategy, KeyringProviderType, TrustedHost};
+use uv_distribution_types::{File, IndexCapabilities, IndexLocations};
+use uv_extract::hash::Hasher;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{HashDigest, Yanked};
+use uv_resolver::ExcludeNewer;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The path under which the proxy serves the simple repository API.
+const SIMPLE: &str = "simple";
+
+/// The path under which the proxy serves distribution files.
+const FILES: &str = "files";
+
+/// The content type of the JSON format of the simple repository API (PEP 691).
+const JSON_CONTENT_TYPE: &str = "application/vnd.pypi.simple.v1+json";
+
+/// The body of a response served by the proxy: either a page rendered in memory, or a
+/// distribution file streamed from the cache.
+type Body = UnsyncBoxBody<Bytes, std::io::Error>;
+
+/// The upstream location of a distribution listed on a served project page.
+#[derive(Debug, Clone)]
+struct Upstream {
+    url: Url,
+    hashes: Vec<HashDigest>,
+}
+
+/// A distribution listed on a project page, with its URL rewritten to point at the proxy.
+#[derive(Debug)]
+struct Link {
+    href: String,
+    file: File,
+}
+
+/// The distributions available for a package across the configured indexes.
+#[derive(Debug, Default)]
+struct ProjectPage {
+    versions: BTreeSet<Version>,
+    links: Vec<Link>,
+}
+
+impl ProjectPage {
+    /// Render the page in the HTML format of the simple repository API (PEP 503).
+    fn to_html(&self, package: &PackageName) -> Result<String> {
+        let mut page = String::new();
+        writeln!(page, "<!DOCTYPE html>")?;
+        writeln!(page, "<html>")?;
+        writeln!(page, "  <head>")?;
+        writeln!(
+            page,
+            "    <meta name=\"pypi:repository-version\" content=\"1.0\">"
+        )?;
+        writeln!(page, "    <title>Links for {package}</title>")?;
+        writeln!(page, "  </head>")?;
+        writeln!(page, "  <body>")?;
+        writeln!(page, "    <h1>Links for {package}</h1>")?;
+        for Link { href, file } in &self.links {
+            let mut href = href.clone();
+            if let Some(hash) = file.hashes.first() {
+                write!(href, "#{}={}", hash.algorithm, hash.digest)?;
+            }
+            let mut attributes = String::new();
+            if let Some(requires_python) = &file.requires_python {
+                write!(
+                    attributes,
+                    " data-requires-python=\"{}\"",
+                    html_escape::encode_double_quoted_attribute(&requires_python.to_string())
+                )?;
+            }
+            match &file.yanked {
+                Some(Yanked::Reason(reason)) => write!(
+                    attributes,
+                    " data-yanked=\"{}\"",
+                    html_escape::encode_double_quoted_attribute(reason)
+                )?,
+                Some(Yanked::Bool(true)) => attributes.push_str(" data-yanked=\"\""),
+                Some(Yanked::Bool(false)) | None => {}
+            }
+            writeln!(
+                page,
+                "    <a href=\"{}\"{attributes}>{}</a><br />",
+                html_escape::encode_double_quoted_attribute(&href),
+                html_escape::encode_text(&file.filename)
+            )?;
+        }
+        writeln!(page, "  </body>")?;
+        writeln!(page, "</html>")?;
+        Ok(page)
+    }
+
+    /// Render the page in the JSON format of the simple repository API (PEP 691).
+    ///
+    /// Unlike the HTML format, the JSON format includes the upload time of each file, as required
+    /// by clients that filter distributions by upload time (e.g., uv with `--exclude-newer`).
+    fn to_json(&self, package: &PackageName) -> Result<String> {
+        let files = self
+            .links
+            .iter()
+            .map(|Link { href, file }| {
+                let hashes = file
+                    .hashes
+                    .iter()
+                    .map(|hash| (hash.algorithm.to_string(), hash.digest.to_string()))
+                    .collect::<BTreeMap<_, _>>();
+                let yanked = match &file.yanked {
+                    Some(Yanked::Reason(reason)) => serde_json::Value::from(reason.as_str()),
+                    Some(Yanked::Bool(yanked)) => serde_json::Value::from(*yanked),
+                    None => serde_json::Value::from(false),
+                };
+                let upload_time = file
+                    .upload_time_utc_ms
+                    .and_then(|upload_time| Timestamp::from_millisecond(upload_time).ok())
+                    .map(|upload_time| upload_time.to_string());
+                serde_json::json!({
+                    "filename": file.filename,
+                    "url": href,
+                    "hashes": hashes,
+                    "requires-python": file.requires_python.as_ref().map(ToString::to_string),
+                    "yanked": yanked,
+                    "size": file.size,
+                    "upload-time": upload_time,
+                })
+            })
+            .collect::<Vec<_>>();
+        let page = serde_json::json!({
+            "meta": { "api-version": "1.1" },
+            "name": package,
+            "versions": self.versions.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            "files": files,
+        });
+        Ok(serde_json::to_string(&page)?)
+    }
+}
+
+/// The state shared across all requests to the proxy.
+struct Proxy {
+    client: RegistryClient,
+    cache: Cache,
+    capabilities: IndexCapabilities,
+    exclude_newer: Option<ExcludeNewer>,
+    /// The upstream location of each distribution listed on a served project page, keyed by its
+    /// path relative to the [`CacheBucket::Proxy`] bucket.
+    files: Mutex<FxHashMap<String, Upstream>>,
+}
+
+/// Run a local caching proxy for the simple repository API.
+pub(crate) async fn proxy(
+    host: &str,
+    port: u16,
+    index_locations: &IndexLocations,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProviderType,
+    exclude_newer: Option<ExcludeNewer>,
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Add all authenticated sources to the cache.
+    for index in index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            uv_auth::store_credentials(index.raw_url(), credentials);
+        }
+    }
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .keyring(keyring_provider)
+        .allow_insecure_host(allow_insecure_host.to_vec());
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .build();
+
+    let listener = TcpListener::bind((host, port))
+        .await
+        .with_context(|| format!("Failed to listen on `{host}:{port}`"))?;
+    let address = listener.local_addr()?;
+
+    let proxy = Arc::new(Proxy {
+        client,
+        cache: cache.clone(),
+        capabilities: IndexCapabilities::default(),
+        exclude_newer,
+        files: Mutex::new(FxHashMap::default()),
+    });
+
+    writeln!(
+        printer.stderr(),
+        "Serving the simple index at {}",
+        format!("http://{address}/{SIMPLE}/").cyan()
+    )?;
+    for index in index_locations.indexes() {
+        writeln!(printer.stderr(), "  - {}", index.url().redacted())?;
+    }
+    writeln!(printer.stderr(), "{}", "Press Ctrl+C to stop".dimmed())?;
+
+    loop {
+        let (stream, remote) = tokio::select! {
+            result = listener.accept() => result?,
+            _ = tokio::signal::ctrl_c() => break,
+        };
+        debug!("Accepted connection from: {remote}");
+
+        let proxy = proxy.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| handle(proxy.clone(), request));
+            if let Err(err) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                debug!("Failed to serve connection from {remote}: {err}");
+            }
+        });
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Respond to a single request.
+async fn handle(
+    proxy: Arc<Proxy>,
+    request: Request<Incoming>,
+) -> Result<Response<Body>, Infallible> {
+    if !matches!(*request.method(), Method::GET | Method::HEAD) {
+        return Ok(status(StatusCode::METHOD_NOT_ALLOWED));
+    }
+
+    let path = request.uri().path();
+    let segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+
+    let response = match segments.as_slice() {
+        [SIMPLE] => Ok(html(root_page())),
+        [SIMPLE, name] => match PackageName::from_str(name) {
+            // Per PEP 503, redirect to the normalized project URL, with a trailing slash.
+            Ok(package) if package.as_str() != *name || !path.ends_with('/') => {
+                Ok(redirect(&format!("/{SIMPLE}/{package}/")))
+            }
+            Ok(package) => match proxy.project_page(&package).await {
+                Ok(Some(page)) if accepts_json(&request) => page
+                    .to_json(&package)
+                    .map(|page| body(JSON_CONTENT_TYPE, page.into_bytes())),
+                Ok(Some(page)) => page.to_html(&package).map(html),
+                Ok(None) => Ok(status(StatusCode::NOT_FOUND)),
+                Err(err) => Err(err),
+            },
+            Err(_) => Ok(status(StatusCode::NOT_FOUND)),
+        },
+        [FILES, package, digest, filename] => match proxy.file(package, digest, filename).await {
+            Ok(Some(path)) => stream(&path).await,
+            Ok(None) => Ok(status(StatusCode::NOT_FOUND)),
+            Err(err) => Err(err),
+        },
+        _ => Ok(status(StatusCode::NOT_FOUND)),
+    };
+
+    let response = match response {
+        Ok(response) => {
+            debug!("{} {path} {}", request.method(), response.status());
+            response
+        }
+        Err(err) => {
+            let message = format!("{err:#}");
+            warn!("Failed to serve {path}: {message}");
+            let mut response = body("text/plain; charset=utf-8", message.into_bytes());
+            *response.status_mut() = StatusCode::BAD_GATEWAY;
+            response
+        }
+    };
+
+    // Respond to `HEAD` requests with the headers of the equivalent `GET` request, but no body.
+    if *request.method() == Method::HEAD {
+        let (parts, _) = response.into_parts();
+        return Ok(Response::from_parts(parts, full(Bytes::new())));
+    }
+
+    Ok(response)
+}
+
+impl Proxy {
+    /// Assemble the project page for a package from the configured indexes, or return `None` if
+    /// the package can't be found on any index.
+    async fn project_page(&self, package: &PackageName) -> Result<Option<ProjectPage>> {
+        let results = match self.client.simple(package, None, &self.capabilities).await {
+            Ok(results) => results,
+            Err(err) => {
+                return match err.into_kind() {
+                    ErrorKind::PackageNotFound(_)
+                    | ErrorKind::Offline(_)
+                    | ErrorKind::NoIndex(_) => Ok(None),
+                    kind => Err(uv_client::Error::from(kind).into()),
+                }
+            }
+        };
+
+        let mut page = ProjectPage::default();
+        let mut seen = FxHashSet::default();
+        for (_, metadata) in results {
+            let metadata = OwnedArchive::deserialize(&metadata);
+            for datum in metadata.iter() {
+                let files = datum
+                    .files
+                    .wheels
+                    .iter()
+                    .map(|wheel| &wheel.file)
+                    .chain(datum.files.source_dists.iter().map(|sdist| &sdist.file));
+                for file in files {
+                    // If the same file is available on multiple indexes, serve the first.
+                    if !seen.insert(file.filename.clone()) {
+                        continue;
+                    }
+                    if let Some(link) = self.link(package, file) {
+                        page.versions.insert(datum.version.clone());
+                        page.links.push(link);
+                    }
+                }
+            }
+        }
+
+        Ok(Some(page))
+    }
+
+    /// Rewrite a file on a project page to point back at the proxy, and record its upstream
+    /// location.
+    ///
+    /// Returns `None` if the file is excluded by `--exclude-newer`, or its URL is invalid.
+    fn link(&self, package: &PackageName, file: &File) -> Option<Link> {
+        if let Some(exclude_newer) = self.exclude_newer {
+            match file.upload_time_utc_ms {
+                Some(upload_time) if upload_time < exclude_newer.timestamp_millis() => {}
+                _ => return None,
+            }
+        }
+
+        let url = match file.url.to_url() {
+            Ok(url) => url,
+            Err(err) => {
+                debug!("Skipping file with invalid URL: {}: {err}", file.filename);
+                return None;
+            }
+        };
+
+        let key = format!("{package}/{}/{}", cache_digest(&url), file.filename);
+        self.files
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_insert_with(|| Upstream {
+                url,
+                hashes: file.hashes.clone(),
+            });
+
+        Some(Link {
+            href: format!("/{FILES}/{key}"),
+            file: file.clone(),
+        })
+    }
+
+    /// Return the path to a distribution file in the cache, downloading it from its upstream index
+    /// if necessary.
+    ///
+    /// Returns `None` if the file isn't cached, and wasn't listed on a served project page.
+    async fn file(&self, package: &str, digest: &str, filename: &str) -> Result<Option<PathBuf>> {
+        // Avoid serving anything outside the bucket.
+        if [package, digest, filename]
+            .iter()
+            .any(|segment| segment.starts_with('.') || segment.contains('\\'))
+        {
+            return Ok(None);
+        }
+
+        let key = format!("{package}/{digest}/{filename}");
+        let path = self.cache.bucket(CacheBucket::Proxy).join(&key);
+        match fs_err::tokio::metadata(&path).await {
+            Ok(_) => return Ok(Some(path)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        let Some(upstream) = self.files.lock().unwrap().get(&key).cloned() else {
+            return Ok(None);
+        };
+        self.download(&upstream, &path).await?;
+
+        Ok(Some(path))
+    }
+
+    /// Download a distribution file from its upstream index to the given path, verifying its
+    /// hashes.
+    ///
+    /// The file is streamed to a temporary file alongside the cache entry, and only moved into
+    /// place once its hashes are verified, such that a partial or corrupt download is never served.
+    async fn download(&self, upstream: &Upstream, path: &Path) -> Result<()> {
+        let Upstream { url, hashes } = upstream;
+
+        let mut reader = if url.scheme() == "file" {
+            let path = url
+                .to_file_path()
+                .map_err(|()| anyhow!("Invalid file URL: {url}"))?;
+            ReaderStream::new(fs_err::tokio::File::open(&path).await?).boxed()
+        } else {
+            debug!("Downloading: {url}");
+            self.client
+                .uncached_client(url)
+                .get(url.clone())
+                .send()
+                .await
+                .with_context(|| format!("Failed to download: {url}"))?
+                .error_for_status()
+                .with_context(|| format!("Failed to download: {url}"))?
+                .bytes_stream()
+                .map(|chunk| chunk.map_err(std::io::Error::other))
+                .boxed()
+        };
+
+        let parent = path.parent().expect("cache entry has a parent");
+        fs_err::tokio::create_dir_all(parent).await?;
+        let temp_file = tempfile::NamedTempFile::new_in(parent)?;
+        let mut writer = tokio::fs::File::from_std(temp_file.reopen()?);
+        let mut hashers = hashes
+            .iter()
+            .map(|expected| Hasher::from(expected.algorithm()))
+            .collect::<Vec<_>>();
+        while let Some(chunk) = reader.next().await {
+            let chunk = chunk.with_context(|| format!("Failed to download: {url}"))?;
+            for hasher in &mut hashers {
+                hasher.update(&chunk);
+            }
+            writer.write_all(&chunk).await?;
+        }
+        writer.flush().await?;
+        drop(writer);
+
+        // Verify the expected hashes, if any.
+        for (expected, hasher) in hashes.iter().zip(hashers) {
+            let actual = HashDigest::from(hasher);
+            if actual.digest != expected.digest {
+                bail!(
+                    "Hash mismatch for `{url}`\n\nExpected:\n  {expected}\n\nComputed:\n  {actual}"
+                );
+            }
+        }
+
+        temp_file
+            .persist(path)
+            .with_context(|| format!("Failed to write: {}", path.display()))?;
+
+        Ok(())
+    }
+}
+
+/// Render the root page of the simple repository API.
+///
+/// The proxy can't enumerate the packages available on the upstream indexes, so the page lists no
+/// projects; installers only request the project pages.
+fn root_page() -> String {
+    indoc! {r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.0">
+            <title>Simple index</title>
+          </head>
+          <body>
+          </body>
+        </html>
+    "#}
+    .to_string()
+}
+
+/// Returns `true` if the client accepts the JSON format of the simple repository API.
+fn accepts_json(request: &Request<Incoming>) -> bool {
+    request
+        .headers()
+        .get(ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .is_some_and(|accept| accept.contains(JSON_CONTENT_TYPE))
+}
+
+/// Return a [`Body`] with the given contents.
+fn full(contents: Bytes) -> Body {
+    Full::new(contents)
+        .map_err(|never| match never {})
+        .boxed_unsync()
+}
+
+/// Return an HTML response with the given body.
+fn html(page: String) -> Response<Body> {
+    body("text/html; charset=utf-8", page.into_bytes())
+}
+
+/// Return a response with the given content type and body.
+fn body(content_type: &str, contents: Vec<u8>) -> Response<Body> {
+    Response::builder()
+        .header(CONTENT_TYPE, content_type)
+        .header(CONTENT_LENGTH, contents.len())
+        .body(full(Bytes::from(contents)))
+        .expect("response is valid")
+}
+
+/// Return a response that streams the file at the given path.
+async fn stream(path: &Path) -> Result<Response<Body>> {
+    let file = fs_err::tokio::File::open(path).await?;
+    let size = file.metadata().await?.len();
+    let body = StreamBody::new(ReaderStream::new(file).map(|chunk| chunk.map(Frame::data)));
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .header(CONTENT_LENGTH, size)
+        .body(BodyExt::boxed_unsync(body))
+        .expect("response is valid"))
+}
+
+/// Return a permanent redirect to the given location.
+fn redirect(location: &str) -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::MOVED_PERMANENTLY)
+        .header(LOCATION, location)
+        .body(full(Bytes::new()))
+        .expect("response is valid")
+}
+
+/// Return an empty response with the given status.
+fn status(status: StatusCode) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .body(full(Bytes::new()))
+        .expect("response is valid")
+}
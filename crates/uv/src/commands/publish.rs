@@ -8,7 +8,7 @@ use std::fmt::Write;
 use std::iter;
 use std::sync::Arc;
 use std::time::Duration;
-use tracing::info;
+use tracing::{debug, info};
 use url::Url;
 use uv_cache::Cache;
 use uv_client::{
@@ -17,7 +17,8 @@ use uv_client::{
 use uv_configuration::{KeyringProviderType, TrustedHost, TrustedPublishing};
 use uv_distribution_types::{Index, IndexCapabilities, IndexLocations, IndexUrl};
 use uv_publish::{
-    check_trusted_publishing, files_for_publishing, upload, CheckUrlClient, TrustedPublishResult,
+    check_trusted_publishing, files_for_publishing, upload, CheckIndex, CheckUrlClient,
+    TrustedPublishResult,
 };
 
 pub(crate) async fn publish(
@@ -29,6 +30,7 @@ pub(crate) async fn publish(
     username: Option<String>,
     password: Option<String>,
     check_url: Option<IndexUrl>,
+    skip_existing: bool,
     cache: &Cache,
     connectivity: Connectivity,
     native_tls: bool,
@@ -38,6 +40,21 @@ pub(crate) async fn publish(
         bail!("Unable to publish files in offline mode");
     }
 
+    // Determine the index to check for existing files, if any.
+    let check_index = if let Some(index_url) = check_url {
+        Some(CheckIndex::Simple(index_url))
+    } else if skip_existing {
+        let Some(check_index) = CheckIndex::from_publish_url(&publish_url) else {
+            bail!(
+                "Unable to determine how to check {publish_url} for existing files; use \
+                `--check-url` to provide the URL of the simple API for your index instead"
+            );
+        };
+        Some(check_index)
+    } else {
+        None
+    };
+
     let files = files_for_publishing(paths)?;
     match files.len() {
         0 => bail!("No files found to publish"),
@@ -70,13 +87,13 @@ pub(crate) async fn publish(
         .wrap_existing(&upload_client);
 
     // Initialize the registry client.
-    let check_url_client = if let Some(index_url) = check_url {
-        let index_urls = IndexLocations::new(
-            vec![Index::from_index_url(index_url.clone())],
-            Vec::new(),
-            false,
-        )
-        .index_urls();
+    let check_url_client = if let Some(index) = check_index {
+        debug!("Checking for existing files in: {index}");
+        let indexes = match &index {
+            CheckIndex::Simple(index_url) => vec![Index::from_index_url(index_url.clone())],
+            CheckIndex::PypiJson(_) => Vec::new(),
+        };
+        let index_urls = IndexLocations::new(indexes, Vec::new(), false).index_urls();
         let registry_client_builder = RegistryClientBuilder::new(cache.clone())
             .native_tls(native_tls)
             .connectivity(connectivity)
@@ -84,7 +101,7 @@ pub(crate) async fn publish(
             .keyring(keyring_provider)
             .allow_insecure_host(allow_insecure_host.to_vec());
         Some(CheckUrlClient {
-            index_url,
+            index,
             registry_client_builder,
             client: &upload_client,
             index_capabilities: IndexCapabilities::default(),
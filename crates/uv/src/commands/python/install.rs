@@ -16,7 +16,7 @@ use uv_configuration::TrustedHost;
 use uv_fs::Simplified;
 use uv_python::downloads::{DownloadResult, ManagedPythonDownload, PythonDownloadRequest};
 use uv_python::managed::{
-    python_executable_dir, ManagedPythonInstallation, ManagedPythonInstallations,
+    python_executable_dir, ManagedPythonInstallation, ManagedPythonInstallations, RegistryScope,
 };
 use uv_python::{
     PythonDownloads, PythonInstallationKey, PythonRequest, PythonVersionFile,
@@ -115,6 +115,7 @@ pub(crate) async fn install(
     force: bool,
     python_install_mirror: Option<String>,
     pypy_install_mirror: Option<String>,
+    registry: Option<RegistryScope>,
     python_downloads: PythonDownloads,
     native_tls: bool,
     connectivity: Connectivity,
@@ -275,12 +276,29 @@ pub(crate) async fn install(
         None
     };
 
+    if registry.is_some() && !cfg!(windows) {
+        warn_user!(
+            "Registering Python installations in the Windows registry is only supported on Windows"
+        );
+    }
+
     // Ensure that the installations are _complete_ for both downloaded installations and existing
     // installations that match the request
     for installation in downloaded.iter().chain(satisfied.iter().copied()) {
         installation.ensure_externally_managed()?;
         installation.ensure_canonical_executables()?;
 
+        #[cfg(windows)]
+        if let Some(scope) = registry {
+            match installation.register(scope) {
+                Ok(()) => debug!(
+                    "Registered {} in the Windows registry ({scope})",
+                    installation.key()
+                ),
+                Err(err) => errors.push((installation.key(), anyhow::Error::new(err))),
+            }
+        }
+
         if preview.is_disabled() {
             debug!("Skipping installation of Python executables, use `--preview` to enable.");
             continue;
@@ -1,15 +1,18 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use owo_colors::OwoColorize;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
 use uv_cache::Cache;
 use uv_fs::Simplified;
-use uv_python::downloads::PythonDownloadRequest;
+use uv_python::downloads::{ManagedPythonDownload, PythonDownloadRequest};
 use uv_python::{
     find_python_installations, DiscoveryError, EnvironmentPreference, PythonDownloads,
-    PythonInstallation, PythonNotFound, PythonPreference, PythonRequest, PythonSource,
+    PythonInstallation, PythonInstallationKey, PythonNotFound, PythonPreference, PythonRequest,
+    PythonSource,
 };
 
 use crate::commands::ExitStatus;
@@ -23,18 +26,53 @@ enum Kind {
     System,
 }
 
+/// The source of a Python version, as displayed in the JSON output.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Source {
+    /// The Python version is available for download, but not installed.
+    Download,
+    /// The Python version is installed and managed by uv.
+    Managed,
+    /// The Python version was installed by `pyenv`.
+    Pyenv,
+    /// The Python version was installed by some other means, e.g., the system package manager.
+    System,
+}
+
+/// A Python version, as displayed in the JSON output.
+#[derive(Debug, Serialize)]
+struct Entry {
+    key: String,
+    version: String,
+    implementation: String,
+    os: String,
+    arch: String,
+    libc: String,
+    variant: String,
+    source: Source,
+    path: Option<String>,
+    symlink: Option<String>,
+    url: Option<&'static str>,
+    sha256: Option<&'static str>,
+    size: Option<u64>,
+    end_of_life: Option<&'static str>,
+}
+
 /// List available Python installations.
 #[allow(clippy::too_many_arguments)]
 pub(crate) async fn list(
     kinds: PythonListKinds,
     all_versions: bool,
     all_platforms: bool,
+    json: bool,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let mut output = BTreeSet::new();
+    let mut downloads_by_key = FxHashMap::default();
     if python_preference != PythonPreference::OnlySystem {
         let download_request = match kinds {
             PythonListKinds::Installed => None,
@@ -62,6 +100,7 @@ pub(crate) async fn list(
 
         for download in downloads {
             output.insert((download.key().clone(), Kind::Download, None));
+            downloads_by_key.insert(download.key().clone(), download);
         }
     };
 
@@ -140,15 +179,28 @@ pub(crate) async fn list(
                 }
             }
         }
-        include.push((key, path));
+        include.push((key, kind, path));
+    }
+
+    if json {
+        let entries = include
+            .into_iter()
+            .map(|(key, kind, path)| entry(key, kind, path.as_deref(), &downloads_by_key))
+            .collect::<Result<Vec<_>>>()?;
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&entries)?
+        )?;
+        return Ok(ExitStatus::Success);
     }
 
     // Compute the width of the first column.
     let width = include
         .iter()
-        .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
+        .fold(0usize, |acc, (key, ..)| acc.max(key.to_string().len()));
 
-    for (key, path) in include {
+    for (key, _, path) in include {
         let key = key.to_string();
         if let Some(path) = path {
             let is_symlink = fs_err::symlink_metadata(path)?.is_symlink();
@@ -177,3 +229,55 @@ pub(crate) async fn list(
 
     Ok(ExitStatus::Success)
 }
+
+/// Construct the JSON [`Entry`] for a Python version.
+fn entry(
+    key: &PythonInstallationKey,
+    kind: &Kind,
+    path: Option<&Path>,
+    downloads: &FxHashMap<PythonInstallationKey, &'static ManagedPythonDownload>,
+) -> Result<Entry> {
+    let source = match kind {
+        Kind::Download => Source::Download,
+        Kind::Managed => Source::Managed,
+        Kind::System if path.is_some_and(is_pyenv) => Source::Pyenv,
+        Kind::System => Source::System,
+    };
+    let symlink = match path {
+        Some(path) if fs_err::symlink_metadata(path)?.is_symlink() => {
+            Some(path.read_link()?.simplified_display().to_string())
+        }
+        _ => None,
+    };
+    let download = matches!(kind, Kind::Download)
+        .then(|| downloads.get(key).copied())
+        .flatten();
+    Ok(Entry {
+        key: key.to_string(),
+        version: key.version().to_string(),
+        implementation: key.implementation().to_string(),
+        os: key.os().to_string(),
+        arch: key.arch().to_string(),
+        libc: key.libc().to_string(),
+        variant: key.variant().to_string(),
+        source,
+        path: path.map(|path| path.simplified_display().to_string()),
+        symlink,
+        url: download.map(|download| download.url()),
+        sha256: download.and_then(|download| download.sha256()),
+        size: download.and_then(|download| download.size()),
+        end_of_life: key.end_of_life(),
+    })
+}
+
+/// Returns `true` if the Python executable at the given path was installed by `pyenv` (or
+/// `pyenv-win`), i.e., if it's within the `versions` directory of a `pyenv` root.
+fn is_pyenv(path: &Path) -> bool {
+    path.ancestors().any(|ancestor| {
+        ancestor.file_name().is_some_and(|name| name == "versions")
+            && ancestor
+                .parent()
+                .and_then(Path::file_name)
+                .is_some_and(|name| name.to_string_lossy().contains("pyenv"))
+    })
+}
@@ -3,6 +3,7 @@ pub(crate) mod find;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod pin;
+pub(crate) mod refresh;
 pub(crate) mod uninstall;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
@@ -0,0 +1,33 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+
+use uv_cache::{rm_rf, Cache, CacheBucket};
+use uv_fs::Simplified;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Invalidate the cached interpreter information, such that every Python interpreter is queried
+/// again on next use.
+pub(crate) fn refresh(cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    let bucket = cache.bucket(CacheBucket::Interpreter);
+
+    let summary = rm_rf(&bucket).with_context(|| {
+        format!(
+            "Failed to clear interpreter cache at: {}",
+            bucket.user_display()
+        )
+    })?;
+
+    match summary.num_files {
+        0 => writeln!(printer.stderr(), "No cached interpreters found")?,
+        1 => writeln!(printer.stderr(), "Invalidated 1 cached interpreter")?,
+        num_files => writeln!(
+            printer.stderr(),
+            "Invalidated {num_files} cached interpreters"
+        )?,
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -1,17 +1,26 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use console::Term;
 use futures::stream::FuturesUnordered;
 use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 
 use tracing::{debug, warn};
+use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_python::downloads::PythonDownloadRequest;
-use uv_python::managed::{python_executable_dir, ManagedPythonInstallations};
-use uv_python::PythonRequest;
+use uv_python::managed::{
+    python_executable_dir, ManagedPythonInstallation, ManagedPythonInstallations,
+};
+use uv_python::{Interpreter, PyVenvConfiguration, PythonRequest};
+use uv_static::EnvVars;
+use uv_tool::InstalledTools;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace};
 
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
 use crate::commands::{elapsed, ExitStatus};
@@ -19,16 +28,27 @@ use crate::printer::Printer;
 
 /// Uninstall managed Python versions.
 pub(crate) async fn uninstall(
+    project_dir: &Path,
     targets: Vec<String>,
     all: bool,
-
+    migrate: bool,
+    cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let installations = ManagedPythonInstallations::from_settings()?.init()?;
     let _lock = installations.lock().await?;
 
     // Perform the uninstallation.
-    do_uninstall(&installations, targets, all, printer).await?;
+    do_uninstall(
+        &installations,
+        project_dir,
+        targets,
+        all,
+        migrate,
+        cache,
+        printer,
+    )
+    .await?;
 
     // Clean up any empty directories.
     if uv_fs::directories(installations.root()).all(|path| uv_fs::is_temporary(&path)) {
@@ -54,8 +74,11 @@ pub(crate) async fn uninstall(
 /// Perform the uninstallation of managed Python installations.
 async fn do_uninstall(
     installations: &ManagedPythonInstallations,
+    project_dir: &Path,
     targets: Vec<String>,
     all: bool,
+    migrate: bool,
+    cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -123,6 +146,18 @@ async fn do_uninstall(
         return Ok(ExitStatus::Failure);
     }
 
+    // Migrate any environments that use the installations, before they're removed.
+    if migrate {
+        migrate_environments(
+            &matching_installations,
+            &installed_installations,
+            project_dir,
+            cache,
+            printer,
+        )
+        .await?;
+    }
+
     // Collect files in a directory
     let executables = python_executable_dir()?
         .read_dir()
@@ -159,6 +194,21 @@ async fn do_uninstall(
         debug!("Removed {}", executable.user_display());
     }
 
+    // Remove any registrations in the Windows registry, in either scope.
+    #[cfg(windows)]
+    for installation in &matching_installations {
+        for scope in uv_python::managed::RegistryScope::ALL {
+            match installation.deregister(scope) {
+                Ok(true) => debug!(
+                    "Removed {} from the Windows registry ({scope})",
+                    installation.key()
+                ),
+                Ok(false) => {}
+                Err(err) => warn_user!("{:#}", anyhow::Error::new(err)),
+            }
+        }
+    }
+
     let mut tasks = FuturesUnordered::new();
     for installation in &matching_installations {
         tasks.push(async {
@@ -246,3 +296,124 @@ async fn do_uninstall(
 
     Ok(ExitStatus::Success)
 }
+
+/// Re-point any environments that use the given installations to a compatible installation that
+/// will remain installed, reporting any environments that can't be migrated.
+async fn migrate_environments(
+    removed: &BTreeSet<ManagedPythonInstallation>,
+    installed: &[ManagedPythonInstallation],
+    project_dir: &Path,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<()> {
+    let term = Term::stderr();
+
+    for (label, path) in environments(project_dir).await {
+        let Ok(pyvenv_cfg) = PyVenvConfiguration::parse(path.join("pyvenv.cfg")) else {
+            continue;
+        };
+        let Some(home) = pyvenv_cfg.home() else {
+            continue;
+        };
+        let Some(installation) = removed
+            .iter()
+            .find(|installation| is_within(home, installation.path()))
+        else {
+            continue;
+        };
+
+        // Find the latest compatible installation that isn't being removed.
+        let replacement = installed
+            .iter()
+            .filter(|candidate| !removed.contains(*candidate))
+            .filter(|candidate| candidate.can_replace(installation))
+            .max_by(|a, b| a.key().cmp(b.key()));
+
+        let Some(replacement) = replacement else {
+            warn_user!(
+                "The {label} at `{}` uses {}, and no other Python {}.{} installation is available to migrate it to; recreate the environment with a different Python version",
+                path.user_display(),
+                installation.key().cyan(),
+                installation.key().version().major(),
+                installation.key().version().minor(),
+            );
+            continue;
+        };
+
+        if term.is_term() {
+            let prompt = format!(
+                "Migrate the {label} at `{}` to {}?",
+                path.user_display(),
+                replacement.key().cyan()
+            );
+            if !uv_console::confirm(&prompt, &term, true)? {
+                warn_user!(
+                    "The {label} at `{}` was not migrated, and will not be usable once {} is uninstalled",
+                    path.user_display(),
+                    installation.key().cyan()
+                );
+                continue;
+            }
+        }
+
+        let interpreter = Interpreter::query(replacement.executable(), cache)?;
+        uv_virtualenv::repoint_venv(&path, &interpreter)?;
+
+        writeln!(
+            printer.stderr(),
+            "Migrated {label} to {}: {}",
+            format!("Python {}", replacement.key().version()).bold(),
+            path.user_display().cyan()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Return the environments that may use a managed Python installation, i.e., the tool
+/// environments, the project environment, and the active virtual environment, alongside a label
+/// for each.
+async fn environments(project_dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut environments = Vec::new();
+
+    if let Ok(installed_tools) = InstalledTools::from_settings() {
+        for (name, _) in installed_tools.tools().unwrap_or_default() {
+            let path = installed_tools.tool_dir(&name);
+            environments.push((format!("environment for tool `{name}`"), path));
+        }
+    }
+
+    if let Ok(workspace) = Workspace::discover(project_dir, &DiscoveryOptions::default()).await {
+        environments.push(("project environment".to_string(), workspace.venv()));
+    }
+
+    if let Some(path) = std::env::var_os(EnvVars::VIRTUAL_ENV).filter(|path| !path.is_empty()) {
+        environments.push((
+            "active virtual environment".to_string(),
+            PathBuf::from(path),
+        ));
+    }
+
+    // Skip any environments that were discovered more than once (e.g., the project environment,
+    // when it's active).
+    let mut seen = BTreeSet::new();
+    environments.retain(|(_, path)| {
+        seen.insert(uv_fs::canonicalize_executable(path).unwrap_or_else(|_| path.clone()))
+    });
+
+    environments
+}
+
+/// Returns `true` if the `home` directory of an environment is within the installation directory.
+fn is_within(home: &Path, installation: &Path) -> bool {
+    if home.starts_with(installation) {
+        return true;
+    }
+    match (
+        fs_err::canonicalize(home),
+        fs_err::canonicalize(installation),
+    ) {
+        (Ok(home), Ok(installation)) => home.starts_with(installation),
+        _ => false,
+    }
+}
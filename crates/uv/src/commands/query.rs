@@ -0,0 +1,415 @@
+//! A stable, machine-readable interface for editors and other tools (`uv query`).
+//!
+//! Every query writes exactly one JSON object to stdout, tagged with [`SCHEMA_VERSION`]. Within a
+//! schema version, fields may be added, but existing fields are never removed or repurposed.
+use std::path::{Path, PathBuf};
+
+use anstream::println;
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_configuration::{Concurrency, LowerBound, TrustedHost};
+use uv_distribution_types::{DistributionMetadata, Name, Resolution, VersionOrUrlRef};
+use uv_fs::Simplified;
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::Version;
+use uv_platform_tags::TagPreferences;
+use uv_python::{
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest,
+};
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_settings::PythonInstallMirrors;
+use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceError};
+
+use crate::commands::pip::loggers::SummaryResolveLogger;
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::{
+    self, resolve_environment, validate_requires_python, ProjectError, ProjectInterpreter,
+    WorkspacePython,
+};
+use crate::commands::{ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// The version of the `uv query` output schema.
+const SCHEMA_VERSION: u32 = 1;
+
+/// The top-level object written by every query.
+#[derive(Debug, Serialize)]
+struct Envelope<T> {
+    schema_version: u32,
+    query: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<QueryError>,
+}
+
+/// A query failure, with the full chain of causes.
+#[derive(Debug, Serialize)]
+struct QueryError {
+    message: String,
+    causes: Vec<String>,
+}
+
+/// Write the outcome of a query to stdout.
+fn emit<T: Serialize>(query: &'static str, result: Result<T>) -> Result<ExitStatus> {
+    let (envelope, status) = match result {
+        Ok(result) => (
+            Envelope {
+                schema_version: SCHEMA_VERSION,
+                query,
+                result: Some(result),
+                error: None,
+            },
+            ExitStatus::Success,
+        ),
+        Err(err) => (
+            Envelope {
+                schema_version: SCHEMA_VERSION,
+                query,
+                result: None,
+                error: Some(QueryError {
+                    message: err.to_string(),
+                    causes: err.chain().skip(1).map(ToString::to_string).collect(),
+                }),
+            },
+            ExitStatus::Failure,
+        ),
+    };
+    println!("{}", serde_json::to_string(&envelope)?);
+    Ok(status)
+}
+
+#[derive(Debug, Serialize)]
+struct Dependents {
+    package: PackageName,
+    dependents: Vec<DependentPackage>,
+}
+
+#[derive(Debug, Serialize)]
+struct DependentPackage {
+    name: PackageName,
+    version: Version,
+    /// The extra that declares the dependency, if it's an optional dependency.
+    extra: Option<ExtraName>,
+    /// The dependency group that declares the dependency, if it's a development dependency.
+    group: Option<GroupName>,
+}
+
+/// List the packages in the lockfile that depend directly on the given package.
+pub(crate) async fn query_dependents(
+    project_dir: &Path,
+    package: PackageName,
+) -> Result<ExitStatus> {
+    let result = async {
+        let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+        let Some(lock) = project::lock::read(&workspace).await? else {
+            return Err(anyhow!(
+                "No lockfile found for the workspace at `{}`; run `uv lock` to create one",
+                workspace.install_path().user_display()
+            ));
+        };
+        if !lock.packages().iter().any(|pkg| *pkg.name() == package) {
+            return Err(anyhow!("Package `{package}` was not found in the lockfile"));
+        }
+
+        let mut dependents = lock
+            .dependents(&package)
+            .into_iter()
+            .map(|dependent| DependentPackage {
+                name: dependent.package.name().clone(),
+                version: dependent.package.version().clone(),
+                extra: dependent.extra.cloned(),
+                group: dependent.group.cloned(),
+            })
+            .collect::<Vec<_>>();
+        dependents.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+
+        Ok(Dependents {
+            package,
+            dependents,
+        })
+    }
+    .await;
+
+    emit("dependents", result)
+}
+
+#[derive(Debug, Serialize)]
+struct Resolved {
+    /// The Python version that the requirements were resolved against.
+    python_version: String,
+    packages: Vec<ResolvedPackage>,
+}
+
+#[derive(Debug, Serialize)]
+struct ResolvedPackage {
+    name: PackageName,
+    /// The resolved version, for registry distributions.
+    version: Option<String>,
+    /// The resolved URL, for direct URL, Git, and path distributions.
+    url: Option<String>,
+}
+
+/// Resolve the given requirements against the current interpreter, without installing them.
+pub(crate) async fn query_resolve(
+    requirements: Vec<String>,
+    python: Option<String>,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+) -> Result<ExitStatus> {
+    let result = async {
+        let interpreter = PythonInstallation::find(
+            &python
+                .as_deref()
+                .map(PythonRequest::parse)
+                .unwrap_or_default(),
+            EnvironmentPreference::Any,
+            python_preference,
+            cache,
+        )?
+        .into_interpreter();
+
+        let sources = requirements
+            .into_iter()
+            .map(RequirementsSource::Package)
+            .collect::<Vec<_>>();
+        let client_builder = BaseClientBuilder::new()
+            .connectivity(connectivity)
+            .native_tls(native_tls)
+            .allow_insecure_host(allow_insecure_host.to_vec());
+        let spec =
+            RequirementsSpecification::from_simple_sources(&sources, &client_builder).await?;
+
+        let graph = resolve_environment(
+            spec.into(),
+            &interpreter,
+            settings.as_ref(),
+            &SharedState::default(),
+            Box::new(SummaryResolveLogger),
+            connectivity,
+            concurrency,
+            tag_preferences,
+            native_tls,
+            allow_insecure_host,
+            cache,
+            Printer::Quiet,
+        )
+        .await?;
+
+        let resolution = Resolution::from(graph);
+        let mut packages = resolution
+            .distributions()
+            .map(|dist| {
+                let (version, url) = match dist.version_or_url() {
+                    VersionOrUrlRef::Version(version) => (Some(version.to_string()), None),
+                    VersionOrUrlRef::Url(url) => (None, Some(url.to_string())),
+                };
+                ResolvedPackage {
+                    name: dist.name().clone(),
+                    version,
+                    url,
+                }
+            })
+            .collect::<Vec<_>>();
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(Resolved {
+            python_version: interpreter.python_full_version().to_string(),
+            packages,
+        })
+    }
+    .await;
+
+    emit("resolve", result)
+}
+
+#[derive(Debug, Serialize)]
+struct InterpreterInfo {
+    executable: PathBuf,
+    sys_prefix: PathBuf,
+    version: String,
+    implementation: String,
+    virtualenv: bool,
+    /// The `requires-python` of the current project, if any.
+    requires_python: Option<String>,
+    /// Whether the interpreter satisfies the project's `requires-python`, if any.
+    satisfies_requires_python: Option<bool>,
+}
+
+impl InterpreterInfo {
+    fn from_interpreter(interpreter: &Interpreter) -> Result<Self> {
+        Ok(Self {
+            executable: std::path::absolute(interpreter.sys_executable())?
+                .simplified()
+                .to_path_buf(),
+            sys_prefix: interpreter.sys_prefix().simplified().to_path_buf(),
+            version: interpreter.python_full_version().to_string(),
+            implementation: interpreter.implementation_name().to_string(),
+            virtualenv: interpreter.is_virtualenv(),
+            requires_python: None,
+            satisfies_requires_python: None,
+        })
+    }
+}
+
+/// Show the Python interpreter that uv would use in the current directory.
+pub(crate) async fn query_interpreter(
+    project_dir: &Path,
+    request: Option<String>,
+    python_preference: PythonPreference,
+    no_config: bool,
+    cache: &Cache,
+) -> Result<ExitStatus> {
+    let result = async {
+        let project =
+            match VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await {
+                Ok(project) => Some(project),
+                Err(
+                    WorkspaceError::MissingProject(_)
+                    | WorkspaceError::MissingPyprojectToml
+                    | WorkspaceError::NonWorkspace(_),
+                ) => None,
+                Err(err) => return Err(err.into()),
+            };
+
+        let WorkspacePython {
+            source,
+            python_request,
+            requires_python,
+        } = WorkspacePython::from_request(
+            request.as_deref().map(PythonRequest::parse),
+            project.as_ref().map(VirtualProject::workspace),
+            project_dir,
+            no_config,
+        )
+        .await?;
+
+        let python = PythonInstallation::find(
+            &python_request.unwrap_or_default(),
+            EnvironmentPreference::Any,
+            python_preference,
+            cache,
+        )?;
+
+        let mut info = InterpreterInfo::from_interpreter(python.interpreter())?;
+        if let Some(requires_python) = requires_python {
+            info.satisfies_requires_python = Some(
+                validate_requires_python(
+                    python.interpreter(),
+                    project.as_ref().map(VirtualProject::workspace),
+                    &requires_python,
+                    &source,
+                )
+                .is_ok(),
+            );
+            info.requires_python = Some(requires_python.specifiers().to_string());
+        }
+
+        Ok(info)
+    }
+    .await;
+
+    emit("interpreter", result)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum LockStatus {
+    /// The lockfile does not exist.
+    Missing,
+    /// The lockfile exists and satisfies the project requirements.
+    UpToDate,
+    /// The lockfile exists, but would change if the project were re-locked.
+    Outdated,
+}
+
+#[derive(Debug, Serialize)]
+struct LockStatusInfo {
+    lockfile: PathBuf,
+    status: LockStatus,
+}
+
+/// Report whether the project's lockfile is missing, up-to-date, or outdated.
+pub(crate) async fn query_lock_status(
+    project_dir: &Path,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+) -> Result<ExitStatus> {
+    let result = async {
+        let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+        let lockfile = workspace.install_path().join("uv.lock");
+
+        if project::lock::read(&workspace).await?.is_none() {
+            return Ok(LockStatusInfo {
+                lockfile,
+                status: LockStatus::Missing,
+            });
+        }
+
+        let interpreter = ProjectInterpreter::discover(
+            &workspace,
+            project_dir,
+            python.as_deref().map(PythonRequest::parse),
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            allow_insecure_host,
+            install_mirrors,
+            no_config,
+            cache,
+            Printer::Quiet,
+        )
+        .await?
+        .into_interpreter();
+
+        let status = match do_safe_lock(
+            LockMode::Locked(&interpreter),
+            &workspace,
+            settings.as_ref(),
+            LowerBound::Allow,
+            false,
+            &SharedState::default(),
+            Box::new(SummaryResolveLogger),
+            connectivity,
+            concurrency,
+            tag_preferences,
+            native_tls,
+            allow_insecure_host,
+            cache,
+            Printer::Quiet,
+        )
+        .await
+        {
+            Ok(_) => LockStatus::UpToDate,
+            Err(ProjectError::LockMismatch) => LockStatus::Outdated,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(LockStatusInfo { lockfile, status })
+    }
+    .await;
+
+    emit("lock-status", result)
+}
@@ -1,6 +1,6 @@
 use std::env;
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
@@ -15,6 +15,7 @@ use uv_python::PythonInstallationKey;
 use uv_static::EnvVars;
 
 use crate::printer::Printer;
+use crate::progress::{self, Phase, ProgressEvent};
 
 #[derive(Debug)]
 struct ProgressReporter {
@@ -32,6 +33,8 @@ enum ProgressMode {
         multi_progress: MultiProgress,
         state: Arc<Mutex<BarState>>,
     },
+    /// Reports progress as JSON events, in lieu of progress bars.
+    Json { state: Arc<Mutex<JsonState>> },
 }
 
 #[derive(Default, Debug)]
@@ -54,9 +57,42 @@ impl BarState {
     }
 }
 
+/// The minimum interval between JSON progress events for a single download.
+const JSON_DOWNLOAD_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Default, Debug)]
+struct JsonState {
+    /// The in-flight downloads, by download ID.
+    downloads: FxHashMap<usize, JsonDownload>,
+    /// A monotonic counter for event IDs.
+    id: usize,
+}
+
+#[derive(Debug)]
+struct JsonDownload {
+    /// The bytes downloaded so far.
+    bytes: u64,
+    /// The total size of the download, if known.
+    total_bytes: Option<u64>,
+    /// The time at which progress was last reported, if at all.
+    reported: Option<Instant>,
+}
+
+impl JsonState {
+    /// Returns a unique ID for a new download, build, or checkout.
+    fn id(&mut self) -> usize {
+        self.id += 1;
+        self.id
+    }
+}
+
 impl ProgressReporter {
     fn new(root: ProgressBar, multi_progress: MultiProgress, printer: Printer) -> ProgressReporter {
-        let mode = if env::var(EnvVars::JPY_SESSION_NAME).is_ok() {
+        let mode = if progress::is_enabled() {
+            ProgressMode::Json {
+                state: Arc::default(),
+            }
+        } else if env::var(EnvVars::JPY_SESSION_NAME).is_ok() {
             // Disable concurrent progress bars when running inside a Jupyter notebook
             // because the Jupyter terminal does not support clearing previous lines.
             // See: https://github.com/astral-sh/uv/issues/3887.
@@ -75,7 +111,42 @@ impl ProgressReporter {
         }
     }
 
+    /// Report the start of a phase, if reporting JSON events.
+    fn on_phase_start(&self, phase: Phase) {
+        if let ProgressMode::Json { .. } = &self.mode {
+            progress::emit(&ProgressEvent::PhaseStart { phase });
+        }
+    }
+
+    /// Report progress on a phase, based on the state of the root bar, if reporting JSON events.
+    fn on_phase_progress(&self, phase: Phase, package: Option<String>) {
+        if let ProgressMode::Json { .. } = &self.mode {
+            progress::emit(&ProgressEvent::PhaseProgress {
+                phase,
+                package,
+                completed: self.root.position(),
+                total: self.root.length(),
+            });
+        }
+    }
+
+    /// Report the completion of a phase, if reporting JSON events.
+    fn on_phase_complete(&self, phase: Phase) {
+        if let ProgressMode::Json { .. } = &self.mode {
+            progress::emit(&ProgressEvent::PhaseComplete { phase });
+        }
+    }
+
     fn on_build_start(&self, source: &BuildableSource) -> usize {
+        if let ProgressMode::Json { state } = &self.mode {
+            let id = state.lock().unwrap().id();
+            progress::emit(&ProgressEvent::BuildStart {
+                id,
+                source: source.to_string(),
+            });
+            return id;
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -105,6 +176,14 @@ impl ProgressReporter {
     }
 
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        if let ProgressMode::Json { .. } = &self.mode {
+            progress::emit(&ProgressEvent::BuildComplete {
+                id,
+                source: source.to_string(),
+            });
+            return;
+        }
+
         let ProgressMode::Multi { state, .. } = &self.mode else {
             return;
         };
@@ -123,6 +202,28 @@ impl ProgressReporter {
     }
 
     fn on_download_start(&self, name: String, size: Option<u64>) -> usize {
+        if let ProgressMode::Json { state } = &self.mode {
+            let id = {
+                let mut state = state.lock().unwrap();
+                let id = state.id();
+                state.downloads.insert(
+                    id,
+                    JsonDownload {
+                        bytes: 0,
+                        total_bytes: size,
+                        reported: None,
+                    },
+                );
+                id
+            };
+            progress::emit(&ProgressEvent::DownloadStart {
+                id,
+                name: &name,
+                total_bytes: size,
+            });
+            return id;
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -165,6 +266,36 @@ impl ProgressReporter {
     }
 
     fn on_download_progress(&self, id: usize, bytes: u64) {
+        if let ProgressMode::Json { state } = &self.mode {
+            let (bytes, total_bytes) = {
+                let mut state = state.lock().unwrap();
+                let Some(download) = state.downloads.get_mut(&id) else {
+                    return;
+                };
+                download.bytes += bytes;
+
+                // Downloads report progress for every chunk, so throttle the events, while
+                // always reporting the final chunk.
+                let now = Instant::now();
+                let finished = download.total_bytes == Some(download.bytes);
+                if !finished
+                    && download.reported.is_some_and(|reported| {
+                        now.duration_since(reported) < JSON_DOWNLOAD_INTERVAL
+                    })
+                {
+                    return;
+                }
+                download.reported = Some(now);
+                (download.bytes, download.total_bytes)
+            };
+            progress::emit(&ProgressEvent::DownloadProgress {
+                id,
+                bytes,
+                total_bytes,
+            });
+            return;
+        }
+
         let ProgressMode::Multi { state, .. } = &self.mode else {
             return;
         };
@@ -173,6 +304,12 @@ impl ProgressReporter {
     }
 
     fn on_download_complete(&self, id: usize) {
+        if let ProgressMode::Json { state } = &self.mode {
+            state.lock().unwrap().downloads.remove(&id);
+            progress::emit(&ProgressEvent::DownloadComplete { id });
+            return;
+        }
+
         let ProgressMode::Multi { state, .. } = &self.mode else {
             return;
         };
@@ -182,6 +319,16 @@ impl ProgressReporter {
     }
 
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize {
+        if let ProgressMode::Json { state } = &self.mode {
+            let id = state.lock().unwrap().id();
+            progress::emit(&ProgressEvent::CheckoutStart {
+                id,
+                url: url.as_str(),
+                rev,
+            });
+            return id;
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -213,6 +360,15 @@ impl ProgressReporter {
     }
 
     fn on_checkout_complete(&self, url: &Url, rev: &str, id: usize) {
+        if let ProgressMode::Json { .. } = &self.mode {
+            progress::emit(&ProgressEvent::CheckoutComplete {
+                id,
+                url: url.as_str(),
+                rev,
+            });
+            return;
+        }
+
         let ProgressMode::Multi { state, .. } = &self.mode else {
             return;
         };
@@ -250,6 +406,7 @@ impl From<Printer> for PrepareReporter {
         root.set_message("Preparing packages...");
 
         let reporter = ProgressReporter::new(root, multi_progress, printer);
+        reporter.on_phase_start(Phase::Prepare);
         Self { reporter }
     }
 }
@@ -263,8 +420,10 @@ impl PrepareReporter {
 }
 
 impl uv_installer::PrepareReporter for PrepareReporter {
-    fn on_progress(&self, _dist: &CachedDist) {
+    fn on_progress(&self, dist: &CachedDist) {
         self.reporter.root.inc(1);
+        self.reporter
+            .on_phase_progress(Phase::Prepare, Some(dist.to_string()));
     }
 
     fn on_complete(&self) {
@@ -272,6 +431,7 @@ impl uv_installer::PrepareReporter for PrepareReporter {
         // in Jupyter notebooks.
         self.reporter.root.set_message("");
         self.reporter.root.finish_and_clear();
+        self.reporter.on_phase_complete(Phase::Prepare);
     }
 
     fn on_build_start(&self, source: &BuildableSource) -> usize {
@@ -329,25 +489,30 @@ impl From<Printer> for ResolverReporter {
         root.set_message("Resolving dependencies...");
 
         let reporter = ProgressReporter::new(root, multi_progress, printer);
+        reporter.on_phase_start(Phase::Resolve);
         Self { reporter }
     }
 }
 
 impl uv_resolver::ResolverReporter for ResolverReporter {
     fn on_progress(&self, name: &PackageName, version_or_url: &VersionOrUrlRef) {
-        match version_or_url {
-            VersionOrUrlRef::Version(version) => {
-                self.reporter.root.set_message(format!("{name}=={version}"));
-            }
-            VersionOrUrlRef::Url(url) => {
-                self.reporter.root.set_message(format!("{name} @ {url}"));
-            }
+        let message = match version_or_url {
+            VersionOrUrlRef::Version(version) => format!("{name}=={version}"),
+            VersionOrUrlRef::Url(url) => format!("{name} @ {url}"),
+        };
+        if let ProgressMode::Json { .. } = &self.reporter.mode {
+            self.reporter.root.inc(1);
+            self.reporter
+                .on_phase_progress(Phase::Resolve, Some(message));
+        } else {
+            self.reporter.root.set_message(message);
         }
     }
 
     fn on_complete(&self) {
         self.reporter.root.set_message("");
         self.reporter.root.finish_and_clear();
+        self.reporter.on_phase_complete(Phase::Resolve);
     }
 
     fn on_build_start(&self, source: &BuildableSource) -> usize {
@@ -421,6 +586,9 @@ impl From<Printer> for InstallReporter {
             ProgressStyle::with_template("{bar:20} [{pos}/{len}] {wide_msg:.dim}").unwrap(),
         );
         progress.set_message("Installing wheels...");
+        progress::emit(&ProgressEvent::PhaseStart {
+            phase: Phase::Install,
+        });
         Self { progress }
     }
 }
@@ -437,11 +605,20 @@ impl uv_installer::InstallReporter for InstallReporter {
     fn on_install_progress(&self, wheel: &CachedDist) {
         self.progress.set_message(format!("{wheel}"));
         self.progress.inc(1);
+        progress::emit(&ProgressEvent::PhaseProgress {
+            phase: Phase::Install,
+            package: Some(wheel.to_string()),
+            completed: self.progress.position(),
+            total: self.progress.length(),
+        });
     }
 
     fn on_install_complete(&self) {
         self.progress.set_message("");
         self.progress.finish_and_clear();
+        progress::emit(&ProgressEvent::PhaseComplete {
+            phase: Phase::Install,
+        });
     }
 }
 
@@ -0,0 +1,117 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, LowerBound, TrustedHost};
+use uv_fs::Simplified;
+use uv_platform_tags::TagPreferences;
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_scripts::{Pep723Item, Pep723Script};
+use uv_settings::PythonInstallMirrors;
+use uv_workspace::pyproject::MaintenancePolicy;
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{
+    do_safe_lock, report_python_support, report_unmaintained, LockMode,
+};
+use crate::commands::project::maintenance::MaintenanceChecks;
+use crate::commands::script::{report_lock_error, script_interpreter};
+use crate::commands::{ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Audit the script's locked dependencies.
+pub(crate) async fn audit(
+    script: Pep723Script,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Represent the script as a workspace, with the lockfile alongside the script.
+    let workspace = script.to_workspace()?;
+    let script = Pep723Item::Script(script);
+    let path = script.path().expect("script is local");
+
+    // Find an interpreter for the script.
+    let interpreter = script_interpreter(
+        &script,
+        python.as_deref(),
+        &install_mirrors,
+        python_preference,
+        python_downloads,
+        connectivity,
+        native_tls,
+        allow_insecure_host,
+        no_config,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Verify that the lockfile exists and is up-to-date, without updating it.
+    let lock = match do_safe_lock(
+        LockMode::Locked(&interpreter),
+        &workspace,
+        settings.as_ref(),
+        LowerBound::Allow,
+        false,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(err) => return report_lock_error(err, path, &workspace),
+    };
+
+    // Check that the locked packages support the script's `requires-python`.
+    let supported = report_python_support(&lock, printer)?;
+
+    // Check for unmaintained dependencies, treating any as a failure.
+    let maintained = report_unmaintained(
+        &workspace,
+        &MaintenanceChecks::from_workspace(&workspace).with_policy(MaintenancePolicy::Fail),
+        &lock,
+        settings.as_ref(),
+        connectivity,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await?;
+
+    if supported && maintained {
+        writeln!(
+            printer.stderr(),
+            "No problems found in the locked dependencies of `{}`",
+            path.user_display().cyan()
+        )?;
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
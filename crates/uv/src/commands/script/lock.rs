@@ -0,0 +1,120 @@
+use std::fmt::Write;
+
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, LowerBound, TrustedHost};
+use uv_platform_tags::TagPreferences;
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_scripts::{Pep723Item, Pep723Script};
+use uv_settings::PythonInstallMirrors;
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{do_safe_lock, report_upgrades, LockMode, LockResult};
+use crate::commands::script::{report_lock_error, script_interpreter};
+use crate::commands::{ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Resolve the script's dependencies into a lockfile alongside the script.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn lock(
+    script: Pep723Script,
+    locked: bool,
+    frozen: bool,
+    dry_run: bool,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    // Represent the script as a workspace, with the lockfile alongside the script.
+    let workspace = script.to_workspace()?;
+    let script = Pep723Item::Script(script);
+
+    // Determine the lock mode.
+    let interpreter;
+    let mode = if frozen {
+        LockMode::Frozen
+    } else {
+        // Find an interpreter for the script.
+        interpreter = script_interpreter(
+            &script,
+            python.as_deref(),
+            &install_mirrors,
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            allow_insecure_host,
+            no_config,
+            cache,
+            printer,
+        )
+        .await?;
+
+        if locked {
+            LockMode::Locked(&interpreter)
+        } else if dry_run {
+            LockMode::DryRun(&interpreter)
+        } else {
+            LockMode::Write(&interpreter)
+        }
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Perform the lock operation.
+    let result = do_safe_lock(
+        mode,
+        &workspace,
+        settings.as_ref(),
+        LowerBound::Warn,
+        false,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await;
+
+    match result {
+        Ok(lock) => {
+            if dry_run {
+                let changed = if let LockResult::Changed(previous, lock) = &lock {
+                    report_upgrades(previous.as_ref(), lock, printer, dry_run)?
+                } else {
+                    false
+                };
+                if !changed {
+                    writeln!(
+                        printer.stderr(),
+                        "{}",
+                        "No lockfile changes detected".bold()
+                    )?;
+                }
+            } else if let LockResult::Changed(Some(previous), lock) = &lock {
+                report_upgrades(Some(previous), lock, printer, dry_run)?;
+            }
+
+            Ok(ExitStatus::Success)
+        }
+        Err(err) => report_lock_error(err, script.path().expect("script is local"), &workspace),
+    }
+}
@@ -0,0 +1,113 @@
+use std::path::Path;
+
+use anyhow::bail;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_configuration::TrustedHost;
+use uv_fs::Simplified;
+use uv_python::{
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest,
+};
+use uv_scripts::Pep723Item;
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+use uv_workspace::Workspace;
+
+use crate::commands::project::{validate_script_requires_python, ProjectError, ScriptPython};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{diagnostics, ExitStatus};
+use crate::printer::Printer;
+
+pub(crate) mod audit;
+pub(crate) mod lock;
+pub(crate) mod outdated;
+pub(crate) mod sync;
+pub(crate) mod tree;
+pub(crate) mod vendor;
+
+/// Find (or download) an interpreter for the script, respecting its `requires-python`.
+///
+/// As in `uv run`, an interpreter that doesn't satisfy the script's `requires-python` is used
+/// with a warning, rather than an error.
+pub(crate) async fn script_interpreter(
+    script: &Pep723Item,
+    python: Option<&str>,
+    install_mirrors: &PythonInstallMirrors,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<Interpreter> {
+    let ScriptPython {
+        source,
+        python_request,
+        requires_python,
+    } = ScriptPython::from_request(python.map(PythonRequest::parse), None, script, no_config)
+        .await?;
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .allow_insecure_host(allow_insecure_host.to_vec());
+
+    let download_reporter = PythonDownloadReporter::single(printer);
+
+    let interpreter = PythonInstallation::find_or_download(
+        python_request.as_ref(),
+        EnvironmentPreference::Any,
+        python_preference,
+        python_downloads,
+        &client_builder,
+        cache,
+        Some(&download_reporter),
+        install_mirrors.python_install_mirror.clone(),
+        install_mirrors.pypy_install_mirror.clone(),
+    )
+    .await?
+    .into_interpreter();
+
+    if let Some((requires_python, requires_python_source)) = requires_python {
+        if let Err(err) = validate_script_requires_python(
+            &interpreter,
+            None,
+            &requires_python,
+            &requires_python_source,
+            &source,
+        ) {
+            warn_user!("{err}");
+        }
+    }
+
+    Ok(interpreter)
+}
+
+/// Report a failed lock operation for a script, referring to the script's lockfile (rather than
+/// `uv.lock`) if it's missing or out-of-date.
+pub(crate) fn report_lock_error(
+    err: ProjectError,
+    script: &Path,
+    workspace: &Workspace,
+) -> anyhow::Result<ExitStatus> {
+    match err {
+        ProjectError::Operation(err) => diagnostics::OperationDiagnostic::default()
+            .report(err)
+            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into())),
+        ProjectError::MissingLockfile => bail!(
+            "Unable to find lockfile at `{}`. To create a lockfile, run `uv script lock {}`.",
+            workspace.lockfile().user_display(),
+            script.user_display()
+        ),
+        ProjectError::LockMismatch => bail!(
+            "The lockfile at `{}` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv script lock {}`.",
+            workspace.lockfile().user_display(),
+            script.user_display()
+        ),
+        err => Err(err.into()),
+    }
+}
@@ -0,0 +1,180 @@
+use anstream::println;
+use anyhow::Result;
+use futures::{stream, StreamExt};
+use itertools::Itertools;
+
+use uv_cache::{Cache, Refresh};
+use uv_cache_info::Timestamp;
+use uv_client::{Connectivity, RegistryClientBuilder};
+use uv_configuration::{Concurrency, LowerBound, TrustedHost};
+use uv_distribution_types::IndexCapabilities;
+use uv_platform_tags::TagPreferences;
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_scripts::{Pep723Item, Pep723Script};
+use uv_settings::PythonInstallMirrors;
+
+use crate::commands::pip::latest::LatestClient;
+use crate::commands::pip::list::{Column, MultiZip};
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::script::{report_lock_error, script_interpreter};
+use crate::commands::{ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// List the script's locked dependencies that have newer versions available.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn outdated(
+    script: Pep723Script,
+    locked: bool,
+    frozen: bool,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Represent the script as a workspace, with the lockfile alongside the script.
+    let workspace = script.to_workspace()?;
+    let script = Pep723Item::Script(script);
+
+    // Determine the lock mode.
+    let interpreter;
+    let mode = if frozen {
+        LockMode::Frozen
+    } else {
+        // Find an interpreter for the script.
+        interpreter = script_interpreter(
+            &script,
+            python.as_deref(),
+            &install_mirrors,
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            allow_insecure_host,
+            no_config,
+            cache,
+            printer,
+        )
+        .await?;
+
+        if locked {
+            LockMode::Locked(&interpreter)
+        } else {
+            LockMode::Write(&interpreter)
+        }
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Update the lockfile, if necessary.
+    let lock = match do_safe_lock(
+        mode,
+        &workspace,
+        settings.as_ref(),
+        LowerBound::Allow,
+        false,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(err) => {
+            return report_lock_error(err, script.path().expect("script is local"), &workspace)
+        }
+    };
+
+    let capabilities = IndexCapabilities::default();
+
+    // Initialize the registry client, revalidating the index pages rather than trusting the cache.
+    let client =
+        RegistryClientBuilder::new(cache.clone().with_refresh(Refresh::All(Timestamp::now())))
+            .native_tls(native_tls)
+            .connectivity(connectivity)
+            .keyring(settings.keyring_provider)
+            .allow_insecure_host(allow_insecure_host.to_vec())
+            .build();
+
+    // Initialize the client to fetch the latest version of each package.
+    let client = LatestClient {
+        client: &client,
+        capabilities: &capabilities,
+        prerelease: lock.prerelease_mode(),
+        exclude_newer: lock.exclude_newer(),
+        requires_python: lock.requires_python(),
+        tags: None,
+    };
+
+    // Fetch the latest version of each registry package, retaining those that are out-of-date.
+    let outdated = stream::iter(lock.packages())
+        .filter_map(|package| async {
+            let index = package.index(workspace.install_path()).ok()??;
+            let latest = client
+                .find_upgrade(package.name(), Some(&index))
+                .await
+                .ok()?
+                .dist?;
+            if latest.version() > package.version() {
+                Some((package, latest.into_version()))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .sorted_by(|(a, _), (b, _)| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
+        .collect::<Vec<_>>();
+
+    if outdated.is_empty() {
+        return Ok(ExitStatus::Success);
+    }
+
+    let columns = [
+        Column {
+            header: String::from("Package"),
+            rows: outdated
+                .iter()
+                .map(|(package, _)| package.name().to_string())
+                .collect(),
+        },
+        Column {
+            header: String::from("Locked"),
+            rows: outdated
+                .iter()
+                .map(|(package, _)| package.version().to_string())
+                .collect(),
+        },
+        Column {
+            header: String::from("Latest"),
+            rows: outdated
+                .iter()
+                .map(|(_, latest)| latest.to_string())
+                .collect(),
+        },
+    ];
+
+    for elems in MultiZip(columns.iter().map(Column::fmt).collect_vec()) {
+        println!("{}", elems.join(" ").trim_end());
+    }
+
+    Ok(ExitStatus::Success)
+}
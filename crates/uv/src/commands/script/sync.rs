@@ -0,0 +1,157 @@
+use std::fmt::Write;
+
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{
+    Concurrency, DevGroupsSpecification, ExtrasSpecification, InstallOptions, LowerBound,
+    TrustedHost,
+};
+use uv_fs::Simplified;
+use uv_normalize::DEV_DEPENDENCIES;
+use uv_platform_tags::TagPreferences;
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_resolver::InstallTarget;
+use uv_scripts::{Pep723Item, Pep723Script};
+use uv_settings::PythonInstallMirrors;
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::project::environment::CachedEnvironment;
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::ProjectError;
+use crate::commands::script::{report_lock_error, script_interpreter};
+use crate::commands::{diagnostics, ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// Install the script's locked dependencies into its cached environment.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn sync(
+    script: Pep723Script,
+    locked: bool,
+    frozen: bool,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    // Represent the script as a workspace, with the lockfile alongside the script.
+    let workspace = script.to_workspace()?;
+    let script = Pep723Item::Script(script);
+    let path = script.path().expect("script is local");
+
+    // Find an interpreter for the script.
+    let interpreter = script_interpreter(
+        &script,
+        python.as_deref(),
+        &install_mirrors,
+        python_preference,
+        python_downloads,
+        connectivity,
+        native_tls,
+        allow_insecure_host,
+        no_config,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // Determine the lock mode.
+    let mode = if frozen {
+        LockMode::Frozen
+    } else if locked {
+        LockMode::Locked(&interpreter)
+    } else {
+        LockMode::Write(&interpreter)
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Update the lockfile, if necessary.
+    let lock = match do_safe_lock(
+        mode,
+        &workspace,
+        settings.as_ref().into(),
+        LowerBound::Warn,
+        false,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(err) => return report_lock_error(err, path, &workspace),
+    };
+
+    // Read the script's dependencies from the lockfile, which are recorded as the development
+    // dependencies of the (non-project) workspace root.
+    let target = InstallTarget::NonProjectWorkspace {
+        workspace: &workspace,
+        lock: &lock,
+    };
+    let resolution = target.to_resolution(
+        &interpreter.resolver_markers(),
+        &interpreter.tags(tag_preferences)?,
+        &ExtrasSpecification::None,
+        &DevGroupsSpecification::default().with_defaults(vec![DEV_DEPENDENCIES.clone()]),
+        &settings.build_options,
+        &InstallOptions::default(),
+    )?;
+
+    // Install the locked dependencies into the cached environment for the resolution.
+    let environment = match CachedEnvironment::from_resolution(
+        &resolution,
+        interpreter,
+        &settings,
+        &state,
+        Box::new(DefaultInstallLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(environment) => environment,
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::with_context("script")
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Synced environment for `{}` at: {}",
+        path.user_display().cyan(),
+        environment
+            .into_interpreter()
+            .sys_prefix()
+            .user_display()
+            .cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,171 @@
+use anstream::print;
+use anyhow::Result;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, DevGroupsSpecification, LowerBound, TrustedHost};
+use uv_normalize::DEV_DEPENDENCIES;
+use uv_pep508::PackageName;
+use uv_platform_tags::TagPreferences;
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_resolver::{PackageMap, TreeDisplay};
+use uv_scripts::{Pep723Item, Pep723Script};
+use uv_settings::PythonInstallMirrors;
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::resolution_markers;
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::tree::find_latest;
+use crate::commands::script::{report_lock_error, script_interpreter};
+use crate::commands::{ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Display the script's dependency tree.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn tree(
+    script: Pep723Script,
+    locked: bool,
+    frozen: bool,
+    universal: bool,
+    depth: u8,
+    prune: Vec<PackageName>,
+    package: Vec<PackageName>,
+    no_dedupe: bool,
+    invert: bool,
+    outdated: bool,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Represent the script as a workspace, with the lockfile alongside the script.
+    let workspace = script.to_workspace()?;
+    let script = Pep723Item::Script(script);
+
+    // Find an interpreter for the script, unless `--frozen` and `--universal` are both set.
+    let interpreter = if frozen && universal {
+        None
+    } else {
+        Some(
+            script_interpreter(
+                &script,
+                python.as_deref(),
+                &install_mirrors,
+                python_preference,
+                python_downloads,
+                connectivity,
+                native_tls,
+                allow_insecure_host,
+                no_config,
+                cache,
+                printer,
+            )
+            .await?,
+        )
+    };
+
+    // Determine the lock mode.
+    let mode = if frozen {
+        LockMode::Frozen
+    } else if locked {
+        LockMode::Locked(interpreter.as_ref().unwrap())
+    } else {
+        LockMode::Write(interpreter.as_ref().unwrap())
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Update the lockfile, if necessary.
+    let lock = match do_safe_lock(
+        mode,
+        &workspace,
+        settings.as_ref(),
+        LowerBound::Allow,
+        false,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(err) => {
+            return report_lock_error(err, script.path().expect("script is local"), &workspace)
+        }
+    };
+
+    // Determine the markers to use for resolution.
+    let markers =
+        (!universal).then(|| resolution_markers(None, None, interpreter.as_ref().unwrap()));
+
+    // The script has no package of its own in the lockfile, so, unless specific packages were
+    // requested, root the tree at the script's direct dependencies.
+    let package = if package.is_empty() && !invert {
+        script
+            .metadata()
+            .dependencies
+            .iter()
+            .flatten()
+            .filter(|requirement| {
+                markers
+                    .as_ref()
+                    .map_or(true, |markers| requirement.marker.evaluate(markers, &[]))
+            })
+            .map(|requirement| requirement.name.clone())
+            .collect()
+    } else {
+        package
+    };
+
+    // If necessary, look up the latest version of each package.
+    let (latest, unmaintained) = if outdated {
+        find_latest(
+            &lock,
+            &workspace,
+            &settings,
+            connectivity,
+            native_tls,
+            allow_insecure_host,
+            cache,
+        )
+        .await
+    } else {
+        (PackageMap::default(), PackageMap::default())
+    };
+
+    // Render the tree.
+    let tree = TreeDisplay::new(
+        &lock,
+        markers.as_ref(),
+        &latest,
+        &unmaintained,
+        depth.into(),
+        &prune,
+        &package,
+        &DevGroupsSpecification::default().with_defaults(vec![DEV_DEPENDENCIES.clone()]),
+        no_dedupe,
+        invert,
+        false,
+    );
+
+    print!("{tree}");
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,373 @@
+use std::fmt::Write;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::VendorFormat;
+use uv_client::Connectivity;
+use uv_configuration::{
+    Concurrency, DevGroupsSpecification, EditableMode, ExtrasSpecification, InstallOptions,
+    LowerBound, TargetTriple, TrustedHost,
+};
+use uv_distribution_types::{DistributionMetadata, Name, VersionOrUrlRef};
+use uv_fs::Simplified;
+use uv_normalize::{PackageName, DEV_DEPENDENCIES};
+use uv_pep440::Version;
+use uv_platform_tags::TagPreferences;
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, Target};
+use uv_resolver::{InstallTarget, RequiresPython};
+use uv_scripts::{Pep723Item, Pep723Script};
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::pip::operations::{DryRun, Modifications};
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::bundle::walk;
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::sync::do_sync;
+use crate::commands::project::ProjectError;
+use crate::commands::script::{report_lock_error, script_interpreter};
+use crate::commands::{diagnostics, ExitStatus, SharedState};
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// The bootstrap script that serves as the entrypoint of every vendored script.
+const BOOTSTRAP: &str = include_str!("vendor/__main__.py");
+
+/// The name of the manifest file written alongside the vendored dependencies.
+const MANIFEST: &str = "manifest.json";
+
+/// The manifest of a vendored script.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Manifest {
+    /// The filename of the script.
+    script: String,
+    /// The `requires-python` of the script's lockfile.
+    requires_python: RequiresPython,
+    /// The Python version that the dependencies were vendored for.
+    python_version: String,
+    /// The locked dependencies that were not vendored, since they're only available as
+    /// platform-specific wheels.
+    platform_specific: Vec<ManifestPackage>,
+}
+
+/// A platform-specific dependency in the [`Manifest`].
+#[derive(Debug, Serialize)]
+struct ManifestPackage {
+    /// The name of the package.
+    name: PackageName,
+    /// The locked version of the package.
+    version: Version,
+    /// The locked wheels of the package, from which a wheel for the target platform can be
+    /// selected.
+    wheels: Vec<ManifestWheel>,
+}
+
+/// A locked wheel of a [`ManifestPackage`].
+#[derive(Debug, Serialize)]
+struct ManifestWheel {
+    /// The filename of the wheel.
+    filename: String,
+    /// The hash of the wheel, if known.
+    hash: Option<String>,
+}
+
+/// Vendor the script and its locked pure-Python dependencies into a self-contained artifact.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn vendor(
+    script: Pep723Script,
+    format: VendorFormat,
+    output: Option<PathBuf>,
+    locked: bool,
+    frozen: bool,
+    python: Option<String>,
+    python_platform: Option<TargetTriple>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Represent the script as a workspace, with the lockfile alongside the script.
+    let workspace = script.to_workspace()?;
+    let script = Pep723Item::Script(script);
+    let path = script.path().expect("script is local");
+
+    let Some(filename) = path.file_name().and_then(|name| name.to_str()) else {
+        return Err(anyhow::anyhow!(
+            "Unable to determine the filename of `{}`",
+            path.user_display()
+        ));
+    };
+    if filename == "__main__.py" {
+        return Err(anyhow::anyhow!(
+            "Scripts named `__main__.py` can't be vendored, since the name is reserved for the entrypoint; rename `{}` to vendor it",
+            path.user_display()
+        ));
+    }
+
+    // Find an interpreter for the script. The dependencies are vendored for its Python version,
+    // but nothing is installed into its environment.
+    let interpreter = script_interpreter(
+        &script,
+        python.as_deref(),
+        &install_mirrors,
+        python_preference,
+        python_downloads,
+        connectivity,
+        native_tls,
+        allow_insecure_host,
+        no_config,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // Determine the lock mode.
+    let mode = if frozen {
+        LockMode::Frozen
+    } else if locked {
+        LockMode::Locked(&interpreter)
+    } else {
+        LockMode::Write(&interpreter)
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Update the lockfile, if necessary.
+    let lock = match do_safe_lock(
+        mode,
+        &workspace,
+        settings.as_ref().into(),
+        LowerBound::Warn,
+        false,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(err) => return report_lock_error(err, path, &workspace),
+    };
+
+    // Read the script's dependencies from the lockfile, which are recorded as the development
+    // dependencies of the (non-project) workspace root.
+    let target = InstallTarget::NonProjectWorkspace {
+        workspace: &workspace,
+        lock: &lock,
+    };
+    let dev = DevGroupsSpecification::default().with_defaults(vec![DEV_DEPENDENCIES.clone()]);
+
+    // Determine the dependencies that are required on the target platform.
+    let resolution = target.to_resolution(
+        &resolution_markers(None, python_platform.as_ref(), &interpreter),
+        &resolution_tags(
+            None,
+            python_platform.as_ref(),
+            &interpreter,
+            tag_preferences,
+        )?,
+        &ExtrasSpecification::None,
+        &dev,
+        &settings.build_options,
+        &InstallOptions::default(),
+    )?;
+    let required = resolution
+        .distributions()
+        .filter_map(|dist| match dist.version_or_url() {
+            VersionOrUrlRef::Version(version) => Some((dist.name().clone(), version.clone())),
+            VersionOrUrlRef::Url(_) => None,
+        })
+        .collect::<FxHashSet<_>>();
+
+    // Any required dependencies that are only available as platform-specific wheels are listed
+    // in the manifest, rather than vendored.
+    let platform_specific = lock
+        .packages()
+        .iter()
+        .filter(|package| package.is_platform_specific())
+        .filter(|package| required.contains(&(package.name().clone(), package.version().clone())))
+        .map(|package| ManifestPackage {
+            name: package.name().clone(),
+            version: package.version().clone(),
+            wheels: package
+                .wheels()
+                .map(|(filename, hash)| ManifestWheel {
+                    filename: filename.to_string(),
+                    hash: hash.map(ToString::to_string),
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+
+    let output = output.unwrap_or_else(|| match format {
+        VendorFormat::Zipapp => path.with_extension("pyz"),
+        VendorFormat::Directory => path.with_extension(""),
+    });
+
+    // Install into a staging directory for zip applications, or directly into the output
+    // directory otherwise.
+    let staging = cache.venv_dir()?;
+    let root = match format {
+        VendorFormat::Zipapp => staging.path().to_path_buf(),
+        VendorFormat::Directory => {
+            if output.exists() && !output.join("__main__.py").is_file() {
+                return Err(anyhow::anyhow!(
+                    "The output directory `{}` already exists and is not a vendored script",
+                    output.user_display()
+                ));
+            }
+            if output.join("site-packages").exists() {
+                fs_err::remove_dir_all(output.join("site-packages"))?;
+            }
+            fs_err::create_dir_all(&output)?;
+            output.clone()
+        }
+    };
+    let environment = PythonEnvironment::from_interpreter(interpreter)
+        .with_target(Target::from(root.join("site-packages")))?;
+
+    match do_sync(
+        target,
+        &environment,
+        &ExtrasSpecification::None,
+        &dev,
+        EditableMode::NonEditable,
+        InstallOptions::new(
+            false,
+            false,
+            platform_specific
+                .iter()
+                .map(|package| package.name.clone())
+                .collect(),
+        ),
+        Modifications::Exact,
+        DryRun::Disabled,
+        false,
+        python_platform.as_ref(),
+        settings.as_ref().into(),
+        Box::new(DefaultInstallLogger),
+        connectivity,
+        concurrency,
+        tag_preferences,
+        native_tls,
+        allow_insecure_host,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(()) => {}
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::with_context("script")
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    // Write the script, its manifest, and the entrypoint alongside the dependencies.
+    let interpreter = environment.interpreter();
+    let manifest = Manifest {
+        script: filename.to_string(),
+        requires_python: lock.requires_python().clone(),
+        python_version: format!(
+            "{}.{}",
+            interpreter.python_major(),
+            interpreter.python_minor()
+        ),
+        platform_specific,
+    };
+    fs_err::copy(path, root.join(filename))?;
+    fs_err::write(
+        root.join(MANIFEST),
+        serde_json::to_string_pretty(&manifest)? + "\n",
+    )?;
+    fs_err::write(
+        root.join("__main__.py"),
+        BOOTSTRAP.replace("{{ SCRIPT }}", filename),
+    )?;
+
+    if matches!(format, VendorFormat::Zipapp) {
+        let shebang = format!(
+            "#!/usr/bin/env python{}.{}",
+            interpreter.python_major(),
+            interpreter.python_minor()
+        );
+        write_zipapp(&output, &shebang, &root)?;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Vendored {} for Python {}.{} to: {}",
+        filename.cyan(),
+        interpreter.python_major(),
+        interpreter.python_minor(),
+        output.user_display().bold()
+    )?;
+
+    if !manifest.platform_specific.is_empty() {
+        warn_user!(
+            "The following dependencies are only available as platform-specific wheels and were not vendored; install them separately on the target machine: {}",
+            manifest
+                .platform_specific
+                .iter()
+                .map(|package| format!("{}=={}", package.name, package.version))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Write a zip application containing the contents of the given directory.
+fn write_zipapp(output: &Path, shebang: &str, root: &Path) -> Result<()> {
+    if let Some(parent) = output.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+
+    let mut file = fs_err::File::create(output)?;
+    writeln!(file, "{shebang}")?;
+
+    let mut writer = zip::ZipWriter::new(file);
+    for (path, name) in walk(root)? {
+        writer.start_file(
+            name,
+            zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated),
+        )?;
+        writer.write_all(&fs_err::read(&path)?)?;
+    }
+    writer.finish()?;
+
+    // Mark the zip application as executable.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs_err::set_permissions(output, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    Ok(())
+}
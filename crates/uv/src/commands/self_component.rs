@@ -0,0 +1,537 @@
+use std::collections::hash_map::Entry;
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Result};
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use url::Url;
+
+use uv_client::{BaseClient, BaseClientBuilder, Connectivity};
+use uv_configuration::{ComponentChannel, TrustedHost};
+use uv_distribution_filename::SourceDistExtension;
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::{LockedFile, Simplified};
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_state::{StateBucket, StateStore};
+use uv_static::EnvVars;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The platform of artifacts that can be installed on any platform.
+const ANY_PLATFORM: &str = "any";
+
+/// The index of the components available on a release channel.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ComponentIndex {
+    components: Vec<ComponentRelease>,
+}
+
+/// The latest release of a component on a channel, as listed in the channel's index.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ComponentRelease {
+    name: String,
+    version: Version,
+    /// The versions of uv with which the release is compatible.
+    #[serde(default)]
+    requires_uv: Option<VersionSpecifiers>,
+    artifacts: Vec<ComponentArtifact>,
+}
+
+/// An archive containing a component release, built for a specific platform.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ComponentArtifact {
+    /// The platform for which the artifact was built, as `<os>-<arch>` (e.g., `linux-x86_64`), or
+    /// `any`.
+    platform: String,
+    url: String,
+    sha256: String,
+}
+
+/// A `uv-receipt.toml` file tracking the installation of a component.
+#[derive(Debug, Serialize, Deserialize)]
+struct ComponentReceipt {
+    component: InstalledComponent,
+}
+
+/// A component installed via `uv self add`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct InstalledComponent {
+    name: String,
+    version: Version,
+    channel: ComponentChannel,
+    url: String,
+    sha256: String,
+}
+
+/// The directory in which components are installed.
+#[derive(Debug)]
+struct InstalledComponents {
+    root: PathBuf,
+}
+
+impl InstalledComponents {
+    /// Determine the directory from the user's data directory.
+    fn from_settings() -> Result<Self> {
+        Ok(Self {
+            root: StateStore::from_settings(None)?.bucket(StateBucket::Components),
+        })
+    }
+
+    /// Create the directory, if it doesn't exist, and acquire an exclusive lock on it.
+    async fn lock(&self) -> Result<LockedFile> {
+        fs_err::tokio::create_dir_all(&self.root).await?;
+        Ok(LockedFile::acquire(self.root.join(".lock"), self.root.user_display()).await?)
+    }
+
+    /// The directory in which the given component is installed.
+    fn component_dir(&self, name: &str) -> PathBuf {
+        self.root.join(name)
+    }
+
+    /// Read the receipt of the given component, if it's installed.
+    fn get(&self, name: &str) -> Result<Option<InstalledComponent>> {
+        let path = self.component_dir(name).join("uv-receipt.toml");
+        match fs_err::read_to_string(&path) {
+            Ok(contents) => {
+                let receipt: ComponentReceipt = toml::from_str(&contents)
+                    .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+                Ok(Some(receipt.component))
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Read the receipts of all installed components, sorted by name.
+    fn list(&self) -> Result<Vec<InstalledComponent>> {
+        let mut components = Vec::new();
+        for entry in match fs_err::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(components),
+            Err(err) => return Err(err.into()),
+        } {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            let Some(name) = entry.file_name().to_str().map(ToString::to_string) else {
+                continue;
+            };
+            match self.get(&name) {
+                Ok(Some(component)) => components.push(component),
+                Ok(None) => debug!("Ignoring component directory without a receipt: {name}"),
+                Err(err) => debug!("Ignoring invalid component `{name}`: {err}"),
+            }
+        }
+        components.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(components)
+    }
+}
+
+/// Install or upgrade the given components.
+pub(crate) async fn self_add(
+    components: &[String],
+    channel: Option<ComponentChannel>,
+    reinstall: bool,
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    for name in components {
+        validate_name(name)?;
+    }
+
+    let installed = InstalledComponents::from_settings()?;
+    let _lock = installed.lock().await?;
+
+    let client = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .allow_insecure_host(allow_insecure_host.to_vec())
+        .build();
+
+    let mut indexes: FxHashMap<ComponentChannel, ComponentIndex> = FxHashMap::default();
+    for name in components {
+        let existing = installed.get(name)?;
+
+        // Install from the requested channel, falling back to the channel of the existing
+        // installation.
+        let channel = channel
+            .or_else(|| existing.as_ref().map(|existing| existing.channel))
+            .unwrap_or_default();
+        if let Entry::Vacant(entry) = indexes.entry(channel) {
+            entry.insert(fetch_index(&client, channel).await?);
+        }
+        let release = indexes[&channel]
+            .components
+            .iter()
+            .find(|release| release.name == *name)
+            .ok_or_else(|| {
+                anyhow!("Component `{name}` is not available on the `{channel}` channel")
+            })?;
+
+        if let Some(existing) = existing.as_ref().filter(|existing| {
+            !reinstall && existing.channel == channel && existing.version == release.version
+        }) {
+            writeln!(
+                printer.stderr(),
+                "`{}` is already up-to-date ({})",
+                existing.name.cyan(),
+                format!("v{}", existing.version).bold(),
+            )?;
+            continue;
+        }
+
+        let component = install(&client, &installed, release, channel).await?;
+        match existing {
+            Some(existing) if existing.version != component.version => {
+                writeln!(
+                    printer.stderr(),
+                    "Upgraded {} from {} to {} ({})",
+                    component.name.cyan(),
+                    format!("v{}", existing.version).bold(),
+                    format!("v{}", component.version).bold(),
+                    component.channel,
+                )?;
+            }
+            _ => {
+                writeln!(
+                    printer.stderr(),
+                    "Installed {} {} ({})",
+                    component.name.cyan(),
+                    format!("v{}", component.version).bold(),
+                    component.channel,
+                )?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove the given components.
+pub(crate) async fn self_remove(components: &[String], printer: Printer) -> Result<ExitStatus> {
+    for name in components {
+        validate_name(name)?;
+    }
+
+    let installed = InstalledComponents::from_settings()?;
+    let _lock = installed.lock().await?;
+
+    for name in components {
+        let Some(component) = installed.get(name)? else {
+            bail!("`{name}` is not installed");
+        };
+        fs_err::tokio::remove_dir_all(installed.component_dir(name)).await?;
+        writeln!(
+            printer.stderr(),
+            "Removed {} {}",
+            component.name.cyan(),
+            format!("v{}", component.version).bold(),
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// List the installed components.
+pub(crate) async fn self_list(
+    outdated: bool,
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let installed = InstalledComponents::from_settings()?;
+    let components = installed.list()?;
+
+    if components.is_empty() {
+        writeln!(printer.stderr(), "No components installed")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    if !outdated {
+        for component in &components {
+            writeln!(
+                printer.stdout(),
+                "{} v{} ({})",
+                component.name.bold(),
+                component.version,
+                component.channel,
+            )?;
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    let client = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .allow_insecure_host(allow_insecure_host.to_vec())
+        .build();
+
+    let mut indexes: FxHashMap<ComponentChannel, ComponentIndex> = FxHashMap::default();
+    let mut any_outdated = false;
+    for component in &components {
+        if let Entry::Vacant(entry) = indexes.entry(component.channel) {
+            entry.insert(fetch_index(&client, component.channel).await?);
+        }
+        let Some(release) = indexes[&component.channel]
+            .components
+            .iter()
+            .find(|release| release.name == component.name)
+        else {
+            debug!(
+                "Component `{}` is no longer available on the `{}` channel",
+                component.name, component.channel
+            );
+            continue;
+        };
+        if release.version > component.version {
+            any_outdated = true;
+            writeln!(
+                printer.stdout(),
+                "{} v{} -> {} ({})",
+                component.name.bold(),
+                component.version,
+                format!("v{}", release.version).green(),
+                component.channel,
+            )?;
+        }
+    }
+
+    if !any_outdated {
+        writeln!(printer.stderr(), "All components are up-to-date")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Discover any plugins provided by installed components, i.e., executables named
+/// `uv-plugin-<name>` in a component's `bin` directory.
+pub(crate) fn component_plugins() -> Vec<uv_plugin::Plugin> {
+    let installed = match InstalledComponents::from_settings() {
+        Ok(installed) => installed,
+        Err(err) => {
+            debug!("Failed to read installed components for plugin discovery: {err}");
+            return Vec::new();
+        }
+    };
+    let components = match installed.list() {
+        Ok(components) => components,
+        Err(err) => {
+            debug!("Failed to read installed components for plugin discovery: {err}");
+            return Vec::new();
+        }
+    };
+
+    let mut plugins = Vec::new();
+    for component in components {
+        let bin = installed.component_dir(&component.name).join("bin");
+        let Ok(entries) = fs_err::read_dir(&bin) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let file_name = entry.file_name();
+            let Some(name) = file_name
+                .to_str()
+                .map(|name| {
+                    name.strip_suffix(std::env::consts::EXE_SUFFIX)
+                        .unwrap_or(name)
+                })
+                .and_then(|name| name.strip_prefix(uv_plugin::PLUGIN_PREFIX))
+            else {
+                continue;
+            };
+            plugins.push(uv_plugin::Plugin::new(name.to_string(), entry.path()));
+        }
+    }
+    plugins
+}
+
+/// Fetch the index of the components available on the given channel, from the URL in
+/// `UV_COMPONENT_INDEX_URL`.
+async fn fetch_index(client: &BaseClient, channel: ComponentChannel) -> Result<ComponentIndex> {
+    let Ok(base) = std::env::var(EnvVars::UV_COMPONENT_INDEX_URL) else {
+        bail!(
+            "No component index is configured; set `{}` to the URL of a component index",
+            EnvVars::UV_COMPONENT_INDEX_URL
+        );
+    };
+    let url = Url::parse(&format!(
+        "{}/components-{channel}.json",
+        base.trim_end_matches('/')
+    ))?;
+    debug!("Fetching component index from: {url}");
+
+    let contents = if url.scheme() == "file" {
+        let path = url
+            .to_file_path()
+            .map_err(|()| anyhow!("Invalid file URL: {url}"))?;
+        fs_err::tokio::read(path).await?
+    } else {
+        client
+            .for_host(&url)
+            .get(url.clone())
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?
+            .to_vec()
+    };
+
+    serde_json::from_slice(&contents)
+        .with_context(|| format!("Failed to parse component index: {url}"))
+}
+
+/// Download, verify, and install the given component release, replacing any existing installation.
+async fn install(
+    client: &BaseClient,
+    installed: &InstalledComponents,
+    release: &ComponentRelease,
+    channel: ComponentChannel,
+) -> Result<InstalledComponent> {
+    if let Some(requires_uv) = &release.requires_uv {
+        let version = Version::from_str(uv_version::version())?;
+        if !requires_uv.contains(&version) {
+            bail!(
+                "Component `{}` v{} requires uv {requires_uv}, but the current version is v{version}; run `uv self update` to update uv",
+                release.name,
+                release.version,
+            );
+        }
+    }
+
+    // Select the artifact for the current platform, falling back to a platform-independent
+    // artifact.
+    let platform = format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH);
+    let artifact = release
+        .artifacts
+        .iter()
+        .find(|artifact| artifact.platform == platform)
+        .or_else(|| {
+            release
+                .artifacts
+                .iter()
+                .find(|artifact| artifact.platform == ANY_PLATFORM)
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Component `{}` v{} is not available for the current platform (`{platform}`)",
+                release.name,
+                release.version,
+            )
+        })?;
+
+    let url = Url::parse(&artifact.url)?;
+    let filename = url
+        .path_segments()
+        .and_then(Iterator::last)
+        .unwrap_or_default()
+        .to_string();
+    let ext = SourceDistExtension::from_path(&filename)
+        .with_context(|| format!("Unsupported component archive: {url}"))?;
+
+    // Download the archive into a temporary directory alongside the installed components, such
+    // that the extracted component can be moved into place atomically.
+    let temp_dir = tempfile::tempdir_in(&installed.root)?;
+    let archive = if url.scheme() == "file" {
+        url.to_file_path()
+            .map_err(|()| anyhow!("Invalid file URL: {url}"))?
+    } else {
+        debug!("Downloading component `{}` from: {url}", release.name);
+        let archive = temp_dir.path().join(&filename);
+        let response = client
+            .for_host(&url)
+            .get(url.clone())
+            .send()
+            .await?
+            .error_for_status()?;
+        let mut file = fs_err::File::create(&archive)?;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            std::io::Write::write_all(&mut file, &chunk?)?;
+        }
+        archive
+    };
+
+    // Extract the archive, computing its hash along the way.
+    let extracted = temp_dir.path().join("extracted");
+    let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+    {
+        let reader = fs_err::tokio::File::open(&archive).await?;
+        let mut reader = HashReader::new(reader, &mut hashers);
+        uv_extract::stream::archive(&mut reader, ext, &extracted)
+            .await
+            .with_context(|| format!("Failed to extract: `{filename}`"))?;
+        reader.finish().await?;
+    }
+
+    let actual = HashDigest::from(hashers.remove(0)).digest;
+    if !actual.eq_ignore_ascii_case(&artifact.sha256) {
+        bail!(
+            "Hash mismatch for component `{}` v{}\n\nExpected:\n  sha256:{}\n\nComputed:\n  sha256:{actual}",
+            release.name,
+            release.version,
+            artifact.sha256,
+        );
+    }
+
+    // Use the top-level directory of the archive, if it has one.
+    let source = match uv_extract::strip_component(&extracted) {
+        Ok(top_level) => top_level,
+        Err(uv_extract::Error::NonSingularArchive(_)) => extracted,
+        Err(err) => return Err(err.into()),
+    };
+
+    let component = InstalledComponent {
+        name: release.name.clone(),
+        version: release.version.clone(),
+        channel,
+        url: url.to_string(),
+        sha256: actual.to_string(),
+    };
+    fs_err::write(
+        source.join("uv-receipt.toml"),
+        toml::to_string(&ComponentReceipt {
+            component: component.clone(),
+        })?,
+    )?;
+
+    // Replace the existing installation, if any.
+    let target = installed.component_dir(&release.name);
+    if target.is_dir() {
+        debug!("Removing existing component: {}", target.user_display());
+        fs_err::tokio::remove_dir_all(&target).await?;
+    }
+    fs_err::tokio::rename(&source, &target).await?;
+
+    Ok(component)
+}
+
+/// Validate that a component name is well-formed, i.e., only contains lowercase letters, digits,
+/// and hyphens.
+fn validate_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        bail!(
+            "Invalid component name `{name}`: names may only contain lowercase letters, digits, and hyphens"
+        );
+    }
+    Ok(())
+}
@@ -12,6 +12,7 @@ use uv_distribution_types::UnresolvedRequirementSpecification;
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
+use uv_platform_tags::TagPreferences;
 use uv_pypi_types::{Requirement, RequirementSource};
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
@@ -51,6 +52,7 @@ pub(crate) async fn install(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: Cache,
@@ -118,6 +120,7 @@ pub(crate) async fn install(
                 &state,
                 connectivity,
                 concurrency,
+                tag_preferences,
                 native_tls,
                 allow_insecure_host,
                 &cache,
@@ -188,6 +191,7 @@ pub(crate) async fn install(
                 &state,
                 connectivity,
                 concurrency,
+                tag_preferences,
                 native_tls,
                 allow_insecure_host,
                 &cache,
@@ -238,6 +242,7 @@ pub(crate) async fn install(
                 &state,
                 connectivity,
                 concurrency,
+                tag_preferences,
                 native_tls,
                 allow_insecure_host,
                 &cache,
@@ -362,6 +367,7 @@ pub(crate) async fn install(
             Box::new(DefaultInstallLogger),
             connectivity,
             concurrency,
+            tag_preferences,
             native_tls,
             allow_insecure_host,
             &cache,
@@ -396,6 +402,7 @@ pub(crate) async fn install(
             Box::new(DefaultResolveLogger),
             connectivity,
             concurrency,
+            tag_preferences,
             native_tls,
             allow_insecure_host,
             &cache,
@@ -429,6 +436,7 @@ pub(crate) async fn install(
             Box::new(DefaultInstallLogger),
             connectivity,
             concurrency,
+            tag_preferences,
             native_tls,
             allow_insecure_host,
             &cache,
@@ -4,6 +4,9 @@ use tracing::debug;
 
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_tool::InstalledTools;
+
+use crate::commands::self_component::component_plugins;
 
 mod common;
 pub(crate) mod dir;
@@ -117,3 +120,33 @@ impl<'a> Target<'a> {
         matches!(self, Self::Latest(_) | Self::FromLatest(_, _))
     }
 }
+
+/// Discover any plugins installed as tools, i.e., tool entrypoints named `uv-plugin-<name>`, or
+/// provided by components installed via `uv self add`.
+pub(crate) fn installed_plugins() -> Vec<uv_plugin::Plugin> {
+    let tools = match InstalledTools::from_settings().and_then(|installed| installed.tools()) {
+        Ok(tools) => tools,
+        Err(err) => {
+            debug!("Failed to read installed tools for plugin discovery: {err}");
+            return Vec::new();
+        }
+    };
+
+    let mut plugins = tools
+        .into_iter()
+        .filter_map(|(_, tool)| tool.ok())
+        .flat_map(|tool| tool.entrypoints().to_vec())
+        .filter_map(|entrypoint| {
+            let name = entrypoint
+                .name
+                .strip_suffix(std::env::consts::EXE_SUFFIX)
+                .unwrap_or(&entrypoint.name)
+                .strip_prefix(uv_plugin::PLUGIN_PREFIX)?
+                .to_string();
+            Some(uv_plugin::Plugin::new(name, entrypoint.install_path))
+        })
+        .chain(component_plugins())
+        .collect::<Vec<_>>();
+    plugins.sort_by(|a, b| a.name().cmp(b.name()));
+    plugins
+}
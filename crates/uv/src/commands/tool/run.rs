@@ -20,6 +20,7 @@ use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
+use uv_platform_tags::TagPreferences;
 use uv_pypi_types::{Requirement, RequirementSource};
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
@@ -77,6 +78,7 @@ pub(crate) async fn run(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: Cache,
@@ -120,6 +122,7 @@ pub(crate) async fn run(
         python_downloads,
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         &cache,
@@ -436,6 +439,7 @@ async fn get_or_create_environment(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -513,6 +517,7 @@ async fn get_or_create_environment(
             &state,
             connectivity,
             concurrency,
+            tag_preferences,
             native_tls,
             allow_insecure_host,
             cache,
@@ -544,6 +549,7 @@ async fn get_or_create_environment(
                 &state,
                 connectivity,
                 concurrency,
+                tag_preferences,
                 native_tls,
                 allow_insecure_host,
                 cache,
@@ -621,6 +627,7 @@ async fn get_or_create_environment(
         },
         connectivity,
         concurrency,
+        tag_preferences,
         native_tls,
         allow_insecure_host,
         cache,
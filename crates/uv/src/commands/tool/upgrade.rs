@@ -9,6 +9,7 @@ use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::{Concurrency, TrustedHost};
 use uv_normalize::PackageName;
+use uv_platform_tags::TagPreferences;
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
     PythonRequest,
@@ -40,6 +41,7 @@ pub(crate) async fn upgrade(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
@@ -114,6 +116,7 @@ pub(crate) async fn upgrade(
             &filesystem,
             connectivity,
             concurrency,
+            tag_preferences,
             native_tls,
             allow_insecure_host,
         )
@@ -202,6 +205,7 @@ async fn upgrade_tool(
     filesystem: &ResolverInstallerOptions,
     connectivity: Connectivity,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     allow_insecure_host: &[TrustedHost],
 ) -> Result<UpgradeOutcome> {
@@ -274,6 +278,7 @@ async fn upgrade_tool(
             Box::new(SummaryResolveLogger),
             connectivity,
             concurrency,
+            tag_preferences,
             native_tls,
             allow_insecure_host,
             cache,
@@ -291,6 +296,7 @@ async fn upgrade_tool(
             Box::new(DefaultInstallLogger),
             connectivity,
             concurrency,
+            tag_preferences,
             native_tls,
             allow_insecure_host,
             cache,
@@ -315,6 +321,7 @@ async fn upgrade_tool(
             Box::new(UpgradeInstallLogger::new(name.clone())),
             connectivity,
             concurrency,
+            tag_preferences,
             native_tls,
             allow_insecure_host,
             cache,
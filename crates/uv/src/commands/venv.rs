@@ -19,6 +19,7 @@ use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations};
 use uv_fs::Simplified;
 use uv_install_wheel::linker::LinkMode;
+use uv_platform_tags::TagPreferences;
 use uv_pypi_types::Requirement;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
@@ -59,6 +60,7 @@ pub(crate) async fn venv(
     allow_existing: bool,
     exclude_newer: Option<ExcludeNewer>,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     no_config: bool,
     no_project: bool,
@@ -86,6 +88,7 @@ pub(crate) async fn venv(
         allow_existing,
         exclude_newer,
         concurrency,
+        tag_preferences,
         native_tls,
         no_config,
         no_project,
@@ -144,6 +147,7 @@ async fn venv_impl(
     allow_existing: bool,
     exclude_newer: Option<ExcludeNewer>,
     concurrency: Concurrency,
+    tag_preferences: &TagPreferences,
     native_tls: bool,
     no_config: bool,
     no_project: bool,
@@ -306,7 +310,7 @@ async fn venv_impl(
 
         // Resolve the flat indexes from `--find-links`.
         let flat_index = {
-            let tags = interpreter.tags().map_err(VenvError::Tags)?;
+            let tags = interpreter.tags(tag_preferences).map_err(VenvError::Tags)?;
             let client = FlatIndexClient::new(&client, cache);
             let entries = client
                 .fetch(index_locations.flat_indexes().map(Index::url))
@@ -314,7 +318,7 @@ async fn venv_impl(
                 .map_err(VenvError::FlatIndex)?;
             FlatIndex::from_entries(
                 entries,
-                Some(tags),
+                Some(&tags),
                 &HashStrategy::None,
                 &BuildOptions::new(NoBinary::None, NoBuild::All),
             )
@@ -355,6 +359,7 @@ async fn venv_impl(
             LowerBound::Allow,
             sources,
             concurrency,
+            tag_preferences,
         );
 
         // Resolve the seed packages.
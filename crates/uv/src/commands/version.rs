@@ -1,6 +1,19 @@
-use anyhow::Result;
+use std::fmt::Write;
+use std::path::Path;
 
-use uv_cli::VersionFormat;
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cli::{VersionBump, VersionFormat};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::{BumpCommand, PrereleaseKind, Version};
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+use uv_workspace::{DiscoveryOptions, ProjectWorkspace};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
 
 /// Display version information
 pub(crate) fn version(output_format: VersionFormat, buffer: &mut dyn std::io::Write) -> Result<()> {
@@ -18,3 +31,105 @@ pub(crate) fn version(output_format: VersionFormat, buffer: &mut dyn std::io::Wr
     };
     Ok(())
 }
+
+/// The result of bumping the version of a project.
+#[derive(Debug, Serialize)]
+struct BumpedVersion {
+    /// The name of the project.
+    name: PackageName,
+    /// The version prior to the bump.
+    previous: Version,
+    /// The version after the bump.
+    version: Version,
+}
+
+/// Bump the version of the current project.
+pub(crate) async fn version_bump(
+    project_dir: &Path,
+    bumps: &[VersionBump],
+    dry_run: bool,
+    output_format: VersionFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let project = ProjectWorkspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+    let pyproject_path = project.project_root().join("pyproject.toml");
+
+    let Some(previous) = project.current_project().project().version.clone() else {
+        bail!(
+            "Project `{}` does not declare a static `project.version` in `{}`",
+            project.project_name(),
+            pyproject_path.user_display()
+        );
+    };
+
+    // Apply each bump in order.
+    let mut version = previous.clone();
+    for bump in bumps {
+        version = version
+            .bump(bump_command(*bump))
+            .with_context(|| format!("Failed to bump version of `{}`", project.project_name()))?;
+    }
+
+    if version <= previous {
+        bail!(
+            "Bumping `{}` from {previous} to {version} would not increase its version",
+            project.project_name(),
+        );
+    }
+
+    if !dry_run {
+        let content = fs_err::tokio::read_to_string(&pyproject_path).await?;
+        let mut toml = PyProjectTomlMut::from_toml(&content, DependencyTarget::PyProjectToml)?;
+        toml.set_version(&version)?;
+        fs_err::tokio::write(&pyproject_path, toml.to_string()).await?;
+    }
+
+    let bumped = BumpedVersion {
+        name: project.project_name().clone(),
+        previous,
+        version,
+    };
+    match output_format {
+        VersionFormat::Text => {
+            writeln!(
+                printer.stdout(),
+                "{} {} => {}",
+                bumped.name.bold(),
+                bumped.previous,
+                bumped.version.cyan()
+            )?;
+        }
+        VersionFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&bumped)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Convert a [`VersionBump`] from the command line into a [`BumpCommand`].
+fn bump_command(bump: VersionBump) -> BumpCommand {
+    match bump {
+        VersionBump::Major => BumpCommand::Release { index: 0 },
+        VersionBump::Minor => BumpCommand::Release { index: 1 },
+        VersionBump::Patch => BumpCommand::Release { index: 2 },
+        VersionBump::Stable => BumpCommand::Stable,
+        VersionBump::Alpha => BumpCommand::Pre {
+            kind: PrereleaseKind::Alpha,
+        },
+        VersionBump::Beta => BumpCommand::Pre {
+            kind: PrereleaseKind::Beta,
+        },
+        VersionBump::Rc => BumpCommand::Pre {
+            kind: PrereleaseKind::Rc,
+        },
+        VersionBump::Prerelease => BumpCommand::Prerelease,
+        VersionBump::Post => BumpCommand::Post,
+        VersionBump::Dev => BumpCommand::Dev,
+        VersionBump::Epoch => BumpCommand::Epoch,
+    }
+}
@@ -0,0 +1,677 @@
+//! Inspect the members of a workspace and the dependencies between them (`uv workspace`).
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_fs::{Simplified, CWD};
+use uv_git::GIT;
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::{ExtraName, MarkerTree, Requirement, VersionOrUrl};
+use uv_pypi_types::VerbatimParsedUrl;
+use uv_workspace::pyproject::{DependencyGroupSpecifier, Source, Sources, ToolUvSources};
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceMember};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// A member of the workspace.
+#[derive(Debug, Serialize)]
+struct Member {
+    /// The name of the member.
+    name: PackageName,
+    /// The version of the member, if it's not dynamic.
+    version: Option<Version>,
+    /// The path to the member, relative to the workspace root.
+    path: String,
+}
+
+/// The members of the workspace.
+#[derive(Debug, Serialize)]
+struct Members {
+    /// The root of the workspace.
+    root: PathBuf,
+    members: Vec<Member>,
+}
+
+/// A member of the workspace, along with the members it depends on.
+#[derive(Debug, Serialize)]
+struct GraphMember {
+    name: PackageName,
+    dependencies: BTreeSet<PackageName>,
+}
+
+/// The dependencies between the members of the workspace.
+#[derive(Debug, Serialize)]
+struct Graph {
+    members: Vec<GraphMember>,
+}
+
+/// The members of the workspace affected by a set of changed files.
+#[derive(Debug, Serialize)]
+struct Affected {
+    /// The members that contain a changed file.
+    changed: BTreeSet<PackageName>,
+    /// The members that contain a changed file, or depend on a member that does, transitively.
+    affected: BTreeSet<PackageName>,
+}
+
+/// A requirement declared by a member of the workspace.
+#[derive(Debug, Serialize)]
+struct Declaration {
+    /// The member that declares the requirement.
+    member: PackageName,
+    /// The section of the member's `pyproject.toml` that contains the requirement (e.g.,
+    /// `project.dependencies`).
+    section: String,
+    /// The requirement, as written.
+    requirement: String,
+}
+
+/// A dependency that's specified inconsistently across the workspace.
+#[derive(Debug, Serialize)]
+struct InconsistentDependency {
+    name: PackageName,
+    declarations: Vec<Declaration>,
+}
+
+/// The dependencies that are specified inconsistently across the workspace.
+#[derive(Debug, Serialize)]
+struct DedupeReport {
+    dependencies: Vec<InconsistentDependency>,
+}
+
+/// A requirement declared by a member of the workspace, along with its location.
+struct MemberRequirement {
+    member: PackageName,
+    section: String,
+    raw: String,
+    requirement: Requirement<VerbatimParsedUrl>,
+}
+
+/// List the members of the workspace.
+pub(crate) async fn workspace_list(
+    project_dir: &Path,
+    json: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    let members = Members {
+        root: workspace.install_path().clone(),
+        members: workspace
+            .packages()
+            .iter()
+            .map(|(name, member)| Member {
+                name: name.clone(),
+                version: member.project().version.clone(),
+                path: relative_path(&workspace, member),
+            })
+            .collect(),
+    };
+
+    if json {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&members)?
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for member in &members.members {
+        let version = member
+            .version
+            .as_ref()
+            .map(|version| format!(" v{version}"))
+            .unwrap_or_default();
+        writeln!(
+            printer.stdout(),
+            "{}{version} {}",
+            member.name.bold(),
+            format!("({})", member.path).dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Show the dependencies between the members of the workspace.
+pub(crate) async fn workspace_graph(
+    project_dir: &Path,
+    json: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+    let dependencies = member_dependencies(&workspace)?;
+
+    if json {
+        let graph = Graph {
+            members: dependencies
+                .into_iter()
+                .map(|(name, dependencies)| GraphMember { name, dependencies })
+                .collect(),
+        };
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&graph)?
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for (name, dependencies) in &dependencies {
+        writeln!(printer.stdout(), "{}", name.bold())?;
+        for dependency in dependencies {
+            writeln!(printer.stdout(), "  {} {dependency}", "->".dimmed())?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// List the members of the workspace that are affected by a set of changed files.
+pub(crate) async fn workspace_affected(
+    project_dir: &Path,
+    files: &[PathBuf],
+    base: Option<&str>,
+    json: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+    let dependencies = member_dependencies(&workspace)?;
+
+    let mut paths = files
+        .iter()
+        .map(|file| uv_fs::normalize_path(&CWD.join(file)))
+        .collect::<Vec<_>>();
+    if let Some(base) = base {
+        paths.extend(changed_files(workspace.install_path(), base)?);
+    }
+
+    // Determine the members that contain a changed file.
+    let mut changed = BTreeSet::new();
+    for path in &paths {
+        if is_workspace_file(&workspace, path) {
+            debug!("Changes to `{}` affect every member", path.user_display());
+            changed.extend(workspace.packages().keys().cloned());
+            continue;
+        }
+        match owning_member(&workspace, path) {
+            Some(name) => {
+                debug!("`{}` is owned by `{name}`", path.user_display());
+                changed.insert(name.clone());
+            }
+            None => {
+                debug!(
+                    "`{}` is not within any workspace member",
+                    path.user_display()
+                );
+            }
+        }
+    }
+
+    // Propagate the changes to the dependents of each member.
+    let mut dependents: BTreeMap<&PackageName, Vec<&PackageName>> = BTreeMap::new();
+    for (name, dependencies) in &dependencies {
+        for dependency in dependencies {
+            dependents.entry(dependency).or_default().push(name);
+        }
+    }
+    let mut affected = changed.clone();
+    let mut queue = changed.iter().collect::<VecDeque<_>>();
+    while let Some(name) = queue.pop_front() {
+        for dependent in dependents.get(name).into_iter().flatten() {
+            if affected.insert((*dependent).clone()) {
+                queue.push_back(*dependent);
+            }
+        }
+    }
+
+    if json {
+        let affected = Affected { changed, affected };
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&affected)?
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for name in &affected {
+        writeln!(printer.stdout(), "{name}")?;
+    }
+    if affected.is_empty() {
+        writeln!(printer.stderr(), "No workspace members are affected")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Report the dependencies that are specified inconsistently across the workspace, optionally
+/// moving their version specifiers to `tool.uv.workspace.dependencies`.
+pub(crate) async fn workspace_dedupe_report(
+    project_dir: &Path,
+    write: bool,
+    json: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+    let shared = workspace.shared_dependencies();
+
+    // Group the requirements on third-party packages by package name.
+    let mut requirements: BTreeMap<PackageName, Vec<MemberRequirement>> = BTreeMap::new();
+    for requirement in member_requirements(&workspace)? {
+        if workspace
+            .packages()
+            .contains_key(&requirement.requirement.name)
+        {
+            continue;
+        }
+        requirements
+            .entry(requirement.requirement.name.clone())
+            .or_default()
+            .push(requirement);
+    }
+
+    // A dependency is inconsistent if its requirements differ in their (inherited) version
+    // specifiers, URL, source, or extras.
+    requirements.retain(|name, requirements| {
+        let mut specifications = Vec::new();
+        for MemberRequirement {
+            member,
+            requirement,
+            ..
+        } in requirements.iter()
+        {
+            let source = member_source(&workspace, member, name);
+            let (version_or_url, source) = match shared
+                .get(name)
+                .filter(|_| source.is_some_and(is_workspace_source))
+            {
+                Some(dependency) => (
+                    dependency.version.as_ref().map(ToString::to_string),
+                    dependency.source.clone().map(Sources::from),
+                ),
+                None => (
+                    match &requirement.version_or_url {
+                        Some(VersionOrUrl::VersionSpecifier(specifiers))
+                            if !specifiers.is_empty() =>
+                        {
+                            Some(specifiers.to_string())
+                        }
+                        Some(VersionOrUrl::Url(url)) => Some(url.to_string()),
+                        _ => None,
+                    },
+                    source.cloned(),
+                ),
+            };
+            let extras = requirement.extras.iter().collect::<BTreeSet<&ExtraName>>();
+            let specification = (version_or_url, source, extras);
+            if !specifications.contains(&specification) {
+                specifications.push(specification);
+            }
+        }
+        specifications.len() > 1
+    });
+
+    if json {
+        let report = DedupeReport {
+            dependencies: requirements
+                .iter()
+                .map(|(name, requirements)| InconsistentDependency {
+                    name: name.clone(),
+                    declarations: requirements
+                        .iter()
+                        .map(|requirement| Declaration {
+                            member: requirement.member.clone(),
+                            section: requirement.section.clone(),
+                            requirement: requirement.raw.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        };
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&report)?
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for (name, requirements) in &requirements {
+        writeln!(printer.stdout(), "{}", name.bold())?;
+        for requirement in requirements {
+            writeln!(
+                printer.stdout(),
+                "  {} {}: {}",
+                requirement.member,
+                format!("({})", requirement.section).dimmed(),
+                requirement.raw
+            )?;
+        }
+    }
+
+    match requirements.len() {
+        0 => writeln!(
+            printer.stderr(),
+            "No dependencies are specified inconsistently"
+        )?,
+        1 => writeln!(
+            printer.stderr(),
+            "Found {} inconsistently specified dependency",
+            "1".bold()
+        )?,
+        n => writeln!(
+            printer.stderr(),
+            "Found {} inconsistently specified dependencies",
+            n.to_string().bold()
+        )?,
+    }
+
+    if !write {
+        return Ok(ExitStatus::Success);
+    }
+
+    // Edit each `pyproject.toml` at most once, since the workspace root may also be a member.
+    let root = workspace.install_path().join("pyproject.toml");
+    let mut documents: BTreeMap<PathBuf, PyProjectTomlMut> = BTreeMap::new();
+    for (name, requirements) in &requirements {
+        if requirements.iter().any(
+            |MemberRequirement {
+                 member,
+                 requirement,
+                 ..
+             }| {
+                matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_)))
+                    || !requirement.marker.is_true()
+                    || member_source(&workspace, member, name)
+                        .is_some_and(|source| !is_workspace_source(source))
+            },
+        ) {
+            writeln!(
+                printer.stderr(),
+                "Skipping `{}`, which is declared with a URL, environment markers, or a source",
+                name.cyan()
+            )?;
+            continue;
+        }
+
+        // Combine the version specifiers across the members, since the members are resolved
+        // together, and so must satisfy every specifier anyway.
+        let mut specifiers = Vec::new();
+        for specifier in shared
+            .get(name)
+            .and_then(|dependency| dependency.version.as_ref())
+            .into_iter()
+            .flat_map(|specifiers| specifiers.iter())
+            .chain(
+                requirements
+                    .iter()
+                    .filter_map(|MemberRequirement { requirement, .. }| {
+                        match &requirement.version_or_url {
+                            Some(VersionOrUrl::VersionSpecifier(specifiers)) => Some(specifiers),
+                            _ => None,
+                        }
+                    })
+                    .flat_map(|specifiers| specifiers.iter()),
+            )
+        {
+            if !specifiers.contains(specifier) {
+                specifiers.push(specifier.clone());
+            }
+        }
+        if specifiers.is_empty() {
+            continue;
+        }
+        let specifiers = specifiers.into_iter().collect::<VersionSpecifiers>();
+
+        document(&mut documents, &root)?.set_shared_dependency(name, &specifiers)?;
+        for member in requirements
+            .iter()
+            .map(|requirement| &requirement.member)
+            .collect::<BTreeSet<_>>()
+        {
+            let path = workspace.packages()[member].root().join("pyproject.toml");
+            let document = document(&mut documents, &path)?;
+            document.clear_dependency_bounds(name)?;
+            if !member_sources(&workspace, member)
+                .is_some_and(|sources| sources.get(name).is_some())
+            {
+                document.add_source(
+                    name,
+                    &Source::Workspace {
+                        workspace: true,
+                        marker: MarkerTree::TRUE,
+                    },
+                )?;
+            }
+        }
+
+        writeln!(
+            printer.stderr(),
+            "Moved the version specifiers for `{}` to `{}`: `{specifiers}`",
+            name.cyan(),
+            "tool.uv.workspace.dependencies".green()
+        )?;
+    }
+
+    for (path, document) in documents {
+        fs_err::write(&path, document.to_string())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the `tool.uv.sources` table declared by the given member.
+fn member_sources<'a>(workspace: &'a Workspace, member: &PackageName) -> Option<&'a ToolUvSources> {
+    workspace.packages()[member]
+        .pyproject_toml()
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.sources.as_ref())
+}
+
+/// Return the sources that apply to the given member's requirements on a package, from the
+/// member's `tool.uv.sources`, or the workspace root's.
+fn member_source<'a>(
+    workspace: &'a Workspace,
+    member: &PackageName,
+    name: &PackageName,
+) -> Option<&'a Sources> {
+    member_sources(workspace, member)
+        .and_then(|sources| sources.get(name))
+        .or_else(|| workspace.sources().get(name))
+}
+
+/// Returns `true` if the sources consist solely of workspace sources (`workspace = true`).
+fn is_workspace_source(sources: &Sources) -> bool {
+    sources.iter().all(|source| {
+        matches!(
+            source,
+            Source::Workspace {
+                workspace: true,
+                ..
+            }
+        )
+    })
+}
+
+/// Return the editable `pyproject.toml` at the given path, reading it if necessary.
+fn document<'a>(
+    documents: &'a mut BTreeMap<PathBuf, PyProjectTomlMut>,
+    path: &Path,
+) -> Result<&'a mut PyProjectTomlMut> {
+    if !documents.contains_key(path) {
+        let content = fs_err::read_to_string(path)?;
+        let document = PyProjectTomlMut::from_toml(&content, DependencyTarget::PyProjectToml)
+            .with_context(|| format!("Failed to parse `{}`", path.user_display()))?;
+        documents.insert(path.to_path_buf(), document);
+    }
+    Ok(documents.get_mut(path).expect("document was inserted"))
+}
+
+/// Return every requirement declared by the members of the workspace, in their project
+/// dependencies, optional dependencies, dependency groups, or development dependencies.
+fn member_requirements(workspace: &Workspace) -> Result<Vec<MemberRequirement>> {
+    let mut requirements = Vec::new();
+    for (name, member) in workspace.packages() {
+        let project = member.project();
+        let pyproject_toml = member.pyproject_toml();
+
+        let declared = project
+            .dependencies
+            .iter()
+            .flatten()
+            .map(|requirement| ("project.dependencies".to_string(), requirement))
+            .chain(project.optional_dependencies.iter().flatten().flat_map(
+                |(extra, requirements)| {
+                    requirements.iter().map(move |requirement| {
+                        (
+                            format!("project.optional-dependencies.{extra}"),
+                            requirement,
+                        )
+                    })
+                },
+            ))
+            .chain(
+                pyproject_toml
+                    .dependency_groups
+                    .iter()
+                    .flat_map(|groups| groups.into_iter())
+                    .flat_map(|(group, specifiers)| {
+                        specifiers
+                            .iter()
+                            .filter_map(move |specifier| match specifier {
+                                DependencyGroupSpecifier::Requirement(requirement) => {
+                                    Some((format!("dependency-groups.{group}"), requirement))
+                                }
+                                _ => None,
+                            })
+                    }),
+            );
+        for (section, raw) in declared {
+            let requirement = Requirement::<VerbatimParsedUrl>::from_str(raw)
+                .with_context(|| format!("Failed to parse requirement `{raw}` of `{name}`"))?;
+            requirements.push(MemberRequirement {
+                member: name.clone(),
+                section,
+                raw: raw.clone(),
+                requirement,
+            });
+        }
+
+        requirements.extend(
+            pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.dev_dependencies.as_ref())
+                .into_iter()
+                .flatten()
+                .map(|requirement| MemberRequirement {
+                    member: name.clone(),
+                    section: "tool.uv.dev-dependencies".to_string(),
+                    raw: requirement.to_string(),
+                    requirement: requirement.clone(),
+                }),
+        );
+    }
+    Ok(requirements)
+}
+
+/// Return the workspace members that each member depends on, via its project dependencies,
+/// optional dependencies, dependency groups, or development dependencies.
+fn member_dependencies(
+    workspace: &Workspace,
+) -> Result<BTreeMap<PackageName, BTreeSet<PackageName>>> {
+    let mut graph: BTreeMap<PackageName, BTreeSet<PackageName>> = workspace
+        .packages()
+        .keys()
+        .map(|name| (name.clone(), BTreeSet::new()))
+        .collect();
+    for MemberRequirement {
+        member,
+        requirement,
+        ..
+    } in member_requirements(workspace)?
+    {
+        if requirement.name != member && workspace.packages().contains_key(&requirement.name) {
+            graph.entry(member).or_default().insert(requirement.name);
+        }
+    }
+    Ok(graph)
+}
+
+/// Return the files that changed since the given Git revision, as absolute paths.
+fn changed_files(root: &Path, base: &str) -> Result<Vec<PathBuf>> {
+    let Ok(git) = GIT.as_ref() else {
+        bail!("Git executable not found; `--base` requires Git to be installed");
+    };
+
+    // With `--relative`, paths are relative to the workspace root, and changes outside of the
+    // workspace are omitted.
+    let output = std::process::Command::new(git)
+        .arg("diff")
+        .arg("--name-only")
+        .arg("--relative")
+        .arg(base)
+        .arg("--")
+        .current_dir(root)
+        .output()
+        .context("Failed to run `git diff`")?;
+    if !output.status.success() {
+        bail!(
+            "Failed to determine the files changed since `{base}`:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| root.join(line))
+        .collect())
+}
+
+/// Returns `true` if the file applies to the workspace as a whole, i.e., it's the workspace's
+/// lockfile or root `pyproject.toml`.
+fn is_workspace_file(workspace: &Workspace, path: &Path) -> bool {
+    path.parent() == Some(workspace.install_path().as_path())
+        && path
+            .file_name()
+            .is_some_and(|name| name == "uv.lock" || name == "pyproject.toml")
+}
+
+/// Return the member whose directory contains the given path, preferring the innermost member
+/// for nested members.
+fn owning_member<'a>(workspace: &'a Workspace, path: &Path) -> Option<&'a PackageName> {
+    workspace
+        .packages()
+        .iter()
+        .filter(|(_, member)| path.starts_with(member.root()))
+        .max_by_key(|(_, member)| member.root().components().count())
+        .map(|(name, _)| name)
+}
+
+/// Return the path to the member, relative to the workspace root.
+fn relative_path(workspace: &Workspace, member: &WorkspaceMember) -> String {
+    let path = member
+        .root()
+        .strip_prefix(workspace.install_path())
+        .unwrap_or(member.root());
+    if path.as_os_str().is_empty() {
+        ".".to_string()
+    } else {
+        path.portable_display().to_string()
+    }
+}
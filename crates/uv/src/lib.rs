@@ -16,14 +16,21 @@ use tokio::task::spawn_blocking;
 use tracing::{debug, instrument};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+#[cfg(feature = "self-update")]
+use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand,
+    ConfigCommand, ConfigNamespace, DaemonCommand, DaemonNamespace, PinCommand, PinNamespace,
+    PipCommand, PipNamespace, ProjectCommand, QueryCommand, QueryNamespace, ScriptCommand,
+    ScriptNamespace, WorkspaceCommand, WorkspaceNamespace,
 };
-use uv_cli::{PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, TopLevelArgs};
-#[cfg(feature = "self-update")]
-use uv_cli::{SelfCommand, SelfNamespace, SelfUpdateArgs};
-use uv_fs::CWD;
+use uv_cli::{
+    ProgressFormat, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand,
+    ToolNamespace, TopLevelArgs,
+};
+use uv_client::BaseClientBuilder;
+use uv_distribution_types::IndexUrl;
+use uv_fs::{Simplified, CWD};
 use uv_pypi_types::ConflictingGroupList;
 use uv_requirements::RequirementsSource;
 use uv_scripts::{Pep723Item, Pep723Metadata, Pep723Script};
@@ -43,10 +50,16 @@ use crate::settings::{
 pub(crate) mod commands;
 pub(crate) mod logging;
 pub(crate) mod printer;
+pub(crate) mod progress;
 pub(crate) mod settings;
 
 #[instrument(skip_all)]
 async fn run(mut cli: Cli) -> Result<ExitStatus> {
+    // `uv query` reserves stdout for its JSON output, and suppresses all other output.
+    if matches!(*cli.command, Commands::Query(_)) {
+        cli.top_level.global_args.quiet = true;
+    }
+
     // Enable flag to pick up warnings generated by workspace loading.
     if !cli.top_level.global_args.quiet {
         uv_warnings::enable();
@@ -86,7 +99,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             }
 
             // Preview APIs. Ignore `--isolated` and warn.
-            Commands::Project(_) | Commands::Tool(_) | Commands::Python(_) => {
+            Commands::Project(_)
+            | Commands::Tool(_)
+            | Commands::Script(_)
+            | Commands::Python(_) => {
                 warn_user!("The `--isolated` flag is deprecated and has no effect. Instead, use `--no-config` to prevent uv from discovering configuration files.");
                 false
             }
@@ -102,19 +118,24 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     };
 
     // Load configuration from the filesystem, prioritizing (in order):
-    // 1. The configuration file specified on the command-line.
+    // 1. The configuration files specified on the command-line, with later files taking
+    //    precedence over earlier ones.
     // 2. The nearest configuration file (`uv.toml` or `pyproject.toml`) above the workspace root.
     //    If found, this file is combined with the user configuration file.
     // 3. The nearest configuration file (`uv.toml` or `pyproject.toml`) in the directory tree,
     //    starting from the current directory.
-    let filesystem = if let Some(config_file) = cli.top_level.config_file.as_ref() {
-        if config_file
-            .file_name()
-            .is_some_and(|file_name| file_name == "pyproject.toml")
-        {
-            warn_user!("The `--config-file` argument expects to receive a `uv.toml` file, not a `pyproject.toml`. If you're trying to run a command from another project, use the `--project` argument instead.");
+    let filesystem = if !cli.top_level.config_file.is_empty() {
+        let mut filesystem = None;
+        for config_file in &cli.top_level.config_file {
+            if config_file
+                .file_name()
+                .is_some_and(|file_name| file_name == "pyproject.toml")
+            {
+                warn_user!("The `--config-file` argument expects to receive a `uv.toml` file, not a `pyproject.toml`. If you're trying to run a command from another project, use the `--project` argument instead.");
+            }
+            filesystem = Some(FilesystemOptions::from_file(config_file)?).combine(filesystem);
         }
-        Some(FilesystemOptions::from_file(config_file)?)
+        filesystem
     } else if deprecated_isolated || cli.top_level.no_config {
         None
     } else if matches!(&*cli.command, Commands::Tool(_)) {
@@ -186,6 +207,25 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         } else {
             None
         }
+    } else if let Commands::Script(ScriptNamespace { command }) = &*cli.command {
+        let path = match command {
+            ScriptCommand::Lock(args) => &args.script,
+            ScriptCommand::Sync(args) => &args.script,
+            ScriptCommand::Tree(args) => &args.script,
+            ScriptCommand::Outdated(args) => &args.script,
+            ScriptCommand::Audit(args) => &args.script,
+            ScriptCommand::Vendor(args) => &args.script,
+        };
+        let Some(script) = Pep723Script::read(path).await? else {
+            if path.is_file() {
+                bail!(
+                    "`{}` does not contain inline script metadata",
+                    path.user_display()
+                );
+            }
+            bail!("Script not found: `{}`", path.user_display());
+        };
+        Some(Pep723Item::Script(script))
     } else {
         None
     };
@@ -218,6 +258,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             2.. => logging::Level::ExtraVerbose,
         },
         duration_layer,
+        logging::setup_otel(
+            BaseClientBuilder::new()
+                .connectivity(globals.connectivity)
+                .native_tls(globals.native_tls)
+                .allow_insecure_host(globals.allow_insecure_host.clone()),
+        ),
     )?;
 
     // Configure the `Printer`, which controls user-facing output in the CLI.
@@ -225,12 +271,27 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         Printer::Quiet
     } else if globals.verbose > 0 {
         Printer::Verbose
-    } else if globals.no_progress {
+    } else if globals.no_progress || globals.progress_format == ProgressFormat::Json {
         Printer::NoProgress
     } else {
         Printer::Default
     };
 
+    // Configure JSON progress events, which replace the progress bars.
+    if globals.progress_format == ProgressFormat::Json {
+        progress::enable(progress::writer(globals.progress_fd)?);
+    }
+
+    // Configure build failure reports, which are written when a source distribution fails to build.
+    if let Some(dir) = globals.build_report.clone() {
+        commands::enable_build_report(dir);
+    }
+
+    // Configure the file to which the suggested fixes for a failed resolution are written.
+    if let Some(path) = globals.resolution_suggestions.clone() {
+        commands::enable_resolution_suggestions(path);
+    }
+
     // Configure the `warn!` macros, which control user-facing warnings in the CLI.
     if globals.quiet {
         uv_warnings::disable();
@@ -280,7 +341,29 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_lock_timeout(
+            cache_settings
+                .cache_lock_timeout
+                .map(|cache_lock_timeout| cache_lock_timeout.as_duration()),
+        )
+        .with_revalidate(cache_settings.revalidate_package)
+        .with_offline_grace(
+            cache_settings
+                .offline_grace
+                .map(|offline_grace| offline_grace.as_duration()),
+        );
+
+    // If a daemon is running, delegate the invocation to it.
+    #[cfg(unix)]
+    if let Some(status) = commands::daemon_delegate(&cli.command, &globals, &cache)? {
+        return Ok(status);
+    }
+
+    // Discover any plugins installed as tools.
+    if globals.preview.is_enabled() && std::env::var_os(EnvVars::UV_NO_PLUGINS).is_none() {
+        uv_plugin::register(commands::installed_plugins());
+    }
 
     let result = match *cli.command {
         Commands::Help(args) => commands::help(
@@ -365,7 +448,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python_version,
                 args.settings.python_platform,
                 args.settings.universal,
+                args.split_output_dir.as_deref(),
+                args.split_python_versions,
+                args.split_python_platforms,
+                args.pyodide_index_url,
                 args.settings.exclude_newer,
+                args.settings.max_backtracks,
                 args.settings.sources,
                 args.settings.annotation_style,
                 args.settings.link_mode,
@@ -373,6 +461,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.system,
                 globals.python_preference,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 globals.quiet,
                 cache,
@@ -400,6 +489,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 .src_file
                 .into_iter()
                 .map(RequirementsSource::from_requirements_file)
+                .chain(
+                    args.from_conda
+                        .into_iter()
+                        .map(RequirementsSource::CondaEnvironment),
+                )
                 .collect::<Vec<_>>();
             let constraints = args
                 .constraint
@@ -419,6 +513,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.reinstall,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
+                args.settings.invalidation_mode,
+                &args.settings.script_launcher,
                 args.settings.hash_checking,
                 args.settings.index_locations,
                 args.settings.index_strategy,
@@ -441,6 +537,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.prefix,
                 args.settings.sources,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 cache,
@@ -511,6 +608,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.reinstall,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
+                args.settings.invalidation_mode,
+                &args.settings.script_launcher,
                 args.settings.hash_checking,
                 globals.connectivity,
                 &args.settings.config_setting,
@@ -522,6 +621,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python_platform,
                 args.settings.strict,
                 args.settings.exclude_newer,
+                args.settings.max_backtracks,
                 args.settings.sources,
                 args.settings.python,
                 args.settings.system,
@@ -529,10 +629,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.target,
                 args.settings.prefix,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 cache,
                 args.dry_run,
+                args.report.as_deref(),
                 printer,
             )
             .await
@@ -609,6 +711,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &args.exclude,
                 &args.format,
                 args.outdated,
+                args.uptodate,
                 args.settings.prerelease,
                 args.settings.index_locations,
                 args.settings.index_strategy,
@@ -620,6 +723,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python.as_deref(),
                 args.settings.system,
                 globals.native_tls,
+                &globals.tag_preferences,
                 &cache,
                 printer,
             )
@@ -641,6 +745,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python.as_deref(),
                 args.settings.system,
                 args.files,
+                &args.category,
                 &cache,
                 printer,
             )
@@ -698,12 +803,217 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
             commands::cache_prune(args.ci, &cache, printer)
         }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_verify(args.repair, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Stats(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_stats(&project_dir, &args.lockfile, args.json, &cache, printer).await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Warm(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CacheWarmSettings::resolve(*args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::cache_warm(
+                &project_dir,
+                &args.requirements,
+                args.python,
+                &args.python_versions,
+                &args.python_platforms,
+                args.resolver,
+                globals.python_preference,
+                globals.connectivity,
+                globals.concurrency,
+                &globals.tag_preferences,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                cli.top_level.no_config,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Export(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CacheExportSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::cache_export(
+                &args.packages,
+                args.output.as_deref(),
+                &args.index_locations,
+                args.index_strategy,
+                args.keyring_provider,
+                args.exclude_newer,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::List(args),
+        }) => {
+            commands::config_list(
+                &cli.top_level.config_file,
+                cli.top_level.no_config,
+                &project_dir,
+                args.origin,
+                printer,
+            )
+            .await
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Get(args),
+        }) => {
+            commands::config_get(
+                &cli.top_level.config_file,
+                cli.top_level.no_config,
+                &project_dir,
+                &args.key,
+                args.origin,
+                printer,
+            )
+            .await
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Set(args),
+        }) => {
+            commands::config_set(
+                &cli.top_level.config_file,
+                cli.top_level.no_config,
+                &project_dir,
+                &args.key,
+                &args.value,
+                args.user,
+                args.file,
+                printer,
+            )
+            .await
+        }
+        #[cfg(unix)]
+        Commands::Daemon(DaemonNamespace {
+            command: DaemonCommand::Stop,
+        }) => commands::daemon_stop(&cache, printer),
+        #[cfg(unix)]
+        Commands::Daemon(DaemonNamespace {
+            command: DaemonCommand::Status,
+        }) => commands::daemon_status(&cache, printer),
+        // On Unix, `uv daemon start` is handled before the runtime is initialized.
+        Commands::Daemon(_) => bail!("`uv daemon` is only supported on Unix"),
+        Commands::Query(QueryNamespace {
+            command: QueryCommand::Dependents(args),
+        }) => commands::query_dependents(&project_dir, args.package).await,
+        Commands::Query(QueryNamespace {
+            command: QueryCommand::Resolve(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::QueryResolveSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::query_resolve(
+                args.requirements,
+                args.python,
+                args.resolver,
+                globals.python_preference,
+                globals.connectivity,
+                globals.concurrency,
+                &globals.tag_preferences,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                &cache,
+            )
+            .await
+        }
+        Commands::Query(QueryNamespace {
+            command: QueryCommand::Interpreter(args),
+        }) => {
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::query_interpreter(
+                &project_dir,
+                args.request,
+                globals.python_preference,
+                cli.top_level.no_config,
+                &cache,
+            )
+            .await
+        }
+        Commands::Query(QueryNamespace {
+            command: QueryCommand::LockStatus(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::QueryLockStatusSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::query_lock_status(
+                &project_dir,
+                args.python,
+                args.install_mirrors,
+                args.resolver,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.connectivity,
+                globals.concurrency,
+                &globals.tag_preferences,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                cli.top_level.no_config,
+                &cache,
+            )
+            .await
+        }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::List(args),
+        }) => commands::workspace_list(&project_dir, args.json, printer).await,
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::Graph(args),
+        }) => commands::workspace_graph(&project_dir, args.json, printer).await,
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::Affected(args),
+        }) => {
+            commands::workspace_affected(
+                &project_dir,
+                &args.files,
+                args.base.as_deref(),
+                args.json,
+                printer,
+            )
+            .await
+        }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::DedupeReport(args),
+        }) => commands::workspace_dedupe_report(&project_dir, args.write, args.json, printer).await,
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem);
@@ -730,6 +1040,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.out_dir,
                 args.sdist,
                 args.wheel,
+                args.target,
                 args.build_logs,
                 build_constraints,
                 args.hash_checking,
@@ -741,6 +1052,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.python_downloads,
                 globals.connectivity,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 &cache,
@@ -795,6 +1107,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.allow_existing,
                 args.settings.exclude_newer,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 cli.top_level.no_config,
                 args.no_project,
@@ -827,20 +1140,83 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 }),
         }) => commands::self_update(target_version, token, printer).await,
         #[cfg(not(feature = "self-update"))]
-        Commands::Self_(_) => {
+        Commands::Self_(SelfNamespace {
+            command: SelfCommand::Update(_),
+        }) => {
             anyhow::bail!(
                 "uv was installed through an external package manager, and self-update \
                 is not available. Please use your package manager to update uv."
             );
         }
-        Commands::Version { output_format } => {
-            commands::version(output_format, &mut stdout())?;
+        Commands::Self_(SelfNamespace {
+            command: SelfCommand::Add(args),
+        }) => {
+            commands::self_add(
+                &args.components,
+                args.channel,
+                args.reinstall,
+                globals.connectivity,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                printer,
+            )
+            .await
+        }
+        Commands::Self_(SelfNamespace {
+            command: SelfCommand::Remove(args),
+        }) => commands::self_remove(&args.components, printer).await,
+        Commands::Self_(SelfNamespace {
+            command: SelfCommand::List(args),
+        }) => {
+            commands::self_list(
+                args.outdated,
+                globals.connectivity,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                printer,
+            )
+            .await
+        }
+        Commands::Version(args) if !args.bump.is_empty() => {
+            commands::version_bump(
+                &project_dir,
+                &args.bump,
+                args.dry_run,
+                args.output_format,
+                printer,
+            )
+            .await
+        }
+        Commands::Version(args) => {
+            commands::version(args.output_format, &mut stdout())?;
             Ok(ExitStatus::Success)
         }
         Commands::GenerateShellCompletion(args) => {
             args.shell.generate(&mut Cli::command(), &mut stdout());
+            if let Some(script) = commands::dynamic_completion_script(args.shell) {
+                std::io::Write::write_all(&mut stdout(), script.as_bytes())?;
+            }
             Ok(ExitStatus::Success)
         }
+        Commands::Complete(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CompleteSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::complete(
+                args.shell,
+                &args.line,
+                &project_dir,
+                &args.index_locations,
+                args.keyring_provider,
+                globals.connectivity,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Tool(ToolNamespace {
             command: run_variant @ (ToolCommand::Uvx(_) | ToolCommand::Run(_)),
         }) => {
@@ -916,6 +1292,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.python_downloads,
                 globals.connectivity,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 cache,
@@ -967,6 +1344,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.python_downloads,
                 globals.connectivity,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 cache,
@@ -1012,6 +1390,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.python_preference,
                 globals.python_downloads,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 &cache,
@@ -1044,6 +1423,203 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::tool_dir(args.bin, globals.preview)?;
             Ok(ExitStatus::Success)
         }
+        Commands::Script(ScriptNamespace { command }) => {
+            // The script is read ahead of time, to merge its settings into the configuration.
+            let Some(Pep723Item::Script(script)) = script else {
+                unreachable!("`uv script` requires a script with inline metadata");
+            };
+
+            match command {
+                ScriptCommand::Lock(args) => {
+                    // Resolve the settings from the command-line arguments and workspace configuration.
+                    let args = settings::ScriptLockSettings::resolve(args, filesystem);
+                    show_settings!(args);
+
+                    // Initialize the cache.
+                    let cache = cache.init()?.with_refresh(
+                        args.refresh
+                            .combine(Refresh::from(args.settings.upgrade.clone())),
+                    );
+
+                    commands::script_lock(
+                        script,
+                        args.locked,
+                        args.frozen,
+                        args.dry_run,
+                        args.python,
+                        args.install_mirrors,
+                        args.settings,
+                        globals.python_preference,
+                        globals.python_downloads,
+                        globals.connectivity,
+                        globals.concurrency,
+                        &globals.tag_preferences,
+                        globals.native_tls,
+                        &globals.allow_insecure_host,
+                        cli.top_level.no_config,
+                        &cache,
+                        printer,
+                    )
+                    .await
+                }
+                ScriptCommand::Sync(args) => {
+                    // Resolve the settings from the command-line arguments and workspace configuration.
+                    let args = settings::ScriptSyncSettings::resolve(args, filesystem);
+                    show_settings!(args);
+
+                    // Initialize the cache.
+                    let cache = cache.init()?.with_refresh(
+                        args.refresh
+                            .combine(Refresh::from(args.settings.reinstall.clone()))
+                            .combine(Refresh::from(args.settings.upgrade.clone())),
+                    );
+
+                    Box::pin(commands::script_sync(
+                        script,
+                        args.locked,
+                        args.frozen,
+                        args.python,
+                        args.install_mirrors,
+                        args.settings,
+                        globals.python_preference,
+                        globals.python_downloads,
+                        globals.connectivity,
+                        globals.concurrency,
+                        &globals.tag_preferences,
+                        globals.native_tls,
+                        &globals.allow_insecure_host,
+                        cli.top_level.no_config,
+                        &cache,
+                        printer,
+                    ))
+                    .await
+                }
+                ScriptCommand::Tree(args) => {
+                    // Resolve the settings from the command-line arguments and workspace configuration.
+                    let args = settings::ScriptTreeSettings::resolve(args, filesystem);
+                    show_settings!(args);
+
+                    // Initialize the cache.
+                    let cache = cache.init()?;
+
+                    commands::script_tree(
+                        script,
+                        args.locked,
+                        args.frozen,
+                        args.universal,
+                        args.depth,
+                        args.prune,
+                        args.package,
+                        args.no_dedupe,
+                        args.invert,
+                        args.outdated,
+                        args.python,
+                        args.install_mirrors,
+                        args.resolver,
+                        globals.python_preference,
+                        globals.python_downloads,
+                        globals.connectivity,
+                        globals.concurrency,
+                        &globals.tag_preferences,
+                        globals.native_tls,
+                        &globals.allow_insecure_host,
+                        cli.top_level.no_config,
+                        &cache,
+                        printer,
+                    )
+                    .await
+                }
+                ScriptCommand::Outdated(args) => {
+                    // Resolve the settings from the command-line arguments and workspace configuration.
+                    let args = settings::ScriptOutdatedSettings::resolve(args, filesystem);
+                    show_settings!(args);
+
+                    // Initialize the cache.
+                    let cache = cache.init()?;
+
+                    commands::script_outdated(
+                        script,
+                        args.locked,
+                        args.frozen,
+                        args.python,
+                        args.install_mirrors,
+                        args.resolver,
+                        globals.python_preference,
+                        globals.python_downloads,
+                        globals.connectivity,
+                        globals.concurrency,
+                        &globals.tag_preferences,
+                        globals.native_tls,
+                        &globals.allow_insecure_host,
+                        cli.top_level.no_config,
+                        &cache,
+                        printer,
+                    )
+                    .await
+                }
+                ScriptCommand::Audit(args) => {
+                    // Resolve the settings from the command-line arguments and workspace configuration.
+                    let args = settings::ScriptAuditSettings::resolve(args, filesystem);
+                    show_settings!(args);
+
+                    // Initialize the cache.
+                    let cache = cache.init()?;
+
+                    commands::script_audit(
+                        script,
+                        args.python,
+                        args.install_mirrors,
+                        args.resolver,
+                        globals.python_preference,
+                        globals.python_downloads,
+                        globals.connectivity,
+                        globals.concurrency,
+                        &globals.tag_preferences,
+                        globals.native_tls,
+                        &globals.allow_insecure_host,
+                        cli.top_level.no_config,
+                        &cache,
+                        printer,
+                    )
+                    .await
+                }
+                ScriptCommand::Vendor(args) => {
+                    // Resolve the settings from the command-line arguments and workspace configuration.
+                    let args = settings::ScriptVendorSettings::resolve(args, filesystem);
+                    show_settings!(args);
+
+                    // Initialize the cache.
+                    let cache = cache.init()?.with_refresh(
+                        args.refresh
+                            .combine(Refresh::from(args.settings.reinstall.clone()))
+                            .combine(Refresh::from(args.settings.upgrade.clone())),
+                    );
+
+                    Box::pin(commands::script_vendor(
+                        script,
+                        args.format,
+                        args.output,
+                        args.locked,
+                        args.frozen,
+                        args.python,
+                        args.python_platform,
+                        args.install_mirrors,
+                        args.settings,
+                        globals.python_preference,
+                        globals.python_downloads,
+                        globals.connectivity,
+                        globals.concurrency,
+                        &globals.tag_preferences,
+                        globals.native_tls,
+                        &globals.allow_insecure_host,
+                        cli.top_level.no_config,
+                        &cache,
+                        printer,
+                    ))
+                    .await
+                }
+            }
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::List(args),
         }) => {
@@ -1058,6 +1634,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.kinds,
                 args.all_versions,
                 args.all_platforms,
+                args.json,
                 globals.python_preference,
                 globals.python_downloads,
                 &cache,
@@ -1079,6 +1656,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.force,
                 args.python_install_mirror,
                 args.pypy_install_mirror,
+                args.registry,
                 globals.python_downloads,
                 globals.native_tls,
                 globals.connectivity,
@@ -1096,7 +1674,18 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let args = settings::PythonUninstallSettings::resolve(args, filesystem);
             show_settings!(args);
 
-            commands::python_uninstall(args.targets, args.all, printer).await
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_uninstall(
+                &project_dir,
+                args.targets,
+                args.all,
+                args.migrate,
+                &cache,
+                printer,
+            )
+            .await
         }
         Commands::Python(PythonNamespace {
             command: PythonCommand::Find(args),
@@ -1148,6 +1737,66 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::python_dir(args.bin)?;
             Ok(ExitStatus::Success)
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Refresh,
+        }) => {
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_refresh(&cache, printer)
+        }
+        Commands::Download(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::DownloadSettings::resolve(*args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::download(
+                &project_dir,
+                &args.packages,
+                &args.requirements,
+                args.out_dir.as_deref(),
+                args.python,
+                &args.python_versions,
+                &args.python_platforms,
+                args.resolver,
+                globals.python_preference,
+                globals.connectivity,
+                globals.concurrency,
+                &globals.tag_preferences,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                cli.top_level.no_config,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Proxy(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ProxySettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::proxy(
+                &args.host,
+                args.port,
+                &args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.keyring_provider,
+                args.settings.exclude_newer,
+                globals.connectivity,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Publish(args) => {
             show_settings!(args);
 
@@ -1155,21 +1804,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 warn_user_once!("`uv publish` is experimental and may change without warning");
             }
 
-            if args.skip_existing {
-                bail!(
-                    "`uv publish` does not support `--skip-existing` because there is not a \
-                    reliable way to identify when an upload fails due to an existing \
-                    distribution. Instead, use `--check-url` to provide the URL to the simple \
-                    API for your index. uv will check the index for existing distributions before \
-                    attempting uploads."
-                );
-            }
-
             // Resolve the settings from the command-line arguments and workspace configuration.
             let PublishSettings {
                 files,
                 username,
                 password,
+                skip_existing,
                 publish_url,
                 trusted_publishing,
                 keyring_provider,
@@ -1185,6 +1825,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 username,
                 password,
                 check_url,
+                skip_existing,
                 &cache,
                 globals.connectivity,
                 globals.native_tls,
@@ -1295,6 +1936,9 @@ async fn run_project(
             .await
         }
         ProjectCommand::Run(args) => {
+            // The number of trailing arguments that make up the command to run.
+            let trailing = args.command.as_ref().map_or(0, |command| command.len());
+
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::RunSettings::resolve(args, filesystem);
             show_settings!(args);
@@ -1306,6 +1950,19 @@ async fn run_project(
                     .combine(Refresh::from(args.settings.upgrade.clone())),
             );
 
+            if let Some(image) = args.container.as_deref() {
+                return Box::pin(commands::run_in_container(
+                    project_dir,
+                    image,
+                    trailing,
+                    args.locked || args.frozen || args.no_sync,
+                    &cache,
+                    globals.preview,
+                    printer,
+                ))
+                .await;
+            }
+
             let requirements = args
                 .with
                 .into_iter()
@@ -1327,6 +1984,8 @@ async fn run_project(
                 script,
                 command,
                 requirements,
+                args.with_lock,
+                args.with_overlay,
                 args.show_resolution || globals.verbose > 0,
                 args.locked,
                 args.frozen,
@@ -1346,6 +2005,7 @@ async fn run_project(
                 globals.python_downloads,
                 globals.connectivity,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 &cache,
@@ -1367,31 +2027,69 @@ async fn run_project(
                     .combine(Refresh::from(args.settings.upgrade.clone())),
             );
 
-            commands::sync(
-                project_dir,
-                args.locked,
-                args.frozen,
-                args.all_packages,
-                args.package,
-                args.extras,
-                args.dev,
-                args.editable,
-                args.install_options,
-                args.modifications,
-                args.python,
-                args.install_mirrors,
-                globals.python_preference,
-                globals.python_downloads,
-                args.settings,
-                globals.connectivity,
-                globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
-                no_config,
-                &cache,
-                printer,
-            )
-            .await
+            if args.cache_summary.is_some() {
+                uv_cache::metrics::reset();
+            }
+
+            let sync = || {
+                let args = args.clone();
+                let globals = &globals;
+                let cache = &cache;
+                async move {
+                    commands::sync(
+                        project_dir,
+                        args.locked,
+                        args.frozen,
+                        args.all_packages,
+                        args.package,
+                        args.extras,
+                        args.dev,
+                        args.editable,
+                        args.install_options,
+                        args.modifications,
+                        args.dry_run,
+                        args.locked_indexes,
+                        args.refresh_scripts,
+                        args.target,
+                        args.python,
+                        args.python_platform,
+                        args.install_mirrors,
+                        globals.python_preference,
+                        globals.python_downloads,
+                        args.settings,
+                        globals.connectivity,
+                        globals.concurrency,
+                        &globals.tag_preferences,
+                        globals.native_tls,
+                        &globals.allow_insecure_host,
+                        no_config,
+                        cache,
+                        printer,
+                    )
+                    .await
+                }
+            };
+
+            let status = if args.watch {
+                let find_links = args
+                    .settings
+                    .index_locations
+                    .flat_indexes()
+                    .filter_map(|index| match index.url() {
+                        IndexUrl::Path(url) => url.to_file_path().ok(),
+                        IndexUrl::Pypi(_) | IndexUrl::Url(_) => None,
+                    })
+                    .collect::<Vec<_>>();
+                commands::sync_watch(project_dir, &find_links, printer, sync).await?
+            } else {
+                sync().await?
+            };
+
+            if let Some(format) = args.cache_summary {
+                commands::write_cache_summary(format, printer)?;
+            }
+
+            Ok(status)
         }
         ProjectCommand::Lock(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
@@ -1404,11 +2102,24 @@ async fn run_project(
                     .combine(Refresh::from(args.settings.upgrade.clone())),
             );
 
-            commands::lock(
+            if args.cache_summary.is_some() {
+                uv_cache::metrics::reset();
+            }
+
+            let status = commands::lock(
                 project_dir,
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.minimize,
+                args.check_requires_python,
+                args.check_source_builds,
+                args.check_conflicts,
+                args.provenance,
+                args.verify_provenance,
+                args.report.as_deref(),
+                args.interactive,
+                args.environment.as_deref(),
                 args.python,
                 args.install_mirrors,
                 args.settings,
@@ -1416,13 +2127,20 @@ async fn run_project(
                 globals.python_downloads,
                 globals.connectivity,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 no_config,
                 &cache,
                 printer,
             )
-            .await
+            .await?;
+
+            if let Some(format) = args.cache_summary {
+                commands::write_cache_summary(format, printer)?;
+            }
+
+            Ok(status)
         }
         ProjectCommand::Add(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
@@ -1453,9 +2171,11 @@ async fn run_project(
                 args.frozen,
                 args.no_sync,
                 requirements,
+                args.from_imports,
                 args.editable,
                 args.dependency_type,
                 args.raw_sources,
+                args.bound,
                 args.indexes,
                 args.rev,
                 args.tag,
@@ -1470,6 +2190,7 @@ async fn run_project(
                 globals.python_downloads,
                 globals.connectivity,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 no_config,
@@ -1503,6 +2224,8 @@ async fn run_project(
                 args.frozen,
                 args.no_sync,
                 args.packages,
+                args.unused,
+                args.yes,
                 args.dependency_type,
                 args.package,
                 args.python,
@@ -1513,6 +2236,7 @@ async fn run_project(
                 globals.python_downloads,
                 globals.connectivity,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 no_config,
@@ -1521,6 +2245,20 @@ async fn run_project(
             )
             .await
         }
+        ProjectCommand::Pin(PinNamespace {
+            command: PinCommand::Add(args),
+        }) => commands::pin_add(project_dir, args.packages, args.global, printer).await,
+        ProjectCommand::Pin(PinNamespace {
+            command: PinCommand::Remove(args),
+        }) => commands::pin_remove(project_dir, args.packages, args.global, printer).await,
+        ProjectCommand::Pin(PinNamespace {
+            command: PinCommand::List,
+        }) => commands::pin_list(
+            &filesystem
+                .and_then(|filesystem| filesystem.into_options().pins)
+                .unwrap_or_default(),
+            printer,
+        ),
         ProjectCommand::Tree(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::TreeSettings::resolve(args, filesystem);
@@ -1540,6 +2278,9 @@ async fn run_project(
                 args.package,
                 args.no_dedupe,
                 args.invert,
+                args.why,
+                args.diff.as_deref(),
+                args.json,
                 args.outdated,
                 args.python_version,
                 args.python_platform,
@@ -1550,6 +2291,7 @@ async fn run_project(
                 globals.python_downloads,
                 globals.connectivity,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 no_config,
@@ -1572,8 +2314,13 @@ async fn run_project(
                 args.all_packages,
                 args.package,
                 args.hashes,
+                args.annotate,
                 args.install_options,
                 args.output_file,
+                &args.distro_packages,
+                args.environment.as_deref(),
+                args.python_platform,
+                args.pyodide_index_url.as_ref(),
                 args.extras,
                 args.dev,
                 args.editable,
@@ -1587,6 +2334,7 @@ async fn run_project(
                 globals.python_downloads,
                 globals.connectivity,
                 globals.concurrency,
+                &globals.tag_preferences,
                 globals.native_tls,
                 &globals.allow_insecure_host,
                 no_config,
@@ -1596,6 +2344,73 @@ async fn run_project(
             )
             .await
         }
+        ProjectCommand::BuildImage(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::BuildImageSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::build_image(
+                project_dir,
+                args.tag,
+                args.platforms,
+                args.export,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.connectivity,
+                globals.concurrency,
+                &globals.tag_preferences,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                no_config,
+                &cache,
+                globals.preview,
+                printer,
+            ))
+            .await
+        }
+        ProjectCommand::Bundle(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::BundleSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            Box::pin(commands::bundle(
+                project_dir,
+                args.format,
+                args.output,
+                args.entry_point,
+                args.package,
+                args.extras,
+                args.dev,
+                args.locked,
+                args.frozen,
+                args.python,
+                args.python_platform,
+                args.install_mirrors,
+                args.settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.connectivity,
+                globals.concurrency,
+                &globals.tag_preferences,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                no_config,
+                &cache,
+                globals.preview,
+                printer,
+            ))
+            .await
+        }
     }
 }
 
@@ -1662,6 +2477,23 @@ where
         }
     };
 
+    execute(cli).into()
+}
+
+/// Run the parsed command-line arguments to completion, reporting any error.
+fn execute(cli: Cli) -> ExitStatus {
+    // The daemon forks a child for each delegated invocation, so it must start before the runtime
+    // (or any other process-global state) is initialized.
+    #[cfg(unix)]
+    if matches!(
+        &*cli.command,
+        Commands::Daemon(DaemonNamespace {
+            command: DaemonCommand::Start
+        })
+    ) {
+        return report(daemon_start(cli));
+    }
+
     // Windows has a default stack size of 1MB, which is lower than the linux and mac default.
     // https://learn.microsoft.com/en-us/cpp/build/reference/stack-stack-allocations?view=msvc-170
     // We support increasing the stack size to avoid stack overflows in debug mode on Windows. In
@@ -1702,8 +2534,16 @@ where
         result
     };
 
+    // Export any recorded spans, now that the top-level span has closed.
+    logging::flush_otel();
+
+    report(result)
+}
+
+/// Report the error, if any, returning the exit status.
+fn report(result: Result<ExitStatus>) -> ExitStatus {
     match result {
-        Ok(code) => code.into(),
+        Ok(code) => code,
         Err(err) => {
             let mut causes = err.chain();
             eprintln!(
@@ -1714,7 +2554,34 @@ where
             for err in causes {
                 eprintln!("  {}: {}", "Caused by".red().bold(), err.to_string().trim());
             }
-            ExitStatus::Error.into()
+            ExitStatus::Error
         }
     }
 }
+
+/// Start the daemon, resolving its settings from the command-line and user-level configuration.
+#[cfg(unix)]
+fn daemon_start(cli: Cli) -> Result<ExitStatus> {
+    let filesystem = FilesystemOptions::user()?.combine(FilesystemOptions::system()?);
+    let globals = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
+    let cache_settings = CacheSettings::resolve(*cli.top_level.cache_args, filesystem.as_ref());
+
+    let printer = if globals.quiet {
+        Printer::Quiet
+    } else {
+        Printer::NoProgress
+    };
+    if globals.quiet {
+        uv_warnings::disable();
+    } else {
+        uv_warnings::enable();
+    }
+    anstream::ColorChoice::write_global(globals.color.into());
+
+    if cache_settings.no_cache {
+        bail!("`uv daemon` requires a persistent cache, but `--no-cache` was provided");
+    }
+    let cache = Cache::from_settings(false, cache_settings.cache_dir)?.init()?;
+
+    commands::daemon_start(&cache, globals.preview, execute, printer)
+}
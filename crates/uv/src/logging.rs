@@ -22,6 +22,10 @@ use tracing_tree::HierarchicalLayer;
 #[cfg(feature = "tracing-durations-export")]
 use uv_static::EnvVars;
 
+pub(crate) use otel::{flush_otel, setup_otel, OtelLayer};
+
+mod otel;
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Level {
     /// Suppress all tracing output by default (overridable by `RUST_LOG`).
@@ -117,6 +121,7 @@ where
 pub(crate) fn setup_logging(
     level: Level,
     durations: impl Layer<Registry> + Send + Sync,
+    otel: Option<OtelLayer>,
 ) -> anyhow::Result<()> {
     let default_directive = match level {
         Level::Default => {
@@ -134,6 +139,10 @@ pub(crate) fn setup_logging(
         tracing_subscriber::filter::Targets::new()
             .with_target("", tracing::level_filters::LevelFilter::INFO),
     );
+    let otel_layer = otel.with_filter(
+        tracing_subscriber::filter::Targets::new()
+            .with_target("", tracing::level_filters::LevelFilter::INFO),
+    );
 
     let filter = EnvFilter::builder()
         .with_default_directive(default_directive)
@@ -156,6 +165,7 @@ pub(crate) fn setup_logging(
             };
             tracing_subscriber::registry()
                 .with(durations_layer)
+                .with(otel_layer)
                 .with(
                     tracing_subscriber::fmt::layer()
                         .event_format(format)
@@ -169,6 +179,7 @@ pub(crate) fn setup_logging(
             // Regardless of the tracing level, include the uptime and target for each message.
             tracing_subscriber::registry()
                 .with(durations_layer)
+                .with(otel_layer)
                 .with(
                     HierarchicalLayer::default()
                         .with_targets(true)
@@ -0,0 +1,324 @@
+//! Export `tracing` spans to an OpenTelemetry collector via OTLP.
+//!
+//! Spans are buffered in memory (up to [`MAX_SPANS`]) for the lifetime of the process, then sent in
+//! a single batch to `UV_OTEL_ENDPOINT` using the OTLP/HTTP JSON encoding. If a W3C `TRACEPARENT`
+//! is present in the environment (e.g., as set by a CI system), uv's spans are attached to that
+//! trace.
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Value};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::Subscriber;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+use url::Url;
+
+use uv_client::BaseClientBuilder;
+use uv_static::EnvVars;
+use uv_version::version;
+use uv_warnings::warn_user_once;
+
+/// The maximum number of spans to buffer for export, matching the default queue size of the
+/// OpenTelemetry SDKs. Any further spans are dropped.
+const MAX_SPANS: usize = 2048;
+
+/// The exporter for the current process, if enabled.
+static EXPORTER: OnceLock<Arc<Exporter>> = OnceLock::new();
+
+/// The state shared between the [`OtelLayer`] and the final [`flush_otel`].
+#[derive(Debug)]
+struct Exporter {
+    /// The OTLP traces endpoint (e.g., `http://localhost:4318/v1/traces`).
+    endpoint: Url,
+    /// The trace to which all spans belong.
+    trace_id: u128,
+    /// The span that should parent all root spans, from `TRACEPARENT`.
+    parent_span_id: Option<u64>,
+    /// The client used to send the spans, configured with the user's network settings.
+    client: BaseClientBuilder<'static>,
+    /// The spans that have been closed, in OTLP JSON format.
+    spans: Mutex<Vec<Value>>,
+    /// The number of spans that were dropped, since the buffer was full.
+    dropped: AtomicUsize,
+}
+
+/// The in-progress state for an open span, stored in the span's extensions.
+struct SpanData {
+    span_id: u64,
+    parent_span_id: Option<u64>,
+    start: SystemTime,
+    attributes: Vec<Value>,
+}
+
+/// A [`Layer`] that records spans for export via OTLP.
+#[derive(Debug)]
+pub(crate) struct OtelLayer {
+    exporter: Arc<Exporter>,
+}
+
+/// Enable OTLP export if `UV_OTEL_ENDPOINT` is set, returning the [`Layer`] that records spans.
+///
+/// The spans are exported with the given client, such that the export respects the same TLS,
+/// proxy, and connectivity settings as any other request.
+pub(crate) fn setup_otel(client: BaseClientBuilder<'static>) -> Option<OtelLayer> {
+    let endpoint = std::env::var(EnvVars::UV_OTEL_ENDPOINT).ok()?;
+    let endpoint = match traces_endpoint(&endpoint) {
+        Ok(endpoint) => endpoint,
+        Err(err) => {
+            warn_user_once!("Ignoring invalid `UV_OTEL_ENDPOINT`: {err}");
+            return None;
+        }
+    };
+
+    let (trace_id, parent_span_id) = std::env::var(EnvVars::TRACEPARENT)
+        .ok()
+        .and_then(|traceparent| parse_traceparent(&traceparent))
+        .map_or_else(
+            || ((u128::from(random()) << 64) | u128::from(random()), None),
+            |(trace_id, span_id)| (trace_id, Some(span_id)),
+        );
+
+    let exporter = Arc::new(Exporter {
+        endpoint,
+        trace_id,
+        parent_span_id,
+        client,
+        spans: Mutex::new(Vec::new()),
+        dropped: AtomicUsize::new(0),
+    });
+    EXPORTER.set(exporter.clone()).ok()?;
+    Some(OtelLayer { exporter })
+}
+
+/// Send all recorded spans to the OTLP endpoint.
+///
+/// Should be called once, after the top-level span has closed. Failures are reported as warnings,
+/// since they should never cause the command itself to fail.
+pub(crate) fn flush_otel() {
+    let Some(exporter) = EXPORTER.get() else {
+        return;
+    };
+    let spans = std::mem::take(&mut *exporter.spans.lock().unwrap());
+    let dropped = exporter.dropped.load(Ordering::Relaxed);
+    if dropped > 0 {
+        warn_user_once!(
+            "Dropped {dropped} spans, since only the first {MAX_SPANS} spans are exported to `UV_OTEL_ENDPOINT`"
+        );
+    }
+    if spans.is_empty() {
+        return;
+    }
+
+    let body = json!({
+        "resourceSpans": [{
+            "resource": {
+                "attributes": [
+                    attribute("service.name", "uv"),
+                    attribute("service.version", version()),
+                ],
+            },
+            "scopeSpans": [{
+                "scope": { "name": "uv", "version": version() },
+                "spans": spans,
+            }],
+        }],
+    });
+
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            warn_user_once!("Failed to export spans to `UV_OTEL_ENDPOINT`: {err}");
+            return;
+        }
+    };
+    let result: anyhow::Result<()> = runtime.block_on(async {
+        let client = exporter
+            .client
+            .clone()
+            .retries(0)
+            .default_timeout(Duration::from_secs(10))
+            .build();
+        client
+            .for_host(&exporter.endpoint)
+            .post(exporter.endpoint.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&body)?)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    });
+    if let Err(err) = result {
+        warn_user_once!("Failed to export spans to `UV_OTEL_ENDPOINT`: {err}");
+    }
+}
+
+impl<S> Layer<S> for OtelLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let parent_span_id = span
+            .parent()
+            .and_then(|parent| {
+                parent
+                    .extensions()
+                    .get::<SpanData>()
+                    .map(|data| data.span_id)
+            })
+            .or(self.exporter.parent_span_id);
+        let mut visitor = AttributeVisitor(Vec::new());
+        attrs.record(&mut visitor);
+        span.extensions_mut().insert(SpanData {
+            span_id: random(),
+            parent_span_id,
+            start: SystemTime::now(),
+            attributes: visitor.0,
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(data) = extensions.get_mut::<SpanData>() {
+            let mut visitor = AttributeVisitor(Vec::new());
+            values.record(&mut visitor);
+            data.attributes.extend(visitor.0);
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(data) = span.extensions_mut().remove::<SpanData>() else {
+            return;
+        };
+        let metadata = span.metadata();
+        let mut attributes = data.attributes;
+        attributes.push(attribute("code.namespace", metadata.target()));
+
+        let mut value = json!({
+            "traceId": format!("{:032x}", self.exporter.trace_id),
+            "spanId": format!("{:016x}", data.span_id),
+            "name": metadata.name(),
+            // `SPAN_KIND_INTERNAL`
+            "kind": 1,
+            "startTimeUnixNano": unix_nanos(data.start),
+            "endTimeUnixNano": unix_nanos(SystemTime::now()),
+            "attributes": attributes,
+        });
+        if let Some(parent_span_id) = data.parent_span_id {
+            value["parentSpanId"] = Value::String(format!("{parent_span_id:016x}"));
+        }
+        let mut spans = self.exporter.spans.lock().unwrap();
+        if spans.len() < MAX_SPANS {
+            spans.push(value);
+        } else {
+            self.exporter.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Collects span fields as OTLP attributes.
+struct AttributeVisitor(Vec<Value>);
+
+impl Visit for AttributeVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push(attribute(field.name(), value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.push(json!({
+            "key": field.name(),
+            "value": { "intValue": value.to_string() },
+        }));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.push(json!({
+            "key": field.name(),
+            "value": { "intValue": value.to_string() },
+        }));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.push(json!({
+            "key": field.name(),
+            "value": { "boolValue": value },
+        }));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push(attribute(field.name(), &format!("{value:?}")));
+    }
+}
+
+/// Create a string-valued OTLP attribute.
+fn attribute(key: &str, value: &str) -> Value {
+    json!({ "key": key, "value": { "stringValue": value } })
+}
+
+/// Format a timestamp as nanoseconds since the Unix epoch, as expected by OTLP.
+fn unix_nanos(time: SystemTime) -> String {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+        .to_string()
+}
+
+/// Generate a random, non-zero identifier.
+fn random() -> u64 {
+    loop {
+        let id = RandomState::new().build_hasher().finish();
+        if id != 0 {
+            return id;
+        }
+    }
+}
+
+/// Determine the OTLP traces endpoint from the value of `UV_OTEL_ENDPOINT`.
+///
+/// As in the OpenTelemetry SDKs, `/v1/traces` is appended to the base endpoint, unless already
+/// present.
+fn traces_endpoint(endpoint: &str) -> Result<Url, url::ParseError> {
+    let mut endpoint = Url::parse(endpoint)?;
+    if !endpoint.path().ends_with("/v1/traces") {
+        let path = format!("{}/v1/traces", endpoint.path().trim_end_matches('/'));
+        endpoint.set_path(&path);
+    }
+    Ok(endpoint)
+}
+
+/// Parse a W3C `traceparent` header (e.g., `00-<trace-id>-<parent-id>-<flags>`) into the trace ID
+/// and parent span ID.
+fn parse_traceparent(traceparent: &str) -> Option<(u128, u64)> {
+    let mut parts = traceparent.trim().split('-');
+    let _version = parts.next()?;
+    let trace_id = parts.next().filter(|part| part.len() == 32)?;
+    let span_id = parts.next().filter(|part| part.len() == 16)?;
+    let trace_id = u128::from_str_radix(trace_id, 16)
+        .ok()
+        .filter(|id| *id != 0)?;
+    let span_id = u64::from_str_radix(span_id, 16)
+        .ok()
+        .filter(|id| *id != 0)?;
+    Some((trace_id, span_id))
+}
+
+#[cfg(test)]
+mod tests;
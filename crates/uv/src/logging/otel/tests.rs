@@ -0,0 +1,167 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use serde_json::json;
+use tracing_subscriber::layer::SubscriberExt;
+
+use uv_client::BaseClientBuilder;
+
+use super::{parse_traceparent, traces_endpoint, Exporter, OtelLayer, MAX_SPANS};
+
+fn exporter() -> Arc<Exporter> {
+    Arc::new(Exporter {
+        endpoint: traces_endpoint("http://localhost:4318").unwrap(),
+        trace_id: 0x4bf9_2f35_77b3_4da6_a3ce_929d_0e0e_4736,
+        parent_span_id: Some(0x00f0_67aa_0ba9_02b7),
+        client: BaseClientBuilder::new(),
+        spans: Mutex::new(Vec::new()),
+        dropped: AtomicUsize::new(0),
+    })
+}
+
+#[test]
+fn traceparent() {
+    assert_eq!(
+        parse_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+        Some((
+            0x4bf9_2f35_77b3_4da6_a3ce_929d_0e0e_4736,
+            0x00f0_67aa_0ba9_02b7
+        ))
+    );
+
+    // Surrounding whitespace is ignored.
+    assert!(
+        parse_traceparent(" 00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01\n").is_some()
+    );
+
+    // All-zero identifiers are invalid.
+    assert_eq!(
+        parse_traceparent("00-00000000000000000000000000000000-00f067aa0ba902b7-01"),
+        None
+    );
+    assert_eq!(
+        parse_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01"),
+        None
+    );
+
+    // Identifiers must have the expected length, and be hexadecimal.
+    assert_eq!(
+        parse_traceparent("00-4bf92f3577b34da6a3ce929d0e0e473-00f067aa0ba902b7-01"),
+        None
+    );
+    assert_eq!(
+        parse_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902bz-01"),
+        None
+    );
+    assert_eq!(
+        parse_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736"),
+        None
+    );
+    assert_eq!(parse_traceparent(""), None);
+}
+
+#[test]
+fn endpoint() {
+    let endpoint = |value: &str| traces_endpoint(value).unwrap().to_string();
+
+    assert_eq!(
+        endpoint("http://localhost:4318"),
+        "http://localhost:4318/v1/traces"
+    );
+    assert_eq!(
+        endpoint("http://localhost:4318/"),
+        "http://localhost:4318/v1/traces"
+    );
+    assert_eq!(
+        endpoint("https://otel.example.com/collector/"),
+        "https://otel.example.com/collector/v1/traces"
+    );
+
+    // An endpoint that already includes the traces path is used as-is.
+    assert_eq!(
+        endpoint("http://localhost:4318/v1/traces"),
+        "http://localhost:4318/v1/traces"
+    );
+
+    assert!(traces_endpoint("not a url").is_err());
+}
+
+#[test]
+fn spans() {
+    let exporter = exporter();
+    let subscriber = tracing_subscriber::registry().with(OtelLayer {
+        exporter: exporter.clone(),
+    });
+
+    tracing::subscriber::with_default(subscriber, || {
+        let outer = tracing::info_span!(
+            "outer",
+            package = "anyio",
+            count = 3u64,
+            offset = -1i64,
+            cached = true,
+            later = tracing::field::Empty,
+        );
+        let _guard = outer.enter();
+        outer.record("later", "recorded");
+
+        let _inner = tracing::info_span!("inner", version = ?(1, 2));
+    });
+
+    let spans = exporter.spans.lock().unwrap();
+    assert_eq!(spans.len(), 2);
+    let (inner, outer) = (&spans[0], &spans[1]);
+
+    for span in [inner, outer] {
+        assert_eq!(span["traceId"], "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(span["kind"], 1);
+        assert_eq!(span["spanId"].as_str().map(str::len), Some(16));
+        let start = span["startTimeUnixNano"].as_str().unwrap();
+        let end = span["endTimeUnixNano"].as_str().unwrap();
+        assert!(start.parse::<u128>().unwrap() <= end.parse::<u128>().unwrap());
+    }
+
+    // The root span is parented to the span from `TRACEPARENT`, and the inner span to the root.
+    assert_eq!(outer["name"], "outer");
+    assert_eq!(outer["parentSpanId"], "00f067aa0ba902b7");
+    assert_eq!(inner["name"], "inner");
+    assert_eq!(inner["parentSpanId"], outer["spanId"]);
+
+    // Fields are serialized as typed attributes, followed by the span's target.
+    assert_eq!(
+        outer["attributes"],
+        json!([
+            { "key": "package", "value": { "stringValue": "anyio" } },
+            { "key": "count", "value": { "intValue": "3" } },
+            { "key": "offset", "value": { "intValue": "-1" } },
+            { "key": "cached", "value": { "boolValue": true } },
+            { "key": "later", "value": { "stringValue": "recorded" } },
+            { "key": "code.namespace", "value": { "stringValue": module_path!() } },
+        ])
+    );
+    assert_eq!(
+        inner["attributes"],
+        json!([
+            { "key": "version", "value": { "stringValue": "(1, 2)" } },
+            { "key": "code.namespace", "value": { "stringValue": module_path!() } },
+        ])
+    );
+}
+
+#[test]
+fn spans_limit() {
+    let exporter = exporter();
+    let subscriber = tracing_subscriber::registry().with(OtelLayer {
+        exporter: exporter.clone(),
+    });
+
+    tracing::subscriber::with_default(subscriber, || {
+        for _ in 0..MAX_SPANS + 3 {
+            let _span = tracing::info_span!("span").entered();
+        }
+    });
+
+    // Spans beyond the limit are dropped, rather than buffered.
+    assert_eq!(exporter.spans.lock().unwrap().len(), MAX_SPANS);
+    assert_eq!(exporter.dropped.load(Ordering::Relaxed), 3);
+}
@@ -0,0 +1,140 @@
+//! Machine-readable progress reporting, enabled via `--progress-format json`.
+//!
+//! Each event is written as a single line of JSON, such that consumers (e.g., editor integrations)
+//! can render their own progress UI rather than parsing the progress bars.
+//!
+//! Events are buffered, and flushed whenever an operation starts or completes. Progress events
+//! (which are throttled, in the case of downloads) may thus be delivered in batches.
+use std::io::{BufWriter, Write};
+use std::sync::{Mutex, OnceLock};
+
+use serde::Serialize;
+
+/// The destination for JSON progress events, if enabled.
+static EVENTS: OnceLock<Mutex<Box<dyn Write + Send>>> = OnceLock::new();
+
+/// Enable JSON progress events, writing them to the given destination.
+pub(crate) fn enable(writer: Box<dyn Write + Send>) {
+    let _ = EVENTS.set(Mutex::new(Box::new(BufWriter::new(writer))));
+}
+
+/// Open the destination for JSON progress events: the given file descriptor, or stderr.
+pub(crate) fn writer(fd: Option<u32>) -> anyhow::Result<Box<dyn Write + Send>> {
+    let Some(fd) = fd else {
+        return Ok(Box::new(std::io::stderr()));
+    };
+
+    #[cfg(unix)]
+    {
+        let file = fs_err::OpenOptions::new()
+            .write(true)
+            .open(format!("/dev/fd/{fd}"))?;
+        Ok(Box::new(file))
+    }
+
+    #[cfg(not(unix))]
+    {
+        anyhow::bail!("`--progress-fd {fd}` is only supported on Unix")
+    }
+}
+
+/// Returns `true` if JSON progress events are enabled.
+pub(crate) fn is_enabled() -> bool {
+    EVENTS.get().is_some()
+}
+
+/// Emit a progress event, if JSON progress events are enabled.
+pub(crate) fn emit(event: &ProgressEvent) {
+    let Some(events) = EVENTS.get() else {
+        return;
+    };
+    let Ok(line) = serde_json::to_string(event) else {
+        return;
+    };
+    let mut writer = events.lock().unwrap();
+    // Progress reporting is best-effort, so ignore failures (e.g., if the consumer hung up).
+    let _ = writeln!(writer, "{line}");
+    if !event.is_progress() {
+        let _ = writer.flush();
+    }
+}
+
+/// A phase of an operation, for which progress is reported in aggregate.
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum Phase {
+    /// Resolving dependencies.
+    Resolve,
+    /// Downloading and building distributions.
+    Prepare,
+    /// Installing distributions into the environment.
+    Install,
+}
+
+/// A progress event.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+pub(crate) enum ProgressEvent<'a> {
+    /// A phase has started.
+    PhaseStart { phase: Phase },
+    /// A phase has made progress, e.g., a package was resolved or installed.
+    PhaseProgress {
+        phase: Phase,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        package: Option<String>,
+        completed: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total: Option<u64>,
+    },
+    /// A phase has completed.
+    PhaseComplete { phase: Phase },
+    /// A download has started.
+    DownloadStart {
+        id: usize,
+        name: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total_bytes: Option<u64>,
+    },
+    /// A download has made progress.
+    DownloadProgress {
+        id: usize,
+        bytes: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total_bytes: Option<u64>,
+    },
+    /// A download has completed.
+    DownloadComplete { id: usize },
+    /// A source distribution build has started.
+    BuildStart { id: usize, source: String },
+    /// A source distribution build has completed.
+    BuildComplete { id: usize, source: String },
+    /// A Git checkout has started.
+    CheckoutStart {
+        id: usize,
+        url: &'a str,
+        rev: &'a str,
+    },
+    /// A Git checkout has completed.
+    CheckoutComplete {
+        id: usize,
+        url: &'a str,
+        rev: &'a str,
+    },
+    /// A sync has started, in `uv sync --watch`.
+    SyncStart,
+    /// A sync has completed, in `uv sync --watch`.
+    SyncComplete { success: bool },
+    /// A watched file has changed, triggering a re-sync, in `uv sync --watch`.
+    WatchChange { paths: Vec<String> },
+}
+
+impl ProgressEvent<'_> {
+    /// Returns `true` if the event reports incremental progress, rather than the start or
+    /// completion of an operation.
+    fn is_progress(&self) -> bool {
+        matches!(
+            self,
+            Self::PhaseProgress { .. } | Self::DownloadProgress { .. }
+        )
+    }
+}
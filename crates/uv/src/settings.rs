@@ -6,30 +6,39 @@ use std::str::FromStr;
 
 use url::Url;
 use uv_cache::{CacheArgs, Refresh};
+use uv_cache_info::CacheDuration;
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     options::{flag, resolver_installer_options, resolver_options},
-    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ToolUpgradeArgs,
+    AuthorFrom, BuildArgs, BuildImageArgs, BundleArgs, BundleFormat, CacheExportArgs, CompleteArgs,
+    DownloadArgs, ExportArgs, ProxyArgs, PublishArgs, PythonDirArgs, QueryLockStatusArgs,
+    QueryResolveArgs, ScriptAuditArgs, ScriptLockArgs, ScriptOutdatedArgs, ScriptSyncArgs,
+    ScriptTreeArgs, ScriptVendorArgs, ToolUpgradeArgs, VendorFormat, WarmArgs,
 };
 use uv_cli::{
-    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+    AddArgs, CacheSummaryFormat, ColorChoice, ExternalCommand, FileCategory, GlobalArgs, InitArgs,
+    ListFormat, LockArgs, Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs,
+    PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, ProgressFormat,
+    PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonPinArgs, PythonUninstallArgs,
+    RemoveArgs, RunArgs, SyncArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
+    ToolUninstallArgs, TreeArgs, VenvArgs,
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, DevGroupsSpecification, EditableMode, ExportFormat,
-    ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions, KeyringProviderType,
-    NoBinary, NoBuild, PreviewMode, ProjectBuildBackend, Reinstall, SourceStrategy, TargetTriple,
+    AddBound, BuildOptions, BuildTarget, CompileBytecode, Concurrency, ConfigSettings,
+    DevGroupsSpecification, DistroPackages, EditableMode, ExportFormat, ExtrasSpecification,
+    HashCheckingMode, IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild,
+    PreviewMode, ProjectBuildBackend, PycInvalidationMode, Reinstall, SourceStrategy, TargetTriple,
     TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, IndexUrl};
 use uv_install_wheel::linker::LinkMode;
+use uv_install_wheel::ScriptLauncher;
 use uv_normalize::PackageName;
 use uv_pep508::{ExtraName, RequirementOrigin};
+use uv_platform_tags::TagPreferences;
 use uv_pypi_types::{Requirement, SupportedEnvironments};
+use uv_python::managed::RegistryScope;
 use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
 use uv_resolver::{AnnotationStyle, DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
 use uv_settings::{
@@ -41,7 +50,10 @@ use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::DependencyType;
 
 use crate::commands::ToolRunCommand;
-use crate::commands::{pip::operations::Modifications, InitKind, InitProjectKind};
+use crate::commands::{
+    pip::operations::{DryRun, Modifications},
+    InitKind, InitProjectKind,
+};
 
 /// The default publish URL.
 const PYPI_PUBLISH_URL: &str = "https://upload.pypi.org/legacy/";
@@ -61,7 +73,12 @@ pub(crate) struct GlobalSettings {
     pub(crate) preview: PreviewMode,
     pub(crate) python_preference: PythonPreference,
     pub(crate) python_downloads: PythonDownloads,
+    pub(crate) tag_preferences: TagPreferences,
     pub(crate) no_progress: bool,
+    pub(crate) progress_format: ProgressFormat,
+    pub(crate) progress_fd: Option<u32>,
+    pub(crate) build_report: Option<PathBuf>,
+    pub(crate) resolution_suggestions: Option<PathBuf>,
 }
 
 impl GlobalSettings {
@@ -148,7 +165,17 @@ impl GlobalSettings {
                 .combine(env(env::UV_PYTHON_DOWNLOADS))
                 .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
                 .unwrap_or_default(),
+            tag_preferences: TagPreferences::new(
+                workspace
+                    .and_then(|workspace| workspace.globals.platform_tag_preference.clone())
+                    .unwrap_or_default(),
+                workspace.and_then(|workspace| workspace.globals.max_manylinux),
+            ),
             no_progress: args.no_progress,
+            progress_format: args.progress_format.unwrap_or_default(),
+            progress_fd: args.progress_fd,
+            build_report: args.build_report.clone(),
+            resolution_suggestions: args.resolution_suggestions.clone(),
         }
     }
 }
@@ -159,6 +186,9 @@ impl GlobalSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) cache_lock_timeout: Option<CacheDuration>,
+    pub(crate) offline_grace: Option<CacheDuration>,
+    pub(crate) revalidate_package: Vec<PackageName>,
 }
 
 impl CacheSettings {
@@ -172,6 +202,9 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            cache_lock_timeout: args.cache_lock_timeout,
+            offline_grace: args.offline_grace,
+            revalidate_package: args.revalidate_package,
         }
     }
 }
@@ -260,7 +293,10 @@ pub(crate) struct RunSettings {
     pub(crate) with: Vec<String>,
     pub(crate) with_editable: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
+    pub(crate) with_lock: Option<PathBuf>,
+    pub(crate) with_overlay: Option<PathBuf>,
     pub(crate) isolated: bool,
+    pub(crate) container: Option<String>,
     pub(crate) show_resolution: bool,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
@@ -295,7 +331,10 @@ impl RunSettings {
             with,
             with_editable,
             with_requirements,
+            with_lock,
+            with_overlay,
             isolated,
+            container,
             no_sync,
             locked,
             frozen,
@@ -339,7 +378,10 @@ impl RunSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            with_lock,
+            with_overlay,
             isolated,
+            container,
             show_resolution,
             all_packages,
             package,
@@ -652,6 +694,7 @@ pub(crate) struct PythonListSettings {
     pub(crate) kinds: PythonListKinds,
     pub(crate) all_platforms: bool,
     pub(crate) all_versions: bool,
+    pub(crate) json: bool,
 }
 
 impl PythonListSettings {
@@ -662,6 +705,7 @@ impl PythonListSettings {
             all_versions,
             all_platforms,
             only_installed,
+            json,
         } = args;
 
         let kinds = if only_installed {
@@ -674,6 +718,7 @@ impl PythonListSettings {
             kinds,
             all_platforms,
             all_versions,
+            json,
         }
     }
 }
@@ -704,6 +749,7 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) force: bool,
     pub(crate) python_install_mirror: Option<String>,
     pub(crate) pypy_install_mirror: Option<String>,
+    pub(crate) registry: Option<RegistryScope>,
 }
 
 impl PythonInstallSettings {
@@ -727,14 +773,24 @@ impl PythonInstallSettings {
             force,
             mirror: _,
             pypy_mirror: _,
+            registry,
+            no_registry,
+            registry_scope,
         } = args;
 
+        let registry = match flag(registry, no_registry) {
+            Some(true) => Some(registry_scope.unwrap_or_default()),
+            Some(false) => None,
+            None => registry_scope,
+        };
+
         Self {
             targets,
             reinstall,
             force,
             python_install_mirror: python_mirror,
             pypy_install_mirror: pypy_mirror,
+            registry,
         }
     }
 }
@@ -745,6 +801,7 @@ impl PythonInstallSettings {
 pub(crate) struct PythonUninstallSettings {
     pub(crate) targets: Vec<String>,
     pub(crate) all: bool,
+    pub(crate) migrate: bool,
 }
 
 impl PythonUninstallSettings {
@@ -754,9 +811,17 @@ impl PythonUninstallSettings {
         args: PythonUninstallArgs,
         _filesystem: Option<FilesystemOptions>,
     ) -> Self {
-        let PythonUninstallArgs { targets, all } = args;
+        let PythonUninstallArgs {
+            targets,
+            all,
+            migrate,
+        } = args;
 
-        Self { targets, all }
+        Self {
+            targets,
+            all,
+            migrate,
+        }
     }
 }
 
@@ -827,9 +892,16 @@ pub(crate) struct SyncSettings {
     pub(crate) editable: EditableMode,
     pub(crate) install_options: InstallOptions,
     pub(crate) modifications: Modifications,
+    pub(crate) dry_run: DryRun,
+    pub(crate) locked_indexes: bool,
+    pub(crate) refresh_scripts: bool,
+    pub(crate) target: Option<PathBuf>,
+    pub(crate) watch: bool,
+    pub(crate) cache_summary: Option<CacheSummaryFormat>,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
+    pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
@@ -855,14 +927,25 @@ impl SyncSettings {
             no_install_project,
             no_install_workspace,
             no_install_package,
+            only,
+            except,
             locked,
             frozen,
+            dry_run,
+            show_sizes,
+            json,
+            locked_indexes,
+            refresh_scripts,
+            target,
+            watch,
+            cache_summary,
             installer,
             build,
             refresh,
             all_packages,
             package,
             python,
+            python_platform,
         } = args;
         let install_mirrors = filesystem
             .clone()
@@ -889,15 +972,23 @@ impl SyncSettings {
                 no_install_project,
                 no_install_workspace,
                 no_install_package,
-            ),
+            )
+            .with_subgraph(only, except),
             modifications: if flag(exact, inexact).unwrap_or(true) {
                 Modifications::Exact
             } else {
                 Modifications::Sufficient
             },
+            dry_run: DryRun::from_args(dry_run, show_sizes, json),
+            locked_indexes,
+            refresh_scripts,
+            target,
+            watch,
+            cache_summary,
             all_packages,
             package,
             python: python.and_then(Maybe::into_option),
+            python_platform,
             refresh: Refresh::from(refresh),
             settings,
             install_mirrors,
@@ -912,6 +1003,16 @@ pub(crate) struct LockSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) dry_run: bool,
+    pub(crate) minimize: bool,
+    pub(crate) check_requires_python: bool,
+    pub(crate) check_source_builds: bool,
+    pub(crate) check_conflicts: bool,
+    pub(crate) provenance: bool,
+    pub(crate) verify_provenance: bool,
+    pub(crate) report: Option<PathBuf>,
+    pub(crate) interactive: bool,
+    pub(crate) cache_summary: Option<CacheSummaryFormat>,
+    pub(crate) environment: Option<String>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -926,6 +1027,16 @@ impl LockSettings {
             locked,
             frozen,
             dry_run,
+            minimize,
+            check_requires_python,
+            check_source_builds,
+            check_conflicts,
+            provenance,
+            verify_provenance,
+            report,
+            interactive,
+            cache_summary,
+            environment,
             resolver,
             build,
             refresh,
@@ -941,6 +1052,16 @@ impl LockSettings {
             locked,
             frozen,
             dry_run,
+            minimize,
+            check_requires_python,
+            check_source_builds,
+            check_conflicts,
+            provenance,
+            verify_provenance,
+            report,
+            interactive,
+            cache_summary,
+            environment,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -958,10 +1079,12 @@ pub(crate) struct AddSettings {
     pub(crate) no_sync: bool,
     pub(crate) packages: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) from_imports: bool,
     pub(crate) dependency_type: DependencyType,
     pub(crate) editable: Option<bool>,
     pub(crate) extras: Vec<ExtraName>,
     pub(crate) raw_sources: bool,
+    pub(crate) bound: AddBound,
     pub(crate) rev: Option<String>,
     pub(crate) tag: Option<String>,
     pub(crate) branch: Option<String>,
@@ -981,6 +1104,7 @@ impl AddSettings {
         let AddArgs {
             packages,
             requirements,
+            from_imports,
             dev,
             optional,
             group,
@@ -988,6 +1112,7 @@ impl AddSettings {
             no_editable,
             extra,
             raw_sources,
+            bound,
             rev,
             tag,
             branch,
@@ -1062,14 +1187,20 @@ impl AddSettings {
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
 
+        let bound = bound
+            .or_else(|| filesystem.as_ref().and_then(|fs| fs.add_bound))
+            .unwrap_or_default();
+
         Self {
             locked,
             frozen,
             no_sync,
             packages,
             requirements,
+            from_imports,
             dependency_type,
             raw_sources,
+            bound,
             rev,
             tag,
             branch,
@@ -1097,108 +1228,641 @@ pub(crate) struct RemoveSettings {
     pub(crate) frozen: bool,
     pub(crate) no_sync: bool,
     pub(crate) packages: Vec<PackageName>,
+    pub(crate) unused: bool,
+    pub(crate) yes: bool,
     pub(crate) dependency_type: DependencyType,
     pub(crate) package: Option<PackageName>,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
-    pub(crate) install_mirrors: PythonInstallMirrors,
-    pub(crate) refresh: Refresh,
-    pub(crate) settings: ResolverInstallerSettings,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl RemoveSettings {
+    /// Resolve the [`RemoveSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: RemoveArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let RemoveArgs {
+            dev,
+            optional,
+            packages,
+            unused,
+            yes,
+            group,
+            no_sync,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            package,
+            script,
+            python,
+        } = args;
+
+        let dependency_type = if let Some(extra) = optional {
+            DependencyType::Optional(extra)
+        } else if let Some(group) = group {
+            DependencyType::Group(group)
+        } else if dev {
+            DependencyType::Dev
+        } else {
+            DependencyType::Production
+        };
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            locked,
+            frozen,
+            no_sync,
+            packages,
+            unused,
+            yes,
+            dependency_type,
+            package,
+            script,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for a `tree` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct TreeSettings {
+    pub(crate) dev: DevGroupsSpecification,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) universal: bool,
+    pub(crate) depth: u8,
+    pub(crate) prune: Vec<PackageName>,
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) no_dedupe: bool,
+    pub(crate) invert: bool,
+    pub(crate) why: Vec<PackageName>,
+    pub(crate) diff: Option<PathBuf>,
+    pub(crate) json: bool,
+    pub(crate) outdated: bool,
+    pub(crate) python_version: Option<PythonVersion>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl TreeSettings {
+    /// Resolve the [`TreeSettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(args: TreeArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let TreeArgs {
+            tree,
+            why,
+            diff,
+            json,
+            universal,
+            dev,
+            only_dev,
+            no_dev,
+            group,
+            no_group,
+            only_group,
+            locked,
+            frozen,
+            build,
+            resolver,
+            python_version,
+            python_platform,
+            python,
+        } = args;
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            dev: DevGroupsSpecification::from_args(
+                dev, no_dev, only_dev, group, no_group, only_group,
+            ),
+            locked,
+            frozen,
+            universal,
+            depth: tree.depth,
+            prune: tree.prune,
+            package: tree.package,
+            no_dedupe: tree.no_dedupe,
+            invert: tree.invert,
+            why,
+            diff,
+            json,
+            outdated: tree.outdated,
+            python_version,
+            python_platform,
+            python: python.and_then(Maybe::into_option),
+            resolver: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for a `script lock` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ScriptLockSettings {
+    pub(crate) script: PathBuf,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) dry_run: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl ScriptLockSettings {
+    /// Resolve the [`ScriptLockSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ScriptLockArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ScriptLockArgs {
+            script,
+            locked,
+            frozen,
+            dry_run,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            script,
+            locked,
+            frozen,
+            dry_run,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for a `script sync` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ScriptSyncSettings {
+    pub(crate) script: PathBuf,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl ScriptSyncSettings {
+    /// Resolve the [`ScriptSyncSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ScriptSyncArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ScriptSyncArgs {
+            script,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            script,
+            locked,
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for a `script tree` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ScriptTreeSettings {
+    pub(crate) script: PathBuf,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) universal: bool,
+    pub(crate) depth: u8,
+    pub(crate) prune: Vec<PackageName>,
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) no_dedupe: bool,
+    pub(crate) invert: bool,
+    pub(crate) outdated: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl ScriptTreeSettings {
+    /// Resolve the [`ScriptTreeSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: ScriptTreeArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ScriptTreeArgs {
+            script,
+            universal,
+            tree,
+            locked,
+            frozen,
+            resolver,
+            build,
+            python,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            script,
+            locked,
+            frozen,
+            universal,
+            depth: tree.depth,
+            prune: tree.prune,
+            package: tree.package,
+            no_dedupe: tree.no_dedupe,
+            invert: tree.invert,
+            outdated: tree.outdated,
+            python: python.and_then(Maybe::into_option),
+            resolver: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for a `script outdated` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ScriptOutdatedSettings {
+    pub(crate) script: PathBuf,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl ScriptOutdatedSettings {
+    /// Resolve the [`ScriptOutdatedSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: ScriptOutdatedArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ScriptOutdatedArgs {
+            script,
+            locked,
+            frozen,
+            resolver,
+            build,
+            python,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            script,
+            locked,
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            resolver: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for a `script audit` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ScriptAuditSettings {
+    pub(crate) script: PathBuf,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl ScriptAuditSettings {
+    /// Resolve the [`ScriptAuditSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: ScriptAuditArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ScriptAuditArgs {
+            script,
+            resolver,
+            build,
+            python,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            script,
+            python: python.and_then(Maybe::into_option),
+            resolver: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for a `script vendor` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ScriptVendorSettings {
+    pub(crate) script: PathBuf,
+    pub(crate) format: VendorFormat,
+    pub(crate) output: Option<PathBuf>,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl ScriptVendorSettings {
+    /// Resolve the [`ScriptVendorSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ScriptVendorArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ScriptVendorArgs {
+            script,
+            format,
+            output,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            python,
+            python_platform,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            script,
+            format,
+            output,
+            locked,
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            python_platform,
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for a `query resolve` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct QueryResolveSettings {
+    pub(crate) requirements: Vec<String>,
+    pub(crate) python: Option<String>,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl QueryResolveSettings {
+    /// Resolve the [`QueryResolveSettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(args: QueryResolveArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let QueryResolveArgs {
+            requirements,
+            build,
+            resolver,
+            python,
+        } = args;
+
+        Self {
+            requirements,
+            python: python.and_then(Maybe::into_option),
+            resolver: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache export` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheExportSettings {
+    pub(crate) packages: Vec<PackageName>,
+    pub(crate) output: Option<PathBuf>,
+    pub(crate) index_locations: IndexLocations,
+    pub(crate) index_strategy: IndexStrategy,
+    pub(crate) keyring_provider: KeyringProviderType,
+    pub(crate) exclude_newer: Option<ExcludeNewer>,
+}
+
+impl CacheExportSettings {
+    /// Resolve the [`CacheExportSettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(args: CacheExportArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let CacheExportArgs {
+            package,
+            output,
+            fetch,
+        } = args;
+        let PipSettings {
+            index_locations,
+            index_strategy,
+            keyring_provider,
+            exclude_newer,
+            ..
+        } = PipSettings::combine(PipOptions::from(fetch), filesystem);
+        Self {
+            packages: package,
+            output,
+            index_locations,
+            index_strategy,
+            keyring_provider,
+            exclude_newer,
+        }
+    }
+}
+
+/// The resolved settings to use for a `__complete` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CompleteSettings {
+    pub(crate) shell: clap_complete_command::Shell,
+    pub(crate) line: String,
+    pub(crate) index_locations: IndexLocations,
+    pub(crate) keyring_provider: KeyringProviderType,
+}
+
+impl CompleteSettings {
+    /// Resolve the [`CompleteSettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(args: CompleteArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let CompleteArgs { shell, line } = args;
+        let PipSettings {
+            index_locations,
+            keyring_provider,
+            ..
+        } = PipSettings::combine(PipOptions::default(), filesystem);
+        Self {
+            shell,
+            line,
+            index_locations,
+            keyring_provider,
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache warm` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheWarmSettings {
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) python: Option<String>,
+    pub(crate) python_versions: Vec<PythonVersion>,
+    pub(crate) python_platforms: Vec<TargetTriple>,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl CacheWarmSettings {
+    /// Resolve the [`CacheWarmSettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(args: WarmArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let WarmArgs {
+            requirements,
+            python_version,
+            python_platform,
+            build,
+            resolver,
+            python,
+        } = args;
+        Self {
+            requirements,
+            python: python.and_then(Maybe::into_option),
+            python_versions: python_version,
+            python_platforms: python_platform,
+            resolver: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for a `download` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct DownloadSettings {
+    pub(crate) packages: Vec<String>,
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) out_dir: Option<PathBuf>,
+    pub(crate) python: Option<String>,
+    pub(crate) python_versions: Vec<PythonVersion>,
+    pub(crate) python_platforms: Vec<TargetTriple>,
+    pub(crate) resolver: ResolverSettings,
 }
 
-impl RemoveSettings {
-    /// Resolve the [`RemoveSettings`] from the CLI and filesystem configuration.
-    #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: RemoveArgs, filesystem: Option<FilesystemOptions>) -> Self {
-        let RemoveArgs {
-            dev,
-            optional,
-            packages,
-            group,
-            no_sync,
-            locked,
-            frozen,
-            installer,
-            build,
-            refresh,
+impl DownloadSettings {
+    /// Resolve the [`DownloadSettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(args: DownloadArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let DownloadArgs {
             package,
-            script,
+            requirements,
+            out_dir,
+            python_version,
+            python_platform,
+            build,
+            resolver,
             python,
         } = args;
+        Self {
+            packages: package,
+            requirements,
+            out_dir,
+            python: python.and_then(Maybe::into_option),
+            python_versions: python_version,
+            python_platforms: python_platform,
+            resolver: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+        }
+    }
+}
 
-        let dependency_type = if let Some(extra) = optional {
-            DependencyType::Optional(extra)
-        } else if let Some(group) = group {
-            DependencyType::Group(group)
-        } else if dev {
-            DependencyType::Dev
-        } else {
-            DependencyType::Production
-        };
+/// The resolved settings to use for a `proxy` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ProxySettings {
+    pub(crate) host: String,
+    pub(crate) port: u16,
+    pub(crate) settings: PipSettings,
+}
 
-        let install_mirrors = filesystem
-            .clone()
-            .map(|fs| fs.install_mirrors.clone())
-            .unwrap_or_default();
+impl ProxySettings {
+    /// Resolve the [`ProxySettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: ProxyArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ProxyArgs {
+            host,
+            port,
+            index_args,
+            index_strategy,
+            keyring_provider,
+            exclude_newer,
+        } = args;
 
         Self {
-            locked,
-            frozen,
-            no_sync,
-            packages,
-            dependency_type,
-            package,
-            script,
-            python: python.and_then(Maybe::into_option),
-            refresh: Refresh::from(refresh),
-            settings: ResolverInstallerSettings::combine(
-                resolver_installer_options(installer, build),
+            host,
+            port,
+            settings: PipSettings::combine(
+                PipOptions {
+                    index_strategy,
+                    keyring_provider,
+                    exclude_newer,
+                    ..PipOptions::from(index_args)
+                },
                 filesystem,
             ),
-            install_mirrors,
         }
     }
 }
 
-/// The resolved settings to use for a `tree` invocation.
-#[allow(clippy::struct_excessive_bools)]
+/// The resolved settings to use for a `query lock-status` invocation.
 #[derive(Debug, Clone)]
-pub(crate) struct TreeSettings {
-    pub(crate) dev: DevGroupsSpecification,
-    pub(crate) locked: bool,
-    pub(crate) frozen: bool,
-    pub(crate) universal: bool,
-    pub(crate) depth: u8,
-    pub(crate) prune: Vec<PackageName>,
-    pub(crate) package: Vec<PackageName>,
-    pub(crate) no_dedupe: bool,
-    pub(crate) invert: bool,
-    pub(crate) outdated: bool,
-    pub(crate) python_version: Option<PythonVersion>,
-    pub(crate) python_platform: Option<TargetTriple>,
+pub(crate) struct QueryLockStatusSettings {
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) resolver: ResolverSettings,
 }
 
-impl TreeSettings {
-    /// Resolve the [`TreeSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: TreeArgs, filesystem: Option<FilesystemOptions>) -> Self {
-        let TreeArgs {
-            tree,
-            universal,
-            dev,
-            only_dev,
-            no_dev,
-            group,
-            no_group,
-            only_group,
-            locked,
-            frozen,
+impl QueryLockStatusSettings {
+    /// Resolve the [`QueryLockStatusSettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(
+        args: QueryLockStatusArgs,
+        filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let QueryLockStatusArgs {
             build,
             resolver,
-            python_version,
-            python_platform,
             python,
         } = args;
         let install_mirrors = filesystem
@@ -1207,20 +1871,6 @@ impl TreeSettings {
             .unwrap_or_default();
 
         Self {
-            dev: DevGroupsSpecification::from_args(
-                dev, no_dev, only_dev, group, no_group, only_group,
-            ),
-            locked,
-            frozen,
-            universal,
-            depth: tree.depth,
-            prune: tree.prune,
-            package: tree.package,
-            no_dedupe: tree.no_dedupe,
-            invert: tree.invert,
-            outdated: tree.outdated,
-            python_version,
-            python_platform,
             python: python.and_then(Maybe::into_option),
             resolver: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
             install_mirrors,
@@ -1239,8 +1889,13 @@ pub(crate) struct ExportSettings {
     pub(crate) dev: DevGroupsSpecification,
     pub(crate) editable: EditableMode,
     pub(crate) hashes: bool,
+    pub(crate) annotate: bool,
     pub(crate) install_options: InstallOptions,
     pub(crate) output_file: Option<PathBuf>,
+    pub(crate) distro_packages: DistroPackages,
+    pub(crate) environment: Option<String>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) pyodide_index_url: Option<IndexUrl>,
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) include_header: bool,
@@ -1272,10 +1927,16 @@ impl ExportSettings {
             no_editable,
             hashes,
             no_hashes,
+            annotate,
+            no_annotate,
             output_file,
+            distro_package,
             no_emit_project,
             no_emit_workspace,
             no_emit_package,
+            environment,
+            python_platform,
+            pyodide_index_url,
             locked,
             frozen,
             resolver,
@@ -1301,12 +1962,17 @@ impl ExportSettings {
             ),
             editable: EditableMode::from_args(no_editable),
             hashes: flag(hashes, no_hashes).unwrap_or(true),
+            annotate: flag(annotate, no_annotate).unwrap_or(false),
             install_options: InstallOptions::new(
                 no_emit_project,
                 no_emit_workspace,
                 no_emit_package,
             ),
             output_file,
+            distro_packages: DistroPackages::from_entries(distro_package),
+            environment,
+            python_platform,
+            pyodide_index_url,
             locked,
             frozen,
             include_header: flag(header, no_header).unwrap_or(true),
@@ -1318,6 +1984,166 @@ impl ExportSettings {
     }
 }
 
+/// The resolved settings to use for a `build-image` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct BuildImageSettings {
+    pub(crate) tag: Option<String>,
+    pub(crate) platforms: Vec<String>,
+    pub(crate) export: ExportSettings,
+}
+
+impl BuildImageSettings {
+    /// Resolve the [`BuildImageSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: BuildImageArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let BuildImageArgs {
+            tag,
+            platform,
+            all_packages,
+            package,
+            extra,
+            all_extras,
+            no_all_extras,
+            dev,
+            no_dev,
+            only_dev,
+            group,
+            no_group,
+            only_group,
+            no_editable,
+            no_install_project,
+            no_install_workspace,
+            no_install_package,
+            locked,
+            frozen,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            tag,
+            platforms: platform,
+            export: ExportSettings {
+                format: ExportFormat::Dockerfile,
+                all_packages,
+                package,
+                extras: ExtrasSpecification::from_args(
+                    flag(all_extras, no_all_extras).unwrap_or_default(),
+                    extra.unwrap_or_default(),
+                ),
+                dev: DevGroupsSpecification::from_args(
+                    dev, no_dev, only_dev, group, no_group, only_group,
+                ),
+                editable: EditableMode::from_args(no_editable),
+                hashes: false,
+                annotate: false,
+                install_options: InstallOptions::new(
+                    no_install_project,
+                    no_install_workspace,
+                    no_install_package,
+                ),
+                output_file: None,
+                distro_packages: DistroPackages::default(),
+                environment: None,
+                python_platform: None,
+                pyodide_index_url: None,
+                locked,
+                frozen,
+                include_header: true,
+                python: python.and_then(Maybe::into_option),
+                refresh: Refresh::from(refresh),
+                settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+                install_mirrors,
+            },
+        }
+    }
+}
+
+/// The resolved settings to use for a `bundle` invocation.
+#[allow(clippy::struct_excessive_bools, dead_code)]
+#[derive(Debug, Clone)]
+pub(crate) struct BundleSettings {
+    pub(crate) format: BundleFormat,
+    pub(crate) output: Option<PathBuf>,
+    pub(crate) entry_point: Option<String>,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) dev: DevGroupsSpecification,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl BundleSettings {
+    /// Resolve the [`BundleSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: BundleArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let BundleArgs {
+            format,
+            output,
+            entry_point,
+            package,
+            extra,
+            all_extras,
+            no_all_extras,
+            dev,
+            group,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            python,
+            python_platform,
+        } = args;
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        let settings = ResolverInstallerSettings::combine(
+            resolver_installer_options(installer, build),
+            filesystem,
+        );
+
+        Self {
+            format,
+            output,
+            entry_point,
+            package,
+            extras: ExtrasSpecification::from_args(
+                flag(all_extras, no_all_extras).unwrap_or_default(),
+                extra.unwrap_or_default(),
+            ),
+            dev: DevGroupsSpecification::from_args(
+                dev,
+                false,
+                false,
+                group,
+                Vec::new(),
+                Vec::new(),
+            ),
+            locked,
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            python_platform,
+            refresh: Refresh::from(refresh),
+            settings,
+            install_mirrors,
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip compile` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -1329,6 +2155,10 @@ pub(crate) struct PipCompileSettings {
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) split_output_dir: Option<PathBuf>,
+    pub(crate) split_python_versions: Vec<PythonVersion>,
+    pub(crate) split_python_platforms: Vec<TargetTriple>,
+    pub(crate) pyodide_index_url: Option<IndexUrl>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1372,6 +2202,10 @@ impl PipCompileSettings {
             python_platform,
             universal,
             no_universal,
+            split_output_dir,
+            split_python_version,
+            split_python_platform,
+            pyodide_index_url,
             no_emit_package,
             emit_index_url,
             no_emit_index_url,
@@ -1437,6 +2271,10 @@ impl PipCompileSettings {
             constraints_from_workspace,
             overrides_from_workspace,
             environments,
+            split_output_dir,
+            split_python_versions: split_python_version,
+            split_python_platforms: split_python_platform,
+            pyodide_index_url,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -1478,6 +2316,7 @@ impl PipCompileSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
+    pub(crate) from_conda: Vec<PathBuf>,
     pub(crate) constraint: Vec<PathBuf>,
     pub(crate) build_constraint: Vec<PathBuf>,
     pub(crate) dry_run: bool,
@@ -1490,6 +2329,7 @@ impl PipSyncSettings {
     pub(crate) fn resolve(args: Box<PipSyncArgs>, filesystem: Option<FilesystemOptions>) -> Self {
         let PipSyncArgs {
             src_file,
+            from_conda,
             constraint,
             build_constraint,
             installer,
@@ -1505,6 +2345,7 @@ impl PipSyncSettings {
             no_break_system_packages,
             target,
             prefix,
+            root,
             allow_empty_requirements,
             no_allow_empty_requirements,
             no_build,
@@ -1521,6 +2362,7 @@ impl PipSyncSettings {
 
         Self {
             src_file,
+            from_conda,
             constraint: constraint
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -1538,6 +2380,7 @@ impl PipSyncSettings {
                     break_system_packages: flag(break_system_packages, no_break_system_packages),
                     target,
                     prefix,
+                    root,
                     require_hashes: flag(require_hashes, no_require_hashes),
                     verify_hashes: flag(verify_hashes, no_verify_hashes),
                     no_build: flag(no_build, build),
@@ -1569,6 +2412,7 @@ pub(crate) struct PipInstallSettings {
     pub(crate) r#override: Vec<PathBuf>,
     pub(crate) build_constraint: Vec<PathBuf>,
     pub(crate) dry_run: bool,
+    pub(crate) report: Option<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) modifications: Modifications,
@@ -1604,6 +2448,7 @@ impl PipInstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            root,
             no_build,
             build,
             no_binary,
@@ -1615,6 +2460,7 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            report,
             compat_args: _,
         } = args;
 
@@ -1663,6 +2509,7 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run,
+            report,
             constraints_from_workspace,
             overrides_from_workspace,
             modifications: if flag(exact, inexact).unwrap_or(false) {
@@ -1678,6 +2525,7 @@ impl PipInstallSettings {
                     break_system_packages: flag(break_system_packages, no_break_system_packages),
                     target,
                     prefix,
+                    root,
                     no_build: flag(no_build, build),
                     no_binary,
                     only_binary,
@@ -1786,6 +2634,7 @@ pub(crate) struct PipListSettings {
     pub(crate) exclude: Vec<PackageName>,
     pub(crate) format: ListFormat,
     pub(crate) outdated: bool,
+    pub(crate) uptodate: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -1799,6 +2648,8 @@ impl PipListSettings {
             format,
             outdated,
             no_outdated,
+            uptodate,
+            no_uptodate,
             strict,
             no_strict,
             fetch,
@@ -1813,6 +2664,7 @@ impl PipListSettings {
             exclude,
             format,
             outdated: flag(outdated, no_outdated).unwrap_or(false),
+            uptodate: flag(uptodate, no_uptodate).unwrap_or(false),
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -1832,6 +2684,7 @@ impl PipListSettings {
 pub(crate) struct PipShowSettings {
     pub(crate) package: Vec<PackageName>,
     pub(crate) files: bool,
+    pub(crate) category: Vec<FileCategory>,
     pub(crate) settings: PipSettings,
 }
 
@@ -1843,6 +2696,7 @@ impl PipShowSettings {
             strict,
             no_strict,
             files,
+            category,
             python,
             system,
             no_system,
@@ -1851,7 +2705,8 @@ impl PipShowSettings {
 
         Self {
             package,
-            files,
+            files: files || !category.is_empty(),
+            category,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -1953,6 +2808,7 @@ pub(crate) struct BuildSettings {
     pub(crate) out_dir: Option<PathBuf>,
     pub(crate) sdist: bool,
     pub(crate) wheel: bool,
+    pub(crate) target: Vec<BuildTarget>,
     pub(crate) build_logs: bool,
     pub(crate) build_constraint: Vec<PathBuf>,
     pub(crate) hash_checking: Option<HashCheckingMode>,
@@ -1972,6 +2828,7 @@ impl BuildSettings {
             all_packages,
             sdist,
             wheel,
+            target,
             build_constraint,
             require_hashes,
             no_require_hashes,
@@ -1997,6 +2854,7 @@ impl BuildSettings {
             out_dir,
             sdist,
             wheel,
+            target,
             build_logs: flag(build_logs, no_build_logs).unwrap_or(true),
             build_constraint: build_constraint
                 .into_iter()
@@ -2089,7 +2947,9 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
-    pub(crate) compile_bytecode: bool,
+    pub(crate) compile_bytecode: &'a CompileBytecode,
+    pub(crate) invalidation_mode: Option<PycInvalidationMode>,
+    pub(crate) script_launcher: &'a ScriptLauncher,
     pub(crate) reinstall: &'a Reinstall,
     pub(crate) build_options: &'a BuildOptions,
     pub(crate) sources: SourceStrategy,
@@ -2112,6 +2972,7 @@ pub(crate) struct ResolverSettings {
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: Vec<PackageName>,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) max_backtracks: Option<usize>,
     pub(crate) link_mode: LinkMode,
     pub(crate) upgrade: Upgrade,
     pub(crate) build_options: BuildOptions,
@@ -2130,6 +2991,7 @@ pub(crate) struct ResolverSettingsRef<'a> {
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) max_backtracks: Option<usize>,
     pub(crate) link_mode: LinkMode,
     pub(crate) upgrade: &'a Upgrade,
     pub(crate) build_options: &'a BuildOptions,
@@ -2139,14 +3001,16 @@ pub(crate) struct ResolverSettingsRef<'a> {
 impl ResolverSettings {
     /// Resolve the [`ResolverSettings`] from the CLI and filesystem configuration.
     pub(crate) fn combine(args: ResolverOptions, filesystem: Option<FilesystemOptions>) -> Self {
-        let options = args.combine(ResolverOptions::from(
-            filesystem
-                .map(FilesystemOptions::into_options)
-                .map(|options| options.top_level)
-                .unwrap_or_default(),
-        ));
+        let Options {
+            top_level, pins, ..
+        } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+        let options = args.combine(ResolverOptions::from(top_level));
 
-        Self::from(options)
+        let mut settings = Self::from(options);
+        settings.upgrade = settings.upgrade.with_pins(&pins.unwrap_or_default());
+        settings
     }
 
     pub(crate) fn as_ref(&self) -> ResolverSettingsRef {
@@ -2161,6 +3025,7 @@ impl ResolverSettings {
             no_build_isolation: self.no_build_isolation,
             no_build_isolation_package: &self.no_build_isolation_package,
             exclude_newer: self.exclude_newer,
+            max_backtracks: self.max_backtracks,
             link_mode: self.link_mode,
             upgrade: &self.upgrade,
             build_options: &self.build_options,
@@ -2199,6 +3064,7 @@ impl From<ResolverOptions> for ResolverSettings {
             no_build_isolation: value.no_build_isolation.unwrap_or_default(),
             no_build_isolation_package: value.no_build_isolation_package.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
+            max_backtracks: value.max_backtracks,
             link_mode: value.link_mode.unwrap_or_default(),
             sources: SourceStrategy::from_args(value.no_sources.unwrap_or_default()),
             upgrade: Upgrade::from_args(
@@ -2230,8 +3096,11 @@ pub(crate) struct ResolverInstallerSettingsRef<'a> {
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) max_backtracks: Option<usize>,
     pub(crate) link_mode: LinkMode,
-    pub(crate) compile_bytecode: bool,
+    pub(crate) compile_bytecode: &'a CompileBytecode,
+    pub(crate) invalidation_mode: Option<PycInvalidationMode>,
+    pub(crate) script_launcher: &'a ScriptLauncher,
     pub(crate) sources: SourceStrategy,
     pub(crate) upgrade: &'a Upgrade,
     pub(crate) reinstall: &'a Reinstall,
@@ -2256,8 +3125,11 @@ pub(crate) struct ResolverInstallerSettings {
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: Vec<PackageName>,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) max_backtracks: Option<usize>,
     pub(crate) link_mode: LinkMode,
-    pub(crate) compile_bytecode: bool,
+    pub(crate) compile_bytecode: CompileBytecode,
+    pub(crate) invalidation_mode: Option<PycInvalidationMode>,
+    pub(crate) script_launcher: ScriptLauncher,
     pub(crate) sources: SourceStrategy,
     pub(crate) upgrade: Upgrade,
     pub(crate) reinstall: Reinstall,
@@ -2270,14 +3142,16 @@ impl ResolverInstallerSettings {
         args: ResolverInstallerOptions,
         filesystem: Option<FilesystemOptions>,
     ) -> Self {
-        let options = args.combine(
-            filesystem
-                .map(FilesystemOptions::into_options)
-                .map(|options| options.top_level)
-                .unwrap_or_default(),
-        );
+        let Options {
+            top_level, pins, ..
+        } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+        let options = args.combine(top_level);
 
-        Self::from(options)
+        let mut settings = Self::from(options);
+        settings.upgrade = settings.upgrade.with_pins(&pins.unwrap_or_default());
+        settings
     }
 
     pub(crate) fn as_ref(&self) -> ResolverInstallerSettingsRef {
@@ -2292,8 +3166,11 @@ impl ResolverInstallerSettings {
             no_build_isolation: self.no_build_isolation,
             no_build_isolation_package: &self.no_build_isolation_package,
             exclude_newer: self.exclude_newer,
+            max_backtracks: self.max_backtracks,
             link_mode: self.link_mode,
-            compile_bytecode: self.compile_bytecode,
+            compile_bytecode: &self.compile_bytecode,
+            invalidation_mode: self.invalidation_mode,
+            script_launcher: &self.script_launcher,
             sources: self.sources,
             upgrade: &self.upgrade,
             reinstall: &self.reinstall,
@@ -2332,9 +3209,12 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
             no_build_isolation: value.no_build_isolation.unwrap_or_default(),
             no_build_isolation_package: value.no_build_isolation_package.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
+            max_backtracks: value.max_backtracks,
             link_mode: value.link_mode.unwrap_or_default(),
             sources: SourceStrategy::from_args(value.no_sources.unwrap_or_default()),
             compile_bytecode: value.compile_bytecode.unwrap_or_default(),
+            invalidation_mode: value.invalidation_mode,
+            script_launcher: value.script_launcher.unwrap_or_default(),
             upgrade: Upgrade::from_args(
                 value.upgrade,
                 value
@@ -2394,6 +3274,7 @@ pub(crate) struct PipSettings {
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) universal: bool,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) max_backtracks: Option<usize>,
     pub(crate) no_emit_package: Vec<PackageName>,
     pub(crate) emit_index_url: bool,
     pub(crate) emit_find_links: bool,
@@ -2402,7 +3283,9 @@ pub(crate) struct PipSettings {
     pub(crate) emit_index_annotation: bool,
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
-    pub(crate) compile_bytecode: bool,
+    pub(crate) compile_bytecode: CompileBytecode,
+    pub(crate) invalidation_mode: Option<PycInvalidationMode>,
+    pub(crate) script_launcher: ScriptLauncher,
     pub(crate) sources: SourceStrategy,
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) upgrade: Upgrade,
@@ -2416,6 +3299,7 @@ impl PipSettings {
             top_level,
             pip,
             install_mirrors,
+            pins,
             ..
         } = filesystem
             .map(FilesystemOptions::into_options)
@@ -2427,6 +3311,7 @@ impl PipSettings {
             break_system_packages,
             target,
             prefix,
+            root,
             index,
             index_url,
             extra_index_url,
@@ -2459,6 +3344,7 @@ impl PipSettings {
             python_platform,
             universal,
             exclude_newer,
+            max_backtracks,
             no_emit_package,
             emit_index_url,
             emit_find_links,
@@ -2468,6 +3354,8 @@ impl PipSettings {
             annotation_style,
             link_mode,
             compile_bytecode,
+            invalidation_mode,
+            script_launcher,
             require_hashes,
             verify_hashes,
             no_sources,
@@ -2492,8 +3380,11 @@ impl PipSettings {
             no_build_isolation: top_level_no_build_isolation,
             no_build_isolation_package: top_level_no_build_isolation_package,
             exclude_newer: top_level_exclude_newer,
+            max_backtracks: top_level_max_backtracks,
             link_mode: top_level_link_mode,
             compile_bytecode: top_level_compile_bytecode,
+            invalidation_mode: top_level_invalidation_mode,
+            script_launcher: top_level_script_launcher,
             no_sources: top_level_no_sources,
             upgrade: top_level_upgrade,
             upgrade_package: top_level_upgrade_package,
@@ -2524,8 +3415,11 @@ impl PipSettings {
         let no_build_isolation_package =
             no_build_isolation_package.combine(top_level_no_build_isolation_package);
         let exclude_newer = exclude_newer.combine(top_level_exclude_newer);
+        let max_backtracks = max_backtracks.combine(top_level_max_backtracks);
         let link_mode = link_mode.combine(top_level_link_mode);
         let compile_bytecode = compile_bytecode.combine(top_level_compile_bytecode);
+        let invalidation_mode = invalidation_mode.combine(top_level_invalidation_mode);
+        let script_launcher = script_launcher.combine(top_level_script_launcher);
         let no_sources = no_sources.combine(top_level_no_sources);
         let upgrade = upgrade.combine(top_level_upgrade);
         let upgrade_package = upgrade_package.combine(top_level_upgrade_package);
@@ -2615,6 +3509,7 @@ impl PipSettings {
             python_platform: args.python_platform.combine(python_platform),
             universal: args.universal.combine(universal).unwrap_or_default(),
             exclude_newer: args.exclude_newer.combine(exclude_newer),
+            max_backtracks: args.max_backtracks.combine(max_backtracks),
             no_emit_package: args
                 .no_emit_package
                 .combine(no_emit_package)
@@ -2655,11 +3550,22 @@ impl PipSettings {
                 .combine(break_system_packages)
                 .unwrap_or_default(),
             target: args.target.combine(target).map(Target::from),
-            prefix: args.prefix.combine(prefix).map(Prefix::from),
+            prefix: args.prefix.combine(prefix).map(|prefix| {
+                let prefix = Prefix::from(prefix);
+                match args.root.combine(root) {
+                    Some(root) => prefix.with_root(root),
+                    None => prefix,
+                }
+            }),
             compile_bytecode: args
                 .compile_bytecode
                 .combine(compile_bytecode)
                 .unwrap_or_default(),
+            invalidation_mode: args.invalidation_mode.combine(invalidation_mode),
+            script_launcher: args
+                .script_launcher
+                .combine(script_launcher)
+                .unwrap_or_default(),
             sources: SourceStrategy::from_args(
                 args.no_sources.combine(no_sources).unwrap_or_default(),
             ),
@@ -2672,7 +3578,8 @@ impl PipSettings {
                     .flatten()
                     .map(Requirement::from)
                     .collect(),
-            ),
+            )
+            .with_pins(&pins.unwrap_or_default()),
             reinstall: Reinstall::from_args(
                 args.reinstall.combine(reinstall),
                 args.reinstall_package
@@ -2712,6 +3619,7 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for ResolverSettingsRef<'a> {
             no_build_isolation: settings.no_build_isolation,
             no_build_isolation_package: settings.no_build_isolation_package,
             exclude_newer: settings.exclude_newer,
+            max_backtracks: settings.max_backtracks,
             link_mode: settings.link_mode,
             upgrade: settings.upgrade,
             build_options: settings.build_options,
@@ -2733,6 +3641,8 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for InstallerSettingsRef<'a> {
             exclude_newer: settings.exclude_newer,
             link_mode: settings.link_mode,
             compile_bytecode: settings.compile_bytecode,
+            invalidation_mode: settings.invalidation_mode,
+            script_launcher: settings.script_launcher,
             reinstall: settings.reinstall,
             build_options: settings.build_options,
             sources: settings.sources,
@@ -2748,6 +3658,7 @@ pub(crate) struct PublishSettings {
     pub(crate) files: Vec<String>,
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
+    pub(crate) skip_existing: bool,
 
     // Both CLI and configuration.
     pub(crate) publish_url: Url,
@@ -2784,6 +3695,7 @@ impl PublishSettings {
             files: args.files,
             username,
             password,
+            skip_existing: args.skip_existing,
             publish_url: args
                 .publish_url
                 .combine(publish_url)
@@ -2085,3 +2085,48 @@ fn build_non_package() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn build_target_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    // A platform tag is required.
+    uv_snapshot!(&context.filters(), context.build().arg("project").arg("--target").arg("cp312"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'cp312' for '--target <TARGET>': Expected a target of the form `<python-tag>-<platform-tag>` (e.g., `cp312-manylinux_2_28_x86_64`), but found: `cp312`
+
+    For more information, try '--help'.
+    "###);
+
+    // Only CPython and PyPy tags are supported.
+    uv_snapshot!(&context.filters(), context.build().arg("project").arg("--target").arg("py3-manylinux_2_28_x86_64"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'py3-manylinux_2_28_x86_64' for '--target <TARGET>': Unsupported Python tag `py3`; expected a CPython or PyPy tag (e.g., `cp312` or `pp310`)
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
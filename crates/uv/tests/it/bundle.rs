@@ -0,0 +1,121 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Bundle a project without dependencies, and run a script with the bundle.
+#[test]
+fn bundle_zipapp() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(indoc! {r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+    "#})?;
+
+    context
+        .temp_dir
+        .child("script.py")
+        .write_str("import sys; print(sys.argv)")?;
+
+    uv_snapshot!(context.filters(), context.bundle().arg("--frozen").arg("--preview"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited in [TIME]
+    Bundled project for Python 3.12 to: dist/project.pyz
+    "###);
+
+    let bundle = context.temp_dir.child("dist").child("project.pyz");
+    bundle.assert(predicates::path::is_file());
+
+    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg("python").arg(bundle.path()).arg("script.py").arg("--flag"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ['script.py', '--flag']
+
+    ----- stderr -----
+    Audited in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Bundle a project as a directory.
+#[test]
+fn bundle_directory() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(indoc! {r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.bundle().arg("--frozen").arg("--preview").arg("--format").arg("directory").arg("--output").arg("bundle"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited in [TIME]
+    Bundled project for Python 3.12 to: bundle
+    "###);
+
+    context
+        .temp_dir
+        .child("bundle")
+        .child("__main__.py")
+        .assert(predicates::path::is_file());
+    context
+        .temp_dir
+        .child("bundle")
+        .child("site-packages")
+        .assert(predicates::path::is_dir());
+
+    // Bundling to an existing directory that isn't a bundle should fail.
+    context.temp_dir.child("other").child("file.txt").touch()?;
+    uv_snapshot!(context.filters(), context.bundle().arg("--frozen").arg("--preview").arg("--format").arg("directory").arg("--output").arg("other"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The output directory `other` already exists and is not a bundle
+    "###);
+
+    Ok(())
+}
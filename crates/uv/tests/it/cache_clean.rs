@@ -51,7 +51,7 @@ fn clean_package_pypi() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v14")
+        .child("simple-v15")
         .child("pypi")
         .child("iniconfig.rkyv");
     assert!(
@@ -123,7 +123,7 @@ fn clean_package_index() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v14")
+        .child("simple-v15")
         .child("index")
         .child("e8208120cae3ba69")
         .child("iniconfig.rkyv");
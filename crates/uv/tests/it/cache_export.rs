@@ -0,0 +1,78 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// `cache export` should export the cached index metadata, along with the cached metadata of each
+/// version, without accessing the network.
+#[test]
+fn export() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Start from an empty cache, as creating the test environment populates the cache.
+    fs_err::remove_dir_all(&context.cache_dir)?;
+
+    uv_snapshot!(context.filters(), context.cache_export(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported 0 packages (0 versions with metadata) in [TIME]
+    "###);
+
+    // Populate the cache.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.cache_export().arg("--output").arg("metadata.jsonl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported 1 package (1 versions with metadata) in [TIME]
+    Wrote metadata to: metadata.jsonl
+    "###);
+
+    let contents = context.read("metadata.jsonl");
+    let records = contents
+        .lines()
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(records.len(), 1);
+
+    let record = &records[0];
+    assert_eq!(record["name"], "iniconfig");
+    assert_eq!(record["index"], "https://pypi.org/simple");
+
+    // Only the installed version has cached metadata; the other versions are listed without it.
+    let versions = record["versions"].as_array().unwrap();
+    assert!(versions.len() > 1);
+    for version in versions {
+        if version["version"] == "2.0.0" {
+            assert_eq!(version["requires_python"], ">=3.7");
+            assert_eq!(version["requires_dist"], serde_json::json!([]));
+            assert_eq!(version["provides_extras"], serde_json::json!([]));
+            assert_eq!(version["yanked"], false);
+        } else {
+            assert!(version["requires_dist"].is_null());
+            assert!(version["provides_extras"].is_null());
+        }
+    }
+
+    // Packages that aren't in the cache are omitted.
+    uv_snapshot!(context.filters(), context.cache_export().arg("flask"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported 0 packages (0 versions with metadata) in [TIME]
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+#![cfg(not(windows))]
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// Write an unzipped wheel to the cache, linked from the cache's wheel bucket.
+fn write_wheel(context: &TestContext) -> Result<()> {
+    // Start from an empty cache, as creating the test environment populates the cache.
+    fs_err::remove_dir_all(&context.cache_dir)?;
+
+    let archive = context.cache_dir.child("archive-v0").child("example");
+    archive
+        .child("example")
+        .child("__init__.py")
+        .write_str("print('hello')\n")?;
+    archive
+        .child("example-1.0.0.dist-info")
+        .child("RECORD")
+        .write_str("example/__init__.py,,\n")?;
+
+    let directory = context
+        .cache_dir
+        .child("wheels-v3")
+        .child("pypi")
+        .child("example");
+    directory.create_dir_all()?;
+    directory
+        .child("example-1.0.0-py3-none-any")
+        .symlink_to_dir(archive.path())?;
+    Ok(())
+}
+
+/// Write a lockfile that contains `example`, which is cached, and `other`, which isn't.
+fn write_lockfile(context: &TestContext) -> Result<()> {
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "example"
+        version = "1.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/example-1.0.0-py3-none-any.whl", hash = "sha256:0000000000000000000000000000000000000000000000000000000000000000", size = 100 },
+        ]
+
+        [[package]]
+        name = "other"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/other-2.0.0-py3-none-any.whl", hash = "sha256:0000000000000000000000000000000000000000000000000000000000000000", size = 100 },
+        ]
+    "#})?;
+    Ok(())
+}
+
+/// `cache stats` should report the usage of each bucket, and attribute usage to the lockfile.
+#[test]
+fn stats() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    write_wheel(&context)?;
+    write_lockfile(&context)?;
+
+    uv_snapshot!(context.filters(), context.cache_stats().arg("--lockfile").arg("uv.lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Cache at: [CACHE_DIR]/
+    wheels-v3: [SIZE] in 0 files
+    archive-v0: [SIZE] in 2 files (oldest modified just now, newest modified just now)
+    Total: [SIZE] in 2 files (oldest modified just now, newest modified just now)
+
+    Project at: uv.lock
+    Cached packages: 1 of 2 (50%)
+    Cache usage: [SIZE] in 2 files (oldest modified just now, newest modified just now)
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// `cache stats --json` should report the same statistics as JSON.
+#[test]
+fn stats_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    write_wheel(&context)?;
+    write_lockfile(&context)?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r#""\d{4}-\d{2}-\d{2}T[^"]+""#, r#""[TIMESTAMP]""#)])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.cache_stats().arg("--lockfile").arg("uv.lock").arg("--json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "root": "[CACHE_DIR]/",
+      "total": {
+        "size": 37,
+        "files": 2,
+        "oldest": "[TIMESTAMP]",
+        "newest": "[TIMESTAMP]"
+      },
+      "buckets": [
+        {
+          "name": "wheels-v3",
+          "size": 0,
+          "files": 0,
+          "oldest": null,
+          "newest": null
+        },
+        {
+          "name": "archive-v0",
+          "size": 37,
+          "files": 2,
+          "oldest": "[TIMESTAMP]",
+          "newest": "[TIMESTAMP]"
+        }
+      ],
+      "projects": [
+        {
+          "lockfile": "uv.lock",
+          "packages": 2,
+          "cached": 1,
+          "hit_rate": 0.5,
+          "size": 37,
+          "files": 2,
+          "oldest": "[TIMESTAMP]",
+          "newest": "[TIMESTAMP]"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+#![cfg(not(windows))]
+
+use anyhow::Result;
+use assert_fs::fixture::ChildPath;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// Write an unzipped wheel to the cache's archive bucket.
+fn write_archive(context: &TestContext, id: &str, content: &str) -> Result<()> {
+    let archive = context.cache_dir.child("archive-v0").child(id);
+    archive
+        .child("example")
+        .child("__init__.py")
+        .write_str(content)?;
+    archive
+        .child("example-1.0.0.dist-info")
+        .child("RECORD")
+        .write_str(indoc! {"
+            example/__init__.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+            example-1.0.0.dist-info/RECORD,,
+        "})?;
+    Ok(())
+}
+
+/// Link an unzipped wheel from the cache's wheel bucket.
+fn link_archive(context: &TestContext, id: &str) -> Result<ChildPath> {
+    let directory = context
+        .cache_dir
+        .child("wheels-v3")
+        .child("pypi")
+        .child("example");
+    directory.create_dir_all()?;
+    let link = directory.child("example-1.0.0-py3-none-any");
+    link.symlink_to_dir(context.cache_dir.child("archive-v0").child(id).path())?;
+    Ok(link)
+}
+
+/// `cache verify` should succeed if every entry in the cache is intact.
+#[test]
+fn verify_no_op() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    write_archive(&context, "intact", "print('hello')\n")?;
+    link_archive(&context, "intact")?;
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified 1 archive; no problems found
+    "###);
+
+    Ok(())
+}
+
+/// `cache verify` should report any corrupted archives, and remove them with `--repair`.
+#[test]
+fn verify_corrupted() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    write_archive(&context, "intact", "print('hello')\n")?;
+    write_archive(&context, "corrupted", "print('goodbye')\n")?;
+    let link = link_archive(&context, "corrupted")?;
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Corrupted archive: [CACHE_DIR]/archive-v0/corrupted (RECORD file doesn't match wheel contents: Size mismatch for example/__init__.py: expected 15 bytes, found 17 bytes)
+    Orphaned archive: [CACHE_DIR]/archive-v0/intact
+    Found 2 problems; run `uv cache verify --repair` to remove the affected entries
+    "###);
+
+    uv_snapshot!(context.filters(), context.cache_verify().arg("--repair"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Corrupted archive: [CACHE_DIR]/archive-v0/corrupted (RECORD file doesn't match wheel contents: Size mismatch for example/__init__.py: expected 15 bytes, found 17 bytes)
+    Orphaned archive: [CACHE_DIR]/archive-v0/intact
+    Repaired 2 problems, removing 5 files ([SIZE])
+    "###);
+
+    // The link to the corrupted archive should be removed, too.
+    assert!(!link.path().exists());
+    assert!(!link.path().is_symlink());
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified 0 archives; no problems found
+    "###);
+
+    Ok(())
+}
+
+/// `cache verify` should report any links to archives that no longer exist.
+#[test]
+fn verify_broken_link() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    write_archive(&context, "missing", "print('hello')\n")?;
+    link_archive(&context, "missing")?;
+    fs_err::remove_dir_all(context.cache_dir.child("archive-v0").child("missing"))?;
+
+    uv_snapshot!(context.filters(), context.cache_verify().arg("--repair"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Broken link: [CACHE_DIR]/wheels-v3/pypi/example/example-1.0.0-py3-none-any
+    Repaired 1 problem, removing 1 file ([SIZE])
+    "###);
+
+    Ok(())
+}
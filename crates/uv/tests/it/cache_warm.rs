@@ -0,0 +1,139 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// `cache warm` should prefetch the distributions in the lockfile, without installing them.
+#[test]
+fn warm_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [dependency-groups]
+        dev = ["typing-extensions"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.cache_warm().arg("--python-platform").arg("x86_64-unknown-linux-gnu"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prefetching 2 packages for Python 3.12 on linux (x86_64)
+    Prefetched 2 packages for 1 target in [TIME]
+    "###);
+
+    // Nothing should be installed.
+    assert!(!context.temp_dir.child(".venv").exists());
+
+    // A second invocation should be served entirely from the cache.
+    uv_snapshot!(context.filters(), context.cache_warm().arg("--python-platform").arg("x86_64-unknown-linux-gnu"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prefetched 0 packages for 1 target in [TIME]
+    2 packages already cached
+    "###);
+
+    Ok(())
+}
+
+/// `cache warm` should skip any targets that aren't supported by the lockfile.
+#[test]
+fn warm_lockfile_incompatible_python() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.cache_warm()
+        .arg("--python-version").arg("3.11")
+        .arg("--python-version").arg("3.12")
+        .arg("--python-platform").arg("x86_64-unknown-linux-gnu"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping Python 3.11 on linux (x86_64), which is not compatible with the locked Python requirement: `>=3.12`
+    Prefetching 1 package for Python 3.12 on linux (x86_64)
+    Prefetched 1 package for 1 target in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// `cache warm` should prefetch the distributions required by a requirements file, for each
+/// target.
+#[test]
+fn warm_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.cache_warm()
+        .arg("--requirements").arg("requirements.txt")
+        .arg("--python-platform").arg("x86_64-unknown-linux-gnu")
+        .arg("--python-platform").arg("aarch64-apple-darwin"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prefetching 1 package for Python 3.12 on linux (x86_64)
+    Resolved 1 package in [TIME]
+    Prefetched 1 package for 2 targets in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// `cache warm` requires a lockfile if no requirements are provided.
+#[test]
+fn warm_missing_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.cache_warm(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    "###);
+
+    Ok(())
+}
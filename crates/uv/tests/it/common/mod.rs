@@ -454,6 +454,9 @@ impl TestContext {
     /// * Use a temporary virtual environment with the Python version of [`Self`]
     /// * Don't wrap text output based on the terminal we're in, the test output doesn't get printed
     ///   but snapshotted to a string.
+    /// * Hide the suggested fixes for resolution failures, such that the many snapshots of
+    ///   resolution errors don't change whenever the suggestions do. The suggestions are covered by
+    ///   `lock_conflict_suggestions`, which opts back in.
     /// * Use a fake `HOME` to avoid accidentally changing the developer's machine.
     /// * Hide other Pythons with `UV_PYTHON_INSTALL_DIR` and installed interpreters with
     ///   `UV_TEST_PYTHON_PATH` and an active venv (if applicable) by removing `VIRTUAL_ENV`.
@@ -615,6 +618,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv bundle` command with options shared across scenarios.
+    pub fn bundle(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("bundle");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv build` command with options shared across scenarios.
     pub fn build(&self) -> Command {
         let mut command = self.new_command();
@@ -650,6 +661,18 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python list` command with options shared across scenarios.
+    pub fn python_list(&self) -> Command {
+        let mut command = self.new_command();
+        command
+            .arg("python")
+            .arg("list")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, "")
+            .current_dir(&self.temp_dir);
+        self.add_shared_args(&mut command, true);
+        command
+    }
+
     /// Create a `uv python install` command with options shared across scenarios.
     pub fn python_install(&self) -> Command {
         let mut command = self.new_command();
@@ -695,6 +718,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python refresh` command with options shared across scenarios.
+    pub fn python_refresh(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("python").arg("refresh");
+        self.add_shared_args(&mut command, true);
+        command
+    }
+
     /// Create a `uv python dir` command with options shared across scenarios.
     pub fn python_dir(&self) -> Command {
         let mut command = self.new_command();
@@ -779,6 +810,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv pin` command with options shared across scenarios.
+    pub fn pin(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pin");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv tree` command with options shared across scenarios.
     pub fn tree(&self) -> Command {
         let mut command = self.new_command();
@@ -787,6 +826,46 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv version` command with options shared across scenarios.
+    pub fn version(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("version");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv query` command with options shared across scenarios.
+    pub fn query(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("query");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv script` command with options shared across scenarios.
+    pub fn script(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("script");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv config` command with options shared across scenarios.
+    pub fn config(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("config");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv workspace` command with options shared across scenarios.
+    pub fn workspace(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("workspace");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache clean` command.
     pub fn clean(&self) -> Command {
         let mut command = self.new_command();
@@ -803,6 +882,38 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache stats` command.
+    pub fn cache_stats(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("stats");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache export` command.
+    pub fn cache_export(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("export");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache verify` command.
+    pub fn cache_verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache warm` command.
+    pub fn cache_warm(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("warm");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -1342,6 +1453,63 @@ pub fn make_project(dir: &Path, name: &str, body: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Create a minimal, pure-Python wheel for `name` at `version` in `dir`, containing a single
+/// module that exposes the version as `__version__`. Returns the path to the wheel.
+pub fn make_wheel(dir: &Path, name: &str, version: &str) -> anyhow::Result<PathBuf> {
+    make_wheel_with_dependencies(dir, name, version, &[])
+}
+
+/// Create a minimal, pure-Python wheel, as in [`make_wheel`], that declares the given
+/// `Requires-Dist` entries.
+pub fn make_wheel_with_dependencies(
+    dir: &Path,
+    name: &str,
+    version: &str,
+    requires_dist: &[&str],
+) -> anyhow::Result<PathBuf> {
+    use std::io::Write;
+
+    let module = name.replace('-', "_");
+    let dist_info = format!("{module}-{version}.dist-info");
+    let files = [
+        (
+            format!("{module}/__init__.py"),
+            format!("__version__ = \"{version}\"\n"),
+        ),
+        (
+            format!("{dist_info}/METADATA"),
+            format!(
+                "Metadata-Version: 2.1\nName: {name}\nVersion: {version}\n{}",
+                requires_dist
+                    .iter()
+                    .map(|requirement| format!("Requires-Dist: {requirement}\n"))
+                    .collect::<String>()
+            ),
+        ),
+        (
+            format!("{dist_info}/WHEEL"),
+            "Wheel-Version: 1.0\nGenerator: uv-test\nRoot-Is-Purelib: true\nTag: py3-none-any\n"
+                .to_string(),
+        ),
+        (
+            format!("{dist_info}/RECORD"),
+            format!(
+                "{module}/__init__.py,,\n{dist_info}/METADATA,,\n{dist_info}/WHEEL,,\n{dist_info}/RECORD,,\n"
+            ),
+        ),
+    ];
+
+    fs_err::create_dir_all(dir)?;
+    let path = dir.join(format!("{module}-{version}-py3-none-any.whl"));
+    let mut archive = zip::ZipWriter::new(fs_err::File::create(&path)?);
+    for (name, contents) in files {
+        archive.start_file(name, zip::write::FileOptions::default())?;
+        archive.write_all(contents.as_bytes())?;
+    }
+    archive.finish()?;
+    Ok(path)
+}
+
 // This is a fine-grained token that only has read-only access to the `uv-private-pypackage` repository
 pub const READ_ONLY_GITHUB_TOKEN: &[&str] = &[
     "Z2l0aHViX3BhdA==",
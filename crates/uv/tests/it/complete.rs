@@ -0,0 +1,146 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Complete the extras and dependency groups of the current project.
+#[test]
+fn complete_extras_and_groups() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cli = ["click"]
+        docs = ["mkdocs"]
+
+        [dependency-groups]
+        lint = ["ruff"]
+        test = ["pytest"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("bash").arg("--").arg("uv sync --extra "), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cli
+    docs
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("bash").arg("--").arg("uv run --group t"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    test
+
+    ----- stderr -----
+    "###);
+
+    // Bash splits words on `=`, so only the value is completed; other shells expect the entire
+    // word.
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("bash").arg("--").arg("uv sync --extra=d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    docs
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("zsh").arg("--").arg("uv sync --extra=d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    --extra=docs
+
+    ----- stderr -----
+    "###);
+
+    // Options without dynamic values, and arguments after `--`, fall back to the static
+    // completions.
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("bash").arg("--").arg("uv sync --python "), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("bash").arg("--").arg("uv run -- python --extra "), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Complete package names and versions from the cache.
+#[test]
+fn complete_packages() {
+    let context = TestContext::new("3.12");
+
+    // Populate the cache.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("bash").arg("--").arg("uv add ini"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("bash").arg("--").arg("uv pip install iniconfig==2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    2.0.0
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("zsh").arg("--").arg("uv add iniconfig==2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+
+    ----- stderr -----
+    "###);
+
+    // Packages that aren't in the cache aren't completed, nor are the requirements of commands
+    // that don't accept them.
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("bash").arg("--").arg("uv add flask=="), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().arg("__complete").arg("bash").arg("--").arg("uv remove ini"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+}
@@ -0,0 +1,405 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// List the effective settings, along with their origins.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn config_list() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+        resolution = "lowest-direct"
+
+        [pip]
+        index-url = "https://test.pypi.org/simple"
+    "#})?;
+
+    let xdg = context.temp_dir.child("xdg");
+    xdg.child("uv")
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+        python-preference = "only-managed"
+        resolution = "highest"
+    "#})?;
+
+    // The project configuration takes precedence over the user configuration.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("list")
+        .env(EnvVars::XDG_CONFIG_HOME, xdg.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    exclude-newer = "2024-03-25T00:00:00Z"
+    pip.index-url = "https://test.pypi.org/simple"
+    python-preference = "only-managed"
+    resolution = "lowest-direct"
+
+    ----- stderr -----
+    "###
+    );
+
+    // Environment variables take precedence over any configuration file.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("list")
+        .arg("--origin")
+        .env(EnvVars::XDG_CONFIG_HOME, xdg.path())
+        .env(EnvVars::UV_RESOLUTION, "lowest"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    env:UV_EXCLUDE_NEWER	exclude-newer = "2024-03-25T00:00:00Z"
+    file:uv.toml	pip.index-url = "https://test.pypi.org/simple"
+    file:xdg/uv/uv.toml	python-preference = "only-managed"
+    env:UV_RESOLUTION	resolution = "lowest"
+
+    ----- stderr -----
+    "###
+    );
+
+    // With `--no-config`, only environment variables are respected.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("list")
+        .arg("--origin")
+        .arg("--no-config")
+        .env(EnvVars::XDG_CONFIG_HOME, xdg.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    env:UV_EXCLUDE_NEWER	exclude-newer = "2024-03-25T00:00:00Z"
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Layer multiple configuration files, with later files taking precedence.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn config_list_config_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("base.toml")
+        .write_str(indoc::indoc! {r#"
+        resolution = "lowest-direct"
+        link-mode = "copy"
+    "#})?;
+    context
+        .temp_dir
+        .child("ci.toml")
+        .write_str(indoc::indoc! {r#"
+        resolution = "highest"
+    "#})?;
+
+    // Discovered configuration files are ignored.
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+        compile-bytecode = true
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("list")
+        .arg("--origin")
+        .arg("--config-file")
+        .arg("base.toml")
+        .arg("--config-file")
+        .arg("ci.toml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    env:UV_EXCLUDE_NEWER	exclude-newer = "2024-03-25T00:00:00Z"
+    file:base.toml	link-mode = "copy"
+    file:ci.toml	resolution = "highest"
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.config()
+        .arg("list")
+        .arg("--origin")
+        .arg("--config-file")
+        .arg("ci.toml")
+        .arg("--config-file")
+        .arg("base.toml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    env:UV_EXCLUDE_NEWER	exclude-newer = "2024-03-25T00:00:00Z"
+    file:base.toml	link-mode = "copy"
+    file:base.toml	resolution = "lowest-direct"
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Show the effective value of a single setting.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn config_get() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        resolution = "lowest-direct"
+
+        [tool.uv.pip]
+        index-url = "https://test.pypi.org/simple"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.config().arg("get").arg("resolution"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    "lowest-direct"
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.config().arg("get").arg("pip.index-url").arg("--origin"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    file:pyproject.toml	"https://test.pypi.org/simple"
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.config().arg("get").arg("link-mode"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    `link-mode` is not set
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.config().arg("get").arg("pip"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unknown setting: `pip`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Set a value in the project configuration file.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn config_set() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Without a configuration file, a `uv.toml` is created.
+    uv_snapshot!(context.filters(), context.config().arg("set").arg("resolution").arg("lowest-direct"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `resolution` in: uv.toml
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.config().arg("set").arg("pip.index-url").arg("https://test.pypi.org/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `pip.index-url` in: uv.toml
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.config().arg("set").arg("concurrent-downloads").arg("4"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `concurrent-downloads` in: uv.toml
+    "###
+    );
+
+    let uv_toml = fs_err::read_to_string(context.temp_dir.child("uv.toml"))?;
+    assert_snapshot!(uv_toml, @r###"
+    resolution = "lowest-direct"
+    concurrent-downloads = 4
+
+    [pip]
+    index-url = "https://test.pypi.org/simple"
+    "###
+    );
+
+    // Invalid values are rejected, and the file is left unchanged.
+    uv_snapshot!(context.filters(), context.config().arg("set").arg("resolution").arg("bogus"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `uv.toml`
+      Caused by: TOML parse error at line 1, column 14
+      |
+    1 | resolution = "bogus"
+      |              ^^^^^^^
+    unknown variant `bogus`, expected one of `highest`, `lowest`, `lowest-direct`
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.config().arg("set").arg("unknown").arg("1"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unknown setting: `unknown`
+    "###
+    );
+
+    let uv_toml = fs_err::read_to_string(context.temp_dir.child("uv.toml"))?;
+    assert_snapshot!(uv_toml, @r###"
+    resolution = "lowest-direct"
+    concurrent-downloads = 4
+
+    [pip]
+    index-url = "https://test.pypi.org/simple"
+    "###
+    );
+
+    // A value that's overridden by an environment variable is written, with a warning.
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("resolution")
+        .arg("highest")
+        .env(EnvVars::UV_RESOLUTION, "lowest"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `resolution` in: uv.toml
+    warning: `resolution` is overridden by `env:UV_RESOLUTION`, which takes precedence
+    "###
+    );
+
+    Ok(())
+}
+
+/// Set a value in the `[tool.uv]` table of a `pyproject.toml`, or in the user configuration.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn config_set_pyproject_toml() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        resolution = "lowest-direct"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.config().arg("set").arg("link-mode").arg("copy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `link-mode` in: pyproject.toml
+    "###
+    );
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.child("pyproject.toml"))?;
+    assert_snapshot!(pyproject_toml, @r###"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+
+    [tool.uv]
+    resolution = "lowest-direct"
+    link-mode = "copy"
+    "###
+    );
+
+    // With `--user`, the setting is written to the user configuration instead, where it's
+    // shadowed by the project configuration.
+    let xdg = context.temp_dir.child("xdg");
+    uv_snapshot!(context.filters(), context.config()
+        .arg("set")
+        .arg("resolution")
+        .arg("highest")
+        .arg("--user")
+        .env(EnvVars::XDG_CONFIG_HOME, xdg.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Set `resolution` in: xdg/uv/uv.toml
+    warning: `resolution` is overridden by `file:pyproject.toml`, which takes precedence
+    "###
+    );
+
+    let uv_toml = fs_err::read_to_string(xdg.child("uv").child("uv.toml"))?;
+    assert_snapshot!(uv_toml, @r###"
+    resolution = "highest"
+    "###
+    );
+
+    Ok(())
+}
@@ -0,0 +1,278 @@
+use std::io::Write;
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::UnixStream;
+use std::process::{Child, Stdio};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use predicates::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Start a daemon for the given context, waiting until it's accepting connections.
+fn start_daemon(context: &TestContext) -> Result<Child> {
+    let child = context
+        .command()
+        .arg("daemon")
+        .arg("start")
+        .arg("--preview")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    let socket = context.cache_dir.child("daemon").child("daemon.sock");
+    let start = Instant::now();
+    while !socket.exists() {
+        anyhow::ensure!(
+            start.elapsed() < Duration::from_secs(10),
+            "The daemon failed to start"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    Ok(child)
+}
+
+#[test]
+fn daemon_start_stop() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"pid \d+", "pid [PID]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.command().arg("daemon").arg("status"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No daemon is running
+    "###);
+
+    let mut daemon = start_daemon(&context)?;
+
+    // The socket should only be accessible to the current user.
+    let directory = std::fs::metadata(context.cache_dir.child("daemon"))?;
+    assert_eq!(directory.permissions().mode() & 0o777, 0o700);
+
+    uv_snapshot!(filters, context.command().arg("daemon").arg("status"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Daemon running (pid [PID]) at: [CACHE_DIR]/daemon/daemon.sock
+
+    ----- stderr -----
+    "###);
+
+    // A second daemon can't be started on the same cache.
+    uv_snapshot!(filters, context.command().arg("daemon").arg("start").arg("--preview"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: A daemon is already running at: `[CACHE_DIR]/daemon/daemon.sock`
+    "###);
+
+    uv_snapshot!(filters, context.command().arg("daemon").arg("stop"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stopped daemon
+    "###);
+
+    assert!(daemon.wait()?.success());
+    context
+        .cache_dir
+        .child("daemon")
+        .child("daemon.sock")
+        .assert(predicate::path::missing());
+
+    uv_snapshot!(filters, context.command().arg("daemon").arg("stop"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No daemon is running
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn daemon_delegate() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    let mut daemon = start_daemon(&context)?;
+
+    // Resolution commands are delegated to the daemon.
+    context
+        .lock()
+        .arg("-v")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Delegating to daemon at:"));
+
+    // The delegated invocation writes to the client's standard streams, and the lockfile is
+    // written to the client's working directory.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicate::path::exists());
+
+    // The exit code of the delegated invocation is propagated.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "sniffio"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    "###);
+
+    // Commands that modify the environment or the cache run in the current process.
+    context
+        .sync()
+        .arg("-v")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Delegating to daemon at:").not());
+    context
+        .prune()
+        .arg("-v")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Delegating to daemon at:").not());
+
+    // Pruning the cache retains the daemon's socket.
+    context
+        .cache_dir
+        .child("daemon")
+        .child("daemon.sock")
+        .assert(predicate::path::exists());
+
+    // As do invocations that opt out of the daemon.
+    context
+        .lock()
+        .arg("-v")
+        .env(EnvVars::UV_NO_DAEMON, "1")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Delegating to daemon at:").not());
+
+    context
+        .command()
+        .arg("daemon")
+        .arg("stop")
+        .assert()
+        .success();
+    assert!(daemon.wait()?.success());
+
+    // Once the daemon is stopped, invocations run in the current process.
+    context
+        .lock()
+        .arg("-v")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Delegating to daemon at:").not());
+
+    Ok(())
+}
+
+#[test]
+fn daemon_misbehaving_clients() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"pid \d+", "pid [PID]")])
+        .collect::<Vec<_>>();
+
+    let mut daemon = start_daemon(&context)?;
+    let socket = context.cache_dir.child("daemon").child("daemon.sock");
+
+    // A client that connects, but never sends its request, shouldn't block any others.
+    let stalled = UnixStream::connect(&socket)?;
+
+    // Nor should clients that send an oversized frame, a malformed request, or disconnect early.
+    let mut oversized = UnixStream::connect(&socket)?;
+    oversized.write_all(&u32::MAX.to_be_bytes())?;
+    let mut malformed = UnixStream::connect(&socket)?;
+    malformed.write_all(&7u32.to_be_bytes())?;
+    malformed.write_all(b"{\"type\"")?;
+    let mut truncated = UnixStream::connect(&socket)?;
+    truncated.write_all(&64u32.to_be_bytes())?;
+    drop(truncated);
+    let mut unknown = UnixStream::connect(&socket)?;
+    unknown.write_all(&17u32.to_be_bytes())?;
+    unknown.write_all(b"{\"type\":\"bogus\"}")?;
+
+    uv_snapshot!(filters, context.command().arg("daemon").arg("status"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Daemon running (pid [PID]) at: [CACHE_DIR]/daemon/daemon.sock
+
+    ----- stderr -----
+    "###);
+
+    drop((stalled, oversized, malformed, unknown));
+
+    uv_snapshot!(filters, context.command().arg("daemon").arg("status"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Daemon running (pid [PID]) at: [CACHE_DIR]/daemon/daemon.sock
+
+    ----- stderr -----
+    "###);
+
+    context
+        .command()
+        .arg("daemon")
+        .arg("stop")
+        .assert()
+        .success();
+    assert!(daemon.wait()?.success());
+
+    Ok(())
+}
@@ -0,0 +1,240 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+use sha2::{Digest, Sha256};
+
+use crate::common::{make_wheel, uv_snapshot, TestContext};
+
+/// Filter the total size of the downloaded files, which varies with the generated wheels.
+fn download_filters(context: &TestContext) -> Vec<(&str, &str)> {
+    context
+        .filters()
+        .into_iter()
+        .chain([(r"\(\d+\.\d[KMG]?i?B\)", "([SIZE])")])
+        .collect()
+}
+
+/// Return the names of the files in the given directory, sorted.
+fn list(dir: &std::path::Path) -> Result<Vec<String>> {
+    let mut files = fs_err::read_dir(dir)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<Result<Vec<_>>>()?;
+    files.sort();
+    Ok(files)
+}
+
+/// Download the distributions in the project's lockfile.
+#[test]
+fn download_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    // A lockfile is required.
+    uv_snapshot!(context.filters(), context.command().arg("download"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    "###);
+
+    context.lock().assert().success();
+
+    // The project itself has no archive, and so is skipped.
+    uv_snapshot!(download_filters(&context), context.command().arg("download"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Downloading 1 file to wheelhouse
+    Downloaded 1 file ([SIZE]) for 1 target in [TIME]
+    Skipped 1 package without a distribution archive: project
+    "###);
+
+    let wheelhouse = context.temp_dir.child("wheelhouse");
+    assert_eq!(
+        list(&wheelhouse)?,
+        vec!["iniconfig-2.0.0-py3-none-any.whl", "manifest.json"]
+    );
+
+    // The manifest records the source and hashes of each file.
+    assert_snapshot!(context.read("wheelhouse/manifest.json"), @r###"
+    {
+      "files": [
+        {
+          "filename": "iniconfig-2.0.0-py3-none-any.whl",
+          "name": "iniconfig",
+          "version": "2.0.0",
+          "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+          "size": 5892,
+          "hashes": [
+            "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+          ]
+        }
+      ]
+    }
+    "###);
+
+    Ok(())
+}
+
+/// A file whose hash doesn't match the lockfile is rejected, and not written to the output
+/// directory.
+#[test]
+fn download_hash_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Corrupt the locked hash of the wheel.
+    let lock = context.read("uv.lock").replace(
+        "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+        "sha256:0000000000000000000000000000000000000000000000000000000000000000",
+    );
+    context.temp_dir.child("uv.lock").write_str(&lock)?;
+
+    uv_snapshot!(download_filters(&context), context.command().arg("download"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Downloading 1 file to wheelhouse
+    error: Hash mismatch for `iniconfig-2.0.0-py3-none-any.whl`
+
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+    Computed:
+      sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "###);
+
+    // Neither the file nor a manifest is written.
+    assert!(list(&context.temp_dir.child("wheelhouse"))?.is_empty());
+
+    Ok(())
+}
+
+/// Download the distributions required by a set of requirements, resolved against a local
+/// `--find-links` directory.
+#[test]
+fn download_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.temp_dir.child("links");
+    let wheel = make_wheel(&links, "foo", "1.0.0")?;
+    make_wheel(&links, "foo", "2.0.0")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("foo<2")?;
+
+    uv_snapshot!(download_filters(&context), context.command()
+        .arg("download")
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--out-dir")
+        .arg("out")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--no-index"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloading 1 file to out
+    Downloaded 1 file ([SIZE]) for 1 target in [TIME]
+    "###);
+
+    let out = context.temp_dir.child("out");
+    assert_eq!(
+        list(&out)?,
+        vec!["foo-1.0.0-py3-none-any.whl", "manifest.json"]
+    );
+
+    // The file is copied as-is, and its SHA-256 hash and size are recorded in the manifest.
+    let contents = fs_err::read(&wheel)?;
+    assert_eq!(
+        fs_err::read(out.child("foo-1.0.0-py3-none-any.whl"))?,
+        contents
+    );
+
+    let manifest: serde_json::Value = serde_json::from_str(&context.read("out/manifest.json"))?;
+    let entry = &manifest["files"][0];
+    assert_eq!(entry["filename"], "foo-1.0.0-py3-none-any.whl");
+    assert_eq!(entry["name"], "foo");
+    assert_eq!(entry["version"], "1.0.0");
+    assert_eq!(entry["size"], contents.len());
+    assert_eq!(
+        entry["hashes"][0],
+        format!("sha256:{:x}", Sha256::digest(&contents))
+    );
+    assert!(entry["url"]
+        .as_str()
+        .is_some_and(|url| url.starts_with("file://")));
+
+    Ok(())
+}
+
+/// Download the distributions for multiple target platforms, with a platform-specific wheel for
+/// each.
+#[test]
+fn download_multiple_platforms() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(download_filters(&context), context.command()
+        .arg("download")
+        .arg("markupsafe==2.1.5")
+        .arg("--python-version")
+        .arg("3.12")
+        .arg("--python-platform")
+        .arg("x86_64-manylinux_2_17")
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Resolved 1 package in [TIME]
+    Downloading 2 files to wheelhouse
+    Downloaded 2 files ([SIZE]) for 2 targets in [TIME]
+    "###);
+
+    assert_eq!(
+        list(&context.temp_dir.child("wheelhouse"))?,
+        vec![
+            "MarkupSafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl",
+            "MarkupSafe-2.1.5-cp312-cp312-win_amd64.whl",
+            "manifest.json",
+        ]
+    );
+
+    Ok(())
+}
@@ -865,7 +865,7 @@ fn add_raw_error() -> Result<()> {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used with '--raw-sources'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     "###);
@@ -3806,7 +3806,7 @@ fn add_reject_multiple_git_ref_flags() {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used with '--branch <BRANCH>'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     "###
@@ -3827,7 +3827,7 @@ fn add_reject_multiple_git_ref_flags() {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used with '--rev <REV>'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     "###
@@ -3848,7 +3848,7 @@ fn add_reject_multiple_git_ref_flags() {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used multiple times
 
-    Usage: uv add [OPTIONS] <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add [OPTIONS] <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     "###
@@ -4353,6 +4353,200 @@ fn add_lower_bound_local() -> Result<()> {
     Ok(())
 }
 
+/// Cap newly-added dependencies at the next major version with `--bound major`.
+#[test]
+fn add_bound_major() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("anyio").arg("--bound").arg("major"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio>=4.3.0, <5",
+        ]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Respect the `add-bound` setting in the project configuration.
+#[test]
+fn add_bound_setting() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.uv]
+        add-bound = "exact"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // The `--bound` flag takes precedence over the setting.
+    uv_snapshot!(context.filters(), context.add().arg("sniffio").arg("--bound").arg("minor"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig==2.0.0",
+            "sniffio>=1.3.1, <1.4",
+        ]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.uv]
+        add-bound = "exact"
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Rewrite the bounds of existing dependencies that are upgraded.
+#[test]
+fn add_bound_upgrade() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("anyio").arg("--bound").arg("major").arg("--upgrade-package").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio>=3.7.0, <4",
+        ]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "###
+        );
+    });
+
+    Ok(())
+}
+
 /// Add dependencies to a (legacy) non-project workspace root.
 #[test]
 fn add_non_project() -> Result<()> {
@@ -4627,9 +4821,103 @@ fn add_requirements_file() -> Result<()> {
 
     ----- stderr -----
     error: the following required arguments were not provided:
-      <PACKAGES|--requirements <REQUIREMENTS>>
+      <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
+
+    Usage: uv add --cache-dir [CACHE_DIR] --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
+
+/// Add the third-party packages imported by the project's sources.
+#[test]
+fn add_from_imports() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    // Standard library, local, and already-declared imports are ignored, and imports from a
+    // shared namespace package can't be mapped to a distribution.
+    let package = context.temp_dir.child("src").child("project");
+    package.child("__init__.py").write_str(indoc! {r"
+        import os
+        import google.auth
+        import sniffio
+        from iniconfig import IniConfig
+
+        from . import helpers
+        from project.helpers import helper
+    "})?;
+    package.child("helpers.py").write_str(indoc! {r"
+        from typing import Any
+
+        def helper() -> Any:
+            pass
+    "})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("--from-imports"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Unable to determine the package that provides `google` (imported in `src/project/__init__.py`)
+    Found 1 undeclared third-party import:
+     sniffio (`sniffio`) in `src/project/__init__.py`
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig==2.0.0",
+            "sniffio>=1.3.1",
+        ]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "###
+        );
+    });
 
-    Usage: uv add --cache-dir [CACHE_DIR] --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    // `--from-imports` can't be combined with a script.
+    uv_snapshot!(context.filters(), context.add().arg("--from-imports").arg("--script").arg("script.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--from-imports' cannot be used with '--script <SCRIPT>'
+
+    Usage: uv add --cache-dir [CACHE_DIR] --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     "###);
@@ -5328,6 +5616,237 @@ fn remove_repeated() -> Result<()> {
     Ok(())
 }
 
+/// Remove the dependencies that aren't imported by the project's sources.
+#[test]
+fn remove_unused() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0", "typing-extensions"]
+
+        [tool.uv]
+        ignore-unused-dependencies = ["typing-extensions"]
+    "#})?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("import anyio\n")?;
+
+    // Without an interactive terminal, the unused dependencies are only reported.
+    uv_snapshot!(context.filters(), context.remove().arg("--unused"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Found 1 dependency that is not imported:
+     iniconfig
+    hint: Pass `--yes` to remove it
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0", "typing-extensions"]
+
+        [tool.uv]
+        ignore-unused-dependencies = ["typing-extensions"]
+        "###
+        );
+    });
+
+    // With `--yes`, they're removed without confirmation.
+    uv_snapshot!(context.filters(), context.remove().arg("--unused").arg("--yes").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Found 1 dependency that is not imported:
+     iniconfig
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "typing-extensions"]
+
+        [tool.uv]
+        ignore-unused-dependencies = ["typing-extensions"]
+        "###
+        );
+    });
+
+    // Once every dependency is imported (or ignored), there's nothing to remove.
+    uv_snapshot!(context.filters(), context.remove().arg("--unused").arg("--yes").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No unused dependencies found
+    "###);
+
+    Ok(())
+}
+
+/// Remove the development dependencies that aren't imported by the project's sources.
+#[test]
+fn remove_unused_dev() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [dependency-groups]
+        dev = ["anyio==3.7.0", "iniconfig==2.0.0"]
+    "#})?;
+    context
+        .temp_dir
+        .child("tests")
+        .child("test_project.py")
+        .write_str("import iniconfig\n")?;
+
+    // Only the development dependencies are considered: `anyio` isn't imported, but it's only
+    // removed from the `dev` group.
+    uv_snapshot!(context.filters(), context.remove().arg("--unused").arg("--dev").arg("--yes").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Found 1 dependency that is not imported:
+     anyio
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [dependency-groups]
+        dev = ["iniconfig==2.0.0"]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Confirm or decline the proposed removals in an interactive terminal.
+#[test]
+#[cfg(target_os = "linux")]
+fn remove_unused_interactive() -> Result<()> {
+    use predicates::prelude::predicate;
+    use std::process::Command;
+
+    /// Run the command in a pseudo-terminal via `script`, such that uv prompts for confirmation.
+    fn in_terminal(command: &Command) -> assert_cmd::Command {
+        let shell_command = std::iter::once(command.get_program())
+            .chain(command.get_args())
+            .map(|arg| format!("'{}'", arg.to_string_lossy()))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut terminal = Command::new("script");
+        terminal.args([
+            "--quiet",
+            "--return",
+            "--command",
+            &shell_command,
+            "/dev/null",
+        ]);
+        for (key, value) in command.get_envs() {
+            match value {
+                Some(value) => terminal.env(key, value),
+                None => terminal.env_remove(key),
+            };
+        }
+        if let Some(dir) = command.get_current_dir() {
+            terminal.current_dir(dir);
+        }
+        assert_cmd::Command::from_std(terminal)
+    }
+
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0"]
+    "#})?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("import anyio\n")?;
+
+    // Decline the prompt.
+    in_terminal(context.remove().arg("--unused").arg("--frozen"))
+        .write_stdin("n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Remove this dependency from the project?",
+        ));
+    assert!(context
+        .read("pyproject.toml")
+        .contains(r#"dependencies = ["anyio==3.7.0", "iniconfig==2.0.0"]"#));
+
+    // Accept the prompt.
+    in_terminal(context.remove().arg("--unused").arg("--frozen"))
+        .write_stdin("y")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Remove this dependency from the project?",
+        ));
+    assert!(context
+        .read("pyproject.toml")
+        .contains(r#"dependencies = ["anyio==3.7.0"]"#));
+
+    Ok(())
+}
+
 /// Remove from a PEP732 script,
 #[test]
 fn remove_script() -> Result<()> {
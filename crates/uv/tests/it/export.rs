@@ -626,7 +626,7 @@ fn frozen() -> Result<()> {
     ----- stderr -----
       × Failed to build `project @ file://[TEMP_DIR]/`
       ├─▶ Failed to parse entry: `child`
-      ╰─▶ Package is not included as workspace package in `tool.uv.workspace`
+      ╰─▶ `child` is declared as a workspace source (`workspace = true`), but is neither a member of the workspace nor declared in `tool.uv.workspace.dependencies`; to depend on a member of another workspace, use a `path` source instead
     "###);
 
     uv_snapshot!(context.filters(), context.export().arg("--all-packages").arg("--frozen"), @r###"
@@ -896,6 +896,67 @@ fn no_hashes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn annotate() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [dependency-groups]
+        dev = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes").arg("--annotate"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-hashes --annotate
+    -e .
+    anyio==3.7.0
+        # via project
+        # from https://pypi.org/simple
+    idna==3.6
+        # via anyio
+        # from https://pypi.org/simple
+    iniconfig==2.0.0
+        # via project (group: dev)
+        # from https://pypi.org/simple
+    sniffio==1.3.1
+        # via anyio
+        # from https://pypi.org/simple
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    // Annotations are only supported by the `requirements-txt` format.
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("dockerfile").arg("--annotate"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The `--annotate` option is only supported with the `requirements-txt` format
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn output_file() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -1299,3 +1360,884 @@ fn export_group() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn dockerfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "child", "vendored"]
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        vendored = { path = "vendored" }
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(
+        r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [manifest]
+        members = [
+            "child",
+            "project",
+        ]
+
+        [[package]]
+        name = "child"
+        version = "0.1.0"
+        source = { editable = "packages/child" }
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "child" },
+            { name = "iniconfig" },
+            { name = "vendored" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "child", editable = "packages/child" },
+            { name = "iniconfig" },
+            { name = "vendored", directory = "vendored" },
+        ]
+
+        [[package]]
+        name = "vendored"
+        version = "0.1.0"
+        source = { directory = "vendored" }
+        "#,
+    )?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"uv:\d+\.\d+\.\d+(-[a-z0-9.]+)?", "uv:[VERSION]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.export().arg("--frozen").arg("--format").arg("dockerfile").arg("--extra").arg("foo").arg("--no-dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # syntax=docker/dockerfile:1
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --format dockerfile --extra foo --no-dev
+    FROM python:3.12-slim
+
+    # Install uv.
+    COPY --from=ghcr.io/astral-sh/uv:[VERSION] /uv /uvx /bin/
+
+    # Compile bytecode for faster startup, copy from the cache mount rather than linking,
+    # and use the base image's Python interpreter.
+    ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy UV_PYTHON_DOWNLOADS=never
+
+    WORKDIR /app
+
+    # Install the locked third-party dependencies, in a layer that's only invalidated when
+    # the lockfile or a `pyproject.toml` changes.
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        --mount=type=bind,source=uv.lock,target=uv.lock \
+        --mount=type=bind,source=packages/child/pyproject.toml,target=packages/child/pyproject.toml \
+        --mount=type=bind,source=pyproject.toml,target=pyproject.toml \
+        uv sync --frozen --no-install-workspace --no-install-package vendored --extra foo --no-dev
+
+    # Install the project.
+    COPY . /app
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        uv sync --frozen --extra foo --no-dev
+
+    # Place the environment's executables on the `PATH`.
+    ENV PATH="/app/.venv/bin:$PATH"
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn nix() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig", "colorama ; sys_platform == 'win32'", "vendored"]
+
+        [tool.uv.sources]
+        vendored = { path = "vendored" }
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(
+        r#"
+        version = 1
+        requires-python = ">=3.11"
+
+        [[package]]
+        name = "colorama"
+        version = "0.4.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/d1/d6/3965ed04c63042e047cb6a3e6ed1a63a35087b6a609aa3a15ed8ac56c221/colorama-0.4.6-py2.py3-none-any.whl", hash = "sha256:4f1d9991f5acc0ca119f9d443620b77f9d6b33703e51011c16baf57afb285fc6", size = 25335 },
+        ]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+            { name = "vendored" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+            { name = "vendored", directory = "vendored" },
+        ]
+
+        [[package]]
+        name = "vendored"
+        version = "0.1.0"
+        source = { directory = "vendored" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--format").arg("nix"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --format nix
+    {
+      description = "A Python environment exported from uv.lock";
+
+      inputs.nixpkgs.url = "github:NixOS/nixpkgs/nixos-unstable";
+
+      outputs =
+        { nixpkgs, ... }:
+        let
+          # The following local packages are omitted, as they're built from source:
+          #     project
+          #     vendored
+          wheels = {
+            "aarch64-darwin" = [
+              {
+                pname = "iniconfig";
+                version = "2.0.0";
+                url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl";
+                sha256 = "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374";
+              }
+            ];
+            "aarch64-linux" = [
+              {
+                pname = "iniconfig";
+                version = "2.0.0";
+                url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl";
+                sha256 = "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374";
+              }
+            ];
+            "x86_64-darwin" = [
+              {
+                pname = "iniconfig";
+                version = "2.0.0";
+                url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl";
+                sha256 = "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374";
+              }
+            ];
+            "x86_64-linux" = [
+              {
+                pname = "iniconfig";
+                version = "2.0.0";
+                url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl";
+                sha256 = "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374";
+              }
+            ];
+          };
+          environment =
+            system:
+            let
+              pkgs = nixpkgs.legacyPackages.${system};
+              python = pkgs.python311;
+            in
+            python.withPackages (
+              ps:
+              map (
+                wheel:
+                ps.buildPythonPackage {
+                  inherit (wheel) pname version;
+                  format = "wheel";
+                  src = pkgs.fetchurl { inherit (wheel) url sha256; };
+                  dontCheckRuntimeDeps = true;
+                }
+              ) wheels.${system}
+            );
+        in
+        {
+          packages = builtins.mapAttrs (system: _: { default = environment system; }) wheels;
+        };
+    }
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn bazel() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "anyio ; sys_platform == 'linux'", "vendored"]
+
+        [tool.uv.sources]
+        vendored = { path = "vendored" }
+
+        [[tool.uv.index]]
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        explicit = true
+
+        [tool.uv.sources.anyio]
+        index = "test"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(
+        r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "anyio"
+        version = "3.5.0"
+        source = { registry = "https://test.pypi.org/simple" }
+        sdist = { url = "https://test-files.pythonhosted.org/packages/ab/cd/anyio-3.5.0.tar.gz", hash = "sha256:a3e2f7e5ab1d0b3378a6a5b4a1b5ba9b8f7cd5f0ac9a3e6f2d9e1a0f1e2d3c4b", size = 140000 }
+        wheels = [
+            { url = "https://test-files.pythonhosted.org/packages/ef/gh/anyio-3.5.0-py3-none-any.whl", hash = "sha256:dfc5c5dc21d0f4d8a2f6b9e3c1d4a5b6c7d8e9f0a1b2c3d4e5f6a7b8c9d0e1f2", size = 79000 },
+        ]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "anyio", marker = "sys_platform == 'linux'" },
+            { name = "iniconfig" },
+            { name = "vendored" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "anyio", marker = "sys_platform == 'linux'", index = "https://test.pypi.org/simple" },
+            { name = "iniconfig" },
+            { name = "vendored", directory = "vendored" },
+        ]
+
+        [[package]]
+        name = "vendored"
+        version = "0.1.0"
+        source = { directory = "vendored" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--format").arg("bazel"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --format bazel
+    --index-url https://pypi.org/simple
+    --extra-index-url https://test.pypi.org/simple
+
+    anyio==3.5.0 ; sys_platform == 'linux' \
+        --hash=sha256:a3e2f7e5ab1d0b3378a6a5b4a1b5ba9b8f7cd5f0ac9a3e6f2d9e1a0f1e2d3c4b \
+        --hash=sha256:dfc5c5dc21d0f4d8a2f6b9e3c1d4a5b6c7d8e9f0a1b2c3d4e5f6a7b8c9d0e1f2
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    # The following local packages are omitted:
+    #     project
+    #     vendored
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn rpm_spec() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0rc1"
+        description = "A sample project"
+        license = "MIT"
+        authors = [{ name = "Jane Doe", email = "jane@example.com" }]
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "pyyaml", "colorama ; sys_platform == 'win32'"]
+
+        [project.scripts]
+        project = "project:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(
+        r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "colorama"
+        version = "0.4.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697 }
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0rc1"
+        source = { editable = "." }
+        dependencies = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+            { name = "pyyaml" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+            { name = "pyyaml" },
+        ]
+
+        [[package]]
+        name = "pyyaml"
+        version = "6.0.2"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/54/ed/79a089b6be93607fa5cdaedf301d7dfb23af5f25c398d5ead2525b063e17/pyyaml-6.0.2.tar.gz", hash = "sha256:d584d9ec91ad65861cc08d42e834324ef890a082e591037abe114850ff7bbc3e", size = 130631 }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--format").arg("rpm-spec").arg("--distro-package").arg("pyyaml=python3-pyyaml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --format rpm-spec --distro-package pyyaml=python3-pyyaml
+    Name:           project
+    Version:        0.1.0~rc1
+    Release:        1%{?dist}
+    Summary:        A sample project
+    License:        MIT
+    Source0:        project-0.1.0rc1.tar.gz
+    BuildArch:      noarch
+
+    BuildRequires:  python3-devel
+    BuildRequires:  uv
+    BuildRequires:  python3dist(hatchling)
+
+    Requires:       python3dist(iniconfig) >= 2.0.0
+    Requires:       python3-pyyaml >= 6.0.2
+
+    %description
+    A sample project
+
+    %prep
+    %autosetup -n project-0.1.0rc1
+
+    %build
+    uv build --wheel --no-build-isolation --python %{python3} --out-dir dist
+
+    %install
+    uv pip install --no-deps --no-index --python %{python3} --root %{buildroot} --prefix %{_prefix} dist/*.whl
+
+    %files
+    %{python3_sitelib}/*
+    %{_bindir}/project
+
+    ----- stderr -----
+    "###);
+
+    // `--all-packages` is not supported.
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--format").arg("rpm-spec").arg("--all-packages"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The `rpm-spec` and `deb-control` formats describe a single project, and can't be used with `--all-packages`
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn deb_control() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        description = "A sample project"
+        authors = [{ name = "Jane Doe", email = "jane@example.com" }]
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "pyyaml", "colorama ; sys_platform == 'win32'"]
+        "#,
+    )?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(
+        r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "colorama"
+        version = "0.4.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697 }
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+            { name = "pyyaml" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+            { name = "pyyaml" },
+        ]
+
+        [[package]]
+        name = "pyyaml"
+        version = "6.0.2"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/54/ed/79a089b6be93607fa5cdaedf301d7dfb23af5f25c398d5ead2525b063e17/pyyaml-6.0.2.tar.gz", hash = "sha256:d584d9ec91ad65861cc08d42e834324ef890a082e591037abe114850ff7bbc3e", size = 130631 }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--format").arg("deb-control").arg("--distro-package").arg("pyyaml=python3-yaml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --format deb-control --distro-package pyyaml=python3-yaml
+    Source: project
+    Section: python
+    Priority: optional
+    Maintainer: Jane Doe <jane@example.com>
+    Build-Depends:
+     debhelper-compat (= 13),
+     python3,
+     uv,
+     python3-setuptools,
+    Rules-Requires-Root: no
+
+    Package: project
+    Architecture: all
+    Depends:
+     ${misc:Depends},
+     python3,
+     python3-iniconfig (>= 2.0.0),
+     python3-yaml (>= 6.0.2),
+    Description: A sample project
+
+    # The build and install steps, for `debian/rules`:
+    #
+    #     %:
+    #     	dh $@
+    #
+    #     override_dh_auto_build:
+    #     	uv build --wheel --no-build-isolation --python python3 --out-dir dist
+    #
+    #     override_dh_auto_install:
+    #     	uv pip install --no-deps --no-index --python python3 --root $(CURDIR)/debian/project --prefix /usr dist/*.whl
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn requirements_in() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=2", "anyio[trio]>=3 ; sys_platform == 'linux'"]
+
+        [project.optional-dependencies]
+        async = ["sniffio>=1.3"]
+
+        [dependency-groups]
+        dev = ["pytest>=8"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(
+        r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+
+        [package.metadata]
+        requires-dist = [
+            { name = "anyio", extras = ["trio"], marker = "sys_platform == 'linux'", specifier = ">=3" },
+            { name = "iniconfig", specifier = ">=2" },
+            { name = "sniffio", marker = "extra == 'async'", specifier = ">=1.3" },
+        ]
+
+        [package.metadata.requires-dev]
+        dev = [{ name = "pytest", specifier = ">=8" }]
+        "#,
+    )?;
+
+    // The direct requirements are exported with their declared specifiers, including the default
+    // dependency groups.
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--format").arg("requirements-in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --format requirements-in
+    -e .
+    anyio[trio]>=3 ; sys_platform == 'linux'
+    iniconfig>=2
+    pytest>=8
+
+    ----- stderr -----
+    "###);
+
+    // Requirements from enabled extras are included, without the `extra` marker.
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--format").arg("requirements-in").arg("--extra").arg("async").arg("--no-dev").arg("--no-emit-project"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --format requirements-in --extra async --no-dev --no-emit-project
+    anyio[trio]>=3 ; sys_platform == 'linux'
+    iniconfig>=2
+    sniffio>=1.3
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "colorama ; sys_platform == 'win32'"]
+
+        [tool.uv.environments]
+        linux = { sys_platform = "linux", platform_machine = "armv7l" }
+        windows = "sys_platform == 'win32'"
+        "#,
+    )?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(
+        r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "colorama"
+        version = "0.4.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697 }
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+        ]
+        "#,
+    )?;
+
+    // Packages that aren't required on Linux are omitted.
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--no-hashes").arg("--environment").arg("linux"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --no-hashes --environment linux
+    -e .
+    iniconfig==2.0.0
+
+    ----- stderr -----
+    "###);
+
+    // Markers that are always satisfied on Windows are omitted.
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--no-hashes").arg("--environment").arg("windows"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --no-hashes --environment windows
+    -e .
+    colorama==0.4.6
+    iniconfig==2.0.0
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--environment").arg("pyodide"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No environment named `pyodide` is declared in `tool.uv.environments`
+
+    hint: the declared environments are: `linux`, `windows`
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn python_platform() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "colorama ; sys_platform == 'win32'", "typing-extensions ; sys_platform == 'emscripten'"]
+        "#,
+    )?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(
+        r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "colorama"
+        version = "0.4.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697 }
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+            { name = "typing-extensions", marker = "sys_platform == 'emscripten'" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+            { name = "typing-extensions", marker = "sys_platform == 'emscripten'" },
+        ]
+
+        [[package]]
+        name = "typing-extensions"
+        version = "4.10.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/16/3a/0d26ce356c7465a19c9ea8814b960f8a36c3b0d07c323176620b7b483e44/typing_extensions-4.10.0.tar.gz", hash = "sha256:b0abd7c89e8fb96f98db18d86106ff1d90ab692004eb746cf6eda2682f91b3cb", size = 77558 }
+        "#,
+    )?;
+
+    // Packages that aren't required on Pyodide are omitted, as are the markers that are always
+    // satisfied on Pyodide.
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--no-hashes").arg("--python-platform").arg("wasm32-pyodide"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --no-hashes --python-platform wasm32-pyodide
+    -e .
+    iniconfig==2.0.0
+    typing-extensions==4.10.0
+
+    ----- stderr -----
+    "###);
+
+    // The Pyodide package index is included in the output, for use by installers.
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--no-hashes").arg("--python-platform").arg("wasm32-pyodide").arg("--pyodide-index-url").arg("https://example.com/pyodide/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --no-hashes --python-platform wasm32-pyodide --pyodide-index-url https://example.com/pyodide/simple
+    --extra-index-url https://example.com/pyodide/simple
+    -e .
+    iniconfig==2.0.0
+    typing-extensions==4.10.0
+
+    ----- stderr -----
+    "###);
+
+    // The Pyodide package index is ignored for other platforms.
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--no-hashes").arg("--python-platform").arg("windows").arg("--pyodide-index-url").arg("https://example.com/pyodide/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --frozen --no-hashes --python-platform windows --pyodide-index-url https://example.com/pyodide/simple
+    -e .
+    colorama==0.4.6
+    iniconfig==2.0.0
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
@@ -18,26 +18,41 @@ fn help() {
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
+      pin                        Manage pinned packages, which can't be upgraded past a given version
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
       tool                       Run and install commands provided by Python packages
+      script                     Lock, sync, and inspect the dependencies of standalone scripts
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      config                     Inspect and modify uv's configuration
+      daemon                     Run uv as a long-lived daemon with warm caches
+      query                      Query project and environment state in a machine-readable format
       self                       Manage the uv executable
       version                    Display uv's version
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                       Avoid reading from or writing to the cache, instead using a
+                                           temporary directory for the duration of the operation [env:
+                                           UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]          Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <DURATION>  The maximum time to wait for a cache lock held by another uv
+                                           process (e.g., `30s` or `10m`) [env: UV_CACHE_LOCK_TIMEOUT=]
+          --offline-grace <DURATION>       Allow the use of stale index metadata from the cache if the
+                                           network is unavailable, as long as it was fetched or
+                                           revalidated within the given duration (e.g., `30m`, `24h`, or
+                                           `7d`) [env: UV_OFFLINE_GRACE=]
+          --revalidate-package <PACKAGE>   Always revalidate cached index metadata for a specific
+                                           package, regardless of the `Cache-Control` headers returned
+                                           by the index or any `cache-ttl` configured for the index
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -62,6 +77,16 @@ fn help() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress-format <PROGRESS_FORMAT>
+              The format in which to report progress [env: UV_PROGRESS_FORMAT=] [possible values: text,
+              json]
+          --progress-fd <PROGRESS_FD>
+              The file descriptor to which JSON progress events should be written [env: UV_PROGRESS_FD=]
+          --build-report <DIR>
+              Write a report to the given directory when a source distribution fails to build [env:
+              UV_BUILD_REPORT=]
+          --resolution-suggestions <FILE>
+              Write the suggested fixes for a failed resolution to the given file, as JSON
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -99,25 +124,40 @@ fn help_flag() {
       init     Create a new project
       add      Add dependencies to the project
       remove   Remove dependencies from the project
+      pin      Manage pinned packages, which can't be upgraded past a given version
       sync     Update the project's environment
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
       tool     Run and install commands provided by Python packages
+      script   Lock, sync, and inspect the dependencies of standalone scripts
       python   Manage Python versions and installations
       pip      Manage Python packages with a pip-compatible interface
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
       cache    Manage uv's cache
+      config   Inspect and modify uv's configuration
+      daemon   Run uv as a long-lived daemon with warm caches
+      query    Query project and environment state in a machine-readable format
       self     Manage the uv executable
       version  Display uv's version
       help     Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                       Avoid reading from or writing to the cache, instead using a
+                                           temporary directory for the duration of the operation [env:
+                                           UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]          Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <DURATION>  The maximum time to wait for a cache lock held by another uv
+                                           process (e.g., `30s` or `10m`) [env: UV_CACHE_LOCK_TIMEOUT=]
+          --offline-grace <DURATION>       Allow the use of stale index metadata from the cache if the
+                                           network is unavailable, as long as it was fetched or
+                                           revalidated within the given duration (e.g., `30m`, `24h`, or
+                                           `7d`) [env: UV_OFFLINE_GRACE=]
+          --revalidate-package <PACKAGE>   Always revalidate cached index metadata for a specific
+                                           package, regardless of the `Cache-Control` headers returned
+                                           by the index or any `cache-ttl` configured for the index
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -142,6 +182,16 @@ fn help_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress-format <PROGRESS_FORMAT>
+              The format in which to report progress [env: UV_PROGRESS_FORMAT=] [possible values: text,
+              json]
+          --progress-fd <PROGRESS_FD>
+              The file descriptor to which JSON progress events should be written [env: UV_PROGRESS_FD=]
+          --build-report <DIR>
+              Write a report to the given directory when a source distribution fails to build [env:
+              UV_BUILD_REPORT=]
+          --resolution-suggestions <FILE>
+              Write the suggested fixes for a failed resolution to the given file, as JSON
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -178,25 +228,40 @@ fn help_short_flag() {
       init     Create a new project
       add      Add dependencies to the project
       remove   Remove dependencies from the project
+      pin      Manage pinned packages, which can't be upgraded past a given version
       sync     Update the project's environment
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
       tool     Run and install commands provided by Python packages
+      script   Lock, sync, and inspect the dependencies of standalone scripts
       python   Manage Python versions and installations
       pip      Manage Python packages with a pip-compatible interface
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
       cache    Manage uv's cache
+      config   Inspect and modify uv's configuration
+      daemon   Run uv as a long-lived daemon with warm caches
+      query    Query project and environment state in a machine-readable format
       self     Manage the uv executable
       version  Display uv's version
       help     Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                       Avoid reading from or writing to the cache, instead using a
+                                           temporary directory for the duration of the operation [env:
+                                           UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]          Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <DURATION>  The maximum time to wait for a cache lock held by another uv
+                                           process (e.g., `30s` or `10m`) [env: UV_CACHE_LOCK_TIMEOUT=]
+          --offline-grace <DURATION>       Allow the use of stale index metadata from the cache if the
+                                           network is unavailable, as long as it was fetched or
+                                           revalidated within the given duration (e.g., `30m`, `24h`, or
+                                           `7d`) [env: UV_OFFLINE_GRACE=]
+          --revalidate-package <PACKAGE>   Always revalidate cached index metadata for a specific
+                                           package, regardless of the `Cache-Control` headers returned
+                                           by the index or any `cache-ttl` configured for the index
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -221,6 +286,16 @@ fn help_short_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress-format <PROGRESS_FORMAT>
+              The format in which to report progress [env: UV_PROGRESS_FORMAT=] [possible values: text,
+              json]
+          --progress-fd <PROGRESS_FD>
+              The file descriptor to which JSON progress events should be written [env: UV_PROGRESS_FD=]
+          --build-report <DIR>
+              Write a report to the given directory when a source distribution fails to build [env:
+              UV_BUILD_REPORT=]
+          --resolution-suggestions <FILE>
+              Write the suggested fixes for a failed resolution to the given file, as JSON
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -315,6 +390,31 @@ fn help_subcommand() {
               
               [env: UV_CACHE_DIR=]
 
+          --cache-lock-timeout <DURATION>
+              The maximum time to wait for a cache lock held by another uv process (e.g., `30s` or
+              `10m`).
+              
+              By default, uv waits indefinitely for other processes to release their locks, e.g., when
+              multiple processes attempt to build the same source distribution.
+              
+              [env: UV_CACHE_LOCK_TIMEOUT=]
+
+          --offline-grace <DURATION>
+              Allow the use of stale index metadata from the cache if the network is unavailable, as
+              long as it was fetched or revalidated within the given duration (e.g., `30m`, `24h`, or
+              `7d`).
+              
+              By default, uv fails if cached index metadata is stale and the index can't be reached.
+              
+              [env: UV_OFFLINE_GRACE=]
+
+          --revalidate-package <PACKAGE>
+              Always revalidate cached index metadata for a specific package, regardless of the
+              `Cache-Control` headers returned by the index or any `cache-ttl` configured for the index.
+              
+              Unlike `--refresh-package`, cached wheels and source distribution builds for the package
+              are reused.
+
     Python options:
           --python-preference <PYTHON_PREFERENCE>
               Whether to prefer uv-managed or system Python installations.
@@ -396,6 +496,41 @@ fn help_subcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --progress-format <PROGRESS_FORMAT>
+              The format in which to report progress.
+              
+              With `json`, progress bars are replaced by a stream of newline-delimited JSON events
+              (e.g., resolution phases, and per-package download, build, and install progress), intended
+              for editors and other tools that render their own progress UI.
+              
+              [env: UV_PROGRESS_FORMAT=]
+
+              Possible values:
+              - text: Display progress bars and spinners
+              - json: Emit newline-delimited JSON progress events
+
+          --progress-fd <PROGRESS_FD>
+              The file descriptor to which JSON progress events should be written.
+              
+              Defaults to stderr. Only supported on Unix, and only used with `--progress-format json`.
+              
+              [env: UV_PROGRESS_FD=]
+
+          --build-report <DIR>
+              Write a report to the given directory when a source distribution fails to build.
+              
+              The report is a JSON file that includes the output of the build backend, the resolved
+              requirements of the build environment, and the chain of errors that led to the failure.
+              
+              [env: UV_BUILD_REPORT=]
+
+          --resolution-suggestions <FILE>
+              Write the suggested fixes for a failed resolution to the given file, as JSON.
+              
+              When resolution fails due to a version conflict, uv suggests actions that may resolve the
+              conflict (e.g., relaxing a requirement, or adding an override). The same suggestions are
+              written to the file, for consumption by other tools.
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -420,6 +555,9 @@ fn help_subcommand() {
           --config-file <CONFIG_FILE>
               The path to a `uv.toml` file to use for configuration.
               
+              May be provided multiple times, in which case the files are layered, with later files
+              taking precedence over earlier ones.
+              
               While uv configuration can be included in a `pyproject.toml` file, it is not allowed in
               this context.
               
@@ -533,6 +671,31 @@ fn help_subsubcommand() {
               
               [env: UV_CACHE_DIR=]
 
+          --cache-lock-timeout <DURATION>
+              The maximum time to wait for a cache lock held by another uv process (e.g., `30s` or
+              `10m`).
+              
+              By default, uv waits indefinitely for other processes to release their locks, e.g., when
+              multiple processes attempt to build the same source distribution.
+              
+              [env: UV_CACHE_LOCK_TIMEOUT=]
+
+          --offline-grace <DURATION>
+              Allow the use of stale index metadata from the cache if the network is unavailable, as
+              long as it was fetched or revalidated within the given duration (e.g., `30m`, `24h`, or
+              `7d`).
+              
+              By default, uv fails if cached index metadata is stale and the index can't be reached.
+              
+              [env: UV_OFFLINE_GRACE=]
+
+          --revalidate-package <PACKAGE>
+              Always revalidate cached index metadata for a specific package, regardless of the
+              `Cache-Control` headers returned by the index or any `cache-ttl` configured for the index.
+              
+              Unlike `--refresh-package`, cached wheels and source distribution builds for the package
+              are reused.
+
     Python options:
           --python-preference <PYTHON_PREFERENCE>
               Whether to prefer uv-managed or system Python installations.
@@ -614,6 +777,41 @@ fn help_subsubcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --progress-format <PROGRESS_FORMAT>
+              The format in which to report progress.
+              
+              With `json`, progress bars are replaced by a stream of newline-delimited JSON events
+              (e.g., resolution phases, and per-package download, build, and install progress), intended
+              for editors and other tools that render their own progress UI.
+              
+              [env: UV_PROGRESS_FORMAT=]
+
+              Possible values:
+              - text: Display progress bars and spinners
+              - json: Emit newline-delimited JSON progress events
+
+          --progress-fd <PROGRESS_FD>
+              The file descriptor to which JSON progress events should be written.
+              
+              Defaults to stderr. Only supported on Unix, and only used with `--progress-format json`.
+              
+              [env: UV_PROGRESS_FD=]
+
+          --build-report <DIR>
+              Write a report to the given directory when a source distribution fails to build.
+              
+              The report is a JSON file that includes the output of the build backend, the resolved
+              requirements of the build environment, and the chain of errors that led to the failure.
+              
+              [env: UV_BUILD_REPORT=]
+
+          --resolution-suggestions <FILE>
+              Write the suggested fixes for a failed resolution to the given file, as JSON.
+              
+              When resolution fails due to a version conflict, uv suggests actions that may resolve the
+              conflict (e.g., relaxing a requirement, or adding an override). The same suggestions are
+              written to the file, for consumption by other tools.
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -638,6 +836,9 @@ fn help_subsubcommand() {
           --config-file <CONFIG_FILE>
               The path to a `uv.toml` file to use for configuration.
               
+              May be provided multiple times, in which case the files are layered, with later files
+              taking precedence over earlier ones.
+              
               While uv configuration can be included in a `pyproject.toml` file, it is not allowed in
               this context.
               
@@ -683,9 +884,19 @@ fn help_flag_subcommand() {
       uninstall  Uninstall Python versions
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                       Avoid reading from or writing to the cache, instead using a
+                                           temporary directory for the duration of the operation [env:
+                                           UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]          Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <DURATION>  The maximum time to wait for a cache lock held by another uv
+                                           process (e.g., `30s` or `10m`) [env: UV_CACHE_LOCK_TIMEOUT=]
+          --offline-grace <DURATION>       Allow the use of stale index metadata from the cache if the
+                                           network is unavailable, as long as it was fetched or
+                                           revalidated within the given duration (e.g., `30m`, `24h`, or
+                                           `7d`) [env: UV_OFFLINE_GRACE=]
+          --revalidate-package <PACKAGE>   Always revalidate cached index metadata for a specific
+                                           package, regardless of the `Cache-Control` headers returned
+                                           by the index or any `cache-ttl` configured for the index
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -710,6 +921,16 @@ fn help_flag_subcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress-format <PROGRESS_FORMAT>
+              The format in which to report progress [env: UV_PROGRESS_FORMAT=] [possible values: text,
+              json]
+          --progress-fd <PROGRESS_FD>
+              The file descriptor to which JSON progress events should be written [env: UV_PROGRESS_FD=]
+          --build-report <DIR>
+              Write a report to the given directory when a source distribution fails to build [env:
+              UV_BUILD_REPORT=]
+          --resolution-suggestions <FILE>
+              Write the suggested fixes for a failed resolution to the given file, as JSON
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -753,9 +974,19 @@ fn help_flag_subsubcommand() {
       -f, --force                      Replace existing Python executables during installation
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                       Avoid reading from or writing to the cache, instead using a
+                                           temporary directory for the duration of the operation [env:
+                                           UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]          Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <DURATION>  The maximum time to wait for a cache lock held by another uv
+                                           process (e.g., `30s` or `10m`) [env: UV_CACHE_LOCK_TIMEOUT=]
+          --offline-grace <DURATION>       Allow the use of stale index metadata from the cache if the
+                                           network is unavailable, as long as it was fetched or
+                                           revalidated within the given duration (e.g., `30m`, `24h`, or
+                                           `7d`) [env: UV_OFFLINE_GRACE=]
+          --revalidate-package <PACKAGE>   Always revalidate cached index metadata for a specific
+                                           package, regardless of the `Cache-Control` headers returned
+                                           by the index or any `cache-ttl` configured for the index
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -780,6 +1011,16 @@ fn help_flag_subsubcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress-format <PROGRESS_FORMAT>
+              The format in which to report progress [env: UV_PROGRESS_FORMAT=] [possible values: text,
+              json]
+          --progress-fd <PROGRESS_FD>
+              The file descriptor to which JSON progress events should be written [env: UV_PROGRESS_FD=]
+          --build-report <DIR>
+              Write a report to the given directory when a source distribution fails to build [env:
+              UV_BUILD_REPORT=]
+          --resolution-suggestions <FILE>
+              Write the suggested fixes for a failed resolution to the given file, as JSON
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -823,6 +1064,8 @@ fn help_unknown_subcommand() {
         build
         publish
         cache
+        daemon
+        query
         self
         version
         generate-shell-completion
@@ -850,6 +1093,8 @@ fn help_unknown_subcommand() {
         build
         publish
         cache
+        daemon
+        query
         self
         version
         generate-shell-completion
@@ -893,26 +1138,41 @@ fn help_with_global_option() {
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
+      pin                        Manage pinned packages, which can't be upgraded past a given version
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
       tool                       Run and install commands provided by Python packages
+      script                     Lock, sync, and inspect the dependencies of standalone scripts
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      config                     Inspect and modify uv's configuration
+      daemon                     Run uv as a long-lived daemon with warm caches
+      query                      Query project and environment state in a machine-readable format
       self                       Manage the uv executable
       version                    Display uv's version
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                       Avoid reading from or writing to the cache, instead using a
+                                           temporary directory for the duration of the operation [env:
+                                           UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]          Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <DURATION>  The maximum time to wait for a cache lock held by another uv
+                                           process (e.g., `30s` or `10m`) [env: UV_CACHE_LOCK_TIMEOUT=]
+          --offline-grace <DURATION>       Allow the use of stale index metadata from the cache if the
+                                           network is unavailable, as long as it was fetched or
+                                           revalidated within the given duration (e.g., `30m`, `24h`, or
+                                           `7d`) [env: UV_OFFLINE_GRACE=]
+          --revalidate-package <PACKAGE>   Always revalidate cached index metadata for a specific
+                                           package, regardless of the `Cache-Control` headers returned
+                                           by the index or any `cache-ttl` configured for the index
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -937,6 +1197,16 @@ fn help_with_global_option() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress-format <PROGRESS_FORMAT>
+              The format in which to report progress [env: UV_PROGRESS_FORMAT=] [possible values: text,
+              json]
+          --progress-fd <PROGRESS_FD>
+              The file descriptor to which JSON progress events should be written [env: UV_PROGRESS_FD=]
+          --build-report <DIR>
+              Write a report to the given directory when a source distribution fails to build [env:
+              UV_BUILD_REPORT=]
+          --resolution-suggestions <FILE>
+              Write the suggested fixes for a failed resolution to the given file, as JSON
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -1009,26 +1279,41 @@ fn help_with_no_pager() {
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
+      pin                        Manage pinned packages, which can't be upgraded past a given version
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
       tool                       Run and install commands provided by Python packages
+      script                     Lock, sync, and inspect the dependencies of standalone scripts
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      config                     Inspect and modify uv's configuration
+      daemon                     Run uv as a long-lived daemon with warm caches
+      query                      Query project and environment state in a machine-readable format
       self                       Manage the uv executable
       version                    Display uv's version
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                       Avoid reading from or writing to the cache, instead using a
+                                           temporary directory for the duration of the operation [env:
+                                           UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]          Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-lock-timeout <DURATION>  The maximum time to wait for a cache lock held by another uv
+                                           process (e.g., `30s` or `10m`) [env: UV_CACHE_LOCK_TIMEOUT=]
+          --offline-grace <DURATION>       Allow the use of stale index metadata from the cache if the
+                                           network is unavailable, as long as it was fetched or
+                                           revalidated within the given duration (e.g., `30m`, `24h`, or
+                                           `7d`) [env: UV_OFFLINE_GRACE=]
+          --revalidate-package <PACKAGE>   Always revalidate cached index metadata for a specific
+                                           package, regardless of the `Cache-Control` headers returned
+                                           by the index or any `cache-ttl` configured for the index
 
     Python options:
           --python-preference <PYTHON_PREFERENCE>
@@ -1053,6 +1338,16 @@ fn help_with_no_pager() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress-format <PROGRESS_FORMAT>
+              The format in which to report progress [env: UV_PROGRESS_FORMAT=] [possible values: text,
+              json]
+          --progress-fd <PROGRESS_FD>
+              The file descriptor to which JSON progress events should be written [env: UV_PROGRESS_FD=]
+          --build-report <DIR>
+              Write a report to the given directory when a source distribution fails to build [env:
+              UV_BUILD_REPORT=]
+          --resolution-suggestions <FILE>
+              Write the suggested fixes for a failed resolution to the given file, as JSON
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
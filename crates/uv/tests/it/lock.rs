@@ -7,8 +7,8 @@ use std::io::BufReader;
 use url::Url;
 
 use crate::common::{
-    self, build_vendor_links_url, decode_token, download_to_disk, packse_index_url, uv_snapshot,
-    venv_bin_path, TestContext,
+    self, build_vendor_links_url, decode_token, download_to_disk, make_wheel,
+    make_wheel_with_dependencies, packse_index_url, uv_snapshot, venv_bin_path, TestContext,
 };
 use uv_fs::Simplified;
 use uv_static::EnvVars;
@@ -505,6 +505,263 @@ fn lock_sdist_git() -> Result<()> {
     Ok(())
 }
 
+/// Lock a Mercurial requirement using `tool.uv.sources`.
+#[test]
+#[cfg(all(feature = "hg", feature = "pypi"))]
+fn lock_sdist_hg() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a Mercurial repository containing a package.
+    let repository = context.temp_dir.child("hg-repo");
+    repository.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "hg-dep"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    repository
+        .child("src")
+        .child("hg_dep")
+        .child("__init__.py")
+        .touch()?;
+    std::process::Command::new("hg")
+        .arg("init")
+        .current_dir(&repository)
+        .assert()
+        .success();
+    std::process::Command::new("hg")
+        .args(["commit", "--addremove", "--message", "Initial commit"])
+        .args(["--user", "uv", "--date", "0 0"])
+        .current_dir(&repository)
+        .assert()
+        .success();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["hg-dep"]
+
+        [tool.uv.sources]
+        hg-dep = {{ hg = "{}" }}
+        "#,
+        Url::from_file_path(&repository).unwrap(),
+    })?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"[0-9a-f]{40}", "[CHANGESET]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => filters.clone(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "hg-dep"
+        version = "0.1.0"
+        source = { hg = "file://[TEMP_DIR]/hg-repo#[CHANGESET]" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "hg-dep" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "hg-dep", hg = "file://[TEMP_DIR]/hg-repo" }]
+        "###
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(filters, context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Install from the lockfile.
+    uv_snapshot!(filters, context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + hg-dep==0.1.0 (from hg+file://[TEMP_DIR]/hg-repo@[CHANGESET])
+    "###);
+
+    // Re-run the sync; the installed package should be recognized as up-to-date.
+    uv_snapshot!(filters, context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Lock a Subversion requirement using PEP 508.
+#[test]
+#[cfg(all(feature = "svn", feature = "pypi"))]
+fn lock_sdist_svn() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a Subversion repository containing a package on its trunk.
+    let repository = context.temp_dir.child("svn-repo");
+    std::process::Command::new("svnadmin")
+        .arg("create")
+        .arg(repository.path())
+        .assert()
+        .success();
+
+    let source = context.temp_dir.child("svn-src");
+    source.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "svn-dep"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    source
+        .child("src")
+        .child("svn_dep")
+        .child("__init__.py")
+        .touch()?;
+
+    let trunk = format!("{}/trunk", Url::from_file_path(&repository).unwrap());
+    std::process::Command::new("svn")
+        .arg("import")
+        .arg(source.path())
+        .arg(&trunk)
+        .args(["--message", "Initial commit", "--non-interactive"])
+        .assert()
+        .success();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["svn-dep @ svn+{trunk}"]
+        "#,
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "svn-dep" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "svn-dep", svn = "file://[TEMP_DIR]/svn-repo/trunk" }]
+
+        [[package]]
+        name = "svn-dep"
+        version = "0.1.0"
+        source = { svn = "file://[TEMP_DIR]/svn-repo/trunk#1" }
+        "###
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Install from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + svn-dep==0.1.0 (from svn+file://[TEMP_DIR]/svn-repo/trunk@1)
+    "###);
+
+    // Re-run the sync; the installed package should be recognized as up-to-date.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
 /// Lock a Git requirement using PEP 508.
 #[test]
 #[cfg(feature = "git")]
@@ -2207,6 +2464,12 @@ fn lock_conflicting_extra_basic() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[project2] depends on sortedcontainers==2.4.0 and project[project1] depends on sortedcontainers==2.3.0, we can conclude that project[project1] and project[project2] are incompatible.
           And because your project requires project[project1] and project[project2], we can conclude that your projects's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Declare `project[project1]` and `project[project2]` as conflicting (via `tool.uv.conflicting-groups`)
+            2. Relax the requirement `sortedcontainers==2.4.0` in `project[project2]`
+            3. Relax the requirement `sortedcontainers==2.3.0` in `project[project1]`
+            4. Add an override for `sortedcontainers==2.3.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     // And now with the same extra configuration, we tell uv about
@@ -2406,6 +2669,12 @@ fn lock_conflicting_extra_basic_three_extras() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[project3] depends on sortedcontainers==2.4.0 and project[project1] depends on sortedcontainers==2.2.0, we can conclude that project[project1] and project[project3] are incompatible.
           And because your project requires project[project1] and project[project3], we can conclude that your projects's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Declare `project[project1]` and `project[project3]` as conflicting (via `tool.uv.conflicting-groups`)
+            2. Relax the requirement `sortedcontainers==2.4.0` in `project[project3]`
+            3. Relax the requirement `sortedcontainers==2.2.0` in `project[project1]`
+            4. Add an override for `sortedcontainers==2.2.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     // And now with the same extra configuration, we tell uv about
@@ -2705,6 +2974,12 @@ fn lock_conflicting_extra_multiple_not_conflicting2() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[project4] depends on sortedcontainers==2.4.0 and project[project1] depends on sortedcontainers==2.3.0, we can conclude that project[project1] and project[project4] are incompatible.
           And because your project requires project[project1] and project[project4], we can conclude that your projects's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Declare `project[project1]` and `project[project4]` as conflicting (via `tool.uv.conflicting-groups`)
+            2. Relax the requirement `sortedcontainers==2.4.0` in `project[project4]`
+            3. Relax the requirement `sortedcontainers==2.3.0` in `project[project1]`
+            4. Add an override for `sortedcontainers==2.3.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     // If we define project1/project2 as conflicting and project3/project4
@@ -2750,6 +3025,12 @@ fn lock_conflicting_extra_multiple_not_conflicting2() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[project3] depends on sortedcontainers==2.3.0 and project[project2] depends on sortedcontainers==2.4.0, we can conclude that project[project2] and project[project3] are incompatible.
           And because your project requires project[project2] and project[project3], we can conclude that your projects's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Declare `project[project2]` and `project[project3]` as conflicting (via `tool.uv.conflicting-groups`)
+            2. Relax the requirement `sortedcontainers==2.3.0` in `project[project3]`
+            3. Relax the requirement `sortedcontainers==2.4.0` in `project[project2]`
+            4. Add an override for `sortedcontainers==2.4.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     // One could try to declare all pairs of conflicting extras as
@@ -2886,6 +3167,12 @@ fn lock_conflicting_extra_multiple_independent() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[project4] depends on anyio==4.2.0 and project[project3] depends on anyio==4.1.0, we can conclude that project[project3] and project[project4] are incompatible.
           And because your project requires project[project3] and project[project4], we can conclude that your projects's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Declare `project[project3]` and `project[project4]` as conflicting (via `tool.uv.conflicting-groups`)
+            2. Relax the requirement `anyio==4.2.0` in `project[project4]`
+            3. Relax the requirement `anyio==4.1.0` in `project[project3]`
+            4. Add an override for `anyio==4.1.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     // OK, responding to the error, we declare our anyio extras
@@ -2927,6 +3214,12 @@ fn lock_conflicting_extra_multiple_independent() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[project2] depends on sortedcontainers==2.4.0 and project[project1] depends on sortedcontainers==2.3.0, we can conclude that project[project1] and project[project2] are incompatible.
           And because your project requires project[project1] and project[project2], we can conclude that your projects's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Declare `project[project1]` and `project[project2]` as conflicting (via `tool.uv.conflicting-groups`)
+            2. Relax the requirement `sortedcontainers==2.4.0` in `project[project2]`
+            3. Relax the requirement `sortedcontainers==2.3.0` in `project[project1]`
+            4. Add an override for `sortedcontainers==2.3.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     // Once we declare ALL our conflicting extras, resolution succeeds.
@@ -3238,6 +3531,12 @@ fn lock_conflicting_extra_config_change_ignore_lockfile() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[project2] depends on sortedcontainers==2.4.0 and project[project1] depends on sortedcontainers==2.3.0, we can conclude that project[project1] and project[project2] are incompatible.
           And because your project requires project[project1] and project[project2], we can conclude that your projects's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Declare `project[project1]` and `project[project2]` as conflicting (via `tool.uv.conflicting-groups`)
+            2. Relax the requirement `sortedcontainers==2.4.0` in `project[project2]`
+            3. Relax the requirement `sortedcontainers==2.3.0` in `project[project1]`
+            4. Add an override for `sortedcontainers==2.3.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     Ok(())
@@ -9711,62 +10010,158 @@ fn lock_sources_url() -> Result<()> {
     Ok(())
 }
 
-/// Lock a local archive requirement that uses `tool.uv.sources` internally (specifically, the root
-/// package `workspace` depends on `anyio`, and declares a source pointing to a local stub of
-/// `anyio`).
-///
-/// When resolving, we should ignore the `tool.uv.sources` and instead pull in `anyio` from PyPI.
+/// Lock a URL source with a hash pinned in `tool.uv.sources`. A mismatched hash should be rejected
+/// when the archive is downloaded, before it's built.
 #[test]
-fn lock_sources_archive() -> Result<()> {
+fn lock_sources_url_hash() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Download the source.
-    let workspace_archive = context.temp_dir.child("workspace.zip");
-    download_to_disk(
-        "https://github.com/user-attachments/files/16592193/workspace.zip",
-        &workspace_archive,
-    );
-
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(&formatdoc! {
+    pyproject_toml.write_str(
         r#"
         [project]
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["workspace @ {}"]
+        dependencies = ["source-distribution"]
 
-        [build-system]
-        requires = ["setuptools>=42"]
-        build-backend = "setuptools.build_meta"
+        [tool.uv.sources]
+        source-distribution = { url = "https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz", hash = "sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a" }
         "#,
-        Url::from_file_path(&workspace_archive).unwrap(),
-    })?;
+    )?;
 
-    uv_snapshot!( context.lock(), @r###"
-    success: true
-    exit_code: 0
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 5 packages in [TIME]
+      × Failed to download and build `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
+      ╰─▶ Hash mismatch for `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
+
+          Expected:
+            sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+
+          Computed:
+            sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106
     "###);
 
-    let lock = context.read("uv.lock");
+    // No lockfile should be written.
+    assert!(!context.temp_dir.child("uv.lock").exists());
 
-    // insta::with_settings!({
-    //     filters => context.filters(),
-    // }, {
-    assert_snapshot!(
-        lock, @r###"
-        version = 1
+    // With the correct hash, locking should succeed.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
         requires-python = ">=3.12"
+        dependencies = ["source-distribution"]
 
-        [options]
-        exclude-newer = "2024-03-25T00:00:00Z"
+        [tool.uv.sources]
+        source-distribution = { url = "https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz", hash = "sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106" }
+        "#,
+    )?;
 
-        [[package]]
-        name = "anyio"
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Re-pinning the source to a different hash should invalidate the existing lockfile, even
+    // though the URL is unchanged.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution"]
+
+        [tool.uv.sources]
+        source-distribution = { url = "https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz", hash = "sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: Hash mismatch for `source-distribution` (from `https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`)
+
+    Expected:
+      sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+
+    Locked:
+      sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106
+    "###);
+
+    Ok(())
+}
+
+/// Lock a local archive requirement that uses `tool.uv.sources` internally (specifically, the root
+/// package `workspace` depends on `anyio`, and declares a source pointing to a local stub of
+/// `anyio`).
+///
+/// When resolving, we should ignore the `tool.uv.sources` and instead pull in `anyio` from PyPI.
+#[test]
+fn lock_sources_archive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Download the source.
+    let workspace_archive = context.temp_dir.child("workspace.zip");
+    download_to_disk(
+        "https://github.com/user-attachments/files/16592193/workspace.zip",
+        &workspace_archive,
+    );
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["workspace @ {}"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+        Url::from_file_path(&workspace_archive).unwrap(),
+    })?;
+
+    uv_snapshot!( context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    // insta::with_settings!({
+    //     filters => context.filters(),
+    // }, {
+    assert_snapshot!(
+        lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "anyio"
         version = "4.3.0"
         source = { registry = "https://pypi.org/simple" }
         dependencies = [
@@ -14249,6 +14644,158 @@ fn lock_trailing_slash() -> Result<()> {
     Ok(())
 }
 
+/// A minimal HTTP forward proxy that serves a single-package index for `pypi.internal.example`,
+/// a host that doesn't resolve, such that the index is only reachable through the proxy.
+struct IndexProxy {
+    /// The URL of the proxy, e.g., `http://127.0.0.1:12345`.
+    url: String,
+    /// The method and target of each request received by the proxy.
+    requests: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl IndexProxy {
+    fn start(html: String, wheel: Vec<u8>) -> Result<Self> {
+        use std::io::{BufRead, Write};
+        use std::sync::{Arc, Mutex};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let html = Arc::new(html);
+        let wheel = Arc::new(wheel);
+
+        std::thread::spawn({
+            let requests = requests.clone();
+            move || {
+                for stream in listener.incoming().flatten() {
+                    let requests = requests.clone();
+                    let html = html.clone();
+                    let wheel = wheel.clone();
+                    std::thread::spawn(move || -> std::io::Result<()> {
+                        let mut reader = BufReader::new(stream.try_clone()?);
+                        let mut stream = stream;
+                        loop {
+                            let mut request = String::new();
+                            if reader.read_line(&mut request)? == 0 {
+                                return Ok(());
+                            }
+                            // Skip the headers; none of the requests have a body.
+                            loop {
+                                let mut header = String::new();
+                                if reader.read_line(&mut header)? == 0 || header == "\r\n" {
+                                    break;
+                                }
+                            }
+
+                            let mut parts = request.split_whitespace();
+                            let method = parts.next().unwrap_or_default().to_string();
+                            let target = parts.next().unwrap_or_default().to_string();
+                            requests.lock().unwrap().push(format!("{method} {target}"));
+
+                            let (status, content_type, body) =
+                                if target == "http://pypi.internal.example/simple/iniconfig/" {
+                                    ("200 OK", "text/html", html.as_bytes())
+                                } else if target.ends_with(".whl") {
+                                    ("200 OK", "application/octet-stream", wheel.as_slice())
+                                } else {
+                                    ("404 Not Found", "text/plain", &b""[..])
+                                };
+                            write!(
+                                stream,
+                                "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\r\n",
+                                body.len()
+                            )?;
+                            if method != "HEAD" {
+                                stream.write_all(body)?;
+                            }
+                            stream.flush()?;
+                        }
+                    });
+                }
+            }
+        });
+
+        Ok(Self { url, requests })
+    }
+
+    /// Return the method and target of each request received by the proxy.
+    fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Lock and sync against an index that's only reachable through the proxy configured for it.
+#[test]
+fn lock_index_proxy() -> Result<()> {
+    use sha2::Digest;
+
+    let context = TestContext::new("3.12");
+
+    let wheels = context.temp_dir.child("wheels");
+    wheels.create_dir_all()?;
+    let wheel = fs_err::read(make_wheel(&wheels, "iniconfig", "2.0.0")?)?;
+    let sha256 = format!("{:x}", sha2::Sha256::digest(&wheel));
+    let html = formatdoc! {r#"
+        <!DOCTYPE html>
+        <html>
+          <body>
+            <h1>Links for iniconfig</h1>
+            <a href="http://pypi.internal.example/files/iniconfig-2.0.0-py3-none-any.whl#sha256={sha256}">iniconfig-2.0.0-py3-none-any.whl</a>
+          </body>
+        </html>
+    "#};
+    let proxy = IndexProxy::start(html, wheel)?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "http://pypi.internal.example/simple"
+        default = true
+        proxy = "{}"
+        "#,
+        proxy.url
+    })?;
+
+    // The index proxy takes precedence over `NO_PROXY`.
+    uv_snapshot!(context.filters(), context.lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(EnvVars::NO_PROXY, "pypi.internal.example"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    assert!(proxy
+        .requests()
+        .contains(&"GET http://pypi.internal.example/simple/iniconfig/".to_string()));
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    assert!(proxy.requests().iter().any(|request| request
+        == "GET http://pypi.internal.example/files/iniconfig-2.0.0-py3-none-any.whl"));
+
+    Ok(())
+}
+
 #[test]
 fn lock_invalid_index() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -14410,8 +14957,10 @@ fn lock_explicit_index() -> Result<()> {
     Ok(())
 }
 
+/// A glob pattern in `tool.uv.sources` should pin every matching package to the index, including
+/// transitive dependencies, and require that the index is explicit.
 #[test]
-fn lock_explicit_default_index() -> Result<()> {
+fn lock_pattern_index() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -14421,20 +14970,45 @@ fn lock_explicit_default_index() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["iniconfig==2.0.0"]
+        dependencies = ["pytest==8.1.1"]
 
-        [build-system]
-        requires = ["setuptools>=42"]
-        build-backend = "setuptools.build_meta"
+        [tool.uv.sources]
+        "ini*" = { index = "test" }
+
+        [[tool.uv.index]]
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        "#,
+    )?;
+
+    // The index must be explicit, such that packages that don't match the pattern can't be
+    // installed from it.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `project @ file://[TEMP_DIR]/`
+      ├─▶ Failed to parse entry: `pytest`
+      ╰─▶ Index `test` is used by the `tool.uv.sources` pattern `ini*`, and so must be marked as `explicit = true`
+    "###);
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["pytest==8.1.1"]
 
         [tool.uv.sources]
-        iniconfig = { index = "test" }
+        "ini*" = { index = "test" }
 
         [[tool.uv.index]]
         name = "test"
         url = "https://test.pypi.org/simple"
         explicit = true
-        default = true
         "#,
     )?;
 
@@ -14444,95 +15018,63 @@ fn lock_explicit_default_index() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    Resolved 5 packages in [TIME]
     "###);
 
-    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
-
-    insta::with_settings!({
-        filters => context.filters(),
-    }, {
-        assert_snapshot!(
-            lock, @r###"
-        version = 1
-        requires-python = ">=3.12"
-
-        [options]
-        exclude-newer = "2024-03-25T00:00:00Z"
-
+    // `iniconfig` is a transitive dependency (via `pytest`), but is still pinned to the index; the
+    // remaining packages aren't.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(indoc! {r#"
         [[package]]
         name = "iniconfig"
         version = "2.0.0"
         source = { registry = "https://test.pypi.org/simple" }
-        sdist = { url = "https://test-files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
-        wheels = [
-            { url = "https://test-files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
-        ]
-
+    "#}));
+    assert!(lock.contains(indoc! {r#"
         [[package]]
-        name = "project"
-        version = "0.1.0"
-        source = { editable = "." }
-        dependencies = [
-            { name = "iniconfig" },
-        ]
+        name = "pytest"
+        version = "8.1.1"
+        source = { registry = "https://pypi.org/simple" }
+    "#}));
 
-        [package.metadata]
-        requires-dist = [{ name = "iniconfig", specifier = "==2.0.0", index = "https://test.pypi.org/simple" }]
-        "###
-        );
-    });
+    Ok(())
+}
+
+/// A package with a hardware variant should be pinned to the index that shares the variant's name.
+#[test]
+fn lock_variant_index() -> Result<()> {
+    let context = TestContext::new("3.12");
 
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
         [project]
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["anyio"]
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0"]
 
         [build-system]
         requires = ["setuptools>=42"]
         build-backend = "setuptools.build_meta"
 
+        [tool.uv.variants]
+        iniconfig = "test"
+
         [[tool.uv.index]]
         name = "test"
         url = "https://test.pypi.org/simple"
         explicit = true
-        default = true
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock().arg("--verbose"), @r###"
-    success: false
-    exit_code: 1
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    DEBUG uv [VERSION] ([COMMIT] DATE)
-    DEBUG Found workspace root: `[TEMP_DIR]/`
-    DEBUG Adding current workspace member: `[TEMP_DIR]/`
-    DEBUG Using Python request `>=3.12` from `requires-python` metadata
-    DEBUG The virtual environment's Python version satisfies `>=3.12`
-    DEBUG Using request timeout of [TIME]
-    DEBUG Found static `pyproject.toml` for: project @ file://[TEMP_DIR]/
-    DEBUG No workspace root found, using project root
-    DEBUG Ignoring existing lockfile due to mismatched `requires-dist` for: `project==0.1.0`
-      Expected: {Requirement { name: PackageName("anyio"), extras: [], marker: true, source: Registry { specifier: VersionSpecifiers([]), index: None }, origin: None }}
-      Actual: {Requirement { name: PackageName("iniconfig"), extras: [], marker: true, source: Registry { specifier: VersionSpecifiers([VersionSpecifier { operator: Equal, version: "2.0.0" }]), index: Some(Url { scheme: "https", cannot_be_a_base: false, username: "", password: None, host: Some(Domain("test.pypi.org")), port: None, path: "/simple", query: None, fragment: None }) }, origin: None }}
-    DEBUG Solving with installed Python version: 3.12.[X]
-    DEBUG Solving with target Python version: >=3.12
-    DEBUG Adding direct dependency: project*
-    DEBUG Searching for a compatible version of project @ file://[TEMP_DIR]/ (*)
-    DEBUG Adding transitive dependency for project==0.1.0: anyio*
-    DEBUG Searching for a compatible version of anyio (*)
-    DEBUG No compatible version found for: anyio
-    DEBUG Searching for a compatible version of project @ file://[TEMP_DIR]/ (<0.1.0 | >0.1.0)
-    DEBUG No compatible version found for: project
-      × No solution found when resolving dependencies:
-      ╰─▶ Because anyio was not found in the provided package locations and your project depends on anyio, we can conclude that your project's requirements are unsatisfiable.
-
-          hint: Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `--find-links <uri>`)
+    Resolved 5 packages in [TIME]
     "###);
 
     let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
@@ -14549,10 +15091,32 @@ fn lock_explicit_default_index() -> Result<()> {
         exclude-newer = "2024-03-25T00:00:00Z"
 
         [[package]]
-        name = "iniconfig"
-        version = "2.0.0"
-        source = { registry = "https://test.pypi.org/simple" }
-        sdist = { url = "https://test-files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        name = "anyio"
+        version = "3.7.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz", hash = "sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce", size = 142737 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl", hash = "sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0", size = 80873 },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567 },
+        ]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://test.pypi.org/simple" }
+        sdist = { url = "https://test-files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
         wheels = [
             { url = "https://test-files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
         ]
@@ -14562,11 +15126,24 @@ fn lock_explicit_default_index() -> Result<()> {
         version = "0.1.0"
         source = { editable = "." }
         dependencies = [
+            { name = "anyio" },
             { name = "iniconfig" },
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "iniconfig", specifier = "==2.0.0", index = "https://test.pypi.org/simple" }]
+        requires-dist = [
+            { name = "anyio", specifier = "==3.7.0" },
+            { name = "iniconfig", specifier = "==2.0.0", index = "https://test.pypi.org/simple" },
+        ]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235 },
+        ]
         "###
         );
     });
@@ -14574,10 +15151,17 @@ fn lock_explicit_default_index() -> Result<()> {
     Ok(())
 }
 
+/// A package with a hardware variant should prefer versions that match the variant, even over
+/// newer versions without a local version label.
 #[test]
-fn lock_named_index() -> Result<()> {
+fn lock_variant_prefer_local() -> Result<()> {
     let context = TestContext::new("3.12");
 
+    let links = context.temp_dir.child("links");
+    for version in ["2.5.1+cu124", "2.5.1+cpu", "2.6.0"] {
+        common::make_wheel(&links, "accel", version)?;
+    }
+
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
@@ -14585,20 +15169,14 @@ fn lock_named_index() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["typing-extensions"]
-
-        [[tool.uv.index]]
-        name = "pytorch"
-        url = "https://download.pytorch.org/whl/cu121"
-        explicit = true
+        dependencies = ["accel"]
 
-        [[tool.uv.index]]
-        name = "heron"
-        url = "https://pypi-proxy.fly.dev/simple"
+        [tool.uv]
+        find-links = ["./links"]
+        no-index = true
 
-        [[tool.uv.index]]
-        name = "test"
-        url = "https://test.pypi.org/simple"
+        [tool.uv.variants]
+        accel = "cu124"
         "#,
     )?;
 
@@ -14610,51 +15188,74 @@ fn lock_named_index() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     "###);
+    assert!(context
+        .read("uv.lock")
+        .contains(r#"version = "2.5.1+cu124""#));
 
-    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
-
-    insta::with_settings!({
-        filters => context.filters(),
-    }, {
-        assert_snapshot!(
-            lock, @r###"
-        version = 1
+    // If no version matches the variant, versions without a local version label are used.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
         requires-python = ">=3.12"
+        dependencies = ["accel>=2.6"]
 
-        [options]
-        exclude-newer = "2024-03-25T00:00:00Z"
+        [tool.uv]
+        find-links = ["./links"]
+        no-index = true
 
-        [[package]]
+        [tool.uv.variants]
+        accel = "cu124"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Updated accel v2.5.1+cu124 -> v2.6.0
+    "###);
+    assert!(context.read("uv.lock").contains(r#"version = "2.6.0""#));
+
+    // Versions with a different local version label are never selected.
+    pyproject_toml.write_str(
+        r#"
+        [project]
         name = "project"
         version = "0.1.0"
-        source = { virtual = "." }
-        dependencies = [
-            { name = "typing-extensions" },
-        ]
+        requires-python = ">=3.12"
+        dependencies = ["accel<2.6"]
 
-        [package.metadata]
-        requires-dist = [{ name = "typing-extensions" }]
+        [tool.uv]
+        find-links = ["./links"]
+        no-index = true
 
-        [[package]]
-        name = "typing-extensions"
-        version = "4.10.0"
-        source = { registry = "https://pypi-proxy.fly.dev/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/16/3a/0d26ce356c7465a19c9ea8814b960f8a36c3b0d07c323176620b7b483e44/typing_extensions-4.10.0.tar.gz", hash = "sha256:b0abd7c89e8fb96f98db18d86106ff1d90ab692004eb746cf6eda2682f91b3cb", size = 77558 }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/f9/de/dc04a3ea60b22624b51c703a84bbe0184abcd1d0b9bc8074b5d6b7ab90bb/typing_extensions-4.10.0-py3-none-any.whl", hash = "sha256:69b1a937c3a517342112fb4c6df7e72fc39a38e7891a5730ed4985b5214b5475", size = 33926 },
-        ]
-        "###
-        );
-    });
+        [tool.uv.variants]
+        accel = "rocm"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only accel>=2.6 is available and your project depends on accel<2.6, we can conclude that your project's requirements are unsatisfiable.
+    "###);
 
     Ok(())
 }
 
 #[test]
-fn lock_default_index() -> Result<()> {
+fn lock_explicit_default_index() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // If an index is included, PyPI will still be used as the default index.
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
@@ -14662,11 +15263,20 @@ fn lock_default_index() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["iniconfig"]
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.uv.sources]
+        iniconfig = { index = "test" }
 
         [[tool.uv.index]]
-        name = "pytorch"
-        url = "https://download.pytorch.org/whl/cu121"
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        explicit = true
+        default = true
         "#,
     )?;
 
@@ -14695,52 +15305,76 @@ fn lock_default_index() -> Result<()> {
         [[package]]
         name = "iniconfig"
         version = "2.0.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        source = { registry = "https://test.pypi.org/simple" }
+        sdist = { url = "https://test-files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
         wheels = [
-            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+            { url = "https://test-files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
         ]
 
         [[package]]
         name = "project"
         version = "0.1.0"
-        source = { virtual = "." }
+        source = { editable = "." }
         dependencies = [
             { name = "iniconfig" },
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "iniconfig" }]
+        requires-dist = [{ name = "iniconfig", specifier = "==2.0.0", index = "https://test.pypi.org/simple" }]
         "###
         );
     });
 
-    // Unless that index is marked as the default.
     pyproject_toml.write_str(
         r#"
         [project]
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["iniconfig"]
+        dependencies = ["anyio"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
 
         [[tool.uv.index]]
-        name = "pytorch"
-        url = "https://download.pytorch.org/whl/cu121"
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        explicit = true
         default = true
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
+    uv_snapshot!(context.filters(), context.lock().arg("--verbose"), @r###"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Found workspace root: `[TEMP_DIR]/`
+    DEBUG Adding current workspace member: `[TEMP_DIR]/`
+    DEBUG Using Python request `>=3.12` from `requires-python` metadata
+    DEBUG The virtual environment's Python version satisfies `>=3.12`
+    DEBUG Using request timeout of [TIME]
+    DEBUG Found static `pyproject.toml` for: project @ file://[TEMP_DIR]/
+    DEBUG No workspace root found, using project root
+    DEBUG Ignoring existing lockfile due to mismatched `requires-dist` for: `project==0.1.0`
+      Expected: {Requirement { name: PackageName("anyio"), extras: [], marker: true, source: Registry { specifier: VersionSpecifiers([]), index: None }, origin: None }}
+      Actual: {Requirement { name: PackageName("iniconfig"), extras: [], marker: true, source: Registry { specifier: VersionSpecifiers([VersionSpecifier { operator: Equal, version: "2.0.0" }]), index: Some(Url { scheme: "https", cannot_be_a_base: false, username: "", password: None, host: Some(Domain("test.pypi.org")), port: None, path: "/simple", query: None, fragment: None }) }, origin: None }}
+    DEBUG Solving with installed Python version: 3.12.[X]
+    DEBUG Solving with target Python version: >=3.12
+    DEBUG Adding direct dependency: project*
+    DEBUG Searching for a compatible version of project @ file://[TEMP_DIR]/ (*)
+    DEBUG Adding transitive dependency for project==0.1.0: anyio*
+    DEBUG Searching for a compatible version of anyio (*)
+    DEBUG No compatible version found for: anyio
+    DEBUG Searching for a compatible version of project @ file://[TEMP_DIR]/ (<0.1.0 | >0.1.0)
+    DEBUG No compatible version found for: project
       × No solution found when resolving dependencies:
-      ╰─▶ Because iniconfig was not found in the package registry and your project depends on iniconfig, we can conclude that your project's requirements are unsatisfiable.
+      ╰─▶ Because anyio was not found in the provided package locations and your project depends on anyio, we can conclude that your project's requirements are unsatisfiable.
 
-          hint: An index URL (https://download.pytorch.org/whl/cu121) could not be queried due to a lack of valid authentication credentials (403 Forbidden).
+          hint: Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `--find-links <uri>`)
     "###);
 
     let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
@@ -14759,22 +15393,22 @@ fn lock_default_index() -> Result<()> {
         [[package]]
         name = "iniconfig"
         version = "2.0.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        source = { registry = "https://test.pypi.org/simple" }
+        sdist = { url = "https://test-files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
         wheels = [
-            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+            { url = "https://test-files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
         ]
 
         [[package]]
         name = "project"
         version = "0.1.0"
-        source = { virtual = "." }
+        source = { editable = "." }
         dependencies = [
             { name = "iniconfig" },
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "iniconfig" }]
+        requires-dist = [{ name = "iniconfig", specifier = "==2.0.0", index = "https://test.pypi.org/simple" }]
         "###
         );
     });
@@ -14783,7 +15417,7 @@ fn lock_default_index() -> Result<()> {
 }
 
 #[test]
-fn lock_named_index_cli() -> Result<()> {
+fn lock_named_index() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -14793,51 +15427,259 @@ fn lock_named_index_cli() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["jinja2==3.1.2"]
+        dependencies = ["typing-extensions"]
 
-        [tool.uv.sources]
-        jinja2 = { index = "pytorch" }
-        "#,
-    )?;
+        [[tool.uv.index]]
+        name = "pytorch"
+        url = "https://download.pytorch.org/whl/cu121"
+        explicit = true
 
-    // The package references a non-existent index.
-    uv_snapshot!(context.filters(), context.lock().env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
+        [[tool.uv.index]]
+        name = "heron"
+        url = "https://pypi-proxy.fly.dev/simple"
 
-    ----- stderr -----
-      × Failed to build `project @ file://[TEMP_DIR]/`
-      ├─▶ Failed to parse entry: `jinja2`
-      ╰─▶ Package `jinja2` references an undeclared index: `pytorch`
-    "###);
+        [[tool.uv.index]]
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        "#,
+    )?;
 
-    // But it's fine if it comes from the CLI.
-    uv_snapshot!(context.filters(), context.lock().arg("--index").arg("pytorch=https://download.pytorch.org/whl/cu121").env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
+    Resolved 2 packages in [TIME]
     "###);
 
-    Ok(())
-}
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
 
-/// If a name is reused, the higher-priority index should "overwrite" the lower-priority index.
-/// In other words, the lower-priority index should be ignored entirely during implicit resolution.
-///
-/// In this test, we should use PyPI (the default index) and ignore `https://example.com` entirely.
-/// (Querying `https://example.com` would fail with a 500.)
-#[test]
-fn lock_repeat_named_index() -> Result<()> {
-    let context = TestContext::new("3.12");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "typing-extensions" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "typing-extensions" }]
+
+        [[package]]
+        name = "typing-extensions"
+        version = "4.10.0"
+        source = { registry = "https://pypi-proxy.fly.dev/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/16/3a/0d26ce356c7465a19c9ea8814b960f8a36c3b0d07c323176620b7b483e44/typing_extensions-4.10.0.tar.gz", hash = "sha256:b0abd7c89e8fb96f98db18d86106ff1d90ab692004eb746cf6eda2682f91b3cb", size = 77558 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/f9/de/dc04a3ea60b22624b51c703a84bbe0184abcd1d0b9bc8074b5d6b7ab90bb/typing_extensions-4.10.0-py3-none-any.whl", hash = "sha256:69b1a937c3a517342112fb4c6df7e72fc39a38e7891a5730ed4985b5214b5475", size = 33926 },
+        ]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+#[test]
+fn lock_default_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // If an index is included, PyPI will still be used as the default index.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "pytorch"
+        url = "https://download.pytorch.org/whl/cu121"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig" }]
+        "###
+        );
+    });
+
+    // Unless that index is marked as the default.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [[tool.uv.index]]
+        name = "pytorch"
+        url = "https://download.pytorch.org/whl/cu121"
+        default = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the package registry and your project depends on iniconfig, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: An index URL (https://download.pytorch.org/whl/cu121) could not be queried due to a lack of valid authentication credentials (403 Forbidden).
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig" }]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+#[test]
+fn lock_named_index_cli() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["jinja2==3.1.2"]
+
+        [tool.uv.sources]
+        jinja2 = { index = "pytorch" }
+        "#,
+    )?;
+
+    // The package references a non-existent index.
+    uv_snapshot!(context.filters(), context.lock().env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `project @ file://[TEMP_DIR]/`
+      ├─▶ Failed to parse entry: `jinja2`
+      ╰─▶ Package `jinja2` references an undeclared index: `pytorch`
+    "###);
+
+    // But it's fine if it comes from the CLI.
+    uv_snapshot!(context.filters(), context.lock().arg("--index").arg("pytorch=https://download.pytorch.org/whl/cu121").env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// If a name is reused, the higher-priority index should "overwrite" the lower-priority index.
+/// In other words, the lower-priority index should be ignored entirely during implicit resolution.
+///
+/// In this test, we should use PyPI (the default index) and ignore `https://example.com` entirely.
+/// (Querying `https://example.com` would fail with a 500.)
+#[test]
+fn lock_repeat_named_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
@@ -19237,3 +20079,1000 @@ fn lock_dynamic_version() -> Result<()> {
 
     Ok(())
 }
+
+/// Report the packages that would be built from source on each supported environment.
+#[test]
+fn lock_check_source_builds() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution==0.0.1", "iniconfig==2.0.0"]
+
+        [tool.uv]
+        environments = ["sys_platform == 'linux'", "sys_platform == 'win32'"]
+        no-build-package = ["source-distribution; sys_platform == 'win32'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-source-builds").env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    `source-distribution==0.0.1` would be built from source on `sys_platform == 'linux'` (required by: project==0.1.0 -> source-distribution==0.0.1)
+    warning: `source-distribution==0.0.1` would need to be built from source on `sys_platform == 'win32'`, but building it is disabled (required by: project==0.1.0 -> source-distribution==0.0.1)
+    "###);
+
+    // Without a package that requires a build, nothing is reported.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-source-builds"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    All locked packages have wheels for every target environment
+    "###);
+
+    Ok(())
+}
+
+/// Write a report of aggregate statistics on the lockfile.
+#[test]
+fn lock_report() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        test = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--report").arg("lock-report.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Wrote lock report to: lock-report.json
+    "###);
+
+    let report = context.read("lock-report.json");
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r#""uv_version": ".*""#, r#""uv_version": "[VERSION]""#)])
+        .collect::<Vec<_>>();
+
+    insta::with_settings!({
+        filters => filters,
+    }, {
+        assert_snapshot!(
+            report, @r###"
+        {
+          "uv_version": "[VERSION]",
+          "requires_python": ">=3.12",
+          "packages": 1,
+          "build_dependencies": 0,
+          "groups": {
+            "test": 1
+          },
+          "environments": [
+            {
+              "marker": "os_name == 'posix' and platform_system == 'Linux' and sys_platform == 'linux'",
+              "packages": 1
+            },
+            {
+              "marker": "os_name == 'nt' and platform_system == 'Windows' and sys_platform == 'win32'",
+              "packages": 1
+            },
+            {
+              "marker": "os_name == 'posix' and platform_system == 'Darwin' and sys_platform == 'darwin'",
+              "packages": 1
+            }
+          ],
+          "artifacts": {
+            "sdists": 1,
+            "wheels": 1,
+            "sdist_size": 4646,
+            "wheel_size": 5892,
+            "unknown_size": 0
+          },
+          "changes": {
+            "added": [
+              "iniconfig",
+              "project"
+            ],
+            "removed": [],
+            "updated": []
+          }
+        }
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// With `--interactive`, a resolution failure should be reported as usual when stderr isn't a
+/// terminal, without modifying the `pyproject.toml`.
+#[test]
+fn lock_interactive_non_terminal() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject = r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        project1 = ["sortedcontainers==2.3.0"]
+        project2 = ["sortedcontainers==2.4.0"]
+        "#;
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(pyproject)?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--interactive"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because project[project2] depends on sortedcontainers==2.4.0 and project[project1] depends on sortedcontainers==2.3.0, we can conclude that project[project1] and project[project2] are incompatible.
+          And because your project requires project[project1] and project[project2], we can conclude that your projects's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Declare `project[project1]` and `project[project2]` as conflicting (via `tool.uv.conflicting-groups`)
+            2. Relax the requirement `sortedcontainers==2.4.0` in `project[project2]`
+            3. Relax the requirement `sortedcontainers==2.3.0` in `project[project1]`
+            4. Add an override for `sortedcontainers==2.3.0` (via `tool.uv.override-dependencies` or `--override`)
+    "###);
+
+    assert_eq!(context.read("pyproject.toml"), pyproject);
+
+    Ok(())
+}
+
+/// Lock a project that vendors a conflicting version of a leaf dependency for one of its
+/// dependents, via `tool.uv.vendor`.
+#[test]
+fn lock_vendored() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=2", "legacy-lib"]
+
+        [tool.uv.sources]
+        legacy-lib = { path = "legacy-lib" }
+
+        [[tool.uv.vendor]]
+        name = "iniconfig"
+        version = "<2"
+        alias = "iniconfig_legacy"
+        dependents = ["legacy-lib"]
+        "#,
+    )?;
+
+    let legacy_lib = context.temp_dir.child("legacy-lib");
+    legacy_lib.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "legacy-lib"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig<2"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    legacy_lib
+        .child("src")
+        .child("legacy_lib")
+        .child("__init__.py")
+        .write_str("import iniconfig\n")?;
+
+    // `legacy-lib`'s requirement on `iniconfig` is omitted from the main resolution, and
+    // `iniconfig<2` is resolved separately.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Resolved 1 package in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "legacy-lib"
+        version = "0.1.0"
+        source = { directory = "legacy-lib" }
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", specifier = "<2" }]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+            { name = "legacy-lib" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "iniconfig", specifier = ">=2" },
+            { name = "legacy-lib", directory = "legacy-lib" },
+        ]
+
+        [[vendored]]
+        name = "iniconfig"
+        version = "1.1.1"
+        source = { registry = "https://pypi.org/simple" }
+        alias = "iniconfig_legacy"
+        dependents = ["legacy-lib"]
+        sdist = { url = "https://files.pythonhosted.org/packages/23/a2/97899f6bd0e873fed3a7e67ae8d3a08b21799430fb4da15cfedf10d6e2c2/iniconfig-1.1.1.tar.gz", hash = "sha256:bc3af051d7d14b2ee5ef9969666def0cd1a000e121eaea580d4a313df4b37f32", size = 8104 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/9b/dd/b3c12c6d707058fa947864b67f0c4e0c39ef8610988d7baea9578f3c48f3/iniconfig-1.1.1-py2.py3-none-any.whl", hash = "sha256:011e24c64b7f47f6ebd835bb12a743f2fbe9a26d4cecaa7f53bc4f35ee9da8b3", size = 4990 },
+        ]
+        "###
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // Without vendoring, the requirements are unsatisfiable.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=2", "legacy-lib"]
+
+        [tool.uv.sources]
+        legacy-lib = { path = "legacy-lib" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because legacy-lib depends on iniconfig<2 and your project depends on iniconfig>=2, we can conclude that your project and legacy-lib are incompatible.
+          And because your project depends on legacy-lib, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `iniconfig<2` in `legacy-lib`
+            2. Relax the requirement `iniconfig>=2` in `project`
+            3. Add an override for `iniconfig>=2` (via `tool.uv.override-dependencies` or `--override`)
+    "###);
+
+    Ok(())
+}
+
+/// Suggest concrete fixes for a version conflict between two dependencies.
+#[test]
+fn lock_conflict_suggestions() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["legacy", "modern"]
+
+        [tool.uv.sources]
+        legacy = { path = "legacy" }
+        modern = { path = "modern" }
+        "#,
+    )?;
+
+    for (name, specifier) in [("legacy", "<2"), ("modern", ">=2")] {
+        let package = context.temp_dir.child(name);
+        package.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["iniconfig{specifier}"]
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+        "#})?;
+        package
+            .child("src")
+            .child(name)
+            .child("__init__.py")
+            .touch()?;
+    }
+
+    // The requirement that excludes the newest version of `iniconfig` is listed first.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because legacy depends on iniconfig<2 and modern depends on iniconfig>=2, we can conclude that legacy and modern are incompatible.
+          And because your project depends on legacy and modern, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `iniconfig<2` in `legacy`
+            2. Relax the requirement `iniconfig>=2` in `modern`
+            3. Add an override for `iniconfig>=2` (via `tool.uv.override-dependencies` or `--override`)
+    "###);
+
+    // The suggestions can also be written to a file, as JSON.
+    let suggestions = context.temp_dir.child("suggestions.json");
+    context
+        .lock()
+        .arg("--resolution-suggestions")
+        .arg(suggestions.path())
+        .assert()
+        .failure();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            fs_err::read_to_string(suggestions.path())?, @r###"
+        [
+          {
+            "kind": "relax",
+            "dependent": "legacy",
+            "package": "iniconfig",
+            "specifier": "<2"
+          },
+          {
+            "kind": "relax",
+            "dependent": "modern",
+            "package": "iniconfig",
+            "specifier": ">=2"
+          },
+          {
+            "kind": "override",
+            "package": "iniconfig",
+            "specifier": ">=2"
+          }
+        ]
+        "###
+        );
+    });
+
+    // The suggestions can be hidden.
+    uv_snapshot!(context.filters(), context.lock().env(EnvVars::UV_NO_RESOLUTION_SUGGESTIONS, "1"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because legacy depends on iniconfig<2 and modern depends on iniconfig>=2, we can conclude that legacy and modern are incompatible.
+          And because your project depends on legacy and modern, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `iniconfig<2` in `legacy`
+            2. Relax the requirement `iniconfig>=2` in `modern`
+            3. Add an override for `iniconfig>=2` (via `tool.uv.override-dependencies` or `--override`)
+    "###);
+
+    Ok(())
+}
+
+/// With `--minimize`, a package that was locked at multiple versions across forks should be merged
+/// into a single version when one satisfies every fork, while a package that requires different
+/// versions in different forks should be reported as irreducible.
+#[test]
+fn lock_minimize() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // `a` is pinned to a different version on each platform, such that it can't be merged. On
+    // Windows, `a` requires `b<2`; elsewhere, `b` is unconstrained.
+    let links = context.temp_dir.child("links");
+    common::make_wheel_with_dependencies(&links, "a", "1.0.0", &["b<2"])?;
+    common::make_wheel_with_dependencies(&links, "a", "2.0.0", &["b"])?;
+    common::make_wheel(&links, "b", "1.0.0")?;
+    common::make_wheel(&links, "b", "2.0.0")?;
+
+    // With `lowest-direct`, the versions selected in one fork aren't used as preferences in the
+    // others, so `b` is locked at the latest compatible version in each fork.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "a==1.0.0 ; sys_platform == 'win32'",
+            "a==2.0.0 ; sys_platform != 'win32'",
+        ]
+
+        [tool.uv]
+        find-links = ["./links"]
+        no-index = true
+        resolution = "lowest-direct"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+    assert_eq!(lock.matches("[[package]]\nname = \"b\"").count(), 2);
+
+    // `b==1.0.0` satisfies both forks, so it's merged; `a` is kept at both versions.
+    uv_snapshot!(context.filters(), context.lock().arg("--minimize"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Kept a v1.0.0, v2.0.0 (irreducible)
+    Merged b v1.0.0, v2.0.0 -> v1.0.0
+    "###);
+
+    let lock = context.read("uv.lock");
+    assert_eq!(lock.matches("[[package]]\nname = \"a\"").count(), 2);
+    assert_eq!(lock.matches("[[package]]\nname = \"b\"").count(), 1);
+    assert!(lock.contains("[[package]]\nname = \"b\"\nversion = \"1.0.0\""));
+
+    // Once merged, only the irreducible duplicate remains.
+    uv_snapshot!(context.filters(), context.lock().arg("--minimize"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Kept a v1.0.0, v2.0.0 (irreducible)
+    "###);
+
+    Ok(())
+}
+
+/// Record the inputs to the resolution with `--provenance`, and detect when they change with
+/// `--verify-provenance`.
+#[test]
+fn lock_provenance() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    // Verifying requires that the provenance was recorded.
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.lock().arg("--verify-provenance"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No provenance found at `uv.provenance.toml`; run `uv lock --provenance` to record it
+    "###);
+
+    uv_snapshot!(context.filters(), context.lock().arg("--provenance"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Recorded provenance to: uv.provenance.toml
+    "###);
+
+    let provenance: toml::Table = toml::from_str(&context.read("uv.provenance.toml"))?;
+    assert_eq!(
+        provenance["exclude-newer"].as_str(),
+        Some("2024-03-25T00:00:00Z")
+    );
+    assert_eq!(provenance["resolution"].as_str(), Some("highest"));
+    assert_eq!(
+        provenance["prerelease"].as_str(),
+        Some("if-necessary-or-explicit")
+    );
+    assert_eq!(provenance["requires-python"].as_str(), Some(">=3.12"));
+    assert!(provenance["environment"]["python"]
+        .as_str()
+        .is_some_and(|python| python.starts_with("3.12.")));
+    let indexes = provenance["index"].as_array().unwrap();
+    assert_eq!(indexes.len(), 1);
+    assert_eq!(indexes[0]["url"].as_str(), Some("https://pypi.org/simple"));
+    assert_eq!(indexes[0]["packages"].as_integer(), Some(1));
+
+    // The inputs are unchanged.
+    uv_snapshot!(context.filters(), context.lock().arg("--verify-provenance"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No provenance drift detected
+    "###);
+
+    // If an input has changed, each change is reported, and the lockfile is left untouched.
+    let lock = context.read("uv.lock");
+    let recorded = context
+        .read("uv.provenance.toml")
+        .replace(r#"resolution = "highest""#, r#"resolution = "lowest""#);
+    context
+        .temp_dir
+        .child("uv.provenance.toml")
+        .write_str(&recorded)?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--verify-provenance"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The inputs to the lockfile have changed since its provenance was recorded:
+      - `resolution` changed from `lowest` to `highest`
+    "###);
+
+    assert_eq!(context.read("uv.lock"), lock);
+
+    // `--verify-provenance` can't be combined with `--provenance`.
+    uv_snapshot!(context.filters(), context.lock().arg("--provenance").arg("--verify-provenance"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--provenance' cannot be used with '--verify-provenance'
+
+    Usage: uv lock --cache-dir [CACHE_DIR] --provenance --exclude-newer <EXCLUDE_NEWER>
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
+
+/// Abort resolution once the resolver exceeds `--max-backtracks`, and summarize the packages
+/// that consumed the most effort.
+#[test]
+fn lock_max_backtracks() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // `a==2.0.0` depends on a version of `b` that doesn't exist, so the resolver has to backtrack
+    // to `a==1.0.0`.
+    let links = context.temp_dir.child("links");
+    make_wheel_with_dependencies(&links, "a", "2.0.0", &["b==9.9.9"])?;
+    make_wheel(&links, "a", "1.0.0")?;
+    make_wheel(&links, "b", "1.0.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--max-backtracks")
+        .arg("0"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolution was aborted after exceeding the limit of 0 backtracks (`--max-backtracks`). The following packages consumed the most effort; consider constraining or pinning them:
+    - a: 1 backtrack, 2 versions tried
+    - b: 0 backtracks, 1 version tried
+    - project: 0 backtracks, 1 version tried
+    "###);
+
+    // A single backtrack is within the limit.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--max-backtracks")
+        .arg("1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // The limit can also be provided via the environment.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--upgrade")
+        .env(EnvVars::UV_MAX_BACKTRACKS, "0"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolution was aborted after exceeding the limit of 0 backtracks (`--max-backtracks`). The following packages consumed the most effort; consider constraining or pinning them:
+    - a: 1 backtrack, 2 versions tried
+    - b: 0 backtracks, 1 version tried
+    - project: 0 backtracks, 1 version tried
+    "###);
+
+    Ok(())
+}
+
+/// Check that every locked package supports the full `requires-python` range with
+/// `--check-requires-python`.
+#[test]
+fn lock_check_requires_python() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.temp_dir.child("links");
+    make_wheel(&links, "wide", "1.0.0")?;
+
+    // Only Python 3.12 is supported by the `narrow` wheel.
+    let wheel = make_wheel(&links, "narrow", "1.0.0")?;
+    fs_err::rename(&wheel, links.join("narrow-1.0.0-cp312-none-any.whl"))?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["wide"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--check-requires-python"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    All locked packages support Python 3.12, 3.13
+    "###);
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["wide", "narrow"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--check-requires-python"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Added narrow v1.0.0
+    warning: `narrow==1.0.0` has no compatible distributions for Python 3.13, which is included in `requires-python = ">=3.12"` (required by: project==0.1.0 -> narrow==1.0.0)
+    The locked packages support Python 3.12 of 3.12, 3.13
+    "###);
+
+    Ok(())
+}
+
+/// Validate the declared conflicting extras with `--check-conflicts`.
+#[test]
+fn lock_check_conflicts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The declared conflicts are minimal and complete.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [tool.uv]
+        conflicting-groups = [
+            [
+              { extra = "project1" },
+              { extra = "project2" },
+            ],
+        ]
+
+        [project.optional-dependencies]
+        project1 = ["sortedcontainers==2.3.0"]
+        project2 = ["sortedcontainers==2.4.0"]
+        project3 = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-conflicts"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    The declared conflicts are minimal and complete
+    "###);
+
+    // `project3` doesn't conflict with the other extras in its set.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [tool.uv]
+        conflicting-groups = [
+            [
+              { extra = "project1" },
+              { extra = "project2" },
+              { extra = "project3" },
+            ],
+        ]
+
+        [project.optional-dependencies]
+        project1 = ["sortedcontainers==2.3.0"]
+        project2 = ["sortedcontainers==2.4.0"]
+        project3 = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-conflicts"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    warning: `project[project3]` is declared in `tool.uv.conflicting-groups`, but doesn't conflict with any other extra in its set (`project[project1]`, `project[project2]`, `project[project3]`)
+    "###);
+
+    // `project1` and `project2` conflict, but aren't declared. The conflicts are reported even
+    // though the resolution fails.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        project1 = ["sortedcontainers==2.3.0"]
+        project2 = ["sortedcontainers==2.4.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check-conflicts"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `project[project1]` and `project[project2]` conflict, but aren't declared in `tool.uv.conflicting-groups` (project[project1] -> sortedcontainers==2.3.0 vs. project[project2] -> sortedcontainers==2.4.0)
+      × No solution found when resolving dependencies:
+      ╰─▶ Because project[project2] depends on sortedcontainers==2.4.0 and project[project1] depends on sortedcontainers==2.3.0, we can conclude that project[project1] and project[project2] are incompatible.
+          And because your project requires project[project1] and project[project2], we can conclude that your projects's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Declare `project[project1]` and `project[project2]` as conflicting (via `tool.uv.conflicting-groups`)
+            2. Relax the requirement `sortedcontainers==2.4.0` in `project[project2]`
+            3. Relax the requirement `sortedcontainers==2.3.0` in `project[project1]`
+            4. Add an override for `sortedcontainers==2.3.0` (via `tool.uv.override-dependencies` or `--override`)
+    "###);
+
+    Ok(())
+}
+
+/// Resolve the direct dependencies of a dependency group with its own resolution strategy, via
+/// `tool.uv.group-resolution`.
+#[test]
+fn lock_group_resolution() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [dependency-groups]
+        test-lowest = ["anyio>=3.0.0"]
+
+        [tool.uv.group-resolution]
+        test-lowest = "lowest-direct"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    // `anyio` is resolved to its lower bound, while `iniconfig` and the transitive dependencies
+    // of `anyio` use the latest compatible versions.
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen").arg("--group").arg("test-lowest"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    ├── iniconfig v2.0.0
+    └── anyio v3.0.0 (group: test-lowest)
+        ├── idna v3.6
+        └── sniffio v1.3.1
+
+    ----- stderr -----
+    "###);
+
+    // The strategies are recorded in the lockfile.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(r#"group-resolution = { test-lowest = "lowest-direct" }"#));
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    // Removing the strategy invalidates the lockfile, and `anyio` is resolved to its latest
+    // version.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [dependency-groups]
+        test-lowest = ["anyio>=3.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in group resolution strategies
+    Resolved 5 packages in [TIME]
+    Updated anyio v3.0.0 -> v4.3.0
+    "###);
+
+    Ok(())
+}
@@ -433,6 +433,11 @@ fn conflict_in_fork() -> Result<()> {
               package-a{sys_platform == 'darwin'}==1.0.0
               package-a{sys_platform == 'darwin'}>2
           and your project depends on package-a{sys_platform == 'darwin'}<2, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `package-d==1` in `package-b`
+            2. Relax the requirement `package-d==2` in `package-c`
+            3. Add an override for `package-d==2` (via `tool.uv.override-dependencies` or `--override`)
     "###
     );
 
@@ -2782,6 +2787,11 @@ fn fork_non_local_fork_marker_direct() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because package-a{sys_platform == 'linux'}==1.0.0 depends on package-c<2.0.0 and package-b{sys_platform == 'darwin'}==1.0.0 depends on package-c>=2.0.0, we can conclude that package-a{sys_platform == 'linux'}==1.0.0 and package-b{sys_platform == 'darwin'}==1.0.0 are incompatible.
           And because your project depends on package-a{sys_platform == 'linux'}==1.0.0 and package-b{sys_platform == 'darwin'}==1.0.0, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `package-c<2.0.0` in `package-a`
+            2. Relax the requirement `package-c>=2.0.0` in `package-b`
+            3. Add an override for `package-c>=2.0.0` (via `tool.uv.override-dependencies` or `--override`)
     "###
     );
 
@@ -2858,6 +2868,11 @@ fn fork_non_local_fork_marker_transitive() -> Result<()> {
           we can conclude that package-a==1.0.0 depends on package-c{sys_platform == 'linux'}==1.0.0.
           And because only package-c{sys_platform == 'darwin'}<=2.0.0 is available and package-b==1.0.0 depends on package-c{sys_platform == 'darwin'}>=2.0.0, we can conclude that package-a==1.0.0 and package-b==1.0.0 are incompatible.
           And because your project depends on package-a==1.0.0 and package-b==1.0.0, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `package-c<2.0.0` in `package-a`
+            2. Relax the requirement `package-c>=2.0.0` in `package-b`
+            3. Add an override for `package-c>=2.0.0` (via `tool.uv.override-dependencies` or `--override`)
     "###
     );
 
@@ -11,12 +11,39 @@ mod build;
 #[cfg(feature = "python")]
 mod build_backend;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod bundle;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_export;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_stats;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_verify;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_warm;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod complete;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod config;
+
+#[cfg(all(unix, feature = "python", feature = "pypi"))]
+mod daemon;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod download;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod ecosystem;
 
@@ -61,6 +88,12 @@ mod pip_sync;
 mod pip_tree;
 mod pip_uninstall;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod pin;
+
+#[cfg(feature = "python")]
+mod proxy;
+
 #[cfg(feature = "pypi")]
 mod publish;
 
@@ -72,12 +105,27 @@ mod python_find;
 #[cfg(feature = "python-managed")]
 mod python_install;
 
+#[cfg(feature = "python")]
+mod python_list;
+
 #[cfg(feature = "python")]
 mod python_pin;
 
+#[cfg(feature = "python")]
+mod query;
+
+#[cfg(feature = "python")]
+mod resolver;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod run;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod script;
+
+#[cfg(unix)]
+mod self_component;
+
 #[cfg(feature = "self-update")]
 mod self_update;
 
@@ -111,6 +159,8 @@ mod tree;
 #[cfg(feature = "python")]
 mod venv;
 
+mod version;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod workflow;
 
@@ -0,0 +1,290 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Add, list, and remove pins in a project.
+#[test]
+fn pin_add_remove() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pin().arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No packages are pinned
+    "###);
+
+    // Pin a package at an explicit version.
+    uv_snapshot!(context.filters(), context.pin().arg("add").arg("idna==3.6"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pinned `idna==3.6` in: pyproject.toml
+    "###);
+
+    // Pin a package at its locked version.
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.pin().arg("add").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pinned `anyio==3.7.0` in: pyproject.toml
+    "###);
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("pyproject.toml"), @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [tool.uv.pins]
+        idna = "3.6"
+        anyio = "3.7.0"
+        "###
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.pin().arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==3.7.0
+    idna==3.6
+
+    ----- stderr -----
+    "###);
+
+    // Pins must be exact.
+    uv_snapshot!(context.filters(), context.pin().arg("add").arg("sniffio>=1"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Pins must provide an exact version (e.g., `sniffio==1.0.0`), or none at all, but found: `>=1`
+    "###);
+
+    uv_snapshot!(context.filters(), context.pin().arg("remove").arg("anyio").arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Unpinned `anyio` in: pyproject.toml
+    Unpinned `idna` in: pyproject.toml
+    "###);
+
+    uv_snapshot!(context.filters(), context.pin().arg("remove").arg("anyio"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `anyio` is not pinned in: pyproject.toml
+    "###);
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("pyproject.toml"), @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Pins cap upgrades, but not the initial resolution.
+#[test]
+fn pin_upgrade() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<=2"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Remove the constraint, and pin `anyio` below the latest version.
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+
+        [tool.uv.pins]
+        anyio = "3.7.1"
+    "#})?;
+
+    // Upgrading `anyio` respects the pin.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated anyio v2.0.0 -> v3.7.1
+    "###);
+
+    // Pins aren't part of the lockfile, so the lockfile remains up-to-date.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // A fresh resolution ignores the pin.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"anyio\"\nversion = \"4.3.0\""));
+
+    Ok(())
+}
+
+/// Global pins apply to every project, but project pins take precedence.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn pin_global() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+
+        [tool.uv.pins]
+        anyio = "3.7.1"
+    "#})?;
+
+    let xdg = context.temp_dir.child("xdg");
+
+    uv_snapshot!(context.filters(), context.pin()
+        .arg("add")
+        .arg("--global")
+        .arg("anyio==4.0.0")
+        .arg("idna==3.6")
+        .env(EnvVars::XDG_CONFIG_HOME, xdg.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pinned `anyio==4.0.0` in: xdg/uv/uv.pins.toml
+    Pinned `idna==3.6` in: xdg/uv/uv.pins.toml
+    "###);
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("xdg/uv/uv.pins.toml"), @r###"
+        anyio = "4.0.0"
+        idna = "3.6"
+        "###
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.pin()
+        .arg("list")
+        .env(EnvVars::XDG_CONFIG_HOME, xdg.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==3.7.1
+    idna==3.6
+
+    ----- stderr -----
+    "###);
+
+    // With `--no-config`, the global pins are ignored.
+    uv_snapshot!(context.filters(), context.pin()
+        .arg("list")
+        .arg("--no-config")
+        .env(EnvVars::XDG_CONFIG_HOME, xdg.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==3.7.1
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.pin()
+        .arg("remove")
+        .arg("--global")
+        .arg("anyio")
+        .env(EnvVars::XDG_CONFIG_HOME, xdg.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Unpinned `anyio` in: xdg/uv/uv.pins.toml
+    "###);
+
+    Ok(())
+}
@@ -2,9 +2,12 @@
 
 use std::env::current_dir;
 use std::fs;
+use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
+use std::process::{Command, Stdio};
 
 use anyhow::{bail, Context, Result};
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
 use indoc::indoc;
 use url::Url;
@@ -6826,6 +6829,141 @@ fn universal() -> Result<()> {
     Ok(())
 }
 
+/// Perform a universal resolution, and write a fully-evaluated output file for each platform.
+#[test]
+fn universal_split_output() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        trio ; python_version > '3.11'
+        trio ; sys_platform == 'win32'
+    "})?;
+
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--universal")
+            .arg("--no-annotate")
+            .arg("--split-output-dir")
+            .arg("out")
+            .arg("--split-python-platform")
+            .arg("linux")
+            .arg("--split-python-platform")
+            .arg("windows"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --no-annotate --split-output-dir out --split-python-platform linux --split-python-platform windows
+    attrs==23.2.0
+    cffi==1.16.0 ; implementation_name != 'pypy' and os_name == 'nt'
+    idna==3.6
+    outcome==1.3.0.post0
+    pycparser==2.21 ; implementation_name != 'pypy' and os_name == 'nt'
+    sniffio==1.3.1
+    sortedcontainers==2.4.0
+    trio==0.25.0
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    Wrote requirements for Python 3.12 on linux (x86_64) to: out/requirements-py312-linux-x86_64.txt
+    Wrote requirements for Python 3.12 on win32 (x86_64) to: out/requirements-py312-win32-x86_64.txt
+    "###
+    );
+
+    let linux = fs::read_to_string(
+        context
+            .temp_dir
+            .child("out")
+            .child("requirements-py312-linux-x86_64.txt"),
+    )?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(linux, @r###"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --no-annotate --split-output-dir out --split-python-platform linux --split-python-platform windows
+        # Evaluated for Python 3.12 on linux (x86_64)
+        attrs==23.2.0
+        idna==3.6
+        outcome==1.3.0.post0
+        sniffio==1.3.1
+        sortedcontainers==2.4.0
+        trio==0.25.0
+        "###);
+    });
+
+    let windows = fs::read_to_string(
+        context
+            .temp_dir
+            .child("out")
+            .child("requirements-py312-win32-x86_64.txt"),
+    )?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(windows, @r###"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --no-annotate --split-output-dir out --split-python-platform linux --split-python-platform windows
+        # Evaluated for Python 3.12 on win32 (x86_64)
+        attrs==23.2.0
+        cffi==1.16.0
+        idna==3.6
+        outcome==1.3.0.post0
+        pycparser==2.21
+        sniffio==1.3.1
+        sortedcontainers==2.4.0
+        trio==0.25.0
+        "###);
+    });
+
+    Ok(())
+}
+
+/// Split output files can't target a Python version below the minimum of the universal resolution.
+#[test]
+fn universal_split_output_below_minimum() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--universal")
+            .arg("--split-output-dir")
+            .arg("out")
+            .arg("--split-python-version")
+            .arg("3.8"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The split Python version `3.8` is below the minimum Python version of the universal resolution (`3.12`)
+    "###
+    );
+
+    // `--split-output-dir` requires `--universal`.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--split-output-dir")
+            .arg("out"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --universal
+
+    Usage: uv pip compile --universal --cache-dir [CACHE_DIR] --split-output-dir <DIR> --exclude-newer <EXCLUDE_NEWER> <SRC_FILE>...
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
 /// Perform a universal resolution with conflicting versions and markers.
 #[test]
 fn universal_conflicting() -> Result<()> {
@@ -11769,6 +11907,81 @@ fn python_platform() -> Result<()> {
     Ok(())
 }
 
+/// Compile for Pyodide, searching a Pyodide package index for packages that aren't published to
+/// PyPI for the platform.
+#[test]
+fn python_platform_pyodide() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a local Pyodide package index with a single wheel.
+    let index = context.temp_dir.child("pyodide");
+    let wheel = index.join("uv_pyodide_example-1.0.0-cp312-cp312-pyodide_2024_0_wasm32.whl");
+    fs_err::rename(
+        crate::common::make_wheel(&index, "uv-pyodide-example", "1.0.0")?,
+        &wheel,
+    )?;
+    index
+        .child("uv-pyodide-example")
+        .child("index.html")
+        .write_str(&format!(
+            r#"<a href="{}">uv_pyodide_example-1.0.0-cp312-cp312-pyodide_2024_0_wasm32.whl</a>"#,
+            Url::from_file_path(&wheel).unwrap()
+        ))?;
+    let index_url = Url::from_directory_path(index.path()).unwrap();
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        uv-pyodide-example
+        colorama ; sys_platform == 'win32'
+        typing-extensions ; sys_platform == 'emscripten'
+    "})?;
+
+    // The package is only available from the Pyodide package index.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--python-platform")
+        .arg("wasm32-pyodide")
+        .arg("--python-version")
+        .arg("3.12")
+        .arg("--pyodide-index-url")
+        .arg(index_url.as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform wasm32-pyodide --python-version 3.12 --pyodide-index-url file://[TEMP_DIR]/pyodide/
+    typing-extensions==4.10.0
+        # via -r requirements.in
+    uv-pyodide-example==1.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    // The Pyodide package index is only searched when resolving for Pyodide.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--python-platform")
+        .arg("linux")
+        .arg("--python-version")
+        .arg("3.12")
+        .arg("--pyodide-index-url")
+        .arg(index_url.as_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because uv-pyodide-example was not found in the package registry and you require uv-pyodide-example, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific source distribution via a Git HTTPS dependency.
 #[test]
 #[cfg(feature = "git")]
@@ -13226,3 +13439,214 @@ fn same_version_multi_index_incompatibility() -> Result<()> {
 
     Ok(())
 }
+
+/// Plugins can exclude candidates from a `--find-links` directory, not only from a registry.
+#[test]
+#[cfg(unix)]
+fn compile_plugin_filter_find_links() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // A plugin that excludes `iniconfig==2.0.0`.
+    let plugin = context.temp_dir.child("policy");
+    plugin.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "policy"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.scripts]
+        uv-plugin-policy = "policy:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    plugin
+        .child("src")
+        .child("policy")
+        .child("__init__.py")
+        .write_str(indoc! {r#"
+        import json
+        import sys
+
+
+        def main():
+            for line in sys.stdin:
+                request = json.loads(line)
+                if request["method"] == "initialize":
+                    result = {"hooks": ["filter-candidates"]}
+                else:
+                    params = request["params"]
+                    result = {
+                        "exclude": [
+                            version
+                            for version in params["versions"]
+                            if params["package"] == "iniconfig" and version == "2.0.0"
+                        ]
+                    }
+                response = {"jsonrpc": "2.0", "id": request["id"], "result": result}
+                print(json.dumps(response), flush=True)
+        "#})?;
+
+    context
+        .tool_install()
+        .arg("./policy")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    let wheels = context.temp_dir.child("wheels");
+    wheels.create_dir_all()?;
+    crate::common::make_wheel(&wheels, "iniconfig", "1.0.0")?;
+    crate::common::make_wheel(&wheels, "iniconfig", "2.0.0")?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(wheels.path())
+        .env(EnvVars::UV_PREVIEW, "1")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index
+    iniconfig==1.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Fall back to stale index metadata from the cache within `--offline-grace` if the index is
+/// unreachable.
+#[test]
+fn compile_offline_grace() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create an index that links to a local wheel, such that only the index page itself is
+    // fetched over the network.
+    let wheels = context.temp_dir.child("wheels");
+    let wheel = crate::common::make_wheel(&wheels, "iniconfig", "2.0.0")?;
+    let project = context
+        .temp_dir
+        .child("index")
+        .child("simple")
+        .child("iniconfig");
+    project.create_dir_all()?;
+    project
+        .child("index.html")
+        .write_str(&indoc::formatdoc! {r#"
+        <!DOCTYPE html>
+        <html>
+          <body>
+            <h1>Links for iniconfig</h1>
+            <a href="{}">iniconfig-2.0.0-py3-none-any.whl</a>
+          </body>
+        </html>
+    "#, Url::from_file_path(&wheel).unwrap().as_str()})?;
+
+    // Serve the index on an arbitrary port.
+    let mut server = Command::new(context.interpreter())
+        .arg("-u")
+        .arg("-m")
+        .arg("http.server")
+        .arg("0")
+        .arg("--bind")
+        .arg("127.0.0.1")
+        .arg("--directory")
+        .arg(context.temp_dir.child("index").path())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+    let mut line = String::new();
+    BufReader::new(server.stdout.take().context("stdout is piped")?).read_line(&mut line)?;
+    // e.g., `Serving HTTP on 127.0.0.1 port 8000 (http://127.0.0.1:8000/) ...`
+    let port = line
+        .split_whitespace()
+        .nth(5)
+        .context("The index server didn't report its port")?;
+    let index = format!("http://127.0.0.1:{port}");
+
+    let index_filter = regex::escape(&index);
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (index_filter.as_str(), "[INDEX]"),
+            (r"from \S+ ago", "from [AGE] ago"),
+        ])
+        .collect::<Vec<_>>();
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(filters, context.pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.in")
+        .arg("--no-header")
+        .arg("--index-url")
+        .arg(format!("{index}/simple")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // Take the index offline.
+    server.kill()?;
+    server.wait()?;
+
+    // Without a grace period, stale metadata can't be used.
+    context
+        .pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.in")
+        .arg("--no-header")
+        .arg("--index-url")
+        .arg(format!("{index}/simple"))
+        .arg("--revalidate-package")
+        .arg("iniconfig")
+        .assert()
+        .failure();
+
+    // Within the grace period, the cached metadata is used instead.
+    uv_snapshot!(filters, context.pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("requirements.in")
+        .arg("--no-header")
+        .arg("--index-url")
+        .arg(format!("{index}/simple"))
+        .arg("--revalidate-package")
+        .arg("iniconfig")
+        .arg("--offline-grace")
+        .arg("1h"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    warning: Failed to reach `[INDEX]/simple/iniconfig/`; using cached metadata for `iniconfig` from [AGE] ago
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
@@ -5,11 +5,13 @@ use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 use fs_err as fs;
 use indoc::indoc;
+use insta::assert_snapshot;
 use predicates::prelude::predicate;
 use url::Url;
 
 use crate::common::{
-    self, build_vendor_links_url, decode_token, get_bin, uv_snapshot, venv_bin_path, TestContext,
+    self, build_vendor_links_url, decode_token, get_bin, site_packages_path, uv_snapshot,
+    venv_bin_path, TestContext,
 };
 use uv_fs::Simplified;
 use uv_static::EnvVars;
@@ -316,6 +318,89 @@ dependencies = ["flask==1.0.x"]
     Ok(())
 }
 
+/// Write a structured report when a source distribution fails to build.
+#[test]
+fn build_report() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["setuptools>=40.8.0"]
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    project.child("backend.py").write_str(indoc! {r#"
+        import sys
+
+        def get_requires_for_build_wheel(config_settings=None):
+            print("Building project...")
+            print("error: failed to compile extension", file=sys.stderr)
+            sys.exit(1)
+    "#})?;
+
+    let filters = std::iter::once((r"exit code: 1", "exit status: 1"))
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+    uv_snapshot!(filters, context.pip_install()
+        .arg("./project")
+        .arg("--build-report")
+        .arg("reports"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `project @ file://[TEMP_DIR]/project`
+      ╰─▶ Build backend failed to determine requirements with `build_wheel()` (exit status: 1)
+
+          [stdout]
+          Building project...
+
+          [stderr]
+          error: failed to compile extension
+
+    hint: A build report was written to: reports/project.json
+    "###
+    );
+
+    let report = context.read("reports/project.json");
+    insta::with_settings!({
+        filters => filters,
+    }, {
+        insta::assert_snapshot!(
+            report, @r###"
+        {
+          "package": "project",
+          "version": null,
+          "distribution": "project @ file://[TEMP_DIR]/project",
+          "errors": [
+            "Failed to build `project @ file://[TEMP_DIR]/project`",
+            "Build backend failed to determine requirements with `build_wheel()` (exit status: 1)"
+          ],
+          "build_requirements": [
+            "setuptools==69.2.0"
+          ],
+          "stdout": [
+            "Building project..."
+          ],
+          "stderr": [
+            "error: failed to compile extension"
+          ]
+        }
+        "###
+        );
+    });
+
+    Ok(())
+}
+
 #[test]
 fn missing_pip() {
     uv_snapshot!(Command::new(get_bin()).arg("install"), @r###"
@@ -350,6 +435,11 @@ fn no_solution() {
       × No solution found when resolving dependencies:
       ╰─▶ Because only flask<=3.0.2 is available and flask==3.0.2 depends on werkzeug>=3.0.0, we can conclude that flask>=3.0.2 depends on werkzeug>=3.0.0.
           And because you require flask>=3.0.2 and werkzeug<1.0.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `werkzeug>=3.0.0` in `flask`
+            2. Relax the requirement `werkzeug<1.0.0` in your requirements
+            3. Add an override for `werkzeug<1.0.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 }
 
@@ -383,6 +473,54 @@ fn install_package() {
     context.assert_command("import flask").success();
 }
 
+/// Report progress as newline-delimited JSON events.
+#[test]
+fn install_progress_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--progress-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    // Summarize each event by its type and phase. Consecutive duplicates are collapsed, since the
+    // number of download progress events depends on how the response is chunked.
+    let mut events: Vec<String> = Vec::new();
+    for line in String::from_utf8(output.stderr)?
+        .lines()
+        .filter(|line| line.starts_with('{'))
+    {
+        let event: serde_json::Value = serde_json::from_str(line)?;
+        let summary = match event.get("phase").and_then(serde_json::Value::as_str) {
+            Some(phase) => format!("{} ({phase})", event["event"].as_str().unwrap_or_default()),
+            None => event["event"].as_str().unwrap_or_default().to_string(),
+        };
+        if events.last() != Some(&summary) {
+            events.push(summary);
+        }
+    }
+
+    assert_snapshot!(events.join("\n"), @r###"
+    phase-start (resolve)
+    phase-progress (resolve)
+    phase-complete (resolve)
+    phase-start (prepare)
+    download-start
+    download-progress
+    download-complete
+    phase-progress (prepare)
+    phase-complete (prepare)
+    phase-start (install)
+    phase-progress (install)
+    phase-complete (install)
+    "###);
+
+    Ok(())
+}
+
 /// Install a package from a `requirements.txt` into a virtual environment.
 #[test]
 fn install_requirements_txt() -> Result<()> {
@@ -472,6 +610,11 @@ werkzeug==3.0.1
       × No solution found when resolving dependencies:
       ╰─▶ Because flask==3.0.2 depends on click>=8.1.3 and you require click==7.0.0, we can conclude that your requirements and flask==3.0.2 are incompatible.
           And because you require flask==3.0.2, we can conclude that your requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `click==7.0.0` in your requirements
+            2. Relax the requirement `click>=8.1.3` in `flask`
+            3. Add an override for `click>=8.1.3` (via `tool.uv.override-dependencies` or `--override`)
     "###
     );
 
@@ -3097,6 +3240,66 @@ fn launcher_with_symlink() -> Result<()> {
     Ok(())
 }
 
+/// Install a package with scripts into a `--prefix` directory, staged under a `--root` directory.
+#[test]
+fn launcher_with_root() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let project_root = fs_err::canonicalize(std::env::current_dir()?.join("../.."))?;
+    let root = fs_err::canonicalize(context.temp_dir.path())?;
+
+    let filters = [
+        (r"(\d+m )?(\d+\.)?\d+(ms|s)", "[TIME]"),
+        (
+            r"simple-launcher==0\.1\.0 \(from .+\.whl\)",
+            "simple_launcher.whl",
+        ),
+    ];
+
+    // Stage the installation under the directory that contains the virtual environment, such that
+    // the installing interpreter is within the root.
+    uv_snapshot!(
+        filters,
+        context.pip_install()
+        .arg(format!("simple_launcher@{}", project_root.join("scripts/links/simple_launcher-0.1.0-py3-none-any.whl").display()))
+        .arg("--root")
+        .arg(&root)
+        .arg("--prefix")
+        .arg("/usr")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + simple_launcher.whl
+    "###
+    );
+
+    // The package is installed into the `--prefix` within the `--root`, rather than the
+    // environment.
+    let prefix = root.join("usr");
+    assert!(site_packages_path(&prefix, "python3.12")
+        .join("simple_launcher")
+        .is_dir());
+    assert!(!context.site_packages().join("simple_launcher").exists());
+
+    // The script references the interpreter's location outside of the root.
+    #[cfg(unix)]
+    {
+        let script = fs_err::read_to_string(prefix.join("bin").join("simple_launcher"))?;
+        let shebang = script.lines().next().unwrap_or_default();
+        assert!(
+            shebang.starts_with("#!/.venv/bin/python"),
+            "unexpected shebang: {shebang}"
+        );
+    }
+
+    Ok(())
+}
+
 #[test]
 fn config_settings() {
     let context = TestContext::new("3.12");
@@ -4219,19 +4422,78 @@ fn dry_run_install() -> std::result::Result<(), Box<dyn std::error::Error>> {
     Resolved 7 packages in [TIME]
     Would download 7 packages
     Would install 7 packages
-     + anyio==4.3.0
-     + certifi==2024.2.2
-     + h11==0.14.0
-     + httpcore==1.0.4
-     + httpx==0.25.1
-     + idna==3.6
-     + sniffio==1.3.1
+     + anyio==4.3.0 (wheel, [SIZE] download)
+     + certifi==2024.2.2 (wheel, [SIZE] download)
+     + h11==0.14.0 (wheel, [SIZE] download)
+     + httpcore==1.0.4 (wheel, [SIZE] download)
+     + httpx==0.25.1 (wheel, [SIZE] download)
+     + idna==3.6 (wheel, [SIZE] download)
+     + sniffio==1.3.1 (wheel, [SIZE] download)
     "###
     );
 
     Ok(())
 }
 
+/// Write a pip-compatible installation report.
+#[test]
+fn install_report() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--dry-run")
+        .arg("--report")
+        .arg("report.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + iniconfig==2.0.0 (wheel, [SIZE] download)
+    "###
+    );
+
+    // The environment varies by platform, so we only snapshot the installed distributions.
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(context.temp_dir.child("report.json"))?)?;
+    assert_eq!(report["version"], "1");
+    assert!(report["environment"]["python_full_version"].is_string());
+
+    insta::assert_snapshot!(serde_json::to_string_pretty(&report["install"])?, @r###"
+    [
+      {
+        "download_info": {
+          "archive_info": {
+            "hash": "sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+            "hashes": {
+              "sha256": "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+            }
+          },
+          "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"
+        },
+        "is_direct": false,
+        "is_yanked": false,
+        "metadata": {
+          "metadata_version": "2.1",
+          "name": "iniconfig",
+          "requires_python": ">=3.7",
+          "version": "2.0.0"
+        },
+        "requested": true
+      }
+    ]
+    "###);
+
+    // The environment should not be modified.
+    context.assert_command("import iniconfig").failure();
+
+    Ok(())
+}
+
 #[test]
 fn dry_run_install_url_dependency() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let context = TestContext::new("3.12");
@@ -4251,9 +4513,9 @@ fn dry_run_install_url_dependency() -> std::result::Result<(), Box<dyn std::erro
     Resolved 3 packages in [TIME]
     Would download 3 packages
     Would install 3 packages
-     + anyio @ https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz
-     + idna==3.6
-     + sniffio==1.3.1
+     + anyio @ https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz (source distribution, requires build)
+     + idna==3.6 (wheel, [SIZE] download)
+     + sniffio==1.3.1 (wheel, [SIZE] download)
     "###
     );
 
@@ -4303,8 +4565,7 @@ fn dry_run_uninstall_url_dependency() -> std::result::Result<(), Box<dyn std::er
     Would download 1 package
     Would uninstall 1 package
     Would install 1 package
-     - anyio==4.2.0 (from https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz)
-     + anyio==4.3.0
+     ~ anyio 4.2.0 (from https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz) -> 4.3.0 (wheel, [SIZE] download)
     "###
     );
 
@@ -4401,10 +4662,10 @@ fn dry_run_install_transitive_dependency_already_installed(
     Resolved 7 packages in [TIME]
     Would download 4 packages
     Would install 4 packages
-     + anyio==4.3.0
-     + httpx==0.25.1
-     + idna==3.6
-     + sniffio==1.3.1
+     + anyio==4.3.0 (wheel, [SIZE] download)
+     + httpx==0.25.1 (wheel, [SIZE] download)
+     + idna==3.6 (wheel, [SIZE] download)
+     + sniffio==1.3.1 (wheel, [SIZE] download)
     "###
     );
 
@@ -4455,8 +4716,7 @@ fn dry_run_install_then_upgrade() -> std::result::Result<(), Box<dyn std::error:
     Would download 1 package
     Would uninstall 1 package
     Would install 1 package
-     - httpx==0.25.0
-     + httpx==0.25.1
+     ~ httpx 0.25.0 -> 0.25.1 (wheel, [SIZE] download)
     "###
     );
 
@@ -5638,6 +5898,100 @@ fn find_links_no_binary() {
     );
 }
 
+/// Wheels that are rebuilt in (or added to) a `--find-links` directory should be picked up without
+/// `--refresh`.
+#[test]
+fn find_links_rebuilt_in_place() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.temp_dir.child("links");
+    crate::common::make_wheel(&links, "foo", "1.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0
+    "###
+    );
+
+    // Rebuild the wheel in-place, at the same version. Set the modification time explicitly, since
+    // the rebuild may otherwise land within the filesystem's timestamp resolution.
+    let wheel = crate::common::make_wheel(&links, "foo", "1.0.0")?;
+    let later =
+        filetime::FileTime::from_unix_time(filetime::FileTime::now().unix_seconds() + 10, 0);
+    filetime::set_file_mtime(&wheel, later)?;
+
+    // The rebuilt wheel is reinstalled.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ foo==1.0.0
+    "###
+    );
+
+    // Absent any further changes, the installed wheel is left as-is.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    // Add a new version to the directory, which is picked up by the next resolution.
+    crate::common::make_wheel(&links, "foo", "2.0.0")?;
+    filetime::set_file_mtime(links.path(), later)?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo>1")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - foo==1.0.0
+     + foo==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
 /// Provide valid hashes for all dependencies with `--require-hashes`.
 #[test]
 fn require_hashes() -> Result<()> {
@@ -7319,3 +7673,173 @@ fn sklearn() {
     "#
     );
 }
+
+/// Read the invalidation flags from the header of a compiled `.pyc` file (PEP 552).
+fn pyc_flags(pyc: &std::path::Path) -> Result<u32> {
+    let header = fs::read(pyc)?;
+    Ok(u32::from_le_bytes(header[4..8].try_into()?))
+}
+
+/// Select the `.pyc` invalidation mode with `--invalidation-mode`.
+#[test]
+fn compile_invalidation_mode() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.temp_dir.child("links");
+    let wheel = crate::common::make_wheel(&links, "compiled", "1.0.0")?;
+    let pyc = context
+        .site_packages()
+        .join("compiled")
+        .join("__pycache__")
+        .join("__init__.cpython-312.pyc");
+
+    // By default, `.pyc` files are invalidated by timestamp.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--compile-bytecode")
+        .arg(&wheel), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 2 files in [TIME]
+     + compiled==1.0.0 (from file://[TEMP_DIR]/links/compiled-1.0.0-py3-none-any.whl)
+    "###
+    );
+    assert_eq!(pyc_flags(&pyc)?, 0);
+
+    // Request checked hash-based `.pyc` files.
+    context
+        .pip_install()
+        .arg("--compile-bytecode")
+        .arg("--invalidation-mode")
+        .arg("checked-hash")
+        .arg("--reinstall")
+        .arg(&wheel)
+        .assert()
+        .success();
+    assert_eq!(pyc_flags(&pyc)?, 3);
+
+    // Request unchecked hash-based `.pyc` files.
+    context
+        .pip_install()
+        .arg("--compile-bytecode")
+        .arg("--invalidation-mode")
+        .arg("unchecked-hash")
+        .arg("--reinstall")
+        .arg(&wheel)
+        .assert()
+        .success();
+    assert_eq!(pyc_flags(&pyc)?, 1);
+
+    // Without an explicit mode, `SOURCE_DATE_EPOCH` implies checked hash-based `.pyc` files.
+    context
+        .pip_install()
+        .arg("--compile-bytecode")
+        .arg("--reinstall")
+        .arg(&wheel)
+        .env("SOURCE_DATE_EPOCH", "0")
+        .assert()
+        .success();
+    assert_eq!(pyc_flags(&pyc)?, 3);
+
+    // An unknown mode is rejected.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--compile-bytecode")
+        .arg("--invalidation-mode")
+        .arg("never")
+        .arg(&wheel), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'never' for '--invalidation-mode <INVALIDATION_MODE>'
+      [possible values: timestamp, checked-hash, unchecked-hash]
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Give up waiting for a cache lock held by another process after `--cache-lock-timeout`.
+#[test]
+fn install_cache_lock_timeout() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+
+    // Build the project, populating its shard in the source distribution cache.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./project"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/project)
+    "###
+    );
+
+    // Hold the shard locks, as if another process were building the project.
+    let locks = walkdir::WalkDir::new(context.cache_dir.join("sdists-v6"))
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name() == ".lock")
+        .map(|entry| uv_fs::LockedFile::acquire_blocking(entry.path(), "test"))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert!(!locks.is_empty());
+
+    // Rebuilding the project times out, naming the process that holds the lock.
+    context
+        .pip_install()
+        .arg("./project")
+        .arg("--reinstall")
+        .arg("--cache-lock-timeout")
+        .arg("1s")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Timed out after 1s waiting to acquire lock",
+        ))
+        .stderr(predicate::str::contains(format!(
+            "(held by process {})",
+            std::process::id()
+        )));
+
+    // If the lock is released within the timeout, the rebuild proceeds.
+    let release = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        drop(locks);
+    });
+    context
+        .pip_install()
+        .arg("./project")
+        .arg("--reinstall")
+        .env(EnvVars::UV_CACHE_LOCK_TIMEOUT, "5m")
+        .assert()
+        .success();
+    release.join().unwrap();
+
+    Ok(())
+}
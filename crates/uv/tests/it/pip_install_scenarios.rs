@@ -837,6 +837,11 @@ fn extra_incompatible_with_extra() {
       ╰─▶ Because only package-a[extra-c]==1.0.0 is available and package-a[extra-c]==1.0.0 depends on package-b==2.0.0, we can conclude that all versions of package-a[extra-c] depend on package-b==2.0.0.
           And because package-a[extra-b]==1.0.0 depends on package-b==1.0.0 and only package-a[extra-b]==1.0.0 is available, we can conclude that all versions of package-a[extra-b] and all versions of package-a[extra-c] are incompatible.
           And because you require package-a[extra-b] and package-a[extra-c], we can conclude that your requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `package-b==2.0.0` in `package-a[extra-c]`
+            2. Relax the requirement `package-b==1.0.0` in `package-a[extra-b]`
+            3. Add an override for `package-b==1.0.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     // Because both `extra_b` and `extra_c` are requested and they require incompatible versions of `b`, `a` cannot be installed.
@@ -888,6 +893,11 @@ fn extra_incompatible_with_root() {
       × No solution found when resolving dependencies:
       ╰─▶ Because only package-a[extra]==1.0.0 is available and package-a[extra]==1.0.0 depends on package-b==1.0.0, we can conclude that all versions of package-a[extra] depend on package-b==1.0.0.
           And because you require package-a[extra] and package-b==2.0.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `package-b==1.0.0` in `package-a[extra]`
+            2. Relax the requirement `package-b==2.0.0` in your requirements
+            3. Add an override for `package-b==2.0.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     // Because the user requested `b==2.0.0` but the requested extra requires `b==1.0.0`, the dependencies cannot be satisfied.
@@ -1196,6 +1206,11 @@ fn transitive_incompatible_with_root_version() {
       × No solution found when resolving dependencies:
       ╰─▶ Because only package-a==1.0.0 is available and package-a==1.0.0 depends on package-b==2.0.0, we can conclude that all versions of package-a depend on package-b==2.0.0.
           And because you require package-a and package-b==1.0.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `package-b==1.0.0` in your requirements
+            2. Relax the requirement `package-b==2.0.0` in `package-a`
+            3. Add an override for `package-b==2.0.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     assert_not_installed(
@@ -1254,6 +1269,11 @@ fn transitive_incompatible_with_transitive() {
       ╰─▶ Because only package-a==1.0.0 is available and package-a==1.0.0 depends on package-c==1.0.0, we can conclude that all versions of package-a depend on package-c==1.0.0.
           And because package-b==1.0.0 depends on package-c==2.0.0 and only package-b==1.0.0 is available, we can conclude that all versions of package-a and all versions of package-b are incompatible.
           And because you require package-a and package-b, we can conclude that your requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `package-c==1.0.0` in `package-a`
+            2. Relax the requirement `package-c==2.0.0` in `package-b`
+            3. Add an override for `package-c==2.0.0` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     assert_not_installed(
@@ -1836,6 +1856,11 @@ fn local_transitive_greater_than() {
       × No solution found when resolving dependencies:
       ╰─▶ Because only package-a==1.0.0 is available and package-a==1.0.0 depends on package-b>2.0.0, we can conclude that all versions of package-a depend on package-b>2.0.0.
           And because you require package-a and package-b==2.0.0+foo, we can conclude that your requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `package-b>2.0.0` in `package-a`
+            2. Relax the requirement `package-b==2.0.0+foo` in your requirements
+            3. Add an override for `package-b==2.0.0+foo` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     assert_not_installed(
@@ -1948,6 +1973,11 @@ fn local_transitive_less_than() {
       × No solution found when resolving dependencies:
       ╰─▶ Because only package-a==1.0.0 is available and package-a==1.0.0 depends on package-b<2.0.0, we can conclude that all versions of package-a depend on package-b<2.0.0.
           And because you require package-a and package-b==2.0.0+foo, we can conclude that your requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `package-b<2.0.0` in `package-a`
+            2. Relax the requirement `package-b==2.0.0+foo` in your requirements
+            3. Add an override for `package-b==2.0.0+foo` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     assert_not_installed(
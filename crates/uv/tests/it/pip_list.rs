@@ -273,6 +273,127 @@ fn list_outdated_index() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn list_uptodate() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==3.0.0")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    uv_snapshot!(context.pip_list().arg("--uptodate"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version
+    ------- -------
+    idna    3.6
+    sniffio 1.3.1
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.pip_list().arg("--uptodate").arg("--outdated"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--uptodate' cannot be used with '--outdated'
+
+    Usage: uv pip list --cache-dir [CACHE_DIR] --uptodate --exclude-newer <EXCLUDE_NEWER>
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Show the newer versions that can't be installed, along with the constraint that blocks them.
+#[test]
+fn list_uptodate_blocked() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("numpy==1.26.4")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + numpy==1.26.4
+    "###
+    );
+
+    // The newest version is a pre-release, which isn't allowed by default.
+    uv_snapshot!(context.pip_list().arg("--uptodate"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Blocked upgrade
+    ------- ------- ---------------------
+    numpy   1.26.4  2.0.0b1 (pre-release)
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.pip_list().arg("--uptodate").arg("--format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"numpy","version":"1.26.4","latest_version":"1.26.4","latest_filetype":"wheel","blocked_version":"2.0.0b1","blocked_by":"pre-release"}]
+
+    ----- stderr -----
+    "###
+    );
+
+    // Allowing pre-releases removes the constraint.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(r#"prerelease = "allow""#)?;
+
+    uv_snapshot!(context.pip_list().arg("--outdated"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Latest  Type
+    ------- ------- ------- -----
+    numpy   1.26.4  2.0.0b1 wheel
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn list_editable() {
     let context = TestContext::new("3.12");
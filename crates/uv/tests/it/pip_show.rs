@@ -502,6 +502,7 @@ fn show_files() {
       requests-2.31.0.dist-info/REQUESTED
       requests-2.31.0.dist-info/WHEEL
       requests-2.31.0.dist-info/top_level.txt
+      requests-2.31.0.dist-info/uv_index.json
       requests/__init__.py
       requests/__version__.py
       requests/_internal_utils.py
@@ -524,3 +525,83 @@ fn show_files() {
     ----- stderr -----
     "#);
 }
+
+/// Show the installed files in a given category, e.g., the headers and shared data files
+/// installed from a wheel's `.data` directory.
+#[test]
+#[cfg(not(windows))]
+fn show_files_category() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    project.child("setup.py").write_str(indoc! {r#"
+        from setuptools import setup
+
+        setup(
+            name="project",
+            version="0.1.0",
+            py_modules=["project"],
+            headers=["project.h"],
+            data_files=[("share/man/man1", ["project.1"])],
+        )
+    "#})?;
+    project.child("project.py").write_str("")?;
+    project.child("project.h").write_str("")?;
+    project.child("project.1").write_str("")?;
+
+    context.pip_install().arg("./project").assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_show().arg("project").arg("--category").arg("headers"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: project
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Files:
+      ../../../include/site/python3.12/project/project.h
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_show().arg("project").arg("--category").arg("data"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: project
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Files:
+      ../../../share/man/man1/project.1
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_show().arg("project").arg("--category").arg("scripts"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: project
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Files:
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
@@ -1057,7 +1057,7 @@ fn warn_on_yanked_dry_run() -> Result<()> {
     Resolved 1 package in [TIME]
     Would download 1 package
     Would install 1 package
-     + colorama==0.4.2
+     + colorama==0.4.2 (wheel, [SIZE] download)
     warning: `colorama==0.4.2` is yanked (reason: "Bad build, missing files, will not install")
     "###
     );
@@ -5665,3 +5665,146 @@ fn semicolon_no_space() -> Result<()> {
 
     Ok(())
 }
+
+/// Install the `pip` dependencies of a conda environment file.
+#[test]
+fn sync_from_conda() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let environment_yml = context.temp_dir.child("environment.yml");
+    environment_yml.write_str(indoc! {r#"
+        name: example
+        channels:
+          - conda-forge
+        dependencies:
+          - python=3.12
+          - pip
+          - pip:
+            - iniconfig==2.0.0  # Installed by uv.
+            - "tomli==2.0.1"
+    "#})?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("--from-conda")
+        .arg("environment.yml")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + tomli==2.0.1
+    "###
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
+/// Reject unsupported YAML in a conda environment file.
+#[test]
+fn sync_from_conda_flow_list() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let environment_yml = context.temp_dir.child("environment.yml");
+    environment_yml.write_str(indoc! {r"
+        name: example
+        dependencies:
+          - python=3.12
+          - pip: [iniconfig]
+    "})?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("--from-conda")
+        .arg("environment.yml"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `environment.yml`
+      Caused by: Flow-style lists (e.g., `[a, b]`) are not supported at line 4
+    "###
+    );
+
+    Ok(())
+}
+
+/// Packages installed by conda should be neither removed nor replaced.
+#[test]
+fn sync_from_conda_preserve_conda_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Simulate a conda-installed package, by recording its metadata in `conda-meta`.
+    let dist_info = context.site_packages().join("tomli-2.0.1.dist-info");
+    fs::create_dir_all(&dist_info)?;
+    fs::write(
+        dist_info.join("METADATA"),
+        "Metadata-Version: 2.1\nName: tomli\nVersion: 2.0.1\n",
+    )?;
+    fs::write(dist_info.join("RECORD"), "")?;
+    let relative = dist_info
+        .strip_prefix(&context.venv)?
+        .to_string_lossy()
+        .replace('\\', "/");
+    let conda_meta = context.venv.child("conda-meta");
+    conda_meta.create_dir_all()?;
+    conda_meta
+        .child("tomli-2.0.1-pyhd8ed1ab_0.json")
+        .write_str(&format!(
+            r#"{{"name": "tomli", "version": "2.0.1", "files": ["{relative}/METADATA", "{relative}/RECORD"]}}"#
+        ))?;
+
+    let environment_yml = context.temp_dir.child("environment.yml");
+    environment_yml.write_str(indoc! {r"
+        dependencies:
+          - tomli
+          - pip:
+            - iniconfig==2.0.0
+    "})?;
+
+    // The conda-managed package isn't removed, despite being absent from the `pip` section.
+    uv_snapshot!(context.pip_sync()
+        .arg("--from-conda")
+        .arg("environment.yml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The conda-managed package can't be replaced.
+    environment_yml.write_str(indoc! {r"
+        dependencies:
+          - tomli
+          - pip:
+            - iniconfig==2.0.0
+            - tomli==2.0.0
+    "})?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("--from-conda")
+        .arg("environment.yml"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: `tomli==2.0.1` is managed by conda, and can't be replaced with `tomli==2.0.0`. Use `conda install` to change it, or pass `--reinstall-package tomli` to replace it anyway.
+    "###
+    );
+
+    Ok(())
+}
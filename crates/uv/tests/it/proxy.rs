@@ -0,0 +1,301 @@
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Stdio};
+
+use anyhow::{Context, Result};
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+use insta::assert_snapshot;
+use reqwest::blocking::Client;
+use reqwest::header::{ACCEPT, CONTENT_LENGTH, LOCATION};
+use reqwest::StatusCode;
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use uv_static::EnvVars;
+
+use crate::common::{make_wheel, uv_snapshot, TestContext};
+
+/// A running `uv proxy`, which is stopped on drop.
+struct Proxy {
+    child: Child,
+    /// The base URL of the proxy, e.g., `http://127.0.0.1:12345`.
+    url: String,
+}
+
+impl Proxy {
+    /// Start a proxy for the given index on an arbitrary port, waiting until it's accepting
+    /// connections.
+    fn start(context: &TestContext, index: &Url) -> Result<Self> {
+        let mut child = context
+            .command()
+            .arg("proxy")
+            .arg("--port")
+            .arg("0")
+            .arg("--index-url")
+            .arg(index.as_str())
+            .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let mut stderr = BufReader::new(child.stderr.take().context("stderr is piped")?);
+        let mut line = String::new();
+        let url = loop {
+            line.clear();
+            if stderr.read_line(&mut line)? == 0 {
+                anyhow::bail!("The proxy exited before serving the index");
+            }
+            if let Some(url) = line.trim().strip_prefix("Serving the simple index at ") {
+                break url
+                    .trim_end_matches('/')
+                    .trim_end_matches("/simple")
+                    .to_string();
+            }
+        };
+
+        // Drain the remaining output, such that the proxy never blocks on a full pipe.
+        std::thread::spawn(move || std::io::copy(&mut stderr, &mut std::io::sink()));
+
+        Ok(Self { child, url })
+    }
+}
+
+impl Drop for Proxy {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Create a PEP 503 index with a single wheel, `iniconfig==2.0.0`, listed with the given hash (or
+/// its actual hash, if `None`).
+fn index(context: &TestContext, sha256: Option<&str>) -> Result<(Url, Vec<u8>)> {
+    let wheels = context.temp_dir.child("wheels");
+    wheels.create_dir_all()?;
+    let wheel = make_wheel(&wheels, "iniconfig", "2.0.0")?;
+    let contents = fs_err::read(&wheel)?;
+    let sha256 = sha256.map_or_else(
+        || format!("{:x}", Sha256::digest(&contents)),
+        ToString::to_string,
+    );
+
+    let root = context.temp_dir.child("index");
+    root.child("iniconfig")
+        .child("index.html")
+        .write_str(&formatdoc! {r#"
+        <!DOCTYPE html>
+        <html>
+          <body>
+            <h1>Links for iniconfig</h1>
+            <a href="{}#sha256={sha256}" data-requires-python=">=3.7">iniconfig-2.0.0-py3-none-any.whl</a>
+          </body>
+        </html>
+    "#, Url::from_file_path(&wheel).unwrap()})?;
+
+    Ok((Url::from_directory_path(root.path()).unwrap(), contents))
+}
+
+/// Filter the port of the proxy, and the digests of the upstream URLs.
+fn filters(context: &TestContext, proxy: &Proxy) -> Vec<(String, String)> {
+    [
+        (regex::escape(&proxy.url), "[PROXY]".to_string()),
+        (
+            r"/files/iniconfig/[0-9a-f]+/".to_string(),
+            "/files/iniconfig/[DIGEST]/".to_string(),
+        ),
+        (r"[0-9a-f]{64}".to_string(), "[SHA256]".to_string()),
+    ]
+    .into_iter()
+    .chain(
+        context
+            .filters()
+            .into_iter()
+            .map(|(a, b)| (a.to_string(), b.to_string())),
+    )
+    .collect()
+}
+
+#[test]
+fn proxy_project_page() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let (index, _) = index(&context, None)?;
+    let proxy = Proxy::start(&context, &index)?;
+    let client = Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()?;
+    let filters = filters(&context, &proxy);
+
+    // Project names are normalized, per PEP 503.
+    let response = client
+        .get(format!("{}/simple/IniConfig", proxy.url))
+        .send()?;
+    assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(response.headers()[LOCATION], "/simple/iniconfig/");
+
+    // The HTML page links to the proxy, rather than the upstream index.
+    let response = client
+        .get(format!("{}/simple/iniconfig/", proxy.url))
+        .send()?
+        .error_for_status()?;
+    insta::with_settings!({
+        filters => filters.iter().map(|(a, b)| (a.as_str(), b.as_str())).collect::<Vec<_>>(),
+    }, {
+        assert_snapshot!(response.text()?, @r###"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.0">
+            <title>Links for iniconfig</title>
+          </head>
+          <body>
+            <h1>Links for iniconfig</h1>
+            <a href="/files/iniconfig/[DIGEST]/iniconfig-2.0.0-py3-none-any.whl#sha256=[SHA256]" data-requires-python="&gt;=3.7">iniconfig-2.0.0-py3-none-any.whl</a><br />
+          </body>
+        </html>
+        "###);
+    });
+
+    // As does the JSON page, if requested.
+    let response = client
+        .get(format!("{}/simple/iniconfig/", proxy.url))
+        .header(ACCEPT, "application/vnd.pypi.simple.v1+json")
+        .send()?
+        .error_for_status()?;
+    let page = serde_json::from_str::<serde_json::Value>(&response.text()?)?;
+    assert_eq!(page["name"], "iniconfig");
+    assert_eq!(page["versions"], serde_json::json!(["2.0.0"]));
+    assert_eq!(
+        page["files"][0]["filename"],
+        "iniconfig-2.0.0-py3-none-any.whl"
+    );
+
+    // Unknown projects aren't found.
+    let response = client.get(format!("{}/simple/flask/", proxy.url)).send()?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[test]
+fn proxy_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let (index, contents) = index(&context, None)?;
+    let proxy = Proxy::start(&context, &index)?;
+    let client = Client::new();
+
+    // Files can only be served once they're listed on a project page.
+    let page = client
+        .get(format!("{}/simple/iniconfig/", proxy.url))
+        .header(ACCEPT, "application/vnd.pypi.simple.v1+json")
+        .send()?
+        .error_for_status()?
+        .text()?;
+    let page = serde_json::from_str::<serde_json::Value>(&page)?;
+    let href = page["files"][0]["url"]
+        .as_str()
+        .context("the file should have a URL")?
+        .to_string();
+    let file = format!("{}{href}", proxy.url);
+
+    // A `HEAD` request returns the size of the file, but no body.
+    let response = client.head(&file).send()?.error_for_status()?;
+    assert_eq!(
+        response.headers()[CONTENT_LENGTH],
+        contents.len().to_string().as_str()
+    );
+    assert!(response.bytes()?.is_empty());
+
+    let response = client.get(&file).send()?.error_for_status()?;
+    assert_eq!(response.bytes()?.as_ref(), contents.as_slice());
+
+    // Once downloaded, the file is served from the cache, even if it's removed upstream.
+    fs_err::remove_dir_all(context.temp_dir.child("wheels"))?;
+    let response = client.get(&file).send()?.error_for_status()?;
+    assert_eq!(response.bytes()?.as_ref(), contents.as_slice());
+
+    // Unlisted files aren't found.
+    let response = client
+        .get(format!(
+            "{}/files/iniconfig/0000/iniconfig-1.0.0-py3-none-any.whl",
+            proxy.url
+        ))
+        .send()?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+/// Install a package through the proxy.
+#[test]
+fn proxy_install() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let (index, _) = index(&context, None)?;
+    let proxy = Proxy::start(&context, &index)?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg(format!("{}/simple", proxy.url))
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// A file that doesn't match the hash on the upstream index is never served, or cached.
+#[test]
+fn proxy_hash_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let (index, _) = index(&context, Some(&"0".repeat(64)))?;
+    let proxy = Proxy::start(&context, &index)?;
+    let client = Client::new();
+    let filters = filters(&context, &proxy);
+
+    let page = client
+        .get(format!("{}/simple/iniconfig/", proxy.url))
+        .header(ACCEPT, "application/vnd.pypi.simple.v1+json")
+        .send()?
+        .error_for_status()?
+        .text()?;
+    let page = serde_json::from_str::<serde_json::Value>(&page)?;
+    let href = page["files"][0]["url"]
+        .as_str()
+        .context("the file should have a URL")?
+        .to_string();
+
+    let response = client.get(format!("{}{href}", proxy.url)).send()?;
+    assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+    insta::with_settings!({
+        filters => filters.iter().map(|(a, b)| (a.as_str(), b.as_str())).collect::<Vec<_>>(),
+    }, {
+        assert_snapshot!(response.text()?, @r###"
+        Hash mismatch for `file://[TEMP_DIR]/wheels/iniconfig-2.0.0-py3-none-any.whl`
+
+        Expected:
+          sha256:[SHA256]
+
+        Computed:
+          sha256:[SHA256]
+        "###);
+    });
+
+    // Nothing is left in the cache.
+    let files = context.cache_dir.child("proxy-v0");
+    let cached = walkdir::WalkDir::new(files.path())
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .count();
+    assert_eq!(cached, 0);
+
+    Ok(())
+}
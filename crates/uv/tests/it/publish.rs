@@ -113,6 +113,35 @@ fn missing_trusted_publishing_permission() {
     );
 }
 
+/// Emulate a missing `id_tokens` configuration on GitLab CI.
+#[test]
+fn gitlab_missing_id_token() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("--trusted-publishing")
+        .arg("always")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        // Emulate CI
+        .env(EnvVars::GITLAB_CI, "true")
+        .env_remove(EnvVars::GITHUB_ACTIONS)
+        // Just to make sure
+        .env_remove(EnvVars::PYPI_ID_TOKEN), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 1 file to https://test.pypi.org/legacy/
+    error: Failed to obtain token for trusted publishing
+      Caused by: Environment variable PYPI_ID_TOKEN not set, is an ID token configured via `id_tokens` for the job?
+    "###
+    );
+}
+
 /// Check the error when there are no credentials provided on GitHub Actions. Is it an incorrect
 /// trusted publishing configuration?
 #[test]
@@ -144,22 +173,22 @@ fn no_credentials() {
     );
 }
 
-/// Hint people that it's not `--skip-existing` but `--check-url`.
+/// `--skip-existing` requires a registry for which the index can be inferred.
 #[test]
-fn skip_existing_redirect() {
+fn skip_existing_unknown_registry() {
     let context = TestContext::new("3.12");
 
     uv_snapshot!(context.filters(), context.publish()
         .arg("--skip-existing")
         .arg("--publish-url")
-        .arg("https://test.pypi.org/legacy/"), @r###"
+        .arg("https://example.com/upload/"), @r###"
     success: false
     exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
     warning: `uv publish` is experimental and may change without warning
-    error: `uv publish` does not support `--skip-existing` because there is not a reliable way to identify when an upload fails due to an existing distribution. Instead, use `--check-url` to provide the URL to the simple API for your index. uv will check the index for existing distributions before attempting uploads.
+    error: Unable to determine how to check https://example.com/upload/ for existing files; use `--check-url` to provide the URL of the simple API for your index instead
     "###
     );
 }
@@ -1,3 +1,4 @@
+use assert_cmd::prelude::*;
 use assert_fs::prelude::PathChild;
 use assert_fs::{fixture::FileWriteStr, prelude::PathCreateDir};
 use fs_err::remove_dir_all;
@@ -560,3 +561,39 @@ fn python_find_unsupported_version() {
     error: Invalid version request: Python <3.13 does not support free-threading but 3.12t was requested.
     "###);
 }
+
+#[test]
+fn python_refresh() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    // Query an interpreter, to populate the cache.
+    context.python_find().arg("3.12").assert().success();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"Invalidated \d+ cached interpreters?",
+            "Invalidated [N] cached interpreters",
+        )])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.python_refresh(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Invalidated [N] cached interpreters
+    "###);
+
+    // The cache is now empty.
+    uv_snapshot!(context.filters(), context.python_refresh(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cached interpreters found
+    "###);
+}
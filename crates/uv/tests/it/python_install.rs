@@ -1,13 +1,15 @@
 use std::{path::Path, process::Command};
 
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::{
     assert::PathAssert,
     prelude::{FileTouch, PathChild},
 };
 use predicates::prelude::predicate;
 use uv_fs::Simplified;
+use uv_static::EnvVars;
 
-use crate::common::{uv_snapshot, TestContext};
+use crate::common::{uv_snapshot, venv_to_interpreter, TestContext};
 
 #[test]
 fn python_install() {
@@ -431,6 +433,130 @@ fn python_install_invalid_request() {
     "###);
 }
 
+#[test]
+fn python_uninstall_migrate() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12.5").arg("3.12.6"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 2 versions in [TIME]
+     + cpython-3.12.5-[PLATFORM]
+     + cpython-3.12.6-[PLATFORM]
+    "###);
+
+    // Create an environment with the older patch version.
+    let venv = context.temp_dir.child("venv");
+    context
+        .venv()
+        .arg(venv.as_os_str())
+        .arg("--python")
+        .arg("3.12.5")
+        .arg("--python-preference")
+        .arg("only-managed")
+        .env(
+            EnvVars::UV_PYTHON_INSTALL_DIR,
+            context.temp_dir.join("managed"),
+        )
+        .assert()
+        .success();
+
+    // Uninstalling it should re-point the environment to the remaining patch version.
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.12.5").arg("--migrate").env(EnvVars::VIRTUAL_ENV, venv.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.12.5
+    Migrated active virtual environment to Python 3.12.6: venv
+    Uninstalled Python 3.12.5 in [TIME]
+     - cpython-3.12.5-[PLATFORM]
+    "###);
+
+    // The environment should remain usable.
+    uv_snapshot!(context.filters(), Command::new(venv_to_interpreter(&venv))
+        .arg("-c").arg("import platform; print(platform.python_version())"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12.6
+
+    ----- stderr -----
+    "###);
+
+    // If no compatible installation remains, the environment is reported instead.
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.12.6").arg("--migrate").env(EnvVars::VIRTUAL_ENV, venv.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.12.6
+    warning: The active virtual environment at `venv` uses cpython-3.12.6-[PLATFORM], and no other Python 3.12 installation is available to migrate it to; recreate the environment with a different Python version
+    Uninstalled Python 3.12.6 in [TIME]
+     - cpython-3.12.6-[PLATFORM]
+    "###);
+}
+
+#[test]
+fn python_install_registry() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
+
+    // Registration is only supported on Windows; elsewhere, the installation proceeds regardless.
+    #[cfg(not(windows))]
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12.6").arg("--registry"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Registering Python installations in the Windows registry is only supported on Windows
+    Installed Python 3.12.6 in [TIME]
+     + cpython-3.12.6-[PLATFORM]
+    "###);
+
+    #[cfg(windows)]
+    {
+        uv_snapshot!(context.filters(), context.python_install().arg("3.12.6").arg("--registry"), @r###"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        Installed Python 3.12.6 in [TIME]
+         + cpython-3.12.6-[PLATFORM]
+        "###);
+
+        // The installation is registered under the `Astral` company, keyed by the installation key.
+        let key = fs_err::read_dir(context.temp_dir.join("managed"))
+            .unwrap()
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .find(|name| name.starts_with("cpython-3.12.6"))
+            .unwrap();
+        let registration = format!(r"HKCU\Software\Python\Astral\{key}");
+        Command::new("reg")
+            .arg("query")
+            .arg(&registration)
+            .arg("/v")
+            .arg("SysVersion")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("3.12"));
+
+        // Uninstalling the version removes the registration.
+        context.python_uninstall().arg("3.12.6").assert().success();
+        Command::new("reg")
+            .arg("query")
+            .arg(&registration)
+            .assert()
+            .failure();
+    }
+}
+
 fn read_link_path(path: &Path) -> String {
     if cfg!(unix) {
         path.read_link()
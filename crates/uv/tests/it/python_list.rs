@@ -0,0 +1,87 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use insta::assert_snapshot;
+use serde_json::Value;
+
+use crate::common::TestContext;
+
+/// Read the JSON output of `uv python list --json`.
+fn entries(output: &[u8]) -> Result<Vec<Value>> {
+    Ok(serde_json::from_slice::<Vec<Value>>(output)?)
+}
+
+#[test]
+fn python_list_json_download() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .python_list()
+        .arg("--all-platforms")
+        .arg("--all-versions")
+        .arg("--json")
+        .assert()
+        .success();
+    let entries = entries(&output.get_output().stdout)?;
+
+    // Downloads include the URL and hash of the archive.
+    let download = entries
+        .iter()
+        .find(|entry| {
+            entry["key"] == "cpython-3.13.0-linux-x86_64-gnu" && entry["source"] == "download"
+        })
+        .expect("the download should be listed");
+    assert_snapshot!(serde_json::to_string_pretty(download)?, @r###"
+    {
+      "key": "cpython-3.13.0-linux-x86_64-gnu",
+      "version": "3.13.0",
+      "implementation": "cpython",
+      "os": "linux",
+      "arch": "x86_64",
+      "libc": "gnu",
+      "variant": "default",
+      "source": "download",
+      "path": null,
+      "symlink": null,
+      "url": "https://github.com/indygreg/python-build-standalone/releases/download/20241016/cpython-3.13.0%2B20241016-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz",
+      "sha256": "b5e74d1e16402b633c6f04519618231fc0dbae7d2f9e4b1ac17c294cc3d3d076",
+      "size": null,
+      "end_of_life": "2029-10"
+    }
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn python_list_json_installed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .python_list()
+        .arg("--only-installed")
+        .arg("--json")
+        .assert()
+        .success();
+    let entries = entries(&output.get_output().stdout)?;
+
+    // Installed versions include the path to the interpreter, but no download metadata.
+    let installed = entries
+        .iter()
+        .find(|entry| {
+            entry["version"]
+                .as_str()
+                .is_some_and(|version| version.starts_with("3.12."))
+        })
+        .expect("the test interpreter should be listed");
+    assert_eq!(installed["source"], "system");
+    assert!(installed["path"].is_string());
+    assert!(installed["url"].is_null());
+    assert!(installed["sha256"].is_null());
+    assert!(installed["size"].is_null());
+    assert_eq!(installed["end_of_life"], "2028-10");
+
+    // No downloads are listed.
+    assert!(entries.iter().all(|entry| entry["source"] != "download"));
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn query_dependents() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [project.optional-dependencies]
+        web = ["idna==3.6"]
+    "#})?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(indoc! {r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "anyio"
+        version = "3.7.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8296b8b9ee8ad8209c4/anyio-3.7.0.tar.gz", hash = "sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce", size = 142737 }
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426 }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+        ]
+
+        [package.optional-dependencies]
+        web = [
+            { name = "idna" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "anyio", specifier = "==3.7.0" },
+            { name = "idna", marker = "extra == 'web'", specifier = "==3.6" },
+        ]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372 }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.query().arg("dependents").arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"schema_version":1,"query":"dependents","result":{"package":"idna","dependents":[{"name":"anyio","version":"3.7.0","extra":null,"group":null},{"name":"project","version":"0.1.0","extra":"web","group":null}]}}
+
+    ----- stderr -----
+    "###);
+
+    // Querying a package that isn't in the lockfile is an error, reported as JSON.
+    uv_snapshot!(context.filters(), context.query().arg("dependents").arg("flask"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {"schema_version":1,"query":"dependents","error":{"message":"Package `flask` was not found in the lockfile","causes":[]}}
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn query_lock_status_missing() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.query().arg("lock-status"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"schema_version":1,"query":"lock-status","result":{"lockfile":"[TEMP_DIR]/uv.lock","status":"missing"}}
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(unix)]
+fn query_interpreter() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.query().arg("interpreter"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"schema_version":1,"query":"interpreter","result":{"executable":"[VENV]/bin/python3","sys_prefix":"[VENV]/","version":"3.12.[X]","implementation":"cpython","virtualenv":true,"requires_python":">=3.12","satisfies_requires_python":true}}
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::RegistryClientBuilder;
+use uv_configuration::{
+    BuildOptions, Concurrency, ConfigSettings, Constraints, IndexStrategy, LowerBound,
+    SourceStrategy,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution_types::{
+    DependencyMetadata, Index, IndexCapabilities, IndexLocations, IndexUrl,
+};
+use uv_git::GitResolver;
+use uv_install_wheel::linker::LinkMode;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::VerbatimUrl;
+use uv_platform_tags::TagPreferences;
+use uv_pypi_types::{Requirement, VerbatimParsedUrl};
+use uv_python::Interpreter;
+use uv_resolver::{FlatIndex, InMemoryIndex, Resolver};
+use uv_types::{BuildIsolation, HashStrategy, InFlight};
+
+use crate::common::{make_wheel, TestContext};
+
+/// Resolve requirements against an index provided to the [`Resolver::builder`], rather than the
+/// indexes configured on the client.
+#[test]
+fn builder_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a local index that serves a version of `iniconfig` that doesn't exist on PyPI.
+    let root = context.temp_dir.child("simple-html");
+    let iniconfig = root.child("iniconfig");
+    iniconfig.create_dir_all()?;
+    let wheel = make_wheel(&iniconfig, "iniconfig", "9.9.9")?;
+    iniconfig.child("index.html").write_str(&formatdoc! {r#"
+        <!DOCTYPE html>
+        <html>
+          <body>
+            <h1>Links for iniconfig</h1>
+            <a href="{}">iniconfig-9.9.9-py3-none-any.whl</a>
+          </body>
+        </html>
+    "#, Url::from_file_path(&wheel).unwrap().as_str()})?;
+
+    let cache = Cache::from_path(context.cache_dir.path()).init()?;
+    let interpreter = Interpreter::query(context.interpreter(), &cache)?;
+
+    // The client is configured to use PyPI.
+    let client = RegistryClientBuilder::new(cache.clone()).build();
+
+    let index_locations = IndexLocations::default();
+    let flat_index = FlatIndex::default();
+    let dependency_metadata = DependencyMetadata::default();
+    let source_index = InMemoryIndex::default();
+    let git = GitResolver::default();
+    let capabilities = IndexCapabilities::default();
+    let in_flight = InFlight::default();
+    let config_settings = ConfigSettings::default();
+    let build_options = BuildOptions::default();
+    let tag_preferences = TagPreferences::default();
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        Constraints::default(),
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        &source_index,
+        &git,
+        &capabilities,
+        &in_flight,
+        IndexStrategy::default(),
+        &config_settings,
+        BuildIsolation::Isolated,
+        LinkMode::default(),
+        &build_options,
+        &HashStrategy::None,
+        None,
+        LowerBound::Warn,
+        SourceStrategy::default(),
+        Concurrency::default(),
+        &tag_preferences,
+    );
+
+    let index = IndexUrl::from(VerbatimUrl::from_absolute_path(root.path())?);
+    let requirement = uv_pep508::Requirement::<VerbatimParsedUrl>::from_str("iniconfig==9.9.9")?;
+
+    let resolution = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(
+            Resolver::builder(&client, &build_dispatch)
+                .index(Index::from_index_url(index))
+                .resolve([Requirement::from(requirement)]),
+        )?;
+
+    let name = PackageName::from_str("iniconfig")?;
+    assert_eq!(
+        resolution.versions(&name).into_iter().collect::<Vec<_>>(),
+        [&Version::new([9, 9, 9])]
+    );
+
+    Ok(())
+}
@@ -11,7 +11,7 @@ use std::path::Path;
 use uv_python::PYTHON_VERSION_FILENAME;
 use uv_static::EnvVars;
 
-use crate::common::{copy_dir_all, uv_snapshot, TestContext};
+use crate::common::{copy_dir_all, uv_snapshot, venv_bin_path, TestContext};
 
 #[test]
 fn run_with_python_version() -> Result<()> {
@@ -1611,6 +1611,76 @@ fn run_empty_requirements_txt() -> Result<()> {
     Ok(())
 }
 
+/// Record the `--with` requirements to an overlay file, then restore them via `--with-overlay`.
+#[test]
+fn run_with_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let overlay = context.temp_dir.child("overlay.txt");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("--with-lock")
+        .arg("overlay.txt")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    assert_snapshot!(fs_err::read_to_string(&overlay)?, @r###"
+    # This file was autogenerated by uv to record the requirements of an ephemeral environment.
+    # To restore the environment, run: uv run --with-overlay overlay.txt
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "###);
+
+    // Restoring the overlay should reuse the same environment.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with-overlay")
+        .arg("overlay.txt")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    // Recording an overlay requires additional requirements.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with-lock")
+        .arg("overlay.txt")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--with-lock` requires additional requirements to be provided via `--with`, `--with-editable`, or `--with-requirements`
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn run_requirements_txt() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -3044,3 +3114,207 @@ fn run_with_not_existing_env_file() -> Result<()> {
 
     Ok(())
 }
+
+/// Run a command in a container, using a stub runtime that records the arguments it receives.
+#[cfg(unix)]
+#[test]
+fn run_container() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#
+    })?;
+
+    context.lock().assert().success();
+
+    // `--container` requires `--isolated`, as the project environment isn't used.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview")
+        .arg("--container")
+        .arg("python:3.12")
+        .arg("python")
+        .arg("-c")
+        .arg("print('hello')"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --isolated
+
+    Usage: uv run --cache-dir [CACHE_DIR] --container <IMAGE> --isolated --exclude-newer <EXCLUDE_NEWER> <COMMAND>
+
+    For more information, try '--help'.
+    "###);
+
+    // A missing runtime is reported as such.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview")
+        .arg("--isolated")
+        .arg("--container")
+        .arg("python:3.12")
+        .arg("python")
+        .arg("-c")
+        .arg("print('hello')")
+        .env(EnvVars::UV_CONTAINER_RUNTIME, "uv-missing-runtime"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Running in container python:3.12 with uv-missing-runtime
+    error: Failed to run `uv-missing-runtime`; is it installed and on the `PATH`? Use `UV_CONTAINER_RUNTIME` to select a different container runtime
+      Caused by: No such file or directory (os error 2)
+    "###);
+
+    // Use a runtime that writes its arguments to a file, one per line.
+    let runtime = context.temp_dir.child("runtime");
+    runtime.write_str(indoc! { r#"
+        #!/bin/sh
+        printf '%s\n' "$@" > "$(dirname "$0")/args.txt"
+        "#
+    })?;
+    fs_err::set_permissions(&runtime, std::fs::Permissions::from_mode(0o755))?;
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview")
+        .arg("--isolated")
+        .arg("--container")
+        .arg("python:3.12")
+        .arg("python")
+        .arg("-c")
+        .arg("print('hello')")
+        .env(EnvVars::UV_CONTAINER_RUNTIME, runtime.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Running in container python:3.12 with [TEMP_DIR]/runtime
+    "###);
+
+    let args = context.read("args.txt");
+    let args = args.lines().collect::<Vec<_>>();
+
+    // The workspace is mounted, and the image is run with the entrypoint script.
+    let project = format!("{}:/project", context.temp_dir.path().display());
+    assert!(args
+        .windows(2)
+        .any(|window| window == ["--volume", project.as_str()]));
+    assert!(args
+        .windows(2)
+        .any(|window| window == ["--workdir", "/project"]));
+    assert!(args.contains(&"python:3.12"));
+
+    // The host-only options are dropped, and the lockfile is enforced in the container.
+    assert!(!args.contains(&"--isolated"));
+    assert!(!args.contains(&"--container"));
+    assert!(!args.contains(&"--cache-dir"));
+    assert!(args.windows(2).any(|window| window == ["run", "--locked"]));
+
+    // The command itself is forwarded verbatim.
+    assert!(args.ends_with(&["python", "-c", "print('hello')"]));
+
+    Ok(())
+}
+
+/// Repair a project's entry points with `uv sync --refresh-scripts`, e.g., after the interpreter
+/// they point to was moved.
+#[test]
+#[cfg(unix)]
+fn run_after_refresh_scripts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        main = "main:main"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+
+    let main_script = context.temp_dir.child("main.py");
+    main_script.write_str(indoc! { r"
+        import platform
+
+        def main():
+            print(platform.python_version())
+       "
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("main"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12.[X]
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // Point the entry point at an interpreter that no longer exists.
+    let entrypoint = venv_bin_path(&context.venv).join("main");
+    let contents = fs_err::read_to_string(&entrypoint)?;
+    let (_, body) = contents.split_once('\n').unwrap();
+    fs_err::write(&entrypoint, format!("#!/missing/bin/python\n{body}"))?;
+
+    // The environment is in sync, so `uv run` doesn't repair the entry point.
+    uv_snapshot!(context.filters(), context.run().arg("main"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    error: Failed to spawn: `main`
+      Caused by: No such file or directory (os error 2)
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync().arg("--refresh-scripts"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    Refreshed 1 entry point in [TIME]
+    "###);
+
+    assert!(!fs_err::read_to_string(&entrypoint)?.starts_with("#!/missing/"));
+
+    uv_snapshot!(context.filters(), context.run().arg("main"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12.[X]
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,346 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn script_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("main.py");
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "anyio",
+        # ]
+        # ///
+
+        import anyio
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.script().arg("lock").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // The lockfile should be written alongside the script.
+    let lock = context.read("main.py.lock");
+    assert!(lock.contains(r#"name = "anyio""#));
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    // Re-locking with `--locked` should succeed.
+    uv_snapshot!(context.filters(), context.script().arg("lock").arg("main.py").arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // Adding a dependency should invalidate the lockfile.
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "anyio",
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import anyio
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.script().arg("lock").arg("main.py").arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    error: The lockfile at `main.py.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv script lock main.py`.
+    "###);
+
+    uv_snapshot!(context.filters(), context.script().arg("lock").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Added iniconfig v2.0.0
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn script_lock_frozen_missing() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("main.py");
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.script().arg("tree").arg("main.py").arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `main.py.lock`. To create a lockfile, run `uv script lock main.py`.
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn script_tree() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("main.py");
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "anyio",
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import anyio
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.script().arg("tree").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio v4.3.0
+    ├── idna v3.6
+    └── sniffio v1.3.1
+    iniconfig v2.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // `uv script tree` should create the lockfile.
+    assert!(context.temp_dir.child("main.py.lock").exists());
+
+    Ok(())
+}
+
+#[test]
+fn script_sync() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("main.py");
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+       "#
+    })?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"environments-v\d+/[^\s]+", "environments-v[VERSION]/[ENV]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.script().arg("sync").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Synced environment for `main.py` at: [CACHE_DIR]/environments-v[VERSION]/[ENV]
+    "###);
+
+    // Running the script should use the locked, synced environment.
+    uv_snapshot!(context.filters(), context.run().arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Reading inline script metadata from `main.py`
+    Resolved 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn script_vendor() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("main.py");
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+
+        print(iniconfig.__name__)
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.script().arg("vendor").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Vendored main.py for Python 3.12 to: main.pyz
+    "###);
+
+    // The zip application should run without uv, importing the vendored dependencies.
+    uv_snapshot!(context.filters(), Command::new(context.interpreter()).arg("main.pyz").current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn script_vendor_directory() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("main.py");
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+
+        print(iniconfig.__name__)
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.script().arg("vendor").arg("main.py").arg("--format").arg("directory").arg("--output").arg("vendored"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Vendored main.py for Python 3.12 to: vendored
+    "###);
+
+    let vendored = context.temp_dir.child("vendored");
+    vendored
+        .child("__main__.py")
+        .assert(predicates::path::is_file());
+    vendored
+        .child("main.py")
+        .assert(predicates::path::is_file());
+    vendored
+        .child("site-packages")
+        .child("iniconfig")
+        .assert(predicates::path::is_dir());
+
+    // The manifest shouldn't list any platform-specific dependencies.
+    let manifest = context.read("vendored/manifest.json");
+    assert!(manifest.contains(r#""platform-specific": []"#));
+
+    uv_snapshot!(context.filters(), Command::new(context.interpreter()).arg("vendored").current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig
+
+    ----- stderr -----
+    "###);
+
+    // Vendoring to an existing directory that isn't a vendored script should fail.
+    context.temp_dir.child("other").child("file.txt").touch()?;
+    uv_snapshot!(context.filters(), context.script().arg("vendor").arg("main.py").arg("--format").arg("directory").arg("--output").arg("other"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The output directory `other` already exists and is not a vendored script
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn script_missing_metadata() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("main.py");
+    script.write_str("import iniconfig\n")?;
+
+    uv_snapshot!(context.filters(), context.script().arg("lock").arg("main.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `main.py` does not contain inline script metadata
+    "###);
+
+    uv_snapshot!(context.filters(), context.script().arg("lock").arg("missing.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Script not found: `missing.py`
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,253 @@
+use std::io::Write;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// A `file://` component index, with a single component, `audit-db`.
+struct ComponentIndex {
+    url: Url,
+}
+
+impl ComponentIndex {
+    /// Create an index in the given directory, with a release of `audit-db` for each of the given
+    /// channels.
+    fn new(dir: &assert_fs::fixture::ChildPath, releases: &[(&str, &str)]) -> Result<Self> {
+        dir.create_dir_all()?;
+        for (channel, version) in releases {
+            let archive = dir.child(format!("audit-db-{version}.zip"));
+            let mut writer = zip::ZipWriter::new(fs_err::File::create(archive.path())?);
+            let options = zip::write::FileOptions::default();
+            writer.start_file(format!("audit-db-{version}/README.md"), options)?;
+            writer.write_all(format!("audit-db {version}\n").as_bytes())?;
+            writer.finish()?;
+
+            let sha256 = format!("{:x}", Sha256::digest(fs_err::read(archive.path())?));
+            let index = serde_json::json!({
+                "components": [{
+                    "name": "audit-db",
+                    "version": version,
+                    "artifacts": [{
+                        "platform": "any",
+                        "url": Url::from_file_path(archive.path()).unwrap(),
+                        "sha256": sha256,
+                    }],
+                }],
+            });
+            dir.child(format!("components-{channel}.json"))
+                .write_str(&serde_json::to_string(&index)?)?;
+        }
+        Ok(Self {
+            url: Url::from_directory_path(dir.path()).unwrap(),
+        })
+    }
+}
+
+#[test]
+fn self_add_list_remove() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let data_home = context.temp_dir.child("data");
+    let index = ComponentIndex::new(
+        &context.temp_dir.child("index"),
+        &[("stable", "1.0.0"), ("preview", "1.1.0a1")],
+    )?;
+
+    let self_command = |subcommand: &str| {
+        let mut command = context.command();
+        command
+            .arg("self")
+            .arg(subcommand)
+            .env(EnvVars::UV_COMPONENT_INDEX_URL, index.url.as_str())
+            .env(EnvVars::XDG_DATA_HOME, data_home.as_os_str());
+        command
+    };
+
+    uv_snapshot!(context.filters(), self_command("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No components installed
+    "###);
+
+    uv_snapshot!(context.filters(), self_command("add").arg("audit-db"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed audit-db v1.0.0 (stable)
+    "###);
+
+    // The archive's top-level directory is stripped.
+    data_home
+        .child("uv")
+        .child("components")
+        .child("audit-db")
+        .child("README.md")
+        .assert("audit-db 1.0.0\n");
+
+    uv_snapshot!(context.filters(), self_command("add").arg("audit-db"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `audit-db` is already up-to-date (v1.0.0)
+    "###);
+
+    uv_snapshot!(context.filters(), self_command("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    audit-db v1.0.0 (stable)
+
+    ----- stderr -----
+    "###);
+
+    // Switching channels upgrades the component; subsequent upgrades use the new channel.
+    uv_snapshot!(context.filters(), self_command("add").arg("audit-db").arg("--channel").arg("preview"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Upgraded audit-db from v1.0.0 to v1.1.0a1 (preview)
+    "###);
+
+    uv_snapshot!(context.filters(), self_command("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    audit-db v1.1.0a1 (preview)
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), self_command("list").arg("--outdated"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All components are up-to-date
+    "###);
+
+    uv_snapshot!(context.filters(), self_command("remove").arg("audit-db"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed audit-db v1.1.0a1
+    "###);
+
+    uv_snapshot!(context.filters(), self_command("remove").arg("audit-db"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `audit-db` is not installed
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn self_add_errors() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let data_home = context.temp_dir.child("data");
+    let index = ComponentIndex::new(&context.temp_dir.child("index"), &[("stable", "1.0.0")])?;
+
+    // There's no default component index.
+    uv_snapshot!(context.filters(), context.command()
+        .arg("self")
+        .arg("add")
+        .arg("audit-db")
+        .env_remove(EnvVars::UV_COMPONENT_INDEX_URL)
+        .env(EnvVars::XDG_DATA_HOME, data_home.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No component index is configured; set `UV_COMPONENT_INDEX_URL` to the URL of a component index
+    "###);
+
+    // The component isn't available on the channel.
+    uv_snapshot!(context.filters(), context.command()
+        .arg("self")
+        .arg("add")
+        .arg("audit-db")
+        .arg("--channel")
+        .arg("preview")
+        .env(EnvVars::UV_COMPONENT_INDEX_URL, index.url.as_str())
+        .env(EnvVars::XDG_DATA_HOME, data_home.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: failed to read from file `[TEMP_DIR]/index/components-preview.json`
+      Caused by: No such file or directory (os error 2)
+    "###);
+
+    // The archive doesn't match the hash in the index.
+    let archive = context.temp_dir.child("index").child("audit-db-1.0.0.zip");
+    let mut writer = zip::ZipWriter::new(fs_err::File::create(archive.path())?);
+    writer.start_file(
+        "audit-db-1.0.0/README.md",
+        zip::write::FileOptions::default(),
+    )?;
+    writer.write_all(b"tampered\n")?;
+    writer.finish()?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"sha256:[0-9a-f]{64}", "sha256:[HASH]")])
+        .collect::<Vec<_>>();
+    uv_snapshot!(filters, context.command()
+        .arg("self")
+        .arg("add")
+        .arg("audit-db")
+        .env(EnvVars::UV_COMPONENT_INDEX_URL, index.url.as_str())
+        .env(EnvVars::XDG_DATA_HOME, data_home.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Hash mismatch for component `audit-db` v1.0.0
+
+    Expected:
+      sha256:[HASH]
+
+    Computed:
+      sha256:[HASH]
+    "###);
+
+    // Invalid component names are rejected.
+    uv_snapshot!(context.filters(), context.command()
+        .arg("self")
+        .arg("add")
+        .arg("Audit_DB")
+        .env(EnvVars::UV_COMPONENT_INDEX_URL, index.url.as_str())
+        .env(EnvVars::XDG_DATA_HOME, data_home.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid component name `Audit_DB`: names may only contain lowercase letters, digits, and hyphens
+    "###);
+
+    Ok(())
+}
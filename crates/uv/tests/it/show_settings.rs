@@ -59,13 +59,24 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -76,9 +87,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -116,6 +131,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -166,6 +183,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -174,7 +192,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -210,13 +235,24 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -227,9 +263,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -267,6 +307,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -317,6 +359,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -325,7 +368,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -362,13 +412,24 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -379,9 +440,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -419,6 +484,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -469,6 +536,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -477,7 +545,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -546,13 +621,24 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -563,9 +649,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -603,6 +693,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -653,6 +745,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -661,7 +754,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -699,13 +799,24 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -716,9 +827,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -777,6 +892,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -785,7 +901,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -832,13 +955,24 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -849,9 +983,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -889,6 +1027,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -939,6 +1079,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -947,7 +1088,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -1008,13 +1156,24 @@ fn resolve_index_url() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -1025,9 +1184,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1065,6 +1228,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                     Index {
@@ -1093,6 +1258,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -1143,6 +1310,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -1151,7 +1319,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -1189,13 +1364,24 @@ fn resolve_index_url() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -1206,9 +1392,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1246,6 +1436,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: Some(
                             Cli,
                         ),
@@ -1276,6 +1468,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                     Index {
@@ -1304,6 +1498,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -1354,6 +1550,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -1362,7 +1559,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -1423,13 +1627,24 @@ fn resolve_find_links() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -1440,9 +1655,13 @@ fn resolve_find_links() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1481,6 +1700,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -1530,6 +1751,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -1538,7 +1760,14 @@ fn resolve_find_links() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -1598,13 +1827,24 @@ fn resolve_top_level() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -1615,9 +1855,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1676,6 +1920,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -1684,7 +1929,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -1737,13 +1989,24 @@ fn resolve_top_level() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -1754,9 +2017,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1794,6 +2061,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                     Index {
@@ -1822,6 +2091,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -1872,6 +2143,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -1880,7 +2152,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -1916,13 +2195,24 @@ fn resolve_top_level() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -1933,9 +2223,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1973,6 +2267,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                     Index {
@@ -2001,6 +2297,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -2051,6 +2349,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -2059,7 +2358,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -2119,13 +2425,24 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -2136,9 +2453,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2197,6 +2518,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -2205,7 +2527,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -2248,13 +2577,24 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -2265,9 +2605,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2326,6 +2670,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -2334,7 +2679,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -2377,13 +2729,24 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -2394,9 +2757,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2455,6 +2822,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -2463,7 +2831,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -2508,13 +2883,24 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -2525,9 +2911,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2586,6 +2976,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -2594,7 +2985,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -2658,13 +3056,24 @@ fn resolve_tool() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -2702,10 +3111,13 @@ fn resolve_tool() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             link_mode: Some(
                 Clone,
             ),
             compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: None,
             no_sources: None,
             upgrade: None,
             upgrade_package: None,
@@ -2739,8 +3151,16 @@ fn resolve_tool() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             upgrade: None,
             reinstall: None,
@@ -2819,13 +3239,24 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -2836,9 +3267,13 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2897,6 +3332,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -2905,7 +3341,14 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -2976,13 +3419,24 @@ fn resolve_both() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -2993,9 +3447,13 @@ fn resolve_both() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3033,6 +3491,8 @@ fn resolve_both() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -3083,6 +3543,7 @@ fn resolve_both() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -3091,7 +3552,14 @@ fn resolve_both() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -3250,13 +3718,24 @@ fn resolve_config_file() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -3267,9 +3746,13 @@ fn resolve_config_file() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3307,6 +3790,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -3357,6 +3842,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -3365,7 +3851,14 @@ fn resolve_config_file() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -3405,7 +3898,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `publish-url`, `trusted-publishing`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `environments`, `conflicting-groups`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dev-dependencies`
+    unknown field `project`, expected one of `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `platform-tag-preference`, `max-manylinux`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `max-backtracks`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `publish-url`, `trusted-publishing`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `environments`, `conflicting-groups`, `workspace`, `sources`, `variants`, `vendor`, `managed`, `package`, `default-groups`, `dev-dependencies`
     "###
     );
 
@@ -3502,13 +3995,24 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -3519,9 +4023,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3580,6 +4088,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -3588,7 +4097,14 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -3634,13 +4150,24 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -3651,9 +4178,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3712,6 +4243,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -3720,7 +4252,14 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -3785,13 +4324,24 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -3802,9 +4352,13 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3863,6 +4417,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -3871,7 +4426,14 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -3928,13 +4490,24 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -3945,9 +4518,13 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3985,6 +4562,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4015,6 +4594,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -4065,6 +4646,7 @@ fn index_priority() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -4073,7 +4655,14 @@ fn index_priority() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -4109,13 +4698,24 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -4126,9 +4726,13 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4166,6 +4770,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4196,6 +4802,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -4246,6 +4854,7 @@ fn index_priority() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -4254,7 +4863,14 @@ fn index_priority() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -4296,13 +4912,24 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -4313,9 +4940,13 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4353,6 +4984,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4383,6 +5016,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -4433,6 +5068,7 @@ fn index_priority() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -4441,7 +5077,14 @@ fn index_priority() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -4478,13 +5121,24 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -4495,9 +5149,13 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4535,6 +5193,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4565,6 +5225,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -4615,6 +5277,7 @@ fn index_priority() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -4623,7 +5286,14 @@ fn index_priority() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -4667,13 +5337,24 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -4684,9 +5365,13 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4724,6 +5409,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4754,6 +5441,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -4804,6 +5493,7 @@ fn index_priority() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -4812,7 +5502,14 @@ fn index_priority() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -4849,13 +5546,24 @@ fn index_priority() -> anyhow::Result<()> {
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        tag_preferences: TagPreferences {
+            prefer: [],
+            max_manylinux: None,
+        },
         no_progress: false,
+        progress_format: Text,
+        progress_fd: None,
+        build_report: None,
+        resolution_suggestions: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_lock_timeout: None,
+        offline_grace: None,
+        revalidate_package: [],
     }
     PipCompileSettings {
         src_file: [
@@ -4866,9 +5574,13 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
-        environments: SupportedEnvironments(
-            [],
-        ),
+        environments: SupportedEnvironments {
+            markers: [],
+            names: [],
+        },
+        split_output_dir: None,
+        split_python_versions: [],
+        split_python_platforms: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4906,6 +5618,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4936,6 +5650,8 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        proxy: None,
+                        cache_ttl: None,
                         origin: None,
                     },
                 ],
@@ -4986,6 +5702,7 @@ fn index_priority() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            max_backtracks: None,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -4994,7 +5711,14 @@ fn index_priority() -> anyhow::Result<()> {
             emit_index_annotation: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: None,
+            invalidation_mode: None,
+            script_launcher: ScriptLauncher {
+                gui: [],
+                console: [],
+                long_paths: false,
+                arch: None,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
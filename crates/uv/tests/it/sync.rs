@@ -4,10 +4,10 @@ use assert_fs::{fixture::ChildPath, prelude::*};
 use indoc::indoc;
 use insta::assert_snapshot;
 
-use predicates::prelude::predicate;
+use predicates::prelude::*;
 use tempfile::tempdir_in;
 
-use crate::common::{download_to_disk, uv_snapshot, venv_bin_path, TestContext};
+use crate::common::{download_to_disk, make_wheel, uv_snapshot, venv_bin_path, TestContext};
 use uv_static::EnvVars;
 
 #[test]
@@ -116,6 +116,175 @@ fn locked() -> Result<()> {
     Ok(())
 }
 
+/// With `--locked-indexes`, refuse to retain a package that was installed from an index other than
+/// the one recorded in the lockfile.
+#[test]
+fn locked_indexes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // The package was installed from the locked index, so the environment is accepted as-is.
+    uv_snapshot!(context.filters(), context.sync().arg("--locked-indexes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    // Replace the package with an identically-named wheel from a different source.
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    make_wheel(&links, "iniconfig", "2.0.0")?;
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    "###);
+
+    // Without `--locked-indexes`, the package is retained, since its version matches.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    // With `--locked-indexes`, uv should refuse to retain it.
+    uv_snapshot!(context.filters(), context.sync().arg("--locked-indexes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: `iniconfig` is installed from `file://[TEMP_DIR]/links`, but the lockfile requires `iniconfig` from `https://pypi.org/simple`, and `--locked-indexes` was provided.
+
+    hint: To reinstall `iniconfig` from the locked index, pass `--reinstall-package iniconfig`
+    "###);
+
+    // Reinstalling the package from the locked index should succeed.
+    uv_snapshot!(context.filters(), context.sync().arg("--locked-indexes").arg("--reinstall-package").arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync().arg("--locked-indexes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// With `--locked-indexes`, refuse to retain a package whose index is unknown, e.g., because it was
+/// installed by another tool.
+#[test]
+fn locked_indexes_unknown() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.sync().assert().success();
+
+    // Remove the record of the index from which the package was installed.
+    fs_err::remove_file(
+        context
+            .site_packages()
+            .join("iniconfig-2.0.0.dist-info")
+            .join("uv_index.json"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--locked-indexes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: `iniconfig` is installed from an unknown index, but the lockfile requires `iniconfig` from `https://pypi.org/simple`, and `--locked-indexes` was provided.
+
+    hint: To reinstall `iniconfig` from the locked index, pass `--reinstall-package iniconfig`
+    "###);
+
+    // With `--reinstall`, the package is replaced, so the sync should succeed.
+    uv_snapshot!(context.filters(), context.sync().arg("--locked-indexes").arg("--reinstall"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn frozen() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -1961,6 +2130,77 @@ fn no_install_package() -> Result<()> {
     Ok(())
 }
 
+/// Restrict the installation to a subgraph of the lockfile with `--only` and `--except`.
+#[test]
+fn only_and_except_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    // Generate a lockfile.
+    context.lock().assert().success();
+
+    // Running with `--only anyio` should install anyio and its dependencies, but nothing else.
+    uv_snapshot!(context.filters(), context.sync().arg("--only").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###);
+
+    // Running with `--except anyio` should omit anyio along with the dependencies that are only
+    // required through it.
+    uv_snapshot!(context.filters(), context.sync().arg("--except").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 3 packages in [TIME]
+    Installed 2 packages in [TIME]
+     - anyio==3.7.0
+     - idna==3.6
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     - sniffio==1.3.1
+    "###);
+
+    // A package that isn't part of the dependency graph can't be selected.
+    uv_snapshot!(context.filters(), context.sync().arg("--only").arg("typing-extensions"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    error: Could not find `typing-extensions` among the packages to install
+    "###);
+
+    Ok(())
+}
+
 /// Ensure that `--no-build` isn't enforced for projects that aren't installed in the first place.
 #[test]
 fn no_install_project_no_build() -> Result<()> {
@@ -4270,3 +4510,700 @@ fn sync_all_groups() -> Result<()> {
 
     Ok(())
 }
+
+/// Report the size of each package that would be installed, without creating the environment.
+#[test]
+fn sync_dry_run_show_sizes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    let lock = context.temp_dir.child("uv.lock");
+    lock.write_str(
+        r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig" }]
+        "#,
+    )?;
+
+    // Remove the environment, which should not be recreated.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--dry-run").arg("--show-sizes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Would create virtual environment at: .venv
+    Would download 1 package
+    Would install 1 package
+     + iniconfig==2.0.0 (wheel, [SIZE] download)
+    Package sizes:
+     + iniconfig==2.0.0 ([SIZE] download)
+    Would download [SIZE] and change the environment size by +0B (excluding 1 package of unknown unpacked size)
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--dry-run").arg("--show-sizes").arg("--json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "packages": [
+        {
+          "name": "iniconfig",
+          "version": "2.0.0",
+          "action": "install",
+          "download_size": 5892,
+          "unpacked_size": null
+        }
+      ],
+      "download_size": 5892,
+      "environment_delta": 0,
+      "unknown": 1
+    }
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Would create virtual environment at: .venv
+    "###);
+
+    assert!(!context.venv.exists());
+
+    Ok(())
+}
+
+/// Print a summary of the cache usage after syncing.
+#[test]
+fn sync_cache_summary() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    context.temp_dir.child("uv.lock").write_str(
+        r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--cache-summary"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited in [TIME]
+    Cache summary: 0 requests (0 cached, 0 revalidated, 0 from network), 0 wheels (0 cached, 0 downloaded), 0 source distributions (0 cached, 0 built)
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--cache-summary=json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "requests": {
+        "cached": 0,
+        "revalidated": 0,
+        "network": 0
+      },
+      "wheels": {
+        "cached": 0,
+        "downloaded": 0
+      },
+      "builds": {
+        "cached": 0,
+        "built": 0
+      },
+      "phases": []
+    }
+
+    ----- stderr -----
+    Audited in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Sync a URL source with a hash pinned in `tool.uv.sources`. A lockfile that doesn't match the
+/// pinned hash should be rejected before anything is installed.
+#[test]
+fn sync_sources_url_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution"]
+
+        [tool.uv.sources]
+        source-distribution = { url = "https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz", hash = "sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106" }
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Pin a different hash, and install from the existing lockfile.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution"]
+
+        [tool.uv.sources]
+        source-distribution = { url = "https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz", hash = "sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    error: Hash mismatch for `source-distribution` (from `https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`)
+
+    Expected:
+      sha256:a7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+
+    Locked:
+      sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106
+    "###);
+
+    // Pin a hash for a URL that isn't in the lockfile; verification must not be skipped.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution"]
+
+        [tool.uv.sources]
+        source-distribution = { url = "https://example.com/source_distribution-0.0.1.tar.gz", hash = "sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `source-distribution` has a hash pinned in `tool.uv.sources`, but the lockfile doesn't include `source-distribution` from `https://example.com/source_distribution-0.0.1.tar.gz`
+    "###);
+
+    // Nothing should have been installed.
+    context
+        .assert_command("import source_distribution")
+        .failure();
+
+    Ok(())
+}
+
+/// Install the rebuild hook for an editable member with native sources, and remove it once the
+/// member leaves the workspace.
+#[test]
+fn sync_rebuild_on_import() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+
+        [tool.uv]
+        rebuild-on-import = true
+        "#,
+    )?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .touch()?;
+    child
+        .child("src")
+        .child("child")
+        .child("_speedups.c")
+        .write_str("int answer(void) { return 42; }\n")?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // The hook is installed for the member with native sources, and recorded in its `RECORD`.
+    let site_packages = ChildPath::new(context.site_packages());
+    for extension in ["pth", "py", "json"] {
+        site_packages
+            .child(format!("_uv_rebuild_child.{extension}"))
+            .assert(predicate::path::exists());
+        site_packages
+            .child(format!("_uv_rebuild_project.{extension}"))
+            .assert(predicate::path::missing());
+    }
+    site_packages
+        .child("child-0.1.0.dist-info")
+        .child("RECORD")
+        .assert(
+            predicate::str::contains("_uv_rebuild_child.pth,sha256=")
+                .and(predicate::str::contains("_uv_rebuild_child.py,sha256="))
+                .and(predicate::str::contains("_uv_rebuild_child.json,sha256=")),
+        );
+
+    // Syncing again doesn't duplicate the hook's `RECORD` entries.
+    context.sync().assert().success();
+    let record =
+        fs_err::read_to_string(site_packages.child("child-0.1.0.dist-info").child("RECORD"))?;
+    assert_eq!(record.matches("_uv_rebuild_child.pth,").count(), 1);
+
+    // Remove the member from the workspace, but leave it installed.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+
+    context.sync().arg("--inexact").assert().success();
+
+    // The member's hook is removed, along with its `RECORD` entries.
+    for extension in ["pth", "py", "json"] {
+        site_packages
+            .child(format!("_uv_rebuild_child.{extension}"))
+            .assert(predicate::path::missing());
+    }
+    site_packages
+        .child("child-0.1.0.dist-info")
+        .child("RECORD")
+        .assert(predicate::str::contains("_uv_rebuild_child").not());
+
+    Ok(())
+}
+
+/// Install a vendored copy of a leaf dependency under its alias, and rewrite the imports of its
+/// dependents to use it.
+#[test]
+fn sync_vendored() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=2", "legacy-lib"]
+
+        [tool.uv.sources]
+        legacy-lib = { path = "legacy-lib" }
+
+        [[tool.uv.vendor]]
+        name = "iniconfig"
+        version = "<2"
+        alias = "iniconfig_legacy"
+        dependents = ["legacy-lib"]
+        "#,
+    )?;
+
+    let legacy_lib = context.temp_dir.child("legacy-lib");
+    legacy_lib.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "legacy-lib"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig<2"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    legacy_lib
+        .child("src")
+        .child("legacy_lib")
+        .child("__init__.py")
+        .write_str(indoc! {r"
+            import iniconfig
+
+            def module():
+                return iniconfig.__name__
+        "})?;
+
+    // The vendored copy isn't installed as `iniconfig`, so only the main resolution is listed.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Resolved 1 package in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + legacy-lib==0.1.0 (from file://[TEMP_DIR]/legacy-lib)
+    "###);
+
+    // The vendored copy is installed under its alias, alongside the version from the main
+    // resolution.
+    let site_packages = ChildPath::new(context.site_packages());
+    site_packages
+        .child("iniconfig_legacy")
+        .child("__init__.py")
+        .assert(predicate::path::exists());
+    site_packages
+        .child("iniconfig_legacy-1.1.1.dist-info")
+        .assert(predicate::path::is_dir());
+    site_packages
+        .child("iniconfig-2.0.0.dist-info")
+        .assert(predicate::path::is_dir());
+
+    // The imports of the dependent are rewritten to refer to the alias.
+    site_packages
+        .child("legacy_lib")
+        .child("__init__.py")
+        .assert(predicate::str::starts_with(
+            "import iniconfig_legacy as iniconfig\n",
+        ));
+    context
+        .assert_command(
+            "import iniconfig, legacy_lib; print(legacy_lib.module()); print(iniconfig.__name__)",
+        )
+        .success()
+        .stdout("iniconfig_legacy\niniconfig\n");
+
+    // Syncing again is a no-op, and leaves the rewritten imports intact.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited 2 packages in [TIME]
+    "###);
+    context
+        .assert_command("import legacy_lib; print(legacy_lib.module())")
+        .success()
+        .stdout("iniconfig_legacy\n");
+
+    Ok(())
+}
+
+/// With `--watch`, the environment is re-synced whenever the `pyproject.toml` changes.
+#[test]
+fn sync_watch() -> Result<()> {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+    use std::sync::mpsc;
+    use std::time::{Duration, Instant};
+
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    // `--watch` can't be combined with `--dry-run`.
+    uv_snapshot!(context.filters(), context.sync().arg("--watch").arg("--dry-run"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--watch' cannot be used with '--dry-run'
+
+    Usage: uv sync --cache-dir [CACHE_DIR] --watch --exclude-newer <EXCLUDE_NEWER>
+
+    For more information, try '--help'.
+    "###);
+
+    let mut child = context
+        .sync()
+        .arg("--watch")
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Forward each line of output, such that we can wait for a given message without blocking.
+    let (sender, receiver) = mpsc::channel();
+    let stderr = child.stderr.take().unwrap();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    let wait_for = |message: &str| -> Result<Vec<String>> {
+        let start = Instant::now();
+        let mut lines = Vec::new();
+        loop {
+            let remaining = Duration::from_secs(60).saturating_sub(start.elapsed());
+            let line = receiver.recv_timeout(remaining)?;
+            let found = line.contains(message);
+            lines.push(line);
+            if found {
+                return Ok(lines);
+            }
+        }
+    };
+
+    // The initial sync is followed by a watch.
+    let lines = wait_for("for changes...")?;
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("Resolved 1 package")));
+
+    // Adding a dependency triggers a re-sync.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    let lines = wait_for("for changes...")?;
+    assert!(lines
+        .iter()
+        .any(|line| line == "Detected changes to `pyproject.toml`; re-syncing..."));
+    assert!(lines.iter().any(|line| line == " + iniconfig==2.0.0"));
+
+    child.kill()?;
+    child.wait()?;
+
+    // The lockfile and environment reflect the change.
+    assert!(context.read("uv.lock").contains("name = \"iniconfig\""));
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
+/// Install the project and its runtime dependencies into a flat `--target` directory.
+#[test]
+fn sync_target() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [dependency-groups]
+        dev = ["typing-extensions"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    // The default groups are omitted, and the project is installed as a non-editable package.
+    uv_snapshot!(context.filters(), context.sync().arg("--target").arg("target"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // The packages are installed at the top-level of the directory.
+    let target = context.temp_dir.child("target");
+    assert!(target.child("iniconfig").child("__init__.py").is_file());
+    assert!(target.child("iniconfig-2.0.0.dist-info").is_dir());
+    assert!(!target.child("typing_extensions.py").exists());
+    assert!(!target.child("lib").exists());
+
+    // The project environment is untouched.
+    context.assert_command("import iniconfig").failure();
+
+    assert_snapshot!(context.read("target/uv-target.toml"), @r###"
+    python = "3.12"
+
+    [[package]]
+    name = "iniconfig"
+    version = "2.0.0"
+
+    [[package]]
+    name = "project"
+    version = "0.1.0"
+    "###);
+
+    // Extraneous packages are removed on subsequent syncs, and the manifest is updated.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--target").arg("target"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==2.0.0
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    assert!(!target.child("iniconfig").exists());
+
+    assert_snapshot!(context.read("target/uv-target.toml"), @r###"
+    python = "3.12"
+
+    [[package]]
+    name = "project"
+    version = "0.1.0"
+    "###);
+
+    // `--python-platform` requires `--target`.
+    uv_snapshot!(context.filters(), context.sync().arg("--python-platform").arg("linux"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --target <TARGET>
+
+    Usage: uv sync --cache-dir [CACHE_DIR] --python-platform <PYTHON_PLATFORM> --target <TARGET> --exclude-newer <EXCLUDE_NEWER>
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
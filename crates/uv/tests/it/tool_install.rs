@@ -2443,6 +2443,11 @@ fn tool_install_preserve_environment() {
       × No solution found when resolving dependencies:
       ╰─▶ Because black==24.1.1 depends on packaging>=22.0 and you require black==24.1.1, we can conclude that you require packaging>=22.0.
           And because you require packaging==0.0.1, we can conclude that your requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `packaging>=22.0` in `black`
+            2. Relax the requirement `packaging==0.0.1` in your requirements
+            3. Add an override for `packaging==0.0.1` (via `tool.uv.override-dependencies` or `--override`)
     "###);
 
     // Install `black`. The tool should already be installed, since we didn't remove the environment.
@@ -1031,3 +1031,259 @@ fn workspace_dev() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn diff() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+    "#,
+    )?;
+
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("previous.lock"),
+    )?;
+
+    // Comparing against an identical lockfile should report no differences.
+    uv_snapshot!(context.filters(), context.tree().arg("--diff").arg("previous.lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    No lockfile differences detected
+    "###
+    );
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.1", "iniconfig"]
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--diff").arg("previous.lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Packages:
+    + iniconfig v2.0.0
+    ~ anyio v3.7.0 -> v3.7.1
+
+    Dependencies:
+    project
+    └── + iniconfig
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.tree().arg("--diff").arg("previous.lock").arg("--json").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "added": [
+        {
+          "name": "iniconfig",
+          "versions": [
+            "2.0.0"
+          ]
+        }
+      ],
+      "removed": [],
+      "updated": [
+        {
+          "name": "anyio",
+          "previous": [
+            "3.7.0"
+          ],
+          "current": [
+            "3.7.1"
+          ]
+        }
+      ],
+      "dependencies": [
+        {
+          "dependent": "project",
+          "added": [
+            {
+              "package": "iniconfig"
+            }
+          ],
+          "removed": []
+        }
+      ],
+      "derivations": []
+    }
+
+    ----- stderr -----
+    "###
+    );
+
+    // `--json` requires `--diff`.
+    uv_snapshot!(context.filters(), context.tree().arg("--json"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --diff <PATH>
+
+    Usage: uv tree --cache-dir [CACHE_DIR] --json --diff <PATH>
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn why() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [dependency-groups]
+        dev = ["anyio"]
+        "#,
+    )?;
+
+    // `idna` is only included via `anyio`, which is only included via the `dev` group.
+    uv_snapshot!(context.filters(), context.tree().arg("--why").arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    idna v3.6
+    └── anyio v4.3.0 (via group: dev)
+        └── project v0.1.0 (group: dev)
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    // `iniconfig` is always included.
+    uv_snapshot!(context.filters(), context.tree().arg("--why").arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig v2.0.0
+    └── project v0.1.0
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    // Packages outside the lockfile are flagged.
+    uv_snapshot!(context.filters(), context.tree().arg("--why").arg("flask"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    warning: `flask` is not included in the lockfile
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn why_collapse() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["sniffio"]
+
+        [dependency-groups]
+        dev = ["anyio", "sniffio"]
+        "#,
+    )?;
+
+    // The edges from `project` to `sniffio` are merged into a single line, which is unconditional
+    // since one of the edges is; the subtree under `anyio` is only displayed once.
+    uv_snapshot!(context.filters(), context.tree().arg("--why").arg("idna").arg("--why").arg("sniffio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    idna v3.6
+    └── anyio v4.3.0 (via group: dev)
+        └── project v0.1.0 (group: dev)
+    sniffio v1.3.1
+    ├── anyio v4.3.0 (via group: dev) (*)
+    └── project v0.1.0
+    (*) Package tree already displayed
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn why_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig ; sys_platform == 'win32'"]
+
+        [project.optional-dependencies]
+        cli = ["iniconfig"]
+        "#,
+    )?;
+
+    // The conditions of each edge are displayed as alternatives.
+    uv_snapshot!(context.filters(), context.tree().arg("--universal").arg("--why").arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig v2.0.0
+    └── project v0.1.0 (marker: sys_platform == 'win32') or (extra: cli)
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
@@ -0,0 +1,256 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Bump the version of a project with `uv version --bump`.
+#[test]
+fn version_bump() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        # The version is bumped in-place.
+        version = "1.2.3" # Managed by `uv version`.
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("patch"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.3 => 1.2.4
+
+    ----- stderr -----
+    "###);
+
+    // The version is updated, preserving any comments.
+    assert_snapshot!(context.read("pyproject.toml"), @r###"
+    [project]
+    name = "project"
+    # The version is bumped in-place.
+    version = "1.2.4" # Managed by `uv version`.
+    requires-python = ">=3.12"
+    dependencies = []
+    "###);
+
+    // Multiple bumps are applied in order.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor").arg("--bump").arg("alpha"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.4 => 1.3.0a1
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("prerelease"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.3.0a1 => 1.3.0a2
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("rc"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.3.0a2 => 1.3.0rc1
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("stable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.3.0rc1 => 1.3.0
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("post"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.3.0 => 1.3.0.post1
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("epoch"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.3.0.post1 => 1!1.3.0.post1
+
+    ----- stderr -----
+    "###);
+
+    assert_snapshot!(context.read("pyproject.toml"), @r###"
+    [project]
+    name = "project"
+    # The version is bumped in-place.
+    version = "1!1.3.0.post1" # Managed by `uv version`.
+    requires-python = ">=3.12"
+    dependencies = []
+    "###);
+
+    Ok(())
+}
+
+/// Display the bumped version with `--dry-run`, without updating the `pyproject.toml`.
+#[test]
+fn version_bump_dry_run() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "1.2.3"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("major").arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.3 => 2.0.0
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor").arg("--dry-run").arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "name": "project",
+      "previous": "1.2.3",
+      "version": "1.3.0"
+    }
+
+    ----- stderr -----
+    "###);
+
+    // The `pyproject.toml` is unchanged.
+    assert_snapshot!(context.read("pyproject.toml"), @r###"
+    [project]
+    name = "project"
+    version = "1.2.3"
+    requires-python = ">=3.12"
+    dependencies = []
+    "###);
+
+    // `--dry-run` requires `--bump`.
+    uv_snapshot!(context.filters(), context.version().arg("--dry-run"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --bump <BUMP>
+
+    Usage: uv version --bump <BUMP> --cache-dir [CACHE_DIR] --dry-run
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
+
+/// Reject bumps that can't be applied, or that wouldn't increase the version.
+#[test]
+fn version_bump_invalid() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "1.2.3rc1"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    // A dev-release sorts before the release itself.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("stable").arg("--bump").arg("dev"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Bumping `project` from 1.2.3rc1 to 1.2.3.dev1 would not increase its version
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("beta"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to bump version of `project`
+      Caused by: Cannot bump `1.2.3rc1` to an earlier kind of pre-release (`b`)
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("stable").arg("--bump").arg("prerelease"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to bump version of `project`
+      Caused by: Cannot bump the pre-release of `1.2.3`, since it is not a pre-release
+    "###);
+
+    // None of the failed bumps were written.
+    assert_snapshot!(context.read("pyproject.toml"), @r###"
+    [project]
+    name = "project"
+    version = "1.2.3rc1"
+    requires-python = ">=3.12"
+    dependencies = []
+    "###);
+
+    Ok(())
+}
+
+/// A project with a dynamic version can't be bumped.
+#[test]
+fn version_bump_dynamic() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        dynamic = ["version"]
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("patch"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Project `project` does not declare a static `project.version` in `pyproject.toml`
+    "###);
+
+    Ok(())
+}
@@ -1403,6 +1403,11 @@ fn workspace_unsatisfiable_member_dependencies_conflicting() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because bar depends on anyio==4.2.0 and foo depends on anyio==4.1.0, we can conclude that bar and foo are incompatible.
           And because your workspace requires bar and foo, we can conclude that your workspace's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `anyio==4.2.0` in `bar`
+            2. Relax the requirement `anyio==4.1.0` in `foo`
+            3. Add an override for `anyio==4.1.0` (via `tool.uv.override-dependencies` or `--override`)
     "###
     );
 
@@ -1486,6 +1491,11 @@ fn workspace_unsatisfiable_member_dependencies_conflicting_threeway() -> Result<
       × No solution found when resolving dependencies:
       ╰─▶ Because bird depends on anyio==4.3.0 and knot depends on anyio==4.2.0, we can conclude that bird and knot are incompatible.
           And because your workspace requires bird and knot, we can conclude that your workspace's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `anyio==4.3.0` in `bird`
+            2. Relax the requirement `anyio==4.2.0` in `knot`
+            3. Add an override for `anyio==4.2.0` (via `tool.uv.override-dependencies` or `--override`)
     "###
     );
 
@@ -1556,6 +1566,11 @@ fn workspace_unsatisfiable_member_dependencies_conflicting_extra() -> Result<()>
       × No solution found when resolving dependencies:
       ╰─▶ Because bar[some-extra] depends on anyio==4.2.0 and foo depends on anyio==4.1.0, we can conclude that foo and bar[some-extra] are incompatible.
           And because your workspace requires bar[some-extra] and foo, we can conclude that your workspace's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `anyio==4.2.0` in `bar[some-extra]`
+            2. Relax the requirement `anyio==4.1.0` in `foo`
+            3. Add an override for `anyio==4.1.0` (via `tool.uv.override-dependencies` or `--override`)
     "###
     );
 
@@ -1627,6 +1642,11 @@ fn workspace_unsatisfiable_member_dependencies_conflicting_dev() -> Result<()> {
       ╰─▶ Because bar depends on bar:dev and bar:dev depends on anyio==4.2.0, we can conclude that bar depends on anyio==4.2.0.
           And because foo depends on anyio==4.1.0, we can conclude that bar and foo are incompatible.
           And because your workspace requires bar and foo, we can conclude that your workspace's requirements are unsatisfiable.
+
+          hint: The conflict may be resolved by one of the following (most likely first):
+            1. Relax the requirement `anyio==4.2.0` in `bar:dev`
+            2. Relax the requirement `anyio==4.1.0` in `foo`
+            3. Add an override for `anyio==4.1.0` (via `tool.uv.override-dependencies` or `--override`)
     "###
     );
 
@@ -1905,3 +1925,517 @@ fn transitive_dep_in_git_workspace_with_root() -> Result<()> {
 
     Ok(())
 }
+
+/// Write a workspace in which `albatross` depends on `bird-feeder`, which depends on `seeds` via a
+/// dependency group, and `nest` is independent.
+fn write_member_graph(context: &TestContext) -> Result<()> {
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder", "anyio>3"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+    context
+        .temp_dir
+        .child("packages/bird-feeder/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "bird-feeder"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+
+        [dependency-groups]
+        test = ["seeds", "pytest"]
+
+        [tool.uv.sources]
+        seeds = { workspace = true }
+    "#})?;
+    context
+        .temp_dir
+        .child("packages/seeds/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "seeds"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+    "#})?;
+    context
+        .temp_dir
+        .child("packages/nest/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "nest"
+        dynamic = ["version"]
+        requires-python = ">=3.12"
+    "#})?;
+    Ok(())
+}
+
+#[test]
+fn workspace_list_and_graph() -> Result<()> {
+    let context = TestContext::new("3.12");
+    write_member_graph(&context)?;
+
+    uv_snapshot!(context.filters(), context.workspace().arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross v0.1.0 (.)
+    bird-feeder v1.0.0 (packages/bird-feeder)
+    nest (packages/nest)
+    seeds v1.0.0 (packages/seeds)
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.workspace().arg("graph"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross
+      -> bird-feeder
+    bird-feeder
+      -> seeds
+    nest
+    seeds
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.workspace().arg("graph").arg("--json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "members": [
+        {
+          "name": "albatross",
+          "dependencies": [
+            "bird-feeder"
+          ]
+        },
+        {
+          "name": "bird-feeder",
+          "dependencies": [
+            "seeds"
+          ]
+        },
+        {
+          "name": "nest",
+          "dependencies": []
+        },
+        {
+          "name": "seeds",
+          "dependencies": []
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn workspace_affected() -> Result<()> {
+    let context = TestContext::new("3.12");
+    write_member_graph(&context)?;
+
+    // A change to `seeds` affects its transitive dependents.
+    uv_snapshot!(context.filters(), context.workspace().arg("affected").arg("packages/seeds/src/seeds/__init__.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross
+    bird-feeder
+    seeds
+
+    ----- stderr -----
+    "###);
+
+    // A change to `albatross` (the root) affects nothing else.
+    uv_snapshot!(context.filters(), context.workspace().arg("affected").arg("src/albatross/main.py").arg("--json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "changed": [
+        "albatross"
+      ],
+      "affected": [
+        "albatross"
+      ]
+    }
+
+    ----- stderr -----
+    "###);
+
+    // A change to the lockfile affects every member.
+    uv_snapshot!(context.filters(), context.workspace().arg("affected").arg("uv.lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross
+    bird-feeder
+    nest
+    seeds
+
+    ----- stderr -----
+    "###);
+
+    // Files outside the workspace affect nothing.
+    uv_snapshot!(context.filters(), context.workspace().arg("affected").arg("../elsewhere.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No workspace members are affected
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn workspace_dedupe_report() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder", "anyio>=3", "iniconfig"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+    context
+        .temp_dir
+        .child("packages/bird-feeder/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "bird-feeder"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=4,<5", "iniconfig"]
+
+        [dependency-groups]
+        test = ["pytest"]
+    "#})?;
+    context
+        .temp_dir
+        .child("packages/seeds/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "seeds"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["pytest>=8"]
+
+        [project.optional-dependencies]
+        trio = ["anyio[trio]>=4"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.workspace().arg("dedupe-report"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio
+      albatross (project.dependencies): anyio>=3
+      bird-feeder (project.dependencies): anyio>=4,<5
+      seeds (project.optional-dependencies.trio): anyio[trio]>=4
+    pytest
+      bird-feeder (dependency-groups.test): pytest
+      seeds (project.dependencies): pytest>=8
+
+    ----- stderr -----
+    Found 2 inconsistently specified dependencies
+    "###);
+
+    uv_snapshot!(context.filters(), context.workspace().arg("dedupe-report").arg("--json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "dependencies": [
+        {
+          "name": "anyio",
+          "declarations": [
+            {
+              "member": "albatross",
+              "section": "project.dependencies",
+              "requirement": "anyio>=3"
+            },
+            {
+              "member": "bird-feeder",
+              "section": "project.dependencies",
+              "requirement": "anyio>=4,<5"
+            },
+            {
+              "member": "seeds",
+              "section": "project.optional-dependencies.trio",
+              "requirement": "anyio[trio]>=4"
+            }
+          ]
+        },
+        {
+          "name": "pytest",
+          "declarations": [
+            {
+              "member": "bird-feeder",
+              "section": "dependency-groups.test",
+              "requirement": "pytest"
+            },
+            {
+              "member": "seeds",
+              "section": "project.dependencies",
+              "requirement": "pytest>=8"
+            }
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.workspace().arg("dedupe-report").arg("--write"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio
+      albatross (project.dependencies): anyio>=3
+      bird-feeder (project.dependencies): anyio>=4,<5
+      seeds (project.optional-dependencies.trio): anyio[trio]>=4
+    pytest
+      bird-feeder (dependency-groups.test): pytest
+      seeds (project.dependencies): pytest>=8
+
+    ----- stderr -----
+    Found 2 inconsistently specified dependencies
+    Moved the version specifiers for `anyio` to `tool.uv.workspace.dependencies`: `>=3, >=4, <5`
+    Moved the version specifiers for `pytest` to `tool.uv.workspace.dependencies`: `>=8`
+    "###);
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("pyproject.toml"), @r###"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder", "anyio", "iniconfig"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+        anyio = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [tool.uv.workspace.dependencies]
+        anyio = ">=3, >=4, <5"
+        pytest = ">=8"
+        "###
+        );
+    });
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("packages/seeds/pyproject.toml"), @r###"
+        [project]
+        name = "seeds"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["pytest"]
+
+        [project.optional-dependencies]
+        trio = ["anyio[trio]"]
+
+        [tool.uv.sources]
+        anyio = { workspace = true }
+        pytest = { workspace = true }
+        "###
+        );
+    });
+
+    // The members now inherit the shared version specifiers; only the extras differ.
+    uv_snapshot!(context.filters(), context.workspace().arg("dedupe-report"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio
+      albatross (project.dependencies): anyio
+      bird-feeder (project.dependencies): anyio
+      seeds (project.optional-dependencies.trio): anyio[trio]
+
+    ----- stderr -----
+    Found 1 inconsistently specified dependency
+    "###);
+
+    Ok(())
+}
+
+/// Members that opt in to `tool.uv.workspace.dependencies` inherit the shared specifications when
+/// locking.
+#[test]
+#[cfg(feature = "pypi")]
+fn workspace_shared_dependencies() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder", "anyio"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+        anyio = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [tool.uv.workspace.dependencies]
+        anyio = "<4"
+        iniconfig = { version = "<2", index = "pypi" }
+
+        [[tool.uv.index]]
+        name = "pypi"
+        url = "https://pypi.org/simple"
+    "#})?;
+    let member = context
+        .temp_dir
+        .child("packages/bird-feeder/pyproject.toml");
+    member.write_str(indoc! {r#"
+        [project]
+        name = "bird-feeder"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "iniconfig", "idna>=3"]
+
+        [tool.uv.sources]
+        iniconfig = { workspace = true }
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+
+    context.lock().assert().success();
+
+    // Both members inherit `anyio` (via the workspace root's sources); `bird-feeder` inherits
+    // `iniconfig` along with its index.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(r#"{ name = "anyio", specifier = "<4" }"#));
+    assert!(lock.contains(
+        r#"{ name = "iniconfig", specifier = "<2", index = "https://pypi.org/simple" }"#
+    ));
+    assert!(lock.contains("name = \"anyio\"\nversion = \"3.7.1\""));
+
+    // Changes to the shared specifications invalidate the lockfile.
+    pyproject_toml.write_str(
+        &context
+            .read("pyproject.toml")
+            .replace(r#"anyio = "<4""#, r#"anyio = "<3""#),
+    )?;
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    "###);
+
+    // A member that opts in can't declare its own version specifiers.
+    member.write_str(indoc! {r#"
+        [project]
+        name = "bird-feeder"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=1"]
+
+        [tool.uv.sources]
+        iniconfig = { workspace = true }
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `bird-feeder @ file://[TEMP_DIR]/packages/bird-feeder`
+      ├─▶ Failed to parse entry: `iniconfig`
+      ╰─▶ `iniconfig` inherits its specification from `tool.uv.workspace.dependencies` (`workspace = true`), and so can't declare its own version specifiers or URL
+    "###);
+
+    Ok(())
+}
+
+/// Workspace members can't be declared in `tool.uv.workspace.dependencies`.
+#[test]
+fn workspace_shared_dependencies_member() -> Result<()> {
+    let context = TestContext::new("3.12");
+    write_member_graph(&context)?;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [tool.uv.workspace.dependencies]
+        bird-feeder = ">=1"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.workspace().arg("list"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `bird-feeder` is a workspace member, and so can't be declared in `tool.uv.workspace.dependencies`
+    "###);
+
+    Ok(())
+}